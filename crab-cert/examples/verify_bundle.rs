@@ -0,0 +1,49 @@
+//! Verify a signed bundle (e.g. an order audit bundle exported by edge-server)
+//!
+//! ```text
+//! cargo run -p crab-cert --example verify_bundle -- audit_bundle.json [root_ca.pem]
+//! ```
+//!
+//! Without a Root CA only the bundle's own issuer CA is trusted.
+//! Prints the verified content on success; exits with status 1 on failure.
+
+use crab_cert::SignedBundle;
+use std::fs;
+
+fn main() -> Result<(), Box<dyn std::error::Error>> {
+    let mut args = std::env::args().skip(1);
+    let Some(bundle_path) = args.next() else {
+        eprintln!("Usage: verify_bundle <bundle.json> [root_ca.pem]");
+        std::process::exit(2);
+    };
+    let root_ca = args.next().map(fs::read_to_string).transpose()?;
+
+    let bundle: SignedBundle = serde_json::from_str(&fs::read_to_string(&bundle_path)?)?;
+    let metadata = crab_cert::CertMetadata::from_pem(&bundle.signer_cert_pem)?;
+
+    match bundle.verify(root_ca.as_deref()) {
+        Ok(()) => {
+            println!("OK: bundle signature and certificate chain are valid");
+            println!(
+                "  signer:  {}",
+                metadata.common_name.as_deref().unwrap_or("-")
+            );
+            println!("  sha256:  {}", bundle.content_sha256);
+            println!(
+                "  trusted: {}",
+                if root_ca.is_some() {
+                    "root CA"
+                } else {
+                    "bundle issuer CA only"
+                }
+            );
+            let content: serde_json::Value = bundle.content_json()?;
+            println!("{}", serde_json::to_string_pretty(&content)?);
+            Ok(())
+        }
+        Err(e) => {
+            eprintln!("FAILED: {}", e);
+            std::process::exit(1);
+        }
+    }
+}
@@ -0,0 +1,98 @@
+//! 签名包 (Signed Bundle)
+//!
+//! 把一段 JSON 内容连同签名和签名证书链打包，交给第三方离线验证：
+//!
+//! 1. `content_sha256` 与内容一致 (内容未被改动)
+//! 2. 签名由 `signer_cert_pem` 对应的私钥生成
+//! 3. 签名证书由 `issuer_ca_pem` 签发，且 (可选) 签发 CA 链到受信任的 Root CA
+//!
+//! 签名覆盖 `content` 的原始字节，不做 JSON 规范化：验证方必须原样使用 `content` 字符串。
+
+use base64::Engine;
+use serde::{Deserialize, Serialize};
+use sha2::{Digest, Sha256};
+
+use crate::crypto;
+use crate::error::{CertError, Result};
+use crate::trust::verify_chain_against_root;
+
+/// 当前签名包格式
+pub const SIGNED_BUNDLE_FORMAT: &str = "crab-signed-bundle/v1";
+
+/// 带签名和证书链的内容包
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SignedBundle {
+    /// 格式标识 ([`SIGNED_BUNDLE_FORMAT`])
+    pub format: String,
+    /// 被签名的 JSON 文本
+    pub content: String,
+    /// `content` 的 SHA-256 (hex)
+    pub content_sha256: String,
+    /// 对 `content` 字节的签名 (base64)
+    pub signature: String,
+    /// 签名证书 (PEM)
+    pub signer_cert_pem: String,
+    /// 签发签名证书的 CA (PEM)
+    pub issuer_ca_pem: String,
+}
+
+impl SignedBundle {
+    /// 用签名私钥对内容签名
+    pub fn sign(
+        content: String,
+        signer_key_pem: &str,
+        signer_cert_pem: &str,
+        issuer_ca_pem: &str,
+    ) -> Result<Self> {
+        let signature = crypto::sign(signer_key_pem, content.as_bytes())?;
+        // 签名证书与私钥必须配对，否则生成的包无法验证
+        crypto::verify(signer_cert_pem, content.as_bytes(), &signature)?;
+
+        Ok(Self {
+            format: SIGNED_BUNDLE_FORMAT.to_string(),
+            content_sha256: hex::encode(Sha256::digest(content.as_bytes())),
+            content,
+            signature: base64::engine::general_purpose::STANDARD.encode(signature),
+            signer_cert_pem: signer_cert_pem.to_string(),
+            issuer_ca_pem: issuer_ca_pem.to_string(),
+        })
+    }
+
+    /// 验证内容哈希、签名和证书链
+    ///
+    /// `root_ca_pem` 为 None 时只验证签名证书由包内 CA 签发 (信任包内 CA)。
+    pub fn verify(&self, root_ca_pem: Option<&str>) -> Result<()> {
+        if self.format != SIGNED_BUNDLE_FORMAT {
+            return Err(CertError::ValidationFailed(format!(
+                "Unsupported bundle format: {}",
+                self.format
+            )));
+        }
+
+        let digest = hex::encode(Sha256::digest(self.content.as_bytes()));
+        if digest != self.content_sha256 {
+            return Err(CertError::VerificationFailed(
+                "Content hash mismatch".into(),
+            ));
+        }
+
+        let signature = base64::engine::general_purpose::STANDARD
+            .decode(self.signature.as_bytes())
+            .map_err(|e| CertError::VerificationFailed(format!("Invalid signature: {}", e)))?;
+        crypto::verify(&self.signer_cert_pem, self.content.as_bytes(), &signature)?;
+
+        match root_ca_pem {
+            Some(root) => {
+                let chain = format!("{}\n{}", self.signer_cert_pem, self.issuer_ca_pem);
+                verify_chain_against_root(&chain, root)
+            }
+            None => verify_chain_against_root(&self.signer_cert_pem, &self.issuer_ca_pem),
+        }
+    }
+
+    /// 解析已验证的内容
+    pub fn content_json<T: serde::de::DeserializeOwned>(&self) -> Result<T> {
+        serde_json::from_str(&self.content)
+            .map_err(|e| CertError::ValidationFailed(format!("Invalid bundle content: {}", e)))
+    }
+}
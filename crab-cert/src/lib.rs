@@ -1,4 +1,5 @@
 mod adapter;
+pub mod bundle;
 mod ca;
 mod credential;
 mod crypto;
@@ -13,6 +14,7 @@ pub mod signer;
 pub mod trust;
 
 pub use adapter::{SkipHostnameVerifier, to_identity_pem, verify_client_cert, verify_server_cert};
+pub use bundle::{SIGNED_BUNDLE_FORMAT, SignedBundle};
 pub use ca::CertificateAuthority;
 pub use credential::{Credential, CredentialStorage};
 pub use crypto::{decrypt, encrypt, sign, to_rustls_certs, to_rustls_key, verify};
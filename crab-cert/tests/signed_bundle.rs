@@ -0,0 +1,89 @@
+use crab_cert::{CaProfile, CertProfile, CertificateAuthority, SignedBundle};
+
+/// Root → tenant CA → edge server cert, as provisioned in production
+fn edge_identity() -> (CertificateAuthority, CertificateAuthority, String, String) {
+    let root_profile = CaProfile {
+        common_name: "Crab Root CA".to_string(),
+        ..Default::default()
+    };
+    let root = CertificateAuthority::new_root(root_profile).expect("Failed to create Root CA");
+
+    let tenant_profile = CaProfile {
+        common_name: "Tenant CA".to_string(),
+        ..Default::default()
+    };
+    let tenant = CertificateAuthority::new_intermediate(tenant_profile, &root)
+        .expect("Failed to create tenant CA");
+
+    let edge_profile = CertProfile::new_server(
+        "edge.local",
+        vec!["edge.local".to_string()],
+        None,
+        "edge-device".to_string(),
+    );
+    let (cert_pem, key_pem) = tenant
+        .issue_cert(&edge_profile)
+        .expect("Failed to issue edge cert");
+    (root, tenant, cert_pem, key_pem)
+}
+
+#[test]
+fn test_signed_bundle_roundtrip() {
+    let (root, tenant, cert_pem, key_pem) = edge_identity();
+    let content = r#"{"order_id":1,"events":[]}"#.to_string();
+
+    let bundle = SignedBundle::sign(content, &key_pem, &cert_pem, tenant.cert_pem())
+        .expect("Signing failed");
+    bundle.verify(None).expect("Issuer verification failed");
+    bundle
+        .verify(Some(root.cert_pem()))
+        .expect("Root verification failed");
+
+    // Survives JSON transport unchanged
+    let json = serde_json::to_string(&bundle).unwrap();
+    let parsed: SignedBundle = serde_json::from_str(&json).unwrap();
+    parsed.verify(Some(root.cert_pem())).unwrap();
+    let value: serde_json::Value = parsed.content_json().unwrap();
+    assert_eq!(value["order_id"], 1);
+}
+
+#[test]
+fn test_signed_bundle_detects_tampering() {
+    let (root, tenant, cert_pem, key_pem) = edge_identity();
+    let bundle = SignedBundle::sign(
+        r#"{"total":10.0}"#.to_string(),
+        &key_pem,
+        &cert_pem,
+        tenant.cert_pem(),
+    )
+    .unwrap();
+
+    // Edited content (hash mismatch)
+    let mut tampered = bundle.clone();
+    tampered.content = r#"{"total":1.0}"#.to_string();
+    assert!(tampered.verify(None).is_err());
+
+    // Edited content with recomputed hash (signature mismatch)
+    tampered.content_sha256 = {
+        use sha2::Digest;
+        hex::encode(sha2::Sha256::digest(tampered.content.as_bytes()))
+    };
+    assert!(tampered.verify(None).is_err());
+
+    // Signing key that doesn't match the certificate
+    let (_, _, _, other_key) = edge_identity();
+    assert!(
+        SignedBundle::sign(
+            r#"{"total":1.0}"#.to_string(),
+            &other_key,
+            &cert_pem,
+            tenant.cert_pem(),
+        )
+        .is_err()
+    );
+
+    // Signed by a different tenant's chain
+    let (other_root, _, _, _) = edge_identity();
+    assert!(bundle.verify(Some(other_root.cert_pem())).is_err());
+    bundle.verify(Some(root.cert_pem())).unwrap();
+}
@@ -0,0 +1,5 @@
+//! Binding generator for the `ffi` feature (see `src/ffi.rs`).
+
+fn main() {
+    uniffi::uniffi_bindgen_main()
+}
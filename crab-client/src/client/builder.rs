@@ -4,7 +4,9 @@
 //! in different modes (Remote/Local).
 
 use std::path::PathBuf;
+use std::time::Duration;
 
+use crate::MessageClientConfig;
 use crate::error::ClientError;
 use crate::types::{Disconnected, Remote, StateMarker};
 
@@ -34,6 +36,7 @@ pub struct RemoteClientBuilder {
     edge_server_url: Option<String>,
     cert_path: Option<PathBuf>,
     client_name: Option<String>,
+    message_config: MessageClientConfig,
 }
 
 impl Default for RemoteClientBuilder {
@@ -50,6 +53,7 @@ impl RemoteClientBuilder {
             edge_server_url: None,
             cert_path: None,
             client_name: None,
+            message_config: MessageClientConfig::default(),
         }
     }
 
@@ -86,6 +90,49 @@ impl RemoteClientBuilder {
         self
     }
 
+    /// Replaces the message bus connection settings.
+    ///
+    /// Defaults to [`MessageClientConfig::lan`]. Integrations running outside the
+    /// store network usually want [`MessageClientConfig::wan`].
+    pub fn message_config(mut self, config: MessageClientConfig) -> Self {
+        self.message_config = config;
+        self
+    }
+
+    /// Sets the reconnect backoff: the first retry waits `initial`, each
+    /// following retry doubles the wait up to `max`.
+    pub fn reconnect_backoff(mut self, initial: Duration, max: Duration) -> Self {
+        self.message_config = self.message_config.with_reconnect_backoff(initial, max);
+        self
+    }
+
+    /// Sets the maximum number of reconnect attempts (0 = retry forever).
+    pub fn max_reconnect_attempts(mut self, attempts: u32) -> Self {
+        self.message_config = self.message_config.with_max_reconnect_attempts(attempts);
+        self
+    }
+
+    /// Enables or disables automatic reconnection.
+    pub fn auto_reconnect(mut self, enabled: bool) -> Self {
+        self.message_config = self.message_config.with_auto_reconnect(enabled);
+        self
+    }
+
+    /// Sets the heartbeat interval (`Duration::ZERO` disables it) and timeout.
+    pub fn heartbeat(mut self, interval: Duration, timeout: Duration) -> Self {
+        self.message_config = self
+            .message_config
+            .with_heartbeat_interval(interval)
+            .with_heartbeat_timeout(timeout);
+        self
+    }
+
+    /// Sets the default RPC request timeout.
+    pub fn request_timeout(mut self, timeout: Duration) -> Self {
+        self.message_config = self.message_config.with_request_timeout(timeout);
+        self
+    }
+
     /// Builds the remote client.
     ///
     /// # Errors
@@ -129,6 +176,7 @@ impl RemoteClientBuilder {
                 edge_url: edge_server_url,
                 cert_path: Some(cert_path),
                 client_name: Some(client_name),
+                message: self.message_config,
             },
         })
     }
@@ -258,6 +306,7 @@ impl LocalClientBuilder {
                 edge_url: None,
                 cert_path: None,
                 client_name: None,
+                message: MessageClientConfig::default(),
             },
         })
     }
@@ -278,4 +327,6 @@ pub struct ClientConfig {
     pub cert_path: Option<PathBuf>,
     /// Client name (Remote mode only).
     pub client_name: Option<String>,
+    /// Message bus connection settings (Remote mode only).
+    pub message: MessageClientConfig,
 }
@@ -228,6 +228,38 @@ impl<S: ClientState> CrabClient<Remote, S> {
         self.message.as_ref()
     }
 
+    /// Subscribes to the typed order event stream.
+    ///
+    /// Suitable for headless integrations (accounting bridges, KDS adapters):
+    /// only an mTLS connection is needed, no employee login. See
+    /// [`events::order_events`](super::events::order_events) for lag/close semantics.
+    ///
+    /// # Errors
+    ///
+    /// Returns `ClientError::Connection` if the client has not been connected yet.
+    pub fn order_events(
+        &self,
+    ) -> ClientResult<impl futures::Stream<Item = shared::order::OrderEvent> + use<S>> {
+        self.message
+            .as_ref()
+            .map(|m| m.order_events())
+            .ok_or_else(|| ClientError::Connection("Not connected".into()))
+    }
+
+    /// Watches the message bus connection state (Connected / Reconnecting / Disconnected).
+    ///
+    /// # Errors
+    ///
+    /// Returns `ClientError::Connection` if the client has not been connected yet.
+    pub fn watch_connection_state(
+        &self,
+    ) -> ClientResult<tokio::sync::watch::Receiver<super::message::ConnectionState>> {
+        self.message
+            .as_ref()
+            .map(|m| m.watch_connection_state())
+            .ok_or_else(|| ClientError::Connection("Not connected".into()))
+    }
+
     /// Transforms the client to a new state (internal use only).
     pub(crate) fn transition<NewS: ClientState>(self) -> CrabClient<Remote, NewS> {
         CrabClient {
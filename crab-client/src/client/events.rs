@@ -0,0 +1,191 @@
+// crab-client/src/client/events.rs
+// 类型化订单事件流 - 无 Tauri 依赖，供第三方集成 (会计桥接等) 使用
+
+use std::collections::{BTreeMap, VecDeque};
+
+use crab_order_core::{EventAction, EventApplier};
+use futures::Stream;
+use serde::Deserialize;
+use shared::cloud::SyncResource;
+use shared::message::{BusMessage, EventType, SyncPayload};
+use shared::order::{OrderEvent, OrderSnapshot};
+use tokio::sync::broadcast;
+
+/// `resource = order_sync` 的 data 字段
+///
+/// Edge 单事件推送 `{event, snapshot}`，同一命令产生的多事件合并为
+/// `{events, snapshots}`（见 edge-server `build_order_sync_payload`）。
+#[derive(Deserialize)]
+#[serde(untagged)]
+enum OrderSyncData {
+    Batch { events: Vec<OrderEvent> },
+    Single { event: Box<OrderEvent> },
+}
+
+/// 从一条总线消息中提取订单事件 (非订单同步消息返回空)
+pub fn decode_order_events(msg: &BusMessage) -> Vec<OrderEvent> {
+    if msg.event_type != EventType::Sync {
+        return Vec::new();
+    }
+    let Ok(sync) = msg.parse_versioned::<SyncPayload>() else {
+        return Vec::new();
+    };
+    if sync.resource != SyncResource::OrderSync {
+        return Vec::new();
+    }
+    match sync.data.map(serde_json::from_value::<OrderSyncData>) {
+        Some(Ok(OrderSyncData::Batch { events })) => events,
+        Some(Ok(OrderSyncData::Single { event })) => vec![*event],
+        Some(Err(e)) => {
+            tracing::warn!("Failed to decode order sync payload: {}", e);
+            Vec::new()
+        }
+        None => Vec::new(),
+    }
+}
+
+/// 把总线广播转换为 `OrderEvent` 流
+///
+/// - 批次按 sequence 顺序逐条展开
+/// - 接收方落后 (`Lagged`) 时跳过丢失的消息并记录告警；集成方应在
+///   `ReconnectEvent::Reconnected` 或发现 sequence 断档后通过 HTTP API 全量补齐
+/// - 底层连接被释放 (通道关闭) 时流结束
+pub fn order_events(rx: broadcast::Receiver<BusMessage>) -> impl Stream<Item = OrderEvent> {
+    futures::stream::unfold((rx, VecDeque::new()), |(mut rx, mut pending)| async move {
+        loop {
+            if let Some(event) = pending.pop_front() {
+                return Some((event, (rx, pending)));
+            }
+            match rx.recv().await {
+                Ok(msg) => pending.extend(decode_order_events(&msg)),
+                Err(broadcast::error::RecvError::Lagged(skipped)) => {
+                    tracing::warn!("Order event stream lagged, {} messages skipped", skipped);
+                }
+                Err(broadcast::error::RecvError::Closed) => return None,
+            }
+        }
+    })
+}
+
+/// 由事件流在本地维护的订单快照
+///
+/// 与 edge-server 使用同一份 applier (`crab-order-core`)，金额计算逐位一致。
+/// 中途接入的集成方应先用 HTTP 同步到的快照 `seed`，再应用后续事件；
+/// sequence 不大于快照 `last_sequence` 的事件视为已包含，直接忽略。
+#[derive(Debug, Default)]
+pub struct LocalOrderSnapshots {
+    snapshots: BTreeMap<i64, OrderSnapshot>,
+}
+
+impl LocalOrderSnapshots {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// 以权威快照作为起点 (覆盖本地状态)
+    pub fn seed(&mut self, snapshot: OrderSnapshot) {
+        self.snapshots.insert(snapshot.order_id, snapshot);
+    }
+
+    /// 应用一条事件，返回更新后的快照
+    pub fn apply(&mut self, event: &OrderEvent) -> &OrderSnapshot {
+        let snapshot = self
+            .snapshots
+            .entry(event.order_id)
+            .or_insert_with(|| OrderSnapshot::new(event.order_id));
+        if event.sequence > snapshot.last_sequence {
+            EventAction::from(event).apply(snapshot, event);
+        }
+        snapshot
+    }
+
+    pub fn get(&self, order_id: i64) -> Option<&OrderSnapshot> {
+        self.snapshots.get(&order_id)
+    }
+
+    /// 移出订单 (集成方处理完终态订单后调用)
+    pub fn remove(&mut self, order_id: i64) -> Option<OrderSnapshot> {
+        self.snapshots.remove(&order_id)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use futures::StreamExt;
+    use shared::message::{NotificationPayload, SyncChangeType};
+    use shared::order::{EventPayload, OrderEventType};
+
+    fn completed(sequence: u64, order_id: i64) -> OrderEvent {
+        OrderEvent::new(
+            sequence,
+            order_id,
+            1,
+            "Ana".to_string(),
+            sequence as i64,
+            None,
+            OrderEventType::OrderCompleted,
+            EventPayload::OrderCompleted {
+                receipt_number: format!("FAC{order_id}"),
+                service_type: None,
+                final_total: 12.5,
+                payment_summary: vec![],
+                fiscal_code: None,
+            },
+        )
+    }
+
+    fn order_sync(data: serde_json::Value) -> BusMessage {
+        BusMessage::sync(&SyncPayload {
+            resource: SyncResource::OrderSync,
+            version: 1,
+            action: SyncChangeType::Updated,
+            id: 1,
+            data: Some(data),
+            cloud_origin: false,
+        })
+    }
+
+    #[tokio::test]
+    async fn test_order_events_flattens_batches_and_skips_other_messages() {
+        let (tx, rx) = broadcast::channel(16);
+        let stream = order_events(rx);
+
+        tx.send(BusMessage::notification(&NotificationPayload::info(
+            "x", "y",
+        )))
+        .unwrap();
+        tx.send(order_sync(serde_json::json!({
+            "event": completed(1, 10),
+            "snapshot": {}
+        })))
+        .unwrap();
+        tx.send(order_sync(serde_json::json!({
+            "events": [completed(2, 11), completed(3, 12)],
+            "snapshots": []
+        })))
+        .unwrap();
+        drop(tx);
+
+        let sequences: Vec<u64> = stream.map(|e| e.sequence).collect().await;
+        assert_eq!(sequences, vec![1, 2, 3]);
+    }
+
+    #[test]
+    fn test_local_snapshots_apply_events_once() {
+        let mut local = LocalOrderSnapshots::new();
+
+        let snapshot = local.apply(&completed(5, 10));
+        assert_eq!(snapshot.status, shared::order::OrderStatus::Completed);
+        assert_eq!(snapshot.receipt_number, "FAC10");
+        assert_eq!(snapshot.last_sequence, 5);
+
+        // Already covered by the seeded snapshot
+        let mut seeded = OrderSnapshot::new(11);
+        seeded.last_sequence = 8;
+        local.seed(seeded);
+        assert_eq!(local.apply(&completed(7, 11)).last_sequence, 8);
+        assert_eq!(local.remove(11).unwrap().last_sequence, 8);
+        assert!(local.get(11).is_none());
+    }
+}
@@ -2,19 +2,27 @@
 // RPC 消息客户端 - mTLS 和内存通信
 
 use rustls_pki_types::{CertificateDer, ServerName};
-use shared::message::{BusMessage, HandshakePayload, PROTOCOL_VERSION, RequestCommandPayload};
+use shared::message::{
+    BusMessage, HandshakeAck, HandshakePayload, PROTOCOL_VERSION, PayloadCompression,
+    PayloadEncoding, RequestCommandPayload, compression, trace_context,
+};
+use shared::models::TerminalProfile;
+use shared::order::OrderCommand;
 use std::collections::HashMap;
 use std::sync::Arc;
 use std::sync::atomic::{AtomicBool, AtomicU32, Ordering};
 use std::time::Duration;
 use tokio::io::{AsyncReadExt, AsyncWriteExt, ReadHalf, WriteHalf};
 use tokio::net::TcpStream;
-use tokio::sync::{Mutex, Notify, RwLock, broadcast, oneshot};
+use tokio::sync::{Mutex, Notify, RwLock, broadcast, oneshot, watch};
 use tokio::task::JoinHandle;
 use tokio_rustls::TlsConnector;
 use tokio_rustls::client::TlsStream;
 use uuid::Uuid;
 
+use super::offline_queue::{
+    OfflineCommandQueue, OrderCommandOutcome, ReplayResult, command_response, order_request,
+};
 use crate::MessageClientConfig;
 use crate::error::ClientError;
 
@@ -22,6 +30,8 @@ use crate::error::ClientError;
 const TCP_CONNECT_TIMEOUT: Duration = Duration::from_secs(5);
 /// TLS handshake timeout
 const TLS_HANDSHAKE_TIMEOUT: Duration = Duration::from_secs(10);
+/// 压缩帧解压后的载荷上限 (与服务端单帧上限一致)
+const MAX_DECOMPRESSED_SIZE: usize = 16 * 1024 * 1024;
 
 /// 连接参数 (用于重连)
 #[derive(Clone)]
@@ -82,12 +92,15 @@ pub struct HeartbeatStatus {
 /// - 非响应消息广播给所有订阅者
 /// - 心跳任务检测连接状态
 /// - 自动重连并通知订阅者
+/// - 断线期间的订单命令持久化入队，重连后按序重放 (启用 `offline_queue_path` 时)
 #[derive(Clone)]
 pub struct NetworkMessageClient {
     /// 写入流 (发送请求)
     write_stream: Arc<RwLock<Option<WriteHalf<TlsStream<TcpStream>>>>>,
     /// 连接状态
     state: Arc<AtomicU32>,
+    /// 连接状态观察通道 (与 `state` 同步更新)
+    state_tx: Arc<watch::Sender<ConnectionState>>,
     /// 非响应消息广播通道 (通知、同步信号等)
     notification_tx: broadcast::Sender<BusMessage>,
     /// 等待响应的 RPC 请求 (correlation_id -> response sender)
@@ -106,6 +119,14 @@ pub struct NetworkMessageClient {
     stopped: Arc<AtomicBool>,
     /// 后台读取任务句柄 (用于重连时 abort 旧任务)
     reader_handle: Arc<Mutex<Option<JoinHandle<()>>>>,
+    /// 断线命令队列 (未配置时断线命令直接失败)
+    offline_queue: Option<OfflineCommandQueue>,
+    /// 订单命令发送锁 (队列重放与新命令串行，保证顺序)
+    order_lock: Arc<Mutex<()>>,
+    /// 离线命令重放结果通道
+    replay_tx: broadcast::Sender<ReplayResult>,
+    /// 最近一次握手响应 (终端身份 + 漫游配置，重连时刷新)
+    handshake_ack: Arc<RwLock<HandshakeAck>>,
 }
 
 impl std::fmt::Debug for NetworkMessageClient {
@@ -135,6 +156,7 @@ impl NetworkMessageClient {
             ConnectionState::Reconnecting => 2,
         };
         self.state.store(val, Ordering::SeqCst);
+        self.state_tx.send_replace(state);
     }
 
     /// 使用 mTLS 连接到 Edge Server
@@ -186,6 +208,13 @@ impl NetworkMessageClient {
             client_name: client_name.to_string(),
         };
 
+        // 断线命令队列 (先于连接打开，队列文件损坏时直接报错)
+        let offline_queue = config
+            .offline_queue_path
+            .as_ref()
+            .map(OfflineCommandQueue::open)
+            .transpose()?;
+
         // 建立 TLS 连接
         let tls_stream = Self::establish_tls_connection(&conn_params).await?;
 
@@ -196,6 +225,7 @@ impl NetworkMessageClient {
         let (notification_tx, _) = broadcast::channel(64);
         let (reconnect_tx, _) = broadcast::channel(16);
         let (heartbeat_tx, _) = broadcast::channel(16);
+        let (replay_tx, _) = broadcast::channel(64);
         let pending_requests: Arc<Mutex<HashMap<Uuid, oneshot::Sender<BusMessage>>>> =
             Arc::new(Mutex::new(HashMap::new()));
         let stop_notify = Arc::new(Notify::new());
@@ -203,6 +233,7 @@ impl NetworkMessageClient {
         let client = Self {
             write_stream: Arc::new(RwLock::new(Some(write_half))),
             state: Arc::new(AtomicU32::new(0)), // Connected
+            state_tx: Arc::new(watch::channel(ConnectionState::Connected).0),
             notification_tx,
             pending_requests,
             reconnect_tx,
@@ -212,6 +243,10 @@ impl NetworkMessageClient {
             stop_notify,
             stopped: Arc::new(AtomicBool::new(false)),
             reader_handle: Arc::new(Mutex::new(None)),
+            offline_queue,
+            order_lock: Arc::new(Mutex::new(())),
+            replay_tx,
+            handshake_ack: Arc::new(RwLock::new(HandshakeAck::default())),
         };
 
         // 启动后台读取任务
@@ -227,6 +262,9 @@ impl NetworkMessageClient {
             client.spawn_heartbeat_task();
         }
 
+        // 上次运行遗留的离线命令
+        client.spawn_replay_task();
+
         Ok(client)
     }
 
@@ -321,6 +359,7 @@ impl NetworkMessageClient {
         let pending = self.pending_requests.clone();
         let notify_tx = self.notification_tx.clone();
         let state = self.state.clone();
+        let state_tx = self.state_tx.clone();
         let reconnect_tx = self.reconnect_tx.clone();
         let stop_notify = self.stop_notify.clone();
         let stopped = self.stopped.clone();
@@ -331,6 +370,7 @@ impl NetworkMessageClient {
                 pending,
                 notify_tx,
                 state,
+                state_tx,
                 reconnect_tx,
                 stop_notify,
                 stopped,
@@ -450,6 +490,7 @@ impl NetworkMessageClient {
         {
             return;
         }
+        self.state_tx.send_replace(ConnectionState::Disconnected);
 
         tracing::info!("Connection lost, starting reconnection...");
 
@@ -554,6 +595,7 @@ impl NetworkMessageClient {
 
                         // 通知订阅者
                         let _ = self.reconnect_tx.send(ReconnectEvent::Reconnected);
+                        self.spawn_replay_task();
                         break;
                     }
                 }
@@ -601,11 +643,13 @@ impl NetworkMessageClient {
     }
 
     /// 后台读取任务循环
+    #[allow(clippy::too_many_arguments)]
     async fn reader_task_loop(
         mut read_half: ReadHalf<TlsStream<TcpStream>>,
         pending_requests: Arc<Mutex<HashMap<Uuid, oneshot::Sender<BusMessage>>>>,
         notification_tx: broadcast::Sender<BusMessage>,
         state: Arc<AtomicU32>,
+        state_tx: Arc<watch::Sender<ConnectionState>>,
         reconnect_tx: broadcast::Sender<ReconnectEvent>,
         stop_notify: Arc<Notify>,
         stopped: Arc<AtomicBool>,
@@ -653,6 +697,7 @@ impl NetworkMessageClient {
                                 .compare_exchange(0, 1, Ordering::SeqCst, Ordering::SeqCst)
                                 .is_ok()
                             {
+                                state_tx.send_replace(ConnectionState::Disconnected);
                                 let _ = reconnect_tx.send(ReconnectEvent::Disconnected);
                             }
                             break;
@@ -679,7 +724,9 @@ impl NetworkMessageClient {
             .await
             .map_err(|e| ClientError::Connection(format!("Read type failed: {}", e)))?;
 
-        let event_type = shared::EventType::try_from(type_buf[0])
+        let (type_byte, compressed) = compression::split_type_byte(type_buf[0]);
+        let (type_byte, traced) = trace_context::split_type_byte(type_byte);
+        let event_type = shared::EventType::try_from(type_byte)
             .map_err(|_| ClientError::InvalidMessage("Invalid event type".to_string()))?;
 
         // 读取 Request ID (16 字节)
@@ -703,6 +750,21 @@ impl NetworkMessageClient {
             Some(correlation_id_raw)
         };
 
+        // 读取 Trace Context (1 字节长度 + traceparent，握手协商后服务端响应携带)
+        let trace_context = if traced {
+            let ctx_len = &mut [0u8; 1];
+            stream.read_exact(ctx_len).await.map_err(|e| {
+                ClientError::Connection(format!("Read trace context failed: {}", e))
+            })?;
+            let mut ctx = vec![0u8; ctx_len[0] as usize];
+            stream.read_exact(&mut ctx).await.map_err(|e| {
+                ClientError::Connection(format!("Read trace context failed: {}", e))
+            })?;
+            trace_context::from_wire(&ctx)
+        } else {
+            None
+        };
+
         // 读取载荷长度 (4 字节)
         let len_buf = &mut [0u8; 4];
         stream
@@ -719,6 +781,12 @@ impl NetworkMessageClient {
             .await
             .map_err(|e| ClientError::Connection(format!("Read payload failed: {}", e)))?;
 
+        // 压缩帧 (握手协商后服务端对大载荷启用)
+        if compressed {
+            payload = compression::decompress(&payload, MAX_DECOMPRESSED_SIZE)
+                .map_err(ClientError::InvalidMessage)?;
+        }
+
         Ok(BusMessage {
             request_id,
             event_type,
@@ -726,6 +794,7 @@ impl NetworkMessageClient {
             correlation_id,
             target: None,
             payload,
+            trace_context,
         })
     }
 
@@ -736,6 +805,21 @@ impl NetworkMessageClient {
             client_name: Some(client_name.to_string()),
             client_version: Some(env!("CARGO_PKG_VERSION").to_string()),
             client_id: Some(Uuid::new_v4().to_string()),
+            accept_encodings: if self.config.binary_payloads {
+                vec![PayloadEncoding::Postcard, PayloadEncoding::Json]
+            } else {
+                Vec::new()
+            },
+            accept_compression: if self.config.compression {
+                vec![PayloadCompression::Zstd]
+            } else {
+                Vec::new()
+            },
+            subscribe_kpi: self.config.subscribe_kpi,
+            announcements: true,
+            topics: self.config.topics.clone(),
+            filters: self.config.filters.clone(),
+            trace_context: true,
         });
 
         // 发送握手消息
@@ -758,13 +842,29 @@ impl NetworkMessageClient {
                     payload.message
                 )));
             }
-            tracing::debug!("Handshake successful: {}", payload.message);
+            // 下行载荷由 parse_payload 透明解码，这里只记录协商结果
+            let ack: HandshakeAck = payload
+                .data
+                .and_then(|data| serde_json::from_value(data).ok())
+                .unwrap_or_default();
+            tracing::debug!(
+                encoding = ?ack.encoding,
+                compression = ?ack.compression,
+                trace_context = ack.trace_context,
+                identity = ?ack.identity,
+                has_profile = ack.terminal_profile.is_some(),
+                "Handshake successful: {}",
+                payload.message
+            );
+            *self.handshake_ack.write().await = ack;
         }
 
         Ok(())
     }
 
     /// 写入消息
+    ///
+    /// 服务端确认支持时附带 trace context (消息自带或当前 span 的 `traceparent`)
     async fn write_message(&self, msg: &BusMessage) -> Result<(), ClientError> {
         // 检查是否有活跃连接
         {
@@ -774,6 +874,15 @@ impl NetworkMessageClient {
             }
         }
 
+        let traceparent = if self.handshake_ack.read().await.trace_context {
+            msg.trace_context
+                .clone()
+                .or_else(shared::telemetry::current_traceparent)
+        } else {
+            None
+        };
+        let ctx = trace_context::wire_value(traceparent.as_deref());
+
         // 获取写锁来实际写入
         let mut guard = self.write_stream.write().await;
         let stream = guard
@@ -782,7 +891,10 @@ impl NetworkMessageClient {
 
         // 序列化消息
         let mut data = Vec::new();
-        data.push(msg.event_type as u8);
+        match ctx {
+            Some(_) => data.push(msg.event_type as u8 | trace_context::TRACE_CONTEXT_FLAG),
+            None => data.push(msg.event_type as u8),
+        }
         data.extend_from_slice(msg.request_id.as_bytes());
 
         // Correlation ID (16 bytes, nil if None)
@@ -793,6 +905,12 @@ impl NetworkMessageClient {
             .to_vec();
         data.extend_from_slice(&correlation_bytes);
 
+        // Trace context (1 byte length + traceparent, only when flagged)
+        if let Some(ctx) = ctx {
+            data.push(ctx.len() as u8);
+            data.extend_from_slice(ctx);
+        }
+
         // Payload length (4 bytes) + payload
         let payload_len = msg.payload.len() as u32;
         data.extend_from_slice(&payload_len.to_le_bytes());
@@ -841,6 +959,22 @@ impl NetworkMessageClient {
         self.get_state()
     }
 
+    /// 观察连接状态变化
+    ///
+    /// 与 [`subscribe_reconnect`](Self::subscribe_reconnect) 不同，watch 只保留最新状态，
+    /// 适合 UI / 集成方按需读取或 `changed().await` 等待状态切换（包括 `Reconnecting`）。
+    pub fn watch_connection_state(&self) -> watch::Receiver<ConnectionState> {
+        self.state_tx.subscribe()
+    }
+
+    /// 订阅订单事件流 (类型化)
+    ///
+    /// 解析 `resource = order_sync` 的 Sync 消息（单条与合并批次），按顺序逐条产出
+    /// [`OrderEvent`](shared::order::OrderEvent)。详见 [`super::events::order_events`]。
+    pub fn order_events(&self) -> impl futures::Stream<Item = shared::order::OrderEvent> + use<> {
+        super::events::order_events(self.notification_tx.subscribe())
+    }
+
     /// 订阅重连事件
     ///
     /// 当连接断开或重连成功时会收到通知。
@@ -1014,9 +1148,175 @@ impl NetworkMessageClient {
 
         // 通知订阅者
         let _ = self.reconnect_tx.send(ReconnectEvent::Reconnected);
+        self.spawn_replay_task();
 
         Ok(())
     }
+
+    // ========== 订单命令 (断线队列) ==========
+
+    /// 发送订单命令
+    ///
+    /// 启用断线队列时：先重放队列中的命令 (保证顺序)；未连接、连接中断或超时时
+    /// 命令入队并返回 [`OrderCommandOutcome::Queued`]，重连后重放，结果通过
+    /// [`subscribe_replay`](Self::subscribe_replay) 通知。服务端按 `command_id` 幂等，
+    /// 超时但已执行的命令重放时返回成功。
+    pub async fn send_order_command(
+        &self,
+        action: &str,
+        command: &OrderCommand,
+    ) -> Result<OrderCommandOutcome, ClientError> {
+        let msg = order_request(action, command)?;
+        let Some(queue) = &self.offline_queue else {
+            let response = self.request_default(&msg).await?;
+            return Ok(OrderCommandOutcome::Completed(command_response(
+                command.command_id,
+                &response,
+            )));
+        };
+
+        let _guard = self.order_lock.lock().await;
+        if self.is_connected() {
+            self.replay_queue(queue).await;
+        }
+        if self.is_connected() && queue.is_empty()? {
+            match self.request_default(&msg).await {
+                Ok(response) => {
+                    return Ok(OrderCommandOutcome::Completed(command_response(
+                        command.command_id,
+                        &response,
+                    )));
+                }
+                Err(ClientError::Connection(e)) | Err(ClientError::Timeout(e)) => {
+                    tracing::warn!(
+                        command_id = command.command_id,
+                        action,
+                        "Order command failed ({}), queued for replay",
+                        e
+                    );
+                }
+                Err(e) => return Err(e),
+            }
+        }
+
+        let pending = queue.enqueue(action, command)?;
+        tracing::info!(
+            command_id = command.command_id,
+            action,
+            pending,
+            "Order command queued while offline"
+        );
+        Ok(OrderCommandOutcome::Queued {
+            command_id: command.command_id,
+            pending,
+        })
+    }
+
+    /// 待重放的离线命令数 (未启用断线队列时为 0)
+    pub fn pending_offline_commands(&self) -> usize {
+        self.offline_queue
+            .as_ref()
+            .and_then(|q| q.len().ok())
+            .unwrap_or(0)
+    }
+
+    /// 订阅离线命令重放结果
+    ///
+    /// 重连后每条重放的命令产出一个 [`ReplayResult`]；`is_conflict()` 表示服务端拒绝
+    /// (离线期间订单已被其他终端修改)，调用方应提示操作员并刷新订单。
+    pub fn subscribe_replay(&self) -> broadcast::Receiver<ReplayResult> {
+        self.replay_tx.subscribe()
+    }
+
+    /// 服务端认定的终端身份 (证书 CN)，保存漫游配置时作为键；旧服务端为 None
+    pub async fn terminal_identity(&self) -> Option<String> {
+        self.handshake_ack.read().await.identity.clone()
+    }
+
+    /// 握手时服务端下发的终端漫游配置 (该身份从未保存过时为 None)
+    pub async fn terminal_profile(&self) -> Option<TerminalProfile> {
+        self.handshake_ack.read().await.terminal_profile.clone()
+    }
+
+    /// 启动后台重放任务
+    fn spawn_replay_task(&self) {
+        if self.offline_queue.is_none() {
+            return;
+        }
+        let client = self.clone();
+        tokio::spawn(async move {
+            let Some(queue) = &client.offline_queue else {
+                return;
+            };
+            let _guard = client.order_lock.lock().await;
+            client.replay_queue(queue).await;
+        });
+    }
+
+    /// 按入队顺序重放 (调用方持有 `order_lock`)
+    ///
+    /// 连接再次中断时停止，剩余命令留在队列中等待下次重连。
+    async fn replay_queue(&self, queue: &OfflineCommandQueue) {
+        loop {
+            let entry = match queue.front() {
+                Ok(Some(entry)) => entry,
+                Ok(None) => return,
+                Err(e) => {
+                    tracing::error!("Failed to read offline command queue: {}", e);
+                    return;
+                }
+            };
+            if !self.is_connected() {
+                return;
+            }
+
+            let response = match order_request(&entry.action, &entry.command) {
+                Ok(msg) => match self.request_default(&msg).await {
+                    Ok(response) => command_response(entry.command.command_id, &response),
+                    Err(ClientError::Connection(_)) | Err(ClientError::Timeout(_)) => {
+                        tracing::debug!(
+                            "Offline replay interrupted, {} command(s) left",
+                            queue.len().unwrap_or(0)
+                        );
+                        return;
+                    }
+                    Err(e) => command_response_error(entry.command.command_id, e),
+                },
+                Err(e) => command_response_error(entry.command.command_id, e),
+            };
+
+            if let Err(e) = queue.remove(entry.seq) {
+                tracing::error!("Failed to remove replayed command: {}", e);
+                return;
+            }
+            if !response.success {
+                tracing::warn!(
+                    command_id = entry.command.command_id,
+                    action = %entry.action,
+                    "Offline command rejected on replay"
+                );
+            }
+            let _ = self.replay_tx.send(ReplayResult {
+                action: entry.action,
+                command: entry.command,
+                response,
+            });
+        }
+    }
+}
+
+/// 非连接类错误 → 失败的 CommandResponse (重放时不再重试)
+fn command_response_error(command_id: i64, error: ClientError) -> shared::order::CommandResponse {
+    shared::order::CommandResponse {
+        command_id,
+        success: false,
+        order_id: None,
+        error: Some(shared::order::CommandError::new(
+            shared::order::CommandErrorCode::InternalError,
+            error.to_string(),
+        )),
+        current_snapshot: None,
+    }
 }
 
 /// 内存消息客户端 (同进程通信)
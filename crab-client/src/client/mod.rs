@@ -6,14 +6,18 @@
 // Core modules
 mod builder;
 mod common;
+pub mod events;
 pub mod http;
 #[cfg(feature = "in-process")]
 pub mod http_oneshot;
 mod local;
 pub mod message;
+pub mod offline_queue;
 mod remote;
 
 // Re-export main types
+#[cfg(feature = "ffi")]
+pub(crate) use builder::RemoteClientBuilder;
 pub use common::CrabClient;
 pub use http::{HttpClient, NetworkHttpClient};
 #[cfg(feature = "in-process")]
@@ -21,6 +25,7 @@ pub use http_oneshot::OneshotHttpClient;
 pub use message::{
     ConnectionState, HeartbeatStatus, InMemoryMessageClient, NetworkMessageClient, ReconnectEvent,
 };
+pub use offline_queue::{OfflineCommandQueue, OrderCommandOutcome, QueuedCommand, ReplayResult};
 
 // Re-export message config from parent module
 pub use crate::message::MessageClientConfig;
@@ -0,0 +1,264 @@
+//! 断线命令队列 (Remote 模式离线操作)
+//!
+//! 连接断开时订单命令持久化到本地 redb，重连后按入队顺序重放：
+//!
+//! ```text
+//! send_order_command ──connected──→ RequestCommand ──→ Completed(CommandResponse)
+//!        │ disconnected / timeout
+//!        ▼
+//! offline_commands (redb) ──reconnect──→ replay in order ──→ ReplayResult (subscribe_replay)
+//! ```
+//!
+//! - 服务端按 `command_id` 幂等：超时后已执行的命令重放时返回成功 (duplicate)
+//! - 重放被拒绝 (订单已被其他终端修改/结单等) 视为冲突，通过 [`ReplayResult`] 交给调用方处理，
+//!   不阻塞后续命令
+
+use std::path::Path;
+use std::sync::Arc;
+
+use redb::{Database, ReadableDatabase, ReadableTable, ReadableTableMetadata, TableDefinition};
+use serde::{Deserialize, Serialize};
+use shared::message::{BusMessage, RequestCommandPayload, ResponsePayload};
+use shared::order::{CommandError, CommandErrorCode, CommandResponse, OrderCommand};
+
+use crate::error::{ClientError, ClientResult};
+
+/// 队列表: key = 入队序号, value = JSON QueuedCommand
+const QUEUE_TABLE: TableDefinition<u64, &[u8]> = TableDefinition::new("offline_commands");
+
+/// 已入队的订单命令
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct QueuedCommand {
+    /// 入队序号 (重放顺序)
+    pub seq: u64,
+    /// RequestCommand action (e.g. "order.add_items")
+    pub action: String,
+    pub command: OrderCommand,
+    /// 入队时间 (Unix 毫秒)
+    pub queued_at: i64,
+}
+
+/// 订单命令发送结果
+#[derive(Debug, Clone)]
+pub enum OrderCommandOutcome {
+    /// 服务端已处理
+    Completed(CommandResponse),
+    /// 已离线入队，重连后重放 (结果见 `subscribe_replay`)
+    Queued {
+        command_id: i64,
+        /// 队列中待重放的命令数 (含本条)
+        pending: usize,
+    },
+}
+
+/// 离线命令重放结果
+#[derive(Debug, Clone)]
+pub struct ReplayResult {
+    pub action: String,
+    pub command: OrderCommand,
+    pub response: CommandResponse,
+}
+
+impl ReplayResult {
+    /// 服务端拒绝了该命令 (离线期间订单状态已变化)
+    pub fn is_conflict(&self) -> bool {
+        !self.response.success
+    }
+}
+
+/// redb 持久化的离线命令队列
+#[derive(Clone)]
+pub struct OfflineCommandQueue {
+    db: Arc<Database>,
+}
+
+impl std::fmt::Debug for OfflineCommandQueue {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("OfflineCommandQueue")
+            .field("pending", &self.len().ok())
+            .finish()
+    }
+}
+
+fn storage_err(e: impl std::fmt::Display) -> ClientError {
+    ClientError::Internal(format!("Offline queue: {e}"))
+}
+
+impl OfflineCommandQueue {
+    /// 打开 (或创建) 队列文件
+    pub fn open(path: impl AsRef<Path>) -> ClientResult<Self> {
+        let db = Database::create(path.as_ref()).map_err(storage_err)?;
+        Self::init(db)
+    }
+
+    /// 内存队列 (测试用)
+    pub fn open_in_memory() -> ClientResult<Self> {
+        let db = Database::builder()
+            .create_with_backend(redb::backends::InMemoryBackend::new())
+            .map_err(storage_err)?;
+        Self::init(db)
+    }
+
+    fn init(db: Database) -> ClientResult<Self> {
+        let txn = db.begin_write().map_err(storage_err)?;
+        txn.open_table(QUEUE_TABLE).map_err(storage_err)?;
+        txn.commit().map_err(storage_err)?;
+        Ok(Self { db: Arc::new(db) })
+    }
+
+    /// 追加命令，返回队列长度
+    pub fn enqueue(&self, action: &str, command: &OrderCommand) -> ClientResult<usize> {
+        let txn = self.db.begin_write().map_err(storage_err)?;
+        let len = {
+            let mut table = txn.open_table(QUEUE_TABLE).map_err(storage_err)?;
+            let seq = table
+                .last()
+                .map_err(storage_err)?
+                .map(|(k, _)| k.value() + 1)
+                .unwrap_or(1);
+            let entry = QueuedCommand {
+                seq,
+                action: action.to_string(),
+                command: command.clone(),
+                queued_at: shared::util::now_millis(),
+            };
+            let value = serde_json::to_vec(&entry)?;
+            table.insert(seq, value.as_slice()).map_err(storage_err)?;
+            table.len().map_err(storage_err)? as usize
+        };
+        txn.commit().map_err(storage_err)?;
+        Ok(len)
+    }
+
+    /// 队首命令
+    pub fn front(&self) -> ClientResult<Option<QueuedCommand>> {
+        let txn = self.db.begin_read().map_err(storage_err)?;
+        let table = txn.open_table(QUEUE_TABLE).map_err(storage_err)?;
+        match table.first().map_err(storage_err)? {
+            Some((_, value)) => Ok(Some(serde_json::from_slice(value.value())?)),
+            None => Ok(None),
+        }
+    }
+
+    /// 全部待重放命令 (按入队顺序)
+    pub fn pending(&self) -> ClientResult<Vec<QueuedCommand>> {
+        let txn = self.db.begin_read().map_err(storage_err)?;
+        let table = txn.open_table(QUEUE_TABLE).map_err(storage_err)?;
+        let mut commands = Vec::new();
+        for row in table.iter().map_err(storage_err)? {
+            let (_, value) = row.map_err(storage_err)?;
+            commands.push(serde_json::from_slice(value.value())?);
+        }
+        Ok(commands)
+    }
+
+    /// 移除已重放的命令
+    pub fn remove(&self, seq: u64) -> ClientResult<()> {
+        let txn = self.db.begin_write().map_err(storage_err)?;
+        {
+            let mut table = txn.open_table(QUEUE_TABLE).map_err(storage_err)?;
+            table.remove(seq).map_err(storage_err)?;
+        }
+        txn.commit().map_err(storage_err)?;
+        Ok(())
+    }
+
+    /// 待重放命令数
+    pub fn len(&self) -> ClientResult<usize> {
+        let txn = self.db.begin_read().map_err(storage_err)?;
+        let table = txn.open_table(QUEUE_TABLE).map_err(storage_err)?;
+        Ok(table.len().map_err(storage_err)? as usize)
+    }
+
+    pub fn is_empty(&self) -> ClientResult<bool> {
+        Ok(self.len()? == 0)
+    }
+}
+
+/// 构建订单命令的 RequestCommand 消息 (params = 完整 OrderCommand，保留 command_id)
+pub(crate) fn order_request(action: &str, command: &OrderCommand) -> ClientResult<BusMessage> {
+    Ok(BusMessage::request_command(&RequestCommandPayload {
+        action: action.to_string(),
+        params: Some(serde_json::to_value(command)?),
+    }))
+}
+
+/// 解析订单命令响应 (RPC 失败映射为失败的 CommandResponse)
+pub(crate) fn command_response(command_id: i64, response: &BusMessage) -> CommandResponse {
+    let failed = |message: String| CommandResponse {
+        command_id,
+        success: false,
+        order_id: None,
+        error: Some(CommandError::new(CommandErrorCode::InternalError, message)),
+        current_snapshot: None,
+    };
+    match response.parse_payload::<ResponsePayload>() {
+        Ok(payload) if payload.success => match payload.data {
+            Some(data) => serde_json::from_value(data)
+                .unwrap_or_else(|e| failed(format!("Failed to parse server response: {e}"))),
+            None => CommandResponse::duplicate(command_id),
+        },
+        Ok(payload) => failed(payload.message),
+        Err(e) => failed(format!("Invalid response: {e}")),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use shared::order::OrderCommandPayload;
+
+    fn command(order_id: i64) -> OrderCommand {
+        OrderCommand::new(
+            1,
+            "Cashier".to_string(),
+            OrderCommandPayload::AddOrderNote {
+                order_id,
+                note: "no onions".to_string(),
+            },
+        )
+    }
+
+    #[test]
+    fn test_queue_preserves_order() {
+        let queue = OfflineCommandQueue::open_in_memory().unwrap();
+        assert!(queue.is_empty().unwrap());
+
+        let first = command(1);
+        let second = command(2);
+        assert_eq!(queue.enqueue("order.add_order_note", &first).unwrap(), 1);
+        assert_eq!(queue.enqueue("order.add_order_note", &second).unwrap(), 2);
+
+        let pending = queue.pending().unwrap();
+        assert_eq!(pending.len(), 2);
+        assert_eq!(pending[0].command.command_id, first.command_id);
+        assert_eq!(pending[1].command.command_id, second.command_id);
+
+        let front = queue.front().unwrap().unwrap();
+        assert_eq!(front.command.command_id, first.command_id);
+        queue.remove(front.seq).unwrap();
+        assert_eq!(
+            queue.front().unwrap().unwrap().command.command_id,
+            second.command_id
+        );
+
+        // 序号单调递增 (移除队首后不复用)
+        assert_eq!(queue.enqueue("order.add_order_note", &first).unwrap(), 2);
+        assert!(queue.pending().unwrap()[1].seq > pending[1].seq);
+    }
+
+    #[test]
+    fn test_command_response_maps_rejection() {
+        let rejected =
+            BusMessage::response(&ResponsePayload::error("Order already completed", None));
+        let response = command_response(42, &rejected);
+        assert!(!response.success);
+        assert_eq!(response.command_id, 42);
+
+        let ok = BusMessage::response(&ResponsePayload::success(
+            "ok",
+            serde_json::to_value(CommandResponse::duplicate(42)).ok(),
+        ));
+        assert!(command_response(42, &ok).success);
+    }
+}
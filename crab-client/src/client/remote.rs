@@ -94,15 +94,17 @@ impl CrabClient<Remote, Disconnected> {
 
         // 4. Connect to message server
         tracing::info!("Connecting to message server: {}", message_addr);
-        let message_client = crate::client::message::NetworkMessageClient::connect_mtls(
-            message_addr,
-            ca_cert_pem.as_bytes(),
-            cert_pem.as_bytes(),
-            key_pem.as_bytes(),
-            &handshake_name,
-        )
-        .await
-        .map_err(|e| ClientError::Connection(e.to_string()))?;
+        let message_client =
+            crate::client::message::NetworkMessageClient::connect_mtls_with_config(
+                message_addr,
+                ca_cert_pem.as_bytes(),
+                cert_pem.as_bytes(),
+                key_pem.as_bytes(),
+                &handshake_name,
+                self.config.message.clone(),
+            )
+            .await
+            .map_err(|e| ClientError::Connection(e.to_string()))?;
 
         self.message = Some(message_client);
 
@@ -197,15 +199,17 @@ impl CrabClient<Remote, Disconnected> {
             "Connecting to message server with cached credentials: {}",
             message_addr
         );
-        let message_client = crate::client::message::NetworkMessageClient::connect_mtls(
-            message_addr,
-            ca_cert_pem.as_bytes(),
-            cert_pem.as_bytes(),
-            key_pem.as_bytes(),
-            &handshake_name,
-        )
-        .await
-        .map_err(|e| ClientError::Connection(e.to_string()))?;
+        let message_client =
+            crate::client::message::NetworkMessageClient::connect_mtls_with_config(
+                message_addr,
+                ca_cert_pem.as_bytes(),
+                cert_pem.as_bytes(),
+                key_pem.as_bytes(),
+                &handshake_name,
+                self.config.message.clone(),
+            )
+            .await
+            .map_err(|e| ClientError::Connection(e.to_string()))?;
 
         self.message = Some(message_client);
 
@@ -575,6 +579,24 @@ impl CrabClient<Remote, Authenticated> {
         client.request(msg, timeout).await
     }
 
+    /// Sends an order command, queueing it while offline.
+    ///
+    /// Requires `MessageClientConfig::with_offline_queue`; without it this behaves
+    /// like [`request`](Self::request) and fails when disconnected. Replay results
+    /// are delivered via `NetworkMessageClient::subscribe_replay`.
+    pub async fn send_order_command(
+        &self,
+        action: &str,
+        command: &shared::order::OrderCommand,
+    ) -> Result<crate::OrderCommandOutcome, ClientError> {
+        let client = self
+            .message
+            .as_ref()
+            .ok_or_else(|| ClientError::Connection("Not connected".into()))?;
+
+        client.send_order_command(action, command).await
+    }
+
     /// Logs out the employee.
     ///
     /// This clears the session token but keeps the connection open.
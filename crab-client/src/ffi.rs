@@ -0,0 +1,347 @@
+//! FFI bindings (uniffi) for Python and other non-Rust integrators.
+//!
+//! Enabled with the `ffi` feature. Exposes a deliberately small surface —
+//! connect, login, JSON `get`/`post`, and event subscriptions — on top of the
+//! typestate [`CrabClient`], with mTLS and certificate caching handled by the
+//! Rust side exactly as for the POS.
+//!
+//! Payloads cross the boundary as JSON strings so that binding code never has
+//! to mirror `shared` types.
+//!
+//! # Building the Python module
+//!
+//! ```bash
+//! cargo rustc -p crab-client --features ffi --release --crate-type cdylib
+//! cargo run -p crab-client --features ffi --bin uniffi-bindgen -- \
+//!     generate --library target/release/libcrab_client.so --language python --out-dir out/
+//! ```
+//!
+//! ```python
+//! from crab_client import EdgeClient
+//!
+//! client = EdgeClient("https://auth.example.com", "./certs", "accounting-bridge", None)
+//! await client.connect("edge.local:8081")
+//! events = await client.subscribe_order_events()
+//! while (event := await events.next()) is not None:
+//!     print(event.kind, event.payload_json)
+//! ```
+
+use std::pin::Pin;
+use std::sync::Arc;
+
+use futures::{Stream, StreamExt};
+use tokio::sync::{Mutex, broadcast};
+
+use crate::client::CrabClient;
+use crate::error::ClientError;
+use crate::message::BusMessage;
+use crate::types::{Authenticated, Connected, Disconnected, Remote};
+
+/// Error surfaced to foreign callers (message only, via `Display`)
+#[derive(Debug, thiserror::Error, uniffi::Error)]
+#[uniffi(flat_error)]
+pub enum FfiError {
+    #[error(transparent)]
+    Client(#[from] ClientError),
+
+    /// Method called in the wrong connection stage (e.g. `get` before `login`)
+    #[error("Invalid state: {0}")]
+    InvalidState(String),
+
+    #[error("Invalid JSON: {0}")]
+    Json(#[from] serde_json::Error),
+}
+
+/// Server-pushed event
+#[derive(Debug, Clone, uniffi::Record)]
+pub struct FfiEvent {
+    /// Bus event type (`notification`, `sync`, ...) or `order_event`
+    pub kind: String,
+    /// JSON payload
+    pub payload_json: String,
+}
+
+/// Typestate client behind a lock — FFI objects are shared (`Arc`) and
+/// cannot move between types, so each transition swaps the stage.
+enum Stage {
+    Disconnected(CrabClient<Remote, Disconnected>),
+    Connected(CrabClient<Remote, Connected>),
+    Authenticated(CrabClient<Remote, Authenticated>),
+}
+
+impl Stage {
+    fn name(&self) -> &'static str {
+        match self {
+            Stage::Disconnected(_) => "disconnected",
+            Stage::Connected(_) => "connected",
+            Stage::Authenticated(_) => "authenticated",
+        }
+    }
+
+    fn message_client(&self) -> Option<&crate::NetworkMessageClient> {
+        match self {
+            Stage::Disconnected(c) => c.message_client(),
+            Stage::Connected(c) => c.message_client(),
+            Stage::Authenticated(c) => c.message_client(),
+        }
+    }
+}
+
+/// Remote (mTLS) edge client for foreign languages
+#[derive(uniffi::Object)]
+pub struct EdgeClient {
+    builder: crate::client::RemoteClientBuilder,
+    /// `None` only while a transition is in flight
+    stage: Mutex<Option<Stage>>,
+}
+
+impl EdgeClient {
+    fn take(stage: &mut Option<Stage>) -> Result<Stage, FfiError> {
+        stage
+            .take()
+            .ok_or_else(|| FfiError::InvalidState("client is poisoned".into()))
+    }
+
+    /// Fresh disconnected client (the previous one was consumed by a failed connect)
+    fn rebuild(&self) -> Result<Stage, FfiError> {
+        Ok(Stage::Disconnected(self.builder.clone().build()?))
+    }
+
+    fn wrong_stage(expected: &str, stage: &Stage) -> FfiError {
+        FfiError::InvalidState(format!("expected {expected}, client is {}", stage.name()))
+    }
+
+    async fn message_client<T>(
+        &self,
+        f: impl FnOnce(&crate::NetworkMessageClient) -> T,
+    ) -> Result<T, FfiError> {
+        let guard = self.stage.lock().await;
+        guard
+            .as_ref()
+            .and_then(Stage::message_client)
+            .map(f)
+            .ok_or_else(|| FfiError::InvalidState("not connected".into()))
+    }
+
+    async fn connect_with(
+        &self,
+        connect: impl AsyncFnOnce(
+            CrabClient<Remote, Disconnected>,
+        ) -> Result<CrabClient<Remote, Connected>, ClientError>,
+    ) -> Result<(), FfiError> {
+        let mut guard = self.stage.lock().await;
+        let client = match Self::take(&mut guard)? {
+            Stage::Disconnected(client) => client,
+            other => {
+                let err = Self::wrong_stage("disconnected", &other);
+                *guard = Some(other);
+                return Err(err);
+            }
+        };
+        match connect(client).await {
+            Ok(client) => {
+                *guard = Some(Stage::Connected(client));
+                Ok(())
+            }
+            Err(e) => {
+                *guard = Some(self.rebuild()?);
+                Err(e.into())
+            }
+        }
+    }
+}
+
+#[uniffi::export(async_runtime = "tokio")]
+impl EdgeClient {
+    /// Create a client; certificates are cached under `{cert_path}/{client_name}/`
+    #[uniffi::constructor]
+    pub fn new(
+        auth_server: String,
+        cert_path: String,
+        client_name: String,
+        edge_server: Option<String>,
+    ) -> Result<Arc<Self>, FfiError> {
+        let mut builder = CrabClient::remote()
+            .auth_server(auth_server)
+            .cert_path(cert_path)
+            .client_name(client_name)
+            .message_config(crate::MessageClientConfig::wan());
+        if let Some(url) = edge_server {
+            builder = builder.edge_server(url);
+        }
+        let client = builder.clone().build()?;
+        Ok(Arc::new(Self {
+            builder,
+            stage: Mutex::new(Some(Stage::Disconnected(client))),
+        }))
+    }
+
+    /// Whether certificates from a previous `setup` are cached locally
+    pub async fn has_cached_credentials(&self) -> bool {
+        match self.stage.lock().await.as_ref() {
+            Some(Stage::Disconnected(c)) => c.has_cached_credentials(),
+            Some(Stage::Connected(c)) => c.has_cached_credentials(),
+            Some(Stage::Authenticated(c)) => c.has_cached_credentials(),
+            None => false,
+        }
+    }
+
+    /// First-time setup: download certificates with tenant credentials, then connect
+    pub async fn setup(
+        &self,
+        tenant_username: String,
+        tenant_password: String,
+        message_addr: String,
+    ) -> Result<(), FfiError> {
+        self.connect_with(async |client| {
+            client
+                .setup(&tenant_username, &tenant_password, &message_addr)
+                .await
+        })
+        .await
+    }
+
+    /// Connect with cached certificates
+    pub async fn connect(&self, message_addr: String) -> Result<(), FfiError> {
+        self.connect_with(async |client| client.connect_with_credentials(&message_addr).await)
+            .await
+    }
+
+    /// Employee login (required for `get` / `post`)
+    pub async fn login(&self, username: String, password: String) -> Result<(), FfiError> {
+        let mut guard = self.stage.lock().await;
+        let client = match Self::take(&mut guard)? {
+            Stage::Connected(client) => client,
+            other => {
+                let err = Self::wrong_stage("connected", &other);
+                *guard = Some(other);
+                return Err(err);
+            }
+        };
+        match client.login(&username, &password).await {
+            Ok(client) => {
+                *guard = Some(Stage::Authenticated(client));
+                Ok(())
+            }
+            Err((e, client)) => {
+                *guard = Some(Stage::Connected(client));
+                Err(e.into())
+            }
+        }
+    }
+
+    pub async fn logout(&self) -> Result<(), FfiError> {
+        let mut guard = self.stage.lock().await;
+        *guard = Some(match Self::take(&mut guard)? {
+            Stage::Authenticated(client) => Stage::Connected(client.logout().await),
+            other => other,
+        });
+        Ok(())
+    }
+
+    pub async fn disconnect(&self) -> Result<(), FfiError> {
+        let mut guard = self.stage.lock().await;
+        *guard = Some(match Self::take(&mut guard)? {
+            Stage::Connected(client) => Stage::Disconnected(client.disconnect().await),
+            Stage::Authenticated(client) => Stage::Disconnected(client.disconnect().await),
+            other => other,
+        });
+        Ok(())
+    }
+
+    pub async fn is_connected(&self) -> bool {
+        self.stage
+            .lock()
+            .await
+            .as_ref()
+            .and_then(Stage::message_client)
+            .is_some_and(|m| m.is_connected())
+    }
+
+    /// `GET {path}` on the edge HTTPS API, returns the JSON response body
+    pub async fn get(&self, path: String) -> Result<String, FfiError> {
+        let guard = self.stage.lock().await;
+        match guard.as_ref() {
+            Some(Stage::Authenticated(client)) => {
+                let value: serde_json::Value = client.get(&path).await?;
+                Ok(value.to_string())
+            }
+            Some(other) => Err(Self::wrong_stage("authenticated", other)),
+            None => Err(FfiError::InvalidState("client is poisoned".into())),
+        }
+    }
+
+    /// `POST {path}` with a JSON body, returns the JSON response body
+    pub async fn post(&self, path: String, body_json: String) -> Result<String, FfiError> {
+        let body: serde_json::Value = serde_json::from_str(&body_json)?;
+        let guard = self.stage.lock().await;
+        match guard.as_ref() {
+            Some(Stage::Authenticated(client)) => {
+                let value: serde_json::Value = client.post(&path, &body).await?;
+                Ok(value.to_string())
+            }
+            Some(other) => Err(Self::wrong_stage("authenticated", other)),
+            None => Err(FfiError::InvalidState("client is poisoned".into())),
+        }
+    }
+
+    /// All server-pushed messages (notifications, sync signals, ...)
+    pub async fn subscribe(&self) -> Result<Arc<EventSubscription>, FfiError> {
+        let rx = self.message_client(|m| m.subscribe()).await?;
+        Ok(EventSubscription::new(bus_events(rx)))
+    }
+
+    /// Typed order events (one `order_event` per event, batches flattened)
+    pub async fn subscribe_order_events(&self) -> Result<Arc<EventSubscription>, FfiError> {
+        let events = self.message_client(|m| m.order_events()).await?;
+        Ok(EventSubscription::new(events.map(|event| FfiEvent {
+            kind: "order_event".to_string(),
+            payload_json: serde_json::to_string(&event).unwrap_or_default(),
+        })))
+    }
+}
+
+/// Non-response bus messages as `FfiEvent`s (lagged messages are skipped)
+fn bus_events(rx: broadcast::Receiver<BusMessage>) -> impl Stream<Item = FfiEvent> {
+    futures::stream::unfold(rx, |mut rx| async move {
+        loop {
+            match rx.recv().await {
+                Ok(msg) => {
+                    let payload = msg
+                        .parse_payload::<serde_json::Value>()
+                        .unwrap_or(serde_json::Value::Null);
+                    let event = FfiEvent {
+                        kind: msg.event_type.to_string(),
+                        payload_json: payload.to_string(),
+                    };
+                    return Some((event, rx));
+                }
+                Err(broadcast::error::RecvError::Lagged(skipped)) => {
+                    tracing::warn!("FFI subscription lagged, {} messages skipped", skipped);
+                }
+                Err(broadcast::error::RecvError::Closed) => return None,
+            }
+        }
+    })
+}
+
+/// Pull-based event subscription (`await sub.next()`, `None` once closed)
+#[derive(uniffi::Object)]
+pub struct EventSubscription {
+    stream: Mutex<Pin<Box<dyn Stream<Item = FfiEvent> + Send>>>,
+}
+
+impl EventSubscription {
+    fn new(stream: impl Stream<Item = FfiEvent> + Send + 'static) -> Arc<Self> {
+        Arc::new(Self {
+            stream: Mutex::new(Box::pin(stream)),
+        })
+    }
+}
+
+#[uniffi::export(async_runtime = "tokio")]
+impl EventSubscription {
+    pub async fn next(&self) -> Option<FfiEvent> {
+        self.stream.lock().await.next().await
+    }
+}
@@ -38,6 +38,43 @@
 //! # }
 //! ```
 //!
+//! # Example (Headless integration)
+//!
+//! External integrations (accounting bridges, delivery adapters) need no
+//! Tauri runtime and no employee login — an mTLS connection is enough to
+//! follow orders as they change:
+//!
+//! ```no_run
+//! use std::time::Duration;
+//! use crab_client::{CrabClient, MessageClientConfig};
+//! use futures::StreamExt;
+//!
+//! # async fn example() -> Result<(), crab_client::ClientError> {
+//! let client = CrabClient::remote()
+//!     .auth_server("https://auth.example.com")
+//!     .cert_path("./certs")
+//!     .client_name("accounting-bridge")
+//!     .message_config(MessageClientConfig::wan())
+//!     .reconnect_backoff(Duration::from_secs(2), Duration::from_secs(120))
+//!     .build()?
+//!     .connect_with_credentials("edge:8081")
+//!     .await?;
+//!
+//! let mut state = client.watch_connection_state()?;
+//! tokio::spawn(async move {
+//!     while state.changed().await.is_ok() {
+//!         println!("bus: {:?}", *state.borrow());
+//!     }
+//! });
+//!
+//! let mut events = Box::pin(client.order_events()?);
+//! while let Some(event) = events.next().await {
+//!     println!("order {} seq {}: {:?}", event.order_id, event.sequence, event.event_type);
+//! }
+//! # Ok(())
+//! # }
+//! ```
+//!
 //! # Example (Local Mode - requires "in-process" feature)
 //!
 //! ```ignore
@@ -64,10 +101,15 @@
 //! let response = client.request(&msg).await?;
 //! ```
 
+#[cfg(feature = "ffi")]
+uniffi::setup_scaffolding!();
+
 // Core modules
 mod cert;
 mod client;
 pub mod error;
+#[cfg(feature = "ffi")]
+pub mod ffi;
 pub mod message;
 pub mod types;
 
@@ -79,9 +121,13 @@ pub use cert::{CertError, CertManager, Credential, CredentialStorage};
 pub use client::OneshotHttpClient;
 pub use client::{
     ConnectionState, CrabClient, HeartbeatStatus, HttpClient, InMemoryMessageClient,
-    MessageClientConfig, NetworkHttpClient, NetworkMessageClient, ReconnectEvent,
+    MessageClientConfig, NetworkHttpClient, NetworkMessageClient, OfflineCommandQueue,
+    OrderCommandOutcome, QueuedCommand, ReconnectEvent, ReplayResult,
 };
 
+// Typed event streams (headless integrations)
+pub use client::events;
+
 // Re-export type markers
 pub use types::{
     Authenticated, ClientMode, ClientState, ClientStatus, Connected, Disconnected, Local, Remote,
@@ -1,8 +1,9 @@
 // crab-client/src/message/mod.rs
 // 消息模块 - RPC 客户端配置和错误类型
 
-pub use shared::message::{BusMessage, EventType};
+pub use shared::message::{BusMessage, BusTopic, EventType, SubscriptionFilter};
 
+use std::path::PathBuf;
 use std::time::Duration;
 
 /// 消息客户端配置
@@ -24,6 +25,18 @@ pub struct MessageClientConfig {
     pub heartbeat_timeout: Duration,
     /// 重连时网络探测间隔 (在退避等待期间探测网络恢复)
     pub reconnect_probe_interval: Duration,
+    /// 握手时请求二进制载荷编码 (服务端不支持时自动退回 JSON)
+    pub binary_payloads: bool,
+    /// 握手时请求帧压缩 (大载荷 zstd 压缩；服务端不支持时不压缩)
+    pub compression: bool,
+    /// 握手时订阅实时经营指标推送 (经理看板)
+    pub subscribe_kpi: bool,
+    /// 订阅的广播主题 (为空 = 全量；`System` 始终投递)
+    pub topics: Vec<BusTopic>,
+    /// 服务端订阅过滤 (如只接收某区域的订单事件；为空 = 不过滤)
+    pub filters: Vec<SubscriptionFilter>,
+    /// 断线命令队列文件 (redb)；设置后断线期间的订单命令入队，重连后重放
+    pub offline_queue_path: Option<PathBuf>,
 }
 
 impl Default for MessageClientConfig {
@@ -43,6 +56,12 @@ impl Default for MessageClientConfig {
             heartbeat_interval: Duration::from_secs(5),  // 每 5 秒心跳
             heartbeat_timeout: Duration::from_secs(1),   // 1 秒超时（局域网 RTT <1ms）
             reconnect_probe_interval: Duration::from_secs(1), // 每 1 秒探测
+            binary_payloads: true,
+            compression: true,
+            subscribe_kpi: false,
+            topics: Vec::new(),
+            filters: Vec::new(),
+            offline_queue_path: None,
         }
     }
 }
@@ -77,6 +96,12 @@ impl MessageClientConfig {
             heartbeat_interval: Duration::from_secs(30),
             heartbeat_timeout: Duration::from_secs(5),
             reconnect_probe_interval: Duration::from_secs(5),
+            binary_payloads: true,
+            compression: true,
+            subscribe_kpi: false,
+            topics: Vec::new(),
+            filters: Vec::new(),
+            offline_queue_path: None,
         }
     }
 
@@ -104,6 +129,49 @@ impl MessageClientConfig {
         self
     }
 
+    /// 设置是否请求二进制载荷编码
+    pub fn with_binary_payloads(mut self, enabled: bool) -> Self {
+        self.binary_payloads = enabled;
+        self
+    }
+
+    /// 设置是否请求帧压缩
+    pub fn with_compression(mut self, enabled: bool) -> Self {
+        self.compression = enabled;
+        self
+    }
+
+    /// 设置是否订阅实时经营指标推送
+    pub fn with_kpi_subscription(mut self, enabled: bool) -> Self {
+        self.subscribe_kpi = enabled;
+        self
+    }
+
+    /// 设置订阅的广播主题 (如 KDS 只需 `Orders` + `Kds`)
+    pub fn with_topics(mut self, topics: impl IntoIterator<Item = BusTopic>) -> Self {
+        self.topics = topics.into_iter().collect();
+        self
+    }
+
+    /// 设置服务端订阅过滤 (条件之间为 OR，响应和定向消息始终投递)
+    pub fn with_filters(mut self, filters: impl IntoIterator<Item = SubscriptionFilter>) -> Self {
+        self.filters = filters.into_iter().collect();
+        self
+    }
+
+    /// 启用断线命令队列 (见 [`crate::OfflineCommandQueue`])
+    pub fn with_offline_queue(mut self, path: impl Into<PathBuf>) -> Self {
+        self.offline_queue_path = Some(path.into());
+        self
+    }
+
+    /// 设置重连退避 (首次延迟，之后每次翻倍直到上限)
+    pub fn with_reconnect_backoff(mut self, initial: Duration, max: Duration) -> Self {
+        self.reconnect_delay = initial;
+        self.max_reconnect_delay = max.max(initial);
+        self
+    }
+
     /// 设置最大重连尝试次数 (0 表示无限重试)
     pub fn with_max_reconnect_attempts(mut self, attempts: u32) -> Self {
         self.max_reconnect_attempts = attempts;
@@ -134,6 +202,14 @@ mod tests {
         assert!(!config.auto_reconnect);
     }
 
+    #[test]
+    fn test_reconnect_backoff_cap_not_below_initial() {
+        let config = MessageClientConfig::wan()
+            .with_reconnect_backoff(Duration::from_secs(5), Duration::from_secs(1));
+        assert_eq!(config.reconnect_delay, Duration::from_secs(5));
+        assert_eq!(config.max_reconnect_delay, Duration::from_secs(5));
+    }
+
     #[tokio::test]
     async fn test_in_memory_rpc() {
         use crate::InMemoryMessageClient;
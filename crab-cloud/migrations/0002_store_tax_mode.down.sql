@@ -0,0 +1 @@
+ALTER TABLE stores DROP COLUMN IF EXISTS tax_mode;
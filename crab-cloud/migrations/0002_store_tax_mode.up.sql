@@ -0,0 +1,2 @@
+-- Store-level pricing mode: 'INCLUSIVE' (prices include tax) | 'EXCLUSIVE' (tax added on top)
+ALTER TABLE stores ADD COLUMN tax_mode TEXT NOT NULL DEFAULT 'INCLUSIVE';
@@ -0,0 +1 @@
+ALTER TABLE store_price_rules DROP COLUMN IF EXISTS priority;
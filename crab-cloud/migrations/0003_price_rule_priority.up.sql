@@ -0,0 +1,2 @@
+-- Explicit priority among overlapping price rules of the same scope (higher wins)
+ALTER TABLE store_price_rules ADD COLUMN priority INTEGER NOT NULL DEFAULT 0;
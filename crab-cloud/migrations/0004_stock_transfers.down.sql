@@ -0,0 +1,3 @@
+DROP INDEX IF EXISTS idx_stock_transfers_from_pending;
+DROP INDEX IF EXISTS idx_stock_transfers_to_pending;
+DROP TABLE IF EXISTS stock_transfers;
@@ -0,0 +1,19 @@
+-- Inter-store stock transfers relayed between edges of the same tenant (门店间调拨).
+-- The dispatch is re-delivered to the receiving store and the receipt to the
+-- sending store on every reconnect until the edge acknowledges it.
+CREATE TABLE IF NOT EXISTS stock_transfers (
+    id                    BIGINT  PRIMARY KEY,   -- assigned by the sending edge
+    tenant_id             BIGINT  NOT NULL,
+    from_store_id         BIGINT  NOT NULL,
+    to_store_id           BIGINT  NOT NULL,
+    dispatch              JSONB   NOT NULL,
+    receipt               JSONB,
+    sent_at               BIGINT  NOT NULL,
+    received_at           BIGINT,
+    dispatch_delivered_at BIGINT,
+    receipt_delivered_at  BIGINT
+);
+CREATE INDEX IF NOT EXISTS idx_stock_transfers_to_pending
+    ON stock_transfers(to_store_id) WHERE dispatch_delivered_at IS NULL;
+CREATE INDEX IF NOT EXISTS idx_stock_transfers_from_pending
+    ON stock_transfers(from_store_id) WHERE receipt IS NOT NULL AND receipt_delivered_at IS NULL;
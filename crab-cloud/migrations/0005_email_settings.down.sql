@@ -0,0 +1,2 @@
+DROP TABLE IF EXISTS tenant_email_templates;
+DROP TABLE IF EXISTS tenant_smtp_settings;
@@ -0,0 +1,22 @@
+-- Tenant-owned SMTP relay (password AES-256-GCM encrypted with the master key)
+CREATE TABLE IF NOT EXISTS tenant_smtp_settings (
+    tenant_id           BIGINT PRIMARY KEY,
+    host                TEXT NOT NULL,
+    port                INTEGER NOT NULL,
+    security            TEXT NOT NULL DEFAULT 'starttls',
+    username            TEXT,
+    password_encrypted  TEXT,
+    from_address        TEXT NOT NULL,
+    from_name           TEXT,
+    updated_at          BIGINT NOT NULL
+);
+
+-- Tenant overrides of the built-in email templates ('receipt' | 'report' | 'reservation_confirmation')
+CREATE TABLE IF NOT EXISTS tenant_email_templates (
+    tenant_id   BIGINT NOT NULL,
+    kind        TEXT NOT NULL,
+    subject     TEXT NOT NULL,
+    body        TEXT NOT NULL,
+    updated_at  BIGINT NOT NULL,
+    PRIMARY KEY (tenant_id, kind)
+);
@@ -0,0 +1,2 @@
+DROP INDEX IF EXISTS idx_store_archived_orders_metadata;
+ALTER TABLE store_archived_orders DROP COLUMN IF EXISTS metadata;
@@ -0,0 +1,4 @@
+-- Per-order integrator metadata synced from edge (外卖平台单号、酒店房号等)
+ALTER TABLE store_archived_orders ADD COLUMN IF NOT EXISTS metadata JSONB;
+CREATE INDEX IF NOT EXISTS idx_store_archived_orders_metadata
+    ON store_archived_orders USING GIN (metadata);
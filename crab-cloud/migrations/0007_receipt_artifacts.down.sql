@@ -0,0 +1,2 @@
+DROP INDEX IF EXISTS idx_sra_receipt;
+DROP TABLE IF EXISTS store_receipt_artifacts;
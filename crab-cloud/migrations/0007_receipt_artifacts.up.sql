@@ -0,0 +1,18 @@
+-- Archived receipt bytes synced from edge (收据存档, opt-in per store).
+-- Write-once: rows are never updated; re-sync of the same artifact is ignored.
+CREATE TABLE IF NOT EXISTS store_receipt_artifacts (
+    id              BIGSERIAL PRIMARY KEY,
+    store_id        BIGINT  NOT NULL,
+    tenant_id       BIGINT  NOT NULL,
+    source_id       BIGINT  NOT NULL,
+    receipt_number  TEXT    NOT NULL,
+    kind            TEXT    NOT NULL,
+    sha256          TEXT    NOT NULL,
+    reprint         BOOLEAN NOT NULL DEFAULT FALSE,
+    content         BYTEA   NOT NULL,
+    created_at      BIGINT  NOT NULL,
+    synced_at       BIGINT  NOT NULL,
+    UNIQUE(tenant_id, store_id, source_id)
+);
+CREATE INDEX IF NOT EXISTS idx_sra_receipt
+    ON store_receipt_artifacts(store_id, tenant_id, receipt_number);
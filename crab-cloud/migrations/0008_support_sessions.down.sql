@@ -0,0 +1,6 @@
+DROP INDEX IF EXISTS idx_support_log_lines_session;
+DROP TABLE IF EXISTS support_log_lines;
+DROP INDEX IF EXISTS idx_support_messages_session;
+DROP TABLE IF EXISTS support_messages;
+DROP INDEX IF EXISTS idx_support_sessions_opened;
+DROP TABLE IF EXISTS support_sessions;
@@ -0,0 +1,41 @@
+-- Remote support sessions relayed from edge (远程支持).
+-- Opened on the edge with store consent; log lines arrive already sanitized and
+-- are only accepted while the session is open and not expired.
+CREATE TABLE IF NOT EXISTS support_sessions (
+    id              BIGSERIAL PRIMARY KEY,
+    store_id        BIGINT  NOT NULL,
+    tenant_id       BIGINT  NOT NULL,
+    session_id      BIGINT  NOT NULL,
+    subject         TEXT    NOT NULL,
+    opened_by       TEXT    NOT NULL,
+    consent_by      TEXT    NOT NULL,
+    diagnostics     JSONB   NOT NULL,
+    opened_at       BIGINT  NOT NULL,
+    expires_at      BIGINT  NOT NULL,
+    closed_at       BIGINT,
+    UNIQUE(store_id, session_id)
+);
+CREATE INDEX IF NOT EXISTS idx_support_sessions_opened
+    ON support_sessions(opened_at DESC);
+
+-- Chat between store staff and support agents (author: 'STORE' | 'AGENT')
+CREATE TABLE IF NOT EXISTS support_messages (
+    id              BIGINT  PRIMARY KEY,
+    support_id      BIGINT  NOT NULL REFERENCES support_sessions(id) ON DELETE CASCADE,
+    author          TEXT    NOT NULL,
+    author_name     TEXT    NOT NULL,
+    text            TEXT    NOT NULL,
+    sent_at         BIGINT  NOT NULL
+);
+CREATE INDEX IF NOT EXISTS idx_support_messages_session
+    ON support_messages(support_id, sent_at);
+
+-- Sanitized edge log lines (purged 7 days after the session closes)
+CREATE TABLE IF NOT EXISTS support_log_lines (
+    id              BIGSERIAL PRIMARY KEY,
+    support_id      BIGINT  NOT NULL REFERENCES support_sessions(id) ON DELETE CASCADE,
+    line            TEXT    NOT NULL,
+    received_at     BIGINT  NOT NULL
+);
+CREATE INDEX IF NOT EXISTS idx_support_log_lines_session
+    ON support_log_lines(support_id, id);
@@ -0,0 +1,2 @@
+DROP INDEX IF EXISTS idx_store_coupons_store;
+DROP TABLE IF EXISTS store_coupons;
@@ -0,0 +1,23 @@
+-- Coupons / promo codes (优惠码)
+-- used_count is incremented on the edge when an order completes and synced back.
+CREATE TABLE IF NOT EXISTS store_coupons (
+    id               BIGSERIAL PRIMARY KEY,
+    store_id         BIGINT NOT NULL REFERENCES stores(id) ON DELETE CASCADE,
+    source_id        BIGINT NOT NULL,
+    code             TEXT NOT NULL,
+    name             TEXT NOT NULL,
+    product_scope    TEXT NOT NULL,
+    target_id        BIGINT,
+    adjustment_type  TEXT NOT NULL,
+    adjustment_value DOUBLE PRECISION NOT NULL,
+    max_uses         BIGINT,
+    used_count       BIGINT NOT NULL DEFAULT 0,
+    valid_from       BIGINT,
+    valid_until      BIGINT,
+    is_active        BOOLEAN NOT NULL DEFAULT TRUE,
+    created_at       BIGINT NOT NULL,
+    updated_at       BIGINT NOT NULL,
+    UNIQUE (store_id, source_id),
+    UNIQUE (store_id, code)
+);
+CREATE INDEX IF NOT EXISTS idx_store_coupons_store ON store_coupons(store_id);
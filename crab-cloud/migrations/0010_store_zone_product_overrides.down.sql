@@ -0,0 +1,2 @@
+DROP INDEX IF EXISTS idx_store_zone_overrides_zone;
+DROP TABLE IF EXISTS store_zone_product_overrides;
@@ -0,0 +1,14 @@
+-- Per-zone product price / availability overrides (区域商品覆盖)
+CREATE TABLE IF NOT EXISTS store_zone_product_overrides (
+    id                BIGSERIAL PRIMARY KEY,
+    store_id          BIGINT NOT NULL REFERENCES stores(id) ON DELETE CASCADE,
+    source_id         BIGINT NOT NULL,
+    zone_source_id    BIGINT NOT NULL,
+    product_source_id BIGINT NOT NULL,
+    price             DOUBLE PRECISION,
+    is_available      BOOLEAN NOT NULL DEFAULT TRUE,
+    updated_at        BIGINT NOT NULL,
+    UNIQUE (store_id, source_id)
+);
+CREATE INDEX IF NOT EXISTS idx_store_zone_overrides_zone
+    ON store_zone_product_overrides(store_id, zone_source_id);
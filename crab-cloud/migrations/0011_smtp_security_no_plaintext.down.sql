@@ -0,0 +1 @@
+ALTER TABLE tenant_smtp_settings DROP CONSTRAINT IF EXISTS tenant_smtp_settings_security_check;
@@ -0,0 +1,5 @@
+-- Plaintext relays are no longer supported: force TLS upgrade on existing rows
+UPDATE tenant_smtp_settings SET security = 'starttls' WHERE security NOT IN ('tls', 'starttls');
+
+ALTER TABLE tenant_smtp_settings
+    ADD CONSTRAINT tenant_smtp_settings_security_check CHECK (security IN ('tls', 'starttls'));
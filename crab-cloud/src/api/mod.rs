@@ -11,6 +11,7 @@ pub mod pki;
 pub mod register;
 pub mod store;
 pub mod stripe_webhook;
+pub mod support;
 pub mod sync;
 pub mod tenant;
 pub mod update;
@@ -22,6 +23,7 @@ use crate::auth::rate_limit::{
     global_rate_limit, login_rate_limit, p12_upload_rate_limit, password_reset_rate_limit,
     register_rate_limit,
 };
+use crate::auth::support_auth::support_auth_middleware;
 use crate::auth::tenant_auth::tenant_auth_middleware;
 use crate::state::AppState;
 use axum::extract::DefaultBodyLimit;
@@ -32,7 +34,8 @@ use tower_http::cors::{AllowOrigin, CorsLayer};
 
 /// Public router — served on HTTP port (no mTLS)
 ///
-/// Includes: health, registration, Stripe webhook, app update, tenant management API
+/// Includes: health, registration, Stripe webhook, app update, tenant management API,
+/// remote support agent API
 pub fn public_router(state: AppState) -> Router {
     // Public registration (rate-limited)
     let registration = Router::new()
@@ -131,6 +134,24 @@ pub fn public_router(state: AppState) -> Router {
         .route("/api/tenant/audit-log", get(tenant::audit_log))
         .route("/api/tenant/sessions", get(tenant::list_sessions))
         .route("/api/tenant/sessions/revoke", post(tenant::revoke_session))
+        .route(
+            "/api/tenant/email/smtp",
+            get(tenant::get_smtp_settings)
+                .put(tenant::update_smtp_settings)
+                .delete(tenant::delete_smtp_settings),
+        )
+        .route(
+            "/api/tenant/email/smtp/test",
+            post(tenant::test_smtp_settings),
+        )
+        .route(
+            "/api/tenant/email/templates",
+            get(tenant::list_email_templates),
+        )
+        .route(
+            "/api/tenant/email/templates/{kind}",
+            put(tenant::update_email_template).delete(tenant::reset_email_template),
+        )
         .route(
             "/api/tenant/stores/{id}/commands",
             post(tenant::create_command).get(tenant::list_commands),
@@ -212,6 +233,14 @@ pub fn public_router(state: AppState) -> Router {
             "/api/tenant/stores/{id}/price-rules/{rid}",
             put(store::update_price_rule).delete(store::delete_price_rule),
         )
+        .route(
+            "/api/tenant/stores/{id}/coupons",
+            get(store::list_coupons).post(store::create_coupon),
+        )
+        .route(
+            "/api/tenant/stores/{id}/coupons/{cid}",
+            put(store::update_coupon).delete(store::delete_coupon),
+        )
         // ── Employee CRUD ──
         .route(
             "/api/tenant/stores/{id}/employees",
@@ -267,6 +296,24 @@ pub fn public_router(state: AppState) -> Router {
             tenant_auth_middleware,
         ));
 
+    // Remote support agent API (SUPPORT_API_KEY)
+    let support_api = Router::new()
+        .route("/api/support/sessions", get(support::list_sessions))
+        .route("/api/support/sessions/{id}", get(support::get_session))
+        .route("/api/support/sessions/{id}/logs", get(support::list_logs))
+        .route(
+            "/api/support/sessions/{id}/messages",
+            post(support::send_message),
+        )
+        .route(
+            "/api/support/sessions/{id}/close",
+            post(support::close_session),
+        )
+        .layer(middleware::from_fn_with_state(
+            state.clone(),
+            support_auth_middleware,
+        ));
+
     // Tenant login (rate-limited)
     let tenant_login = Router::new()
         .route("/api/tenant/login", post(tenant::login))
@@ -354,6 +401,7 @@ pub fn public_router(state: AppState) -> Router {
         .merge(webhook)
         .merge(app_update)
         .merge(tenant_api)
+        .merge(support_api)
         .merge(console_ws)
         .merge(tenant_login)
         .merge(password_reset)
@@ -367,6 +415,7 @@ pub fn public_router(state: AppState) -> Router {
         ))
         .layer(DefaultBodyLimit::max(1024 * 1024)) // 1MB
         .layer(cors)
+        .layer(middleware::from_fn(trace_request))
         .with_state(state)
 }
 
@@ -384,5 +433,34 @@ pub fn edge_router(state: AppState) -> Router {
             edge_auth_middleware,
         ))
         .layer(DefaultBodyLimit::max(10 * 1024 * 1024)) // 10MB for sync batches
+        .layer(middleware::from_fn(trace_request))
         .with_state(state)
 }
+
+/// Per-request `http.request` span (continues the caller's `traceparent` when present)
+///
+/// Exported through OTLP when the `otel` feature and `OTEL_EXPORTER_OTLP_ENDPOINT` are set.
+async fn trace_request(
+    request: axum::extract::Request,
+    next: middleware::Next,
+) -> axum::response::Response {
+    use tracing::Instrument;
+
+    let span = tracing::info_span!(
+        "http.request",
+        method = %request.method(),
+        path = request.uri().path(),
+        status = tracing::field::Empty,
+    );
+    if let Some(parent) = request
+        .headers()
+        .get(shared::telemetry::TRACEPARENT_HEADER)
+        .and_then(|v| v.to_str().ok())
+    {
+        shared::telemetry::set_parent(&span, parent);
+    }
+
+    let response = next.run(request).instrument(span.clone()).await;
+    span.record("status", response.status().as_u16());
+    response
+}
@@ -0,0 +1,105 @@
+use axum::{
+    Extension, Json,
+    extract::{Path, State},
+};
+use shared::cloud::store_op::{StoreOp, StoreOpResult};
+use shared::error::AppError;
+
+use crate::auth::tenant_auth::TenantIdentity;
+use crate::db::store;
+use crate::state::AppState;
+
+use super::{internal, push_to_edge, verify_store};
+
+type ApiResult<T> = Result<Json<T>, AppError>;
+
+pub async fn list_coupons(
+    State(state): State<AppState>,
+    Extension(identity): Extension<TenantIdentity>,
+    Path(store_id): Path<i64>,
+) -> ApiResult<Vec<shared::models::Coupon>> {
+    verify_store(&state, store_id, identity.tenant_id).await?;
+    let coupons = store::list_coupons(&state.pool, store_id)
+        .await
+        .map_err(internal)?;
+    Ok(Json(coupons))
+}
+
+pub async fn create_coupon(
+    State(state): State<AppState>,
+    Extension(identity): Extension<TenantIdentity>,
+    Path(store_id): Path<i64>,
+    Json(mut data): Json<shared::models::CouponCreate>,
+) -> ApiResult<StoreOpResult> {
+    verify_store(&state, store_id, identity.tenant_id).await?;
+
+    // create_coupon_direct fills in a generated code so the edge stores the same one
+    let (source_id, op_data) =
+        store::create_coupon_direct(&state.pool, store_id, &mut data).await?;
+    store::increment_store_version(&state.pool, store_id)
+        .await
+        .map_err(internal)?;
+
+    push_to_edge(
+        &state,
+        store_id,
+        identity.tenant_id,
+        StoreOp::CreateCoupon {
+            id: Some(source_id),
+            data,
+        },
+    )
+    .await;
+
+    Ok(Json(StoreOpResult::created(source_id).with_data(op_data)))
+}
+
+pub async fn update_coupon(
+    State(state): State<AppState>,
+    Extension(identity): Extension<TenantIdentity>,
+    Path((store_id, coupon_id)): Path<(i64, i64)>,
+    Json(data): Json<shared::models::CouponUpdate>,
+) -> ApiResult<StoreOpResult> {
+    verify_store(&state, store_id, identity.tenant_id).await?;
+
+    store::update_coupon_direct(&state.pool, store_id, coupon_id, &data).await?;
+    store::increment_store_version(&state.pool, store_id)
+        .await
+        .map_err(internal)?;
+
+    push_to_edge(
+        &state,
+        store_id,
+        identity.tenant_id,
+        StoreOp::UpdateCoupon {
+            id: coupon_id,
+            data,
+        },
+    )
+    .await;
+
+    Ok(Json(StoreOpResult::ok()))
+}
+
+pub async fn delete_coupon(
+    State(state): State<AppState>,
+    Extension(identity): Extension<TenantIdentity>,
+    Path((store_id, coupon_id)): Path<(i64, i64)>,
+) -> ApiResult<StoreOpResult> {
+    verify_store(&state, store_id, identity.tenant_id).await?;
+
+    store::delete_coupon_direct(&state.pool, store_id, coupon_id).await?;
+    store::increment_store_version(&state.pool, store_id)
+        .await
+        .map_err(internal)?;
+
+    push_to_edge(
+        &state,
+        store_id,
+        identity.tenant_id,
+        StoreOp::DeleteCoupon { id: coupon_id },
+    )
+    .await;
+
+    Ok(Json(StoreOpResult::ok()))
+}
@@ -102,6 +102,8 @@ pub async fn build_catalog_export(
                 kitchen_print_name: p.kitchen_print_name.clone(),
                 is_kitchen_print_enabled: p.is_kitchen_print_enabled,
                 is_label_print_enabled: p.is_label_print_enabled,
+                kitchen_print_destinations: vec![],
+                label_print_destinations: vec![],
                 is_active: p.is_active,
                 external_id: p.external_id,
                 specs: p
@@ -153,6 +155,7 @@ pub async fn build_catalog_export(
                     kitchen_print_name: o.kitchen_print_name.clone(),
                     enable_quantity: o.enable_quantity,
                     max_quantity: o.max_quantity,
+                    stock_level_id: None,
                 })
                 .collect(),
         })
@@ -283,6 +286,8 @@ pub async fn export_catalog(
                 kitchen_print_name: p.kitchen_print_name.clone(),
                 is_kitchen_print_enabled: p.is_kitchen_print_enabled,
                 is_label_print_enabled: p.is_label_print_enabled,
+                kitchen_print_destinations: vec![],
+                label_print_destinations: vec![],
                 is_active: p.is_active,
                 external_id: p.external_id,
                 specs: p
@@ -334,6 +339,7 @@ pub async fn export_catalog(
                     kitchen_print_name: o.kitchen_print_name.clone(),
                     enable_quantity: o.enable_quantity,
                     max_quantity: o.max_quantity,
+                    stock_level_id: None,
                 })
                 .collect(),
         })
@@ -9,6 +9,7 @@
 
 pub mod attribute;
 pub mod category;
+pub mod coupon;
 pub mod data_transfer;
 pub mod dining_table;
 pub mod employee;
@@ -21,6 +22,7 @@ pub mod zone;
 
 pub use attribute::*;
 pub use category::*;
+pub use coupon::*;
 pub use dining_table::*;
 pub use employee::*;
 pub use label_template::*;
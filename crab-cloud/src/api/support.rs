@@ -0,0 +1,176 @@
+//! Remote support agent API (远程支持)
+//!
+//! Support staff list the sessions stores have opened, read diagnostics and the
+//! sanitized log stream, and send guidance that is shown on the POS. Sessions
+//! are opened on the edge only (with store consent); agents can close them.
+
+use axum::{
+    Json,
+    extract::{Path, Query, State},
+    http::HeaderMap,
+};
+use serde::Deserialize;
+use shared::cloud::CloudMessage;
+use shared::cloud::support::{
+    MAX_SUPPORT_MESSAGE_LEN, SupportAuthor, SupportMessage, SupportMessageCreate,
+};
+use shared::error::{AppError, ErrorCode};
+
+use crate::db::support::{self, SupportLogLine, SupportSessionRow};
+use crate::state::AppState;
+
+type ApiResult<T> = Result<Json<T>, AppError>;
+
+/// Header carrying the agent's display name (shown on the POS)
+const AGENT_NAME_HEADER: &str = "x-support-agent";
+const DEFAULT_AGENT_NAME: &str = "Support";
+
+fn db_error(e: sqlx::Error) -> AppError {
+    tracing::error!("Support query error: {e}");
+    AppError::new(ErrorCode::InternalError)
+}
+
+async fn load_session(state: &AppState, id: i64) -> Result<SupportSessionRow, AppError> {
+    support::find(&state.pool, id)
+        .await
+        .map_err(db_error)?
+        .ok_or_else(|| AppError::with_message(ErrorCode::NotFound, "Support session not found"))
+}
+
+#[derive(Deserialize)]
+pub struct ListQuery {
+    #[serde(default)]
+    pub open: bool,
+    pub limit: Option<i64>,
+}
+
+/// GET /api/support/sessions?open=true
+pub async fn list_sessions(
+    State(state): State<AppState>,
+    Query(query): Query<ListQuery>,
+) -> ApiResult<Vec<SupportSessionRow>> {
+    let limit = query.limit.unwrap_or(50).clamp(1, 200);
+    let sessions = support::list(&state.pool, query.open, shared::util::now_millis(), limit)
+        .await
+        .map_err(db_error)?;
+    Ok(Json(sessions))
+}
+
+/// GET /api/support/sessions/:id — session, diagnostics and conversation
+pub async fn get_session(
+    State(state): State<AppState>,
+    Path(id): Path<i64>,
+) -> ApiResult<serde_json::Value> {
+    let session = load_session(&state, id).await?;
+    let messages = support::list_messages(&state.pool, id)
+        .await
+        .map_err(db_error)?;
+    let edge_online = state.edges.connected.contains_key(&session.store_id);
+    Ok(Json(serde_json::json!({
+        "session": session,
+        "messages": messages,
+        "edge_online": edge_online,
+    })))
+}
+
+#[derive(Deserialize)]
+pub struct LogsQuery {
+    /// Cursor: last log line id already received
+    #[serde(default)]
+    pub after_id: i64,
+    pub limit: Option<i64>,
+}
+
+/// GET /api/support/sessions/:id/logs?after_id=
+pub async fn list_logs(
+    State(state): State<AppState>,
+    Path(id): Path<i64>,
+    Query(query): Query<LogsQuery>,
+) -> ApiResult<Vec<SupportLogLine>> {
+    load_session(&state, id).await?;
+    let limit = query.limit.unwrap_or(500).clamp(1, 2000);
+    let lines = support::list_log_lines(&state.pool, id, query.after_id, limit)
+        .await
+        .map_err(db_error)?;
+    Ok(Json(lines))
+}
+
+/// POST /api/support/sessions/:id/messages — guidance shown on the POS
+pub async fn send_message(
+    State(state): State<AppState>,
+    Path(id): Path<i64>,
+    headers: HeaderMap,
+    Json(req): Json<SupportMessageCreate>,
+) -> ApiResult<SupportMessage> {
+    let text = req.text.trim();
+    if text.is_empty() || text.len() > MAX_SUPPORT_MESSAGE_LEN {
+        return Err(AppError::with_message(
+            ErrorCode::ValidationFailed,
+            format!("text must be 1..{MAX_SUPPORT_MESSAGE_LEN} chars"),
+        ));
+    }
+
+    let now = shared::util::now_millis();
+    let session = load_session(&state, id).await?;
+    if !session.is_open(now) {
+        return Err(AppError::with_message(
+            ErrorCode::ValidationFailed,
+            "Support session is closed",
+        ));
+    }
+    let sender = state
+        .edges
+        .connected
+        .get(&session.store_id)
+        .map(|s| s.clone())
+        .ok_or_else(|| AppError::with_message(ErrorCode::NotFound, "Edge server is offline"))?;
+
+    let author_name = headers
+        .get(AGENT_NAME_HEADER)
+        .and_then(|v| v.to_str().ok())
+        .map(str::trim)
+        .filter(|s| !s.is_empty())
+        .unwrap_or(DEFAULT_AGENT_NAME);
+    let message = SupportMessage {
+        id: shared::util::snowflake_id(),
+        session_id: session.session_id,
+        author: SupportAuthor::Agent,
+        author_name: author_name.chars().take(100).collect(),
+        text: text.to_string(),
+        sent_at: now,
+    };
+    support::insert_agent_message(&state.pool, id, &message)
+        .await
+        .map_err(db_error)?;
+
+    if sender
+        .try_send(CloudMessage::SupportChat {
+            message: message.clone(),
+        })
+        .is_err()
+    {
+        return Err(AppError::with_message(
+            ErrorCode::NotFound,
+            "Edge server command queue full",
+        ));
+    }
+
+    Ok(Json(message))
+}
+
+/// POST /api/support/sessions/:id/close — stops log streaming on the edge
+pub async fn close_session(State(state): State<AppState>, Path(id): Path<i64>) -> ApiResult<bool> {
+    let session = load_session(&state, id).await?;
+    let closed = support::close(&state.pool, id, shared::util::now_millis())
+        .await
+        .map_err(db_error)?;
+
+    // Edge offline: it keeps the session until expiry (log lines are refused here)
+    if let Some(sender) = state.edges.connected.get(&session.store_id) {
+        let _ = sender.try_send(CloudMessage::SupportSessionClosed {
+            session_id: session.session_id,
+        });
+    }
+
+    Ok(Json(closed))
+}
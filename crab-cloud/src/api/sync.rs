@@ -14,6 +14,11 @@ use crate::state::AppState;
 /// 2. Auto-register edge-server if new
 /// 3. Process each sync item
 /// 4. Return response with accepted/rejected counts
+#[tracing::instrument(
+    name = "cloud.sync",
+    skip_all,
+    fields(edge_id = %identity.entity_id, items = batch.items.len())
+)]
 pub async fn handle_sync(
     State(state): State<AppState>,
     Extension(identity): Extension<EdgeIdentity>,
@@ -79,14 +84,7 @@ pub async fn handle_sync(
             Ok(effect) => {
                 accepted += 1;
 
-                // Handle side-effects (e.g. broadcast StoreInfo to consoles)
-                if let sync_store::SyncEffect::StoreInfoUpdated(info) = effect {
-                    state.live_orders.publish_store_info_updated(
-                        identity.tenant_id,
-                        store_id,
-                        *info,
-                    );
-                }
+                handle_sync_effect(&state, identity.tenant_id, store_id, effect);
 
                 // Update sync cursor
                 if let Err(e) = sync_store::update_cursor(
@@ -149,3 +147,32 @@ pub async fn handle_sync(
         errors,
     }))
 }
+
+/// Apply the side-effect of an accepted sync item (shared by HTTP and WS sync)
+pub(crate) fn handle_sync_effect(
+    state: &AppState,
+    tenant_id: i64,
+    store_id: i64,
+    effect: sync_store::SyncEffect,
+) {
+    match effect {
+        sync_store::SyncEffect::None => {}
+        sync_store::SyncEffect::StoreInfoUpdated(info) => {
+            state
+                .live_orders
+                .publish_store_info_updated(tenant_id, store_id, *info);
+        }
+        sync_store::SyncEffect::DailyReportCreated(report) => {
+            let state = state.clone();
+            tokio::spawn(async move {
+                if let Err(e) = state
+                    .email
+                    .send_daily_report(&state.pool, &state.master_key, tenant_id, store_id, &report)
+                    .await
+                {
+                    tracing::warn!(tenant_id, store_id, "Daily report email failed: {e}");
+                }
+            });
+        }
+    }
+}
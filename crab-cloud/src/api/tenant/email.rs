@@ -0,0 +1,359 @@
+//! Email settings: tenant SMTP relay, send-test, template overrides
+
+use axum::{
+    Extension, Json,
+    extract::{Path, State},
+};
+use serde::{Deserialize, Serialize};
+use shared::error::{AppError, ErrorCode};
+
+use crate::auth::tenant_auth::TenantIdentity;
+use crate::db::{self, email_settings};
+use crate::email::EmailService;
+use crate::email::smtp::{self, SmtpConfig, SmtpSecurity};
+use crate::email::template::{self, EmailTemplate, EmailTemplateKind};
+use crate::state::AppState;
+
+use super::ApiResult;
+
+const MAX_SUBJECT_LEN: usize = 200;
+const MAX_BODY_LEN: usize = 20_000;
+
+/// SMTP settings as shown to the tenant (password never leaves the server)
+#[derive(Serialize)]
+pub struct SmtpSettingsResponse {
+    pub host: String,
+    pub port: i32,
+    pub security: String,
+    pub username: Option<String>,
+    pub has_password: bool,
+    pub from_address: String,
+    pub from_name: Option<String>,
+    pub updated_at: i64,
+}
+
+fn db_error(context: &str, e: impl std::fmt::Display) -> AppError {
+    tracing::error!("{context}: {e}");
+    AppError::new(ErrorCode::InternalError)
+}
+
+fn parse_kind(kind: &str) -> Result<EmailTemplateKind, AppError> {
+    EmailTemplateKind::parse(kind)
+        .ok_or_else(|| AppError::with_message(ErrorCode::NotFound, "Unknown email template"))
+}
+
+/// GET /api/tenant/email/smtp
+pub async fn get_smtp_settings(
+    State(state): State<AppState>,
+    Extension(identity): Extension<TenantIdentity>,
+) -> ApiResult<Option<SmtpSettingsResponse>> {
+    let row = email_settings::find_smtp(&state.pool, identity.tenant_id)
+        .await
+        .map_err(|e| db_error("SMTP settings query error", e))?;
+
+    Ok(Json(row.map(|r| SmtpSettingsResponse {
+        host: r.host,
+        port: r.port,
+        security: r.security,
+        username: r.username,
+        has_password: r.password_encrypted.is_some(),
+        from_address: r.from_address,
+        from_name: r.from_name,
+        updated_at: r.updated_at,
+    })))
+}
+
+/// PUT /api/tenant/email/smtp
+#[derive(Deserialize)]
+pub struct UpdateSmtpRequest {
+    pub host: String,
+    pub port: u16,
+    pub security: SmtpSecurity,
+    pub username: Option<String>,
+    /// None = keep the stored password, "" = clear it
+    pub password: Option<String>,
+    pub from_address: String,
+    pub from_name: Option<String>,
+}
+
+pub async fn update_smtp_settings(
+    State(state): State<AppState>,
+    Extension(identity): Extension<TenantIdentity>,
+    Json(req): Json<UpdateSmtpRequest>,
+) -> ApiResult<SmtpSettingsResponse> {
+    let host = req.host.trim();
+    if host.is_empty() || req.port == 0 {
+        return Err(AppError::with_message(
+            ErrorCode::ValidationFailed,
+            "SMTP host and port are required",
+        ));
+    }
+    if let Err(e) = smtp::resolve_public(host, req.port).await {
+        tracing::warn!(tenant_id = identity.tenant_id, host, port = req.port, "{e}");
+        return Err(AppError::with_message(
+            ErrorCode::ValidationFailed,
+            "SMTP host must be a public mail server on port 25, 465, 587 or 2525",
+        ));
+    }
+    let from_address = req.from_address.trim();
+    if from_address.parse::<lettre::Address>().is_err() {
+        return Err(AppError::with_message(
+            ErrorCode::InvalidFormat,
+            "Invalid sender address",
+        ));
+    }
+
+    let existing = email_settings::find_smtp(&state.pool, identity.tenant_id)
+        .await
+        .map_err(|e| db_error("SMTP settings query error", e))?;
+    let password_encrypted = match req.password.as_deref() {
+        None => existing.and_then(|r| r.password_encrypted),
+        Some("") => None,
+        Some(password) => Some(
+            state
+                .master_key
+                .encrypt_string(password)
+                .map_err(|e| db_error("SMTP password encryption error", e))?,
+        ),
+    };
+
+    let now = shared::util::now_millis();
+    let row = email_settings::SmtpSettingsRow {
+        host: host.to_string(),
+        port: i32::from(req.port),
+        security: req.security.as_str().to_string(),
+        username: req
+            .username
+            .map(|u| u.trim().to_string())
+            .filter(|u| !u.is_empty()),
+        password_encrypted,
+        from_address: from_address.to_string(),
+        from_name: req
+            .from_name
+            .map(|n| n.trim().to_string())
+            .filter(|n| !n.is_empty()),
+        updated_at: now,
+    };
+    email_settings::upsert_smtp(&state.pool, identity.tenant_id, &row)
+        .await
+        .map_err(|e| db_error("SMTP settings update error", e))?;
+
+    let detail =
+        serde_json::json!({ "host": row.host, "port": row.port, "from_address": row.from_address });
+    let _ = db::audit::log(
+        &state.pool,
+        identity.tenant_id,
+        "smtp_settings_updated",
+        Some(&detail),
+        None,
+        now,
+    )
+    .await;
+
+    Ok(Json(SmtpSettingsResponse {
+        host: row.host,
+        port: row.port,
+        security: row.security,
+        username: row.username,
+        has_password: row.password_encrypted.is_some(),
+        from_address: row.from_address,
+        from_name: row.from_name,
+        updated_at: row.updated_at,
+    }))
+}
+
+/// DELETE /api/tenant/email/smtp — fall back to the platform sender
+pub async fn delete_smtp_settings(
+    State(state): State<AppState>,
+    Extension(identity): Extension<TenantIdentity>,
+) -> ApiResult<serde_json::Value> {
+    let deleted = email_settings::delete_smtp(&state.pool, identity.tenant_id)
+        .await
+        .map_err(|e| db_error("SMTP settings delete error", e))?;
+    if !deleted {
+        return Err(AppError::new(ErrorCode::NotFound));
+    }
+
+    let now = shared::util::now_millis();
+    let _ = db::audit::log(
+        &state.pool,
+        identity.tenant_id,
+        "smtp_settings_deleted",
+        None,
+        None,
+        now,
+    )
+    .await;
+
+    Ok(Json(serde_json::json!({ "deleted": true })))
+}
+
+/// POST /api/tenant/email/smtp/test
+#[derive(Deserialize)]
+pub struct SmtpTestRequest {
+    /// Recipient (defaults to the tenant's account email)
+    pub to: Option<String>,
+}
+
+pub async fn test_smtp_settings(
+    State(state): State<AppState>,
+    Extension(identity): Extension<TenantIdentity>,
+    Json(req): Json<SmtpTestRequest>,
+) -> ApiResult<serde_json::Value> {
+    let row = email_settings::find_smtp(&state.pool, identity.tenant_id)
+        .await
+        .map_err(|e| db_error("SMTP settings query error", e))?
+        .ok_or_else(|| AppError::with_message(ErrorCode::ConfigError, "SMTP is not configured"))?;
+    let config = SmtpConfig::from_row(row, &state.master_key)
+        .map_err(|e| db_error("SMTP settings decode error", e))?;
+
+    let to = req
+        .to
+        .map(|t| t.trim().to_string())
+        .filter(|t| !t.is_empty())
+        .unwrap_or_else(|| identity.email.clone());
+    let subject = "Prueba SMTP / SMTP test";
+    let text = "Este es un correo de prueba de Red Coral. Tu configuración SMTP funciona.\n\n\
+                This is a test email from Red Coral. Your SMTP settings work.";
+
+    // The relay's reply stays in our logs: echoing it would turn this
+    // endpoint into a probe of whatever the host points at
+    if let Err(e) = config.send(&to, subject, text).await {
+        tracing::warn!(tenant_id = identity.tenant_id, "SMTP test failed: {e}");
+        return Err(AppError::with_message(
+            ErrorCode::NetworkError,
+            "SMTP test failed — check the host, port, security mode and credentials",
+        ));
+    }
+
+    let now = shared::util::now_millis();
+    let detail = serde_json::json!({ "to": to, "host": config.host });
+    let _ = db::audit::log(
+        &state.pool,
+        identity.tenant_id,
+        "smtp_test_sent",
+        Some(&detail),
+        None,
+        now,
+    )
+    .await;
+
+    Ok(Json(serde_json::json!({ "sent": true, "to": to })))
+}
+
+/// GET /api/tenant/email/templates — every kind, override or default
+pub async fn list_email_templates(
+    State(state): State<AppState>,
+    Extension(identity): Extension<TenantIdentity>,
+) -> ApiResult<Vec<EmailTemplate>> {
+    let mut templates = Vec::with_capacity(EmailTemplateKind::ALL.len());
+    for kind in EmailTemplateKind::ALL {
+        templates.push(
+            EmailService::resolve_template(&state.pool, identity.tenant_id, kind)
+                .await
+                .map_err(|e| db_error("Email template query error", e))?,
+        );
+    }
+    Ok(Json(templates))
+}
+
+/// PUT /api/tenant/email/templates/:kind
+#[derive(Deserialize)]
+pub struct UpdateEmailTemplateRequest {
+    pub subject: String,
+    pub body: String,
+}
+
+pub async fn update_email_template(
+    State(state): State<AppState>,
+    Extension(identity): Extension<TenantIdentity>,
+    Path(kind): Path<String>,
+    Json(req): Json<UpdateEmailTemplateRequest>,
+) -> ApiResult<EmailTemplate> {
+    let kind = parse_kind(&kind)?;
+    let subject = req.subject.trim();
+    if subject.is_empty() || req.body.trim().is_empty() {
+        return Err(AppError::with_message(
+            ErrorCode::RequiredField,
+            "Subject and body are required",
+        ));
+    }
+    if subject.len() > MAX_SUBJECT_LEN || req.body.len() > MAX_BODY_LEN {
+        return Err(AppError::with_message(
+            ErrorCode::ValidationFailed,
+            "Template is too long",
+        ));
+    }
+    let mut unknown = template::unknown_variables(kind, subject);
+    for name in template::unknown_variables(kind, &req.body) {
+        if !unknown.contains(&name) {
+            unknown.push(name);
+        }
+    }
+    if !unknown.is_empty() {
+        return Err(AppError::with_message(
+            ErrorCode::ValidationFailed,
+            format!("Unknown template variables: {}", unknown.join(", ")),
+        ));
+    }
+
+    let now = shared::util::now_millis();
+    email_settings::upsert_template(
+        &state.pool,
+        identity.tenant_id,
+        kind.as_str(),
+        subject,
+        &req.body,
+        now,
+    )
+    .await
+    .map_err(|e| db_error("Email template update error", e))?;
+
+    let detail = serde_json::json!({ "kind": kind.as_str() });
+    let _ = db::audit::log(
+        &state.pool,
+        identity.tenant_id,
+        "email_template_updated",
+        Some(&detail),
+        None,
+        now,
+    )
+    .await;
+
+    Ok(Json(EmailTemplate {
+        kind,
+        subject: subject.to_string(),
+        body: req.body,
+        is_default: false,
+        variables: kind.variables(),
+        updated_at: Some(now),
+    }))
+}
+
+/// DELETE /api/tenant/email/templates/:kind — reset to the built-in default
+pub async fn reset_email_template(
+    State(state): State<AppState>,
+    Extension(identity): Extension<TenantIdentity>,
+    Path(kind): Path<String>,
+) -> ApiResult<EmailTemplate> {
+    let kind = parse_kind(&kind)?;
+    let deleted = email_settings::delete_template(&state.pool, identity.tenant_id, kind.as_str())
+        .await
+        .map_err(|e| db_error("Email template delete error", e))?;
+
+    if deleted {
+        let now = shared::util::now_millis();
+        let detail = serde_json::json!({ "kind": kind.as_str() });
+        let _ = db::audit::log(
+            &state.pool,
+            identity.tenant_id,
+            "email_template_reset",
+            Some(&detail),
+            None,
+            now,
+        )
+        .await;
+    }
+
+    Ok(Json(EmailTemplate::default_for(kind)))
+}
@@ -6,6 +6,7 @@ mod audit;
 mod auth;
 mod billing;
 mod command;
+mod email;
 mod order;
 mod session;
 mod store;
@@ -52,6 +53,11 @@ pub use order::{
 
 pub use command::{create_command, list_commands};
 
+pub use email::{
+    delete_smtp_settings, get_smtp_settings, list_email_templates, reset_email_template,
+    test_smtp_settings, update_email_template, update_smtp_settings,
+};
+
 pub use billing::{
     billing_portal, cancel_subscription, change_plan, create_checkout, resume_subscription,
 };
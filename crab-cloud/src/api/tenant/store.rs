@@ -66,6 +66,7 @@ pub struct UpdateStoreRequest {
     pub receipt_locale: Option<String>,
     pub receipt_header: Option<String>,
     pub receipt_footer: Option<String>,
+    pub tax_mode: Option<shared::models::store_info::TaxMode>,
 }
 
 pub async fn update_store(
@@ -100,6 +101,7 @@ pub async fn update_store(
         receipt_locale: payload.receipt_locale,
         receipt_header: payload.receipt_header,
         receipt_footer: payload.receipt_footer,
+        tax_mode: payload.tax_mode,
         ..Default::default()
     };
 
@@ -15,7 +15,7 @@ use tokio::sync::mpsc;
 use tokio::time::Instant;
 
 use crate::auth::EdgeIdentity;
-use crate::db::{audit, sync_store};
+use crate::db::{audit, stock_transfers, support, sync_store};
 use crate::state::AppState;
 
 /// Server-side ping interval (seconds). Cloud proactively pings edge to detect dead connections.
@@ -26,6 +26,9 @@ const PING_INTERVAL_SECS: u64 = 30;
 /// sends pings every 30s) has ample time to respond even under transient network hiccups.
 const HEARTBEAT_TIMEOUT_SECS: u64 = 90;
 
+/// Upper bound for one variable of an edge-requested email (receipt item lists included)
+const MAX_EMAIL_VAR_LEN: usize = 8_000;
+
 /// GET /api/edge/ws — upgrade to WebSocket
 pub async fn handle_edge_ws(
     State(state): State<AppState>,
@@ -154,6 +157,29 @@ async fn handle_ws_connection(socket: WebSocket, state: AppState, identity: Edge
         tracing::info!(store_id, count = sent, "Pending ops replayed");
     }
 
+    // 门店间调拨：可选收货门店 + 未确认的调拨单 / 收货确认
+    let mut transfer_msgs = Vec::new();
+    match stock_transfers::list_peers(&state.pool, identity.tenant_id, store_id).await {
+        Ok(stores) => transfer_msgs.push(CloudMessage::TransferPeers { stores }),
+        Err(e) => tracing::warn!(store_id, "Failed to list transfer peers: {e}"),
+    }
+    match stock_transfers::pending_for_store(&state.pool, store_id).await {
+        Ok(pending) => transfer_msgs.extend(pending),
+        Err(e) => tracing::warn!(store_id, "Failed to fetch pending stock transfers: {e}"),
+    }
+    for msg in transfer_msgs {
+        if let Ok(json) = serde_json::to_string(&msg)
+            && ws_sink.send(Message::Text(json.into())).await.is_err()
+        {
+            tracing::warn!(
+                store_id,
+                "Failed to send stock transfer relay, disconnecting"
+            );
+            state.edges.connected.remove(&store_id);
+            return;
+        }
+    }
+
     // 所有初始化发送完成后，标记 edge 上线（通知正在观看的 console）
     state
         .live_orders
@@ -348,14 +374,12 @@ async fn handle_edge_message<S>(
                     Ok(effect) => {
                         accepted += 1;
 
-                        // Handle side-effects (e.g. broadcast StoreInfo to consoles)
-                        if let sync_store::SyncEffect::StoreInfoUpdated(info) = effect {
-                            state.live_orders.publish_store_info_updated(
-                                identity.tenant_id,
-                                store_id,
-                                *info,
-                            );
-                        }
+                        super::sync::handle_sync_effect(
+                            state,
+                            identity.tenant_id,
+                            store_id,
+                            effect,
+                        );
 
                         let version = i64::try_from(item.version).unwrap_or(i64::MAX);
                         let entry = cursor_maxes
@@ -522,6 +546,190 @@ async fn handle_edge_message<S>(
             }
         }
 
+        CloudMessage::SupportSessionOpened {
+            session,
+            diagnostics,
+        } => {
+            let diagnostics = serde_json::to_value(&*diagnostics).unwrap_or_default();
+            match support::upsert_session(
+                &state.pool,
+                store_id,
+                identity.tenant_id,
+                &session,
+                &diagnostics,
+            )
+            .await
+            {
+                Ok(id) => {
+                    tracing::info!(store_id, support_id = id, "Support session opened by edge");
+                    let _ = support::purge_logs(&state.pool, now - support::LOG_RETENTION_MS).await;
+                }
+                Err(e) => tracing::warn!(store_id, "Failed to record support session: {e}"),
+            }
+        }
+
+        CloudMessage::SupportLogs { session_id, lines } => {
+            if lines.len() > shared::cloud::support::MAX_SUPPORT_LOG_LINES {
+                tracing::warn!(
+                    store_id,
+                    count = lines.len(),
+                    "Support log batch too large, ignoring"
+                );
+                return;
+            }
+            if let Err(e) =
+                support::append_logs(&state.pool, store_id, session_id, &lines, now).await
+            {
+                tracing::warn!(store_id, session_id, "Failed to store support logs: {e}");
+            }
+        }
+
+        CloudMessage::SupportChat { message } => {
+            if message.author != shared::cloud::support::SupportAuthor::Store {
+                tracing::warn!(store_id, "Ignoring support message not authored by store");
+                return;
+            }
+            if let Err(e) = support::insert_edge_message(&state.pool, store_id, &message).await {
+                tracing::warn!(store_id, "Failed to store support message: {e}");
+            }
+        }
+
+        CloudMessage::SupportSessionClosed { session_id } => {
+            if let Err(e) = support::close_by_edge(&state.pool, store_id, session_id, now).await {
+                tracing::warn!(store_id, session_id, "Failed to close support session: {e}");
+            }
+        }
+
+        CloudMessage::StockTransferDispatched { mut dispatch } => {
+            let to_store_id = dispatch.store_id;
+            let peers = match stock_transfers::list_peers(&state.pool, identity.tenant_id, store_id)
+                .await
+            {
+                Ok(peers) => peers,
+                Err(e) => {
+                    tracing::warn!(store_id, "Failed to list transfer peers: {e}");
+                    return;
+                }
+            };
+            if !peers.iter().any(|p| p.store_id == to_store_id)
+                || dispatch.lines.is_empty()
+                || dispatch.lines.len() > shared::cloud::transfer::MAX_TRANSFER_LINES
+            {
+                tracing::warn!(
+                    store_id,
+                    to_store_id,
+                    transfer_id = dispatch.transfer_id,
+                    "Rejected stock transfer dispatch"
+                );
+                return;
+            }
+            // Receiving edge sees the sending store as counterpart
+            dispatch.store_id = store_id;
+            dispatch.store_name = match stock_transfers::store_name(&state.pool, store_id).await {
+                Ok(name) => name,
+                Err(e) => {
+                    tracing::warn!(store_id, "Failed to load store name: {e}");
+                    return;
+                }
+            };
+            match stock_transfers::insert_dispatch(
+                &state.pool,
+                identity.tenant_id,
+                to_store_id,
+                &dispatch,
+            )
+            .await
+            {
+                Ok(true) => {
+                    tracing::info!(
+                        store_id,
+                        to_store_id,
+                        transfer_id = dispatch.transfer_id,
+                        "Stock transfer dispatched"
+                    );
+                    // Receiving edge offline: delivered on its next connect
+                    if let Some(sender) = state.edges.connected.get(&to_store_id) {
+                        let _ = sender.try_send(CloudMessage::StockTransferDispatched { dispatch });
+                    }
+                }
+                Ok(false) => {}
+                Err(e) => tracing::warn!(store_id, "Failed to store stock transfer: {e}"),
+            }
+        }
+
+        CloudMessage::StockTransferReceived { mut receipt } => {
+            // Sending edge sees the receiving store as counterpart
+            receipt.store_id = store_id;
+            match stock_transfers::record_receipt(&state.pool, store_id, &receipt).await {
+                Ok(Some(from_store_id)) => {
+                    tracing::info!(
+                        store_id,
+                        from_store_id,
+                        transfer_id = receipt.transfer_id,
+                        "Stock transfer received"
+                    );
+                    if let Some(sender) = state.edges.connected.get(&from_store_id) {
+                        let _ = sender.try_send(CloudMessage::StockTransferReceived { receipt });
+                    }
+                }
+                Ok(None) => {}
+                Err(e) => tracing::warn!(store_id, "Failed to store transfer receipt: {e}"),
+            }
+        }
+
+        CloudMessage::StockTransferAck { transfer_id } => {
+            if let Err(e) =
+                stock_transfers::mark_delivered(&state.pool, store_id, transfer_id, now).await
+            {
+                tracing::warn!(store_id, transfer_id, "Failed to ack stock transfer: {e}");
+            }
+        }
+
+        CloudMessage::SendEmail { email } => {
+            if !email.kind.edge_requestable()
+                || email.to.parse::<lettre::Address>().is_err()
+                || email.vars.iter().any(|(name, value)| {
+                    !email.kind.variables().contains(&name.as_str())
+                        || value.len() > MAX_EMAIL_VAR_LEN
+                })
+            {
+                tracing::warn!(
+                    store_id,
+                    kind = email.kind.as_str(),
+                    "Rejected edge email request"
+                );
+                return;
+            }
+            let state = state.clone();
+            let tenant_id = identity.tenant_id;
+            tokio::spawn(async move {
+                let vars: std::collections::HashMap<&str, String> = email
+                    .vars
+                    .iter()
+                    .map(|(name, value)| (name.as_str(), value.clone()))
+                    .collect();
+                if let Err(e) = state
+                    .email
+                    .send_templated(
+                        &state.pool,
+                        &state.master_key,
+                        tenant_id,
+                        email.kind,
+                        &email.to,
+                        &vars,
+                    )
+                    .await
+                {
+                    tracing::warn!(
+                        tenant_id,
+                        store_id,
+                        kind = email.kind.as_str(),
+                        "Edge email failed: {e}"
+                    );
+                }
+            });
+        }
+
         _ => {
             tracing::debug!("Ignoring unexpected CloudMessage from edge");
         }
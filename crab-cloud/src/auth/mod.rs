@@ -1,8 +1,9 @@
-//! Authentication middleware for edge-server and tenant connections
+//! Authentication middleware for edge-server, tenant and support agent connections
 
 pub mod edge_auth;
 pub mod quota;
 pub mod rate_limit;
+pub mod support_auth;
 pub mod tenant_auth;
 
 pub use edge_auth::EdgeIdentity;
@@ -0,0 +1,65 @@
+//! Support agent authentication for the remote support API
+//!
+//! Agents authenticate with a shared key (`SUPPORT_API_KEY`) sent as
+//! `Authorization: Bearer <key>`. The API is disabled when the key is not set.
+
+use axum::{
+    extract::{Request, State},
+    middleware::Next,
+    response::{IntoResponse, Response},
+};
+use sha2::{Digest, Sha256};
+use shared::error::{AppError, ErrorCode};
+
+use crate::state::AppState;
+
+/// Middleware that verifies the support API key
+pub async fn support_auth_middleware(
+    State(state): State<AppState>,
+    request: Request,
+    next: Next,
+) -> Result<Response, Response> {
+    let Some(expected) = state.support_api_key.as_deref() else {
+        return Err(AppError::with_message(
+            ErrorCode::PermissionDenied,
+            "Support API is not configured",
+        )
+        .into_response());
+    };
+
+    let provided = request
+        .headers()
+        .get("Authorization")
+        .and_then(|v| v.to_str().ok())
+        .and_then(|v| v.strip_prefix("Bearer "))
+        .ok_or_else(|| AppError::new(ErrorCode::NotAuthenticated).into_response())?;
+
+    if !keys_match(provided, expected) {
+        tracing::warn!("Support API key rejected");
+        return Err(AppError::new(ErrorCode::NotAuthenticated).into_response());
+    }
+
+    Ok(next.run(request).await)
+}
+
+/// Constant-time comparison (digests hide length differences)
+fn keys_match(provided: &str, expected: &str) -> bool {
+    let a = Sha256::digest(provided.as_bytes());
+    let b = Sha256::digest(expected.as_bytes());
+    a.iter()
+        .zip(b.iter())
+        .fold(0u8, |acc, (x, y)| acc | (x ^ y))
+        == 0
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_keys_match() {
+        assert!(keys_match("s3cret-key", "s3cret-key"));
+        assert!(!keys_match("s3cret-key", "s3cret-kez"));
+        assert!(!keys_match("", "s3cret-key"));
+    }
+}
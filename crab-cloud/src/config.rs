@@ -49,6 +49,11 @@ pub struct Config {
     pub stripe_pro_yearly_price_id: String,
     /// Secrets Manager key prefix (default: "crab", dev: "crab-dev")
     pub secrets_prefix: String,
+    /// Shared key for the remote support agent API (None = API disabled)
+    pub support_api_key: Option<String>,
+    /// OTLP trace export (env: OTEL_EXPORTER_OTLP_ENDPOINT / OTEL_EXPORTER_OTLP_HEADERS,
+    /// None = logs only; requires the `otel` feature)
+    pub otlp: Option<shared::telemetry::OtlpConfig>,
 }
 
 impl Config {
@@ -117,6 +122,10 @@ impl Config {
             stripe_pro_yearly_price_id: std::env::var("STRIPE_PRO_YEARLY_PRICE_ID")
                 .unwrap_or_else(|_| "price_pro_yearly_placeholder".into()),
             secrets_prefix: std::env::var("SECRETS_PREFIX").unwrap_or_else(|_| "crab".into()),
+            support_api_key: std::env::var("SUPPORT_API_KEY")
+                .ok()
+                .filter(|s| !s.is_empty()),
+            otlp: shared::telemetry::OtlpConfig::from_env("crab-cloud"),
         })
     }
 }
@@ -0,0 +1,138 @@
+//! Tenant SMTP settings & email template overrides
+
+use sqlx::PgPool;
+
+/// SMTP 配置记录（密码加密存储）
+#[derive(sqlx::FromRow)]
+pub struct SmtpSettingsRow {
+    pub host: String,
+    pub port: i32,
+    /// 'starttls' | 'tls'
+    pub security: String,
+    pub username: Option<String>,
+    /// AES-256-GCM encrypted with the master key
+    pub password_encrypted: Option<String>,
+    pub from_address: String,
+    pub from_name: Option<String>,
+    pub updated_at: i64,
+}
+
+pub async fn find_smtp(
+    pool: &PgPool,
+    tenant_id: i64,
+) -> Result<Option<SmtpSettingsRow>, sqlx::Error> {
+    sqlx::query_as::<_, SmtpSettingsRow>(
+        "SELECT host, port, security, username, password_encrypted, from_address, from_name, updated_at
+            FROM tenant_smtp_settings WHERE tenant_id = $1",
+    )
+    .bind(tenant_id)
+    .fetch_optional(pool)
+    .await
+}
+
+pub async fn upsert_smtp(
+    pool: &PgPool,
+    tenant_id: i64,
+    row: &SmtpSettingsRow,
+) -> Result<(), sqlx::Error> {
+    sqlx::query(
+        "INSERT INTO tenant_smtp_settings
+            (tenant_id, host, port, security, username, password_encrypted, from_address, from_name, updated_at)
+         VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9)
+         ON CONFLICT (tenant_id) DO UPDATE SET
+            host = $2, port = $3, security = $4, username = $5, password_encrypted = $6,
+            from_address = $7, from_name = $8, updated_at = $9",
+    )
+    .bind(tenant_id)
+    .bind(&row.host)
+    .bind(row.port)
+    .bind(&row.security)
+    .bind(&row.username)
+    .bind(&row.password_encrypted)
+    .bind(&row.from_address)
+    .bind(&row.from_name)
+    .bind(row.updated_at)
+    .execute(pool)
+    .await?;
+    Ok(())
+}
+
+pub async fn delete_smtp(pool: &PgPool, tenant_id: i64) -> Result<bool, sqlx::Error> {
+    let result = sqlx::query("DELETE FROM tenant_smtp_settings WHERE tenant_id = $1")
+        .bind(tenant_id)
+        .execute(pool)
+        .await?;
+    Ok(result.rows_affected() > 0)
+}
+
+#[derive(sqlx::FromRow)]
+pub struct EmailTemplateRow {
+    pub kind: String,
+    pub subject: String,
+    pub body: String,
+    pub updated_at: i64,
+}
+
+pub async fn list_templates(
+    pool: &PgPool,
+    tenant_id: i64,
+) -> Result<Vec<EmailTemplateRow>, sqlx::Error> {
+    sqlx::query_as::<_, EmailTemplateRow>(
+        "SELECT kind, subject, body, updated_at FROM tenant_email_templates WHERE tenant_id = $1",
+    )
+    .bind(tenant_id)
+    .fetch_all(pool)
+    .await
+}
+
+pub async fn find_template(
+    pool: &PgPool,
+    tenant_id: i64,
+    kind: &str,
+) -> Result<Option<EmailTemplateRow>, sqlx::Error> {
+    sqlx::query_as::<_, EmailTemplateRow>(
+        "SELECT kind, subject, body, updated_at FROM tenant_email_templates WHERE tenant_id = $1 AND kind = $2",
+    )
+    .bind(tenant_id)
+    .bind(kind)
+    .fetch_optional(pool)
+    .await
+}
+
+pub async fn upsert_template(
+    pool: &PgPool,
+    tenant_id: i64,
+    kind: &str,
+    subject: &str,
+    body: &str,
+    now: i64,
+) -> Result<(), sqlx::Error> {
+    sqlx::query(
+        "INSERT INTO tenant_email_templates (tenant_id, kind, subject, body, updated_at)
+         VALUES ($1, $2, $3, $4, $5)
+         ON CONFLICT (tenant_id, kind) DO UPDATE SET subject = $3, body = $4, updated_at = $5",
+    )
+    .bind(tenant_id)
+    .bind(kind)
+    .bind(subject)
+    .bind(body)
+    .bind(now)
+    .execute(pool)
+    .await?;
+    Ok(())
+}
+
+/// Drop the override so the built-in default applies again
+pub async fn delete_template(
+    pool: &PgPool,
+    tenant_id: i64,
+    kind: &str,
+) -> Result<bool, sqlx::Error> {
+    let result =
+        sqlx::query("DELETE FROM tenant_email_templates WHERE tenant_id = $1 AND kind = $2")
+            .bind(tenant_id)
+            .bind(kind)
+            .execute(pool)
+            .await?;
+    Ok(result.rows_affected() > 0)
+}
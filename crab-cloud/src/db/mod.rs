@@ -4,11 +4,14 @@ pub mod activations;
 pub mod audit;
 pub mod client_connections;
 pub mod commands;
+pub mod email_settings;
 pub mod email_verifications;
 pub mod p12;
 pub mod refresh_tokens;
+pub mod stock_transfers;
 pub mod store;
 pub mod subscriptions;
+pub mod support;
 pub mod sync_store;
 pub mod tenant_images;
 pub mod tenant_queries;
@@ -0,0 +1,139 @@
+//! Inter-store stock transfers relayed between edges (门店间调拨)
+
+use shared::cloud::CloudMessage;
+use shared::cloud::transfer::{TransferDispatch, TransferPeer, TransferReceipt};
+use sqlx::PgPool;
+use sqlx::types::Json;
+
+/// Other active stores of the tenant (transfer destinations for `store_id`)
+pub async fn list_peers(
+    pool: &PgPool,
+    tenant_id: i64,
+    store_id: i64,
+) -> Result<Vec<TransferPeer>, sqlx::Error> {
+    let rows: Vec<(i64, String)> = sqlx::query_as(
+        "SELECT id, COALESCE(NULLIF(name, ''), alias) FROM stores
+          WHERE tenant_id = $1 AND id != $2 AND status = 'active'
+          ORDER BY store_number",
+    )
+    .bind(tenant_id)
+    .bind(store_id)
+    .fetch_all(pool)
+    .await?;
+    Ok(rows
+        .into_iter()
+        .map(|(store_id, name)| TransferPeer { store_id, name })
+        .collect())
+}
+
+/// Display name of a store
+pub async fn store_name(pool: &PgPool, store_id: i64) -> Result<String, sqlx::Error> {
+    sqlx::query_scalar("SELECT COALESCE(NULLIF(name, ''), alias) FROM stores WHERE id = $1")
+        .bind(store_id)
+        .fetch_one(pool)
+        .await
+}
+
+/// Record a dispatch as relayed to the receiving store (re-sent by the edge
+/// until delivered → idempotent). `dispatch.store_id` is the source store.
+pub async fn insert_dispatch(
+    pool: &PgPool,
+    tenant_id: i64,
+    to_store_id: i64,
+    dispatch: &TransferDispatch,
+) -> Result<bool, sqlx::Error> {
+    let result = sqlx::query(
+        "INSERT INTO stock_transfers (id, tenant_id, from_store_id, to_store_id, dispatch, sent_at)
+         VALUES ($1, $2, $3, $4, $5, $6)
+         ON CONFLICT (id) DO NOTHING",
+    )
+    .bind(dispatch.transfer_id)
+    .bind(tenant_id)
+    .bind(dispatch.store_id)
+    .bind(to_store_id)
+    .bind(Json(dispatch))
+    .bind(dispatch.sent_at)
+    .execute(pool)
+    .await?;
+    Ok(result.rows_affected() > 0)
+}
+
+/// Record the receiving store's confirmation; returns the sending store, or
+/// None when the transfer is unknown, not addressed to `to_store_id` or
+/// already confirmed. `receipt.store_id` is the receiving store.
+pub async fn record_receipt(
+    pool: &PgPool,
+    to_store_id: i64,
+    receipt: &TransferReceipt,
+) -> Result<Option<i64>, sqlx::Error> {
+    sqlx::query_scalar(
+        "UPDATE stock_transfers SET receipt = $3, received_at = $4
+          WHERE id = $1 AND to_store_id = $2 AND receipt IS NULL
+         RETURNING from_store_id",
+    )
+    .bind(receipt.transfer_id)
+    .bind(to_store_id)
+    .bind(Json(receipt))
+    .bind(receipt.received_at)
+    .fetch_optional(pool)
+    .await
+}
+
+/// Edge acknowledged a relayed dispatch (receiving store) or receipt (sending store)
+pub async fn mark_delivered(
+    pool: &PgPool,
+    store_id: i64,
+    transfer_id: i64,
+    now: i64,
+) -> Result<(), sqlx::Error> {
+    sqlx::query(
+        "UPDATE stock_transfers SET
+            dispatch_delivered_at = CASE WHEN to_store_id = $2
+                THEN COALESCE(dispatch_delivered_at, $3) ELSE dispatch_delivered_at END,
+            receipt_delivered_at = CASE WHEN from_store_id = $2 AND receipt IS NOT NULL
+                THEN COALESCE(receipt_delivered_at, $3) ELSE receipt_delivered_at END
+          WHERE id = $1",
+    )
+    .bind(transfer_id)
+    .bind(store_id)
+    .bind(now)
+    .execute(pool)
+    .await?;
+    Ok(())
+}
+
+/// Relayed documents the store has not acknowledged yet (oldest first)
+pub async fn pending_for_store(
+    pool: &PgPool,
+    store_id: i64,
+) -> Result<Vec<CloudMessage>, sqlx::Error> {
+    let dispatches: Vec<Json<TransferDispatch>> = sqlx::query_scalar(
+        "SELECT dispatch FROM stock_transfers
+          WHERE to_store_id = $1 AND dispatch_delivered_at IS NULL
+          ORDER BY sent_at",
+    )
+    .bind(store_id)
+    .fetch_all(pool)
+    .await?;
+    let receipts: Vec<Json<TransferReceipt>> = sqlx::query_scalar(
+        "SELECT receipt FROM stock_transfers
+          WHERE from_store_id = $1 AND receipt IS NOT NULL AND receipt_delivered_at IS NULL
+          ORDER BY received_at",
+    )
+    .bind(store_id)
+    .fetch_all(pool)
+    .await?;
+
+    let dispatches =
+        dispatches
+            .into_iter()
+            .map(|Json(dispatch)| CloudMessage::StockTransferDispatched {
+                dispatch: Box::new(dispatch),
+            });
+    let receipts = receipts
+        .into_iter()
+        .map(|Json(receipt)| CloudMessage::StockTransferReceived {
+            receipt: Box::new(receipt),
+        });
+    Ok(dispatches.chain(receipts).collect())
+}
@@ -407,6 +407,7 @@ pub async fn create_attribute_direct(
             kitchen_print_name: r.kitchen_print_name,
             enable_quantity: r.enable_quantity,
             max_quantity: r.max_quantity,
+            stock_level_id: None,
         })
         .collect();
 
@@ -0,0 +1,325 @@
+//! Coupon database operations (优惠码)
+//!
+//! used_count 由 edge 在订单完成时递增并同步上来，Console 不直接修改。
+
+use shared::cloud::store_op::StoreOpData;
+use shared::models::price_rule::{AdjustmentType, ProductScope};
+use shared::models::{
+    Coupon, CouponCreate, CouponUpdate, generate_coupon_code, normalize_coupon_code,
+};
+use sqlx::PgPool;
+
+use super::BoxError;
+
+/// 生成码碰撞重试次数
+const CODE_ATTEMPTS: usize = 5;
+
+fn db_err(e: sqlx::Error) -> shared::error::AppError {
+    shared::error::AppError::with_message(shared::ErrorCode::InternalError, e.to_string())
+}
+
+fn is_unique_violation(e: &sqlx::Error) -> bool {
+    e.as_database_error()
+        .is_some_and(|d| d.is_unique_violation())
+}
+
+fn enum_str<T: serde::Serialize>(value: &T) -> String {
+    serde_json::to_value(value)
+        .ok()
+        .and_then(|v| v.as_str().map(String::from))
+        .unwrap_or_default()
+}
+
+/// Validate scope/target and discount (Tag scope unsupported: snapshot items carry no tags)
+fn validate_discount(
+    product_scope: &ProductScope,
+    target_id: Option<i64>,
+    adjustment_type: &AdjustmentType,
+    value: f64,
+) -> Result<(), shared::error::AppError> {
+    match product_scope {
+        ProductScope::Global => {}
+        ProductScope::Category | ProductScope::Product => {
+            if target_id.is_none() {
+                return Err(shared::error::AppError::validation(
+                    "target_id is required for category/product coupons",
+                ));
+            }
+        }
+        ProductScope::Tag => {
+            return Err(shared::error::AppError::validation(
+                "Tag scope is not supported for coupons",
+            ));
+        }
+    }
+    if !value.is_finite() || value <= 0.0 {
+        return Err(shared::error::AppError::validation(
+            "adjustment_value must be a positive number",
+        ));
+    }
+    match adjustment_type {
+        AdjustmentType::Percentage if value > 100.0 => Err(shared::error::AppError::validation(
+            "Percentage adjustment_value must be between 0 and 100",
+        )),
+        AdjustmentType::FixedAmount if value > 1_000_000.0 => {
+            Err(shared::error::AppError::validation(
+                "FixedAmount adjustment_value must not exceed 1,000,000",
+            ))
+        }
+        _ => Ok(()),
+    }
+}
+
+fn validate_code(code: &str) -> Result<(), shared::error::AppError> {
+    if code.is_empty() || !code.chars().all(|c| c.is_ascii_alphanumeric() || c == '-') {
+        return Err(shared::error::AppError::validation(
+            "code may only contain letters, digits and '-'",
+        ));
+    }
+    Ok(())
+}
+
+// ── Edge Sync ──
+
+pub async fn upsert_coupon_from_sync(
+    pool: &PgPool,
+    store_id: i64,
+    source_id: i64,
+    data: &serde_json::Value,
+    now: i64,
+) -> Result<(), BoxError> {
+    let coupon: Coupon = serde_json::from_value(data.clone())?;
+
+    sqlx::query(
+        r#"
+        INSERT INTO store_coupons (
+            store_id, source_id, code, name, product_scope, target_id,
+            adjustment_type, adjustment_value, max_uses, used_count,
+            valid_from, valid_until, is_active, created_at, updated_at
+        )
+        VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9, $10, $11, $12, $13, $14, $15)
+        ON CONFLICT (store_id, source_id)
+        DO UPDATE SET
+            code = EXCLUDED.code, name = EXCLUDED.name,
+            product_scope = EXCLUDED.product_scope, target_id = EXCLUDED.target_id,
+            adjustment_type = EXCLUDED.adjustment_type, adjustment_value = EXCLUDED.adjustment_value,
+            max_uses = EXCLUDED.max_uses, used_count = EXCLUDED.used_count,
+            valid_from = EXCLUDED.valid_from, valid_until = EXCLUDED.valid_until,
+            is_active = EXCLUDED.is_active, updated_at = EXCLUDED.updated_at
+        WHERE store_coupons.updated_at <= EXCLUDED.updated_at
+        "#,
+    )
+    .bind(store_id)
+    .bind(source_id)
+    .bind(&coupon.code)
+    .bind(&coupon.name)
+    .bind(enum_str(&coupon.product_scope))
+    .bind(coupon.target_id)
+    .bind(enum_str(&coupon.adjustment_type))
+    .bind(coupon.adjustment_value)
+    .bind(coupon.max_uses)
+    .bind(coupon.used_count)
+    .bind(coupon.valid_from)
+    .bind(coupon.valid_until)
+    .bind(coupon.is_active)
+    .bind(coupon.created_at)
+    .bind(now)
+    .execute(pool)
+    .await?;
+    Ok(())
+}
+
+// ── Console Read ──
+
+#[derive(sqlx::FromRow)]
+struct CouponRow {
+    source_id: i64,
+    code: String,
+    name: String,
+    product_scope: String,
+    target_id: Option<i64>,
+    adjustment_type: String,
+    adjustment_value: f64,
+    max_uses: Option<i64>,
+    used_count: i64,
+    valid_from: Option<i64>,
+    valid_until: Option<i64>,
+    is_active: bool,
+    created_at: i64,
+    updated_at: i64,
+}
+
+impl CouponRow {
+    fn into_coupon(self) -> Coupon {
+        Coupon {
+            id: self.source_id,
+            code: self.code,
+            name: self.name,
+            product_scope: serde_json::from_value::<ProductScope>(serde_json::Value::String(
+                self.product_scope.clone(),
+            ))
+            .unwrap_or_else(|e| {
+                tracing::warn!(product_scope = %self.product_scope, error = %e, "Invalid product_scope, defaulting to Global");
+                ProductScope::Global
+            }),
+            target_id: self.target_id,
+            adjustment_type: serde_json::from_value::<AdjustmentType>(
+                serde_json::Value::String(self.adjustment_type.clone()),
+            )
+            .unwrap_or_else(|e| {
+                tracing::warn!(adjustment_type = %self.adjustment_type, error = %e, "Invalid adjustment_type, defaulting to Percentage");
+                AdjustmentType::Percentage
+            }),
+            adjustment_value: self.adjustment_value,
+            max_uses: self.max_uses,
+            used_count: self.used_count,
+            valid_from: self.valid_from,
+            valid_until: self.valid_until,
+            is_active: self.is_active,
+            created_at: self.created_at,
+            updated_at: self.updated_at,
+        }
+    }
+}
+
+pub async fn list_coupons(pool: &PgPool, store_id: i64) -> Result<Vec<Coupon>, BoxError> {
+    let rows = sqlx::query_as::<_, CouponRow>(
+        r#"
+        SELECT source_id, code, name, product_scope, target_id,
+               adjustment_type, adjustment_value, max_uses, used_count,
+               valid_from, valid_until, is_active, created_at, updated_at
+        FROM store_coupons
+        WHERE store_id = $1
+        ORDER BY created_at DESC
+        "#,
+    )
+    .bind(store_id)
+    .fetch_all(pool)
+    .await?;
+
+    Ok(rows.into_iter().map(|r| r.into_coupon()).collect())
+}
+
+// ── Console CRUD ──
+
+/// Create a coupon; a missing code is generated here and written back into `data`
+/// so the edge stores the same code.
+pub async fn create_coupon_direct(
+    pool: &PgPool,
+    store_id: i64,
+    data: &mut CouponCreate,
+) -> Result<(i64, StoreOpData), shared::error::AppError> {
+    validate_discount(
+        &data.product_scope,
+        data.target_id,
+        &data.adjustment_type,
+        data.adjustment_value,
+    )?;
+    if data.max_uses.is_some_and(|max| max <= 0) {
+        return Err(shared::error::AppError::validation(
+            "max_uses must be positive",
+        ));
+    }
+    let given_code = data.code.as_deref().map(normalize_coupon_code);
+    if let Some(code) = &given_code {
+        validate_code(code)?;
+    }
+
+    let now = shared::util::now_millis();
+    let product_scope_str = enum_str(&data.product_scope);
+    let adjustment_type_str = enum_str(&data.adjustment_type);
+    let source_id = super::snowflake_id();
+    let attempts = if given_code.is_some() {
+        1
+    } else {
+        CODE_ATTEMPTS
+    };
+
+    for _ in 0..attempts {
+        let code = given_code
+            .clone()
+            .unwrap_or_else(|| generate_coupon_code(None));
+        let result = sqlx::query(
+            r#"INSERT INTO store_coupons (store_id, source_id, code, name, product_scope, target_id, adjustment_type, adjustment_value, max_uses, used_count, valid_from, valid_until, is_active, created_at, updated_at) VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9, 0, $10, $11, TRUE, $12, $12)"#,
+        )
+        .bind(store_id).bind(source_id).bind(&code).bind(&data.name).bind(&product_scope_str).bind(data.target_id).bind(&adjustment_type_str).bind(data.adjustment_value).bind(data.max_uses).bind(data.valid_from).bind(data.valid_until).bind(now)
+        .execute(pool)
+        .await;
+
+        match result {
+            Ok(_) => {
+                data.code = Some(code.clone());
+                let coupon = Coupon {
+                    id: source_id,
+                    code,
+                    name: data.name.clone(),
+                    product_scope: data.product_scope.clone(),
+                    target_id: data.target_id,
+                    adjustment_type: data.adjustment_type.clone(),
+                    adjustment_value: data.adjustment_value,
+                    max_uses: data.max_uses,
+                    used_count: 0,
+                    valid_from: data.valid_from,
+                    valid_until: data.valid_until,
+                    is_active: true,
+                    created_at: now,
+                    updated_at: now,
+                };
+                return Ok((source_id, StoreOpData::Coupon(coupon)));
+            }
+            Err(e) if is_unique_violation(&e) => continue,
+            Err(e) => return Err(db_err(e)),
+        }
+    }
+    Err(shared::error::AppError::already_exists(format!(
+        "Coupon code {}",
+        given_code.as_deref().unwrap_or("(generated)")
+    )))
+}
+
+pub async fn update_coupon_direct(
+    pool: &PgPool,
+    store_id: i64,
+    source_id: i64,
+    data: &CouponUpdate,
+) -> Result<(), shared::error::AppError> {
+    if let (Some(scope), Some(adj_type), Some(value)) = (
+        &data.product_scope,
+        &data.adjustment_type,
+        data.adjustment_value,
+    ) {
+        validate_discount(scope, data.target_id, adj_type, value)?;
+    }
+    let now = shared::util::now_millis();
+    let product_scope_str = data.product_scope.as_ref().map(enum_str);
+    let adjustment_type_str = data.adjustment_type.as_ref().map(enum_str);
+
+    let rows = sqlx::query("UPDATE store_coupons SET name = COALESCE($1, name), product_scope = COALESCE($2, product_scope), target_id = COALESCE($3, target_id), adjustment_type = COALESCE($4, adjustment_type), adjustment_value = COALESCE($5, adjustment_value), max_uses = COALESCE($6, max_uses), valid_from = COALESCE($7, valid_from), valid_until = COALESCE($8, valid_until), is_active = COALESCE($9, is_active), updated_at = $10 WHERE store_id = $11 AND source_id = $12")
+        .bind(&data.name).bind(&product_scope_str).bind(data.target_id).bind(&adjustment_type_str).bind(data.adjustment_value).bind(data.max_uses).bind(data.valid_from).bind(data.valid_until).bind(data.is_active).bind(now).bind(store_id).bind(source_id)
+        .execute(pool).await.map_err(db_err)?;
+    if rows.rows_affected() == 0 {
+        return Err(shared::error::AppError::not_found(format!(
+            "Coupon {source_id}"
+        )));
+    }
+    Ok(())
+}
+
+pub async fn delete_coupon_direct(
+    pool: &PgPool,
+    store_id: i64,
+    source_id: i64,
+) -> Result<(), shared::error::AppError> {
+    let rows = sqlx::query("DELETE FROM store_coupons WHERE store_id = $1 AND source_id = $2")
+        .bind(store_id)
+        .bind(source_id)
+        .execute(pool)
+        .await
+        .map_err(db_err)?;
+    if rows.rows_affected() == 0 {
+        return Err(shared::error::AppError::not_found(format!(
+            "Coupon {source_id}"
+        )));
+    }
+    Ok(())
+}
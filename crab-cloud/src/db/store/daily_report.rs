@@ -7,6 +7,8 @@ use super::BoxError;
 
 // ── Edge Sync ──
 
+/// Returns the report when this sync created it (first time the cloud sees
+/// it), so the caller can send the report email exactly once.
 pub async fn upsert_daily_report_from_sync(
     pool: &PgPool,
     store_id: i64,
@@ -14,11 +16,11 @@ pub async fn upsert_daily_report_from_sync(
     source_id: i64,
     data: &serde_json::Value,
     now: i64,
-) -> Result<(), BoxError> {
+) -> Result<Option<DailyReport>, BoxError> {
     let report: DailyReport = serde_json::from_value(data.clone())?;
     let mut tx = pool.begin().await?;
 
-    let row: Option<(i64,)> = sqlx::query_as(
+    let row: Option<(i64, bool)> = sqlx::query_as(
         r#"
         INSERT INTO store_daily_reports (
             store_id, tenant_id, source_id, business_date,
@@ -41,7 +43,7 @@ pub async fn upsert_daily_report_from_sync(
             note = EXCLUDED.note,
             updated_at = EXCLUDED.updated_at
         WHERE store_daily_reports.updated_at <= EXCLUDED.updated_at
-        RETURNING id
+        RETURNING id, (xmax = 0) AS inserted
         "#,
     )
     .bind(store_id)
@@ -62,9 +64,9 @@ pub async fn upsert_daily_report_from_sync(
     .await?;
 
     // Stale data — cloud already has a newer version, skip child table updates
-    let Some((pg_id,)) = row else {
+    let Some((pg_id, inserted)) = row else {
         tx.commit().await?;
-        return Ok(());
+        return Ok(None);
     };
 
     // Replace shift breakdowns
@@ -211,5 +213,5 @@ pub async fn upsert_daily_report_from_sync(
     }
 
     tx.commit().await?;
-    Ok(())
+    Ok(inserted.then_some(report))
 }
@@ -141,6 +141,7 @@ pub async fn create_employee_direct(
         is_system: false,
         is_active: true,
         created_at: now,
+        photo: None,
     };
     Ok((source_id, StoreOpData::Employee(employee)))
 }
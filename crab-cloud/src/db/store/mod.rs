@@ -6,6 +6,7 @@
 
 pub mod attribute;
 pub mod category;
+pub mod coupon;
 pub mod daily_report;
 pub mod data_transfer;
 pub mod devices;
@@ -22,6 +23,7 @@ pub mod zone;
 
 pub use attribute::*;
 pub use category::*;
+pub use coupon::*;
 pub use daily_report::*;
 pub use dining_table::*;
 pub use employee::*;
@@ -63,11 +63,11 @@ pub async fn upsert_price_rule_from_sync(
         INSERT INTO store_price_rules (
             store_id, source_id, name, receipt_name, description,
             rule_type, product_scope, target_id, zone_scope,
-            adjustment_type, adjustment_value, is_stackable, is_exclusive,
+            adjustment_type, adjustment_value, is_stackable, is_exclusive, priority,
             valid_from, valid_until, active_days, active_start_time, active_end_time,
             is_active, created_by, created_at, updated_at
         )
-        VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9, $10, $11, $12, $13, $14, $15, $16, $17, $18, $19, $20, $21, $22)
+        VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9, $10, $11, $12, $13, $14, $15, $16, $17, $18, $19, $20, $21, $22, $23)
         ON CONFLICT (store_id, source_id)
         DO UPDATE SET
             name = EXCLUDED.name,
@@ -76,6 +76,7 @@ pub async fn upsert_price_rule_from_sync(
             target_id = EXCLUDED.target_id, zone_scope = EXCLUDED.zone_scope,
             adjustment_type = EXCLUDED.adjustment_type, adjustment_value = EXCLUDED.adjustment_value,
             is_stackable = EXCLUDED.is_stackable, is_exclusive = EXCLUDED.is_exclusive,
+            priority = EXCLUDED.priority,
             valid_from = EXCLUDED.valid_from, valid_until = EXCLUDED.valid_until,
             active_days = EXCLUDED.active_days, active_start_time = EXCLUDED.active_start_time,
             active_end_time = EXCLUDED.active_end_time, is_active = EXCLUDED.is_active,
@@ -96,6 +97,7 @@ pub async fn upsert_price_rule_from_sync(
     .bind(rule.adjustment_value)
     .bind(rule.is_stackable)
     .bind(rule.is_exclusive)
+    .bind(rule.priority)
     .bind(rule.valid_from)
     .bind(rule.valid_until)
     .bind(active_days_mask)
@@ -126,6 +128,7 @@ struct PriceRuleRow {
     adjustment_value: f64,
     is_stackable: bool,
     is_exclusive: bool,
+    priority: i32,
     valid_from: Option<i64>,
     valid_until: Option<i64>,
     active_days: Option<i32>,
@@ -171,6 +174,7 @@ impl PriceRuleRow {
             adjustment_value: self.adjustment_value,
             is_stackable: self.is_stackable,
             is_exclusive: self.is_exclusive,
+            priority: self.priority,
             valid_from: self.valid_from,
             valid_until: self.valid_until,
             active_days: self.active_days.map(|mask| {
@@ -193,7 +197,7 @@ pub async fn list_price_rules(
         r#"
         SELECT source_id, name, receipt_name, description,
                rule_type, product_scope, target_id, zone_scope,
-               adjustment_type, adjustment_value, is_stackable, is_exclusive,
+               adjustment_type, adjustment_value, is_stackable, is_exclusive, priority,
                valid_from, valid_until, active_days, active_start_time, active_end_time,
                is_active, created_by, created_at
         FROM store_price_rules
@@ -220,6 +224,7 @@ pub async fn create_price_rule_direct(
     let zone_scope = data.zone_scope.as_deref().unwrap_or("all");
     let is_stackable = data.is_stackable.unwrap_or(true);
     let is_exclusive = data.is_exclusive.unwrap_or(false);
+    let priority = data.priority.unwrap_or(0);
     let active_days_mask: Option<i32> = data
         .active_days
         .as_ref()
@@ -240,9 +245,9 @@ pub async fn create_price_rule_direct(
     let source_id = super::snowflake_id();
 
     sqlx::query(
-        r#"INSERT INTO store_price_rules (store_id, source_id, name, receipt_name, description, rule_type, product_scope, target_id, zone_scope, adjustment_type, adjustment_value, is_stackable, is_exclusive, valid_from, valid_until, active_days, active_start_time, active_end_time, is_active, created_by, created_at, updated_at) VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9, $10, $11, $12, $13, $14, $15, $16, $17, $18, TRUE, $19, $20, $20)"#,
+        r#"INSERT INTO store_price_rules (store_id, source_id, name, receipt_name, description, rule_type, product_scope, target_id, zone_scope, adjustment_type, adjustment_value, is_stackable, is_exclusive, priority, valid_from, valid_until, active_days, active_start_time, active_end_time, is_active, created_by, created_at, updated_at) VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9, $10, $11, $12, $13, $14, $15, $16, $17, $18, $19, TRUE, $20, $21, $21)"#,
     )
-    .bind(store_id).bind(source_id).bind(&data.name).bind(&data.receipt_name).bind(&data.description).bind(&rule_type_str).bind(&product_scope_str).bind(data.target_id).bind(zone_scope).bind(&adjustment_type_str).bind(data.adjustment_value).bind(is_stackable).bind(is_exclusive).bind(data.valid_from).bind(data.valid_until).bind(active_days_mask).bind(&data.active_start_time).bind(&data.active_end_time).bind(data.created_by).bind(now)
+    .bind(store_id).bind(source_id).bind(&data.name).bind(&data.receipt_name).bind(&data.description).bind(&rule_type_str).bind(&product_scope_str).bind(data.target_id).bind(zone_scope).bind(&adjustment_type_str).bind(data.adjustment_value).bind(is_stackable).bind(is_exclusive).bind(priority).bind(data.valid_from).bind(data.valid_until).bind(active_days_mask).bind(&data.active_start_time).bind(&data.active_end_time).bind(data.created_by).bind(now)
     .execute(pool).await.map_err(db_err)?;
 
     let rule = shared::models::PriceRule {
@@ -258,6 +263,7 @@ pub async fn create_price_rule_direct(
         adjustment_value: data.adjustment_value,
         is_stackable,
         is_exclusive,
+        priority,
         valid_from: data.valid_from,
         valid_until: data.valid_until,
         active_days: data.active_days.clone(),
@@ -301,8 +307,8 @@ pub async fn update_price_rule_direct(
         .as_ref()
         .map(|days| days.iter().fold(0i32, |mask, &day| mask | (1 << day)));
 
-    let rows = sqlx::query("UPDATE store_price_rules SET name = COALESCE($1, name), receipt_name = COALESCE($2, receipt_name), description = COALESCE($3, description), rule_type = COALESCE($4, rule_type), product_scope = COALESCE($5, product_scope), target_id = COALESCE($6, target_id), zone_scope = COALESCE($7, zone_scope), adjustment_type = COALESCE($8, adjustment_type), adjustment_value = COALESCE($9, adjustment_value), is_stackable = COALESCE($10, is_stackable), is_exclusive = COALESCE($11, is_exclusive), valid_from = COALESCE($12, valid_from), valid_until = COALESCE($13, valid_until), active_days = COALESCE($14, active_days), active_start_time = COALESCE($15, active_start_time), active_end_time = COALESCE($16, active_end_time), is_active = COALESCE($17, is_active), updated_at = $18 WHERE store_id = $19 AND source_id = $20")
-        .bind(&data.name).bind(&data.receipt_name).bind(&data.description).bind(&rule_type_str).bind(&product_scope_str).bind(data.target_id).bind(&data.zone_scope).bind(&adjustment_type_str).bind(data.adjustment_value).bind(data.is_stackable).bind(data.is_exclusive).bind(data.valid_from).bind(data.valid_until).bind(active_days_mask).bind(&data.active_start_time).bind(&data.active_end_time).bind(data.is_active).bind(now).bind(store_id).bind(source_id)
+    let rows = sqlx::query("UPDATE store_price_rules SET name = COALESCE($1, name), receipt_name = COALESCE($2, receipt_name), description = COALESCE($3, description), rule_type = COALESCE($4, rule_type), product_scope = COALESCE($5, product_scope), target_id = COALESCE($6, target_id), zone_scope = COALESCE($7, zone_scope), adjustment_type = COALESCE($8, adjustment_type), adjustment_value = COALESCE($9, adjustment_value), is_stackable = COALESCE($10, is_stackable), is_exclusive = COALESCE($11, is_exclusive), valid_from = COALESCE($12, valid_from), valid_until = COALESCE($13, valid_until), active_days = COALESCE($14, active_days), active_start_time = COALESCE($15, active_start_time), active_end_time = COALESCE($16, active_end_time), is_active = COALESCE($17, is_active), priority = COALESCE($18, priority), updated_at = $19 WHERE store_id = $20 AND source_id = $21")
+        .bind(&data.name).bind(&data.receipt_name).bind(&data.description).bind(&rule_type_str).bind(&product_scope_str).bind(data.target_id).bind(&data.zone_scope).bind(&adjustment_type_str).bind(data.adjustment_value).bind(data.is_stackable).bind(data.is_exclusive).bind(data.valid_from).bind(data.valid_until).bind(active_days_mask).bind(&data.active_start_time).bind(&data.active_end_time).bind(data.is_active).bind(data.priority).bind(now).bind(store_id).bind(source_id)
         .execute(pool).await.map_err(db_err)?;
     if rows.rows_affected() == 0 {
         return Err(shared::error::AppError::new(
@@ -418,6 +418,8 @@ pub async fn create_product_direct(
         kitchen_print_name: data.kitchen_print_name.clone(),
         is_kitchen_print_enabled: data.is_kitchen_print_enabled.unwrap_or(-1),
         is_label_print_enabled: data.is_label_print_enabled.unwrap_or(-1),
+        kitchen_print_destinations: vec![],
+        label_print_destinations: vec![],
         is_active: true,
         external_id: data.external_id,
         specs,
@@ -27,9 +27,10 @@ pub async fn upsert_store_info_from_sync(
             currency_decimal_places = $12, timezone = $13,
             receipt_locale = $14,
             receipt_header = $15, receipt_footer = $16,
-            created_at = COALESCE(created_at, $17),
-            updated_at = $18
-        WHERE id = $1 AND (updated_at IS NULL OR updated_at <= $18)
+            tax_mode = $17,
+            created_at = COALESCE(created_at, $18),
+            updated_at = $19
+        WHERE id = $1 AND (updated_at IS NULL OR updated_at <= $19)
         "#,
     )
     .bind(store_id)
@@ -48,6 +49,7 @@ pub async fn upsert_store_info_from_sync(
     .bind(&info.receipt_locale)
     .bind(&info.receipt_header)
     .bind(&info.receipt_footer)
+    .bind(info.tax_mode.as_str())
     .bind(info.created_at)
     .bind(now)
     .execute(pool)
@@ -82,11 +84,12 @@ pub async fn update_store_info_direct(
             receipt_locale = COALESCE($14, receipt_locale),
             receipt_header = COALESCE($15, receipt_header),
             receipt_footer = COALESCE($16, receipt_footer),
-            updated_at = $17
+            tax_mode = COALESCE($17, tax_mode),
+            updated_at = $18
         WHERE id = $1
         RETURNING 1::BIGINT AS id, name, address, nif, logo_url, phone, email, website,
                   business_day_cutoff, currency_code, currency_symbol, currency_decimal_places,
-                  timezone, receipt_locale, receipt_header, receipt_footer, created_at, updated_at
+                  timezone, receipt_locale, receipt_header, receipt_footer, tax_mode, created_at, updated_at
         "#,
     )
     .bind(store_id)
@@ -105,6 +108,7 @@ pub async fn update_store_info_direct(
     .bind(&data.receipt_locale)
     .bind(&data.receipt_header)
     .bind(&data.receipt_footer)
+    .bind(data.tax_mode.map(|m| m.as_str()))
     .bind(now)
     .fetch_one(pool)
     .await?;
@@ -129,7 +133,7 @@ pub async fn get_store_info(pool: &PgPool, store_id: i64) -> Result<Option<Store
         r#"
         SELECT 1::BIGINT AS id, name, address, nif, logo_url, phone, email, website,
                business_day_cutoff, currency_code, currency_symbol, currency_decimal_places,
-               timezone, receipt_locale, receipt_header, receipt_footer, created_at, updated_at
+               timezone, receipt_locale, receipt_header, receipt_footer, tax_mode, created_at, updated_at
         FROM stores
         WHERE id = $1
         "#,
@@ -1,7 +1,7 @@
 //! Zone database operations (normalized columns, not JSONB)
 
 use shared::cloud::store_op::StoreOpData;
-use shared::models::zone::{Zone, ZoneCreate};
+use shared::models::zone::{Zone, ZoneCreate, ZoneProductOverride};
 use sqlx::PgPool;
 
 use super::BoxError;
@@ -40,6 +40,42 @@ pub async fn upsert_zone_from_sync(
     Ok(())
 }
 
+pub async fn upsert_zone_override_from_sync(
+    pool: &PgPool,
+    store_id: i64,
+    source_id: i64,
+    data: &serde_json::Value,
+    now: i64,
+) -> Result<(), BoxError> {
+    let o: ZoneProductOverride = serde_json::from_value(data.clone())?;
+    sqlx::query(
+        r#"
+        INSERT INTO store_zone_product_overrides (
+            store_id, source_id, zone_source_id, product_source_id,
+            price, is_available, updated_at
+        )
+        VALUES ($1, $2, $3, $4, $5, $6, $7)
+        ON CONFLICT (store_id, source_id)
+        DO UPDATE SET
+            zone_source_id = EXCLUDED.zone_source_id,
+            product_source_id = EXCLUDED.product_source_id,
+            price = EXCLUDED.price, is_available = EXCLUDED.is_available,
+            updated_at = EXCLUDED.updated_at
+        WHERE store_zone_product_overrides.updated_at <= EXCLUDED.updated_at
+        "#,
+    )
+    .bind(store_id)
+    .bind(source_id)
+    .bind(o.zone_id)
+    .bind(o.product_id)
+    .bind(o.price)
+    .bind(o.is_available)
+    .bind(now)
+    .execute(pool)
+    .await?;
+    Ok(())
+}
+
 // ── Console Read ──
 
 pub async fn list_zones(pool: &PgPool, store_id: i64) -> Result<Vec<Zone>, BoxError> {
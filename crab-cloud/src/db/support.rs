@@ -0,0 +1,259 @@
+//! Remote support sessions relayed from edge (远程支持)
+
+use shared::cloud::support::{SupportMessage, SupportSession};
+use sqlx::PgPool;
+
+/// Log lines of sessions closed / expired longer than this are purged
+pub const LOG_RETENTION_MS: i64 = 7 * 24 * 60 * 60 * 1000;
+
+#[derive(sqlx::FromRow, serde::Serialize)]
+pub struct SupportSessionRow {
+    pub id: i64,
+    pub store_id: i64,
+    pub tenant_id: i64,
+    /// Edge-side session id (carried by every WS support message)
+    pub session_id: i64,
+    pub subject: String,
+    pub opened_by: String,
+    pub consent_by: String,
+    pub diagnostics: serde_json::Value,
+    pub opened_at: i64,
+    pub expires_at: i64,
+    pub closed_at: Option<i64>,
+}
+
+impl SupportSessionRow {
+    pub fn is_open(&self, now: i64) -> bool {
+        self.closed_at.is_none() && now < self.expires_at
+    }
+}
+
+#[derive(sqlx::FromRow, serde::Serialize)]
+pub struct SupportMessageRow {
+    pub id: i64,
+    /// 'STORE' | 'AGENT'
+    pub author: String,
+    pub author_name: String,
+    pub text: String,
+    pub sent_at: i64,
+}
+
+#[derive(sqlx::FromRow, serde::Serialize)]
+pub struct SupportLogLine {
+    pub id: i64,
+    pub line: String,
+    pub received_at: i64,
+}
+
+/// Record a session announced by the edge (re-sent on reconnect → idempotent)
+pub async fn upsert_session(
+    pool: &PgPool,
+    store_id: i64,
+    tenant_id: i64,
+    session: &SupportSession,
+    diagnostics: &serde_json::Value,
+) -> Result<i64, sqlx::Error> {
+    sqlx::query_scalar(
+        "INSERT INTO support_sessions
+            (store_id, tenant_id, session_id, subject, opened_by, consent_by, diagnostics, opened_at, expires_at)
+         VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9)
+         ON CONFLICT (store_id, session_id) DO UPDATE SET expires_at = EXCLUDED.expires_at
+         RETURNING id",
+    )
+    .bind(store_id)
+    .bind(tenant_id)
+    .bind(session.session_id)
+    .bind(&session.subject)
+    .bind(&session.opened_by)
+    .bind(&session.consent_by)
+    .bind(diagnostics)
+    .bind(session.opened_at)
+    .bind(session.expires_at)
+    .fetch_one(pool)
+    .await
+}
+
+/// Append sanitized log lines — ignored unless the session is open and not expired
+pub async fn append_logs(
+    pool: &PgPool,
+    store_id: i64,
+    session_id: i64,
+    lines: &[String],
+    now: i64,
+) -> Result<u64, sqlx::Error> {
+    let result = sqlx::query(
+        "INSERT INTO support_log_lines (support_id, line, received_at)
+         SELECT s.id, l.line, $4
+           FROM support_sessions s,
+                UNNEST($3::text[]) WITH ORDINALITY AS l(line, ord)
+          WHERE s.store_id = $1 AND s.session_id = $2
+            AND s.closed_at IS NULL AND s.expires_at > $4
+          ORDER BY l.ord",
+    )
+    .bind(store_id)
+    .bind(session_id)
+    .bind(lines)
+    .bind(now)
+    .execute(pool)
+    .await?;
+    Ok(result.rows_affected())
+}
+
+/// Store a chat message from the edge (store staff)
+pub async fn insert_edge_message(
+    pool: &PgPool,
+    store_id: i64,
+    message: &SupportMessage,
+) -> Result<bool, sqlx::Error> {
+    let result = sqlx::query(
+        "INSERT INTO support_messages (id, support_id, author, author_name, text, sent_at)
+         SELECT $3, s.id, $4, $5, $6, $7
+           FROM support_sessions s
+          WHERE s.store_id = $1 AND s.session_id = $2
+         ON CONFLICT (id) DO NOTHING",
+    )
+    .bind(store_id)
+    .bind(message.session_id)
+    .bind(message.id)
+    .bind(message.author.as_str())
+    .bind(&message.author_name)
+    .bind(&message.text)
+    .bind(message.sent_at)
+    .execute(pool)
+    .await?;
+    Ok(result.rows_affected() > 0)
+}
+
+/// Store a chat message from a support agent
+pub async fn insert_agent_message(
+    pool: &PgPool,
+    support_id: i64,
+    message: &SupportMessage,
+) -> Result<(), sqlx::Error> {
+    sqlx::query(
+        "INSERT INTO support_messages (id, support_id, author, author_name, text, sent_at)
+         VALUES ($1, $2, $3, $4, $5, $6)",
+    )
+    .bind(message.id)
+    .bind(support_id)
+    .bind(message.author.as_str())
+    .bind(&message.author_name)
+    .bind(&message.text)
+    .bind(message.sent_at)
+    .execute(pool)
+    .await?;
+    Ok(())
+}
+
+/// Edge closed the session (or it expired there)
+pub async fn close_by_edge(
+    pool: &PgPool,
+    store_id: i64,
+    session_id: i64,
+    now: i64,
+) -> Result<bool, sqlx::Error> {
+    let result = sqlx::query(
+        "UPDATE support_sessions SET closed_at = $3
+          WHERE store_id = $1 AND session_id = $2 AND closed_at IS NULL",
+    )
+    .bind(store_id)
+    .bind(session_id)
+    .bind(now)
+    .execute(pool)
+    .await?;
+    Ok(result.rows_affected() > 0)
+}
+
+/// Agent closed the session
+pub async fn close(pool: &PgPool, id: i64, now: i64) -> Result<bool, sqlx::Error> {
+    let result = sqlx::query(
+        "UPDATE support_sessions SET closed_at = $2 WHERE id = $1 AND closed_at IS NULL",
+    )
+    .bind(id)
+    .bind(now)
+    .execute(pool)
+    .await?;
+    Ok(result.rows_affected() > 0)
+}
+
+pub async fn find(pool: &PgPool, id: i64) -> Result<Option<SupportSessionRow>, sqlx::Error> {
+    sqlx::query_as::<_, SupportSessionRow>(
+        "SELECT id, store_id, tenant_id, session_id, subject, opened_by, consent_by, diagnostics,
+                opened_at, expires_at, closed_at
+           FROM support_sessions WHERE id = $1",
+    )
+    .bind(id)
+    .fetch_optional(pool)
+    .await
+}
+
+/// Newest first; `open_only` = not closed and not expired
+pub async fn list(
+    pool: &PgPool,
+    open_only: bool,
+    now: i64,
+    limit: i64,
+) -> Result<Vec<SupportSessionRow>, sqlx::Error> {
+    sqlx::query_as::<_, SupportSessionRow>(
+        "SELECT id, store_id, tenant_id, session_id, subject, opened_by, consent_by, diagnostics,
+                opened_at, expires_at, closed_at
+           FROM support_sessions
+          WHERE NOT $1 OR (closed_at IS NULL AND expires_at > $2)
+          ORDER BY opened_at DESC
+          LIMIT $3",
+    )
+    .bind(open_only)
+    .bind(now)
+    .bind(limit)
+    .fetch_all(pool)
+    .await
+}
+
+pub async fn list_messages(
+    pool: &PgPool,
+    support_id: i64,
+) -> Result<Vec<SupportMessageRow>, sqlx::Error> {
+    sqlx::query_as::<_, SupportMessageRow>(
+        "SELECT id, author, author_name, text, sent_at
+           FROM support_messages WHERE support_id = $1 ORDER BY sent_at, id",
+    )
+    .bind(support_id)
+    .fetch_all(pool)
+    .await
+}
+
+/// Log lines after `after_id` (cursor for polling)
+pub async fn list_log_lines(
+    pool: &PgPool,
+    support_id: i64,
+    after_id: i64,
+    limit: i64,
+) -> Result<Vec<SupportLogLine>, sqlx::Error> {
+    sqlx::query_as::<_, SupportLogLine>(
+        "SELECT id, line, received_at
+           FROM support_log_lines
+          WHERE support_id = $1 AND id > $2
+          ORDER BY id
+          LIMIT $3",
+    )
+    .bind(support_id)
+    .bind(after_id)
+    .bind(limit)
+    .fetch_all(pool)
+    .await
+}
+
+/// Drop log lines of sessions that ended before `before`
+pub async fn purge_logs(pool: &PgPool, before: i64) -> Result<u64, sqlx::Error> {
+    let result = sqlx::query(
+        "DELETE FROM support_log_lines
+          WHERE support_id IN (
+              SELECT id FROM support_sessions
+               WHERE COALESCE(closed_at, expires_at) < $1
+          )",
+    )
+    .bind(before)
+    .execute(pool)
+    .await?;
+    Ok(result.rows_affected())
+}
@@ -7,6 +7,7 @@ use std::collections::HashMap;
 
 use rust_decimal::Decimal;
 use shared::cloud::{CloudSyncItem, SyncResource};
+use shared::models::daily_report::DailyReport;
 use shared::models::store_info::StoreInfo;
 use sqlx::PgPool;
 
@@ -28,6 +29,9 @@ pub enum SyncEffect {
     None,
     /// StoreInfo was upserted — callers should broadcast to consoles.
     StoreInfoUpdated(Box<StoreInfo>),
+    /// A daily report reached the cloud for the first time — callers should
+    /// email it to the tenant.
+    DailyReportCreated(Box<DailyReport>),
 }
 
 /// Safely convert u64 version to i64 for PostgreSQL storage.
@@ -242,10 +246,13 @@ pub async fn upsert_resource(
         }
         SyncResource::DailyReport => {
             let source_id = item.resource_id;
-            super::store::upsert_daily_report_from_sync(
+            if let Some(report) = super::store::upsert_daily_report_from_sync(
                 pool, store_id, tenant_id, source_id, &item.data, now,
             )
-            .await?;
+            .await?
+            {
+                return Ok(SyncEffect::DailyReportCreated(Box::new(report)));
+            }
         }
         SyncResource::StoreInfo => {
             let info =
@@ -309,6 +316,17 @@ pub async fn upsert_resource(
             )
             .await?;
         }
+        SyncResource::Coupon => {
+            let source_id = item.resource_id;
+            super::store::upsert_coupon_from_sync(
+                pool,
+                store_id,
+                source_id,
+                &item.data,
+                effective_ts,
+            )
+            .await?;
+        }
         SyncResource::Zone => {
             let source_id = item.resource_id;
             super::store::upsert_zone_from_sync(
@@ -320,6 +338,17 @@ pub async fn upsert_resource(
             )
             .await?;
         }
+        SyncResource::ZoneProductOverride => {
+            let source_id = item.resource_id;
+            super::store::upsert_zone_override_from_sync(
+                pool,
+                store_id,
+                source_id,
+                &item.data,
+                effective_ts,
+            )
+            .await?;
+        }
         SyncResource::DiningTable => {
             let source_id = item.resource_id;
             super::store::upsert_dining_table_from_sync(
@@ -355,6 +384,9 @@ pub async fn upsert_resource(
         SyncResource::ChainEntry => {
             upsert_chain_entry(pool, store_id, tenant_id, item, now).await?;
         }
+        SyncResource::ReceiptArtifact => {
+            insert_receipt_artifact(pool, store_id, tenant_id, item, now).await?;
+        }
         SyncResource::ChainBreak => {
             // BREAK markers are logged but not stored in a separate table.
             // The chain_entry with entry_type='BREAK' is already stored via ChainEntry sync.
@@ -543,9 +575,9 @@ async fn upsert_archived_order(
             version, synced_at,
             is_voided, is_upgraded, customer_nif, customer_nombre,
             customer_address, customer_email, customer_phone,
-            mg_discount_amount, marketing_group_name
+            mg_discount_amount, marketing_group_name, metadata
         )
-        VALUES ($1,$2,$3,$4,$5,$6,$7,$8,$9,$10,$11,$12,$13,$14,$15,$16,$17,$18,$19,$20,$21,$22,$23,$24,$25,$26,$27,$28,$29,$30,$31,$32,$33,$34,$35,$36,$37,$38,$39,$40,$41,$42,$43,$44,$45,$46,$47,$48,$49,$50)
+        VALUES ($1,$2,$3,$4,$5,$6,$7,$8,$9,$10,$11,$12,$13,$14,$15,$16,$17,$18,$19,$20,$21,$22,$23,$24,$25,$26,$27,$28,$29,$30,$31,$32,$33,$34,$35,$36,$37,$38,$39,$40,$41,$42,$43,$44,$45,$46,$47,$48,$49,$50,$51)
         ON CONFLICT (tenant_id, store_id, order_id)
         DO UPDATE SET receipt_number = EXCLUDED.receipt_number,
                       status = EXCLUDED.status,
@@ -591,7 +623,8 @@ async fn upsert_archived_order(
                       customer_email = EXCLUDED.customer_email,
                       customer_phone = EXCLUDED.customer_phone,
                       mg_discount_amount = EXCLUDED.mg_discount_amount,
-                      marketing_group_name = EXCLUDED.marketing_group_name
+                      marketing_group_name = EXCLUDED.marketing_group_name,
+                      metadata = EXCLUDED.metadata
         WHERE store_archived_orders.version <= EXCLUDED.version
         RETURNING id
         "#,
@@ -646,6 +679,7 @@ async fn upsert_archived_order(
     .bind(&d.customer_phone)                 // $48
     .bind(dec(d.mg_discount_amount))         // $49
     .bind(&d.marketing_group_name)           // $50
+    .bind((!d.metadata.is_empty()).then(|| sqlx::types::Json(&d.metadata))) // $51
     .fetch_optional(&mut *tx)
     .await?;
 
@@ -1085,8 +1119,10 @@ fn deletable_table(resource: SyncResource) -> Option<&'static str> {
         SyncResource::AttributeBinding => Some("store_attribute_bindings"),
         SyncResource::PriceRule => Some("store_price_rules"),
         SyncResource::Zone => Some("store_zones"),
+        SyncResource::ZoneProductOverride => Some("store_zone_product_overrides"),
         SyncResource::DiningTable => Some("store_dining_tables"),
         SyncResource::LabelTemplate => Some("store_label_templates"),
+        SyncResource::Coupon => Some("store_coupons"),
         _ => None,
     }
 }
@@ -1168,6 +1204,55 @@ async fn upsert_chain_entry(
     Ok(())
 }
 
+/// Insert archived receipt bytes (write-once — re-sync never overwrites).
+async fn insert_receipt_artifact(
+    pool: &PgPool,
+    store_id: i64,
+    tenant_id: i64,
+    item: &CloudSyncItem,
+    now: i64,
+) -> Result<(), BoxError> {
+    use base64::Engine;
+    use sha2::{Digest, Sha256};
+    use shared::cloud::sync::ReceiptArtifactSync;
+
+    let ra: ReceiptArtifactSync = serde_json::from_value(item.data.clone())?;
+    let content = base64::engine::general_purpose::STANDARD.decode(&ra.content_base64)?;
+    let sha256 = hex::encode(Sha256::digest(&content));
+    if sha256 != ra.sha256 {
+        return Err(format!(
+            "receipt artifact {} hash mismatch: expected {}, got {sha256}",
+            ra.receipt_number, ra.sha256
+        )
+        .into());
+    }
+
+    sqlx::query(
+        r#"
+        INSERT INTO store_receipt_artifacts (
+            store_id, tenant_id, source_id, receipt_number, kind,
+            sha256, reprint, content, created_at, synced_at
+        )
+        VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9, $10)
+        ON CONFLICT (tenant_id, store_id, source_id) DO NOTHING
+        "#,
+    )
+    .bind(store_id)
+    .bind(tenant_id)
+    .bind(ra.id) // source_id = receipt_artifact.id from edge
+    .bind(&ra.receipt_number)
+    .bind(ra.kind.as_str())
+    .bind(&ra.sha256)
+    .bind(ra.reprint)
+    .bind(&content)
+    .bind(ra.created_at)
+    .bind(now)
+    .execute(pool)
+    .await?;
+
+    Ok(())
+}
+
 /// Upsert Verifactu anulación (RegistroFacturaBaja) — invoice voiding record.
 async fn upsert_anulacion(
     pool: &PgPool,
@@ -1495,8 +1580,13 @@ mod tests {
             (SyncResource::AttributeBinding, "store_attribute_bindings"),
             (SyncResource::PriceRule, "store_price_rules"),
             (SyncResource::Zone, "store_zones"),
+            (
+                SyncResource::ZoneProductOverride,
+                "store_zone_product_overrides",
+            ),
             (SyncResource::DiningTable, "store_dining_tables"),
             (SyncResource::LabelTemplate, "store_label_templates"),
+            (SyncResource::Coupon, "store_coupons"),
         ];
         for (resource, table) in expected {
             assert_eq!(
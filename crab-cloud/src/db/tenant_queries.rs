@@ -647,6 +647,7 @@ pub async fn get_order_detail(
         marketing_group_name: Option<String>,
         queue_number: Option<String>,
         shift_id: Option<i64>,
+        metadata: Option<sqlx::types::Json<std::collections::BTreeMap<String, String>>>,
     }
 
     let header = sqlx::query_as::<_, HeaderRow>(
@@ -660,7 +661,7 @@ pub async fn get_order_detail(
                guest_count, discount_amount, void_type, loss_amount,
                is_voided, is_upgraded, customer_nif, customer_nombre,
                customer_address, customer_email, customer_phone,
-               mg_discount_amount, marketing_group_name, queue_number, shift_id
+               mg_discount_amount, marketing_group_name, queue_number, shift_id, metadata
         FROM store_archived_orders
         WHERE store_id = $1 AND tenant_id = $2 AND order_id = $3
         "#,
@@ -969,6 +970,7 @@ pub async fn get_order_detail(
         customer_address: header.customer_address,
         customer_email: header.customer_email,
         customer_phone: header.customer_phone,
+        metadata: header.metadata.map(|m| m.0).unwrap_or_default(),
     }))
 }
 
@@ -1,6 +1,24 @@
 //! Email service — Resend API for transactional emails
+//!
+//! Tenant-facing emails (receipt, report, reservation) go through
+//! [`EmailService::send_templated`], which applies the tenant's template
+//! overrides and SMTP relay when configured. Daily reports are emailed when
+//! they first sync; receipts and reservation confirmations are requested by
+//! the edge (`CloudMessage::SendEmail`).
+
+pub mod smtp;
+pub mod template;
+
+use std::collections::HashMap;
 
 use reqwest::Client;
+use shared::models::daily_report::DailyReport;
+use sqlx::PgPool;
+
+use crate::crypto::MasterKey;
+use crate::db::{self, email_settings};
+use smtp::SmtpConfig;
+use template::{EmailTemplate, EmailTemplateKind};
 
 /// Resend email client wrapper
 #[derive(Clone)]
@@ -19,6 +37,98 @@ impl EmailService {
         }
     }
 
+    /// Tenant template (override or default) for `kind`
+    pub async fn resolve_template(
+        pool: &PgPool,
+        tenant_id: i64,
+        kind: EmailTemplateKind,
+    ) -> Result<EmailTemplate, sqlx::Error> {
+        let row = email_settings::find_template(pool, tenant_id, kind.as_str()).await?;
+        Ok(match row {
+            Some(row) => EmailTemplate {
+                kind,
+                subject: row.subject,
+                body: row.body,
+                is_default: false,
+                variables: kind.variables(),
+                updated_at: Some(row.updated_at),
+            },
+            None => EmailTemplate::default_for(kind),
+        })
+    }
+
+    /// Render the tenant's `kind` template and send it — via the tenant's
+    /// SMTP relay if configured, otherwise via Resend.
+    pub async fn send_templated(
+        &self,
+        pool: &PgPool,
+        master_key: &MasterKey,
+        tenant_id: i64,
+        kind: EmailTemplateKind,
+        to: &str,
+        vars: &HashMap<&str, String>,
+    ) -> Result<(), Box<dyn std::error::Error + Send + Sync>> {
+        let template = Self::resolve_template(pool, tenant_id, kind).await?;
+        let (subject, text) = template.render(vars);
+        match email_settings::find_smtp(pool, tenant_id).await? {
+            Some(row) => {
+                SmtpConfig::from_row(row, master_key)?
+                    .send(to, &subject, &text)
+                    .await?
+            }
+            None => self.send(to, &subject, &text).await?,
+        }
+        tracing::info!(
+            to = to,
+            tenant_id = tenant_id,
+            kind = kind.as_str(),
+            "Templated email sent"
+        );
+        Ok(())
+    }
+
+    /// Email a daily report to the tenant's account address the first time
+    /// the cloud receives it.
+    pub async fn send_daily_report(
+        &self,
+        pool: &PgPool,
+        master_key: &MasterKey,
+        tenant_id: i64,
+        store_id: i64,
+        report: &DailyReport,
+    ) -> Result<(), Box<dyn std::error::Error + Send + Sync>> {
+        let tenant = db::tenants::find_by_id(pool, tenant_id)
+            .await?
+            .ok_or("Tenant not found")?;
+        let info = db::store::get_store_info(pool, store_id).await?;
+        let store_name = info.as_ref().map(|i| i.name.clone()).unwrap_or_default();
+        let currency = info
+            .and_then(|i| i.currency_symbol)
+            .unwrap_or_else(|| "€".to_string());
+
+        let summary = format!(
+            "Ventas netas / Net revenue: {currency}{:.2}\n\
+             Pedidos / Orders: {}\n\
+             Devoluciones / Refunds: {} ({currency}{:.2})",
+            report.net_revenue, report.total_orders, report.refund_count, report.refund_amount,
+        );
+        let vars = HashMap::from([
+            ("store_name", store_name),
+            ("report_name", "Informe diario / Daily report".to_string()),
+            ("date", report.business_date.clone()),
+            ("summary", summary),
+        ]);
+        self.send_templated(
+            pool,
+            master_key,
+            tenant_id,
+            EmailTemplateKind::Report,
+            &tenant.email,
+            &vars,
+        )
+        .await
+    }
+
     async fn send(
         &self,
         to: &str,
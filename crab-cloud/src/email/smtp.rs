@@ -0,0 +1,256 @@
+//! Tenant SMTP relay — used instead of Resend when the tenant configured one
+
+use std::net::{IpAddr, Ipv4Addr, SocketAddr};
+use std::time::Duration;
+
+use lettre::message::{Mailbox, header::ContentType};
+use lettre::transport::smtp::authentication::Credentials;
+use lettre::transport::smtp::client::{Tls, TlsParameters};
+use lettre::{AsyncSmtpTransport, AsyncTransport, Message, Tokio1Executor};
+use serde::{Deserialize, Serialize};
+
+use crate::crypto::MasterKey;
+use crate::db::email_settings::SmtpSettingsRow;
+
+type BoxError = Box<dyn std::error::Error + Send + Sync>;
+
+const SMTP_TIMEOUT: Duration = Duration::from_secs(15);
+
+/// Submission ports a tenant relay may listen on
+pub const ALLOWED_PORTS: [u16; 4] = [25, 465, 587, 2525];
+
+/// Relay host resolves to a non-public address (or not at all)
+#[derive(Debug)]
+pub struct ForbiddenHost;
+
+impl std::fmt::Display for ForbiddenHost {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str("SMTP host is not a public address")
+    }
+}
+
+impl std::error::Error for ForbiddenHost {}
+
+/// Whether `ip` is a globally routable unicast address.
+///
+/// Tenants pick the relay host, so anything that would reach the cloud's own
+/// network (loopback, RFC1918, link-local/metadata, CGNAT, ULA, ...) is refused.
+pub fn is_public_ip(ip: IpAddr) -> bool {
+    match ip {
+        IpAddr::V4(v4) => is_public_v4(v4),
+        IpAddr::V6(v6) => {
+            if let Some(v4) = v6.to_ipv4_mapped() {
+                return is_public_v4(v4);
+            }
+            let seg = v6.segments();
+            // NAT64 (64:ff9b::/96) embeds an IPv4 target
+            if seg[..6] == [0x64, 0xff9b, 0, 0, 0, 0] {
+                let [a, b] = seg[6].to_be_bytes();
+                let [c, d] = seg[7].to_be_bytes();
+                return is_public_v4(Ipv4Addr::new(a, b, c, d));
+            }
+            !(v6.is_loopback()
+                || v6.is_unspecified()
+                || v6.is_multicast()
+                || (seg[0] & 0xfe00) == 0xfc00 // unique local fc00::/7
+                || (seg[0] & 0xffc0) == 0xfe80 // link-local fe80::/10
+                || (seg[0] & 0xffc0) == 0xfec0 // site-local fec0::/10
+                || (seg[0] == 0x2001 && seg[1] == 0x0db8)) // documentation
+        }
+    }
+}
+
+fn is_public_v4(ip: Ipv4Addr) -> bool {
+    let [a, b, c, _] = ip.octets();
+    !(ip.is_loopback()
+        || ip.is_private()
+        || ip.is_link_local()
+        || ip.is_unspecified()
+        || ip.is_broadcast()
+        || ip.is_multicast()
+        || ip.is_documentation()
+        || a == 0 // "this network" 0.0.0.0/8
+        || (a == 100 && (b & 0xc0) == 64) // CGNAT 100.64.0.0/10
+        || (a == 192 && b == 0 && c == 0) // IETF protocol assignments
+        || (a == 198 && (b & 0xfe) == 18) // benchmarking 198.18.0.0/15
+        || a >= 240) // reserved 240.0.0.0/4
+}
+
+/// Resolve `host:port` and return the first address, refusing the host if
+/// any of its addresses is non-public.
+pub async fn resolve_public(host: &str, port: u16) -> Result<SocketAddr, ForbiddenHost> {
+    if !ALLOWED_PORTS.contains(&port) {
+        return Err(ForbiddenHost);
+    }
+    let addrs: Vec<SocketAddr> = tokio::net::lookup_host((host, port))
+        .await
+        .map_err(|_| ForbiddenHost)?
+        .collect();
+    if addrs.is_empty() || addrs.iter().any(|a| !is_public_ip(a.ip())) {
+        return Err(ForbiddenHost);
+    }
+    Ok(addrs[0])
+}
+
+/// Transport security for a tenant relay.
+///
+/// There is no plaintext mode: relay hosts must be public (see `resolve_public`),
+/// so credentials and mail would cross the internet unencrypted.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum SmtpSecurity {
+    /// Implicit TLS (usually port 465)
+    Tls,
+    /// Plain connection upgraded via STARTTLS (usually port 587)
+    Starttls,
+}
+
+impl SmtpSecurity {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            SmtpSecurity::Tls => "tls",
+            SmtpSecurity::Starttls => "starttls",
+        }
+    }
+
+    pub fn parse(s: &str) -> Option<Self> {
+        match s {
+            "tls" => Some(SmtpSecurity::Tls),
+            "starttls" => Some(SmtpSecurity::Starttls),
+            _ => None,
+        }
+    }
+}
+
+/// Decrypted SMTP settings, only ever held in memory
+pub struct SmtpConfig {
+    pub host: String,
+    pub port: u16,
+    pub security: SmtpSecurity,
+    pub username: Option<String>,
+    pub password: Option<String>,
+    pub from_address: String,
+    pub from_name: Option<String>,
+}
+
+impl SmtpConfig {
+    pub fn from_row(row: SmtpSettingsRow, master_key: &MasterKey) -> Result<Self, BoxError> {
+        let password = row
+            .password_encrypted
+            .as_deref()
+            .map(|enc| master_key.decrypt_string(enc))
+            .transpose()?;
+        Ok(Self {
+            host: row.host,
+            port: u16::try_from(row.port)?,
+            security: SmtpSecurity::parse(&row.security)
+                .ok_or_else(|| format!("Unknown SMTP security mode: {}", row.security))?,
+            username: row.username,
+            password,
+            from_address: row.from_address,
+            from_name: row.from_name,
+        })
+    }
+
+    pub async fn send(&self, to: &str, subject: &str, text: &str) -> Result<(), BoxError> {
+        let from = Mailbox::new(self.from_name.clone(), self.from_address.parse()?);
+        let message = Message::builder()
+            .from(from)
+            .to(to.parse()?)
+            .subject(subject)
+            .header(ContentType::TEXT_PLAIN)
+            .body(text.to_string())?;
+
+        // Connect to the checked address (not the name) so a DNS answer that
+        // changes between check and connect can't redirect us inward; TLS
+        // still verifies the certificate against the configured host name.
+        let addr = resolve_public(&self.host, self.port).await?;
+        let builder =
+            AsyncSmtpTransport::<Tokio1Executor>::builder_dangerous(addr.ip().to_string())
+                .port(self.port)
+                .timeout(Some(SMTP_TIMEOUT));
+        let mut builder = match self.security {
+            SmtpSecurity::Tls => builder.tls(Tls::Wrapper(TlsParameters::new(self.host.clone())?)),
+            SmtpSecurity::Starttls => {
+                builder.tls(Tls::Required(TlsParameters::new(self.host.clone())?))
+            }
+        };
+        if let (Some(username), Some(password)) = (&self.username, &self.password) {
+            builder = builder.credentials(Credentials::new(username.clone(), password.clone()));
+        }
+
+        builder.build().send(message).await?;
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn ip(s: &str) -> IpAddr {
+        s.parse().unwrap()
+    }
+
+    #[test]
+    fn test_rejects_internal_v4() {
+        for s in [
+            "127.0.0.1",
+            "10.1.2.3",
+            "172.16.0.1",
+            "192.168.1.1",
+            "169.254.169.254",
+            "100.100.100.200",
+            "0.0.0.0",
+            "255.255.255.255",
+            "240.0.0.1",
+        ] {
+            assert!(!is_public_ip(ip(s)), "{s} should be rejected");
+        }
+    }
+
+    #[test]
+    fn test_rejects_internal_v6() {
+        for s in [
+            "::1",
+            "::",
+            "fe80::1",
+            "fd00:ec2::254",
+            "::ffff:127.0.0.1",
+            "::ffff:169.254.169.254",
+            "64:ff9b::a00:1",
+        ] {
+            assert!(!is_public_ip(ip(s)), "{s} should be rejected");
+        }
+    }
+
+    #[test]
+    fn test_accepts_public() {
+        for s in [
+            "8.8.8.8",
+            "142.250.1.27",
+            "2a00:1450:4003::1b",
+            "::ffff:8.8.8.8",
+        ] {
+            assert!(is_public_ip(ip(s)), "{s} should be accepted");
+        }
+    }
+
+    #[test]
+    fn test_security_has_no_plaintext_mode() {
+        assert_eq!(SmtpSecurity::parse("tls"), Some(SmtpSecurity::Tls));
+        assert_eq!(
+            SmtpSecurity::parse("starttls"),
+            Some(SmtpSecurity::Starttls)
+        );
+        assert_eq!(SmtpSecurity::parse("none"), None);
+        assert!(serde_json::from_str::<SmtpSecurity>("\"none\"").is_err());
+    }
+
+    #[tokio::test]
+    async fn test_resolve_public_rejects_localhost_and_odd_ports() {
+        assert!(resolve_public("localhost", 587).await.is_err());
+        assert!(resolve_public("127.0.0.1", 465).await.is_err());
+        assert!(resolve_public("8.8.8.8", 6379).await.is_err());
+    }
+}
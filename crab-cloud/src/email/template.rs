@@ -0,0 +1,148 @@
+//! Tenant email templates — built-in defaults + `{{variable}}` substitution
+//!
+//! Tenants may override subject/body per kind; anything not overridden falls
+//! back to the bilingual default below. Unknown placeholders are rejected on
+//! save so a typo never reaches a customer's inbox.
+
+use serde::Serialize;
+use std::collections::HashMap;
+
+pub use shared::cloud::email::EmailTemplateKind;
+
+/// Built-in bilingual subject for `kind`
+pub fn default_subject(kind: EmailTemplateKind) -> &'static str {
+    match kind {
+        EmailTemplateKind::Receipt => "Tu recibo de {{store_name}} / Your receipt",
+        EmailTemplateKind::Report => "{{report_name}} — {{store_name}} ({{date}})",
+        EmailTemplateKind::ReservationConfirmation => {
+            "Reserva confirmada en {{store_name}} / Reservation confirmed"
+        }
+    }
+}
+
+/// Built-in bilingual body for `kind`
+pub fn default_body(kind: EmailTemplateKind) -> &'static str {
+    match kind {
+        EmailTemplateKind::Receipt => {
+            "Hola {{customer_name}},\n\
+             Gracias por tu visita a {{store_name}}.\n\
+             Recibo {{receipt_number}} ({{date}})\n\n\
+             {{items}}\n\n\
+             Total: {{total}}\n\n\
+             Hi {{customer_name}},\n\
+             Thank you for visiting {{store_name}}.\n\
+             Receipt {{receipt_number}} ({{date}}) — Total: {{total}}"
+        }
+        EmailTemplateKind::Report => "{{report_name}} — {{store_name}}\n{{date}}\n\n{{summary}}",
+        EmailTemplateKind::ReservationConfirmation => {
+            "Hola {{customer_name}},\n\
+             Tu reserva en {{store_name}} para {{party_size}} personas el {{date}} a las {{time}} está confirmada.\n\
+             Para cambios llama al {{store_phone}}.\n\n\
+             Hi {{customer_name}},\n\
+             Your reservation at {{store_name}} for {{party_size}} on {{date}} at {{time}} is confirmed.\n\
+             To make changes call {{store_phone}}."
+        }
+    }
+}
+
+/// Resolved template (tenant override or default)
+#[derive(Debug, Clone, Serialize)]
+pub struct EmailTemplate {
+    pub kind: EmailTemplateKind,
+    pub subject: String,
+    pub body: String,
+    pub is_default: bool,
+    pub variables: &'static [&'static str],
+    pub updated_at: Option<i64>,
+}
+
+impl EmailTemplate {
+    pub fn default_for(kind: EmailTemplateKind) -> Self {
+        Self {
+            kind,
+            subject: default_subject(kind).to_string(),
+            body: default_body(kind).to_string(),
+            is_default: true,
+            variables: kind.variables(),
+            updated_at: None,
+        }
+    }
+
+    /// Render subject and body with the given variables
+    pub fn render(&self, vars: &HashMap<&str, String>) -> (String, String) {
+        (render(&self.subject, vars), render(&self.body, vars))
+    }
+}
+
+/// Replace `{{name}}` placeholders (whitespace inside braces allowed).
+/// Missing variables render as empty; unterminated braces are kept verbatim.
+pub fn render(template: &str, vars: &HashMap<&str, String>) -> String {
+    let mut out = String::with_capacity(template.len());
+    let mut rest = template;
+    while let Some(start) = rest.find("{{") {
+        out.push_str(&rest[..start]);
+        let after = &rest[start + 2..];
+        match after.find("}}") {
+            Some(end) => {
+                if let Some(value) = vars.get(after[..end].trim()) {
+                    out.push_str(value);
+                }
+                rest = &after[end + 2..];
+            }
+            None => {
+                out.push_str(&rest[start..]);
+                return out;
+            }
+        }
+    }
+    out.push_str(rest);
+    out
+}
+
+/// Placeholders used in `template` that `kind` does not provide
+pub fn unknown_variables(kind: EmailTemplateKind, template: &str) -> Vec<String> {
+    let allowed = kind.variables();
+    let mut unknown = Vec::new();
+    let mut rest = template;
+    while let Some(start) = rest.find("{{") {
+        let after = &rest[start + 2..];
+        let Some(end) = after.find("}}") else { break };
+        let name = after[..end].trim();
+        if !allowed.contains(&name) && !unknown.iter().any(|u| u == name) {
+            unknown.push(name.to_string());
+        }
+        rest = &after[end + 2..];
+    }
+    unknown
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_render_substitutes_and_blanks_missing() {
+        let vars = HashMap::from([
+            ("store_name", "Red Coral".to_string()),
+            ("total", "12,50 €".to_string()),
+        ]);
+        assert_eq!(
+            render("{{store_name}}: {{ total }} {{customer_name}}!", &vars),
+            "Red Coral: 12,50 € !"
+        );
+        assert_eq!(render("broken {{store_name", &vars), "broken {{store_name");
+    }
+
+    #[test]
+    fn test_defaults_only_use_declared_variables() {
+        for kind in EmailTemplateKind::ALL {
+            assert!(unknown_variables(kind, default_subject(kind)).is_empty());
+            assert!(unknown_variables(kind, default_body(kind)).is_empty());
+            assert_eq!(EmailTemplateKind::parse(kind.as_str()), Some(kind));
+        }
+        assert_eq!(
+            unknown_variables(EmailTemplateKind::Report, "{{summary}} {{totl}} {{totl}}"),
+            vec!["totl".to_string()]
+        );
+    }
+}
@@ -34,16 +34,11 @@ async fn main() -> Result<(), BoxError> {
     // Load .env file
     let _ = dotenvy::dotenv();
 
-    // Initialize tracing
-    tracing_subscriber::fmt()
-        .with_env_filter(
-            tracing_subscriber::EnvFilter::try_from_default_env()
-                .unwrap_or_else(|_| "crab_cloud=info,tower_http=info".into()),
-        )
-        .init();
-
     let config = Config::from_env()?;
 
+    // Initialize tracing (+ OTLP export when configured)
+    init_tracing(config.otlp.as_ref());
+
     tracing::info!("Starting crab-cloud (env: {})", config.environment);
 
     // Initialize application state
@@ -255,9 +250,45 @@ async fn main() -> Result<(), BoxError> {
     }
 
     tracing::info!("crab-cloud shut down gracefully");
+    #[cfg(feature = "otel")]
+    shared::telemetry::shutdown();
     Ok(())
 }
 
+/// Initialize tracing: env-filtered fmt output, plus an OTLP exporter layer when configured
+fn init_tracing(otlp: Option<&shared::telemetry::OtlpConfig>) {
+    use tracing_subscriber::prelude::*;
+
+    let filter = tracing_subscriber::EnvFilter::try_from_default_env()
+        .unwrap_or_else(|_| "crab_cloud=info,tower_http=info".into());
+
+    #[cfg(feature = "otel")]
+    let (otel_layer, otel_error) = match otlp.map(shared::telemetry::layer) {
+        Some(Ok(layer)) => (Some(layer), None),
+        Some(Err(e)) => (None, Some(e)),
+        None => (None, None),
+    };
+    #[cfg(not(feature = "otel"))]
+    let (otel_layer, otel_error) = (
+        None::<tracing_subscriber::layer::Identity>,
+        otlp.map(|_| {
+            "OTLP endpoint configured but crab-cloud was built without `otel`".to_string()
+        }),
+    );
+
+    tracing_subscriber::registry()
+        .with(filter)
+        .with(tracing_subscriber::fmt::layer())
+        .with(otel_layer)
+        .init();
+
+    if let Some(e) = otel_error {
+        tracing::warn!("Trace export disabled: {e}");
+    } else if let Some(config) = otlp {
+        tracing::info!(endpoint = %config.endpoint, "Exporting traces via OTLP");
+    }
+}
+
 async fn shutdown_signal() {
     let ctrl_c = async {
         signal::ctrl_c().await.expect("failed to listen for ctrl+c");
@@ -114,6 +114,8 @@ pub struct AppState {
     pub console_connections: Arc<DashMap<i64, AtomicUsize>>,
     /// Environment: development | staging | production
     pub environment: String,
+    /// Remote support agent API key (None = API disabled)
+    pub support_api_key: Option<String>,
 }
 
 impl AppState {
@@ -201,6 +203,7 @@ impl AppState {
             live_orders: LiveOrderHub::new(),
             console_connections: Arc::new(DashMap::new()),
             environment: config.environment.clone(),
+            support_api_key: config.support_api_key.clone(),
         })
     }
 }
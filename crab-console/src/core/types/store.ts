@@ -231,6 +231,7 @@ export interface PriceRule {
   adjustment_value: number;
   is_stackable: boolean;
   is_exclusive: boolean;
+  priority: number;
   valid_from: number | null;
   valid_until: number | null;
   active_days: number[] | null;
@@ -253,6 +254,7 @@ export interface PriceRuleCreate {
   adjustment_value: number;
   is_stackable?: boolean;
   is_exclusive?: boolean;
+  priority?: number;
   valid_from?: number;
   valid_until?: number;
   active_days?: number[];
@@ -272,6 +274,7 @@ export interface PriceRuleUpdate {
   adjustment_value?: number;
   is_stackable?: boolean;
   is_exclusive?: boolean;
+  priority?: number;
   valid_from?: number;
   valid_until?: number;
   active_days?: number[];
@@ -0,0 +1,69 @@
+import { request } from './client';
+
+export type SmtpSecurity = 'tls' | 'starttls';
+
+export interface SmtpSettings {
+  host: string;
+  port: number;
+  security: SmtpSecurity;
+  username: string | null;
+  has_password: boolean;
+  from_address: string;
+  from_name: string | null;
+  updated_at: number;
+}
+
+export interface SmtpSettingsUpdate {
+  host: string;
+  port: number;
+  security: SmtpSecurity;
+  username?: string | null;
+  /** Omit to keep the stored password, '' to clear it */
+  password?: string;
+  from_address: string;
+  from_name?: string | null;
+}
+
+export type EmailTemplateKind = 'receipt' | 'report' | 'reservation_confirmation';
+
+export interface EmailTemplate {
+  kind: EmailTemplateKind;
+  subject: string;
+  body: string;
+  is_default: boolean;
+  variables: string[];
+  updated_at: number | null;
+}
+
+export function getSmtpSettings(token: string): Promise<SmtpSettings | null> {
+  return request('GET', '/api/tenant/email/smtp', undefined, token);
+}
+
+export function updateSmtpSettings(token: string, data: SmtpSettingsUpdate): Promise<SmtpSettings> {
+  return request('PUT', '/api/tenant/email/smtp', data, token);
+}
+
+export function deleteSmtpSettings(token: string): Promise<{ deleted: boolean }> {
+  return request('DELETE', '/api/tenant/email/smtp', undefined, token);
+}
+
+export function testSmtpSettings(token: string, to?: string): Promise<{ sent: boolean; to: string }> {
+  return request('POST', '/api/tenant/email/smtp/test', { to }, token);
+}
+
+export function listEmailTemplates(token: string): Promise<EmailTemplate[]> {
+  return request('GET', '/api/tenant/email/templates', undefined, token);
+}
+
+export function updateEmailTemplate(
+  token: string,
+  kind: EmailTemplateKind,
+  subject: string,
+  body: string,
+): Promise<EmailTemplate> {
+  return request('PUT', `/api/tenant/email/templates/${kind}`, { subject, body }, token);
+}
+
+export function resetEmailTemplate(token: string, kind: EmailTemplateKind): Promise<EmailTemplate> {
+  return request('DELETE', `/api/tenant/email/templates/${kind}`, undefined, token);
+}
@@ -2,7 +2,7 @@ import React, { useMemo } from 'react';
 import {
   Clock, Utensils, CheckCircle, ShoppingBag, Pencil, Trash2, Tag,
   Gift, Ban, Coins, Split, Users, XCircle, ArrowRight, ArrowLeft,
-  UserPlus, UserMinus, Award,
+  UserPlus, UserMinus, Award, CalendarClock,
   type LucideIcon,
 } from 'lucide-react';
 import { formatCurrency } from '@/utils/format';
@@ -71,6 +71,8 @@ const EVENT_CONFIG: Record<string, { icon: LucideIcon; color: string; titleKey:
   ORDER_DISCOUNT_APPLIED:     { icon: Tag,          color: 'bg-orange-400',  titleKey: 'timeline.discount_applied' },
   ORDER_SURCHARGE_APPLIED:    { icon: Tag,          color: 'bg-purple-400',  titleKey: 'timeline.surcharge_applied' },
   ORDER_NOTE_ADDED:           { icon: Pencil,       color: 'bg-blue-400',    titleKey: 'timeline.note_added' },
+  ORDER_METADATA_SET:         { icon: Pencil,       color: 'bg-blue-400',    titleKey: 'timeline.metadata_set' },
+  ORDER_CARRIED_OVER:         { icon: CalendarClock, color: 'bg-amber-500',  titleKey: 'timeline.carried_over' },
   MEMBER_LINKED:              { icon: UserPlus,     color: 'bg-red-400',     titleKey: 'timeline.member_linked' },
   MEMBER_UNLINKED:            { icon: UserMinus,    color: 'bg-red-400',     titleKey: 'timeline.member_unlinked' },
   ITEM_SPLIT:                 { icon: Split,        color: 'bg-teal-500',    titleKey: 'timeline.item_split' },
@@ -325,6 +327,17 @@ export function renderEventDisplay(event: TimelineEvent, t: (k: string) => strin
       if (p.previous_note) details.push(`← ${p.previous_note}`);
       break;
     }
+    case 'ORDER_METADATA_SET': {
+      for (const [key, value] of Object.entries<string>(p.changes ?? {})) {
+        const previous = p.previous?.[key];
+        details.push(previous ? `${key}: ${previous} → ${value || '∅'}` : `${key}: ${value || '∅'}`);
+      }
+      break;
+    }
+    case 'ORDER_CARRIED_OVER': {
+      summary = `${p.from_business_date} → ${p.to_business_date}`;
+      break;
+    }
     case 'MEMBER_LINKED': {
       if (p.member_name) summary = p.member_name;
       if (p.marketing_group_name) details.push(p.marketing_group_name);
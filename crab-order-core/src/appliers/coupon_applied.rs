@@ -0,0 +1,129 @@
+//! CouponApplied event applier
+//!
+//! 纯函数：设置/清除快照中的优惠码，折扣额由 recalculate_totals 按当前菜品重算。
+
+use crate::EventApplier;
+use crate::money::recalculate_totals;
+use shared::order::{EventPayload, OrderEvent, OrderSnapshot};
+
+/// CouponApplied applier
+pub struct CouponAppliedApplier;
+
+impl EventApplier for CouponAppliedApplier {
+    fn apply(&self, snapshot: &mut OrderSnapshot, event: &OrderEvent) {
+        if let EventPayload::CouponApplied { coupon } = &event.payload {
+            // 1. Set or clear the coupon
+            snapshot.coupon = coupon.clone();
+
+            // 2. Recalculate all totals (writes coupon.amount)
+            recalculate_totals(snapshot);
+
+            // 3. Update sequence and timestamp
+            snapshot.last_sequence = event.sequence;
+            snapshot.updated_at = event.timestamp;
+
+            // 4. Update checksum
+            snapshot.update_checksum();
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use shared::models::price_rule::{AdjustmentType, ProductScope};
+    use shared::order::{AppliedCoupon, CartItemSnapshot, OrderEventType, OrderStatus};
+
+    fn create_test_snapshot(order_id: i64, price: f64) -> OrderSnapshot {
+        let mut snapshot = OrderSnapshot::new(order_id);
+        snapshot.status = OrderStatus::Active;
+        snapshot.items.push(CartItemSnapshot {
+            id: 1,
+            instance_id: "inst-1".to_string(),
+            name: "Test Product".to_string(),
+            price,
+            original_price: price,
+            quantity: 1,
+            unpaid_quantity: 1,
+            selected_options: None,
+            selected_specification: None,
+            manual_discount_percent: None,
+            rule_discount_amount: 0.0,
+            rule_surcharge_amount: 0.0,
+            applied_rules: vec![],
+            applied_mg_rules: vec![],
+            mg_discount_amount: 0.0,
+            unit_price: 0.0,
+            line_total: 0.0,
+            tax: 0.0,
+            tax_rate: 0,
+            note: None,
+            authorizer_id: None,
+            authorizer_name: None,
+            category_id: None,
+            category_name: None,
+            is_comped: false,
+            course: None,
+            is_held: false,
+        });
+        recalculate_totals(&mut snapshot);
+        snapshot
+    }
+
+    fn create_coupon_event(order_id: i64, seq: u64, coupon: Option<AppliedCoupon>) -> OrderEvent {
+        OrderEvent::new(
+            seq,
+            order_id,
+            1,
+            "Test User".to_string(),
+            shared::util::snowflake_id(),
+            Some(1234567890),
+            OrderEventType::CouponApplied,
+            EventPayload::CouponApplied { coupon },
+        )
+    }
+
+    fn percent_coupon(value: f64) -> AppliedCoupon {
+        AppliedCoupon {
+            coupon_id: 42,
+            code: "WELCOME10".to_string(),
+            name: "Welcome".to_string(),
+            product_scope: ProductScope::Global,
+            target_id: None,
+            adjustment_type: AdjustmentType::Percentage,
+            adjustment_value: value,
+            amount: 0.0,
+        }
+    }
+
+    #[test]
+    fn test_coupon_counts_as_order_discount() {
+        let mut snapshot = create_test_snapshot(1001, 40.0);
+
+        CouponAppliedApplier.apply(
+            &mut snapshot,
+            &create_coupon_event(1001, 2, Some(percent_coupon(10.0))),
+        );
+
+        let coupon = snapshot.coupon.as_ref().unwrap();
+        assert_eq!(coupon.coupon_id, 42);
+        assert_eq!(coupon.amount, 4.0);
+        assert_eq!(snapshot.discount, 4.0);
+        assert_eq!(snapshot.total, 36.0);
+        assert_eq!(snapshot.last_sequence, 2);
+    }
+
+    #[test]
+    fn test_none_removes_coupon() {
+        let mut snapshot = create_test_snapshot(1001, 40.0);
+        CouponAppliedApplier.apply(
+            &mut snapshot,
+            &create_coupon_event(1001, 2, Some(percent_coupon(10.0))),
+        );
+        CouponAppliedApplier.apply(&mut snapshot, &create_coupon_event(1001, 3, None));
+
+        assert_eq!(snapshot.coupon, None);
+        assert_eq!(snapshot.discount, 0.0);
+        assert_eq!(snapshot.total, 40.0);
+    }
+}
@@ -0,0 +1,102 @@
+//! CourseFired event applier
+//!
+//! Releases the fired items of a course: they are no longer held.
+//! Does NOT affect financial calculations.
+
+use crate::EventApplier;
+use shared::order::{EventPayload, OrderEvent, OrderSnapshot};
+
+/// CourseFired applier
+pub struct CourseFiredApplier;
+
+impl EventApplier for CourseFiredApplier {
+    fn apply(&self, snapshot: &mut OrderSnapshot, event: &OrderEvent) {
+        if let EventPayload::CourseFired { items, .. } = &event.payload {
+            for item in snapshot
+                .items
+                .iter_mut()
+                .filter(|i| items.iter().any(|f| f.instance_id == i.instance_id))
+            {
+                item.is_held = false;
+            }
+
+            // Update sequence and timestamp
+            snapshot.last_sequence = event.sequence;
+            snapshot.updated_at = event.timestamp;
+
+            // Update checksum (no recalculate_totals needed - firing doesn't affect money)
+            snapshot.update_checksum();
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use shared::order::{CartItemSnapshot, OrderEventType};
+
+    fn create_test_item(instance_id: &str, course: Option<i32>, is_held: bool) -> CartItemSnapshot {
+        CartItemSnapshot {
+            id: 1,
+            instance_id: instance_id.to_string(),
+            name: "Steak".to_string(),
+            price: 20.0,
+            original_price: 0.0,
+            quantity: 1,
+            unpaid_quantity: 1,
+            selected_options: None,
+            selected_specification: None,
+            manual_discount_percent: None,
+            rule_discount_amount: 0.0,
+            rule_surcharge_amount: 0.0,
+            applied_rules: vec![],
+            applied_mg_rules: vec![],
+            mg_discount_amount: 0.0,
+            unit_price: 0.0,
+            line_total: 0.0,
+            tax: 0.0,
+            tax_rate: 0,
+            note: None,
+            authorizer_id: None,
+            authorizer_name: None,
+            category_id: None,
+            category_name: None,
+            is_comped: false,
+            course,
+            is_held,
+        }
+    }
+
+    #[test]
+    fn test_apply_releases_fired_items() {
+        let mut snapshot = OrderSnapshot::new(1001);
+        snapshot
+            .items
+            .push(create_test_item("steak", Some(2), true));
+        snapshot.items.push(create_test_item("cake", Some(3), true));
+        assert!(!snapshot.is_course_fired(2));
+
+        let event = OrderEvent::new(
+            5,
+            1001,
+            1,
+            "Test User".to_string(),
+            shared::util::snowflake_id(),
+            Some(1234567890),
+            OrderEventType::CourseFired,
+            EventPayload::CourseFired {
+                course: 2,
+                items: vec![create_test_item("steak", Some(2), false)],
+            },
+        );
+
+        CourseFiredApplier.apply(&mut snapshot, &event);
+
+        assert!(!snapshot.items[0].is_held);
+        assert!(snapshot.items[1].is_held);
+        assert!(snapshot.is_course_fired(2));
+        assert!(!snapshot.is_course_fired(3));
+        assert_eq!(snapshot.last_sequence, 5);
+        assert!(snapshot.verify_checksum());
+    }
+}
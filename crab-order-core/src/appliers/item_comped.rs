@@ -4,8 +4,8 @@
 //! Handles both full comp and partial comp (split + mark).
 //! Uses source_instance_id for deterministic replay.
 
-use crate::order_money;
-use crate::orders::traits::EventApplier;
+use crate::EventApplier;
+use crate::money;
 use shared::order::{CompRecord, EventPayload, OrderEvent, OrderSnapshot};
 
 /// ItemComped applier
@@ -92,7 +92,7 @@ impl EventApplier for ItemCompedApplier {
             snapshot.updated_at = event.timestamp;
 
             // Recalculate totals using precise decimal arithmetic
-            order_money::recalculate_totals(snapshot);
+            money::recalculate_totals(snapshot);
 
             // Update checksum
             snapshot.update_checksum();
@@ -138,6 +138,8 @@ mod tests {
             category_id: None,
             category_name: None,
             is_comped: false,
+            course: None,
+            is_held: false,
         }
     }
 
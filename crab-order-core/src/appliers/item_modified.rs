@@ -3,8 +3,8 @@
 //! Applies the ItemModified event to update items in the snapshot.
 //! Handles both full modifications and split scenarios.
 
-use crate::order_money;
-use crate::orders::traits::EventApplier;
+use crate::EventApplier;
+use crate::money;
 use shared::order::{
     CartItemSnapshot, EventPayload, ItemChanges, ItemModificationResult, OrderEvent, OrderSnapshot,
 };
@@ -35,7 +35,7 @@ impl EventApplier for ItemModifiedApplier {
             snapshot.updated_at = event.timestamp;
 
             // Recalculate totals using precise decimal arithmetic
-            order_money::recalculate_totals(snapshot);
+            money::recalculate_totals(snapshot);
 
             // Update checksum
             snapshot.update_checksum();
@@ -307,6 +307,8 @@ mod tests {
             category_id: None,
             category_name: None,
             is_comped: false,
+            course: None,
+            is_held: false,
         }
     }
 
@@ -942,7 +944,7 @@ mod tests {
     /// 4. Should have 2 separate items (not merged)
     #[test]
     fn test_modified_item_does_not_merge_with_new_item() {
-        use crate::orders::appliers::items_added::ItemsAddedApplier;
+        use crate::appliers::ItemsAddedApplier;
 
         let mut snapshot = OrderSnapshot::new(1001);
 
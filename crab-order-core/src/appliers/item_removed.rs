@@ -7,8 +7,8 @@
 //! snapshot (or their quantity is reduced). For full audit trail support,
 //! a future enhancement could mark items as "voided" instead.
 
-use crate::order_money;
-use crate::orders::traits::EventApplier;
+use crate::EventApplier;
+use crate::money;
 use shared::order::{EventPayload, OrderEvent, OrderSnapshot};
 
 /// ItemRemoved applier
@@ -29,7 +29,7 @@ impl EventApplier for ItemRemovedApplier {
             snapshot.updated_at = event.timestamp;
 
             // Recalculate totals using precise decimal arithmetic
-            order_money::recalculate_totals(snapshot);
+            money::recalculate_totals(snapshot);
 
             // Update checksum
             snapshot.update_checksum();
@@ -74,7 +74,7 @@ fn apply_item_removed(snapshot: &mut OrderSnapshot, instance_id: &str, quantity:
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::order_money::recalculate_totals;
+    use crate::money::recalculate_totals;
     use shared::order::{CartItemSnapshot, OrderEventType};
 
     fn create_test_item(
@@ -110,6 +110,8 @@ mod tests {
             category_id: None,
             category_name: None,
             is_comped: false,
+            course: None,
+            is_held: false,
         }
     }
 
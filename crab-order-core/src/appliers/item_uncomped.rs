@@ -4,8 +4,8 @@
 //! If merged_into is Some, merges the comped item back into the source.
 //! If merged_into is None, restores the item's price in place.
 
-use crate::order_money;
-use crate::orders::traits::EventApplier;
+use crate::EventApplier;
+use crate::money;
 use shared::order::{EventPayload, OrderEvent, OrderSnapshot};
 
 /// ItemUncomped applier
@@ -63,7 +63,7 @@ impl EventApplier for ItemUncompedApplier {
             snapshot.updated_at = event.timestamp;
 
             // Recalculate totals using precise decimal arithmetic
-            order_money::recalculate_totals(snapshot);
+            money::recalculate_totals(snapshot);
 
             // Update checksum
             snapshot.update_checksum();
@@ -110,6 +110,8 @@ mod tests {
             category_id: None,
             category_name: None,
             is_comped,
+            course: None,
+            is_held: false,
         }
     }
 
@@ -2,8 +2,8 @@
 //!
 //! Applies the ItemsAdded event to add items to the snapshot.
 
-use crate::order_money;
-use crate::orders::traits::EventApplier;
+use crate::EventApplier;
+use crate::money;
 use shared::order::{CartItemSnapshot, EventPayload, OrderEvent, OrderSnapshot};
 
 /// ItemsAdded applier
@@ -22,7 +22,7 @@ impl EventApplier for ItemsAddedApplier {
             snapshot.updated_at = event.timestamp;
 
             // Recalculate totals using precise decimal arithmetic
-            order_money::recalculate_totals(snapshot);
+            money::recalculate_totals(snapshot);
 
             // Update checksum
             snapshot.update_checksum();
@@ -94,6 +94,8 @@ mod tests {
             category_id: None,
             category_name: None,
             is_comped: false,
+            course: None,
+            is_held: false,
         }
     }
 
@@ -0,0 +1,106 @@
+//! ItemsHeld event applier
+//!
+//! Moves the listed items into the target course and keeps them held.
+//! Does NOT affect financial calculations.
+
+use crate::EventApplier;
+use shared::order::{EventPayload, OrderEvent, OrderSnapshot};
+
+/// ItemsHeld applier
+pub struct ItemsHeldApplier;
+
+impl EventApplier for ItemsHeldApplier {
+    fn apply(&self, snapshot: &mut OrderSnapshot, event: &OrderEvent) {
+        if let EventPayload::ItemsHeld {
+            course,
+            instance_ids,
+        } = &event.payload
+        {
+            for item in snapshot
+                .items
+                .iter_mut()
+                .filter(|i| instance_ids.contains(&i.instance_id))
+            {
+                item.course = Some(*course);
+                item.is_held = true;
+            }
+
+            // Update sequence and timestamp
+            snapshot.last_sequence = event.sequence;
+            snapshot.updated_at = event.timestamp;
+
+            // Update checksum (no recalculate_totals needed - course doesn't affect money)
+            snapshot.update_checksum();
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use shared::order::{CartItemSnapshot, OrderEventType};
+
+    fn create_test_item(instance_id: &str, course: Option<i32>, is_held: bool) -> CartItemSnapshot {
+        CartItemSnapshot {
+            id: 1,
+            instance_id: instance_id.to_string(),
+            name: "Steak".to_string(),
+            price: 20.0,
+            original_price: 0.0,
+            quantity: 1,
+            unpaid_quantity: 1,
+            selected_options: None,
+            selected_specification: None,
+            manual_discount_percent: None,
+            rule_discount_amount: 0.0,
+            rule_surcharge_amount: 0.0,
+            applied_rules: vec![],
+            applied_mg_rules: vec![],
+            mg_discount_amount: 0.0,
+            unit_price: 0.0,
+            line_total: 0.0,
+            tax: 0.0,
+            tax_rate: 0,
+            note: None,
+            authorizer_id: None,
+            authorizer_name: None,
+            category_id: None,
+            category_name: None,
+            is_comped: false,
+            course,
+            is_held,
+        }
+    }
+
+    #[test]
+    fn test_apply_moves_items_to_course() {
+        let mut snapshot = OrderSnapshot::new(1001);
+        snapshot
+            .items
+            .push(create_test_item("steak", Some(2), true));
+        snapshot.items.push(create_test_item("soup", None, false));
+
+        let event = OrderEvent::new(
+            5,
+            1001,
+            1,
+            "Test User".to_string(),
+            shared::util::snowflake_id(),
+            Some(1234567890),
+            OrderEventType::ItemsHeld,
+            EventPayload::ItemsHeld {
+                course: 3,
+                instance_ids: vec!["steak".to_string()],
+            },
+        );
+
+        ItemsHeldApplier.apply(&mut snapshot, &event);
+
+        assert_eq!(snapshot.items[0].course, Some(3));
+        assert!(snapshot.items[0].is_held);
+        assert_eq!(snapshot.items[1].course, None);
+        assert!(!snapshot.items[1].is_held);
+        assert_eq!(snapshot.last_sequence, 5);
+        assert!(snapshot.verify_checksum());
+    }
+}
@@ -0,0 +1,206 @@
+//! ItemsTransferredOut and ItemsTransferredIn event appliers
+//!
+//! Handles the partial item transfer for both orders:
+//! - ItemsTransferredOut: Source order drops the transferred items and their comp records
+//! - ItemsTransferredIn: Target order receives the items and comp records
+
+use super::items_added::add_or_merge_item;
+use crate::EventApplier;
+use crate::money;
+use shared::order::{EventPayload, OrderEvent, OrderSnapshot};
+
+/// ItemsTransferredOut applier - applies to the source order
+pub struct ItemsTransferredOutApplier;
+
+impl EventApplier for ItemsTransferredOutApplier {
+    fn apply(&self, snapshot: &mut OrderSnapshot, event: &OrderEvent) {
+        if let EventPayload::ItemsTransferredOut { instance_ids, .. } = &event.payload {
+            // Remove transferred items and their comp records
+            snapshot
+                .items
+                .retain(|i| !instance_ids.contains(&i.instance_id));
+            snapshot
+                .comps
+                .retain(|c| !instance_ids.contains(&c.instance_id));
+
+            // Update sequence and timestamp
+            snapshot.last_sequence = event.sequence;
+            snapshot.updated_at = event.timestamp;
+
+            // Recalculate totals using precise decimal arithmetic
+            money::recalculate_totals(snapshot);
+
+            // Update checksum
+            snapshot.update_checksum();
+        }
+    }
+}
+
+/// ItemsTransferredIn applier - applies to the target order
+pub struct ItemsTransferredInApplier;
+
+impl EventApplier for ItemsTransferredInApplier {
+    fn apply(&self, snapshot: &mut OrderSnapshot, event: &OrderEvent) {
+        if let EventPayload::ItemsTransferredIn { items, comps, .. } = &event.payload {
+            // Add items (deduplicate by instance_id)
+            for item in items {
+                add_or_merge_item(snapshot, item);
+            }
+
+            // Comp records follow their comped items
+            for comp in comps {
+                if !snapshot
+                    .comps
+                    .iter()
+                    .any(|c| c.instance_id == comp.instance_id)
+                {
+                    snapshot.comps.push(comp.clone());
+                }
+            }
+
+            // Update sequence and timestamp
+            snapshot.last_sequence = event.sequence;
+            snapshot.updated_at = event.timestamp;
+
+            // Recalculate totals (order-level discounts re-apply on the new subtotal)
+            money::recalculate_totals(snapshot);
+
+            // Update checksum
+            snapshot.update_checksum();
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use shared::order::{CartItemSnapshot, CompRecord, OrderEventType, OrderStatus};
+
+    fn create_test_snapshot(order_id: i64) -> OrderSnapshot {
+        let mut snapshot = OrderSnapshot::new(order_id);
+        snapshot.status = OrderStatus::Active;
+        snapshot
+    }
+
+    fn create_test_item(instance_id: &str, price: f64, quantity: i32) -> CartItemSnapshot {
+        CartItemSnapshot {
+            id: 1,
+            instance_id: instance_id.to_string(),
+            name: "Test".to_string(),
+            price,
+            original_price: 0.0,
+            quantity,
+            unpaid_quantity: quantity,
+            selected_options: None,
+            selected_specification: None,
+            manual_discount_percent: None,
+            rule_discount_amount: 0.0,
+            rule_surcharge_amount: 0.0,
+            applied_rules: vec![],
+            applied_mg_rules: vec![],
+            mg_discount_amount: 0.0,
+            unit_price: 0.0,
+            line_total: 0.0,
+            tax: 0.0,
+            tax_rate: 0,
+            note: None,
+            authorizer_id: None,
+            authorizer_name: None,
+            category_id: None,
+            category_name: None,
+            is_comped: false,
+            course: None,
+            is_held: false,
+        }
+    }
+
+    fn create_comp(instance_id: &str) -> CompRecord {
+        CompRecord {
+            comp_id: 1,
+            instance_id: instance_id.to_string(),
+            source_instance_id: "paella".to_string(),
+            item_name: "Paella".to_string(),
+            quantity: 1,
+            original_price: 10.0,
+            reason: "birthday".to_string(),
+            authorizer_id: 9,
+            authorizer_name: "Manager".to_string(),
+            timestamp: 1,
+        }
+    }
+
+    fn create_event(order_id: i64, seq: u64, payload: EventPayload) -> OrderEvent {
+        let event_type = match &payload {
+            EventPayload::ItemsTransferredOut { .. } => OrderEventType::ItemsTransferredOut,
+            _ => OrderEventType::ItemsTransferredIn,
+        };
+        OrderEvent::new(
+            seq,
+            order_id,
+            1,
+            "Test User".to_string(),
+            1,
+            Some(1234567890),
+            event_type,
+            payload,
+        )
+    }
+
+    #[test]
+    fn test_items_transferred_out_removes_items_and_comps() {
+        let mut snapshot = create_test_snapshot(1001);
+        snapshot.items.push(create_test_item("paella", 10.0, 2));
+        snapshot.items.push(create_test_item("wine", 5.0, 1));
+        let mut comped = create_test_item("paella::comp::1", 0.0, 1);
+        comped.is_comped = true;
+        snapshot.items.push(comped);
+        snapshot.comps.push(create_comp("paella::comp::1"));
+        money::recalculate_totals(&mut snapshot);
+
+        let event = create_event(
+            1001,
+            5,
+            EventPayload::ItemsTransferredOut {
+                target_order_id: 1002,
+                target_table_name: "Mesa 5".to_string(),
+                instance_ids: vec!["paella".to_string(), "paella::comp::1".to_string()],
+            },
+        );
+        ItemsTransferredOutApplier.apply(&mut snapshot, &event);
+
+        assert_eq!(snapshot.items.len(), 1);
+        assert_eq!(snapshot.items[0].instance_id, "wine");
+        assert!(snapshot.comps.is_empty());
+        assert_eq!(snapshot.total, 5.0);
+        assert_eq!(snapshot.last_sequence, 5);
+        assert_eq!(snapshot.status, OrderStatus::Active);
+    }
+
+    #[test]
+    fn test_items_transferred_in_merges_items_and_comps() {
+        let mut snapshot = create_test_snapshot(1002);
+        snapshot.items.push(create_test_item("paella", 10.0, 1));
+        money::recalculate_totals(&mut snapshot);
+
+        let event = create_event(
+            1002,
+            6,
+            EventPayload::ItemsTransferredIn {
+                source_order_id: 1001,
+                source_table_name: "Mesa 1".to_string(),
+                items: vec![
+                    create_test_item("paella", 10.0, 2),
+                    create_test_item("wine", 5.0, 1),
+                ],
+                comps: vec![create_comp("paella::comp::1")],
+            },
+        );
+        ItemsTransferredInApplier.apply(&mut snapshot, &event);
+
+        assert_eq!(snapshot.items.len(), 2);
+        assert_eq!(snapshot.items[0].quantity, 3);
+        assert_eq!(snapshot.comps.len(), 1);
+        assert_eq!(snapshot.total, 35.0);
+        assert_eq!(snapshot.last_sequence, 6);
+    }
+}
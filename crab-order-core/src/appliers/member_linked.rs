@@ -2,8 +2,8 @@
 //!
 //! Applies the MemberLinked event to set member info and MG discounts on the snapshot.
 
-use crate::order_money;
-use crate::orders::traits::EventApplier;
+use crate::EventApplier;
+use crate::money;
 use shared::order::{EventPayload, OrderEvent, OrderSnapshot};
 
 /// MemberLinked applier
@@ -40,7 +40,7 @@ impl EventApplier for MemberLinkedApplier {
             snapshot.updated_at = event.timestamp;
 
             // Recalculate totals (now including MG discounts)
-            order_money::recalculate_totals(snapshot);
+            money::recalculate_totals(snapshot);
 
             // Update checksum
             snapshot.update_checksum();
@@ -110,6 +110,8 @@ mod tests {
             category_id: None,
             category_name: None,
             is_comped: false,
+            course: None,
+            is_held: false,
         }
     }
 
@@ -1,10 +1,10 @@
 //! MemberUnlinked event applier
 //!
-//! Clears member info and MG discount data from the snapshot.
+//! Clears member info, MG discount data and points redemption from the snapshot.
 //! Recalculates totals since MG discounts are removed.
 
-use crate::order_money;
-use crate::orders::traits::EventApplier;
+use crate::EventApplier;
+use crate::money;
 use shared::order::{EventPayload, OrderEvent, OrderSnapshot};
 
 /// MemberUnlinked applier
@@ -76,12 +76,15 @@ impl EventApplier for MemberUnlinkedApplier {
                 snapshot.stamp_redemptions.clear();
             }
 
+            // Points belong to the unlinked member
+            snapshot.points_redemption = None;
+
             // Update sequence and timestamp
             snapshot.last_sequence = event.sequence;
             snapshot.updated_at = event.timestamp;
 
             // Recalculate totals (MG discounts removed)
-            order_money::recalculate_totals(snapshot);
+            money::recalculate_totals(snapshot);
 
             // Update checksum
             snapshot.update_checksum();
@@ -137,6 +140,8 @@ mod tests {
             category_id: None,
             category_name: None,
             is_comped: false,
+            course: None,
+            is_held: false,
         }
     }
 
@@ -228,6 +233,8 @@ mod tests {
             category_id: None,
             category_name: None,
             is_comped: true,
+            course: None,
+            is_held: false,
         }
     }
 
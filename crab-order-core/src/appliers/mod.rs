@@ -3,19 +3,25 @@
 //! Each applier implements the `EventApplier` trait and handles
 //! one specific event type. Appliers are PURE functions.
 
-use crate::orders::traits::EventApplier;
+use crate::EventApplier;
 use shared::order::{EventPayload, OrderEvent, OrderSnapshot};
 
+mod coupon_applied;
+mod course_fired;
 mod item_comped;
 mod item_modified;
 mod item_removed;
 mod item_uncomped;
 mod items_added;
+mod items_held;
+mod items_transferred;
 mod member_linked;
 mod member_unlinked;
 mod order_adjustment_applied;
+mod order_carried_over;
 mod order_completed;
 mod order_info_updated;
+mod order_metadata_set;
 mod order_moved;
 mod order_note_added;
 mod order_split;
@@ -23,21 +29,28 @@ mod order_voided;
 mod orders_merged;
 mod payment_added;
 mod payment_cancelled;
+mod points_redeemed;
 mod rule_skip_toggled;
 mod stamp_redeemed;
 mod stamp_redemption_cancelled;
 mod table_opened;
 
+pub use coupon_applied::CouponAppliedApplier;
+pub use course_fired::CourseFiredApplier;
 pub use item_comped::ItemCompedApplier;
 pub use item_modified::ItemModifiedApplier;
 pub use item_removed::ItemRemovedApplier;
 pub use item_uncomped::ItemUncompedApplier;
 pub use items_added::ItemsAddedApplier;
+pub use items_held::ItemsHeldApplier;
+pub use items_transferred::{ItemsTransferredInApplier, ItemsTransferredOutApplier};
 pub use member_linked::MemberLinkedApplier;
 pub use member_unlinked::MemberUnlinkedApplier;
 pub use order_adjustment_applied::{OrderDiscountAppliedApplier, OrderSurchargeAppliedApplier};
+pub use order_carried_over::OrderCarriedOverApplier;
 pub use order_completed::OrderCompletedApplier;
 pub use order_info_updated::OrderInfoUpdatedApplier;
+pub use order_metadata_set::OrderMetadataSetApplier;
 pub use order_moved::OrderMovedApplier;
 pub use order_note_added::OrderNoteAddedApplier;
 pub use order_split::{
@@ -48,6 +61,7 @@ pub use order_voided::OrderVoidedApplier;
 pub use orders_merged::{OrderMergedApplier, OrderMergedOutApplier};
 pub use payment_added::PaymentAddedApplier;
 pub use payment_cancelled::PaymentCancelledApplier;
+pub use points_redeemed::PointsRedeemedApplier;
 pub use rule_skip_toggled::RuleSkipToggledApplier;
 pub use stamp_redeemed::StampRedeemedApplier;
 pub use stamp_redemption_cancelled::StampRedemptionCancelledApplier;
@@ -69,6 +83,8 @@ pub enum EventAction {
     OrderVoided(OrderVoidedApplier),
     OrderMerged(OrderMergedApplier),
     OrderMergedOut(OrderMergedOutApplier),
+    ItemsTransferredOut(ItemsTransferredOutApplier),
+    ItemsTransferredIn(ItemsTransferredInApplier),
     ItemSplit(ItemSplitApplier),
     AmountSplit(AmountSplitApplier),
     AaSplitStarted(AaSplitStartedApplier),
@@ -77,11 +93,17 @@ pub enum EventAction {
     RuleSkipToggled(RuleSkipToggledApplier),
     OrderDiscountApplied(OrderDiscountAppliedApplier),
     OrderSurchargeApplied(OrderSurchargeAppliedApplier),
+    ItemsHeld(ItemsHeldApplier),
+    CourseFired(CourseFiredApplier),
     OrderNoteAdded(OrderNoteAddedApplier),
+    OrderMetadataSet(OrderMetadataSetApplier),
+    OrderCarriedOver(OrderCarriedOverApplier),
     MemberLinked(MemberLinkedApplier),
     MemberUnlinked(MemberUnlinkedApplier),
     StampRedeemed(StampRedeemedApplier),
     StampRedemptionCancelled(StampRedemptionCancelledApplier),
+    PointsRedeemed(PointsRedeemedApplier),
+    CouponApplied(CouponAppliedApplier),
     /// Record-only events: persisted for timeline display, no snapshot mutation
     RecordOnly,
 }
@@ -104,6 +126,8 @@ impl EventApplier for EventAction {
             EventAction::OrderVoided(applier) => applier.apply(snapshot, event),
             EventAction::OrderMerged(applier) => applier.apply(snapshot, event),
             EventAction::OrderMergedOut(applier) => applier.apply(snapshot, event),
+            EventAction::ItemsTransferredOut(applier) => applier.apply(snapshot, event),
+            EventAction::ItemsTransferredIn(applier) => applier.apply(snapshot, event),
             EventAction::ItemSplit(applier) => applier.apply(snapshot, event),
             EventAction::AmountSplit(applier) => applier.apply(snapshot, event),
             EventAction::AaSplitStarted(applier) => applier.apply(snapshot, event),
@@ -112,11 +136,17 @@ impl EventApplier for EventAction {
             EventAction::RuleSkipToggled(applier) => applier.apply(snapshot, event),
             EventAction::OrderDiscountApplied(applier) => applier.apply(snapshot, event),
             EventAction::OrderSurchargeApplied(applier) => applier.apply(snapshot, event),
+            EventAction::ItemsHeld(applier) => applier.apply(snapshot, event),
+            EventAction::CourseFired(applier) => applier.apply(snapshot, event),
             EventAction::OrderNoteAdded(applier) => applier.apply(snapshot, event),
+            EventAction::OrderMetadataSet(applier) => applier.apply(snapshot, event),
+            EventAction::OrderCarriedOver(applier) => applier.apply(snapshot, event),
             EventAction::MemberLinked(applier) => applier.apply(snapshot, event),
             EventAction::MemberUnlinked(applier) => applier.apply(snapshot, event),
             EventAction::StampRedeemed(applier) => applier.apply(snapshot, event),
             EventAction::StampRedemptionCancelled(applier) => applier.apply(snapshot, event),
+            EventAction::PointsRedeemed(applier) => applier.apply(snapshot, event),
+            EventAction::CouponApplied(applier) => applier.apply(snapshot, event),
             EventAction::RecordOnly => {}
         }
     }
@@ -150,6 +180,12 @@ impl From<&OrderEvent> for EventAction {
             EventPayload::OrderMergedOut { .. } => {
                 EventAction::OrderMergedOut(OrderMergedOutApplier)
             }
+            EventPayload::ItemsTransferredOut { .. } => {
+                EventAction::ItemsTransferredOut(ItemsTransferredOutApplier)
+            }
+            EventPayload::ItemsTransferredIn { .. } => {
+                EventAction::ItemsTransferredIn(ItemsTransferredInApplier)
+            }
             EventPayload::ItemSplit { .. } => EventAction::ItemSplit(ItemSplitApplier),
             EventPayload::AmountSplit { .. } => EventAction::AmountSplit(AmountSplitApplier),
             EventPayload::AaSplitStarted { .. } => {
@@ -168,9 +204,17 @@ impl From<&OrderEvent> for EventAction {
             EventPayload::OrderSurchargeApplied { .. } => {
                 EventAction::OrderSurchargeApplied(OrderSurchargeAppliedApplier)
             }
+            EventPayload::ItemsHeld { .. } => EventAction::ItemsHeld(ItemsHeldApplier),
+            EventPayload::CourseFired { .. } => EventAction::CourseFired(CourseFiredApplier),
             EventPayload::OrderNoteAdded { .. } => {
                 EventAction::OrderNoteAdded(OrderNoteAddedApplier)
             }
+            EventPayload::OrderMetadataSet { .. } => {
+                EventAction::OrderMetadataSet(OrderMetadataSetApplier)
+            }
+            EventPayload::OrderCarriedOver { .. } => {
+                EventAction::OrderCarriedOver(OrderCarriedOverApplier)
+            }
             // Record-only events: persisted for timeline, no snapshot mutation
             EventPayload::OrderMovedOut { .. } | EventPayload::TableReassigned { .. } => {
                 EventAction::RecordOnly
@@ -184,6 +228,10 @@ impl From<&OrderEvent> for EventAction {
             EventPayload::StampRedemptionCancelled { .. } => {
                 EventAction::StampRedemptionCancelled(StampRedemptionCancelledApplier)
             }
+            EventPayload::PointsRedeemed { .. } => {
+                EventAction::PointsRedeemed(PointsRedeemedApplier)
+            }
+            EventPayload::CouponApplied { .. } => EventAction::CouponApplied(CouponAppliedApplier),
         }
     }
 }
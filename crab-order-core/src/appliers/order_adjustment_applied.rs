@@ -2,8 +2,8 @@
 //!
 //! 纯函数：将订单级手动折扣/附加费事件应用到快照。
 
-use crate::order_money::recalculate_totals;
-use crate::orders::traits::EventApplier;
+use crate::EventApplier;
+use crate::money::recalculate_totals;
 use shared::order::{EventPayload, OrderEvent, OrderSnapshot};
 
 /// OrderDiscountApplied applier
@@ -94,6 +94,8 @@ mod tests {
             category_id: None,
             category_name: None,
             is_comped: false,
+            course: None,
+            is_held: false,
         }
     }
 
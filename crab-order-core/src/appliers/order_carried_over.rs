@@ -0,0 +1,59 @@
+//! OrderCarriedOver event applier
+//!
+//! Records the business day the order was carried into.
+//! Does NOT affect financial calculations.
+
+use crate::EventApplier;
+use shared::order::{EventPayload, OrderEvent, OrderSnapshot};
+
+/// OrderCarriedOver applier
+pub struct OrderCarriedOverApplier;
+
+impl EventApplier for OrderCarriedOverApplier {
+    fn apply(&self, snapshot: &mut OrderSnapshot, event: &OrderEvent) {
+        if let EventPayload::OrderCarriedOver {
+            to_business_date, ..
+        } = &event.payload
+        {
+            snapshot.carry_over_date = Some(to_business_date.clone());
+
+            // Update sequence and timestamp
+            snapshot.last_sequence = event.sequence;
+            snapshot.updated_at = event.timestamp;
+
+            // Update checksum (no recalculate_totals needed - money unchanged)
+            snapshot.update_checksum();
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use shared::models::CarryOverPolicy;
+    use shared::order::OrderEventType;
+
+    #[test]
+    fn test_carried_over_sets_business_date() {
+        let mut snapshot = OrderSnapshot::new(1);
+        let event = OrderEvent::new(
+            3,
+            1,
+            0,
+            "System".to_string(),
+            shared::util::snowflake_id(),
+            Some(1234567890),
+            OrderEventType::OrderCarriedOver,
+            EventPayload::OrderCarriedOver {
+                from_business_date: "2024-01-22".to_string(),
+                to_business_date: "2024-01-23".to_string(),
+                policy: CarryOverPolicy::Transfer,
+            },
+        );
+        OrderCarriedOverApplier.apply(&mut snapshot, &event);
+
+        assert_eq!(snapshot.carry_over_date.as_deref(), Some("2024-01-23"));
+        assert_eq!(snapshot.last_sequence, 3);
+        assert!(snapshot.verify_checksum());
+    }
+}
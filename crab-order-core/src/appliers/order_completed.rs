@@ -2,8 +2,8 @@
 //!
 //! Applies the OrderCompleted event to mark the order as completed.
 
-use crate::order_money;
-use crate::orders::traits::EventApplier;
+use crate::EventApplier;
+use crate::money;
 use shared::order::{EventPayload, OrderEvent, OrderSnapshot, OrderStatus};
 
 /// OrderCompleted applier
@@ -16,6 +16,7 @@ impl EventApplier for OrderCompletedApplier {
             service_type,
             final_total: _,
             payment_summary: _,
+            fiscal_code,
         } = &event.payload
         {
             // Set status to Completed
@@ -27,6 +28,9 @@ impl EventApplier for OrderCompletedApplier {
             // 结单时设置服务类型（零售订单才有值）
             snapshot.service_type = *service_type;
 
+            // 税控码 (外部税控设备)
+            snapshot.fiscal_code = fiscal_code.clone();
+
             // Set end time
             snapshot.end_time = Some(event.timestamp);
 
@@ -42,7 +46,7 @@ impl EventApplier for OrderCompletedApplier {
             snapshot.updated_at = event.timestamp;
 
             // Recalculate totals to ensure consistency
-            order_money::recalculate_totals(snapshot);
+            money::recalculate_totals(snapshot);
 
             // Update checksum
             snapshot.update_checksum();
@@ -76,6 +80,7 @@ mod tests {
                 service_type: Some(ServiceType::DineIn),
                 final_total,
                 payment_summary,
+                fiscal_code: None,
             },
         )
     }
@@ -233,9 +238,11 @@ mod tests {
             category_id: None,
             category_name: None,
             is_comped: false,
+            course: None,
+            is_held: false,
         });
         // Recalculate to set total/subtotal correctly
-        crate::order_money::recalculate_totals(&mut snapshot);
+        crate::money::recalculate_totals(&mut snapshot);
         assert_eq!(snapshot.total, 150.0);
 
         let event = create_order_completed_event(1001, 1, "RCP-001", 150.0, vec![]);
@@ -333,6 +340,7 @@ mod tests {
                 service_type: Some(ServiceType::Takeout),
                 final_total: 50.0,
                 payment_summary: vec![],
+                fiscal_code: None,
             },
         );
 
@@ -3,7 +3,7 @@
 //! Applies the OrderInfoUpdated event to update order metadata in the snapshot.
 //! Only updates fields that are present (Some) in the event payload.
 
-use crate::orders::traits::EventApplier;
+use crate::EventApplier;
 use shared::order::{EventPayload, OrderEvent, OrderSnapshot};
 
 /// OrderInfoUpdated applier
@@ -336,6 +336,7 @@ mod tests {
                 service_type: Some(ServiceType::DineIn),
                 final_total: 100.0,
                 payment_summary: vec![],
+                fiscal_code: None,
             },
         );
 
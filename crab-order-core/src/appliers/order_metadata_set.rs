@@ -0,0 +1,77 @@
+//! OrderMetadataSet event applier
+//!
+//! Merges the changed keys into the order metadata.
+//! Empty value removes the key. Does NOT affect financial calculations.
+
+use crate::EventApplier;
+use shared::order::{EventPayload, OrderEvent, OrderSnapshot};
+
+/// OrderMetadataSet applier
+pub struct OrderMetadataSetApplier;
+
+impl EventApplier for OrderMetadataSetApplier {
+    fn apply(&self, snapshot: &mut OrderSnapshot, event: &OrderEvent) {
+        if let EventPayload::OrderMetadataSet { changes, .. } = &event.payload {
+            for (key, value) in changes {
+                if value.is_empty() {
+                    snapshot.metadata.remove(key);
+                } else {
+                    snapshot.metadata.insert(key.clone(), value.clone());
+                }
+            }
+
+            // Update sequence and timestamp
+            snapshot.last_sequence = event.sequence;
+            snapshot.updated_at = event.timestamp;
+
+            // Update checksum (no recalculate_totals needed - metadata doesn't affect money)
+            snapshot.update_checksum();
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use shared::order::OrderEventType;
+    use std::collections::BTreeMap;
+
+    #[test]
+    fn test_metadata_set_merges_and_removes() {
+        let mut snapshot = OrderSnapshot::new(1);
+        snapshot
+            .metadata
+            .insert("room".to_string(), "204".to_string());
+        snapshot
+            .metadata
+            .insert("platform".to_string(), "glovo".to_string());
+
+        let event = OrderEvent::new(
+            5,
+            1,
+            1,
+            "Test User".to_string(),
+            shared::util::snowflake_id(),
+            Some(1234567890),
+            OrderEventType::OrderMetadataSet,
+            EventPayload::OrderMetadataSet {
+                changes: BTreeMap::from([
+                    ("room".to_string(), String::new()),
+                    ("platform.order_id".to_string(), "GLV-9".to_string()),
+                ]),
+                previous: BTreeMap::from([("room".to_string(), "204".to_string())]),
+            },
+        );
+        OrderMetadataSetApplier.apply(&mut snapshot, &event);
+
+        assert_eq!(
+            snapshot.metadata,
+            BTreeMap::from([
+                ("platform".to_string(), "glovo".to_string()),
+                ("platform.order_id".to_string(), "GLV-9".to_string()),
+            ])
+        );
+        assert_eq!(snapshot.last_sequence, 5);
+        assert!(snapshot.verify_checksum());
+    }
+}
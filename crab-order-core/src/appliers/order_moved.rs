@@ -3,7 +3,7 @@
 //! Applies the OrderMoved event to update table and zone information in the snapshot.
 //! Updates table_id, table_name, zone_id, and zone_name from the event's target fields.
 
-use crate::orders::traits::EventApplier;
+use crate::EventApplier;
 use shared::order::{EventPayload, OrderEvent, OrderSnapshot};
 
 /// OrderMoved applier
@@ -255,6 +255,8 @@ mod tests {
             category_id: None,
             category_name: None,
             is_comped: false,
+            course: None,
+            is_held: false,
         };
         snapshot.items.push(item.clone());
 
@@ -301,6 +303,7 @@ mod tests {
                 service_type: Some(ServiceType::DineIn),
                 final_total: 100.0,
                 payment_summary: vec![],
+                fiscal_code: None,
             },
         );
 
@@ -4,7 +4,7 @@
 //! Empty note string clears the note (sets to None).
 //! Does NOT affect financial calculations.
 
-use crate::orders::traits::EventApplier;
+use crate::EventApplier;
 use shared::order::{EventPayload, OrderEvent, OrderSnapshot};
 
 /// OrderNoteAdded applier
@@ -7,8 +7,8 @@
 //! - `AaSplitPaidApplier` — AA 支付（进度）
 //! - `AaSplitCancelledApplier` — AA 取消（解锁）
 
-use crate::order_money::{self, MONEY_TOLERANCE, to_decimal, to_f64};
-use crate::orders::traits::EventApplier;
+use crate::EventApplier;
+use crate::money::{self, MONEY_TOLERANCE, to_decimal, to_f64};
 use shared::order::{
     CartItemSnapshot, EventPayload, OrderEvent, OrderSnapshot, PaymentRecord, SplitType,
 };
@@ -74,6 +74,7 @@ impl EventApplier for ItemSplitApplier {
                 tendered: *tendered,
                 change: *change,
                 note,
+                reference: None,
                 timestamp: event.timestamp,
                 cancelled: false,
                 cancel_reason: None,
@@ -99,7 +100,7 @@ impl EventApplier for ItemSplitApplier {
             }
 
             // Recalculate totals
-            order_money::recalculate_totals(snapshot);
+            money::recalculate_totals(snapshot);
 
             snapshot.last_sequence = event.sequence;
             snapshot.updated_at = event.timestamp;
@@ -138,6 +139,7 @@ impl EventApplier for AmountSplitApplier {
                 tendered: *tendered,
                 change: *change,
                 note: None,
+                reference: None,
                 timestamp: event.timestamp,
                 cancelled: false,
                 cancel_reason: None,
@@ -147,7 +149,7 @@ impl EventApplier for AmountSplitApplier {
             };
             snapshot.payments.push(payment);
 
-            order_money::recalculate_totals(snapshot);
+            money::recalculate_totals(snapshot);
 
             snapshot.last_sequence = event.sequence;
             snapshot.updated_at = event.timestamp;
@@ -208,6 +210,7 @@ impl EventApplier for AaSplitPaidApplier {
                 tendered: *tendered,
                 change: *change,
                 note: None,
+                reference: None,
                 timestamp: event.timestamp,
                 cancelled: false,
                 cancel_reason: None,
@@ -217,7 +220,7 @@ impl EventApplier for AaSplitPaidApplier {
             };
             snapshot.payments.push(payment);
 
-            order_money::recalculate_totals(snapshot);
+            money::recalculate_totals(snapshot);
 
             snapshot.last_sequence = event.sequence;
             snapshot.updated_at = event.timestamp;
@@ -281,6 +284,8 @@ mod tests {
             category_id: None,
             category_name: None,
             is_comped: false,
+            course: None,
+            is_held: false,
             tax: 0.0,
             tax_rate: 0,
         };
@@ -308,6 +313,8 @@ mod tests {
             category_id: None,
             category_name: None,
             is_comped: false,
+            course: None,
+            is_held: false,
             tax: 0.0,
             tax_rate: 0,
         };
@@ -2,7 +2,7 @@
 //!
 //! Applies the OrderVoided event to mark the order as voided.
 
-use crate::orders::traits::EventApplier;
+use crate::EventApplier;
 use shared::order::{EventPayload, OrderEvent, OrderSnapshot, OrderStatus};
 
 /// OrderVoided applier
@@ -292,6 +292,7 @@ mod tests {
             tendered: Some(100.0),
             change: Some(0.0),
             note: None,
+            reference: None,
             timestamp: 1234567800,
             cancelled: false,
             cancel_reason: None,
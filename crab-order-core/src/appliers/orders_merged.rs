@@ -5,8 +5,8 @@
 //! - OrderMergedOut: Source order is marked as Merged status
 
 use super::items_added::add_or_merge_item;
-use crate::order_money::{self, to_decimal, to_f64};
-use crate::orders::traits::EventApplier;
+use crate::EventApplier;
+use crate::money::{self, to_decimal, to_f64};
 use shared::order::{EventPayload, OrderEvent, OrderSnapshot, OrderStatus};
 
 /// OrderMerged applier - applies to the target order
@@ -50,7 +50,7 @@ impl EventApplier for OrderMergedApplier {
                 to_f64(to_decimal(snapshot.paid_amount) + to_decimal(*paid_amount));
 
             // Recalculate totals after merging items (updates subtotal, total, remaining, etc.)
-            order_money::recalculate_totals(snapshot);
+            money::recalculate_totals(snapshot);
 
             // Merge split state
             if *has_amount_split {
@@ -87,7 +87,7 @@ impl EventApplier for OrderMergedOutApplier {
             snapshot.payments.clear();
             snapshot.comps.clear();
             snapshot.paid_item_quantities.clear();
-            order_money::recalculate_totals(snapshot);
+            money::recalculate_totals(snapshot);
 
             // Update sequence and timestamp
             snapshot.last_sequence = event.sequence;
@@ -140,6 +140,8 @@ mod tests {
             category_id: None,
             category_name: None,
             is_comped: false,
+            course: None,
+            is_held: false,
         }
     }
 
@@ -373,6 +375,7 @@ mod tests {
                 service_type: Some(ServiceType::DineIn),
                 final_total: 100.0,
                 payment_summary: vec![],
+                fiscal_code: None,
             },
         );
 
@@ -490,6 +493,7 @@ mod tests {
                 service_type: Some(ServiceType::DineIn),
                 final_total: 100.0,
                 payment_summary: vec![],
+                fiscal_code: None,
             },
         );
 
@@ -549,6 +553,7 @@ mod tests {
             tendered: None,
             change: None,
             note: None,
+            reference: None,
             timestamp: 1234567890,
             cancelled: false,
             cancel_reason: None,
@@ -607,6 +612,7 @@ mod tests {
             tendered: None,
             change: None,
             note: None,
+            reference: None,
             timestamp: 1234567890,
             cancelled: false,
             cancel_reason: None,
@@ -622,6 +628,7 @@ mod tests {
             tendered: None,
             change: None,
             note: None,
+            reference: None,
             timestamp: 1234567891,
             cancelled: false,
             cancel_reason: None,
@@ -2,10 +2,10 @@
 //!
 //! Applies the PaymentAdded event to add a payment to the snapshot.
 
-use crate::order_money::{self, MONEY_TOLERANCE, to_decimal, to_f64};
-use crate::orders::traits::EventApplier;
+use crate::EventApplier;
+use crate::money::{self, MONEY_TOLERANCE, to_decimal, to_f64};
 use rust_decimal::Decimal;
-use shared::order::{EventPayload, OrderEvent, OrderSnapshot, PaymentRecord};
+use shared::order::{EventPayload, OrderEvent, OrderSnapshot, PaymentRecord, PaymentSurchargeLine};
 
 /// PaymentAdded applier
 pub struct PaymentAddedApplier;
@@ -19,6 +19,8 @@ impl EventApplier for PaymentAddedApplier {
             tendered,
             change,
             note,
+            reference,
+            surcharge,
         } = &event.payload
         {
             // Create payment record
@@ -29,6 +31,7 @@ impl EventApplier for PaymentAddedApplier {
                 tendered: *tendered,
                 change: *change,
                 note: note.clone(),
+                reference: reference.clone(),
                 timestamp: event.timestamp,
                 cancelled: false,
                 cancel_reason: None,
@@ -40,6 +43,17 @@ impl EventApplier for PaymentAddedApplier {
             // Add payment to snapshot
             snapshot.payments.push(payment);
 
+            // Payment-method surcharge raises the total by the part of `amount` it covers
+            if let Some(surcharge) = surcharge {
+                snapshot.payment_surcharges.push(PaymentSurchargeLine {
+                    payment_id: *payment_id,
+                    method: method.clone(),
+                    amount: *surcharge,
+                    cancelled: false,
+                });
+                money::recalculate_totals(snapshot);
+            }
+
             // Update paid_amount using Decimal for precision
             snapshot.paid_amount = to_f64(to_decimal(snapshot.paid_amount) + to_decimal(*amount));
 
@@ -63,7 +77,7 @@ impl EventApplier for PaymentAddedApplier {
             }
 
             // Always recalculate to update unpaid_quantity per item
-            order_money::recalculate_totals(snapshot);
+            money::recalculate_totals(snapshot);
 
             // Update sequence and timestamp
             snapshot.last_sequence = event.sequence;
@@ -109,8 +123,10 @@ mod tests {
             category_id: None,
             category_name: None,
             is_comped: false,
+            course: None,
+            is_held: false,
         });
-        order_money::recalculate_totals(&mut snapshot);
+        money::recalculate_totals(&mut snapshot);
         snapshot
     }
 
@@ -139,6 +155,8 @@ mod tests {
                 tendered,
                 change,
                 note,
+                reference: None,
+                surcharge: None,
             },
         )
     }
@@ -303,4 +321,23 @@ mod tests {
         assert_eq!(snapshot.remaining_amount(), 0.0);
         assert!(snapshot.is_fully_paid());
     }
+
+    #[test]
+    fn test_payment_added_applier_surcharge_line() {
+        let mut snapshot = snapshot_with_total(1001, 100.0);
+
+        let mut event = create_payment_added_event(1001, 1, 4001, "CARD", 101.5, None, None, None);
+        if let EventPayload::PaymentAdded { surcharge, .. } = &mut event.payload {
+            *surcharge = Some(1.5);
+        }
+        PaymentAddedApplier.apply(&mut snapshot, &event);
+
+        assert_eq!(snapshot.payment_surcharges.len(), 1);
+        assert_eq!(snapshot.payment_surcharge_amount, 1.5);
+        assert_eq!(snapshot.total, 101.5);
+        assert_eq!(snapshot.paid_amount, 101.5);
+        assert!(snapshot.is_fully_paid());
+        // 附加费不计入商品/整单调整
+        assert_eq!(snapshot.total_surcharge, 0.0);
+    }
 }
@@ -6,8 +6,8 @@
 //! For split payments with `split_items`, this applier also restores items
 //! using "add items" logic - merging with existing items or creating new ones.
 
-use crate::order_money::{self, to_decimal, to_f64};
-use crate::orders::traits::EventApplier;
+use crate::EventApplier;
+use crate::money::{self, to_decimal, to_f64};
 use shared::order::{CartItemSnapshot, EventPayload, OrderEvent, OrderSnapshot};
 
 /// PaymentCancelled applier
@@ -47,6 +47,14 @@ impl EventApplier for PaymentCancelledApplier {
                 payment.cancelled = true;
                 payment.cancel_reason = reason.clone();
             }
+            // The payment-method surcharge goes with its payment
+            if let Some(line) = snapshot
+                .payment_surcharges
+                .iter_mut()
+                .find(|s| s.payment_id == *payment_id)
+            {
+                line.cancelled = true;
+            }
 
             // Subtract from paid_amount using Decimal for precision
             snapshot.paid_amount = to_f64(to_decimal(snapshot.paid_amount) - to_decimal(amount));
@@ -82,7 +90,7 @@ impl EventApplier for PaymentCancelledApplier {
             }
 
             // Recalculate totals to update unpaid_quantity and financial fields
-            order_money::recalculate_totals(snapshot);
+            money::recalculate_totals(snapshot);
 
             // Update sequence and timestamp
             snapshot.last_sequence = event.sequence;
@@ -183,6 +191,7 @@ mod tests {
             tendered: None,
             change: None,
             note: None,
+            reference: None,
             timestamp: 1234567800,
             cancelled: false,
             cancel_reason: None,
@@ -432,6 +441,8 @@ mod tests {
             category_id: None,
             category_name: None,
             is_comped: false,
+            course: None,
+            is_held: false,
         };
         snapshot.items.push(item);
         snapshot.total = 100.0;
@@ -493,6 +504,8 @@ mod tests {
             category_id: None,
             category_name: None,
             is_comped: false,
+            course: None,
+            is_held: false,
         };
         snapshot.items.push(item.clone());
 
@@ -562,6 +575,8 @@ mod tests {
             category_id: None,
             category_name: None,
             is_comped: false,
+            course: None,
+            is_held: false,
         };
         snapshot.items.push(modified_item);
 
@@ -592,6 +607,8 @@ mod tests {
             category_id: None,
             category_name: None,
             is_comped: false,
+            course: None,
+            is_held: false,
         };
 
         let mut payment = create_payment_record(4101, "CASH", 20.0);
@@ -667,6 +684,8 @@ mod tests {
             category_id: None,
             category_name: None,
             is_comped: false,
+            course: None,
+            is_held: false,
         };
         snapshot.items.push(modified_item);
 
@@ -697,6 +716,8 @@ mod tests {
             category_id: None,
             category_name: None,
             is_comped: false,
+            course: None,
+            is_held: false,
         };
 
         let mut payment = create_payment_record(4101, "CASH", 20.0);
@@ -778,6 +799,8 @@ mod tests {
             category_id: None,
             category_name: None,
             is_comped: false,
+            course: None,
+            is_held: false,
         };
         snapshot.items.push(modified_item);
 
@@ -808,6 +831,8 @@ mod tests {
             category_id: None,
             category_name: None,
             is_comped: false,
+            course: None,
+            is_held: false,
         };
         snapshot.items.push(re_added_item);
 
@@ -838,6 +863,8 @@ mod tests {
             category_id: None,
             category_name: None,
             is_comped: false,
+            course: None,
+            is_held: false,
         };
 
         let mut payment = create_payment_record(4101, "CASH", 20.0);
@@ -903,6 +930,8 @@ mod tests {
             category_id: None,
             category_name: None,
             is_comped: false,
+            course: None,
+            is_held: false,
         };
         snapshot.items.push(item);
 
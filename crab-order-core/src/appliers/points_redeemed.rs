@@ -0,0 +1,118 @@
+//! PointsRedeemed event applier
+//!
+//! 纯函数：设置/清除快照中的积分抵扣，兑换值计入订单级折扣。
+
+use crate::EventApplier;
+use crate::money::recalculate_totals;
+use shared::order::{EventPayload, OrderEvent, OrderSnapshot, PointsRedemption};
+
+/// PointsRedeemed applier
+pub struct PointsRedeemedApplier;
+
+impl EventApplier for PointsRedeemedApplier {
+    fn apply(&self, snapshot: &mut OrderSnapshot, event: &OrderEvent) {
+        if let EventPayload::PointsRedeemed { points, amount } = &event.payload {
+            // 1. Set or clear the redemption (0 points = cleared)
+            snapshot.points_redemption = (*points > 0).then_some(PointsRedemption {
+                points: *points,
+                amount: *amount,
+            });
+
+            // 2. Recalculate all totals
+            recalculate_totals(snapshot);
+
+            // 3. Update sequence and timestamp
+            snapshot.last_sequence = event.sequence;
+            snapshot.updated_at = event.timestamp;
+
+            // 4. Update checksum
+            snapshot.update_checksum();
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use shared::order::{CartItemSnapshot, OrderEventType, OrderStatus};
+
+    fn create_test_snapshot(order_id: i64, price: f64) -> OrderSnapshot {
+        let mut snapshot = OrderSnapshot::new(order_id);
+        snapshot.status = OrderStatus::Active;
+        snapshot.member_id = Some(7);
+        snapshot.items.push(CartItemSnapshot {
+            id: 1,
+            instance_id: "inst-1".to_string(),
+            name: "Test Product".to_string(),
+            price,
+            original_price: price,
+            quantity: 1,
+            unpaid_quantity: 1,
+            selected_options: None,
+            selected_specification: None,
+            manual_discount_percent: None,
+            rule_discount_amount: 0.0,
+            rule_surcharge_amount: 0.0,
+            applied_rules: vec![],
+            applied_mg_rules: vec![],
+            mg_discount_amount: 0.0,
+            unit_price: 0.0,
+            line_total: 0.0,
+            tax: 0.0,
+            tax_rate: 0,
+            note: None,
+            authorizer_id: None,
+            authorizer_name: None,
+            category_id: None,
+            category_name: None,
+            is_comped: false,
+            course: None,
+            is_held: false,
+        });
+        recalculate_totals(&mut snapshot);
+        snapshot
+    }
+
+    fn create_points_event(order_id: i64, seq: u64, points: i64, amount: f64) -> OrderEvent {
+        OrderEvent::new(
+            seq,
+            order_id,
+            1,
+            "Test User".to_string(),
+            shared::util::snowflake_id(),
+            Some(1234567890),
+            OrderEventType::PointsRedeemed,
+            EventPayload::PointsRedeemed { points, amount },
+        )
+    }
+
+    #[test]
+    fn test_redemption_counts_as_order_discount() {
+        let mut snapshot = create_test_snapshot(1001, 40.0);
+
+        PointsRedeemedApplier.apply(&mut snapshot, &create_points_event(1001, 2, 200, 10.0));
+
+        assert_eq!(
+            snapshot.points_redemption,
+            Some(PointsRedemption {
+                points: 200,
+                amount: 10.0
+            })
+        );
+        assert_eq!(snapshot.subtotal, 40.0);
+        assert_eq!(snapshot.discount, 10.0);
+        assert_eq!(snapshot.total, 30.0);
+        assert_eq!(snapshot.last_sequence, 2);
+    }
+
+    #[test]
+    fn test_zero_points_clears_redemption() {
+        let mut snapshot = create_test_snapshot(1001, 40.0);
+        PointsRedeemedApplier.apply(&mut snapshot, &create_points_event(1001, 2, 200, 10.0));
+        PointsRedeemedApplier.apply(&mut snapshot, &create_points_event(1001, 3, 0, 0.0));
+
+        assert_eq!(snapshot.points_redemption, None);
+        assert_eq!(snapshot.discount, 0.0);
+        assert_eq!(snapshot.total, 40.0);
+    }
+}
@@ -3,8 +3,8 @@
 //! Applies the RuleSkipToggled event to toggle a rule's skipped status
 //! and recalculate order totals using precise decimal arithmetic.
 
-use crate::order_money;
-use crate::orders::traits::EventApplier;
+use crate::EventApplier;
+use crate::money;
 use shared::order::{EventPayload, OrderEvent, OrderSnapshot};
 
 /// RuleSkipToggled applier
@@ -37,7 +37,7 @@ impl EventApplier for RuleSkipToggledApplier {
             snapshot.updated_at = event.timestamp;
 
             // 4. Recalculate totals using precise decimal arithmetic
-            order_money::recalculate_totals(snapshot);
+            money::recalculate_totals(snapshot);
 
             // 5. Update checksum
             snapshot.update_checksum();
@@ -107,6 +107,8 @@ mod tests {
             category_id: None,
             category_name: None,
             is_comped: false,
+            course: None,
+            is_held: false,
         }
     }
 
@@ -234,6 +236,8 @@ mod tests {
             category_id: None,
             category_name: None,
             is_comped: false,
+            course: None,
+            is_held: false,
         });
 
         // Order-level rule
@@ -582,7 +586,7 @@ mod tests {
         // Apply with discount active
         let applier = RuleSkipToggledApplier;
         // First recalculate to set baseline
-        order_money::recalculate_totals(&mut snapshot);
+        money::recalculate_totals(&mut snapshot);
         let tax_with_discount = snapshot.items[0].tax;
         // Tax on 90: 90 * 21/121 ≈ 15.62
         assert_eq!(tax_with_discount, 15.62);
@@ -633,7 +637,7 @@ mod tests {
         }];
         snapshot.order_rule_discount_amount = 9.0;
 
-        order_money::recalculate_totals(&mut snapshot);
+        money::recalculate_totals(&mut snapshot);
         // subtotal = 90, order_discount = 9, total = 81
         assert_eq!(snapshot.subtotal, 90.0);
         assert_eq!(snapshot.total, 81.0);
@@ -746,9 +750,11 @@ mod tests {
             category_id: None,
             category_name: None,
             is_comped: false,
+            course: None,
+            is_held: false,
         });
 
-        order_money::recalculate_totals(&mut snapshot);
+        money::recalculate_totals(&mut snapshot);
         // Both active: 100 - 10 - 8 = 82
         assert_eq!(snapshot.subtotal, 82.0);
 
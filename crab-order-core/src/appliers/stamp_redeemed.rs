@@ -3,8 +3,8 @@
 //! Adds the reward item as a new comped line in the order and records
 //! the redemption in snapshot.stamp_redemptions for reversal on member unlink.
 
-use crate::order_money;
-use crate::orders::traits::EventApplier;
+use crate::EventApplier;
+use crate::money;
 use shared::order::{
     CartItemSnapshot, EventPayload, OrderEvent, OrderSnapshot, StampRedemptionState,
 };
@@ -99,6 +99,8 @@ impl EventApplier for StampRedeemedApplier {
                     category_id: *category_id,
                     category_name: category_name.clone(),
                     is_comped: true,
+                    course: None,
+                    is_held: false,
                 };
                 snapshot.items.push(reward_item);
             }
@@ -116,7 +118,7 @@ impl EventApplier for StampRedeemedApplier {
             snapshot.updated_at = event.timestamp;
 
             // Recalculate totals
-            order_money::recalculate_totals(snapshot);
+            money::recalculate_totals(snapshot);
 
             // Update checksum
             snapshot.update_checksum();
@@ -223,8 +225,10 @@ mod tests {
             category_id: None,
             category_name: None,
             is_comped: false,
+            course: None,
+            is_held: false,
         });
-        order_money::recalculate_totals(&mut snapshot);
+        money::recalculate_totals(&mut snapshot);
         assert!((snapshot.total - 5.00).abs() < f64::EPSILON);
 
         // Redeem stamp — adds free Coffee
@@ -270,6 +274,8 @@ mod tests {
             category_id: Some(1),
             category_name: Some("Food".to_string()),
             is_comped: false,
+            course: None,
+            is_held: false,
         }
     }
 
@@ -314,7 +320,7 @@ mod tests {
         // Item qty=1, reward_qty=1 → full comp (no split)
         let mut snapshot = OrderSnapshot::new(1001);
         snapshot.items.push(create_test_item("item-1", 50, 4.50, 1));
-        order_money::recalculate_totals(&mut snapshot);
+        money::recalculate_totals(&mut snapshot);
 
         // Full comp: reward_instance_id == comp_existing_instance_id
         let event = create_comp_existing_event(1001, 1, "item-1", "item-1", 1);
@@ -346,7 +352,7 @@ mod tests {
         // Event quantity is already capped by action to min(3, 2) = 2
         let mut snapshot = OrderSnapshot::new(1001);
         snapshot.items.push(create_test_item("item-1", 50, 4.50, 2));
-        order_money::recalculate_totals(&mut snapshot);
+        money::recalculate_totals(&mut snapshot);
 
         // Full comp: reward_instance_id == comp_existing_instance_id
         let event = create_comp_existing_event(1001, 1, "item-1", "item-1", 2);
@@ -368,7 +374,7 @@ mod tests {
         // Item qty=7, reward_qty=1 → partial comp (split 1 off)
         let mut snapshot = OrderSnapshot::new(1001);
         snapshot.items.push(create_test_item("item-1", 50, 4.50, 7));
-        order_money::recalculate_totals(&mut snapshot);
+        money::recalculate_totals(&mut snapshot);
         let initial_total = snapshot.total;
 
         // Partial comp: reward_instance_id != comp_existing_instance_id
@@ -425,7 +431,7 @@ mod tests {
         snapshot
             .items
             .push(create_test_item("item-1", 50, 2.00, 10));
-        order_money::recalculate_totals(&mut snapshot);
+        money::recalculate_totals(&mut snapshot);
 
         let event = create_comp_existing_event(1001, 1, "stamp_reward::cmd-2", "item-1", 3);
         let applier = StampRedeemedApplier;
@@ -458,7 +464,7 @@ mod tests {
         // Item qty=5, unpaid_qty=5, reward_qty=2
         let mut snapshot = OrderSnapshot::new(1001);
         snapshot.items.push(create_test_item("item-1", 50, 3.00, 5));
-        order_money::recalculate_totals(&mut snapshot);
+        money::recalculate_totals(&mut snapshot);
 
         let event = create_comp_existing_event(1001, 1, "stamp_reward::cmd-2", "item-1", 2);
         let applier = StampRedeemedApplier;
@@ -492,7 +498,7 @@ mod tests {
             .items
             .push(create_test_item("potato-1", 50, 4.50, 7)); // 7 potatoes (target)
         snapshot.items.push(create_test_item("cake-1", 20, 5.00, 1)); // 1 cake
-        order_money::recalculate_totals(&mut snapshot);
+        money::recalculate_totals(&mut snapshot);
 
         let event = create_comp_existing_event(1001, 1, "stamp_reward::cmd-2", "potato-1", 1);
         let applier = StampRedeemedApplier;
@@ -542,7 +548,7 @@ mod tests {
     fn test_comp_existing_checksum_updates() {
         let mut snapshot = OrderSnapshot::new(1001);
         snapshot.items.push(create_test_item("item-1", 50, 4.50, 3));
-        order_money::recalculate_totals(&mut snapshot);
+        money::recalculate_totals(&mut snapshot);
         snapshot.update_checksum();
         let initial_checksum = snapshot.state_checksum.clone();
 
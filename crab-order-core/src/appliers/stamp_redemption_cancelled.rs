@@ -3,8 +3,8 @@
 //! Removes the reward item and the stamp_redemption record from the snapshot.
 //! Recalculates totals since a comped item is being removed.
 
-use crate::order_money;
-use crate::orders::traits::EventApplier;
+use crate::EventApplier;
+use crate::money;
 use shared::order::{EventPayload, OrderEvent, OrderSnapshot};
 
 /// StampRedemptionCancelled applier
@@ -69,7 +69,7 @@ impl EventApplier for StampRedemptionCancelledApplier {
             snapshot.updated_at = event.timestamp;
 
             // 4. Recalculate totals
-            order_money::recalculate_totals(snapshot);
+            money::recalculate_totals(snapshot);
 
             // 5. Update checksum
             snapshot.update_checksum();
@@ -128,6 +128,8 @@ mod tests {
             category_id: None,
             category_name: Some("Drinks".to_string()),
             is_comped: true,
+            course: None,
+            is_held: false,
         }
     }
 
@@ -158,6 +160,8 @@ mod tests {
             category_id: None,
             category_name: None,
             is_comped: false,
+            course: None,
+            is_held: false,
         }
     }
 
@@ -199,7 +203,7 @@ mod tests {
             is_comp_existing: false,
             comp_source_instance_id: None,
         });
-        order_money::recalculate_totals(&mut snapshot);
+        money::recalculate_totals(&mut snapshot);
 
         let event = create_cancel_event(1001, 2);
         let applier = StampRedemptionCancelledApplier;
@@ -269,6 +273,8 @@ mod tests {
             category_id: Some(1),
             category_name: Some("Food".to_string()),
             is_comped: false,
+            course: None,
+            is_held: false,
         }
     }
 
@@ -304,6 +310,8 @@ mod tests {
             category_id: Some(1),
             category_name: Some("Food".to_string()),
             is_comped: true,
+            course: None,
+            is_held: false,
         }
     }
 
@@ -344,7 +352,7 @@ mod tests {
             is_comp_existing: true,
             comp_source_instance_id: None,
         });
-        order_money::recalculate_totals(&mut snapshot);
+        money::recalculate_totals(&mut snapshot);
         assert!((snapshot.total - 0.0).abs() < f64::EPSILON); // comped = free
 
         let event = create_cancel_comp_existing_event(1001, 2, "item-1", None);
@@ -378,7 +386,7 @@ mod tests {
             is_comp_existing: true,
             comp_source_instance_id: Some("item-1".to_string()),
         });
-        order_money::recalculate_totals(&mut snapshot);
+        money::recalculate_totals(&mut snapshot);
 
         let event =
             create_cancel_comp_existing_event(1001, 2, "stamp_reward::cmd-2", Some("item-1"));
@@ -418,7 +426,7 @@ mod tests {
             is_comp_existing: true,
             comp_source_instance_id: Some("potato-1".to_string()),
         });
-        order_money::recalculate_totals(&mut snapshot);
+        money::recalculate_totals(&mut snapshot);
 
         let event =
             create_cancel_comp_existing_event(1001, 2, "stamp_reward::cmd-2", Some("potato-1"));
@@ -458,7 +466,7 @@ mod tests {
             is_comp_existing: true,
             comp_source_instance_id: Some("item-1".to_string()),
         });
-        order_money::recalculate_totals(&mut snapshot);
+        money::recalculate_totals(&mut snapshot);
 
         let event =
             create_cancel_comp_existing_event(1001, 2, "stamp_reward::cmd-2", Some("item-1"));
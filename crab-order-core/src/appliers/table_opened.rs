@@ -2,7 +2,7 @@
 //!
 //! Applies the TableOpened event to create initial snapshot state.
 
-use crate::orders::traits::EventApplier;
+use crate::EventApplier;
 use shared::order::{EventPayload, OrderEvent, OrderSnapshot, OrderStatus};
 
 /// TableOpened applier
@@ -19,6 +19,7 @@ impl EventApplier for TableOpenedApplier {
             is_retail,
             queue_number,
             receipt_number,
+            tax_mode,
         } = &event.payload
         {
             // Set order_id from event (important for replay scenarios)
@@ -31,6 +32,7 @@ impl EventApplier for TableOpenedApplier {
             snapshot.is_retail = *is_retail;
             snapshot.queue_number = *queue_number;
             snapshot.receipt_number = receipt_number.clone();
+            snapshot.tax_mode = *tax_mode;
             snapshot.status = OrderStatus::Active;
             snapshot.start_time = event.timestamp;
             snapshot.created_at = event.timestamp;
@@ -68,6 +70,7 @@ mod tests {
                 is_retail: false,
                 queue_number: None,
                 receipt_number: "RCP-TEST-001".to_string(),
+                tax_mode: shared::models::TaxMode::Inclusive,
             },
         );
 
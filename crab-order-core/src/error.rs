@@ -0,0 +1,41 @@
+//! Order operation errors
+
+use shared::order::types::CommandErrorCode;
+use thiserror::Error;
+
+/// Errors that can occur during order operations
+#[derive(Debug, Error)]
+pub enum OrderError {
+    #[error("Order not found: {0}")]
+    OrderNotFound(i64),
+
+    #[error("Order already completed: {0}")]
+    OrderAlreadyCompleted(i64),
+
+    #[error("Order already voided: {0}")]
+    OrderAlreadyVoided(i64),
+
+    #[error("Item not found: {0}")]
+    ItemNotFound(String),
+
+    #[error("Payment not found: {0}")]
+    PaymentNotFound(i64),
+
+    #[error("Insufficient quantity")]
+    InsufficientQuantity,
+
+    #[error("Invalid amount")]
+    InvalidAmount,
+
+    #[error("Invalid operation [{0:?}]: {1}")]
+    InvalidOperation(CommandErrorCode, String),
+
+    #[error("Table is already occupied: {0}")]
+    TableOccupied(String),
+
+    #[error("Insufficient stamps: {current}/{required}")]
+    InsufficientStamps { current: i32, required: i32 },
+
+    #[error("Storage error: {0}")]
+    Storage(String),
+}
@@ -0,0 +1,76 @@
+//! # crab-order-core
+//!
+//! 订单快照计算核心：事件 applier + 金额计算，与存储/运行时无关。
+//!
+//! ## Scope
+//!
+//! - **appliers**: `EventApplier` 实现，每种事件一个纯函数
+//! - **money**: rust_decimal 精确金额计算 (`recalculate_totals` 等) 与命令输入校验
+//! - **validation**: 订单文本字段长度校验
+//! - **wasm** (`wasm` feature): wasm-bindgen 导出，供 Web 前端调用同一份计算
+//!
+//! edge-server 用它执行命令和重放事件；crab-client / red_coral 用它在本地
+//! 重建快照 (离线、乐观显示)。各端共用同一份代码，计算结果与服务端逐位一致。
+//!
+//! 本 crate 不依赖 tokio / sqlx / redb，新增依赖前请确认仍满足这一约束。
+
+pub mod appliers;
+mod error;
+pub mod money;
+mod traits;
+pub mod validation;
+#[cfg(feature = "wasm")]
+pub mod wasm;
+
+pub use appliers::EventAction;
+pub use error::OrderError;
+pub use traits::EventApplier;
+
+use shared::order::{OrderEvent, OrderSnapshot};
+
+/// 从空快照按顺序应用事件，重建订单快照
+///
+/// 调用方负责传入同一订单、按 sequence 排好序的事件。
+pub fn rebuild_snapshot(order_id: i64, events: &[OrderEvent]) -> OrderSnapshot {
+    let mut snapshot = OrderSnapshot::new(order_id);
+    for event in events {
+        EventAction::from(event).apply(&mut snapshot, event);
+    }
+    snapshot
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use shared::order::{EventPayload, OrderEventType};
+
+    fn note_event(order_id: i64, seq: u64, note: &str) -> OrderEvent {
+        OrderEvent::new(
+            seq,
+            order_id,
+            1,
+            "Test User".to_string(),
+            shared::util::snowflake_id(),
+            Some(1234567890),
+            OrderEventType::OrderNoteAdded,
+            EventPayload::OrderNoteAdded {
+                note: note.to_string(),
+                previous_note: None,
+            },
+        )
+    }
+
+    #[test]
+    fn test_rebuild_snapshot_applies_events_in_order() {
+        let events = vec![
+            note_event(1001, 3, "sin gluten"),
+            note_event(1001, 4, "terraza"),
+        ];
+
+        let snapshot = rebuild_snapshot(1001, &events);
+
+        assert_eq!(snapshot.order_id, 1001);
+        assert_eq!(snapshot.note.as_deref(), Some("terraza"));
+        assert_eq!(snapshot.last_sequence, 4);
+    }
+}
@@ -4,14 +4,17 @@
 //! All calculations are done using `Decimal` internally, then converted to `f64`
 //! for storage/serialization.
 
-use crate::orders::traits::OrderError;
-use crate::utils::validation::{MAX_NAME_LEN, MAX_NOTE_LEN, validate_order_optional_text};
+use crate::OrderError;
+use crate::validation::{MAX_NAME_LEN, MAX_NOTE_LEN, validate_order_optional_text};
 use rust_decimal::prelude::*;
-use shared::models::price_rule::{AdjustmentType, RuleType};
+use shared::models::TaxMode;
+use shared::models::price_rule::{AdjustmentType, ProductScope, RuleType};
 use shared::order::types::CommandErrorCode;
 use shared::order::{
-    CartItemInput, CartItemSnapshot, ItemChanges, MAX_OPTION_QUANTITY, OrderSnapshot, PaymentInput,
+    AppliedCoupon, CartItemInput, CartItemSnapshot, ItemChanges, MAX_OPTION_QUANTITY,
+    OrderSnapshot, PaymentInput, TaxBreakdownLine,
 };
+use std::collections::BTreeMap;
 
 /// Rounding strategy for monetary values (2 decimal places, half-up)
 const DECIMAL_PLACES: u32 = 2;
@@ -25,6 +28,12 @@ const MAX_PRICE: f64 = 1_000_000.0;
 const MAX_QUANTITY: i32 = 9999;
 /// Maximum allowed payment amount (€1,000,000)
 const MAX_PAYMENT_AMOUNT: f64 = 1_000_000.0;
+/// The only payment method that may return change from the drawer
+pub const CASH_METHOD: &str = "CASH";
+/// Member stored-credit tender (debited from the linked member's wallet)
+pub const MEMBER_CREDIT_METHOD: &str = "MEMBER_CREDIT";
+/// Hotel room charge (posted to the PMS; `reference` carries the room number)
+pub const ROOM_CHARGE_METHOD: &str = "ROOM_CHARGE";
 
 /// Validate that a f64 value is finite (not NaN, not Infinity)
 #[inline]
@@ -182,6 +191,78 @@ pub fn validate_payment(payment: &PaymentInput) -> Result<(), OrderError> {
     Ok(())
 }
 
+/// Tendered/change pair recorded on a payment event
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct TenderOutcome {
+    pub tendered: Option<f64>,
+    pub change: Option<f64>,
+}
+
+/// Compute change due for a payment (over-tender handling)
+///
+/// - Cash without tendered: exact tender, recorded as `tendered = amount, change = 0`
+/// - Cash over-tender: change = tendered - amount, capped by `max_change`
+///   (store drawer policy, see `StoreInfo::max_cash_change`)
+/// - Non-cash: tendered may not exceed amount (no change for card)
+/// - Any tendered below amount: `InsufficientTender`
+pub fn calculate_change(
+    method: &str,
+    amount: f64,
+    tendered: Option<f64>,
+    max_change: f64,
+) -> Result<TenderOutcome, OrderError> {
+    let amount_dec = to_decimal(amount);
+    let is_cash = method == CASH_METHOD;
+
+    let Some(t) = tendered else {
+        return Ok(if is_cash {
+            TenderOutcome {
+                tendered: Some(to_f64(amount_dec)),
+                change: Some(0.0),
+            }
+        } else {
+            TenderOutcome {
+                tendered: None,
+                change: None,
+            }
+        });
+    };
+
+    let tendered_dec = to_decimal(t);
+    if tendered_dec < amount_dec - MONEY_TOLERANCE {
+        return Err(OrderError::InvalidOperation(
+            CommandErrorCode::InsufficientTender,
+            format!("Tendered {:.2} is less than required {:.2}", t, amount),
+        ));
+    }
+
+    let change = (tendered_dec - amount_dec).max(Decimal::ZERO);
+    if !is_cash && change >= MONEY_TOLERANCE {
+        return Err(OrderError::InvalidOperation(
+            CommandErrorCode::ChangeNotAllowed,
+            format!(
+                "{} payment cannot return change (tendered {:.2}, amount {:.2})",
+                method, t, amount
+            ),
+        ));
+    }
+    if change > to_decimal(max_change) {
+        return Err(OrderError::InvalidOperation(
+            CommandErrorCode::ChangeExceedsDrawerLimit,
+            format!(
+                "Change {:.2} exceeds drawer limit {:.2}",
+                to_f64(change),
+                max_change
+            ),
+        ));
+    }
+
+    Ok(TenderOutcome {
+        tendered: Some(t),
+        change: Some(to_f64(change)),
+    })
+}
+
 /// Validate item changes (from ModifyItem command)
 pub fn validate_item_changes(changes: &ItemChanges) -> Result<(), OrderError> {
     if let Some(p) = changes.price {
@@ -337,6 +418,36 @@ fn effective_order_rule_discount(snapshot: &OrderSnapshot, subtotal: Decimal) ->
     }
 }
 
+/// Sum of `line_total` over non-comped items within the coupon's product scope.
+pub fn coupon_eligible_base(snapshot: &OrderSnapshot, coupon: &AppliedCoupon) -> Decimal {
+    snapshot
+        .items
+        .iter()
+        .filter(|item| !item.is_comped)
+        .filter(|item| match coupon.product_scope {
+            ProductScope::Global => true,
+            ProductScope::Category => {
+                coupon.target_id.is_some() && item.category_id == coupon.target_id
+            }
+            ProductScope::Product => coupon.target_id == Some(item.id),
+            // Snapshot items carry no tags; coupons reject this scope on create
+            ProductScope::Tag => false,
+        })
+        .map(|item| to_decimal(item.line_total))
+        .sum()
+}
+
+/// Coupon discount: percentage of the eligible base, or a fixed amount capped at it.
+fn coupon_discount(snapshot: &OrderSnapshot, coupon: &AppliedCoupon) -> Decimal {
+    let base = coupon_eligible_base(snapshot, coupon);
+    match coupon.adjustment_type {
+        AdjustmentType::Percentage => {
+            base * to_decimal(coupon.adjustment_value) / Decimal::ONE_HUNDRED
+        }
+        AdjustmentType::FixedAmount => to_decimal(coupon.adjustment_value).min(base),
+    }
+}
+
 /// Compute effective order-level rule surcharge, dynamically recalculating from `adjustment_value`.
 /// `subtotal` is the order subtotal (basis for percentage order-level surcharges).
 /// Falls back to pre-computed `order_rule_surcharge_amount` when `order_applied_rules` is absent.
@@ -445,6 +556,34 @@ pub fn calculate_unit_price(item: &CartItemSnapshot) -> Decimal {
         .round_dp_with_strategy(DECIMAL_PLACES, RoundingStrategy::MidpointAwayFromZero)
 }
 
+/// Calculate line tax for a given line amount
+///
+/// - `Inclusive`: `amount` already contains tax → `tax = amount * rate / (100 + rate)`
+/// - `Exclusive`: `amount` is net → `tax = amount * rate / 100`, rounded per line (2dp)
+pub fn calculate_line_tax(amount: Decimal, tax_rate: i32, mode: TaxMode) -> Decimal {
+    let rate = Decimal::from(tax_rate);
+    if rate <= Decimal::ZERO {
+        return Decimal::ZERO;
+    }
+    match mode {
+        TaxMode::Inclusive => amount * rate / (Decimal::ONE_HUNDRED + rate),
+        TaxMode::Exclusive => (amount * rate / Decimal::ONE_HUNDRED)
+            .round_dp_with_strategy(DECIMAL_PLACES, RoundingStrategy::MidpointAwayFromZero),
+    }
+}
+
+/// Calculate the amount the customer pays for `quantity` units of an item
+///
+/// Inclusive: unit_price * quantity
+/// Exclusive: unit_price * quantity + line tax
+pub fn calculate_line_gross(item: &CartItemSnapshot, quantity: i32, mode: TaxMode) -> Decimal {
+    let net = calculate_unit_price(item) * Decimal::from(quantity);
+    match mode {
+        TaxMode::Inclusive => net,
+        TaxMode::Exclusive => net + calculate_line_tax(net, item.tax_rate, mode),
+    }
+}
+
 /// Calculate item line total with precise decimal arithmetic
 ///
 /// Formula: unit_price * quantity
@@ -478,6 +617,8 @@ pub fn recalculate_totals(snapshot: &mut OrderSnapshot) {
     let mut item_mg_discount_total = Decimal::ZERO;
     let mut comp_total = Decimal::ZERO;
     let mut total_tax = Decimal::ZERO;
+    let mut breakdown: BTreeMap<i32, (Decimal, Decimal)> = BTreeMap::new();
+    let tax_mode = snapshot.tax_mode;
 
     for item in &mut snapshot.items {
         let quantity = Decimal::from(item.quantity);
@@ -591,20 +732,25 @@ pub fn recalculate_totals(snapshot: &mut OrderSnapshot) {
         // Sync item.price to match computed unit_price (keeps price = "final price after rules")
         item.price = to_f64(unit_price);
 
-        // Calculate and set line_total (unit_price * quantity)
-        let item_total = unit_price * quantity;
-        item.line_total = to_f64(item_total);
-
-        // Calculate item tax (Spain IVA: prices are tax-inclusive)
-        // Formula: tax = gross_amount * tax_rate / (100 + tax_rate)
-        let tax_rate = Decimal::from(item.tax_rate);
-        let item_tax = if tax_rate > Decimal::ZERO {
-            item_total * tax_rate / (Decimal::ONE_HUNDRED + tax_rate)
-        } else {
-            Decimal::ZERO
+        // Calculate item tax and line_total (always tax-inclusive, so reports can use line_total - tax)
+        // Inclusive (Spain IVA): line_total = unit_price * quantity, tax extracted from it
+        // Exclusive: line_total = unit_price * quantity + tax
+        let line_amount = unit_price * quantity;
+        let item_tax = calculate_line_tax(line_amount, item.tax_rate, tax_mode);
+        let item_total = match tax_mode {
+            TaxMode::Inclusive => line_amount,
+            TaxMode::Exclusive => line_amount + item_tax,
         };
+        item.line_total = to_f64(item_total);
         item.tax = to_f64(item_tax);
         total_tax += item_tax;
+        if !item.is_comped {
+            let entry = breakdown
+                .entry(item.tax_rate)
+                .or_insert((Decimal::ZERO, Decimal::ZERO));
+            entry.0 += item_total - item_tax;
+            entry.1 += item_tax;
+        }
 
         // Accumulate comp total (original value of comped items)
         // Use original_price for comp value since item.price is zeroed on comp
@@ -655,7 +801,25 @@ pub fn recalculate_totals(snapshot: &mut OrderSnapshot) {
     let order_manual_surcharge_r = round(order_manual_surcharge);
     let eff_order_rule_discount_r = round(eff_order_rule_discount);
     let eff_order_rule_surcharge_r = round(eff_order_rule_surcharge);
-    let order_discount = order_manual_discount_r + eff_order_rule_discount_r;
+    // Loyalty points redemption (fixed value, settled on completion)
+    let points_redemption_r = round(
+        snapshot
+            .points_redemption
+            .as_ref()
+            .map_or(Decimal::ZERO, |r| to_decimal(r.amount)),
+    );
+    // Coupon (recomputed from the current items, amount written back to the snapshot)
+    let coupon_r = round(
+        snapshot
+            .coupon
+            .as_ref()
+            .map_or(Decimal::ZERO, |c| coupon_discount(snapshot, c)),
+    );
+    if let Some(coupon) = snapshot.coupon.as_mut() {
+        coupon.amount = to_f64(coupon_r);
+    }
+    let order_discount =
+        order_manual_discount_r + eff_order_rule_discount_r + points_redemption_r + coupon_r;
     let order_surcharge = order_manual_surcharge_r + eff_order_rule_surcharge_r;
 
     // Sync calculated_amount in order_applied_rules so snapshot stays consistent
@@ -671,14 +835,23 @@ pub fn recalculate_totals(snapshot: &mut OrderSnapshot) {
         });
     }
 
+    // Payment-method surcharges (already rounded when added at AddPayment)
+    let payment_surcharge: Decimal = snapshot
+        .payment_surcharges
+        .iter()
+        .filter(|s| !s.cancelled)
+        .map(|s| to_decimal(s.amount))
+        .sum();
+
     // Total discount and surcharge (item-level + order-level, MG tracked separately in mg_discount_amount)
     let total_discount = item_discount_total + order_discount;
     let total_surcharge = item_surcharge_total + order_surcharge;
 
-    // Final total (Spanish IVA: tax is already included in subtotal)
+    // Final total (subtotal is tax-inclusive in both modes, see line_total above)
     // Clamp to zero — extreme discounts must not produce negative totals
     // Uses rounded order components so total = subtotal - displayed_discount + displayed_surcharge
-    let total = (subtotal - order_discount + order_surcharge).max(Decimal::ZERO);
+    let total =
+        (subtotal - order_discount + order_surcharge).max(Decimal::ZERO) + payment_surcharge;
     let paid = to_decimal(snapshot.paid_amount);
     let remaining = (total - paid).max(Decimal::ZERO);
 
@@ -688,6 +861,14 @@ pub fn recalculate_totals(snapshot: &mut OrderSnapshot) {
     snapshot.total_discount = to_f64(total_discount);
     snapshot.total_surcharge = to_f64(total_surcharge);
     snapshot.tax = to_f64(total_tax);
+    snapshot.tax_breakdown = breakdown
+        .into_iter()
+        .map(|(tax_rate, (base, tax))| TaxBreakdownLine {
+            tax_rate,
+            base_amount: to_f64(base),
+            tax_amount: to_f64(tax),
+        })
+        .collect();
     snapshot.discount = to_f64(order_discount);
     snapshot.comp_total_amount = to_f64(comp_total);
     snapshot.order_manual_discount_amount = to_f64(order_manual_discount_r);
@@ -695,6 +876,7 @@ pub fn recalculate_totals(snapshot: &mut OrderSnapshot) {
     snapshot.order_rule_discount_amount = to_f64(eff_order_rule_discount_r);
     snapshot.order_rule_surcharge_amount = to_f64(eff_order_rule_surcharge_r);
     snapshot.mg_discount_amount = to_f64(item_mg_discount_total);
+    snapshot.payment_surcharge_amount = to_f64(payment_surcharge);
     snapshot.total = to_f64(total);
     snapshot.remaining_amount = to_f64(remaining);
 
@@ -49,6 +49,8 @@ fn test_calculate_item_total_no_discount() {
         category_id: None,
         category_name: None,
         is_comped: false,
+        course: None,
+        is_held: false,
         unit_price: 0.0,
         line_total: 0.0,
         tax: 0.0,
@@ -83,6 +85,8 @@ fn test_calculate_item_total_with_discount() {
         category_id: None,
         category_name: None,
         is_comped: false,
+        course: None,
+        is_held: false,
         unit_price: 0.0,
         line_total: 0.0,
         tax: 0.0,
@@ -118,6 +122,8 @@ fn test_calculate_item_total_33_percent_discount() {
         category_id: None,
         category_name: None,
         is_comped: false,
+        course: None,
+        is_held: false,
         unit_price: 0.0,
         line_total: 0.0,
         tax: 0.0,
@@ -182,6 +188,8 @@ fn test_many_small_items() {
             category_id: None,
             category_name: None,
             is_comped: false,
+            course: None,
+            is_held: false,
             unit_price: 0.0,
             line_total: 0.0,
             tax: 0.0,
@@ -220,6 +228,8 @@ fn test_is_pre_payment_reset_when_total_changes() {
         category_id: None,
         category_name: None,
         is_comped: false,
+        course: None,
+        is_held: false,
         unit_price: 0.0,
         line_total: 0.0,
         tax: 0.0,
@@ -263,6 +273,8 @@ fn test_is_pre_payment_reset_when_total_changes() {
         category_id: None,
         category_name: None,
         is_comped: false,
+        course: None,
+        is_held: false,
         unit_price: 0.0,
         line_total: 0.0,
         tax: 0.0,
@@ -304,6 +316,8 @@ fn test_is_pre_payment_not_affected_when_false() {
         category_id: None,
         category_name: None,
         is_comped: false,
+        course: None,
+        is_held: false,
         unit_price: 0.0,
         line_total: 0.0,
         tax: 0.0,
@@ -416,6 +430,8 @@ fn test_unit_price_negative_base_clamped_to_zero() {
         category_id: None,
         category_name: None,
         is_comped: false,
+        course: None,
+        is_held: false,
         unit_price: 0.0,
         line_total: 0.0,
         tax: 0.0,
@@ -455,6 +471,8 @@ fn test_unit_price_discount_exceeding_100_percent() {
         category_id: None,
         category_name: None,
         is_comped: false,
+        course: None,
+        is_held: false,
         unit_price: 0.0,
         line_total: 0.0,
         tax: 0.0,
@@ -490,6 +508,8 @@ fn test_unit_price_nan_price_becomes_zero() {
         category_id: None,
         category_name: None,
         is_comped: false,
+        course: None,
+        is_held: false,
         unit_price: 0.0,
         line_total: 0.0,
         tax: 0.0,
@@ -528,6 +548,8 @@ fn test_unit_price_infinity_price_becomes_zero() {
         category_id: None,
         category_name: None,
         is_comped: false,
+        course: None,
+        is_held: false,
         unit_price: 0.0,
         line_total: 0.0,
         tax: 0.0,
@@ -566,6 +588,8 @@ fn test_unit_price_negative_discount_increases_price() {
         category_id: None,
         category_name: None,
         is_comped: false,
+        course: None,
+        is_held: false,
         unit_price: 0.0,
         line_total: 0.0,
         tax: 0.0,
@@ -609,6 +633,8 @@ fn test_calculate_item_total_negative_quantity() {
         category_id: None,
         category_name: None,
         is_comped: false,
+        course: None,
+        is_held: false,
         unit_price: 0.0,
         line_total: 0.0,
         tax: 0.0,
@@ -648,6 +674,8 @@ fn test_calculate_item_total_zero_quantity() {
         category_id: None,
         category_name: None,
         is_comped: false,
+        course: None,
+        is_held: false,
         unit_price: 0.0,
         line_total: 0.0,
         tax: 0.0,
@@ -687,6 +715,8 @@ fn test_calculate_item_total_large_quantity_times_price() {
         category_id: None,
         category_name: None,
         is_comped: false,
+        course: None,
+        is_held: false,
         unit_price: 0.0,
         line_total: 0.0,
         tax: 0.0,
@@ -731,6 +761,8 @@ fn test_recalculate_totals_with_mixed_edge_items() {
         category_id: None,
         category_name: None,
         is_comped: false,
+        course: None,
+        is_held: false,
         unit_price: 0.0,
         line_total: 0.0,
         tax: 0.0,
@@ -760,6 +792,8 @@ fn test_recalculate_totals_with_mixed_edge_items() {
         category_id: None,
         category_name: None,
         is_comped: false,
+        course: None,
+        is_held: false,
         unit_price: 0.0,
         line_total: 0.0,
         tax: 0.0,
@@ -800,6 +834,8 @@ fn test_recalculate_totals_order_discount_exceeds_subtotal() {
         category_id: None,
         category_name: None,
         is_comped: false,
+        course: None,
+        is_held: false,
         unit_price: 0.0,
         line_total: 0.0,
         tax: 0.0,
@@ -899,6 +935,8 @@ fn test_unit_price_with_original_price_and_options_no_double_counting() {
         category_id: None,
         category_name: None,
         is_comped: false,
+        course: None,
+        is_held: false,
     };
 
     let unit_price = calculate_unit_price(&item);
@@ -950,6 +988,8 @@ fn test_rule_discount_plus_options_plus_manual_discount() {
         category_id: None,
         category_name: None,
         is_comped: false,
+        course: None,
+        is_held: false,
     };
 
     let unit_price = calculate_unit_price(&item);
@@ -1007,6 +1047,8 @@ fn test_option_quantity_multiplies_price_modifier() {
         category_id: None,
         category_name: None,
         is_comped: false,
+        course: None,
+        is_held: false,
     };
 
     let unit_price = calculate_unit_price(&item);
@@ -1075,6 +1117,8 @@ fn test_multiple_options_with_different_quantities() {
         category_id: None,
         category_name: None,
         is_comped: false,
+        course: None,
+        is_held: false,
     };
 
     let unit_price = calculate_unit_price(&item);
@@ -1119,6 +1163,8 @@ fn test_rule_discount_exceeding_price_clamps_to_zero() {
         category_id: None,
         category_name: None,
         is_comped: false,
+        course: None,
+        is_held: false,
     };
 
     let unit_price = calculate_unit_price(&item);
@@ -1391,6 +1437,7 @@ fn test_sum_payments_single() {
         tendered: None,
         change: None,
         note: None,
+        reference: None,
         cancelled: false,
         cancel_reason: None,
         split_items: None,
@@ -1411,6 +1458,7 @@ fn test_sum_payments_with_cancelled() {
             tendered: None,
             change: None,
             note: None,
+            reference: None,
             cancelled: true, // cancelled — should be excluded
             cancel_reason: Some("wrong".to_string()),
             split_items: None,
@@ -1425,6 +1473,7 @@ fn test_sum_payments_with_cancelled() {
             tendered: None,
             change: None,
             note: None,
+            reference: None,
             cancelled: false,
             cancel_reason: None,
             split_items: None,
@@ -1449,6 +1498,7 @@ fn test_sum_payments_all_cancelled() {
         tendered: None,
         change: None,
         note: None,
+        reference: None,
         cancelled: true,
         cancel_reason: None,
         split_items: None,
@@ -1470,6 +1520,7 @@ fn test_sum_payments_precision() {
             tendered: None,
             change: None,
             note: None,
+            reference: None,
             cancelled: false,
             cancel_reason: None,
             split_items: None,
@@ -1546,6 +1597,8 @@ fn make_item_with_rules(
         category_id: None,
         category_name: None,
         is_comped: false,
+        course: None,
+        is_held: false,
     }
 }
 
@@ -2301,6 +2354,8 @@ fn test_recalculate_totals_with_option_quantity() {
         category_id: None,
         category_name: None,
         is_comped: false,
+        course: None,
+        is_held: false,
     };
     snapshot.items.push(item);
 
@@ -2350,6 +2405,8 @@ fn make_item_with_options(
         category_id: None,
         category_name: None,
         is_comped: false,
+        course: None,
+        is_held: false,
     }
 }
 
@@ -2563,6 +2620,7 @@ fn test_validate_cart_item_option_quantity_must_be_positive() {
         note: None,
         authorizer_id: None,
         authorizer_name: None,
+        course: None,
     };
 
     let result = validate_cart_item(&input);
@@ -2596,6 +2654,7 @@ fn test_validate_cart_item_option_quantity_negative() {
         note: None,
         authorizer_id: None,
         authorizer_name: None,
+        course: None,
     };
 
     let result = validate_cart_item(&input);
@@ -2629,6 +2688,7 @@ fn test_validate_cart_item_option_exceeds_max_quantity() {
         note: None,
         authorizer_id: None,
         authorizer_name: None,
+        course: None,
     };
 
     let result = validate_cart_item(&input);
@@ -2637,3 +2697,265 @@ fn test_validate_cart_item_option_exceeds_max_quantity() {
         "Option quantity exceeding MAX must be rejected"
     );
 }
+
+// ========================================================================
+// Change due (over-tender handling)
+// ========================================================================
+
+use shared::models::DEFAULT_MAX_CASH_CHANGE;
+
+#[test]
+fn test_calculate_change_cash_over_tender() {
+    let tender = calculate_change("CASH", 85.0, Some(100.0), DEFAULT_MAX_CASH_CHANGE).unwrap();
+    assert_eq!(tender.tendered, Some(100.0));
+    assert_eq!(tender.change, Some(15.0));
+}
+
+#[test]
+fn test_calculate_change_cash_exact_records_zero_change() {
+    let tender = calculate_change("CASH", 42.5, None, DEFAULT_MAX_CASH_CHANGE).unwrap();
+    assert_eq!(tender.tendered, Some(42.5));
+    assert_eq!(tender.change, Some(0.0));
+}
+
+#[test]
+fn test_calculate_change_card_without_tender() {
+    let tender = calculate_change("CARD", 42.5, None, DEFAULT_MAX_CASH_CHANGE).unwrap();
+    assert_eq!(tender.tendered, None);
+    assert_eq!(tender.change, None);
+}
+
+#[test]
+fn test_calculate_change_card_over_tender_rejected() {
+    let result = calculate_change("CARD", 42.5, Some(50.0), DEFAULT_MAX_CASH_CHANGE);
+    assert!(matches!(
+        result,
+        Err(OrderError::InvalidOperation(
+            CommandErrorCode::ChangeNotAllowed,
+            _
+        ))
+    ));
+}
+
+#[test]
+fn test_calculate_change_insufficient_tender() {
+    let result = calculate_change("CASH", 10.0, Some(5.0), DEFAULT_MAX_CASH_CHANGE);
+    assert!(matches!(
+        result,
+        Err(OrderError::InvalidOperation(
+            CommandErrorCode::InsufficientTender,
+            _
+        ))
+    ));
+}
+
+#[test]
+fn test_calculate_change_exceeds_drawer_limit() {
+    // 200.00 change is allowed, 200.01 is not
+    assert!(calculate_change("CASH", 50.0, Some(250.0), DEFAULT_MAX_CASH_CHANGE).is_ok());
+    let result = calculate_change("CASH", 49.99, Some(250.0), DEFAULT_MAX_CASH_CHANGE);
+    assert!(matches!(
+        result,
+        Err(OrderError::InvalidOperation(
+            CommandErrorCode::ChangeExceedsDrawerLimit,
+            _
+        ))
+    ));
+}
+
+#[test]
+fn test_calculate_change_store_drawer_limit() {
+    // Store lowered the limit to 50.00
+    assert!(calculate_change("CASH", 50.0, Some(100.0), 50.0).is_ok());
+    let result = calculate_change("CASH", 49.99, Some(100.0), 50.0);
+    assert!(matches!(
+        result,
+        Err(OrderError::InvalidOperation(
+            CommandErrorCode::ChangeExceedsDrawerLimit,
+            _
+        ))
+    ));
+    // Zero limit: exact cash only
+    assert!(calculate_change("CASH", 20.0, Some(20.0), 0.0).is_ok());
+    assert!(calculate_change("CASH", 20.0, Some(20.5), 0.0).is_err());
+}
+
+// ========================================================================
+// TaxMode: 含税 / 不含税定价
+// ========================================================================
+
+fn make_taxed_item(
+    instance_id: &str,
+    price: f64,
+    quantity: i32,
+    tax_rate: i32,
+) -> CartItemSnapshot {
+    CartItemSnapshot {
+        id: 1,
+        instance_id: instance_id.to_string(),
+        name: "Item".to_string(),
+        price,
+        original_price: 0.0,
+        quantity,
+        unpaid_quantity: quantity,
+        selected_options: None,
+        selected_specification: None,
+        manual_discount_percent: None,
+        rule_discount_amount: 0.0,
+        rule_surcharge_amount: 0.0,
+        applied_rules: vec![],
+        applied_mg_rules: vec![],
+        mg_discount_amount: 0.0,
+        note: None,
+        authorizer_id: None,
+        authorizer_name: None,
+        category_id: None,
+        category_name: None,
+        is_comped: false,
+        course: None,
+        is_held: false,
+        unit_price: 0.0,
+        line_total: 0.0,
+        tax: 0.0,
+        tax_rate,
+    }
+}
+
+#[test]
+fn test_recalculate_totals_tax_inclusive() {
+    let mut snapshot = OrderSnapshot::new(1001);
+    snapshot.items.push(make_taxed_item("i1", 12.10, 1, 21));
+
+    recalculate_totals(&mut snapshot);
+
+    // 12.10 含 21% IVA → 税额 2.10
+    assert_eq!(snapshot.items[0].line_total, 12.10);
+    assert_eq!(snapshot.items[0].tax, 2.10);
+    assert_eq!(snapshot.tax, 2.10);
+    assert_eq!(snapshot.total, 12.10);
+}
+
+#[test]
+fn test_recalculate_totals_tax_exclusive() {
+    let mut snapshot = OrderSnapshot::new(1001);
+    snapshot.tax_mode = TaxMode::Exclusive;
+    snapshot.items.push(make_taxed_item("i1", 10.0, 2, 21));
+    snapshot.items.push(make_taxed_item("i2", 3.33, 1, 10));
+
+    recalculate_totals(&mut snapshot);
+
+    // 净价 20.00 + 21% = 4.20; 净价 3.33 + 10% = 0.333 → 0.33 (逐行舍入)
+    assert_eq!(snapshot.items[0].unit_price, 10.0);
+    assert_eq!(snapshot.items[0].tax, 4.20);
+    assert_eq!(snapshot.items[0].line_total, 24.20);
+    assert_eq!(snapshot.items[1].tax, 0.33);
+    assert_eq!(snapshot.items[1].line_total, 3.66);
+    assert_eq!(snapshot.tax, 4.53);
+    assert_eq!(snapshot.subtotal, 27.86);
+    assert_eq!(snapshot.total, 27.86);
+    assert_eq!(snapshot.remaining_amount, 27.86);
+}
+
+#[test]
+fn test_recalculate_totals_tax_breakdown_by_rate() {
+    let mut snapshot = OrderSnapshot::new(1001);
+    snapshot.tax_mode = TaxMode::Exclusive;
+    snapshot.items.push(make_taxed_item("i1", 10.0, 1, 21));
+    snapshot.items.push(make_taxed_item("i2", 5.0, 1, 21));
+    snapshot.items.push(make_taxed_item("i3", 4.0, 1, 10));
+
+    recalculate_totals(&mut snapshot);
+
+    assert_eq!(
+        snapshot.tax_breakdown,
+        vec![
+            TaxBreakdownLine {
+                tax_rate: 10,
+                base_amount: 4.0,
+                tax_amount: 0.40,
+            },
+            TaxBreakdownLine {
+                tax_rate: 21,
+                base_amount: 15.0,
+                tax_amount: 3.15,
+            },
+        ]
+    );
+    // 报表口径: Σ(line_total - tax) = Σ base
+    let base: f64 = snapshot.items.iter().map(|i| i.line_total - i.tax).sum();
+    assert!((base - 19.0).abs() < 1e-9);
+}
+
+#[test]
+fn test_calculate_line_gross_by_mode() {
+    let item = make_taxed_item("i1", 10.0, 3, 21);
+    assert_eq!(
+        calculate_line_gross(&item, 2, TaxMode::Inclusive),
+        Decimal::new(2000, 2)
+    );
+    assert_eq!(
+        calculate_line_gross(&item, 2, TaxMode::Exclusive),
+        Decimal::new(2420, 2)
+    );
+}
+
+fn make_coupon(
+    product_scope: ProductScope,
+    target_id: Option<i64>,
+    adjustment_type: AdjustmentType,
+    adjustment_value: f64,
+) -> AppliedCoupon {
+    AppliedCoupon {
+        coupon_id: 1,
+        code: "WELCOME".to_string(),
+        name: "Welcome".to_string(),
+        product_scope,
+        target_id,
+        adjustment_type,
+        adjustment_value,
+        amount: 0.0,
+    }
+}
+
+#[test]
+fn test_recalculate_totals_coupon_scoped_to_category() {
+    let mut snapshot = OrderSnapshot::new(1001);
+    let mut drink = make_taxed_item("i1", 10.0, 2, 10);
+    drink.category_id = Some(5);
+    snapshot.items.push(drink);
+    snapshot.items.push(make_taxed_item("i2", 15.0, 1, 10));
+    snapshot.coupon = Some(make_coupon(
+        ProductScope::Category,
+        Some(5),
+        AdjustmentType::Percentage,
+        25.0,
+    ));
+
+    recalculate_totals(&mut snapshot);
+
+    // 仅饮品类 20.00 参与 → 25% = 5.00
+    assert_eq!(snapshot.coupon.as_ref().unwrap().amount, 5.0);
+    assert_eq!(snapshot.discount, 5.0);
+    assert_eq!(snapshot.total, 30.0);
+}
+
+#[test]
+fn test_recalculate_totals_fixed_coupon_capped_at_eligible_base() {
+    let mut snapshot = OrderSnapshot::new(1001);
+    snapshot.items.push(make_taxed_item("i1", 4.0, 1, 10));
+    let mut comped = make_taxed_item("i2", 8.0, 1, 10);
+    comped.is_comped = true;
+    snapshot.items.push(comped);
+    snapshot.coupon = Some(make_coupon(
+        ProductScope::Global,
+        None,
+        AdjustmentType::FixedAmount,
+        10.0,
+    ));
+
+    recalculate_totals(&mut snapshot);
+
+    // 赠送菜品不计入，固定 10.00 封顶于 4.00
+    assert_eq!(snapshot.coupon.as_ref().unwrap().amount, 4.0);
+    assert_eq!(snapshot.total, 0.0);
+}
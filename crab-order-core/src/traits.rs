@@ -0,0 +1,19 @@
+//! Event applier trait
+
+use shared::order::{OrderEvent, OrderSnapshot};
+
+/// Event applier trait
+///
+/// Implementations apply event data to snapshots.
+/// This is a PURE function - no business logic, no side effects, no I/O.
+/// Used for both command execution and event replay.
+pub trait EventApplier: Send + Sync {
+    /// Apply the event to the snapshot
+    ///
+    /// # Guarantees
+    /// - Pure function: same input always produces same output
+    /// - No I/O operations
+    /// - No ID generation (IDs come from the event)
+    /// - No business logic validation
+    fn apply(&self, snapshot: &mut OrderSnapshot, event: &OrderEvent);
+}
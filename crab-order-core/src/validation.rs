@@ -0,0 +1,41 @@
+//! Order text validation
+//!
+//! 订单命令文本字段的长度上限与校验，edge-server 的 CRUD 校验复用同一组常量。
+
+use shared::order::types::CommandErrorCode;
+
+use crate::OrderError;
+
+/// Entity names: product, category, attribute, zone, table, tag, role, etc.
+pub const MAX_NAME_LEN: usize = 200;
+
+/// Notes, descriptions, reasons (void note, comp reason, order note, etc.)
+pub const MAX_NOTE_LEN: usize = 500;
+
+/// Validate a required string for order actions (non-empty + max length).
+pub fn validate_order_text(value: &str, field: &str, max_len: usize) -> Result<(), OrderError> {
+    if value.len() > max_len {
+        return Err(OrderError::InvalidOperation(
+            CommandErrorCode::InvalidOperation,
+            format!("{field} is too long ({} chars, max {max_len})", value.len()),
+        ));
+    }
+    Ok(())
+}
+
+/// Validate an optional string for order actions (max length).
+pub fn validate_order_optional_text(
+    value: &Option<String>,
+    field: &str,
+    max_len: usize,
+) -> Result<(), OrderError> {
+    if let Some(v) = value
+        && v.len() > max_len
+    {
+        return Err(OrderError::InvalidOperation(
+            CommandErrorCode::InvalidOperation,
+            format!("{field} is too long ({} chars, max {max_len})", v.len()),
+        ));
+    }
+    Ok(())
+}
@@ -0,0 +1,165 @@
+//! WebAssembly exports (`wasm` feature)
+//!
+//! Web 前端通过 wasm-bindgen 调用与服务端同一份快照/金额计算，替代 TypeScript 中
+//! 手写的重复公式。参数与返回值均为 JSON 字符串，结构与 Rust 类型的 serde 表示一致
+//! (ID 为 53 位 snowflake，可安全使用 JS number)。
+//!
+//! ```bash
+//! wasm-pack build crab-order-core --target web --features wasm
+//! ```
+//!
+//! 每个导出函数都只是 `*_json` 纯函数的薄包装，便于在原生目标上测试。
+
+use serde::Serialize;
+use serde::de::DeserializeOwned;
+use shared::order::{CartItemSnapshot, OrderEvent, OrderSnapshot};
+use wasm_bindgen::prelude::*;
+
+use crate::{EventAction, EventApplier, OrderError, money};
+
+fn parse<T: DeserializeOwned>(json: &str, what: &str) -> Result<T, String> {
+    serde_json::from_str(json).map_err(|e| format!("Invalid {what} JSON: {e}"))
+}
+
+fn to_json<T: Serialize>(value: &T) -> Result<String, String> {
+    serde_json::to_string(value).map_err(|e| format!("Serialization failed: {e}"))
+}
+
+/// `CODE: message` — 前端按首个 ':' 拆出命令错误码
+fn order_error_message(error: OrderError) -> String {
+    match error {
+        OrderError::InvalidOperation(code, message) => {
+            let code = serde_json::to_value(code)
+                .ok()
+                .and_then(|v| v.as_str().map(String::from))
+                .unwrap_or_default();
+            format!("{code}: {message}")
+        }
+        other => other.to_string(),
+    }
+}
+
+fn rebuild_snapshot_json(order_id: i64, events_json: &str) -> Result<String, String> {
+    let events: Vec<OrderEvent> = parse(events_json, "events")?;
+    to_json(&crate::rebuild_snapshot(order_id, &events))
+}
+
+fn apply_events_json(snapshot_json: &str, events_json: &str) -> Result<String, String> {
+    let mut snapshot: OrderSnapshot = parse(snapshot_json, "snapshot")?;
+    let events: Vec<OrderEvent> = parse(events_json, "events")?;
+    for event in &events {
+        EventAction::from(event).apply(&mut snapshot, event);
+    }
+    to_json(&snapshot)
+}
+
+fn recalculate_totals_json(snapshot_json: &str) -> Result<String, String> {
+    let mut snapshot: OrderSnapshot = parse(snapshot_json, "snapshot")?;
+    money::recalculate_totals(&mut snapshot);
+    to_json(&snapshot)
+}
+
+/// 单品价格：`unit_price` / `line_total`，与 `recalculate_totals` 写回的值一致
+#[derive(Serialize)]
+struct ItemPrices {
+    unit_price: f64,
+    line_total: f64,
+}
+
+fn calculate_item_prices_json(item_json: &str) -> Result<String, String> {
+    let item: CartItemSnapshot = parse(item_json, "item")?;
+    to_json(&ItemPrices {
+        unit_price: money::to_f64(money::calculate_unit_price(&item)),
+        line_total: money::to_f64(money::calculate_item_total(&item)),
+    })
+}
+
+#[derive(Serialize)]
+struct TenderJson {
+    tendered: Option<f64>,
+    change: Option<f64>,
+}
+
+fn calculate_change_json(
+    method: &str,
+    amount: f64,
+    tendered: Option<f64>,
+    max_change: f64,
+) -> Result<String, String> {
+    let outcome = money::calculate_change(method, amount, tendered, max_change)
+        .map_err(order_error_message)?;
+    to_json(&TenderJson {
+        tendered: outcome.tendered,
+        change: outcome.change,
+    })
+}
+
+/// 从事件重建订单快照 (`OrderEvent[]` → `OrderSnapshot`)
+#[wasm_bindgen(js_name = rebuildSnapshot)]
+pub fn rebuild_snapshot(order_id: f64, events_json: &str) -> Result<String, JsError> {
+    rebuild_snapshot_json(order_id as i64, events_json).map_err(|e| JsError::new(&e))
+}
+
+/// 在已有快照上按顺序应用事件 (增量更新)
+#[wasm_bindgen(js_name = applyEvents)]
+pub fn apply_events(snapshot_json: &str, events_json: &str) -> Result<String, JsError> {
+    apply_events_json(snapshot_json, events_json).map_err(|e| JsError::new(&e))
+}
+
+/// 重新计算订单金额 (小计 / 折扣 / 税 / 应付 / 剩余)
+#[wasm_bindgen(js_name = recalculateTotals)]
+pub fn recalculate_totals(snapshot_json: &str) -> Result<String, JsError> {
+    recalculate_totals_json(snapshot_json).map_err(|e| JsError::new(&e))
+}
+
+/// 计算单品 `{ unit_price, line_total }` (`CartItemSnapshot` JSON)
+#[wasm_bindgen(js_name = calculateItemPrices)]
+pub fn calculate_item_prices(item_json: &str) -> Result<String, JsError> {
+    calculate_item_prices_json(item_json).map_err(|e| JsError::new(&e))
+}
+
+/// 计算找零 `{ tendered, change }` (`max_change` = 门店找零上限)，失败时错误信息为 `CODE: message`
+#[wasm_bindgen(js_name = calculateChange)]
+pub fn calculate_change(
+    method: &str,
+    amount: f64,
+    tendered: Option<f64>,
+    max_change: f64,
+) -> Result<String, JsError> {
+    calculate_change_json(method, amount, tendered, max_change).map_err(|e| JsError::new(&e))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_rebuild_and_recalculate_round_trip_json() {
+        let snapshot: OrderSnapshot =
+            serde_json::from_str(&rebuild_snapshot_json(42, "[]").unwrap()).unwrap();
+        assert_eq!(snapshot.order_id, 42);
+
+        let recalculated: OrderSnapshot = serde_json::from_str(
+            &recalculate_totals_json(&serde_json::to_string(&snapshot).unwrap()).unwrap(),
+        )
+        .unwrap();
+        assert_eq!(recalculated.total, 0.0);
+
+        assert!(
+            rebuild_snapshot_json(42, "not json")
+                .unwrap_err()
+                .starts_with("Invalid events JSON")
+        );
+    }
+
+    #[test]
+    fn test_calculate_change_reports_command_error_code() {
+        let ok: serde_json::Value =
+            serde_json::from_str(&calculate_change_json("CASH", 7.5, Some(10.0), 200.0).unwrap())
+                .unwrap();
+        assert_eq!(ok["change"], 2.5);
+
+        let err = calculate_change_json("CASH", 10.0, Some(5.0), 200.0).unwrap_err();
+        assert!(err.starts_with("INSUFFICIENT_TENDER: "), "{err}");
+    }
+}
@@ -0,0 +1,122 @@
+//! Customer display (pole display) command builder
+//!
+//! Builds ESC/POS customer display commands (Epson DM-D compatible):
+//! two fixed-width lines, text encoded as CP858 (displays have no GBK font).
+//! Most pole displays and secondary VFD screens accept the same command set
+//! over a network / serial-over-IP adapter.
+
+use crate::encoding::{encode_cp858, gbk_width, pad_gbk, truncate_gbk};
+
+/// Default display width (20 columns × 2 lines)
+pub const DEFAULT_DISPLAY_COLUMNS: usize = 20;
+
+/// Customer display command builder
+///
+/// Each `show` call clears the screen and writes both lines, so the
+/// display never keeps stale characters from a previous message.
+pub struct LineDisplayBuilder {
+    buf: Vec<u8>,
+    columns: usize,
+}
+
+impl LineDisplayBuilder {
+    /// Create a builder for a display with `columns` characters per line
+    pub fn new(columns: usize) -> Self {
+        let mut buf = Vec::with_capacity(64);
+        // ESC @ - Initialize display
+        buf.extend_from_slice(&[0x1B, 0x40]);
+        // ESC t 19 - Select PC858 (Euro) code table
+        buf.extend_from_slice(&[0x1B, 0x74, 19]);
+        // US C 0 - Hide cursor
+        buf.extend_from_slice(&[0x1F, 0x43, 0x00]);
+        Self {
+            buf,
+            columns: columns.max(1),
+        }
+    }
+
+    /// Get the configured line width
+    pub fn columns(&self) -> usize {
+        self.columns
+    }
+
+    /// Clear the screen
+    pub fn clear(&mut self) -> &mut Self {
+        // CLR
+        self.buf.push(0x0C);
+        self
+    }
+
+    /// Show two lines (each truncated / padded to the display width)
+    pub fn show(&mut self, top: &str, bottom: &str) -> &mut Self {
+        self.clear();
+        self.write_line(1, top);
+        self.write_line(2, bottom);
+        self
+    }
+
+    /// Left and right text on one line (right text wins when too long)
+    pub fn pair(&self, left: &str, right: &str) -> String {
+        let right = truncate_gbk(right, self.columns);
+        let room = self.columns - gbk_width(&right);
+        if room == 0 {
+            return right;
+        }
+        // At least one space between label and value
+        let left = truncate_gbk(left, room.saturating_sub(1));
+        format!("{}{}", pad_gbk(&left, room, false), right)
+    }
+
+    fn write_line(&mut self, row: u8, text: &str) {
+        // US $ x y - Move cursor to column 1 of the row
+        self.buf.extend_from_slice(&[0x1F, 0x24, 0x01, row]);
+        let clean: String = text
+            .chars()
+            .map(|c| if c.is_control() { ' ' } else { c })
+            .collect();
+        let line = pad_gbk(&clean, self.columns, false);
+        self.buf.extend_from_slice(&encode_cp858(&line));
+    }
+
+    /// Build the final byte buffer
+    pub fn build(self) -> Vec<u8> {
+        self.buf
+    }
+}
+
+impl Default for LineDisplayBuilder {
+    fn default() -> Self {
+        Self::new(DEFAULT_DISPLAY_COLUMNS)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_show_pads_and_encodes() {
+        let mut d = LineDisplayBuilder::new(10);
+        d.show("Café con leche", "1,50 €");
+        let data = d.build();
+
+        // Row 1: truncated to 10 columns, é → CP858 0x82
+        let row1 = [0x1F, 0x24, 0x01, 0x01, b'C', b'a', b'f', 0x82];
+        assert!(data.windows(row1.len()).any(|w| w == row1));
+        // Row 2: padded to 10 columns, € → CP858 0xD5
+        let row2 = [0x1F, 0x24, 0x01, 0x02, b'1', b',', b'5', b'0', b' ', 0xD5];
+        let pos = data.windows(row2.len()).position(|w| w == row2).unwrap();
+        assert_eq!(data.len() - pos - 4, 10);
+    }
+
+    #[test]
+    fn test_pair() {
+        let d = LineDisplayBuilder::new(20);
+        assert_eq!(d.pair("TOTAL", "12,50 €"), "TOTAL        12,50 €");
+        assert_eq!(
+            d.pair("Hamburguesa especial de la casa", "9,90"),
+            "Hamburguesa esp 9,90"
+        );
+        assert_eq!(d.pair("x", &"9".repeat(25)).len(), 20);
+    }
+}
@@ -60,6 +60,39 @@ pub fn convert_to_gbk(bytes: &[u8]) -> Vec<u8> {
     // FS C 1 (0x1C 0x43 0x01) - Select GBK code page
     result.extend_from_slice(&[0x1C, 0x26, 0x1C, 0x43, 0x01]);
 
+    encode_text_into(bytes, &mut result);
+
+    // Exit Chinese mode at the end
+    result.extend_from_slice(&[0x1C, 0x2E]);
+
+    result
+}
+
+/// Convert text + ESC/POS segments, copying binary segments verbatim
+///
+/// Binary segments (QR / barcode payloads, raster data) may contain bytes
+/// of 0x80 and above in length headers; routing them through the text
+/// encoder would corrupt them.
+pub(crate) fn convert_segments_to_gbk<'a>(
+    segments: impl IntoIterator<Item = (&'a [u8], bool)>,
+) -> Vec<u8> {
+    let mut result = Vec::new();
+    result.extend_from_slice(&[0x1C, 0x26, 0x1C, 0x43, 0x01]);
+
+    for (bytes, binary) in segments {
+        if binary {
+            result.extend_from_slice(bytes);
+        } else {
+            encode_text_into(bytes, &mut result);
+        }
+    }
+
+    result.extend_from_slice(&[0x1C, 0x2E]);
+    result
+}
+
+/// Encode one text segment (UTF-8 + ESC/POS commands) into `result`
+fn encode_text_into(bytes: &[u8], result: &mut Vec<u8>) {
     let mut buffer = Vec::new();
     let mut i = 0;
 
@@ -68,7 +101,7 @@ pub fn convert_to_gbk(bytes: &[u8]) -> Vec<u8> {
 
         // Check for INIT command (ESC @ = 0x1B 0x40)
         if b == 0x1B && i + 1 < bytes.len() && bytes[i + 1] == 0x40 {
-            flush_buffer(&mut buffer, &mut result);
+            flush_buffer(&mut buffer, result);
             result.push(0x1B);
             result.push(0x40);
             // Re-enable Chinese mode after INIT
@@ -79,7 +112,7 @@ pub fn convert_to_gbk(bytes: &[u8]) -> Vec<u8> {
 
         if b < 128 {
             // ASCII byte — flush non-ASCII buffer, then pass through
-            flush_buffer(&mut buffer, &mut result);
+            flush_buffer(&mut buffer, result);
             result.push(b);
         } else {
             // Non-ASCII byte (part of UTF-8 sequence)
@@ -88,12 +121,22 @@ pub fn convert_to_gbk(bytes: &[u8]) -> Vec<u8> {
         i += 1;
     }
 
-    flush_buffer(&mut buffer, &mut result);
-
-    // Exit Chinese mode at the end
-    result.extend_from_slice(&[0x1C, 0x2E]);
+    flush_buffer(&mut buffer, result);
+}
 
-    result
+/// Encode text as single-byte CP858 (customer displays have no GBK font)
+///
+/// Characters outside ASCII / CP858 are replaced with `?`.
+pub(crate) fn encode_cp858(s: &str) -> Vec<u8> {
+    s.chars()
+        .map(|ch| {
+            if ch.is_ascii() {
+                ch as u8
+            } else {
+                unicode_to_cp858(ch).unwrap_or(b'?')
+            }
+        })
+        .collect()
 }
 
 /// Flush non-ASCII buffer: route each character to GBK or CP858
@@ -21,6 +21,10 @@ pub enum PrintError {
     #[error("Timeout: {0}")]
     Timeout(String),
 
+    /// Barcode data not valid for the symbology
+    #[error("Invalid barcode: {0}")]
+    InvalidBarcode(String),
+
     /// Invalid printer configuration
     #[error("Invalid config: {0}")]
     InvalidConfig(String),
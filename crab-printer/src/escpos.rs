@@ -2,9 +2,113 @@
 //!
 //! Provides a fluent API for building ESC/POS print data.
 
-use crate::encoding::{convert_to_gbk, gbk_width};
+use std::ops::Range;
+
+use crate::encoding::{convert_segments_to_gbk, convert_to_gbk, gbk_width};
+use crate::error::{PrintError, PrintResult};
 use tracing::instrument;
 
+/// QR code error correction level (GS ( k fn 169)
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum QrErrorCorrection {
+    /// ~7% recovery
+    #[default]
+    L,
+    /// ~15% recovery
+    M,
+    /// ~25% recovery
+    Q,
+    /// ~30% recovery
+    H,
+}
+
+impl QrErrorCorrection {
+    fn byte(self) -> u8 {
+        match self {
+            Self::L => 0x30,
+            Self::M => 0x31,
+            Self::Q => 0x32,
+            Self::H => 0x33,
+        }
+    }
+}
+
+/// 1D barcode symbology (GS k, function B: m = 65..73)
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum BarcodeSymbology {
+    UpcA,
+    UpcE,
+    Ean13,
+    Ean8,
+    Code39,
+    Itf,
+    Codabar,
+    Code93,
+    Code128,
+}
+
+impl BarcodeSymbology {
+    fn byte(self) -> u8 {
+        match self {
+            Self::UpcA => 65,
+            Self::UpcE => 66,
+            Self::Ean13 => 67,
+            Self::Ean8 => 68,
+            Self::Code39 => 69,
+            Self::Itf => 70,
+            Self::Codabar => 71,
+            Self::Code93 => 72,
+            Self::Code128 => 73,
+        }
+    }
+
+    /// Validate data against the symbology's character set and length
+    fn validate(self, data: &str) -> Result<(), String> {
+        let digits = data.bytes().all(|b| b.is_ascii_digit());
+        let len = data.len();
+        let ok = match self {
+            // Check digit optional: printer computes it when omitted
+            Self::UpcA => digits && (len == 11 || len == 12),
+            Self::UpcE => digits && matches!(len, 6..=8 | 11 | 12),
+            Self::Ean13 => digits && (len == 12 || len == 13),
+            Self::Ean8 => digits && (len == 7 || len == 8),
+            Self::Code39 => {
+                len > 0
+                    && data.bytes().all(|b| {
+                        b.is_ascii_uppercase() || b.is_ascii_digit() || b" $%*+-./".contains(&b)
+                    })
+            }
+            Self::Itf => digits && len >= 2 && len.is_multiple_of(2),
+            Self::Codabar => {
+                len >= 2
+                    && data
+                        .bytes()
+                        .all(|b| b.is_ascii_digit() || b"ABCDabcd$+-./:".contains(&b))
+            }
+            Self::Code93 | Self::Code128 => len > 0 && data.is_ascii(),
+        };
+        if !ok {
+            return Err(format!("invalid {:?} barcode data: {:?}", self, data));
+        }
+        // Code128 needs a 2-byte code set prefix
+        let max = if self == Self::Code128 { 253 } else { 255 };
+        if len > max {
+            return Err(format!("{:?} barcode data too long ({} bytes)", self, len));
+        }
+        Ok(())
+    }
+}
+
+/// Human-readable interpretation (HRI) position (GS H)
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum BarcodeHri {
+    #[default]
+    None,
+    Above,
+    Below,
+    Both,
+}
+
 /// ESC/POS command builder
 ///
 /// Builds ESC/POS byte sequences for thermal printers.
@@ -12,6 +116,8 @@ use tracing::instrument;
 pub struct EscPosBuilder {
     buf: Vec<u8>,
     width: usize,
+    /// Ranges of `buf` holding binary command data (skipped by GBK conversion)
+    binary: Vec<Range<usize>>,
 }
 
 impl EscPosBuilder {
@@ -24,7 +130,11 @@ impl EscPosBuilder {
         let mut buf = Vec::with_capacity(4096);
         // Initialize printer (ESC @)
         buf.extend_from_slice(&[0x1B, 0x40]);
-        Self { buf, width }
+        Self {
+            buf,
+            width,
+            binary: Vec::new(),
+        }
     }
 
     /// Get the configured paper width
@@ -202,38 +312,53 @@ impl EscPosBuilder {
 
     // === QR Code ===
 
-    /// Print a QR code
+    /// Print a QR code (Model 2)
     ///
-    /// Size: 1-16 (module size in dots)
-    pub fn qr_code(&mut self, data: &str, size: u8) -> &mut Self {
-        let size = size.clamp(1, 16);
-
-        // Function 165: Select model (Model 2)
-        self.buf
-            .extend_from_slice(&[0x1D, 0x28, 0x6B, 0x04, 0x00, 0x31, 0x41, 0x31, 0x00]);
-
-        // Function 167: Set module size
-        self.buf
-            .extend_from_slice(&[0x1D, 0x28, 0x6B, 0x03, 0x00, 0x31, 0x43, size]);
+    /// Size: 1-16 (module size in dots). Data is stored as-is (UTF-8 bytes),
+    /// so URLs with non-ASCII characters are not GBK converted.
+    pub fn qr_code(&mut self, data: &str, size: u8, ecc: QrErrorCorrection) -> &mut Self {
+        // pL/pH may be >= 0x80 → binary segment
+        self.binary_segment(&qr_code_command(data, size, ecc));
+        self
+    }
 
-        // Function 169: Set error correction (L)
-        self.buf
-            .extend_from_slice(&[0x1D, 0x28, 0x6B, 0x03, 0x00, 0x31, 0x45, 0x31]);
+    // === Barcode ===
 
-        // Function 180: Store data
-        let data_bytes = data.as_bytes();
-        let len = data_bytes.len() + 3;
-        let p_l = (len & 0xFF) as u8;
-        let p_h = ((len >> 8) & 0xFF) as u8;
-        self.buf
-            .extend_from_slice(&[0x1D, 0x28, 0x6B, p_l, p_h, 0x31, 0x50, 0x30]);
-        self.buf.extend_from_slice(data_bytes);
+    /// Print a 1D barcode
+    ///
+    /// `height` in dots (1-255). For EAN/UPC the check digit may be omitted.
+    /// Code128 data is printed in code set B.
+    pub fn barcode(
+        &mut self,
+        symbology: BarcodeSymbology,
+        data: &str,
+        height: u8,
+        hri: BarcodeHri,
+    ) -> PrintResult<&mut Self> {
+        symbology
+            .validate(data)
+            .map_err(PrintError::InvalidBarcode)?;
+
+        // GS H n - HRI position
+        self.buf.extend_from_slice(&[0x1D, 0x48, hri as u8]);
+        // GS h n - Barcode height
+        self.buf.extend_from_slice(&[0x1D, 0x68, height.max(1)]);
+        // GS w n - Module width (2 = default, readable on 58mm/80mm)
+        self.buf.extend_from_slice(&[0x1D, 0x77, 0x02]);
+
+        // GS k m n d1..dn (function B)
+        let mut payload = Vec::with_capacity(data.len() + 2);
+        if symbology == BarcodeSymbology::Code128 {
+            payload.extend_from_slice(b"{B");
+        }
+        payload.extend_from_slice(data.as_bytes());
 
-        // Function 181: Print
-        self.buf
-            .extend_from_slice(&[0x1D, 0x28, 0x6B, 0x03, 0x00, 0x31, 0x51, 0x30]);
+        let mut cmd = vec![0x1D, 0x6B, symbology.byte(), payload.len() as u8];
+        cmd.extend_from_slice(&payload);
+        self.binary_segment(&cmd);
+        self.buf.push(b'\n');
 
-        self
+        Ok(self)
     }
 
     // === Raw Commands ===
@@ -250,13 +375,20 @@ impl EscPosBuilder {
         self
     }
 
+    /// Append command bytes that must bypass GBK conversion
+    fn binary_segment(&mut self, bytes: &[u8]) {
+        let start = self.buf.len();
+        self.buf.extend_from_slice(bytes);
+        self.binary.push(start..self.buf.len());
+    }
+
     // === Build ===
 
     /// Build the final byte buffer with GBK encoding
     ///
     /// This converts all UTF-8 text to GBK while preserving ESC/POS commands.
     pub fn build(self) -> Vec<u8> {
-        convert_to_gbk(&self.buf)
+        encode_with_binary(&self.buf, &self.binary)
     }
 
     /// Build without GBK conversion (for debugging or ASCII-only content)
@@ -271,29 +403,76 @@ impl Default for EscPosBuilder {
     }
 }
 
+/// GBK-encode `buf`, copying the `binary` command ranges verbatim
+fn encode_with_binary(buf: &[u8], binary: &[Range<usize>]) -> Vec<u8> {
+    if binary.is_empty() {
+        return convert_to_gbk(buf);
+    }
+
+    let mut segments = Vec::with_capacity(binary.len() * 2 + 1);
+    let mut pos = 0;
+    for range in binary {
+        segments.push((&buf[pos..range.start], false));
+        segments.push((&buf[range.clone()], true));
+        pos = range.end;
+    }
+    segments.push((&buf[pos..], false));
+    convert_segments_to_gbk(segments)
+}
+
+/// QR code (Model 2) command sequence shared by both builders
+///
+/// Size: 1-16 (module size in dots). Data is stored as-is (UTF-8 bytes).
+fn qr_code_command(data: &str, size: u8, ecc: QrErrorCorrection) -> Vec<u8> {
+    let size = size.clamp(1, 16);
+    let data_bytes = data.as_bytes();
+    let mut cmd = Vec::with_capacity(data_bytes.len() + 41);
+
+    // Function 165: Select model (Model 2)
+    cmd.extend_from_slice(&[0x1D, 0x28, 0x6B, 0x04, 0x00, 0x31, 0x41, 0x32, 0x00]);
+
+    // Function 167: Set module size
+    cmd.extend_from_slice(&[0x1D, 0x28, 0x6B, 0x03, 0x00, 0x31, 0x43, size]);
+
+    // Function 169: Set error correction level
+    cmd.extend_from_slice(&[0x1D, 0x28, 0x6B, 0x03, 0x00, 0x31, 0x45, ecc.byte()]);
+
+    // Function 180: Store data
+    let len = data_bytes.len() + 3;
+    let p_l = (len & 0xFF) as u8;
+    let p_h = ((len >> 8) & 0xFF) as u8;
+    cmd.extend_from_slice(&[0x1D, 0x28, 0x6B, p_l, p_h, 0x31, 0x50, 0x30]);
+    cmd.extend_from_slice(data_bytes);
+
+    // Function 181: Print
+    cmd.extend_from_slice(&[0x1D, 0x28, 0x6B, 0x03, 0x00, 0x31, 0x51, 0x30]);
+
+    cmd
+}
+
 // ============================================================================
-// String-based ESC/POS Builder (for receipt rendering)
+// Text ESC/POS Builder (for receipt rendering)
 // ============================================================================
 
-/// String-based ESC/POS command builder
-///
-/// Unlike `EscPosBuilder` which works with bytes and converts to GBK at the end,
-/// this builder accumulates a UTF-8 String that should be converted to GBK
-/// separately (e.g., using `convert_to_gbk`).
+/// Text-oriented ESC/POS command builder
 ///
-/// This is useful when you want to build receipt content as a String first,
-/// then handle the binary conversion and printing separately.
+/// Same byte model as `EscPosBuilder` (UTF-8 text + commands, GBK converted
+/// in [`Self::build`], binary command data kept verbatim), with the
+/// `write` / `pair` vocabulary used by the receipt renderer.
 pub struct EscPosTextBuilder {
-    buf: String,
+    buf: Vec<u8>,
     width: usize,
+    /// Ranges of `buf` holding binary command data (skipped by GBK conversion)
+    binary: Vec<Range<usize>>,
 }
 
 impl EscPosTextBuilder {
     /// Create a new text builder with specified paper width in characters
     pub fn new(width: usize) -> Self {
         Self {
-            buf: String::new(),
+            buf: Vec::new(),
             width,
+            binary: Vec::new(),
         }
     }
 
@@ -306,14 +485,14 @@ impl EscPosTextBuilder {
 
     /// Write raw text
     pub fn write(&mut self, s: &str) -> &mut Self {
-        self.buf.push_str(s);
+        self.buf.extend_from_slice(s.as_bytes());
         self
     }
 
     /// Write text followed by newline
     pub fn write_line(&mut self, s: &str) -> &mut Self {
-        self.buf.push_str(s);
-        self.buf.push('\n');
+        self.buf.extend_from_slice(s.as_bytes());
+        self.buf.push(b'\n');
         self
     }
 
@@ -321,19 +500,19 @@ impl EscPosTextBuilder {
 
     /// Align text to center
     pub fn align_center(&mut self) -> &mut Self {
-        self.buf.push_str("\x1B\x61\x01");
+        self.buf.extend_from_slice(b"\x1B\x61\x01");
         self
     }
 
     /// Align text to left (default)
     pub fn align_left(&mut self) -> &mut Self {
-        self.buf.push_str("\x1B\x61\x00");
+        self.buf.extend_from_slice(b"\x1B\x61\x00");
         self
     }
 
     /// Align text to right
     pub fn align_right(&mut self) -> &mut Self {
-        self.buf.push_str("\x1B\x61\x02");
+        self.buf.extend_from_slice(b"\x1B\x61\x02");
         self
     }
 
@@ -341,37 +520,37 @@ impl EscPosTextBuilder {
 
     /// Enable bold text
     pub fn bold_on(&mut self) -> &mut Self {
-        self.buf.push_str("\x1B\x45\x01");
+        self.buf.extend_from_slice(b"\x1B\x45\x01");
         self
     }
 
     /// Disable bold text
     pub fn bold_off(&mut self) -> &mut Self {
-        self.buf.push_str("\x1B\x45\x00");
+        self.buf.extend_from_slice(b"\x1B\x45\x00");
         self
     }
 
     /// Double width and height
     pub fn size_double(&mut self) -> &mut Self {
-        self.buf.push_str("\x1D\x21\x11");
+        self.buf.extend_from_slice(b"\x1D\x21\x11");
         self
     }
 
     /// Double height only
     pub fn size_double_height(&mut self) -> &mut Self {
-        self.buf.push_str("\x1D\x21\x01");
+        self.buf.extend_from_slice(b"\x1D\x21\x01");
         self
     }
 
     /// Double width only
     pub fn size_double_width(&mut self) -> &mut Self {
-        self.buf.push_str("\x1D\x21\x10");
+        self.buf.extend_from_slice(b"\x1D\x21\x10");
         self
     }
 
     /// Reset to normal size
     pub fn size_reset(&mut self) -> &mut Self {
-        self.buf.push_str("\x1D\x21\x00");
+        self.buf.extend_from_slice(b"\x1D\x21\x00");
         self
     }
 
@@ -436,16 +615,27 @@ impl EscPosTextBuilder {
         self.line_lr(key, value)
     }
 
-    // === Build ===
+    // === QR Code ===
 
-    /// Finalize and return the accumulated string
-    pub fn finalize(self) -> String {
-        self.buf
+    /// Print a QR code (same command sequence as `EscPosBuilder::qr_code`)
+    pub fn qr_code(&mut self, data: &str, size: u8) -> &mut Self {
+        // pL/pH may be >= 0x80 → binary segment
+        self.binary_segment(&qr_code_command(data, size, QrErrorCorrection::M));
+        self
     }
 
-    /// Get the current buffer as a string reference
-    pub fn as_str(&self) -> &str {
-        &self.buf
+    /// Append command bytes that must bypass GBK conversion
+    fn binary_segment(&mut self, bytes: &[u8]) {
+        let start = self.buf.len();
+        self.buf.extend_from_slice(bytes);
+        self.binary.push(start..self.buf.len());
+    }
+
+    // === Build ===
+
+    /// Build the final byte buffer with GBK encoding
+    pub fn build(self) -> Vec<u8> {
+        encode_with_binary(&self.buf, &self.binary)
     }
 }
 
@@ -588,4 +778,83 @@ mod tests {
         let s = String::from_utf8_lossy(&data);
         assert!(s.contains("=========="));
     }
+
+    #[test]
+    fn test_text_qr_code_long_payload_survives_build() {
+        // 200 bytes → pL = 203 (>= 0x80), must not be re-encoded
+        let data = format!("https://survey.example/s/{}", "A".repeat(175));
+        let mut b = EscPosTextBuilder::new(48);
+        b.write_line("ñ").qr_code(&data, 6).write_line("二维码");
+        let bytes = b.build();
+
+        let mut store = vec![0x1D, 0x28, 0x6B, 203, 0x00, 0x31, 0x50, 0x30];
+        store.extend_from_slice(data.as_bytes());
+        assert!(bytes.windows(store.len()).any(|w| w == store.as_slice()));
+    }
+
+    #[test]
+    fn test_qr_code_long_payload_survives_build() {
+        // 200 bytes → pL = 203 (>= 0x80), must not be re-encoded
+        let data = "A".repeat(200);
+        let mut b = EscPosBuilder::new(48);
+        b.line("ñ")
+            .qr_code(&data, 6, QrErrorCorrection::M)
+            .line("二维码");
+        let bytes = b.build();
+
+        let mut store = vec![0x1D, 0x28, 0x6B, 203, 0x00, 0x31, 0x50, 0x30];
+        store.extend_from_slice(data.as_bytes());
+        assert!(bytes.windows(store.len()).any(|w| w == store.as_slice()));
+        let ecc = [0x1D, 0x28, 0x6B, 0x03, 0x00, 0x31, 0x45, 0x31];
+        assert!(bytes.windows(ecc.len()).any(|w| w == ecc));
+    }
+
+    #[test]
+    fn test_barcode_ean13() {
+        let mut b = EscPosBuilder::new(48);
+        b.barcode(
+            BarcodeSymbology::Ean13,
+            "842000012345",
+            80,
+            BarcodeHri::Below,
+        )
+        .unwrap();
+        let bytes = b.build();
+
+        let hri = [0x1D, 0x48, 0x02];
+        assert!(bytes.windows(3).any(|w| w == hri));
+        let mut cmd = vec![0x1D, 0x6B, 67, 12];
+        cmd.extend_from_slice(b"842000012345");
+        assert!(bytes.windows(cmd.len()).any(|w| w == cmd.as_slice()));
+    }
+
+    #[test]
+    fn test_barcode_validation() {
+        let mut b = EscPosBuilder::new(48);
+        assert!(
+            b.barcode(BarcodeSymbology::Ean13, "84200001234", 80, BarcodeHri::None)
+                .is_err()
+        );
+        assert!(
+            b.barcode(
+                BarcodeSymbology::Ean13,
+                "84200001234X",
+                80,
+                BarcodeHri::None
+            )
+            .is_err()
+        );
+        assert!(
+            b.barcode(BarcodeSymbology::Code39, "order-1", 80, BarcodeHri::None)
+                .is_err()
+        );
+        assert!(
+            b.barcode(BarcodeSymbology::Code128, "order-1", 80, BarcodeHri::None)
+                .is_ok()
+        );
+        let bytes = b.build_raw();
+        let mut cmd = vec![0x1D, 0x6B, 73, 9];
+        cmd.extend_from_slice(b"{Border-1");
+        assert!(bytes.windows(cmd.len()).any(|w| w == cmd.as_slice()));
+    }
 }
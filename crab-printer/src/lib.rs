@@ -6,6 +6,7 @@
 //!
 //! This crate handles HOW to print:
 //! - ESC/POS command building
+//! - Customer (pole) display commands
 //! - GBK encoding for Chinese printers
 //! - Network printing (TCP port 9100)
 //! - Windows driver printing (optional)
@@ -36,15 +37,19 @@
 //! printer.print(&builder.build()).await?;
 //! ```
 
+mod display;
 mod encoding;
 mod error;
 mod escpos;
 mod printer;
 
 // Re-exports
+pub use display::{DEFAULT_DISPLAY_COLUMNS, LineDisplayBuilder};
 pub use encoding::{convert_to_gbk, gbk_width, pad_gbk, truncate_gbk};
 pub use error::{PrintError, PrintResult};
-pub use escpos::{EscPosBuilder, EscPosTextBuilder};
+pub use escpos::{
+    BarcodeHri, BarcodeSymbology, EscPosBuilder, EscPosTextBuilder, QrErrorCorrection,
+};
 pub use printer::{NetworkPrinter, Printer};
 
 #[cfg(feature = "image")]
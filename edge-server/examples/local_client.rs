@@ -116,6 +116,7 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
         title: "Test".to_string(),
         message: "Hello from server!".to_string(),
         data: None,
+        durable_id: None,
     });
 
     // 通过 server_tx 发送广播
@@ -0,0 +1,42 @@
+//! Replay Session Example - 重放门店录制的会话
+//!
+//! 把 `/api/session-recording` 录制的文件喂给一个全新的 OrdersManager，
+//! 打印与现场结果不一致的命令，用于复现门店报告的问题。
+//!
+//! 运行: cargo run -p edge-server --example replay_session -- session-20250301-213000.jsonl
+
+use edge_server::orders::OrdersManager;
+use edge_server::orders::recorder::RecordedSession;
+use edge_server::orders::replay::replay;
+
+#[tokio::main]
+async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    tracing_subscriber::fmt::init();
+
+    let path = std::env::args()
+        .nth(1)
+        .ok_or("usage: replay_session <session.jsonl>")?;
+    let session = RecordedSession::load(&path)?;
+    println!(
+        "Session {} — {} entries, {} active orders at start",
+        path,
+        session.entries.len(),
+        session.header.active_orders.len()
+    );
+
+    let dir = tempfile::tempdir()?;
+    let manager = OrdersManager::new(dir.path().join("replay.redb"), chrono_tz::Europe::Madrid, 1)?;
+    let report = replay(&session, &manager).await?;
+
+    println!(
+        "Replayed {} commands, {} matched",
+        report.commands, report.matched
+    );
+    for divergence in &report.divergences {
+        println!("  ✗ {divergence}");
+    }
+    if !report.is_faithful() {
+        std::process::exit(1);
+    }
+    Ok(())
+}
@@ -0,0 +1,68 @@
+//! Scenario Runner Example - 执行声明式订单场景
+//!
+//! 与单元测试共用 `scenarios/*.json` 场景定义 (见 `orders::scenario`):
+//! - 压测: `--repeat N` 在同一个 OrdersManager 上重复执行，打印吞吐量
+//! - 演示数据: `--redb PATH` 写入指定的订单库 (默认临时目录)
+//!
+//! 重复执行要求场景结束时桌台已释放 (订单已结单 / 作废)。
+//!
+//! 运行: cargo run -p edge-server --example scenario -- scenarios/dine_in_discount_split_payment.json --repeat 500
+
+use std::time::Instant;
+
+use edge_server::orders::OrdersManager;
+use edge_server::orders::scenario::Scenario;
+
+#[tokio::main]
+async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    tracing_subscriber::fmt::init();
+
+    let mut args = std::env::args().skip(1);
+    let mut path = None;
+    let mut repeat = 1usize;
+    let mut redb = None;
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "--repeat" => repeat = args.next().ok_or("--repeat needs a value")?.parse()?,
+            "--redb" => redb = Some(args.next().ok_or("--redb needs a path")?),
+            _ => path = Some(arg),
+        }
+    }
+    let path = path.ok_or("usage: scenario <scenario.json> [--repeat N] [--redb PATH]")?;
+    let scenario = Scenario::load(&path)?;
+
+    let dir = tempfile::tempdir()?;
+    let db_path = redb
+        .map(std::path::PathBuf::from)
+        .unwrap_or_else(|| dir.path().join("scenario.redb"));
+    let manager = OrdersManager::new(&db_path, chrono_tz::Europe::Madrid, 1)?;
+
+    println!(
+        "Scenario \"{}\" — {} steps × {} run(s) → {}",
+        scenario.name,
+        scenario.steps.len(),
+        repeat,
+        db_path.display()
+    );
+
+    let started = Instant::now();
+    let mut commands = 0;
+    for run in 0..repeat {
+        match scenario.run(&manager).await {
+            Ok(outcome) => commands += outcome.commands,
+            Err(e) => {
+                println!("  ✗ run {run}: {e}");
+                std::process::exit(1);
+            }
+        }
+    }
+
+    let elapsed = started.elapsed();
+    println!(
+        "  ✓ {} commands in {:.2?} ({:.0} cmd/s)",
+        commands,
+        elapsed,
+        commands as f64 / elapsed.as_secs_f64().max(f64::EPSILON)
+    );
+    Ok(())
+}
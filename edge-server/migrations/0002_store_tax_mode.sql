@@ -0,0 +1,2 @@
+-- Store-level pricing mode: 'INCLUSIVE' (prices include tax) | 'EXCLUSIVE' (tax added on top)
+ALTER TABLE store_info ADD COLUMN tax_mode TEXT NOT NULL DEFAULT 'INCLUSIVE';
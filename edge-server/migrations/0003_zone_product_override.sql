@@ -0,0 +1,10 @@
+-- Zone-level product overrides: price override for the default spec and per-zone availability
+CREATE TABLE zone_product_override (
+    id           INTEGER PRIMARY KEY,
+    zone_id      INTEGER NOT NULL REFERENCES zone(id) ON DELETE CASCADE,
+    product_id   INTEGER NOT NULL REFERENCES product(id) ON DELETE CASCADE,
+    price        REAL,
+    is_available INTEGER NOT NULL DEFAULT 1,
+    updated_at   INTEGER NOT NULL DEFAULT 0
+);
+CREATE UNIQUE INDEX idx_zone_product_override ON zone_product_override(zone_id, product_id);
@@ -0,0 +1,13 @@
+-- Catalog change history: before/after JSON snapshot per CRUD change (revert source)
+CREATE TABLE catalog_change (
+    id              INTEGER PRIMARY KEY,
+    entity_type     TEXT    NOT NULL,          -- product / category / tag / price_rule
+    entity_id       INTEGER NOT NULL,
+    action          TEXT    NOT NULL,          -- CREATE / UPDATE / DELETE / REVERT
+    before_snapshot TEXT,                      -- JSON snapshot (NULL for CREATE)
+    after_snapshot  TEXT,                      -- JSON snapshot (NULL for DELETE)
+    operator_id     INTEGER,
+    operator_name   TEXT,
+    created_at      INTEGER NOT NULL
+);
+CREATE INDEX idx_catalog_change_entity ON catalog_change(entity_type, entity_id, created_at);
@@ -0,0 +1,4 @@
+-- Card last-4 / terminal reference on archived payments (customer service lookup)
+ALTER TABLE archived_order_payment ADD COLUMN reference TEXT;
+CREATE INDEX idx_archived_payment_reference ON archived_order_payment(reference);
+CREATE INDEX idx_archived_order_total ON archived_order(total_amount);
@@ -0,0 +1,12 @@
+-- Receipt footer promotions: promo lines + survey QR, scheduled by weekday
+CREATE TABLE receipt_footer (
+    id          INTEGER PRIMARY KEY,
+    name        TEXT    NOT NULL,
+    lines       TEXT    NOT NULL DEFAULT '',
+    survey_url  TEXT,
+    active_days TEXT,               -- JSON array: 0=Sunday..6=Saturday (NULL = every day)
+    sort_order  INTEGER NOT NULL DEFAULT 0,
+    is_active   INTEGER NOT NULL DEFAULT 1,
+    created_at  INTEGER NOT NULL,
+    updated_at  INTEGER NOT NULL
+);
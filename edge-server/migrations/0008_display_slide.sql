@@ -0,0 +1,16 @@
+-- Customer display slideshow: promotional images shown on idle customer screens
+CREATE TABLE display_slide (
+    id                INTEGER PRIMARY KEY,
+    name              TEXT    NOT NULL,
+    image             TEXT    NOT NULL,    -- image content hash (see image_ref)
+    duration_secs     INTEGER NOT NULL DEFAULT 8,
+    sort_order        INTEGER NOT NULL DEFAULT 0,
+    active_days       TEXT,                -- JSON array: 0=Sunday..6=Saturday (NULL = every day)
+    active_start_time TEXT,                -- HH:MM (store timezone)
+    active_end_time   TEXT,                -- HH:MM (exclusive)
+    valid_from        INTEGER,
+    valid_until       INTEGER,
+    is_active         INTEGER NOT NULL DEFAULT 1,
+    created_at        INTEGER NOT NULL,
+    updated_at        INTEGER NOT NULL
+);
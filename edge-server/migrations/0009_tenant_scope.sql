@@ -0,0 +1,6 @@
+-- Tenant scope guard: the tenant that owns this database (single row)
+CREATE TABLE tenant_scope (
+    id        INTEGER PRIMARY KEY CHECK (id = 1),
+    tenant_id INTEGER NOT NULL,
+    bound_at  INTEGER NOT NULL
+);
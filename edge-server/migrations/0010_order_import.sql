@@ -0,0 +1,4 @@
+-- Historical orders imported from a legacy POS (no events, hash chain entry or invoice)
+ALTER TABLE archived_order ADD COLUMN is_imported INTEGER NOT NULL DEFAULT 0;
+ALTER TABLE archived_order ADD COLUMN import_source TEXT;    -- adapter format, e.g. 'csv'
+CREATE INDEX idx_archived_order_imported ON archived_order(is_imported);
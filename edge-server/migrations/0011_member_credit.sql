@@ -0,0 +1,22 @@
+-- Member stored-credit wallet (储值余额)
+ALTER TABLE member ADD COLUMN credit_balance REAL NOT NULL DEFAULT 0;
+
+-- Wallet ledger: every balance change, signed amount (+ top-up / refund, - payment)
+CREATE TABLE member_credit_txn (
+    id             INTEGER PRIMARY KEY,
+    member_id      INTEGER NOT NULL REFERENCES member(id),
+    kind           TEXT    NOT NULL,   -- TOP_UP / PAYMENT / REFUND
+    amount         REAL    NOT NULL,
+    balance_after  REAL    NOT NULL,
+    order_id       INTEGER,
+    payment_id     INTEGER,
+    method         TEXT,               -- top-up tender (CASH / CARD)
+    note           TEXT,
+    operator_id    INTEGER,
+    operator_name  TEXT,
+    created_at     INTEGER NOT NULL
+);
+CREATE INDEX idx_member_credit_txn_member ON member_credit_txn(member_id, created_at);
+-- One debit and at most one refund per order payment
+CREATE UNIQUE INDEX idx_member_credit_txn_payment ON member_credit_txn(payment_id, kind)
+    WHERE payment_id IS NOT NULL;
@@ -0,0 +1,70 @@
+-- Event bookings (宴会预订): proforma orders prepared days ahead of large events,
+-- materialized into a real order on the event day
+CREATE TABLE event_booking (
+    id                INTEGER PRIMARY KEY,
+    event_name        TEXT    NOT NULL,
+    customer_name     TEXT    NOT NULL,
+    customer_phone    TEXT,
+    member_id         INTEGER REFERENCES member(id),
+    event_start       INTEGER NOT NULL,     -- Unix millis
+    headcount         INTEGER NOT NULL,
+    table_id          INTEGER,
+    table_name        TEXT,
+    zone_id           INTEGER,
+    zone_name         TEXT,
+    note              TEXT,
+    status            TEXT    NOT NULL DEFAULT 'DRAFT',  -- DRAFT / CONFIRMED / MATERIALIZED / CANCELLED
+    revision          INTEGER NOT NULL DEFAULT 1,
+    estimated_total   REAL    NOT NULL DEFAULT 0,
+    deposit_total     REAL    NOT NULL DEFAULT 0,
+    order_id          INTEGER,                           -- active order created on the event day
+    materialized_at   INTEGER,
+    materialize_error TEXT,                              -- last automatic materialization failure
+    created_by_id     INTEGER NOT NULL,
+    created_by_name   TEXT    NOT NULL,
+    created_at        INTEGER NOT NULL,
+    updated_at        INTEGER NOT NULL
+);
+CREATE INDEX idx_event_booking_start ON event_booking(status, event_start);
+
+-- Menu selections (agreed unit prices)
+CREATE TABLE event_booking_item (
+    id                     INTEGER PRIMARY KEY,
+    booking_id             INTEGER NOT NULL REFERENCES event_booking(id) ON DELETE CASCADE,
+    product_id             INTEGER NOT NULL,
+    name                   TEXT    NOT NULL,
+    price                  REAL    NOT NULL,
+    quantity               INTEGER NOT NULL,
+    selected_options       TEXT,                         -- JSON array of ItemOption
+    selected_specification TEXT,                         -- JSON SpecificationInfo
+    course                 TEXT,                         -- serving course (BEO grouping)
+    note                   TEXT,
+    sort_order             INTEGER NOT NULL DEFAULT 0
+);
+CREATE INDEX idx_event_booking_item_booking ON event_booking_item(booking_id);
+
+-- Deposits collected before the event (carried to the order as payments)
+CREATE TABLE event_booking_deposit (
+    id            INTEGER PRIMARY KEY,
+    booking_id    INTEGER NOT NULL REFERENCES event_booking(id) ON DELETE CASCADE,
+    method        TEXT    NOT NULL,
+    amount        REAL    NOT NULL,
+    reference     TEXT,
+    note          TEXT,
+    operator_id   INTEGER NOT NULL,
+    operator_name TEXT    NOT NULL,
+    created_at    INTEGER NOT NULL
+);
+CREATE INDEX idx_event_booking_deposit_booking ON event_booking_deposit(booking_id);
+
+-- Amendment history: one row per revision change
+CREATE TABLE event_booking_amendment (
+    id            INTEGER PRIMARY KEY,
+    booking_id    INTEGER NOT NULL REFERENCES event_booking(id) ON DELETE CASCADE,
+    revision      INTEGER NOT NULL,
+    changes       TEXT    NOT NULL,                      -- JSON: field diffs + item changes
+    operator_id   INTEGER NOT NULL,
+    operator_name TEXT    NOT NULL,
+    created_at    INTEGER NOT NULL
+);
+CREATE INDEX idx_event_booking_amendment_booking ON event_booking_amendment(booking_id, revision);
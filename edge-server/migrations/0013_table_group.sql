@@ -0,0 +1,21 @@
+-- Joined tables (拼桌): several physical tables combined into one logical table
+-- for a large party. The order stays bound to the host table; the other tables
+-- cannot open their own orders until the group is split.
+CREATE TABLE table_group (
+    id              INTEGER PRIMARY KEY,
+    host_table_id   INTEGER NOT NULL UNIQUE REFERENCES dining_table(id),
+    zone_id         INTEGER NOT NULL,
+    name            TEXT    NOT NULL,     -- display name, e.g. "5+6"
+    created_by_id   INTEGER NOT NULL,
+    created_by_name TEXT    NOT NULL,
+    created_at      INTEGER NOT NULL,
+    updated_at      INTEGER NOT NULL
+);
+
+-- Membership (host included); a table belongs to at most one group
+CREATE TABLE table_group_member (
+    table_id   INTEGER PRIMARY KEY REFERENCES dining_table(id),
+    group_id   INTEGER NOT NULL REFERENCES table_group(id) ON DELETE CASCADE,
+    sort_order INTEGER NOT NULL DEFAULT 0
+);
+CREATE INDEX idx_table_group_member_group ON table_group_member(group_id);
@@ -0,0 +1,15 @@
+-- Breaks within a shift (班次休息): at most one open break (end_time NULL) per shift.
+-- PAID breaks count as worked time, UNPAID breaks are deducted in labor reports.
+CREATE TABLE shift_break (
+    id          INTEGER PRIMARY KEY,
+    shift_id    INTEGER NOT NULL REFERENCES shift(id),
+    break_type  TEXT    NOT NULL DEFAULT 'UNPAID',
+    start_time  INTEGER NOT NULL,
+    end_time    INTEGER,
+    note        TEXT
+);
+CREATE INDEX idx_shift_break_shift ON shift_break(shift_id);
+
+-- Break totals per shift in the daily report (millis)
+ALTER TABLE daily_report_shift_breakdown ADD COLUMN paid_break_ms INTEGER NOT NULL DEFAULT 0;
+ALTER TABLE daily_report_shift_breakdown ADD COLUMN unpaid_break_ms INTEGER NOT NULL DEFAULT 0;
@@ -0,0 +1,27 @@
+-- Staff announcements (员工公告): broadcast to all terminals or selected ones.
+CREATE TABLE announcement (
+    id          INTEGER PRIMARY KEY,
+    message     TEXT    NOT NULL,
+    priority    TEXT    NOT NULL DEFAULT 'NORMAL',
+    sender_id   INTEGER NOT NULL,
+    sender_name TEXT    NOT NULL,
+    created_at  INTEGER NOT NULL
+);
+CREATE INDEX idx_announcement_created ON announcement(created_at);
+
+-- Target terminal names; no rows = all terminals
+CREATE TABLE announcement_target (
+    announcement_id INTEGER NOT NULL REFERENCES announcement(id),
+    terminal        TEXT    NOT NULL,
+    PRIMARY KEY (announcement_id, terminal)
+);
+
+-- One acknowledgement per employee
+CREATE TABLE announcement_ack (
+    announcement_id INTEGER NOT NULL REFERENCES announcement(id),
+    employee_id     INTEGER NOT NULL,
+    employee_name   TEXT    NOT NULL,
+    terminal        TEXT,
+    acked_at        INTEGER NOT NULL,
+    PRIMARY KEY (announcement_id, employee_id)
+);
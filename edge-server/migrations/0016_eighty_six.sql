@@ -0,0 +1,18 @@
+-- 86 board (临时沽清): products/specs temporarily unavailable.
+-- Rows are never deleted: cleared/expired entries stay for the manager review.
+CREATE TABLE eighty_six (
+    id              INTEGER PRIMARY KEY,
+    product_id      INTEGER NOT NULL,
+    product_name    TEXT    NOT NULL,
+    spec_id         INTEGER,              -- NULL = whole product
+    spec_name       TEXT,
+    reason          TEXT,
+    expires_at      INTEGER,              -- NULL = until cleared
+    created_by_id   INTEGER NOT NULL,
+    created_by_name TEXT    NOT NULL,
+    created_at      INTEGER NOT NULL,
+    cleared_at      INTEGER,
+    cleared_by_name TEXT
+);
+CREATE INDEX idx_eighty_six_created ON eighty_six(created_at);
+CREATE INDEX idx_eighty_six_open ON eighty_six(product_id) WHERE cleared_at IS NULL;
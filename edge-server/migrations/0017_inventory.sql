@@ -0,0 +1,36 @@
+-- Inventory (库存): stock levels per product or spec, with an append-only
+-- adjustment trail. Only products with a stock_level row are tracked;
+-- spec_id NULL = whole product (covers specs without their own row).
+CREATE TABLE stock_level (
+    id                  INTEGER PRIMARY KEY,
+    product_id          INTEGER NOT NULL,
+    product_name        TEXT    NOT NULL,
+    spec_id             INTEGER,
+    spec_name           TEXT,
+    quantity            INTEGER NOT NULL DEFAULT 0,  -- may go negative (sales are never blocked)
+    low_stock_threshold INTEGER,                     -- NULL = no low-stock alert
+    created_at          INTEGER NOT NULL,
+    updated_at          INTEGER NOT NULL
+);
+CREATE UNIQUE INDEX idx_stock_level_product_spec
+    ON stock_level(product_id, COALESCE(spec_id, 0));
+
+CREATE TABLE stock_adjustment (
+    id              INTEGER PRIMARY KEY,
+    stock_level_id  INTEGER NOT NULL REFERENCES stock_level(id) ON DELETE CASCADE,
+    kind            TEXT    NOT NULL,               -- SALE | MANUAL
+    delta           INTEGER NOT NULL,
+    quantity_after  INTEGER NOT NULL,
+    order_id        INTEGER,                        -- SALE: completed order
+    note            TEXT,
+    operator_id     INTEGER,
+    operator_name   TEXT,
+    created_at      INTEGER NOT NULL
+);
+CREATE INDEX idx_stock_adjustment_level ON stock_adjustment(stock_level_id, created_at);
+CREATE INDEX idx_stock_adjustment_order ON stock_adjustment(order_id) WHERE order_id IS NOT NULL;
+
+-- Modifier-level inventory: an attribute option (extra cheese, side swap)
+-- may consume a stock level on sale. Edge-local; stock isn't cloud-synced.
+ALTER TABLE attribute_option
+    ADD COLUMN stock_level_id INTEGER REFERENCES stock_level(id) ON DELETE SET NULL;
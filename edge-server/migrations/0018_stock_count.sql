@@ -0,0 +1,35 @@
+-- Stock takes (盘点): theoretical quantities are frozen when a session
+-- starts, counted values are recorded by hand or by scanning a level's
+-- barcode, and a manager's approval posts counted − theoretical as COUNT
+-- adjustments. Sessions are never deleted (accountant history).
+ALTER TABLE stock_level ADD COLUMN barcode TEXT;
+CREATE UNIQUE INDEX idx_stock_level_barcode ON stock_level(barcode) WHERE barcode IS NOT NULL;
+
+CREATE TABLE stock_count (
+    id               INTEGER PRIMARY KEY,
+    status           TEXT    NOT NULL,               -- OPEN | SUBMITTED | APPROVED | REJECTED
+    note             TEXT,
+    created_by_id    INTEGER NOT NULL,
+    created_by_name  TEXT    NOT NULL,
+    created_at       INTEGER NOT NULL,
+    submitted_at     INTEGER,
+    reviewed_by_id   INTEGER,
+    reviewed_by_name TEXT,
+    reviewed_at      INTEGER,
+    review_note      TEXT
+);
+CREATE INDEX idx_stock_count_created ON stock_count(created_at);
+
+CREATE TABLE stock_count_line (
+    id                   INTEGER PRIMARY KEY,
+    stock_count_id       INTEGER NOT NULL REFERENCES stock_count(id),
+    stock_level_id       INTEGER NOT NULL,           -- no FK: history outlives the level
+    product_name         TEXT    NOT NULL,
+    spec_name            TEXT,
+    theoretical_quantity INTEGER NOT NULL,           -- frozen at session start
+    counted_quantity     INTEGER,                    -- NULL = not counted (left unchanged)
+    counted_at           INTEGER
+);
+CREATE UNIQUE INDEX idx_stock_count_line_level ON stock_count_line(stock_count_id, stock_level_id);
+
+ALTER TABLE stock_adjustment ADD COLUMN stock_count_id INTEGER;  -- COUNT: approved session
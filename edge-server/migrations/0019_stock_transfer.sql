@@ -0,0 +1,35 @@
+-- Inter-store stock transfers (门店间调拨)
+-- The sending store posts TRANSFER_OUT when goods leave; the document stays
+-- IN_TRANSIT until the receiving store confirms, which posts TRANSFER_IN there.
+-- Both stores keep a copy under the same id, relayed through crab-cloud.
+
+CREATE TABLE stock_transfer (
+    id               INTEGER PRIMARY KEY,            -- assigned by the sending store
+    direction        TEXT    NOT NULL,               -- OUT | IN
+    status           TEXT    NOT NULL,               -- IN_TRANSIT | RECEIVED
+    peer_store_id    INTEGER NOT NULL,               -- counterpart cloud store
+    peer_store_name  TEXT    NOT NULL,
+    note             TEXT,
+    sent_by          TEXT    NOT NULL,
+    sent_at          INTEGER NOT NULL,
+    received_by      TEXT,
+    received_at      INTEGER,
+    relay_pending    INTEGER NOT NULL DEFAULT 0      -- 1 = dispatch / receipt not yet sent to cloud
+);
+CREATE INDEX idx_stock_transfer_sent ON stock_transfer(sent_at);
+CREATE INDEX idx_stock_transfer_relay ON stock_transfer(relay_pending) WHERE relay_pending = 1;
+
+CREATE TABLE stock_transfer_line (
+    id                 INTEGER PRIMARY KEY,
+    stock_transfer_id  INTEGER NOT NULL REFERENCES stock_transfer(id),
+    line_no            INTEGER NOT NULL,
+    stock_level_id     INTEGER,                      -- no FK: history outlives the level
+    product_name       TEXT    NOT NULL,
+    spec_name          TEXT,
+    barcode            TEXT,
+    quantity           INTEGER NOT NULL,
+    received_quantity  INTEGER
+);
+CREATE UNIQUE INDEX idx_stock_transfer_line_no ON stock_transfer_line(stock_transfer_id, line_no);
+
+ALTER TABLE stock_adjustment ADD COLUMN stock_transfer_id INTEGER;  -- TRANSFER_OUT / TRANSFER_IN
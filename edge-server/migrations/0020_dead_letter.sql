@@ -0,0 +1,16 @@
+-- Dead-letter store (死信队列): bus messages whose processor errored,
+-- panicked or requested a retry. Inspected / redelivered by an admin.
+CREATE TABLE dead_letter (
+    id          INTEGER PRIMARY KEY,
+    event_type  TEXT    NOT NULL,
+    action      TEXT,                   -- RequestCommand action
+    request_id  TEXT    NOT NULL,
+    source      TEXT,
+    payload     TEXT,                   -- decoded JSON payload, NULL = undecodable
+    failure     TEXT    NOT NULL,       -- ERROR | PANIC | RETRY
+    error       TEXT    NOT NULL,
+    status      TEXT    NOT NULL DEFAULT 'PENDING', -- PENDING | REDELIVERED | DISCARDED
+    created_at  INTEGER NOT NULL,
+    resolved_at INTEGER
+);
+CREATE INDEX idx_dead_letter_status ON dead_letter(status, created_at);
@@ -0,0 +1,11 @@
+-- Report scope permissions (报表可见范围): reports:view now covers operational
+-- data only. Roles that manage settings (managers) keep full visibility.
+UPDATE role
+SET permissions = json_insert(permissions, '$[#]', 'reports:financials')
+WHERE EXISTS (SELECT 1 FROM json_each(role.permissions) WHERE value = 'settings:manage')
+  AND NOT EXISTS (SELECT 1 FROM json_each(role.permissions) WHERE value = 'reports:financials');
+
+UPDATE role
+SET permissions = json_insert(permissions, '$[#]', 'reports:labor')
+WHERE EXISTS (SELECT 1 FROM json_each(role.permissions) WHERE value = 'settings:manage')
+  AND NOT EXISTS (SELECT 1 FROM json_each(role.permissions) WHERE value = 'reports:labor');
@@ -0,0 +1,42 @@
+-- Cash-up denominations (点钞面额): one set per currency. The primary set is the
+-- store currency; secondary sets are converted with exchange_rate at count time.
+CREATE TABLE cash_denomination_set (
+    id              INTEGER PRIMARY KEY,
+    currency_code   TEXT    NOT NULL,
+    currency_symbol TEXT    NOT NULL DEFAULT '',
+    is_primary      INTEGER NOT NULL DEFAULT 0,
+    exchange_rate   REAL    NOT NULL DEFAULT 1.0,  -- primary units per 1 unit
+    is_active       INTEGER NOT NULL DEFAULT 1,
+    created_at      INTEGER NOT NULL,
+    updated_at      INTEGER NOT NULL
+);
+CREATE UNIQUE INDEX idx_cash_denomination_set_currency ON cash_denomination_set(currency_code);
+CREATE UNIQUE INDEX idx_cash_denomination_set_primary ON cash_denomination_set(is_primary) WHERE is_primary = 1;
+
+CREATE TABLE cash_denomination (
+    id      INTEGER PRIMARY KEY,
+    set_id  INTEGER NOT NULL REFERENCES cash_denomination_set(id),
+    kind    TEXT    NOT NULL,                       -- COIN | NOTE
+    value   REAL    NOT NULL
+);
+CREATE UNIQUE INDEX idx_cash_denomination_value ON cash_denomination(set_id, value);
+
+-- Counted denominations at shift close (kept for the cash variance review)
+CREATE TABLE shift_cash_count (
+    shift_id      INTEGER NOT NULL REFERENCES shift(id),
+    currency_code TEXT    NOT NULL,
+    value         REAL    NOT NULL,
+    quantity      INTEGER NOT NULL,
+    exchange_rate REAL    NOT NULL,
+    PRIMARY KEY (shift_id, currency_code, value)
+);
+
+-- Default: euro coins and notes
+INSERT INTO cash_denomination_set (id, currency_code, currency_symbol, is_primary, exchange_rate, is_active, created_at, updated_at)
+VALUES (1, 'EUR', '€', 1, 1.0, 1, 0, 0);
+
+INSERT INTO cash_denomination (id, set_id, kind, value) VALUES
+    (1, 1, 'COIN', 0.01), (2, 1, 'COIN', 0.02), (3, 1, 'COIN', 0.05), (4, 1, 'COIN', 0.10),
+    (5, 1, 'COIN', 0.20), (6, 1, 'COIN', 0.50), (7, 1, 'COIN', 1.00), (8, 1, 'COIN', 2.00),
+    (9, 1, 'NOTE', 5.00), (10, 1, 'NOTE', 10.00), (11, 1, 'NOTE', 20.00), (12, 1, 'NOTE', 50.00),
+    (13, 1, 'NOTE', 100.00), (14, 1, 'NOTE', 200.00), (15, 1, 'NOTE', 500.00);
@@ -0,0 +1,4 @@
+-- Per-order integrator metadata (外卖平台单号、酒店房号等)
+-- JSON object of string → string; NULL when the order has none.
+-- Kept on the summary row so it survives detail cleanup and stays searchable.
+ALTER TABLE archived_order ADD COLUMN metadata TEXT;
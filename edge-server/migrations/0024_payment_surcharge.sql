@@ -0,0 +1,23 @@
+-- Payment-method surcharges (支付方式附加费, e.g. card surcharge where legal).
+-- One rule per method; applied at AddPayment only while the store toggle is on.
+CREATE TABLE payment_surcharge_rule (
+    id              INTEGER PRIMARY KEY,
+    payment_method  TEXT    NOT NULL,
+    adjustment_type TEXT    NOT NULL,               -- PERCENTAGE | FIXED_AMOUNT
+    value           REAL    NOT NULL,
+    min_ticket      REAL,                           -- ticket total threshold (NULL = always)
+    is_active       INTEGER NOT NULL DEFAULT 1,
+    created_at      INTEGER NOT NULL,
+    updated_at      INTEGER NOT NULL
+);
+CREATE UNIQUE INDEX idx_payment_surcharge_rule_method ON payment_surcharge_rule(payment_method);
+
+-- Store toggle + legal disclaimer printed on receipts carrying a surcharge
+ALTER TABLE store_info ADD COLUMN payment_surcharge_enabled INTEGER NOT NULL DEFAULT 0;
+ALTER TABLE store_info ADD COLUMN payment_surcharge_disclaimer TEXT;
+
+-- Surcharge portion of each archived payment (included in amount)
+ALTER TABLE archived_order_payment ADD COLUMN surcharge REAL;
+
+-- Daily report breakout
+ALTER TABLE daily_report ADD COLUMN payment_surcharge_amount REAL NOT NULL DEFAULT 0.0;
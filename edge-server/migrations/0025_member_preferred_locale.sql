@@ -0,0 +1,2 @@
+-- 会员收据语言偏好 (打印收据时优先于门店 receipt_locale)
+ALTER TABLE member ADD COLUMN preferred_locale TEXT;
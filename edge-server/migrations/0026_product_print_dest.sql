@@ -0,0 +1,8 @@
+-- Product -> print_destination overrides (unified, like category_print_dest).
+-- No rows for a purpose = inherit the category destinations.
+CREATE TABLE product_print_dest (
+    product_id           INTEGER NOT NULL REFERENCES product(id) ON DELETE CASCADE,
+    print_destination_id INTEGER NOT NULL REFERENCES print_destination(id) ON DELETE CASCADE,
+    PRIMARY KEY (product_id, print_destination_id)
+);
+CREATE INDEX idx_product_print_dest_dest ON product_print_dest(print_destination_id);
@@ -0,0 +1,24 @@
+-- Takeaway pickup tickets (取餐码 + 出餐短信/WhatsApp 通知)
+CREATE TABLE pickup_ticket (
+    id                 INTEGER PRIMARY KEY,
+    order_id           INTEGER NOT NULL,
+    receipt_number     TEXT    NOT NULL,
+    queue_number       INTEGER,
+    pickup_code        TEXT    NOT NULL,
+    phone              TEXT,
+    channel            TEXT    NOT NULL DEFAULT 'SMS',   -- SMS | WHATSAPP
+    status             TEXT    NOT NULL DEFAULT 'WAITING', -- WAITING | READY | COLLECTED
+    delivery_status    TEXT,                             -- SENT | DELIVERED | FAILED
+    attempts           INTEGER NOT NULL DEFAULT 0,
+    gateway_message_id TEXT,
+    last_error         TEXT,
+    created_at         INTEGER NOT NULL,
+    ready_at           INTEGER,
+    notified_at        INTEGER,
+    delivered_at       INTEGER,
+    collected_at       INTEGER,
+    updated_at         INTEGER NOT NULL
+);
+CREATE UNIQUE INDEX idx_pickup_ticket_order ON pickup_ticket(order_id);
+CREATE UNIQUE INDEX idx_pickup_ticket_code ON pickup_ticket(pickup_code);
+CREATE INDEX idx_pickup_ticket_message ON pickup_ticket(gateway_message_id);
@@ -0,0 +1,14 @@
+-- Durable critical notifications (关键通知落库，离线终端重连后补发)
+CREATE TABLE durable_notification (
+    id         INTEGER PRIMARY KEY AUTOINCREMENT,  -- 单调递增 = 投递游标
+    payload    TEXT    NOT NULL,                   -- NotificationPayload JSON
+    created_at INTEGER NOT NULL
+);
+CREATE INDEX idx_durable_notification_created ON durable_notification(created_at);
+
+-- Per-client delivery cursor (客户端身份 → 已确认投递的最大通知 id)
+CREATE TABLE notification_cursor (
+    client     TEXT    PRIMARY KEY,
+    last_id    INTEGER NOT NULL,
+    updated_at INTEGER NOT NULL
+);
@@ -0,0 +1,21 @@
+-- Stamp activity campaign windows (集章活动档期 / 每日上限)
+ALTER TABLE stamp_activity ADD COLUMN valid_from INTEGER;
+ALTER TABLE stamp_activity ADD COLUMN valid_until INTEGER;
+ALTER TABLE stamp_activity ADD COLUMN active_days TEXT;          -- JSON [0..6], 0 = Sunday
+ALTER TABLE stamp_activity ADD COLUMN active_start_time TEXT;    -- HH:MM
+ALTER TABLE stamp_activity ADD COLUMN active_end_time TEXT;      -- HH:MM
+ALTER TABLE stamp_activity ADD COLUMN max_stamps_per_day INTEGER; -- 每会员每营业日上限
+
+-- Stamp ledger (集章流水：每日上限判定 + 活动效果统计)
+CREATE TABLE stamp_ledger (
+    id                INTEGER PRIMARY KEY AUTOINCREMENT,
+    member_id         INTEGER NOT NULL,
+    stamp_activity_id INTEGER NOT NULL,
+    order_id          INTEGER,
+    kind              TEXT    NOT NULL,            -- EARN | REDEEM
+    stamps            INTEGER NOT NULL,
+    capped            INTEGER NOT NULL DEFAULT 0,  -- 因每日上限未发放的章数
+    created_at        INTEGER NOT NULL
+);
+CREATE INDEX idx_stamp_ledger_member ON stamp_ledger(member_id, stamp_activity_id, created_at);
+CREATE INDEX idx_stamp_ledger_activity ON stamp_ledger(stamp_activity_id, created_at);
@@ -0,0 +1,2 @@
+-- Campaign ROI report: stamp redemptions are joined to archived orders by order_id
+CREATE INDEX idx_stamp_ledger_order ON stamp_ledger(order_id, kind);
@@ -0,0 +1,11 @@
+-- Product pairings (关联推荐: 点了 X 的顾客也点了 Y)
+-- Recomputed nightly from archived orders; the whole table is replaced on each run.
+CREATE TABLE product_pairing (
+    product_id        INTEGER NOT NULL,
+    paired_product_id INTEGER NOT NULL,
+    support           INTEGER NOT NULL,  -- 同时出现的订单数
+    confidence        REAL    NOT NULL,  -- support / 含 X 的订单数
+    lift              REAL    NOT NULL,  -- confidence / Y 的整体出现率
+    computed_at       INTEGER NOT NULL,
+    PRIMARY KEY (product_id, paired_product_id)
+);
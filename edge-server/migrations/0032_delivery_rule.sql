@@ -0,0 +1,15 @@
+-- Delivery channel rules (外卖渠道起送价 / 配送费阶梯)
+-- Delivery orders: TAKEOUT orders with metadata "platform" (channel) and optional "delivery_zone".
+-- One rule per channel + zone; zone NULL = channel default.
+CREATE TABLE delivery_rule (
+    id          INTEGER PRIMARY KEY,
+    channel     TEXT    NOT NULL,
+    zone        TEXT,
+    min_order   REAL,                           -- NULL = no minimum
+    fee_tiers   TEXT    NOT NULL DEFAULT '[]',  -- JSON [{min_subtotal, fee}]
+    is_active   INTEGER NOT NULL DEFAULT 1,
+    created_at  INTEGER NOT NULL,
+    updated_at  INTEGER NOT NULL
+);
+CREATE UNIQUE INDEX idx_delivery_rule_channel_zone
+    ON delivery_rule(channel COLLATE NOCASE, COALESCE(zone, '') COLLATE NOCASE);
@@ -0,0 +1,20 @@
+-- Receipt archive (收据存档): exact printed bytes of every checkout receipt.
+-- Files live under {work_dir}/receipts/{receipt_number}/ and are write-once;
+-- this table indexes them by receipt number and content hash.
+CREATE TABLE receipt_artifact (
+    id              INTEGER PRIMARY KEY,
+    receipt_number  TEXT    NOT NULL,
+    kind            TEXT    NOT NULL,               -- ESC_POS | PDF
+    sha256          TEXT    NOT NULL,
+    size_bytes      INTEGER NOT NULL,
+    reprint         INTEGER NOT NULL DEFAULT 0,
+    created_at      INTEGER NOT NULL,
+    cloud_synced    INTEGER NOT NULL DEFAULT 0
+);
+CREATE UNIQUE INDEX idx_receipt_artifact_content
+    ON receipt_artifact(receipt_number, kind, sha256);
+CREATE INDEX idx_receipt_artifact_cloud_synced ON receipt_artifact(cloud_synced);
+
+-- Store toggles
+ALTER TABLE store_info ADD COLUMN receipt_archive_enabled INTEGER NOT NULL DEFAULT 0;
+ALTER TABLE store_info ADD COLUMN receipt_archive_cloud INTEGER NOT NULL DEFAULT 0;
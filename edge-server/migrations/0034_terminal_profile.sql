@@ -0,0 +1,9 @@
+-- Terminal roaming profiles (终端漫游配置): device-local preferences keyed by
+-- the client certificate identity, returned in the message bus handshake so a
+-- replacement device restores its configuration at connect.
+CREATE TABLE terminal_profile (
+    identity    TEXT    PRIMARY KEY,
+    settings    TEXT    NOT NULL DEFAULT '{}',   -- JSON object
+    version     INTEGER NOT NULL DEFAULT 0,
+    updated_at  INTEGER NOT NULL
+);
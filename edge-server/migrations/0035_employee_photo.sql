@@ -0,0 +1,3 @@
+-- Employee face photo (防代打卡): SHA-256 of the JPEG stored under
+-- {work_dir}/employee_photos/, returned inline with login/escalation responses.
+ALTER TABLE employee ADD COLUMN photo TEXT;
@@ -0,0 +1,25 @@
+-- Reservations (订位): customer, party size and a time slot, optionally pinned
+-- to a zone or a table. Active (BOOKED / SEATED) slots on the same table must
+-- not overlap; zone-only reservations are checked against the zone's seats.
+CREATE TABLE reservation (
+    id              INTEGER PRIMARY KEY,
+    customer_name   TEXT    NOT NULL,
+    customer_phone  TEXT,
+    customer_email  TEXT,
+    party_size      INTEGER NOT NULL,
+    start_at        INTEGER NOT NULL,                 -- Unix millis
+    end_at          INTEGER NOT NULL,                 -- Unix millis, exclusive
+    zone_id         INTEGER,
+    zone_name       TEXT,
+    table_id        INTEGER,
+    table_name      TEXT,
+    note            TEXT,
+    status          TEXT    NOT NULL DEFAULT 'BOOKED', -- BOOKED / SEATED / NO_SHOW / CANCELLED
+    seated_at       INTEGER,
+    created_by_id   INTEGER NOT NULL,
+    created_by_name TEXT    NOT NULL,
+    created_at      INTEGER NOT NULL,
+    updated_at      INTEGER NOT NULL
+);
+CREATE INDEX idx_reservation_start ON reservation(start_at);
+CREATE INDEX idx_reservation_table ON reservation(table_id, start_at) WHERE table_id IS NOT NULL;
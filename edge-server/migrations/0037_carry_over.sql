@@ -0,0 +1,10 @@
+-- Business-day carry-over (跨营业日未结订单): what happens to orders still
+-- open at the cutoff — BLOCK | TRANSFER | FORCE_COMPLETE
+ALTER TABLE store_info ADD COLUMN carry_over_policy TEXT NOT NULL DEFAULT 'TRANSFER';
+
+-- Daily report: orders opened on an earlier day and completed on this one
+-- (carried in), and orders opened on this day still open at its end (carried out)
+ALTER TABLE daily_report ADD COLUMN carried_in_count INTEGER NOT NULL DEFAULT 0;
+ALTER TABLE daily_report ADD COLUMN carried_in_amount REAL NOT NULL DEFAULT 0.0;
+ALTER TABLE daily_report ADD COLUMN carried_out_count INTEGER NOT NULL DEFAULT 0;
+ALTER TABLE daily_report ADD COLUMN carried_out_amount REAL NOT NULL DEFAULT 0.0;
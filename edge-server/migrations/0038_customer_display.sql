@@ -0,0 +1,14 @@
+-- Customer displays (顾客显示屏): one pole display / secondary screen per POS
+-- terminal, keyed by the terminal's certificate identity and reached over TCP.
+CREATE TABLE customer_display (
+    id          INTEGER PRIMARY KEY,
+    identity    TEXT    NOT NULL UNIQUE,
+    name        TEXT    NOT NULL,
+    host        TEXT    NOT NULL,
+    port        INTEGER NOT NULL DEFAULT 9100,
+    columns     INTEGER NOT NULL DEFAULT 20,
+    idle_text   TEXT,
+    is_active   INTEGER NOT NULL DEFAULT 1,
+    created_at  INTEGER NOT NULL,
+    updated_at  INTEGER NOT NULL
+);
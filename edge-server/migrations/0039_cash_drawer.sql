@@ -0,0 +1,23 @@
+-- Cash drawer (钱箱): every drawer open (cash sale / no sale) with reason /
+-- operator / shift, plus paid-in / paid-out movements that adjust the shift's
+-- expected cash.
+CREATE TABLE cash_drawer_event (
+    id            INTEGER PRIMARY KEY,
+    kind          TEXT    NOT NULL,               -- SALE | NO_SALE | PAID_IN | PAID_OUT
+    amount        REAL    NOT NULL DEFAULT 0.0,   -- 0 for SALE / NO_SALE
+    reason        TEXT,
+    operator_id   INTEGER NOT NULL,
+    operator_name TEXT    NOT NULL,
+    shift_id      INTEGER,                        -- NULL outside shifts
+    created_at    INTEGER NOT NULL
+);
+CREATE INDEX idx_cash_drawer_event_shift ON cash_drawer_event(shift_id, created_at);
+CREATE INDEX idx_cash_drawer_event_created ON cash_drawer_event(created_at);
+
+ALTER TABLE daily_report ADD COLUMN no_sale_opens INTEGER NOT NULL DEFAULT 0;
+ALTER TABLE daily_report ADD COLUMN paid_in_amount REAL NOT NULL DEFAULT 0.0;
+ALTER TABLE daily_report ADD COLUMN paid_out_amount REAL NOT NULL DEFAULT 0.0;
+
+ALTER TABLE daily_report_shift_breakdown ADD COLUMN no_sale_opens INTEGER NOT NULL DEFAULT 0;
+ALTER TABLE daily_report_shift_breakdown ADD COLUMN paid_in_amount REAL NOT NULL DEFAULT 0.0;
+ALTER TABLE daily_report_shift_breakdown ADD COLUMN paid_out_amount REAL NOT NULL DEFAULT 0.0;
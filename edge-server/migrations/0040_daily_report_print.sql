@@ -0,0 +1,3 @@
+-- Auto-print a daily report summary at the business-day cutoff (日结小票自动打印)
+-- NULL = disabled; otherwise the print_destination that receives the summary
+ALTER TABLE store_info ADD COLUMN daily_report_print_destination_id INTEGER;
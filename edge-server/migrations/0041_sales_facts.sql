@@ -0,0 +1,56 @@
+-- Reporting read models (报表读模型): maintained by the projection service from
+-- the order event stream, independent of archived_order. Facts are replaced per
+-- order, so projecting the same order twice is harmless.
+CREATE TABLE sales_fact (
+    order_id       INTEGER PRIMARY KEY,
+    receipt_number TEXT    NOT NULL,
+    status         TEXT    NOT NULL,               -- COMPLETED | VOID
+    is_retail      INTEGER NOT NULL DEFAULT 0,
+    service_type   TEXT,                           -- DINE_IN | TAKEOUT
+    zone_name      TEXT,
+    table_name     TEXT,
+    guest_count    INTEGER NOT NULL DEFAULT 0,
+    member_id      INTEGER,
+    item_count     INTEGER NOT NULL DEFAULT 0,
+    original_total REAL    NOT NULL DEFAULT 0.0,
+    discount       REAL    NOT NULL DEFAULT 0.0,
+    surcharge      REAL    NOT NULL DEFAULT 0.0,
+    tax            REAL    NOT NULL DEFAULT 0.0,
+    total          REAL    NOT NULL DEFAULT 0.0,
+    paid_amount    REAL    NOT NULL DEFAULT 0.0,
+    operator_id    INTEGER NOT NULL,               -- operator of the terminal event
+    operator_name  TEXT    NOT NULL,
+    start_time     INTEGER NOT NULL,
+    end_time       INTEGER NOT NULL,
+    hour           INTEGER NOT NULL                -- local hour of end_time (store timezone)
+);
+CREATE INDEX idx_sales_fact_end_time ON sales_fact(end_time);
+
+CREATE TABLE item_fact (
+    order_id      INTEGER NOT NULL REFERENCES sales_fact(order_id) ON DELETE CASCADE,
+    instance_id   TEXT    NOT NULL,
+    product_id    INTEGER NOT NULL,
+    name          TEXT    NOT NULL,
+    spec_name     TEXT,
+    category_id   INTEGER,
+    category_name TEXT,
+    quantity      INTEGER NOT NULL,
+    unit_price    REAL    NOT NULL,
+    line_total    REAL    NOT NULL,
+    tax           REAL    NOT NULL DEFAULT 0.0,
+    tax_rate      INTEGER NOT NULL DEFAULT 0,
+    is_comped     INTEGER NOT NULL DEFAULT 0,
+    end_time      INTEGER NOT NULL,
+    PRIMARY KEY (order_id, instance_id)
+);
+CREATE INDEX idx_item_fact_end_time ON item_fact(end_time);
+
+CREATE TABLE payment_fact (
+    payment_id INTEGER PRIMARY KEY,
+    order_id   INTEGER NOT NULL REFERENCES sales_fact(order_id) ON DELETE CASCADE,
+    method     TEXT    NOT NULL,
+    amount     REAL    NOT NULL,
+    paid_at    INTEGER NOT NULL,
+    end_time   INTEGER NOT NULL
+);
+CREATE INDEX idx_payment_fact_end_time ON payment_fact(end_time);
@@ -0,0 +1,3 @@
+-- External fiscal device code (certified fiscal module registers the sale on completion)
+-- NULL when no fiscal device is configured or the store policy allowed completion without one.
+ALTER TABLE archived_order ADD COLUMN fiscal_code TEXT;
@@ -0,0 +1,15 @@
+-- First-run setup wizard: one row per confirmed (or skipped) step.
+-- Steps: STORE_INFO | TAX_RATES | TABLES | ADMIN | PRINTER
+CREATE TABLE setup_step (
+    step         TEXT    PRIMARY KEY,
+    skipped      INTEGER NOT NULL DEFAULT 0,
+    completed_by INTEGER,                        -- NULL for steps recorded by migration
+    completed_at INTEGER NOT NULL
+);
+
+-- Stores already in operation (named or with archived orders) skip the wizard.
+INSERT INTO setup_step (step, skipped, completed_at)
+SELECT column1, 0, CAST(strftime('%s', 'now') AS INTEGER) * 1000
+FROM (VALUES ('STORE_INFO'), ('TAX_RATES'), ('TABLES'), ('ADMIN'), ('PRINTER'))
+WHERE EXISTS (SELECT 1 FROM archived_order)
+   OR EXISTS (SELECT 1 FROM store_info WHERE name != '');
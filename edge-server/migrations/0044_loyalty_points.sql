@@ -0,0 +1,21 @@
+-- Spend-based loyalty points (积分): per-marketing-group burn rule
+-- points_earn_rate (already exists): points = floor(paid_amount × rate)
+-- Redemption: every points_redeem_unit points are worth points_redeem_value €
+ALTER TABLE marketing_group ADD COLUMN points_redeem_unit INTEGER;
+ALTER TABLE marketing_group ADD COLUMN points_redeem_value REAL;
+
+-- Points ledger: every balance change, signed points (+ earn, - redeem)
+CREATE TABLE member_points_txn (
+    id             INTEGER PRIMARY KEY,
+    member_id      INTEGER NOT NULL REFERENCES member(id),
+    kind           TEXT    NOT NULL,   -- EARN / REDEEM
+    points         INTEGER NOT NULL,
+    balance_after  INTEGER NOT NULL,
+    order_id       INTEGER,
+    amount         REAL    NOT NULL DEFAULT 0,   -- EARN: paid amount / REDEEM: discount value
+    created_at     INTEGER NOT NULL
+);
+CREATE INDEX idx_member_points_txn_member ON member_points_txn(member_id, created_at);
+-- One accrual and at most one redemption per order
+CREATE UNIQUE INDEX idx_member_points_txn_order ON member_points_txn(order_id, kind)
+    WHERE order_id IS NOT NULL;
@@ -0,0 +1,29 @@
+-- Coupon / promo codes (优惠码): created locally or pushed from crab-cloud
+-- max_uses: NULL = unlimited, 1 = single-use; used_count increments on order completion
+CREATE TABLE coupon (
+    id                INTEGER PRIMARY KEY,
+    code              TEXT    NOT NULL UNIQUE,   -- uppercase
+    name              TEXT    NOT NULL,
+    product_scope     TEXT    NOT NULL,          -- GLOBAL / CATEGORY / PRODUCT
+    target_id         INTEGER,
+    adjustment_type   TEXT    NOT NULL,          -- PERCENTAGE / FIXED_AMOUNT
+    adjustment_value  REAL    NOT NULL,
+    max_uses          INTEGER,
+    used_count        INTEGER NOT NULL DEFAULT 0,
+    valid_from        INTEGER,
+    valid_until       INTEGER,
+    is_active         INTEGER NOT NULL DEFAULT 1,
+    created_at        INTEGER NOT NULL,
+    updated_at        INTEGER NOT NULL
+);
+
+-- Redemption ledger: one row per completed order (settlement can be retried safely)
+CREATE TABLE coupon_redemption (
+    id          INTEGER PRIMARY KEY,
+    coupon_id   INTEGER NOT NULL REFERENCES coupon(id) ON DELETE CASCADE,
+    order_id    INTEGER NOT NULL,
+    amount      REAL    NOT NULL,
+    created_at  INTEGER NOT NULL,
+    UNIQUE (coupon_id, order_id)
+);
+CREATE INDEX idx_coupon_redemption_order ON coupon_redemption(order_id);
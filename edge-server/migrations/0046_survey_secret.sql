@@ -0,0 +1,3 @@
+-- Per-store key for receipt survey codes (收据调查码签名密钥)
+-- Generated on first use; shared with the survey site so it can verify codes
+ALTER TABLE store_info ADD COLUMN survey_secret TEXT;
@@ -0,0 +1,2 @@
+-- Drawer policy: maximum change a single cash tender may take out of the drawer (单笔现金找零上限)
+ALTER TABLE store_info ADD COLUMN max_cash_change REAL NOT NULL DEFAULT 200;
@@ -0,0 +1,12 @@
+//! Admin API Handlers
+
+use axum::{Json, extract::State};
+
+use crate::core::ServerState;
+use crate::core::profile::{self, ConfigInspection};
+use crate::utils::AppResult;
+
+/// GET /api/admin/config - 生效配置 (Profile、各项来源，密钥脱敏)
+pub async fn config(State(state): State<ServerState>) -> AppResult<Json<ConfigInspection>> {
+    Ok(Json(profile::inspect(&state.config)))
+}
@@ -0,0 +1,18 @@
+//! Admin API 模块 (运行配置检视)
+
+mod handler;
+
+use axum::{Router, middleware, routing::get};
+
+use crate::auth::require_permission;
+use crate::core::ServerState;
+
+pub fn router() -> Router<ServerState> {
+    Router::new()
+        .nest("/api/admin", routes())
+        .layer(middleware::from_fn(require_permission("settings:manage")))
+}
+
+fn routes() -> Router<ServerState> {
+    Router::new().route("/config", get(handler::config))
+}
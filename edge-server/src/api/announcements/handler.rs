@@ -0,0 +1,136 @@
+//! Announcement API Handlers
+
+use axum::{
+    Json,
+    extract::{Extension, Path, Query, State},
+};
+use serde::Deserialize;
+
+use crate::audit::{AuditAction, create_snapshot};
+use crate::audit_log;
+use crate::auth::CurrentUser;
+use crate::core::ServerState;
+use crate::db::repository::announcement as announcement_repo;
+use crate::utils::AppResult;
+use crate::utils::validation::{MAX_NAME_LEN, MAX_NOTE_LEN, validate_required_text};
+use shared::message::BusMessage;
+use shared::models::{Announcement, AnnouncementAckInput, AnnouncementCreate, ConnectedTerminal};
+
+/// 默认返回最近公告条数
+const DEFAULT_RECENT_LIMIT: i64 = 50;
+const MAX_RECENT_LIMIT: i64 = 200;
+
+#[derive(Debug, Deserialize)]
+pub struct RecentQuery {
+    pub limit: Option<i64>,
+}
+
+/// 已连接终端 (名称取自客户端证书，缺失时用连接 ID)
+fn connected_terminals(state: &ServerState) -> Vec<ConnectedTerminal> {
+    state
+        .message_bus()
+        .get_connected_clients()
+        .into_iter()
+        .map(|c| ConnectedTerminal {
+            name: c.peer_identity.unwrap_or_else(|| c.id.clone()),
+            client_id: c.id,
+            addr: c.addr,
+        })
+        .collect()
+}
+
+/// 推送公告：无目标时广播，否则逐个单播给匹配的已连接终端
+///
+/// 主机终端 (服务端进程内) 只接收广播公告。
+async fn dispatch(state: &ServerState, announcement: &Announcement) {
+    let msg = BusMessage::announcement(announcement);
+    let bus = state.message_bus();
+    if announcement.targets.is_empty() {
+        if let Err(e) = bus.publish(msg).await {
+            tracing::debug!(
+                id = announcement.id,
+                "Announcement broadcast skipped: {}",
+                e
+            );
+        }
+        return;
+    }
+    for terminal in connected_terminals(state)
+        .into_iter()
+        .filter(|t| announcement.is_for(&t.name))
+    {
+        let msg = BusMessage::announcement(announcement).with_target(&terminal.client_id);
+        if let Err(e) = bus.publish(msg).await {
+            tracing::debug!(id = announcement.id, terminal = %terminal.name, "Announcement unicast skipped: {}", e);
+        }
+    }
+}
+
+/// GET /api/announcements - 最近公告 (含确认记录)
+pub async fn list_recent(
+    State(state): State<ServerState>,
+    Query(query): Query<RecentQuery>,
+) -> AppResult<Json<Vec<Announcement>>> {
+    let limit = query
+        .limit
+        .unwrap_or(DEFAULT_RECENT_LIMIT)
+        .clamp(1, MAX_RECENT_LIMIT);
+    let announcements = announcement_repo::find_recent(&state.pool, limit).await?;
+    Ok(Json(announcements))
+}
+
+/// GET /api/announcements/terminals - 可选的目标终端
+pub async fn list_terminals(
+    State(state): State<ServerState>,
+) -> AppResult<Json<Vec<ConnectedTerminal>>> {
+    let mut terminals = connected_terminals(&state);
+    terminals.sort_by(|a, b| a.name.cmp(&b.name));
+    Ok(Json(terminals))
+}
+
+/// POST /api/announcements - 发送公告
+pub async fn send(
+    State(state): State<ServerState>,
+    Extension(current_user): Extension<CurrentUser>,
+    Json(payload): Json<AnnouncementCreate>,
+) -> AppResult<Json<Announcement>> {
+    validate_required_text(&payload.message, "message", MAX_NOTE_LEN)?;
+    for terminal in &payload.targets {
+        validate_required_text(terminal, "target", MAX_NAME_LEN)?;
+    }
+
+    let announcement =
+        announcement_repo::create(&state.pool, payload, current_user.id, &current_user.name)
+            .await?;
+
+    audit_log!(
+        state.audit_service,
+        AuditAction::AnnouncementSent,
+        "announcement",
+        &announcement.id.to_string(),
+        operator_id = Some(current_user.id),
+        operator_name = Some(current_user.name.clone()),
+        details = create_snapshot(&announcement, "announcement")
+    );
+
+    dispatch(&state, &announcement).await;
+    Ok(Json(announcement))
+}
+
+/// POST /api/announcements/:id/ack - 当前员工确认已读
+pub async fn acknowledge(
+    State(state): State<ServerState>,
+    Extension(current_user): Extension<CurrentUser>,
+    Path(id): Path<i64>,
+    Json(payload): Json<AnnouncementAckInput>,
+) -> AppResult<Json<Announcement>> {
+    let announcement = announcement_repo::acknowledge(
+        &state.pool,
+        id,
+        current_user.id,
+        &current_user.name,
+        payload.terminal.as_deref(),
+    )
+    .await?;
+    Ok(Json(announcement))
+}
@@ -0,0 +1,35 @@
+//! Announcement API Module
+//!
+//! 员工公告 — 经理向全部/指定终端广播，员工确认已读
+
+mod handler;
+
+use axum::{
+    Router, middleware,
+    routing::{get, post},
+};
+
+use crate::auth::require_permission;
+use crate::core::ServerState;
+
+/// Announcement router
+pub fn router() -> Router<ServerState> {
+    Router::new().nest("/api/announcements", routes())
+}
+
+fn routes() -> Router<ServerState> {
+    // 员工路由：查看最近公告、确认已读
+    let staff_routes = Router::new()
+        .route("/", get(handler::list_recent))
+        .route("/{id}/ack", post(handler::acknowledge));
+
+    // 发送路由：需要 announcements:send 权限
+    let send_routes = Router::new()
+        .route("/", post(handler::send))
+        .route("/terminals", get(handler::list_terminals))
+        .layer(middleware::from_fn(require_permission(
+            "announcements:send",
+        )));
+
+    staff_routes.merge(send_routes)
+}
@@ -9,7 +9,7 @@ use crate::audit::{AuditAction, create_diff, create_snapshot};
 use crate::audit_log;
 use crate::auth::CurrentUser;
 use crate::core::ServerState;
-use crate::db::repository::attribute;
+use crate::db::repository::{attribute, inventory};
 use crate::utils::validation::{
     MAX_NAME_LEN, MAX_RECEIPT_NAME_LEN, validate_optional_text, validate_required_text,
 };
@@ -75,6 +75,21 @@ fn validate_update(payload: &AttributeUpdate) -> AppResult<()> {
     Ok(())
 }
 
+/// Ensure the option's stock link (if any) points at an existing stock level
+async fn validate_stock_link(state: &ServerState, opt: &AttributeOptionInput) -> AppResult<()> {
+    if let Some(stock_level_id) = opt.stock_level_id
+        && inventory::find_by_id(&state.pool, stock_level_id)
+            .await?
+            .is_none()
+    {
+        return Err(AppError::not_found(format!(
+            "Stock level {}",
+            stock_level_id
+        )));
+    }
+    Ok(())
+}
+
 /// Validate an option input before saving
 fn validate_option(opt: &AttributeOptionInput) -> AppResult<()> {
     validate_required_text(&opt.name, "option name", MAX_NAME_LEN)?;
@@ -269,6 +284,7 @@ pub async fn add_option(
     Json(option): Json<AttributeOptionInput>,
 ) -> AppResult<Json<Attribute>> {
     validate_option(&option)?;
+    validate_stock_link(&state, &option).await?;
 
     // 读取当前属性，将新选项追加到现有选项列表后，整体替换
     let current = attribute::find_by_id(&state.pool, id)
@@ -284,6 +300,7 @@ pub async fn add_option(
         .options
         .iter()
         .map(|o| AttributeOptionInput {
+            id: Some(o.id),
             name: o.name.clone(),
             price_modifier: o.price_modifier,
             display_order: o.display_order,
@@ -291,6 +308,7 @@ pub async fn add_option(
             kitchen_print_name: o.kitchen_print_name.clone(),
             enable_quantity: o.enable_quantity,
             max_quantity: o.max_quantity,
+            stock_level_id: o.stock_level_id,
         })
         .collect();
 
@@ -334,6 +352,7 @@ pub async fn update_option(
     Json(option): Json<AttributeOptionInput>,
 ) -> AppResult<Json<Attribute>> {
     validate_option(&option)?;
+    validate_stock_link(&state, &option).await?;
 
     let current = attribute::find_by_id(&state.pool, id)
         .await?
@@ -348,6 +367,7 @@ pub async fn update_option(
         .options
         .iter()
         .map(|o| AttributeOptionInput {
+            id: Some(o.id),
             name: o.name.clone(),
             price_modifier: o.price_modifier,
             display_order: o.display_order,
@@ -355,6 +375,7 @@ pub async fn update_option(
             kitchen_print_name: o.kitchen_print_name.clone(),
             enable_quantity: o.enable_quantity,
             max_quantity: o.max_quantity,
+            stock_level_id: o.stock_level_id,
         })
         .collect();
 
@@ -367,7 +388,10 @@ pub async fn update_option(
     }
 
     let option_name = option.name.clone();
-    options[idx] = option;
+    options[idx] = AttributeOptionInput {
+        id: options[idx].id,
+        ..option
+    };
 
     let update_data = AttributeUpdate {
         options: Some(options),
@@ -419,6 +443,7 @@ pub async fn remove_option(
         .options
         .iter()
         .map(|o| AttributeOptionInput {
+            id: Some(o.id),
             name: o.name.clone(),
             price_modifier: o.price_modifier,
             display_order: o.display_order,
@@ -426,6 +451,7 @@ pub async fn remove_option(
             kitchen_print_name: o.kitchen_print_name.clone(),
             enable_quantity: o.enable_quantity,
             max_quantity: o.max_quantity,
+            stock_level_id: o.stock_level_id,
         })
         .collect();
 
@@ -11,6 +11,7 @@ use crate::audit::AuditAction;
 use crate::auth::CurrentUser;
 use crate::core::ServerState;
 use crate::db::repository::{employee, role};
+use crate::services::EmployeePhotoService;
 use shared::models::Role;
 
 // Re-use shared DTOs for API consistency
@@ -19,6 +20,13 @@ use shared::client::{EscalateRequest, EscalateResponse, LoginRequest, LoginRespo
 /// Fixed delay for authentication to prevent timing attacks
 const AUTH_FIXED_DELAY_MS: u64 = 500;
 
+/// Photo of the employee whose credentials were used (data URL, shown by the POS)
+async fn employee_photo(state: &ServerState, photo: Option<&str>) -> Option<String> {
+    EmployeePhotoService::new(state.config.employee_photo_dir())
+        .data_url(photo)
+        .await
+}
+
 /// Login handler
 ///
 /// Authenticates user credentials and returns a JWT token
@@ -125,6 +133,7 @@ pub async fn login(
         "User logged in successfully"
     );
 
+    let photo = employee_photo(&state, emp.photo.as_deref()).await;
     let response = LoginResponse {
         token,
         user: UserInfo {
@@ -137,6 +146,7 @@ pub async fn login(
             is_system: emp.is_system,
             is_active: emp.is_active,
             created_at: emp.created_at,
+            photo,
         },
     };
 
@@ -154,6 +164,7 @@ pub async fn me(
         .ok_or_else(|| AppError::new(shared::ErrorCode::EmployeeNotFound))?;
 
     let (is_active, created_at) = (emp.is_active, emp.created_at);
+    let photo = employee_photo(&state, emp.photo.as_deref()).await;
 
     let user_info = UserInfo {
         id: user.id,
@@ -165,6 +176,7 @@ pub async fn me(
         is_system: user.is_system,
         is_active,
         created_at,
+        photo,
     };
 
     Ok(Json(user_info))
@@ -310,6 +322,7 @@ pub async fn escalate(
         "Permission escalation successful"
     );
 
+    let photo = employee_photo(&state, emp.photo.as_deref()).await;
     let response = EscalateResponse {
         authorizer: UserInfo {
             id: emp.id,
@@ -321,6 +334,7 @@ pub async fn escalate(
             is_system: emp.is_system,
             is_active: emp.is_active,
             created_at: emp.created_at,
+            photo,
         },
     };
 
@@ -0,0 +1,189 @@
+//! Capabilities API Handlers
+
+use std::collections::BTreeMap;
+
+use axum::{Json, extract::State};
+use serde::Serialize;
+
+use crate::core::ServerState;
+use shared::activation::{PlanType, SubscriptionStatus};
+use shared::message::{
+    BusTopic, NotificationPayload, PROTOCOL_VERSION, PayloadCompression, PayloadEncoding,
+    RequestCommandPayload, ResponsePayload, ServerCommandPayload, SyncPayload,
+};
+use shared::models::TaxMode;
+use shared::order::{OrderEvent, OrderSnapshot};
+use shared::schema::SchemaVersioned;
+
+/// 本构建编译进的功能模块
+const MODULES: &[&str] = &[
+    "orders",
+    "kitchen_printing",
+    "members",
+    "marketing",
+    "shifts",
+    "daily_reports",
+    "event_bookings",
+    "reservations",
+    "announcements",
+    "statistics",
+    "invoicing",
+    "pickup",
+    "pms",
+    "data_transfer",
+    #[cfg(windows)]
+    "label_printing",
+    #[cfg(feature = "chaos")]
+    "chaos",
+];
+
+/// 功能集响应
+#[derive(Serialize)]
+pub struct CapabilitiesResponse {
+    version: &'static str,
+    git_hash: &'static str,
+    /// 编译进本构建的模块
+    modules: &'static [&'static str],
+    /// 已配置的外部集成 (pms / message_gateway / event_journal / public_status)
+    integrations: Vec<&'static str>,
+    /// 订阅计划权益 (未激活时为 None)
+    plan: Option<PlanEntitlements>,
+    protocol: ProtocolInfo,
+    fiscalization: FiscalizationInfo,
+}
+
+/// 订阅计划权益
+#[derive(Serialize)]
+pub struct PlanEntitlements {
+    plan: PlanType,
+    status: SubscriptionStatus,
+    /// 计划启用的功能
+    features: Vec<String>,
+    /// 0 = 无限
+    max_stores: u32,
+    /// 0 = 无限
+    max_clients: u32,
+    /// 订阅被阻止 (状态或签名陈旧)
+    blocked: bool,
+}
+
+/// 支持的协议范围
+#[derive(Serialize)]
+pub struct ProtocolInfo {
+    /// 消息总线协议版本 (握手版本必须在范围内)
+    message_bus: VersionRange,
+    /// 服务端 → 客户端载荷编码
+    encodings: Vec<PayloadEncoding>,
+    /// 服务端 → 客户端帧压缩 (超过阈值的载荷)
+    compression: Vec<PayloadCompression>,
+    /// 握手可订阅的主题
+    topics: Vec<BusTopic>,
+    /// 握手支持订阅过滤 (`HandshakePayload.filters`)
+    subscription_filters: bool,
+    /// 载荷 schema 版本 (客户端可读取 <= 该版本的文档)
+    schema_versions: BTreeMap<&'static str, u16>,
+}
+
+#[derive(Serialize)]
+pub struct VersionRange {
+    min: u16,
+    max: u16,
+}
+
+/// 税务申报模式
+#[derive(Serialize)]
+pub struct FiscalizationInfo {
+    /// verifactu | disabled (门店未配置 NIF)
+    mode: &'static str,
+    /// Verifactu 发票序列
+    #[serde(skip_serializing_if = "Option::is_none")]
+    serie: Option<String>,
+    /// P12 签名证书可用 (AEAT 提交需要)
+    signing_certificate: bool,
+    tax_mode: TaxMode,
+}
+
+/// GET /api/capabilities - 本边缘节点启用的功能集
+pub async fn get(State(state): State<ServerState>) -> Json<CapabilitiesResponse> {
+    let mut integrations = Vec::new();
+    if state.config.pms_url.is_some() {
+        integrations.push("pms");
+    }
+    if state.message_gateway.is_some() {
+        integrations.push("message_gateway");
+    }
+    if state.config.event_journal {
+        integrations.push("event_journal");
+    }
+    if state.config.public_status {
+        integrations.push("public_status");
+    }
+
+    let subscription = {
+        let cache = state.activation.credential_cache.read().await;
+        cache.as_ref().and_then(|cred| cred.subscription.clone())
+    };
+    let plan = match subscription {
+        Some(sub) => Some(PlanEntitlements {
+            plan: sub.plan,
+            status: sub.status,
+            features: sub.features,
+            max_stores: sub.max_stores,
+            max_clients: sub.max_clients,
+            blocked: state.is_subscription_blocked().await,
+        }),
+        None => None,
+    };
+
+    let schema_versions = BTreeMap::from([
+        ("order_event", OrderEvent::SCHEMA_VERSION),
+        ("order_snapshot", OrderSnapshot::SCHEMA_VERSION),
+        ("request_command", RequestCommandPayload::SCHEMA_VERSION),
+        ("response", ResponsePayload::SCHEMA_VERSION),
+        ("sync", SyncPayload::SCHEMA_VERSION),
+        ("notification", NotificationPayload::SCHEMA_VERSION),
+        ("server_command", ServerCommandPayload::SCHEMA_VERSION),
+    ]);
+
+    let serie = state
+        .orders_manager
+        .archive_service()
+        .and_then(|archive| archive.invoice_service())
+        .map(|invoice| invoice.serie().to_string());
+    let tax_mode = match crate::db::repository::store_info::get(&state.pool).await {
+        Ok(info) => info.map(|i| i.tax_mode).unwrap_or_default(),
+        Err(e) => {
+            tracing::warn!("Failed to load store info for capabilities: {}", e);
+            TaxMode::default()
+        }
+    };
+
+    Json(CapabilitiesResponse {
+        version: env!("CARGO_PKG_VERSION"),
+        git_hash: shared::GIT_HASH,
+        modules: MODULES,
+        integrations,
+        plan,
+        protocol: ProtocolInfo {
+            message_bus: VersionRange {
+                min: PROTOCOL_VERSION,
+                max: PROTOCOL_VERSION,
+            },
+            encodings: vec![PayloadEncoding::Json, PayloadEncoding::Postcard],
+            compression: PayloadCompression::SUPPORTED.to_vec(),
+            topics: BusTopic::CHANNELS.to_vec(),
+            subscription_filters: true,
+            schema_versions,
+        },
+        fiscalization: FiscalizationInfo {
+            mode: if serie.is_some() {
+                "verifactu"
+            } else {
+                "disabled"
+            },
+            serie,
+            signing_certificate: !state.is_p12_blocked().await,
+            tax_mode,
+        },
+    })
+}
@@ -0,0 +1,13 @@
+//! Capabilities API 模块 (功能发现)
+//!
+//! 前端和第三方集成按返回的功能集动态适配，不再硬编码版本判断。
+
+mod handler;
+
+use axum::{Router, routing::get};
+
+use crate::core::ServerState;
+
+pub fn router() -> Router<ServerState> {
+    Router::new().route("/api/capabilities", get(handler::get))
+}
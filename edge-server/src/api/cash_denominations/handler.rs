@@ -0,0 +1,114 @@
+//! Cash Denomination API Handlers
+
+use axum::{
+    Json,
+    extract::{Extension, Path, State},
+};
+
+use crate::audit::{AuditAction, create_diff, create_snapshot};
+use crate::audit_log;
+use crate::auth::CurrentUser;
+use crate::core::ServerState;
+use crate::db::repository::cash_denomination;
+use crate::utils::validation::validate_optional_text;
+use crate::utils::{AppError, AppResult};
+use shared::models::{
+    CashCountLine, CashCountSummary, CashDenominationSet, CashDenominationSetCreate,
+    CashDenominationSetUpdate,
+};
+
+/// 货币符号最大长度
+const MAX_SYMBOL_LEN: usize = 8;
+
+/// GET /api/cash-denominations - 全部面额组 (主币种在前)
+pub async fn list(State(state): State<ServerState>) -> AppResult<Json<Vec<CashDenominationSet>>> {
+    let sets = cash_denomination::find_all(&state.pool).await?;
+    Ok(Json(sets))
+}
+
+/// POST /api/cash-denominations/count - 点钞合计预览 (不落库)
+pub async fn count(
+    State(state): State<ServerState>,
+    Json(lines): Json<Vec<CashCountLine>>,
+) -> AppResult<Json<CashCountSummary>> {
+    let summary = cash_denomination::summarize(&state.pool, &lines).await?;
+    Ok(Json(summary))
+}
+
+/// POST /api/cash-denominations - 新增面额组
+pub async fn create(
+    State(state): State<ServerState>,
+    Extension(current_user): Extension<CurrentUser>,
+    Json(payload): Json<CashDenominationSetCreate>,
+) -> AppResult<Json<CashDenominationSet>> {
+    validate_optional_text(
+        &Some(payload.currency_symbol.clone()),
+        "currency_symbol",
+        MAX_SYMBOL_LEN,
+    )?;
+
+    let set = cash_denomination::create(&state.pool, &payload).await?;
+
+    audit_log!(
+        state.audit_service,
+        AuditAction::StoreInfoChanged,
+        "cash_denomination_set",
+        &set.id.to_string(),
+        operator_id = Some(current_user.id),
+        operator_name = Some(current_user.name.clone()),
+        details = create_snapshot(&set, "cash_denomination_set")
+    );
+
+    Ok(Json(set))
+}
+
+/// PUT /api/cash-denominations/:id - 更新面额组 (denominations 整体替换)
+pub async fn update(
+    State(state): State<ServerState>,
+    Extension(current_user): Extension<CurrentUser>,
+    Path(id): Path<i64>,
+    Json(payload): Json<CashDenominationSetUpdate>,
+) -> AppResult<Json<CashDenominationSet>> {
+    validate_optional_text(&payload.currency_symbol, "currency_symbol", MAX_SYMBOL_LEN)?;
+
+    let old_set = cash_denomination::find_by_id(&state.pool, id)
+        .await?
+        .ok_or_else(|| AppError::not_found(format!("Denomination set {}", id)))?;
+    let set = cash_denomination::update(&state.pool, id, &payload).await?;
+
+    audit_log!(
+        state.audit_service,
+        AuditAction::StoreInfoChanged,
+        "cash_denomination_set",
+        &id.to_string(),
+        operator_id = Some(current_user.id),
+        operator_name = Some(current_user.name.clone()),
+        details = create_diff(&old_set, &set, "cash_denomination_set")
+    );
+
+    Ok(Json(set))
+}
+
+/// DELETE /api/cash-denominations/:id - 删除副币种面额组
+pub async fn delete(
+    State(state): State<ServerState>,
+    Extension(current_user): Extension<CurrentUser>,
+    Path(id): Path<i64>,
+) -> AppResult<Json<bool>> {
+    let set = cash_denomination::find_by_id(&state.pool, id)
+        .await?
+        .ok_or_else(|| AppError::not_found(format!("Denomination set {}", id)))?;
+    cash_denomination::delete(&state.pool, id).await?;
+
+    audit_log!(
+        state.audit_service,
+        AuditAction::StoreInfoChanged,
+        "cash_denomination_set",
+        &id.to_string(),
+        operator_id = Some(current_user.id),
+        operator_name = Some(current_user.name.clone()),
+        details = serde_json::json!({"deleted": set.currency_code})
+    );
+
+    Ok(Json(true))
+}
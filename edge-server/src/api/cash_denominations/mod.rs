@@ -0,0 +1,34 @@
+//! Cash Denomination API Module (点钞面额)
+
+mod handler;
+
+use axum::{
+    Router, middleware,
+    routing::{get, post},
+};
+
+use crate::auth::require_permission;
+use crate::core::ServerState;
+
+/// Cash denomination router
+pub fn router() -> Router<ServerState> {
+    Router::new().nest("/api/cash-denominations", routes())
+}
+
+fn routes() -> Router<ServerState> {
+    // 读取路由：无需权限检查（收班点钞时加载面额并预览合计）
+    let read_routes = Router::new()
+        .route("/", get(handler::list))
+        .route("/count", post(handler::count));
+
+    // 写入路由：需要 settings:manage 权限
+    let write_routes = Router::new()
+        .route("/", post(handler::create))
+        .route(
+            "/{id}",
+            axum::routing::put(handler::update).delete(handler::delete),
+        )
+        .layer(middleware::from_fn(require_permission("settings:manage")));
+
+    read_routes.merge(write_routes)
+}
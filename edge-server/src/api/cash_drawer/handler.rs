@@ -0,0 +1,151 @@
+//! Cash Drawer API Handlers
+
+use axum::{
+    Json,
+    extract::{Extension, Path, Query, State},
+};
+use serde::Deserialize;
+
+use crate::audit::AuditAction;
+use crate::audit_log;
+use crate::auth::CurrentUser;
+use crate::core::ServerState;
+use crate::db::repository::{cash_drawer, shift};
+use crate::utils::time;
+use crate::utils::validation::{MAX_NOTE_LEN, validate_optional_text};
+use crate::utils::{AppError, AppResult};
+use shared::cloud::SyncResource;
+use shared::error::ErrorCode;
+use shared::message::SyncChangeType;
+use shared::models::{
+    CashDrawerEvent, CashDrawerEventCreate, CashDrawerEventKind, ShiftCashDrawer,
+};
+
+/// Query params for listing drawer events
+#[derive(Debug, Deserialize)]
+pub struct ListQuery {
+    pub shift_id: Option<i64>,
+    pub start_date: Option<String>,
+    pub end_date: Option<String>,
+}
+
+/// GET /api/cash-drawer/events - 钱箱记录（按班次或日期范围）
+pub async fn list(
+    State(state): State<ServerState>,
+    Query(query): Query<ListQuery>,
+) -> AppResult<Json<Vec<CashDrawerEvent>>> {
+    let events = match (query.shift_id, query.start_date, query.end_date) {
+        (Some(shift_id), _, _) => cash_drawer::find_by_shift(&state.pool, shift_id).await?,
+        (None, Some(start), Some(end)) => {
+            let tz = state.config.timezone;
+            let start_date = time::parse_date(&start)?;
+            let end_date = time::parse_date(&end)?;
+            cash_drawer::find_by_date_range(
+                &state.pool,
+                time::day_start_millis(start_date, tz),
+                time::day_end_millis(end_date, tz),
+            )
+            .await?
+        }
+        _ => {
+            return Err(AppError::validation(
+                "shift_id or start_date + end_date is required",
+            ));
+        }
+    };
+    Ok(Json(events))
+}
+
+/// GET /api/cash-drawer/shifts/:shift_id - 班次钱箱明细 + 汇总（收班对账）
+pub async fn get_shift(
+    State(state): State<ServerState>,
+    Path(shift_id): Path<i64>,
+) -> AppResult<Json<ShiftCashDrawer>> {
+    shift::find_by_id(&state.pool, shift_id)
+        .await?
+        .ok_or_else(|| {
+            AppError::with_message(
+                ErrorCode::ShiftNotFound,
+                format!("Shift {} not found", shift_id),
+            )
+        })?;
+    let summary = cash_drawer::summarize_shift(&state.pool, shift_id).await?;
+    let events = cash_drawer::find_by_shift(&state.pool, shift_id).await?;
+    Ok(Json(ShiftCashDrawer {
+        shift_id,
+        summary,
+        events,
+    }))
+}
+
+/// POST /api/cash-drawer/events - 记录钱箱开启 / 存入 / 取出
+///
+/// 终端打开钱箱后上报；存取现金计入当前班次的预期现金。
+/// 现金收款开箱 (SALE) 只记录不审计，收款本身已由订单事件覆盖；
+/// 其余类型需要 `cash_drawer:open` 权限。
+pub async fn record(
+    State(state): State<ServerState>,
+    Extension(current_user): Extension<CurrentUser>,
+    Json(payload): Json<CashDrawerEventCreate>,
+) -> AppResult<Json<CashDrawerEvent>> {
+    if payload.kind != CashDrawerEventKind::Sale && !current_user.has_permission("cash_drawer:open")
+    {
+        return Err(AppError::forbidden(
+            "Permission denied: cash_drawer:open required",
+        ));
+    }
+    validate_optional_text(&payload.reason, "reason", MAX_NOTE_LEN)?;
+    if payload.kind.cash_sign() != 0.0
+        && payload
+            .reason
+            .as_deref()
+            .is_none_or(|r| r.trim().is_empty())
+    {
+        return Err(AppError::validation(
+            "reason is required for paid-in / paid-out",
+        ));
+    }
+
+    let event =
+        cash_drawer::record(&state.pool, payload, current_user.id, &current_user.name).await?;
+
+    let action = match event.kind {
+        CashDrawerEventKind::Sale => None,
+        CashDrawerEventKind::NoSale => Some(AuditAction::CashDrawerOpened),
+        CashDrawerEventKind::PaidIn => Some(AuditAction::CashPaidIn),
+        CashDrawerEventKind::PaidOut => Some(AuditAction::CashPaidOut),
+    };
+    if let Some(action) = action {
+        audit_log!(
+            state.audit_service,
+            action,
+            "cash_drawer",
+            &event.id.to_string(),
+            operator_id = Some(current_user.id),
+            operator_name = Some(current_user.name.clone()),
+            details = serde_json::json!({
+                "amount": event.amount,
+                "reason": event.reason,
+                "shift_id": event.shift_id,
+            })
+        );
+    }
+
+    // 存取现金改变了班次预期现金，通知其他终端刷新
+    if event.kind.cash_sign() != 0.0
+        && let Some(shift_id) = event.shift_id
+        && let Ok(Some(s)) = shift::find_by_id(&state.pool, shift_id).await
+    {
+        state
+            .broadcast_sync(
+                SyncResource::Shift,
+                SyncChangeType::Updated,
+                shift_id,
+                Some(&s),
+                false,
+            )
+            .await;
+    }
+
+    Ok(Json(event))
+}
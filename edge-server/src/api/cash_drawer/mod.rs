@@ -0,0 +1,19 @@
+//! Cash Drawer API 模块 (钱箱管理)
+
+mod handler;
+
+use axum::{Router, routing::get};
+
+use crate::core::ServerState;
+
+pub fn router() -> Router<ServerState> {
+    Router::new().nest("/api/cash-drawer", routes())
+}
+
+fn routes() -> Router<ServerState> {
+    // 读取无需权限检查（与班次查看一致）；
+    // 记录按类型在 handler 中检查 cash_drawer:open（现金收款开箱任何收银员都可上报）
+    Router::new()
+        .route("/events", get(handler::list).post(handler::record))
+        .route("/shifts/{shift_id}", get(handler::get_shift))
+}
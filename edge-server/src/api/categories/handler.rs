@@ -11,15 +11,20 @@ use crate::audit_log;
 use crate::auth::CurrentUser;
 use crate::core::ServerState;
 use crate::db::repository::attribute;
+use crate::services::catalog_history;
 use crate::utils::types::{BatchUpdateResponse, SortOrderUpdate};
 use crate::utils::validation::{MAX_NAME_LEN, validate_optional_text, validate_required_text};
 use crate::utils::{AppError, AppResult};
 use shared::error::ErrorCode;
 use shared::message::SyncChangeType;
-use shared::models::{Attribute, AttributeBinding, Category, CategoryCreate, CategoryUpdate};
+use shared::models::{
+    Attribute, AttributeBinding, CatalogChange, CatalogChangeAction, Category, CategoryCreate,
+    CategoryUpdate,
+};
 
 use shared::cloud::SyncResource;
 const RESOURCE: SyncResource = SyncResource::Category;
+const HISTORY_ENTITY: &str = "category";
 
 fn validate_create(payload: &CategoryCreate) -> AppResult<()> {
     validate_required_text(&payload.name, "name", MAX_NAME_LEN)?;
@@ -65,6 +70,17 @@ pub async fn create(
 
     let category = state.catalog_service.create_category(None, payload).await?;
 
+    catalog_history::record(
+        &state.pool,
+        HISTORY_ENTITY,
+        category.id,
+        CatalogChangeAction::Create,
+        None,
+        Some(&category),
+        Some(&current_user),
+    )
+    .await;
+
     let id_str = category.id.to_string();
 
     audit_log!(
@@ -111,6 +127,17 @@ pub async fn update(
 
     let category = state.catalog_service.update_category(id, payload).await?;
 
+    catalog_history::record(
+        &state.pool,
+        HISTORY_ENTITY,
+        id,
+        CatalogChangeAction::Update,
+        Some(&old_category),
+        Some(&category),
+        Some(&current_user),
+    )
+    .await;
+
     audit_log!(
         state.audit_service,
         AuditAction::CategoryUpdated,
@@ -143,13 +170,24 @@ pub async fn delete(
     let id_str = id.to_string();
     tracing::info!(id = %id, "Deleting category");
 
-    let name_for_audit = state
-        .catalog_service
-        .get_category(id)
+    let old_category = state.catalog_service.get_category(id);
+    let name_for_audit = old_category
+        .as_ref()
         .map(|c| c.name.clone())
         .unwrap_or_default();
     state.catalog_service.delete_category(id).await?;
 
+    catalog_history::record(
+        &state.pool,
+        HISTORY_ENTITY,
+        id,
+        CatalogChangeAction::Delete,
+        old_category.as_ref(),
+        None,
+        Some(&current_user),
+    )
+    .await;
+
     audit_log!(
         state.audit_service,
         AuditAction::CategoryDeleted,
@@ -167,6 +205,15 @@ pub async fn delete(
     Ok(Json(true))
 }
 
+/// GET /api/categories/:id/history - 分类变更历史
+pub async fn list_history(
+    State(state): State<ServerState>,
+    Path(id): Path<i64>,
+) -> AppResult<Json<Vec<CatalogChange>>> {
+    let changes = catalog_history::list(&state.pool, HISTORY_ENTITY, id).await?;
+    Ok(Json(changes))
+}
+
 // =========================================================================
 // Batch Sort Order Update
 // =========================================================================
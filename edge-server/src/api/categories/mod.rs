@@ -19,7 +19,8 @@ fn routes() -> Router<ServerState> {
     let read_routes = Router::new()
         .route("/", get(handler::list))
         .route("/{id}", get(handler::get_by_id))
-        .route("/{id}/attributes", get(handler::list_category_attributes));
+        .route("/{id}/attributes", get(handler::list_category_attributes))
+        .route("/{id}/history", get(handler::list_history));
 
     // 管理路由：需要 menu:manage 权限
     let manage_routes = Router::new()
@@ -0,0 +1,70 @@
+//! Chaos API Handlers
+
+use axum::{
+    Json,
+    extract::{Path, State},
+};
+use serde::{Deserialize, Serialize};
+
+use crate::chaos::{ArmedFault, FaultPoint, FaultSpec};
+use crate::core::ServerState;
+use crate::utils::{AppError, AppResult};
+
+#[derive(Debug, Deserialize)]
+pub struct ArmRequest {
+    pub point: FaultPoint,
+    #[serde(flatten)]
+    pub spec: FaultSpec,
+}
+
+#[derive(Debug, Serialize)]
+pub struct DisconnectResponse {
+    pub disconnected: usize,
+}
+
+/// GET /api/chaos - 当前配置的故障
+pub async fn list(State(state): State<ServerState>) -> Json<Vec<ArmedFault>> {
+    Json(state.faults.armed())
+}
+
+/// POST /api/chaos/faults - 配置故障
+pub async fn arm(
+    State(state): State<ServerState>,
+    Json(req): Json<ArmRequest>,
+) -> AppResult<Json<Vec<ArmedFault>>> {
+    if let Some(p) = req.spec.probability
+        && !(0.0..=1.0).contains(&p)
+    {
+        return Err(AppError::validation("probability must be within 0.0..=1.0"));
+    }
+    if req.spec.count == Some(0) {
+        return Err(AppError::validation("count must be at least 1"));
+    }
+    state.faults.arm(req.point, req.spec);
+    Ok(Json(state.faults.armed()))
+}
+
+/// DELETE /api/chaos/faults/{point} - 解除单个故障
+pub async fn disarm(
+    State(state): State<ServerState>,
+    Path(point): Path<String>,
+) -> AppResult<Json<Vec<ArmedFault>>> {
+    let point = FaultPoint::parse(&point)
+        .ok_or_else(|| AppError::validation(format!("Unknown fault point: {point}")))?;
+    if !state.faults.disarm(point) {
+        return Err(AppError::not_found(format!("Fault {point:?} is not armed")));
+    }
+    Ok(Json(state.faults.armed()))
+}
+
+/// DELETE /api/chaos - 解除所有故障
+pub async fn clear(State(state): State<ServerState>) -> Json<Vec<ArmedFault>> {
+    state.faults.clear();
+    Json(Vec::new())
+}
+
+/// POST /api/chaos/tls-disconnect - 立即断开所有客户端连接
+pub async fn tls_disconnect(State(state): State<ServerState>) -> Json<DisconnectResponse> {
+    let disconnected = state.message_bus().disconnect_all_clients().await;
+    Json(DisconnectResponse { disconnected })
+}
@@ -0,0 +1,25 @@
+//! Chaos API 模块 (故障注入，仅 `chaos` feature)
+
+mod handler;
+
+use axum::{
+    Router, middleware,
+    routing::{delete, get, post},
+};
+
+use crate::auth::require_admin;
+use crate::core::ServerState;
+
+pub fn router() -> Router<ServerState> {
+    Router::new()
+        .nest("/api/chaos", routes())
+        .layer(middleware::from_fn(require_admin))
+}
+
+fn routes() -> Router<ServerState> {
+    Router::new()
+        .route("/", get(handler::list).delete(handler::clear))
+        .route("/faults", post(handler::arm))
+        .route("/faults/{point}", delete(handler::disarm))
+        .route("/tls-disconnect", post(handler::tls_disconnect))
+}
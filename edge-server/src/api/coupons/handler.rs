@@ -0,0 +1,306 @@
+//! Coupon API Handlers
+
+use axum::{
+    Json,
+    extract::{Extension, Path, State},
+};
+
+use crate::audit::{AuditAction, create_diff, create_snapshot};
+use crate::audit_log;
+use crate::auth::CurrentUser;
+use crate::core::ServerState;
+use crate::db::repository::coupon;
+use crate::utils::validation::{MAX_NAME_LEN, MAX_SHORT_TEXT_LEN, validate_required_text};
+use crate::utils::{AppError, AppResult};
+use shared::message::SyncChangeType;
+use shared::models::price_rule::{AdjustmentType, ProductScope};
+use shared::models::{Coupon, CouponCreate, CouponGenerate, CouponUpdate, MAX_COUPON_BATCH};
+
+use shared::cloud::SyncResource;
+const RESOURCE: SyncResource = SyncResource::Coupon;
+
+/// Codes are typed at the till: letters, digits and '-' only
+fn validate_code(code: &str, field: &str) -> AppResult<()> {
+    validate_required_text(code, field, MAX_SHORT_TEXT_LEN)?;
+    if !code
+        .trim()
+        .chars()
+        .all(|c| c.is_ascii_alphanumeric() || c == '-')
+    {
+        return Err(AppError::validation(format!(
+            "{field} may only contain letters, digits and '-'"
+        )));
+    }
+    Ok(())
+}
+
+/// Validate scope/target and discount (Tag scope unsupported: snapshot items carry no tags)
+fn validate_discount(
+    product_scope: &ProductScope,
+    target_id: Option<i64>,
+    adjustment_type: &AdjustmentType,
+    value: f64,
+) -> AppResult<()> {
+    match product_scope {
+        ProductScope::Global => {}
+        ProductScope::Category | ProductScope::Product => {
+            if target_id.is_none() {
+                return Err(AppError::validation(
+                    "target_id is required for category/product coupons",
+                ));
+            }
+        }
+        ProductScope::Tag => {
+            return Err(AppError::validation(
+                "Tag scope is not supported for coupons",
+            ));
+        }
+    }
+    if !value.is_finite() || value <= 0.0 {
+        return Err(AppError::validation(
+            "adjustment_value must be a positive number",
+        ));
+    }
+    match adjustment_type {
+        AdjustmentType::Percentage if value > 100.0 => Err(AppError::validation(
+            "Percentage adjustment_value must be between 0 and 100",
+        )),
+        AdjustmentType::FixedAmount if value > 1_000_000.0 => Err(AppError::validation(
+            "FixedAmount adjustment_value must not exceed 1,000,000",
+        )),
+        _ => Ok(()),
+    }
+}
+
+fn validate_limits(
+    max_uses: Option<i64>,
+    valid_from: Option<i64>,
+    valid_until: Option<i64>,
+) -> AppResult<()> {
+    if let Some(max) = max_uses
+        && max <= 0
+    {
+        return Err(AppError::validation(format!(
+            "max_uses must be positive, got {max}"
+        )));
+    }
+    if let (Some(from), Some(until)) = (valid_from, valid_until)
+        && from > until
+    {
+        return Err(AppError::validation(
+            "valid_from must be before valid_until",
+        ));
+    }
+    Ok(())
+}
+
+fn validate_create(payload: &CouponCreate) -> AppResult<()> {
+    if let Some(code) = &payload.code {
+        validate_code(code, "code")?;
+    }
+    validate_required_text(&payload.name, "name", MAX_NAME_LEN)?;
+    validate_discount(
+        &payload.product_scope,
+        payload.target_id,
+        &payload.adjustment_type,
+        payload.adjustment_value,
+    )?;
+    validate_limits(payload.max_uses, payload.valid_from, payload.valid_until)
+}
+
+fn validate_generate(payload: &CouponGenerate) -> AppResult<()> {
+    if !(1..=MAX_COUPON_BATCH).contains(&payload.count) {
+        return Err(AppError::validation(format!(
+            "count must be between 1 and {MAX_COUPON_BATCH}, got {}",
+            payload.count
+        )));
+    }
+    if let Some(prefix) = &payload.prefix {
+        validate_code(prefix, "prefix")?;
+    }
+    validate_required_text(&payload.name, "name", MAX_NAME_LEN)?;
+    validate_discount(
+        &payload.product_scope,
+        payload.target_id,
+        &payload.adjustment_type,
+        payload.adjustment_value,
+    )?;
+    validate_limits(None, payload.valid_from, payload.valid_until)
+}
+
+async fn find_or_404(state: &ServerState, id: i64) -> AppResult<Coupon> {
+    coupon::find_by_id(&state.pool, id)
+        .await?
+        .ok_or_else(|| AppError::not_found(format!("Coupon {id}")))
+}
+
+/// GET /api/coupons - 获取所有优惠码
+pub async fn list(State(state): State<ServerState>) -> AppResult<Json<Vec<Coupon>>> {
+    let coupons = coupon::find_all(&state.pool).await?;
+    Ok(Json(coupons))
+}
+
+/// GET /api/coupons/:id - 获取单个优惠码
+pub async fn get_by_id(
+    State(state): State<ServerState>,
+    Path(id): Path<i64>,
+) -> AppResult<Json<Coupon>> {
+    Ok(Json(find_or_404(&state, id).await?))
+}
+
+/// GET /api/coupons/by-code/:code - 按码查询（大小写不敏感）
+pub async fn get_by_code(
+    State(state): State<ServerState>,
+    Path(code): Path<String>,
+) -> AppResult<Json<Coupon>> {
+    let found = coupon::find_by_code(&state.pool, &code)
+        .await?
+        .ok_or_else(|| AppError::not_found(format!("Coupon {}", code.trim())))?;
+    Ok(Json(found))
+}
+
+/// POST /api/coupons - 创建优惠码（未指定 code 时自动生成）
+pub async fn create(
+    State(state): State<ServerState>,
+    Extension(current_user): Extension<CurrentUser>,
+    Json(payload): Json<CouponCreate>,
+) -> AppResult<Json<Coupon>> {
+    validate_create(&payload)?;
+    let created = coupon::create(&state.pool, None, payload).await?;
+
+    let id = created.id.to_string();
+    audit_log!(
+        state.audit_service,
+        AuditAction::CouponCreated,
+        "coupon",
+        &id,
+        operator_id = Some(current_user.id),
+        operator_name = Some(current_user.name.clone()),
+        details = create_snapshot(&created, "coupon")
+    );
+
+    state
+        .broadcast_sync(
+            RESOURCE,
+            SyncChangeType::Created,
+            created.id,
+            Some(&created),
+            false,
+        )
+        .await;
+
+    Ok(Json(created))
+}
+
+/// POST /api/coupons/generate - 批量生成单次优惠码
+pub async fn generate(
+    State(state): State<ServerState>,
+    Extension(current_user): Extension<CurrentUser>,
+    Json(payload): Json<CouponGenerate>,
+) -> AppResult<Json<Vec<Coupon>>> {
+    validate_generate(&payload)?;
+    let name = payload.name.clone();
+    let coupons = coupon::generate(&state.pool, payload).await?;
+
+    audit_log!(
+        state.audit_service,
+        AuditAction::CouponCreated,
+        "coupon",
+        "batch",
+        operator_id = Some(current_user.id),
+        operator_name = Some(current_user.name.clone()),
+        details = serde_json::json!({"op": "generate", "name": name, "count": coupons.len()})
+    );
+
+    for created in &coupons {
+        state
+            .broadcast_sync(
+                RESOURCE,
+                SyncChangeType::Created,
+                created.id,
+                Some(created),
+                false,
+            )
+            .await;
+    }
+
+    Ok(Json(coupons))
+}
+
+/// PUT /api/coupons/:id - 更新优惠码（code 不可修改）
+pub async fn update(
+    State(state): State<ServerState>,
+    Extension(current_user): Extension<CurrentUser>,
+    Path(id): Path<i64>,
+    Json(payload): Json<CouponUpdate>,
+) -> AppResult<Json<Coupon>> {
+    if let Some(name) = &payload.name {
+        validate_required_text(name, "name", MAX_NAME_LEN)?;
+    }
+
+    let old = find_or_404(&state, id).await?;
+
+    // 部分更新时用旧值补齐后校验
+    validate_discount(
+        payload.product_scope.as_ref().unwrap_or(&old.product_scope),
+        payload.target_id.or(old.target_id),
+        payload
+            .adjustment_type
+            .as_ref()
+            .unwrap_or(&old.adjustment_type),
+        payload.adjustment_value.unwrap_or(old.adjustment_value),
+    )?;
+    validate_limits(
+        payload.max_uses.or(old.max_uses),
+        payload.valid_from.or(old.valid_from),
+        payload.valid_until.or(old.valid_until),
+    )?;
+
+    let updated = coupon::update(&state.pool, id, payload).await?;
+
+    let id_str = id.to_string();
+    audit_log!(
+        state.audit_service,
+        AuditAction::CouponUpdated,
+        "coupon",
+        &id_str,
+        operator_id = Some(current_user.id),
+        operator_name = Some(current_user.name.clone()),
+        details = create_diff(&old, &updated, "coupon")
+    );
+
+    state
+        .broadcast_sync(RESOURCE, SyncChangeType::Updated, id, Some(&updated), false)
+        .await;
+
+    Ok(Json(updated))
+}
+
+/// DELETE /api/coupons/:id - 删除优惠码
+pub async fn delete(
+    State(state): State<ServerState>,
+    Extension(current_user): Extension<CurrentUser>,
+    Path(id): Path<i64>,
+) -> AppResult<Json<bool>> {
+    let old = find_or_404(&state, id).await?;
+    let result = coupon::delete(&state.pool, id).await?;
+
+    if result {
+        let id_str = id.to_string();
+        audit_log!(
+            state.audit_service,
+            AuditAction::CouponDeleted,
+            "coupon",
+            &id_str,
+            operator_id = Some(current_user.id),
+            operator_name = Some(current_user.name.clone()),
+            details = serde_json::json!({"code": old.code, "name": old.name})
+        );
+
+        state
+            .broadcast_sync::<()>(RESOURCE, SyncChangeType::Deleted, id, None, false)
+            .await;
+    }
+
+    Ok(Json(result))
+}
@@ -0,0 +1,32 @@
+//! Coupon API 模块
+
+mod handler;
+
+use axum::{
+    Router, middleware,
+    routing::{get, post, put},
+};
+
+use crate::auth::require_permission;
+use crate::core::ServerState;
+
+pub fn router() -> Router<ServerState> {
+    Router::new().nest("/api/coupons", routes())
+}
+
+fn routes() -> Router<ServerState> {
+    // 读取路由：无需权限检查（收银台按码查询）
+    let read_routes = Router::new()
+        .route("/", get(handler::list))
+        .route("/{id}", get(handler::get_by_id))
+        .route("/by-code/{code}", get(handler::get_by_code));
+
+    // 管理路由：需要 marketing:manage 权限
+    let manage_routes = Router::new()
+        .route("/", post(handler::create))
+        .route("/generate", post(handler::generate))
+        .route("/{id}", put(handler::update).delete(handler::delete))
+        .layer(middleware::from_fn(require_permission("marketing:manage")));
+
+    read_routes.merge(manage_routes)
+}
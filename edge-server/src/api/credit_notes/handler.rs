@@ -91,16 +91,12 @@ pub async fn get_receipt(
         .as_ref()
         .and_then(|i| i.receipt_locale.clone())
         .unwrap_or_else(|| "es-ES".to_string());
-    let currency = store_info
+    let format = store_info
         .as_ref()
-        .and_then(|i| i.currency_symbol.clone())
-        .unwrap_or_else(|| "EUR".to_string());
-    let renderer = crate::printing::CreditNoteReceiptRenderer::new(
-        48,
-        state.config.timezone,
-        locale,
-        currency,
-    );
+        .map(shared::models::LocaleFormat::from_store_info)
+        .unwrap_or_default();
+    let renderer =
+        crate::printing::CreditNoteReceiptRenderer::new(48, state.config.timezone, locale, format);
     let bytes = renderer.render(&detail);
     Ok(bytes)
 }
@@ -0,0 +1,167 @@
+//! Customer Display API Handlers
+
+use axum::{
+    Json,
+    extract::{Extension, Path, State},
+};
+
+use crate::audit::{AuditAction, create_diff, create_snapshot};
+use crate::audit_log;
+use crate::auth::CurrentUser;
+use crate::core::ServerState;
+use crate::db::repository::customer_display;
+use crate::utils::validation::{MAX_NAME_LEN, validate_optional_text, validate_required_text};
+use crate::utils::{AppError, AppResult};
+use crab_printer::LineDisplayBuilder;
+use shared::error::ErrorCode;
+use shared::models::{CustomerDisplay, CustomerDisplayCreate, CustomerDisplayUpdate};
+
+/// 客显支持的行宽 (常见 VFD 为 20，副屏文本模式最多 40)
+const COLUMNS_RANGE: std::ops::RangeInclusive<i32> = 8..=40;
+
+fn validate_port(port: Option<i32>) -> AppResult<()> {
+    if let Some(port) = port
+        && !(1..=65535).contains(&port)
+    {
+        return Err(AppError::validation("port must be between 1 and 65535"));
+    }
+    Ok(())
+}
+
+fn validate_columns(columns: Option<i32>) -> AppResult<()> {
+    if let Some(columns) = columns
+        && !COLUMNS_RANGE.contains(&columns)
+    {
+        return Err(AppError::validation("columns must be between 8 and 40"));
+    }
+    Ok(())
+}
+
+fn validate_create(payload: &CustomerDisplayCreate) -> AppResult<()> {
+    validate_required_text(&payload.identity, "identity", MAX_NAME_LEN)?;
+    validate_required_text(&payload.name, "name", MAX_NAME_LEN)?;
+    validate_required_text(&payload.host, "host", MAX_NAME_LEN)?;
+    validate_port(payload.port)?;
+    validate_columns(payload.columns)?;
+    validate_optional_text(&payload.idle_text, "idle_text", MAX_NAME_LEN)
+}
+
+fn validate_update(payload: &CustomerDisplayUpdate) -> AppResult<()> {
+    if let Some(identity) = &payload.identity {
+        validate_required_text(identity, "identity", MAX_NAME_LEN)?;
+    }
+    if let Some(name) = &payload.name {
+        validate_required_text(name, "name", MAX_NAME_LEN)?;
+    }
+    if let Some(host) = &payload.host {
+        validate_required_text(host, "host", MAX_NAME_LEN)?;
+    }
+    validate_port(payload.port)?;
+    validate_columns(payload.columns)?;
+    validate_optional_text(&payload.idle_text, "idle_text", MAX_NAME_LEN)
+}
+
+/// GET /api/customer-displays - List all customer displays (including inactive)
+pub async fn list(State(state): State<ServerState>) -> AppResult<Json<Vec<CustomerDisplay>>> {
+    let displays = customer_display::find_all(&state.pool).await?;
+    Ok(Json(displays))
+}
+
+/// POST /api/customer-displays - Create a customer display
+pub async fn create(
+    State(state): State<ServerState>,
+    Extension(current_user): Extension<CurrentUser>,
+    Json(payload): Json<CustomerDisplayCreate>,
+) -> AppResult<Json<CustomerDisplay>> {
+    validate_create(&payload)?;
+
+    let display = customer_display::create(&state.pool, payload).await?;
+
+    audit_log!(
+        state.audit_service,
+        AuditAction::StoreInfoChanged,
+        "customer_display",
+        &display.id.to_string(),
+        operator_id = Some(current_user.id),
+        operator_name = Some(current_user.name.clone()),
+        details = create_snapshot(&display, "customer_display")
+    );
+
+    Ok(Json(display))
+}
+
+/// PUT /api/customer-displays/:id - Update a customer display
+pub async fn update(
+    State(state): State<ServerState>,
+    Extension(current_user): Extension<CurrentUser>,
+    Path(id): Path<i64>,
+    Json(payload): Json<CustomerDisplayUpdate>,
+) -> AppResult<Json<CustomerDisplay>> {
+    validate_update(&payload)?;
+
+    let old_display = customer_display::find_by_id(&state.pool, id)
+        .await?
+        .ok_or_else(|| AppError::not_found(format!("Customer display {}", id)))?;
+    let display = customer_display::update(&state.pool, id, payload).await?;
+
+    audit_log!(
+        state.audit_service,
+        AuditAction::StoreInfoChanged,
+        "customer_display",
+        &id.to_string(),
+        operator_id = Some(current_user.id),
+        operator_name = Some(current_user.name.clone()),
+        details = create_diff(&old_display, &display, "customer_display")
+    );
+
+    Ok(Json(display))
+}
+
+/// DELETE /api/customer-displays/:id - Delete a customer display
+pub async fn delete(
+    State(state): State<ServerState>,
+    Extension(current_user): Extension<CurrentUser>,
+    Path(id): Path<i64>,
+) -> AppResult<Json<bool>> {
+    let display = customer_display::find_by_id(&state.pool, id)
+        .await?
+        .ok_or_else(|| AppError::not_found(format!("Customer display {}", id)))?;
+    let result = customer_display::delete(&state.pool, id).await?;
+
+    if result {
+        audit_log!(
+            state.audit_service,
+            AuditAction::StoreInfoChanged,
+            "customer_display",
+            &id.to_string(),
+            operator_id = Some(current_user.id),
+            operator_name = Some(current_user.name.clone()),
+            details = serde_json::json!({"deleted": display.name})
+        );
+    }
+
+    Ok(Json(result))
+}
+
+/// POST /api/customer-displays/:id/test - Show the display name and idle text
+pub async fn test(State(state): State<ServerState>, Path(id): Path<i64>) -> AppResult<Json<bool>> {
+    let display = customer_display::find_by_id(&state.pool, id)
+        .await?
+        .ok_or_else(|| AppError::not_found(format!("Customer display {}", id)))?;
+
+    let mut builder = LineDisplayBuilder::new(display.columns.max(1) as usize);
+    builder.show(
+        &display.name,
+        display.idle_text.as_deref().unwrap_or_default(),
+    );
+    crate::customer_display::send(&display, &builder.build())
+        .await
+        .map_err(|e| {
+            AppError::with_message(
+                ErrorCode::PrinterNotAvailable,
+                format!("Customer display {} unreachable: {}", display.name, e),
+            )
+        })?;
+
+    Ok(Json(true))
+}
@@ -0,0 +1,30 @@
+//! Customer Display API Module
+
+mod handler;
+
+use axum::{Router, middleware, routing::get};
+
+use crate::auth::require_permission;
+use crate::core::ServerState;
+
+/// Customer display router
+pub fn router() -> Router<ServerState> {
+    Router::new().nest("/api/customer-displays", routes())
+}
+
+fn routes() -> Router<ServerState> {
+    // 读取路由：无需权限检查
+    let read_routes = Router::new().route("/", get(handler::list));
+
+    // 写入路由：需要 settings:manage 权限
+    let write_routes = Router::new()
+        .route("/", axum::routing::post(handler::create))
+        .route(
+            "/{id}",
+            axum::routing::put(handler::update).delete(handler::delete),
+        )
+        .route("/{id}/test", axum::routing::post(handler::test))
+        .layer(middleware::from_fn(require_permission("settings:manage")));
+
+    read_routes.merge(write_routes)
+}
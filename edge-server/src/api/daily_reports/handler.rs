@@ -9,6 +9,8 @@ use serde::Deserialize;
 use crate::audit::AuditAction;
 use crate::audit_log;
 use crate::auth::CurrentUser;
+use crate::auth::permissions::ReportScope;
+use crate::carry_over;
 use crate::core::ServerState;
 use crate::db::repository::daily_report;
 use crate::utils::time;
@@ -18,7 +20,7 @@ use crate::utils::validation::{
 use crate::utils::{AppError, AppResult};
 use shared::error::ErrorCode;
 use shared::message::SyncChangeType;
-use shared::models::{DailyReport, DailyReportGenerate};
+use shared::models::{CarryOverPolicy, DailyReport, DailyReportGenerate};
 
 use shared::cloud::SyncResource;
 const RESOURCE: SyncResource = SyncResource::DailyReport;
@@ -38,9 +40,40 @@ fn default_limit() -> i32 {
     50
 }
 
+/// 按报表范围裁剪日报
+///
+/// - 无 `reports:labor`：去掉班次明细 (员工、工时、休息)
+/// - 无 `reports:financials`：去掉退款、支付附加费、钱箱存取与班次中的现金/税/调整金额
+fn scoped(mut report: DailyReport, scope: ReportScope) -> DailyReport {
+    if !scope.labor {
+        report.shift_breakdowns.clear();
+    }
+    if !scope.financials {
+        report.refund_amount = 0.0;
+        report.refund_count = 0;
+        report.payment_surcharge_amount = 0.0;
+        report.paid_in_amount = 0.0;
+        report.paid_out_amount = 0.0;
+        for shift in &mut report.shift_breakdowns {
+            shift.starting_cash = 0.0;
+            shift.expected_cash = 0.0;
+            shift.actual_cash = None;
+            shift.cash_variance = None;
+            shift.void_amount = 0.0;
+            shift.total_tax = 0.0;
+            shift.total_discount = 0.0;
+            shift.total_surcharge = 0.0;
+            shift.paid_in_amount = 0.0;
+            shift.paid_out_amount = 0.0;
+        }
+    }
+    report
+}
+
 /// GET /api/daily-reports - 获取日结报告列表
 pub async fn list(
     State(state): State<ServerState>,
+    Extension(current_user): Extension<CurrentUser>,
     Query(query): Query<ListQuery>,
 ) -> AppResult<Json<Vec<DailyReport>>> {
     let reports = if let (Some(start), Some(end)) = (query.start_date, query.end_date) {
@@ -49,12 +82,16 @@ pub async fn list(
         daily_report::find_all(&state.pool, query.limit, query.offset).await
     }?;
 
-    Ok(Json(reports))
+    let scope = ReportScope::of(&current_user);
+    Ok(Json(
+        reports.into_iter().map(|r| scoped(r, scope)).collect(),
+    ))
 }
 
 /// GET /api/daily-reports/:id - 获取单个日结报告
 pub async fn get_by_id(
     State(state): State<ServerState>,
+    Extension(current_user): Extension<CurrentUser>,
     Path(id): Path<i64>,
 ) -> AppResult<Json<DailyReport>> {
     let report = daily_report::find_by_id(&state.pool, id)
@@ -65,12 +102,13 @@ pub async fn get_by_id(
                 format!("Daily report {} not found", id),
             )
         })?;
-    Ok(Json(report))
+    Ok(Json(scoped(report, ReportScope::of(&current_user))))
 }
 
 /// GET /api/daily-reports/date/:date - 按日期获取日结报告
 pub async fn get_by_date(
     State(state): State<ServerState>,
+    Extension(current_user): Extension<CurrentUser>,
     Path(date): Path<String>,
 ) -> AppResult<Json<DailyReport>> {
     let report = daily_report::find_by_date(&state.pool, &date)
@@ -81,7 +119,7 @@ pub async fn get_by_date(
                 format!("Daily report for {} not found", date),
             )
         })?;
-    Ok(Json(report))
+    Ok(Json(scoped(report, ReportScope::of(&current_user))))
 }
 
 /// POST /api/daily-reports/generate - 生成日结报告
@@ -108,6 +146,23 @@ pub async fn generate(
     let next_day = date.succ_opt().unwrap_or(date);
     let end_millis = time::day_start_millis(next_day, tz);
 
+    // BLOCK 策略：该营业日仍有未结订单时拒绝生成
+    if carry_over::load_policy(&state).await == CarryOverPolicy::Block {
+        let open = carry_over::open_orders_before(&state, end_millis);
+        if !open.is_empty() {
+            return Err(AppError::with_message(
+                ErrorCode::DailyReportBlocked,
+                format!(
+                    "{} order(s) from {} are still open",
+                    open.len(),
+                    business_date
+                ),
+            ));
+        }
+    }
+    let open_orders = carry_over::open_order_totals(&state);
+
+    let scope = ReportScope::of(&current_user);
     let audit_operator_id = current_user.id;
     let audit_operator_name = current_user.name.clone();
 
@@ -119,6 +174,7 @@ pub async fn generate(
         payload,
         start_millis,
         end_millis,
+        &open_orders,
         operator_id,
         operator_name,
         false, // manual generation
@@ -149,5 +205,5 @@ pub async fn generate(
         )
         .await;
 
-    Ok(Json(report))
+    Ok(Json(scoped(report, scope)))
 }
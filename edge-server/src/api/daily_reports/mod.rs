@@ -15,7 +15,7 @@ pub fn router() -> Router<ServerState> {
 }
 
 fn routes() -> Router<ServerState> {
-    // 日报查看：需要 reports:view 权限
+    // 日报查看：需要 reports:view 权限 (财务/人员字段在 handler 中按 ReportScope 裁剪)
     Router::new()
         .route("/", get(handler::list))
         .route("/generate", post(handler::generate))
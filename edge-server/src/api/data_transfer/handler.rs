@@ -511,8 +511,8 @@ pub async fn import_catalog_data(
             .map(|v| serde_json::to_string(v).unwrap_or_else(|_| "null".to_string()));
 
         sqlx::query(
-            "INSERT INTO price_rule (id, name, receipt_name, description, rule_type, product_scope, target_id, zone_scope, adjustment_type, adjustment_value, is_stackable, is_exclusive, valid_from, valid_until, active_days, active_start_time, active_end_time, is_active, created_by, created_at, updated_at) \
-             VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8, ?9, ?10, ?11, ?12, ?13, ?14, ?15, ?16, ?17, ?18, ?19, ?20, ?21)",
+            "INSERT INTO price_rule (id, name, receipt_name, description, rule_type, product_scope, target_id, zone_scope, adjustment_type, adjustment_value, is_stackable, is_exclusive, priority, valid_from, valid_until, active_days, active_start_time, active_end_time, is_active, created_by, created_at, updated_at) \
+             VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8, ?9, ?10, ?11, ?12, ?13, ?14, ?15, ?16, ?17, ?18, ?19, ?20, ?21, ?22)",
         )
         .bind(pr.id)
         .bind(&pr.name)
@@ -526,6 +526,7 @@ pub async fn import_catalog_data(
         .bind(pr.adjustment_value)
         .bind(pr.is_stackable)
         .bind(pr.is_exclusive)
+        .bind(pr.priority)
         .bind(pr.valid_from)
         .bind(pr.valid_until)
         .bind(&active_days_json)
@@ -676,6 +677,8 @@ async fn export_all_products(
             kitchen_print_name: product.kitchen_print_name,
             is_kitchen_print_enabled: product.is_kitchen_print_enabled,
             is_label_print_enabled: product.is_label_print_enabled,
+            kitchen_print_destinations: vec![],
+            label_print_destinations: vec![],
             is_active: product.is_active,
             external_id: product.external_id,
             specs,
@@ -0,0 +1,116 @@
+//! Dead Letter API Handlers
+
+use axum::{
+    Json,
+    extract::{Extension, Path, Query, State},
+};
+use serde::Deserialize;
+
+use crate::audit::{AuditAction, create_snapshot};
+use crate::audit_log;
+use crate::auth::CurrentUser;
+use crate::core::ServerState;
+use crate::db::repository::dead_letter as dead_letter_repo;
+use crate::utils::{AppError, AppResult};
+use shared::message::{BusMessage, EventType};
+use shared::models::{DeadLetter, DeadLetterStatus};
+
+const DEFAULT_LIMIT: i64 = 100;
+const MAX_LIMIT: i64 = 500;
+
+#[derive(Debug, Deserialize)]
+pub struct ListQuery {
+    pub status: Option<DeadLetterStatus>,
+    pub limit: Option<i64>,
+}
+
+/// GET /api/dead-letters - 死信列表 (最新在前)
+pub async fn list(
+    State(state): State<ServerState>,
+    Query(query): Query<ListQuery>,
+) -> AppResult<Json<Vec<DeadLetter>>> {
+    let limit = query.limit.unwrap_or(DEFAULT_LIMIT).clamp(1, MAX_LIMIT);
+    let letters = dead_letter_repo::list(&state.pool, query.status, limit).await?;
+    Ok(Json(letters))
+}
+
+/// GET /api/dead-letters/:id
+pub async fn get_by_id(
+    State(state): State<ServerState>,
+    Path(id): Path<i64>,
+) -> AppResult<Json<DeadLetter>> {
+    let letter = dead_letter_repo::find_by_id(&state.pool, id)
+        .await?
+        .ok_or_else(|| AppError::not_found(format!("Dead letter {id}")))?;
+    Ok(Json(letter))
+}
+
+/// POST /api/dead-letters/:id/redeliver - 重新发布到总线
+///
+/// 以新的 request_id 重新投递，响应仍发回原客户端 (若在线)。
+/// 再次失败会生成一条新的死信。
+pub async fn redeliver(
+    State(state): State<ServerState>,
+    Extension(current_user): Extension<CurrentUser>,
+    Path(id): Path<i64>,
+) -> AppResult<Json<DeadLetter>> {
+    let letter = dead_letter_repo::find_by_id(&state.pool, id)
+        .await?
+        .ok_or_else(|| AppError::not_found(format!("Dead letter {id}")))?;
+    if letter.status != DeadLetterStatus::Pending {
+        return Err(AppError::validation(format!(
+            "Dead letter {id} is already {:?}",
+            letter.status
+        )));
+    }
+    let payload = letter.payload.as_ref().ok_or_else(|| {
+        AppError::validation("Payload could not be decoded; message cannot be redelivered")
+    })?;
+    let event_type: EventType = serde_json::from_value(serde_json::Value::String(
+        letter.event_type.clone(),
+    ))
+    .map_err(|_| AppError::validation(format!("Unknown event type: {}", letter.event_type)))?;
+
+    let mut msg = BusMessage::new(
+        event_type,
+        serde_json::to_vec(payload).map_err(|e| AppError::internal(e.to_string()))?,
+    );
+    msg.source = letter.source.clone();
+
+    // 先标记再投递，避免并发重复投递
+    let letter = dead_letter_repo::mark_redelivered(&state.pool, id).await?;
+    state.message_bus().send_to_server(msg).await?;
+
+    audit_log!(
+        state.audit_service,
+        AuditAction::DeadLetterRedelivered,
+        "dead_letter",
+        &letter.id.to_string(),
+        operator_id = Some(current_user.id),
+        operator_name = Some(current_user.name.clone()),
+        details = create_snapshot(&letter, "dead_letter")
+    );
+
+    Ok(Json(letter))
+}
+
+/// DELETE /api/dead-letters/:id - 丢弃 (保留记录)
+pub async fn discard(
+    State(state): State<ServerState>,
+    Extension(current_user): Extension<CurrentUser>,
+    Path(id): Path<i64>,
+) -> AppResult<Json<DeadLetter>> {
+    let letter = dead_letter_repo::mark_discarded(&state.pool, id).await?;
+
+    audit_log!(
+        state.audit_service,
+        AuditAction::DeadLetterDiscarded,
+        "dead_letter",
+        &letter.id.to_string(),
+        operator_id = Some(current_user.id),
+        operator_name = Some(current_user.name.clone()),
+        details = create_snapshot(&letter, "dead_letter")
+    );
+
+    Ok(Json(letter))
+}
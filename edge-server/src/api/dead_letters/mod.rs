@@ -0,0 +1,24 @@
+//! Dead Letters API 模块 (死信检查与重新投递)
+
+mod handler;
+
+use axum::{
+    Router, middleware,
+    routing::{get, post},
+};
+
+use crate::auth::require_admin;
+use crate::core::ServerState;
+
+pub fn router() -> Router<ServerState> {
+    Router::new()
+        .nest("/api/dead-letters", routes())
+        .layer(middleware::from_fn(require_admin))
+}
+
+fn routes() -> Router<ServerState> {
+    Router::new()
+        .route("/", get(handler::list))
+        .route("/{id}", get(handler::get_by_id).delete(handler::discard))
+        .route("/{id}/redeliver", post(handler::redeliver))
+}
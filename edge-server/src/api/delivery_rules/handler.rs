@@ -0,0 +1,122 @@
+//! Delivery Rule API Handlers
+
+use axum::{
+    Json,
+    extract::{Extension, Path, Query, State},
+};
+use serde::Deserialize;
+
+use crate::audit::{AuditAction, create_diff, create_snapshot};
+use crate::audit_log;
+use crate::auth::CurrentUser;
+use crate::core::ServerState;
+use crate::db::repository::delivery_rule;
+use crate::utils::validation::{
+    MAX_SHORT_TEXT_LEN, validate_optional_text, validate_required_text,
+};
+use crate::utils::{AppError, AppResult};
+use shared::models::{DeliveryQuote, DeliveryRule, DeliveryRuleCreate, DeliveryRuleUpdate};
+
+/// GET /api/delivery-rules - 全部外卖渠道规则
+pub async fn list(State(state): State<ServerState>) -> AppResult<Json<Vec<DeliveryRule>>> {
+    let rules = delivery_rule::find_all(&state.pool).await?;
+    Ok(Json(rules))
+}
+
+/// POST /api/delivery-rules - 新增渠道规则 (每个渠道 + 区域一条)
+pub async fn create(
+    State(state): State<ServerState>,
+    Extension(current_user): Extension<CurrentUser>,
+    Json(payload): Json<DeliveryRuleCreate>,
+) -> AppResult<Json<DeliveryRule>> {
+    validate_required_text(&payload.channel, "channel", MAX_SHORT_TEXT_LEN)?;
+    validate_optional_text(&payload.zone, "zone", MAX_SHORT_TEXT_LEN)?;
+
+    let rule = delivery_rule::create(&state.pool, &payload).await?;
+
+    audit_log!(
+        state.audit_service,
+        AuditAction::StoreInfoChanged,
+        "delivery_rule",
+        &rule.id.to_string(),
+        operator_id = Some(current_user.id),
+        operator_name = Some(current_user.name.clone()),
+        details = create_snapshot(&rule, "delivery_rule")
+    );
+
+    Ok(Json(rule))
+}
+
+/// PUT /api/delivery-rules/:id - 更新渠道规则
+pub async fn update(
+    State(state): State<ServerState>,
+    Extension(current_user): Extension<CurrentUser>,
+    Path(id): Path<i64>,
+    Json(payload): Json<DeliveryRuleUpdate>,
+) -> AppResult<Json<DeliveryRule>> {
+    let old_rule = delivery_rule::find_by_id(&state.pool, id)
+        .await?
+        .ok_or_else(|| AppError::not_found(format!("Delivery rule {}", id)))?;
+    let rule = delivery_rule::update(&state.pool, id, &payload).await?;
+
+    audit_log!(
+        state.audit_service,
+        AuditAction::StoreInfoChanged,
+        "delivery_rule",
+        &id.to_string(),
+        operator_id = Some(current_user.id),
+        operator_name = Some(current_user.name.clone()),
+        details = create_diff(&old_rule, &rule, "delivery_rule")
+    );
+
+    Ok(Json(rule))
+}
+
+/// DELETE /api/delivery-rules/:id - 删除渠道规则
+pub async fn delete(
+    State(state): State<ServerState>,
+    Extension(current_user): Extension<CurrentUser>,
+    Path(id): Path<i64>,
+) -> AppResult<Json<bool>> {
+    let rule = delivery_rule::find_by_id(&state.pool, id)
+        .await?
+        .ok_or_else(|| AppError::not_found(format!("Delivery rule {}", id)))?;
+    delivery_rule::delete(&state.pool, id).await?;
+
+    audit_log!(
+        state.audit_service,
+        AuditAction::StoreInfoChanged,
+        "delivery_rule",
+        &id.to_string(),
+        operator_id = Some(current_user.id),
+        operator_name = Some(current_user.name.clone()),
+        details = serde_json::json!({"deleted": rule.channel, "zone": rule.zone})
+    );
+
+    Ok(Json(true))
+}
+
+#[derive(Debug, Deserialize)]
+pub struct QuoteQuery {
+    pub channel: String,
+    pub zone: Option<String>,
+    pub subtotal: f64,
+}
+
+/// GET /api/public/delivery/quote - 购物车起送价 / 配送费提示 (免登录)
+///
+/// 用于 QR 点餐 / 自助机显示 "再加 €3 免配送费"
+pub async fn quote(
+    State(state): State<ServerState>,
+    Query(query): Query<QuoteQuery>,
+) -> AppResult<Json<DeliveryQuote>> {
+    if !query.subtotal.is_finite() || query.subtotal < 0.0 {
+        return Err(AppError::validation(
+            "subtotal must be a non-negative number",
+        ));
+    }
+    let rule = delivery_rule::find_applicable(&state.pool, &query.channel, query.zone.as_deref())
+        .await?
+        .ok_or_else(|| AppError::not_found(format!("No delivery rule for {}", query.channel)))?;
+    Ok(Json(rule.quote(query.subtotal)))
+}
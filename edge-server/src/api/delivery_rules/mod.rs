@@ -0,0 +1,39 @@
+//! Delivery Rule API Module (外卖渠道起送价 / 配送费)
+
+mod handler;
+
+use axum::{
+    Router, middleware,
+    routing::{get, post},
+};
+
+use crate::auth::require_permission;
+use crate::core::ServerState;
+
+/// Delivery rule router
+pub fn router() -> Router<ServerState> {
+    Router::new()
+        .nest("/api/delivery-rules", routes())
+        .nest("/api/public/delivery", public_routes())
+}
+
+fn routes() -> Router<ServerState> {
+    // 读取路由：无需权限检查
+    let read_routes = Router::new().route("/", get(handler::list));
+
+    // 写入路由：需要 settings:manage 权限
+    let write_routes = Router::new()
+        .route("/", post(handler::create))
+        .route(
+            "/{id}",
+            axum::routing::put(handler::update).delete(handler::delete),
+        )
+        .layer(middleware::from_fn(require_permission("settings:manage")));
+
+    read_routes.merge(write_routes)
+}
+
+/// 免登录路由 (QR 点餐 / 自助机，require_auth 跳过 `/api/public/`)
+fn public_routes() -> Router<ServerState> {
+    Router::new().route("/quote", get(handler::quote))
+}
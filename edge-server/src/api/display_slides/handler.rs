@@ -0,0 +1,235 @@
+//! Display Slide API Handlers
+
+use axum::{
+    Json,
+    extract::{Extension, Path, State},
+};
+use chrono::{Datelike, Timelike};
+
+use crate::audit::{AuditAction, create_diff, create_snapshot};
+use crate::audit_log;
+use crate::auth::CurrentUser;
+use crate::core::ServerState;
+use crate::db::repository::{display_slide, image_ref};
+use crate::services::ImageCleanupService;
+use crate::utils::validation::{MAX_NAME_LEN, validate_required_text};
+use crate::utils::{AppError, AppResult};
+use shared::message::SyncChangeType;
+use shared::models::{DisplaySlide, DisplaySlideCreate, DisplaySlideUpdate, scheduled_slides};
+
+use shared::cloud::SyncResource;
+const RESOURCE: SyncResource = SyncResource::DisplaySlide;
+
+/// 单张幻灯片最长停留时间（秒）
+const MAX_DURATION_SECS: i32 = 300;
+
+/// 图片必须是已上传到本 edge 的内容哈希（客户端按哈希下载缓存）
+fn validate_image(state: &ServerState, image: &str) -> AppResult<()> {
+    let is_hash = image.len() == 64 && image.bytes().all(|b| b.is_ascii_hexdigit());
+    if !is_hash {
+        return Err(AppError::validation("image must be an uploaded image hash"));
+    }
+    if !state
+        .config
+        .images_dir()
+        .join(format!("{}.jpg", image))
+        .exists()
+    {
+        return Err(AppError::validation(format!(
+            "Image {} has not been uploaded",
+            image
+        )));
+    }
+    Ok(())
+}
+
+fn validate_schedule(
+    duration_secs: Option<i32>,
+    active_days: &Option<Vec<u8>>,
+    start: &Option<String>,
+    end: &Option<String>,
+) -> AppResult<()> {
+    if let Some(secs) = duration_secs
+        && !(1..=MAX_DURATION_SECS).contains(&secs)
+    {
+        return Err(AppError::validation(format!(
+            "duration_secs must be between 1 and {}",
+            MAX_DURATION_SECS
+        )));
+    }
+    if let Some(days) = active_days
+        && days.iter().any(|d| *d > 6)
+    {
+        return Err(AppError::validation(
+            "active_days must contain values 0 (Sunday) to 6 (Saturday)",
+        ));
+    }
+    for (value, field) in [(start, "active_start_time"), (end, "active_end_time")] {
+        if let Some(value) = value
+            && chrono::NaiveTime::parse_from_str(value, "%H:%M").is_err()
+        {
+            return Err(AppError::validation(format!("{} must be HH:MM", field)));
+        }
+    }
+    Ok(())
+}
+
+fn validate_create(state: &ServerState, payload: &DisplaySlideCreate) -> AppResult<()> {
+    validate_required_text(&payload.name, "name", MAX_NAME_LEN)?;
+    validate_image(state, &payload.image)?;
+    validate_schedule(
+        payload.duration_secs,
+        &payload.active_days,
+        &payload.active_start_time,
+        &payload.active_end_time,
+    )
+}
+
+fn validate_update(state: &ServerState, payload: &DisplaySlideUpdate) -> AppResult<()> {
+    if let Some(name) = &payload.name {
+        validate_required_text(name, "name", MAX_NAME_LEN)?;
+    }
+    if let Some(image) = &payload.image {
+        validate_image(state, image)?;
+    }
+    validate_schedule(
+        payload.duration_secs,
+        &payload.active_days,
+        &payload.active_start_time,
+        &payload.active_end_time,
+    )
+}
+
+/// Delete the image file once no other entity references it
+async fn cleanup_orphan_image(state: &ServerState, old_image: &str) {
+    let removed = vec![old_image.to_string()];
+    if let Ok(orphans) = image_ref::find_orphan_hashes(&state.pool, &removed).await
+        && !orphans.is_empty()
+    {
+        let cleanup = ImageCleanupService::new(state.config.images_dir());
+        cleanup.cleanup_orphan_images(&orphans).await;
+    }
+}
+
+/// GET /api/display-slides - List all display slides (including inactive)
+pub async fn list(State(state): State<ServerState>) -> AppResult<Json<Vec<DisplaySlide>>> {
+    let slides = display_slide::find_all(&state.pool).await?;
+    Ok(Json(slides))
+}
+
+/// GET /api/display-slides/playlist - Slides scheduled right now
+///
+/// Schedule is evaluated in the store timezone; display clients re-fetch
+/// at the end of each loop so time windows take effect without a restart.
+pub async fn playlist(State(state): State<ServerState>) -> AppResult<Json<Vec<DisplaySlide>>> {
+    let slides = display_slide::find_active(&state.pool).await?;
+    let now = chrono::Utc::now();
+    let local = now.with_timezone(&state.config.timezone);
+    let weekday = local.weekday().num_days_from_sunday() as u8;
+    let minute_of_day = (local.hour() * 60 + local.minute()) as u16;
+    let playlist = scheduled_slides(&slides, now.timestamp_millis(), weekday, minute_of_day)
+        .into_iter()
+        .cloned()
+        .collect();
+    Ok(Json(playlist))
+}
+
+/// POST /api/display-slides - Create a display slide
+pub async fn create(
+    State(state): State<ServerState>,
+    Extension(current_user): Extension<CurrentUser>,
+    Json(payload): Json<DisplaySlideCreate>,
+) -> AppResult<Json<DisplaySlide>> {
+    validate_create(&state, &payload)?;
+
+    let slide = display_slide::create(&state.pool, payload).await?;
+
+    audit_log!(
+        state.audit_service,
+        AuditAction::StoreInfoChanged,
+        "display_slide",
+        &slide.id.to_string(),
+        operator_id = Some(current_user.id),
+        operator_name = Some(current_user.name.clone()),
+        details = create_snapshot(&slide, "display_slide")
+    );
+
+    state
+        .broadcast_sync(
+            RESOURCE,
+            SyncChangeType::Created,
+            slide.id,
+            Some(&slide),
+            false,
+        )
+        .await;
+
+    Ok(Json(slide))
+}
+
+/// PUT /api/display-slides/:id - Update a display slide
+pub async fn update(
+    State(state): State<ServerState>,
+    Extension(current_user): Extension<CurrentUser>,
+    Path(id): Path<i64>,
+    Json(payload): Json<DisplaySlideUpdate>,
+) -> AppResult<Json<DisplaySlide>> {
+    validate_update(&state, &payload)?;
+
+    let old_slide = display_slide::find_by_id(&state.pool, id)
+        .await?
+        .ok_or_else(|| AppError::not_found(format!("Display slide {}", id)))?;
+    let slide = display_slide::update(&state.pool, id, payload).await?;
+
+    if old_slide.image != slide.image {
+        cleanup_orphan_image(&state, &old_slide.image).await;
+    }
+
+    audit_log!(
+        state.audit_service,
+        AuditAction::StoreInfoChanged,
+        "display_slide",
+        &id.to_string(),
+        operator_id = Some(current_user.id),
+        operator_name = Some(current_user.name.clone()),
+        details = create_diff(&old_slide, &slide, "display_slide")
+    );
+
+    state
+        .broadcast_sync(RESOURCE, SyncChangeType::Updated, id, Some(&slide), false)
+        .await;
+
+    Ok(Json(slide))
+}
+
+/// DELETE /api/display-slides/:id - Delete a display slide
+pub async fn delete(
+    State(state): State<ServerState>,
+    Extension(current_user): Extension<CurrentUser>,
+    Path(id): Path<i64>,
+) -> AppResult<Json<bool>> {
+    let slide = display_slide::find_by_id(&state.pool, id)
+        .await?
+        .ok_or_else(|| AppError::not_found(format!("Display slide {}", id)))?;
+    let result = display_slide::delete(&state.pool, id).await?;
+
+    if result {
+        cleanup_orphan_image(&state, &slide.image).await;
+
+        audit_log!(
+            state.audit_service,
+            AuditAction::StoreInfoChanged,
+            "display_slide",
+            &id.to_string(),
+            operator_id = Some(current_user.id),
+            operator_name = Some(current_user.name.clone()),
+            details = serde_json::json!({"deleted": slide.name})
+        );
+
+        state
+            .broadcast_sync::<()>(RESOURCE, SyncChangeType::Deleted, id, None, false)
+            .await;
+    }
+
+    Ok(Json(result))
+}
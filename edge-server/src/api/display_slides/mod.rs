@@ -0,0 +1,31 @@
+//! Display Slide API Module
+
+mod handler;
+
+use axum::{Router, middleware, routing::get};
+
+use crate::auth::require_permission;
+use crate::core::ServerState;
+
+/// Display slide router
+pub fn router() -> Router<ServerState> {
+    Router::new().nest("/api/display-slides", routes())
+}
+
+fn routes() -> Router<ServerState> {
+    // 读取路由：无需权限检查（客显屏按排期拉取播放列表）
+    let read_routes = Router::new()
+        .route("/", get(handler::list))
+        .route("/playlist", get(handler::playlist));
+
+    // 写入路由：需要 settings:manage 权限
+    let write_routes = Router::new()
+        .route("/", axum::routing::post(handler::create))
+        .route(
+            "/{id}",
+            axum::routing::put(handler::update).delete(handler::delete),
+        )
+        .layer(middleware::from_fn(require_permission("settings:manage")));
+
+    read_routes.merge(write_routes)
+}
@@ -0,0 +1,113 @@
+//! 86 Board API Handlers
+
+use axum::{
+    Json,
+    extract::{Extension, Path, Query, State},
+};
+use serde::Deserialize;
+
+use crate::audit::{AuditAction, create_snapshot};
+use crate::audit_log;
+use crate::auth::CurrentUser;
+use crate::core::ServerState;
+use crate::db::repository::eighty_six;
+use crate::utils::AppResult;
+use crate::utils::validation::{MAX_NOTE_LEN, validate_optional_text};
+use shared::cloud::SyncResource;
+use shared::message::SyncChangeType;
+use shared::models::{EightySix, EightySixCreate};
+
+const RESOURCE: SyncResource = SyncResource::EightySix;
+
+/// 复盘默认时间窗口 (24 小时)
+const DEFAULT_HISTORY_WINDOW_MS: i64 = 24 * 60 * 60 * 1000;
+
+#[derive(Debug, Deserialize)]
+pub struct HistoryQuery {
+    pub from: Option<i64>,
+    pub to: Option<i64>,
+}
+
+/// GET /api/eighty-six - 当前沽清列表
+pub async fn list(State(state): State<ServerState>) -> AppResult<Json<Vec<EightySix>>> {
+    Ok(Json(state.catalog_service.list_eighty_sixed()))
+}
+
+/// GET /api/eighty-six/history - 沽清记录 (含已恢复/已过期，经理复盘)
+pub async fn history(
+    State(state): State<ServerState>,
+    Query(query): Query<HistoryQuery>,
+) -> AppResult<Json<Vec<EightySix>>> {
+    let to = query.to.unwrap_or_else(shared::util::now_millis);
+    let from = query.from.unwrap_or(to - DEFAULT_HISTORY_WINDOW_MS);
+    let entries = eighty_six::find_created_between(&state.pool, from, to).await?;
+    Ok(Json(entries))
+}
+
+/// POST /api/eighty-six - 标记沽清
+pub async fn create(
+    State(state): State<ServerState>,
+    Extension(current_user): Extension<CurrentUser>,
+    Json(payload): Json<EightySixCreate>,
+) -> AppResult<Json<EightySix>> {
+    validate_optional_text(&payload.reason, "reason", MAX_NOTE_LEN)?;
+
+    let entry = state
+        .catalog_service
+        .eighty_six(payload, current_user.id, &current_user.name)
+        .await?;
+
+    audit_log!(
+        state.audit_service,
+        AuditAction::ProductEightySixed,
+        "product",
+        &entry.product_id.to_string(),
+        operator_id = Some(current_user.id),
+        operator_name = Some(current_user.name.clone()),
+        details = create_snapshot(&entry, "eighty_six")
+    );
+
+    state
+        .broadcast_sync(
+            RESOURCE,
+            SyncChangeType::Created,
+            entry.id,
+            Some(&entry),
+            false,
+        )
+        .await;
+
+    Ok(Json(entry))
+}
+
+/// DELETE /api/eighty-six/:id - 恢复供应
+pub async fn clear(
+    State(state): State<ServerState>,
+    Extension(current_user): Extension<CurrentUser>,
+    Path(id): Path<i64>,
+) -> AppResult<Json<EightySix>> {
+    let entry = state
+        .catalog_service
+        .clear_eighty_six(id, &current_user.name)
+        .await?;
+
+    audit_log!(
+        state.audit_service,
+        AuditAction::ProductEightySixCleared,
+        "product",
+        &entry.product_id.to_string(),
+        operator_id = Some(current_user.id),
+        operator_name = Some(current_user.name.clone()),
+        details = serde_json::json!({
+            "product_name": entry.product_name,
+            "spec_name": entry.spec_name,
+            "eighty_sixed_at": entry.created_at,
+        })
+    );
+
+    state
+        .broadcast_sync::<()>(RESOURCE, SyncChangeType::Deleted, id, None, false)
+        .await;
+
+    Ok(Json(entry))
+}
@@ -0,0 +1,32 @@
+//! 86 Board API Module
+//!
+//! 临时沽清 — 任意终端可标记商品/规格暂不可售，经理次日复盘
+
+mod handler;
+
+use axum::{
+    Router, middleware,
+    routing::{delete, get},
+};
+
+use crate::auth::require_permission;
+use crate::core::ServerState;
+
+/// 86 board router
+pub fn router() -> Router<ServerState> {
+    Router::new().nest("/api/eighty-six", routes())
+}
+
+fn routes() -> Router<ServerState> {
+    // 员工路由：查看、标记、恢复
+    let staff_routes = Router::new()
+        .route("/", get(handler::list).post(handler::create))
+        .route("/{id}", delete(handler::clear));
+
+    // 复盘路由：需要 reports:view 权限
+    let review_routes = Router::new()
+        .route("/history", get(handler::history))
+        .layer(middleware::from_fn(require_permission("reports:view")));
+
+    staff_routes.merge(review_routes)
+}
@@ -3,24 +3,39 @@
 use axum::{
     Json,
     extract::{Extension, Path, State},
+    http::header,
+    response::IntoResponse,
 };
+use base64::Engine;
 
 use crate::audit::{AuditAction, create_diff, create_snapshot};
 use crate::audit_log;
 use crate::auth::CurrentUser;
 use crate::core::ServerState;
 use crate::db::repository::employee;
+use crate::services::EmployeePhotoService;
 use crate::utils::validation::{
     MAX_NAME_LEN, MAX_PASSWORD_LEN, validate_optional_text, validate_required_text,
 };
 use crate::utils::{AppError, AppResult};
 use shared::error::ErrorCode;
 use shared::message::SyncChangeType;
-use shared::models::{Employee, EmployeeCreate, EmployeeUpdate};
+use shared::models::{Employee, EmployeeCreate, EmployeePhotoUpload, EmployeeUpdate};
 
 use shared::cloud::SyncResource;
 const RESOURCE: SyncResource = SyncResource::Employee;
 
+fn photo_service(state: &ServerState) -> EmployeePhotoService {
+    EmployeePhotoService::new(state.config.employee_photo_dir())
+}
+
+fn employee_not_found(id: i64) -> AppError {
+    AppError::with_message(
+        ErrorCode::EmployeeNotFound,
+        format!("Employee {} not found", id),
+    )
+}
+
 fn validate_create(payload: &EmployeeCreate) -> AppResult<()> {
     validate_required_text(&payload.username, "username", MAX_NAME_LEN)?;
     validate_required_text(&payload.password, "password", MAX_PASSWORD_LEN)?;
@@ -151,9 +166,14 @@ pub async fn delete(
             None
         }
     };
+    let photo = emp_for_audit.as_ref().and_then(|e| e.photo.clone());
     let result = employee::delete(&state.pool, id).await?;
 
     if result {
+        if let Some(photo) = photo {
+            release_photo(&state, &photo).await;
+        }
+
         let id_str = id.to_string();
         let (name, username) = emp_for_audit
             .map(|e| (e.name, e.username))
@@ -175,3 +195,115 @@ pub async fn delete(
 
     Ok(Json(result))
 }
+
+/// 删除不再被任何员工引用的照片文件
+async fn release_photo(state: &ServerState, photo: &str) {
+    match employee::photo_in_use(&state.pool, photo).await {
+        Ok(false) => photo_service(state).remove(photo).await,
+        Ok(true) => {}
+        Err(e) => tracing::warn!(photo = %photo, "Failed to check employee photo usage: {e}"),
+    }
+}
+
+/// GET /api/employees/:id/photo - 员工照片 (仅本人或管理员)
+pub async fn get_photo(
+    State(state): State<ServerState>,
+    Extension(current_user): Extension<CurrentUser>,
+    Path(id): Path<i64>,
+) -> AppResult<impl IntoResponse> {
+    if current_user.id != id && !current_user.is_admin() {
+        return Err(AppError::permission_denied(
+            "Only the employee or an admin can view this photo",
+        ));
+    }
+    let emp = employee::find_by_id(&state.pool, id)
+        .await?
+        .ok_or_else(|| employee_not_found(id))?;
+    let bytes = match emp.photo.as_deref() {
+        Some(photo) => photo_service(&state).read(photo).await,
+        None => None,
+    }
+    .ok_or_else(|| AppError::not_found(format!("Photo of employee {id}")))?;
+    Ok((
+        [
+            (header::CONTENT_TYPE, "image/jpeg"),
+            (header::CACHE_CONTROL, "private, no-store"),
+        ],
+        bytes,
+    ))
+}
+
+/// PUT /api/employees/:id/photo - 上传 / 替换员工照片
+pub async fn upload_photo(
+    State(state): State<ServerState>,
+    Extension(current_user): Extension<CurrentUser>,
+    Path(id): Path<i64>,
+    Json(payload): Json<EmployeePhotoUpload>,
+) -> AppResult<Json<Employee>> {
+    let content = base64::engine::general_purpose::STANDARD
+        .decode(payload.content_base64.as_bytes())
+        .map_err(|e| AppError::validation(format!("Invalid content_base64: {e}")))?;
+    if employee::find_by_id(&state.pool, id).await?.is_none() {
+        return Err(employee_not_found(id));
+    }
+
+    let hash = photo_service(&state).store(&content).await?;
+    let previous = employee::set_photo(&state.pool, id, Some(&hash)).await?;
+    if let Some(previous) = previous.filter(|p| *p != hash) {
+        release_photo(&state, &previous).await;
+    }
+    let emp = employee::find_by_id(&state.pool, id)
+        .await?
+        .ok_or_else(|| employee_not_found(id))?;
+
+    let id_str = id.to_string();
+    audit_log!(
+        state.audit_service,
+        AuditAction::EmployeeUpdated,
+        "employee",
+        &id_str,
+        operator_id = Some(current_user.id),
+        operator_name = Some(current_user.name.clone()),
+        details = serde_json::json!({"photo": &hash})
+    );
+
+    state
+        .broadcast_sync(RESOURCE, SyncChangeType::Updated, id, Some(&emp), false)
+        .await;
+
+    Ok(Json(emp))
+}
+
+/// DELETE /api/employees/:id/photo - 删除员工照片
+pub async fn delete_photo(
+    State(state): State<ServerState>,
+    Extension(current_user): Extension<CurrentUser>,
+    Path(id): Path<i64>,
+) -> AppResult<Json<Employee>> {
+    let previous = employee::set_photo(&state.pool, id, None).await?;
+    if let Some(previous) = &previous {
+        release_photo(&state, previous).await;
+    }
+    let emp = employee::find_by_id(&state.pool, id)
+        .await?
+        .ok_or_else(|| employee_not_found(id))?;
+
+    if previous.is_some() {
+        let id_str = id.to_string();
+        audit_log!(
+            state.audit_service,
+            AuditAction::EmployeeUpdated,
+            "employee",
+            &id_str,
+            operator_id = Some(current_user.id),
+            operator_name = Some(current_user.name.clone()),
+            details = serde_json::json!({"photo": null})
+        );
+
+        state
+            .broadcast_sync(RESOURCE, SyncChangeType::Updated, id, Some(&emp), false)
+            .await;
+    }
+
+    Ok(Json(emp))
+}
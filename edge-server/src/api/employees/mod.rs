@@ -2,11 +2,16 @@
 
 mod handler;
 
-use axum::{Router, middleware, routing::get};
+use axum::{Router, extract::DefaultBodyLimit, middleware, routing::get};
 
 use crate::auth::require_admin;
 use crate::core::ServerState;
 
+/// 照片上传 (base64 JSON) 上限，原图大小限制见 [`MAX_EMPLOYEE_PHOTO_UPLOAD_SIZE`]
+///
+/// [`MAX_EMPLOYEE_PHOTO_UPLOAD_SIZE`]: crate::services::employee_photo::MAX_EMPLOYEE_PHOTO_UPLOAD_SIZE
+const PHOTO_BODY_LIMIT: usize = 14 * 1024 * 1024;
+
 /// Employee router
 pub fn router() -> Router<ServerState> {
     Router::new().nest("/api/employees", routes())
@@ -14,10 +19,12 @@ pub fn router() -> Router<ServerState> {
 
 fn routes() -> Router<ServerState> {
     // 读取路由：无需权限检查（查看员工列表是基础操作）
+    // 照片仅本人或管理员可读 (handler 内检查)
     let read_routes = Router::new()
         .route("/", get(handler::list))
         .route("/all", get(handler::list_with_inactive))
-        .route("/{id}", get(handler::get_by_id));
+        .route("/{id}", get(handler::get_by_id))
+        .route("/{id}/photo", get(handler::get_photo));
 
     // 管理路由：仅管理员可用 (users:manage)
     let manage_routes = Router::new()
@@ -28,5 +35,14 @@ fn routes() -> Router<ServerState> {
         )
         .layer(middleware::from_fn(require_admin));
 
-    read_routes.merge(manage_routes)
+    // 照片上传：仅管理员，放宽请求体上限
+    let photo_routes = Router::new()
+        .route(
+            "/{id}/photo",
+            axum::routing::put(handler::upload_photo).delete(handler::delete_photo),
+        )
+        .layer(DefaultBodyLimit::max(PHOTO_BODY_LIMIT))
+        .layer(middleware::from_fn(require_admin));
+
+    read_routes.merge(manage_routes).merge(photo_routes)
 }
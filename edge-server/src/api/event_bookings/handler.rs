@@ -0,0 +1,367 @@
+//! Event Booking API Handlers
+
+use axum::{
+    Json,
+    extract::{Extension, Path, Query, State},
+};
+use serde::Deserialize;
+
+use crate::audit::{AuditAction, create_snapshot};
+use crate::audit_log;
+use crate::auth::CurrentUser;
+use crate::core::ServerState;
+use crate::db::repository::event_booking;
+use crate::utils::validation::{
+    MAX_NAME_LEN, MAX_NOTE_LEN, MAX_SHORT_TEXT_LEN, validate_optional_text, validate_required_text,
+};
+use crate::utils::{AppError, AppResult};
+use crab_order_core::money::MEMBER_CREDIT_METHOD;
+use shared::error::ErrorCode;
+use shared::message::SyncChangeType;
+use shared::models::{
+    EventBooking, EventBookingCreate, EventBookingDeposit, EventBookingDepositCreate,
+    EventBookingDetail, EventBookingItemInput, EventBookingStatus, EventBookingUpdate,
+};
+
+use shared::cloud::SyncResource;
+const RESOURCE: SyncResource = SyncResource::EventBooking;
+
+/// 默认列表回看窗口（昨天起的预订，覆盖当晚仍在进行的活动）
+const DEFAULT_LOOKBACK_MS: i64 = 24 * 60 * 60 * 1000;
+
+#[derive(Debug, Deserialize)]
+pub struct ListQuery {
+    /// Event start lower bound (Unix millis, inclusive)
+    pub from: Option<i64>,
+    /// Event start upper bound (Unix millis, exclusive)
+    pub to: Option<i64>,
+    pub status: Option<EventBookingStatus>,
+}
+
+fn validate_items(items: &[EventBookingItemInput]) -> AppResult<()> {
+    for item in items {
+        validate_required_text(&item.name, "item name", MAX_NAME_LEN)?;
+        validate_optional_text(&item.course, "course", MAX_SHORT_TEXT_LEN)?;
+        validate_optional_text(&item.note, "item note", MAX_NOTE_LEN)?;
+        if item.quantity <= 0 {
+            return Err(AppError::validation(format!(
+                "quantity must be positive for {}",
+                item.name
+            )));
+        }
+        if !item.price.is_finite() || item.price < 0.0 {
+            return Err(AppError::validation(format!(
+                "price must be a non-negative number for {}",
+                item.name
+            )));
+        }
+    }
+    Ok(())
+}
+
+fn validate_headcount(headcount: i32) -> AppResult<()> {
+    if headcount <= 0 {
+        return Err(AppError::validation("headcount must be positive"));
+    }
+    Ok(())
+}
+
+fn validate_create(payload: &EventBookingCreate) -> AppResult<()> {
+    validate_required_text(&payload.event_name, "event_name", MAX_NAME_LEN)?;
+    validate_required_text(&payload.customer_name, "customer_name", MAX_NAME_LEN)?;
+    validate_optional_text(
+        &payload.customer_phone,
+        "customer_phone",
+        MAX_SHORT_TEXT_LEN,
+    )?;
+    validate_optional_text(&payload.table_name, "table_name", MAX_NAME_LEN)?;
+    validate_optional_text(&payload.zone_name, "zone_name", MAX_NAME_LEN)?;
+    validate_optional_text(&payload.note, "note", MAX_NOTE_LEN)?;
+    validate_headcount(payload.headcount)?;
+    validate_items(&payload.items)
+}
+
+fn validate_update(payload: &EventBookingUpdate) -> AppResult<()> {
+    if let Some(name) = &payload.event_name {
+        validate_required_text(name, "event_name", MAX_NAME_LEN)?;
+    }
+    if let Some(name) = &payload.customer_name {
+        validate_required_text(name, "customer_name", MAX_NAME_LEN)?;
+    }
+    validate_optional_text(
+        &payload.customer_phone,
+        "customer_phone",
+        MAX_SHORT_TEXT_LEN,
+    )?;
+    validate_optional_text(&payload.table_name, "table_name", MAX_NAME_LEN)?;
+    validate_optional_text(&payload.zone_name, "zone_name", MAX_NAME_LEN)?;
+    validate_optional_text(&payload.note, "note", MAX_NOTE_LEN)?;
+    if let Some(headcount) = payload.headcount {
+        validate_headcount(headcount)?;
+    }
+    if let Some(items) = &payload.items {
+        validate_items(items)?;
+    }
+    Ok(())
+}
+
+fn validate_deposit(payload: &EventBookingDepositCreate) -> AppResult<()> {
+    validate_required_text(&payload.method, "method", MAX_SHORT_TEXT_LEN)?;
+    validate_optional_text(&payload.reference, "reference", MAX_SHORT_TEXT_LEN)?;
+    validate_optional_text(&payload.note, "note", MAX_NOTE_LEN)?;
+    // 储值余额在订单支付时扣减，预订阶段无法锁定
+    if payload.method == MEMBER_CREDIT_METHOD {
+        return Err(AppError::validation(
+            "Member credit cannot be used as a booking deposit",
+        ));
+    }
+    if !payload.amount.is_finite() || payload.amount <= 0.0 {
+        return Err(AppError::validation("amount must be positive"));
+    }
+    Ok(())
+}
+
+async fn broadcast(state: &ServerState, booking: &EventBooking, change: SyncChangeType) {
+    state
+        .broadcast_sync(RESOURCE, change, booking.id, Some(booking), false)
+        .await;
+}
+
+/// GET /api/event-bookings - 按活动时间列出预订
+pub async fn list(
+    State(state): State<ServerState>,
+    Query(query): Query<ListQuery>,
+) -> AppResult<Json<Vec<EventBooking>>> {
+    let from = query
+        .from
+        .unwrap_or_else(|| shared::util::now_millis() - DEFAULT_LOOKBACK_MS);
+    let to = query.to.unwrap_or(i64::MAX);
+    let bookings = event_booking::find_range(&state.pool, from, to, query.status).await?;
+    Ok(Json(bookings))
+}
+
+/// GET /api/event-bookings/:id - 预订详情（菜单、定金、修改记录）
+pub async fn get_by_id(
+    State(state): State<ServerState>,
+    Path(id): Path<i64>,
+) -> AppResult<Json<EventBookingDetail>> {
+    let detail = event_booking::find_detail(&state.pool, id)
+        .await?
+        .ok_or_else(|| {
+            AppError::with_message(
+                ErrorCode::EventBookingNotFound,
+                format!("Event booking {} not found", id),
+            )
+        })?;
+    Ok(Json(detail))
+}
+
+/// GET /api/event-bookings/:id/beo - BEO 打印数据 (ESC/POS bytes)
+pub async fn get_beo(State(state): State<ServerState>, Path(id): Path<i64>) -> AppResult<Vec<u8>> {
+    let detail = event_booking::find_detail(&state.pool, id)
+        .await?
+        .ok_or_else(|| {
+            AppError::with_message(
+                ErrorCode::EventBookingNotFound,
+                format!("Event booking {} not found", id),
+            )
+        })?;
+
+    let locale = crate::db::repository::store_info::get(&state.pool)
+        .await
+        .ok()
+        .flatten()
+        .and_then(|i| i.receipt_locale)
+        .unwrap_or_else(|| "es-ES".to_string());
+    let renderer = crate::printing::BeoRenderer::new(48, state.config.timezone, locale);
+    Ok(renderer.render(&detail))
+}
+
+/// POST /api/event-bookings - 创建预订（草稿）
+pub async fn create(
+    State(state): State<ServerState>,
+    Extension(current_user): Extension<CurrentUser>,
+    Json(payload): Json<EventBookingCreate>,
+) -> AppResult<Json<EventBookingDetail>> {
+    validate_create(&payload)?;
+
+    let detail =
+        event_booking::create(&state.pool, payload, current_user.id, &current_user.name).await?;
+
+    audit_log!(
+        state.audit_service,
+        AuditAction::EventBookingCreated,
+        "event_booking",
+        &detail.booking.id.to_string(),
+        operator_id = Some(current_user.id),
+        operator_name = Some(current_user.name.clone()),
+        details = create_snapshot(&detail.booking, "event_booking")
+    );
+
+    broadcast(&state, &detail.booking, SyncChangeType::Created).await;
+    Ok(Json(detail))
+}
+
+/// PUT /api/event-bookings/:id - 修改预订（每次实际变更生成一个新版本）
+pub async fn update(
+    State(state): State<ServerState>,
+    Extension(current_user): Extension<CurrentUser>,
+    Path(id): Path<i64>,
+    Json(payload): Json<EventBookingUpdate>,
+) -> AppResult<Json<EventBookingDetail>> {
+    validate_update(&payload)?;
+
+    let (detail, changes) = event_booking::update(
+        &state.pool,
+        id,
+        payload,
+        current_user.id,
+        &current_user.name,
+    )
+    .await?;
+
+    if !changes.is_empty() {
+        audit_log!(
+            state.audit_service,
+            AuditAction::EventBookingUpdated,
+            "event_booking",
+            &id.to_string(),
+            operator_id = Some(current_user.id),
+            operator_name = Some(current_user.name.clone()),
+            details = serde_json::json!({
+                "revision": detail.booking.revision,
+                "changes": changes,
+            })
+        );
+        broadcast(&state, &detail.booking, SyncChangeType::Updated).await;
+    }
+
+    Ok(Json(detail))
+}
+
+/// POST /api/event-bookings/:id/confirm - 确认预订（进入自动转单队列）
+pub async fn confirm(
+    State(state): State<ServerState>,
+    Extension(current_user): Extension<CurrentUser>,
+    Path(id): Path<i64>,
+) -> AppResult<Json<EventBookingDetail>> {
+    let detail = event_booking::transition(
+        &state.pool,
+        id,
+        &[EventBookingStatus::Draft],
+        EventBookingStatus::Confirmed,
+    )
+    .await?;
+
+    audit_log!(
+        state.audit_service,
+        AuditAction::EventBookingUpdated,
+        "event_booking",
+        &id.to_string(),
+        operator_id = Some(current_user.id),
+        operator_name = Some(current_user.name.clone()),
+        details = serde_json::json!({ "status": detail.booking.status })
+    );
+
+    broadcast(&state, &detail.booking, SyncChangeType::Updated).await;
+    Ok(Json(detail))
+}
+
+/// POST /api/event-bookings/:id/cancel - 取消预订
+///
+/// 已收定金不自动退还，由店员按原支付方式处理。
+pub async fn cancel(
+    State(state): State<ServerState>,
+    Extension(current_user): Extension<CurrentUser>,
+    Path(id): Path<i64>,
+) -> AppResult<Json<EventBookingDetail>> {
+    let detail = event_booking::transition(
+        &state.pool,
+        id,
+        &[EventBookingStatus::Draft, EventBookingStatus::Confirmed],
+        EventBookingStatus::Cancelled,
+    )
+    .await?;
+
+    audit_log!(
+        state.audit_service,
+        AuditAction::EventBookingCancelled,
+        "event_booking",
+        &id.to_string(),
+        operator_id = Some(current_user.id),
+        operator_name = Some(current_user.name.clone()),
+        details = serde_json::json!({
+            "event_name": detail.booking.event_name,
+            "deposit_total": detail.booking.deposit_total,
+        })
+    );
+
+    broadcast(&state, &detail.booking, SyncChangeType::Updated).await;
+    Ok(Json(detail))
+}
+
+/// POST /api/event-bookings/:id/deposits - 收取定金
+pub async fn add_deposit(
+    State(state): State<ServerState>,
+    Extension(current_user): Extension<CurrentUser>,
+    Path(id): Path<i64>,
+    Json(payload): Json<EventBookingDepositCreate>,
+) -> AppResult<Json<EventBookingDeposit>> {
+    validate_deposit(&payload)?;
+
+    let deposit = event_booking::add_deposit(
+        &state.pool,
+        id,
+        &payload,
+        current_user.id,
+        &current_user.name,
+    )
+    .await?;
+
+    audit_log!(
+        state.audit_service,
+        AuditAction::EventBookingDepositRecorded,
+        "event_booking",
+        &id.to_string(),
+        operator_id = Some(current_user.id),
+        operator_name = Some(current_user.name.clone()),
+        details = create_snapshot(&deposit, "event_booking_deposit")
+    );
+
+    if let Some(booking) = event_booking::find_by_id(&state.pool, id).await? {
+        broadcast(&state, &booking, SyncChangeType::Updated).await;
+    }
+    Ok(Json(deposit))
+}
+
+/// POST /api/event-bookings/:id/materialize - 立即转为正式订单（不等待调度器）
+pub async fn materialize(
+    State(state): State<ServerState>,
+    Extension(current_user): Extension<CurrentUser>,
+    Path(id): Path<i64>,
+) -> AppResult<Json<EventBookingDetail>> {
+    let order_id = crate::event_bookings::materialize(&state, id)
+        .await
+        .map_err(|e| AppError::with_message(ErrorCode::EventBookingInvalidState, e))?;
+
+    audit_log!(
+        state.audit_service,
+        AuditAction::EventBookingUpdated,
+        "event_booking",
+        &id.to_string(),
+        operator_id = Some(current_user.id),
+        operator_name = Some(current_user.name.clone()),
+        details =
+            serde_json::json!({ "status": EventBookingStatus::Materialized, "order_id": order_id })
+    );
+
+    let detail = event_booking::find_detail(&state.pool, id)
+        .await?
+        .ok_or_else(|| {
+            AppError::with_message(
+                ErrorCode::EventBookingNotFound,
+                format!("Event booking {} not found", id),
+            )
+        })?;
+    Ok(Json(detail))
+}
@@ -0,0 +1,38 @@
+//! Event Booking API Module
+//!
+//! 宴会预订 — 预订单（菜单/人数/定金）、修改记录、转正式订单、BEO 打印
+
+mod handler;
+
+use axum::{
+    Router, middleware,
+    routing::{get, post},
+};
+
+use crate::auth::require_permission;
+use crate::core::ServerState;
+
+/// Event booking router
+pub fn router() -> Router<ServerState> {
+    Router::new().nest("/api/event-bookings", routes())
+}
+
+fn routes() -> Router<ServerState> {
+    // 读取路由：前台与厨房均可查看预订和 BEO
+    let read_routes = Router::new()
+        .route("/", get(handler::list))
+        .route("/{id}", get(handler::get_by_id))
+        .route("/{id}/beo", get(handler::get_beo));
+
+    // 管理路由：需要 bookings:manage 权限
+    let manage_routes = Router::new()
+        .route("/", post(handler::create))
+        .route("/{id}", axum::routing::put(handler::update))
+        .route("/{id}/confirm", post(handler::confirm))
+        .route("/{id}/cancel", post(handler::cancel))
+        .route("/{id}/deposits", post(handler::add_deposit))
+        .route("/{id}/materialize", post(handler::materialize))
+        .layer(middleware::from_fn(require_permission("bookings:manage")));
+
+    read_routes.merge(manage_routes)
+}
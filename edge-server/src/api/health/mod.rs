@@ -6,6 +6,7 @@
 //! |------|------|------|------|
 //! | /health | GET | 简单健康检查 | 无 |
 //! | /health/detailed | GET | 详细健康检查 | 无 |
+//! | /status | GET | 公开可用性状态 (需 `PUBLIC_STATUS`) | 无 |
 //!
 //! # 响应示例
 //!
@@ -14,15 +15,24 @@
 //!   "status": "ok",
 //!   "version": "0.1.0",
 //!   "is_activated": true,
-//!   "tenant_id": 123456789
+//!   "tenant_id": 123456789,
+//!   "ready": true
 //! }
 //! ```
+//!
+//! 启动预热期间 `ready = false`，`/health/detailed` 的 `components`
+//! 列出各组件状态 (pending / ready / failed)，`client_queues` 列出各 TCP
+//! 连接的出站队列积压，`load_shed` 列出订单命令耗时与低优先级通道状态。
 
 use axum::{Json, Router, extract::State, routing::get};
 use serde::Serialize;
-use std::time::SystemTime;
+use std::collections::BTreeMap;
 
-use crate::core::ServerState;
+use crate::core::load_shed::LoadShedStatus;
+use crate::core::{Component, ComponentState, ServerState};
+use crate::db::repository::delivery_rule;
+use crate::message::ClientQueueStats;
+use crate::utils::AppError;
 use shared::activation::SubscriptionInfo;
 
 /// 健康检查路由 - 公共路由 (无需认证)
@@ -30,6 +40,7 @@ pub fn router() -> Router<ServerState> {
     Router::new()
         .route("/health", get(health))
         .route("/health/detailed", get(detailed_health))
+        .route("/status", get(public_status))
 }
 
 /// 简单健康检查响应
@@ -52,6 +63,8 @@ pub struct HealthResponse {
     /// 订阅状态 (如果已激活且有订阅信息)
     #[serde(skip_serializing_if = "Option::is_none")]
     subscription: Option<SubscriptionInfo>,
+    /// 启动预热是否完成 (false 时业务 API 返回 503)
+    ready: bool,
 }
 
 /// 详细健康检查响应
@@ -64,6 +77,44 @@ pub struct DetailedHealthResponse {
     uptime_seconds: u64,
     /// 各组件检查结果
     checks: HealthChecks,
+    /// 启动组件就绪状态
+    components: BTreeMap<Component, ComponentState>,
+    /// TCP 客户端出站队列指标
+    client_queues: Vec<ClientQueueStats>,
+    /// 负载保护状态
+    load_shed: LoadShedStatus,
+}
+
+/// 公开状态响应 — 供第三方平台轮询，不含租户/设备身份
+#[derive(Serialize)]
+pub struct PublicStatusResponse {
+    /// open | unavailable
+    status: &'static str,
+    version: &'static str,
+    /// 消息总线协议版本
+    protocol_version: u16,
+    uptime_seconds: u64,
+    /// 当前是否接受外部下单
+    accepting_orders: bool,
+    /// 拒单原因 (warming_up | not_activated | subscription_blocked)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    reason: Option<&'static str>,
+    /// 各下单集成 (QR 点餐 / 外卖渠道) 的接单状态
+    integrations: Vec<IntegrationStatus>,
+}
+
+/// 单个下单集成的接单状态
+#[derive(Serialize)]
+pub struct IntegrationStatus {
+    /// qr_ordering | delivery
+    kind: &'static str,
+    /// 外卖渠道 (仅 delivery，与订单 `platform` 元数据一致)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    channel: Option<String>,
+    accepting_orders: bool,
+    /// 拒单原因 (节点级原因，或 delivery 的 channel_disabled)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    reason: Option<&'static str>,
 }
 
 /// 健康检查详情
@@ -112,17 +163,6 @@ impl CheckResult {
     }
 }
 
-// 服务器启动时间 (懒加载静态变量)
-static START_TIME: std::sync::OnceLock<SystemTime> = std::sync::OnceLock::new();
-
-fn get_uptime_seconds() -> u64 {
-    let start = START_TIME.get_or_init(SystemTime::now);
-    SystemTime::now()
-        .duration_since(*start)
-        .map(|d| d.as_secs())
-        .unwrap_or(0)
-}
-
 /// 基础健康检查
 ///
 /// 现在包含激活状态信息，以便客户端查询边缘节点身份
@@ -148,6 +188,7 @@ pub async fn health(State(state): State<ServerState>) -> Json<HealthResponse> {
         edge_id: activation.edge_id,
         is_activated: activation.is_activated,
         subscription,
+        ready: state.readiness.is_warm(),
     })
 }
 
@@ -167,15 +208,84 @@ pub async fn detailed_health(State(state): State<ServerState>) -> Json<DetailedH
     let bus_check = CheckResult::ok(); // 只要服务器在运行，消息总线总是就绪的
 
     let all_ok = db_check.status == "ok" && bus_check.status == "ok";
+    let components = state.readiness.snapshot();
+    let status = if !all_ok
+        || components
+            .values()
+            .any(|c| matches!(c, ComponentState::Failed { .. }))
+    {
+        "degraded"
+    } else if !state.readiness.is_warm() {
+        "warming_up"
+    } else {
+        "healthy"
+    };
 
     Json(DetailedHealthResponse {
-        status: if all_ok { "healthy" } else { "degraded" },
+        status,
         version: env!("CARGO_PKG_VERSION"),
         git_hash: shared::GIT_HASH,
-        uptime_seconds: get_uptime_seconds(),
+        uptime_seconds: state.readiness.uptime().as_secs(),
         checks: HealthChecks {
             database: db_check,
             message_bus: bus_check,
         },
+        components,
+        client_queues: state.message_bus().client_queue_stats(),
+        load_shed: state.load_shedder.status(),
     })
 }
+
+/// 公开可用性状态 (未开启 `PUBLIC_STATUS` 时返回 404)
+pub async fn public_status(
+    State(state): State<ServerState>,
+) -> Result<Json<PublicStatusResponse>, AppError> {
+    if !state.config.public_status {
+        return Err(AppError::not_found("Status page"));
+    }
+
+    let reason = if !state.readiness.is_warm() {
+        Some("warming_up")
+    } else if !state.is_activated().await {
+        Some("not_activated")
+    } else if state.is_subscription_blocked().await {
+        Some("subscription_blocked")
+    } else {
+        None
+    };
+
+    let mut integrations = vec![IntegrationStatus {
+        kind: "qr_ordering",
+        channel: None,
+        accepting_orders: reason.is_none(),
+        reason,
+    }];
+    // 外卖渠道按规则配置列出，渠道下所有规则均停用时不接单
+    let mut channels: BTreeMap<String, bool> = BTreeMap::new();
+    for rule in delivery_rule::find_all(&state.pool).await? {
+        *channels.entry(rule.channel.to_lowercase()).or_default() |= rule.is_active;
+    }
+    integrations.extend(channels.into_iter().map(|(channel, active)| {
+        let reason = reason.or((!active).then_some("channel_disabled"));
+        IntegrationStatus {
+            kind: "delivery",
+            channel: Some(channel),
+            accepting_orders: reason.is_none(),
+            reason,
+        }
+    }));
+
+    Ok(Json(PublicStatusResponse {
+        status: if reason.is_none() {
+            "open"
+        } else {
+            "unavailable"
+        },
+        version: env!("CARGO_PKG_VERSION"),
+        protocol_version: shared::message::PROTOCOL_VERSION,
+        uptime_seconds: state.readiness.uptime().as_secs(),
+        accepting_orders: reason.is_none(),
+        reason,
+        integrations,
+    }))
+}
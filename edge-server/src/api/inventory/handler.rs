@@ -0,0 +1,222 @@
+//! Inventory API Handlers
+
+use axum::{
+    Json,
+    extract::{Extension, Path, Query, State},
+};
+use serde::Deserialize;
+
+use crate::audit::{AuditAction, create_diff, create_snapshot};
+use crate::audit_log;
+use crate::auth::CurrentUser;
+use crate::core::ServerState;
+use crate::db::repository::inventory;
+use crate::inventory::{RESOURCE, notify_low_stock};
+use crate::utils::validation::{MAX_NOTE_LEN, MAX_SHORT_TEXT_LEN, validate_optional_text};
+use crate::utils::{AppError, AppResult};
+use shared::message::SyncChangeType;
+use shared::models::{
+    StockAdjustment, StockAdjustmentCreate, StockLevel, StockLevelCreate, StockLevelUpdate,
+};
+
+/// 调整记录默认条数
+const DEFAULT_ADJUSTMENT_LIMIT: i64 = 100;
+
+#[derive(Debug, Deserialize)]
+pub struct AdjustmentsQuery {
+    pub limit: Option<i64>,
+}
+
+fn stock_level_not_found(id: i64) -> AppError {
+    AppError::not_found(format!("Stock level {}", id))
+}
+
+/// GET /api/inventory - 全部库存
+pub async fn list(State(state): State<ServerState>) -> AppResult<Json<Vec<StockLevel>>> {
+    let levels = inventory::find_all(&state.pool).await?;
+    Ok(Json(levels))
+}
+
+/// GET /api/inventory/low - 低于阈值的库存
+pub async fn list_low(State(state): State<ServerState>) -> AppResult<Json<Vec<StockLevel>>> {
+    let levels = inventory::find_low(&state.pool).await?;
+    Ok(Json(levels))
+}
+
+/// GET /api/inventory/:id/adjustments - 调整记录 (最新在前)
+pub async fn adjustments(
+    State(state): State<ServerState>,
+    Path(id): Path<i64>,
+    Query(query): Query<AdjustmentsQuery>,
+) -> AppResult<Json<Vec<StockAdjustment>>> {
+    let limit = query
+        .limit
+        .unwrap_or(DEFAULT_ADJUSTMENT_LIMIT)
+        .clamp(1, 1000);
+    let adjustments = inventory::find_adjustments(&state.pool, id, limit).await?;
+    Ok(Json(adjustments))
+}
+
+/// POST /api/inventory - 开始跟踪商品 / 规格库存
+pub async fn create(
+    State(state): State<ServerState>,
+    Extension(current_user): Extension<CurrentUser>,
+    Json(payload): Json<StockLevelCreate>,
+) -> AppResult<Json<StockLevel>> {
+    validate_optional_text(&payload.barcode, "barcode", MAX_SHORT_TEXT_LEN)?;
+    let product = state
+        .catalog_service
+        .get_product(payload.product_id)
+        .ok_or_else(|| AppError::not_found(format!("Product {}", payload.product_id)))?;
+    let spec_name = match payload.spec_id {
+        Some(spec_id) => Some(
+            product
+                .specs
+                .iter()
+                .find(|s| s.id == spec_id)
+                .ok_or_else(|| AppError::not_found(format!("Spec {}", spec_id)))?
+                .name
+                .clone(),
+        ),
+        None => None,
+    };
+
+    let level = inventory::create(
+        &state.pool,
+        &payload,
+        &product.name,
+        spec_name.as_deref(),
+        current_user.id,
+        &current_user.name,
+    )
+    .await?;
+
+    audit_log!(
+        state.audit_service,
+        AuditAction::StockLevelCreated,
+        "stock_level",
+        &level.id.to_string(),
+        operator_id = Some(current_user.id),
+        operator_name = Some(current_user.name.clone()),
+        details = create_snapshot(&level, "stock_level")
+    );
+
+    state
+        .broadcast_sync(
+            RESOURCE,
+            SyncChangeType::Created,
+            level.id,
+            Some(&level),
+            false,
+        )
+        .await;
+
+    Ok(Json(level))
+}
+
+/// PUT /api/inventory/:id - 更新低库存阈值与条码
+pub async fn update(
+    State(state): State<ServerState>,
+    Extension(current_user): Extension<CurrentUser>,
+    Path(id): Path<i64>,
+    Json(payload): Json<StockLevelUpdate>,
+) -> AppResult<Json<StockLevel>> {
+    validate_optional_text(&payload.barcode, "barcode", MAX_SHORT_TEXT_LEN)?;
+    let old_level = inventory::find_by_id(&state.pool, id)
+        .await?
+        .ok_or_else(|| stock_level_not_found(id))?;
+    let level = inventory::update(&state.pool, id, &payload).await?;
+
+    audit_log!(
+        state.audit_service,
+        AuditAction::StockLevelUpdated,
+        "stock_level",
+        &id.to_string(),
+        operator_id = Some(current_user.id),
+        operator_name = Some(current_user.name.clone()),
+        details = create_diff(&old_level, &level, "stock_level")
+    );
+
+    state
+        .broadcast_sync(RESOURCE, SyncChangeType::Updated, id, Some(&level), false)
+        .await;
+
+    Ok(Json(level))
+}
+
+/// DELETE /api/inventory/:id - 停止跟踪库存
+pub async fn delete(
+    State(state): State<ServerState>,
+    Extension(current_user): Extension<CurrentUser>,
+    Path(id): Path<i64>,
+) -> AppResult<Json<bool>> {
+    let level = inventory::find_by_id(&state.pool, id)
+        .await?
+        .ok_or_else(|| stock_level_not_found(id))?;
+    inventory::delete(&state.pool, id).await?;
+
+    audit_log!(
+        state.audit_service,
+        AuditAction::StockLevelDeleted,
+        "stock_level",
+        &id.to_string(),
+        operator_id = Some(current_user.id),
+        operator_name = Some(current_user.name.clone()),
+        details = serde_json::json!({
+            "product_name": level.product_name,
+            "spec_name": level.spec_name,
+            "quantity": level.quantity,
+        })
+    );
+
+    state
+        .broadcast_sync::<()>(RESOURCE, SyncChangeType::Deleted, id, None, false)
+        .await;
+
+    Ok(Json(true))
+}
+
+/// POST /api/inventory/:id/adjust - 手动调整 (进货、报损)
+pub async fn adjust(
+    State(state): State<ServerState>,
+    Extension(current_user): Extension<CurrentUser>,
+    Path(id): Path<i64>,
+    Json(payload): Json<StockAdjustmentCreate>,
+) -> AppResult<Json<StockLevel>> {
+    validate_optional_text(&payload.note, "note", MAX_NOTE_LEN)?;
+
+    let level = inventory::adjust(
+        &state.pool,
+        id,
+        payload.delta,
+        payload.note.as_deref(),
+        current_user.id,
+        &current_user.name,
+    )
+    .await?;
+
+    audit_log!(
+        state.audit_service,
+        AuditAction::StockAdjusted,
+        "stock_level",
+        &id.to_string(),
+        operator_id = Some(current_user.id),
+        operator_name = Some(current_user.name.clone()),
+        details = serde_json::json!({
+            "product_name": level.product_name,
+            "spec_name": level.spec_name,
+            "delta": payload.delta,
+            "quantity_after": level.quantity,
+            "note": payload.note,
+        })
+    );
+
+    state
+        .broadcast_sync(RESOURCE, SyncChangeType::Updated, id, Some(&level), false)
+        .await;
+    if level.became_low(level.quantity - payload.delta) {
+        notify_low_stock(state.message_bus(), &level).await;
+    }
+
+    Ok(Json(level))
+}
@@ -0,0 +1,35 @@
+//! Inventory API Module
+//!
+//! 库存 — 订单完成自动扣减，经理手动调整 (进货、报损)；盘点见 `stock_counts`，门店间调拨见 `stock_transfers`
+
+mod handler;
+
+use axum::{
+    Router, middleware,
+    routing::{get, post, put},
+};
+
+use crate::auth::require_permission;
+use crate::core::ServerState;
+
+/// Inventory router
+pub fn router() -> Router<ServerState> {
+    Router::new().nest("/api/inventory", routes())
+}
+
+fn routes() -> Router<ServerState> {
+    // 读取路由：无需权限检查
+    let read_routes = Router::new()
+        .route("/", get(handler::list))
+        .route("/low", get(handler::list_low))
+        .route("/{id}/adjustments", get(handler::adjustments));
+
+    // 管理路由：需要 menu:manage 权限
+    let manage_routes = Router::new()
+        .route("/", post(handler::create))
+        .route("/{id}", put(handler::update).delete(handler::delete))
+        .route("/{id}/adjust", post(handler::adjust))
+        .layer(middleware::from_fn(require_permission("menu:manage")));
+
+    read_routes.merge(manage_routes)
+}
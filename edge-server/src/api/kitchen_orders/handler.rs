@@ -7,7 +7,7 @@
 //! - Label record management
 //!
 //! For archived orders (redb records cleaned up), falls back to rebuilding
-//! from archived_order_event ITEMS_ADDED payloads.
+//! from archived_order_event ITEMS_ADDED / COURSE_FIRED payloads.
 
 use std::collections::HashMap;
 
@@ -24,7 +24,7 @@ use crate::printing::{
 };
 use crate::utils::{AppError, AppResult};
 use shared::error::ErrorCode;
-use shared::order::EventPayload;
+use shared::order::{CartItemSnapshot, EventPayload};
 
 /// Query params for listing kitchen orders
 #[derive(Debug, Deserialize)]
@@ -124,7 +124,7 @@ pub async fn reprint(
         // Fallback: the `id` might be an event_id from archived events.
         let event_row = sqlx::query_as::<_, (i64, i64, Option<String>)>(
             "SELECT e.id, e.timestamp, e.data FROM archived_order_event e \
-             WHERE e.event_type = 'ITEMS_ADDED' AND e.id = ?",
+             WHERE e.event_type IN ('ITEMS_ADDED', 'COURSE_FIRED') AND e.id = ?",
         )
         .bind(id)
         .fetch_optional(&state.pool)
@@ -182,13 +182,7 @@ pub async fn reprint(
         .map(|d| (d.id.to_string(), d))
         .collect();
 
-    let locale = crate::db::repository::store_info::get(&state.pool)
-        .await
-        .ok()
-        .flatten()
-        .and_then(|i| i.receipt_locale)
-        .unwrap_or_else(|| "es-ES".to_string());
-    let executor = PrintExecutor::with_config(48, state.config.timezone, locale);
+    let executor = kitchen_executor(&state).await;
     if let Err(e) = executor.print_kitchen_order(&order, &dest_map).await {
         tracing::warn!(
             kitchen_order_id = %id,
@@ -201,6 +195,17 @@ pub async fn reprint(
     Ok(Json(true))
 }
 
+/// Kitchen ticket executor with the store's receipt locale
+async fn kitchen_executor(state: &ServerState) -> PrintExecutor {
+    let locale = crate::db::repository::store_info::get(&state.pool)
+        .await
+        .ok()
+        .flatten()
+        .and_then(|i| i.receipt_locale)
+        .unwrap_or_else(|| "es-ES".to_string());
+    PrintExecutor::with_config(48, state.config.timezone, locale)
+}
+
 /// Query params for listing label records
 #[derive(Debug, Deserialize)]
 pub struct LabelListQuery {
@@ -342,7 +347,7 @@ pub async fn reprint_label(
 
 // ============ Archive Fallback Helpers ============
 
-/// Rebuild KitchenOrder list from archived ITEMS_ADDED events
+/// Rebuild KitchenOrder list from archived ITEMS_ADDED / COURSE_FIRED events
 async fn rebuild_kitchen_orders_from_archive(
     state: &ServerState,
     order_id: i64,
@@ -367,7 +372,7 @@ async fn rebuild_kitchen_orders_from_archive(
                 continue;
             }
         };
-        let EventPayload::ItemsAdded { items } = payload else {
+        let Some((items, course)) = kitchen_ticket_items(payload) else {
             continue;
         };
 
@@ -394,6 +399,7 @@ async fn rebuild_kitchen_orders_from_archive(
             is_retail: meta.is_retail,
             created_at: event.timestamp,
             items: kitchen_items,
+            course,
             print_count: 0, // Archived — no redb counter
         });
     }
@@ -401,7 +407,7 @@ async fn rebuild_kitchen_orders_from_archive(
     Ok(orders)
 }
 
-/// Rebuild LabelPrintRecord list from archived ITEMS_ADDED events
+/// Rebuild LabelPrintRecord list from archived ITEMS_ADDED / COURSE_FIRED events
 async fn rebuild_label_records_from_archive(
     state: &ServerState,
     order_id: i64,
@@ -426,7 +432,7 @@ async fn rebuild_label_records_from_archive(
                 continue;
             }
         };
-        let EventPayload::ItemsAdded { items } = payload else {
+        let Some((items, _)) = kitchen_ticket_items(payload) else {
             continue;
         };
 
@@ -478,7 +484,7 @@ fn rebuild_single_kitchen_order(
     let data = data.ok_or_else(|| AppError::not_found(format!("Kitchen order {event_id}")))?;
     let payload: EventPayload = serde_json::from_str(data)
         .map_err(|e| AppError::database(format!("Failed to parse event payload: {e}")))?;
-    let EventPayload::ItemsAdded { items } = payload else {
+    let Some((items, course)) = kitchen_ticket_items(payload) else {
         return Err(AppError::not_found(format!("Kitchen order {event_id}")));
     };
 
@@ -500,15 +506,30 @@ fn rebuild_single_kitchen_order(
         is_retail,
         created_at: timestamp,
         items: kitchen_items,
+        course,
         print_count: 0,
     })
 }
 
+/// Extract the items a kitchen ticket event sent to the kitchen
+///
+/// 分道次暂缓的商品不在 ItemsAdded 出单，而是随 CourseFired 出单。
+fn kitchen_ticket_items(payload: EventPayload) -> Option<(Vec<CartItemSnapshot>, Option<i32>)> {
+    match payload {
+        EventPayload::ItemsAdded { items } => Some((
+            items.into_iter().filter(|item| !item.is_held).collect(),
+            None,
+        )),
+        EventPayload::CourseFired { course, items } => Some((items, Some(course))),
+        _ => None,
+    }
+}
+
 /// Build PrintItemContext from CartItemSnapshot using CatalogService
 ///
 /// Mirrors KitchenPrintService::build_print_context but accessible outside the service.
 fn build_print_context_from_catalog(
-    item: &shared::order::CartItemSnapshot,
+    item: &CartItemSnapshot,
     catalog: &crate::services::CatalogService,
 ) -> PrintItemContext {
     let product = catalog.get_product(item.id);
@@ -128,7 +128,9 @@ pub async fn update(
     // sync_refs is called inside label_template::update() and returns removed hashes
     // We need to check if those removed hashes are truly orphaned (not referenced elsewhere)
     let old_hashes: std::collections::HashSet<String> =
-        crate::db::repository::label_template::extract_image_hashes_from_fields(&old_template.fields);
+        crate::db::repository::label_template::extract_image_hashes_from_fields(
+            &old_template.fields,
+        );
     let new_hashes: std::collections::HashSet<String> =
         crate::db::repository::label_template::extract_image_hashes_from_fields(&template.fields);
     let removed: Vec<String> = old_hashes.difference(&new_hashes).cloned().collect();
@@ -189,8 +191,7 @@ pub async fn delete(
             .into_iter()
             .collect();
         if !removed_hashes.is_empty()
-            && let Ok(orphans) =
-                image_ref::find_orphan_hashes(&state.pool, &removed_hashes).await
+            && let Ok(orphans) = image_ref::find_orphan_hashes(&state.pool, &removed_hashes).await
             && !orphans.is_empty()
         {
             let cleanup = ImageCleanupService::new(state.config.images_dir());
@@ -11,7 +11,7 @@ use crate::auth::CurrentUser;
 use crate::core::ServerState;
 use crate::db::repository::marketing_group;
 use crate::utils::validation::{
-    MAX_NAME_LEN, MAX_NOTE_LEN, MAX_RECEIPT_NAME_LEN, validate_optional_text,
+    MAX_NAME_LEN, MAX_NOTE_LEN, MAX_RECEIPT_NAME_LEN, MAX_SHORT_TEXT_LEN, validate_optional_text,
     validate_required_text,
 };
 use crate::utils::{AppError, AppResult};
@@ -57,13 +57,52 @@ fn validate_rule_update(payload: &MgDiscountRuleUpdate) -> AppResult<()> {
 
 fn validate_activity_create(payload: &shared::models::StampActivityCreate) -> AppResult<()> {
     validate_required_text(&payload.name, "name", MAX_NAME_LEN)?;
-    Ok(())
+    validate_activity_window(
+        payload.valid_from,
+        payload.valid_until,
+        &payload.active_start_time,
+        &payload.active_end_time,
+        payload.max_stamps_per_day,
+    )
 }
 
 fn validate_activity_update(payload: &shared::models::StampActivityUpdate) -> AppResult<()> {
     if let Some(name) = &payload.name {
         validate_required_text(name, "name", MAX_NAME_LEN)?;
     }
+    validate_activity_window(
+        payload.valid_from,
+        payload.valid_until,
+        &payload.active_start_time,
+        &payload.active_end_time,
+        payload.max_stamps_per_day,
+    )
+}
+
+/// Validate stamp activity campaign window and daily cap
+fn validate_activity_window(
+    valid_from: Option<i64>,
+    valid_until: Option<i64>,
+    active_start_time: &Option<String>,
+    active_end_time: &Option<String>,
+    max_stamps_per_day: Option<i32>,
+) -> AppResult<()> {
+    validate_optional_text(active_start_time, "active_start_time", MAX_SHORT_TEXT_LEN)?;
+    validate_optional_text(active_end_time, "active_end_time", MAX_SHORT_TEXT_LEN)?;
+    if let (Some(from), Some(until)) = (valid_from, valid_until)
+        && from > until
+    {
+        return Err(AppError::validation(
+            "valid_from must be before valid_until",
+        ));
+    }
+    if let Some(max) = max_stamps_per_day
+        && max <= 0
+    {
+        return Err(AppError::validation(format!(
+            "max_stamps_per_day must be positive, got {max}"
+        )));
+    }
     Ok(())
 }
 
@@ -84,6 +123,30 @@ fn validate_points_earn_rate(rate: Option<f64>) -> AppResult<()> {
     Ok(())
 }
 
+/// Validate points redemption rule (points_redeem_unit / points_redeem_value)
+fn validate_points_redeem_rule(unit: Option<i64>, value: Option<f64>) -> AppResult<()> {
+    if let Some(u) = unit
+        && u <= 0
+    {
+        return Err(AppError::validation(format!(
+            "points_redeem_unit must be positive, got {u}"
+        )));
+    }
+    if let Some(v) = value {
+        if !v.is_finite() {
+            return Err(AppError::validation(
+                "points_redeem_value must be a finite number",
+            ));
+        }
+        if v <= 0.0 {
+            return Err(AppError::validation(format!(
+                "points_redeem_value must be positive, got {v}"
+            )));
+        }
+    }
+    Ok(())
+}
+
 /// Validate MG discount rule adjustment_value
 fn validate_mg_adjustment(adjustment_type: &AdjustmentType, value: f64) -> AppResult<()> {
     if !value.is_finite() {
@@ -174,6 +237,7 @@ pub async fn create(
 ) -> AppResult<Json<MarketingGroup>> {
     validate_group_create(&payload)?;
     validate_points_earn_rate(payload.points_earn_rate)?;
+    validate_points_redeem_rule(payload.points_redeem_unit, payload.points_redeem_value)?;
 
     let group = marketing_group::create(&state.pool, payload).await?;
 
@@ -211,6 +275,7 @@ pub async fn update(
 ) -> AppResult<Json<MarketingGroup>> {
     validate_group_update(&payload)?;
     validate_points_earn_rate(payload.points_earn_rate)?;
+    validate_points_redeem_rule(payload.points_redeem_unit, payload.points_redeem_value)?;
 
     let old_group = marketing_group::find_by_id(&state.pool, id)
         .await?
@@ -2,6 +2,7 @@
 
 use axum::{
     Json,
+    body::Bytes,
     extract::{Extension, Path, Query, State},
 };
 
@@ -9,7 +10,8 @@ use crate::audit::{AuditAction, create_diff, create_snapshot};
 use crate::audit_log;
 use crate::auth::CurrentUser;
 use crate::core::ServerState;
-use crate::db::repository::{member, stamp};
+use crate::db::repository::{marketing_group, member, member_credit, member_points, stamp};
+use crate::marketing::member_import::{self, MemberImportOptions, MemberImportReport};
 use crate::utils::validation::{
     MAX_EMAIL_LEN, MAX_NAME_LEN, MAX_NOTE_LEN, MAX_SHORT_TEXT_LEN, validate_optional_text,
     validate_required_text,
@@ -22,6 +24,20 @@ use shared::models::MemberWithGroup;
 use shared::cloud::SyncResource;
 const RESOURCE: SyncResource = SyncResource::Member;
 
+/// 收据语言偏好必须是支持的收据语言 (更新时空字符串 = 恢复门店默认)
+fn validate_locale(locale: &Option<String>) -> AppResult<()> {
+    if let Some(locale) = locale
+        && !locale.is_empty()
+        && !shared::models::RECEIPT_LOCALES.contains(&locale.as_str())
+    {
+        return Err(AppError::validation(format!(
+            "preferred_locale must be one of {:?}",
+            shared::models::RECEIPT_LOCALES
+        )));
+    }
+    Ok(())
+}
+
 fn validate_create(payload: &shared::models::MemberCreate) -> AppResult<()> {
     validate_required_text(&payload.name, "name", MAX_NAME_LEN)?;
     validate_optional_text(&payload.phone, "phone", MAX_SHORT_TEXT_LEN)?;
@@ -29,6 +45,7 @@ fn validate_create(payload: &shared::models::MemberCreate) -> AppResult<()> {
     validate_optional_text(&payload.birthday, "birthday", MAX_SHORT_TEXT_LEN)?;
     validate_optional_text(&payload.email, "email", MAX_EMAIL_LEN)?;
     validate_optional_text(&payload.notes, "notes", MAX_NOTE_LEN)?;
+    validate_locale(&payload.preferred_locale)?;
     Ok(())
 }
 
@@ -41,6 +58,7 @@ fn validate_update(payload: &shared::models::MemberUpdate) -> AppResult<()> {
     validate_optional_text(&payload.birthday, "birthday", MAX_SHORT_TEXT_LEN)?;
     validate_optional_text(&payload.email, "email", MAX_EMAIL_LEN)?;
     validate_optional_text(&payload.notes, "notes", MAX_NOTE_LEN)?;
+    validate_locale(&payload.preferred_locale)?;
     Ok(())
 }
 
@@ -262,3 +280,145 @@ pub async fn delete(
 
     Ok(Json(result))
 }
+
+/// POST /api/members/import?dry_run=&on_duplicate=&default_group_id= - 批量导入会员 (body = CSV)
+///
+/// 同步返回校验报告；非 dry_run 时有效行在后台写入，进度通过总线通知推送。
+pub async fn import(
+    State(state): State<ServerState>,
+    Extension(current_user): Extension<CurrentUser>,
+    Query(options): Query<MemberImportOptions>,
+    body: Bytes,
+) -> AppResult<Json<MemberImportReport>> {
+    let rows = tokio::task::spawn_blocking(move || member_import::parse_rows(&body))
+        .await
+        .map_err(|e| AppError::internal(format!("Import parser panicked: {e}")))??;
+    let plan = member_import::plan(&state.pool, &rows, &options).await?;
+
+    let mut report = plan.report;
+    if options.dry_run || plan.actions.is_empty() {
+        return Ok(Json(report));
+    }
+
+    let job_id = uuid::Uuid::new_v4().to_string();
+    report.job_id = Some(job_id.clone());
+    tokio::spawn(member_import::apply(
+        state.clone(),
+        job_id,
+        plan.actions,
+        current_user,
+    ));
+    Ok(Json(report))
+}
+
+// ========== Stored Credit ==========
+
+/// 单次充值上限
+const MAX_TOP_UP_AMOUNT: f64 = 1_000_000.0;
+
+#[derive(Debug, serde::Deserialize)]
+pub struct StatementQuery {
+    /// 起始时间（Unix 毫秒，含；默认全部）
+    pub from: Option<i64>,
+    /// 截止时间（Unix 毫秒，不含；默认当前）
+    pub to: Option<i64>,
+}
+
+/// GET /api/members/:id/credit/statement?from=&to= - 储值对账单
+pub async fn credit_statement(
+    State(state): State<ServerState>,
+    Path(id): Path<i64>,
+    Query(query): Query<StatementQuery>,
+) -> AppResult<Json<shared::models::MemberCreditStatement>> {
+    if member::find_by_id(&state.pool, id).await?.is_none() {
+        return Err(AppError::with_message(
+            ErrorCode::MemberNotFound,
+            format!("Member {} not found", id),
+        ));
+    }
+    let from = query.from.unwrap_or(0);
+    let to = query.to.unwrap_or_else(shared::util::now_millis);
+    if from > to {
+        return Err(AppError::validation("from must not be after to"));
+    }
+
+    let statement = member_credit::statement(&state.pool, id, from, to).await?;
+    Ok(Json(statement))
+}
+
+/// 积分接口返回的最近流水条数
+const POINTS_RECENT_LIMIT: i64 = 50;
+
+/// GET /api/members/:id/points - 积分余额 + 营销组积分规则 + 最近流水
+pub async fn points(
+    State(state): State<ServerState>,
+    Path(id): Path<i64>,
+) -> AppResult<Json<shared::models::MemberPointsSummary>> {
+    let found = member::find_by_id(&state.pool, id).await?.ok_or_else(|| {
+        AppError::with_message(
+            ErrorCode::MemberNotFound,
+            format!("Member {} not found", id),
+        )
+    })?;
+    let group = marketing_group::find_by_id(&state.pool, found.marketing_group_id).await?;
+    let transactions = member_points::find_recent(&state.pool, id, POINTS_RECENT_LIMIT).await?;
+
+    Ok(Json(shared::models::MemberPointsSummary {
+        member_id: id,
+        points_balance: found.points_balance,
+        points_earn_rate: group.as_ref().and_then(|g| g.points_earn_rate),
+        points_redeem_unit: group.as_ref().and_then(|g| g.points_redeem_unit),
+        points_redeem_value: group.as_ref().and_then(|g| g.points_redeem_value),
+        transactions,
+    }))
+}
+
+/// POST /api/members/:id/credit/top-up - 储值充值
+pub async fn credit_top_up(
+    State(state): State<ServerState>,
+    Extension(current_user): Extension<CurrentUser>,
+    Path(id): Path<i64>,
+    Json(payload): Json<shared::models::MemberCreditTopUp>,
+) -> AppResult<Json<shared::models::MemberCreditTransaction>> {
+    if !payload.amount.is_finite() || payload.amount <= 0.0 || payload.amount > MAX_TOP_UP_AMOUNT {
+        return Err(AppError::validation(format!(
+            "amount must be between 0 and {MAX_TOP_UP_AMOUNT}"
+        )));
+    }
+    validate_required_text(&payload.method, "method", MAX_SHORT_TEXT_LEN)?;
+    validate_optional_text(&payload.note, "note", MAX_NOTE_LEN)?;
+
+    let txn = member_credit::top_up(
+        &state.pool,
+        id,
+        payload.amount,
+        &payload.method,
+        payload.note.as_deref(),
+        current_user.id,
+        &current_user.name,
+    )
+    .await?;
+
+    let id_str = id.to_string();
+    audit_log!(
+        state.audit_service,
+        AuditAction::MemberCreditToppedUp,
+        "member",
+        &id_str,
+        operator_id = Some(current_user.id),
+        operator_name = Some(current_user.name.clone()),
+        details = serde_json::json!({
+            "amount": txn.amount,
+            "method": payload.method,
+            "balance_after": txn.balance_after,
+        })
+    );
+
+    if let Some(member) = member::find_by_id(&state.pool, id).await? {
+        state
+            .broadcast_sync(RESOURCE, SyncChangeType::Updated, id, Some(&member), false)
+            .await;
+    }
+
+    Ok(Json(txn))
+}
@@ -3,13 +3,18 @@
 mod handler;
 
 use axum::{
-    Router, middleware,
+    Router,
+    extract::DefaultBodyLimit,
+    middleware,
     routing::{get, post, put},
 };
 
 use crate::auth::require_permission;
 use crate::core::ServerState;
 
+/// 会员导入 CSV 上限 (5 万行远小于此)
+const IMPORT_BODY_LIMIT: usize = 16 * 1024 * 1024;
+
 pub fn router() -> Router<ServerState> {
     Router::new().nest("/api/members", routes())
 }
@@ -19,13 +24,22 @@ fn routes() -> Router<ServerState> {
     let read_routes = Router::new()
         .route("/", get(handler::list))
         .route("/search", get(handler::search))
-        .route("/{id}", get(handler::get_by_id));
+        .route("/{id}", get(handler::get_by_id))
+        .route("/{id}/credit/statement", get(handler::credit_statement))
+        .route("/{id}/points", get(handler::points));
 
     // 管理路由：需要 marketing:manage 权限
     let manage_routes = Router::new()
         .route("/", post(handler::create))
         .route("/{id}", put(handler::update).delete(handler::delete))
+        .route("/{id}/credit/top-up", post(handler::credit_top_up))
+        .layer(middleware::from_fn(require_permission("marketing:manage")));
+
+    // 批量导入：需要 marketing:manage 权限
+    let import_routes = Router::new()
+        .route("/import", post(handler::import))
+        .layer(DefaultBodyLimit::max(IMPORT_BODY_LIMIT))
         .layer(middleware::from_fn(require_permission("marketing:manage")));
 
-    read_routes.merge(manage_routes)
+    read_routes.merge(manage_routes).merge(import_routes)
 }
@@ -0,0 +1,18 @@
+//! Metrics API Handlers
+
+use axum::extract::State;
+use axum::response::IntoResponse;
+
+use crate::core::ServerState;
+
+/// Prometheus 文本格式 Content-Type
+const CONTENT_TYPE: &str = "text/plain; version=0.0.4; charset=utf-8";
+
+/// GET /metrics - Prometheus 指标
+pub async fn get(State(state): State<ServerState>) -> impl IntoResponse {
+    let connected_clients = state.message_bus().clients_count();
+    (
+        [(http::header::CONTENT_TYPE, CONTENT_TYPE)],
+        state.metrics.render(connected_clients),
+    )
+}
@@ -0,0 +1,13 @@
+//! Metrics API 模块 (Prometheus 抓取)
+//!
+//! `/metrics` 不在 `/api/` 下，与 `/health` 一样无需认证，供 crab-desktop 和运维看板抓取。
+
+mod handler;
+
+use axum::{Router, routing::get};
+
+use crate::core::ServerState;
+
+pub fn router() -> Router<ServerState> {
+    Router::new().route("/metrics", get(handler::get))
+}
@@ -3,6 +3,7 @@
 //! # 结构
 //!
 //! - [`health`] - 健康检查和指标
+//! - [`metrics`] - Prometheus 指标
 //! - [`auth`] - 认证相关接口
 //! - [`role`] - 角色管理接口
 //! - [`upload`] - 文件上传接口
@@ -13,42 +14,72 @@
 //! - [`zones`] - 区域管理接口
 //! - [`tables`] - 桌台管理接口
 //! - [`price_rules`] - 价格规则管理接口
+//! - [`pricing`] - 价格规则冲突诊断接口
 //! - [`employees`] - 员工管理接口
 //! - [`orders`] - 订单管理接口
 //! - [`system_state`] - 系统状态接口
+//! - [`setup`] - 首次安装向导接口
+//! - [`admin`] - 运行配置检视接口
 
 pub mod auth;
 pub mod health;
+pub mod metrics;
 pub mod role;
 pub mod upload;
 
 // Data models API
 pub mod attributes;
 pub mod categories;
+pub mod customer_displays;
+pub mod delivery_rules;
+pub mod display_slides;
+pub mod eighty_six;
 pub mod employees;
 pub mod has_attribute;
+pub mod inventory;
 pub mod kitchen_orders;
 pub mod label_template;
 pub mod orders;
+pub mod payment_surcharges;
 pub mod price_rules;
+pub mod pricing;
 pub mod print_config;
 pub mod print_destinations;
+pub mod print_jobs;
+pub mod print_routing;
 pub mod products;
+pub mod receipt_archive;
+pub mod receipt_footers;
+pub mod stock_counts;
+pub mod stock_transfers;
 pub mod store_info;
 pub mod sync;
 pub mod system_state;
 pub mod tables;
 pub mod tags;
+pub mod terminal_profiles;
 pub mod zones;
 
 // Membership & Marketing
+pub mod coupons;
 pub mod marketing_groups;
 pub mod members;
 
 // Operations (班次与日结)
+pub mod cash_denominations;
+pub mod cash_drawer;
 pub mod daily_reports;
 pub mod shifts;
 
+// Event Bookings (宴会预订)
+pub mod event_bookings;
+
+// Reservations (订位)
+pub mod reservations;
+
+// Staff Announcements (员工公告)
+pub mod announcements;
+
 // Analytics (数据统计)
 pub mod statistics;
 
@@ -76,5 +107,36 @@ pub mod audit_log;
 // System Issues (系统问题)
 pub mod system_issues;
 
+// Dead Letters (死信队列)
+pub mod dead_letters;
+
+// Session Recording (现场问题录制)
+pub mod session_recording;
+
+// Remote Support (远程支持)
+pub mod support;
+
+// Recovery (异常关闭恢复报告)
+pub mod recovery;
+
+// PMS (酒店挂房账)
+pub mod pms;
+
+// Pickup (外卖取餐通知)
+pub mod pickup;
+
+// Capabilities (功能发现)
+pub mod capabilities;
+
+// Setup Wizard (首次安装向导)
+pub mod setup;
+
+// Admin (运行配置检视)
+pub mod admin;
+
+// Chaos (故障注入，仅测试构建)
+#[cfg(feature = "chaos")]
+pub mod chaos;
+
 // Re-export common types for handlers
 pub use crate::utils::AppResult;
@@ -2,16 +2,27 @@
 //!
 //! Only provides read-only access to archived orders in SQLite.
 //! All order mutations are handled through OrderManager event sourcing.
-
+//! The only write path is the legacy POS import (historical orders, outside the hash chain).
+//! Receipts can be emailed to the customer (queued for crab-cloud to deliver).
+
+use crate::archiving::import::{self, OrderImportSummary};
+use crate::archiving::{ReceiptArchive, audit_bundle};
+use crate::audit::AuditAction;
+use crate::audit_log;
+use crate::auth::CurrentUser;
 use crate::core::ServerState;
-use crate::db::repository::order;
+use crate::db::repository::{order, store_info};
 use crate::utils::time;
+use crate::utils::validation::MAX_EMAIL_LEN;
 use crate::utils::{AppError, AppResult};
 use axum::{
-    Json,
+    Extension, Json,
+    body::Bytes,
     extract::{Path, Query, State},
 };
 use serde::{Deserialize, Serialize};
+use shared::cloud::email::{EmailTemplateKind, TemplatedEmail};
+use shared::models::StoreInfo;
 
 // =========================================================================
 // Order Detail (Archived)
@@ -68,6 +79,7 @@ pub struct OrderPaymentDetail {
     pub split_items: Vec<SplitItemDetail>,
     pub aa_shares: Option<i32>,
     pub aa_total_shares: Option<i32>,
+    pub reference: Option<String>,
 }
 
 /// Split item detail
@@ -124,6 +136,13 @@ pub struct OrderDetail {
     pub queue_number: Option<i32>,
     pub is_voided: bool,
     pub is_upgraded: bool,
+    pub is_imported: bool,
+    /// Integrator metadata (external references)
+    #[serde(skip_serializing_if = "std::collections::BTreeMap::is_empty")]
+    pub metadata: std::collections::BTreeMap<String, String>,
+    /// 外部税控设备税控码
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub fiscal_code: Option<String>,
     pub items: Vec<OrderItemDetail>,
     pub order_adjustments: Vec<order::OrderDetailAdjustment>,
     pub payments: Vec<OrderPaymentDetail>,
@@ -171,6 +190,9 @@ pub async fn get_by_id(
         queue_number: detail.queue_number,
         is_voided: detail.is_voided,
         is_upgraded: detail.is_upgraded,
+        is_imported: detail.is_imported,
+        metadata: detail.metadata,
+        fiscal_code: detail.fiscal_code,
         items: detail
             .items
             .into_iter()
@@ -230,6 +252,7 @@ pub async fn get_by_id(
                     split_items,
                     aa_shares: p.aa_shares,
                     aa_total_shares: p.aa_total_shares,
+                    reference: p.reference,
                 }
             })
             .collect(),
@@ -248,6 +271,49 @@ pub async fn get_by_id(
     Ok(Json(response))
 }
 
+// =========================================================================
+// Audit Bundle (signed, for third-party verification)
+// =========================================================================
+
+/// GET /api/orders/:id/audit_bundle - 导出订单审计包
+///
+/// 归档事件 + 每步快照 + 操作员 + 收据原文，由边缘服务器证书签名。
+/// 第三方用 `crab-cert` 的 `verify_bundle` 工具验证。
+pub async fn get_audit_bundle(
+    State(state): State<ServerState>,
+    Extension(current_user): Extension<CurrentUser>,
+    Path(id): Path<i64>,
+) -> AppResult<Json<crab_cert::SignedBundle>> {
+    let archive_service = state
+        .orders_manager
+        .archive_service()
+        .ok_or_else(|| AppError::internal("Archive service not available"))?;
+    let receipts = ReceiptArchive::new(state.config.receipt_archive_dir());
+
+    let bundle = audit_bundle::build(&state.pool, archive_service, &receipts, id).await?;
+    let content = serde_json::to_string(&bundle)
+        .map_err(|e| AppError::internal(format!("Serialize audit bundle: {e}")))?;
+    let signed = state.cert_service.sign_bundle(content)?;
+
+    audit_log!(
+        state.audit_service,
+        AuditAction::OrderAuditExported,
+        "order",
+        &id.to_string(),
+        operator_id = Some(current_user.id),
+        operator_name = Some(current_user.name.clone()),
+        details = serde_json::json!({
+            "receipt_number": bundle.order.receipt_number,
+            "events": bundle.events.len(),
+            "receipts": bundle.receipts.len(),
+            "events_chain_valid": bundle.verification.events_chain_valid,
+            "content_sha256": signed.content_sha256,
+        })
+    );
+
+    Ok(Json(signed))
+}
+
 // =========================================================================
 // Order Invoices (F2 + R5)
 // =========================================================================
@@ -393,6 +459,200 @@ pub async fn fetch_order_list(
     }))
 }
 
+// =========================================================================
+// Order Search (客服查单)
+// =========================================================================
+
+/// Default window for amount-only search (7 days)
+const AMOUNT_SEARCH_WINDOW_MS: i64 = 7 * 86_400_000;
+
+/// Query params for order search
+///
+/// At least one of `receipt` / `amount` / `reference` / `meta_key` is required;
+/// all given criteria must match.
+#[derive(Debug, Deserialize)]
+pub struct OrderSearchQuery {
+    /// Receipt number prefix (case-insensitive)
+    pub receipt: Option<String>,
+    /// Exact order total (within 0.005)
+    pub amount: Option<f64>,
+    /// Card last-4 or payment reference (suffix match)
+    pub reference: Option<String>,
+    /// Metadata key (e.g. `delivery_order_id`)
+    pub meta_key: Option<String>,
+    /// Metadata value (exact match; omit to match any order having `meta_key`)
+    pub meta_value: Option<String>,
+    /// Window start as UTC milliseconds (amount-only search defaults to last 7 days)
+    pub start_time: Option<i64>,
+    /// Window end as UTC milliseconds (default: now + 1 day)
+    pub end_time: Option<i64>,
+    pub limit: Option<i32>,
+}
+
+#[derive(Debug, sqlx::FromRow)]
+struct OrderSearchRow {
+    #[sqlx(flatten)]
+    summary: OrderSummary,
+    paid_amount: f64,
+    refunded_amount: f64,
+    matched_reference: Option<String>,
+}
+
+/// Search hit with reprint/refund affordances
+#[derive(Debug, Serialize)]
+pub struct OrderSearchResult {
+    #[serde(flatten)]
+    pub summary: OrderSummary,
+    pub paid_amount: f64,
+    pub refunded_amount: f64,
+    /// Payment reference that matched `reference` (or the first one recorded)
+    pub matched_reference: Option<String>,
+    /// Receipt can be reprinted (completed orders only)
+    pub can_reprint: bool,
+    /// Completed and not fully refunded by credit notes
+    pub can_refund: bool,
+}
+
+/// Escape LIKE wildcards so user input matches literally (`ESCAPE '\'`)
+fn escape_like(s: &str) -> String {
+    let mut escaped = String::with_capacity(s.len());
+    for c in s.chars() {
+        if matches!(c, '\\' | '%' | '_') {
+            escaped.push('\\');
+        }
+        escaped.push(c);
+    }
+    escaped
+}
+
+/// Search filters; `receipt` / `reference` are already-escaped LIKE patterns
+struct SearchCriteria<'a> {
+    start_millis: i64,
+    end_millis: i64,
+    receipt: Option<&'a str>,
+    amount: Option<f64>,
+    reference: Option<&'a str>,
+    meta_key: Option<&'a str>,
+    meta_value: Option<&'a str>,
+    limit: i32,
+}
+
+async fn fetch_search_rows(
+    pool: &sqlx::SqlitePool,
+    criteria: &SearchCriteria<'_>,
+) -> Result<Vec<OrderSearchRow>, sqlx::Error> {
+    sqlx::query_as::<_, OrderSearchRow>(
+        "SELECT o.id AS order_id, o.receipt_number, o.table_name, UPPER(o.status) AS status, o.is_retail, \
+         o.total_amount AS total, o.guest_count, o.start_time, o.end_time, o.void_type, o.loss_reason, o.loss_amount, \
+         o.paid_amount, \
+         (SELECT COALESCE(SUM(cn.total_credit), 0.0) FROM credit_note cn WHERE cn.original_order_pk = o.id) AS refunded_amount, \
+         (SELECT p.reference FROM archived_order_payment p \
+          WHERE p.order_pk = o.id AND p.cancelled = 0 AND p.reference IS NOT NULL AND (?5 IS NULL OR p.reference LIKE ?5 ESCAPE '\\') \
+          ORDER BY p.seq LIMIT 1) AS matched_reference \
+         FROM archived_order o \
+         WHERE o.end_time >= ?1 AND o.end_time < ?2 \
+         AND (?3 IS NULL OR LOWER(o.receipt_number) LIKE ?3 ESCAPE '\\') \
+         AND (?4 IS NULL OR ABS(o.total_amount - ?4) < 0.005) \
+         AND (?5 IS NULL OR EXISTS (SELECT 1 FROM archived_order_payment p \
+              WHERE p.order_pk = o.id AND p.cancelled = 0 AND p.reference LIKE ?5 ESCAPE '\\')) \
+         AND (?7 IS NULL OR EXISTS (SELECT 1 FROM json_each(o.metadata) m \
+              WHERE m.key = ?7 AND (?8 IS NULL OR m.value = ?8))) \
+         ORDER BY o.end_time DESC LIMIT ?6",
+    )
+    .bind(criteria.start_millis)
+    .bind(criteria.end_millis)
+    .bind(criteria.receipt)
+    .bind(criteria.amount)
+    .bind(criteria.reference)
+    .bind(criteria.limit)
+    .bind(criteria.meta_key)
+    .bind(criteria.meta_value)
+    .fetch_all(pool)
+    .await
+}
+
+/// Search archived orders by receipt prefix, exact amount or payment reference
+pub async fn search_orders(
+    State(state): State<ServerState>,
+    Query(params): Query<OrderSearchQuery>,
+) -> AppResult<Json<Vec<OrderSearchResult>>> {
+    let receipt = params
+        .receipt
+        .as_deref()
+        .map(str::trim)
+        .filter(|s| !s.is_empty())
+        .map(|s| format!("{}%", escape_like(&s.to_lowercase())));
+    let reference = params
+        .reference
+        .as_deref()
+        .map(str::trim)
+        .filter(|s| !s.is_empty())
+        .map(|s| format!("%{}", escape_like(s)));
+    let amount = params.amount;
+    let meta_key = params
+        .meta_key
+        .as_deref()
+        .map(str::trim)
+        .filter(|s| !s.is_empty());
+    let meta_value = params.meta_value.as_deref().map(str::trim);
+    if meta_value.is_some() && meta_key.is_none() {
+        return Err(AppError::validation("meta_value requires meta_key"));
+    }
+
+    if receipt.is_none() && reference.is_none() && amount.is_none() && meta_key.is_none() {
+        return Err(AppError::validation(
+            "Provide a receipt number, amount, payment reference or metadata key",
+        ));
+    }
+    if amount.is_some_and(|a| !a.is_finite() || a < 0.0) {
+        return Err(AppError::validation("Amount must be a non-negative number"));
+    }
+
+    let now = shared::util::now_millis();
+    let amount_only =
+        amount.is_some() && receipt.is_none() && reference.is_none() && meta_key.is_none();
+    let start_millis = params.start_time.unwrap_or(if amount_only {
+        now - AMOUNT_SEARCH_WINDOW_MS
+    } else {
+        0
+    });
+    let end_millis = params.end_time.unwrap_or(now + 86_400_000);
+    let limit = params.limit.unwrap_or(20).clamp(1, 100);
+
+    let rows = fetch_search_rows(
+        &state.pool,
+        &SearchCriteria {
+            start_millis,
+            end_millis,
+            receipt: receipt.as_deref(),
+            amount,
+            reference: reference.as_deref(),
+            meta_key,
+            meta_value,
+            limit,
+        },
+    )
+    .await
+    .map_err(|e| AppError::database(e.to_string()))?;
+
+    let results = rows
+        .into_iter()
+        .map(|row| {
+            let completed = row.summary.status == "COMPLETED";
+            OrderSearchResult {
+                can_reprint: completed,
+                can_refund: completed && row.summary.total - row.refunded_amount > 0.005,
+                summary: row.summary,
+                paid_amount: row.paid_amount,
+                refunded_amount: row.refunded_amount,
+                matched_reference: row.matched_reference,
+            }
+        })
+        .collect();
+
+    Ok(Json(results))
+}
+
 // =========================================================================
 // Member Spending History (Archived)
 // =========================================================================
@@ -448,3 +708,244 @@ pub async fn fetch_member_history(
         limit,
     }))
 }
+
+// =========================================================================
+// Legacy POS Import
+// =========================================================================
+
+#[derive(Debug, Deserialize)]
+pub struct ImportQuery {
+    /// Adapter format (`csv` / `json`)
+    pub format: String,
+}
+
+/// Import historical orders from a legacy POS export
+///
+/// POST /api/orders/import?format=csv (body = raw export file)
+pub async fn import_legacy(
+    State(state): State<ServerState>,
+    current_user: CurrentUser,
+    Query(params): Query<ImportQuery>,
+    body: Bytes,
+) -> AppResult<Json<OrderImportSummary>> {
+    let adapter = import::adapter_for(&params.format).ok_or_else(|| {
+        AppError::validation(format!(
+            "Unsupported import format '{}', expected one of: {}",
+            params.format,
+            import::supported_formats().join(", ")
+        ))
+    })?;
+
+    // 大文件解析是 CPU 密集操作，不占用 async 工作线程
+    let tz = state.config.timezone;
+    let orders = tokio::task::spawn_blocking(move || adapter.parse(&body, tz))
+        .await
+        .map_err(|e| AppError::internal(format!("Import parser panicked: {e}")))??;
+
+    let summary = import::import_orders(&state.pool, adapter.format(), &orders).await?;
+
+    audit_log!(
+        state.audit_service,
+        AuditAction::OrdersImported,
+        "order",
+        adapter.format(),
+        operator_id = Some(current_user.id),
+        operator_name = Some(current_user.name.clone()),
+        details = serde_json::json!({
+            "format": summary.format,
+            "parsed": summary.parsed,
+            "imported": summary.imported,
+            "skipped_existing": summary.skipped_existing,
+            "first_order_time": summary.first_order_time,
+            "last_order_time": summary.last_order_time,
+        })
+    );
+
+    Ok(Json(summary))
+}
+
+// =========================================================================
+// Email Receipt
+// =========================================================================
+
+#[derive(Debug, Deserialize)]
+pub struct EmailReceiptRequest {
+    pub to: String,
+}
+
+/// 电子小票邮件 (收件人已校验)
+fn receipt_email(
+    detail: &order::OrderDetail,
+    store: Option<&StoreInfo>,
+    tz: chrono_tz::Tz,
+    to: &str,
+) -> TemplatedEmail {
+    let currency = store
+        .and_then(|s| s.currency_symbol.clone())
+        .unwrap_or_else(|| "€".to_string());
+    let date =
+        chrono::DateTime::from_timestamp_millis(detail.end_time.unwrap_or(detail.start_time))
+            .map(|dt| dt.with_timezone(&tz).format("%d/%m/%Y %H:%M").to_string())
+            .unwrap_or_default();
+    let items = detail
+        .items
+        .iter()
+        .map(|item| {
+            let name = match &item.spec_name {
+                Some(spec) => format!("{} ({spec})", item.name),
+                None => item.name.clone(),
+            };
+            format!(
+                "{} x {name}  {currency}{:.2}",
+                item.quantity, item.line_total
+            )
+        })
+        .collect::<Vec<_>>()
+        .join("\n");
+    let vars = [
+        (
+            "store_name",
+            store.map(|s| s.name.clone()).unwrap_or_default(),
+        ),
+        (
+            "customer_name",
+            detail.member_name.clone().unwrap_or_default(),
+        ),
+        ("receipt_number", detail.receipt_number.clone()),
+        ("date", date),
+        ("total", format!("{currency}{:.2}", detail.total)),
+        ("items", items),
+    ];
+    TemplatedEmail {
+        kind: EmailTemplateKind::Receipt,
+        to: to.to_string(),
+        vars: vars
+            .into_iter()
+            .map(|(name, value)| (name.to_string(), value))
+            .collect(),
+    }
+}
+
+/// POST /api/orders/:id/email-receipt - 发送电子小票 (经 cloud 按租户模板投递)
+pub async fn email_receipt(
+    State(state): State<ServerState>,
+    Path(id): Path<i64>,
+    Json(req): Json<EmailReceiptRequest>,
+) -> AppResult<Json<serde_json::Value>> {
+    let to = req.to.trim();
+    if to.len() > MAX_EMAIL_LEN
+        || to.contains(char::is_whitespace)
+        || !to
+            .split_once('@')
+            .is_some_and(|(user, domain)| !user.is_empty() && domain.contains('.'))
+    {
+        return Err(AppError::validation("Invalid email address"));
+    }
+
+    let detail = order::get_order_detail(&state.pool, id).await?;
+    if detail.status != "COMPLETED" || detail.is_voided {
+        return Err(AppError::business_rule(
+            "Only completed orders have a receipt to send",
+        ));
+    }
+    let store = store_info::get(&state.pool).await?;
+
+    state.mail.enqueue(receipt_email(
+        &detail,
+        store.as_ref(),
+        state.config.timezone,
+        to,
+    ));
+    Ok(Json(serde_json::json!({ "queued": true })))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::db::test_pool;
+    use sqlx::SqlitePool;
+
+    async fn insert_order(pool: &SqlitePool, id: i64, receipt: &str, reference: &str) {
+        sqlx::query(
+            "INSERT INTO archived_order (id, receipt_number, status, total_amount, start_time, end_time, created_at) \
+             VALUES (?1, ?2, 'COMPLETED', 10.0, 1000, 1000, 1000)",
+        )
+        .bind(id)
+        .bind(receipt)
+        .execute(pool)
+        .await
+        .unwrap();
+        sqlx::query(
+            "INSERT INTO archived_order_payment (id, order_pk, seq, payment_id, method, amount, time, reference) \
+             VALUES (?1, ?1, 0, ?2, 'CARD', 10.0, 1000, ?3)",
+        )
+        .bind(id)
+        .bind(format!("pay-{id}"))
+        .bind(reference)
+        .execute(pool)
+        .await
+        .unwrap();
+    }
+
+    async fn search(
+        pool: &SqlitePool,
+        receipt: Option<&str>,
+        reference: Option<&str>,
+    ) -> Vec<String> {
+        let receipt = receipt.map(|r| format!("{}%", escape_like(&r.to_lowercase())));
+        let reference = reference.map(|r| format!("%{}", escape_like(r)));
+        fetch_search_rows(
+            pool,
+            &SearchCriteria {
+                start_millis: 0,
+                end_millis: 2000,
+                receipt: receipt.as_deref(),
+                amount: None,
+                reference: reference.as_deref(),
+                meta_key: None,
+                meta_value: None,
+                limit: 20,
+            },
+        )
+        .await
+        .unwrap()
+        .into_iter()
+        .map(|row| row.summary.receipt_number)
+        .collect()
+    }
+
+    #[test]
+    fn test_escape_like() {
+        assert_eq!(escape_like("R-001"), "R-001");
+        assert_eq!(escape_like("50%_off\\"), "50\\%\\_off\\\\");
+    }
+
+    #[tokio::test]
+    async fn test_search_treats_wildcards_literally() {
+        let pool = test_pool().await;
+        insert_order(&pool, 1, "A_1001", "4242").await;
+        insert_order(&pool, 2, "AB1002", "x%42").await;
+
+        assert_eq!(search(&pool, Some("a_"), None).await, vec!["A_1001"]);
+        assert!(search(&pool, Some("%"), None).await.is_empty());
+        assert_eq!(search(&pool, Some("ab"), None).await, vec!["AB1002"]);
+
+        assert_eq!(search(&pool, None, Some("%42")).await, vec!["AB1002"]);
+        assert!(search(&pool, None, Some("_2")).await.is_empty());
+        assert_eq!(search(&pool, None, Some("242")).await, vec!["A_1001"]);
+    }
+
+    #[tokio::test]
+    async fn test_receipt_email_vars() {
+        let pool = test_pool().await;
+        insert_order(&pool, 1, "FAC-0001", "").await;
+        let detail = order::get_order_detail(&pool, 1).await.unwrap();
+
+        let email = receipt_email(&detail, None, chrono_tz::UTC, "ana@example.com");
+        assert_eq!(email.kind, EmailTemplateKind::Receipt);
+        assert_eq!(email.to, "ana@example.com");
+        assert_eq!(email.vars["receipt_number"], "FAC-0001");
+        assert_eq!(email.vars["total"], "€10.00");
+        assert_eq!(email.vars["date"], "01/01/1970 00:00");
+    }
+}
@@ -1,23 +1,48 @@
 //! Order API Module
 //!
 //! Read-only access to archived orders. All mutations go through OrderManager.
+//! Exception: legacy POS import backfills historical orders (settings:manage).
 
 mod handler;
 
-use axum::{Router, routing::get};
+use axum::{
+    Router,
+    extract::DefaultBodyLimit,
+    middleware,
+    routing::{get, post},
+};
 
+use crate::auth::require_permission;
 use crate::core::ServerState;
 
+/// 历史订单导入文件大小上限
+const IMPORT_BODY_LIMIT: usize = 64 * 1024 * 1024;
+
 /// Order router
 pub fn router() -> Router<ServerState> {
     Router::new().nest("/api/orders", routes())
 }
 
 fn routes() -> Router<ServerState> {
-    // 订单历史查询：无需权限检查（基础操作）
-    Router::new()
+    // 历史订单导入：需要 settings:manage 权限
+    let import_routes = Router::new()
+        .route("/import", post(handler::import_legacy))
+        .layer(DefaultBodyLimit::max(IMPORT_BODY_LIMIT))
+        .layer(middleware::from_fn(require_permission("settings:manage")));
+
+    // 审计包导出：需要完整财务权限
+    let audit_routes = Router::new()
+        .route("/{id}/audit_bundle", get(handler::get_audit_bundle))
+        .layer(middleware::from_fn(require_permission(
+            "reports:financials",
+        )));
+
+    // 订单历史查询 / 电子小票：无需权限检查（基础操作）
+    let read_routes = Router::new()
         // Order history (archived orders)
         .route("/history", get(handler::fetch_order_list))
+        // Customer service lookup (receipt prefix / amount / card last-4)
+        .route("/search", get(handler::search_orders))
         // Member spending history
         .route(
             "/member/{member_id}/history",
@@ -27,4 +52,8 @@ fn routes() -> Router<ServerState> {
         .route("/{id}", get(handler::get_by_id))
         // Invoices linked to an order (F2 + R5)
         .route("/{id}/invoices", get(handler::get_order_invoices))
+        // Email the receipt to the customer (delivered by crab-cloud)
+        .route("/{id}/email-receipt", post(handler::email_receipt));
+
+    read_routes.merge(import_routes).merge(audit_routes)
 }
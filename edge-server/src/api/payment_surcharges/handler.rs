@@ -0,0 +1,99 @@
+//! Payment Surcharge API Handlers
+
+use axum::{
+    Json,
+    extract::{Extension, Path, State},
+};
+
+use crate::audit::{AuditAction, create_diff, create_snapshot};
+use crate::audit_log;
+use crate::auth::CurrentUser;
+use crate::core::ServerState;
+use crate::db::repository::payment_surcharge;
+use crate::utils::validation::{MAX_SHORT_TEXT_LEN, validate_required_text};
+use crate::utils::{AppError, AppResult};
+use shared::models::{
+    PaymentSurchargeRule, PaymentSurchargeRuleCreate, PaymentSurchargeRuleUpdate,
+};
+
+/// GET /api/payment-surcharges - 全部附加费规则
+pub async fn list(State(state): State<ServerState>) -> AppResult<Json<Vec<PaymentSurchargeRule>>> {
+    let rules = payment_surcharge::find_all(&state.pool).await?;
+    Ok(Json(rules))
+}
+
+/// POST /api/payment-surcharges - 新增附加费规则 (每种支付方式一条)
+pub async fn create(
+    State(state): State<ServerState>,
+    Extension(current_user): Extension<CurrentUser>,
+    Json(payload): Json<PaymentSurchargeRuleCreate>,
+) -> AppResult<Json<PaymentSurchargeRule>> {
+    validate_required_text(
+        &payload.payment_method,
+        "payment_method",
+        MAX_SHORT_TEXT_LEN,
+    )?;
+
+    let rule = payment_surcharge::create(&state.pool, &payload).await?;
+
+    audit_log!(
+        state.audit_service,
+        AuditAction::StoreInfoChanged,
+        "payment_surcharge_rule",
+        &rule.id.to_string(),
+        operator_id = Some(current_user.id),
+        operator_name = Some(current_user.name.clone()),
+        details = create_snapshot(&rule, "payment_surcharge_rule")
+    );
+
+    Ok(Json(rule))
+}
+
+/// PUT /api/payment-surcharges/:id - 更新附加费规则
+pub async fn update(
+    State(state): State<ServerState>,
+    Extension(current_user): Extension<CurrentUser>,
+    Path(id): Path<i64>,
+    Json(payload): Json<PaymentSurchargeRuleUpdate>,
+) -> AppResult<Json<PaymentSurchargeRule>> {
+    let old_rule = payment_surcharge::find_by_id(&state.pool, id)
+        .await?
+        .ok_or_else(|| AppError::not_found(format!("Surcharge rule {}", id)))?;
+    let rule = payment_surcharge::update(&state.pool, id, &payload).await?;
+
+    audit_log!(
+        state.audit_service,
+        AuditAction::StoreInfoChanged,
+        "payment_surcharge_rule",
+        &id.to_string(),
+        operator_id = Some(current_user.id),
+        operator_name = Some(current_user.name.clone()),
+        details = create_diff(&old_rule, &rule, "payment_surcharge_rule")
+    );
+
+    Ok(Json(rule))
+}
+
+/// DELETE /api/payment-surcharges/:id - 删除附加费规则
+pub async fn delete(
+    State(state): State<ServerState>,
+    Extension(current_user): Extension<CurrentUser>,
+    Path(id): Path<i64>,
+) -> AppResult<Json<bool>> {
+    let rule = payment_surcharge::find_by_id(&state.pool, id)
+        .await?
+        .ok_or_else(|| AppError::not_found(format!("Surcharge rule {}", id)))?;
+    payment_surcharge::delete(&state.pool, id).await?;
+
+    audit_log!(
+        state.audit_service,
+        AuditAction::StoreInfoChanged,
+        "payment_surcharge_rule",
+        &id.to_string(),
+        operator_id = Some(current_user.id),
+        operator_name = Some(current_user.name.clone()),
+        details = serde_json::json!({"deleted": rule.payment_method})
+    );
+
+    Ok(Json(true))
+}
@@ -0,0 +1,32 @@
+//! Payment Surcharge API Module (支付方式附加费)
+
+mod handler;
+
+use axum::{
+    Router, middleware,
+    routing::{get, post},
+};
+
+use crate::auth::require_permission;
+use crate::core::ServerState;
+
+/// Payment surcharge rule router
+pub fn router() -> Router<ServerState> {
+    Router::new().nest("/api/payment-surcharges", routes())
+}
+
+fn routes() -> Router<ServerState> {
+    // 读取路由：无需权限检查（收银界面预览附加费）
+    let read_routes = Router::new().route("/", get(handler::list));
+
+    // 写入路由：需要 settings:manage 权限
+    let write_routes = Router::new()
+        .route("/", post(handler::create))
+        .route(
+            "/{id}",
+            axum::routing::put(handler::update).delete(handler::delete),
+        )
+        .layer(middleware::from_fn(require_permission("settings:manage")));
+
+    read_routes.merge(write_routes)
+}
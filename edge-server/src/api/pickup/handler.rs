@@ -0,0 +1,196 @@
+//! Pickup API Handlers
+
+use axum::{
+    Json,
+    extract::{Path, State},
+    http::HeaderMap,
+};
+
+use crate::core::ServerState;
+use crate::db::repository::{pickup as pickup_repo, store_info as store_info_repo};
+use crate::notify::{OutboundMessage, pickup_ready_message};
+use crate::utils::validation::normalize_phone;
+use crate::utils::{AppError, AppResult};
+use shared::models::{
+    DeliveryReport, DeliveryStatus, PickupCreate, PickupPublicStatus, PickupStatus, PickupTicket,
+};
+use shared::order::ServiceType;
+
+/// 后台发送取餐通知，结果写回取餐单 (网关超时不阻塞出餐操作)
+fn dispatch_ready_message(state: &ServerState, ticket: &PickupTicket) {
+    let (Some(gateway), Some(phone)) = (state.message_gateway.clone(), ticket.phone.clone()) else {
+        return;
+    };
+    let pool = state.pool.clone();
+    let ticket = ticket.clone();
+    tokio::spawn(async move {
+        let store_name = match store_info_repo::get(&pool).await {
+            Ok(info) => info.map(|i| i.name).unwrap_or_default(),
+            Err(e) => {
+                tracing::warn!("Failed to load store name for pickup message: {}", e);
+                String::new()
+            }
+        };
+        let message = OutboundMessage {
+            to: phone,
+            channel: ticket.channel,
+            body: pickup_ready_message(&store_name, ticket.queue_number, &ticket.pickup_code),
+            external_id: format!("pickup-{}-{}", ticket.id, ticket.attempts + 1),
+        };
+        let result = gateway.send(&message).await;
+        let recorded = match &result {
+            Ok(receipt) => {
+                pickup_repo::record_send(&pool, ticket.id, Ok(&receipt.message_id)).await
+            }
+            Err(e) => {
+                tracing::warn!(ticket_id = ticket.id, "Pickup message failed: {}", e);
+                pickup_repo::record_send(&pool, ticket.id, Err(&e.to_string())).await
+            }
+        };
+        if let Err(e) = recorded {
+            tracing::error!(
+                ticket_id = ticket.id,
+                "Failed to record pickup message: {}",
+                e
+            );
+        }
+    });
+}
+
+/// POST /api/pickup - 为外卖订单登记取餐单 (生成取餐码)
+pub async fn register(
+    State(state): State<ServerState>,
+    Json(payload): Json<PickupCreate>,
+) -> AppResult<Json<PickupTicket>> {
+    let phone = match payload.phone.as_deref().map(str::trim) {
+        Some(p) if !p.is_empty() => Some(normalize_phone(p)?),
+        _ => None,
+    };
+
+    let (receipt_number, queue_number, is_takeaway) = match state
+        .orders_manager
+        .get_snapshot(payload.order_id)
+        .map_err(|e| AppError::internal(e.to_string()))?
+    {
+        Some(snapshot) => (
+            snapshot.receipt_number.clone(),
+            snapshot.queue_number.map(i64::from),
+            snapshot.is_retail || snapshot.service_type == Some(ServiceType::Takeout),
+        ),
+        None => pickup_repo::find_archived_order(&state.pool, payload.order_id)
+            .await?
+            .ok_or_else(|| AppError::not_found(format!("Order {} not found", payload.order_id)))?,
+    };
+    if !is_takeaway {
+        return Err(AppError::validation(format!(
+            "Order {} is not a takeaway order",
+            payload.order_id
+        )));
+    }
+
+    let ticket = pickup_repo::create(
+        &state.pool,
+        payload.order_id,
+        &receipt_number,
+        queue_number,
+        phone.as_deref(),
+        payload.channel,
+    )
+    .await?;
+    Ok(Json(ticket))
+}
+
+/// GET /api/pickup - 未取餐的取餐单 (取餐看板)
+pub async fn list_active(State(state): State<ServerState>) -> AppResult<Json<Vec<PickupTicket>>> {
+    let tickets = pickup_repo::find_active(&state.pool).await?;
+    Ok(Json(tickets))
+}
+
+/// GET /api/pickup/order/:order_id - 订单的取餐单 (打印小票取餐码)
+pub async fn get_by_order(
+    State(state): State<ServerState>,
+    Path(order_id): Path<i64>,
+) -> AppResult<Json<PickupTicket>> {
+    let ticket = pickup_repo::find_by_order(&state.pool, order_id)
+        .await?
+        .ok_or_else(|| AppError::not_found(format!("No pickup ticket for order {order_id}")))?;
+    Ok(Json(ticket))
+}
+
+/// POST /api/pickup/:id/ready - 出餐，首次标记时通知顾客
+pub async fn mark_ready(
+    State(state): State<ServerState>,
+    Path(id): Path<i64>,
+) -> AppResult<Json<PickupTicket>> {
+    let (ticket, newly_ready) = pickup_repo::mark_ready(&state.pool, id).await?;
+    if newly_ready {
+        dispatch_ready_message(&state, &ticket);
+    }
+    Ok(Json(ticket))
+}
+
+/// POST /api/pickup/:id/resend - 重发取餐通知 (发送失败或顾客未收到)
+pub async fn resend(
+    State(state): State<ServerState>,
+    Path(id): Path<i64>,
+) -> AppResult<Json<PickupTicket>> {
+    let ticket = pickup_repo::find_by_id(&state.pool, id)
+        .await?
+        .ok_or_else(|| AppError::not_found(format!("Pickup ticket {id} not found")))?;
+    if ticket.status != PickupStatus::Ready {
+        return Err(AppError::validation("Only ready pickups can be notified"));
+    }
+    if ticket.phone.is_none() {
+        return Err(AppError::validation("Pickup ticket has no phone number"));
+    }
+    if state.message_gateway.is_none() {
+        return Err(AppError::validation("Message gateway is not configured"));
+    }
+    dispatch_ready_message(&state, &ticket);
+    Ok(Json(ticket))
+}
+
+/// POST /api/pickup/:id/collect - 顾客已取餐
+pub async fn collect(
+    State(state): State<ServerState>,
+    Path(id): Path<i64>,
+) -> AppResult<Json<PickupTicket>> {
+    let ticket = pickup_repo::mark_collected(&state.pool, id).await?;
+    Ok(Json(ticket))
+}
+
+/// GET /api/public/pickup/:code - 顾客凭取餐码查询状态 (免登录，不含手机号)
+pub async fn public_status(
+    State(state): State<ServerState>,
+    Path(code): Path<String>,
+) -> AppResult<Json<PickupPublicStatus>> {
+    let ticket = pickup_repo::find_by_code(&state.pool, &code)
+        .await?
+        .ok_or_else(|| AppError::not_found("Unknown pickup code"))?;
+    Ok(Json(PickupPublicStatus::from(&ticket)))
+}
+
+/// POST /api/public/pickup/delivery - 网关送达回执 (Bearer = 网关 API key)
+pub async fn delivery_report(
+    State(state): State<ServerState>,
+    headers: HeaderMap,
+    Json(report): Json<DeliveryReport>,
+) -> AppResult<Json<bool>> {
+    let token = headers
+        .get(axum::http::header::AUTHORIZATION)
+        .and_then(|h| h.to_str().ok())
+        .and_then(|h| h.strip_prefix("Bearer "))
+        .unwrap_or_default();
+    let authorized = state
+        .message_gateway
+        .as_ref()
+        .is_some_and(|gateway| gateway.verify_callback(token));
+    if !authorized {
+        return Err(AppError::unauthorized());
+    }
+    if report.status == DeliveryStatus::Failed {
+        tracing::warn!(message_id = %report.message_id, "Pickup message undelivered: {:?}", report.error);
+    }
+    let applied = pickup_repo::apply_delivery_report(&state.pool, &report).await?;
+    Ok(Json(applied.is_some()))
+}
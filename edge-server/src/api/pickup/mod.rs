@@ -0,0 +1,35 @@
+//! Pickup API Module
+//!
+//! 外卖取餐单 — 登记取餐码、出餐通知 (SMS / WhatsApp)、顾客公开查询
+
+mod handler;
+
+use axum::{
+    Router,
+    routing::{get, post},
+};
+
+use crate::core::ServerState;
+
+/// Pickup router
+pub fn router() -> Router<ServerState> {
+    Router::new()
+        .nest("/api/pickup", routes())
+        .nest("/api/public/pickup", public_routes())
+}
+
+fn routes() -> Router<ServerState> {
+    Router::new()
+        .route("/", get(handler::list_active).post(handler::register))
+        .route("/order/{order_id}", get(handler::get_by_order))
+        .route("/{id}/ready", post(handler::mark_ready))
+        .route("/{id}/resend", post(handler::resend))
+        .route("/{id}/collect", post(handler::collect))
+}
+
+/// 免登录路由 (require_auth 跳过 `/api/public/`)
+fn public_routes() -> Router<ServerState> {
+    Router::new()
+        .route("/delivery", post(handler::delivery_report))
+        .route("/{code}", get(handler::public_status))
+}
@@ -0,0 +1,39 @@
+//! PMS API Handlers
+
+use axum::{
+    Json,
+    extract::{Query, State},
+};
+use serde::Deserialize;
+
+use crate::core::ServerState;
+use crate::pms::{PmsError, PmsGuest};
+use crate::utils::{AppError, AppResult};
+
+#[derive(Debug, Deserialize)]
+pub struct GuestQuery {
+    pub room: String,
+}
+
+/// GET /api/pms/guest?room= - 挂房账前核验在住客人
+pub async fn verify_guest(
+    State(state): State<ServerState>,
+    Query(query): Query<GuestQuery>,
+) -> AppResult<Json<PmsGuest>> {
+    let room = query.room.trim();
+    if room.is_empty() {
+        return Err(AppError::validation("room is required"));
+    }
+    let pms = state
+        .orders_manager()
+        .pms_client()
+        .ok_or_else(|| AppError::invalid("Room charge is not configured on this server"))?;
+    match pms.verify_guest(room).await {
+        Ok(guest) => Ok(Json(guest)),
+        Err(PmsError::GuestNotFound(_)) => {
+            Err(AppError::not_found(format!("Guest in room {room}")))
+        }
+        Err(e @ PmsError::Rejected(_)) => Err(AppError::business_rule(e.to_string())),
+        Err(e) => Err(AppError::internal(e.to_string())),
+    }
+}
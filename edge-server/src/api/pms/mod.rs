@@ -0,0 +1,15 @@
+//! PMS API 模块 (挂房账住客核验)
+
+mod handler;
+
+use axum::{Router, routing::get};
+
+use crate::core::ServerState;
+
+pub fn router() -> Router<ServerState> {
+    Router::new().nest("/api/pms", routes())
+}
+
+fn routes() -> Router<ServerState> {
+    Router::new().route("/guest", get(handler::verify_guest))
+}
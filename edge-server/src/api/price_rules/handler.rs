@@ -10,6 +10,8 @@ use crate::audit_log;
 use crate::auth::CurrentUser;
 use crate::core::ServerState;
 use crate::db::repository::price_rule;
+use crate::pricing::find_unordered_overlaps;
+use crate::services::catalog_history;
 use crate::utils::validation::{
     MAX_NAME_LEN, MAX_NOTE_LEN, MAX_RECEIPT_NAME_LEN, MAX_SHORT_TEXT_LEN, validate_optional_text,
     validate_required_text,
@@ -18,10 +20,14 @@ use crate::utils::{AppError, AppResult};
 use shared::error::ErrorCode;
 use shared::message::SyncChangeType;
 use shared::models::price_rule::AdjustmentType;
-use shared::models::{PriceRule, PriceRuleCreate, PriceRuleUpdate, ProductScope};
+use shared::models::{
+    CatalogChange, CatalogChangeAction, PriceRule, PriceRuleCreate, PriceRuleUpdate, ProductScope,
+    ZONE_SCOPE_ALL,
+};
 
 use shared::cloud::SyncResource;
 const RESOURCE: SyncResource = SyncResource::PriceRule;
+const HISTORY_ENTITY: &str = "price_rule";
 
 fn validate_create(payload: &PriceRuleCreate) -> AppResult<()> {
     validate_required_text(&payload.name, "name", MAX_NAME_LEN)?;
@@ -93,6 +99,100 @@ fn validate_adjustment_value(adjustment_type: &AdjustmentType, value: f64) -> Re
     Ok(())
 }
 
+/// 检查与现有规则的重叠：同作用域、时间窗口重叠且 priority 相同时拒绝，
+/// 要求运营显式排序，避免按 created_at 隐式决胜
+async fn validate_no_overlap(state: &ServerState, candidate: &PriceRule) -> AppResult<()> {
+    let existing = price_rule::find_all(&state.pool).await?;
+    let overlaps = find_unordered_overlaps(candidate, &existing);
+    if overlaps.is_empty() {
+        return Ok(());
+    }
+    let names: Vec<&str> = overlaps.iter().map(|r| r.name.as_str()).collect();
+    Err(AppError::with_message(
+        ErrorCode::PriceRuleConflict,
+        format!(
+            "Price rule overlaps {} with the same priority ({}); set a different priority",
+            names.join(", "),
+            candidate.priority
+        ),
+    ))
+}
+
+/// 由创建载荷构造待校验规则（与 repository::create 的默认值一致）
+fn candidate_from_create(payload: &PriceRuleCreate) -> PriceRule {
+    PriceRule {
+        id: 0,
+        name: payload.name.clone(),
+        receipt_name: payload.receipt_name.clone(),
+        description: payload.description.clone(),
+        rule_type: payload.rule_type.clone(),
+        product_scope: payload.product_scope.clone(),
+        target_id: payload.target_id,
+        zone_scope: payload
+            .zone_scope
+            .clone()
+            .unwrap_or_else(|| ZONE_SCOPE_ALL.to_string()),
+        adjustment_type: payload.adjustment_type.clone(),
+        adjustment_value: payload.adjustment_value,
+        is_stackable: payload.is_stackable.unwrap_or(true),
+        is_exclusive: payload.is_exclusive.unwrap_or(false),
+        priority: payload.priority.unwrap_or(0),
+        valid_from: payload.valid_from,
+        valid_until: payload.valid_until,
+        active_days: payload.active_days.clone(),
+        active_start_time: payload.active_start_time.clone(),
+        active_end_time: payload.active_end_time.clone(),
+        is_active: true,
+        created_by: payload.created_by,
+        created_at: shared::util::now_millis(),
+    }
+}
+
+/// 将部分更新合并到旧规则上（与 repository::update 的 COALESCE 语义一致）
+fn candidate_from_update(old: &PriceRule, payload: &PriceRuleUpdate) -> PriceRule {
+    let mut rule = old.clone();
+    if let Some(v) = &payload.rule_type {
+        rule.rule_type = v.clone();
+    }
+    if let Some(v) = &payload.product_scope {
+        rule.product_scope = v.clone();
+    }
+    if payload.target_id.is_some() {
+        rule.target_id = payload.target_id;
+    }
+    if let Some(v) = &payload.zone_scope {
+        rule.zone_scope = v.clone();
+    }
+    if let Some(v) = payload.is_stackable {
+        rule.is_stackable = v;
+    }
+    if let Some(v) = payload.is_exclusive {
+        rule.is_exclusive = v;
+    }
+    if let Some(v) = payload.priority {
+        rule.priority = v;
+    }
+    if payload.valid_from.is_some() {
+        rule.valid_from = payload.valid_from;
+    }
+    if payload.valid_until.is_some() {
+        rule.valid_until = payload.valid_until;
+    }
+    if payload.active_days.is_some() {
+        rule.active_days = payload.active_days.clone();
+    }
+    if payload.active_start_time.is_some() {
+        rule.active_start_time = payload.active_start_time.clone();
+    }
+    if payload.active_end_time.is_some() {
+        rule.active_end_time = payload.active_end_time.clone();
+    }
+    if let Some(v) = payload.is_active {
+        rule.is_active = v;
+    }
+    rule
+}
+
 /// GET /api/price-rules - 获取所有价格规则
 pub async fn list(State(state): State<ServerState>) -> AppResult<Json<Vec<PriceRule>>> {
     let rules = price_rule::find_all(&state.pool).await?;
@@ -157,8 +257,20 @@ pub async fn create(
 ) -> AppResult<Json<PriceRule>> {
     validate_create(&payload)?;
     validate_adjustment_value(&payload.adjustment_type, payload.adjustment_value)?;
+    validate_no_overlap(&state, &candidate_from_create(&payload)).await?;
     let rule = price_rule::create(&state.pool, None, payload).await?;
 
+    catalog_history::record(
+        &state.pool,
+        HISTORY_ENTITY,
+        rule.id,
+        CatalogChangeAction::Create,
+        None,
+        Some(&rule),
+        Some(&current_user),
+    )
+    .await;
+
     let id = rule.id.to_string();
 
     audit_log!(
@@ -212,9 +324,21 @@ pub async fn update(
         .adjustment_value
         .unwrap_or(old_rule.adjustment_value);
     validate_adjustment_value(adj_type, adj_value)?;
+    validate_no_overlap(&state, &candidate_from_update(&old_rule, &payload)).await?;
 
     let rule = price_rule::update(&state.pool, id, payload).await?;
 
+    catalog_history::record(
+        &state.pool,
+        HISTORY_ENTITY,
+        id,
+        CatalogChangeAction::Update,
+        Some(&old_rule),
+        Some(&rule),
+        Some(&current_user),
+    )
+    .await;
+
     let id_str = id.to_string();
 
     audit_log!(
@@ -240,10 +364,9 @@ pub async fn delete(
     Extension(current_user): Extension<CurrentUser>,
     Path(id): Path<i64>,
 ) -> AppResult<Json<bool>> {
-    let name_for_audit = price_rule::find_by_id(&state.pool, id)
-        .await
-        .ok()
-        .flatten()
+    let old_rule = price_rule::find_by_id(&state.pool, id).await.ok().flatten();
+    let name_for_audit = old_rule
+        .as_ref()
         .map(|r| r.name.clone())
         .unwrap_or_default();
     let result = price_rule::delete(&state.pool, id).await?;
@@ -251,6 +374,17 @@ pub async fn delete(
     let id_str = id.to_string();
 
     if result {
+        catalog_history::record(
+            &state.pool,
+            HISTORY_ENTITY,
+            id,
+            CatalogChangeAction::Delete,
+            old_rule.as_ref(),
+            None,
+            Some(&current_user),
+        )
+        .await;
+
         audit_log!(
             state.audit_service,
             AuditAction::PriceRuleDeleted,
@@ -268,3 +402,114 @@ pub async fn delete(
 
     Ok(Json(result))
 }
+
+/// GET /api/price-rules/:id/history - 价格规则变更历史
+pub async fn list_history(
+    State(state): State<ServerState>,
+    Path(id): Path<i64>,
+) -> AppResult<Json<Vec<CatalogChange>>> {
+    let changes = catalog_history::list(&state.pool, HISTORY_ENTITY, id).await?;
+    Ok(Json(changes))
+}
+
+/// POST /api/price-rules/:id/history/:change_id/revert - 回滚价格规则到指定版本
+///
+/// 规则已删除时按原 ID 重建。回滚结果同样要通过重叠校验。
+pub async fn revert(
+    State(state): State<ServerState>,
+    Extension(current_user): Extension<CurrentUser>,
+    Path((id, change_id)): Path<(i64, i64)>,
+) -> AppResult<Json<PriceRule>> {
+    let version: PriceRule =
+        catalog_history::version_snapshot(&state.pool, HISTORY_ENTITY, id, change_id).await?;
+    validate_adjustment_value(&version.adjustment_type, version.adjustment_value)?;
+    validate_no_overlap(&state, &version).await?;
+
+    let old_rule = price_rule::find_by_id(&state.pool, id).await?;
+    if old_rule.is_none() {
+        price_rule::create(
+            &state.pool,
+            Some(id),
+            PriceRuleCreate {
+                name: version.name.clone(),
+                receipt_name: version.receipt_name.clone(),
+                description: version.description.clone(),
+                rule_type: version.rule_type.clone(),
+                product_scope: version.product_scope.clone(),
+                target_id: version.target_id,
+                zone_scope: Some(version.zone_scope.clone()),
+                adjustment_type: version.adjustment_type.clone(),
+                adjustment_value: version.adjustment_value,
+                is_stackable: Some(version.is_stackable),
+                is_exclusive: Some(version.is_exclusive),
+                priority: Some(version.priority),
+                valid_from: version.valid_from,
+                valid_until: version.valid_until,
+                active_days: version.active_days.clone(),
+                active_start_time: version.active_start_time.clone(),
+                active_end_time: version.active_end_time.clone(),
+                created_by: version.created_by,
+            },
+        )
+        .await?;
+    }
+
+    let rule = price_rule::update(
+        &state.pool,
+        id,
+        PriceRuleUpdate {
+            name: Some(version.name),
+            receipt_name: version.receipt_name,
+            description: version.description,
+            rule_type: Some(version.rule_type),
+            product_scope: Some(version.product_scope),
+            target_id: version.target_id,
+            zone_scope: Some(version.zone_scope),
+            adjustment_type: Some(version.adjustment_type),
+            adjustment_value: Some(version.adjustment_value),
+            is_stackable: Some(version.is_stackable),
+            is_exclusive: Some(version.is_exclusive),
+            priority: Some(version.priority),
+            valid_from: version.valid_from,
+            valid_until: version.valid_until,
+            active_days: version.active_days,
+            active_start_time: version.active_start_time,
+            active_end_time: version.active_end_time,
+            is_active: Some(version.is_active),
+        },
+    )
+    .await?;
+
+    catalog_history::record(
+        &state.pool,
+        HISTORY_ENTITY,
+        id,
+        CatalogChangeAction::Revert,
+        old_rule.as_ref(),
+        Some(&rule),
+        Some(&current_user),
+    )
+    .await;
+
+    let id_str = id.to_string();
+    let (action, change_type) = if old_rule.is_some() {
+        (AuditAction::PriceRuleUpdated, SyncChangeType::Updated)
+    } else {
+        (AuditAction::PriceRuleCreated, SyncChangeType::Created)
+    };
+    audit_log!(
+        state.audit_service,
+        action,
+        "price_rule",
+        &id_str,
+        operator_id = Some(current_user.id),
+        operator_name = Some(current_user.name.clone()),
+        details = serde_json::json!({"op": "revert", "change_id": change_id})
+    );
+
+    state
+        .broadcast_sync(RESOURCE, change_type, id, Some(&rule), false)
+        .await;
+
+    Ok(Json(rule))
+}
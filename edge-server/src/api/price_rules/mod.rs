@@ -16,6 +16,7 @@ fn routes() -> Router<ServerState> {
     let read_routes = Router::new()
         .route("/", get(handler::list))
         .route("/{id}", get(handler::get_by_id))
+        .route("/{id}/history", get(handler::list_history))
         .route("/by-scope/{scope}", get(handler::list_by_scope))
         .route("/for-product/{product_id}", get(handler::list_for_product));
 
@@ -26,6 +27,10 @@ fn routes() -> Router<ServerState> {
             "/{id}",
             axum::routing::put(handler::update).delete(handler::delete),
         )
+        .route(
+            "/{id}/history/{change_id}/revert",
+            axum::routing::post(handler::revert),
+        )
         .layer(middleware::from_fn(require_permission(
             "price_rules:manage",
         )));
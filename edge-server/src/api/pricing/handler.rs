@@ -0,0 +1,16 @@
+//! Pricing API Handlers
+
+use axum::{Json, extract::State};
+
+use crate::core::ServerState;
+use crate::db::repository::price_rule;
+use crate::pricing::{RuleConflict, find_conflicts};
+use crate::utils::AppResult;
+
+/// GET /api/pricing/conflicts - 列出相互重叠的生效价格规则
+pub async fn list_conflicts(
+    State(state): State<ServerState>,
+) -> AppResult<Json<Vec<RuleConflict>>> {
+    let rules = price_rule::find_all(&state.pool).await?;
+    Ok(Json(find_conflicts(&rules)))
+}
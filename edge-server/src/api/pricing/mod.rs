@@ -0,0 +1,16 @@
+//! Pricing API 模块（价格规则诊断）
+
+mod handler;
+
+use axum::{Router, routing::get};
+
+use crate::core::ServerState;
+
+pub fn router() -> Router<ServerState> {
+    Router::new().nest("/api/pricing", routes())
+}
+
+fn routes() -> Router<ServerState> {
+    // 读取路由：无需权限检查（与价格规则读取一致）
+    Router::new().route("/conflicts", get(handler::list_conflicts))
+}
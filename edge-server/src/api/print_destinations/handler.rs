@@ -162,6 +162,24 @@ pub async fn delete(
         ));
     }
 
+    // 检查是否有商品覆盖正在使用此打印目标
+    let product_refs: i64 = sqlx::query_scalar(
+        "SELECT COUNT(*) FROM product_print_dest WHERE print_destination_id = ?",
+    )
+    .bind(id)
+    .fetch_one(&state.pool)
+    .await
+    .map_err(|e| AppError::internal(e.to_string()))?;
+    if product_refs > 0 {
+        return Err(AppError::with_message(
+            ErrorCode::PrintDestinationInUse,
+            format!(
+                "Cannot delete print destination: {} product reference(s) exist",
+                product_refs
+            ),
+        ));
+    }
+
     let name_for_audit = print_destination::find_by_id(&state.pool, id)
         .await
         .ok()
@@ -0,0 +1,70 @@
+//! Print Job API Handlers
+//!
+//! 失败的目的地打印持久化在 redb 队列中，由 KitchenPrintWorker 按退避自动重试；
+//! 超过重试上限的任务标记为 FAILED，等待店员重打或取消。
+
+use std::collections::HashMap;
+
+use axum::{
+    Json,
+    extract::{Path, Query, State},
+};
+use serde::Deserialize;
+
+use crate::core::ServerState;
+use crate::db::repository::print_destination;
+use crate::printing::{
+    PrintExecutor, PrintJob, PrintJobOutcome, PrintJobStatus, attempt_print_job,
+};
+use crate::utils::{AppError, AppResult};
+
+#[derive(Debug, Deserialize)]
+pub struct ListQuery {
+    pub status: Option<PrintJobStatus>,
+}
+
+/// GET /api/print-jobs - 打印队列 (最早在前)
+pub async fn list(
+    State(state): State<ServerState>,
+    Query(query): Query<ListQuery>,
+) -> AppResult<Json<Vec<PrintJob>>> {
+    let jobs = state.kitchen_print_service().list_print_jobs()?;
+    Ok(Json(match query.status {
+        Some(status) => jobs.into_iter().filter(|j| j.status == status).collect(),
+        None => jobs,
+    }))
+}
+
+/// POST /api/print-jobs/:id/reprint - 立即重打
+///
+/// 手动重打不计入自动重试次数。返回 false 表示打印机仍然失败（任务保留在队列中）。
+pub async fn reprint(
+    State(state): State<ServerState>,
+    Path(id): Path<i64>,
+) -> AppResult<Json<bool>> {
+    let service = state.kitchen_print_service();
+    let (job, _) = service.get_print_job(id)?;
+
+    let destinations = print_destination::find_all(&state.pool)
+        .await
+        .map_err(|e| AppError::database(e.to_string()))?;
+    let dest_map: HashMap<String, _> = destinations
+        .into_iter()
+        .map(|d| (d.id.to_string(), d))
+        .collect();
+
+    // 队列中保存的是已渲染数据，执行器只负责发送
+    let executor = PrintExecutor::new();
+    let outcome = attempt_print_job(service, &executor, &dest_map, job, false).await?;
+    Ok(Json(matches!(outcome, PrintJobOutcome::Printed)))
+}
+
+/// DELETE /api/print-jobs/:id - 取消任务 (不再重试)
+pub async fn cancel(
+    State(state): State<ServerState>,
+    Path(id): Path<i64>,
+) -> AppResult<Json<bool>> {
+    state.kitchen_print_service().remove_print_job(id)?;
+    tracing::info!(job_id = %id, "Print job cancelled");
+    Ok(Json(true))
+}
@@ -0,0 +1,21 @@
+//! Print Jobs API 模块 (打印队列：失败任务查看 / 取消 / 重打)
+
+mod handler;
+
+use axum::{
+    Router,
+    routing::{delete, get, post},
+};
+
+use crate::core::ServerState;
+
+pub fn router() -> Router<ServerState> {
+    Router::new().nest("/api/print-jobs", routes())
+}
+
+fn routes() -> Router<ServerState> {
+    Router::new()
+        .route("/", get(handler::list))
+        .route("/{id}", delete(handler::cancel))
+        .route("/{id}/reprint", post(handler::reprint))
+}
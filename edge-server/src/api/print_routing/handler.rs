@@ -0,0 +1,19 @@
+//! Print Routing API Handlers
+
+use axum::Json;
+use axum::extract::State;
+
+use crate::core::ServerState;
+use crate::db::repository::print_destination;
+use crate::utils::AppResult;
+use shared::models::RoutingMatrix;
+
+/// GET /api/print/routing_matrix
+///
+/// Effective kitchen / label destinations of every active product, resolved
+/// with the same chain as printing: product override > category > system default.
+pub async fn routing_matrix(State(state): State<ServerState>) -> AppResult<Json<RoutingMatrix>> {
+    let destinations = print_destination::find_all_with_inactive(&state.pool).await?;
+    let rows = state.catalog_service.routing_matrix();
+    Ok(Json(RoutingMatrix { destinations, rows }))
+}
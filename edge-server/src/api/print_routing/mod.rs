@@ -0,0 +1,21 @@
+//! Print Routing API 模块
+//!
+//! Effective routing matrix (product → kitchen / label destinations).
+
+mod handler;
+
+use axum::{Router, middleware, routing::get};
+
+use crate::auth::require_permission;
+use crate::core::ServerState;
+
+pub fn router() -> Router<ServerState> {
+    Router::new().nest("/api/print", routes())
+}
+
+fn routes() -> Router<ServerState> {
+    // 管理路由：需要 settings:manage 权限
+    Router::new()
+        .route("/routing_matrix", get(handler::routing_matrix))
+        .layer(middleware::from_fn(require_permission("settings:manage")))
+}
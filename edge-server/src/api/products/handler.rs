@@ -4,7 +4,8 @@ use crate::audit::{AuditAction, create_diff, create_snapshot};
 use crate::audit_log;
 use crate::auth::CurrentUser;
 use crate::core::ServerState;
-use crate::db::repository::attribute;
+use crate::db::repository::{attribute, product_pairing};
+use crate::services::catalog_history;
 use crate::utils::types::{BatchUpdateResponse, SortOrderUpdate};
 use crate::utils::validation::{
     MAX_NAME_LEN, MAX_RECEIPT_NAME_LEN, MAX_URL_LEN, validate_optional_text, validate_required_text,
@@ -12,13 +13,18 @@ use crate::utils::validation::{
 use crate::utils::{AppError, AppResult, ErrorCode};
 use axum::{
     Json,
-    extract::{Extension, Path, State},
+    extract::{Extension, Path, Query, State},
 };
+use serde::Deserialize;
 use shared::message::SyncChangeType;
-use shared::models::{AttributeBindingFull, ProductCreate, ProductFull, ProductUpdate};
+use shared::models::{
+    AttributeBindingFull, CatalogChange, CatalogChangeAction, ProductCreate, ProductFull,
+    ProductSpecInput, ProductUpdate, UpsellSuggestion,
+};
 
 use shared::cloud::SyncResource;
 const RESOURCE_PRODUCT: SyncResource = SyncResource::Product;
+const HISTORY_ENTITY: &str = "product";
 
 fn validate_create(payload: &ProductCreate) -> AppResult<()> {
     validate_required_text(&payload.name, "name", MAX_NAME_LEN)?;
@@ -170,6 +176,17 @@ pub async fn create(
 
     let product = state.catalog_service.create_product(None, payload).await?;
 
+    catalog_history::record(
+        &state.pool,
+        HISTORY_ENTITY,
+        product.id,
+        CatalogChangeAction::Create,
+        None,
+        Some(&product),
+        Some(&current_user),
+    )
+    .await;
+
     let id_str = product.id.to_string();
 
     audit_log!(
@@ -232,6 +249,17 @@ pub async fn update(
 
     let product = state.catalog_service.update_product(id, payload).await?;
 
+    catalog_history::record(
+        &state.pool,
+        HISTORY_ENTITY,
+        id,
+        CatalogChangeAction::Update,
+        Some(&old_product),
+        Some(&product),
+        Some(&current_user),
+    )
+    .await;
+
     tracing::debug!(
         "Product updated - is_kitchen_print_enabled: {}, is_label_print_enabled: {}",
         product.is_kitchen_print_enabled,
@@ -269,14 +297,25 @@ pub async fn delete(
 ) -> AppResult<Json<bool>> {
     let id_str = id.to_string();
 
-    // 删除前查名称用于审计
-    let name_for_audit = state
-        .catalog_service
-        .get_product(id)
+    // 删除前取快照用于审计和变更历史
+    let old_product = state.catalog_service.get_product(id);
+    let name_for_audit = old_product
+        .as_ref()
         .map(|p| p.name.clone())
         .unwrap_or_default();
     state.catalog_service.delete_product(id).await?;
 
+    catalog_history::record(
+        &state.pool,
+        HISTORY_ENTITY,
+        id,
+        CatalogChangeAction::Delete,
+        old_product.as_ref(),
+        None,
+        Some(&current_user),
+    )
+    .await;
+
     audit_log!(
         state.audit_service,
         AuditAction::ProductDeleted,
@@ -318,6 +357,138 @@ pub async fn list_product_attributes(
     Ok(Json(result))
 }
 
+// =============================================================================
+// Product History Handlers
+// =============================================================================
+
+/// GET /api/products/:id/history - 商品变更历史
+pub async fn list_history(
+    State(state): State<ServerState>,
+    Path(id): Path<i64>,
+) -> AppResult<Json<Vec<CatalogChange>>> {
+    let changes = catalog_history::list(&state.pool, HISTORY_ENTITY, id).await?;
+    Ok(Json(changes))
+}
+
+/// POST /api/products/:id/history/:change_id/revert - 回滚商品到指定版本
+///
+/// 商品已删除时按原 ID 重建。属性绑定不在快照回滚范围内。
+pub async fn revert(
+    State(state): State<ServerState>,
+    Extension(current_user): Extension<CurrentUser>,
+    Path((id, change_id)): Path<(i64, i64)>,
+) -> AppResult<Json<ProductFull>> {
+    let version: ProductFull =
+        catalog_history::version_snapshot(&state.pool, HISTORY_ENTITY, id, change_id).await?;
+    let specs: Vec<ProductSpecInput> = version
+        .specs
+        .iter()
+        .map(|s| ProductSpecInput {
+            name: s.name.clone(),
+            price: s.price,
+            display_order: s.display_order,
+            is_default: s.is_default,
+            is_active: s.is_active,
+            receipt_name: s.receipt_name.clone(),
+            is_root: s.is_root,
+        })
+        .collect();
+    validate_specs(&specs)?;
+
+    // 回滚到的 external_id 可能已被其他商品占用
+    if let Some(eid) = version.external_id
+        && check_duplicate_external_id(&state, eid, Some(id)).await?
+    {
+        return Err(
+            AppError::new(ErrorCode::ProductExternalIdExists).with_detail("external_id", eid)
+        );
+    }
+
+    let tag_ids: Vec<i64> = version.tags.iter().map(|t| t.id).collect();
+    let old_product = state.catalog_service.get_product(id);
+    if old_product.is_none() {
+        state
+            .catalog_service
+            .create_product(
+                Some(id),
+                ProductCreate {
+                    name: version.name.clone(),
+                    image: Some(version.image.clone()),
+                    category_id: version.category_id,
+                    sort_order: Some(version.sort_order),
+                    tax_rate: Some(version.tax_rate),
+                    receipt_name: version.receipt_name.clone(),
+                    kitchen_print_name: version.kitchen_print_name.clone(),
+                    is_kitchen_print_enabled: Some(version.is_kitchen_print_enabled),
+                    is_label_print_enabled: Some(version.is_label_print_enabled),
+                    kitchen_print_destinations: vec![],
+                    label_print_destinations: vec![],
+                    external_id: version.external_id,
+                    tags: Some(tag_ids.clone()),
+                    specs: specs.clone(),
+                },
+            )
+            .await?;
+    }
+
+    let product = state
+        .catalog_service
+        .update_product(
+            id,
+            ProductUpdate {
+                name: Some(version.name),
+                image: Some(version.image),
+                category_id: Some(version.category_id),
+                sort_order: Some(version.sort_order),
+                tax_rate: Some(version.tax_rate),
+                receipt_name: version.receipt_name,
+                kitchen_print_name: version.kitchen_print_name,
+                is_kitchen_print_enabled: Some(version.is_kitchen_print_enabled),
+                is_label_print_enabled: Some(version.is_label_print_enabled),
+                kitchen_print_destinations: None,
+                label_print_destinations: None,
+                is_active: Some(version.is_active),
+                external_id: version.external_id,
+                tags: Some(tag_ids),
+                specs: Some(specs),
+            },
+        )
+        .await?;
+
+    catalog_history::record(
+        &state.pool,
+        HISTORY_ENTITY,
+        id,
+        CatalogChangeAction::Revert,
+        old_product.as_ref(),
+        Some(&product),
+        Some(&current_user),
+    )
+    .await;
+
+    let id_str = id.to_string();
+    let (action, change_type) = if old_product.is_some() {
+        (AuditAction::ProductUpdated, SyncChangeType::Updated)
+    } else {
+        (AuditAction::ProductCreated, SyncChangeType::Created)
+    };
+    audit_log!(
+        state.audit_service,
+        action,
+        "product",
+        &id_str,
+        operator_id = Some(current_user.id),
+        operator_name = Some(current_user.name.clone()),
+        details = serde_json::json!({"op": "revert", "change_id": change_id})
+    );
+
+    state
+        .broadcast_sync(RESOURCE_PRODUCT, change_type, id, Some(&product), false)
+        .await;
+
+    Ok(Json(product))
+}
+
 // =============================================================================
 // Product Tag Handlers
 // =============================================================================
@@ -328,11 +499,23 @@ pub async fn add_product_tag(
     Extension(current_user): Extension<CurrentUser>,
     Path((product_id, tag_id)): Path<(i64, i64)>,
 ) -> AppResult<Json<ProductFull>> {
+    let old_product = state.catalog_service.get_product(product_id);
     let product = state
         .catalog_service
         .add_product_tag(product_id, tag_id)
         .await?;
 
+    catalog_history::record(
+        &state.pool,
+        HISTORY_ENTITY,
+        product_id,
+        CatalogChangeAction::Update,
+        old_product.as_ref(),
+        Some(&product),
+        Some(&current_user),
+    )
+    .await;
+
     let product_id_str = product_id.to_string();
 
     audit_log!(
@@ -365,11 +548,23 @@ pub async fn remove_product_tag(
     Extension(current_user): Extension<CurrentUser>,
     Path((product_id, tag_id)): Path<(i64, i64)>,
 ) -> AppResult<Json<ProductFull>> {
+    let old_product = state.catalog_service.get_product(product_id);
     let product = state
         .catalog_service
         .remove_product_tag(product_id, tag_id)
         .await?;
 
+    catalog_history::record(
+        &state.pool,
+        HISTORY_ENTITY,
+        product_id,
+        CatalogChangeAction::Update,
+        old_product.as_ref(),
+        Some(&product),
+        Some(&current_user),
+    )
+    .await;
+
     let product_id_str = product_id.to_string();
 
     audit_log!(
@@ -433,6 +628,8 @@ pub async fn batch_update_sort_order(
                     kitchen_print_name: None,
                     is_kitchen_print_enabled: None,
                     is_label_print_enabled: None,
+                    kitchen_print_destinations: None,
+                    label_print_destinations: None,
                     is_active: None,
                     external_id: None,
                     specs: None,
@@ -466,3 +663,37 @@ pub async fn batch_update_sort_order(
         updated: updated_count,
     }))
 }
+
+// =============================================================================
+// Upsell Handlers (关联推荐)
+// =============================================================================
+
+/// Default / maximum number of upsell suggestions
+const UPSELL_DEFAULT_LIMIT: i64 = 3;
+const UPSELL_MAX_LIMIT: i64 = 10;
+
+#[derive(Debug, Deserialize)]
+pub struct UpsellQuery {
+    pub limit: Option<i64>,
+}
+
+/// GET /api/products/:id/upsell - 加菜时的搭配推荐 (已下架 / 沽清商品除外)
+pub async fn list_upsell(
+    State(state): State<ServerState>,
+    Path(id): Path<i64>,
+    Query(query): Query<UpsellQuery>,
+) -> AppResult<Json<Vec<UpsellSuggestion>>> {
+    let limit = query
+        .limit
+        .unwrap_or(UPSELL_DEFAULT_LIMIT)
+        .clamp(1, UPSELL_MAX_LIMIT);
+    let suggestions =
+        product_pairing::suggestions(&state.pool, id, shared::util::now_millis(), limit).await?;
+    Ok(Json(suggestions))
+}
+
+/// POST /api/products/upsell/recompute - 立即重算搭配 (默认每营业日 cutoff 自动重算)
+pub async fn recompute_upsell(State(state): State<ServerState>) -> AppResult<Json<u64>> {
+    let count = crate::marketing::upsell::recompute(&state).await?;
+    Ok(Json(count))
+}
@@ -20,14 +20,18 @@ fn product_routes() -> Router<ServerState> {
         .route("/", get(handler::list))
         .route("/{id}", get(handler::get_by_id))
         .route("/{id}/attributes", get(handler::list_product_attributes))
+        .route("/{id}/history", get(handler::list_history))
+        .route("/{id}/upsell", get(handler::list_upsell))
         .route("/by-category/{category_id}", get(handler::list_by_category));
 
     // 写入/删除路由：需要 menu:manage 权限
     let manage_routes = Router::new()
         .route("/", post(handler::create))
         .route("/sort-order", put(handler::batch_update_sort_order))
+        .route("/upsell/recompute", post(handler::recompute_upsell))
         .route("/{id}", put(handler::update))
         .route("/{id}/tags/{tag_id}", post(handler::add_product_tag))
+        .route("/{id}/history/{change_id}/revert", post(handler::revert))
         .route("/{id}", axum::routing::delete(handler::delete))
         .route(
             "/{id}/tags/{tag_id}",
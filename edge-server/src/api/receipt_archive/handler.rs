@@ -0,0 +1,89 @@
+//! Receipt Archive API Handlers
+
+use axum::{
+    Json,
+    extract::{Path, State},
+    http::header,
+    response::IntoResponse,
+};
+use base64::Engine;
+
+use crate::archiving::ReceiptArchive;
+use crate::core::ServerState;
+use crate::db::repository::{receipt_artifact, store_info};
+use crate::utils::{AppError, AppResult};
+use shared::models::{ReceiptArtifact, ReceiptArtifactCreate};
+
+fn archive(state: &ServerState) -> ReceiptArchive {
+    ReceiptArchive::new(state.config.receipt_archive_dir())
+}
+
+/// POST /api/receipt-archive - 存档一张收据的打印原文
+pub async fn upload(
+    State(state): State<ServerState>,
+    Json(payload): Json<ReceiptArtifactCreate>,
+) -> AppResult<Json<ReceiptArtifact>> {
+    let info = store_info::get_or_create(&state.pool).await?;
+    if !info.receipt_archive_enabled {
+        return Err(AppError::invalid_request("Receipt archive is disabled"));
+    }
+    let content = base64::engine::general_purpose::STANDARD
+        .decode(payload.content_base64.as_bytes())
+        .map_err(|e| AppError::validation(format!("Invalid content_base64: {e}")))?;
+
+    let artifact = archive(&state)
+        .store(
+            &state.pool,
+            payload.receipt_number.trim(),
+            payload.kind,
+            &content,
+            payload.reprint,
+        )
+        .await?;
+
+    tracing::debug!(
+        receipt_number = %artifact.receipt_number,
+        kind = artifact.kind.as_str(),
+        size = artifact.size_bytes,
+        "Receipt artifact archived"
+    );
+    Ok(Json(artifact))
+}
+
+/// GET /api/receipt-archive/:receipt_number - 收据的存档列表 (含分单收据)
+pub async fn list(
+    State(state): State<ServerState>,
+    Path(receipt_number): Path<String>,
+) -> AppResult<Json<Vec<ReceiptArtifact>>> {
+    let artifacts = receipt_artifact::find_by_receipt(&state.pool, &receipt_number).await?;
+    Ok(Json(artifacts))
+}
+
+/// GET /api/receipt-archive/artifacts/:id/content - 下载存档原文 (校验哈希)
+pub async fn content(
+    State(state): State<ServerState>,
+    Path(id): Path<i64>,
+) -> AppResult<impl IntoResponse> {
+    let artifact = receipt_artifact::find_by_id(&state.pool, id)
+        .await?
+        .ok_or_else(|| AppError::not_found(format!("Receipt artifact {id}")))?;
+    let bytes = archive(&state).read(&artifact).await?;
+    Ok((
+        [
+            (
+                header::CONTENT_TYPE,
+                artifact.kind.content_type().to_string(),
+            ),
+            (
+                header::CONTENT_DISPOSITION,
+                format!(
+                    "attachment; filename=\"{}.{}\"",
+                    artifact.receipt_number,
+                    artifact.kind.extension()
+                ),
+            ),
+            (header::ETAG, format!("\"{}\"", artifact.sha256)),
+        ],
+        bytes,
+    ))
+}
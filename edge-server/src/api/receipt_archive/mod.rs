@@ -0,0 +1,25 @@
+//! Receipt Archive API Module (收据存档)
+
+mod handler;
+
+use axum::{
+    Router,
+    routing::{get, post},
+};
+
+use crate::core::ServerState;
+
+/// Receipt archive router
+///
+/// 上传由收银终端在打印结账收据后自动调用 (门店开启 `receipt_archive_enabled` 时)，
+/// 存档只读：没有更新/删除路由。
+pub fn router() -> Router<ServerState> {
+    Router::new().nest("/api/receipt-archive", routes())
+}
+
+fn routes() -> Router<ServerState> {
+    Router::new()
+        .route("/", post(handler::upload))
+        .route("/{receipt_number}", get(handler::list))
+        .route("/artifacts/{id}/content", get(handler::content))
+}
@@ -0,0 +1,196 @@
+//! Receipt Footer API Handlers
+
+use axum::{
+    Json,
+    extract::{Extension, Path, Query, State},
+};
+use chrono::Datelike;
+use serde::Deserialize;
+
+use crate::audit::{AuditAction, create_diff, create_snapshot};
+use crate::audit_log;
+use crate::auth::CurrentUser;
+use crate::core::ServerState;
+use crate::db::repository::{receipt_footer, store_info};
+use crate::utils::validation::{
+    MAX_ADDRESS_LEN, MAX_NAME_LEN, MAX_URL_LEN, validate_optional_text, validate_required_text,
+};
+use crate::utils::{AppError, AppResult};
+use shared::models::{
+    ReceiptFooter, ReceiptFooterCreate, ReceiptFooterUpdate, ResolvedReceiptFooter,
+    select_receipt_footer,
+};
+
+fn validate_active_days(days: &Option<Vec<u8>>) -> AppResult<()> {
+    if let Some(days) = days
+        && days.iter().any(|d| *d > 6)
+    {
+        return Err(AppError::validation(
+            "active_days must contain values 0 (Sunday) to 6 (Saturday)",
+        ));
+    }
+    Ok(())
+}
+
+fn validate_survey_url(url: &Option<String>) -> AppResult<()> {
+    validate_optional_text(url, "survey_url", MAX_URL_LEN)?;
+    if let Some(url) = url.as_deref().map(str::trim)
+        && !url.is_empty()
+        && !(url.starts_with("https://") || url.starts_with("http://"))
+    {
+        return Err(AppError::validation("survey_url must be an http(s) URL"));
+    }
+    Ok(())
+}
+
+fn validate_create(payload: &ReceiptFooterCreate) -> AppResult<()> {
+    validate_required_text(&payload.name, "name", MAX_NAME_LEN)?;
+    validate_required_text(&payload.lines, "lines", MAX_ADDRESS_LEN)?;
+    validate_survey_url(&payload.survey_url)?;
+    validate_active_days(&payload.active_days)
+}
+
+fn validate_update(payload: &ReceiptFooterUpdate) -> AppResult<()> {
+    if let Some(name) = &payload.name {
+        validate_required_text(name, "name", MAX_NAME_LEN)?;
+    }
+    if let Some(lines) = &payload.lines {
+        validate_required_text(lines, "lines", MAX_ADDRESS_LEN)?;
+    }
+    validate_survey_url(&payload.survey_url)?;
+    validate_active_days(&payload.active_days)
+}
+
+/// GET /api/receipt-footers - List all receipt footers (including inactive)
+pub async fn list(State(state): State<ServerState>) -> AppResult<Json<Vec<ReceiptFooter>>> {
+    let footers = receipt_footer::find_all(&state.pool).await?;
+    Ok(Json(footers))
+}
+
+/// Query params for footer resolution
+#[derive(Debug, Deserialize)]
+pub struct ResolveQuery {
+    /// Receipt number for the survey code (omit for pre-payment bills)
+    pub receipt_number: Option<String>,
+}
+
+/// GET /api/receipt-footers/resolve - Footer for today's receipts
+///
+/// Picks the footer scheduled for the current weekday in the store timezone.
+pub async fn resolve(
+    State(state): State<ServerState>,
+    Query(params): Query<ResolveQuery>,
+) -> AppResult<Json<Option<ResolvedReceiptFooter>>> {
+    let footers = receipt_footer::find_active(&state.pool).await?;
+    let weekday = chrono::Utc::now()
+        .with_timezone(&state.config.timezone)
+        .weekday()
+        .num_days_from_sunday() as u8;
+    let receipt_number = params.receipt_number.as_deref().filter(|s| !s.is_empty());
+    let Some(footer) = select_receipt_footer(&footers, weekday) else {
+        return Ok(Json(None));
+    };
+    let secret = store_info::survey_secret(&state.pool).await?;
+    Ok(Json(Some(footer.resolve(receipt_number, &secret))))
+}
+
+/// GET /api/receipt-footers/survey-secret - Key the survey site uses to verify codes
+pub async fn get_survey_secret(State(state): State<ServerState>) -> AppResult<Json<String>> {
+    Ok(Json(store_info::survey_secret(&state.pool).await?))
+}
+
+/// POST /api/receipt-footers/survey-secret/rotate - Replace the survey key
+pub async fn rotate_survey_secret(
+    State(state): State<ServerState>,
+    Extension(current_user): Extension<CurrentUser>,
+) -> AppResult<Json<String>> {
+    let secret = store_info::rotate_survey_secret(&state.pool).await?;
+
+    audit_log!(
+        state.audit_service,
+        AuditAction::StoreInfoChanged,
+        "receipt_footer",
+        "survey_secret",
+        operator_id = Some(current_user.id),
+        operator_name = Some(current_user.name.clone()),
+        details = serde_json::json!({"survey_secret_rotated": true})
+    );
+
+    Ok(Json(secret))
+}
+
+/// POST /api/receipt-footers - Create a receipt footer
+pub async fn create(
+    State(state): State<ServerState>,
+    Extension(current_user): Extension<CurrentUser>,
+    Json(payload): Json<ReceiptFooterCreate>,
+) -> AppResult<Json<ReceiptFooter>> {
+    validate_create(&payload)?;
+
+    let footer = receipt_footer::create(&state.pool, payload).await?;
+
+    audit_log!(
+        state.audit_service,
+        AuditAction::StoreInfoChanged,
+        "receipt_footer",
+        &footer.id.to_string(),
+        operator_id = Some(current_user.id),
+        operator_name = Some(current_user.name.clone()),
+        details = create_snapshot(&footer, "receipt_footer")
+    );
+
+    Ok(Json(footer))
+}
+
+/// PUT /api/receipt-footers/:id - Update a receipt footer
+pub async fn update(
+    State(state): State<ServerState>,
+    Extension(current_user): Extension<CurrentUser>,
+    Path(id): Path<i64>,
+    Json(payload): Json<ReceiptFooterUpdate>,
+) -> AppResult<Json<ReceiptFooter>> {
+    validate_update(&payload)?;
+
+    let old_footer = receipt_footer::find_by_id(&state.pool, id)
+        .await?
+        .ok_or_else(|| AppError::not_found(format!("Receipt footer {}", id)))?;
+    let footer = receipt_footer::update(&state.pool, id, payload).await?;
+
+    audit_log!(
+        state.audit_service,
+        AuditAction::StoreInfoChanged,
+        "receipt_footer",
+        &id.to_string(),
+        operator_id = Some(current_user.id),
+        operator_name = Some(current_user.name.clone()),
+        details = create_diff(&old_footer, &footer, "receipt_footer")
+    );
+
+    Ok(Json(footer))
+}
+
+/// DELETE /api/receipt-footers/:id - Delete a receipt footer
+pub async fn delete(
+    State(state): State<ServerState>,
+    Extension(current_user): Extension<CurrentUser>,
+    Path(id): Path<i64>,
+) -> AppResult<Json<bool>> {
+    let footer = receipt_footer::find_by_id(&state.pool, id)
+        .await?
+        .ok_or_else(|| AppError::not_found(format!("Receipt footer {}", id)))?;
+    let result = receipt_footer::delete(&state.pool, id).await?;
+
+    if result {
+        audit_log!(
+            state.audit_service,
+            AuditAction::StoreInfoChanged,
+            "receipt_footer",
+            &id.to_string(),
+            operator_id = Some(current_user.id),
+            operator_name = Some(current_user.name.clone()),
+            details = serde_json::json!({"deleted": footer.name})
+        );
+    }
+
+    Ok(Json(result))
+}
@@ -0,0 +1,36 @@
+//! Receipt Footer API Module
+
+mod handler;
+
+use axum::{Router, middleware, routing::get};
+
+use crate::auth::require_permission;
+use crate::core::ServerState;
+
+/// Receipt footer router
+pub fn router() -> Router<ServerState> {
+    Router::new().nest("/api/receipt-footers", routes())
+}
+
+fn routes() -> Router<ServerState> {
+    // 读取路由：无需权限检查（打印收据时解析页脚）
+    let read_routes = Router::new()
+        .route("/", get(handler::list))
+        .route("/resolve", get(handler::resolve));
+
+    // 写入路由：需要 settings:manage 权限
+    let write_routes = Router::new()
+        .route("/", axum::routing::post(handler::create))
+        .route("/survey-secret", get(handler::get_survey_secret))
+        .route(
+            "/survey-secret/rotate",
+            axum::routing::post(handler::rotate_survey_secret),
+        )
+        .route(
+            "/{id}",
+            axum::routing::put(handler::update).delete(handler::delete),
+        )
+        .layer(middleware::from_fn(require_permission("settings:manage")));
+
+    read_routes.merge(write_routes)
+}
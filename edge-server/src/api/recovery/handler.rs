@@ -0,0 +1,25 @@
+//! Recovery API Handlers
+
+use axum::{Json, extract::State};
+
+use crate::core::ServerState;
+use crate::recovery::RecoveryReport;
+use crate::utils::{AppError, AppResult};
+
+/// GET /api/recovery - 最近一次异常关闭的恢复报告 (无则返回 null)
+pub async fn get(State(state): State<ServerState>) -> AppResult<Json<Option<RecoveryReport>>> {
+    let report = RecoveryReport::load(&state.config.recovery_report_path())
+        .map_err(|e| AppError::internal(format!("Failed to read recovery report: {e}")))?;
+    Ok(Json(report))
+}
+
+/// DELETE /api/recovery - 确认并清除恢复报告
+pub async fn dismiss(State(state): State<ServerState>) -> AppResult<Json<bool>> {
+    match std::fs::remove_file(state.config.recovery_report_path()) {
+        Ok(()) => Ok(Json(true)),
+        Err(e) if e.kind() == std::io::ErrorKind::NotFound => Ok(Json(false)),
+        Err(e) => Err(AppError::internal(format!(
+            "Failed to remove recovery report: {e}"
+        ))),
+    }
+}
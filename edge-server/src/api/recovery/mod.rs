@@ -0,0 +1,20 @@
+//! Recovery API 模块 (异常关闭恢复报告)
+
+mod handler;
+
+use axum::{Router, middleware, routing::get};
+
+use crate::auth::require_permission;
+use crate::core::ServerState;
+
+pub fn router() -> Router<ServerState> {
+    Router::new()
+        .nest("/api/recovery", routes())
+        .layer(middleware::from_fn(require_permission(
+            crate::recovery::NOTIFY_PERMISSION,
+        )))
+}
+
+fn routes() -> Router<ServerState> {
+    Router::new().route("/", get(handler::get).delete(handler::dismiss))
+}
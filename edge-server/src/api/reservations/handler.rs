@@ -0,0 +1,402 @@
+//! Reservation API Handlers
+
+use axum::{
+    Json,
+    extract::{Extension, Path, Query, State},
+};
+use serde::Deserialize;
+
+use crate::audit::{AuditAction, create_diff, create_snapshot};
+use crate::audit_log;
+use crate::auth::CurrentUser;
+use crate::core::ServerState;
+use crate::db::repository::{reservation, store_info};
+use crate::utils::time::{day_end_millis, day_start_millis, parse_date};
+use crate::utils::validation::{
+    MAX_EMAIL_LEN, MAX_NAME_LEN, MAX_NOTE_LEN, MAX_SHORT_TEXT_LEN, validate_optional_text,
+    validate_required_text,
+};
+use crate::utils::{AppError, AppResult};
+use shared::cloud::email::{EmailTemplateKind, TemplatedEmail};
+use shared::error::ErrorCode;
+use shared::message::SyncChangeType;
+use shared::models::{
+    MAX_PARTY_SIZE, MAX_RESERVATION_MINUTES, Reservation, ReservationCalendar, ReservationCreate,
+    ReservationStatus, ReservationUpdate, StoreInfo, calendar_slots,
+};
+
+use shared::cloud::SyncResource;
+const RESOURCE: SyncResource = SyncResource::Reservation;
+
+/// 默认列表回看窗口（当天仍在进行的订位）
+const DEFAULT_LOOKBACK_MS: i64 = 12 * 60 * 60 * 1000;
+/// 日历默认时段长度（分钟）
+const DEFAULT_SLOT_MINUTES: i32 = 30;
+/// 最短订位时长（分钟）
+const MIN_RESERVATION_MINUTES: i32 = 15;
+
+#[derive(Debug, Deserialize)]
+pub struct ListQuery {
+    /// Slot lower bound (Unix millis, inclusive)
+    pub from: Option<i64>,
+    /// Slot upper bound (Unix millis, exclusive)
+    pub to: Option<i64>,
+    pub status: Option<ReservationStatus>,
+    pub zone_id: Option<i64>,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct CalendarQuery {
+    /// Local date (YYYY-MM-DD)
+    pub date: String,
+    pub slot_minutes: Option<i32>,
+    pub zone_id: Option<i64>,
+}
+
+fn validate_party_size(party_size: i32) -> AppResult<()> {
+    if !(1..=MAX_PARTY_SIZE).contains(&party_size) {
+        return Err(AppError::validation(format!(
+            "party_size must be between 1 and {MAX_PARTY_SIZE}"
+        )));
+    }
+    Ok(())
+}
+
+fn validate_duration(minutes: Option<i32>) -> AppResult<()> {
+    if let Some(minutes) = minutes
+        && !(MIN_RESERVATION_MINUTES..=MAX_RESERVATION_MINUTES).contains(&minutes)
+    {
+        return Err(AppError::validation(format!(
+            "duration_minutes must be between {MIN_RESERVATION_MINUTES} and {MAX_RESERVATION_MINUTES}"
+        )));
+    }
+    Ok(())
+}
+
+fn validate_contact(
+    phone: &Option<String>,
+    email: &Option<String>,
+    note: &Option<String>,
+) -> AppResult<()> {
+    validate_optional_text(phone, "customer_phone", MAX_SHORT_TEXT_LEN)?;
+    validate_optional_text(email, "customer_email", MAX_EMAIL_LEN)?;
+    validate_optional_text(note, "note", MAX_NOTE_LEN)
+}
+
+fn validate_create(payload: &ReservationCreate) -> AppResult<()> {
+    validate_required_text(&payload.customer_name, "customer_name", MAX_NAME_LEN)?;
+    validate_contact(
+        &payload.customer_phone,
+        &payload.customer_email,
+        &payload.note,
+    )?;
+    validate_party_size(payload.party_size)?;
+    validate_duration(payload.duration_minutes)?;
+    if payload.start_at <= 0 {
+        return Err(AppError::validation("start_at is required"));
+    }
+    Ok(())
+}
+
+fn validate_update(payload: &ReservationUpdate) -> AppResult<()> {
+    if let Some(name) = &payload.customer_name {
+        validate_required_text(name, "customer_name", MAX_NAME_LEN)?;
+    }
+    validate_contact(
+        &payload.customer_phone,
+        &payload.customer_email,
+        &payload.note,
+    )?;
+    if let Some(party_size) = payload.party_size {
+        validate_party_size(party_size)?;
+    }
+    validate_duration(payload.duration_minutes)?;
+    if payload.start_at.is_some_and(|s| s <= 0) {
+        return Err(AppError::validation("start_at must be a valid timestamp"));
+    }
+    Ok(())
+}
+
+async fn broadcast(state: &ServerState, reservation: &Reservation, change: SyncChangeType) {
+    state
+        .broadcast_sync(RESOURCE, change, reservation.id, Some(reservation), false)
+        .await;
+}
+
+/// 预订确认邮件 (顾客未留邮箱时为 None)
+fn confirmation_email(
+    reservation: &Reservation,
+    store: Option<&StoreInfo>,
+    tz: chrono_tz::Tz,
+) -> Option<TemplatedEmail> {
+    let to = reservation
+        .customer_email
+        .as_deref()
+        .map(str::trim)
+        .filter(|e| !e.is_empty())?;
+    let start = chrono::DateTime::from_timestamp_millis(reservation.start_at)?.with_timezone(&tz);
+    let vars = [
+        (
+            "store_name",
+            store.map(|s| s.name.clone()).unwrap_or_default(),
+        ),
+        ("customer_name", reservation.customer_name.clone()),
+        ("date", start.format("%d/%m/%Y").to_string()),
+        ("time", start.format("%H:%M").to_string()),
+        ("party_size", reservation.party_size.to_string()),
+        (
+            "store_phone",
+            store.and_then(|s| s.phone.clone()).unwrap_or_default(),
+        ),
+    ];
+    Some(TemplatedEmail {
+        kind: EmailTemplateKind::ReservationConfirmation,
+        to: to.to_string(),
+        vars: vars
+            .into_iter()
+            .map(|(name, value)| (name.to_string(), value))
+            .collect(),
+    })
+}
+
+/// 排队发送预订确认 (经 cloud 按租户模板投递)
+async fn send_confirmation(state: &ServerState, reservation: &Reservation) {
+    let store = match store_info::get(&state.pool).await {
+        Ok(store) => store,
+        Err(e) => {
+            tracing::warn!(
+                reservation_id = reservation.id,
+                "Store info unavailable: {e}"
+            );
+            None
+        }
+    };
+    if let Some(email) = confirmation_email(reservation, store.as_ref(), state.config.timezone) {
+        state.mail.enqueue(email);
+    }
+}
+
+/// GET /api/reservations - 按时段列出订位
+pub async fn list(
+    State(state): State<ServerState>,
+    Query(query): Query<ListQuery>,
+) -> AppResult<Json<Vec<Reservation>>> {
+    let from = query
+        .from
+        .unwrap_or_else(|| shared::util::now_millis() - DEFAULT_LOOKBACK_MS);
+    let to = query.to.unwrap_or(i64::MAX);
+    let reservations =
+        reservation::find_range(&state.pool, from, to, query.status, query.zone_id).await?;
+    Ok(Json(reservations))
+}
+
+/// GET /api/reservations/calendar?date=YYYY-MM-DD - 某天的时段占用和订位
+pub async fn calendar(
+    State(state): State<ServerState>,
+    Query(query): Query<CalendarQuery>,
+) -> AppResult<Json<ReservationCalendar>> {
+    let date = parse_date(&query.date)?;
+    let slot_minutes = query.slot_minutes.unwrap_or(DEFAULT_SLOT_MINUTES);
+    if !(MIN_RESERVATION_MINUTES..=240).contains(&slot_minutes) {
+        return Err(AppError::validation(format!(
+            "slot_minutes must be between {MIN_RESERVATION_MINUTES} and 240"
+        )));
+    }
+
+    let tz = state.config.timezone;
+    let from = day_start_millis(date, tz);
+    let to = day_end_millis(date, tz);
+    let reservations = reservation::find_range(&state.pool, from, to, None, query.zone_id).await?;
+    let slots = calendar_slots(&reservations, from, to, slot_minutes as i64 * 60 * 1000);
+
+    Ok(Json(ReservationCalendar {
+        date: date.to_string(),
+        slot_minutes,
+        slots,
+        reservations,
+    }))
+}
+
+/// GET /api/reservations/:id - 订位详情
+pub async fn get_by_id(
+    State(state): State<ServerState>,
+    Path(id): Path<i64>,
+) -> AppResult<Json<Reservation>> {
+    let reservation = reservation::find_by_id(&state.pool, id)
+        .await?
+        .ok_or_else(|| {
+            AppError::with_message(
+                ErrorCode::ReservationNotFound,
+                format!("Reservation {} not found", id),
+            )
+        })?;
+    Ok(Json(reservation))
+}
+
+/// POST /api/reservations - 新建订位（冲突时返回 409）
+pub async fn create(
+    State(state): State<ServerState>,
+    Extension(current_user): Extension<CurrentUser>,
+    Json(payload): Json<ReservationCreate>,
+) -> AppResult<Json<Reservation>> {
+    validate_create(&payload)?;
+
+    let reservation =
+        reservation::create(&state.pool, &payload, current_user.id, &current_user.name).await?;
+
+    audit_log!(
+        state.audit_service,
+        AuditAction::ReservationCreated,
+        "reservation",
+        &reservation.id.to_string(),
+        operator_id = Some(current_user.id),
+        operator_name = Some(current_user.name.clone()),
+        details = create_snapshot(&reservation, "reservation")
+    );
+
+    broadcast(&state, &reservation, SyncChangeType::Created).await;
+    send_confirmation(&state, &reservation).await;
+    Ok(Json(reservation))
+}
+
+/// PUT /api/reservations/:id - 修改订位（仅 BOOKED）
+pub async fn update(
+    State(state): State<ServerState>,
+    Extension(current_user): Extension<CurrentUser>,
+    Path(id): Path<i64>,
+    Json(payload): Json<ReservationUpdate>,
+) -> AppResult<Json<Reservation>> {
+    validate_update(&payload)?;
+
+    let (before, after) = reservation::update(&state.pool, id, &payload).await?;
+
+    audit_log!(
+        state.audit_service,
+        AuditAction::ReservationUpdated,
+        "reservation",
+        &id.to_string(),
+        operator_id = Some(current_user.id),
+        operator_name = Some(current_user.name.clone()),
+        details = create_diff(&before, &after, "reservation")
+    );
+
+    broadcast(&state, &after, SyncChangeType::Updated).await;
+
+    // 时间 / 人数 / 邮箱变化后重新确认
+    if before.start_at != after.start_at
+        || before.party_size != after.party_size
+        || before.customer_email != after.customer_email
+    {
+        send_confirmation(&state, &after).await;
+    }
+
+    Ok(Json(after))
+}
+
+async fn transition(
+    state: &ServerState,
+    current_user: &CurrentUser,
+    id: i64,
+    to: ReservationStatus,
+) -> AppResult<Json<Reservation>> {
+    let reservation = reservation::transition(&state.pool, id, to).await?;
+
+    let action = match to {
+        ReservationStatus::Cancelled => AuditAction::ReservationCancelled,
+        _ => AuditAction::ReservationUpdated,
+    };
+    audit_log!(
+        state.audit_service,
+        action,
+        "reservation",
+        &id.to_string(),
+        operator_id = Some(current_user.id),
+        operator_name = Some(current_user.name.clone()),
+        details = serde_json::json!({
+            "customer_name": reservation.customer_name,
+            "start_at": reservation.start_at,
+            "status": reservation.status,
+        })
+    );
+
+    broadcast(state, &reservation, SyncChangeType::Updated).await;
+    Ok(Json(reservation))
+}
+
+/// POST /api/reservations/:id/seat - 顾客到店入座
+pub async fn seat(
+    State(state): State<ServerState>,
+    Extension(current_user): Extension<CurrentUser>,
+    Path(id): Path<i64>,
+) -> AppResult<Json<Reservation>> {
+    transition(&state, &current_user, id, ReservationStatus::Seated).await
+}
+
+/// POST /api/reservations/:id/no-show - 标记未到店（释放桌台）
+pub async fn no_show(
+    State(state): State<ServerState>,
+    Extension(current_user): Extension<CurrentUser>,
+    Path(id): Path<i64>,
+) -> AppResult<Json<Reservation>> {
+    transition(&state, &current_user, id, ReservationStatus::NoShow).await
+}
+
+/// POST /api/reservations/:id/cancel - 取消订位
+pub async fn cancel(
+    State(state): State<ServerState>,
+    Extension(current_user): Extension<CurrentUser>,
+    Path(id): Path<i64>,
+) -> AppResult<Json<Reservation>> {
+    transition(&state, &current_user, id, ReservationStatus::Cancelled).await
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn reservation(email: Option<&str>) -> Reservation {
+        Reservation {
+            id: 1,
+            customer_name: "Ana".into(),
+            customer_phone: None,
+            customer_email: email.map(str::to_string),
+            party_size: 4,
+            // 2025-06-01 18:30 UTC
+            start_at: 1_748_802_600_000,
+            end_at: 1_748_808_000_000,
+            zone_id: None,
+            zone_name: None,
+            table_id: None,
+            table_name: None,
+            note: None,
+            status: ReservationStatus::Booked,
+            seated_at: None,
+            created_by_id: 1,
+            created_by_name: "Admin".into(),
+            created_at: 0,
+            updated_at: 0,
+        }
+    }
+
+    #[test]
+    fn test_confirmation_email_uses_store_timezone() {
+        let email = confirmation_email(
+            &reservation(Some(" ana@example.com ")),
+            None,
+            chrono_tz::Europe::Madrid,
+        )
+        .unwrap();
+        assert_eq!(email.kind, EmailTemplateKind::ReservationConfirmation);
+        assert_eq!(email.to, "ana@example.com");
+        assert_eq!(email.vars["date"], "01/06/2025");
+        assert_eq!(email.vars["time"], "20:30");
+        assert_eq!(email.vars["party_size"], "4");
+    }
+
+    #[test]
+    fn test_no_confirmation_without_email() {
+        let tz = chrono_tz::Europe::Madrid;
+        assert!(confirmation_email(&reservation(None), None, tz).is_none());
+        assert!(confirmation_email(&reservation(Some("  ")), None, tz).is_none());
+    }
+}
@@ -0,0 +1,28 @@
+//! Reservation API Module
+//!
+//! 订位 — 时段、人数、顾客联系方式，桌台 / 区域冲突检测，日历视图
+
+mod handler;
+
+use axum::{
+    Router,
+    routing::{get, post},
+};
+
+use crate::core::ServerState;
+
+/// Reservation router
+pub fn router() -> Router<ServerState> {
+    Router::new().nest("/api/reservations", routes())
+}
+
+fn routes() -> Router<ServerState> {
+    // 订位属于前台日常操作：登录即可，不单独设权限
+    Router::new()
+        .route("/", get(handler::list).post(handler::create))
+        .route("/calendar", get(handler::calendar))
+        .route("/{id}", get(handler::get_by_id).put(handler::update))
+        .route("/{id}/seat", post(handler::seat))
+        .route("/{id}/no-show", post(handler::no_show))
+        .route("/{id}/cancel", post(handler::cancel))
+}
@@ -0,0 +1,159 @@
+//! Session Recording API Handlers
+
+use std::path::PathBuf;
+use std::time::Duration;
+
+use axum::{
+    Json,
+    extract::{Path, State},
+    http::header,
+    response::IntoResponse,
+};
+use serde::{Deserialize, Serialize};
+
+use crate::core::ServerState;
+use crate::orders::recorder::{
+    DEFAULT_RECORDING_DURATION, RecordingStatus, SESSION_FORMAT, SESSION_VERSION, SessionHeader,
+};
+use crate::utils::{AppError, AppResult};
+
+#[derive(Debug, Deserialize)]
+pub struct StartRequest {
+    /// 录制时长 (秒)，默认 15 分钟，最长 4 小时
+    pub duration_secs: Option<u64>,
+}
+
+#[derive(Debug, Serialize)]
+pub struct RecordingFile {
+    pub name: String,
+    pub size: u64,
+    pub modified_at: Option<i64>,
+}
+
+fn recordings_dir(state: &ServerState) -> PathBuf {
+    state.work_dir().join("recordings")
+}
+
+/// GET /api/session-recording - 当前录制状态
+pub async fn status(State(state): State<ServerState>) -> Json<RecordingStatus> {
+    Json(state.orders_manager().recorder().status())
+}
+
+/// POST /api/session-recording/start - 开始录制 (命令、事件、总线流量)
+pub async fn start(
+    State(state): State<ServerState>,
+    Json(req): Json<StartRequest>,
+) -> AppResult<Json<RecordingStatus>> {
+    let manager = state.orders_manager();
+    let recorder = manager.recorder();
+    if recorder.is_active() {
+        return Err(AppError::conflict("A session recording is already running"));
+    }
+
+    let now = chrono::Utc::now();
+    let header = SessionHeader {
+        format: SESSION_FORMAT.to_string(),
+        version: SESSION_VERSION,
+        started_at: now.timestamp_millis(),
+        epoch: manager.epoch().to_string(),
+        start_sequence: manager.get_current_sequence().unwrap_or(0),
+        active_orders: manager
+            .get_active_orders()
+            .map_err(|e| AppError::internal(e.to_string()))?,
+    };
+    let path =
+        recordings_dir(&state).join(format!("session-{}.jsonl", now.format("%Y%m%d-%H%M%S")));
+    let duration = req
+        .duration_secs
+        .map(Duration::from_secs)
+        .unwrap_or(DEFAULT_RECORDING_DURATION);
+
+    let status = recorder
+        .start(path, &header, duration)
+        .map_err(|e| AppError::internal(format!("Failed to start recording: {e}")))?;
+    let bus = state.message_bus();
+    recorder.spawn_bus_tap(bus.subscribe_to_clients(), bus.subscribe());
+    Ok(Json(status))
+}
+
+/// POST /api/session-recording/stop - 停止录制并落盘
+pub async fn stop(State(state): State<ServerState>) -> AppResult<Json<RecordingStatus>> {
+    state
+        .orders_manager()
+        .recorder()
+        .stop()
+        .map(Json)
+        .ok_or_else(|| AppError::validation("No session recording is running"))
+}
+
+/// GET /api/session-recording/files - 录制文件列表 (最新在前)
+pub async fn list_files(State(state): State<ServerState>) -> AppResult<Json<Vec<RecordingFile>>> {
+    let mut files = Vec::new();
+    let mut dir = match tokio::fs::read_dir(recordings_dir(&state)).await {
+        Ok(dir) => dir,
+        Err(e) if e.kind() == std::io::ErrorKind::NotFound => return Ok(Json(files)),
+        Err(e) => return Err(AppError::internal(e.to_string())),
+    };
+    while let Some(entry) = dir
+        .next_entry()
+        .await
+        .map_err(|e| AppError::internal(e.to_string()))?
+    {
+        let name = entry.file_name().to_string_lossy().into_owned();
+        if !is_recording_name(&name) {
+            continue;
+        }
+        let Ok(meta) = entry.metadata().await else {
+            continue;
+        };
+        files.push(RecordingFile {
+            name,
+            size: meta.len(),
+            modified_at: meta
+                .modified()
+                .ok()
+                .map(|t| chrono::DateTime::<chrono::Utc>::from(t).timestamp_millis()),
+        });
+    }
+    files.sort_by(|a, b| b.name.cmp(&a.name));
+    Ok(Json(files))
+}
+
+/// GET /api/session-recording/files/:name - 下载录制文件
+pub async fn download(
+    State(state): State<ServerState>,
+    Path(name): Path<String>,
+) -> AppResult<impl IntoResponse> {
+    if !is_recording_name(&name) {
+        return Err(AppError::validation(format!(
+            "Invalid recording name: {name}"
+        )));
+    }
+    let bytes = match tokio::fs::read(recordings_dir(&state).join(&name)).await {
+        Ok(bytes) => bytes,
+        Err(e) if e.kind() == std::io::ErrorKind::NotFound => {
+            return Err(AppError::not_found(format!("Recording {name}")));
+        }
+        Err(e) => return Err(AppError::internal(e.to_string())),
+    };
+    Ok((
+        [
+            (header::CONTENT_TYPE, "application/x-ndjson".to_string()),
+            (
+                header::CONTENT_DISPOSITION,
+                format!("attachment; filename=\"{name}\""),
+            ),
+        ],
+        bytes,
+    ))
+}
+
+/// `session-*.jsonl`，只允许安全字符 (防目录穿越)
+fn is_recording_name(name: &str) -> bool {
+    name.starts_with("session-")
+        && name.ends_with(".jsonl")
+        && name
+            .chars()
+            .all(|c| c.is_ascii_alphanumeric() || matches!(c, '-' | '_' | '.'))
+        && !name.contains("..")
+}
@@ -0,0 +1,26 @@
+//! Session Recording API 模块 (现场问题录制)
+
+mod handler;
+
+use axum::{
+    Router, middleware,
+    routing::{get, post},
+};
+
+use crate::auth::require_admin;
+use crate::core::ServerState;
+
+pub fn router() -> Router<ServerState> {
+    Router::new()
+        .nest("/api/session-recording", routes())
+        .layer(middleware::from_fn(require_admin))
+}
+
+fn routes() -> Router<ServerState> {
+    Router::new()
+        .route("/", get(handler::status))
+        .route("/start", post(handler::start))
+        .route("/stop", post(handler::stop))
+        .route("/files", get(handler::list_files))
+        .route("/files/{name}", get(handler::download))
+}
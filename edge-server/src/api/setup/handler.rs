@@ -0,0 +1,41 @@
+//! Setup Wizard API Handlers
+
+use axum::{
+    Json,
+    extract::{Extension, State},
+};
+
+use crate::audit::AuditAction;
+use crate::audit_log;
+use crate::auth::CurrentUser;
+use crate::core::ServerState;
+use crate::utils::AppResult;
+use shared::models::{SetupStatus, SetupStepSubmit};
+
+/// GET /api/setup/steps - 向导步骤与完成状态
+pub async fn list(State(state): State<ServerState>) -> AppResult<Json<SetupStatus>> {
+    let status = crate::setup::status(&state.pool).await?;
+    Ok(Json(status))
+}
+
+/// POST /api/setup/steps - 确认 / 跳过步骤 (必需步骤全部确认后开放开台)
+pub async fn submit(
+    State(state): State<ServerState>,
+    Extension(current_user): Extension<CurrentUser>,
+    Json(payload): Json<SetupStepSubmit>,
+) -> AppResult<Json<SetupStatus>> {
+    let status = crate::setup::submit(&state.pool, &payload, current_user.id).await?;
+    state.orders_manager.update_setup_complete(status.complete);
+
+    audit_log!(
+        state.audit_service,
+        AuditAction::StoreInfoChanged,
+        "setup_step",
+        payload.step.as_str(),
+        operator_id = Some(current_user.id),
+        operator_name = Some(current_user.name.clone()),
+        details = serde_json::json!({ "skipped": payload.skip, "complete": status.complete })
+    );
+
+    Ok(Json(status))
+}
@@ -0,0 +1,27 @@
+//! Setup Wizard API 模块 (首次安装向导)
+
+mod handler;
+
+use axum::{
+    Router, middleware,
+    routing::{get, post},
+};
+
+use crate::auth::require_permission;
+use crate::core::ServerState;
+
+pub fn router() -> Router<ServerState> {
+    Router::new().nest("/api/setup", routes())
+}
+
+fn routes() -> Router<ServerState> {
+    // 读取路由：无需权限检查
+    let read_routes = Router::new().route("/steps", get(handler::list));
+
+    // 写入路由：需要 settings:manage 权限
+    let write_routes = Router::new()
+        .route("/steps", post(handler::submit))
+        .layer(middleware::from_fn(require_permission("settings:manage")));
+
+    read_routes.merge(write_routes)
+}
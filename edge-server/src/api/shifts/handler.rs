@@ -10,7 +10,7 @@ use crate::audit::AuditAction;
 use crate::audit_log;
 use crate::auth::CurrentUser;
 use crate::core::ServerState;
-use crate::db::repository::{shift, store_info};
+use crate::db::repository::{cash_denomination, cash_drawer, shift, store_info};
 use crate::utils::time;
 use crate::utils::validation::{
     MAX_NAME_LEN, MAX_NOTE_LEN, validate_optional_text, validate_required_text,
@@ -18,7 +18,10 @@ use crate::utils::validation::{
 use crate::utils::{AppError, AppResult};
 use shared::error::ErrorCode;
 use shared::message::SyncChangeType;
-use shared::models::{Shift, ShiftClose, ShiftCreate, ShiftForceClose, ShiftUpdate};
+use shared::models::{
+    CashCountLine, Shift, ShiftBreak, ShiftBreakStart, ShiftBreakStatus, ShiftClose, ShiftCreate,
+    ShiftForceClose, ShiftUpdate,
+};
 
 use shared::cloud::SyncResource;
 const RESOURCE: SyncResource = SyncResource::Shift;
@@ -168,12 +171,26 @@ pub async fn close(
     State(state): State<ServerState>,
     Extension(current_user): Extension<CurrentUser>,
     Path(id): Path<i64>,
-    Json(payload): Json<ShiftClose>,
+    Json(mut payload): Json<ShiftClose>,
 ) -> AppResult<Json<Shift>> {
+    // 按面额点钞时，实收现金由服务端计算 (忽略客户端提交的 actual_cash)
+    let cash_count = match payload.cash_count.take() {
+        Some(lines) => {
+            let summary = cash_denomination::summarize(&state.pool, &lines).await?;
+            payload.actual_cash = summary.total;
+            Some(summary)
+        }
+        None => None,
+    };
     validate_cash(payload.actual_cash, "actual_cash")?;
     validate_optional_text(&payload.note, "note", MAX_NOTE_LEN)?;
 
     let s = shift::close(&state.pool, id, payload).await?;
+    if let Some(summary) = &cash_count {
+        cash_denomination::save_shift_count(&state.pool, id, summary).await?;
+    }
+    // 钱箱存取已计入 expected_cash，审计中单列便于对账
+    let drawer = cash_drawer::summarize_shift(&state.pool, id).await?;
 
     let id_str = id.to_string();
 
@@ -189,6 +206,8 @@ pub async fn close(
             "expected_cash": s.expected_cash,
             "actual_cash": s.actual_cash,
             "cash_variance": s.cash_variance,
+            "cash_count": cash_count,
+            "cash_drawer": drawer,
             "closed_at": s.end_time,
         })
     );
@@ -235,6 +254,18 @@ pub async fn force_close(
     Ok(Json(s))
 }
 
+/// GET /api/shifts/:id/cash-count - 收班点钞明细 (未按面额点钞时为空)
+pub async fn get_cash_count(
+    State(state): State<ServerState>,
+    Path(id): Path<i64>,
+) -> AppResult<Json<Vec<CashCountLine>>> {
+    shift::find_by_id(&state.pool, id).await?.ok_or_else(|| {
+        AppError::with_message(ErrorCode::ShiftNotFound, format!("Shift {} not found", id))
+    })?;
+    let lines = cash_denomination::find_shift_count(&state.pool, id).await?;
+    Ok(Json(lines))
+}
+
 /// POST /api/shifts/:id/heartbeat - 心跳更新
 pub async fn heartbeat(
     State(state): State<ServerState>,
@@ -244,6 +275,91 @@ pub async fn heartbeat(
     Ok(Json(true))
 }
 
+/// GET /api/shifts/:id/breaks - 休息记录 + 合规状态
+pub async fn list_breaks(
+    State(state): State<ServerState>,
+    Path(id): Path<i64>,
+) -> AppResult<Json<ShiftBreakStatus>> {
+    let s = shift::find_by_id(&state.pool, id).await?.ok_or_else(|| {
+        AppError::with_message(ErrorCode::ShiftNotFound, format!("Shift {} not found", id))
+    })?;
+    let breaks = shift::find_breaks(&state.pool, id).await?;
+    Ok(Json(ShiftBreakStatus::evaluate(
+        &s,
+        breaks,
+        shared::util::now_millis(),
+    )))
+}
+
+/// POST /api/shifts/:id/breaks/start - 开始休息
+pub async fn start_break(
+    State(state): State<ServerState>,
+    Extension(current_user): Extension<CurrentUser>,
+    Path(id): Path<i64>,
+    Json(payload): Json<ShiftBreakStart>,
+) -> AppResult<Json<ShiftBreak>> {
+    validate_optional_text(&payload.note, "note", MAX_NOTE_LEN)?;
+
+    let b = shift::start_break(&state.pool, id, payload).await?;
+
+    let id_str = id.to_string();
+
+    audit_log!(
+        state.audit_service,
+        AuditAction::ShiftBreakStarted,
+        "shift",
+        &id_str,
+        operator_id = Some(current_user.id),
+        operator_name = Some(current_user.name.clone()),
+        details = serde_json::json!({
+            "break_type": b.break_type,
+            "started_at": b.start_time,
+        })
+    );
+
+    broadcast_shift(&state, id).await;
+
+    Ok(Json(b))
+}
+
+/// POST /api/shifts/:id/breaks/end - 结束休息
+pub async fn end_break(
+    State(state): State<ServerState>,
+    Extension(current_user): Extension<CurrentUser>,
+    Path(id): Path<i64>,
+) -> AppResult<Json<ShiftBreak>> {
+    let b = shift::end_break(&state.pool, id).await?;
+
+    let id_str = id.to_string();
+
+    audit_log!(
+        state.audit_service,
+        AuditAction::ShiftBreakEnded,
+        "shift",
+        &id_str,
+        operator_id = Some(current_user.id),
+        operator_name = Some(current_user.name.clone()),
+        details = serde_json::json!({
+            "break_type": b.break_type,
+            "started_at": b.start_time,
+            "ended_at": b.end_time,
+        })
+    );
+
+    broadcast_shift(&state, id).await;
+
+    Ok(Json(b))
+}
+
+/// 休息变更后广播班次更新，其他终端刷新休息状态
+async fn broadcast_shift(state: &ServerState, id: i64) {
+    if let Ok(Some(s)) = shift::find_by_id(&state.pool, id).await {
+        state
+            .broadcast_sync(RESOURCE, SyncChangeType::Updated, id, Some(&s), false)
+            .await;
+    }
+}
+
 /// POST /api/shifts/recover - 检测并通知跨天的过期班次
 ///
 /// 根据 store_info.business_day_cutoff 计算当前营业日起始时间，
@@ -19,7 +19,9 @@ fn routes() -> Router<ServerState> {
     let read_routes = Router::new()
         .route("/", get(handler::list))
         .route("/current", get(handler::get_current))
-        .route("/{id}", get(handler::get_by_id));
+        .route("/{id}", get(handler::get_by_id))
+        .route("/{id}/breaks", get(handler::list_breaks))
+        .route("/{id}/cash-count", get(handler::get_cash_count));
 
     // 写入路由：需要 shifts:manage 权限
     let write_routes = Router::new()
@@ -29,6 +31,8 @@ fn routes() -> Router<ServerState> {
         .route("/{id}/close", post(handler::close))
         .route("/{id}/force-close", post(handler::force_close))
         .route("/{id}/heartbeat", post(handler::heartbeat))
+        .route("/{id}/breaks/start", post(handler::start_break))
+        .route("/{id}/breaks/end", post(handler::end_break))
         .layer(middleware::from_fn(require_permission("shifts:manage")));
 
     read_routes.merge(write_routes)
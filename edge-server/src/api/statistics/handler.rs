@@ -2,13 +2,15 @@
 
 use axum::{
     Json,
-    extract::{Query, State},
+    extract::{Extension, Query, State},
 };
 use chrono::{Datelike, Duration};
 use serde::{Deserialize, Serialize};
 
+use crate::auth::CurrentUser;
+use crate::auth::permissions::ReportScope;
 use crate::core::ServerState;
-use crate::db::repository::{invoice, store_info};
+use crate::db::repository::{campaign_report, invoice, sales_facts, stamp, store_info};
 use crate::utils::time;
 use crate::utils::{AppError, AppResult};
 
@@ -49,6 +51,33 @@ pub struct StoreOverview {
     pub zone_sales: Vec<ZoneSaleEntry>,
     pub discount_breakdown: Vec<AdjustmentEntry>,
     pub surcharge_breakdown: Vec<AdjustmentEntry>,
+    /// 调用者的报表范围 (前端据此隐藏被裁剪的区块)
+    pub scope: ReportScope,
+}
+
+impl StoreOverview {
+    /// 无 `reports:financials` 时清除财务字段 (税、调整、支付、作废/损失/退款)
+    fn apply_scope(mut self) -> Self {
+        if !self.scope.financials {
+            self.net_revenue = 0.0;
+            self.total_tax = 0.0;
+            self.total_discount = 0.0;
+            self.total_surcharge = 0.0;
+            self.voided_amount = 0.0;
+            self.loss_orders = 0;
+            self.loss_amount = 0.0;
+            self.anulacion_count = 0;
+            self.anulacion_amount = 0.0;
+            self.refund_count = 0;
+            self.refund_amount = 0.0;
+            self.payment_breakdown.clear();
+            self.tax_breakdown.clear();
+            self.refund_method_breakdown.clear();
+            self.discount_breakdown.clear();
+            self.surcharge_breakdown.clear();
+        }
+        self
+    }
 }
 
 #[derive(Debug, Clone, Serialize)]
@@ -286,6 +315,7 @@ fn calculate_time_range(
 /// GET /api/statistics - Get store overview statistics
 pub async fn get_statistics(
     State(state): State<ServerState>,
+    Extension(current_user): Extension<CurrentUser>,
     Query(query): Query<StatisticsQuery>,
 ) -> AppResult<Json<StoreOverview>> {
     let cutoff = store_info::get(&state.pool)
@@ -627,7 +657,7 @@ pub async fn get_statistics(
         }
     }
 
-    Ok(Json(StoreOverview {
+    let overview = StoreOverview {
         revenue,
         net_revenue: revenue - refund_amount,
         orders: total_orders,
@@ -659,7 +689,10 @@ pub async fn get_statistics(
         zone_sales,
         discount_breakdown,
         surcharge_breakdown,
-    }))
+        scope: ReportScope::of(&current_user),
+    };
+
+    Ok(Json(overview.apply_scope()))
 }
 
 /// GET /api/statistics/sales-report - Get paginated sales report
@@ -770,12 +803,15 @@ pub struct RedFlagsResponse {
     pub item_flags: ItemFlags,
     pub order_flags: OrderFlags,
     pub payment_flags: PaymentFlags,
+    /// 按员工拆分 (需要 `reports:labor`)
     pub operator_breakdown: Vec<OperatorRedFlags>,
+    pub scope: ReportScope,
 }
 
 /// GET /api/statistics/red-flags
 pub async fn get_red_flags(
     State(state): State<ServerState>,
+    Extension(current_user): Extension<CurrentUser>,
     Query(query): Query<StatisticsQuery>,
 ) -> AppResult<Json<RedFlagsResponse>> {
     let cutoff = store_info::get(&state.pool)
@@ -965,11 +1001,20 @@ pub async fn get_red_flags(
         .collect();
     operator_breakdown.sort_by(|a, b| b.total_flags.cmp(&a.total_flags));
 
+    let scope = ReportScope::of(&current_user);
+    if !scope.financials {
+        payment_flags.refund_amount = 0.0;
+    }
+    if !scope.labor {
+        operator_breakdown.clear();
+    }
+
     Ok(Json(RedFlagsResponse {
         item_flags,
         order_flags,
         payment_flags,
         operator_breakdown,
+        scope,
     }))
 }
 
@@ -1088,6 +1133,12 @@ pub async fn get_red_flag_log(
                 .fetch_all(pool)
                 .await
                 .map_err(|e| AppError::database(e.to_string()))?;
+        let money = store_info::get(pool)
+            .await
+            .ok()
+            .flatten()
+            .map(|i| shared::models::LocaleFormat::from_store_info(&i))
+            .unwrap_or_default();
 
         for (ts, op_id, op_name, receipt, order_id, amount, reason) in refund_rows {
             entries.push(RedFlagLogEntry {
@@ -1097,7 +1148,7 @@ pub async fn get_red_flag_log(
                 operator_name: op_name,
                 receipt_number: receipt,
                 order_id,
-                detail: Some(format!("{:.2} - {}", amount, reason)),
+                detail: Some(format!("{} - {}", money.money(amount), reason)),
             });
         }
     }
@@ -1155,6 +1206,79 @@ pub struct InvoiceListResponse {
     pub page_size: i32,
 }
 
+/// GET /api/statistics/kpi - 经理看板初始快照 (之后由 `EventType::Kpi` 推送)
+pub async fn get_kpi(
+    State(state): State<ServerState>,
+) -> AppResult<Json<shared::message::KpiPayload>> {
+    Ok(Json(crate::kpi::snapshot(&state).await?))
+}
+
+/// Resolve `from`/`to` millis or a `timeRange` preset (营销报表默认本月)
+async fn resolve_range(state: &ServerState, query: &StatisticsQuery, default: &str) -> (i64, i64) {
+    if let (Some(from), Some(to)) = (query.from, query.to) {
+        return (from, to);
+    }
+    let cutoff = store_info::get(&state.pool)
+        .await
+        .ok()
+        .flatten()
+        .map(|s| s.business_day_cutoff)
+        .unwrap_or(0);
+    calculate_time_range(
+        query.time_range.as_deref().unwrap_or(default),
+        cutoff,
+        query.start_date.as_deref(),
+        query.end_date.as_deref(),
+        state.config.timezone,
+    )
+}
+
+/// GET /api/statistics/stamp-campaigns - 集章活动效果 (发章、上限截留、兑换)
+pub async fn get_stamp_campaigns(
+    State(state): State<ServerState>,
+    Query(query): Query<StatisticsQuery>,
+) -> AppResult<Json<Vec<shared::models::StampCampaignStats>>> {
+    let (start_dt, end_dt) = resolve_range(&state, &query, "this_month").await;
+    let stats = stamp::campaign_stats(&state.pool, start_dt, end_dt).await?;
+    Ok(Json(stats))
+}
+
+/// GET /api/statistics/campaign-roi - 营销活动 ROI (价格规则 / 会员组 / 集章)
+///
+/// 让利成本与营收属于财务字段，无 `reports:financials` 时清零。
+pub async fn get_campaign_roi(
+    State(state): State<ServerState>,
+    Extension(current_user): Extension<CurrentUser>,
+    Query(query): Query<StatisticsQuery>,
+) -> AppResult<Json<shared::models::CampaignRoiReport>> {
+    let (start_dt, end_dt) = resolve_range(&state, &query, "this_month").await;
+    let mut report = campaign_report::campaign_roi(&state.pool, start_dt, end_dt).await?;
+    if !ReportScope::of(&current_user).financials {
+        for campaign in &mut report.campaigns {
+            campaign.discount_cost = 0.0;
+            campaign.attributable_revenue = 0.0;
+            campaign.revenue_per_cost = None;
+        }
+    }
+    Ok(Json(report))
+}
+
+/// GET /api/statistics/sales-facts - 报表读模型 (分时段 / 商品 / 支付方式)
+///
+/// 支付方式构成属于财务字段，无 `reports:financials` 时清空。
+pub async fn get_sales_facts(
+    State(state): State<ServerState>,
+    Extension(current_user): Extension<CurrentUser>,
+    Query(query): Query<StatisticsQuery>,
+) -> AppResult<Json<shared::models::SalesFactsReport>> {
+    let (start_dt, end_dt) = resolve_range(&state, &query, "today").await;
+    let mut report = sales_facts::report(&state.pool, start_dt, end_dt).await?;
+    if !ReportScope::of(&current_user).financials {
+        report.payments.clear();
+    }
+    Ok(Json(report))
+}
+
 /// GET /api/statistics/invoices
 pub async fn list_invoices(
     State(state): State<ServerState>,
@@ -12,12 +12,30 @@ pub fn router() -> Router<ServerState> {
 }
 
 fn routes() -> Router<ServerState> {
-    // 报表查看：需要 reports:view 权限
-    Router::new()
+    // 报表查看：需要 reports:view 权限 (财务/人员字段在 handler 中按 ReportScope 裁剪)
+    let view_routes = Router::new()
         .route("/", get(handler::get_statistics))
         .route("/sales-report", get(handler::get_sales_report))
         .route("/red-flags", get(handler::get_red_flags))
-        .route("/red-flags/log", get(handler::get_red_flag_log))
+        .route("/kpi", get(handler::get_kpi))
+        .route("/stamp-campaigns", get(handler::get_stamp_campaigns))
+        .route("/campaign-roi", get(handler::get_campaign_roi))
+        .route("/sales-facts", get(handler::get_sales_facts));
+
+    // 发票列表：需要 reports:financials 权限
+    let financial_routes = Router::new()
         .route("/invoices", get(handler::list_invoices))
+        .layer(middleware::from_fn(require_permission(
+            "reports:financials",
+        )));
+
+    // 员工异常操作明细：需要 reports:labor 权限
+    let labor_routes = Router::new()
+        .route("/red-flags/log", get(handler::get_red_flag_log))
+        .layer(middleware::from_fn(require_permission("reports:labor")));
+
+    view_routes
+        .merge(financial_routes)
+        .merge(labor_routes)
         .layer(middleware::from_fn(require_permission("reports:view")))
 }
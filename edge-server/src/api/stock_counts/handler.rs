@@ -0,0 +1,218 @@
+//! Stock Count API Handlers
+
+use axum::{
+    Json,
+    extract::{Extension, Path, Query, State},
+};
+use serde::Deserialize;
+
+use crate::audit::AuditAction;
+use crate::audit_log;
+use crate::auth::CurrentUser;
+use crate::core::ServerState;
+use crate::db::repository::stock_count;
+use crate::inventory::{RESOURCE, notify_low_stock};
+use crate::utils::validation::{
+    MAX_NOTE_LEN, MAX_SHORT_TEXT_LEN, validate_optional_text, validate_required_text,
+};
+use crate::utils::{AppError, AppResult};
+use shared::message::SyncChangeType;
+use shared::models::{
+    StockCount, StockCountCreate, StockCountEntry, StockCountLine, StockCountReview, StockCountScan,
+};
+
+/// 盘点单默认条数
+const DEFAULT_COUNT_LIMIT: i64 = 50;
+
+#[derive(Debug, Deserialize)]
+pub struct CountsQuery {
+    pub limit: Option<i64>,
+}
+
+fn stock_count_not_found(id: i64) -> AppError {
+    AppError::not_found(format!("Stock count {}", id))
+}
+
+/// GET /api/stock-counts - 盘点单 (最新在前，不含明细)
+pub async fn list(
+    State(state): State<ServerState>,
+    Query(query): Query<CountsQuery>,
+) -> AppResult<Json<Vec<StockCount>>> {
+    let limit = query.limit.unwrap_or(DEFAULT_COUNT_LIMIT).clamp(1, 1000);
+    let counts = stock_count::find_all(&state.pool, limit).await?;
+    Ok(Json(counts))
+}
+
+/// GET /api/stock-counts/:id - 盘点单及明细
+pub async fn get_by_id(
+    State(state): State<ServerState>,
+    Path(id): Path<i64>,
+) -> AppResult<Json<StockCount>> {
+    let count = stock_count::find_by_id(&state.pool, id)
+        .await?
+        .ok_or_else(|| stock_count_not_found(id))?;
+    Ok(Json(count))
+}
+
+/// POST /api/stock-counts - 开始盘点 (冻结理论数量)
+pub async fn create(
+    State(state): State<ServerState>,
+    Extension(current_user): Extension<CurrentUser>,
+    Json(payload): Json<StockCountCreate>,
+) -> AppResult<Json<StockCount>> {
+    validate_optional_text(&payload.note, "note", MAX_NOTE_LEN)?;
+
+    let count =
+        stock_count::create(&state.pool, &payload, current_user.id, &current_user.name).await?;
+
+    audit_log!(
+        state.audit_service,
+        AuditAction::StockCountStarted,
+        "stock_count",
+        &count.id.to_string(),
+        operator_id = Some(current_user.id),
+        operator_name = Some(current_user.name.clone()),
+        details = serde_json::json!({
+            "lines": count.lines.len(),
+            "note": count.note,
+        })
+    );
+
+    Ok(Json(count))
+}
+
+/// PUT /api/stock-counts/:id/lines/:stock_level_id - 录入实盘数量
+pub async fn record(
+    State(state): State<ServerState>,
+    Path((id, stock_level_id)): Path<(i64, i64)>,
+    Json(payload): Json<StockCountEntry>,
+) -> AppResult<Json<StockCountLine>> {
+    let line =
+        stock_count::record(&state.pool, id, stock_level_id, payload.counted_quantity).await?;
+    Ok(Json(line))
+}
+
+/// POST /api/stock-counts/:id/scan - 扫码计数 (累加)
+pub async fn scan(
+    State(state): State<ServerState>,
+    Path(id): Path<i64>,
+    Json(payload): Json<StockCountScan>,
+) -> AppResult<Json<StockCountLine>> {
+    validate_required_text(&payload.barcode, "barcode", MAX_SHORT_TEXT_LEN)?;
+
+    let line = stock_count::scan(&state.pool, id, &payload.barcode, payload.quantity).await?;
+    Ok(Json(line))
+}
+
+/// POST /api/stock-counts/:id/submit - 提交审批
+pub async fn submit(
+    State(state): State<ServerState>,
+    Extension(current_user): Extension<CurrentUser>,
+    Path(id): Path<i64>,
+) -> AppResult<Json<StockCount>> {
+    let count = stock_count::submit(&state.pool, id).await?;
+
+    audit_log!(
+        state.audit_service,
+        AuditAction::StockCountSubmitted,
+        "stock_count",
+        &id.to_string(),
+        operator_id = Some(current_user.id),
+        operator_name = Some(current_user.name.clone()),
+        details = serde_json::json!({
+            "lines": count.lines.len(),
+            "counted": count.lines.iter().filter(|l| l.counted_quantity.is_some()).count(),
+        })
+    );
+
+    Ok(Json(count))
+}
+
+/// POST /api/stock-counts/:id/approve - 审批通过，过账差异
+pub async fn approve(
+    State(state): State<ServerState>,
+    Extension(current_user): Extension<CurrentUser>,
+    Path(id): Path<i64>,
+    Json(payload): Json<StockCountReview>,
+) -> AppResult<Json<StockCount>> {
+    validate_optional_text(&payload.note, "note", MAX_NOTE_LEN)?;
+
+    let (count, changed) = stock_count::approve(
+        &state.pool,
+        id,
+        payload.note.as_deref(),
+        current_user.id,
+        &current_user.name,
+    )
+    .await?;
+
+    audit_log!(
+        state.audit_service,
+        AuditAction::StockCountApproved,
+        "stock_count",
+        &id.to_string(),
+        operator_id = Some(current_user.id),
+        operator_name = Some(current_user.name.clone()),
+        details = serde_json::json!({
+            "variances": count
+                .lines
+                .iter()
+                .filter(|l| l.variance.is_some_and(|v| v != 0))
+                .map(|l| serde_json::json!({
+                    "product_name": l.product_name,
+                    "spec_name": l.spec_name,
+                    "variance": l.variance,
+                }))
+                .collect::<Vec<_>>(),
+            "note": payload.note,
+        })
+    );
+
+    for (level, before) in changed {
+        state
+            .broadcast_sync(
+                RESOURCE,
+                SyncChangeType::Updated,
+                level.id,
+                Some(&level),
+                false,
+            )
+            .await;
+        if level.became_low(before) {
+            notify_low_stock(state.message_bus(), &level).await;
+        }
+    }
+
+    Ok(Json(count))
+}
+
+/// POST /api/stock-counts/:id/reject - 驳回 (不过账)
+pub async fn reject(
+    State(state): State<ServerState>,
+    Extension(current_user): Extension<CurrentUser>,
+    Path(id): Path<i64>,
+    Json(payload): Json<StockCountReview>,
+) -> AppResult<Json<StockCount>> {
+    validate_optional_text(&payload.note, "note", MAX_NOTE_LEN)?;
+
+    let count = stock_count::reject(
+        &state.pool,
+        id,
+        payload.note.as_deref(),
+        current_user.id,
+        &current_user.name,
+    )
+    .await?;
+
+    audit_log!(
+        state.audit_service,
+        AuditAction::StockCountRejected,
+        "stock_count",
+        &id.to_string(),
+        operator_id = Some(current_user.id),
+        operator_name = Some(current_user.name.clone()),
+        details = serde_json::json!({ "note": payload.note })
+    );
+
+    Ok(Json(count))
+}
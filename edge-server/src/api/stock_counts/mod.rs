@@ -0,0 +1,43 @@
+//! Stock Count API Module
+//!
+//! 盘点 — 冻结理论数量、录入实盘 (手动或扫码)、经理审批后过账差异
+
+mod handler;
+
+use axum::{
+    Router, middleware,
+    routing::{get, post, put},
+};
+
+use crate::auth::require_permission;
+use crate::core::ServerState;
+
+/// Stock count router
+pub fn router() -> Router<ServerState> {
+    Router::new().nest("/api/stock-counts", routes())
+}
+
+fn routes() -> Router<ServerState> {
+    // 读取路由：无需权限检查
+    let read_routes = Router::new()
+        .route("/", get(handler::list))
+        .route("/{id}", get(handler::get_by_id));
+
+    // 盘点路由：需要 menu:manage 权限
+    let count_routes = Router::new()
+        .route("/", post(handler::create))
+        .route("/{id}/lines/{stock_level_id}", put(handler::record))
+        .route("/{id}/scan", post(handler::scan))
+        .route("/{id}/submit", post(handler::submit))
+        .layer(middleware::from_fn(require_permission("menu:manage")));
+
+    // 审批路由：需要 inventory:approve_count 权限
+    let review_routes = Router::new()
+        .route("/{id}/approve", post(handler::approve))
+        .route("/{id}/reject", post(handler::reject))
+        .layer(middleware::from_fn(require_permission(
+            "inventory:approve_count",
+        )));
+
+    read_routes.merge(count_routes).merge(review_routes)
+}
@@ -0,0 +1,166 @@
+//! Stock Transfer API Handlers
+
+use axum::{
+    Json,
+    extract::{Extension, Path, Query, State},
+};
+use serde::Deserialize;
+
+use crate::audit::AuditAction;
+use crate::audit_log;
+use crate::auth::CurrentUser;
+use crate::core::ServerState;
+use crate::db::repository::stock_transfer;
+use crate::inventory::{RESOURCE, notify_low_stock};
+use crate::utils::validation::{MAX_NOTE_LEN, validate_optional_text};
+use crate::utils::{AppError, AppResult};
+use shared::cloud::transfer::TransferPeer;
+use shared::message::SyncChangeType;
+use shared::models::{StockLevel, StockTransfer, StockTransferCreate, StockTransferReceive};
+
+/// 调拨单默认条数
+const DEFAULT_TRANSFER_LIMIT: i64 = 50;
+
+#[derive(Debug, Deserialize)]
+pub struct TransfersQuery {
+    pub limit: Option<i64>,
+}
+
+/// GET /api/stock-transfers - 调拨单 (发出 + 收到，最新在前，不含明细)
+pub async fn list(
+    State(state): State<ServerState>,
+    Query(query): Query<TransfersQuery>,
+) -> AppResult<Json<Vec<StockTransfer>>> {
+    let limit = query.limit.unwrap_or(DEFAULT_TRANSFER_LIMIT).clamp(1, 1000);
+    let transfers = stock_transfer::find_all(&state.pool, limit).await?;
+    Ok(Json(transfers))
+}
+
+/// GET /api/stock-transfers/peers - 可接收调拨的同租户门店 (cloud 未连接时为空)
+pub async fn peers(State(state): State<ServerState>) -> AppResult<Json<Vec<TransferPeer>>> {
+    Ok(Json(state.transfers.peers()))
+}
+
+/// GET /api/stock-transfers/:id - 调拨单及明细
+pub async fn get_by_id(
+    State(state): State<ServerState>,
+    Path(id): Path<i64>,
+) -> AppResult<Json<StockTransfer>> {
+    let transfer = stock_transfer::find_by_id(&state.pool, id)
+        .await?
+        .ok_or_else(|| AppError::not_found(format!("Stock transfer {}", id)))?;
+    Ok(Json(transfer))
+}
+
+/// POST /api/stock-transfers - 发货 (过账出库，经 cloud 发往收货门店)
+pub async fn create(
+    State(state): State<ServerState>,
+    Extension(current_user): Extension<CurrentUser>,
+    Json(payload): Json<StockTransferCreate>,
+) -> AppResult<Json<StockTransfer>> {
+    validate_optional_text(&payload.note, "note", MAX_NOTE_LEN)?;
+    let peer = state
+        .transfers
+        .find_peer(payload.to_store_id)
+        .ok_or_else(|| {
+            AppError::validation(format!(
+                "Store {} is not available for transfers",
+                payload.to_store_id
+            ))
+        })?;
+
+    let (transfer, changed) = stock_transfer::create(
+        &state.pool,
+        &payload,
+        &peer,
+        current_user.id,
+        &current_user.name,
+    )
+    .await?;
+    state.transfers.wake();
+
+    audit_log!(
+        state.audit_service,
+        AuditAction::StockTransferSent,
+        "stock_transfer",
+        &transfer.id.to_string(),
+        operator_id = Some(current_user.id),
+        operator_name = Some(current_user.name.clone()),
+        details = serde_json::json!({
+            "to_store": peer.name,
+            "lines": transfer
+                .lines
+                .iter()
+                .map(|l| serde_json::json!({
+                    "product_name": l.product_name,
+                    "spec_name": l.spec_name,
+                    "quantity": l.quantity,
+                }))
+                .collect::<Vec<_>>(),
+            "note": transfer.note,
+        })
+    );
+
+    broadcast_levels(&state, changed).await;
+    Ok(Json(transfer))
+}
+
+/// POST /api/stock-transfers/:id/receive - 收货确认 (过账入库，通知发货门店)
+pub async fn receive(
+    State(state): State<ServerState>,
+    Extension(current_user): Extension<CurrentUser>,
+    Path(id): Path<i64>,
+    Json(payload): Json<StockTransferReceive>,
+) -> AppResult<Json<StockTransfer>> {
+    let (transfer, changed) = stock_transfer::receive(
+        &state.pool,
+        id,
+        &payload,
+        current_user.id,
+        &current_user.name,
+    )
+    .await?;
+    state.transfers.wake();
+
+    audit_log!(
+        state.audit_service,
+        AuditAction::StockTransferReceived,
+        "stock_transfer",
+        &id.to_string(),
+        operator_id = Some(current_user.id),
+        operator_name = Some(current_user.name.clone()),
+        details = serde_json::json!({
+            "from_store": transfer.peer_store_name,
+            "lines": transfer
+                .lines
+                .iter()
+                .map(|l| serde_json::json!({
+                    "product_name": l.product_name,
+                    "spec_name": l.spec_name,
+                    "quantity": l.quantity,
+                    "received_quantity": l.received_quantity,
+                }))
+                .collect::<Vec<_>>(),
+        })
+    );
+
+    broadcast_levels(&state, changed).await;
+    Ok(Json(transfer))
+}
+
+async fn broadcast_levels(state: &ServerState, changed: Vec<(StockLevel, i64)>) {
+    for (level, before) in changed {
+        state
+            .broadcast_sync(
+                RESOURCE,
+                SyncChangeType::Updated,
+                level.id,
+                Some(&level),
+                false,
+            )
+            .await;
+        if level.became_low(before) {
+            notify_low_stock(state.message_bus(), &level).await;
+        }
+    }
+}
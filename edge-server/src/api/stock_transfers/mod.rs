@@ -0,0 +1,34 @@
+//! Stock Transfer API Module
+//!
+//! 门店间调拨 — 发货即过账出库 (在途)，收货门店确认实收后入库；经 crab-cloud 中继
+
+mod handler;
+
+use axum::{
+    Router, middleware,
+    routing::{get, post},
+};
+
+use crate::auth::require_permission;
+use crate::core::ServerState;
+
+/// Stock transfer router
+pub fn router() -> Router<ServerState> {
+    Router::new().nest("/api/stock-transfers", routes())
+}
+
+fn routes() -> Router<ServerState> {
+    // 读取路由：无需权限检查
+    let read_routes = Router::new()
+        .route("/", get(handler::list))
+        .route("/peers", get(handler::peers))
+        .route("/{id}", get(handler::get_by_id));
+
+    // 调拨路由：需要 menu:manage 权限
+    let manage_routes = Router::new()
+        .route("/", post(handler::create))
+        .route("/{id}/receive", post(handler::receive))
+        .layer(middleware::from_fn(require_permission("menu:manage")));
+
+    read_routes.merge(manage_routes)
+}
@@ -9,12 +9,13 @@ use crate::audit::{AuditAction, create_diff};
 use crate::audit_log;
 use crate::auth::CurrentUser;
 use crate::core::ServerState;
-use crate::db::repository::store_info;
+use crate::db::repository::{print_destination, store_info};
 use crate::utils::validation::{
     MAX_ADDRESS_LEN, MAX_EMAIL_LEN, MAX_NAME_LEN, MAX_SHORT_TEXT_LEN, MAX_URL_LEN,
     validate_optional_text,
 };
 use crate::utils::{AppError, AppResult};
+use shared::error::ErrorCode;
 use shared::message::SyncChangeType;
 use shared::models::{StoreInfo, StoreInfoUpdate};
 
@@ -38,6 +39,11 @@ fn validate_update(payload: &StoreInfoUpdate) -> AppResult<()> {
     validate_optional_text(&payload.timezone, "timezone", MAX_SHORT_TEXT_LEN)?;
     validate_optional_text(&payload.receipt_header, "receipt_header", MAX_ADDRESS_LEN)?;
     validate_optional_text(&payload.receipt_footer, "receipt_footer", MAX_ADDRESS_LEN)?;
+    validate_optional_text(
+        &payload.payment_surcharge_disclaimer,
+        "payment_surcharge_disclaimer",
+        MAX_ADDRESS_LEN,
+    )?;
     if let Some(cutoff) = payload.business_day_cutoff
         && !(0..=480).contains(&cutoff)
     {
@@ -45,6 +51,13 @@ fn validate_update(payload: &StoreInfoUpdate) -> AppResult<()> {
             "business_day_cutoff must be between 0 and 480 (00:00-08:00)",
         ));
     }
+    if let Some(limit) = payload.max_cash_change
+        && !(limit.is_finite() && limit >= 0.0)
+    {
+        return Err(AppError::validation(
+            "max_cash_change must be a non-negative amount",
+        ));
+    }
     Ok(())
 }
 
@@ -61,6 +74,17 @@ pub async fn update(
     Json(payload): Json<StoreInfoUpdate>,
 ) -> AppResult<Json<StoreInfo>> {
     validate_update(&payload)?;
+    if let Some(dest_id) = payload.daily_report_print_destination_id
+        && dest_id != 0
+        && print_destination::find_by_id(&state.pool, dest_id)
+            .await?
+            .is_none()
+    {
+        return Err(AppError::with_message(
+            ErrorCode::PrintDestinationNotFound,
+            format!("Print destination {} not found", dest_id),
+        ));
+    }
 
     let old_store_info = store_info::get_or_create(&state.pool).await?;
     let store_info = store_info::update(&state.pool, payload).await?;
@@ -92,6 +116,10 @@ pub async fn update(
     state
         .orders_manager
         .update_business_day_cutoff(store_info.business_day_cutoff);
+    state.orders_manager.update_tax_mode(store_info.tax_mode);
+    state
+        .orders_manager
+        .update_max_cash_change(store_info.max_cash_change);
 
     Ok(Json(store_info))
 }
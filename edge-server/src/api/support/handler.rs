@@ -0,0 +1,141 @@
+//! Remote Support API Handlers
+
+use axum::{
+    Json,
+    extract::{Extension, State},
+    http::header,
+    response::IntoResponse,
+};
+
+use crate::audit::AuditAction;
+use crate::audit_log;
+use crate::auth::CurrentUser;
+use crate::cloud::support::collect_diagnostics;
+use crate::core::ServerState;
+use crate::utils::validation::{MAX_NAME_LEN, validate_required_text};
+use crate::utils::{AppError, AppResult};
+use shared::cloud::support::{
+    MAX_SUPPORT_MESSAGE_LEN, SupportMessage, SupportMessageCreate, SupportSession,
+    SupportSessionOpen, SupportSessionView,
+};
+
+/// GET /api/support - 当前会话及聊天记录 (无会话时为 null)
+pub async fn current(
+    State(state): State<ServerState>,
+) -> AppResult<Json<Option<SupportSessionView>>> {
+    Ok(Json(state.support.current()))
+}
+
+/// POST /api/support - 开启支持会话 (附带诊断快照，开始推送脱敏日志)
+pub async fn open(
+    State(state): State<ServerState>,
+    Extension(current_user): Extension<CurrentUser>,
+    Json(payload): Json<SupportSessionOpen>,
+) -> AppResult<Json<SupportSession>> {
+    validate_required_text(&payload.subject, "subject", MAX_NAME_LEN)?;
+    if state.config.cloud_url.is_none() {
+        return Err(AppError::business_rule(
+            "Remote support requires a cloud connection",
+        ));
+    }
+
+    let diagnostics = collect_diagnostics(&state);
+    let session = state
+        .support
+        .open(&payload, &current_user.name, diagnostics)?;
+
+    audit_log!(
+        state.audit_service,
+        AuditAction::SupportSessionOpened,
+        "support_session",
+        &session.session_id.to_string(),
+        operator_id = Some(current_user.id),
+        operator_name = Some(current_user.name.clone()),
+        details = serde_json::json!({
+            "subject": session.subject,
+            "consent_by": session.consent_by,
+            "expires_at": session.expires_at,
+        })
+    );
+
+    Ok(Json(session))
+}
+
+/// POST /api/support/messages - 向支持人员发送消息
+pub async fn send_message(
+    State(state): State<ServerState>,
+    Extension(current_user): Extension<CurrentUser>,
+    Json(payload): Json<SupportMessageCreate>,
+) -> AppResult<Json<SupportMessage>> {
+    validate_required_text(&payload.text, "text", MAX_SUPPORT_MESSAGE_LEN)?;
+    let message = state
+        .support
+        .post_store_message(&current_user.name, &payload.text)?;
+    Ok(Json(message))
+}
+
+/// DELETE /api/support - 结束支持会话 (停止推送日志)
+pub async fn close(
+    State(state): State<ServerState>,
+    Extension(current_user): Extension<CurrentUser>,
+) -> AppResult<Json<bool>> {
+    let session = state
+        .support
+        .close()
+        .ok_or_else(|| AppError::not_found("Support session"))?;
+
+    audit_log!(
+        state.audit_service,
+        AuditAction::SupportSessionClosed,
+        "support_session",
+        &session.session_id.to_string(),
+        operator_id = Some(current_user.id),
+        operator_name = Some(current_user.name.clone()),
+        details = serde_json::json!({ "subject": session.subject })
+    );
+
+    Ok(Json(true))
+}
+
+/// GET /api/support/database - 导出脱敏的 SQLite 副本 (会员 / 员工 / 税号替换为假名)
+pub async fn export_database(
+    State(state): State<ServerState>,
+    Extension(current_user): Extension<CurrentUser>,
+) -> AppResult<impl IntoResponse> {
+    let path = std::env::temp_dir().join(format!(
+        "crab-anonymized-{}.db",
+        shared::util::snowflake_id()
+    ));
+    let report = crate::db::anonymize::export_anonymized(&state.pool, &path).await?;
+    let bytes = tokio::fs::read(&path).await;
+    let _ = tokio::fs::remove_file(&path).await;
+    let bytes = bytes.map_err(|e| AppError::internal(e.to_string()))?;
+
+    audit_log!(
+        state.audit_service,
+        AuditAction::AnonymizedDatabaseExported,
+        "database",
+        "main",
+        operator_id = Some(current_user.id),
+        operator_name = Some(current_user.name.clone()),
+        details = serde_json::json!({
+            "bytes": bytes.len(),
+            "columns": report.columns,
+            "values": report.values,
+        })
+    );
+
+    let date = chrono::Utc::now()
+        .with_timezone(&state.config.timezone)
+        .format("%Y%m%d");
+    Ok((
+        [
+            (header::CONTENT_TYPE, "application/vnd.sqlite3".to_string()),
+            (
+                header::CONTENT_DISPOSITION,
+                format!("attachment; filename=\"crab-anonymized-{date}.db\""),
+            ),
+        ],
+        bytes,
+    ))
+}
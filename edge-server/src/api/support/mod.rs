@@ -0,0 +1,34 @@
+//! Remote Support API Module
+//!
+//! 远程支持会话 — 开启 (需同意共享诊断与日志)、聊天、结束
+//! 以及脱敏数据库导出 (交给开发人员复现问题)
+
+mod handler;
+
+use axum::{
+    Router, middleware,
+    routing::{get, post},
+};
+
+use crate::auth::require_permission;
+use crate::core::ServerState;
+
+/// Support router
+pub fn router() -> Router<ServerState> {
+    Router::new().nest("/api/support", routes())
+}
+
+fn routes() -> Router<ServerState> {
+    // 会话与聊天：所有登录员工可查看、回复支持人员的指导
+    let read_routes = Router::new()
+        .route("/", get(handler::current))
+        .route("/messages", post(handler::send_message));
+
+    // 开启 / 结束会话 (共享日志)、脱敏数据库导出：需要 settings:manage 权限
+    let manage_routes = Router::new()
+        .route("/", post(handler::open).delete(handler::close))
+        .route("/database", get(handler::export_database))
+        .layer(middleware::from_fn(require_permission("settings:manage")));
+
+    read_routes.merge(manage_routes)
+}
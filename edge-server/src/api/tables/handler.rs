@@ -9,15 +9,18 @@ use crate::audit::{AuditAction, create_diff, create_snapshot};
 use crate::audit_log;
 use crate::auth::CurrentUser;
 use crate::core::ServerState;
-use crate::db::repository::dining_table;
+use crate::db::repository::{dining_table, table_group};
 use crate::utils::validation::{MAX_NAME_LEN, validate_required_text};
 use crate::utils::{AppError, AppResult};
 use shared::error::ErrorCode;
 use shared::message::SyncChangeType;
-use shared::models::{DiningTable, DiningTableCreate, DiningTableUpdate};
+use shared::models::{
+    DiningTable, DiningTableCreate, DiningTableUpdate, TableGroup, TableJoin, TableSplit,
+};
 
 use shared::cloud::SyncResource;
 const RESOURCE: SyncResource = SyncResource::DiningTable;
+const GROUP_RESOURCE: SyncResource = SyncResource::TableGroup;
 
 fn validate_create(payload: &DiningTableCreate) -> AppResult<()> {
     validate_required_text(&payload.name, "name", MAX_NAME_LEN)?;
@@ -140,6 +143,13 @@ pub async fn delete(
         ));
     }
 
+    if let Some(group) = table_group::find_by_table(&state.pool, id).await? {
+        return Err(AppError::with_message(
+            ErrorCode::TableAlreadyJoined,
+            format!("Cannot delete table: joined to {}", group.name),
+        ));
+    }
+
     let name_for_audit = dining_table::find_by_id(&state.pool, id)
         .await
         .ok()
@@ -168,3 +178,129 @@ pub async fn delete(
 
     Ok(Json(result))
 }
+
+/// GET /api/tables/groups - 获取所有拼桌
+pub async fn list_groups(State(state): State<ServerState>) -> AppResult<Json<Vec<TableGroup>>> {
+    let groups = table_group::find_all(&state.pool).await?;
+    Ok(Json(groups))
+}
+
+/// POST /api/tables/:id/join - 拼桌（将其他桌台并入该桌台）
+///
+/// 订单始终绑定在主桌；被并入的桌台不能有进行中的订单（需先合单）。
+pub async fn join(
+    State(state): State<ServerState>,
+    Extension(current_user): Extension<CurrentUser>,
+    Path(id): Path<i64>,
+    Json(payload): Json<TableJoin>,
+) -> AppResult<Json<TableGroup>> {
+    for &table_id in payload.table_ids.iter().filter(|t| **t != id) {
+        if let Ok(Some(order_id)) = state
+            .orders_manager
+            .storage()
+            .find_active_order_for_table(table_id)
+        {
+            return Err(AppError::with_message(
+                ErrorCode::TableOccupied,
+                format!(
+                    "Table {} has active order {}, merge it into the host table first",
+                    table_id, order_id
+                ),
+            ));
+        }
+    }
+
+    let existed = table_group::find_by_host(&state.pool, id).await?.is_some();
+    let group = table_group::join(
+        &state.pool,
+        id,
+        &payload.table_ids,
+        current_user.id,
+        &current_user.name,
+    )
+    .await?;
+
+    audit_log!(
+        state.audit_service,
+        AuditAction::TablesJoined,
+        "dining_table",
+        &id.to_string(),
+        operator_id = Some(current_user.id),
+        operator_name = Some(current_user.name.clone()),
+        details = serde_json::json!({
+            "name": group.name,
+            "table_ids": group.table_ids,
+        })
+    );
+
+    let change = if existed {
+        SyncChangeType::Updated
+    } else {
+        SyncChangeType::Created
+    };
+    state
+        .broadcast_sync(GROUP_RESOURCE, change, group.id, Some(&group), false)
+        .await;
+
+    Ok(Json(group))
+}
+
+/// POST /api/tables/:id/split - 拆桌（`table_ids` 为空时完全拆开）
+///
+/// 订单留在主桌，不受影响。
+pub async fn split(
+    State(state): State<ServerState>,
+    Extension(current_user): Extension<CurrentUser>,
+    Path(id): Path<i64>,
+    Json(payload): Json<TableSplit>,
+) -> AppResult<Json<Option<TableGroup>>> {
+    let before = table_group::find_by_host(&state.pool, id)
+        .await?
+        .ok_or_else(|| {
+            AppError::with_message(
+                ErrorCode::TableGroupNotFound,
+                format!("Table {} is not the host of a table group", id),
+            )
+        })?;
+    let group = table_group::split(&state.pool, id, &payload.table_ids).await?;
+
+    audit_log!(
+        state.audit_service,
+        AuditAction::TablesSplit,
+        "dining_table",
+        &id.to_string(),
+        operator_id = Some(current_user.id),
+        operator_name = Some(current_user.name.clone()),
+        details = serde_json::json!({
+            "from": before.name,
+            "to": group.as_ref().map(|g| g.name.clone()),
+        })
+    );
+
+    match &group {
+        Some(group) => {
+            state
+                .broadcast_sync(
+                    GROUP_RESOURCE,
+                    SyncChangeType::Updated,
+                    group.id,
+                    Some(group),
+                    false,
+                )
+                .await;
+        }
+        None => {
+            state
+                .broadcast_sync::<()>(
+                    GROUP_RESOURCE,
+                    SyncChangeType::Deleted,
+                    before.id,
+                    None,
+                    false,
+                )
+                .await;
+        }
+    }
+
+    Ok(Json(group))
+}
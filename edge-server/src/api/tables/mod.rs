@@ -15,6 +15,7 @@ fn routes() -> Router<ServerState> {
     // 读取路由：无需权限检查
     let read_routes = Router::new()
         .route("/", get(handler::list))
+        .route("/groups", get(handler::list_groups))
         .route("/{id}", get(handler::get_by_id));
 
     // 管理路由：需要 tables:manage 权限
@@ -26,5 +27,11 @@ fn routes() -> Router<ServerState> {
         )
         .layer(middleware::from_fn(require_permission("tables:manage")));
 
-    read_routes.merge(manage_routes)
+    // 拼桌/拆桌：需要 tables:merge_bill 权限
+    let join_routes = Router::new()
+        .route("/{id}/join", axum::routing::post(handler::join))
+        .route("/{id}/split", axum::routing::post(handler::split))
+        .layer(middleware::from_fn(require_permission("tables:merge_bill")));
+
+    read_routes.merge(manage_routes).merge(join_routes)
 }
@@ -10,16 +10,18 @@ use crate::audit_log;
 use crate::auth::CurrentUser;
 use crate::core::ServerState;
 use crate::db::repository::tag;
+use crate::services::catalog_history;
 use crate::utils::validation::{
     MAX_NAME_LEN, MAX_SHORT_TEXT_LEN, validate_optional_text, validate_required_text,
 };
 use crate::utils::{AppError, AppResult};
 use shared::error::ErrorCode;
 use shared::message::SyncChangeType;
-use shared::models::{Tag, TagCreate, TagUpdate};
+use shared::models::{CatalogChange, CatalogChangeAction, Tag, TagCreate, TagUpdate};
 
 use shared::cloud::SyncResource;
 const RESOURCE: SyncResource = SyncResource::Tag;
+const HISTORY_ENTITY: &str = "tag";
 
 fn validate_create(payload: &TagCreate) -> AppResult<()> {
     validate_required_text(&payload.name, "name", MAX_NAME_LEN)?;
@@ -62,6 +64,17 @@ pub async fn create(
 
     let t = tag::create(&state.pool, None, payload).await?;
 
+    catalog_history::record(
+        &state.pool,
+        HISTORY_ENTITY,
+        t.id,
+        CatalogChangeAction::Create,
+        None,
+        Some(&t),
+        Some(&current_user),
+    )
+    .await;
+
     let id = t.id.to_string();
 
     audit_log!(
@@ -97,6 +110,17 @@ pub async fn update(
 
     let t = tag::update(&state.pool, id, payload).await?;
 
+    catalog_history::record(
+        &state.pool,
+        HISTORY_ENTITY,
+        id,
+        CatalogChangeAction::Update,
+        Some(&old_tag),
+        Some(&t),
+        Some(&current_user),
+    )
+    .await;
+
     let id_str = id.to_string();
     audit_log!(
         state.audit_service,
@@ -138,15 +162,22 @@ pub async fn delete(
         ));
     }
 
-    let name_for_audit = tag::find_by_id(&state.pool, id)
-        .await
-        .ok()
-        .flatten()
-        .map(|t| t.name.clone())
-        .unwrap_or_default();
+    let old_tag = tag::find_by_id(&state.pool, id).await.ok().flatten();
+    let name_for_audit = old_tag.as_ref().map(|t| t.name.clone()).unwrap_or_default();
     let result = tag::delete(&state.pool, id).await?;
 
     if result {
+        catalog_history::record(
+            &state.pool,
+            HISTORY_ENTITY,
+            id,
+            CatalogChangeAction::Delete,
+            old_tag.as_ref(),
+            None,
+            Some(&current_user),
+        )
+        .await;
+
         let id_str = id.to_string();
         audit_log!(
             state.audit_service,
@@ -165,3 +196,12 @@ pub async fn delete(
 
     Ok(Json(result))
 }
+
+/// GET /api/tags/:id/history - 标签变更历史
+pub async fn list_history(
+    State(state): State<ServerState>,
+    Path(id): Path<i64>,
+) -> AppResult<Json<Vec<CatalogChange>>> {
+    let changes = catalog_history::list(&state.pool, HISTORY_ENTITY, id).await?;
+    Ok(Json(changes))
+}
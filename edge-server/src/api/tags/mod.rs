@@ -15,7 +15,8 @@ fn routes() -> Router<ServerState> {
     // 读取路由：无需权限检查
     let read_routes = Router::new()
         .route("/", get(handler::list))
-        .route("/{id}", get(handler::get_by_id));
+        .route("/{id}", get(handler::get_by_id))
+        .route("/{id}/history", get(handler::list_history));
 
     // 管理路由：需要 menu:manage 权限
     let manage_routes = Router::new()
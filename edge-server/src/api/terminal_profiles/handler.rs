@@ -0,0 +1,70 @@
+//! Terminal Profile API Handlers
+
+use axum::{
+    Json,
+    extract::{Extension, Path, State},
+};
+
+use crate::audit::{AuditAction, create_snapshot};
+use crate::audit_log;
+use crate::auth::CurrentUser;
+use crate::core::ServerState;
+use crate::db::repository::terminal_profile;
+use crate::utils::{AppError, AppResult};
+use shared::models::{TerminalProfile, TerminalProfileUpdate};
+
+/// GET /api/terminal-profiles - 全部终端配置
+pub async fn list(State(state): State<ServerState>) -> AppResult<Json<Vec<TerminalProfile>>> {
+    let profiles = terminal_profile::find_all(&state.pool).await?;
+    Ok(Json(profiles))
+}
+
+/// GET /api/terminal-profiles/:identity - 单个终端配置
+pub async fn get_by_identity(
+    State(state): State<ServerState>,
+    Path(identity): Path<String>,
+) -> AppResult<Json<TerminalProfile>> {
+    let profile = terminal_profile::find(&state.pool, &identity)
+        .await?
+        .ok_or_else(|| AppError::not_found(format!("Terminal profile {identity}")))?;
+    Ok(Json(profile))
+}
+
+/// PUT /api/terminal-profiles/:identity - 保存终端配置 (整体覆盖)
+pub async fn save(
+    State(state): State<ServerState>,
+    Path(identity): Path<String>,
+    Json(payload): Json<TerminalProfileUpdate>,
+) -> AppResult<Json<TerminalProfile>> {
+    let profile = terminal_profile::upsert(&state.pool, &identity, &payload.settings).await?;
+    tracing::debug!(
+        identity = %profile.identity,
+        version = profile.version,
+        "Terminal profile saved"
+    );
+    Ok(Json(profile))
+}
+
+/// DELETE /api/terminal-profiles/:identity - 删除终端配置
+pub async fn delete(
+    State(state): State<ServerState>,
+    Extension(current_user): Extension<CurrentUser>,
+    Path(identity): Path<String>,
+) -> AppResult<Json<bool>> {
+    let profile = terminal_profile::find(&state.pool, &identity)
+        .await?
+        .ok_or_else(|| AppError::not_found(format!("Terminal profile {identity}")))?;
+    terminal_profile::delete(&state.pool, &identity).await?;
+
+    audit_log!(
+        state.audit_service,
+        AuditAction::StoreInfoChanged,
+        "terminal_profile",
+        &identity,
+        operator_id = Some(current_user.id),
+        operator_name = Some(current_user.name.clone()),
+        details = create_snapshot(&profile, "terminal_profile")
+    );
+
+    Ok(Json(true))
+}
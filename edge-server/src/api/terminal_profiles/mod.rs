@@ -0,0 +1,32 @@
+//! Terminal Profile API Module (终端漫游配置)
+
+mod handler;
+
+use axum::{
+    Router, middleware,
+    routing::{delete, get},
+};
+
+use crate::auth::require_permission;
+use crate::core::ServerState;
+
+/// Terminal profile router
+///
+/// 终端在偏好变化时保存自己的配置 (键为握手返回的证书身份)，连接时随握手下发；
+/// 删除 (例如终端退役) 需要 settings:manage。
+pub fn router() -> Router<ServerState> {
+    Router::new().nest("/api/terminal-profiles", routes())
+}
+
+fn routes() -> Router<ServerState> {
+    let read_write_routes = Router::new().route("/", get(handler::list)).route(
+        "/{identity}",
+        get(handler::get_by_identity).put(handler::save),
+    );
+
+    let manage_routes = Router::new()
+        .route("/{identity}", delete(handler::delete))
+        .layer(middleware::from_fn(require_permission("settings:manage")));
+
+    read_write_routes.merge(manage_routes)
+}
@@ -16,10 +16,13 @@ use crate::utils::validation::{
 use crate::utils::{AppError, AppResult};
 use shared::error::ErrorCode;
 use shared::message::SyncChangeType;
-use shared::models::{DiningTable, Zone, ZoneCreate, ZoneUpdate};
+use shared::models::{
+    DiningTable, Zone, ZoneCreate, ZoneProductOverride, ZoneProductOverrideUpsert, ZoneUpdate,
+};
 
 use shared::cloud::SyncResource;
 const RESOURCE: SyncResource = SyncResource::Zone;
+const OVERRIDE_RESOURCE: SyncResource = SyncResource::ZoneProductOverride;
 
 fn validate_create(payload: &ZoneCreate) -> AppResult<()> {
     validate_required_text(&payload.name, "name", MAX_NAME_LEN)?;
@@ -130,6 +133,8 @@ pub async fn delete(
     let result = zone::delete(&state.pool, id).await?;
 
     if result {
+        let removed_overrides = state.catalog_service.clear_zone_overrides(id);
+
         let id_str = id.to_string();
         audit_log!(
             state.audit_service,
@@ -144,6 +149,17 @@ pub async fn delete(
         state
             .broadcast_sync::<()>(RESOURCE, SyncChangeType::Deleted, id, None, false)
             .await;
+        for o in &removed_overrides {
+            state
+                .broadcast_sync(
+                    OVERRIDE_RESOURCE,
+                    SyncChangeType::Deleted,
+                    o.id,
+                    Some(o),
+                    false,
+                )
+                .await;
+        }
     }
 
     Ok(Json(result))
@@ -157,3 +173,100 @@ pub async fn list_tables(
     let tables = dining_table::find_by_zone(&state.pool, zone_id).await?;
     Ok(Json(tables))
 }
+
+/// GET /api/zones/:id/overrides - 获取区域商品覆盖（价格 / 可售）
+pub async fn list_overrides(
+    State(state): State<ServerState>,
+    Path(zone_id): Path<i64>,
+) -> AppResult<Json<Vec<ZoneProductOverride>>> {
+    Ok(Json(state.catalog_service.get_zone_overrides(zone_id)))
+}
+
+/// PUT /api/zones/:id/overrides/:product_id - 设置区域商品覆盖
+///
+/// 仅影响之后开台（或移桌到该区域）的订单
+pub async fn upsert_override(
+    State(state): State<ServerState>,
+    Extension(current_user): Extension<CurrentUser>,
+    Path((zone_id, product_id)): Path<(i64, i64)>,
+    Json(payload): Json<ZoneProductOverrideUpsert>,
+) -> AppResult<Json<ZoneProductOverride>> {
+    if let Some(price) = payload.price
+        && (!price.is_finite() || price < 0.0)
+    {
+        return Err(AppError::validation("price must be a non-negative number"));
+    }
+    zone::find_by_id(&state.pool, zone_id)
+        .await?
+        .ok_or_else(|| {
+            AppError::with_message(
+                ErrorCode::ZoneNotFound,
+                format!("Zone {} not found", zone_id),
+            )
+        })?;
+
+    let o = state
+        .catalog_service
+        .upsert_zone_override(zone_id, product_id, payload)
+        .await?;
+
+    let id_str = zone_id.to_string();
+    audit_log!(
+        state.audit_service,
+        AuditAction::ZoneUpdated,
+        "zone",
+        &id_str,
+        operator_id = Some(current_user.id),
+        operator_name = Some(current_user.name.clone()),
+        details = create_snapshot(&o, "zone_product_override")
+    );
+
+    state
+        .broadcast_sync(
+            OVERRIDE_RESOURCE,
+            SyncChangeType::Updated,
+            o.id,
+            Some(&o),
+            false,
+        )
+        .await;
+
+    Ok(Json(o))
+}
+
+/// DELETE /api/zones/:id/overrides/:product_id - 删除区域商品覆盖
+pub async fn delete_override(
+    State(state): State<ServerState>,
+    Extension(current_user): Extension<CurrentUser>,
+    Path((zone_id, product_id)): Path<(i64, i64)>,
+) -> AppResult<Json<bool>> {
+    let removed = state
+        .catalog_service
+        .delete_zone_override(zone_id, product_id)
+        .await?;
+
+    if let Some(o) = &removed {
+        let id_str = zone_id.to_string();
+        audit_log!(
+            state.audit_service,
+            AuditAction::ZoneUpdated,
+            "zone",
+            &id_str,
+            operator_id = Some(current_user.id),
+            operator_name = Some(current_user.name.clone()),
+            details = serde_json::json!({"product_id": product_id, "override_removed": true})
+        );
+
+        state
+            .broadcast_sync(
+                OVERRIDE_RESOURCE,
+                SyncChangeType::Deleted,
+                o.id,
+                Some(o),
+                false,
+            )
+            .await;
+    }
+
+    Ok(Json(removed.is_some()))
+}
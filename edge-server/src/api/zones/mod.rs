@@ -16,7 +16,8 @@ fn routes() -> Router<ServerState> {
     let read_routes = Router::new()
         .route("/", get(handler::list))
         .route("/{id}", get(handler::get_by_id))
-        .route("/{id}/tables", get(handler::list_tables));
+        .route("/{id}/tables", get(handler::list_tables))
+        .route("/{id}/overrides", get(handler::list_overrides));
 
     // 管理路由：需要 tables:manage 权限
     let manage_routes = Router::new()
@@ -25,6 +26,10 @@ fn routes() -> Router<ServerState> {
             "/{id}",
             axum::routing::put(handler::update).delete(handler::delete),
         )
+        .route(
+            "/{id}/overrides/{product_id}",
+            axum::routing::put(handler::upsert_override).delete(handler::delete_override),
+        )
         .layer(middleware::from_fn(require_permission("tables:manage")));
 
     read_routes.merge(manage_routes)
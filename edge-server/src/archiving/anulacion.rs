@@ -80,6 +80,9 @@ impl AnulacionService {
             )
         })?;
 
+        // 导入的历史订单无财务凭证
+        super::import::ensure_not_imported(&self.pool, request.original_order_pk).await?;
+
         if order.status != "COMPLETED" {
             return Err(ArchiveError::BusinessRule(
                 ErrorCode::OrderNotCompleted,
@@ -189,6 +192,9 @@ impl AnulacionService {
             )
         })?;
 
+        // 导入的历史订单无财务凭证
+        super::import::ensure_not_imported(&self.pool, order_pk).await?;
+
         if order.status != "COMPLETED" {
             return Err(ArchiveError::BusinessRule(
                 ErrorCode::OrderNotCompleted,
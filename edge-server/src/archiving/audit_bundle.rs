@@ -0,0 +1,255 @@
+//! 订单审计包 (Order Audit Bundle)
+//!
+//! 为争议交易向第三方证明数据未被篡改：
+//!
+//! - 归档事件 (含哈希链 prev_hash / curr_hash) 与链路验证结果
+//! - 每个事件之后的订单快照 (按归档事件重放)
+//! - 操作员身份 (员工 + 角色)
+//! - 收据打印原文存档 (base64，读取时校验 SHA-256)
+//!
+//! 内容由边缘服务器证书签名 ([`crab_cert::SignedBundle`])，
+//! 第三方使用 crab-cert 的 `verify_bundle` 工具离线验证。
+
+use std::collections::BTreeMap;
+
+use base64::Engine;
+use serde::Serialize;
+use serde_json::Value;
+use sqlx::SqlitePool;
+
+use super::{OrderArchiveService, OrderVerification, ReceiptArchive};
+use crate::db::repository::{employee, receipt_artifact, role};
+use crate::utils::{AppError, AppResult};
+use crab_order_core::{EventAction, EventApplier};
+use shared::models::ReceiptArtifact;
+use shared::order::{EventPayload, OrderEvent, OrderEventType, OrderSnapshot};
+
+/// 审计包内容格式
+pub const AUDIT_BUNDLE_FORMAT: &str = "crab-order-audit/v1";
+
+/// 审计包内容 (签名前的 JSON)
+#[derive(Debug, Serialize)]
+pub struct OrderAuditBundle {
+    pub format: &'static str,
+    pub generated_at: i64,
+    pub order: AuditOrder,
+    /// 事件哈希链验证结果 (导出时)
+    pub verification: OrderVerification,
+    pub events: Vec<AuditEvent>,
+    pub operators: Vec<AuditOperator>,
+    pub receipts: Vec<AuditReceipt>,
+}
+
+#[derive(Debug, Serialize, sqlx::FromRow)]
+pub struct AuditOrder {
+    pub order_id: i64,
+    pub receipt_number: String,
+    pub status: String,
+    pub total_amount: f64,
+    pub start_time: i64,
+    pub end_time: Option<i64>,
+}
+
+/// 归档事件 + 该事件之后的订单快照
+#[derive(Debug, Serialize)]
+pub struct AuditEvent {
+    pub seq: i64,
+    pub event_type: String,
+    pub timestamp: i64,
+    pub operator_id: Option<i64>,
+    pub operator_name: Option<String>,
+    pub payload: Option<Value>,
+    pub prev_hash: String,
+    pub curr_hash: String,
+    /// None: 事件无法解析，之后不再重放
+    pub snapshot: Option<OrderSnapshot>,
+}
+
+#[derive(Debug, Serialize)]
+pub struct AuditOperator {
+    pub id: i64,
+    /// 事件中记录的姓名 (员工可能已改名或删除)
+    pub name: String,
+    pub username: Option<String>,
+    pub role_name: Option<String>,
+}
+
+#[derive(Debug, Serialize)]
+pub struct AuditReceipt {
+    pub artifact: ReceiptArtifact,
+    pub content_base64: Option<String>,
+    /// 原文读取失败 (文件缺失或哈希不符)
+    pub error: Option<String>,
+}
+
+#[derive(sqlx::FromRow)]
+struct EventRow {
+    id: i64,
+    seq: i64,
+    event_type: String,
+    timestamp: i64,
+    data: Option<String>,
+    prev_hash: String,
+    curr_hash: String,
+    operator_id: Option<i64>,
+    operator_name: Option<String>,
+}
+
+/// 由归档事件重建 OrderEvent (归档不保存 command_id / 全局 sequence)
+fn to_order_event(order_id: i64, row: &EventRow) -> Option<OrderEvent> {
+    let event_type: OrderEventType =
+        serde_json::from_value(Value::String(row.event_type.clone())).ok()?;
+    let payload: EventPayload = serde_json::from_str(row.data.as_deref()?).ok()?;
+    Some(OrderEvent {
+        event_id: row.id,
+        sequence: u64::try_from(row.seq).unwrap_or_default(),
+        order_id,
+        timestamp: row.timestamp,
+        client_timestamp: None,
+        operator_id: row.operator_id.unwrap_or_default(),
+        operator_name: row.operator_name.clone().unwrap_or_default(),
+        command_id: 0,
+        event_type,
+        payload,
+    })
+}
+
+/// 构建订单审计包内容
+pub async fn build(
+    pool: &SqlitePool,
+    archive_service: &OrderArchiveService,
+    receipts: &ReceiptArchive,
+    order_id: i64,
+) -> AppResult<OrderAuditBundle> {
+    let order: AuditOrder = sqlx::query_as(
+        "SELECT id AS order_id, receipt_number, status, total_amount, start_time, end_time \
+         FROM archived_order WHERE id = ?",
+    )
+    .bind(order_id)
+    .fetch_optional(pool)
+    .await
+    .map_err(|e| AppError::database(e.to_string()))?
+    .ok_or_else(|| AppError::not_found(format!("Order {order_id}")))?;
+
+    let verification = archive_service.verify_order(&order.receipt_number).await?;
+
+    let rows: Vec<EventRow> = sqlx::query_as(
+        "SELECT id, seq, event_type, timestamp, data, prev_hash, curr_hash, operator_id, operator_name \
+         FROM archived_order_event WHERE order_pk = ? ORDER BY seq",
+    )
+    .bind(order_id)
+    .fetch_all(pool)
+    .await
+    .map_err(|e| AppError::database(e.to_string()))?;
+
+    // 按顺序重放，记录每一步的快照
+    let mut snapshot = Some(OrderSnapshot::new(order_id));
+    let mut operator_names: BTreeMap<i64, String> = BTreeMap::new();
+    let mut events = Vec::with_capacity(rows.len());
+    for row in &rows {
+        if let (Some(id), Some(name)) = (row.operator_id, &row.operator_name) {
+            operator_names.entry(id).or_insert_with(|| name.clone());
+        }
+        snapshot = match (snapshot, to_order_event(order_id, row)) {
+            (Some(mut s), Some(event)) => {
+                EventAction::from(&event).apply(&mut s, &event);
+                Some(s)
+            }
+            _ => None,
+        };
+        events.push(AuditEvent {
+            seq: row.seq,
+            event_type: row.event_type.clone(),
+            timestamp: row.timestamp,
+            operator_id: row.operator_id,
+            operator_name: row.operator_name.clone(),
+            payload: row
+                .data
+                .as_deref()
+                .and_then(|d| serde_json::from_str(d).ok()),
+            prev_hash: row.prev_hash.clone(),
+            curr_hash: row.curr_hash.clone(),
+            snapshot: snapshot.clone(),
+        });
+    }
+
+    let mut operators = Vec::with_capacity(operator_names.len());
+    for (id, name) in operator_names {
+        let emp = employee::find_by_id(pool, id).await?;
+        let role_name = match &emp {
+            Some(e) => role::find_by_id(pool, e.role_id).await?.map(|r| r.name),
+            None => None,
+        };
+        operators.push(AuditOperator {
+            id,
+            name,
+            username: emp.map(|e| e.username),
+            role_name,
+        });
+    }
+
+    let artifacts = receipt_artifact::find_by_receipt(pool, &order.receipt_number).await?;
+    let mut receipt_entries = Vec::with_capacity(artifacts.len());
+    for artifact in artifacts {
+        let (content_base64, error) = match receipts.read(&artifact).await {
+            Ok(bytes) => (
+                Some(base64::engine::general_purpose::STANDARD.encode(bytes)),
+                None,
+            ),
+            Err(e) => (None, Some(e.to_string())),
+        };
+        receipt_entries.push(AuditReceipt {
+            artifact,
+            content_base64,
+            error,
+        });
+    }
+
+    Ok(OrderAuditBundle {
+        format: AUDIT_BUNDLE_FORMAT,
+        generated_at: shared::util::now_millis(),
+        order,
+        verification,
+        events,
+        operators,
+        receipts: receipt_entries,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use shared::models::CarryOverPolicy;
+
+    fn row(event_type: &str, data: Option<String>) -> EventRow {
+        EventRow {
+            id: 1,
+            seq: 0,
+            event_type: event_type.to_string(),
+            timestamp: 1234567890,
+            data,
+            prev_hash: "order_start".to_string(),
+            curr_hash: "abc".to_string(),
+            operator_id: Some(7),
+            operator_name: Some("Ana".to_string()),
+        }
+    }
+
+    #[test]
+    fn test_archived_event_reconstruction() {
+        let payload = EventPayload::OrderCarriedOver {
+            from_business_date: "2024-01-22".to_string(),
+            to_business_date: "2024-01-23".to_string(),
+            policy: CarryOverPolicy::Transfer,
+        };
+        let data = serde_json::to_string(&payload).ok();
+
+        let event = to_order_event(42, &row("ORDER_CARRIED_OVER", data.clone())).unwrap();
+        assert_eq!(event.order_id, 42);
+        assert_eq!(event.event_type, OrderEventType::OrderCarriedOver);
+        assert_eq!(event.operator_id, 7);
+
+        assert!(to_order_event(42, &row("NOT_AN_EVENT", data)).is_none());
+        assert!(to_order_event(42, &row("ORDER_CARRIED_OVER", None)).is_none());
+    }
+}
@@ -0,0 +1,183 @@
+//! 订单事件日志压缩 (redb)
+//!
+//! 终态订单正常由 ArchiveWorker 归档后立即清理；死信订单与崩溃遗留的终态订单
+//! 会一直留在 redb，事件日志无限增长。压缩任务按保留期定期处理：
+//!
+//! 1. 选出最后活动早于保留期的非活跃终态订单 ([`OrderStorage::compaction_candidates`])
+//! 2. 经 [`OrderArchiveService::archive_order`] 归档到 SQLite (已归档则幂等跳过)
+//! 3. 归档确认后从 redb 截断事件 / 快照 / 命令 ID ([`OrderStorage::truncate_order`])
+//! 4. 重建序列索引 ([`OrderStorage::rebuild_sequence_index`])
+//!
+//! 归档失败的订单保留在 redb，下个周期重试。压缩只写归档记录 (哈希链)，
+//! 不补做班次现金、会员积分等归档后处理 — 保留期远超班次周期，补做只会污染已结算数据。
+
+use serde::Serialize;
+
+use super::OrderArchiveService;
+use crate::orders::OrderStorage;
+
+const DAY_MS: i64 = 24 * 3600 * 1000;
+
+/// 单次压缩结果
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize)]
+pub struct CompactionReport {
+    /// 超出保留期的终态订单数
+    pub candidates: usize,
+    /// 本次新归档的订单
+    pub archived: usize,
+    /// 已在 SQLite 中 (幂等命中)
+    pub already_archived: usize,
+    /// 归档或截断失败 (保留在 redb)
+    pub failed: usize,
+    /// 截断的事件数
+    pub truncated_events: usize,
+    /// 重建后的序列计数器
+    pub sequence: u64,
+}
+
+/// 事件日志压缩器
+pub struct EventLogCompactor {
+    storage: OrderStorage,
+    archive_service: OrderArchiveService,
+    retention_days: u64,
+}
+
+impl EventLogCompactor {
+    pub fn new(
+        storage: OrderStorage,
+        archive_service: OrderArchiveService,
+        retention_days: u64,
+    ) -> Self {
+        Self {
+            storage,
+            archive_service,
+            retention_days,
+        }
+    }
+
+    /// 执行一次压缩 (`now` 为毫秒时间戳)
+    pub async fn compact(&self, now: i64) -> CompactionReport {
+        let mut report = CompactionReport::default();
+        let cutoff = now - self.retention_days as i64 * DAY_MS;
+        let candidates = match self.storage.compaction_candidates(cutoff) {
+            Ok(ids) => ids,
+            Err(e) => {
+                tracing::error!(error = %e, "Failed to list compaction candidates");
+                return report;
+            }
+        };
+        report.candidates = candidates.len();
+
+        for order_id in candidates {
+            let (snapshot, events) = match (
+                self.storage.get_snapshot(order_id),
+                self.storage.get_events_for_order(order_id),
+            ) {
+                (Ok(Some(snapshot)), Ok(events)) => (snapshot, events),
+                (Err(e), _) | (_, Err(e)) => {
+                    tracing::error!(order_id, error = %e, "Failed to load order for compaction");
+                    report.failed += 1;
+                    continue;
+                }
+                (Ok(None), _) => continue,
+            };
+
+            match self
+                .archive_service
+                .archive_order(&snapshot, events, None)
+                .await
+            {
+                Ok(true) => {
+                    tracing::warn!(order_id, "Order archived by event log compaction");
+                    report.archived += 1;
+                }
+                Ok(false) => report.already_archived += 1,
+                Err(e) => {
+                    tracing::error!(order_id, error = %e, "Compaction archive failed, keeping events");
+                    report.failed += 1;
+                    continue;
+                }
+            }
+
+            match self.storage.truncate_order(order_id) {
+                Ok(count) => report.truncated_events += count,
+                Err(e) => {
+                    tracing::error!(order_id, error = %e, "Failed to truncate archived order");
+                    report.failed += 1;
+                }
+            }
+        }
+
+        match self.storage.rebuild_sequence_index() {
+            Ok((sequence, _)) => report.sequence = sequence,
+            Err(e) => tracing::error!(error = %e, "Failed to rebuild sequence index"),
+        }
+        report
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::db::test_pool;
+    use crate::orders::OrdersManager;
+    use crate::orders::scenario::Scenario;
+
+    #[tokio::test]
+    async fn test_compacts_expired_terminal_orders() {
+        let dir = tempfile::tempdir().unwrap();
+        let manager =
+            OrdersManager::new(dir.path().join("orders.redb"), chrono_tz::Europe::Madrid, 1)
+                .unwrap();
+        let outcome = Scenario::new("compaction")
+            .product(1, "Caña", 2.0)
+            .open_retail("sold")
+            .add("sold", &[("Caña", 2)])
+            .pay_rest("sold")
+            .complete("sold")
+            .open_retail("open")
+            .add("open", &[("Caña", 1)])
+            .run(&manager)
+            .await
+            .expect("scenario runs");
+        let sold = outcome.order_id("sold").unwrap();
+        let open = outcome.order_id("open").unwrap();
+
+        // 未配置归档服务：终态订单不入队，等同崩溃遗留
+        let storage = manager.storage().clone();
+        assert!(storage.get_pending_archives().unwrap().is_empty());
+        let sequence = storage.get_current_sequence().unwrap();
+
+        let compactor = EventLogCompactor::new(
+            storage.clone(),
+            OrderArchiveService::new(test_pool().await, None),
+            30,
+        );
+
+        // 保留期内：不处理
+        let report = compactor.compact(shared::util::now_millis()).await;
+        assert_eq!(report.candidates, 0);
+        assert!(storage.get_snapshot(sold).unwrap().is_some());
+
+        let later = shared::util::now_millis() + 31 * DAY_MS;
+        let report = compactor.compact(later).await;
+        assert_eq!(report.candidates, 1);
+        assert_eq!(report.archived, 1);
+        assert_eq!(report.failed, 0);
+        assert!(report.truncated_events > 0);
+        assert_eq!(report.sequence, sequence);
+        assert!(storage.get_snapshot(sold).unwrap().is_none());
+        assert!(storage.get_events_for_order(sold).unwrap().is_empty());
+        assert!(storage.get_dead_letters().unwrap().is_empty());
+        assert!(storage.get_snapshot(open).unwrap().is_some());
+
+        // 再次运行幂等
+        assert_eq!(
+            compactor.compact(later).await,
+            CompactionReport {
+                sequence,
+                ..Default::default()
+            }
+        );
+    }
+}
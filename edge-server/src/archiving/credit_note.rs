@@ -3,10 +3,11 @@
 //! Creates credit notes (退款凭证) with hash chain integrity.
 //! Shares the same hash chain lock as OrderArchiveService to prevent TOCTOU races.
 
-use crate::db::repository::credit_note as cn_repo;
+use crate::db::repository::credit_note::{self as cn_repo, RefundedLine};
 use crate::orders::OrdersManager;
 use shared::models::{
-    CreateCreditNoteRequest, CreditNote, CreditNoteDetail, CreditNoteItem, RefundableInfo,
+    CreateCreditNoteRequest, CreditNote, CreditNoteDetail, CreditNoteItem, CreditNoteItemRequest,
+    RefundableInfo,
 };
 use shared::util::snowflake_id;
 use sqlx::SqlitePool;
@@ -46,6 +47,10 @@ impl CreditNoteService {
 
     /// Create a credit note with hash chain integrity.
     ///
+    /// `request.full_refund` 表示整单退款：退还所有尚未退完的商品剩余数量，
+    /// 金额恰好为订单剩余可退金额 (含订单级折扣 / 附加费)，此时 `items` 必须为空；
+    /// 否则 `items` 不能为空。
+    ///
     /// 1. Validate: order exists, not over-refund, items match
     /// 2. Compute amounts from original order items
     /// 3. Insert credit_note + credit_note_item + chain_entry in one tx
@@ -57,6 +62,8 @@ impl CreditNoteService {
         operator_name: &str,
         shift_id: Option<i64>,
     ) -> ArchiveResult<CreditNoteDetail> {
+        validate_item_selection(request.full_refund, &request.items)?;
+
         // Acquire hash chain lock (shared with OrderArchiveService)
         let _hash_lock = self.hash_chain_lock.lock().await;
 
@@ -77,9 +84,12 @@ impl CreditNoteService {
             ))
         })?;
 
+        // 导入的历史订单无财务凭证
+        super::import::ensure_not_imported(&self.pool, request.original_order_pk).await?;
+
         // 2. Fetch original order items for validation and price lookup
         let original_items: Vec<ArchivedItemRef> = sqlx::query_as::<_, ArchivedItemRef>(
-            "SELECT instance_id, name, unit_price, quantity, tax_rate \
+            "SELECT instance_id, name, unit_price, quantity, line_total, tax, tax_rate, is_comped \
              FROM archived_order_item WHERE order_pk = ?",
         )
         .bind(request.original_order_pk)
@@ -92,98 +102,62 @@ impl CreditNoteService {
             .await
             .map_err(|e| ArchiveError::Database(e.to_string()))?;
 
-        // 3b. Per-item already refunded quantities
-        let refunded_items = cn_repo::get_refunded_items(&self.pool, request.original_order_pk)
+        // 3b. Per-item already refunded quantities and amounts
+        let refunded_lines = cn_repo::get_refunded_lines(&self.pool, request.original_order_pk)
             .await
             .map_err(|e| ArchiveError::Database(e.to_string()))?;
 
-        // 4. Build credit note items and compute amounts
-        //
-        // Tax calculation: Spain IVA prices are tax-inclusive.
-        // line_credit = unit_price × quantity (含税总额 = 客户实际支付金额)
-        // item_tax = line_credit × tax_rate / (10000 + tax_rate) (从含税价提取税)
-        // item_subtotal = line_credit - item_tax (税前金额)
-        // total_credit = Σ line_credit (= subtotal_credit + tax_credit)
-        use rust_decimal::prelude::*;
-        let mut cn_items: Vec<CreditNoteItem> = Vec::with_capacity(request.items.len());
-        let mut dec_subtotal = rust_decimal::Decimal::ZERO;
-        let mut dec_tax = rust_decimal::Decimal::ZERO;
-        let hundred = rust_decimal::Decimal::ONE_HUNDRED;
-
-        for req_item in &request.items {
-            let original = original_items
-                .iter()
-                .find(|i| i.instance_id == req_item.instance_id)
-                .ok_or_else(|| {
-                    ArchiveError::BusinessRule(
-                        ErrorCode::OrderItemNotFound,
-                        format!("Item not found in original order: {}", req_item.instance_id),
-                    )
-                })?;
-
-            let already_refunded_qty = refunded_items
-                .iter()
-                .find(|ri| ri.instance_id == req_item.instance_id)
-                .map(|ri| ri.refunded_quantity)
-                .unwrap_or(0);
-            let remaining_qty = original.quantity as i64 - already_refunded_qty;
+        let dec_order_total = rust_decimal::Decimal::try_from(order.total_amount)
+            .map_err(|e| ArchiveError::Validation(format!("total_amount f64→Decimal: {e}")))?;
+        let dec_already_refunded = rust_decimal::Decimal::try_from(already_refunded)
+            .map_err(|e| ArchiveError::Validation(format!("already_refunded f64→Decimal: {e}")))?;
+        let dec_remaining = dec_order_total - dec_already_refunded;
 
-            if req_item.quantity <= 0 || req_item.quantity > remaining_qty {
+        // 4. Compute refund amounts per line
+        //
+        // 归档行金额已按订单 tax_mode 计算 (line_total 恒为含税实付，tax 为行税额)，
+        // 部分退款按数量比例取：INCLUSIVE / EXCLUSIVE 订单都退客户实际支付的金额。
+        // 整单退款退订单剩余可退金额 (含订单级折扣 / 附加费)，按行分摊。
+        let line_refunds = if request.full_refund {
+            let refunds = full_refund_lines(&original_items, &refunded_lines, dec_remaining)?;
+            if refunds.is_empty() {
                 return Err(ArchiveError::BusinessRule(
-                    ErrorCode::CreditNoteItemOverRefund,
-                    format!(
-                        "Invalid quantity {} for item {} (original: {}, already refunded: {}, remaining: {})",
-                        req_item.quantity,
-                        req_item.instance_id,
-                        original.quantity,
-                        already_refunded_qty,
-                        remaining_qty
-                    ),
+                    ErrorCode::CreditNoteOverRefund,
+                    format!("Order {} has nothing left to refund", order.receipt_number),
                 ));
             }
+            refunds
+        } else {
+            partial_refund_lines(&original_items, &refunded_lines, &request.items)?
+        };
 
-            let dec_unit_price = rust_decimal::Decimal::try_from(original.unit_price)
-                .map_err(|e| ArchiveError::Validation(format!("unit_price f64→Decimal: {e}")))?;
-            let dec_qty = rust_decimal::Decimal::from(req_item.quantity);
-            let dec_line = dec_unit_price * dec_qty;
-
-            // Extract tax from tax-inclusive price: tax = gross * rate / (100 + rate)
-            // tax_rate is integer percentage (e.g., 10 = 10% IVA)
-            let tax_rate_dec = rust_decimal::Decimal::from(original.tax_rate);
-            let item_tax = if tax_rate_dec > rust_decimal::Decimal::ZERO {
-                dec_line * tax_rate_dec / (hundred + tax_rate_dec)
-            } else {
-                rust_decimal::Decimal::ZERO
-            };
-            let item_subtotal = dec_line - item_tax;
-
-            let line_credit_f64 = dec_line.to_f64().unwrap_or(0.0);
-            let item_tax_f64 = item_tax.to_f64().unwrap_or(0.0);
+        // total_credit = Σ line_credit (= subtotal_credit + tax_credit)
+        use rust_decimal::prelude::*;
+        let mut cn_items: Vec<CreditNoteItem> = Vec::with_capacity(line_refunds.len());
+        let mut dec_subtotal = rust_decimal::Decimal::ZERO;
+        let mut dec_tax = rust_decimal::Decimal::ZERO;
 
+        for refund in &line_refunds {
+            let original = refund.original;
             cn_items.push(CreditNoteItem {
                 id: 0,             // will be assigned by DB
                 credit_note_id: 0, // will be set after insert
-                original_instance_id: req_item.instance_id.clone(),
+                original_instance_id: original.instance_id.clone(),
                 item_name: original.name.clone(),
-                quantity: req_item.quantity,
+                quantity: refund.quantity,
                 unit_price: original.unit_price,
-                line_credit: line_credit_f64,
+                line_credit: refund.credit.to_f64().unwrap_or(0.0),
                 tax_rate: original.tax_rate,
-                tax_credit: item_tax_f64,
+                tax_credit: refund.tax.to_f64().unwrap_or(0.0),
             });
 
-            dec_subtotal += item_subtotal;
-            dec_tax += item_tax;
+            dec_subtotal += refund.credit - refund.tax;
+            dec_tax += refund.tax;
         }
 
         let dec_total = dec_subtotal + dec_tax;
 
         // 5. Anti-over-refund: verify total (Decimal precision)
-        let dec_order_total = rust_decimal::Decimal::try_from(order.total_amount)
-            .map_err(|e| ArchiveError::Validation(format!("total_amount f64→Decimal: {e}")))?;
-        let dec_already_refunded = rust_decimal::Decimal::try_from(already_refunded)
-            .map_err(|e| ArchiveError::Validation(format!("already_refunded f64→Decimal: {e}")))?;
-        let dec_remaining = dec_order_total - dec_already_refunded;
         if dec_total > dec_remaining {
             return Err(ArchiveError::BusinessRule(
                 ErrorCode::CreditNoteOverRefund,
@@ -451,7 +425,193 @@ struct ArchivedItemRef {
     name: String,
     unit_price: f64,
     quantity: i32,
+    /// 含税行合计 (EXCLUSIVE 订单 = 净额 + 税)
+    line_total: f64,
+    /// 行税额
+    tax: f64,
     tax_rate: i64,
+    is_comped: bool,
+}
+
+/// 一行退款：原商品行 + 退款数量 + (含税金额, 税额)
+struct LineRefund<'a> {
+    original: &'a ArchivedItemRef,
+    quantity: i64,
+    credit: rust_decimal::Decimal,
+    tax: rust_decimal::Decimal,
+}
+
+fn to_cents(value: f64, field: &str) -> ArchiveResult<rust_decimal::Decimal> {
+    use rust_decimal::{Decimal, RoundingStrategy};
+
+    Decimal::try_from(value)
+        .map(|d| d.round_dp_with_strategy(2, RoundingStrategy::MidpointAwayFromZero))
+        .map_err(|e| ArchiveError::Validation(format!("{field} f64→Decimal: {e}")))
+}
+
+fn refunded_for<'a>(refunded: &'a [RefundedLine], instance_id: &str) -> Option<&'a RefundedLine> {
+    refunded
+        .iter()
+        .find(|r| r.original_instance_id == instance_id)
+}
+
+/// 原商品行尚未退回的 (含税金额, 税额)
+fn line_remainder(
+    original: &ArchivedItemRef,
+    refunded: Option<&RefundedLine>,
+) -> ArchiveResult<(rust_decimal::Decimal, rust_decimal::Decimal)> {
+    let mut credit = to_cents(original.line_total, "line_total")?;
+    let mut tax = to_cents(original.tax, "tax")?;
+    if let Some(refunded) = refunded {
+        credit -= to_cents(refunded.line_credit, "line_credit")?;
+        tax -= to_cents(refunded.tax_credit, "tax_credit")?;
+    }
+    Ok((credit, tax))
+}
+
+/// 退 `quantity` 件的 (含税金额, 税额)：按数量比例取归档行合计与行税额，保留 2 位小数
+///
+/// 退完该行最后剩余数量时取行余额 (行合计 / 行税额 − 已退)，逐件退款的舍入差额在最后一件退回。
+fn refund_line(
+    original: &ArchivedItemRef,
+    quantity: i64,
+    refunded: Option<&RefundedLine>,
+) -> ArchiveResult<(rust_decimal::Decimal, rust_decimal::Decimal)> {
+    use rust_decimal::{Decimal, RoundingStrategy};
+
+    if original.quantity <= 0 {
+        return Ok((Decimal::ZERO, Decimal::ZERO));
+    }
+    let refunded_qty = refunded.map(|r| r.quantity).unwrap_or(0);
+    if refunded_qty + quantity >= original.quantity as i64 {
+        return line_remainder(original, refunded);
+    }
+    let line_total = Decimal::try_from(original.line_total)
+        .map_err(|e| ArchiveError::Validation(format!("line_total f64→Decimal: {e}")))?;
+    let line_tax = Decimal::try_from(original.tax)
+        .map_err(|e| ArchiveError::Validation(format!("tax f64→Decimal: {e}")))?;
+    let share = Decimal::from(quantity) / Decimal::from(original.quantity);
+    let round = |d: Decimal| d.round_dp_with_strategy(2, RoundingStrategy::MidpointAwayFromZero);
+    Ok((round(line_total * share), round(line_tax * share)))
+}
+
+/// 整单退款不带明细，部分退款必须带明细
+fn validate_item_selection(
+    full_refund: bool,
+    items: &[CreditNoteItemRequest],
+) -> ArchiveResult<()> {
+    match (full_refund, items.is_empty()) {
+        (true, false) => Err(ArchiveError::Validation(
+            "Full refund must not list items".to_string(),
+        )),
+        (false, true) => Err(ArchiveError::Validation(
+            "Credit note requires at least one item".to_string(),
+        )),
+        _ => Ok(()),
+    }
+}
+
+/// 部分退款明细：校验每行数量不超过剩余可退数量，按数量比例计算金额
+fn partial_refund_lines<'a>(
+    original_items: &'a [ArchivedItemRef],
+    refunded: &[RefundedLine],
+    items: &[CreditNoteItemRequest],
+) -> ArchiveResult<Vec<LineRefund<'a>>> {
+    items
+        .iter()
+        .map(|req_item| {
+            let original = original_items
+                .iter()
+                .find(|i| i.instance_id == req_item.instance_id)
+                .ok_or_else(|| {
+                    ArchiveError::BusinessRule(
+                        ErrorCode::OrderItemNotFound,
+                        format!("Item not found in original order: {}", req_item.instance_id),
+                    )
+                })?;
+
+            let line_refunded = refunded_for(refunded, &req_item.instance_id);
+            let already_refunded_qty = line_refunded.map(|r| r.quantity).unwrap_or(0);
+            let remaining_qty = original.quantity as i64 - already_refunded_qty;
+
+            if req_item.quantity <= 0 || req_item.quantity > remaining_qty {
+                return Err(ArchiveError::BusinessRule(
+                    ErrorCode::CreditNoteItemOverRefund,
+                    format!(
+                        "Invalid quantity {} for item {} (original: {}, already refunded: {}, remaining: {})",
+                        req_item.quantity,
+                        req_item.instance_id,
+                        original.quantity,
+                        already_refunded_qty,
+                        remaining_qty
+                    ),
+                ));
+            }
+
+            let (credit, tax) = refund_line(original, req_item.quantity, line_refunded)?;
+            Ok(LineRefund {
+                original,
+                quantity: req_item.quantity,
+                credit,
+                tax,
+            })
+        })
+        .collect()
+}
+
+/// 整单退款明细：每个非赠送商品的剩余数量，合计恰好等于订单剩余可退金额
+///
+/// 订单级折扣 / 积分抵扣 / 优惠券 / 附加费不在商品行上，行余额之和与 `remaining`
+/// (订单实付 − 已退) 不一致：按各行余额占比分摊 `remaining`，税额同比例缩放，
+/// 末行吸收金额舍入差。
+fn full_refund_lines<'a>(
+    original_items: &'a [ArchivedItemRef],
+    refunded: &[RefundedLine],
+    remaining: rust_decimal::Decimal,
+) -> ArchiveResult<Vec<LineRefund<'a>>> {
+    use rust_decimal::{Decimal, RoundingStrategy};
+
+    let round = |d: Decimal| d.round_dp_with_strategy(2, RoundingStrategy::MidpointAwayFromZero);
+    let remaining = round(remaining);
+    if remaining <= Decimal::ZERO {
+        return Ok(Vec::new());
+    }
+
+    let mut lines = Vec::new();
+    for original in original_items.iter().filter(|item| !item.is_comped) {
+        let line_refunded = refunded_for(refunded, &original.instance_id);
+        let quantity = original.quantity as i64 - line_refunded.map(|r| r.quantity).unwrap_or(0);
+        if quantity <= 0 {
+            continue;
+        }
+        let (credit, tax) = line_remainder(original, line_refunded)?;
+        lines.push(LineRefund {
+            original,
+            quantity,
+            credit,
+            tax,
+        });
+    }
+
+    let lines_total: Decimal = lines.iter().map(|l| l.credit).sum();
+    let ratio = if lines_total > Decimal::ZERO {
+        remaining / lines_total
+    } else {
+        Decimal::ZERO
+    };
+
+    let mut allocated = Decimal::ZERO;
+    let last = lines.len().saturating_sub(1);
+    for (i, line) in lines.iter_mut().enumerate() {
+        line.credit = if i == last {
+            remaining - allocated
+        } else {
+            round(line.credit * ratio)
+        };
+        line.tax = round(line.tax * ratio);
+        allocated += line.credit;
+    }
+    Ok(lines)
 }
 
 // ============================================================================
@@ -460,113 +620,102 @@ struct ArchivedItemRef {
 
 #[cfg(test)]
 mod tests {
-    use rust_decimal::prelude::*;
-
-    /// Helper: extract tax from tax-inclusive price using Spain IVA formula.
-    /// Mirrors the logic in `create_credit_note()` lines 141-148.
-    fn extract_tax(gross: rust_decimal::Decimal, tax_rate: i64) -> rust_decimal::Decimal {
-        let hundred = rust_decimal::Decimal::ONE_HUNDRED;
-        let rate = rust_decimal::Decimal::from(tax_rate);
-        if rate > rust_decimal::Decimal::ZERO {
-            gross * rate / (hundred + rate)
-        } else {
-            rust_decimal::Decimal::ZERO
+    use super::{
+        ArchivedItemRef, RefundedLine, full_refund_lines, partial_refund_lines, refund_line,
+        validate_item_selection,
+    };
+    use rust_decimal::Decimal;
+    use rust_decimal::prelude::ToPrimitive;
+    use shared::models::CreditNoteItemRequest;
+
+    fn line(quantity: i32, line_total: f64, tax: f64, tax_rate: i64) -> ArchivedItemRef {
+        ArchivedItemRef {
+            instance_id: "item".to_string(),
+            name: "item".to_string(),
+            unit_price: 0.0,
+            quantity,
+            line_total,
+            tax,
+            tax_rate,
+            is_comped: false,
         }
     }
 
     // -----------------------------------------------------------------------
-    // Task #47: Tax extraction formula tests
+    // Refund line amounts (taken from archived line_total / tax)
     // -----------------------------------------------------------------------
 
     #[test]
-    fn tax_extraction_10_percent() {
-        // 110€ gross at 10% IVA → tax = 110 * 10 / 110 = 10€
-        let gross = rust_decimal::Decimal::from(110);
-        let tax = extract_tax(gross, 10);
-        assert_eq!(tax, rust_decimal::Decimal::from(10));
-    }
-
-    #[test]
-    fn tax_extraction_21_percent() {
-        // 121€ gross at 21% IVA → tax = 121 * 21 / 121 = 21€
-        let gross = rust_decimal::Decimal::from(121);
-        let tax = extract_tax(gross, 21);
-        assert_eq!(tax, rust_decimal::Decimal::from(21));
-    }
-
-    #[test]
-    fn tax_extraction_0_percent() {
-        let gross = rust_decimal::Decimal::from(100);
-        let tax = extract_tax(gross, 0);
-        assert_eq!(tax, rust_decimal::Decimal::ZERO);
+    fn refund_line_inclusive_full_quantity() {
+        // 2 × 5.50€ 含税 10% IVA → line_total 11.00, tax 1.00
+        let (credit, tax) = refund_line(&line(2, 11.0, 1.0, 10), 2, None).unwrap();
+        assert_eq!(credit, Decimal::new(1100, 2));
+        assert_eq!(tax, Decimal::new(100, 2));
     }
 
     #[test]
-    fn tax_extraction_subtotal_plus_tax_equals_gross() {
-        // For any gross amount, subtotal + tax must equal gross (conservation)
-        let gross = rust_decimal::Decimal::new(1595, 2); // 15.95€
-        let tax = extract_tax(gross, 10);
-        let subtotal = gross - tax;
-        assert_eq!(subtotal + tax, gross);
+    fn refund_line_exclusive_refunds_price_plus_tax() {
+        // EXCLUSIVE: 2 × 10.00€ 净价 + 21% → 客户支付 24.20 (tax 4.20)
+        let item = line(2, 24.2, 4.2, 21);
+        let (credit, tax) = refund_line(&item, 2, None).unwrap();
+        assert_eq!(credit, Decimal::new(2420, 2));
+        assert_eq!(tax, Decimal::new(420, 2));
+        assert_eq!(credit - tax, Decimal::new(2000, 2));
+
+        // 退 1 件 = 一半
+        let (credit, tax) = refund_line(&item, 1, None).unwrap();
+        assert_eq!(credit, Decimal::new(1210, 2));
+        assert_eq!(tax, Decimal::new(210, 2));
     }
 
     #[test]
-    fn tax_extraction_multiple_items_precision() {
-        // 3 items × 3.30€ at 10% IVA
-        let unit_price = rust_decimal::Decimal::new(330, 2); // 3.30€
-        let qty = rust_decimal::Decimal::from(3);
-        let gross = unit_price * qty; // 9.90€
-        let tax = extract_tax(gross, 10);
-        let subtotal = gross - tax;
-
-        // tax = 9.90 * 10 / 110 = 0.9 exactly
-        assert_eq!(tax, rust_decimal::Decimal::new(9, 1));
-        assert_eq!(subtotal, rust_decimal::Decimal::new(9, 0));
-        assert_eq!(subtotal + tax, gross);
+    fn refund_line_partial_quantity_rounds_to_cents() {
+        // 3 件合计 10.00 (tax 0.91)，退 1 件 → 3.33 / 0.30
+        let (credit, tax) = refund_line(&line(3, 10.0, 0.91, 10), 1, None).unwrap();
+        assert_eq!(credit, Decimal::new(333, 2));
+        assert_eq!(tax, Decimal::new(30, 2));
     }
 
     #[test]
-    fn tax_extraction_non_round_result() {
-        // 10€ at 21% → tax = 10 * 21 / 121 = 210/121 ≈ 1.735537...
-        // Decimal division should give exact rational result (not truncated)
-        let gross = rust_decimal::Decimal::from(10);
-        let tax = extract_tax(gross, 21);
-        let subtotal = gross - tax;
-
-        // Key invariant: subtotal + tax == gross
-        assert_eq!(subtotal + tax, gross);
-
-        // tax should be approximately 1.7355...
-        let lower = rust_decimal::Decimal::new(173, 2); // 1.73
-        let upper = rust_decimal::Decimal::new(174, 2); // 1.74
-        assert!(tax > lower && tax < upper, "tax={tax} not in (1.73, 1.74)");
+    fn refund_line_last_unit_credits_line_remainder() {
+        // 3 × 3.333 = 10.00 (tax 0.91)，逐件退款：3.33 + 3.33 + 3.34 = 10.00
+        let item = line(3, 10.0, 0.91, 10);
+        let mut refunded = RefundedLine {
+            original_instance_id: "item".to_string(),
+            quantity: 0,
+            line_credit: 0.0,
+            tax_credit: 0.0,
+        };
+        let mut credited = Decimal::ZERO;
+        let mut taxed = Decimal::ZERO;
+        for _ in 0..3 {
+            let (credit, tax) = refund_line(&item, 1, Some(&refunded)).unwrap();
+            credited += credit;
+            taxed += tax;
+            refunded.quantity += 1;
+            refunded.line_credit += credit.to_f64().unwrap();
+            refunded.tax_credit += tax.to_f64().unwrap();
+        }
+        assert_eq!(credited, Decimal::new(1000, 2));
+        assert_eq!(taxed, Decimal::new(91, 2));
+
+        // 先退 2 件，再退最后 1 件 → 余额 3.34 / 0.31
+        let refunded = RefundedLine {
+            original_instance_id: "item".to_string(),
+            quantity: 2,
+            line_credit: 6.66,
+            tax_credit: 0.6,
+        };
+        let (credit, tax) = refund_line(&item, 1, Some(&refunded)).unwrap();
+        assert_eq!(credit, Decimal::new(334, 2));
+        assert_eq!(tax, Decimal::new(31, 2));
     }
 
     #[test]
-    fn tax_extraction_round_trip_f64() {
-        // Simulate the actual code path: Decimal tax → f64 → reconstruct
-        let unit_price_f64: f64 = 12.50;
-        let qty: i64 = 2;
-        let tax_rate: i64 = 10;
-
-        let dec_unit = rust_decimal::Decimal::try_from(unit_price_f64).unwrap();
-        let dec_qty = rust_decimal::Decimal::from(qty);
-        let gross = dec_unit * dec_qty; // 25.00
-
-        let tax = extract_tax(gross, tax_rate);
-        let subtotal = gross - tax;
-
-        // Convert to f64 as the code does
-        let tax_f64 = tax.to_f64().unwrap();
-        let subtotal_f64 = subtotal.to_f64().unwrap();
-        let total_f64 = gross.to_f64().unwrap();
-
-        // f64 round-trip: subtotal + tax ≈ total (within floating point)
-        let reconstructed = subtotal_f64 + tax_f64;
-        assert!(
-            (reconstructed - total_f64).abs() < 1e-10,
-            "f64 round-trip failed: {subtotal_f64} + {tax_f64} = {reconstructed} != {total_f64}"
-        );
+    fn refund_line_zero_rate_has_no_tax() {
+        let (credit, tax) = refund_line(&line(1, 8.0, 0.0, 0), 1, None).unwrap();
+        assert_eq!(credit, Decimal::new(800, 2));
+        assert_eq!(tax, Decimal::ZERO);
     }
 
     // -----------------------------------------------------------------------
@@ -654,4 +803,132 @@ mod tests {
             check_over_refund(order_total, 12.75, rust_decimal::Decimal::new(1276, 2)).is_err()
         );
     }
+
+    // -----------------------------------------------------------------------
+    // Full refund expansion
+    // -----------------------------------------------------------------------
+
+    fn archived_item(instance_id: &str, quantity: i32, is_comped: bool) -> ArchivedItemRef {
+        ArchivedItemRef {
+            instance_id: instance_id.to_string(),
+            name: instance_id.to_string(),
+            unit_price: 10.0,
+            quantity,
+            line_total: 10.0 * quantity as f64,
+            tax: 0.0,
+            tax_rate: 10,
+            is_comped,
+        }
+    }
+
+    fn refunded(instance_id: &str, quantity: i64, line_credit: f64) -> RefundedLine {
+        RefundedLine {
+            original_instance_id: instance_id.to_string(),
+            quantity,
+            line_credit,
+            tax_credit: 0.0,
+        }
+    }
+
+    #[test]
+    fn full_refund_takes_remaining_quantities() {
+        let items = vec![
+            archived_item("paella", 3, false),
+            archived_item("wine", 1, false),
+            archived_item("paella::comp::1", 1, true),
+        ];
+        let refunded = vec![refunded("paella", 1, 10.0)];
+
+        // 订单 40.00，已退 10.00
+        let lines = full_refund_lines(&items, &refunded, Decimal::from(30)).unwrap();
+        assert_eq!(lines.len(), 2);
+        assert_eq!(lines[0].original.instance_id, "paella");
+        assert_eq!(lines[0].quantity, 2);
+        assert_eq!(lines[0].credit, Decimal::new(2000, 2));
+        assert_eq!(lines[1].original.instance_id, "wine");
+        assert_eq!(lines[1].quantity, 1);
+        assert_eq!(lines[1].credit, Decimal::new(1000, 2));
+    }
+
+    #[test]
+    fn full_refund_skips_fully_refunded_items() {
+        let items = vec![archived_item("paella", 2, false)];
+        let refunded = vec![refunded("paella", 2, 20.0)];
+        assert!(
+            full_refund_lines(&items, &refunded, Decimal::ZERO)
+                .unwrap()
+                .is_empty()
+        );
+    }
+
+    #[test]
+    fn full_refund_credits_order_total_with_discount_and_surcharge() {
+        // 商品行 10.00 (tax 0.91) + 20.00 (tax 1.82) = 30.00
+        // 订单级折扣 −3.00，刷卡附加费 +1.50 → 实付 28.50
+        let items = vec![line(1, 10.0, 0.91, 10), {
+            let mut wine = line(2, 20.0, 1.82, 10);
+            wine.instance_id = "wine".to_string();
+            wine
+        }];
+        let lines = full_refund_lines(&items, &[], Decimal::new(2850, 2)).unwrap();
+
+        let credit: Decimal = lines.iter().map(|l| l.credit).sum();
+        let tax: Decimal = lines.iter().map(|l| l.tax).sum();
+        assert_eq!(credit, Decimal::new(2850, 2));
+        assert_eq!(lines[0].credit, Decimal::new(950, 2));
+        assert_eq!(lines[1].credit, Decimal::new(1900, 2));
+        // 税额按 28.50 / 30.00 同比例分摊
+        assert_eq!(lines[0].tax, Decimal::new(86, 2));
+        assert_eq!(lines[1].tax, Decimal::new(173, 2));
+        assert_eq!(tax, Decimal::new(259, 2));
+    }
+
+    #[test]
+    fn full_refund_after_partial_refund_credits_the_rest() {
+        // 实付 28.50，已部分退款 10.00 (item 行) → 整单退款退剩余 18.50，全部落在 wine 行
+        let items = vec![line(1, 10.0, 0.91, 10), {
+            let mut wine = line(2, 20.0, 1.82, 10);
+            wine.instance_id = "wine".to_string();
+            wine
+        }];
+        let refunded = vec![RefundedLine {
+            original_instance_id: "item".to_string(),
+            quantity: 1,
+            line_credit: 10.0,
+            tax_credit: 0.91,
+        }];
+        let lines = full_refund_lines(&items, &refunded, Decimal::new(1850, 2)).unwrap();
+        assert_eq!(lines.len(), 1);
+        assert_eq!(lines[0].original.instance_id, "wine");
+        assert_eq!(lines[0].credit, Decimal::new(1850, 2));
+    }
+
+    #[test]
+    fn partial_refund_rejects_quantity_over_remaining() {
+        let items = vec![archived_item("paella", 2, false)];
+        let refunded = vec![refunded("paella", 1, 10.0)];
+        let request = |quantity| {
+            vec![CreditNoteItemRequest {
+                instance_id: "paella".to_string(),
+                quantity,
+            }]
+        };
+        assert!(partial_refund_lines(&items, &refunded, &request(2)).is_err());
+        assert!(partial_refund_lines(&items, &refunded, &request(0)).is_err());
+        let lines = partial_refund_lines(&items, &refunded, &request(1)).unwrap();
+        assert_eq!(lines[0].credit, Decimal::new(1000, 2));
+    }
+
+    #[test]
+    fn full_refund_flag_is_explicit() {
+        let items = vec![CreditNoteItemRequest {
+            instance_id: "paella".to_string(),
+            quantity: 1,
+        }];
+        assert!(validate_item_selection(true, &[]).is_ok());
+        assert!(validate_item_selection(false, &items).is_ok());
+        // 部分退款空明细不会被当成整单退款
+        assert!(validate_item_selection(false, &[]).is_err());
+        assert!(validate_item_selection(true, &items).is_err());
+    }
 }
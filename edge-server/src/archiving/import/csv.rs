@@ -0,0 +1,662 @@
+//! CSV 适配器 — 每行一个订单明细（常见旧 POS 的“销售明细”导出）
+//!
+//! 表头按别名识别（中/英/西，忽略大小写和重音），同一收据号的行合并为一个订单：
+//!
+//! | 字段 | 别名示例 | 必填 |
+//! |------|----------|------|
+//! | 收据号 | receipt, ticket, numero | ✓ |
+//! | 结账时间 | date, fecha, closed_at | ✓ |
+//! | 商品 | item, product, articulo | ✓ |
+//! | 数量 | quantity, qty, cantidad | ✓ |
+//! | 单价 / 行总额 | unit_price, precio / line_total, importe | 至少一个 |
+//!
+//! 可选：开单时间、分类、折扣、税率、支付方式、桌台、区域、员工、人数、状态。
+//! 分隔符自动识别（`,` `;` Tab），金额支持小数逗号，非 UTF-8 文件按 Windows-1252 解码。
+
+use chrono::{DateTime, NaiveDate, NaiveDateTime, Timelike};
+use chrono_tz::Tz;
+use std::collections::HashMap;
+
+use super::{ImportedItem, ImportedOrder, ImportedPayment, LegacyPosAdapter};
+use crate::archiving::{ArchiveError, ArchiveResult};
+use crate::utils::time::date_hms_to_millis;
+use crab_order_core::money::{to_decimal, to_f64};
+
+pub struct CsvAdapter;
+
+impl LegacyPosAdapter for CsvAdapter {
+    fn format(&self) -> &'static str {
+        "csv"
+    }
+
+    fn parse(&self, data: &[u8], tz: Tz) -> ArchiveResult<Vec<ImportedOrder>> {
+        let text = decode_text(data);
+        let delimiter = detect_delimiter(&text);
+        let mut records = parse_records(&text, delimiter).into_iter();
+
+        let (_, header) = records
+            .next()
+            .ok_or_else(|| ArchiveError::Validation("CSV file is empty".into()))?;
+        let columns = Columns::from_header(&header)?;
+
+        let mut orders: Vec<ImportedOrder> = Vec::new();
+        let mut index: HashMap<String, usize> = HashMap::new();
+        // 每个订单按支付方式汇总的金额
+        let mut payments: Vec<Vec<ImportedPayment>> = Vec::new();
+
+        for (line, record) in records {
+            let row = Row {
+                line,
+                record: &record,
+                columns: &columns,
+            };
+            let receipt = row.required(Column::Receipt)?.to_string();
+            let end_time = parse_datetime(row.required(Column::Date)?, tz)
+                .ok_or_else(|| row.error("unrecognized date"))?;
+            let item = row.item()?;
+            let line_total = to_f64(
+                to_decimal(item.unit_price) * rust_decimal::Decimal::from(item.quantity)
+                    - to_decimal(item.discount_amount),
+            );
+
+            let idx = *index.entry(receipt.clone()).or_insert_with(|| {
+                orders.push(ImportedOrder {
+                    receipt_number: receipt,
+                    start_time: None,
+                    end_time,
+                    table_name: None,
+                    zone_name: None,
+                    guest_count: None,
+                    operator_name: None,
+                    voided: false,
+                    discount_amount: 0.0,
+                    items: Vec::new(),
+                    payments: Vec::new(),
+                });
+                payments.push(Vec::new());
+                orders.len() - 1
+            });
+
+            let order = &mut orders[idx];
+            order.end_time = order.end_time.max(end_time);
+            if let Some(opened) = row.get(Column::OpenedAt) {
+                let opened =
+                    parse_datetime(opened, tz).ok_or_else(|| row.error("unrecognized date"))?;
+                order.start_time = Some(order.start_time.map_or(opened, |t| t.min(opened)));
+            }
+            order.table_name = order.table_name.take().or_else(|| row.owned(Column::Table));
+            order.zone_name = order.zone_name.take().or_else(|| row.owned(Column::Zone));
+            order.operator_name = order
+                .operator_name
+                .take()
+                .or_else(|| row.owned(Column::Operator));
+            if order.guest_count.is_none() {
+                order.guest_count = row.get(Column::Guests).and_then(|g| g.parse().ok());
+            }
+            order.voided |= row.get(Column::Status).is_some_and(is_void_status);
+            order.items.push(item);
+
+            if let Some(method) = row.get(Column::PaymentMethod) {
+                let method = normalize_payment_method(method);
+                let order_payments = &mut payments[idx];
+                match order_payments.iter_mut().find(|p| p.method == method) {
+                    Some(p) => p.amount = to_f64(to_decimal(p.amount) + to_decimal(line_total)),
+                    None => order_payments.push(ImportedPayment {
+                        method,
+                        amount: line_total,
+                    }),
+                }
+            }
+        }
+
+        for (order, payments) in orders.iter_mut().zip(payments) {
+            order.payments = payments;
+        }
+        Ok(orders)
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+enum Column {
+    Receipt,
+    Date,
+    OpenedAt,
+    Item,
+    Category,
+    Quantity,
+    UnitPrice,
+    LineTotal,
+    Discount,
+    TaxRate,
+    PaymentMethod,
+    Table,
+    Zone,
+    Operator,
+    Guests,
+    Status,
+}
+
+const ALIASES: &[(Column, &[&str])] = &[
+    (
+        Column::Receipt,
+        &[
+            "receipt",
+            "receipt_number",
+            "receipt_no",
+            "ticket",
+            "ticket_number",
+            "ticket_no",
+            "num_ticket",
+            "n_ticket",
+            "no_ticket",
+            "numero",
+            "numero_ticket",
+            "order_number",
+            "order_id",
+            "documento",
+            "单号",
+            "小票号",
+        ],
+    ),
+    (
+        Column::Date,
+        &[
+            "date",
+            "datetime",
+            "closed_at",
+            "close_time",
+            "timestamp",
+            "fecha",
+            "fecha_hora",
+            "fecha_cierre",
+            "日期",
+            "结账时间",
+        ],
+    ),
+    (
+        Column::OpenedAt,
+        &[
+            "opened_at",
+            "open_time",
+            "fecha_apertura",
+            "apertura",
+            "开单时间",
+        ],
+    ),
+    (
+        Column::Item,
+        &[
+            "item",
+            "item_name",
+            "product",
+            "product_name",
+            "name",
+            "articulo",
+            "producto",
+            "descripcion",
+            "description",
+            "concepto",
+            "商品",
+            "菜品",
+        ],
+    ),
+    (
+        Column::Category,
+        &[
+            "category",
+            "category_name",
+            "family",
+            "department",
+            "familia",
+            "categoria",
+            "departamento",
+            "分类",
+        ],
+    ),
+    (
+        Column::Quantity,
+        &[
+            "quantity", "qty", "units", "cantidad", "unidades", "uds", "数量",
+        ],
+    ),
+    (
+        Column::UnitPrice,
+        &[
+            "unit_price",
+            "price",
+            "precio",
+            "precio_unitario",
+            "pvp",
+            "单价",
+        ],
+    ),
+    (
+        Column::LineTotal,
+        &[
+            "line_total",
+            "total",
+            "amount",
+            "importe",
+            "total_linea",
+            "金额",
+        ],
+    ),
+    (
+        Column::Discount,
+        &["discount", "discount_amount", "descuento", "dto", "折扣"],
+    ),
+    (
+        Column::TaxRate,
+        &[
+            "tax_rate", "tax", "vat", "vat_rate", "iva", "tipo_iva", "税率",
+        ],
+    ),
+    (
+        Column::PaymentMethod,
+        &[
+            "payment_method",
+            "payment",
+            "tender",
+            "forma_pago",
+            "forma_de_pago",
+            "metodo_pago",
+            "pago",
+            "支付方式",
+        ],
+    ),
+    (Column::Table, &["table", "table_name", "mesa", "桌台"]),
+    (
+        Column::Zone,
+        &["zone", "zone_name", "area", "sala", "zona", "区域"],
+    ),
+    (
+        Column::Operator,
+        &[
+            "operator",
+            "operator_name",
+            "employee",
+            "staff",
+            "waiter",
+            "cashier",
+            "camarero",
+            "empleado",
+            "usuario",
+            "cajero",
+            "员工",
+        ],
+    ),
+    (
+        Column::Guests,
+        &[
+            "guests",
+            "guest_count",
+            "covers",
+            "pax",
+            "comensales",
+            "人数",
+        ],
+    ),
+    (Column::Status, &["status", "estado", "状态"]),
+];
+
+/// 表头列位置
+struct Columns(HashMap<Column, usize>);
+
+impl Columns {
+    fn from_header(header: &[String]) -> ArchiveResult<Self> {
+        let mut map = HashMap::new();
+        for (pos, name) in header.iter().enumerate() {
+            let name = normalize_header(name);
+            if let Some((column, _)) = ALIASES.iter().find(|(_, a)| a.contains(&name.as_str())) {
+                map.entry(*column).or_insert(pos);
+            }
+        }
+
+        let missing: Vec<&str> = [
+            (Column::Receipt, "receipt"),
+            (Column::Date, "date"),
+            (Column::Item, "item"),
+            (Column::Quantity, "quantity"),
+        ]
+        .iter()
+        .filter(|(c, _)| !map.contains_key(c))
+        .map(|(_, name)| *name)
+        .collect();
+        if !missing.is_empty() {
+            return Err(ArchiveError::Validation(format!(
+                "CSV header is missing required columns: {}",
+                missing.join(", ")
+            )));
+        }
+        if !map.contains_key(&Column::UnitPrice) && !map.contains_key(&Column::LineTotal) {
+            return Err(ArchiveError::Validation(
+                "CSV header needs a unit_price or line_total column".into(),
+            ));
+        }
+        Ok(Self(map))
+    }
+}
+
+struct Row<'a> {
+    line: usize,
+    record: &'a [String],
+    columns: &'a Columns,
+}
+
+impl Row<'_> {
+    fn get(&self, column: Column) -> Option<&str> {
+        let pos = *self.columns.0.get(&column)?;
+        self.record
+            .get(pos)
+            .map(|v| v.trim())
+            .filter(|v| !v.is_empty())
+    }
+
+    fn owned(&self, column: Column) -> Option<String> {
+        self.get(column).map(String::from)
+    }
+
+    fn required(&self, column: Column) -> ArchiveResult<&str> {
+        self.get(column)
+            .ok_or_else(|| self.error(&format!("missing {:?}", column)))
+    }
+
+    fn amount(&self, column: Column) -> ArchiveResult<Option<f64>> {
+        self.get(column)
+            .map(|v| parse_number(v).ok_or_else(|| self.error(&format!("invalid {:?}", column))))
+            .transpose()
+    }
+
+    fn error(&self, message: &str) -> ArchiveError {
+        ArchiveError::Validation(format!("CSV line {}: {}", self.line, message))
+    }
+
+    fn item(&self) -> ArchiveResult<ImportedItem> {
+        let mut name = self.required(Column::Item)?.to_string();
+        let quantity = self
+            .amount(Column::Quantity)?
+            .ok_or_else(|| self.error("missing Quantity"))?;
+        if quantity <= 0.0 {
+            return Err(self.error("quantity must be positive"));
+        }
+        let line_total = self.amount(Column::LineTotal)?;
+        let unit_price = match (self.amount(Column::UnitPrice)?, line_total) {
+            (Some(price), _) => price,
+            (None, Some(total)) => total / quantity,
+            (None, None) => return Err(self.error("missing price")),
+        };
+        // 明细中的行总额低于 数量 × 单价 时视为隐含折扣
+        let discount = match (self.amount(Column::Discount)?, line_total) {
+            (Some(discount), _) => discount,
+            (None, Some(total)) => {
+                to_f64(to_decimal(unit_price) * to_decimal(quantity) - to_decimal(total)).max(0.0)
+            }
+            (None, None) => 0.0,
+        };
+
+        // 称重商品（小数数量）按一行计：数量 1，单价为行金额
+        let (quantity, unit_price) = if quantity.fract() == 0.0 {
+            (quantity as i32, unit_price)
+        } else {
+            name = format!("{} (x{})", name, quantity);
+            (1, to_f64(to_decimal(unit_price) * to_decimal(quantity)))
+        };
+
+        let tax_rate = self
+            .amount(Column::TaxRate)?
+            .map(|rate| {
+                if rate > 0.0 && rate < 1.0 {
+                    rate * 100.0
+                } else {
+                    rate
+                }
+            })
+            .map_or(0, |rate| rate.round() as i32);
+
+        Ok(ImportedItem {
+            name,
+            category_name: self.owned(Column::Category),
+            quantity,
+            unit_price,
+            discount_amount: discount,
+            tax_rate,
+        })
+    }
+}
+
+/// UTF-8（去 BOM），否则按 Windows-1252 解码
+pub(crate) fn decode_text(data: &[u8]) -> String {
+    let data = data.strip_prefix(b"\xEF\xBB\xBF").unwrap_or(data);
+    match std::str::from_utf8(data) {
+        Ok(text) => text.to_string(),
+        Err(_) => encoding_rs::WINDOWS_1252.decode(data).0.into_owned(),
+    }
+}
+
+pub(crate) fn detect_delimiter(text: &str) -> char {
+    let header = text.lines().next().unwrap_or_default();
+    [',', ';', '\t']
+        .into_iter()
+        .max_by_key(|d| header.matches(*d).count())
+        .unwrap_or(',')
+}
+
+/// 解析 CSV 记录（支持引号、转义引号和引号内换行），返回 (起始行号, 字段)
+pub(crate) fn parse_records(text: &str, delimiter: char) -> Vec<(usize, Vec<String>)> {
+    let mut records = Vec::new();
+    let mut record = Vec::new();
+    let mut field = String::new();
+    let mut in_quotes = false;
+    let mut line = 1;
+    let mut record_line = 1;
+    let mut chars = text.chars().peekable();
+
+    while let Some(c) = chars.next() {
+        match c {
+            '"' if in_quotes && chars.peek() == Some(&'"') => {
+                field.push('"');
+                chars.next();
+            }
+            '"' => in_quotes = !in_quotes,
+            '\n' if in_quotes => {
+                field.push(c);
+                line += 1;
+            }
+            '\r' if !in_quotes => {}
+            '\n' => {
+                record.push(std::mem::take(&mut field));
+                if record.iter().any(|f| !f.trim().is_empty()) {
+                    records.push((record_line, std::mem::take(&mut record)));
+                }
+                record.clear();
+                line += 1;
+                record_line = line;
+            }
+            c if c == delimiter && !in_quotes => record.push(std::mem::take(&mut field)),
+            c => field.push(c),
+        }
+    }
+    record.push(field);
+    if record.iter().any(|f| !f.trim().is_empty()) {
+        records.push((record_line, record));
+    }
+    records
+}
+
+/// 表头规范化：小写、去重音、空格 / 标点转下划线
+pub(crate) fn normalize_header(name: &str) -> String {
+    name.trim()
+        .to_lowercase()
+        .chars()
+        .map(|c| match c {
+            'á' | 'à' => 'a',
+            'é' | 'è' => 'e',
+            'í' => 'i',
+            'ó' | 'ò' => 'o',
+            'ú' | 'ü' => 'u',
+            'ñ' => 'n',
+            'º' | '°' => 'o',
+            ' ' | '-' | '.' | '/' => '_',
+            c => c,
+        })
+        .collect::<String>()
+        .trim_matches('_')
+        .replace("__", "_")
+}
+
+/// 解析金额 / 数量："1.234,50" "1,234.50" "3,5" "€ 12.00" "10%"
+fn parse_number(value: &str) -> Option<f64> {
+    let cleaned: String = value
+        .chars()
+        .filter(|c| c.is_ascii_digit() || matches!(c, '.' | ',' | '-'))
+        .collect();
+    let normalized = match (cleaned.rfind(','), cleaned.rfind('.')) {
+        // 最后出现的分隔符为小数点
+        (Some(comma), Some(dot)) if comma > dot => cleaned.replace('.', "").replace(',', "."),
+        (Some(_), Some(_)) => cleaned.replace(',', ""),
+        (Some(_), None) => cleaned.replace(',', "."),
+        _ => cleaned,
+    };
+    normalized.parse::<f64>().ok().filter(|n| n.is_finite())
+}
+
+const DATETIME_FORMATS: &[&str] = &[
+    "%Y-%m-%d %H:%M:%S",
+    "%Y-%m-%d %H:%M",
+    "%Y-%m-%dT%H:%M:%S",
+    "%Y-%m-%dT%H:%M",
+    "%d/%m/%Y %H:%M:%S",
+    "%d/%m/%Y %H:%M",
+    "%d-%m-%Y %H:%M:%S",
+    "%d-%m-%Y %H:%M",
+];
+
+const DATE_FORMATS: &[&str] = &["%Y-%m-%d", "%d/%m/%Y", "%d-%m-%Y"];
+
+/// 解析时间为 Unix millis（无时区的本地时间按门店时区）
+///
+/// 只有日期时按当天 12:00 计，避免营业日切点 (business_day_cutoff) 把订单
+/// 归到前一营业日。
+fn parse_datetime(value: &str, tz: Tz) -> Option<i64> {
+    if let Ok(dt) = DateTime::parse_from_rfc3339(value) {
+        return Some(dt.timestamp_millis());
+    }
+    if let Some(dt) = DATETIME_FORMATS
+        .iter()
+        .find_map(|f| NaiveDateTime::parse_from_str(value, f).ok())
+    {
+        return Some(date_hms_to_millis(
+            dt.date(),
+            dt.hour(),
+            dt.minute(),
+            dt.second(),
+            tz,
+        ));
+    }
+    DATE_FORMATS
+        .iter()
+        .find_map(|f| NaiveDate::parse_from_str(value, f).ok())
+        .map(|date| date_hms_to_millis(date, 12, 0, 0, tz))
+}
+
+fn is_void_status(status: &str) -> bool {
+    matches!(
+        status.to_lowercase().as_str(),
+        "void"
+            | "voided"
+            | "cancelled"
+            | "canceled"
+            | "anulado"
+            | "anulada"
+            | "cancelado"
+            | "cancelada"
+            | "作废"
+    )
+}
+
+fn normalize_payment_method(method: &str) -> String {
+    match method.to_lowercase().as_str() {
+        "cash" | "efectivo" | "metalico" | "metálico" | "现金" => "CASH".to_string(),
+        "card" | "tarjeta" | "visa" | "mastercard" | "datafono" | "datáfono" | "刷卡" => {
+            "CARD".to_string()
+        }
+        _ => method.to_uppercase(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const MADRID: Tz = chrono_tz::Europe::Madrid;
+
+    #[test]
+    fn test_groups_lines_by_receipt() {
+        let csv = "Nº Ticket;Fecha;Artículo;Familia;Cantidad;Precio;Importe;IVA;Forma de pago;Mesa\n\
+                   T-1;03/02/2023 21:15;Café;Bebidas;2;1,50;3,00;10;Efectivo;5\n\
+                   T-1;03/02/2023 21:16;\"Tostada; tomate\";;1;3,30;3,00;10%;Tarjeta;5\n\
+                   T-2;2023-02-04;Caña;Bebidas;0,5;2,00;1,00;21;efectivo;\n";
+        let orders = CsvAdapter.parse(csv.as_bytes(), MADRID).unwrap();
+        assert_eq!(orders.len(), 2);
+
+        let t1 = &orders[0];
+        assert_eq!(t1.receipt_number, "T-1");
+        assert_eq!(t1.table_name.as_deref(), Some("5"));
+        assert_eq!(t1.items.len(), 2);
+        assert_eq!(t1.items[1].name, "Tostada; tomate");
+        // Importe below quantity × price → implicit discount
+        assert_eq!(t1.items[1].discount_amount, 0.3);
+        assert_eq!(
+            t1.payments,
+            vec![
+                ImportedPayment {
+                    method: "CASH".into(),
+                    amount: 3.0
+                },
+                ImportedPayment {
+                    method: "CARD".into(),
+                    amount: 3.0
+                },
+            ]
+        );
+        let expected = date_hms_to_millis(
+            NaiveDate::from_ymd_opt(2023, 2, 3).unwrap(),
+            21,
+            16,
+            0,
+            MADRID,
+        );
+        assert_eq!(t1.end_time, expected);
+
+        // Weighed item: fractional quantity folded into one line
+        let t2 = &orders[1];
+        assert_eq!(t2.items[0].quantity, 1);
+        assert_eq!(t2.items[0].unit_price, 1.0);
+        assert_eq!(t2.items[0].tax_rate, 21);
+    }
+
+    #[test]
+    fn test_missing_columns_rejected() {
+        let err = CsvAdapter
+            .parse(b"receipt,date,item\nA,2023-01-01,Cola\n", MADRID)
+            .unwrap_err();
+        assert!(err.to_string().contains("quantity"));
+    }
+
+    #[test]
+    fn test_parse_number_formats() {
+        assert_eq!(parse_number("1.234,50"), Some(1234.5));
+        assert_eq!(parse_number("1,234.50"), Some(1234.5));
+        assert_eq!(parse_number("3,5"), Some(3.5));
+        assert_eq!(parse_number("€ 12.00"), Some(12.0));
+        assert_eq!(parse_number("n/a"), None);
+    }
+
+    #[test]
+    fn test_windows_1252_fallback() {
+        // "Caña" in Windows-1252
+        let data = b"receipt,date,item,quantity,price\nA,2023-01-01,Ca\xF1a,1,2\n";
+        let orders = CsvAdapter.parse(data, MADRID).unwrap();
+        assert_eq!(orders[0].items[0].name, "Caña");
+    }
+}
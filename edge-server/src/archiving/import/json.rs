@@ -0,0 +1,58 @@
+//! JSON 适配器 — 直接为 [`ImportedOrder`] 结构
+//!
+//! 接受订单数组或 `{ "orders": [...] }`，时间为 Unix millis。
+//! 其他系统的导出可先用脚本转换为此格式再导入。
+
+use chrono_tz::Tz;
+use serde::Deserialize;
+
+use super::{ImportedOrder, LegacyPosAdapter};
+use crate::archiving::{ArchiveError, ArchiveResult};
+
+pub struct JsonAdapter;
+
+#[derive(Deserialize)]
+struct WrappedExport {
+    orders: Vec<ImportedOrder>,
+}
+
+impl LegacyPosAdapter for JsonAdapter {
+    fn format(&self) -> &'static str {
+        "json"
+    }
+
+    fn parse(&self, data: &[u8], _tz: Tz) -> ArchiveResult<Vec<ImportedOrder>> {
+        let data = data.strip_prefix(b"\xEF\xBB\xBF").unwrap_or(data);
+        let value: serde_json::Value = serde_json::from_slice(data)
+            .map_err(|e| ArchiveError::Validation(format!("Invalid JSON: {}", e)))?;
+        let orders = if value.is_array() {
+            serde_json::from_value::<Vec<ImportedOrder>>(value)
+        } else {
+            serde_json::from_value::<WrappedExport>(value).map(|w| w.orders)
+        };
+        orders.map_err(|e| ArchiveError::Validation(format!("Invalid order export: {}", e)))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_accepts_array_and_wrapped() {
+        let order = r#"{"receipt_number":"7","end_time":1600000000000,
+            "items":[{"name":"Cola","quantity":1,"unit_price":2.5}]}"#;
+        let tz = chrono_tz::Europe::Madrid;
+
+        let plain = JsonAdapter
+            .parse(format!("[{}]", order).as_bytes(), tz)
+            .unwrap();
+        let wrapped = JsonAdapter
+            .parse(format!("{{\"orders\":[{}]}}", order).as_bytes(), tz)
+            .unwrap();
+        assert_eq!(plain, wrapped);
+        assert_eq!(plain[0].items[0].tax_rate, 0);
+
+        assert!(JsonAdapter.parse(b"{\"rows\":[]}", tz).is_err());
+    }
+}
@@ -0,0 +1,524 @@
+//! 历史订单导入 — 从旧 POS 导出文件回填归档订单
+//!
+//! 换用本系统的餐厅可保留历史销售数据，统计报表可与过往对比：
+//!
+//! ```text
+//! 导出文件 (CSV / JSON) → LegacyPosAdapter::parse → Vec<ImportedOrder>
+//!                       → import_orders → archived_order (is_imported = 1)
+//! ```
+//!
+//! 导入订单绕过事件溯源管道：不产生 OrderEvent、不进入哈希链 (chain_entry)、
+//! 不生成 Verifactu 发票，也不会同步到云端（云同步以 chain_entry 为准）。
+//! 因此不能对其开具退款凭证、作废 (anulación) 或升级发票。
+//!
+//! 收据号加 [`IMPORTED_RECEIPT_PREFIX`] 前缀，避免与本系统收据号冲突；
+//! 重复导入同一文件时按收据号跳过已存在的订单。
+
+mod csv;
+mod json;
+
+use chrono_tz::Tz;
+use rust_decimal::Decimal;
+use serde::{Deserialize, Serialize};
+use shared::util::snowflake_id;
+use sqlx::{Sqlite, SqlitePool, Transaction};
+use std::collections::HashSet;
+
+use super::{ArchiveError, ArchiveResult};
+use crab_order_core::money::{to_decimal, to_f64};
+
+pub use csv::CsvAdapter;
+/// CSV 解析工具 (会员导入复用)
+pub(crate) use csv::{decode_text, detect_delimiter, normalize_header, parse_records};
+pub use json::JsonAdapter;
+
+/// 导入订单收据号前缀
+pub const IMPORTED_RECEIPT_PREFIX: &str = "IMP-";
+
+/// 无支付明细时使用的支付方式
+const UNKNOWN_PAYMENT_METHOD: &str = "OTHER";
+
+/// 单次导入的订单数上限
+pub const MAX_IMPORT_ORDERS: usize = 200_000;
+
+/// 旧 POS 导出格式适配器
+pub trait LegacyPosAdapter: Send + Sync {
+    /// 格式标识（API `format` 参数，同时记录为 `import_source`）
+    fn format(&self) -> &'static str;
+
+    /// 解析导出文件；无时区信息的本地时间按门店时区 `tz` 解释
+    fn parse(&self, data: &[u8], tz: Tz) -> ArchiveResult<Vec<ImportedOrder>>;
+}
+
+static ADAPTERS: &[&dyn LegacyPosAdapter] = &[&CsvAdapter, &JsonAdapter];
+
+/// 按格式标识查找适配器
+pub fn adapter_for(format: &str) -> Option<&'static dyn LegacyPosAdapter> {
+    ADAPTERS
+        .iter()
+        .copied()
+        .find(|a| a.format().eq_ignore_ascii_case(format))
+}
+
+/// 支持的格式标识
+pub fn supported_formats() -> Vec<&'static str> {
+    ADAPTERS.iter().map(|a| a.format()).collect()
+}
+
+/// 规范化的历史订单（适配器输出 / JSON 格式输入）
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ImportedOrder {
+    /// 旧系统收据号（导入时加前缀）
+    pub receipt_number: String,
+    /// 开单时间 (Unix millis)，缺省为 end_time
+    #[serde(default)]
+    pub start_time: Option<i64>,
+    /// 结账时间 (Unix millis)
+    pub end_time: i64,
+    #[serde(default)]
+    pub table_name: Option<String>,
+    #[serde(default)]
+    pub zone_name: Option<String>,
+    #[serde(default)]
+    pub guest_count: Option<i32>,
+    #[serde(default)]
+    pub operator_name: Option<String>,
+    /// 旧系统中已作废
+    #[serde(default)]
+    pub voided: bool,
+    /// 整单折扣金额
+    #[serde(default)]
+    pub discount_amount: f64,
+    pub items: Vec<ImportedItem>,
+    /// 为空时按总额记一笔 `OTHER` 支付
+    #[serde(default)]
+    pub payments: Vec<ImportedPayment>,
+}
+
+/// 历史订单明细行
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ImportedItem {
+    pub name: String,
+    #[serde(default)]
+    pub category_name: Option<String>,
+    pub quantity: i32,
+    /// 含税单价
+    pub unit_price: f64,
+    /// 行折扣金额（整行）
+    #[serde(default)]
+    pub discount_amount: f64,
+    /// 税率 (%)
+    #[serde(default)]
+    pub tax_rate: i32,
+}
+
+/// 历史订单支付
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ImportedPayment {
+    pub method: String,
+    pub amount: f64,
+}
+
+/// 导入结果
+#[derive(Debug, Clone, Default, Serialize)]
+pub struct OrderImportSummary {
+    pub format: String,
+    /// 文件中解析出的订单数
+    pub parsed: usize,
+    pub imported: usize,
+    /// 收据号已存在而跳过的订单数
+    pub skipped_existing: usize,
+    /// 导入订单的时间范围 (Unix millis)
+    pub first_order_time: Option<i64>,
+    pub last_order_time: Option<i64>,
+}
+
+impl ImportedOrder {
+    fn validate(&self) -> ArchiveResult<()> {
+        let invalid =
+            |msg: &str| ArchiveError::Validation(format!("{}: {}", self.receipt_number, msg));
+        if self.receipt_number.trim().is_empty() {
+            return Err(ArchiveError::Validation(
+                "receipt_number must not be empty".into(),
+            ));
+        }
+        if self.items.is_empty() {
+            return Err(invalid("order has no items"));
+        }
+        if self.start_time.is_some_and(|start| start > self.end_time) {
+            return Err(invalid("start_time is after end_time"));
+        }
+        if self.end_time > shared::util::now_millis() {
+            return Err(invalid("end_time is in the future"));
+        }
+        if !self.discount_amount.is_finite() || self.discount_amount < 0.0 {
+            return Err(invalid("discount_amount must be non-negative"));
+        }
+        for item in &self.items {
+            if item.name.trim().is_empty() {
+                return Err(invalid("item name must not be empty"));
+            }
+            if item.quantity <= 0 {
+                return Err(invalid("item quantity must be positive"));
+            }
+            if !item.unit_price.is_finite() || item.unit_price < 0.0 {
+                return Err(invalid("item unit_price must be non-negative"));
+            }
+            if !item.discount_amount.is_finite() || item.discount_amount < 0.0 {
+                return Err(invalid("item discount_amount must be non-negative"));
+            }
+            if !(0..=100).contains(&item.tax_rate) {
+                return Err(invalid("item tax_rate must be between 0 and 100"));
+            }
+        }
+        if self
+            .payments
+            .iter()
+            .any(|p| p.method.trim().is_empty() || !p.amount.is_finite())
+        {
+            return Err(invalid("payment method and amount are required"));
+        }
+        Ok(())
+    }
+
+    fn archived_receipt_number(&self) -> String {
+        format!("{}{}", IMPORTED_RECEIPT_PREFIX, self.receipt_number.trim())
+    }
+}
+
+/// 计算后的明细金额
+struct ItemAmounts {
+    gross: Decimal,
+    discount: Decimal,
+    line_total: Decimal,
+    tax: Decimal,
+}
+
+fn item_amounts(item: &ImportedItem) -> ItemAmounts {
+    let gross = to_decimal(item.unit_price) * Decimal::from(item.quantity);
+    let discount = to_decimal(item.discount_amount).min(gross);
+    let line_total = gross - discount;
+    // 旧系统导出的价格均为含税价
+    let rate = Decimal::from(item.tax_rate);
+    let tax = line_total * rate / (Decimal::ONE_HUNDRED + rate);
+    ItemAmounts {
+        gross,
+        discount,
+        line_total,
+        tax,
+    }
+}
+
+/// 将解析出的订单写入归档表（单事务，全部成功或全部回滚）
+pub async fn import_orders(
+    pool: &SqlitePool,
+    source: &str,
+    orders: &[ImportedOrder],
+) -> ArchiveResult<OrderImportSummary> {
+    if orders.len() > MAX_IMPORT_ORDERS {
+        return Err(ArchiveError::Validation(format!(
+            "Too many orders in one import ({} > {})",
+            orders.len(),
+            MAX_IMPORT_ORDERS
+        )));
+    }
+    for order in orders {
+        order.validate()?;
+    }
+
+    let mut summary = OrderImportSummary {
+        format: source.to_string(),
+        parsed: orders.len(),
+        ..Default::default()
+    };
+    let now = shared::util::now_millis();
+    let mut seen = HashSet::new();
+
+    let mut tx = pool.begin().await?;
+    for order in orders {
+        let receipt_number = order.archived_receipt_number();
+        if !seen.insert(receipt_number.clone()) {
+            return Err(ArchiveError::Validation(format!(
+                "Duplicate receipt number in file: {}",
+                order.receipt_number
+            )));
+        }
+        let exists: bool = sqlx::query_scalar(
+            "SELECT EXISTS(SELECT 1 FROM archived_order WHERE receipt_number = ?)",
+        )
+        .bind(&receipt_number)
+        .fetch_one(&mut *tx)
+        .await?;
+        if exists {
+            summary.skipped_existing += 1;
+            continue;
+        }
+
+        insert_order(&mut tx, source, &receipt_number, order, now).await?;
+        summary.imported += 1;
+        summary.first_order_time = Some(
+            summary
+                .first_order_time
+                .map_or(order.end_time, |t| t.min(order.end_time)),
+        );
+        summary.last_order_time = Some(
+            summary
+                .last_order_time
+                .map_or(order.end_time, |t| t.max(order.end_time)),
+        );
+    }
+    tx.commit().await?;
+
+    tracing::info!(
+        source,
+        imported = summary.imported,
+        skipped_existing = summary.skipped_existing,
+        "Legacy orders imported"
+    );
+    Ok(summary)
+}
+
+async fn insert_order(
+    tx: &mut Transaction<'_, Sqlite>,
+    source: &str,
+    receipt_number: &str,
+    order: &ImportedOrder,
+    now: i64,
+) -> ArchiveResult<()> {
+    let amounts: Vec<ItemAmounts> = order.items.iter().map(item_amounts).collect();
+    let original_total: Decimal = amounts.iter().map(|a| a.gross).sum();
+    let item_discount: Decimal = amounts.iter().map(|a| a.discount).sum();
+    let subtotal: Decimal = amounts.iter().map(|a| a.line_total).sum();
+    let order_discount = to_decimal(order.discount_amount).min(subtotal);
+    let total = subtotal - order_discount;
+    // 整单折扣按比例分摊税额
+    let item_tax: Decimal = amounts.iter().map(|a| a.tax).sum();
+    let tax = if subtotal.is_zero() {
+        Decimal::ZERO
+    } else {
+        item_tax * total / subtotal
+    };
+
+    let payments: Vec<ImportedPayment> = if order.voided {
+        Vec::new()
+    } else if order.payments.is_empty() {
+        vec![ImportedPayment {
+            method: UNKNOWN_PAYMENT_METHOD.to_string(),
+            amount: to_f64(total),
+        }]
+    } else {
+        order.payments.clone()
+    };
+    let paid_amount: Decimal = payments.iter().map(|p| to_decimal(p.amount)).sum();
+
+    let order_pk = snowflake_id();
+    let status = if order.voided { "VOID" } else { "COMPLETED" };
+    sqlx::query(
+        "INSERT INTO archived_order (\
+            id, receipt_number, zone_name, table_name, status, guest_count, \
+            original_total, subtotal, total_amount, paid_amount, \
+            discount_amount, order_manual_discount_amount, tax, \
+            start_time, end_time, operator_name, created_at, \
+            is_imported, import_source\
+        ) VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8, ?9, ?10, ?11, ?12, ?13, ?14, ?15, ?16, ?17, 1, ?18)",
+    )
+    .bind(order_pk)
+    .bind(receipt_number)
+    .bind(&order.zone_name)
+    .bind(&order.table_name)
+    .bind(status)
+    .bind(order.guest_count)
+    .bind(to_f64(original_total))
+    .bind(to_f64(subtotal))
+    .bind(to_f64(total))
+    .bind(to_f64(paid_amount))
+    .bind(to_f64(item_discount + order_discount))
+    .bind(to_f64(order_discount))
+    .bind(to_f64(tax))
+    .bind(order.start_time.unwrap_or(order.end_time))
+    .bind(order.end_time)
+    .bind(&order.operator_name)
+    .bind(now)
+    .bind(source)
+    .execute(&mut **tx)
+    .await?;
+
+    for (idx, (item, a)) in order.items.iter().zip(&amounts).enumerate() {
+        let item_pk = snowflake_id();
+        let qty = Decimal::from(item.quantity);
+        sqlx::query(
+            "INSERT INTO archived_order_item (\
+                id, order_pk, spec, instance_id, name, price, quantity, \
+                unit_price, line_total, discount_amount, tax, tax_rate, category_name\
+            ) VALUES (?1, ?2, 0, ?3, ?4, ?5, ?6, ?7, ?8, ?9, ?10, ?11, ?12)",
+        )
+        .bind(item_pk)
+        .bind(order_pk)
+        .bind(format!("import-{}", idx))
+        .bind(item.name.trim())
+        .bind(item.unit_price)
+        .bind(item.quantity)
+        .bind(to_f64(a.line_total / qty))
+        .bind(to_f64(a.line_total))
+        .bind(to_f64(a.discount))
+        .bind(to_f64(a.tax))
+        .bind(item.tax_rate)
+        .bind(&item.category_name)
+        .execute(&mut **tx)
+        .await?;
+
+        if !a.discount.is_zero() {
+            insert_manual_discount(tx, order_pk, Some(item_pk), a.discount).await?;
+        }
+    }
+    if !order_discount.is_zero() {
+        insert_manual_discount(tx, order_pk, None, order_discount).await?;
+    }
+
+    for (seq, payment) in payments.iter().enumerate() {
+        sqlx::query(
+            "INSERT INTO archived_order_payment (order_pk, seq, payment_id, method, amount, time) \
+             VALUES (?1, ?2, ?3, ?4, ?5, ?6)",
+        )
+        .bind(order_pk)
+        .bind(i32::try_from(seq).unwrap_or(i32::MAX))
+        .bind(format!("import-{}", seq))
+        .bind(&payment.method)
+        .bind(payment.amount)
+        .bind(order.end_time)
+        .execute(&mut **tx)
+        .await?;
+    }
+
+    Ok(())
+}
+
+async fn insert_manual_discount(
+    tx: &mut Transaction<'_, Sqlite>,
+    order_pk: i64,
+    item_pk: Option<i64>,
+    amount: Decimal,
+) -> ArchiveResult<()> {
+    sqlx::query(
+        "INSERT INTO archived_order_adjustment (id, order_pk, item_pk, source_type, direction, amount) \
+         VALUES (?1, ?2, ?3, 'MANUAL', 'DISCOUNT', ?4)",
+    )
+    .bind(snowflake_id())
+    .bind(order_pk)
+    .bind(item_pk)
+    .bind(to_f64(amount))
+    .execute(&mut **tx)
+    .await?;
+    Ok(())
+}
+
+/// 订单是否为导入的历史订单（无财务凭证，不可开具退款 / 作废 / 升级）
+pub async fn ensure_not_imported(pool: &SqlitePool, order_pk: i64) -> ArchiveResult<()> {
+    let imported: Option<bool> =
+        sqlx::query_scalar("SELECT is_imported FROM archived_order WHERE id = ?")
+            .bind(order_pk)
+            .fetch_optional(pool)
+            .await?;
+    if imported == Some(true) {
+        return Err(ArchiveError::BusinessRule(
+            shared::error::ErrorCode::OrderImported,
+            "Imported historical order has no fiscal record".into(),
+        ));
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::db::test_pool;
+
+    fn order(receipt: &str) -> ImportedOrder {
+        ImportedOrder {
+            receipt_number: receipt.to_string(),
+            start_time: None,
+            end_time: 1_600_000_000_000,
+            table_name: Some("5".to_string()),
+            zone_name: None,
+            guest_count: Some(2),
+            operator_name: Some("Ana".to_string()),
+            voided: false,
+            discount_amount: 1.0,
+            items: vec![
+                ImportedItem {
+                    name: "Café".to_string(),
+                    category_name: Some("Bebidas".to_string()),
+                    quantity: 2,
+                    unit_price: 1.5,
+                    discount_amount: 0.0,
+                    tax_rate: 10,
+                },
+                ImportedItem {
+                    name: "Tostada".to_string(),
+                    category_name: None,
+                    quantity: 1,
+                    unit_price: 3.3,
+                    discount_amount: 0.3,
+                    tax_rate: 10,
+                },
+            ],
+            payments: vec![],
+        }
+    }
+
+    #[tokio::test]
+    async fn test_import_is_idempotent_and_outside_chain() {
+        let pool = test_pool().await;
+        let summary = import_orders(&pool, "json", &[order("A1"), order("A2")])
+            .await
+            .unwrap();
+        assert_eq!(summary.imported, 2);
+
+        let again = import_orders(&pool, "json", &[order("A1")]).await.unwrap();
+        assert_eq!(again.imported, 0);
+        assert_eq!(again.skipped_existing, 1);
+
+        let (total, paid, tax, imported): (f64, f64, f64, bool) = sqlx::query_as(
+            "SELECT total_amount, paid_amount, tax, is_imported FROM archived_order WHERE receipt_number = 'IMP-A1'",
+        )
+        .fetch_one(&pool)
+        .await
+        .unwrap();
+        // 3.00 + 3.00 - 1.00 order discount
+        assert_eq!(total, 5.0);
+        assert_eq!(paid, 5.0);
+        assert_eq!(tax, 0.45);
+        assert!(imported);
+
+        let chain_entries: i64 = sqlx::query_scalar("SELECT COUNT(*) FROM chain_entry")
+            .fetch_one(&pool)
+            .await
+            .unwrap();
+        assert_eq!(chain_entries, 0);
+
+        let pk: i64 =
+            sqlx::query_scalar("SELECT id FROM archived_order WHERE receipt_number = 'IMP-A2'")
+                .fetch_one(&pool)
+                .await
+                .unwrap();
+        assert!(ensure_not_imported(&pool, pk).await.is_err());
+    }
+
+    #[tokio::test]
+    async fn test_invalid_order_rejects_whole_file() {
+        let pool = test_pool().await;
+        let mut bad = order("B2");
+        bad.items[0].quantity = 0;
+        assert!(
+            import_orders(&pool, "json", &[order("B1"), bad])
+                .await
+                .is_err()
+        );
+
+        let count: i64 = sqlx::query_scalar("SELECT COUNT(*) FROM archived_order")
+            .fetch_one(&pool)
+            .await
+            .unwrap();
+        assert_eq!(count, 0);
+    }
+}
@@ -4,22 +4,32 @@
 //! - **credit_note**: CreditNoteService (退款凭证，共享哈希链)
 //! - **worker**: ArchiveWorker (队列处理，并发归档，重试)
 //! - **verify**: VerifyScheduler (启动补扫 + 每日定时验证)
+//! - **import**: 旧 POS 历史订单导入 (不进入哈希链)
+//! - **receipt**: ReceiptArchive (收据打印原文只写一次存档)
+//! - **audit_bundle**: 订单审计包 (事件 + 快照 + 操作员 + 收据原文，证书签名导出)
+//! - **compaction**: EventLogCompactor (redb 事件日志超期归档 + 截断)
 
 pub mod anulacion;
+pub mod audit_bundle;
+pub mod compaction;
 pub mod credit_note;
+pub mod import;
 pub mod invoice;
+pub mod receipt;
 pub mod service;
 pub mod upgrade;
 pub mod verify;
 pub mod worker;
 
 pub use anulacion::AnulacionService;
+pub use compaction::{CompactionReport, EventLogCompactor};
 pub use credit_note::CreditNoteService;
 pub use invoice::InvoiceService;
+pub use receipt::ReceiptArchive;
 pub use service::{
     ArchiveError, ArchiveResult, ChainBreak, ChainReset, DailyChainVerification, EventVerification,
     OrderArchiveService, OrderVerification,
 };
 pub use upgrade::UpgradeService;
 pub use verify::VerifyScheduler;
-pub use worker::ArchiveWorker;
+pub use worker::{ArchiveSweepHandle, ArchiveWorker};
@@ -0,0 +1,186 @@
+//! 收据存档 — 打印原文 (ESC/POS / PDF) 的只写一次存储
+//!
+//! ```text
+//! {work_dir}/receipts/{receipt_number}/{KIND}-{sha256}.{ext}
+//! ```
+//!
+//! - 内容寻址：同一收据重复上传同一内容幂等返回已有记录，重打生成新文件
+//! - 只写一次：`create_new` 写入后设为只读，不提供更新/删除接口
+//! - 读取时校验 SHA-256，文件被改动即报错 (争议举证的前提)
+
+use std::path::{Path, PathBuf};
+
+use sha2::{Digest, Sha256};
+use shared::models::{ReceiptArtifact, ReceiptArtifactKind, is_valid_receipt_number};
+use sqlx::SqlitePool;
+use tokio::io::AsyncWriteExt;
+
+use crate::db::repository::receipt_artifact;
+use crate::utils::{AppError, AppResult};
+
+/// 单个存档文件上限 (含 logo 位图的 ESC/POS 或 PDF)
+pub const MAX_RECEIPT_ARTIFACT_SIZE: usize = 1024 * 1024;
+
+/// 收据存档目录
+#[derive(Debug, Clone)]
+pub struct ReceiptArchive {
+    dir: PathBuf,
+}
+
+impl ReceiptArchive {
+    pub fn new(dir: impl Into<PathBuf>) -> Self {
+        Self { dir: dir.into() }
+    }
+
+    fn path(&self, receipt_number: &str, kind: ReceiptArtifactKind, sha256: &str) -> PathBuf {
+        self.dir.join(receipt_number).join(format!(
+            "{}-{sha256}.{}",
+            kind.as_str(),
+            kind.extension()
+        ))
+    }
+
+    /// 写入存档文件并登记索引
+    pub async fn store(
+        &self,
+        pool: &SqlitePool,
+        receipt_number: &str,
+        kind: ReceiptArtifactKind,
+        content: &[u8],
+        reprint: bool,
+    ) -> AppResult<ReceiptArtifact> {
+        if !is_valid_receipt_number(receipt_number) {
+            return Err(AppError::validation(format!(
+                "Invalid receipt number: {receipt_number}"
+            )));
+        }
+        if content.is_empty() || content.len() > MAX_RECEIPT_ARTIFACT_SIZE {
+            return Err(AppError::validation(format!(
+                "Receipt artifact must be 1..={MAX_RECEIPT_ARTIFACT_SIZE} bytes"
+            )));
+        }
+
+        let sha256 = hex::encode(Sha256::digest(content));
+        let path = self.path(receipt_number, kind, &sha256);
+        write_once(&path, content).await?;
+
+        let artifact = receipt_artifact::insert(
+            pool,
+            receipt_number,
+            kind,
+            &sha256,
+            content.len() as i64,
+            reprint,
+        )
+        .await?;
+        Ok(artifact)
+    }
+
+    /// 读取存档文件 (校验 SHA-256)
+    pub async fn read(&self, artifact: &ReceiptArtifact) -> AppResult<Vec<u8>> {
+        let path = self.path(&artifact.receipt_number, artifact.kind, &artifact.sha256);
+        let content = match tokio::fs::read(&path).await {
+            Ok(content) => content,
+            Err(e) if e.kind() == std::io::ErrorKind::NotFound => {
+                return Err(AppError::not_found(format!(
+                    "Receipt artifact file {}",
+                    path.display()
+                )));
+            }
+            Err(e) => return Err(AppError::internal(format!("Read receipt artifact: {e}"))),
+        };
+        if hex::encode(Sha256::digest(&content)) != artifact.sha256 {
+            tracing::error!(
+                artifact_id = artifact.id,
+                receipt_number = %artifact.receipt_number,
+                "Receipt artifact content does not match its hash"
+            );
+            return Err(AppError::internal(format!(
+                "Receipt artifact {} failed integrity check",
+                artifact.id
+            )));
+        }
+        Ok(content)
+    }
+}
+
+/// 写入新文件；已存在 (同内容重复上传) 时保持原文件不动
+async fn write_once(path: &Path, content: &[u8]) -> AppResult<()> {
+    if let Some(parent) = path.parent() {
+        tokio::fs::create_dir_all(parent)
+            .await
+            .map_err(|e| AppError::internal(format!("Create receipt archive dir: {e}")))?;
+    }
+    let mut file = match tokio::fs::OpenOptions::new()
+        .write(true)
+        .create_new(true)
+        .open(path)
+        .await
+    {
+        Ok(file) => file,
+        Err(e) if e.kind() == std::io::ErrorKind::AlreadyExists => return Ok(()),
+        Err(e) => return Err(AppError::internal(format!("Create receipt artifact: {e}"))),
+    };
+    file.write_all(content)
+        .await
+        .map_err(|e| AppError::internal(format!("Write receipt artifact: {e}")))?;
+    file.sync_all()
+        .await
+        .map_err(|e| AppError::internal(format!("Sync receipt artifact: {e}")))?;
+
+    let mut permissions = file
+        .metadata()
+        .await
+        .map_err(|e| AppError::internal(format!("Stat receipt artifact: {e}")))?
+        .permissions();
+    permissions.set_readonly(true);
+    if let Err(e) = tokio::fs::set_permissions(path, permissions).await {
+        tracing::warn!(path = %path.display(), "Failed to mark receipt artifact read-only: {e}");
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::db::test_pool;
+
+    #[tokio::test]
+    #[allow(clippy::permissions_set_readonly_false)]
+    async fn test_store_read_and_tamper_detection() {
+        let pool = test_pool().await;
+        let dir = tempfile::tempdir().unwrap();
+        let archive = ReceiptArchive::new(dir.path());
+
+        let bytes = b"\x1b\x40TOTAL 12.50\x1dV\x00";
+        let artifact = archive
+            .store(&pool, "R0001", ReceiptArtifactKind::EscPos, bytes, false)
+            .await
+            .unwrap();
+        assert_eq!(artifact.size_bytes, bytes.len() as i64);
+        assert_eq!(archive.read(&artifact).await.unwrap(), bytes);
+
+        // 重复上传幂等
+        let again = archive
+            .store(&pool, "R0001", ReceiptArtifactKind::EscPos, bytes, false)
+            .await
+            .unwrap();
+        assert_eq!(again.id, artifact.id);
+
+        assert!(
+            archive
+                .store(&pool, "../R0001", ReceiptArtifactKind::EscPos, bytes, false)
+                .await
+                .is_err()
+        );
+
+        // 文件被改动 → 校验失败
+        let path = archive.path("R0001", artifact.kind, &artifact.sha256);
+        let mut permissions = std::fs::metadata(&path).unwrap().permissions();
+        assert!(permissions.readonly());
+        permissions.set_readonly(false);
+        std::fs::set_permissions(&path, permissions).unwrap();
+        std::fs::write(&path, b"TOTAL 1.00").unwrap();
+        assert!(archive.read(&artifact).await.is_err());
+    }
+}
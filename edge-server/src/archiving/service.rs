@@ -5,7 +5,7 @@
 //! All archive operations are atomic - either everything succeeds or nothing is written.
 
 use crate::db::repository::system_state;
-use crate::order_money::{to_decimal, to_f64};
+use crab_order_core::money::{to_decimal, to_f64};
 use rust_decimal::Decimal;
 use serde::Serialize;
 use shared::order::{OrderEvent, OrderEventType, OrderSnapshot, OrderStatus};
@@ -365,7 +365,7 @@ impl OrderArchiveService {
                 void_type, loss_reason, loss_amount, void_note, \
                 member_id, member_name, \
                 mg_discount_amount, marketing_group_name, \
-                created_at, queue_number, shift_id, service_type\
+                created_at, queue_number, shift_id, service_type, metadata, fiscal_code\
             ) VALUES (\
                 ?1, ?2, ?3, ?4, ?5, ?6, ?7, \
                 ?8, ?9, ?10, ?11, \
@@ -377,7 +377,7 @@ impl OrderArchiveService {
                 ?24, ?25, ?26, ?27, \
                 ?28, ?29, \
                 ?30, ?31, \
-                ?32, ?33, ?34, ?35\
+                ?32, ?33, ?34, ?35, ?36, ?37\
             )",
         )
         .bind(order_pk)
@@ -425,6 +425,11 @@ impl OrderArchiveService {
         .bind(snapshot.queue_number.map(|q| q as i64))
         .bind(shift_id)
         .bind(snapshot.service_type.as_ref().map(|st| st.as_str()))
+        .bind(
+            (!snapshot.metadata.is_empty())
+                .then(|| serde_json::to_string(&snapshot.metadata).unwrap_or_default()),
+        )
+        .bind(&snapshot.fiscal_code)
         .execute(&mut *tx)
         .await
         .map_err(|e| ArchiveError::Database(e.to_string()))?;
@@ -683,6 +688,11 @@ impl OrderArchiveService {
             });
 
             let seq = i32::try_from(i).unwrap_or(i32::MAX);
+            let surcharge = snapshot
+                .payment_surcharges
+                .iter()
+                .find(|s| s.payment_id == payment.payment_id)
+                .map(|s| s.amount);
             let split_type_str = payment.split_type.as_ref().map(|st| {
                 serde_json::to_value(st)
                     .ok()
@@ -695,8 +705,8 @@ impl OrderArchiveService {
                     order_pk, seq, payment_id, method, amount, time, \
                     cancelled, cancel_reason, \
                     tendered, change_amount, \
-                    split_type, split_items, aa_shares, aa_total_shares\
-                ) VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8, ?9, ?10, ?11, ?12, ?13, ?14)",
+                    split_type, split_items, aa_shares, aa_total_shares, reference, surcharge\
+                ) VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8, ?9, ?10, ?11, ?12, ?13, ?14, ?15, ?16)",
                 order_pk,
                 seq,
                 payment.payment_id,
@@ -711,6 +721,8 @@ impl OrderArchiveService {
                 split_items_str,
                 payment.aa_shares,
                 snapshot.aa_total_shares,
+                payment.reference,
+                surcharge,
             )
             .execute(&mut *tx)
             .await
@@ -1096,10 +1108,14 @@ mod tests {
             total_discount: 0.0,
             total_surcharge: 0.0,
             tax: 0.0,
+            tax_mode: shared::models::TaxMode::Inclusive,
+            tax_breakdown: vec![],
             discount: 0.0,
             comp_total_amount: 0.0,
             order_manual_discount_amount: 0.0,
             order_manual_surcharge_amount: 0.0,
+            payment_surcharges: vec![],
+            payment_surcharge_amount: 0.0,
             total: 100.0,
             paid_amount: 100.0,
             remaining_amount: 0.0,
@@ -1107,6 +1123,9 @@ mod tests {
             receipt_number: "R001".to_string(),
             is_pre_payment: false,
             note: None,
+            metadata: std::collections::BTreeMap::new(),
+            carry_over_date: None,
+            fiscal_code: None,
             order_rule_discount_amount: 0.0,
             order_rule_surcharge_amount: 0.0,
             order_applied_rules: vec![],
@@ -1120,6 +1139,8 @@ mod tests {
             marketing_group_name: None,
             mg_discount_amount: 0.0,
             stamp_redemptions: Vec::new(),
+            points_redemption: None,
+            coupon: None,
             start_time: 1704067200000,
             end_time: Some(1704070800000),
             created_at: 1704067200000,
@@ -1152,6 +1173,7 @@ mod tests {
                 is_retail: false,
                 queue_number: None,
                 receipt_number: "RCP-TEST".to_string(),
+                tax_mode: shared::models::TaxMode::Inclusive,
             },
         }
     }
@@ -1203,6 +1225,7 @@ mod tests {
             is_retail: false,
             queue_number: None,
             receipt_number: "RCP-TEST".to_string(),
+            tax_mode: shared::models::TaxMode::Inclusive,
         };
 
         let hash1 = compute_event_hash_standalone(&event1);
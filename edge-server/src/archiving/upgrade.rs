@@ -85,6 +85,9 @@ impl UpgradeService {
             )
         })?;
 
+        // 导入的历史订单无财务凭证
+        super::import::ensure_not_imported(&self.pool, request.order_pk).await?;
+
         if order.status != "COMPLETED" {
             return Err(ArchiveError::BusinessRule(
                 ErrorCode::OrderNotCompleted,
@@ -213,6 +216,9 @@ impl UpgradeService {
             )
         })?;
 
+        // 导入的历史订单无财务凭证
+        super::import::ensure_not_imported(&self.pool, order_pk).await?;
+
         if order.status != "COMPLETED" {
             return Err(ArchiveError::BusinessRule(
                 ErrorCode::OrderNotCompleted,
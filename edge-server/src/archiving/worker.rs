@@ -9,17 +9,17 @@
 use crate::archiving::service::OrderArchiveService;
 use crate::audit::{AuditAction, AuditService};
 use crate::core::state::ResourceVersions;
-use crate::db::repository::{marketing_group, member, payment, shift};
+use crate::db::repository::{member, payment, shift};
 use crate::message::MessageBus;
-use crate::order_money::{to_decimal, to_f64};
 use crate::orders::storage::{OrderStorage, PendingArchive};
+use crab_order_core::money::{to_decimal, to_f64};
 use rust_decimal::prelude::*;
 use shared::message::{BusMessage, SyncPayload};
 use shared::order::{OrderEvent, OrderEventType, OrderSnapshot};
 use sqlx::SqlitePool;
 use std::sync::Arc;
 use std::time::Duration;
-use tokio::sync::mpsc;
+use tokio::sync::{mpsc, oneshot};
 use tokio_util::sync::CancellationToken;
 
 /// Arc-wrapped OrderEvent (from EventRouter)
@@ -40,6 +40,28 @@ const QUEUE_SCAN_INTERVAL_SECS: u64 = 60;
 /// 并发归档数量（单店场景 10 即可，避免 SQLite 写入压力）
 const ARCHIVE_CONCURRENCY: usize = 10;
 
+/// 最终归档扫描请求 (关闭协调器使用)
+///
+/// 应答为扫描后仍未归档的订单数 (失败或已达重试上限)。
+#[derive(Clone)]
+pub struct ArchiveSweepHandle {
+    tx: mpsc::Sender<oneshot::Sender<usize>>,
+}
+
+impl ArchiveSweepHandle {
+    pub fn channel() -> (Self, mpsc::Receiver<oneshot::Sender<usize>>) {
+        let (tx, rx) = mpsc::channel(1);
+        (Self { tx }, rx)
+    }
+
+    /// 请求一次最终扫描并等待完成 (worker 已退出时返回 None)
+    pub async fn sweep(&self) -> Option<usize> {
+        let (ack_tx, ack_rx) = oneshot::channel();
+        self.tx.send(ack_tx).await.ok()?;
+        ack_rx.await.ok()
+    }
+}
+
 /// Worker for processing archive queue (支持并发归档)
 ///
 /// 通过 EventRouter 解耦，接收 mpsc 通道（已过滤为终端事件）
@@ -82,6 +104,7 @@ impl ArchiveWorker {
     pub async fn run(
         self,
         mut event_rx: mpsc::Receiver<ArcOrderEvent>,
+        mut sweep_rx: mpsc::Receiver<oneshot::Sender<usize>>,
         shutdown: CancellationToken,
     ) {
         tracing::info!(
@@ -133,6 +156,12 @@ impl ArchiveWorker {
                 _ = scan_interval.tick() => {
                     worker.process_pending_queue().await;
                 }
+                // Final sweep before shutdown: 先等并发任务完成，再忽略退避处理所有待归档
+                Some(ack) = sweep_rx.recv() => {
+                    while join_set.join_next().await.is_some() {}
+                    let remaining = worker.final_sweep().await;
+                    let _ = ack.send(remaining);
+                }
                 // Reap completed tasks
                 Some(_) = join_set.join_next(), if !join_set.is_empty() => {}
             }
@@ -179,6 +208,31 @@ impl ArchiveWorker {
         }
     }
 
+    /// 关闭前最终扫描：忽略退避立即重试，返回仍未归档的订单数
+    async fn final_sweep(&self) -> usize {
+        let pending = match self.storage.get_pending_archives() {
+            Ok(p) => p,
+            Err(e) => {
+                tracing::error!(error = %e, "Failed to get pending archives for final sweep");
+                return 0;
+            }
+        };
+        if pending.is_empty() {
+            return 0;
+        }
+
+        tracing::info!(count = pending.len(), "Final archive sweep");
+        for entry in &pending {
+            if entry.retry_count < MAX_RETRY_COUNT {
+                self.process_order(entry.order_id).await;
+            }
+        }
+        self.storage
+            .get_pending_archives()
+            .map(|p| p.len())
+            .unwrap_or(pending.len())
+    }
+
     /// Check if entry should be retried based on backoff
     fn should_retry(&self, entry: &PendingArchive) -> bool {
         if entry.retry_count >= MAX_RETRY_COUNT {
@@ -244,7 +298,7 @@ impl ArchiveWorker {
                     // 5. Write payment records to independent payment table
                     self.write_payment_records(&snapshot, &events).await;
 
-                    // 6. Update member stats (points + total_spent) for completed orders
+                    // 6. Update member stats (total_spent) for completed orders
                     self.update_member_stats(&snapshot).await;
 
                     // 7. Write audit log for terminal event
@@ -446,9 +500,9 @@ impl ArchiveWorker {
             .await;
     }
 
-    /// Update member stats (total_spent + points_balance) for completed orders with a linked member
+    /// Update member total_spent for completed orders with a linked member
     ///
-    /// Uses rust_decimal for precise calculation: points = floor(paid_amount × points_earn_rate)
+    /// 积分累积/兑换在 OrdersManager post_actions 中结算 (marketing::points)
     async fn update_member_stats(&self, snapshot: &OrderSnapshot) {
         use shared::order::OrderStatus;
 
@@ -461,54 +515,18 @@ impl ArchiveWorker {
             return;
         };
 
-        let Some(mg_id) = snapshot.marketing_group_id else {
-            return;
-        };
-
         let spent_amount = snapshot.paid_amount;
         if spent_amount <= 0.0 {
             return;
         }
 
-        // Load marketing group to get points_earn_rate
-        let points_earned = match marketing_group::find_by_id(&self.pool, mg_id).await {
-            Ok(Some(mg)) => {
-                if let Some(rate) = mg.points_earn_rate {
-                    let d_spent = to_decimal(spent_amount);
-                    let d_rate = to_decimal(rate);
-                    let d_points = (d_spent * d_rate).floor();
-                    d_points.to_i64().unwrap_or(0)
-                } else {
-                    0
-                }
-            }
-            Ok(None) => {
-                tracing::warn!(
-                    order_id = %snapshot.order_id,
-                    marketing_group_id = mg_id,
-                    "Marketing group not found for member stats update"
-                );
-                0
-            }
-            Err(e) => {
-                tracing::warn!(
-                    order_id = %snapshot.order_id,
-                    error = %e,
-                    "Failed to load marketing group for points calculation"
-                );
-                0
-            }
-        };
-
-        // Atomically update member stats (total_spent and points_balance)
         let spent_f64 = to_f64(to_decimal(spent_amount));
-        match member::update_member_stats(&self.pool, member_id, spent_f64, points_earned).await {
+        match member::add_total_spent(&self.pool, member_id, spent_f64).await {
             Ok(()) => {
                 tracing::debug!(
                     order_id = %snapshot.order_id,
                     member_id = member_id,
                     spent = spent_f64,
-                    points = points_earned,
                     "Member stats updated"
                 );
             }
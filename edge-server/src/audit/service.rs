@@ -98,7 +98,9 @@ impl AuditService {
     /// 检测到的异常会：
     /// 1. 写入审计日志（不可篡改的记录）
     /// 2. 写入 system_issue 表（前端对话框渲染）
-    pub async fn on_startup(&self) {
+    ///
+    /// 异常关闭时返回上次启动时间戳 (用于启动恢复检查)
+    pub async fn on_startup(&self) -> Option<i64> {
         let now = shared::util::now_millis();
 
         // 先采集最后一条审计日志的时间戳（必须在写入新条目之前，否则长时间停机检测会读到刚写入的条目）
@@ -110,6 +112,7 @@ impl AuditService {
             .and_then(|(entries, _)| entries.first().map(|e| e.timestamp));
 
         // 1. 检测异常关闭：LOCK 文件存在
+        let mut abnormal_since = None;
         if self.lock_path.exists() {
             let lock_content = std::fs::read_to_string(&self.lock_path).unwrap_or_default();
             let last_start_ts: i64 = lock_content.trim().parse().unwrap_or_else(|_| {
@@ -121,6 +124,7 @@ impl AuditService {
                 "Abnormal shutdown detected — LOCK file exists (last start: {})",
                 last_start_ts
             );
+            abnormal_since = Some(last_start_ts);
 
             let details = serde_json::json!({
                 "last_start_timestamp": last_start_ts,
@@ -317,6 +321,7 @@ impl AuditService {
         {
             tracing::error!("Failed to create audit LOCK file: {:?}", e);
         }
+        abnormal_since
     }
 
     /// 系统正常关闭时调用 — 删除 LOCK 文件
@@ -22,6 +22,16 @@ pub enum AuditAction {
     SystemLongDowntime,
     /// 用户回应系统问题（启动异常/远程通知）
     ResolveSystemIssue,
+    /// 死信重新投递
+    DeadLetterRedelivered,
+    /// 死信丢弃
+    DeadLetterDiscarded,
+    /// 开启远程支持会话 (同意共享诊断与日志)
+    SupportSessionOpened,
+    /// 结束远程支持会话
+    SupportSessionClosed,
+    /// 导出脱敏数据库 (交给开发人员复现问题)
+    AnonymizedDatabaseExported,
 
     // ═══ 认证 ═══
     /// 登录成功
@@ -40,6 +50,28 @@ pub enum AuditAction {
     OrderVoided,
     /// 订单合并
     OrderMerged,
+    /// 导入旧 POS 历史订单
+    OrdersImported,
+    /// 导出订单审计包 (签名证明材料)
+    OrderAuditExported,
+
+    // ═══ 宴会预订 ═══
+    /// 预订创建
+    EventBookingCreated,
+    /// 预订修改（菜单/人数/状态）
+    EventBookingUpdated,
+    /// 预订取消
+    EventBookingCancelled,
+    /// 预订定金收取
+    EventBookingDepositRecorded,
+
+    // ═══ 订位 ═══
+    /// 订位创建
+    ReservationCreated,
+    /// 订位修改（时段/人数/桌台/入座/未到店）
+    ReservationUpdated,
+    /// 订位取消
+    ReservationCancelled,
 
     // ═══ 管理操作 ═══
     /// 员工创建
@@ -62,6 +94,18 @@ pub enum AuditAction {
     ShiftUpdated,
     /// 班次关闭
     ShiftClosed,
+    /// 开始休息
+    ShiftBreakStarted,
+    /// 结束休息
+    ShiftBreakEnded,
+    /// 打开钱箱（无销售）
+    CashDrawerOpened,
+    /// 钱箱存入现金
+    CashPaidIn,
+    /// 钱箱取出现金
+    CashPaidOut,
+    /// 发送员工公告
+    AnnouncementSent,
 
     // ═══ 商品目录 ═══
     /// 商品创建
@@ -70,6 +114,30 @@ pub enum AuditAction {
     ProductUpdated,
     /// 商品删除
     ProductDeleted,
+    /// 商品沽清 (86)
+    ProductEightySixed,
+    /// 商品恢复供应
+    ProductEightySixCleared,
+    /// 开始跟踪库存
+    StockLevelCreated,
+    /// 库存设置更新 (低库存阈值、条码)
+    StockLevelUpdated,
+    /// 停止跟踪库存
+    StockLevelDeleted,
+    /// 手动库存调整 (进货、报损)
+    StockAdjusted,
+    /// 开始盘点 (冻结理论数量)
+    StockCountStarted,
+    /// 盘点提交审批
+    StockCountSubmitted,
+    /// 盘点审批通过 (过账差异)
+    StockCountApproved,
+    /// 盘点驳回
+    StockCountRejected,
+    /// 调拨发货 (过账出库，在途)
+    StockTransferSent,
+    /// 调拨收货确认 (过账入库)
+    StockTransferReceived,
     /// 分类创建
     CategoryCreated,
     /// 分类更新
@@ -110,6 +178,10 @@ pub enum AuditAction {
     TableUpdated,
     /// 桌台删除
     TableDeleted,
+    /// 拼桌
+    TablesJoined,
+    /// 拆桌
+    TablesSplit,
 
     // ═══ 打印 ═══
     /// 标签模板创建
@@ -132,6 +204,10 @@ pub enum AuditAction {
     MemberUpdated,
     /// 会员删除
     MemberDeleted,
+    /// 会员储值充值
+    MemberCreditToppedUp,
+    /// 批量导入会员 (CSV)
+    MembersImported,
 
     // ═══ 营销组 ═══
     /// 营销组创建
@@ -141,6 +217,14 @@ pub enum AuditAction {
     /// 营销组删除
     MarketingGroupDeleted,
 
+    // ═══ 优惠码 ═══
+    /// 优惠码创建（含批量生成）
+    CouponCreated,
+    /// 优惠码更新
+    CouponUpdated,
+    /// 优惠码删除
+    CouponDeleted,
+
     // ═══ 日结报告 ═══
     /// 日结报告生成
     DailyReportGenerated,
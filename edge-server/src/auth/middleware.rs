@@ -24,6 +24,7 @@ use crate::security_log;
 /// - 非 `/api/` 路径
 /// - `/api/auth/login` (登录接口)
 /// - `/api/message/emit` (消息发布接口)
+/// - `/api/public/*` (顾客 / 第三方回调，处理器自行校验)
 ///
 /// # 错误处理
 ///
@@ -49,8 +50,10 @@ pub async fn require_auth(
         return Ok(next.run(req).await);
     }
 
-    // 公共 API 路由跳过认证
-    let is_public_api_route = path == "/api/auth/login" || path == "/api/message/emit";
+    // 公共 API 路由跳过认证 (`/api/public/` 供顾客 / 第三方回调，处理器自行校验)
+    let is_public_api_route = path == "/api/auth/login"
+        || path == "/api/message/emit"
+        || path.starts_with("/api/public/");
     if is_public_api_route {
         return Ok(next.run(req).await);
     }
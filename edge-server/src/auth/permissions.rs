@@ -8,30 +8,37 @@
 //! - 敏感操作：单独控制高风险操作
 //! - 用户管理：仅 admin 角色可用（is_system 保护）
 
-/// 可配置权限列表（18 项）
+use super::CurrentUser;
+
+/// 可配置权限列表（23 项）
 /// 不包含 "all" 和 "users:manage"，这些是系统级权限
 pub const ALL_PERMISSIONS: &[&str] = &[
-    // === 模块化权限 (6) ===
+    // === 模块化权限 (10) ===
     "menu:manage",        // 菜单管理（商品/分类/属性/标签 增删改查）
     "tables:manage",      // 桌台管理（区域/餐桌 增删改查）
+    "bookings:manage",    // 宴会预订管理（预订单/定金/转正式订单）
     "shifts:manage",      // 班次管理
-    "reports:view",       // 报表查看
+    "announcements:send", // 发送员工公告
+    "reports:view",       // 报表查看（营业数据：销售额、单量、商品/分类/区域）
+    "reports:financials", // 完整财务（税、折扣/附加费、支付方式、作废/损失/退款、发票、现金差异）
+    "reports:labor",      // 人员报表（班次明细、员工异常操作）
     "price_rules:manage", // 价格规则管理
     "settings:manage",    // 系统设置
     // === 营销与会员 (3) ===
     "marketing:manage",    // 营销组+会员管理
     "orders:link_member",  // 订单关联会员
     "orders:redeem_stamp", // 订单兑换印花
-    // === 敏感操作 (9) ===
-    "orders:void",         // 作废订单
-    "orders:discount",     // 应用折扣/附加费
-    "orders:comp",         // 赠送菜品
-    "orders:refund",       // 退款
-    "orders:modify_price", // 修改价格
-    "orders:cancel_item",  // 删除订单商品
-    "tables:transfer",     // 移台
-    "tables:merge_bill",   // 合台
-    "cash_drawer:open",    // 打开钱箱
+    // === 敏感操作 (10) ===
+    "orders:void",             // 作废订单
+    "orders:discount",         // 应用折扣/附加费
+    "orders:comp",             // 赠送菜品
+    "orders:refund",           // 退款
+    "orders:modify_price",     // 修改价格
+    "orders:cancel_item",      // 删除订单商品
+    "tables:transfer",         // 移台
+    "tables:merge_bill",       // 合台
+    "cash_drawer:open",        // 打开钱箱
+    "inventory:approve_count", // 审批盘点 (过账盘点差异)
 ];
 
 /// Admin 专属权限（不在可配置列表中）
@@ -48,8 +55,12 @@ pub const DEFAULT_MANAGER_PERMISSIONS: &[&str] = &[
     // 模块化
     "menu:manage",
     "tables:manage",
+    "bookings:manage",
     "shifts:manage",
+    "announcements:send",
     "reports:view",
+    "reports:financials",
+    "reports:labor",
     "price_rules:manage",
     "settings:manage",
     // 敏感操作
@@ -62,11 +73,33 @@ pub const DEFAULT_MANAGER_PERMISSIONS: &[&str] = &[
     "tables:transfer",
     "tables:merge_bill",
     "cash_drawer:open",
+    "inventory:approve_count",
 ];
 
 /// 普通员工默认权限（仅查看报表）
 pub const DEFAULT_USER_PERMISSIONS: &[&str] = &["reports:view"];
 
+/// 报表可见范围
+///
+/// `reports:view` 是所有报表接口的入口权限 (营业数据)；财务与人员数据
+/// 在 handler 中按本范围裁剪，班组长等角色只看到营业数据。
+#[derive(Debug, Clone, Copy, PartialEq, Eq, serde::Serialize)]
+pub struct ReportScope {
+    /// 完整财务 (`reports:financials`)
+    pub financials: bool,
+    /// 人员数据 (`reports:labor`)
+    pub labor: bool,
+}
+
+impl ReportScope {
+    pub fn of(user: &CurrentUser) -> Self {
+        Self {
+            financials: user.has_permission("reports:financials"),
+            labor: user.has_permission("reports:labor"),
+        }
+    }
+}
+
 /// Get permissions for a role name
 pub fn get_default_permissions(role_name: &str) -> Vec<String> {
     match role_name {
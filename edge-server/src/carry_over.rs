@@ -0,0 +1,192 @@
+//! 跨营业日未结订单处理 (Carry-over)
+//!
+//! 营业日 cutoff 时仍未结的订单按 `store_info.carry_over_policy` 处理：
+//!
+//! - `BLOCK`: 不处理订单，前一营业日的日报暂缓生成，需店员结清后手动生成
+//! - `TRANSFER`: 记录 `OrderCarriedOver` 事件，订单计入新营业日 (默认)
+//! - `FORCE_COMPLETE`: 记录 `OrderCarriedOver` 事件后尝试结单；
+//!   未付清的订单无法结单，保留为结转状态并发出关键通知
+//!
+//! 日报的结转入 / 结转出金额由 [`open_order_totals`] 提供的活跃订单参与计算。
+
+use chrono::NaiveDate;
+
+use crate::core::ServerState;
+use crate::db::repository::store_info;
+use shared::message::{NotificationCategory, NotificationPayload};
+use shared::models::CarryOverPolicy;
+use shared::order::{OrderCommand, OrderCommandPayload, OrderSnapshot};
+
+/// 系统操作员 (调度器发起的命令)
+const SYSTEM_OPERATOR_ID: i64 = 0;
+const SYSTEM_OPERATOR_NAME: &str = "System";
+
+/// 营业日结转结果
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CarryOverOutcome {
+    /// 没有跨日未结订单，或已全部结转 / 结单
+    Clear,
+    /// BLOCK 策略下仍有 N 个未结订单，日报暂缓生成
+    Blocked(usize),
+}
+
+/// 读取当前结转策略 (每次从 DB 读取，支持动态修改)
+pub async fn load_policy(state: &ServerState) -> CarryOverPolicy {
+    store_info::get(&state.pool)
+        .await
+        .ok()
+        .flatten()
+        .map(|s| s.carry_over_policy)
+        .unwrap_or_default()
+}
+
+/// 活跃订单的 (start_time, total)，供日报计算结转入 / 结转出
+pub fn open_order_totals(state: &ServerState) -> Vec<(i64, f64)> {
+    match state.orders_manager().get_active_orders() {
+        Ok(orders) => orders.iter().map(|o| (o.start_time, o.total)).collect(),
+        Err(e) => {
+            tracing::warn!("Failed to load active orders for carry-over totals: {}", e);
+            Vec::new()
+        }
+    }
+}
+
+/// 在 `boundary_millis` 之前开台、仍未结的订单
+pub fn open_orders_before(state: &ServerState, boundary_millis: i64) -> Vec<OrderSnapshot> {
+    match state.orders_manager().get_active_orders() {
+        Ok(orders) => orders
+            .into_iter()
+            .filter(|o| o.start_time < boundary_millis)
+            .collect(),
+        Err(e) => {
+            tracing::warn!("Failed to load active orders for carry-over: {}", e);
+            Vec::new()
+        }
+    }
+}
+
+/// 处理营业日 `from` 结束时 (`boundary_millis`) 仍未结的订单
+pub async fn carry_over_open_orders(
+    state: &ServerState,
+    from: NaiveDate,
+    boundary_millis: i64,
+) -> CarryOverOutcome {
+    let open = open_orders_before(state, boundary_millis);
+    if open.is_empty() {
+        return CarryOverOutcome::Clear;
+    }
+
+    let policy = load_policy(state).await;
+    let from_date = from.format("%Y-%m-%d").to_string();
+
+    if policy == CarryOverPolicy::Block {
+        tracing::warn!(
+            business_date = %from_date,
+            open = open.len(),
+            "Open orders block close of business day"
+        );
+        let payload = NotificationPayload::warning(
+            "Business day not closed",
+            format!(
+                "{} order(s) from {} are still open; settle them and generate the daily report manually",
+                open.len(),
+                from_date
+            ),
+        )
+        .with_category(NotificationCategory::Business)
+        .with_data(serde_json::json!({
+            "kind": "carry_over_blocked",
+            "business_date": &from_date,
+            "order_ids": open.iter().map(|o| o.order_id).collect::<Vec<_>>(),
+        }));
+        if let Err(e) = state.message_bus().publish_durable(payload).await {
+            tracing::debug!("Carry-over block notification not broadcast: {}", e);
+        }
+        return CarryOverOutcome::Blocked(open.len());
+    }
+
+    let to_date = from
+        .succ_opt()
+        .unwrap_or(from)
+        .format("%Y-%m-%d")
+        .to_string();
+    let manager = state.orders_manager();
+    let command = |payload| {
+        OrderCommand::new(
+            SYSTEM_OPERATOR_ID,
+            SYSTEM_OPERATOR_NAME.to_string(),
+            payload,
+        )
+    };
+
+    let mut unsettled = Vec::new();
+    for order in &open {
+        let response = manager
+            .execute_command(command(OrderCommandPayload::CarryOverOrder {
+                order_id: order.order_id,
+                from_business_date: from_date.clone(),
+                to_business_date: to_date.clone(),
+                policy,
+            }))
+            .await;
+        if !response.success {
+            tracing::warn!(
+                order_id = order.order_id,
+                error = ?response.error,
+                "Failed to carry over order"
+            );
+            continue;
+        }
+
+        if policy == CarryOverPolicy::ForceComplete {
+            let response = manager
+                .execute_command(command(OrderCommandPayload::CompleteOrder {
+                    order_id: order.order_id,
+                    service_type: None,
+                }))
+                .await;
+            if !response.success {
+                tracing::warn!(
+                    order_id = order.order_id,
+                    error = ?response.error,
+                    "Force-complete failed, order stays carried over"
+                );
+                unsettled.push(order);
+            }
+        }
+    }
+
+    tracing::info!(
+        business_date = %from_date,
+        open = open.len(),
+        policy = policy.as_str(),
+        "Carried over open orders"
+    );
+
+    if !unsettled.is_empty() {
+        let payload = NotificationPayload::warning(
+            "Orders could not be completed",
+            format!(
+                "{} order(s) from {} are unpaid and were carried over: {}",
+                unsettled.len(),
+                from_date,
+                unsettled
+                    .iter()
+                    .map(|o| o.receipt_number.as_str())
+                    .collect::<Vec<_>>()
+                    .join(", ")
+            ),
+        )
+        .with_category(NotificationCategory::Business)
+        .with_data(serde_json::json!({
+            "kind": "carry_over_unsettled",
+            "business_date": &from_date,
+            "order_ids": unsettled.iter().map(|o| o.order_id).collect::<Vec<_>>(),
+        }));
+        if let Err(e) = state.message_bus().publish_durable(payload).await {
+            tracing::debug!("Carry-over notification not broadcast: {}", e);
+        }
+    }
+
+    CarryOverOutcome::Clear
+}
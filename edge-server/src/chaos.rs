@@ -0,0 +1,219 @@
+//! Chaos / failure injection (故障注入)
+//!
+//! 仅在 `chaos` feature 下编译，用于在 CI / QA 中主动触发故障，
+//! 验证重连与降级逻辑，而不是等到现场才发现。生产构建中不存在任何注入点。
+//!
+//! 注入点通过 [`FaultRegistry::arm`] 配置 (一般经由 `/api/chaos` 管理接口)，
+//! 在各故障位置调用 [`FaultRegistry::inject`] / [`FaultRegistry::delay`] 检查是否触发。
+//! 注册表属于单个服务器实例，托管模式下对一个租户注入故障不会波及其他租户：
+//!
+//! | 注入点 | 位置 | 效果 |
+//! |--------|------|------|
+//! | `redb_commit` | OrdersManager 事务提交前 | 命令返回存储错误，事务回滚 |
+//! | `sqlite_timeout` | SQLite 连接池获取连接 | 获取连接延迟，超过池超时返回 `PoolTimedOut` |
+//! | `bus_send` | MessageBus publish / send_to_server / send_to_client | 返回内部错误 |
+//!
+//! TLS 断连为一次性动作 (`POST /api/chaos/tls-disconnect`)，不经过注册表。
+//!
+//! ```ignore
+//! state.faults.arm(FaultPoint::RedbCommit, FaultSpec { count: Some(1), ..Default::default() });
+//! assert!(manager.execute_command(cmd).await.error.is_some());
+//! ```
+
+use std::collections::HashMap;
+use std::sync::Arc;
+use std::time::Duration;
+
+use parking_lot::Mutex;
+use serde::{Deserialize, Serialize};
+
+/// SQLite 注入的默认延迟 (大于连接池默认 30s 获取超时)
+pub const DEFAULT_SQLITE_DELAY_MS: u64 = 35_000;
+
+/// 故障注入点
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum FaultPoint {
+    RedbCommit,
+    SqliteTimeout,
+    BusSend,
+}
+
+impl FaultPoint {
+    pub fn parse(s: &str) -> Option<Self> {
+        match s {
+            "redb_commit" => Some(Self::RedbCommit),
+            "sqlite_timeout" => Some(Self::SqliteTimeout),
+            "bus_send" => Some(Self::BusSend),
+            _ => None,
+        }
+    }
+}
+
+/// 故障配置
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct FaultSpec {
+    /// 剩余触发次数 (None = 不限次数，直到解除)
+    #[serde(default)]
+    pub count: Option<u32>,
+    /// 触发概率 0.0..=1.0 (None = 每次触发)
+    #[serde(default)]
+    pub probability: Option<f64>,
+    /// 延迟毫秒数 (仅 `sqlite_timeout` 使用)
+    #[serde(default)]
+    pub delay_ms: Option<u64>,
+}
+
+/// 已配置的故障
+#[derive(Debug, Clone, Serialize)]
+pub struct ArmedFault {
+    pub point: FaultPoint,
+    #[serde(flatten)]
+    pub spec: FaultSpec,
+    /// 已触发次数
+    pub triggered: u64,
+}
+
+/// 故障注册表 (每个服务器实例一份，托管模式下租户之间互不影响)
+///
+/// 由 `ServerState` 持有，克隆共享同一份配置；注入点 (OrdersManager、MessageBus、
+/// SQLite 连接池) 持有同一注册表的克隆。
+#[derive(Debug, Clone, Default)]
+pub struct FaultRegistry {
+    faults: Arc<Mutex<HashMap<FaultPoint, ArmedFault>>>,
+}
+
+impl FaultRegistry {
+    /// 配置故障 (覆盖同一注入点的已有配置)
+    pub fn arm(&self, point: FaultPoint, spec: FaultSpec) {
+        tracing::warn!(?point, ?spec, "Chaos fault armed");
+        self.faults.lock().insert(
+            point,
+            ArmedFault {
+                point,
+                spec,
+                triggered: 0,
+            },
+        );
+    }
+
+    /// 解除单个注入点，返回是否存在
+    pub fn disarm(&self, point: FaultPoint) -> bool {
+        let removed = self.faults.lock().remove(&point).is_some();
+        if removed {
+            tracing::warn!(?point, "Chaos fault disarmed");
+        }
+        removed
+    }
+
+    /// 解除所有注入点
+    pub fn clear(&self) {
+        self.faults.lock().clear();
+        tracing::warn!("All chaos faults cleared");
+    }
+
+    /// 当前配置的故障
+    pub fn armed(&self) -> Vec<ArmedFault> {
+        let mut faults: Vec<ArmedFault> = self.faults.lock().values().cloned().collect();
+        faults.sort_by_key(|f| f.point as u8);
+        faults
+    }
+
+    /// 检查注入点是否触发 (触发时消耗一次计数，计数用尽自动解除)
+    pub fn inject(&self, point: FaultPoint) -> bool {
+        let mut faults = self.faults.lock();
+        let Some(fault) = faults.get_mut(&point) else {
+            return false;
+        };
+        if let Some(p) = fault.spec.probability
+            && rand::random::<f64>() >= p
+        {
+            return false;
+        }
+        fault.triggered += 1;
+        if let Some(count) = fault.spec.count.as_mut() {
+            *count = count.saturating_sub(1);
+            if *count == 0 {
+                faults.remove(&point);
+            }
+        }
+        tracing::warn!(?point, "Chaos fault injected");
+        true
+    }
+
+    /// 延迟类注入点：触发时返回延迟时长
+    pub fn delay(&self, point: FaultPoint) -> Option<Duration> {
+        let delay_ms = self
+            .faults
+            .lock()
+            .get(&point)
+            .map(|f| f.spec.delay_ms.unwrap_or(DEFAULT_SQLITE_DELAY_MS))?;
+        self.inject(point).then(|| Duration::from_millis(delay_ms))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_arm_inject_and_disarm() {
+        let faults = FaultRegistry::default();
+        assert!(!faults.inject(FaultPoint::BusSend));
+
+        faults.arm(
+            FaultPoint::BusSend,
+            FaultSpec {
+                count: Some(2),
+                ..Default::default()
+            },
+        );
+        assert!(faults.inject(FaultPoint::BusSend));
+        assert_eq!(faults.armed()[0].triggered, 1);
+        assert!(faults.inject(FaultPoint::BusSend));
+        // 计数用尽后自动解除
+        assert!(!faults.inject(FaultPoint::BusSend));
+        assert!(faults.armed().is_empty());
+
+        faults.arm(
+            FaultPoint::RedbCommit,
+            FaultSpec {
+                probability: Some(0.0),
+                ..Default::default()
+            },
+        );
+        assert!(!faults.inject(FaultPoint::RedbCommit));
+        assert!(faults.disarm(FaultPoint::RedbCommit));
+        assert!(!faults.disarm(FaultPoint::RedbCommit));
+
+        faults.arm(
+            FaultPoint::SqliteTimeout,
+            FaultSpec {
+                count: Some(1),
+                delay_ms: Some(5),
+                ..Default::default()
+            },
+        );
+        assert_eq!(
+            faults.delay(FaultPoint::SqliteTimeout),
+            Some(Duration::from_millis(5))
+        );
+        assert_eq!(faults.delay(FaultPoint::SqliteTimeout), None);
+
+        assert_eq!(FaultPoint::parse("bus_send"), Some(FaultPoint::BusSend));
+        assert_eq!(FaultPoint::parse("tls"), None);
+    }
+
+    #[test]
+    fn test_registries_are_isolated() {
+        let tenant_a = FaultRegistry::default();
+        let tenant_b = FaultRegistry::default();
+        tenant_a.arm(FaultPoint::BusSend, FaultSpec::default());
+
+        assert!(!tenant_b.inject(FaultPoint::BusSend));
+        assert!(tenant_b.armed().is_empty());
+        // 克隆共享同一份配置
+        assert!(tenant_a.clone().inject(FaultPoint::BusSend));
+        assert_eq!(tenant_a.armed()[0].triggered, 1);
+    }
+}
@@ -0,0 +1,107 @@
+//! 邮件发送请求 outbox
+//!
+//! 面向顾客的邮件 (小票、预订确认) 由 crab-cloud 按租户模板渲染并经租户
+//! SMTP / 平台通道发出。Edge 只提交 [`TemplatedEmail`]，由 CloudWorker 在 WS
+//! 连接上发送；断线期间保留在内存中，重连后补发。
+
+use std::collections::VecDeque;
+
+use parking_lot::Mutex;
+use tokio::sync::Notify;
+
+use shared::cloud::CloudMessage;
+use shared::cloud::email::TemplatedEmail;
+
+/// outbox 上限 (断线期间)，超出时丢弃最旧的请求
+const MAX_OUTBOX: usize = 200;
+
+#[derive(Debug, Default)]
+pub struct MailOutbox {
+    outbox: Mutex<VecDeque<CloudMessage>>,
+    notify: Notify,
+}
+
+impl MailOutbox {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// 提交邮件请求
+    pub fn enqueue(&self, email: TemplatedEmail) {
+        {
+            let mut outbox = self.outbox.lock();
+            if outbox.len() >= MAX_OUTBOX {
+                tracing::warn!("Mail outbox full, dropping oldest request");
+                outbox.pop_front();
+            }
+            outbox.push_back(CloudMessage::SendEmail {
+                email: Box::new(email),
+            });
+        }
+        self.notify.notify_one();
+    }
+
+    pub async fn notified(&self) {
+        self.notify.notified().await;
+    }
+
+    /// WS 重连后唤醒 worker 补发积压请求
+    pub fn resume(&self) {
+        if !self.outbox.lock().is_empty() {
+            self.notify.notify_one();
+        }
+    }
+
+    /// 取出全部待发送消息
+    pub fn drain(&self) -> Vec<CloudMessage> {
+        self.outbox.lock().drain(..).collect()
+    }
+
+    /// 发送失败的消息放回队首 (保持原顺序)
+    pub fn requeue(&self, messages: Vec<CloudMessage>) {
+        let mut outbox = self.outbox.lock();
+        for msg in messages.into_iter().rev() {
+            outbox.push_front(msg);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use shared::cloud::email::EmailTemplateKind;
+
+    fn email(to: &str) -> TemplatedEmail {
+        TemplatedEmail {
+            kind: EmailTemplateKind::Receipt,
+            to: to.to_string(),
+            vars: Default::default(),
+        }
+    }
+
+    fn recipient(msg: &CloudMessage) -> &str {
+        let CloudMessage::SendEmail { email } = msg else {
+            panic!("Expected SendEmail");
+        };
+        &email.to
+    }
+
+    #[test]
+    fn test_requeue_keeps_order_and_cap_drops_oldest() {
+        let outbox = MailOutbox::new();
+        for i in 0..=MAX_OUTBOX {
+            outbox.enqueue(email(&format!("{i}@example.com")));
+        }
+        let drained = outbox.drain();
+        assert_eq!(drained.len(), MAX_OUTBOX);
+        assert_eq!(recipient(&drained[0]), "1@example.com");
+
+        outbox.enqueue(email("new@example.com"));
+        outbox.requeue(drained[..2].to_vec());
+        let again = outbox.drain();
+        assert_eq!(
+            again.iter().map(recipient).collect::<Vec<_>>(),
+            ["1@example.com", "2@example.com", "new@example.com"]
+        );
+    }
+}
@@ -7,12 +7,18 @@
 //!   ├── Startup: archived_order catch-up sync (cursor-based)
 //!   ├── Listen: MessageBus server broadcast (Sync events) → debounced push via WS
 //!   ├── Listen: WS incoming → RPC execution + SyncAck handling
+//!   ├── Support: 远程支持会话 outbox → WS (日志增量 + 聊天)
+//!   ├── Transfer: 门店间调拨单 / 收货确认 ↔ WS (cloud 中继到同租户门店)
+//!   ├── Mail: 邮件请求 outbox → WS (cloud 按租户模板发送)
 //!   └── Reconnect: exponential backoff on WS disconnect
 //! ```
 
+pub mod mail;
 pub mod ops;
 pub mod rpc_executor;
 mod service;
+pub mod support;
+pub mod transfer;
 mod worker;
 
 pub use service::CloudService;
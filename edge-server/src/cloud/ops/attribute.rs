@@ -3,9 +3,11 @@
 use shared::cloud::SyncResource;
 use shared::cloud::store_op::{BindingOwner, StoreOpResult};
 use shared::message::SyncChangeType;
+use shared::models::CatalogChangeAction;
 use shared::models::attribute::{AttributeCreate, AttributeUpdate};
 
 use crate::core::state::ServerState;
+use crate::services::catalog_history;
 
 // ── Attribute ──
 
@@ -356,6 +358,16 @@ pub async fn create_tag(
 
     match tag::create(&state.pool, assigned_id, data.clone()).await {
         Ok(t) => {
+            catalog_history::record(
+                &state.pool,
+                "tag",
+                t.id,
+                CatalogChangeAction::Create,
+                None,
+                Some(&t),
+                None,
+            )
+            .await;
             state
                 .broadcast_sync(
                     SyncResource::Tag,
@@ -378,8 +390,19 @@ pub async fn update_tag(
 ) -> StoreOpResult {
     use crate::db::repository::tag;
 
+    let old = tag::find_by_id(&state.pool, id).await.ok().flatten();
     match tag::update(&state.pool, id, data.clone()).await {
         Ok(t) => {
+            catalog_history::record(
+                &state.pool,
+                "tag",
+                id,
+                CatalogChangeAction::Update,
+                old.as_ref(),
+                Some(&t),
+                None,
+            )
+            .await;
             state
                 .broadcast_sync(
                     SyncResource::Tag,
@@ -399,8 +422,19 @@ pub async fn delete_tag(state: &ServerState, id: i64) -> StoreOpResult {
     use crate::db::repository::tag;
 
     // product_tag 和 category_tag 都有 ON DELETE CASCADE，无需检查引用
+    let old = tag::find_by_id(&state.pool, id).await.ok().flatten();
     match tag::delete(&state.pool, id).await {
         Ok(_) => {
+            catalog_history::record(
+                &state.pool,
+                "tag",
+                id,
+                CatalogChangeAction::Delete,
+                old.as_ref(),
+                None,
+                None,
+            )
+            .await;
             state
                 .broadcast_sync::<()>(SyncResource::Tag, SyncChangeType::Deleted, id, None, true)
                 .await;
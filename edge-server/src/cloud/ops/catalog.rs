@@ -4,11 +4,13 @@ use shared::cloud::SyncResource;
 use shared::cloud::store_op::{StoreOpData, StoreOpResult};
 use shared::message::SyncChangeType;
 use shared::models::{
+    CatalogChangeAction,
     category::{CategoryCreate, CategoryUpdate},
     product::{ProductCreate, ProductUpdate},
 };
 
 use crate::core::state::ServerState;
+use crate::services::catalog_history;
 
 // ── Product ──
 
@@ -23,6 +25,16 @@ pub async fn create_product(
         .await
     {
         Ok(p) => {
+            catalog_history::record(
+                &state.pool,
+                "product",
+                p.id,
+                CatalogChangeAction::Create,
+                None,
+                Some(&p),
+                None,
+            )
+            .await;
             state
                 .broadcast_sync(
                     SyncResource::Product,
@@ -39,8 +51,19 @@ pub async fn create_product(
 }
 
 pub async fn update_product(state: &ServerState, id: i64, data: ProductUpdate) -> StoreOpResult {
+    let old = state.catalog_service.get_product(id);
     match state.catalog_service.update_product(id, data).await {
         Ok(p) => {
+            catalog_history::record(
+                &state.pool,
+                "product",
+                id,
+                CatalogChangeAction::Update,
+                old.as_ref(),
+                Some(&p),
+                None,
+            )
+            .await;
             state
                 .broadcast_sync(
                     SyncResource::Product,
@@ -57,8 +80,19 @@ pub async fn update_product(state: &ServerState, id: i64, data: ProductUpdate) -
 }
 
 pub async fn delete_product(state: &ServerState, id: i64) -> StoreOpResult {
+    let old = state.catalog_service.get_product(id);
     match state.catalog_service.delete_product(id).await {
         Ok(()) => {
+            catalog_history::record(
+                &state.pool,
+                "product",
+                id,
+                CatalogChangeAction::Delete,
+                old.as_ref(),
+                None,
+                None,
+            )
+            .await;
             state
                 .broadcast_sync::<()>(
                     SyncResource::Product,
@@ -137,6 +171,16 @@ pub async fn create_category(
         .await
     {
         Ok(c) => {
+            catalog_history::record(
+                &state.pool,
+                "category",
+                c.id,
+                CatalogChangeAction::Create,
+                None,
+                Some(&c),
+                None,
+            )
+            .await;
             state
                 .broadcast_sync(
                     SyncResource::Category,
@@ -153,8 +197,19 @@ pub async fn create_category(
 }
 
 pub async fn update_category(state: &ServerState, id: i64, data: CategoryUpdate) -> StoreOpResult {
+    let old = state.catalog_service.get_category(id);
     match state.catalog_service.update_category(id, data).await {
         Ok(c) => {
+            catalog_history::record(
+                &state.pool,
+                "category",
+                id,
+                CatalogChangeAction::Update,
+                old.as_ref(),
+                Some(&c),
+                None,
+            )
+            .await;
             state
                 .broadcast_sync(
                     SyncResource::Category,
@@ -171,8 +226,19 @@ pub async fn update_category(state: &ServerState, id: i64, data: CategoryUpdate)
 }
 
 pub async fn delete_category(state: &ServerState, id: i64) -> StoreOpResult {
+    let old = state.catalog_service.get_category(id);
     match state.catalog_service.delete_category(id).await {
         Ok(()) => {
+            catalog_history::record(
+                &state.pool,
+                "category",
+                id,
+                CatalogChangeAction::Delete,
+                old.as_ref(),
+                None,
+                None,
+            )
+            .await;
             state
                 .broadcast_sync::<()>(
                     SyncResource::Category,
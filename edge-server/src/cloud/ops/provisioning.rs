@@ -30,6 +30,11 @@ async fn clear_catalog(state: &ServerState) -> Result<(), String> {
         .execute(pool)
         .await
         .map_err(|e| format!("clear product_tag: {e}"))?;
+    // product_print_dest (references products)
+    sqlx::query("DELETE FROM product_print_dest")
+        .execute(pool)
+        .await
+        .map_err(|e| format!("clear product_print_dest: {e}"))?;
     // product
     sqlx::query("DELETE FROM product")
         .execute(pool)
@@ -1,10 +1,12 @@
-//! Employee + Zone + DiningTable + PriceRule + LabelTemplate operations (via repository)
+//! Employee + Zone + DiningTable + PriceRule + Coupon + LabelTemplate operations (via repository)
 
 use shared::cloud::SyncResource;
 use shared::cloud::store_op::{StoreOpData, StoreOpResult};
 use shared::message::SyncChangeType;
+use shared::models::CatalogChangeAction;
 
 use crate::core::state::ServerState;
+use crate::services::catalog_history;
 
 // ── Employee ──
 
@@ -221,6 +223,16 @@ pub async fn create_price_rule(
 
     match price_rule::create(&state.pool, assigned_id, data).await {
         Ok(rule) => {
+            catalog_history::record(
+                &state.pool,
+                "price_rule",
+                rule.id,
+                CatalogChangeAction::Create,
+                None,
+                Some(&rule),
+                None,
+            )
+            .await;
             state
                 .broadcast_sync(
                     SyncResource::PriceRule,
@@ -243,8 +255,19 @@ pub async fn update_price_rule(
 ) -> StoreOpResult {
     use crate::db::repository::price_rule;
 
+    let old = price_rule::find_by_id(&state.pool, id).await.ok().flatten();
     match price_rule::update(&state.pool, id, data).await {
         Ok(rule) => {
+            catalog_history::record(
+                &state.pool,
+                "price_rule",
+                id,
+                CatalogChangeAction::Update,
+                old.as_ref(),
+                Some(&rule),
+                None,
+            )
+            .await;
             state
                 .broadcast_sync(
                     SyncResource::PriceRule,
@@ -263,8 +286,19 @@ pub async fn update_price_rule(
 pub async fn delete_price_rule(state: &ServerState, id: i64) -> StoreOpResult {
     use crate::db::repository::price_rule;
 
+    let old = price_rule::find_by_id(&state.pool, id).await.ok().flatten();
     match price_rule::delete(&state.pool, id).await {
         Ok(_) => {
+            catalog_history::record(
+                &state.pool,
+                "price_rule",
+                id,
+                CatalogChangeAction::Delete,
+                old.as_ref(),
+                None,
+                None,
+            )
+            .await;
             state
                 .broadcast_sync::<()>(
                     SyncResource::PriceRule,
@@ -280,6 +314,76 @@ pub async fn delete_price_rule(state: &ServerState, id: i64) -> StoreOpResult {
     }
 }
 
+// ── Coupon ──
+
+pub async fn create_coupon(
+    state: &ServerState,
+    assigned_id: Option<i64>,
+    data: shared::models::CouponCreate,
+) -> StoreOpResult {
+    use crate::db::repository::coupon;
+
+    match coupon::create(&state.pool, assigned_id, data).await {
+        Ok(created) => {
+            state
+                .broadcast_sync(
+                    SyncResource::Coupon,
+                    SyncChangeType::Created,
+                    created.id,
+                    Some(&created),
+                    true,
+                )
+                .await;
+            StoreOpResult::created(created.id).with_data(StoreOpData::Coupon(created))
+        }
+        Err(e) => StoreOpResult::err(e.to_string()),
+    }
+}
+
+pub async fn update_coupon(
+    state: &ServerState,
+    id: i64,
+    data: shared::models::CouponUpdate,
+) -> StoreOpResult {
+    use crate::db::repository::coupon;
+
+    match coupon::update(&state.pool, id, data).await {
+        Ok(updated) => {
+            state
+                .broadcast_sync(
+                    SyncResource::Coupon,
+                    SyncChangeType::Updated,
+                    updated.id,
+                    Some(&updated),
+                    true,
+                )
+                .await;
+            StoreOpResult::ok().with_data(StoreOpData::Coupon(updated))
+        }
+        Err(e) => StoreOpResult::err(e.to_string()),
+    }
+}
+
+pub async fn delete_coupon(state: &ServerState, id: i64) -> StoreOpResult {
+    use crate::db::repository::coupon;
+
+    match coupon::delete(&state.pool, id).await {
+        Ok(_) => {
+            state
+                .broadcast_sync::<()>(
+                    SyncResource::Coupon,
+                    SyncChangeType::Deleted,
+                    id,
+                    None,
+                    true,
+                )
+                .await;
+            StoreOpResult::ok()
+        }
+        Err(e) => StoreOpResult::err(e.to_string()),
+    }
+}
+
 // ── StoreInfo ──
 
 pub async fn update_store_info(
@@ -305,6 +409,10 @@ pub async fn update_store_info(
             state
                 .orders_manager
                 .update_business_day_cutoff(info.business_day_cutoff);
+            state.orders_manager.update_tax_mode(info.tax_mode);
+            state
+                .orders_manager
+                .update_max_cash_change(info.max_cash_change);
             StoreOpResult::ok().with_data(StoreOpData::StoreInfo(info))
         }
         Err(e) => StoreOpResult::err(e.to_string()),
@@ -74,7 +74,7 @@ pub async fn execute_rpc(state: &ServerState, rpc: &CloudRpc) -> CloudRpcResult
             }
         }
         CloudRpc::RefreshSubscription => {
-            state.activation.sync_subscription().await;
+            state.sync_subscription().await;
             CloudRpcResult::Json {
                 success: true,
                 data: Some(serde_json::json!({ "message": "Subscription refresh triggered" })),
@@ -218,6 +218,15 @@ pub async fn execute_catalog_op(
         }
         StoreOp::DeleteLabelTemplate { id } => resource::delete_label_template(state, *id).await,
 
+        // ── Coupon ──
+        StoreOp::CreateCoupon { id, data } => {
+            resource::create_coupon(state, *id, data.clone()).await
+        }
+        StoreOp::UpdateCoupon { id, data } => {
+            resource::update_coupon(state, *id, data.clone()).await
+        }
+        StoreOp::DeleteCoupon { id } => resource::delete_coupon(state, *id).await,
+
         // ── StoreInfo ──
         StoreOp::UpdateStoreInfo { data } => resource::update_store_info(state, data.clone()).await,
 
@@ -291,6 +300,7 @@ fn lww_target(op: &StoreOp) -> Option<(&'static str, i64)> {
         StoreOp::UpdateLabelTemplate { id, .. } | StoreOp::DeleteLabelTemplate { id } => {
             Some(("label_template", *id))
         }
+        StoreOp::UpdateCoupon { id, .. } | StoreOp::DeleteCoupon { id } => Some(("coupon", *id)),
         StoreOp::UpdateStoreInfo { .. } => Some(("store_info", 1)), // singleton, always id=1
         _ => None, // Create, FullSync, EnsureImage, Bind/Unbind
     }
@@ -188,6 +188,7 @@ impl CloudService {
     }
 
     /// Push a sync batch to crab-cloud via HTTP (fallback)
+    #[tracing::instrument(name = "cloud.push_batch", skip_all, fields(items = batch.items.len()))]
     pub async fn push_batch(
         &self,
         batch: CloudSyncBatch,
@@ -198,22 +199,23 @@ impl CloudService {
 
         let url = format!("{}/api/edge/sync", self.cloud_url);
 
-        let response = self
+        let mut request = self
             .client
             .post(&url)
-            .header("X-Signed-Binding", &binding_json)
-            .json(&batch)
-            .send()
-            .await
-            .map_err(|e| {
-                let mut msg = format!("Cloud sync request failed: {e}");
-                let mut source: Option<&dyn StdError> = StdError::source(&e);
-                while let Some(s) = source {
-                    msg.push_str(&format!(" → {s}"));
-                    source = s.source();
-                }
-                AppError::internal(msg)
-            })?;
+            .header("X-Signed-Binding", &binding_json);
+        if let Some(traceparent) = shared::telemetry::current_traceparent() {
+            request = request.header(shared::telemetry::TRACEPARENT_HEADER, traceparent);
+        }
+
+        let response = request.json(&batch).send().await.map_err(|e| {
+            let mut msg = format!("Cloud sync request failed: {e}");
+            let mut source: Option<&dyn StdError> = StdError::source(&e);
+            while let Some(s) = source {
+                msg.push_str(&format!(" → {s}"));
+                source = s.source();
+            }
+            AppError::internal(msg)
+        })?;
 
         if !response.status().is_success() {
             let status = response.status();
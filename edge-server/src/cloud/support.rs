@@ -0,0 +1,729 @@
+//! 远程支持 (Support relay)
+//!
+//! 门店在 POS 上开启支持会话 (需明确同意共享诊断与日志)，会话期间：
+//!
+//! - 开启时附带诊断快照 ([`collect_diagnostics`])
+//! - CloudWorker 定期读取最新日志文件的增量，脱敏后推送到 crab-cloud
+//! - 门店 ↔ 支持人员聊天消息经 WS 双向转发，支持人员的指导以通知形式显示在 POS
+//! - 到期自动结束，任一方可提前关闭
+//!
+//! 待发送消息进入 outbox，由 CloudWorker 在 WS 连接上发送；断线期间保留，
+//! 重连后先重发会话信息 (cloud 端幂等)。
+
+use std::collections::VecDeque;
+use std::io::{Read, Seek, SeekFrom};
+use std::path::{Path, PathBuf};
+
+use parking_lot::Mutex;
+use tokio::sync::Notify;
+
+use crate::core::state::ServerState;
+use crate::message::MessageBus;
+use crate::utils::{AppError, AppResult};
+use shared::cloud::CloudMessage;
+use shared::cloud::support::{
+    DEFAULT_SUPPORT_SESSION_MINUTES, MAX_SUPPORT_LOG_LINES, MAX_SUPPORT_SESSION_MINUTES,
+    SupportAuthor, SupportDiagnostics, SupportMessage, SupportSession, SupportSessionOpen,
+    SupportSessionView,
+};
+use shared::message::{NotificationCategory, NotificationPayload};
+
+/// 最短会话时长 (分钟)
+const MIN_SESSION_MINUTES: u32 = 5;
+/// 开启会话时回溯的日志量 (字节)
+const INITIAL_TAIL_BYTES: u64 = 64 * 1024;
+/// 单次读取日志上限 (字节)，超出时跳过较旧部分
+const MAX_READ_BYTES: u64 = 256 * 1024;
+/// 单行日志上限 (字符)
+const MAX_LINE_CHARS: usize = 2000;
+/// 会话内保留的聊天消息数
+const MAX_SESSION_MESSAGES: usize = 500;
+/// outbox 上限 (断线期间)，超出时先丢弃最旧的日志批次
+const MAX_OUTBOX: usize = 256;
+
+/// 被视为敏感的 key (key=value / "key":"value" 形式，值会被替换)
+const SENSITIVE_KEYS: &[&str] = &[
+    "password",
+    "passwd",
+    "pin",
+    "token",
+    "secret",
+    "api_key",
+    "apikey",
+    "authorization",
+    "cookie",
+    "nif",
+    "phone",
+    "email",
+];
+
+const REDACTED: &str = "[redacted]";
+
+#[derive(Debug)]
+struct ActiveSession {
+    session: SupportSession,
+    diagnostics: SupportDiagnostics,
+    messages: Vec<SupportMessage>,
+    /// None = 尚未读取 (首次读取回溯 INITIAL_TAIL_BYTES)
+    log_cursor: Option<LogCursor>,
+}
+
+#[derive(Debug, Clone)]
+struct LogCursor {
+    path: PathBuf,
+    offset: u64,
+}
+
+#[derive(Debug, Default)]
+struct RelayInner {
+    active: Option<ActiveSession>,
+    outbox: VecDeque<CloudMessage>,
+}
+
+impl RelayInner {
+    fn push(&mut self, msg: CloudMessage) {
+        if self.outbox.len() >= MAX_OUTBOX {
+            let oldest_logs = self
+                .outbox
+                .iter()
+                .position(|m| matches!(m, CloudMessage::SupportLogs { .. }));
+            match oldest_logs {
+                Some(idx) => {
+                    self.outbox.remove(idx);
+                }
+                None => {
+                    self.outbox.pop_front();
+                }
+            }
+        }
+        self.outbox.push_back(msg);
+    }
+
+    /// 到期则结束会话，返回已结束的会话
+    fn expire(&mut self, now: i64) -> Option<SupportSession> {
+        if !self
+            .active
+            .as_ref()
+            .is_some_and(|a| a.session.is_expired(now))
+        {
+            return None;
+        }
+        let ended = self.active.take()?.session;
+        self.push(CloudMessage::SupportSessionClosed {
+            session_id: ended.session_id,
+        });
+        Some(ended)
+    }
+}
+
+/// 支持会话中继 (同一时间最多一个会话)
+#[derive(Debug)]
+pub struct SupportRelay {
+    log_dir: PathBuf,
+    inner: Mutex<RelayInner>,
+    outbox_notify: Notify,
+}
+
+impl SupportRelay {
+    pub fn new(log_dir: PathBuf) -> Self {
+        Self {
+            log_dir,
+            inner: Mutex::new(RelayInner::default()),
+            outbox_notify: Notify::new(),
+        }
+    }
+
+    /// 开启会话 (已有进行中的会话时拒绝)
+    pub fn open(
+        &self,
+        request: &SupportSessionOpen,
+        operator_name: &str,
+        diagnostics: SupportDiagnostics,
+    ) -> AppResult<SupportSession> {
+        if !request.consent {
+            return Err(AppError::validation(
+                "Consent to share diagnostics and logs is required",
+            ));
+        }
+        let minutes = request
+            .duration_minutes
+            .unwrap_or(DEFAULT_SUPPORT_SESSION_MINUTES)
+            .clamp(MIN_SESSION_MINUTES, MAX_SUPPORT_SESSION_MINUTES);
+        let now = shared::util::now_millis();
+
+        let mut inner = self.inner.lock();
+        inner.expire(now);
+        if inner.active.is_some() {
+            return Err(AppError::conflict("A support session is already open"));
+        }
+
+        let session = SupportSession {
+            session_id: shared::util::snowflake_id(),
+            subject: request.subject.trim().to_string(),
+            opened_by: operator_name.to_string(),
+            consent_by: operator_name.to_string(),
+            opened_at: now,
+            expires_at: now + i64::from(minutes) * 60_000,
+        };
+        inner.push(CloudMessage::SupportSessionOpened {
+            session: Box::new(session.clone()),
+            diagnostics: Box::new(diagnostics.clone()),
+        });
+        inner.active = Some(ActiveSession {
+            session: session.clone(),
+            diagnostics,
+            messages: Vec::new(),
+            log_cursor: None,
+        });
+        drop(inner);
+
+        self.outbox_notify.notify_one();
+        Ok(session)
+    }
+
+    /// 当前会话及聊天记录
+    pub fn current(&self) -> Option<SupportSessionView> {
+        let mut inner = self.inner.lock();
+        if inner.expire(shared::util::now_millis()).is_some() {
+            self.outbox_notify.notify_one();
+        }
+        inner.active.as_ref().map(|a| SupportSessionView {
+            session: a.session.clone(),
+            messages: a.messages.clone(),
+        })
+    }
+
+    pub fn has_session(&self) -> bool {
+        self.inner.lock().active.is_some()
+    }
+
+    /// 门店发送消息
+    pub fn post_store_message(&self, author_name: &str, text: &str) -> AppResult<SupportMessage> {
+        let mut inner = self.inner.lock();
+        inner.expire(shared::util::now_millis());
+        let active = inner
+            .active
+            .as_mut()
+            .ok_or_else(|| AppError::not_found("Support session"))?;
+
+        let message = SupportMessage {
+            id: shared::util::snowflake_id(),
+            session_id: active.session.session_id,
+            author: SupportAuthor::Store,
+            author_name: author_name.to_string(),
+            text: text.trim().to_string(),
+            sent_at: shared::util::now_millis(),
+        };
+        push_message(&mut active.messages, message.clone());
+        inner.push(CloudMessage::SupportChat {
+            message: message.clone(),
+        });
+        drop(inner);
+
+        self.outbox_notify.notify_one();
+        Ok(message)
+    }
+
+    /// 门店结束会话
+    pub fn close(&self) -> Option<SupportSession> {
+        let mut inner = self.inner.lock();
+        let ended = inner.active.take()?.session;
+        inner.push(CloudMessage::SupportSessionClosed {
+            session_id: ended.session_id,
+        });
+        drop(inner);
+
+        self.outbox_notify.notify_one();
+        Some(ended)
+    }
+
+    /// 收到支持人员消息 (会话不匹配时丢弃)
+    pub fn receive_agent_message(&self, message: SupportMessage) -> Option<SupportMessage> {
+        let mut inner = self.inner.lock();
+        let active = inner
+            .active
+            .as_mut()
+            .filter(|a| a.session.session_id == message.session_id)?;
+        if message.author != SupportAuthor::Agent {
+            return None;
+        }
+        push_message(&mut active.messages, message.clone());
+        Some(message)
+    }
+
+    /// 支持人员结束会话
+    pub fn close_remote(&self, session_id: i64) -> Option<SupportSession> {
+        let mut inner = self.inner.lock();
+        if inner
+            .active
+            .as_ref()
+            .is_none_or(|a| a.session.session_id != session_id)
+        {
+            return None;
+        }
+        inner.active.take().map(|a| a.session)
+    }
+
+    /// WS 重连后重发进行中的会话 (排在积压消息之前)
+    pub fn resume(&self) {
+        let mut inner = self.inner.lock();
+        let Some(active) = inner.active.as_ref() else {
+            return;
+        };
+        let opened = CloudMessage::SupportSessionOpened {
+            session: Box::new(active.session.clone()),
+            diagnostics: Box::new(active.diagnostics.clone()),
+        };
+        inner
+            .outbox
+            .retain(|m| !matches!(m, CloudMessage::SupportSessionOpened { .. }));
+        inner.outbox.push_front(opened);
+        drop(inner);
+
+        self.outbox_notify.notify_one();
+    }
+
+    /// 周期任务：到期检查 + 读取日志增量 (阻塞 IO，在 spawn_blocking 中调用)
+    ///
+    /// 返回因到期而结束的会话
+    pub fn tick(&self) -> Option<SupportSession> {
+        let (session_id, cursor) = {
+            let mut inner = self.inner.lock();
+            if let Some(ended) = inner.expire(shared::util::now_millis()) {
+                drop(inner);
+                self.outbox_notify.notify_one();
+                return Some(ended);
+            }
+            let active = inner.active.as_ref()?;
+            (active.session.session_id, active.log_cursor.clone())
+        };
+
+        // 读文件时不持锁
+        let (lines, cursor) = match read_new_lines(&self.log_dir, cursor) {
+            Ok(result) => result,
+            Err(e) => {
+                tracing::debug!(dir = %self.log_dir.display(), "Support log read failed: {e}");
+                return None;
+            }
+        };
+
+        let mut inner = self.inner.lock();
+        let active = inner
+            .active
+            .as_mut()
+            .filter(|a| a.session.session_id == session_id)?;
+        active.log_cursor = cursor;
+        if lines.is_empty() {
+            return None;
+        }
+        for chunk in lines.chunks(MAX_SUPPORT_LOG_LINES) {
+            inner.push(CloudMessage::SupportLogs {
+                session_id,
+                lines: chunk.to_vec(),
+            });
+        }
+        drop(inner);
+
+        self.outbox_notify.notify_one();
+        None
+    }
+
+    /// 等待 outbox 有新消息
+    pub async fn notified(&self) {
+        self.outbox_notify.notified().await;
+    }
+
+    /// 取出全部待发送消息
+    pub fn drain(&self) -> Vec<CloudMessage> {
+        self.inner.lock().outbox.drain(..).collect()
+    }
+
+    /// 发送失败的消息放回队首 (保持原顺序)
+    pub fn requeue(&self, messages: Vec<CloudMessage>) {
+        let mut inner = self.inner.lock();
+        for msg in messages.into_iter().rev() {
+            inner.outbox.push_front(msg);
+        }
+    }
+}
+
+fn push_message(messages: &mut Vec<SupportMessage>, message: SupportMessage) {
+    if messages.len() >= MAX_SESSION_MESSAGES {
+        messages.remove(0);
+    }
+    messages.push(message);
+}
+
+/// 开启会话时的诊断快照
+pub fn collect_diagnostics(state: &ServerState) -> SupportDiagnostics {
+    let active_orders = state
+        .orders_manager
+        .get_active_orders()
+        .map(|o| o.len())
+        .unwrap_or(0);
+    SupportDiagnostics {
+        app_version: env!("CARGO_PKG_VERSION").to_string(),
+        os: std::env::consts::OS.to_string(),
+        arch: std::env::consts::ARCH.to_string(),
+        epoch: state.epoch.clone(),
+        active_orders,
+        products: state.catalog_service.list_products().len(),
+        categories: state.catalog_service.list_categories().len(),
+        readiness: serde_json::to_value(state.readiness.snapshot()).unwrap_or_default(),
+        database_bytes: std::fs::metadata(state.config.database_path())
+            .ok()
+            .map(|m| m.len()),
+        collected_at: shared::util::now_millis(),
+    }
+}
+
+/// 支持人员指导 → POS 通知 (离线终端重连补发)
+pub async fn notify_guidance(bus: &MessageBus, message: &SupportMessage) {
+    let payload = NotificationPayload::info(
+        format!("Support: {}", message.author_name),
+        message.text.clone(),
+    )
+    .with_category(NotificationCategory::System)
+    .with_data(serde_json::json!({
+        "kind": "support_message",
+        "session_id": message.session_id,
+        "message_id": message.id,
+    }));
+    if let Err(e) = bus.publish_durable(payload).await {
+        tracing::debug!("Support guidance notification not broadcast: {}", e);
+    }
+}
+
+/// 会话结束 (支持人员关闭或到期) → POS 通知
+pub async fn notify_session_ended(bus: &MessageBus, session: &SupportSession) {
+    let payload = NotificationPayload::info("Support session ended", session.subject.clone())
+        .with_category(NotificationCategory::System)
+        .with_data(serde_json::json!({
+            "kind": "support_closed",
+            "session_id": session.session_id,
+        }));
+    if let Err(e) = bus
+        .publish(shared::message::BusMessage::notification(&payload))
+        .await
+    {
+        tracing::debug!("Support session notification not broadcast: {}", e);
+    }
+}
+
+/// 读取最新日志文件的增量 (完整行，已脱敏)
+fn read_new_lines(
+    log_dir: &Path,
+    cursor: Option<LogCursor>,
+) -> std::io::Result<(Vec<String>, Option<LogCursor>)> {
+    let Some(path) = newest_log_file(log_dir)? else {
+        return Ok((Vec::new(), cursor));
+    };
+    let len = std::fs::metadata(&path)?.len();
+
+    // 首次读取 / 日志轮转 / 文件被截断 → 从末尾回溯
+    let start = match &cursor {
+        Some(c) if c.path == path && c.offset <= len => c.offset,
+        Some(c) if c.path != path => 0,
+        _ => len.saturating_sub(INITIAL_TAIL_BYTES),
+    };
+    let start = start.max(len.saturating_sub(MAX_READ_BYTES));
+    if start >= len {
+        return Ok((Vec::new(), Some(LogCursor { path, offset: len })));
+    }
+
+    let mut file = std::fs::File::open(&path)?;
+    file.seek(SeekFrom::Start(start))?;
+    let mut buf = Vec::with_capacity((len - start) as usize);
+    file.take(len - start).read_to_end(&mut buf)?;
+
+    // 只处理完整行；从中间开始读取时丢弃第一段残行
+    let Some(last_newline) = buf.iter().rposition(|&b| b == b'\n') else {
+        return Ok((
+            Vec::new(),
+            Some(LogCursor {
+                path,
+                offset: start,
+            }),
+        ));
+    };
+    let complete = &buf[..last_newline];
+    let resumed = cursor
+        .as_ref()
+        .is_some_and(|c| c.path == path && c.offset == start);
+    let text = String::from_utf8_lossy(complete);
+    let mut lines: Vec<&str> = text.lines().collect();
+    if start > 0 && !resumed && !lines.is_empty() {
+        lines.remove(0);
+    }
+
+    let lines = lines
+        .into_iter()
+        .filter(|l| !l.trim().is_empty())
+        .map(sanitize_line)
+        .collect();
+    let offset = start + last_newline as u64 + 1;
+    Ok((lines, Some(LogCursor { path, offset })))
+}
+
+/// 日志目录中最近修改的文件
+fn newest_log_file(log_dir: &Path) -> std::io::Result<Option<PathBuf>> {
+    let entries = match std::fs::read_dir(log_dir) {
+        Ok(entries) => entries,
+        Err(e) if e.kind() == std::io::ErrorKind::NotFound => return Ok(None),
+        Err(e) => return Err(e),
+    };
+    let newest = entries
+        .filter_map(Result::ok)
+        .filter_map(|entry| {
+            let meta = entry.metadata().ok()?;
+            meta.is_file().then(|| (meta.modified().ok(), entry.path()))
+        })
+        .max_by_key(|(modified, _)| *modified)
+        .map(|(_, path)| path);
+    Ok(newest)
+}
+
+/// 日志行脱敏
+///
+/// - 敏感 key 的值 (`password=…`、`"token":"…"`、`pin: …`) → `[redacted]`
+/// - `Bearer` / `Basic` 凭证、JWT → `[redacted]`
+/// - 邮箱 → `[email]`
+/// - 9–12 位连续数字 (电话号码) → `[number]`；更长的数字保留 (雪花 ID、毫秒时间戳)
+pub fn sanitize_line(line: &str) -> String {
+    let line: String = line.chars().take(MAX_LINE_CHARS).collect();
+    let mut out = Vec::new();
+    let mut redact_next = false;
+
+    for token in line.split(' ') {
+        let lower = token.to_ascii_lowercase();
+        let scheme = lower == "bearer" || lower == "basic";
+        if redact_next && !token.is_empty() && !scheme {
+            out.push(REDACTED.to_string());
+            redact_next = false;
+            continue;
+        }
+        if scheme {
+            out.push(token.to_string());
+            redact_next = true;
+            continue;
+        }
+        if let Some((key, sep, value)) = split_key_value(token)
+            && is_sensitive_key(key)
+        {
+            if value
+                .trim_matches(|c: char| c == '"' || c == ',')
+                .is_empty()
+            {
+                // `password: secret` — 值在下一个 token
+                redact_next = true;
+                out.push(token.to_string());
+            } else {
+                out.push(format!("{key}{sep}{REDACTED}"));
+            }
+            continue;
+        }
+        if is_jwt(token) {
+            out.push(REDACTED.to_string());
+        } else if is_email(token) {
+            out.push("[email]".to_string());
+        } else {
+            out.push(redact_digit_runs(token));
+        }
+    }
+    out.join(" ")
+}
+
+/// `key=value` / `key:value` / `"key":"value"` → (key, 分隔符, value)
+fn split_key_value(token: &str) -> Option<(&str, char, &str)> {
+    let idx = token.find(['=', ':'])?;
+    let sep = token[idx..].chars().next()?;
+    let (key, value) = (&token[..idx], &token[idx + sep.len_utf8()..]);
+    // URL (https://…) 不是 key-value
+    if value.starts_with("//") {
+        return None;
+    }
+    Some((key, sep, value))
+}
+
+fn is_sensitive_key(key: &str) -> bool {
+    let key = key
+        .trim_matches(|c: char| !c.is_ascii_alphanumeric() && c != '_')
+        .to_ascii_lowercase();
+    !key.is_empty() && SENSITIVE_KEYS.iter().any(|s| key.ends_with(s))
+}
+
+fn is_jwt(token: &str) -> bool {
+    token.contains("eyJ") && token.matches('.').count() >= 2
+}
+
+fn is_email(token: &str) -> bool {
+    let Some((local, domain)) = token.split_once('@') else {
+        return false;
+    };
+    let local = local.trim_start_matches(|c: char| !c.is_alphanumeric());
+    !local.is_empty()
+        && domain
+            .split_once('.')
+            .is_some_and(|(host, tld)| !host.is_empty() && tld.chars().any(char::is_alphabetic))
+}
+
+fn redact_digit_runs(token: &str) -> String {
+    let mut out = String::with_capacity(token.len());
+    let mut run = String::new();
+    let flush = |run: &mut String, out: &mut String| {
+        if (9..=12).contains(&run.len()) {
+            out.push_str("[number]");
+        } else {
+            out.push_str(run);
+        }
+        run.clear();
+    };
+    for c in token.chars() {
+        if c.is_ascii_digit() {
+            run.push(c);
+        } else {
+            flush(&mut run, &mut out);
+            out.push(c);
+        }
+    }
+    flush(&mut run, &mut out);
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn diagnostics() -> SupportDiagnostics {
+        SupportDiagnostics {
+            app_version: "1.0.0".into(),
+            os: "linux".into(),
+            arch: "x86_64".into(),
+            epoch: "epoch".into(),
+            active_orders: 0,
+            products: 0,
+            categories: 0,
+            readiness: serde_json::Value::Null,
+            database_bytes: None,
+            collected_at: 0,
+        }
+    }
+
+    fn open_request(consent: bool) -> SupportSessionOpen {
+        SupportSessionOpen {
+            subject: "Printer offline".into(),
+            consent,
+            duration_minutes: None,
+        }
+    }
+
+    #[test]
+    fn test_sanitize_line() {
+        assert_eq!(
+            sanitize_line("login user=ana password=hunter2 ok"),
+            "login user=ana password=[redacted] ok"
+        );
+        assert_eq!(
+            sanitize_line(r#"body {"pin":"1234","name":"Ana"}"#),
+            r#"body {"pin":[redacted]"#
+        );
+        assert_eq!(
+            sanitize_line("Authorization: Bearer abc.def"),
+            "Authorization: Bearer [redacted]"
+        );
+        assert_eq!(
+            sanitize_line("header bearer eyJhbGciOi.eyJzdWIi.sig"),
+            "header bearer [redacted]"
+        );
+        assert_eq!(
+            sanitize_line("sent to ana@example.com and +34600123456"),
+            "sent to [email] and +[number]"
+        );
+        // 雪花 ID 与 URL 保留
+        assert_eq!(
+            sanitize_line("order_id=7312345678901234567 url=https://cloud.example"),
+            "order_id=7312345678901234567 url=https://cloud.example"
+        );
+    }
+
+    #[test]
+    fn test_open_requires_consent_and_single_session() {
+        let relay = SupportRelay::new(PathBuf::from("/nonexistent"));
+        assert!(
+            relay
+                .open(&open_request(false), "Ana", diagnostics())
+                .is_err()
+        );
+
+        let session = relay
+            .open(&open_request(true), "Ana", diagnostics())
+            .unwrap();
+        assert!(
+            relay
+                .open(&open_request(true), "Ana", diagnostics())
+                .is_err()
+        );
+        assert!(matches!(
+            relay.drain().as_slice(),
+            [CloudMessage::SupportSessionOpened { .. }]
+        ));
+
+        relay
+            .post_store_message("Ana", "It stopped printing")
+            .unwrap();
+        let agent = SupportMessage {
+            id: 1,
+            session_id: session.session_id,
+            author: SupportAuthor::Agent,
+            author_name: "Support".into(),
+            text: "Check the cable".into(),
+            sent_at: 0,
+        };
+        assert!(relay.receive_agent_message(agent.clone()).is_some());
+        // 其他会话的消息丢弃
+        let stale = SupportMessage {
+            session_id: session.session_id + 1,
+            ..agent
+        };
+        assert!(relay.receive_agent_message(stale).is_none());
+        assert_eq!(relay.current().unwrap().messages.len(), 2);
+
+        assert!(relay.close().is_some());
+        assert!(relay.current().is_none());
+        assert!(matches!(
+            relay.drain().as_slice(),
+            [
+                CloudMessage::SupportChat { .. },
+                CloudMessage::SupportSessionClosed { .. }
+            ]
+        ));
+    }
+
+    #[test]
+    fn test_log_tail_streams_new_complete_lines() {
+        let dir = tempfile::tempdir().unwrap();
+        let log = dir.path().join("edge-server.2026-10-15");
+        std::fs::write(&log, "first token=abc\nsecond\npartial").unwrap();
+
+        let relay = SupportRelay::new(dir.path().to_path_buf());
+        relay
+            .open(&open_request(true), "Ana", diagnostics())
+            .unwrap();
+        relay.drain();
+
+        relay.tick();
+        let batches = relay.drain();
+        let [CloudMessage::SupportLogs { lines, .. }] = batches.as_slice() else {
+            panic!("expected one log batch");
+        };
+        assert_eq!(lines, &["first token=[redacted]", "second"]);
+
+        std::fs::write(&log, "first token=abc\nsecond\npartial line\nthird\n").unwrap();
+        relay.tick();
+        let batches = relay.drain();
+        let [CloudMessage::SupportLogs { lines, .. }] = batches.as_slice() else {
+            panic!("expected one log batch");
+        };
+        assert_eq!(lines, &["partial line", "third"]);
+    }
+}
@@ -0,0 +1,131 @@
+//! 门店间调拨中继
+//!
+//! 调拨单与收货确认持久化在 `stock_transfer` (`relay_pending = 1`)，由
+//! CloudWorker 在 WS 连接上发送；断线期间保留，重连后补发。cloud 转发来的
+//! 调拨单 / 收货确认处理后回复 `StockTransferAck`，未确认的由 cloud 重发。
+//! 可选的收货门店列表由 cloud 在连接建立后推送 (`TransferPeers`)。
+
+use parking_lot::RwLock;
+use tokio::sync::Notify;
+
+use shared::cloud::CloudMessage;
+use shared::cloud::transfer::{
+    TransferDispatch, TransferDispatchLine, TransferPeer, TransferReceipt, TransferReceiptLine,
+};
+use shared::message::{NotificationCategory, NotificationPayload};
+use shared::models::{StockTransfer, StockTransferDirection, StockTransferStatus};
+
+use crate::message::MessageBus;
+
+#[derive(Debug, Default)]
+pub struct TransferRelay {
+    peers: RwLock<Vec<TransferPeer>>,
+    notify: Notify,
+}
+
+impl TransferRelay {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Stores of the tenant that can receive transfers (empty until cloud connects)
+    pub fn peers(&self) -> Vec<TransferPeer> {
+        self.peers.read().clone()
+    }
+
+    pub fn find_peer(&self, store_id: i64) -> Option<TransferPeer> {
+        self.peers
+            .read()
+            .iter()
+            .find(|p| p.store_id == store_id)
+            .cloned()
+    }
+
+    pub fn set_peers(&self, stores: Vec<TransferPeer>) {
+        *self.peers.write() = stores;
+    }
+
+    /// 有新的调拨单 / 收货确认待发送
+    pub fn wake(&self) {
+        self.notify.notify_one();
+    }
+
+    pub async fn notified(&self) {
+        self.notify.notified().await;
+    }
+}
+
+/// 待发往 cloud 的消息 (发出的调拨单 / 已确认的收货)
+pub fn relay_message(transfer: &StockTransfer) -> Option<CloudMessage> {
+    match (transfer.direction, transfer.status) {
+        (StockTransferDirection::Out, _) => Some(CloudMessage::StockTransferDispatched {
+            dispatch: Box::new(TransferDispatch {
+                transfer_id: transfer.id,
+                store_id: transfer.peer_store_id,
+                store_name: transfer.peer_store_name.clone(),
+                note: transfer.note.clone(),
+                sent_by: transfer.sent_by.clone(),
+                sent_at: transfer.sent_at,
+                lines: transfer
+                    .lines
+                    .iter()
+                    .map(|l| TransferDispatchLine {
+                        line_no: l.line_no,
+                        product_name: l.product_name.clone(),
+                        spec_name: l.spec_name.clone(),
+                        barcode: l.barcode.clone(),
+                        quantity: l.quantity,
+                    })
+                    .collect(),
+            }),
+        }),
+        (StockTransferDirection::In, StockTransferStatus::Received) => {
+            Some(CloudMessage::StockTransferReceived {
+                receipt: Box::new(TransferReceipt {
+                    transfer_id: transfer.id,
+                    store_id: transfer.peer_store_id,
+                    received_by: transfer.received_by.clone().unwrap_or_default(),
+                    received_at: transfer.received_at.unwrap_or(transfer.sent_at),
+                    lines: transfer
+                        .lines
+                        .iter()
+                        .map(|l| TransferReceiptLine {
+                            line_no: l.line_no,
+                            received_quantity: l.received_quantity.unwrap_or(0),
+                        })
+                        .collect(),
+                }),
+            })
+        }
+        (StockTransferDirection::In, StockTransferStatus::InTransit) => None,
+    }
+}
+
+/// 调拨单到达 / 对方已收货通知
+pub async fn notify_transfer(bus: &MessageBus, transfer: &StockTransfer) {
+    let (title, body) = match transfer.direction {
+        StockTransferDirection::In => (
+            "Incoming stock transfer",
+            format!(
+                "{}: {} items in transit",
+                transfer.peer_store_name,
+                transfer.lines.len()
+            ),
+        ),
+        StockTransferDirection::Out => (
+            "Stock transfer received",
+            format!("{} confirmed receipt", transfer.peer_store_name),
+        ),
+    };
+    let payload = NotificationPayload::info(title, body)
+        .with_category(NotificationCategory::Business)
+        .with_data(serde_json::json!({
+            "kind": "stock_transfer",
+            "stock_transfer_id": transfer.id,
+            "direction": transfer.direction,
+            "status": transfer.status,
+        }));
+    if let Err(e) = bus.publish_durable(payload).await {
+        tracing::debug!("Stock transfer notification not broadcast: {}", e);
+    }
+}
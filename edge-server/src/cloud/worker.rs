@@ -4,7 +4,7 @@
 //! 2. Wait for Welcome{cursors} → compare with local ResourceVersions → incremental sync
 //! 3. Catch-up sync via HTTP: archived orders, credit notes, invoices, anulaciones (chain_entry order)
 //! 4. Listen for MessageBus broadcasts → debounced push via WS (products/categories)
-//! 5. Listen for WS incoming → Command execution (cloud→edge only)
+//! 5. Listen for WS incoming → Command execution (cloud→edge only) + relayed stock transfers
 //! 6. Reconnect with exponential backoff on disconnect
 
 use futures::{SinkExt, StreamExt};
@@ -19,7 +19,9 @@ use tokio_util::sync::CancellationToken;
 
 use crate::cloud::service::CloudService;
 use crate::core::state::ServerState;
-use crate::db::repository::{chain_entry, credit_note, invoice, order};
+use crate::db::repository::{
+    RepoError, chain_entry, credit_note, invoice, order, receipt_artifact, stock_transfer,
+};
 
 /// Debounce window for batching changes
 const DEBOUNCE_MS: u64 = 500;
@@ -33,10 +35,15 @@ const MAX_RECONNECT_DELAY_SECS: u64 = 120;
 const MAX_AUTH_FAIL_DELAY_SECS: u64 = 1800; // 30 minutes
 /// Archived order sync batch size
 const ARCHIVED_ORDER_BATCH_SIZE: i64 = 50;
+
+/// Receipt artifacts per HTTP batch (payloads carry the printed bytes)
+const RECEIPT_ARTIFACT_BATCH_SIZE: i64 = 20;
 /// Archived order sync interval (aggregate before pushing)
 const ARCHIVED_ORDER_SYNC_INTERVAL_SECS: u64 = 300; // 5 minutes
 /// WebSocket keepalive ping interval
 const WS_PING_INTERVAL_SECS: u64 = 30;
+/// 远程支持会话：日志增量读取 + 到期检查间隔
+const SUPPORT_TICK_SECS: u64 = 5;
 
 /// Add random jitter (0..50% of delay) to prevent thundering herd
 fn with_jitter(delay: Duration) -> Duration {
@@ -233,6 +240,15 @@ impl CloudWorker {
             tokio::time::interval(Duration::from_secs(ARCHIVED_ORDER_SYNC_INTERVAL_SECS));
         archived_order_sync_interval.tick().await; // skip immediate tick (already did catch-up above)
 
+        // 6. 门店间调拨：重发断线期间未中继的调拨单 / 收货确认
+        self.state.transfers.wake();
+
+        // 7. 远程支持：重发进行中的会话 + 断线期间积压的消息
+        self.state.support.resume();
+        self.state.mail.resume();
+        let mut support_interval = tokio::time::interval(Duration::from_secs(SUPPORT_TICK_SECS));
+        support_interval.set_missed_tick_behavior(tokio::time::MissedTickBehavior::Skip);
+
         let mut pending: HashMap<SyncResource, HashMap<i64, CloudSyncItem>> = HashMap::new();
         let mut debounce_deadline: Option<Instant> = None;
 
@@ -277,6 +293,35 @@ impl CloudWorker {
                     self.sync_archives_http("archive-triggered").await;
                 }
 
+                // 调拨单 / 收货确认待发送
+                _ = self.state.transfers.notified() => {
+                    if !self.flush_transfers(&mut ws_sink).await {
+                        return;
+                    }
+                }
+
+                // 远程支持：到期检查 + 日志增量 (阻塞文件 IO)
+                _ = support_interval.tick(), if self.state.support.has_session() => {
+                    let relay = self.state.support.clone();
+                    if let Ok(Some(ended)) = tokio::task::spawn_blocking(move || relay.tick()).await {
+                        super::support::notify_session_ended(self.state.message_bus(), &ended).await;
+                    }
+                }
+
+                // 远程支持：会话 / 聊天 / 日志消息待发送
+                _ = self.state.support.notified() => {
+                    if !self.flush_support(&mut ws_sink).await {
+                        return;
+                    }
+                }
+
+                // 邮件请求待发送
+                _ = self.state.mail.notified() => {
+                    if !self.flush_mail(&mut ws_sink).await {
+                        return;
+                    }
+                }
+
                 // MessageBus broadcast → buffer for debounce (products, categories, etc.)
                 result = broadcast_rx.recv() => {
                     match result {
@@ -435,12 +480,184 @@ impl CloudWorker {
                     tracing::debug!(accepted, "SyncAck OK");
                 }
             }
+            CloudMessage::SupportChat { message } => {
+                match self.state.support.receive_agent_message(message) {
+                    Some(message) => {
+                        super::support::notify_guidance(self.state.message_bus(), &message).await;
+                    }
+                    None => tracing::debug!("Ignoring support message for inactive session"),
+                }
+            }
+            CloudMessage::SupportSessionClosed { session_id } => {
+                if let Some(ended) = self.state.support.close_remote(session_id) {
+                    tracing::info!(session_id, "Support session closed by agent");
+                    super::support::notify_session_ended(self.state.message_bus(), &ended).await;
+                }
+            }
+            CloudMessage::TransferPeers { stores } => {
+                self.state.transfers.set_peers(stores);
+            }
+            CloudMessage::StockTransferDispatched { dispatch } => {
+                let transfer_id = dispatch.transfer_id;
+                match stock_transfer::record_incoming(&self.state.pool, &dispatch).await {
+                    Ok(incoming) => {
+                        if let Some(transfer) = incoming {
+                            tracing::info!(transfer_id, "Incoming stock transfer recorded");
+                            super::transfer::notify_transfer(self.state.message_bus(), &transfer)
+                                .await;
+                        }
+                        Self::ack_transfer(ws_sink, transfer_id).await;
+                    }
+                    // Not acked: cloud re-delivers on reconnect
+                    Err(e) => tracing::error!(transfer_id, "Failed to record stock transfer: {e}"),
+                }
+            }
+            CloudMessage::StockTransferReceived { receipt } => {
+                let transfer_id = receipt.transfer_id;
+                match stock_transfer::apply_receipt(&self.state.pool, &receipt).await {
+                    Ok(closed) => {
+                        if let Some((transfer, changed)) = closed {
+                            tracing::info!(transfer_id, "Stock transfer receipt applied");
+                            // Shortfall written back to local levels
+                            for (level, _) in changed {
+                                self.state
+                                    .broadcast_sync(
+                                        crate::inventory::RESOURCE,
+                                        SyncChangeType::Updated,
+                                        level.id,
+                                        Some(&level),
+                                        false,
+                                    )
+                                    .await;
+                            }
+                            super::transfer::notify_transfer(self.state.message_bus(), &transfer)
+                                .await;
+                        }
+                        Self::ack_transfer(ws_sink, transfer_id).await;
+                    }
+                    Err(RepoError::NotFound(_) | RepoError::Validation(_)) => {
+                        tracing::warn!(transfer_id, "Ignoring receipt for unknown stock transfer");
+                        Self::ack_transfer(ws_sink, transfer_id).await;
+                    }
+                    Err(e) => tracing::error!(transfer_id, "Failed to apply transfer receipt: {e}"),
+                }
+            }
             _ => {
                 tracing::debug!("Ignoring unexpected CloudMessage variant from cloud");
             }
         }
     }
 
+    /// 发送远程支持 outbox (失败的消息放回队首，重连后重发)
+    ///
+    /// 返回 false 表示 WS 已断开
+    async fn flush_support<S>(&self, ws_sink: &mut S) -> bool
+    where
+        S: futures::Sink<Message, Error = tokio_tungstenite::tungstenite::Error> + Unpin,
+    {
+        let mut outbox = self.state.support.drain().into_iter();
+        while let Some(msg) = outbox.next() {
+            let json = match serde_json::to_string(&msg) {
+                Ok(json) => json,
+                Err(e) => {
+                    tracing::error!("Failed to serialize support message: {e}");
+                    continue;
+                }
+            };
+            if ws_sink.send(Message::Text(json.into())).await.is_err() {
+                tracing::warn!("WS send support message failed, disconnecting");
+                let mut unsent = vec![msg];
+                unsent.extend(outbox);
+                self.state.support.requeue(unsent);
+                return false;
+            }
+        }
+        true
+    }
+
+    /// 发送待中继的调拨单 / 收货确认 (发送成功后清除标记，失败的重连后重发)
+    ///
+    /// 返回 false 表示 WS 已断开
+    async fn flush_transfers<S>(&self, ws_sink: &mut S) -> bool
+    where
+        S: futures::Sink<Message, Error = tokio_tungstenite::tungstenite::Error> + Unpin,
+    {
+        let pending = match stock_transfer::find_relay_pending(&self.state.pool).await {
+            Ok(pending) => pending,
+            Err(e) => {
+                tracing::error!("Failed to load pending stock transfers: {e}");
+                return true;
+            }
+        };
+        for transfer in pending {
+            let Some(msg) = super::transfer::relay_message(&transfer) else {
+                continue;
+            };
+            let json = match serde_json::to_string(&msg) {
+                Ok(json) => json,
+                Err(e) => {
+                    tracing::error!(
+                        transfer_id = transfer.id,
+                        "Failed to serialize stock transfer: {e}"
+                    );
+                    continue;
+                }
+            };
+            if ws_sink.send(Message::Text(json.into())).await.is_err() {
+                tracing::warn!("WS send stock transfer failed, disconnecting");
+                return false;
+            }
+            if let Err(e) = stock_transfer::clear_relay_pending(&self.state.pool, transfer.id).await
+            {
+                tracing::warn!(
+                    transfer_id = transfer.id,
+                    "Failed to clear transfer relay flag: {e}"
+                );
+            }
+        }
+        true
+    }
+
+    /// 确认已处理 cloud 转发的调拨消息
+    async fn ack_transfer<S>(ws_sink: &mut S, transfer_id: i64)
+    where
+        S: futures::Sink<Message, Error = tokio_tungstenite::tungstenite::Error> + Unpin,
+    {
+        let ack = CloudMessage::StockTransferAck { transfer_id };
+        if let Ok(json) = serde_json::to_string(&ack)
+            && let Err(e) = ws_sink.send(Message::Text(json.into())).await
+        {
+            tracing::warn!(transfer_id, "Failed to ack stock transfer: {e}");
+        }
+    }
+
+    /// 发送邮件请求 outbox (失败的消息放回队首，重连后重发)
+    ///
+    /// 返回 false 表示 WS 已断开
+    async fn flush_mail<S>(&self, ws_sink: &mut S) -> bool
+    where
+        S: futures::Sink<Message, Error = tokio_tungstenite::tungstenite::Error> + Unpin,
+    {
+        let mut outbox = self.state.mail.drain().into_iter();
+        while let Some(msg) = outbox.next() {
+            let json = match serde_json::to_string(&msg) {
+                Ok(json) => json,
+                Err(e) => {
+                    tracing::error!("Failed to serialize mail request: {e}");
+                    continue;
+                }
+            };
+            if ws_sink.send(Message::Text(json.into())).await.is_err() {
+                tracing::warn!("WS send mail request failed, disconnecting");
+                let mut unsent = vec![msg];
+                unsent.extend(outbox);
+                self.state.mail.requeue(unsent);
+                return false;
+            }
+        }
+        true
+    }
+
     /// Handle a strongly-typed RPC payload
     async fn handle_rpc(&self, payload: &shared::cloud::CloudRpc) -> shared::cloud::CloudRpcResult {
         use shared::cloud::CloudRpcResult;
@@ -506,7 +723,7 @@ impl CloudWorker {
                 }
             }
             shared::cloud::CloudRpc::RefreshSubscription => {
-                self.state.activation.sync_subscription().await;
+                self.state.sync_subscription().await;
                 CloudRpcResult::Json {
                     success: true,
                     data: Some(serde_json::json!({ "message": "Subscription refresh triggered" })),
@@ -562,6 +779,7 @@ impl CloudWorker {
     }
 
     /// Send initial sync based on cloud cursors — only send resources where local version > cursor
+    #[tracing::instrument(name = "cloud.initial_sync", skip_all)]
     async fn send_initial_sync<S>(
         &mut self,
         cursors: &HashMap<String, u64>,
@@ -697,6 +915,7 @@ impl CloudWorker {
     ///
     /// Order layer: unified chain_entry sync (ORDER + CREDIT_NOTE + ANULACION + UPGRADE + BREAK)
     /// Invoice layer: independent invoice sync (huella chain)
+    #[tracing::instrument(name = "cloud.sync_archives", skip(self))]
     async fn sync_archives_http(&mut self, trigger: &str) {
         if let Err(e) = self.sync_chain_entries_http().await {
             tracing::warn!("{trigger}: chain entry sync failed: {e}");
@@ -704,6 +923,126 @@ impl CloudWorker {
         if let Err(e) = self.sync_invoices_http().await {
             tracing::warn!("{trigger}: invoice sync failed: {e}");
         }
+        if let Err(e) = self.sync_receipt_artifacts_http().await {
+            tracing::warn!("{trigger}: receipt archive sync failed: {e}");
+        }
+    }
+
+    /// Sync archived receipt bytes to cloud (only when the store enabled `receipt_archive_cloud`).
+    ///
+    /// Cloud stores artifacts write-once, so re-sending after a lost response is harmless.
+    /// Unreadable or tampered files are logged and skipped to unblock the queue.
+    async fn sync_receipt_artifacts_http(&mut self) -> Result<(), crate::utils::AppError> {
+        use base64::Engine;
+
+        let info = crate::db::repository::store_info::get(&self.state.pool)
+            .await
+            .map_err(|e| crate::utils::AppError::internal(format!("Load store info: {e}")))?;
+        if !info.is_some_and(|i| i.receipt_archive_cloud) {
+            return Ok(());
+        }
+
+        let binding = self.get_binding().await?;
+        let archive =
+            crate::archiving::ReceiptArchive::new(self.state.config.receipt_archive_dir());
+
+        loop {
+            let artifacts =
+                receipt_artifact::list_unsynced(&self.state.pool, RECEIPT_ARTIFACT_BATCH_SIZE)
+                    .await
+                    .map_err(|e| {
+                        crate::utils::AppError::internal(format!(
+                            "List unsynced receipt artifacts: {e}"
+                        ))
+                    })?;
+            if artifacts.is_empty() {
+                break;
+            }
+
+            let mut items: Vec<CloudSyncItem> = Vec::with_capacity(artifacts.len());
+            let mut skipped_ids: Vec<i64> = Vec::new();
+            for artifact in &artifacts {
+                let content = match archive.read(artifact).await {
+                    Ok(content) => content,
+                    Err(e) => {
+                        tracing::error!(
+                            artifact_id = artifact.id,
+                            receipt_number = %artifact.receipt_number,
+                            "Receipt artifact unreadable, skipping cloud sync: {e}"
+                        );
+                        skipped_ids.push(artifact.id);
+                        continue;
+                    }
+                };
+                let sync = shared::cloud::sync::ReceiptArtifactSync {
+                    id: artifact.id,
+                    receipt_number: artifact.receipt_number.clone(),
+                    kind: artifact.kind,
+                    sha256: artifact.sha256.clone(),
+                    reprint: artifact.reprint,
+                    content_base64: base64::engine::general_purpose::STANDARD.encode(&content),
+                    created_at: artifact.created_at,
+                };
+                match serde_json::to_value(&sync) {
+                    Ok(data) => items.push(CloudSyncItem {
+                        resource: SyncResource::ReceiptArtifact,
+                        version: artifact.id as u64,
+                        action: shared::cloud::SyncAction::Upsert,
+                        resource_id: artifact.id,
+                        data,
+                    }),
+                    Err(e) => {
+                        tracing::error!(
+                            artifact_id = artifact.id,
+                            "Serialize receipt artifact: {e}"
+                        );
+                        skipped_ids.push(artifact.id);
+                    }
+                }
+            }
+
+            if !items.is_empty() {
+                let batch = CloudSyncBatch {
+                    edge_id: self.cloud_service.edge_id().to_string(),
+                    items,
+                    sent_at: shared::util::now_millis(),
+                    counter_state: None,
+                };
+                let response = self
+                    .cloud_service
+                    .push_batch(batch, &binding)
+                    .await
+                    .map_err(|e| {
+                        crate::utils::AppError::internal(format!(
+                            "HTTP sync receipt artifacts: {e}"
+                        ))
+                    })?;
+                for err in &response.errors {
+                    tracing::error!(
+                        resource_id = %err.resource_id,
+                        "Receipt artifact rejected by cloud: {}", err.message
+                    );
+                }
+            }
+
+            if !skipped_ids.is_empty() {
+                tracing::warn!(
+                    count = skipped_ids.len(),
+                    ids = ?skipped_ids,
+                    "Skipped unreadable receipt artifacts, marking as synced"
+                );
+            }
+            let ids: Vec<i64> = artifacts.iter().map(|a| a.id).collect();
+            if let Err(e) = receipt_artifact::mark_synced(&self.state.pool, &ids).await {
+                tracing::error!("Failed to mark receipt artifacts as cloud_synced: {e}");
+                break;
+            }
+            if (artifacts.len() as i64) < RECEIPT_ARTIFACT_BATCH_SIZE {
+                break;
+            }
+        }
+
+        Ok(())
     }
 
     /// Unified chain entry sync — processes all chain_entry types in strict id order.
@@ -1340,7 +1679,8 @@ impl CloudWorker {
         items: &mut Vec<CloudSyncItem>,
     ) {
         use crate::db::repository::{
-            attribute, dining_table, employee, label_template, price_rule, store_info, tag, zone,
+            attribute, coupon, dining_table, employee, label_template, price_rule, store_info, tag,
+            zone,
         };
 
         /// Push serializable records with an `id` field into sync items
@@ -1409,6 +1749,14 @@ impl CloudWorker {
                 Ok(v) => push_many(&v, resource, version, |z| z.id, items),
                 Err(e) => tracing::warn!(resource = %resource, "Failed to collect for sync: {e}"),
             },
+            SyncResource::ZoneProductOverride => {
+                match zone::find_all_overrides(&self.state.pool).await {
+                    Ok(v) => push_many(&v, resource, version, |o| o.id, items),
+                    Err(e) => {
+                        tracing::warn!(resource = %resource, "Failed to collect for sync: {e}")
+                    }
+                }
+            }
             SyncResource::DiningTable => match dining_table::find_all(&self.state.pool).await {
                 Ok(v) => push_many(&v, resource, version, |t| t.id, items),
                 Err(e) => tracing::warn!(resource = %resource, "Failed to collect for sync: {e}"),
@@ -1429,6 +1777,10 @@ impl CloudWorker {
                 Ok(v) => push_many(&v, resource, version, |t| t.id, items),
                 Err(e) => tracing::warn!(resource = %resource, "Failed to collect for sync: {e}"),
             },
+            SyncResource::Coupon => match coupon::find_all(&self.state.pool).await {
+                Ok(v) => push_many(&v, resource, version, |c| c.id, items),
+                Err(e) => tracing::warn!(resource = %resource, "Failed to collect for sync: {e}"),
+            },
             SyncResource::StoreInfo => {
                 match store_info::get(&self.state.pool).await {
                     Ok(Some(info)) => match serde_json::to_value(&info) {
@@ -1456,6 +1808,7 @@ impl CloudWorker {
     }
 
     /// Full sync via HTTP POST (fallback when WS unavailable)
+    #[tracing::instrument(name = "cloud.full_sync", skip_all)]
     async fn full_sync_http(&mut self) -> Result<(), crate::utils::AppError> {
         tracing::info!("Starting full cloud sync via HTTP fallback");
         let items = self.collect_all_sync_items().await;
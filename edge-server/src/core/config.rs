@@ -1,14 +1,19 @@
+use std::collections::BTreeMap;
 use std::path::PathBuf;
 
+use super::profile::{ConfigError, ConfigLoader, ConfigSource, Profile};
 use crate::auth::JwtConfig;
+use crate::fiscal::FiscalPolicy;
+use crate::message::OverflowPolicy;
 use chrono_tz::Tz;
+use shared::telemetry::OtlpConfig;
 
 /// 服务器配置 - 边缘节点的所有配置项
 ///
 /// # 创建方式
 ///
 /// - `Config::builder()` - Builder 模式 (推荐)
-/// - `Config::from_env()` - 从环境变量加载 (仅用于 bin，需先调用 dotenv)
+/// - `Config::load()` - 分层加载 Profile 文件 / 环境变量 (仅用于 bin，需先调用 dotenv)
 ///
 /// # 示例
 ///
@@ -34,6 +39,10 @@ pub struct Config {
     pub jwt: JwtConfig,
     /// 运行环境: development | staging | production
     pub environment: String,
+    /// 部署 Profile (dev / staging / prod / kiosk)
+    pub profile: Profile,
+    /// 强制 mTLS 客户端证书 (仅 dev Profile 可关闭)
+    pub require_client_cert: bool,
     /// 认证服务器 URL (用于边缘激活)
     pub auth_server_url: String,
     /// 最大并发连接数
@@ -46,6 +55,46 @@ pub struct Config {
     pub timezone: Tz,
     /// Cloud sync URL (None = disabled)
     pub cloud_url: Option<String>,
+    /// 活跃订单缓存一致性校验 (每次读取与 redb 比对，排障用)
+    pub orders_cache_verify: bool,
+    /// 订单事件追加日志 (独立于 redb，可在 redb 损坏时重建订单)
+    pub event_journal: bool,
+    /// 实时经营指标推送间隔 (秒，0 = 禁用)
+    pub kpi_interval_secs: u64,
+    /// 资源看门狗采样间隔 (秒，0 = 禁用)
+    pub resource_watchdog_interval_secs: u64,
+    /// 公开 `/status` 端点 (第三方平台轮询可用性，默认关闭)
+    pub public_status: bool,
+    /// 酒店 PMS 地址 (挂房账，None = 禁用)
+    pub pms_url: Option<String>,
+    /// 酒店 PMS API key (Bearer)
+    pub pms_api_key: Option<String>,
+    /// 短信 / WhatsApp 网关地址 (取餐通知，None = 禁用)
+    pub notify_gateway_url: Option<String>,
+    /// 消息网关 API key (Bearer，亦用于校验送达回执)
+    pub notify_gateway_api_key: Option<String>,
+    /// TCP 客户端出站队列容量 (每连接)
+    pub client_queue_capacity: usize,
+    /// 出站队列满时的处理策略
+    pub client_queue_overflow: OverflowPolicy,
+    /// 订单命令耗时超过此值 (毫秒) 时拒绝低优先级请求 (0 = 只限队列)
+    pub load_shed_latency_ms: u64,
+    /// 订单命令 redb 事务 (等待写者 + 提交) 超过此值 (毫秒) 时告警 (0 = 禁用)
+    pub commit_latency_alert_ms: u64,
+    /// 日志目录 (远程支持读取，None = `{work_dir}/logs`)
+    pub log_dir: Option<String>,
+    /// OTLP 链路追踪导出 (None = 仅本地日志；需 `otel` feature)
+    pub otlp: Option<OtlpConfig>,
+    /// redb 事件日志保留天数：超期的终态订单归档后截断 (0 = 禁用压缩)
+    pub event_retention_days: u64,
+    /// 税控设备地址 (`http(s)://...` 或 `serial:/dev/ttyUSB0`，None = 禁用)
+    pub fiscal_device_url: Option<String>,
+    /// 税控设备 API key (Bearer，仅 HTTP)
+    pub fiscal_device_api_key: Option<String>,
+    /// 税控设备不可用时的结单策略
+    pub fiscal_policy: FiscalPolicy,
+    /// 各配置项来源 (分层加载时记录，未出现的键为内置默认值)
+    pub config_sources: BTreeMap<&'static str, ConfigSource>,
 }
 
 /// Config Builder
@@ -56,12 +105,33 @@ pub struct ConfigBuilder {
     message_tcp_port: Option<u16>,
     jwt: Option<JwtConfig>,
     environment: Option<String>,
+    profile: Option<Profile>,
+    require_client_cert: Option<bool>,
     auth_server_url: Option<String>,
     max_connections: Option<u32>,
     request_timeout_ms: Option<u64>,
     shutdown_timeout_ms: Option<u64>,
     timezone: Option<Tz>,
     cloud_url: Option<String>,
+    orders_cache_verify: Option<bool>,
+    event_journal: Option<bool>,
+    kpi_interval_secs: Option<u64>,
+    resource_watchdog_interval_secs: Option<u64>,
+    public_status: Option<bool>,
+    pms_url: Option<String>,
+    pms_api_key: Option<String>,
+    notify_gateway_url: Option<String>,
+    notify_gateway_api_key: Option<String>,
+    client_queue_capacity: Option<usize>,
+    client_queue_overflow: Option<OverflowPolicy>,
+    load_shed_latency_ms: Option<u64>,
+    commit_latency_alert_ms: Option<u64>,
+    log_dir: Option<String>,
+    otlp: Option<OtlpConfig>,
+    event_retention_days: Option<u64>,
+    fiscal_device_url: Option<String>,
+    fiscal_device_api_key: Option<String>,
+    fiscal_policy: Option<FiscalPolicy>,
 }
 
 impl ConfigBuilder {
@@ -94,6 +164,16 @@ impl ConfigBuilder {
         self
     }
 
+    pub fn profile(mut self, value: Profile) -> Self {
+        self.profile = Some(value);
+        self
+    }
+
+    pub fn require_client_cert(mut self, value: bool) -> Self {
+        self.require_client_cert = Some(value);
+        self
+    }
+
     pub fn auth_server_url(mut self, value: impl Into<String>) -> Self {
         self.auth_server_url = Some(value.into());
         self
@@ -127,6 +207,107 @@ impl ConfigBuilder {
         self
     }
 
+    pub fn orders_cache_verify(mut self, value: bool) -> Self {
+        self.orders_cache_verify = Some(value);
+        self
+    }
+
+    pub fn event_journal(mut self, value: bool) -> Self {
+        self.event_journal = Some(value);
+        self
+    }
+
+    pub fn kpi_interval_secs(mut self, secs: u64) -> Self {
+        self.kpi_interval_secs = Some(secs);
+        self
+    }
+
+    pub fn resource_watchdog_interval_secs(mut self, secs: u64) -> Self {
+        self.resource_watchdog_interval_secs = Some(secs);
+        self
+    }
+
+    pub fn public_status(mut self, value: bool) -> Self {
+        self.public_status = Some(value);
+        self
+    }
+
+    pub fn pms_url(mut self, value: impl Into<String>) -> Self {
+        let v = value.into();
+        self.pms_url = if v.is_empty() { None } else { Some(v) };
+        self
+    }
+
+    pub fn pms_api_key(mut self, value: impl Into<String>) -> Self {
+        let v = value.into();
+        self.pms_api_key = if v.is_empty() { None } else { Some(v) };
+        self
+    }
+
+    pub fn notify_gateway_url(mut self, value: impl Into<String>) -> Self {
+        let v = value.into();
+        self.notify_gateway_url = if v.is_empty() { None } else { Some(v) };
+        self
+    }
+
+    pub fn notify_gateway_api_key(mut self, value: impl Into<String>) -> Self {
+        let v = value.into();
+        self.notify_gateway_api_key = if v.is_empty() { None } else { Some(v) };
+        self
+    }
+
+    pub fn client_queue_capacity(mut self, value: usize) -> Self {
+        self.client_queue_capacity = Some(value);
+        self
+    }
+
+    pub fn client_queue_overflow(mut self, value: OverflowPolicy) -> Self {
+        self.client_queue_overflow = Some(value);
+        self
+    }
+
+    pub fn load_shed_latency_ms(mut self, value: u64) -> Self {
+        self.load_shed_latency_ms = Some(value);
+        self
+    }
+
+    pub fn commit_latency_alert_ms(mut self, value: u64) -> Self {
+        self.commit_latency_alert_ms = Some(value);
+        self
+    }
+
+    pub fn log_dir(mut self, value: impl Into<String>) -> Self {
+        self.log_dir = Some(value.into());
+        self
+    }
+
+    pub fn otlp(mut self, value: Option<OtlpConfig>) -> Self {
+        self.otlp = value;
+        self
+    }
+
+    pub fn event_retention_days(mut self, value: u64) -> Self {
+        self.event_retention_days = Some(value);
+        self
+    }
+
+    pub fn fiscal_device_url(mut self, value: impl Into<String>) -> Self {
+        let v = value.into();
+        self.fiscal_device_url = if v.is_empty() { None } else { Some(v) };
+        self
+    }
+
+    pub fn fiscal_device_api_key(mut self, value: impl Into<String>) -> Self {
+        let v = value.into();
+        self.fiscal_device_api_key = if v.is_empty() { None } else { Some(v) };
+        self
+    }
+
+    pub fn fiscal_policy(mut self, value: FiscalPolicy) -> Self {
+        self.fiscal_policy = Some(value);
+        self
+    }
+
     /// 构建配置，使用默认值填充未设置的字段
     pub fn build(self) -> Config {
         let auth_url = self
@@ -144,12 +325,34 @@ impl ConfigBuilder {
             message_tcp_port: self.message_tcp_port.unwrap_or(8081),
             jwt: self.jwt.unwrap_or_default(),
             environment: self.environment.unwrap_or_else(|| "development".into()),
+            profile: self.profile.unwrap_or_default(),
+            require_client_cert: self.require_client_cert.unwrap_or(true),
             auth_server_url,
             max_connections: self.max_connections.unwrap_or(1000),
             request_timeout_ms: self.request_timeout_ms.unwrap_or(30000),
             shutdown_timeout_ms: self.shutdown_timeout_ms.unwrap_or(10000),
             timezone: self.timezone.unwrap_or(chrono_tz::Europe::Madrid),
             cloud_url: self.cloud_url,
+            orders_cache_verify: self.orders_cache_verify.unwrap_or(false),
+            event_journal: self.event_journal.unwrap_or(false),
+            kpi_interval_secs: self.kpi_interval_secs.unwrap_or(15),
+            resource_watchdog_interval_secs: self.resource_watchdog_interval_secs.unwrap_or(300),
+            public_status: self.public_status.unwrap_or(false),
+            pms_url: self.pms_url,
+            pms_api_key: self.pms_api_key,
+            notify_gateway_url: self.notify_gateway_url,
+            notify_gateway_api_key: self.notify_gateway_api_key,
+            client_queue_capacity: self.client_queue_capacity.unwrap_or(256),
+            client_queue_overflow: self.client_queue_overflow.unwrap_or_default(),
+            load_shed_latency_ms: self.load_shed_latency_ms.unwrap_or(800),
+            commit_latency_alert_ms: self.commit_latency_alert_ms.unwrap_or(500),
+            log_dir: self.log_dir,
+            otlp: self.otlp,
+            event_retention_days: self.event_retention_days.unwrap_or(30),
+            fiscal_device_url: self.fiscal_device_url,
+            fiscal_device_api_key: self.fiscal_device_api_key,
+            fiscal_policy: self.fiscal_policy.unwrap_or_default(),
+            config_sources: BTreeMap::new(),
         }
     }
 }
@@ -160,61 +363,48 @@ impl Config {
         ConfigBuilder::new()
     }
 
-    /// 从环境变量加载配置
+    /// 分层加载配置 (内置默认 < Profile 默认 < Profile 文件 < 环境变量)
     ///
     /// **注意**: 此方法仅应在 bin crate 中使用，在调用前应先加载 .env 文件。
-    /// 库代码应使用 `Config::builder()` 显式构建配置。
+    /// 库代码应使用 `Config::builder()` 显式构建配置；需要运行时覆盖时使用
+    /// [`ConfigLoader`]。值无法解析或违反 Profile 约束时返回 [`ConfigError`]。
     ///
     /// # 环境变量
     ///
     /// | 变量 | 默认值 | 说明 |
     /// |------|--------|------|
+    /// | CONFIG_PROFILE | (按 ENVIRONMENT) | dev / staging / prod / kiosk |
+    /// | CONFIG_PROFILE_FILE | profiles/{profile}.env | Profile 文件 (`KEY=VALUE`，键同下表) |
     /// | WORK_DIR | /var/lib/crab/edge | 工作目录 |
     /// | HTTP_PORT | 3000 | HTTP 端口 |
     /// | MESSAGE_TCP_PORT | 8081 | TCP 消息端口 |
-    /// | ENVIRONMENT | development | 运行环境 |
+    /// | ENVIRONMENT | development | 运行环境 (仅在未设置 CONFIG_PROFILE 时用于选择 Profile) |
     /// | AUTH_SERVER_URL | https://cloud.redcoral.app | 认证服务器 |
-    pub fn from_env() -> Self {
-        Self::builder()
-            .work_dir(std::env::var("WORK_DIR").unwrap_or_else(|_| "/var/lib/crab/edge".into()))
-            .http_port(
-                std::env::var("HTTP_PORT")
-                    .ok()
-                    .and_then(|p| p.parse().ok())
-                    .unwrap_or(3000),
-            )
-            .message_tcp_port(
-                std::env::var("MESSAGE_TCP_PORT")
-                    .ok()
-                    .and_then(|p| p.parse().ok())
-                    .unwrap_or(8081),
-            )
-            .environment(std::env::var("ENVIRONMENT").unwrap_or_else(|_| "development".into()))
-            .auth_server_url(
-                std::env::var("AUTH_SERVER_URL")
-                    .unwrap_or_else(|_| shared::DEFAULT_AUTH_SERVER_URL.into()),
-            )
-            .max_connections(
-                std::env::var("MAX_CONNECTIONS")
-                    .ok()
-                    .and_then(|p| p.parse().ok())
-                    .unwrap_or(1000),
-            )
-            .request_timeout_ms(
-                std::env::var("REQUEST_TIMEOUT_MS")
-                    .ok()
-                    .and_then(|p| p.parse().ok())
-                    .unwrap_or(30000),
-            )
-            .shutdown_timeout_ms(
-                std::env::var("SHUTDOWN_TIMEOUT_MS")
-                    .ok()
-                    .and_then(|p| p.parse().ok())
-                    .unwrap_or(10000),
-            )
-            .timezone(std::env::var("TIMEZONE").unwrap_or_else(|_| "Europe/Madrid".into()))
-            .cloud_url(std::env::var("CRAB_CLOUD_URL").unwrap_or_default())
-            .build()
+    /// | REQUIRE_CLIENT_CERT | true (dev: false) | 强制 mTLS 客户端证书 (仅 dev 可关闭) |
+    /// | ORDERS_CACHE_VERIFY | false (dev: true) | 活跃订单缓存一致性校验 |
+    /// | EVENT_JOURNAL | false (staging: true) | 订单事件追加日志 (data/journal/) |
+    /// | KPI_INTERVAL_SECS | 15 (kiosk: 0) | 实时经营指标推送间隔 (0 = 禁用) |
+    /// | RESOURCE_WATCHDOG_INTERVAL_SECS | 300 | 资源泄漏监控采样间隔 (0 = 禁用) |
+    /// | PUBLIC_STATUS | false | 开放公开 `/status` 端点 |
+    /// | PMS_URL | - | 酒店 PMS 地址 (挂房账，未设置 = 禁用) |
+    /// | PMS_API_KEY | - | 酒店 PMS API key |
+    /// | CLIENT_QUEUE_CAPACITY | 256 | TCP 客户端出站队列容量 |
+    /// | CLIENT_QUEUE_OVERFLOW | drop-oldest | 队列满时策略 (drop-oldest / disconnect / block) |
+    /// | OTEL_EXPORTER_OTLP_ENDPOINT | - | OTLP/HTTP traces 端点 (未设置 = 不导出，仅读环境变量) |
+    /// | OTEL_EXPORTER_OTLP_HEADERS | - | 导出请求头 `k1=v1,k2=v2` |
+    /// | EVENT_RETENTION_DAYS | 30 | redb 事件日志保留天数 (0 = 禁用压缩) |
+    /// | FISCAL_DEVICE_URL | - | 税控设备地址 (`http(s)://` 或 `serial:<path>`，未设置 = 禁用) |
+    /// | FISCAL_DEVICE_API_KEY | - | 税控设备 API key |
+    /// | FISCAL_POLICY | block | 设备不可用时结单策略 (block / allow) |
+    pub fn load() -> Result<Self, ConfigError> {
+        let mut config = ConfigLoader::from_env()?.load()?;
+        config.otlp = OtlpConfig::from_env("edge-server");
+        Ok(config)
+    }
+
+    /// 配置项来源
+    pub fn source_of(&self, key: &str) -> ConfigSource {
+        self.config_sources.get(key).copied().unwrap_or_default()
     }
 
     /// 使用自定义值覆盖部分配置 (测试用)
@@ -282,10 +472,45 @@ impl Config {
         self.data_dir().join("print.redb")
     }
 
+    /// 获取订单事件日志目录: {tenant}/server/data/journal/
+    pub fn event_journal_dir(&self) -> PathBuf {
+        self.data_dir().join("journal")
+    }
+
+    /// 获取启动恢复报告路径: {tenant}/server/data/recovery_report.json
+    pub fn recovery_report_path(&self) -> PathBuf {
+        self.data_dir().join("recovery_report.json")
+    }
+
+    /// 获取上次关闭报告路径: {tenant}/server/data/shutdown_report.json
+    pub fn shutdown_report_path(&self) -> PathBuf {
+        self.data_dir().join("shutdown_report.json")
+    }
+
     /// 获取图片存储目录路径: {tenant}/server/images/
     pub fn images_dir(&self) -> PathBuf {
         PathBuf::from(&self.work_dir).join("images")
     }
+
+    /// 获取收据存档目录路径: {tenant}/server/receipts/
+    pub fn receipt_archive_dir(&self) -> PathBuf {
+        PathBuf::from(&self.work_dir).join("receipts")
+    }
+
+    /// 获取日志目录路径: 配置值，默认 {tenant}/server/logs/
+    pub fn logs_dir(&self) -> PathBuf {
+        match &self.log_dir {
+            Some(dir) => PathBuf::from(dir),
+            None => PathBuf::from(&self.work_dir).join("logs"),
+        }
+    }
+
+    /// 获取员工照片目录路径: {tenant}/server/employee_photos/
+    ///
+    /// 与商品图片 (`images/`) 分开存放，不经公共图片路由访问
+    pub fn employee_photo_dir(&self) -> PathBuf {
+        PathBuf::from(&self.work_dir).join("employee_photos")
+    }
 }
 
 impl Default for Config {
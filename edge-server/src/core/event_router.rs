@@ -7,14 +7,17 @@
 //!        │
 //!        └── EventRouter
 //!               ├── mpsc ──► ArchiveWorker (terminal events only) [CRITICAL]
-//!               ├── mpsc ──► KitchenPrintWorker (ItemsAdded + OrderCompleted) [best-effort]
+//!               ├── mpsc ──► ProjectionService (all events, 报表读模型) [ordered]
+//!               ├── mpsc ──► KitchenPrintWorker (ItemsAdded + CourseFired + OrderCompleted) [best-effort]
+//!               ├── mpsc ──► CustomerDisplayService (items / payments / terminal events) [best-effort]
 //!               └── mpsc ──► OrderSyncForwarder (all events) [best-effort]
 //! ```
 //!
 //! ## 优先级策略
 //!
 //! - **Archive**: 关键业务，阻塞发送保证不丢失
-//! - **Sync/Print**: Best-effort，满则丢弃（不阻塞关键路径）
+//! - **Projection**: 折叠完整事件流，阻塞发送保证不跳事件
+//! - **Sync/Print/Display**: Best-effort，满则丢弃（不阻塞关键路径）
 
 use shared::order::{OrderEvent, OrderEventType};
 use std::sync::Arc;
@@ -28,14 +31,66 @@ const TERMINAL_EVENTS: &[OrderEventType] = &[
     OrderEventType::OrderMerged,
 ];
 
+/// 客显事件类型
+const DISPLAY_EVENTS: &[OrderEventType] = &[
+    OrderEventType::ItemsAdded,
+    OrderEventType::PaymentAdded,
+    OrderEventType::OrderCompleted,
+    OrderEventType::OrderVoided,
+    OrderEventType::OrderMerged,
+];
+
 /// 事件通道集合
 pub struct EventChannels {
     /// 归档事件（仅终端事件）- Arc 包装减少克隆开销
     pub archive_rx: mpsc::Receiver<Arc<OrderEvent>>,
-    /// 打印事件（ItemsAdded + OrderCompleted）
+    /// 投影事件（所有事件，报表读模型）
+    pub projection_rx: mpsc::Receiver<Arc<OrderEvent>>,
+    /// 打印事件（ItemsAdded + CourseFired + OrderCompleted）
     pub print_rx: mpsc::Receiver<Arc<OrderEvent>>,
     /// 同步事件（所有事件）
     pub sync_rx: mpsc::Receiver<Arc<OrderEvent>>,
+    /// 客显事件
+    pub display_rx: mpsc::Receiver<Arc<OrderEvent>>,
+}
+
+/// 通道积压探针 (弱引用，不阻止通道关闭)
+///
+/// 关闭协调器用它等待各 Worker 消费完积压事件。
+#[derive(Clone)]
+pub struct EventQueues {
+    archive: mpsc::WeakSender<Arc<OrderEvent>>,
+    projection: mpsc::WeakSender<Arc<OrderEvent>>,
+    print: mpsc::WeakSender<Arc<OrderEvent>>,
+    sync: mpsc::WeakSender<Arc<OrderEvent>>,
+}
+
+impl EventQueues {
+    fn depth(tx: &mpsc::WeakSender<Arc<OrderEvent>>) -> usize {
+        tx.upgrade()
+            .map(|tx| tx.max_capacity() - tx.capacity())
+            .unwrap_or(0)
+    }
+
+    /// 归档通道积压
+    pub fn archive(&self) -> usize {
+        Self::depth(&self.archive)
+    }
+
+    /// 投影通道积压
+    pub fn projection(&self) -> usize {
+        Self::depth(&self.projection)
+    }
+
+    /// 打印通道积压
+    pub fn print(&self) -> usize {
+        Self::depth(&self.print)
+    }
+
+    /// 同步通道积压
+    pub fn sync(&self) -> usize {
+        Self::depth(&self.sync)
+    }
 }
 
 /// 事件路由器
@@ -44,36 +99,54 @@ pub struct EventChannels {
 /// 使用 Arc<OrderEvent> 减少克隆开销。
 pub struct EventRouter {
     archive_tx: mpsc::Sender<Arc<OrderEvent>>,
+    projection_tx: mpsc::Sender<Arc<OrderEvent>>,
     print_tx: mpsc::Sender<Arc<OrderEvent>>,
     sync_tx: mpsc::Sender<Arc<OrderEvent>>,
+    display_tx: mpsc::Sender<Arc<OrderEvent>>,
 }
 
 impl EventRouter {
     /// 创建路由器和通道
     ///
     /// # 参数
-    /// - `archive_buffer`: 归档通道 buffer（关键业务，建议较大）
+    /// - `archive_buffer`: 归档 / 投影通道 buffer（关键业务，建议较大）
     /// - `other_buffer`: 其他通道 buffer（best-effort）
     pub fn new(archive_buffer: usize, other_buffer: usize) -> (Self, EventChannels) {
         let (archive_tx, archive_rx) = mpsc::channel(archive_buffer);
+        let (projection_tx, projection_rx) = mpsc::channel(archive_buffer);
         let (print_tx, print_rx) = mpsc::channel(other_buffer);
         let (sync_tx, sync_rx) = mpsc::channel(other_buffer);
+        let (display_tx, display_rx) = mpsc::channel(other_buffer);
 
         let router = Self {
             archive_tx,
+            projection_tx,
             print_tx,
             sync_tx,
+            display_tx,
         };
 
         let channels = EventChannels {
             archive_rx,
+            projection_rx,
             print_rx,
             sync_rx,
+            display_rx,
         };
 
         (router, channels)
     }
 
+    /// 通道积压探针
+    pub fn queues(&self) -> EventQueues {
+        EventQueues {
+            archive: self.archive_tx.downgrade(),
+            projection: self.projection_tx.downgrade(),
+            print: self.print_tx.downgrade(),
+            sync: self.sync_tx.downgrade(),
+        }
+    }
+
     /// 运行路由器（阻塞直到源通道关闭或收到 shutdown 信号）
     pub async fn run(
         self,
@@ -113,7 +186,8 @@ impl EventRouter {
     ///
     /// 优先级策略：
     /// 1. Archive: 阻塞发送，保证不丢失（关键业务）
-    /// 2. Sync/Print: try_send，满则丢弃（不阻塞关键路径）
+    /// 2. Projection: 阻塞发送，读模型需要完整事件流
+    /// 3. Sync/Print/Display: try_send，满则丢弃（不阻塞关键路径）
     async fn dispatch(&self, event: OrderEvent) {
         let event = Arc::new(event);
 
@@ -124,7 +198,12 @@ impl EventRouter {
             tracing::error!("Archive channel closed - critical data may be lost!");
         }
 
-        // 2. 同步通道：best-effort，满则丢弃
+        // 2. 投影通道：阻塞发送（跳过事件会使折叠结果偏离）
+        if self.projection_tx.send(Arc::clone(&event)).await.is_err() {
+            tracing::debug!("Projection channel closed");
+        }
+
+        // 3. 同步通道：best-effort，满则丢弃
         match self.sync_tx.try_send(Arc::clone(&event)) {
             Ok(()) => {}
             Err(mpsc::error::TrySendError::Full(_)) => {
@@ -139,12 +218,15 @@ impl EventRouter {
             }
         }
 
-        // 3. 打印通道：best-effort，满则丢弃
+        // 4. 打印通道：best-effort，满则丢弃
         //    ItemsAdded: 创建厨房单/标签记录 + 堂食立即打印
+        //    CourseFired: 分道次起菜出单
         //    OrderCompleted: 零售订单延迟打印
         if matches!(
             event.event_type,
-            OrderEventType::ItemsAdded | OrderEventType::OrderCompleted
+            OrderEventType::ItemsAdded
+                | OrderEventType::CourseFired
+                | OrderEventType::OrderCompleted
         ) {
             match self.print_tx.try_send(Arc::clone(&event)) {
                 Ok(()) => {}
@@ -159,6 +241,20 @@ impl EventRouter {
                 }
             }
         }
+
+        // 5. 客显通道：best-effort，满则丢弃
+        if DISPLAY_EVENTS.contains(&event.event_type) {
+            match self.display_tx.try_send(Arc::clone(&event)) {
+                Ok(()) => {}
+                Err(mpsc::error::TrySendError::Full(_)) => {
+                    tracing::debug!(
+                        order_id = %event.order_id,
+                        "Display channel full, display update dropped"
+                    );
+                }
+                Err(mpsc::error::TrySendError::Closed(_)) => {}
+            }
+        }
     }
 }
 
@@ -177,6 +273,7 @@ mod tests {
                 service_type: Some(ServiceType::DineIn),
                 final_total: 100.0,
                 payment_summary: vec![],
+                fiscal_code: None,
             },
             _ => EventPayload::OrderInfoUpdated {
                 guest_count: None,
@@ -213,7 +310,8 @@ mod tests {
         let items_added = make_test_event(OrderEventType::ItemsAdded, 1);
         tx.send(items_added).unwrap();
 
-        // Should receive on sync and print channels (as Arc)
+        // Should receive on projection, sync and print channels (as Arc)
+        assert!(channels.projection_rx.recv().await.is_some());
         assert!(channels.sync_rx.recv().await.is_some());
         assert!(channels.print_rx.recv().await.is_some());
 
@@ -221,7 +319,8 @@ mod tests {
         let completed = make_test_event(OrderEventType::OrderCompleted, 2);
         tx.send(completed).unwrap();
 
-        // Should receive on sync, archive, and print channels
+        // Should receive on projection, sync, archive, and print channels
+        assert!(channels.projection_rx.recv().await.is_some());
         assert!(channels.sync_rx.recv().await.is_some());
         assert!(channels.archive_rx.recv().await.is_some());
         assert!(channels.print_rx.recv().await.is_some());
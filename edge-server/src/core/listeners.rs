@@ -0,0 +1,111 @@
+//! 监听器热重绑定
+//!
+//! 修改 HTTP / 消息总线端口或更换 TLS 证书无需重启服务：
+//!
+//! ```text
+//! rebind(ports)      → 先绑定新端口 → 成功后旧 HTTP 监听器优雅排空 (DRAIN_DEADLINE)
+//!                      绑定失败 → 记录错误，旧监听器继续服务
+//! reload_tls(config) → HTTPS 原地替换证书；消息总线新连接使用新证书
+//! ```
+//!
+//! 消息总线的已建立连接是长连接，与监听器无关，重绑定后继续工作；
+//! 客户端下次重连时使用新端口。
+
+use std::sync::Arc;
+use std::time::Duration;
+use tokio::sync::watch;
+
+/// 旧 HTTP 监听器排空期限（进行中的请求在此期限内完成）
+pub const DRAIN_DEADLINE: Duration = Duration::from_secs(30);
+
+/// 监听端口
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ListenerPorts {
+    pub http_port: u16,
+    pub message_tcp_port: u16,
+}
+
+/// 监听器控制句柄（Clone 共享同一状态）
+///
+/// 由 `Server` 持有，HTTPS 服务和消息总线 TCP 服务订阅变更。
+#[derive(Debug, Clone)]
+pub struct ListenerControl {
+    ports: Arc<watch::Sender<ListenerPorts>>,
+    tls: Arc<watch::Sender<Option<Arc<rustls::ServerConfig>>>>,
+}
+
+impl ListenerControl {
+    pub fn new(ports: ListenerPorts) -> Self {
+        let (ports, _) = watch::channel(ports);
+        let (tls, _) = watch::channel(None);
+        Self {
+            ports: Arc::new(ports),
+            tls: Arc::new(tls),
+        }
+    }
+
+    /// 当前目标端口
+    pub fn ports(&self) -> ListenerPorts {
+        *self.ports.borrow()
+    }
+
+    /// 请求重绑定端口，返回是否有变化
+    pub fn rebind(&self, ports: ListenerPorts) -> bool {
+        let changed = self.ports.send_if_modified(|current| {
+            if *current == ports {
+                return false;
+            }
+            *current = ports;
+            true
+        });
+        if changed {
+            tracing::info!(
+                http_port = ports.http_port,
+                message_tcp_port = ports.message_tcp_port,
+                "Listener rebind requested"
+            );
+        }
+        changed
+    }
+
+    /// 替换 TLS 配置（证书续期 / 重新激活后）
+    pub fn reload_tls(&self, config: Arc<rustls::ServerConfig>) {
+        tracing::info!("TLS reload requested");
+        self.tls.send_replace(Some(config));
+    }
+
+    pub fn subscribe_ports(&self) -> watch::Receiver<ListenerPorts> {
+        self.ports.subscribe()
+    }
+
+    pub fn subscribe_tls(&self) -> watch::Receiver<Option<Arc<rustls::ServerConfig>>> {
+        self.tls.subscribe()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[tokio::test]
+    async fn test_rebind_notifies_only_on_change() {
+        let control = ListenerControl::new(ListenerPorts {
+            http_port: 9625,
+            message_tcp_port: 9626,
+        });
+        let mut rx = control.subscribe_ports();
+        rx.borrow_and_update();
+
+        assert!(!control.rebind(control.ports()));
+        assert!(!rx.has_changed().unwrap());
+
+        let next = ListenerPorts {
+            http_port: 9725,
+            message_tcp_port: 9626,
+        };
+        assert!(control.rebind(next));
+        rx.changed().await.unwrap();
+        assert_eq!(*rx.borrow_and_update(), next);
+        assert_eq!(control.ports(), next);
+    }
+}
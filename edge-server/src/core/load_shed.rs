@@ -0,0 +1,378 @@
+//! 高峰期负载保护 (Load shedding)
+//!
+//! 请求按优先级分为三条通道：
+//!
+//! ```text
+//! Command (订单命令) > Sync (客户端重同步) > Report (报表 / 导出)
+//! ```
+//!
+//! - 订单命令永不排队、永不拒绝，只记录耗时 (EWMA)
+//! - Sync / Report 各有并发上限和等待队列上限，队列满时直接拒绝
+//! - 订单命令耗时超过阈值 (`LOAD_SHED_LATENCY_MS`) 时，低优先级请求全部拒绝：
+//!   HTTP 返回 503 + `Retry-After`，消息总线返回失败响应
+//!
+//! 阈值为 0 时只保留队列上限，不做延迟判定。
+
+use std::sync::Arc;
+use std::sync::atomic::{AtomicU64, AtomicUsize, Ordering};
+use std::time::{Duration, Instant};
+
+use parking_lot::Mutex;
+use serde::Serialize;
+use tokio::sync::{OwnedSemaphorePermit, Semaphore};
+
+/// EWMA 平滑系数 (新样本权重)
+const EWMA_ALPHA: f64 = 0.2;
+/// 超过此时长没有新命令，认为压力已解除
+const LATENCY_STALE_AFTER: Duration = Duration::from_secs(10);
+/// 拒绝时建议客户端的重试间隔
+pub const RETRY_AFTER: Duration = Duration::from_secs(5);
+
+/// 请求优先级
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum Priority {
+    /// 订单命令 (及其他业务请求)
+    Command,
+    /// 客户端重同步
+    Sync,
+    /// 报表、统计、导出
+    Report,
+}
+
+impl Priority {
+    pub fn as_str(self) -> &'static str {
+        match self {
+            Self::Command => "command",
+            Self::Sync => "sync",
+            Self::Report => "report",
+        }
+    }
+}
+
+/// HTTP 路径分类
+pub fn classify_path(path: &str) -> Priority {
+    const REPORT_PREFIXES: &[&str] = &[
+        "/api/statistics",
+        "/api/daily_reports",
+        "/api/data_transfer",
+        "/api/audit_log",
+        "/api/archive_verify",
+        "/api/chain_entries",
+        "/api/orders/history",
+        "/api/orders/search",
+    ];
+    if path.starts_with("/api/sync") {
+        Priority::Sync
+    } else if path.ends_with("/export")
+        || path.ends_with("/audit_bundle")
+        || REPORT_PREFIXES.iter().any(|p| path.starts_with(p))
+    {
+        Priority::Report
+    } else {
+        Priority::Command
+    }
+}
+
+/// 消息总线 RequestCommand 分类
+pub fn classify_action(action: &str) -> Priority {
+    if action.starts_with("sync.") {
+        Priority::Sync
+    } else {
+        Priority::Command
+    }
+}
+
+/// 请求被拒绝
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Shed {
+    pub priority: Priority,
+    pub reason: &'static str,
+    pub retry_after: Duration,
+}
+
+impl std::fmt::Display for Shed {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "Server busy ({}), {} request rejected; retry in {}s",
+            self.reason,
+            self.priority.as_str(),
+            self.retry_after.as_secs()
+        )
+    }
+}
+
+/// 低优先级通道：并发上限 + 等待队列上限
+struct Lane {
+    permits: Arc<Semaphore>,
+    waiting: AtomicUsize,
+    queue_limit: usize,
+    shed: AtomicU64,
+}
+
+impl Lane {
+    fn new(concurrency: usize, queue_limit: usize) -> Self {
+        Self {
+            permits: Arc::new(Semaphore::new(concurrency.max(1))),
+            waiting: AtomicUsize::new(0),
+            queue_limit,
+            shed: AtomicU64::new(0),
+        }
+    }
+}
+
+/// 排队计数 (请求在等待中被取消时也会归还)
+struct WaitingGuard<'a> {
+    counter: &'a AtomicUsize,
+    /// 进入前的排队数
+    position: usize,
+}
+
+impl<'a> WaitingGuard<'a> {
+    fn enter(counter: &'a AtomicUsize) -> Self {
+        let position = counter.fetch_add(1, Ordering::AcqRel);
+        Self { counter, position }
+    }
+}
+
+impl Drop for WaitingGuard<'_> {
+    fn drop(&mut self) {
+        self.counter.fetch_sub(1, Ordering::AcqRel);
+    }
+}
+
+/// 通道状态 (`/health/detailed` 暴露)
+#[derive(Debug, Clone, Serialize)]
+pub struct LaneStatus {
+    pub priority: Priority,
+    pub available: usize,
+    pub waiting: usize,
+    pub shed_total: u64,
+}
+
+/// 负载保护状态
+#[derive(Debug, Clone, Serialize)]
+pub struct LoadShedStatus {
+    /// 订单命令耗时 EWMA (毫秒)
+    pub command_latency_ms: u64,
+    pub latency_threshold_ms: u64,
+    pub stressed: bool,
+    pub lanes: Vec<LaneStatus>,
+}
+
+/// 负载保护器 (ServerState 共享)
+pub struct LoadShedder {
+    /// 0 = 不做延迟判定
+    threshold: Duration,
+    /// (EWMA 毫秒, 最近样本时间)
+    latency: Mutex<(f64, Option<Instant>)>,
+    sync: Lane,
+    report: Lane,
+}
+
+impl std::fmt::Debug for LoadShedder {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("LoadShedder")
+            .field("threshold", &self.threshold)
+            .finish_non_exhaustive()
+    }
+}
+
+impl LoadShedder {
+    /// 默认通道容量：Sync 4 并发 / 16 排队，Report 2 并发 / 4 排队
+    pub fn new(threshold: Duration) -> Self {
+        Self::with_lanes(threshold, (4, 16), (2, 4))
+    }
+
+    /// 指定通道容量 `(并发, 排队)`
+    pub fn with_lanes(threshold: Duration, sync: (usize, usize), report: (usize, usize)) -> Self {
+        Self {
+            threshold,
+            latency: Mutex::new((0.0, None)),
+            sync: Lane::new(sync.0, sync.1),
+            report: Lane::new(report.0, report.1),
+        }
+    }
+
+    fn lane(&self, priority: Priority) -> Option<&Lane> {
+        match priority {
+            Priority::Command => None,
+            Priority::Sync => Some(&self.sync),
+            Priority::Report => Some(&self.report),
+        }
+    }
+
+    /// 记录一次订单命令耗时
+    pub fn record_command_latency(&self, elapsed: Duration) {
+        self.record_at(elapsed, Instant::now());
+    }
+
+    fn record_at(&self, elapsed: Duration, now: Instant) {
+        let sample = elapsed.as_secs_f64() * 1000.0;
+        let mut latency = self.latency.lock();
+        latency.0 = match latency.1 {
+            Some(last) if now.duration_since(last) < LATENCY_STALE_AFTER => {
+                latency.0 + EWMA_ALPHA * (sample - latency.0)
+            }
+            // 无近期样本：从当前样本重新开始
+            _ => sample,
+        };
+        latency.1 = Some(now);
+    }
+
+    /// 订单命令耗时 EWMA (无近期样本时为 0)
+    fn latency_at(&self, now: Instant) -> Duration {
+        let latency = self.latency.lock();
+        match latency.1 {
+            Some(last) if now.duration_since(last) < LATENCY_STALE_AFTER => {
+                Duration::from_secs_f64(latency.0 / 1000.0)
+            }
+            _ => Duration::ZERO,
+        }
+    }
+
+    fn stressed_at(&self, now: Instant) -> bool {
+        !self.threshold.is_zero() && self.latency_at(now) > self.threshold
+    }
+
+    /// 订单命令耗时是否超过阈值
+    pub fn is_stressed(&self) -> bool {
+        self.stressed_at(Instant::now())
+    }
+
+    /// 申请执行许可
+    ///
+    /// 订单命令直接放行 (`Ok(None)`)；低优先级请求在通道内排队，
+    /// 返回的 permit 需持有到请求结束。
+    pub async fn admit(&self, priority: Priority) -> Result<Option<OwnedSemaphorePermit>, Shed> {
+        let Some(lane) = self.lane(priority) else {
+            return Ok(None);
+        };
+
+        let shed = |reason| {
+            lane.shed.fetch_add(1, Ordering::Relaxed);
+            tracing::warn!(
+                priority = priority.as_str(),
+                reason,
+                command_latency_ms = self.latency_at(Instant::now()).as_millis() as u64,
+                "Load shedding request"
+            );
+            Shed {
+                priority,
+                reason,
+                retry_after: RETRY_AFTER,
+            }
+        };
+
+        if self.is_stressed() {
+            return Err(shed("order command latency above threshold"));
+        }
+        if let Ok(permit) = lane.permits.clone().try_acquire_owned() {
+            return Ok(Some(permit));
+        }
+
+        // 排队等待 (队列满则拒绝)
+        let waiting = WaitingGuard::enter(&lane.waiting);
+        if waiting.position >= lane.queue_limit {
+            return Err(shed("queue full"));
+        }
+        let permit = lane.permits.clone().acquire_owned().await;
+        drop(waiting);
+        // Semaphore 从不 close
+        Ok(permit.ok())
+    }
+
+    /// 当前状态
+    pub fn status(&self) -> LoadShedStatus {
+        let now = Instant::now();
+        let lane_status = |priority, lane: &Lane| LaneStatus {
+            priority,
+            available: lane.permits.available_permits(),
+            waiting: lane.waiting.load(Ordering::Acquire),
+            shed_total: lane.shed.load(Ordering::Relaxed),
+        };
+        LoadShedStatus {
+            command_latency_ms: self.latency_at(now).as_millis() as u64,
+            latency_threshold_ms: self.threshold.as_millis() as u64,
+            stressed: self.stressed_at(now),
+            lanes: vec![
+                lane_status(Priority::Sync, &self.sync),
+                lane_status(Priority::Report, &self.report),
+            ],
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_classify() {
+        assert_eq!(classify_path("/api/orders/command"), Priority::Command);
+        assert_eq!(classify_path("/api/orders/42"), Priority::Command);
+        assert_eq!(classify_path("/api/sync/status"), Priority::Sync);
+        assert_eq!(classify_path("/api/statistics/kpi"), Priority::Report);
+        assert_eq!(classify_path("/api/orders/history"), Priority::Report);
+        assert_eq!(
+            classify_path("/api/orders/42/audit_bundle"),
+            Priority::Report
+        );
+        assert_eq!(classify_action("order.add_items"), Priority::Command);
+        assert_eq!(classify_action("sync.orders"), Priority::Sync);
+    }
+
+    #[test]
+    fn test_latency_threshold_and_staleness() {
+        let shedder = LoadShedder::new(Duration::from_millis(500));
+        let t0 = Instant::now();
+        shedder.record_at(Duration::from_millis(100), t0);
+        assert!(!shedder.stressed_at(t0));
+
+        // EWMA 逐步上升
+        for i in 1..=20 {
+            shedder.record_at(Duration::from_secs(2), t0 + Duration::from_millis(i * 10));
+        }
+        assert!(shedder.stressed_at(t0 + Duration::from_secs(1)));
+
+        // 无新命令后压力解除
+        assert!(!shedder.stressed_at(t0 + LATENCY_STALE_AFTER + Duration::from_secs(1)));
+
+        // 阈值 0 = 禁用延迟判定
+        let disabled = LoadShedder::new(Duration::ZERO);
+        disabled.record_at(Duration::from_secs(10), t0);
+        assert!(!disabled.stressed_at(t0));
+    }
+
+    #[tokio::test]
+    async fn test_lane_queue_limit() {
+        let shedder = LoadShedder::with_lanes(Duration::ZERO, (1, 0), (1, 1));
+
+        // 订单命令不受通道限制
+        assert!(shedder.admit(Priority::Command).await.unwrap().is_none());
+
+        let held = shedder.admit(Priority::Sync).await.unwrap();
+        assert!(held.is_some());
+        let err = shedder.admit(Priority::Sync).await.unwrap_err();
+        assert_eq!(err.reason, "queue full");
+        drop(held);
+        assert!(shedder.admit(Priority::Sync).await.is_ok());
+
+        let status = shedder.status();
+        assert_eq!(status.lanes[0].shed_total, 1);
+        assert_eq!(status.lanes[1].shed_total, 0);
+    }
+
+    #[tokio::test]
+    async fn test_stress_sheds_low_priority() {
+        let shedder = LoadShedder::new(Duration::from_millis(100));
+        shedder.record_command_latency(Duration::from_secs(1));
+
+        assert!(shedder.admit(Priority::Command).await.is_ok());
+        let err = shedder.admit(Priority::Report).await.unwrap_err();
+        assert_eq!(err.priority, Priority::Report);
+        assert_eq!(err.retry_after, RETRY_AFTER);
+        assert!(shedder.status().stressed);
+    }
+}
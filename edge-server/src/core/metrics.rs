@@ -0,0 +1,369 @@
+//! Prometheus 指标 (`GET /metrics`)
+//!
+//! 进程内累加计数器 / 直方图，抓取时渲染为 Prometheus 文本格式 (0.0.4)：
+//!
+//! | 指标 | 类型 | 标签 |
+//! |------|------|------|
+//! | `crab_order_command_duration_seconds` | histogram | `command` |
+//! | `crab_order_command_errors_total` | counter | `command` |
+//! | `crab_event_broadcast_lag_seconds` | histogram | - |
+//! | `crab_bus_connected_clients` | gauge | - |
+//! | `crab_redb_transaction_duration_seconds` | histogram | - |
+//! | `crab_redb_writer_wait_seconds` | histogram | - |
+//! | `crab_redb_slow_commits_total` | counter | - |
+//! | `crab_http_requests_total` | counter | `method`, `route`, `status` |
+//! | `crab_http_request_duration_seconds` | histogram | `route` |
+//! | `crab_print_failures_total` | counter | `kind` |
+//!
+//! HTTP `route` 为路由模板 (`/api/orders/{id}`)，未匹配路由统一为 `unmatched`，
+//! 避免路径参数撑爆标签基数。
+
+use std::collections::BTreeMap;
+use std::fmt::Write;
+use std::time::Duration;
+
+use parking_lot::Mutex;
+
+/// 直方图桶上界 (秒)
+const BUCKETS: &[f64] = &[
+    0.001, 0.0025, 0.005, 0.01, 0.025, 0.05, 0.1, 0.25, 0.5, 1.0, 2.5, 5.0, 10.0,
+];
+
+/// 打印失败类型
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub enum PrintFailure {
+    /// 厨房单首次打印失败 (已入重试队列)
+    Kitchen,
+    /// 重试队列中再次失败
+    Retry,
+    /// 重试次数用尽
+    Abandoned,
+    /// 日报汇总小票
+    DailyReport,
+}
+
+impl PrintFailure {
+    pub fn as_str(self) -> &'static str {
+        match self {
+            Self::Kitchen => "kitchen",
+            Self::Retry => "retry",
+            Self::Abandoned => "abandoned",
+            Self::DailyReport => "daily_report",
+        }
+    }
+}
+
+/// 累积直方图
+#[derive(Debug, Clone)]
+struct Histogram {
+    /// 每个桶的累积计数 (`le` 语义)
+    buckets: [u64; BUCKETS.len()],
+    sum: f64,
+    count: u64,
+}
+
+impl Default for Histogram {
+    fn default() -> Self {
+        Self {
+            buckets: [0; BUCKETS.len()],
+            sum: 0.0,
+            count: 0,
+        }
+    }
+}
+
+impl Histogram {
+    fn observe(&mut self, value: Duration) {
+        let secs = value.as_secs_f64();
+        for (bucket, bound) in self.buckets.iter_mut().zip(BUCKETS) {
+            if secs <= *bound {
+                *bucket += 1;
+            }
+        }
+        self.sum += secs;
+        self.count += 1;
+    }
+
+    fn render(&self, out: &mut String, name: &str, labels: &str) {
+        let sep = if labels.is_empty() { "" } else { "," };
+        for (bucket, bound) in self.buckets.iter().zip(BUCKETS) {
+            let _ = writeln!(out, "{name}_bucket{{{labels}{sep}le=\"{bound}\"}} {bucket}");
+        }
+        let _ = writeln!(
+            out,
+            "{name}_bucket{{{labels}{sep}le=\"+Inf\"}} {}",
+            self.count
+        );
+        let labels = if labels.is_empty() {
+            String::new()
+        } else {
+            format!("{{{labels}}}")
+        };
+        let _ = writeln!(out, "{name}_sum{labels} {}", self.sum);
+        let _ = writeln!(out, "{name}_count{labels} {}", self.count);
+    }
+}
+
+#[derive(Debug, Default)]
+struct Inner {
+    order_commands: BTreeMap<&'static str, Histogram>,
+    order_command_errors: BTreeMap<&'static str, u64>,
+    event_broadcast_lag: Histogram,
+    redb_transactions: Histogram,
+    redb_writer_wait: Histogram,
+    redb_slow_commits: u64,
+    /// (method, route, status)
+    http_requests: BTreeMap<(String, String, u16), u64>,
+    http_durations: BTreeMap<String, Histogram>,
+    print_failures: BTreeMap<PrintFailure, u64>,
+}
+
+/// 进程级指标注册表 (ServerState 持有，OrdersManager / 打印 worker 共享)
+#[derive(Debug, Default)]
+pub struct Metrics {
+    inner: Mutex<Inner>,
+}
+
+impl Metrics {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// 订单命令耗时 (`command` = 命令类型，如 `ADD_ITEMS`)
+    pub fn observe_order_command(&self, command: &'static str, elapsed: Duration, success: bool) {
+        let mut inner = self.inner.lock();
+        inner
+            .order_commands
+            .entry(command)
+            .or_default()
+            .observe(elapsed);
+        if !success {
+            *inner.order_command_errors.entry(command).or_default() += 1;
+        }
+    }
+
+    /// 事件从产生到广播给客户端的延迟
+    pub fn observe_event_broadcast_lag(&self, lag: Duration) {
+        self.inner.lock().event_broadcast_lag.observe(lag);
+    }
+
+    /// redb 写事务耗时 (begin_write → commit)
+    pub fn observe_redb_transaction(&self, elapsed: Duration) {
+        self.inner.lock().redb_transactions.observe(elapsed);
+    }
+
+    /// 订单命令等待 redb 写事务 (单写者) 的时间
+    pub fn observe_redb_writer_wait(&self, elapsed: Duration) {
+        self.inner.lock().redb_writer_wait.observe(elapsed);
+    }
+
+    /// 提交耗时超过告警阈值的订单命令
+    pub fn inc_slow_commit(&self) {
+        self.inner.lock().redb_slow_commits += 1;
+    }
+
+    /// HTTP 请求 (`route` 为路由模板)
+    pub fn observe_http_request(&self, method: &str, route: &str, status: u16, elapsed: Duration) {
+        let mut inner = self.inner.lock();
+        *inner
+            .http_requests
+            .entry((method.to_string(), route.to_string(), status))
+            .or_default() += 1;
+        inner
+            .http_durations
+            .entry(route.to_string())
+            .or_default()
+            .observe(elapsed);
+    }
+
+    /// 打印失败计数
+    pub fn inc_print_failure(&self, kind: PrintFailure) {
+        *self.inner.lock().print_failures.entry(kind).or_default() += 1;
+    }
+
+    /// 渲染 Prometheus 文本格式 (gauge 由调用方在抓取时采样)
+    pub fn render(&self, connected_clients: usize) -> String {
+        let inner = self.inner.lock();
+        let mut out = String::with_capacity(4096);
+
+        header(
+            &mut out,
+            "crab_order_command_duration_seconds",
+            "histogram",
+            "Order command processing time by command type",
+        );
+        for (command, histogram) in &inner.order_commands {
+            histogram.render(
+                &mut out,
+                "crab_order_command_duration_seconds",
+                &format!("command=\"{command}\""),
+            );
+        }
+
+        header(
+            &mut out,
+            "crab_order_command_errors_total",
+            "counter",
+            "Order commands rejected or failed by command type",
+        );
+        for (command, count) in &inner.order_command_errors {
+            let _ = writeln!(
+                out,
+                "crab_order_command_errors_total{{command=\"{command}\"}} {count}"
+            );
+        }
+
+        header(
+            &mut out,
+            "crab_event_broadcast_lag_seconds",
+            "histogram",
+            "Delay between an order event and its broadcast to clients",
+        );
+        inner
+            .event_broadcast_lag
+            .render(&mut out, "crab_event_broadcast_lag_seconds", "");
+
+        header(
+            &mut out,
+            "crab_bus_connected_clients",
+            "gauge",
+            "Clients connected to the message bus",
+        );
+        let _ = writeln!(out, "crab_bus_connected_clients {connected_clients}");
+
+        header(
+            &mut out,
+            "crab_redb_transaction_duration_seconds",
+            "histogram",
+            "Order storage write transaction time",
+        );
+        inner
+            .redb_transactions
+            .render(&mut out, "crab_redb_transaction_duration_seconds", "");
+
+        header(
+            &mut out,
+            "crab_redb_writer_wait_seconds",
+            "histogram",
+            "Time order commands waited for the redb write transaction",
+        );
+        inner
+            .redb_writer_wait
+            .render(&mut out, "crab_redb_writer_wait_seconds", "");
+
+        header(
+            &mut out,
+            "crab_redb_slow_commits_total",
+            "counter",
+            "Order commands whose commit exceeded the latency alert threshold",
+        );
+        let _ = writeln!(
+            out,
+            "crab_redb_slow_commits_total {}",
+            inner.redb_slow_commits
+        );
+
+        header(
+            &mut out,
+            "crab_http_requests_total",
+            "counter",
+            "HTTP requests by method, route and status",
+        );
+        for ((method, route, status), count) in &inner.http_requests {
+            let _ = writeln!(
+                out,
+                "crab_http_requests_total{{method=\"{method}\",route=\"{}\",status=\"{status}\"}} {count}",
+                escape(route)
+            );
+        }
+
+        header(
+            &mut out,
+            "crab_http_request_duration_seconds",
+            "histogram",
+            "HTTP request handling time by route",
+        );
+        for (route, histogram) in &inner.http_durations {
+            histogram.render(
+                &mut out,
+                "crab_http_request_duration_seconds",
+                &format!("route=\"{}\"", escape(route)),
+            );
+        }
+
+        header(
+            &mut out,
+            "crab_print_failures_total",
+            "counter",
+            "Failed print attempts by kind",
+        );
+        for (kind, count) in &inner.print_failures {
+            let _ = writeln!(
+                out,
+                "crab_print_failures_total{{kind=\"{}\"}} {count}",
+                kind.as_str()
+            );
+        }
+
+        out
+    }
+}
+
+fn header(out: &mut String, name: &str, kind: &str, help: &str) {
+    let _ = writeln!(out, "# HELP {name} {help}");
+    let _ = writeln!(out, "# TYPE {name} {kind}");
+}
+
+/// 标签值转义 (`\`、`"`、换行)
+fn escape(value: &str) -> String {
+    value
+        .replace('\\', "\\\\")
+        .replace('"', "\\\"")
+        .replace('\n', "\\n")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_histogram_buckets_are_cumulative() {
+        let mut h = Histogram::default();
+        h.observe(Duration::from_millis(3));
+        h.observe(Duration::from_millis(200));
+        h.observe(Duration::from_secs(60));
+        assert_eq!(h.count, 3);
+        // le=0.001
+        assert_eq!(h.buckets[0], 0);
+        // le=0.005
+        assert_eq!(h.buckets[2], 1);
+        // le=0.25
+        assert_eq!(h.buckets[7], 2);
+        // le=10 (60s 只计入 +Inf)
+        assert_eq!(h.buckets[BUCKETS.len() - 1], 2);
+    }
+
+    #[test]
+    fn test_render_exposition() {
+        let metrics = Metrics::new();
+        metrics.observe_order_command("ADD_ITEMS", Duration::from_millis(4), true);
+        metrics.observe_order_command("ADD_ITEMS", Duration::from_millis(8), false);
+        metrics.observe_http_request("GET", "/api/orders/{id}", 200, Duration::from_millis(2));
+        metrics.inc_print_failure(PrintFailure::Kitchen);
+        metrics.inc_print_failure(PrintFailure::Kitchen);
+
+        let text = metrics.render(3);
+        assert!(text.contains("# TYPE crab_order_command_duration_seconds histogram"));
+        assert!(
+            text.contains("crab_order_command_duration_seconds_count{command=\"ADD_ITEMS\"} 2")
+        );
+        assert!(text.contains("crab_order_command_errors_total{command=\"ADD_ITEMS\"} 1"));
+        assert!(text.contains("crab_bus_connected_clients 3"));
+        assert!(text.contains(
+            "crab_http_requests_total{method=\"GET\",route=\"/api/orders/{id}\",status=\"200\"} 1"
+        ));
+        assert!(text.contains("crab_print_failures_total{kind=\"kitchen\"} 2"));
+        assert!(text.contains("crab_event_broadcast_lag_seconds_bucket{le=\"+Inf\"} 0"));
+        assert!(text.contains("crab_redb_slow_commits_total 0"));
+    }
+}
@@ -3,19 +3,37 @@
 //! # 模块结构
 //!
 //! - [`Config`] - 服务器配置
+//! - [`ConfigLoader`] - 分层配置加载 (Profile / 环境变量 / 运行时覆盖)
 //! - [`ServerState`] - 服务器状态
 //! - [`Server`] - HTTP 服务器
 //! - [`BackgroundTasks`] - 后台任务管理
 //! - [`EventRouter`] - 事件路由与分发
+//! - [`Readiness`] - 启动就绪状态
+//! - [`LoadShedder`] - 高峰期按优先级拒绝低优先级请求
+//! - [`Metrics`] - Prometheus 指标 (`/metrics`)
+//! - [`ListenerControl`] - 监听器热重绑定
+//! - [`ShutdownCoordinator`] - 分阶段关闭
 
 pub mod config;
 pub mod event_router;
+pub mod listeners;
+pub mod load_shed;
+pub mod metrics;
+pub mod profile;
+pub mod readiness;
 pub mod server;
+pub mod shutdown;
 pub mod state;
 pub mod tasks;
 
 pub use config::Config;
-pub use event_router::{EventChannels, EventRouter};
+pub use event_router::{EventChannels, EventQueues, EventRouter};
+pub use listeners::{ListenerControl, ListenerPorts};
+pub use load_shed::{LoadShedder, Priority};
+pub use metrics::{Metrics, PrintFailure};
+pub use profile::{ConfigError, ConfigLoader, ConfigSource, Profile};
+pub use readiness::{Component, ComponentState, Readiness};
 pub use server::Server;
+pub use shutdown::{ShutdownCoordinator, ShutdownReport};
 pub use state::ServerState;
 pub use tasks::{BackgroundTasks, TaskKind};
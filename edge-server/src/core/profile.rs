@@ -0,0 +1,728 @@
+//! 配置 Profile 与分层加载
+//!
+//! 优先级 (后者覆盖前者)：
+//!
+//! 1. 内置默认值 ([`ConfigBuilder::build`]) + Profile 默认值 ([`Profile::defaults`])
+//! 2. Profile 文件 (`profiles/{profile}.env`，`KEY=VALUE` 格式，可由 `CONFIG_PROFILE_FILE` 指定)
+//! 3. 环境变量 (含 `.env`)
+//! 4. 运行时覆盖 ([`ConfigLoader::set`]，如托管模式按租户改写)
+//!
+//! Profile 由 `CONFIG_PROFILE` 选择 (dev / staging / prod / kiosk)，未设置时按
+//! `ENVIRONMENT` 推断。所有值在启动时按类型校验，错误带键名与来源 ([`ConfigError`])；
+//! staging / prod / kiosk 强制 mTLS 客户端证书，prod / kiosk 另要求外部服务走 HTTPS。
+//!
+//! [`inspect`] 生成脱敏视图 (`GET /api/admin/config`)，密钥类配置只显示是否已设置。
+
+use std::collections::BTreeMap;
+use std::fmt;
+use std::path::PathBuf;
+use std::str::FromStr;
+
+use chrono_tz::Tz;
+use serde::Serialize;
+use thiserror::Error;
+
+use super::config::{Config, ConfigBuilder};
+
+/// 默认 Profile 文件目录 (相对工作目录)
+const DEFAULT_PROFILE_DIR: &str = "profiles";
+
+/// 部署 Profile
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum Profile {
+    /// 开发：mTLS 客户端证书可选，开启活跃订单缓存校验
+    #[default]
+    Dev,
+    /// 预发布：强制 mTLS，开启事件追加日志便于排障
+    Staging,
+    /// 生产：强制 mTLS，外部服务必须 HTTPS
+    Prod,
+    /// 自助点餐机：同生产，单机低并发，不推送经营指标
+    Kiosk,
+}
+
+impl Profile {
+    pub fn as_str(self) -> &'static str {
+        match self {
+            Self::Dev => "dev",
+            Self::Staging => "staging",
+            Self::Prod => "prod",
+            Self::Kiosk => "kiosk",
+        }
+    }
+
+    /// 对应的 `Config.environment`
+    pub fn environment(self) -> &'static str {
+        match self {
+            Self::Dev => "development",
+            Self::Staging => "staging",
+            Self::Prod | Self::Kiosk => "production",
+        }
+    }
+
+    /// 是否强制 mTLS 客户端证书
+    pub fn requires_client_cert(self) -> bool {
+        !matches!(self, Self::Dev)
+    }
+
+    /// 是否要求外部服务 (云端 / PMS / 消息网关) 走 HTTPS
+    pub fn is_strict(self) -> bool {
+        matches!(self, Self::Prod | Self::Kiosk)
+    }
+
+    /// Profile 默认值 (优先级低于 Profile 文件)
+    pub fn defaults(self) -> &'static [(&'static str, &'static str)] {
+        match self {
+            Self::Dev => &[
+                ("REQUIRE_CLIENT_CERT", "false"),
+                ("ORDERS_CACHE_VERIFY", "true"),
+            ],
+            Self::Staging => &[("EVENT_JOURNAL", "true")],
+            Self::Prod => &[],
+            Self::Kiosk => &[("MAX_CONNECTIONS", "50"), ("KPI_INTERVAL_SECS", "0")],
+        }
+    }
+}
+
+impl fmt::Display for Profile {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl FromStr for Profile {
+    type Err = ConfigError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.trim().to_ascii_lowercase().as_str() {
+            "dev" | "development" => Ok(Self::Dev),
+            "staging" => Ok(Self::Staging),
+            "prod" | "production" => Ok(Self::Prod),
+            "kiosk" => Ok(Self::Kiosk),
+            other => Err(ConfigError::UnknownProfile(other.to_string())),
+        }
+    }
+}
+
+/// 配置值来源
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ConfigSource {
+    /// 内置默认值
+    #[default]
+    Default,
+    /// Profile 默认值
+    Profile,
+    /// Profile 文件
+    ProfileFile,
+    /// 环境变量
+    Env,
+    /// 运行时覆盖
+    Override,
+}
+
+impl fmt::Display for ConfigSource {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(match self {
+            Self::Default => "default",
+            Self::Profile => "profile default",
+            Self::ProfileFile => "profile file",
+            Self::Env => "environment",
+            Self::Override => "override",
+        })
+    }
+}
+
+/// 启动时配置错误
+#[derive(Debug, Error)]
+pub enum ConfigError {
+    #[error("Unknown config profile '{0}' (expected dev, staging, prod or kiosk)")]
+    UnknownProfile(String),
+
+    #[error("Failed to read profile file {path}: {reason}")]
+    ProfileFile { path: PathBuf, reason: String },
+
+    #[error("Unknown config key {key} ({origin})")]
+    UnknownKey { key: String, origin: ConfigSource },
+
+    #[error("Invalid {key}='{value}' ({origin}): {reason}")]
+    Invalid {
+        key: &'static str,
+        value: String,
+        origin: ConfigSource,
+        reason: String,
+    },
+
+    #[error("{key} is not allowed in profile {profile}: {reason}")]
+    Forbidden {
+        key: &'static str,
+        profile: Profile,
+        reason: &'static str,
+    },
+}
+
+type Apply = fn(ConfigBuilder, &str) -> Result<ConfigBuilder, String>;
+type Read = fn(&Config) -> Option<String>;
+
+/// 可分层配置的键
+struct KeySpec {
+    name: &'static str,
+    /// 密钥类：检视时只显示是否已设置
+    secret: bool,
+    apply: Apply,
+    read: Read,
+}
+
+const fn key(name: &'static str, apply: Apply, read: Read) -> KeySpec {
+    KeySpec {
+        name,
+        secret: false,
+        apply,
+        read,
+    }
+}
+
+const fn secret(name: &'static str, apply: Apply, read: Read) -> KeySpec {
+    KeySpec {
+        name,
+        secret: true,
+        apply,
+        read,
+    }
+}
+
+fn parse<T>(value: &str) -> Result<T, String>
+where
+    T: FromStr,
+    T::Err: fmt::Display,
+{
+    value.trim().parse().map_err(|e: T::Err| e.to_string())
+}
+
+fn parse_bool(value: &str) -> Result<bool, String> {
+    match value.trim().to_ascii_lowercase().as_str() {
+        "1" | "true" | "yes" | "on" => Ok(true),
+        "0" | "false" | "no" | "off" => Ok(false),
+        _ => Err("expected true or false".into()),
+    }
+}
+
+/// 空值 = 禁用；否则须为合法 URL
+fn parse_url(value: &str) -> Result<String, String> {
+    let value = value.trim();
+    if !value.is_empty() {
+        reqwest::Url::parse(value).map_err(|e| e.to_string())?;
+    }
+    Ok(value.to_string())
+}
+
+fn parse_tz(value: &str) -> Result<String, String> {
+    value.trim().parse::<Tz>().map_err(|e| e.to_string())?;
+    Ok(value.trim().to_string())
+}
+
+/// kebab-case 枚举的字符串形式
+fn serialized<T: Serialize>(value: &T) -> Option<String> {
+    serde_json::to_value(value)
+        .ok()
+        .and_then(|v| v.as_str().map(String::from))
+}
+
+const KEYS: &[KeySpec] = &[
+    key(
+        "WORK_DIR",
+        |b, v| Ok(b.work_dir(v.trim())),
+        |c| Some(c.work_dir.clone()),
+    ),
+    key(
+        "HTTP_PORT",
+        |b, v| Ok(b.http_port(parse(v)?)),
+        |c| Some(c.http_port.to_string()),
+    ),
+    key(
+        "MESSAGE_TCP_PORT",
+        |b, v| Ok(b.message_tcp_port(parse(v)?)),
+        |c| Some(c.message_tcp_port.to_string()),
+    ),
+    key(
+        "AUTH_SERVER_URL",
+        |b, v| Ok(b.auth_server_url(parse_url(v)?)),
+        |c| Some(c.auth_server_url.clone()),
+    ),
+    key(
+        "MAX_CONNECTIONS",
+        |b, v| Ok(b.max_connections(parse(v)?)),
+        |c| Some(c.max_connections.to_string()),
+    ),
+    key(
+        "REQUEST_TIMEOUT_MS",
+        |b, v| Ok(b.request_timeout_ms(parse(v)?)),
+        |c| Some(c.request_timeout_ms.to_string()),
+    ),
+    key(
+        "SHUTDOWN_TIMEOUT_MS",
+        |b, v| Ok(b.shutdown_timeout_ms(parse(v)?)),
+        |c| Some(c.shutdown_timeout_ms.to_string()),
+    ),
+    key(
+        "TIMEZONE",
+        |b, v| Ok(b.timezone(parse_tz(v)?)),
+        |c| Some(c.timezone.name().to_string()),
+    ),
+    key(
+        "CRAB_CLOUD_URL",
+        |b, v| Ok(b.cloud_url(parse_url(v)?)),
+        |c| c.cloud_url.clone(),
+    ),
+    key(
+        "REQUIRE_CLIENT_CERT",
+        |b, v| Ok(b.require_client_cert(parse_bool(v)?)),
+        |c| Some(c.require_client_cert.to_string()),
+    ),
+    key(
+        "ORDERS_CACHE_VERIFY",
+        |b, v| Ok(b.orders_cache_verify(parse_bool(v)?)),
+        |c| Some(c.orders_cache_verify.to_string()),
+    ),
+    key(
+        "EVENT_JOURNAL",
+        |b, v| Ok(b.event_journal(parse_bool(v)?)),
+        |c| Some(c.event_journal.to_string()),
+    ),
+    key(
+        "KPI_INTERVAL_SECS",
+        |b, v| Ok(b.kpi_interval_secs(parse(v)?)),
+        |c| Some(c.kpi_interval_secs.to_string()),
+    ),
+    key(
+        "RESOURCE_WATCHDOG_INTERVAL_SECS",
+        |b, v| Ok(b.resource_watchdog_interval_secs(parse(v)?)),
+        |c| Some(c.resource_watchdog_interval_secs.to_string()),
+    ),
+    key(
+        "PUBLIC_STATUS",
+        |b, v| Ok(b.public_status(parse_bool(v)?)),
+        |c| Some(c.public_status.to_string()),
+    ),
+    key(
+        "PMS_URL",
+        |b, v| Ok(b.pms_url(parse_url(v)?)),
+        |c| c.pms_url.clone(),
+    ),
+    secret(
+        "PMS_API_KEY",
+        |b, v| Ok(b.pms_api_key(v.trim())),
+        |c| c.pms_api_key.clone(),
+    ),
+    key(
+        "NOTIFY_GATEWAY_URL",
+        |b, v| Ok(b.notify_gateway_url(parse_url(v)?)),
+        |c| c.notify_gateway_url.clone(),
+    ),
+    secret(
+        "NOTIFY_GATEWAY_API_KEY",
+        |b, v| Ok(b.notify_gateway_api_key(v.trim())),
+        |c| c.notify_gateway_api_key.clone(),
+    ),
+    key(
+        "CLIENT_QUEUE_CAPACITY",
+        |b, v| Ok(b.client_queue_capacity(parse(v)?)),
+        |c| Some(c.client_queue_capacity.to_string()),
+    ),
+    key(
+        "CLIENT_QUEUE_OVERFLOW",
+        |b, v| Ok(b.client_queue_overflow(parse(v)?)),
+        |c| serialized(&c.client_queue_overflow),
+    ),
+    key(
+        "LOAD_SHED_LATENCY_MS",
+        |b, v| Ok(b.load_shed_latency_ms(parse(v)?)),
+        |c| Some(c.load_shed_latency_ms.to_string()),
+    ),
+    key(
+        "COMMIT_LATENCY_ALERT_MS",
+        |b, v| Ok(b.commit_latency_alert_ms(parse(v)?)),
+        |c| Some(c.commit_latency_alert_ms.to_string()),
+    ),
+    key(
+        "LOG_DIR",
+        |b, v| Ok(b.log_dir(v.trim())),
+        |c| c.log_dir.clone(),
+    ),
+    key(
+        "EVENT_RETENTION_DAYS",
+        |b, v| Ok(b.event_retention_days(parse(v)?)),
+        |c| Some(c.event_retention_days.to_string()),
+    ),
+    key(
+        "FISCAL_DEVICE_URL",
+        |b, v| Ok(b.fiscal_device_url(v.trim())),
+        |c| c.fiscal_device_url.clone(),
+    ),
+    secret(
+        "FISCAL_DEVICE_API_KEY",
+        |b, v| Ok(b.fiscal_device_api_key(v.trim())),
+        |c| c.fiscal_device_api_key.clone(),
+    ),
+    key(
+        "FISCAL_POLICY",
+        |b, v| Ok(b.fiscal_policy(parse(v)?)),
+        |c| serialized(&c.fiscal_policy),
+    ),
+];
+
+fn spec(name: &str) -> Option<&'static KeySpec> {
+    KEYS.iter().find(|k| k.name == name)
+}
+
+/// Profile 文件位置
+#[derive(Debug, Clone)]
+enum ProfileFile {
+    /// 不读取
+    None,
+    /// 存在则读取 (默认目录)
+    Optional(PathBuf),
+    /// 必须存在 (显式指定)
+    Required(PathBuf),
+}
+
+/// 分层配置加载器
+#[derive(Debug, Clone)]
+pub struct ConfigLoader {
+    profile: Profile,
+    profile_file: ProfileFile,
+    env: Vec<(String, String)>,
+    overrides: Vec<(String, String)>,
+}
+
+impl ConfigLoader {
+    /// 指定 Profile，不读取 Profile 文件与环境变量
+    pub fn new(profile: Profile) -> Self {
+        Self {
+            profile,
+            profile_file: ProfileFile::None,
+            env: Vec::new(),
+            overrides: Vec::new(),
+        }
+    }
+
+    /// 从进程环境选择 Profile 并收集环境变量
+    ///
+    /// | 变量 | 说明 |
+    /// |------|------|
+    /// | CONFIG_PROFILE | dev / staging / prod / kiosk (未设置时按 ENVIRONMENT 推断，默认 dev) |
+    /// | CONFIG_PROFILE_FILE | Profile 文件路径 (须存在；默认 `profiles/{profile}.env`，不存在则跳过) |
+    pub fn from_env() -> Result<Self, ConfigError> {
+        let profile = match std::env::var("CONFIG_PROFILE") {
+            Ok(p) if !p.trim().is_empty() => p.parse()?,
+            _ => std::env::var("ENVIRONMENT")
+                .ok()
+                .filter(|e| !e.trim().is_empty())
+                .map(|e| e.parse())
+                .transpose()?
+                .unwrap_or_default(),
+        };
+        let profile_file = match std::env::var("CONFIG_PROFILE_FILE") {
+            Ok(path) if !path.trim().is_empty() => ProfileFile::Required(PathBuf::from(path)),
+            _ => ProfileFile::Optional(
+                PathBuf::from(DEFAULT_PROFILE_DIR).join(format!("{profile}.env")),
+            ),
+        };
+        Ok(Self {
+            profile_file,
+            ..Self::new(profile)
+        }
+        .env(std::env::vars()))
+    }
+
+    /// 读取指定的 Profile 文件 (须存在)
+    pub fn profile_file(mut self, path: impl Into<PathBuf>) -> Self {
+        self.profile_file = ProfileFile::Required(path.into());
+        self
+    }
+
+    /// 环境变量层 (只取已知键，空值视为未设置)
+    pub fn env(mut self, vars: impl IntoIterator<Item = (String, String)>) -> Self {
+        self.env = vars
+            .into_iter()
+            .filter(|(k, v)| spec(k).is_some() && !v.trim().is_empty())
+            .collect();
+        self
+    }
+
+    /// 运行时覆盖 (最高优先级)
+    pub fn set(mut self, key: impl Into<String>, value: impl Into<String>) -> Self {
+        self.overrides.push((key.into(), value.into()));
+        self
+    }
+
+    fn read_profile_file(&self) -> Result<Vec<(String, String)>, ConfigError> {
+        let path = match &self.profile_file {
+            ProfileFile::None => return Ok(Vec::new()),
+            ProfileFile::Optional(path) if !path.exists() => return Ok(Vec::new()),
+            ProfileFile::Optional(path) | ProfileFile::Required(path) => path,
+        };
+        let file_error = |reason: String| ConfigError::ProfileFile {
+            path: path.clone(),
+            reason,
+        };
+        dotenvy::from_path_iter(path)
+            .map_err(|e| file_error(e.to_string()))?
+            .map(|entry| entry.map_err(|e| file_error(e.to_string())))
+            .collect()
+    }
+
+    /// 合并各层、按类型校验并构建配置
+    pub fn load(self) -> Result<Config, ConfigError> {
+        let profile = self.profile;
+        let defaults = profile
+            .defaults()
+            .iter()
+            .map(|(k, v)| (k.to_string(), v.to_string()));
+        let layers = [
+            (ConfigSource::Profile, defaults.collect::<Vec<_>>()),
+            (ConfigSource::ProfileFile, self.read_profile_file()?),
+            (ConfigSource::Env, self.env),
+            (ConfigSource::Override, self.overrides),
+        ];
+
+        let mut merged: BTreeMap<&'static str, (String, ConfigSource)> = BTreeMap::new();
+        for (source, entries) in layers {
+            for (key, value) in entries {
+                let Some(known) = spec(&key) else {
+                    return Err(ConfigError::UnknownKey {
+                        key,
+                        origin: source,
+                    });
+                };
+                merged.insert(known.name, (value, source));
+            }
+        }
+
+        let mut builder = Config::builder()
+            .profile(profile)
+            .environment(profile.environment());
+        for (&name, (value, source)) in &merged {
+            let known = spec(name).expect("merged keys are known");
+            builder = (known.apply)(builder, value).map_err(|reason| ConfigError::Invalid {
+                key: known.name,
+                value: value.clone(),
+                origin: *source,
+                reason,
+            })?;
+        }
+        let mut config = builder.build();
+        config.config_sources = merged
+            .into_iter()
+            .map(|(name, (_, source))| (name, source))
+            .collect();
+        validate(&config)?;
+        Ok(config)
+    }
+}
+
+/// Profile 约束 + 跨字段校验
+fn validate(config: &Config) -> Result<(), ConfigError> {
+    let profile = config.profile;
+    if profile.requires_client_cert() && !config.require_client_cert {
+        return Err(ConfigError::Forbidden {
+            key: "REQUIRE_CLIENT_CERT",
+            profile,
+            reason: "mTLS client certificates are mandatory outside dev",
+        });
+    }
+    if profile.is_strict() {
+        let outbound = [
+            ("CRAB_CLOUD_URL", &config.cloud_url),
+            ("PMS_URL", &config.pms_url),
+            ("NOTIFY_GATEWAY_URL", &config.notify_gateway_url),
+        ];
+        for (key, url) in outbound {
+            if url.as_deref().is_some_and(|u| !u.starts_with("https://")) {
+                return Err(ConfigError::Forbidden {
+                    key,
+                    profile,
+                    reason: "external services must use https",
+                });
+            }
+        }
+    }
+    if config.http_port != 0 && config.http_port == config.message_tcp_port {
+        return Err(ConfigError::Invalid {
+            key: "MESSAGE_TCP_PORT",
+            value: config.message_tcp_port.to_string(),
+            origin: config.source_of("MESSAGE_TCP_PORT"),
+            reason: "must differ from HTTP_PORT".into(),
+        });
+    }
+    Ok(())
+}
+
+/// 单个配置项 (脱敏)
+#[derive(Debug, Clone, Serialize)]
+pub struct ConfigEntry {
+    pub key: &'static str,
+    /// 生效值 (密钥类为 `"***"`，未设置为 None)
+    pub value: Option<String>,
+    pub source: ConfigSource,
+    pub secret: bool,
+}
+
+/// 配置检视结果 (`GET /api/admin/config`)
+#[derive(Debug, Clone, Serialize)]
+pub struct ConfigInspection {
+    pub profile: Profile,
+    pub environment: String,
+    pub requires_client_cert: bool,
+    pub strict: bool,
+    /// OTLP 导出 (仅读环境变量，不参与分层)
+    pub otlp_enabled: bool,
+    pub entries: Vec<ConfigEntry>,
+}
+
+/// 生成脱敏配置视图
+pub fn inspect(config: &Config) -> ConfigInspection {
+    let entries = KEYS
+        .iter()
+        .map(|spec| {
+            let value = (spec.read)(config);
+            ConfigEntry {
+                key: spec.name,
+                value: if spec.secret {
+                    value.map(|_| "***".to_string())
+                } else {
+                    value
+                },
+                source: config.source_of(spec.name),
+                secret: spec.secret,
+            }
+        })
+        .collect();
+    ConfigInspection {
+        profile: config.profile,
+        environment: config.environment.clone(),
+        requires_client_cert: config.profile.requires_client_cert(),
+        strict: config.profile.is_strict(),
+        otlp_enabled: config.otlp.is_some(),
+        entries,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn vars(pairs: &[(&str, &str)]) -> Vec<(String, String)> {
+        pairs
+            .iter()
+            .map(|(k, v)| (k.to_string(), v.to_string()))
+            .collect()
+    }
+
+    #[test]
+    fn test_layers_override_in_order() {
+        let dir = tempfile::tempdir().unwrap();
+        let file = dir.path().join("kiosk.env");
+        std::fs::write(
+            &file,
+            "HTTP_PORT=4000\nMAX_CONNECTIONS=20\nPMS_API_KEY=s3cret\n",
+        )
+        .unwrap();
+
+        let config = ConfigLoader::new(Profile::Kiosk)
+            .profile_file(&file)
+            .env(vars(&[
+                ("HTTP_PORT", "5000"),
+                ("UNRELATED", "x"),
+                ("TIMEZONE", ""),
+            ]))
+            .set("HTTP_PORT", "6000")
+            .load()
+            .unwrap();
+
+        assert_eq!(config.http_port, 6000);
+        assert_eq!(config.source_of("HTTP_PORT"), ConfigSource::Override);
+        assert_eq!(config.max_connections, 20);
+        assert_eq!(
+            config.source_of("MAX_CONNECTIONS"),
+            ConfigSource::ProfileFile
+        );
+        assert_eq!(config.kpi_interval_secs, 0);
+        assert_eq!(config.source_of("KPI_INTERVAL_SECS"), ConfigSource::Profile);
+        assert_eq!(config.source_of("TIMEZONE"), ConfigSource::Default);
+        assert!(config.is_production());
+        assert!(config.require_client_cert);
+
+        let view = inspect(&config);
+        let pms_key = view
+            .entries
+            .iter()
+            .find(|e| e.key == "PMS_API_KEY")
+            .unwrap();
+        assert_eq!(pms_key.value.as_deref(), Some("***"));
+        assert_eq!(pms_key.source, ConfigSource::ProfileFile);
+    }
+
+    #[test]
+    fn test_typed_errors_and_profile_strictness() {
+        assert!(matches!(
+            ConfigLoader::new(Profile::Prod)
+                .set("HTTP_PORT", "http")
+                .load(),
+            Err(ConfigError::Invalid {
+                key: "HTTP_PORT",
+                origin: ConfigSource::Override,
+                ..
+            })
+        ));
+        assert!(matches!(
+            ConfigLoader::new(Profile::Prod)
+                .set("TIMEZONE", "Mars/Olympus")
+                .load(),
+            Err(ConfigError::Invalid {
+                key: "TIMEZONE",
+                ..
+            })
+        ));
+        assert!(matches!(
+            ConfigLoader::new(Profile::Prod)
+                .set("HTTP_PORTS", "1")
+                .load(),
+            Err(ConfigError::UnknownKey { .. })
+        ));
+        assert!(matches!(
+            ConfigLoader::new(Profile::Prod)
+                .profile_file("/nonexistent/prod.env")
+                .load(),
+            Err(ConfigError::ProfileFile { .. })
+        ));
+        assert!("cloud".parse::<Profile>().is_err());
+
+        // mTLS：dev 默认可选，其余 Profile 不允许关闭
+        let dev = ConfigLoader::new(Profile::Dev).load().unwrap();
+        assert!(!dev.require_client_cert);
+        assert!(matches!(
+            ConfigLoader::new(Profile::Staging)
+                .set("REQUIRE_CLIENT_CERT", "false")
+                .load(),
+            Err(ConfigError::Forbidden {
+                key: "REQUIRE_CLIENT_CERT",
+                ..
+            })
+        ));
+
+        // 外部服务 HTTPS：仅 prod / kiosk
+        let plain = [("PMS_URL", "http://pms.local/api")];
+        assert!(
+            ConfigLoader::new(Profile::Staging)
+                .env(vars(&plain))
+                .load()
+                .is_ok()
+        );
+        assert!(matches!(
+            ConfigLoader::new(Profile::Prod).env(vars(&plain)).load(),
+            Err(ConfigError::Forbidden { key: "PMS_URL", .. })
+        ));
+    }
+}
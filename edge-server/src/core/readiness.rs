@@ -0,0 +1,208 @@
+//! 启动就绪状态
+//!
+//! 关键路径（数据库、健康检查、消息总线）先启动，目录缓存、活跃订单快照和
+//! 活跃订单规则在后台并行预热。各组件就绪状态（含加载进度）通过 `/health/detailed`
+//! 暴露，预热完成前业务 API 返回 503（SystemBusy），消息处理器等待预热完成后
+//! 才开始处理客户端指令，重启后第一单即可命中缓存。
+
+use serde::Serialize;
+use std::collections::BTreeMap;
+use std::sync::Arc;
+use std::time::Instant;
+use tokio::sync::watch;
+
+/// 启动组件
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum Component {
+    /// SQLite + migrations
+    Database,
+    /// 订单 / 打印队列存储 (redb)
+    Storage,
+    /// 商品 / 分类内存缓存
+    Catalog,
+    /// 活跃订单快照缓存 (redb → 内存)
+    ActiveOrders,
+    /// 活跃订单的价格规则快照
+    OrderRules,
+}
+
+impl Component {
+    pub const ALL: &'static [Component] = &[
+        Self::Database,
+        Self::Storage,
+        Self::Catalog,
+        Self::ActiveOrders,
+        Self::OrderRules,
+    ];
+
+    /// 处理订单前必须完成预热的组件
+    pub const WARMUP: &'static [Component] = &[Self::Catalog, Self::ActiveOrders, Self::OrderRules];
+}
+
+/// 组件状态
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+#[serde(tag = "status", rename_all = "snake_case")]
+pub enum ComponentState {
+    Pending,
+    /// 加载中（已完成 / 总数）
+    Loading {
+        done: usize,
+        total: usize,
+    },
+    Ready {
+        /// 自启动起的耗时 (毫秒)
+        elapsed_ms: u64,
+    },
+    /// 初始化失败：服务降级运行（与预热失败时仅记录日志的行为一致）
+    Failed {
+        error: String,
+    },
+}
+
+impl ComponentState {
+    fn is_settled(&self) -> bool {
+        !matches!(self, Self::Pending | Self::Loading { .. })
+    }
+}
+
+/// 启动就绪追踪器（Clone 共享同一状态）
+#[derive(Debug, Clone)]
+pub struct Readiness {
+    started: Instant,
+    tx: Arc<watch::Sender<BTreeMap<Component, ComponentState>>>,
+}
+
+impl Default for Readiness {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Readiness {
+    pub fn new() -> Self {
+        let states = Component::ALL
+            .iter()
+            .map(|c| (*c, ComponentState::Pending))
+            .collect();
+        let (tx, _) = watch::channel(states);
+        Self {
+            started: Instant::now(),
+            tx: Arc::new(tx),
+        }
+    }
+
+    pub fn mark_ready(&self, component: Component) {
+        let elapsed_ms = self.started.elapsed().as_millis() as u64;
+        tracing::info!(component = ?component, elapsed_ms, "Component ready");
+        self.set(component, ComponentState::Ready { elapsed_ms });
+    }
+
+    /// 报告加载进度（总数已知的组件）
+    pub fn mark_progress(&self, component: Component, done: usize, total: usize) {
+        self.set(component, ComponentState::Loading { done, total });
+    }
+
+    pub fn mark_failed(&self, component: Component, error: impl Into<String>) {
+        let error = error.into();
+        tracing::error!(component = ?component, error = %error, "Component failed to initialize");
+        self.set(component, ComponentState::Failed { error });
+    }
+
+    fn set(&self, component: Component, state: ComponentState) {
+        self.tx.send_modify(|states| {
+            states.insert(component, state);
+        });
+    }
+
+    /// 本服务器实例的运行时长 (托管模式下每个租户独立计时)
+    pub fn uptime(&self) -> std::time::Duration {
+        self.started.elapsed()
+    }
+
+    /// 所有组件当前状态
+    pub fn snapshot(&self) -> BTreeMap<Component, ComponentState> {
+        self.tx.borrow().clone()
+    }
+
+    /// 预热是否结束（失败也视为结束，服务降级运行）
+    pub fn is_warm(&self) -> bool {
+        let states = self.tx.borrow();
+        Component::WARMUP
+            .iter()
+            .all(|c| states.get(c).is_some_and(ComponentState::is_settled))
+    }
+
+    /// 尚未就绪的预热组件
+    pub fn pending_warmup(&self) -> Vec<Component> {
+        let states = self.tx.borrow();
+        Component::WARMUP
+            .iter()
+            .filter(|c| !states.get(c).is_some_and(ComponentState::is_settled))
+            .copied()
+            .collect()
+    }
+
+    /// 等待预热结束
+    pub async fn wait_warm(&self) {
+        let mut rx = self.tx.subscribe();
+        let _ = rx
+            .wait_for(|states| {
+                Component::WARMUP
+                    .iter()
+                    .all(|c| states.get(c).is_some_and(ComponentState::is_settled))
+            })
+            .await;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[tokio::test]
+    async fn test_warmup_settles_on_ready_or_failed() {
+        let readiness = Readiness::new();
+        assert!(!readiness.is_warm());
+        assert_eq!(
+            readiness.pending_warmup(),
+            vec![
+                Component::Catalog,
+                Component::ActiveOrders,
+                Component::OrderRules
+            ]
+        );
+
+        let waiter = {
+            let readiness = readiness.clone();
+            tokio::spawn(async move { readiness.wait_warm().await })
+        };
+
+        readiness.mark_ready(Component::Catalog);
+        readiness.mark_ready(Component::ActiveOrders);
+        readiness.mark_progress(Component::OrderRules, 1, 3);
+        assert!(!readiness.is_warm());
+        assert_eq!(readiness.pending_warmup(), vec![Component::OrderRules]);
+        readiness.mark_failed(Component::OrderRules, "redb unavailable");
+        assert!(readiness.is_warm());
+
+        tokio::time::timeout(std::time::Duration::from_secs(1), waiter)
+            .await
+            .unwrap()
+            .unwrap();
+    }
+
+    #[test]
+    fn test_snapshot_serialization() {
+        let readiness = Readiness::new();
+        readiness.mark_failed(Component::Storage, "boom");
+        readiness.mark_progress(Component::OrderRules, 2, 5);
+        let json = serde_json::to_value(readiness.snapshot()).unwrap();
+        assert_eq!(json["database"]["status"], "pending");
+        assert_eq!(json["storage"]["status"], "failed");
+        assert_eq!(json["storage"]["error"], "boom");
+        assert_eq!(json["order_rules"]["status"], "loading");
+        assert_eq!(json["order_rules"]["done"], 2);
+        assert_eq!(json["order_rules"]["total"], 5);
+    }
+}
@@ -8,13 +8,18 @@
 //! 1. ServerState::initialize()      - 初始化服务和数据库
 //! 2. start_background_tasks()       - 启动无需 TLS 的后台任务
 //! 3. wait_for_activation()          - 等待设备激活 + 加载 mTLS 证书
+//! 3.5. verify_tenant_scope()        - 数据库归属校验 (跨租户拒绝)
 //! 4. subscription_check()           - 订阅阻止检查 (blocked → 指数退避重试)
 //! 4.5. p12_check()                  - P12 证书阻止检查 (缺失/过期 → 指数退避重试)
 //! 5. start_tls_tasks()              - 启动需要 TLS 的任务
 //! 6. https.start_server()           - 启动 HTTPS 服务
-//! 7. shutdown()                     - Graceful shutdown
+//! 7. ShutdownCoordinator::run()     - 分阶段关闭 (见 core::shutdown)
 //! ```
+//!
+//! 运行期间可通过 [`Server::listeners`] 重绑定端口 / 替换 TLS 证书，无需重启。
 
+use crate::core::listeners::{ListenerControl, ListenerPorts};
+use crate::core::shutdown::ShutdownCoordinator;
 use crate::core::tasks::BackgroundTasks;
 use crate::core::{Config, ServerState};
 use crate::utils::AppError;
@@ -26,23 +31,35 @@ pub struct Server {
     config: Config,
     state: Option<ServerState>,
     shutdown_token: CancellationToken,
+    listeners: ListenerControl,
 }
 
 impl Server {
     pub fn new(config: Config) -> Self {
+        let listeners = ListenerControl::new(Self::initial_ports(&config));
         Self {
             config,
             state: None,
             shutdown_token: CancellationToken::new(),
+            listeners,
         }
     }
 
     /// Create server with existing state (for sharing with oneshot)
     pub fn with_state(config: Config, state: ServerState) -> Self {
+        let listeners = ListenerControl::new(Self::initial_ports(&config));
         Self {
             config,
             state: Some(state),
             shutdown_token: CancellationToken::new(),
+            listeners,
+        }
+    }
+
+    fn initial_ports(config: &Config) -> ListenerPorts {
+        ListenerPorts {
+            http_port: config.http_port,
+            message_tcp_port: config.message_tcp_port,
         }
     }
 
@@ -51,6 +68,11 @@ impl Server {
         self.shutdown_token.clone()
     }
 
+    /// Get the listener control for runtime port / TLS changes
+    pub fn listeners(&self) -> ListenerControl {
+        self.listeners.clone()
+    }
+
     pub async fn run(&self) -> Result<(), AppError> {
         // ═══════════════════════════════════════════════════════════════════
         // Phase 1: Initialize
@@ -72,12 +94,18 @@ impl Server {
             Some(cfg) => cfg,
             None => {
                 tracing::info!("Shutdown requested during activation wait");
-                Self::cleanup(state, background_tasks).await;
+                self.cleanup(state, background_tasks).await;
                 return Ok(());
             }
         };
         let rustls_config = RustlsConfig::from_config(tls_config.clone());
 
+        // 租户作用域守卫：激活的租户必须拥有该数据库
+        if let Err(e) = state.verify_tenant_scope().await {
+            self.cleanup(state, background_tasks).await;
+            return Err(e);
+        }
+
         // ═══════════════════════════════════════════════════════════════════
         // Phase 4: Subscription check — 指数退避重试
         // ═══════════════════════════════════════════════════════════════════
@@ -90,7 +118,7 @@ impl Server {
             tokio::select! {
                 _ = self.shutdown_token.cancelled() => {
                     tracing::info!("Shutdown requested during subscription check");
-                    Self::cleanup(state, background_tasks).await;
+                    self.cleanup(state, background_tasks).await;
                     return Ok(());
                 }
                 _ = tokio::time::sleep(retry_delay) => {}
@@ -116,7 +144,7 @@ impl Server {
             tokio::select! {
                 _ = self.shutdown_token.cancelled() => {
                     tracing::info!("Shutdown requested during P12 check");
-                    Self::cleanup(state, background_tasks).await;
+                    self.cleanup(state, background_tasks).await;
                     return Ok(());
                 }
                 _ = tokio::time::sleep(p12_retry_delay) => {}
@@ -137,13 +165,13 @@ impl Server {
         // ═══════════════════════════════════════════════════════════════════
         // Phase 5: Start TLS-dependent tasks
         // ═══════════════════════════════════════════════════════════════════
-        state.start_tls_tasks(&mut background_tasks, tls_config);
+        state.start_tls_tasks(&mut background_tasks, tls_config, self.listeners.clone());
         state.print_activated_banner_content().await;
 
         // ═══════════════════════════════════════════════════════════════════
         // Phase 6: Start HTTPS server (blocks until shutdown)
         // ═══════════════════════════════════════════════════════════════════
-        let addr = std::net::SocketAddr::from(([0, 0, 0, 0], self.config.http_port));
+        let addr = std::net::SocketAddr::from(([0, 0, 0, 0], self.listeners.ports().http_port));
         tracing::info!("Crab Edge Server starting on {}", addr);
 
         let token = self.shutdown_token.clone();
@@ -154,7 +182,7 @@ impl Server {
 
         state
             .https
-            .start_server(rustls_config, shutdown)
+            .start_server(rustls_config, shutdown, self.listeners.clone())
             .await
             .map_err(|e| AppError::internal(format!("HTTPS server error: {e}")))?;
 
@@ -181,34 +209,20 @@ impl Server {
             tracing::error!("Failed to log system shutdown: {:?}", e);
         }
 
-        Self::cleanup(state, background_tasks).await;
+        self.cleanup(state, background_tasks).await;
         Ok(())
     }
 
-    /// 统一清理：停止后台任务 + 关闭数据库 + drain audit worker
-    async fn cleanup(state: ServerState, background_tasks: BackgroundTasks) {
-        // 立即打破循环引用 (Router → ServerState → HttpsService → Router)，
-        // 确保即使后续 await 被 abort，Arc<Database> 也能正确释放 redb 文件锁。
-        // 必须在任何 .await 之前执行（同步操作，不可被 cancel）。
-        state.https.clear_router();
-
-        // 删除 LOCK 文件（幂等操作，Phase 7 正常退出时已提前调用过，
-        // 此处确保 activation/subscription/P12 等早期 shutdown 路径也能清理）
-        state.audit_service.on_shutdown();
-        background_tasks.shutdown().await;
-        // 取出 audit worker handle，drop state 关闭 audit mpsc sender → worker drain 残留消息
-        let pool = state.pool.clone();
-        let audit_handle = state.audit_worker_handle.lock().await.take();
-        drop(state);
-        if let Some(handle) = audit_handle
-            && tokio::time::timeout(std::time::Duration::from_millis(500), handle)
-                .await
-                .is_err()
-        {
-            tracing::warn!("Audit worker drain timed out after 500ms");
+    /// 统一清理：按阶段关闭子系统 (见 [`ShutdownCoordinator`])，报告写入 data 目录
+    async fn cleanup(&self, state: ServerState, background_tasks: BackgroundTasks) {
+        // 外层 (hosting supervisor) 以 shutdown_timeout_ms 为上限 abort，预留 20% 写报告
+        let budget = std::time::Duration::from_millis(self.config.shutdown_timeout_ms * 4 / 5);
+        let report = ShutdownCoordinator::new(budget)
+            .run(state, background_tasks)
+            .await;
+        if let Err(e) = report.save(&self.config.shutdown_report_path()) {
+            tracing::warn!("Failed to write shutdown report: {}", e);
         }
-        // audit worker 完成后再关闭 pool，确保残留审计消息写入成功
-        pool.close().await;
     }
 
     /// Wait for activation and load TLS config (可取消)
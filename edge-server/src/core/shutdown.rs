@@ -0,0 +1,378 @@
+//! 分阶段关闭协调器
+//!
+//! 按依赖顺序停止各子系统，每个阶段有独立超时，总耗时不超过
+//! [`Config::shutdown_timeout_ms`](crate::core::Config)：
+//!
+//! ```text
+//! 1. stop_accepting   - HTTPS 已停止；消息总线停止接受新连接 (已建立连接继续收发)
+//! 2. drain_bus        - 等待总线广播 + 订单同步通道排空，然后断开客户端
+//! 3. flush_print      - 等待厨房打印通道排空
+//! 4. archive_sweep    - 等待归档通道排空 + 最终扫描待归档队列 (忽略退避)
+//! 5. stop_tasks       - 取消并等待所有后台任务
+//! 6. close_databases  - drain 审计 worker，关闭 SQLite 连接池 (redb 随 state 释放)
+//! ```
+//!
+//! 某阶段超时不影响后续阶段执行；结果汇总为 [`ShutdownReport`]，
+//! 写入 `data/shutdown_report.json` 供下次启动查看。
+
+use std::path::Path;
+use std::time::{Duration, Instant};
+
+use serde::{Deserialize, Serialize};
+
+use crate::core::{BackgroundTasks, ServerState};
+
+/// 队列排空轮询间隔
+const POLL_INTERVAL: Duration = Duration::from_millis(20);
+
+/// 关闭阶段
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ShutdownStage {
+    StopAccepting,
+    DrainBus,
+    FlushPrint,
+    ArchiveSweep,
+    StopTasks,
+    CloseDatabases,
+}
+
+/// 阶段结果
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum StageOutcome {
+    Completed,
+    TimedOut,
+    /// 子系统未启动 (如激活前关闭)
+    Skipped,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct StageReport {
+    pub stage: ShutdownStage,
+    pub outcome: StageOutcome,
+    pub elapsed_ms: u64,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub detail: Option<String>,
+}
+
+/// 关闭报告
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ShutdownReport {
+    pub started_at: i64,
+    pub elapsed_ms: u64,
+    pub stages: Vec<StageReport>,
+}
+
+impl ShutdownReport {
+    /// 所有阶段均未超时
+    pub fn is_clean(&self) -> bool {
+        self.stages
+            .iter()
+            .all(|s| s.outcome != StageOutcome::TimedOut)
+    }
+
+    pub fn save(&self, path: &Path) -> std::io::Result<()> {
+        let json = serde_json::to_vec_pretty(self).map_err(std::io::Error::other)?;
+        std::fs::write(path, json)
+    }
+
+    /// 读取上次关闭报告 (不存在返回 None)
+    pub fn load(path: &Path) -> std::io::Result<Option<Self>> {
+        match std::fs::read(path) {
+            Ok(bytes) => serde_json::from_slice(&bytes)
+                .map(Some)
+                .map_err(std::io::Error::other),
+            Err(e) if e.kind() == std::io::ErrorKind::NotFound => Ok(None),
+            Err(e) => Err(e),
+        }
+    }
+}
+
+/// 各阶段超时上限
+#[derive(Debug, Clone, Copy)]
+pub struct StageTimeouts {
+    pub drain_bus: Duration,
+    pub flush_print: Duration,
+    pub archive_sweep: Duration,
+    pub stop_tasks: Duration,
+    pub close_databases: Duration,
+}
+
+impl Default for StageTimeouts {
+    fn default() -> Self {
+        Self {
+            drain_bus: Duration::from_secs(1),
+            flush_print: Duration::from_secs(3),
+            archive_sweep: Duration::from_secs(3),
+            stop_tasks: Duration::from_secs(3),
+            close_databases: Duration::from_secs(1),
+        }
+    }
+}
+
+/// 关闭协调器
+pub struct ShutdownCoordinator {
+    budget: Duration,
+    timeouts: StageTimeouts,
+}
+
+/// 阶段计时 + 总预算
+struct Stages {
+    deadline: Instant,
+    reports: Vec<StageReport>,
+}
+
+impl Stages {
+    /// 阶段超时 (不超过剩余总预算)
+    fn timeout(&self, stage_timeout: Duration) -> Duration {
+        stage_timeout.min(self.deadline.saturating_duration_since(Instant::now()))
+    }
+
+    fn record(
+        &mut self,
+        stage: ShutdownStage,
+        started: Instant,
+        outcome: StageOutcome,
+        detail: Option<String>,
+    ) {
+        let elapsed_ms = started.elapsed().as_millis() as u64;
+        match outcome {
+            StageOutcome::TimedOut => {
+                tracing::warn!(?stage, elapsed_ms, detail = ?detail, "Shutdown stage timed out")
+            }
+            _ => tracing::info!(?stage, ?outcome, elapsed_ms, detail = ?detail, "Shutdown stage"),
+        }
+        self.reports.push(StageReport {
+            stage,
+            outcome,
+            elapsed_ms,
+            detail,
+        });
+    }
+}
+
+/// 轮询直到条件满足或超时
+async fn wait_until(timeout: Duration, mut done: impl FnMut() -> bool) -> StageOutcome {
+    let deadline = Instant::now() + timeout;
+    loop {
+        if done() {
+            return StageOutcome::Completed;
+        }
+        if Instant::now() >= deadline {
+            return StageOutcome::TimedOut;
+        }
+        tokio::time::sleep(POLL_INTERVAL).await;
+    }
+}
+
+impl ShutdownCoordinator {
+    /// `budget`: 全部阶段的总耗时上限
+    pub fn new(budget: Duration) -> Self {
+        Self {
+            budget,
+            timeouts: StageTimeouts::default(),
+        }
+    }
+
+    pub fn with_timeouts(mut self, timeouts: StageTimeouts) -> Self {
+        self.timeouts = timeouts;
+        self
+    }
+
+    /// 执行关闭 (HTTPS 服务器已停止后调用)
+    pub async fn run(&self, state: ServerState, tasks: BackgroundTasks) -> ShutdownReport {
+        // 同步操作，必须在任何 .await 之前执行 (不可被 abort 打断)：
+        // 打破循环引用 (Router → ServerState → HttpsService → Router)，确保 redb 文件锁释放；
+        // 删除 LOCK 文件 (幂等)
+        state.https.clear_router();
+        state.audit_service.on_shutdown();
+
+        let started_at = shared::util::now_millis();
+        let started = Instant::now();
+        let mut stages = Stages {
+            deadline: started + self.budget,
+            reports: Vec::with_capacity(6),
+        };
+        let bus = state.message_bus().clone();
+        let queues = tasks.event_queues().cloned();
+
+        // 1. 停止接受新连接
+        let t = Instant::now();
+        bus.stop_accepting();
+        stages.record(
+            ShutdownStage::StopAccepting,
+            t,
+            StageOutcome::Completed,
+            Some(format!("{} clients connected", bus.clients_count())),
+        );
+
+        // 2. 排空总线 (订单同步 → 广播 → 客户端)，然后断开客户端
+        let t = Instant::now();
+        let outcome = wait_until(stages.timeout(self.timeouts.drain_bus), || {
+            queues.as_ref().is_none_or(|q| q.sync() == 0)
+                && bus.sender().is_empty()
+                && bus.sender_to_server().is_empty()
+        })
+        .await;
+        let detail = (outcome == StageOutcome::TimedOut).then(|| {
+            format!(
+                "pending: sync={}, broadcast={}, inbound={}",
+                queues.as_ref().map_or(0, |q| q.sync()),
+                bus.sender().len(),
+                bus.sender_to_server().len()
+            )
+        });
+        bus.shutdown();
+        stages.record(ShutdownStage::DrainBus, t, outcome, detail);
+
+        // 3. 厨房打印
+        let t = Instant::now();
+        match &queues {
+            Some(q) => {
+                let outcome =
+                    wait_until(stages.timeout(self.timeouts.flush_print), || q.print() == 0).await;
+                let detail = (outcome == StageOutcome::TimedOut)
+                    .then(|| format!("{} print jobs pending", q.print()));
+                stages.record(ShutdownStage::FlushPrint, t, outcome, detail);
+            }
+            None => stages.record(ShutdownStage::FlushPrint, t, StageOutcome::Skipped, None),
+        }
+
+        // 4. 最终归档扫描
+        let t = Instant::now();
+        match tasks.archive_sweep().cloned() {
+            Some(sweep) => {
+                let timeout = stages.timeout(self.timeouts.archive_sweep);
+                let deadline = Instant::now() + timeout;
+                if let Some(q) = &queues {
+                    wait_until(timeout, || q.archive() == 0 && q.projection() == 0).await;
+                }
+                let remaining = deadline.saturating_duration_since(Instant::now());
+                let (outcome, detail) = match tokio::time::timeout(remaining, sweep.sweep()).await {
+                    Ok(Some(0)) => (StageOutcome::Completed, None),
+                    Ok(Some(n)) => (
+                        StageOutcome::Completed,
+                        Some(format!("{n} orders left pending (retried on next start)")),
+                    ),
+                    Ok(None) => (
+                        StageOutcome::Skipped,
+                        Some("archive worker already stopped".to_string()),
+                    ),
+                    Err(_) => (StageOutcome::TimedOut, None),
+                };
+                stages.record(ShutdownStage::ArchiveSweep, t, outcome, detail);
+            }
+            None => stages.record(ShutdownStage::ArchiveSweep, t, StageOutcome::Skipped, None),
+        }
+
+        // 5. 后台任务
+        let t = Instant::now();
+        let total = tasks.len();
+        let (outcome, detail) =
+            match tokio::time::timeout(stages.timeout(self.timeouts.stop_tasks), tasks.shutdown())
+                .await
+            {
+                Ok(true) => (StageOutcome::Completed, Some(format!("{total} tasks"))),
+                Ok(false) | Err(_) => (
+                    StageOutcome::TimedOut,
+                    Some(format!("{total} tasks, remaining aborted")),
+                ),
+            };
+        stages.record(ShutdownStage::StopTasks, t, outcome, detail);
+
+        // 6. 数据库：取出 audit worker handle，drop state 关闭 audit mpsc sender →
+        //    worker drain 残留消息；完成后再关闭 pool，确保审计写入成功
+        let t = Instant::now();
+        let pool = state.pool.clone();
+        let audit_handle = state.audit_worker_handle.lock().await.take();
+        drop(state);
+        let timeout = stages.timeout(self.timeouts.close_databases);
+        let audit_drained = match audit_handle {
+            Some(handle) => tokio::time::timeout(timeout, handle).await.is_ok(),
+            None => true,
+        };
+        pool.close().await;
+        let (outcome, detail) = if audit_drained {
+            (StageOutcome::Completed, None)
+        } else {
+            (
+                StageOutcome::TimedOut,
+                Some("audit worker drain timed out".to_string()),
+            )
+        };
+        stages.record(ShutdownStage::CloseDatabases, t, outcome, detail);
+
+        let report = ShutdownReport {
+            started_at,
+            elapsed_ms: started.elapsed().as_millis() as u64,
+            stages: stages.reports,
+        };
+        if report.is_clean() {
+            tracing::info!(elapsed_ms = report.elapsed_ms, "Shutdown completed cleanly");
+        } else {
+            tracing::warn!(
+                elapsed_ms = report.elapsed_ms,
+                "Shutdown completed with timed-out stages"
+            );
+        }
+        report
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[tokio::test]
+    async fn test_wait_until_and_stage_budget() {
+        let mut polls = 0;
+        let outcome = wait_until(Duration::from_secs(1), || {
+            polls += 1;
+            polls >= 3
+        })
+        .await;
+        assert_eq!(outcome, StageOutcome::Completed);
+        assert_eq!(
+            wait_until(Duration::from_millis(30), || false).await,
+            StageOutcome::TimedOut
+        );
+
+        // 阶段超时被剩余总预算截断
+        let stages = Stages {
+            deadline: Instant::now() + Duration::from_millis(100),
+            reports: Vec::new(),
+        };
+        assert!(stages.timeout(Duration::from_secs(3)) <= Duration::from_millis(100));
+    }
+
+    #[test]
+    fn test_report_round_trip() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("shutdown_report.json");
+        assert!(ShutdownReport::load(&path).unwrap().is_none());
+
+        let report = ShutdownReport {
+            started_at: 1,
+            elapsed_ms: 1200,
+            stages: vec![
+                StageReport {
+                    stage: ShutdownStage::DrainBus,
+                    outcome: StageOutcome::Completed,
+                    elapsed_ms: 40,
+                    detail: None,
+                },
+                StageReport {
+                    stage: ShutdownStage::ArchiveSweep,
+                    outcome: StageOutcome::TimedOut,
+                    elapsed_ms: 1100,
+                    detail: None,
+                },
+            ],
+        };
+        report.save(&path).unwrap();
+        let loaded = ShutdownReport::load(&path).unwrap().unwrap();
+        assert!(!loaded.is_clean());
+        assert_eq!(loaded.stages[1].stage, ShutdownStage::ArchiveSweep);
+    }
+}
@@ -1,20 +1,33 @@
 use dashmap::DashMap;
 use shared::cloud::SyncResource;
 use shared::message::{BusMessage, SyncChangeType, SyncPayload};
+use shared::models::PriceRule;
+use shared::order::OrderSnapshot;
 use sqlx::SqlitePool;
+use std::collections::HashMap;
 use std::path::PathBuf;
 use std::sync::Arc;
 use tokio::sync::mpsc;
 
 use crate::audit::{AuditService, AuditWorker};
 use crate::auth::JwtService;
+use crate::cloud::mail::MailOutbox;
+use crate::cloud::support::SupportRelay;
+use crate::cloud::transfer::TransferRelay;
 use crate::core::Config;
+use crate::core::listeners::ListenerControl;
+use crate::core::load_shed::LoadShedder;
+use crate::core::metrics::Metrics;
+use crate::core::readiness::{Component, Readiness};
 use crate::core::tasks::{BackgroundTasks, TaskKind};
 
 use crate::archiving::ArchiveWorker;
+use crate::customer_display::CustomerDisplayService;
 use crate::db::DbService;
+use crate::db::tenant_scope::TenantPool;
+use crate::notify::MessageGateway;
 use crate::orders::OrdersManager;
-use crate::orders::actions::open_table::load_matching_rules;
+use crate::orders::actions::open_table::load_order_rules;
 use crate::printing::{KitchenPrintService, PrintStorage};
 use crate::services::{
     ActivationService, CatalogService, CertService, HttpsService, MessageBusService,
@@ -132,6 +145,27 @@ pub struct ServerState {
     pub epoch: String,
     /// 审计日志 worker handle (shutdown 时 drain)
     pub audit_worker_handle: Arc<tokio::sync::Mutex<Option<tokio::task::JoinHandle<()>>>>,
+    /// 启动就绪状态 (各组件预热进度，/health 暴露)
+    pub readiness: Readiness,
+    /// 顾客消息网关 (取餐通知，未配置时为 None)
+    pub message_gateway: Option<Arc<dyn MessageGateway>>,
+    /// 远程支持会话中继 (CloudWorker 发送)
+    pub support: Arc<SupportRelay>,
+    /// 门店间调拨中继 (CloudWorker 发送，cloud 转发到收货门店)
+    pub transfers: Arc<TransferRelay>,
+    /// 邮件请求 outbox (CloudWorker 发送，cloud 按模板投递)
+    pub mail: Arc<MailOutbox>,
+    /// 高峰期负载保护 (订单命令优先)
+    pub load_shedder: Arc<LoadShedder>,
+    /// 顾客显示屏 (客显)
+    pub customer_display: Arc<CustomerDisplayService>,
+    /// Prometheus 指标 (`/metrics`)
+    pub metrics: Arc<Metrics>,
+    /// 宴会预订转单锁 (调度器与手动转单不会为同一预订重复开台)
+    pub event_materialize_lock: Arc<tokio::sync::Mutex<()>>,
+    /// 故障注入注册表 (仅本服务器实例)
+    #[cfg(feature = "chaos")]
+    pub faults: crate::chaos::FaultRegistry,
 }
 
 impl ServerState {
@@ -156,7 +190,18 @@ impl ServerState {
         archive_notify: Arc<tokio::sync::Notify>,
         epoch: String,
         audit_worker_handle: Arc<tokio::sync::Mutex<Option<tokio::task::JoinHandle<()>>>>,
+        readiness: Readiness,
+        message_gateway: Option<Arc<dyn MessageGateway>>,
+        support: Arc<SupportRelay>,
+        metrics: Arc<Metrics>,
     ) -> Self {
+        let load_shedder = Arc::new(LoadShedder::new(std::time::Duration::from_millis(
+            config.load_shed_latency_ms,
+        )));
+        let customer_display = Arc::new(CustomerDisplayService::new(
+            pool.clone(),
+            orders_manager.clone(),
+        ));
         Self {
             config,
             pool,
@@ -174,6 +219,17 @@ impl ServerState {
             archive_notify,
             epoch,
             audit_worker_handle,
+            readiness,
+            message_gateway,
+            support,
+            transfers: Arc::new(TransferRelay::new()),
+            mail: Arc::new(MailOutbox::new()),
+            load_shedder,
+            customer_display,
+            metrics,
+            event_materialize_lock: Arc::new(tokio::sync::Mutex::new(())),
+            #[cfg(feature = "chaos")]
+            faults: Default::default(),
         }
     }
 
@@ -190,15 +246,36 @@ impl ServerState {
             crate::utils::AppError::internal(format!("Failed to create work directory: {e}"))
         })?;
 
-        // 1. Initialize DB
+        let readiness = Readiness::new();
+
+        // 1. Initialize DB (migrations) ∥ open print queue storage (redb, blocking IO)
         // Database path: {tenant}/server/data/main.db/
         let db_path = config.database_path();
         let db_path_str = db_path.to_string_lossy();
+        let print_db_path = config.print_db_file();
 
-        let db_service = DbService::new(&db_path_str).await.map_err(|e| {
+        #[cfg(feature = "chaos")]
+        let faults = crate::chaos::FaultRegistry::default();
+        #[cfg(feature = "chaos")]
+        let open_db = DbService::with_faults(&db_path_str, faults.clone());
+        #[cfg(not(feature = "chaos"))]
+        let open_db = DbService::new(&db_path_str);
+        let (db_service, print_storage) = tokio::join!(
+            open_db,
+            tokio::task::spawn_blocking(move || PrintStorage::open(&print_db_path)),
+        );
+        let db_service = db_service.map_err(|e| {
             crate::utils::AppError::internal(format!("Failed to initialize database: {e}"))
         })?;
         let pool = db_service.pool;
+        readiness.mark_ready(Component::Database);
+        let print_storage = print_storage
+            .map_err(|e| {
+                crate::utils::AppError::internal(format!("Print storage task failed: {e}"))
+            })?
+            .map_err(|e| {
+                crate::utils::AppError::internal(format!("Failed to initialize print storage: {e}"))
+            })?;
 
         // 2. Load StoreInfo early (for timezone resolution)
         let store_info = crate::db::repository::store_info::get(&pool)
@@ -224,10 +301,20 @@ impl ServerState {
         let activation = ActivationService::new(
             config.auth_server_url.clone(),
             PathBuf::from(&config.work_dir),
-        );
-        let cert_service = CertService::new(PathBuf::from(&config.work_dir));
+        )
+        .with_tenant_pool(TenantPool::new(pool.clone(), &db_path_str));
+        let cert_service = CertService::new(PathBuf::from(&config.work_dir))
+            .with_client_cert_required(config.require_client_cert);
+
+        // 3b. 租户作用域守卫：已激活时数据库必须属于凭证中的租户，
+        // 否则在任何后台任务/API 访问数据之前中止
+        activation.verify_tenant_scope().await?;
         let message_bus = MessageBusService::new(&config);
-        let https = HttpsService::new(config.clone());
+        // 关键通知落库，离线终端重连补发
+        message_bus.bus().set_durable_store(pool.clone());
+        #[cfg(feature = "chaos")]
+        message_bus.bus().set_faults(faults.clone());
+        let https = HttpsService::new();
         let jwt_secret = crate::auth::jwt::load_or_create_persistent_secret(&config.data_dir());
         let jwt_service = Arc::new(JwtService::with_config(crate::auth::jwt::JwtConfig {
             secret: jwt_secret,
@@ -252,6 +339,38 @@ impl ServerState {
                 ))
             })?;
         orders_manager.set_catalog_service(catalog_service.clone());
+        #[cfg(feature = "chaos")]
+        orders_manager.set_faults(faults.clone());
+        let metrics = Arc::new(Metrics::new());
+        orders_manager.set_metrics(metrics.clone());
+        orders_manager.set_commit_alert_threshold(std::time::Duration::from_millis(
+            config.commit_latency_alert_ms,
+        ));
+        if config.orders_cache_verify {
+            orders_manager.set_active_cache_verify(true);
+        }
+        if config.event_journal {
+            match crate::orders::journal::EventJournal::open(config.event_journal_dir()) {
+                Ok(journal) => orders_manager.set_event_journal(journal),
+                Err(e) => {
+                    tracing::error!("Failed to open event journal, continuing without: {}", e)
+                }
+            }
+        }
+        if let Some(pms_url) = &config.pms_url {
+            match crate::pms::HttpPmsClient::new(pms_url.clone(), config.pms_api_key.clone()) {
+                Ok(pms) => orders_manager.set_pms_client(Arc::new(pms)),
+                Err(e) => {
+                    tracing::error!("Failed to set up PMS client, room charge disabled: {}", e)
+                }
+            }
+        }
+        if let Some(fiscal_url) = &config.fiscal_device_url {
+            match crate::fiscal::open(fiscal_url, config.fiscal_device_api_key.clone()) {
+                Ok(device) => orders_manager.set_fiscal_device(device, config.fiscal_policy),
+                Err(e) => tracing::error!("Failed to set up fiscal device: {}", e),
+            }
+        }
 
         // Initialize InvoiceService from store_info (Verifactu)
         let invoice_service = if let Some(ref info) = store_info {
@@ -271,22 +390,36 @@ impl ServerState {
             None
         };
         orders_manager.set_archive_service(pool.clone(), invoice_service);
+        orders_manager.set_inventory_tracker(Arc::new(crate::inventory::InventoryTracker::new(
+            pool.clone(),
+            message_bus.bus().clone(),
+            resource_versions.clone(),
+        )));
+        orders_manager.set_coupon_tracker(Arc::new(crate::marketing::coupon::CouponTracker::new(
+            pool.clone(),
+            message_bus.bus().clone(),
+            resource_versions.clone(),
+        )));
 
         // Initialize business_day_cutoff from store_info
         if let Some(ref info) = store_info {
             orders_manager.update_business_day_cutoff(info.business_day_cutoff);
+            orders_manager.update_tax_mode(info.tax_mode);
+            orders_manager.update_max_cash_change(info.max_cash_change);
+        }
+        // 安装向导未完成时拒绝开台
+        match crate::setup::is_complete(&pool).await {
+            Ok(complete) => orders_manager.update_setup_complete(complete),
+            Err(e) => tracing::error!("Failed to load setup wizard state: {}", e),
         }
 
         // Note: ArchiveWorker is started in start_background_tasks()
 
         let orders_manager = Arc::new(orders_manager);
 
-        // 5. Initialize KitchenPrintService
-        let print_db_path = config.print_db_file();
-        let print_storage = PrintStorage::open(&print_db_path).map_err(|e| {
-            crate::utils::AppError::internal(format!("Failed to initialize print storage: {e}"))
-        })?;
+        // 5. Initialize KitchenPrintService (storage opened alongside the DB)
         let kitchen_print_service = Arc::new(KitchenPrintService::new(print_storage));
+        readiness.mark_ready(Component::Storage);
 
         // 7. Initialize AuditService (税务级审计日志 — SQLite)
         let data_dir = config.data_dir();
@@ -294,7 +427,13 @@ impl ServerState {
             AuditService::new(pool.clone(), &data_dir, 1024, config.timezone);
 
         // 检测异常关闭和长时间停机（通过 LOCK 文件 + pending-ack.json）
-        audit_service.on_startup().await;
+        if let Some(last_start_at) = audit_service.on_startup().await {
+            // 异常关闭 → 完整性检查 + 修复，报告供 API 查看并通知经理
+            let report = crate::recovery::run(&pool, orders_manager.storage(), last_start_at).await;
+            if let Err(e) = report.save(&config.recovery_report_path()) {
+                tracing::error!("Failed to write recovery report: {}", e);
+            }
+        }
 
         // 启动审计日志 worker (with panic catching)
         let dead_letter_path = data_dir.join("audit_dead_letter.jsonl");
@@ -323,6 +462,27 @@ impl ServerState {
         // 8b. Archive completion notifier (唤醒 CloudWorker 立即同步归档订单)
         let archive_notify = Arc::new(tokio::sync::Notify::new());
 
+        // 8c. Customer message gateway (外卖取餐通知)
+        let message_gateway: Option<Arc<dyn MessageGateway>> = match &config.notify_gateway_url {
+            Some(url) => match crate::notify::HttpMessageGateway::new(
+                url,
+                config.notify_gateway_api_key.clone(),
+            ) {
+                Ok(gateway) => Some(Arc::new(gateway)),
+                Err(e) => {
+                    tracing::error!(
+                        "Failed to set up message gateway, pickup SMS disabled: {}",
+                        e
+                    );
+                    None
+                }
+            },
+            None => None,
+        };
+
+        // 8d. Remote support relay (读取日志目录)
+        let support = Arc::new(SupportRelay::new(config.logs_dir()));
+
         // 9. Generate epoch (UUID for server restart detection)
         let epoch = uuid::Uuid::new_v4().to_string();
 
@@ -343,7 +503,13 @@ impl ServerState {
             archive_notify,
             epoch,
             audit_worker_handle,
+            readiness,
+            message_gateway,
+            support,
+            metrics,
         );
+        #[cfg(feature = "chaos")]
+        let state = Self { faults, ..state };
 
         // 3. Late initialization for HttpsService (needs state)
         https.initialize(state.clone());
@@ -369,10 +535,10 @@ impl ServerState {
     /// 必须在 `Server::run()` 之前调用
     ///
     /// 启动的任务：
-    /// - **Warmup**: CatalogService 预热, 价格规则缓存预热
+    /// - **Warmup**: CatalogService 预热 ∥ 活跃订单快照预热, 然后价格规则缓存预热（后台执行，进度见 `readiness`）
     /// - **Worker**: ArchiveWorker, MessageHandler
     /// - **Listener**: 订单事件转发器, 厨房打印事件监听器
-    /// - **Periodic**: 打印记录清理任务, 归档验证调度器, 班次自动关闭调度器
+    /// - **Periodic**: 打印记录清理任务, 归档验证调度器, 班次自动关闭调度器, 宴会预订转单调度器, 资源看门狗
     ///
     /// 返回 `BackgroundTasks` 用于 graceful shutdown
     pub async fn start_background_tasks(&self) -> BackgroundTasks {
@@ -381,16 +547,10 @@ impl ServerState {
         let mut tasks = BackgroundTasks::new();
 
         // ═══════════════════════════════════════════════════════════════════
-        // Warmup Tasks (同步执行，启动时运行一次)
+        // Warmup Tasks (后台执行，与激活/订阅检查及其余任务并行)
         // ═══════════════════════════════════════════════════════════════════
 
-        // Warmup: Load all products and categories into CatalogService cache
-        if let Err(e) = self.catalog_service.warmup().await {
-            tracing::error!("Failed to warmup CatalogService: {:?}", e);
-        }
-
-        // Warmup: Load price rules for all active orders
-        self.warmup_active_order_rules().await;
+        self.register_warmup(&mut tasks);
 
         // ═══════════════════════════════════════════════════════════════════
         // Event Router (事件路由，解耦 OrdersManager 和各 Worker)
@@ -399,6 +559,7 @@ impl ServerState {
         // archive_buffer 较大（关键业务），其他 buffer 适中
         let (router, channels) = EventRouter::new(512, 256);
         let source_rx = self.orders_manager.subscribe();
+        tasks.set_event_queues(router.queues());
 
         let event_router_shutdown = tasks.shutdown_token();
         tasks.spawn("event_router", TaskKind::Worker, async move {
@@ -412,6 +573,9 @@ impl ServerState {
         // ArchiveWorker: 归档已完成订单到 SQLite
         self.register_archive_worker(&mut tasks, channels.archive_rx);
 
+        // ProjectionService: 订单事件 -> 报表读模型
+        self.register_projection_worker(&mut tasks, channels.projection_rx);
+
         // MessageHandler: 处理来自客户端的消息
         self.register_message_handler(&mut tasks);
 
@@ -425,6 +589,9 @@ impl ServerState {
         // KitchenPrintWorker: ItemsAdded 事件 -> 厨房打印
         self.register_kitchen_print_worker(&mut tasks, channels.print_rx);
 
+        // CustomerDisplay: 订单事件 -> 客显
+        self.register_customer_display_worker(&mut tasks, channels.display_rx);
+
         // ═══════════════════════════════════════════════════════════════════
         // Periodic Tasks (定时任务)
         // ═══════════════════════════════════════════════════════════════════
@@ -435,15 +602,39 @@ impl ServerState {
         // ArchiveDetailCleanup: 清理已同步到云端的旧订单详情
         self.register_archive_detail_cleanup(&mut tasks);
 
+        // EventLogCompaction: redb 事件日志超期归档 + 截断
+        self.register_event_log_compaction(&mut tasks);
+
+        // SnapshotCheckpoint: 活跃订单快照合集写入 redb (缓存重建 / 重启时复用)
+        self.register_snapshot_checkpoint(&mut tasks);
+
         // VerifyScheduler: 归档哈希链验证（启动补扫 + 每日触发）
         self.register_verify_scheduler(&mut tasks);
 
         // ShiftAutoCloseScheduler: 自动关闭跨营业日僵尸班次
         self.register_shift_auto_close(&mut tasks);
 
+        // ShiftBreakMonitor: 连续工作超时提醒休息
+        self.register_shift_break_monitor(&mut tasks);
+
         // DailyReportScheduler: 自动生成日报 + 补漏 + 清理
         self.register_daily_report_scheduler(&mut tasks);
 
+        // EventBookingScheduler: 宴会预订活动当天自动转单
+        self.register_event_booking_scheduler(&mut tasks);
+
+        // UpsellScheduler: 每营业日重算商品关联推荐
+        self.register_upsell_scheduler(&mut tasks);
+
+        // KpiService: 经理看板实时指标推送
+        self.register_kpi_service(&mut tasks);
+
+        // RecoveryNotifier: 异常关闭恢复报告 → 首个客户端连接后通知一次
+        self.register_recovery_notifier(&mut tasks);
+
+        // ResourceWatchdog: 长时间运行资源泄漏监控
+        self.register_resource_watchdog(&mut tasks);
+
         // 打印任务摘要
         tasks.log_summary();
 
@@ -457,13 +648,14 @@ impl ServerState {
         &self,
         tasks: &mut BackgroundTasks,
         tls_config: Arc<rustls::ServerConfig>,
+        listeners: ListenerControl,
     ) {
         // MessageBus TCP Server (mTLS)
         let message_bus_service = self.message_bus.clone();
         let credential_cache = self.activation.credential_cache.clone();
         tasks.spawn("message_bus_tcp_server", TaskKind::Worker, async move {
             if let Err(e) = message_bus_service
-                .start_tcp_server(tls_config, credential_cache)
+                .start_tcp_server(tls_config, credential_cache, listeners)
                 .await
             {
                 tracing::error!("Message Bus TCP server failed: {}", e);
@@ -520,41 +712,86 @@ impl ServerState {
         });
     }
 
-    /// 预热活跃订单的价格规则缓存
+    /// 预热活跃订单快照缓存，并从 redb 恢复规则快照
     ///
-    /// 优先从 redb 恢复规则快照（开台时定格的版本），
-    /// 确保重启后活跃订单使用的规则与开台时一致。
-    pub async fn warmup_active_order_rules(&self) {
-        // 从 redb 恢复规则快照到内存
-        let restored = self.orders_manager.restore_rule_snapshots_from_redb();
-
-        if restored > 0 {
-            tracing::info!("Restored {} order rule snapshots from redb", restored,);
-        }
-
-        // 检查是否有活跃订单缺少规则快照（可能是旧数据，redb 中没有）
-        let active_orders = match self.orders_manager.get_active_orders() {
-            Ok(orders) => orders,
+    /// 两者都是 redb 读取，放在阻塞线程执行，与目录缓存加载并行。
+    /// 返回活跃订单（失败时为 None，组件标记为 failed）。
+    pub async fn warmup_active_orders(&self) -> Option<Vec<OrderSnapshot>> {
+        let orders_manager = self.orders_manager.clone();
+        let result = tokio::task::spawn_blocking(move || {
+            let restored = orders_manager.restore_rule_snapshots_from_redb();
+            orders_manager
+                .get_active_orders()
+                .map(|orders| (orders, restored))
+        })
+        .await;
+
+        match result {
+            Ok(Ok((orders, restored))) => {
+                if restored > 0 {
+                    tracing::info!("Restored {} order rule snapshots from redb", restored);
+                }
+                tracing::info!("Active orders cache loaded: {} orders", orders.len());
+                self.readiness.mark_ready(Component::ActiveOrders);
+                Some(orders)
+            }
+            Ok(Err(e)) => {
+                self.readiness
+                    .mark_failed(Component::ActiveOrders, format!("{e:?}"));
+                None
+            }
             Err(e) => {
-                tracing::error!("Failed to get active orders for rule warmup: {:?}", e);
-                return;
+                self.readiness.mark_failed(
+                    Component::ActiveOrders,
+                    format!("Active orders warmup panicked: {e}"),
+                );
+                None
             }
-        };
+        }
+    }
 
+    /// 预热活跃订单的价格规则缓存
+    ///
+    /// 规则快照已在 [`Self::warmup_active_orders`] 从 redb 恢复（开台时定格的版本），
+    /// 确保重启后活跃订单使用的规则与开台时一致。缺少快照的订单（旧数据）
+    /// 从数据库回退加载，同一区域只查询一次；进度通过 readiness 报告。
+    pub async fn warmup_active_order_rules(&self, active_orders: &[OrderSnapshot]) {
+        let missing: Vec<&OrderSnapshot> = active_orders
+            .iter()
+            .filter(|order| {
+                self.orders_manager
+                    .get_cached_rules(order.order_id)
+                    .is_none()
+            })
+            .collect();
+
+        let total = missing.len();
+        let mut zone_rules: HashMap<(Option<i64>, bool), Vec<PriceRule>> = HashMap::new();
         let mut fallback_count = 0;
-        for order in &active_orders {
-            if self
-                .orders_manager
-                .get_cached_rules(order.order_id)
-                .is_none()
-            {
-                // redb 中没有快照，从数据库回退加载
-                let rules = load_matching_rules(&self.pool, order.zone_id, order.is_retail).await;
-
-                if !rules.is_empty() {
-                    self.orders_manager.cache_rules(order.order_id, rules);
-                    fallback_count += 1;
+        for (done, order) in missing.into_iter().enumerate() {
+            self.readiness
+                .mark_progress(Component::OrderRules, done, total);
+
+            // redb 中没有快照，从数据库回退加载
+            let key = (order.zone_id, order.is_retail);
+            let rules = match zone_rules.get(&key) {
+                Some(rules) => rules.clone(),
+                None => {
+                    let rules = load_order_rules(
+                        &self.pool,
+                        &self.catalog_service,
+                        order.zone_id,
+                        order.is_retail,
+                    )
+                    .await;
+                    zone_rules.insert(key, rules.clone());
+                    rules
                 }
+            };
+
+            if !rules.is_empty() {
+                self.orders_manager.cache_rules(order.order_id, rules);
+                fallback_count += 1;
             }
         }
 
@@ -566,11 +803,12 @@ impl ServerState {
         }
 
         tracing::info!(
-            "Rule warmup complete: {} active orders, {} restored from redb, {} fell back to database",
+            "Rule warmup complete: {} active orders, {} fell back to database ({} zone queries)",
             active_orders.len(),
-            restored,
             fallback_count,
+            zone_rules.len(),
         );
+        self.readiness.mark_ready(Component::OrderRules);
     }
 
     // ═══════════════════════════════════════════════════════════════════════
@@ -597,30 +835,88 @@ impl ServerState {
                 self.archive_notify.clone(),
             );
 
+            let (sweep, sweep_rx) = crate::archiving::ArchiveSweepHandle::channel();
+            tasks.set_archive_sweep(sweep);
             let shutdown = tasks.shutdown_token();
             tasks.spawn("archive_worker", TaskKind::Worker, async move {
-                worker.run(event_rx, shutdown).await;
+                worker.run(event_rx, sweep_rx, shutdown).await;
             });
         }
     }
 
+    /// 注册报表投影
+    ///
+    /// 折叠订单事件流，终态订单写入 sales_fact / item_fact / payment_fact
+    fn register_projection_worker(
+        &self,
+        tasks: &mut BackgroundTasks,
+        event_rx: mpsc::Receiver<std::sync::Arc<shared::order::OrderEvent>>,
+    ) {
+        let service = crate::projection::ProjectionService::new(
+            self.pool.clone(),
+            self.orders_manager.storage().clone(),
+            self.config.timezone,
+        );
+        let shutdown = tasks.shutdown_token();
+        tasks.spawn("projection_worker", TaskKind::Worker, async move {
+            service.run(event_rx, shutdown).await;
+        });
+    }
+
+    /// 注册预热任务
+    ///
+    /// 目录缓存与活跃订单快照（redb）互不依赖，并行加载；
+    /// 规则回退加载在两者之后（回退按商品分类匹配规则、使用区域价格覆盖）。
+    /// 预热结束前业务 API 返回 503，消息处理器暂不消费指令。
+    fn register_warmup(&self, tasks: &mut BackgroundTasks) {
+        let state = self.clone();
+        tasks.spawn("warmup", TaskKind::Warmup, async move {
+            // Load all products, categories and attribute bindings into CatalogService cache
+            let catalog = async {
+                match state.catalog_service.warmup().await {
+                    Ok(()) => state.readiness.mark_ready(Component::Catalog),
+                    Err(e) => state
+                        .readiness
+                        .mark_failed(Component::Catalog, format!("{e:?}")),
+                }
+            };
+            // Load active order snapshots + rule snapshots from redb
+            let ((), active_orders) = tokio::join!(catalog, state.warmup_active_orders());
+
+            // Load price rules for active orders without a snapshot
+            match active_orders {
+                Some(orders) => state.warmup_active_order_rules(&orders).await,
+                None => state
+                    .readiness
+                    .mark_failed(Component::OrderRules, "Active orders unavailable"),
+            }
+        });
+    }
+
     /// 注册 MessageHandler
     ///
     /// 处理来自客户端的消息
     fn register_message_handler(&self, tasks: &mut BackgroundTasks) {
         let handler_receiver = self.message_bus.bus().subscribe_to_clients();
         let handler_shutdown = tasks.shutdown_token();
-        let server_tx = self.message_bus.bus().sender().clone();
+        let warmup_shutdown = tasks.shutdown_token();
+        let readiness = self.readiness.clone();
+        let bus = self.message_bus.bus().clone();
 
         let handler = crate::message::MessageHandler::with_default_processors(
             handler_receiver,
             handler_shutdown,
             self.clone().into(),
         )
-        .with_broadcast_tx(server_tx);
+        .with_bus(bus)
+        .with_dead_letter_store(self.pool.clone());
 
         tasks.spawn("message_handler", TaskKind::Worker, async move {
-            handler.run().await;
+            // 客户端指令在预热期间保留在订阅通道中，预热结束后再处理
+            tokio::select! {
+                _ = readiness.wait_warm() => handler.run().await,
+                _ = warmup_shutdown.cancelled() => {}
+            }
         });
     }
 
@@ -634,6 +930,7 @@ impl ServerState {
     ) {
         let message_bus = self.message_bus.bus().clone();
         let orders_manager = self.orders_manager.clone();
+        let metrics = self.metrics.clone();
 
         let shutdown = tasks.shutdown_token();
         tasks.spawn("order_sync_forwarder", TaskKind::Listener, async move {
@@ -646,40 +943,33 @@ impl ServerState {
                         break;
                     }
                     event = event_rx.recv() => {
-                        let Some(event) = event else {
+                        let Some(first) = event else {
                             tracing::debug!("Sync channel closed, order sync forwarder stopping");
                             break;
                         };
 
-                        let order_id = event.order_id;
-                        let sequence = event.sequence;
-                        let action = order_event_type_to_sync_change_type(&event.event_type);
-
-                        // 获取快照，打包 event + snapshot 一起推送
-                        match orders_manager.get_snapshot(order_id) {
-                            Ok(Some(snapshot)) => {
-                                let payload = SyncPayload {
-                                    resource: SyncResource::OrderSync,
-                                    version: sequence,
-                                    action,
-                                    id: order_id,
-                                    data: serde_json::json!({
-                                        "event": event,
-                                        "snapshot": snapshot
-                                    })
-                                    .into(),
-                                    cloud_origin: false,
-                                };
-                                if let Err(e) = message_bus.publish(BusMessage::sync(&payload)).await {
-                                    tracing::warn!("Failed to forward order sync: {}", e);
-                                }
-                            }
-                            Ok(None) => {
-                                tracing::warn!("Order {} not found after event", order_id);
-                            }
-                            Err(e) => {
-                                tracing::error!("Failed to get snapshot for {}: {}", order_id, e);
-                            }
+                        // 合并已到达的连续事件（同一命令的批量事件一并广播）
+                        tokio::task::yield_now().await;
+                        let mut batch = vec![first];
+                        while batch.len() < ORDER_SYNC_BATCH_MAX
+                            && let Ok(next) = event_rx.try_recv()
+                        {
+                            batch.push(next);
+                        }
+
+                        let Some(payload) = build_order_sync_payload(&orders_manager, &batch) else {
+                            continue;
+                        };
+                        if let Err(e) = message_bus.publish(BusMessage::sync(&payload)).await {
+                            tracing::warn!("Failed to forward order sync: {}", e);
+                            continue;
+                        }
+                        let now = shared::util::now_millis();
+                        for event in &batch {
+                            let lag = now.saturating_sub(event.timestamp).max(0) as u64;
+                            metrics.observe_event_broadcast_lag(
+                                std::time::Duration::from_millis(lag),
+                            );
                         }
                     }
                 }
@@ -695,15 +985,19 @@ impl ServerState {
         tasks: &mut BackgroundTasks,
         event_rx: mpsc::Receiver<std::sync::Arc<shared::order::OrderEvent>>,
     ) {
-        use crate::printing::KitchenPrintWorker;
+        use crate::printing::{KitchenPrintWorker, PrintWorkerDeps};
 
         let worker = KitchenPrintWorker::new(
             self.orders_manager.clone(),
             self.kitchen_print_service.clone(),
             self.catalog_service.clone(),
-            self.pool.clone(),
-            self.config.timezone,
-            Some(self.config.images_dir()),
+            PrintWorkerDeps {
+                message_bus: self.message_bus().clone(),
+                pool: self.pool.clone(),
+                timezone: self.config.timezone,
+                images_dir: Some(self.config.images_dir()),
+                metrics: self.metrics.clone(),
+            },
         );
 
         let shutdown = tasks.shutdown_token();
@@ -712,6 +1006,19 @@ impl ServerState {
         });
     }
 
+    /// 注册客显 worker
+    fn register_customer_display_worker(
+        &self,
+        tasks: &mut BackgroundTasks,
+        event_rx: mpsc::Receiver<std::sync::Arc<shared::order::OrderEvent>>,
+    ) {
+        let service = self.customer_display.clone();
+        let shutdown = tasks.shutdown_token();
+        tasks.spawn("customer_display_worker", TaskKind::Listener, async move {
+            service.run(event_rx, shutdown).await;
+        });
+    }
+
     /// 注册打印记录清理任务
     ///
     /// - 启动时立即执行一次清理
@@ -834,6 +1141,83 @@ impl ServerState {
         );
     }
 
+    /// 注册活跃订单快照检查点 (每 5 分钟，无新提交时跳过)
+    fn register_snapshot_checkpoint(&self, tasks: &mut BackgroundTasks) {
+        const CHECKPOINT_INTERVAL_SECS: u64 = 300;
+
+        let orders_manager = self.orders_manager.clone();
+        let shutdown = tasks.shutdown_token();
+
+        tasks.spawn("snapshot_checkpoint", TaskKind::Periodic, async move {
+            let mut interval =
+                tokio::time::interval(std::time::Duration::from_secs(CHECKPOINT_INTERVAL_SECS));
+            // 跳过立即触发的首个 tick：启动时缓存尚未加载
+            interval.tick().await;
+            loop {
+                tokio::select! {
+                    _ = shutdown.cancelled() => break,
+                    _ = interval.tick() => {
+                        match orders_manager.write_snapshot_checkpoint() {
+                            Ok(Some(orders)) => {
+                                tracing::debug!(orders, "Active order snapshot checkpoint written");
+                            }
+                            Ok(None) => {}
+                            Err(e) => {
+                                tracing::warn!(error = %e, "Failed to write snapshot checkpoint");
+                            }
+                        }
+                    }
+                }
+            }
+        });
+    }
+
+    /// 注册事件日志压缩 (每日；保留期见 `Config.event_retention_days`，0 = 禁用)
+    fn register_event_log_compaction(&self, tasks: &mut BackgroundTasks) {
+        const COMPACTION_INTERVAL_SECS: u64 = 24 * 3600; // daily
+
+        let retention_days = self.config.event_retention_days;
+        if retention_days == 0 {
+            return;
+        }
+        let Some(archive_service) = self.orders_manager.archive_service() else {
+            return;
+        };
+        let compactor = crate::archiving::EventLogCompactor::new(
+            self.orders_manager.storage().clone(),
+            archive_service.clone(),
+            retention_days,
+        );
+        let shutdown = tasks.shutdown_token();
+
+        tasks.spawn("event_log_compaction", TaskKind::Periodic, async move {
+            tracing::info!("Event log compaction started (retention: {retention_days}d)");
+            let mut interval =
+                tokio::time::interval(std::time::Duration::from_secs(COMPACTION_INTERVAL_SECS));
+
+            // 首个 tick 立即触发 = 启动时执行一次
+            loop {
+                tokio::select! {
+                    _ = shutdown.cancelled() => break,
+                    _ = interval.tick() => {
+                        let report = compactor.compact(shared::util::now_millis()).await;
+                        if report.candidates > 0 {
+                            tracing::info!(
+                                candidates = report.candidates,
+                                archived = report.archived,
+                                already_archived = report.already_archived,
+                                failed = report.failed,
+                                truncated_events = report.truncated_events,
+                                sequence = report.sequence,
+                                "Event log compacted"
+                            );
+                        }
+                    }
+                }
+            }
+        });
+    }
+
     /// 注册归档验证调度器
     ///
     /// - 启动时补扫未验证的营业日
@@ -870,6 +1254,17 @@ impl ServerState {
         });
     }
 
+    /// 注册班次休息合规检测
+    fn register_shift_break_monitor(&self, tasks: &mut BackgroundTasks) {
+        use crate::shifts::ShiftBreakMonitor;
+
+        let monitor = ShiftBreakMonitor::new(self.clone(), tasks.shutdown_token());
+
+        tasks.spawn("shift_break_monitor", TaskKind::Periodic, async move {
+            monitor.run().await;
+        });
+    }
+
     /// 注册日报自动生成调度器
     ///
     /// - 启动时补漏最近 7 天缺失的日报
@@ -885,6 +1280,82 @@ impl ServerState {
         });
     }
 
+    /// 注册宴会预订转单调度器
+    ///
+    /// - 启动时立即检查
+    /// - 每 5 分钟将即将开始的已确认预订转为正式订单
+    fn register_event_booking_scheduler(&self, tasks: &mut BackgroundTasks) {
+        use crate::event_bookings::EventBookingScheduler;
+
+        let scheduler = EventBookingScheduler::new(self.clone(), tasks.shutdown_token());
+
+        tasks.spawn("event_booking_scheduler", TaskKind::Periodic, async move {
+            scheduler.run().await;
+        });
+    }
+
+    /// 注册关联推荐重算调度器
+    ///
+    /// - 启动时结果缺失或过期则立即重算
+    /// - 之后每个营业日 cutoff 重算
+    fn register_upsell_scheduler(&self, tasks: &mut BackgroundTasks) {
+        use crate::marketing::upsell::UpsellScheduler;
+
+        let scheduler = UpsellScheduler::new(self.clone(), tasks.shutdown_token());
+
+        tasks.spawn("upsell_scheduler", TaskKind::Periodic, async move {
+            scheduler.run().await;
+        });
+    }
+
+    /// 注册实时经营指标推送 (KPI_INTERVAL_SECS = 0 时禁用)
+    fn register_kpi_service(&self, tasks: &mut BackgroundTasks) {
+        use crate::kpi::KpiService;
+
+        if self.config.kpi_interval_secs == 0 {
+            tracing::info!("KPI stream disabled (KPI_INTERVAL_SECS=0)");
+            return;
+        }
+        let service = KpiService::new(
+            self.clone(),
+            tasks.shutdown_token(),
+            std::time::Duration::from_secs(self.config.kpi_interval_secs),
+        );
+
+        tasks.spawn("kpi_service", TaskKind::Periodic, async move {
+            service.run().await;
+        });
+    }
+
+    /// 注册恢复报告通知 (无未通知的报告时立即结束)
+    fn register_recovery_notifier(&self, tasks: &mut BackgroundTasks) {
+        let path = self.config.recovery_report_path();
+        let bus = self.message_bus().clone();
+        let shutdown = tasks.shutdown_token();
+        tasks.spawn("recovery_notifier", TaskKind::Warmup, async move {
+            crate::recovery::notify_once(&path, &bus, shutdown).await;
+        });
+    }
+
+    /// 注册资源泄漏监控 (RESOURCE_WATCHDOG_INTERVAL_SECS = 0 时禁用)
+    fn register_resource_watchdog(&self, tasks: &mut BackgroundTasks) {
+        use crate::watchdog::ResourceWatchdog;
+
+        if self.config.resource_watchdog_interval_secs == 0 {
+            tracing::info!("Resource watchdog disabled (RESOURCE_WATCHDOG_INTERVAL_SECS=0)");
+            return;
+        }
+        let watchdog = ResourceWatchdog::new(
+            self.clone(),
+            tasks.shutdown_token(),
+            std::time::Duration::from_secs(self.config.resource_watchdog_interval_secs),
+        );
+
+        tasks.spawn("resource_watchdog", TaskKind::Periodic, async move {
+            watchdog.run().await;
+        });
+    }
+
     // ═══════════════════════════════════════════════════════════════════════
     // Getter Methods
     // ═══════════════════════════════════════════════════════════════════════
@@ -983,10 +1454,12 @@ impl ServerState {
                 | SyncResource::AttributeBinding
                 | SyncResource::Employee
                 | SyncResource::Zone
+                | SyncResource::ZoneProductOverride
                 | SyncResource::DiningTable
                 | SyncResource::PriceRule
                 | SyncResource::LabelTemplate
                 | SyncResource::StoreInfo
+                | SyncResource::Coupon
         )
     }
 
@@ -1044,6 +1517,13 @@ impl ServerState {
             .await
     }
 
+    /// 校验数据库归属当前激活的租户（未激活时跳过）
+    ///
+    /// 激活完成后调用：首次激活绑定数据库，其他租户的凭证被拒绝。
+    pub async fn verify_tenant_scope(&self) -> Result<(), crate::utils::AppError> {
+        self.activation.verify_tenant_scope().await
+    }
+
     /// 检查订阅是否被阻止
     pub async fn is_subscription_blocked(&self) -> bool {
         self.activation.is_subscription_blocked().await
@@ -1059,8 +1539,37 @@ impl ServerState {
     }
 
     /// 从 auth-server 同步订阅状态
+    ///
+    /// 状态变为欠费 / 被阻止时发布关键通知 (离线终端重连后也能看到)。
     pub async fn sync_subscription(&self) {
+        use shared::activation::SubscriptionStatus;
+        use shared::message::NotificationPayload;
+
+        let before = self.activation.subscription_status().await;
         self.activation.sync_subscription().await;
+        let Some(status) = self.activation.subscription_status().await else {
+            return;
+        };
+        if before == Some(status) || !(status == SubscriptionStatus::PastDue || status.is_blocked())
+        {
+            return;
+        }
+
+        let payload = if status.is_blocked() {
+            NotificationPayload::error(
+                "Subscription blocked",
+                format!("Subscription status changed to {}", status.as_str()),
+            )
+        } else {
+            NotificationPayload::warning(
+                "Subscription past due",
+                "Payment is past due, please renew the subscription",
+            )
+        }
+        .with_data(serde_json::json!({ "subscription_status": status }));
+        if let Err(e) = self.message_bus().publish_durable(payload).await {
+            tracing::debug!("Subscription notification not broadcast: {}", e);
+        }
     }
 
     /// 检查 P12 证书是否被阻止
@@ -1213,6 +1722,60 @@ impl ServerState {
     }
 }
 
+/// 单条订单同步消息最多合并的事件数
+const ORDER_SYNC_BATCH_MAX: usize = 256;
+
+/// 构建订单同步消息
+///
+/// 单个事件：`{event, snapshot}`（与旧客户端兼容）；
+/// 多个事件：`{events, snapshots}`，每个订单只带一份最新快照。
+fn build_order_sync_payload(
+    orders_manager: &OrdersManager,
+    batch: &[Arc<shared::order::OrderEvent>],
+) -> Option<SyncPayload> {
+    let last = batch.last()?;
+
+    let mut order_ids: Vec<i64> = Vec::new();
+    for event in batch {
+        if !order_ids.contains(&event.order_id) {
+            order_ids.push(event.order_id);
+        }
+    }
+    let mut snapshots = Vec::with_capacity(order_ids.len());
+    for order_id in order_ids {
+        match orders_manager.get_snapshot(order_id) {
+            Ok(Some(snapshot)) => snapshots.push(snapshot),
+            Ok(None) => tracing::warn!("Order {} not found after event", order_id),
+            Err(e) => tracing::error!("Failed to get snapshot for {}: {}", order_id, e),
+        }
+    }
+
+    let data = match (batch, snapshots.as_slice()) {
+        ([event], [snapshot]) => serde_json::json!({
+            "event": event,
+            "snapshot": snapshot
+        }),
+        (_, []) => return None,
+        _ => serde_json::json!({
+            "events": batch,
+            "snapshots": snapshots
+        }),
+    };
+
+    Some(SyncPayload {
+        resource: SyncResource::OrderSync,
+        version: batch
+            .iter()
+            .map(|e| e.sequence)
+            .max()
+            .unwrap_or(last.sequence),
+        action: order_event_type_to_sync_change_type(&last.event_type),
+        id: last.order_id,
+        data: data.into(),
+        cloud_origin: false,
+    })
+}
+
 /// Map OrderEventType to SyncChangeType for synchronization
 fn order_event_type_to_sync_change_type(
     event_type: &shared::order::OrderEventType,
@@ -4,11 +4,13 @@
 //!
 //! # 任务类型
 //!
-//! - [`TaskKind::Warmup`] - 启动预热任务（同步执行，运行一次）
+//! - [`TaskKind::Warmup`] - 启动预热任务（后台执行，运行一次）
 //! - [`TaskKind::Worker`] - 长期后台工作者
 //! - [`TaskKind::Listener`] - 事件监听器
 //! - [`TaskKind::Periodic`] - 定时任务
 
+use crate::archiving::ArchiveSweepHandle;
+use crate::core::EventQueues;
 use futures::FutureExt;
 use std::fmt;
 use std::panic::AssertUnwindSafe;
@@ -18,7 +20,7 @@ use tokio_util::sync::CancellationToken;
 /// 任务类型
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum TaskKind {
-    /// 启动预热任务（同步执行，运行一次）
+    /// 启动预热任务（后台执行，运行一次）
     Warmup,
     /// 长期后台工作者
     Worker,
@@ -78,6 +80,10 @@ pub struct BackgroundTasks {
     tasks: Vec<RegisteredTask>,
     /// 全局取消令牌
     shutdown: CancellationToken,
+    /// EventRouter 通道积压探针 (关闭时等待排空)
+    event_queues: Option<EventQueues>,
+    /// ArchiveWorker 最终扫描句柄
+    archive_sweep: Option<ArchiveSweepHandle>,
 }
 
 impl BackgroundTasks {
@@ -86,6 +92,8 @@ impl BackgroundTasks {
         Self {
             tasks: Vec::new(),
             shutdown: CancellationToken::new(),
+            event_queues: None,
+            archive_sweep: None,
         }
     }
 
@@ -94,6 +102,24 @@ impl BackgroundTasks {
         self.shutdown.clone()
     }
 
+    /// 登记 EventRouter 通道积压探针
+    pub fn set_event_queues(&mut self, queues: EventQueues) {
+        self.event_queues = Some(queues);
+    }
+
+    pub fn event_queues(&self) -> Option<&EventQueues> {
+        self.event_queues.as_ref()
+    }
+
+    /// 登记 ArchiveWorker 最终扫描句柄
+    pub fn set_archive_sweep(&mut self, handle: ArchiveSweepHandle) {
+        self.archive_sweep = Some(handle);
+    }
+
+    pub fn archive_sweep(&self) -> Option<&ArchiveSweepHandle> {
+        self.archive_sweep.as_ref()
+    }
+
     /// 注册并启动一个后台任务
     ///
     /// 任务会被包装以捕获 panic，如果任务异常退出会记录错误日志。
@@ -313,9 +339,9 @@ impl BackgroundTasks {
 
     /// Graceful shutdown - 取消所有任务并等待完成
     ///
-    /// 发送取消信号后，等待所有任务完成或超时（5秒）。
-    /// 超时后会强制 abort 所有残留任务。
-    pub async fn shutdown(mut self) {
+    /// 发送取消信号后，等待所有任务完成或超时（2秒）。
+    /// 超时后会强制 abort 所有残留任务，返回 `false`。
+    pub async fn shutdown(mut self) -> bool {
         tracing::info!("Shutting down {} background tasks...", self.tasks.len());
 
         self.shutdown.cancel();
@@ -329,12 +355,14 @@ impl BackgroundTasks {
         match tokio::time::timeout(deadline, Self::await_all(tasks)).await {
             Ok(()) => {
                 tracing::info!("All background tasks stopped gracefully");
+                true
             }
             Err(_) => {
                 tracing::warn!("Background tasks shutdown timed out after 2s, aborting remaining");
                 for handle in &abort_handles {
                     handle.abort();
                 }
+                false
             }
         }
     }
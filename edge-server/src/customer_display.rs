@@ -0,0 +1,303 @@
+//! 顾客显示屏 (Customer display / 客显)
+//!
+//! 收银台的客显 (VFD 杆显 / 副屏) 通过 TCP 连接，按终端证书身份配置
+//! (`customer_display` 表)。订单事件经 EventRouter 送达，显示在最近操作该订单的终端上：
+//!
+//! - `ItemsAdded`: 最后加入的商品 + 金额 / 订单合计
+//! - `PaymentAdded`: 订单合计 / 找零 (无找零时显示已付)
+//! - `OrderVoided`: 恢复待机文字
+//! - `OrderCompleted` / `OrderMerged`: 保留当前画面 (顾客仍需看到找零)，解除订单与终端的关联
+//!
+//! 终端由 [`CustomerDisplayService::note_terminal`] 记录 (消息处理器执行订单命令时调用)，
+//! 只有 mTLS 证书身份会被记录。发送为 best-effort：客显离线只记录日志。
+
+use std::sync::Arc;
+use std::time::Duration;
+
+use crab_printer::{LineDisplayBuilder, NetworkPrinter, PrintResult, Printer};
+use dashmap::DashMap;
+use sqlx::SqlitePool;
+use tokio::sync::mpsc;
+use tokio_util::sync::CancellationToken;
+
+use crate::db::repository::{customer_display, store_info};
+use crate::orders::OrdersManager;
+use shared::models::{CustomerDisplay, LocaleFormat, ReceiptText, receipt_text};
+use shared::order::{EventPayload, OrderEvent, OrderSnapshot};
+
+/// 客显连接超时 (不阻塞后续事件)
+const SEND_TIMEOUT: Duration = Duration::from_secs(2);
+
+/// 顾客显示屏服务
+pub struct CustomerDisplayService {
+    pool: SqlitePool,
+    orders_manager: Arc<OrdersManager>,
+    /// order_id → 最近操作该订单的终端身份
+    terminals: DashMap<i64, String>,
+}
+
+impl std::fmt::Debug for CustomerDisplayService {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("CustomerDisplayService")
+            .field("tracked_orders", &self.terminals.len())
+            .finish_non_exhaustive()
+    }
+}
+
+impl CustomerDisplayService {
+    pub fn new(pool: SqlitePool, orders_manager: Arc<OrdersManager>) -> Self {
+        Self {
+            pool,
+            orders_manager,
+            terminals: DashMap::new(),
+        }
+    }
+
+    /// 记录操作订单的终端 (后续事件显示在该终端的客显上)
+    pub fn note_terminal(&self, order_id: i64, identity: &str) {
+        self.terminals.insert(order_id, identity.to_string());
+    }
+
+    /// 消费 EventRouter 的客显通道
+    pub async fn run(
+        &self,
+        mut event_rx: mpsc::Receiver<Arc<OrderEvent>>,
+        shutdown: CancellationToken,
+    ) {
+        tracing::debug!("Customer display worker started");
+        loop {
+            tokio::select! {
+                _ = shutdown.cancelled() => break,
+                event = event_rx.recv() => {
+                    let Some(event) = event else { break };
+                    self.handle(&event).await;
+                }
+            }
+        }
+        tracing::debug!("Customer display worker stopped");
+    }
+
+    async fn handle(&self, event: &OrderEvent) {
+        let finished = matches!(
+            event.payload,
+            EventPayload::OrderCompleted { .. }
+                | EventPayload::OrderVoided { .. }
+                | EventPayload::OrderMerged { .. }
+        );
+        let identity = if finished {
+            self.terminals.remove(&event.order_id).map(|(_, v)| v)
+        } else {
+            self.terminals.get(&event.order_id).map(|v| v.clone())
+        };
+        let Some(identity) = identity else {
+            return;
+        };
+
+        let target = match customer_display::find_active_by_identity(&self.pool, &identity).await {
+            Ok(Some(target)) => target,
+            Ok(None) => return,
+            Err(e) => {
+                tracing::warn!(identity = %identity, "Failed to load customer display: {e}");
+                return;
+            }
+        };
+
+        let info = store_info::get(&self.pool).await.ok().flatten();
+        let locale = info
+            .as_ref()
+            .and_then(|i| i.receipt_locale.as_deref())
+            .unwrap_or("es-ES");
+        let format = info
+            .as_ref()
+            .map(LocaleFormat::from_store_info)
+            .unwrap_or_default();
+        let snapshot = self
+            .orders_manager
+            .get_snapshot(event.order_id)
+            .ok()
+            .flatten();
+
+        let mut builder = LineDisplayBuilder::new(target.columns.max(1) as usize);
+        let text = receipt_text(locale);
+        let Some((top, bottom)) = render(
+            &builder,
+            event,
+            snapshot.as_ref(),
+            &text,
+            &format,
+            target.idle_text.as_deref().unwrap_or_default(),
+        ) else {
+            return;
+        };
+        builder.show(&top, &bottom);
+
+        if let Err(e) = send(&target, &builder.build()).await {
+            tracing::debug!(
+                display = %target.name,
+                host = %target.host,
+                port = target.port,
+                "Customer display unreachable: {e}"
+            );
+        }
+    }
+}
+
+/// 发送到客显 (测试按钮与事件共用)
+pub async fn send(display: &CustomerDisplay, data: &[u8]) -> PrintResult<()> {
+    let port = u16::try_from(display.port).unwrap_or(shared::models::DEFAULT_CUSTOMER_DISPLAY_PORT);
+    NetworkPrinter::new(&display.host, port)?
+        .with_timeout(SEND_TIMEOUT)
+        .print(data)
+        .await
+}
+
+/// 事件对应的客显两行内容 (None = 不更新画面)
+fn render(
+    builder: &LineDisplayBuilder,
+    event: &OrderEvent,
+    snapshot: Option<&OrderSnapshot>,
+    text: &ReceiptText,
+    format: &LocaleFormat,
+    idle_text: &str,
+) -> Option<(String, String)> {
+    let total_line = |total: f64| builder.pair(text.total_label, &format.money(total));
+    match &event.payload {
+        EventPayload::ItemsAdded { items } => {
+            let item = items.last()?;
+            let name = if item.quantity > 1 {
+                format!("{}x {}", item.quantity, item.name)
+            } else {
+                item.name.clone()
+            };
+            let top = builder.pair(&name, &format.money(item.line_total));
+            let bottom = snapshot.map(|s| total_line(s.total)).unwrap_or_default();
+            Some((top, bottom))
+        }
+        EventPayload::PaymentAdded { amount, change, .. } => {
+            let top = snapshot
+                .map(|s| total_line(s.total))
+                .unwrap_or_else(|| total_line(*amount));
+            let bottom = match change {
+                Some(change) if *change > 0.0 => {
+                    builder.pair(text.change_label, &format.money(*change))
+                }
+                _ => builder.pair(
+                    text.payments_label,
+                    &format.money(snapshot.map(|s| s.paid_amount).unwrap_or(*amount)),
+                ),
+            };
+            Some((top, bottom))
+        }
+        EventPayload::OrderVoided { .. } => Some((idle_text.to_string(), String::new())),
+        _ => None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use shared::order::{CartItemSnapshot, OrderEventType};
+
+    fn event(event_type: OrderEventType, payload: EventPayload) -> OrderEvent {
+        OrderEvent::new(
+            1,
+            42,
+            1,
+            "Ana".to_string(),
+            shared::util::snowflake_id(),
+            Some(1234567890),
+            event_type,
+            payload,
+        )
+    }
+
+    fn spanish() -> (ReceiptText, LocaleFormat) {
+        (
+            receipt_text("es-ES"),
+            LocaleFormat::new("es-ES", Some("€"), Some(2)),
+        )
+    }
+
+    #[test]
+    fn test_render_item_and_total() {
+        let (text, format) = spanish();
+        let builder = LineDisplayBuilder::new(20);
+        let item = CartItemSnapshot {
+            id: 1,
+            instance_id: "inst-1".to_string(),
+            name: "Café".to_string(),
+            price: 1.5,
+            original_price: 1.5,
+            quantity: 2,
+            unpaid_quantity: 2,
+            selected_options: None,
+            selected_specification: None,
+            manual_discount_percent: None,
+            rule_discount_amount: 0.0,
+            rule_surcharge_amount: 0.0,
+            applied_rules: vec![],
+            applied_mg_rules: vec![],
+            mg_discount_amount: 0.0,
+            unit_price: 1.5,
+            line_total: 3.0,
+            tax: 0.0,
+            tax_rate: 0,
+            note: None,
+            authorizer_id: None,
+            authorizer_name: None,
+            category_id: None,
+            category_name: None,
+            is_comped: false,
+            course: None,
+            is_held: false,
+        };
+        let mut snapshot = OrderSnapshot::new(42);
+        snapshot.total = 12.5;
+
+        let (top, bottom) = render(
+            &builder,
+            &event(
+                OrderEventType::ItemsAdded,
+                EventPayload::ItemsAdded { items: vec![item] },
+            ),
+            Some(&snapshot),
+            &text,
+            &format,
+            "",
+        )
+        .unwrap();
+        assert!(top.starts_with("2x Café"));
+        assert!(top.ends_with(&format.money(3.0)));
+        assert!(bottom.starts_with(text.total_label));
+        assert!(bottom.ends_with(&format.money(12.5)));
+    }
+
+    #[test]
+    fn test_render_change_on_payment() {
+        let (text, format) = spanish();
+        let builder = LineDisplayBuilder::new(20);
+        let payment = EventPayload::PaymentAdded {
+            payment_id: 1,
+            method: "CASH".to_string(),
+            amount: 12.5,
+            tendered: Some(20.0),
+            change: Some(7.5),
+            note: None,
+            reference: None,
+            surcharge: None,
+        };
+
+        let (top, bottom) = render(
+            &builder,
+            &event(OrderEventType::PaymentAdded, payment),
+            None,
+            &text,
+            &format,
+            "",
+        )
+        .unwrap();
+        assert!(top.ends_with(&format.money(12.5)));
+        assert!(bottom.starts_with(text.change_label));
+        assert!(bottom.ends_with(&format.money(7.5)));
+    }
+}
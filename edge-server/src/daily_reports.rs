@@ -1,8 +1,13 @@
 //! 日报自动生成调度器
 //!
-//! 在 `business_day_cutoff` 时间点自动生成前一营业日的日报。
+//! 在 `business_day_cutoff` 时间点先处理跨日未结订单 ([`crate::carry_over`])，
+//! 再自动生成前一营业日的日报；`BLOCK` 策略下有未结订单时暂缓生成。
 //! 启动时补漏最近 7 天缺失的日报，定期清理超过 30 天的旧日报。
 //!
+//! 每份自动生成的日报都会发出 `daily_report_ready` 通知；cutoff 生成的日报
+//! 还会按 `store_info.daily_report_print_destination_id` 打印汇总小票
+//! (补漏生成的旧日报不打印，避免启动时一次打出多张)。
+//!
 //! 支持 `config_notify` 信号：修改 cutoff 后立即重算下次触发时间。
 
 use std::sync::Arc;
@@ -11,11 +16,13 @@ use chrono::NaiveTime;
 use tokio::sync::Notify;
 use tokio_util::sync::CancellationToken;
 
+use crate::carry_over::{self, CarryOverOutcome};
 use crate::core::ServerState;
-use crate::db::repository::{daily_report, store_info};
+use crate::db::repository::{daily_report, print_destination, store_info};
+use crate::printing::{DailyReportRenderer, PrintExecutor};
 use crate::utils::time;
-use shared::message::SyncChangeType;
-use shared::models::DailyReportGenerate;
+use shared::message::{NotificationCategory, NotificationPayload, SyncChangeType};
+use shared::models::{CarryOverPolicy, DailyReport, DailyReportGenerate, LocaleFormat};
 
 use shared::cloud::SyncResource;
 const RESOURCE: SyncResource = SyncResource::DailyReport;
@@ -91,7 +98,21 @@ impl DailyReportScheduler {
 
         // 前一营业日
         let prev_day = today - chrono::Duration::days(1);
-        self.generate_for_date(prev_day).await;
+        let boundary = time::day_end_millis(prev_day, tz);
+        if let CarryOverOutcome::Blocked(open) =
+            carry_over::carry_over_open_orders(&self.state, prev_day, boundary).await
+        {
+            tracing::warn!(
+                "Daily report for {} postponed: {} order(s) still open",
+                prev_day,
+                open
+            );
+            return;
+        }
+        if let Some(report) = self.generate_for_date(prev_day).await {
+            let printed = self.print_summary(&report).await;
+            self.notify_ready(&report, printed).await;
+        }
     }
 
     /// 补漏最近 N 天缺失的日报
@@ -115,8 +136,10 @@ impl DailyReportScheduler {
                 }
             }
 
-            self.generate_for_date(date).await;
-            generated += 1;
+            if let Some(report) = self.generate_for_date(date).await {
+                self.notify_ready(&report, None).await;
+                generated += 1;
+            }
         }
 
         if generated > 0 {
@@ -126,8 +149,8 @@ impl DailyReportScheduler {
         }
     }
 
-    /// 为指定日期生成日报（幂等：已存在则跳过）
-    async fn generate_for_date(&self, date: chrono::NaiveDate) {
+    /// 为指定日期生成日报（幂等：已存在则跳过），返回新生成的日报
+    async fn generate_for_date(&self, date: chrono::NaiveDate) -> Option<DailyReport> {
         let date_str = date.format("%Y-%m-%d").to_string();
         let tz = self.state.config.timezone;
 
@@ -135,18 +158,27 @@ impl DailyReportScheduler {
         match daily_report::find_by_date(&self.state.pool, &date_str).await {
             Ok(Some(_)) => {
                 tracing::debug!("Daily report for {} already exists, skipping", date_str);
-                return;
+                return None;
             }
             Ok(None) => {}
             Err(e) => {
                 tracing::error!("Failed to check daily report for {}: {}", date_str, e);
-                return;
+                return None;
             }
         }
 
         let start_millis = time::day_start_millis(date, tz);
         let end_millis = time::day_end_millis(date, tz);
 
+        // BLOCK 策略：该营业日仍有未结订单时不生成
+        if carry_over::load_policy(&self.state).await == CarryOverPolicy::Block
+            && !carry_over::open_orders_before(&self.state, end_millis).is_empty()
+        {
+            tracing::debug!("Daily report for {} blocked by open orders", date_str);
+            return None;
+        }
+        let open_orders = carry_over::open_order_totals(&self.state);
+
         let payload = DailyReportGenerate {
             business_date: date_str.clone(),
             note: None,
@@ -157,6 +189,7 @@ impl DailyReportScheduler {
             payload,
             start_millis,
             end_millis,
+            &open_orders,
             None, // auto-generated, no operator
             None,
             true, // auto_generated
@@ -180,6 +213,7 @@ impl DailyReportScheduler {
                         false,
                     )
                     .await;
+                Some(report)
             }
             Err(e) => {
                 tracing::error!(
@@ -187,7 +221,94 @@ impl DailyReportScheduler {
                     date_str,
                     e
                 );
+                None
+            }
+        }
+    }
+
+    /// 打印日报汇总小票
+    ///
+    /// 返回 `None` 表示未配置打印目的地，`Some(ok)` 为打印结果
+    async fn print_summary(&self, report: &DailyReport) -> Option<bool> {
+        let info = store_info::get(&self.state.pool).await.ok().flatten()?;
+        let dest_id = info.daily_report_print_destination_id?;
+
+        let dest = match print_destination::find_by_id(&self.state.pool, dest_id).await {
+            Ok(Some(dest)) => dest,
+            Ok(None) => {
+                tracing::warn!(
+                    "Daily report print destination {} no longer exists",
+                    dest_id
+                );
+                return Some(false);
+            }
+            Err(e) => {
+                tracing::error!("Failed to load daily report print destination: {}", e);
+                return Some(false);
             }
+        };
+
+        let locale = info
+            .receipt_locale
+            .clone()
+            .unwrap_or_else(|| "es-ES".to_string());
+        let renderer = DailyReportRenderer::new(
+            48,
+            self.state.config.timezone,
+            locale,
+            LocaleFormat::from_store_info(&info),
+        );
+        let data = renderer.render(report, &info.name);
+
+        match PrintExecutor::new().send_to_destination(&dest, &data).await {
+            Ok(()) => {
+                tracing::info!(
+                    "Printed daily report {} to {}",
+                    report.business_date,
+                    dest.name
+                );
+                Some(true)
+            }
+            Err(e) => {
+                self.state
+                    .metrics
+                    .inc_print_failure(crate::core::PrintFailure::DailyReport);
+                tracing::warn!(
+                    "Failed to print daily report {} to {}: {}",
+                    report.business_date,
+                    dest.name,
+                    e
+                );
+                Some(false)
+            }
+        }
+    }
+
+    /// 发出 DailyReportReady 通知（持久化，离线客户端重连后补发）
+    async fn notify_ready(&self, report: &DailyReport, printed: Option<bool>) {
+        let message = format!(
+            "{}: {} order(s), net revenue {:.2}",
+            report.business_date, report.total_orders, report.net_revenue
+        );
+        let payload = if printed == Some(false) {
+            NotificationPayload::warning(
+                "Daily report ready",
+                format!("{} (summary could not be printed)", message),
+            )
+        } else {
+            NotificationPayload::info("Daily report ready", message)
+        }
+        .with_category(NotificationCategory::Business)
+        .with_data(serde_json::json!({
+            "kind": "daily_report_ready",
+            "report_id": report.id,
+            "business_date": &report.business_date,
+            "total_orders": report.total_orders,
+            "net_revenue": report.net_revenue,
+            "printed": printed,
+        }));
+        if let Err(e) = self.state.message_bus().publish_durable(payload).await {
+            tracing::debug!("Daily report notification not broadcast: {}", e);
         }
     }
 
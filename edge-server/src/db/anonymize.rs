@@ -0,0 +1,376 @@
+//! 数据库脱敏导出
+//!
+//! 支持人员复现问题时需要门店的 SQLite 数据库。导出流程：
+//!
+//! 1. `VACUUM INTO` 生成结构完全一致的副本 (表、索引、迁移记录、数据)
+//! 2. 在副本上把会员个人信息、员工姓名、税号 / 客户资料替换为确定性假名
+//! 3. `secure_delete` + `VACUUM` 清除空闲页中残留的原值
+//!
+//! 假名 = `sha256(key ‖ 类别 ‖ 原值)` 派生：同一次导出内同值同假名，跨表关联
+//! (员工 → 各表 operator_name，会员 → 归档订单 member_name，客户税号 → 发票) 保持一致。
+//! `key` 每次导出随机生成，不落盘，假名无法反推原值。
+//!
+//! 仅处理 SQLite 主库；redb 活跃订单不导出。替换后 huella / 归档哈希链无法再验证。
+
+use std::path::Path;
+
+use rand::RngCore;
+use serde::Serialize;
+use sha2::{Digest, Sha256};
+use sqlx::sqlite::SqliteConnectOptions;
+use sqlx::{Connection, SqliteConnection, SqlitePool};
+
+use crate::utils::{AppError, AppResult};
+
+/// 假名类别
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Fake {
+    /// 员工姓名 (含各表操作员快照)
+    Employee,
+    /// 登录用户名
+    Username,
+    /// 会员姓名
+    Member,
+    /// 客户 / 预订联系人姓名
+    Customer,
+    Phone,
+    Email,
+    /// 税号 (格式合法的 NIF：8 位数字 + 校验字母)
+    Nif,
+    Address,
+    Birthday,
+    /// 置空 (自由文本备注、照片路径)
+    Clear,
+}
+
+impl Fake {
+    fn tag(self) -> &'static [u8] {
+        match self {
+            Self::Employee => b"employee",
+            Self::Username => b"username",
+            Self::Member => b"member",
+            Self::Customer => b"customer",
+            Self::Phone => b"phone",
+            Self::Email => b"email",
+            Self::Nif => b"nif",
+            Self::Address => b"address",
+            Self::Birthday => b"birthday",
+            Self::Clear => b"clear",
+        }
+    }
+}
+
+/// 脱敏规则: (表, 列, 类别)
+pub const RULES: &[(&str, &str, Fake)] = &[
+    // 员工
+    ("employee", "username", Fake::Username),
+    ("employee", "name", Fake::Employee),
+    ("employee", "photo", Fake::Clear),
+    // 操作员快照
+    ("shift", "operator_name", Fake::Employee),
+    ("daily_report", "generated_by_name", Fake::Employee),
+    (
+        "daily_report_shift_breakdown",
+        "operator_name",
+        Fake::Employee,
+    ),
+    ("archived_order", "operator_name", Fake::Employee),
+    ("archived_order_event", "operator_name", Fake::Employee),
+    ("payment", "operator_name", Fake::Employee),
+    ("credit_note", "operator_name", Fake::Employee),
+    ("credit_note", "authorizer_name", Fake::Employee),
+    ("invoice_anulacion", "operator_name", Fake::Employee),
+    ("audit_log", "operator_name", Fake::Employee),
+    ("catalog_change", "operator_name", Fake::Employee),
+    ("member_credit_txn", "operator_name", Fake::Employee),
+    ("event_booking", "created_by_name", Fake::Employee),
+    ("event_booking_deposit", "operator_name", Fake::Employee),
+    ("event_booking_amendment", "operator_name", Fake::Employee),
+    ("table_group", "created_by_name", Fake::Employee),
+    ("announcement", "sender_name", Fake::Employee),
+    ("announcement_ack", "employee_name", Fake::Employee),
+    ("eighty_six", "created_by_name", Fake::Employee),
+    ("eighty_six", "cleared_by_name", Fake::Employee),
+    ("stock_adjustment", "operator_name", Fake::Employee),
+    ("stock_count", "created_by_name", Fake::Employee),
+    ("stock_count", "reviewed_by_name", Fake::Employee),
+    ("stock_transfer", "sent_by", Fake::Employee),
+    ("stock_transfer", "received_by", Fake::Employee),
+    ("reservation", "created_by_name", Fake::Employee),
+    ("cash_drawer_event", "operator_name", Fake::Employee),
+    ("sales_fact", "operator_name", Fake::Employee),
+    // 会员
+    ("member", "name", Fake::Member),
+    ("member", "phone", Fake::Phone),
+    ("member", "email", Fake::Email),
+    ("member", "birthday", Fake::Birthday),
+    ("member", "notes", Fake::Clear),
+    ("archived_order", "member_name", Fake::Member),
+    // 门店税号与联系方式
+    ("store_info", "nif", Fake::Nif),
+    ("store_info", "address", Fake::Address),
+    ("store_info", "phone", Fake::Phone),
+    ("store_info", "email", Fake::Email),
+    // 客户 (发票抬头 / 预订 / 取餐)
+    ("archived_order", "customer_nif", Fake::Nif),
+    ("archived_order", "customer_nombre", Fake::Customer),
+    ("archived_order", "customer_address", Fake::Address),
+    ("archived_order", "customer_email", Fake::Email),
+    ("archived_order", "customer_phone", Fake::Phone),
+    ("invoice", "nif", Fake::Nif),
+    ("invoice", "customer_nif", Fake::Nif),
+    ("invoice", "customer_nombre", Fake::Customer),
+    ("invoice", "customer_address", Fake::Address),
+    ("invoice", "customer_email", Fake::Email),
+    ("invoice", "customer_phone", Fake::Phone),
+    ("invoice_anulacion", "nif", Fake::Nif),
+    ("event_booking", "customer_name", Fake::Customer),
+    ("event_booking", "customer_phone", Fake::Phone),
+    ("reservation", "customer_name", Fake::Customer),
+    ("reservation", "customer_phone", Fake::Phone),
+    ("reservation", "customer_email", Fake::Email),
+    ("pickup_ticket", "phone", Fake::Phone),
+];
+
+/// NIF 校验字母表 (DNI 余数 23)
+const NIF_LETTERS: &[u8] = b"TRWAGMYFPDXBNJZSQVHLCKE";
+
+/// 导出结果
+#[derive(Debug, Clone, Default, Serialize)]
+pub struct AnonymizeReport {
+    /// 处理的列数
+    pub columns: usize,
+    /// 替换的不同取值数
+    pub values: u64,
+    /// 被置空的行数
+    pub cleared_rows: u64,
+}
+
+/// 由原值派生假名 (`Fake::Clear` 返回 None)
+pub fn fake_value(key: &[u8], kind: Fake, value: &str) -> Option<String> {
+    let mut hasher = Sha256::new();
+    hasher.update(key);
+    hasher.update(kind.tag());
+    hasher.update([0u8]);
+    hasher.update(value.as_bytes());
+    let digest = hasher.finalize();
+    let short = hex::encode(&digest[..3]);
+    let number = u64::from_be_bytes(digest[..8].try_into().unwrap_or_default());
+
+    Some(match kind {
+        Fake::Employee => format!("Empleado {short}"),
+        Fake::Username => format!("user_{}", hex::encode(&digest[..4])),
+        Fake::Member => format!("Socio {short}"),
+        Fake::Customer => format!("Cliente {short}"),
+        Fake::Phone => format!("+34 6{:08}", number % 100_000_000),
+        Fake::Email => format!("{}@example.invalid", hex::encode(&digest[..4])),
+        Fake::Nif => {
+            let dni = number % 100_000_000;
+            let letter = NIF_LETTERS[(dni % 23) as usize] as char;
+            format!("{dni:08}{letter}")
+        }
+        Fake::Address => format!("Calle Ficticia {}, 28001 Madrid", number % 200 + 1),
+        Fake::Birthday => format!(
+            "{}-{:02}-{:02}",
+            1950 + number % 50,
+            (number >> 8) % 12 + 1,
+            (number >> 16) % 28 + 1
+        ),
+        Fake::Clear => return None,
+    })
+}
+
+/// 导出脱敏副本到 `dest` (文件不得已存在)
+pub async fn export_anonymized(pool: &SqlitePool, dest: &Path) -> AppResult<AnonymizeReport> {
+    if dest.exists() {
+        return Err(AppError::internal(format!(
+            "Export target already exists: {}",
+            dest.display()
+        )));
+    }
+    let dest_str = dest
+        .to_str()
+        .ok_or_else(|| AppError::internal("Export path is not valid UTF-8"))?;
+
+    sqlx::query("VACUUM INTO ?")
+        .bind(dest_str)
+        .execute(pool)
+        .await
+        .map_err(|e| AppError::database(format!("Failed to copy database: {e}")))?;
+
+    let mut key = [0u8; 32];
+    rand::thread_rng().fill_bytes(&mut key);
+
+    let result = anonymize_copy(dest, &key).await;
+    if result.is_err() {
+        // 半脱敏的副本不得留在磁盘上
+        let _ = std::fs::remove_file(dest);
+    }
+    result
+}
+
+async fn anonymize_copy(path: &Path, key: &[u8]) -> AppResult<AnonymizeReport> {
+    let options = SqliteConnectOptions::new()
+        .filename(path)
+        .pragma("secure_delete", "ON")
+        .pragma("foreign_keys", "OFF");
+    let mut conn = SqliteConnection::connect_with(&options)
+        .await
+        .map_err(|e| AppError::database(format!("Failed to open export copy: {e}")))?;
+
+    let report = anonymize(&mut conn, key)
+        .await
+        .map_err(|e| AppError::database(format!("Failed to anonymize export: {e}")))?;
+
+    sqlx::query("VACUUM")
+        .execute(&mut conn)
+        .await
+        .map_err(|e| AppError::database(format!("Failed to compact export: {e}")))?;
+    let _ = conn.close().await;
+
+    Ok(report)
+}
+
+/// 在连接上执行全部规则 (单事务)
+pub async fn anonymize(conn: &mut SqliteConnection, key: &[u8]) -> sqlx::Result<AnonymizeReport> {
+    let mut report = AnonymizeReport::default();
+    let mut tx = conn.begin().await?;
+
+    for (table, column, kind) in RULES {
+        // 旧库可能缺少较新的表 / 列
+        let exists: bool =
+            sqlx::query_scalar("SELECT COUNT(*) > 0 FROM pragma_table_info(?1) WHERE name = ?2")
+                .bind(table)
+                .bind(column)
+                .fetch_one(&mut *tx)
+                .await?;
+        if !exists {
+            continue;
+        }
+        report.columns += 1;
+
+        if *kind == Fake::Clear {
+            let result = sqlx::query(&format!(
+                "UPDATE {table} SET {column} = NULL WHERE {column} IS NOT NULL"
+            ))
+            .execute(&mut *tx)
+            .await?;
+            report.cleared_rows += result.rows_affected();
+            continue;
+        }
+
+        let values: Vec<String> = sqlx::query_scalar(&format!(
+            "SELECT DISTINCT {column} FROM {table} WHERE {column} IS NOT NULL AND {column} != ''"
+        ))
+        .fetch_all(&mut *tx)
+        .await?;
+        for value in values {
+            let fake = fake_value(key, *kind, &value);
+            sqlx::query(&format!(
+                "UPDATE {table} SET {column} = ?1 WHERE {column} = ?2"
+            ))
+            .bind(fake)
+            .bind(&value)
+            .execute(&mut *tx)
+            .await?;
+            report.values += 1;
+        }
+    }
+
+    tx.commit().await?;
+    Ok(report)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::db::test_pool;
+
+    #[test]
+    fn test_fakes_are_deterministic_per_key() {
+        let key = [7u8; 32];
+        let a = fake_value(&key, Fake::Employee, "María");
+        assert_eq!(a, fake_value(&key, Fake::Employee, "María"));
+        assert_ne!(a, fake_value(&key, Fake::Employee, "Pepe"));
+        assert_ne!(a, fake_value(&[8u8; 32], Fake::Employee, "María"));
+        assert_eq!(fake_value(&key, Fake::Clear, "nota"), None);
+    }
+
+    #[test]
+    fn test_fake_nif_has_valid_check_letter() {
+        let nif = fake_value(&[1u8; 32], Fake::Nif, "B12345678").unwrap();
+        assert_eq!(nif.len(), 9);
+        let dni: u64 = nif[..8].parse().unwrap();
+        assert_eq!(nif.as_bytes()[8], NIF_LETTERS[(dni % 23) as usize], "{nif}");
+    }
+
+    #[test]
+    fn test_rules_are_plain_identifiers() {
+        // 规则里的表名 / 列名直接拼入 SQL：只允许标识符字符
+        for (table, column, _) in RULES {
+            assert!(
+                table
+                    .chars()
+                    .chain(column.chars())
+                    .all(|c| c.is_ascii_lowercase() || c == '_'),
+                "{table}.{column}"
+            );
+        }
+    }
+
+    #[tokio::test]
+    async fn test_anonymize_keeps_cross_table_links() {
+        let pool = test_pool().await;
+        sqlx::query(
+            "UPDATE store_info SET nif = 'B12345678', address = 'Calle Mayor 1' WHERE id = 1",
+        )
+        .execute(&pool)
+        .await
+        .unwrap();
+        sqlx::query("INSERT INTO marketing_group (id, name) VALUES (1, 'General')")
+            .execute(&pool)
+            .await
+            .unwrap();
+        sqlx::query("INSERT INTO member (name, phone, email, notes, marketing_group_id) VALUES ('Lucía Gómez', '600111222', 'lucia@mail.es', 'alérgica', 1)")
+            .execute(&pool)
+            .await
+            .unwrap();
+        sqlx::query("INSERT INTO cash_drawer_event (kind, operator_id, operator_name, created_at) VALUES ('NO_SALE', 1, 'María', 0), ('NO_SALE', 2, 'María', 1)")
+            .execute(&pool)
+            .await
+            .unwrap();
+
+        let mut conn = pool.acquire().await.unwrap();
+        let report = anonymize(&mut conn, &[3u8; 32]).await.unwrap();
+        drop(conn);
+        // 所有规则都对应当前 schema 中的列
+        assert_eq!(report.columns, RULES.len());
+        assert_eq!(report.cleared_rows, 1);
+
+        let names: Vec<String> =
+            sqlx::query_scalar("SELECT DISTINCT operator_name FROM cash_drawer_event")
+                .fetch_all(&pool)
+                .await
+                .unwrap();
+        assert_eq!(
+            names,
+            vec![fake_value(&[3u8; 32], Fake::Employee, "María").unwrap()]
+        );
+
+        let (nif, address): (String, String) =
+            sqlx::query_as("SELECT nif, address FROM store_info WHERE id = 1")
+                .fetch_one(&pool)
+                .await
+                .unwrap();
+        assert_ne!(nif, "B12345678");
+        assert!(address.starts_with("Calle Ficticia"));
+
+        let leaked: i64 = sqlx::query_scalar(
+            "SELECT COUNT(*) FROM member WHERE name = 'Lucía Gómez' OR phone = '600111222' OR notes IS NOT NULL",
+        )
+        .fetch_one(&pool)
+        .await
+        .unwrap();
+        assert_eq!(leaked, 0);
+    }
+}
@@ -2,7 +2,9 @@
 //!
 //! Handles SQLite connection pool and migrations
 
+pub mod anonymize;
 pub mod repository;
+pub mod tenant_scope;
 
 use crate::utils::AppError;
 use sqlx::SqlitePool;
@@ -18,6 +20,32 @@ pub struct DbService {
 impl DbService {
     /// Create a new database service with WAL mode and separate read/write pools
     pub async fn new(db_path: &str) -> Result<Self, AppError> {
+        Self::open(db_path, SqlitePoolOptions::new().max_connections(5)).await
+    }
+
+    /// Same as [`new`](Self::new), with the `sqlite_timeout` fault point wired to `faults`
+    #[cfg(feature = "chaos")]
+    pub async fn with_faults(
+        db_path: &str,
+        faults: crate::chaos::FaultRegistry,
+    ) -> Result<Self, AppError> {
+        // 故障注入：获取连接前延迟，超过池获取超时后返回 PoolTimedOut
+        let pool_options =
+            SqlitePoolOptions::new()
+                .max_connections(5)
+                .before_acquire(move |_, _| {
+                    let faults = faults.clone();
+                    Box::pin(async move {
+                        if let Some(delay) = faults.delay(crate::chaos::FaultPoint::SqliteTimeout) {
+                            tokio::time::sleep(delay).await;
+                        }
+                        Ok(true)
+                    })
+                });
+        Self::open(db_path, pool_options).await
+    }
+
+    async fn open(db_path: &str, pool_options: SqlitePoolOptions) -> Result<Self, AppError> {
         // Build connection options: WAL, foreign keys, normal sync
         let options = SqliteConnectOptions::from_str(&format!("sqlite:{db_path}"))
             .map_err(|e| AppError::database(format!("Invalid database path: {e}")))?
@@ -28,8 +56,7 @@ impl DbService {
             .pragma("busy_timeout", "5000")
             .optimize_on_close(true, None);
 
-        let pool = SqlitePoolOptions::new()
-            .max_connections(5)
+        let pool = pool_options
             .connect_with(options)
             .await
             .map_err(|e| AppError::database(format!("Failed to open database: {e}")))?;
@@ -47,3 +74,16 @@ impl DbService {
         Ok(Self { pool })
     }
 }
+
+/// In-memory pool with every migration applied (unit tests)
+#[cfg(test)]
+pub(crate) async fn test_pool() -> SqlitePool {
+    // 单连接：每个 :memory: 连接是独立数据库
+    let pool = SqlitePoolOptions::new()
+        .max_connections(1)
+        .connect("sqlite::memory:")
+        .await
+        .unwrap();
+    sqlx::migrate!("./migrations").run(&pool).await.unwrap();
+    pool
+}
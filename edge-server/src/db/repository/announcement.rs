@@ -0,0 +1,172 @@
+//! Announcement Repository (员工公告)
+
+use super::{RepoError, RepoResult};
+use shared::models::{Announcement, AnnouncementAck, AnnouncementCreate, AnnouncementPriority};
+use sqlx::SqlitePool;
+
+type AnnouncementRow = (i64, String, AnnouncementPriority, i64, String, i64);
+
+const ANNOUNCEMENT_SELECT: &str =
+    "SELECT id, message, priority, sender_id, sender_name, created_at FROM announcement";
+
+async fn load(pool: &SqlitePool, row: AnnouncementRow) -> RepoResult<Announcement> {
+    let (id, message, priority, sender_id, sender_name, created_at) = row;
+    let targets = sqlx::query_scalar::<_, String>(
+        "SELECT terminal FROM announcement_target WHERE announcement_id = ? ORDER BY terminal",
+    )
+    .bind(id)
+    .fetch_all(pool)
+    .await?;
+    let acks = sqlx::query_as::<_, AnnouncementAck>(
+        "SELECT employee_id, employee_name, terminal, acked_at FROM announcement_ack WHERE announcement_id = ? ORDER BY acked_at",
+    )
+    .bind(id)
+    .fetch_all(pool)
+    .await?;
+    Ok(Announcement {
+        id,
+        message,
+        priority,
+        sender_id,
+        sender_name,
+        targets,
+        created_at,
+        acks,
+    })
+}
+
+pub async fn find_by_id(pool: &SqlitePool, id: i64) -> RepoResult<Option<Announcement>> {
+    let row = sqlx::query_as::<_, AnnouncementRow>(&format!("{ANNOUNCEMENT_SELECT} WHERE id = ?"))
+        .bind(id)
+        .fetch_optional(pool)
+        .await?;
+    match row {
+        Some(row) => Ok(Some(load(pool, row).await?)),
+        None => Ok(None),
+    }
+}
+
+/// Most recent announcements first
+pub async fn find_recent(pool: &SqlitePool, limit: i64) -> RepoResult<Vec<Announcement>> {
+    let rows = sqlx::query_as::<_, AnnouncementRow>(&format!(
+        "{ANNOUNCEMENT_SELECT} ORDER BY created_at DESC, id DESC LIMIT ?"
+    ))
+    .bind(limit)
+    .fetch_all(pool)
+    .await?;
+    let mut announcements = Vec::with_capacity(rows.len());
+    for row in rows {
+        announcements.push(load(pool, row).await?);
+    }
+    Ok(announcements)
+}
+
+pub async fn create(
+    pool: &SqlitePool,
+    data: AnnouncementCreate,
+    sender_id: i64,
+    sender_name: &str,
+) -> RepoResult<Announcement> {
+    let id = shared::util::snowflake_id();
+    let now = shared::util::now_millis();
+
+    let mut tx = pool.begin().await?;
+    sqlx::query(
+        "INSERT INTO announcement (id, message, priority, sender_id, sender_name, created_at) VALUES (?, ?, ?, ?, ?, ?)",
+    )
+    .bind(id)
+    .bind(data.message.trim())
+    .bind(data.priority)
+    .bind(sender_id)
+    .bind(sender_name)
+    .bind(now)
+    .execute(&mut *tx)
+    .await?;
+    for terminal in &data.targets {
+        sqlx::query(
+            "INSERT OR IGNORE INTO announcement_target (announcement_id, terminal) VALUES (?, ?)",
+        )
+        .bind(id)
+        .bind(terminal)
+        .execute(&mut *tx)
+        .await?;
+    }
+    tx.commit().await?;
+
+    find_by_id(pool, id)
+        .await?
+        .ok_or_else(|| RepoError::Database("Failed to create announcement".into()))
+}
+
+/// Record an employee's acknowledgement (repeated acks keep the first one)
+pub async fn acknowledge(
+    pool: &SqlitePool,
+    id: i64,
+    employee_id: i64,
+    employee_name: &str,
+    terminal: Option<&str>,
+) -> RepoResult<Announcement> {
+    if find_by_id(pool, id).await?.is_none() {
+        return Err(RepoError::NotFound(format!("Announcement {id} not found")));
+    }
+    sqlx::query(
+        "INSERT OR IGNORE INTO announcement_ack (announcement_id, employee_id, employee_name, terminal, acked_at) VALUES (?, ?, ?, ?, ?)",
+    )
+    .bind(id)
+    .bind(employee_id)
+    .bind(employee_name)
+    .bind(terminal)
+    .bind(shared::util::now_millis())
+    .execute(pool)
+    .await?;
+
+    find_by_id(pool, id)
+        .await?
+        .ok_or_else(|| RepoError::NotFound(format!("Announcement {id} not found")))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::db::test_pool;
+
+    #[tokio::test]
+    async fn test_create_and_acknowledge_once() {
+        let pool = test_pool().await;
+        let announcement = create(
+            &pool,
+            AnnouncementCreate {
+                message: " 86 the salmon ".to_string(),
+                priority: AnnouncementPriority::Urgent,
+                targets: vec!["kitchen".to_string(), "bar".to_string()],
+            },
+            1,
+            "Ana",
+        )
+        .await
+        .unwrap();
+        assert_eq!(announcement.message, "86 the salmon");
+        assert_eq!(announcement.targets, vec!["bar", "kitchen"]);
+        assert!(announcement.acks.is_empty());
+
+        let acked = acknowledge(&pool, announcement.id, 2, "Luis", Some("bar"))
+            .await
+            .unwrap();
+        assert_eq!(acked.acks.len(), 1);
+        let acked = acknowledge(&pool, announcement.id, 2, "Luis", Some("kitchen"))
+            .await
+            .unwrap();
+        assert_eq!(acked.acks.len(), 1);
+        assert_eq!(acked.acks[0].terminal.as_deref(), Some("bar"));
+
+        let recent = find_recent(&pool, 10).await.unwrap();
+        assert_eq!(recent, vec![acked]);
+    }
+
+    #[tokio::test]
+    async fn test_acknowledge_unknown_announcement() {
+        let pool = test_pool().await;
+        let err = acknowledge(&pool, 42, 2, "Luis", None).await.unwrap_err();
+        assert!(matches!(err, RepoError::NotFound(_)));
+    }
+}
@@ -89,7 +89,7 @@ pub async fn create(
         for opt in options {
             let opt_id = shared::util::snowflake_id();
             sqlx::query(
-                "INSERT INTO attribute_option (id, attribute_id, name, price_modifier, display_order, is_active, receipt_name, kitchen_print_name, enable_quantity, max_quantity) VALUES (?1, ?2, ?3, ?4, ?5, 1, ?6, ?7, ?8, ?9)",
+                "INSERT INTO attribute_option (id, attribute_id, name, price_modifier, display_order, is_active, receipt_name, kitchen_print_name, enable_quantity, max_quantity, stock_level_id) VALUES (?1, ?2, ?3, ?4, ?5, 1, ?6, ?7, ?8, ?9, ?10)",
             )
             .bind(opt_id)
             .bind(id)
@@ -100,6 +100,7 @@ pub async fn create(
             .bind(&opt.kitchen_print_name)
             .bind(opt.enable_quantity)
             .bind(opt.max_quantity)
+            .bind(opt.stock_level_id)
             .execute(&mut *tx)
             .await?;
         }
@@ -141,29 +142,78 @@ pub async fn update(pool: &SqlitePool, id: i64, data: AttributeUpdate) -> RepoRe
         return Err(RepoError::NotFound(format!("Attribute {id} not found")));
     }
 
-    // Replace options if provided (atomic: delete + re-create in transaction)
+    // Replace options if provided (atomic). Options carrying an existing id are updated
+    // in place so open orders and stock links keyed by attribute_option.id stay valid.
     if let Some(options) = data.options {
         let mut tx = pool.begin().await?;
-        sqlx::query!("DELETE FROM attribute_option WHERE attribute_id = ?", id)
-            .execute(&mut *tx)
-            .await?;
+        let mut kept = Vec::with_capacity(options.len());
         for opt in &options {
-            let opt_id = shared::util::snowflake_id();
-            sqlx::query(
-                "INSERT INTO attribute_option (id, attribute_id, name, price_modifier, display_order, is_active, receipt_name, kitchen_print_name, enable_quantity, max_quantity) VALUES (?1, ?2, ?3, ?4, ?5, 1, ?6, ?7, ?8, ?9)",
+            let existing = match opt.id {
+                Some(opt_id) => {
+                    sqlx::query_scalar::<_, i64>(
+                        "SELECT id FROM attribute_option WHERE id = ? AND attribute_id = ?",
+                    )
+                    .bind(opt_id)
+                    .bind(id)
+                    .fetch_optional(&mut *tx)
+                    .await?
+                }
+                None => None,
+            };
+            let opt_id = match existing {
+                Some(opt_id) => {
+                    sqlx::query(
+                        "UPDATE attribute_option SET name = ?1, price_modifier = ?2, display_order = ?3, is_active = 1, receipt_name = ?4, kitchen_print_name = ?5, enable_quantity = ?6, max_quantity = ?7, stock_level_id = ?8 WHERE id = ?9",
+                    )
+                    .bind(&opt.name)
+                    .bind(opt.price_modifier)
+                    .bind(opt.display_order)
+                    .bind(&opt.receipt_name)
+                    .bind(&opt.kitchen_print_name)
+                    .bind(opt.enable_quantity)
+                    .bind(opt.max_quantity)
+                    .bind(opt.stock_level_id)
+                    .bind(opt_id)
+                    .execute(&mut *tx)
+                    .await?;
+                    opt_id
+                }
+                None => {
+                    let opt_id = shared::util::snowflake_id();
+                    sqlx::query(
+                        "INSERT INTO attribute_option (id, attribute_id, name, price_modifier, display_order, is_active, receipt_name, kitchen_print_name, enable_quantity, max_quantity, stock_level_id) VALUES (?1, ?2, ?3, ?4, ?5, 1, ?6, ?7, ?8, ?9, ?10)",
+                    )
+                    .bind(opt_id)
+                    .bind(id)
+                    .bind(&opt.name)
+                    .bind(opt.price_modifier)
+                    .bind(opt.display_order)
+                    .bind(&opt.receipt_name)
+                    .bind(&opt.kitchen_print_name)
+                    .bind(opt.enable_quantity)
+                    .bind(opt.max_quantity)
+                    .bind(opt.stock_level_id)
+                    .execute(&mut *tx)
+                    .await?;
+                    opt_id
+                }
+            };
+            kept.push(opt_id);
+        }
+        // Drop options no longer in the list
+        let placeholders = kept.iter().map(|_| "?").collect::<Vec<_>>().join(",");
+        let sql = if kept.is_empty() {
+            "DELETE FROM attribute_option WHERE attribute_id = ?".to_string()
+        } else {
+            format!(
+                "DELETE FROM attribute_option WHERE attribute_id = ? AND id NOT IN ({placeholders})"
             )
-            .bind(opt_id)
-            .bind(id)
-            .bind(&opt.name)
-            .bind(opt.price_modifier)
-            .bind(opt.display_order)
-            .bind(&opt.receipt_name)
-            .bind(&opt.kitchen_print_name)
-            .bind(opt.enable_quantity)
-            .bind(opt.max_quantity)
-            .execute(&mut *tx)
-            .await?;
+        };
+        let mut query = sqlx::query(&sql).bind(id);
+        for opt_id in &kept {
+            query = query.bind(opt_id);
         }
+        query.execute(&mut *tx).await?;
         tx.commit().await?;
     }
 
@@ -195,7 +245,7 @@ async fn find_options_by_attribute(
     attribute_id: i64,
 ) -> RepoResult<Vec<AttributeOption>> {
     let options = sqlx::query_as::<_, AttributeOption>(
-        "SELECT id, attribute_id, name, price_modifier, display_order, is_active, receipt_name, kitchen_print_name, enable_quantity, max_quantity FROM attribute_option WHERE attribute_id = ? ORDER BY display_order",
+        "SELECT id, attribute_id, name, price_modifier, display_order, is_active, receipt_name, kitchen_print_name, enable_quantity, max_quantity, stock_level_id FROM attribute_option WHERE attribute_id = ? ORDER BY display_order",
     )
     .bind(attribute_id)
     .fetch_all(pool)
@@ -211,7 +261,7 @@ async fn batch_load_options(pool: &SqlitePool, attrs: &mut [Attribute]) -> RepoR
     let ids: Vec<i64> = attrs.iter().map(|a| a.id).collect();
     let placeholders = ids.iter().map(|_| "?").collect::<Vec<_>>().join(",");
     let sql = format!(
-        "SELECT id, attribute_id, name, price_modifier, display_order, is_active, receipt_name, kitchen_print_name, enable_quantity, max_quantity FROM attribute_option WHERE attribute_id IN ({placeholders}) ORDER BY display_order"
+        "SELECT id, attribute_id, name, price_modifier, display_order, is_active, receipt_name, kitchen_print_name, enable_quantity, max_quantity, stock_level_id FROM attribute_option WHERE attribute_id IN ({placeholders}) ORDER BY display_order"
     );
     let mut query = sqlx::query_as::<_, AttributeOption>(&sql);
     for id in &ids {
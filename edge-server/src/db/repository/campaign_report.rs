@@ -0,0 +1,329 @@
+//! Campaign ROI Repository (营销活动效果)
+//!
+//! 从归档订单汇总每个活动的使用记录 (一单一行)，再在内存中计算回访提升。
+
+use std::collections::{BTreeMap, HashMap};
+
+use super::RepoResult;
+use shared::models::{CampaignKind, CampaignRoi, CampaignRoiReport};
+use sqlx::SqlitePool;
+
+/// 已完成且未作废的订单，按 end_time 落在 `[?1, ?2)`
+const ORDER_FILTER: &str =
+    "o.status = 'COMPLETED' AND o.is_voided = 0 AND o.end_time >= ?1 AND o.end_time < ?2";
+
+/// (campaign_id, name, order_id, member_id, end_time, order_total, cost)
+type TouchRow = (Option<i64>, String, i64, Option<i64>, i64, f64, f64);
+
+/// One order touched by one campaign
+#[derive(Debug, Clone)]
+struct Touch {
+    kind: CampaignKind,
+    campaign_id: Option<i64>,
+    name: String,
+    member_id: Option<i64>,
+    end_time: i64,
+    order_total: f64,
+    cost: f64,
+}
+
+async fn fetch_touches(
+    pool: &SqlitePool,
+    kind: CampaignKind,
+    sql: &str,
+    from: i64,
+    to: i64,
+) -> RepoResult<Vec<Touch>> {
+    let rows = sqlx::query_as::<_, TouchRow>(sql)
+        .bind(from)
+        .bind(to)
+        .fetch_all(pool)
+        .await?;
+    Ok(rows
+        .into_iter()
+        .map(
+            |(campaign_id, name, _order_id, member_id, end_time, order_total, cost)| Touch {
+                kind,
+                campaign_id,
+                name,
+                member_id,
+                end_time,
+                order_total,
+                cost,
+            },
+        )
+        .collect())
+}
+
+/// Campaign ROI for orders completed in `[from, to)`
+pub async fn campaign_roi(pool: &SqlitePool, from: i64, to: i64) -> RepoResult<CampaignRoiReport> {
+    let mut touches = fetch_touches(
+        pool,
+        CampaignKind::PriceRule,
+        &format!(
+            "SELECT a.rule_id, COALESCE(MAX(a.rule_receipt_name), MAX(a.rule_name), 'PRICE_RULE'), \
+             o.id, o.member_id, o.end_time, o.total_amount, COALESCE(SUM(a.amount), 0.0) \
+             FROM archived_order_adjustment a JOIN archived_order o ON a.order_pk = o.id \
+             WHERE {ORDER_FILTER} AND a.source_type = 'PRICE_RULE' AND a.direction = 'DISCOUNT' \
+               AND a.skipped = 0 AND a.amount > 0 \
+             GROUP BY a.rule_id, o.id"
+        ),
+        from,
+        to,
+    )
+    .await?;
+
+    touches.extend(
+        fetch_touches(
+            pool,
+            CampaignKind::MarketingGroup,
+            &format!(
+                "SELECT (SELECT mg.id FROM marketing_group mg WHERE mg.name = o.marketing_group_name LIMIT 1), \
+                 COALESCE(o.marketing_group_name, 'MEMBER_GROUP'), \
+                 o.id, o.member_id, o.end_time, o.total_amount, o.mg_discount_amount \
+                 FROM archived_order o \
+                 WHERE {ORDER_FILTER} AND o.mg_discount_amount > 0"
+            ),
+            from,
+            to,
+        )
+        .await?,
+    );
+
+    // 奖励行 instance_id 固定前缀 stamp_reward::；一单兑换多个活动时按兑换次数均摊
+    touches.extend(
+        fetch_touches(
+            pool,
+            CampaignKind::StampActivity,
+            &format!(
+                "SELECT l.stamp_activity_id, COALESCE(sa.name, 'STAMP'), \
+                 o.id, o.member_id, o.end_time, o.total_amount, \
+                 COALESCE((SELECT SUM(i.price * i.quantity) FROM archived_order_item i \
+                           WHERE i.order_pk = o.id AND i.is_comped = 1 \
+                             AND i.instance_id LIKE 'stamp_reward::%'), 0.0) \
+                 / (SELECT COUNT(*) FROM stamp_ledger r WHERE r.order_id = o.id AND r.kind = 'REDEEM') \
+                 FROM stamp_ledger l \
+                 JOIN archived_order o ON l.order_id = o.id \
+                 LEFT JOIN stamp_activity sa ON sa.id = l.stamp_activity_id \
+                 WHERE {ORDER_FILTER} AND l.kind = 'REDEEM'"
+            ),
+            from,
+            to,
+        )
+        .await?,
+    );
+
+    let member_orders = sqlx::query_as::<_, (i64, i64)>(&format!(
+        "SELECT o.member_id, o.end_time FROM archived_order o \
+         WHERE {ORDER_FILTER} AND o.member_id IS NOT NULL"
+    ))
+    .bind(from)
+    .bind(to)
+    .fetch_all(pool)
+    .await?;
+
+    let mut visits: HashMap<i64, Vec<i64>> = HashMap::new();
+    for (member_id, end_time) in member_orders {
+        visits.entry(member_id).or_default().push(end_time);
+    }
+
+    let (baseline_repeat_rate, campaigns) = aggregate(touches, &visits);
+    Ok(CampaignRoiReport {
+        from,
+        to,
+        baseline_repeat_rate,
+        campaigns,
+    })
+}
+
+/// Share of members with an order who ordered more than once
+fn baseline_repeat_rate(visits: &HashMap<i64, Vec<i64>>) -> f64 {
+    if visits.is_empty() {
+        return 0.0;
+    }
+    let repeat = visits.values().filter(|v| v.len() > 1).count();
+    repeat as f64 / visits.len() as f64
+}
+
+/// Roll touches up per campaign (sorted by attributable revenue, highest first)
+fn aggregate(touches: Vec<Touch>, visits: &HashMap<i64, Vec<i64>>) -> (f64, Vec<CampaignRoi>) {
+    let baseline = baseline_repeat_rate(visits);
+
+    #[derive(Default)]
+    struct Acc {
+        name: String,
+        redemptions: i64,
+        cost: f64,
+        revenue: f64,
+        /// member → first campaign order time
+        first_touch: HashMap<i64, i64>,
+    }
+
+    let mut groups: BTreeMap<(CampaignKind, Option<i64>, String), Acc> = BTreeMap::new();
+    for touch in touches {
+        // 已删除的活动 (无 id) 按名称区分
+        let key_name = if touch.campaign_id.is_some() {
+            String::new()
+        } else {
+            touch.name.clone()
+        };
+        let acc = groups
+            .entry((touch.kind, touch.campaign_id, key_name))
+            .or_default();
+        acc.name = touch.name;
+        acc.redemptions += 1;
+        acc.cost += touch.cost;
+        acc.revenue += touch.order_total;
+        if let Some(member_id) = touch.member_id {
+            acc.first_touch
+                .entry(member_id)
+                .and_modify(|t| *t = (*t).min(touch.end_time))
+                .or_insert(touch.end_time);
+        }
+    }
+
+    let mut campaigns: Vec<CampaignRoi> = groups
+        .into_iter()
+        .map(|((kind, campaign_id, _), acc)| {
+            let members_reached = acc.first_touch.len() as i64;
+            let returned = acc
+                .first_touch
+                .iter()
+                .filter(|(member_id, first)| {
+                    visits
+                        .get(member_id)
+                        .is_some_and(|times| times.iter().any(|t| t > first))
+                })
+                .count();
+            let repeat_rate = if members_reached > 0 {
+                returned as f64 / members_reached as f64
+            } else {
+                0.0
+            };
+            CampaignRoi {
+                kind,
+                campaign_id,
+                name: acc.name,
+                redemptions: acc.redemptions,
+                discount_cost: acc.cost,
+                attributable_revenue: acc.revenue,
+                revenue_per_cost: (acc.cost > 0.0).then(|| acc.revenue / acc.cost),
+                members_reached,
+                repeat_rate,
+                repeat_lift: if members_reached > 0 {
+                    repeat_rate - baseline
+                } else {
+                    0.0
+                },
+            }
+        })
+        .collect();
+    campaigns.sort_by(|a, b| b.attributable_revenue.total_cmp(&a.attributable_revenue));
+    (baseline, campaigns)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::db::test_pool;
+
+    async fn insert_order(
+        pool: &SqlitePool,
+        id: i64,
+        member_id: Option<i64>,
+        end_time: i64,
+        total: f64,
+        mg: Option<(&str, f64)>,
+    ) {
+        sqlx::query(
+            "INSERT INTO archived_order (id, receipt_number, status, total_amount, start_time, end_time, member_id, marketing_group_name, mg_discount_amount, created_at) \
+             VALUES (?1, ?2, 'COMPLETED', ?3, ?4, ?4, ?5, ?6, ?7, ?4)",
+        )
+        .bind(id)
+        .bind(format!("R-{id}"))
+        .bind(total)
+        .bind(end_time)
+        .bind(member_id)
+        .bind(mg.map(|(name, _)| name))
+        .bind(mg.map(|(_, amount)| amount).unwrap_or(0.0))
+        .execute(pool)
+        .await
+        .unwrap();
+    }
+
+    async fn insert_rule_discount(pool: &SqlitePool, order_id: i64, rule_id: i64, amount: f64) {
+        sqlx::query(
+            "INSERT INTO archived_order_adjustment (id, order_pk, source_type, direction, rule_id, rule_name, amount) \
+             VALUES (?1, ?2, 'PRICE_RULE', 'DISCOUNT', ?3, 'Happy Hour', ?4)",
+        )
+        .bind(order_id * 100 + rule_id)
+        .bind(order_id)
+        .bind(rule_id)
+        .bind(amount)
+        .execute(pool)
+        .await
+        .unwrap();
+    }
+
+    #[tokio::test]
+    async fn test_campaign_roi_report() {
+        let pool = test_pool().await;
+
+        // Member 1: happy hour at t=100, back at t=300 → repeat
+        insert_order(&pool, 1, Some(1), 100, 20.0, None).await;
+        insert_rule_discount(&pool, 1, 7, 2.0).await;
+        insert_order(&pool, 2, Some(1), 300, 15.0, None).await;
+        // Member 2: happy hour once, never back
+        insert_order(&pool, 3, Some(2), 200, 30.0, Some(("VIP", 3.0))).await;
+        insert_rule_discount(&pool, 3, 7, 4.0).await;
+        // Member 3: stamp reward redeemed
+        insert_order(&pool, 4, Some(3), 250, 12.0, None).await;
+        sqlx::query(
+            "INSERT INTO archived_order_item (id, order_pk, spec, instance_id, name, price, quantity, is_comped) \
+             VALUES (40, 4, '1', 'stamp_reward::cmd-1', 'Coffee', 1.5, 1, 1)",
+        )
+        .execute(&pool)
+        .await
+        .unwrap();
+        crate::db::repository::stamp::record_redeem(&pool, 3, 9, 4, 10, 250)
+            .await
+            .unwrap();
+        // Outside the period
+        insert_order(&pool, 5, Some(2), 5_000, 50.0, None).await;
+        insert_rule_discount(&pool, 5, 7, 5.0).await;
+
+        let report = campaign_roi(&pool, 0, 1_000).await.unwrap();
+        // Members 1, 2, 3 ordered; only member 1 came back
+        assert!((report.baseline_repeat_rate - 1.0 / 3.0).abs() < 1e-9);
+        assert_eq!(report.campaigns.len(), 3);
+
+        let rule = &report.campaigns[0];
+        assert_eq!(rule.kind, CampaignKind::PriceRule);
+        assert_eq!(rule.campaign_id, Some(7));
+        assert_eq!(rule.name, "Happy Hour");
+        assert_eq!(rule.redemptions, 2);
+        assert_eq!(rule.discount_cost, 6.0);
+        assert_eq!(rule.attributable_revenue, 50.0);
+        assert_eq!(rule.members_reached, 2);
+        assert_eq!(rule.repeat_rate, 0.5);
+        assert!((rule.repeat_lift - (0.5 - 1.0 / 3.0)).abs() < 1e-9);
+
+        let mg = report
+            .campaigns
+            .iter()
+            .find(|c| c.kind == CampaignKind::MarketingGroup)
+            .unwrap();
+        assert_eq!((mg.campaign_id, mg.name.as_str()), (None, "VIP"));
+        assert_eq!(mg.revenue_per_cost, Some(10.0));
+
+        let stamp = report
+            .campaigns
+            .iter()
+            .find(|c| c.kind == CampaignKind::StampActivity)
+            .unwrap();
+        assert_eq!(stamp.campaign_id, Some(9));
+        assert_eq!(stamp.redemptions, 1);
+        assert_eq!(stamp.discount_cost, 1.5);
+        assert_eq!(stamp.repeat_rate, 0.0);
+    }
+}
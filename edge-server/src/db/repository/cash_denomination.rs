@@ -0,0 +1,351 @@
+//! Cash Denomination Repository (点钞面额)
+
+use super::{RepoError, RepoResult};
+use shared::models::{
+    CashCountLine, CashCountSummary, CashDenomination, CashDenominationInput, CashDenominationSet,
+    CashDenominationSetCreate, CashDenominationSetUpdate,
+};
+use sqlx::{SqliteConnection, SqlitePool};
+
+const SET_SELECT: &str = "SELECT id, currency_code, currency_symbol, is_primary, exchange_rate, is_active, created_at, updated_at FROM cash_denomination_set";
+
+async fn attach_denominations(
+    pool: &SqlitePool,
+    sets: &mut [CashDenominationSet],
+) -> RepoResult<()> {
+    let denominations = sqlx::query_as::<_, CashDenomination>(
+        "SELECT id, set_id, kind, value FROM cash_denomination ORDER BY value",
+    )
+    .fetch_all(pool)
+    .await?;
+    for set in sets.iter_mut() {
+        set.denominations = denominations
+            .iter()
+            .filter(|d| d.set_id == set.id)
+            .cloned()
+            .collect();
+    }
+    Ok(())
+}
+
+/// All sets (primary first) with their denominations
+pub async fn find_all(pool: &SqlitePool) -> RepoResult<Vec<CashDenominationSet>> {
+    let mut sets = sqlx::query_as::<_, CashDenominationSet>(&format!(
+        "{SET_SELECT} ORDER BY is_primary DESC, currency_code"
+    ))
+    .fetch_all(pool)
+    .await?;
+    attach_denominations(pool, &mut sets).await?;
+    Ok(sets)
+}
+
+pub async fn find_by_id(pool: &SqlitePool, id: i64) -> RepoResult<Option<CashDenominationSet>> {
+    let Some(mut set) =
+        sqlx::query_as::<_, CashDenominationSet>(&format!("{SET_SELECT} WHERE id = ?"))
+            .bind(id)
+            .fetch_optional(pool)
+            .await?
+    else {
+        return Ok(None);
+    };
+    attach_denominations(pool, std::slice::from_mut(&mut set)).await?;
+    Ok(Some(set))
+}
+
+fn validate_code(code: &str) -> RepoResult<()> {
+    if code.len() != 3 || !code.chars().all(|c| c.is_ascii_uppercase()) {
+        return Err(RepoError::Validation(format!(
+            "currency_code must be a 3-letter ISO 4217 code, got '{code}'"
+        )));
+    }
+    Ok(())
+}
+
+fn validate_rate(rate: f64) -> RepoResult<()> {
+    if !rate.is_finite() || rate <= 0.0 {
+        return Err(RepoError::Validation(
+            "exchange_rate must be a positive number".into(),
+        ));
+    }
+    Ok(())
+}
+
+fn validate_denominations(denominations: &[CashDenominationInput]) -> RepoResult<()> {
+    if denominations.is_empty() {
+        return Err(RepoError::Validation(
+            "At least one denomination is required".into(),
+        ));
+    }
+    for (i, d) in denominations.iter().enumerate() {
+        if !d.value.is_finite() || d.value <= 0.0 {
+            return Err(RepoError::Validation(format!(
+                "Invalid denomination value: {}",
+                d.value
+            )));
+        }
+        if denominations[..i]
+            .iter()
+            .any(|other| other.value == d.value)
+        {
+            return Err(RepoError::Validation(format!(
+                "Duplicate denomination value: {}",
+                d.value
+            )));
+        }
+    }
+    Ok(())
+}
+
+async fn replace_denominations(
+    conn: &mut SqliteConnection,
+    set_id: i64,
+    denominations: &[CashDenominationInput],
+) -> RepoResult<()> {
+    sqlx::query("DELETE FROM cash_denomination WHERE set_id = ?")
+        .bind(set_id)
+        .execute(&mut *conn)
+        .await?;
+    for d in denominations {
+        sqlx::query("INSERT INTO cash_denomination (id, set_id, kind, value) VALUES (?, ?, ?, ?)")
+            .bind(shared::util::snowflake_id())
+            .bind(set_id)
+            .bind(d.kind)
+            .bind(d.value)
+            .execute(&mut *conn)
+            .await?;
+    }
+    Ok(())
+}
+
+/// Create a set; a new primary set demotes the previous one
+pub async fn create(
+    pool: &SqlitePool,
+    data: &CashDenominationSetCreate,
+) -> RepoResult<CashDenominationSet> {
+    validate_code(&data.currency_code)?;
+    validate_denominations(&data.denominations)?;
+    let exchange_rate = if data.is_primary {
+        1.0
+    } else {
+        data.exchange_rate.ok_or_else(|| {
+            RepoError::Validation("exchange_rate is required for a secondary currency".into())
+        })?
+    };
+    validate_rate(exchange_rate)?;
+
+    let id = shared::util::snowflake_id();
+    let now = shared::util::now_millis();
+    let mut tx = pool.begin().await?;
+    if data.is_primary {
+        sqlx::query(
+            "UPDATE cash_denomination_set SET is_primary = 0, updated_at = ? WHERE is_primary = 1",
+        )
+        .bind(now)
+        .execute(&mut *tx)
+        .await?;
+    }
+    sqlx::query(
+        "INSERT INTO cash_denomination_set (id, currency_code, currency_symbol, is_primary, exchange_rate, is_active, created_at, updated_at) VALUES (?, ?, ?, ?, ?, 1, ?, ?)",
+    )
+    .bind(id)
+    .bind(&data.currency_code)
+    .bind(data.currency_symbol.trim())
+    .bind(data.is_primary)
+    .bind(exchange_rate)
+    .bind(now)
+    .bind(now)
+    .execute(&mut *tx)
+    .await?;
+    replace_denominations(&mut tx, id, &data.denominations).await?;
+    tx.commit().await?;
+
+    find_by_id(pool, id)
+        .await?
+        .ok_or_else(|| RepoError::Database("Failed to create denomination set".into()))
+}
+
+pub async fn update(
+    pool: &SqlitePool,
+    id: i64,
+    data: &CashDenominationSetUpdate,
+) -> RepoResult<CashDenominationSet> {
+    let existing = find_by_id(pool, id)
+        .await?
+        .ok_or_else(|| RepoError::NotFound(format!("Denomination set {id} not found")))?;
+    if let Some(rate) = data.exchange_rate {
+        validate_rate(rate)?;
+        if existing.is_primary && rate != 1.0 {
+            return Err(RepoError::Validation(
+                "The primary currency exchange rate is always 1".into(),
+            ));
+        }
+    }
+    if existing.is_primary && data.is_active == Some(false) {
+        return Err(RepoError::Validation(
+            "The primary currency cannot be deactivated".into(),
+        ));
+    }
+    if let Some(denominations) = &data.denominations {
+        validate_denominations(denominations)?;
+    }
+
+    let mut tx = pool.begin().await?;
+    sqlx::query(
+        "UPDATE cash_denomination_set SET currency_symbol = COALESCE(?1, currency_symbol), exchange_rate = COALESCE(?2, exchange_rate), is_active = COALESCE(?3, is_active), updated_at = ?4 WHERE id = ?5",
+    )
+    .bind(data.currency_symbol.as_deref().map(str::trim))
+    .bind(data.exchange_rate)
+    .bind(data.is_active)
+    .bind(shared::util::now_millis())
+    .bind(id)
+    .execute(&mut *tx)
+    .await?;
+    if let Some(denominations) = &data.denominations {
+        replace_denominations(&mut tx, id, denominations).await?;
+    }
+    tx.commit().await?;
+
+    find_by_id(pool, id)
+        .await?
+        .ok_or_else(|| RepoError::NotFound(format!("Denomination set {id} not found")))
+}
+
+/// Delete a secondary set (the primary set cannot be deleted)
+pub async fn delete(pool: &SqlitePool, id: i64) -> RepoResult<()> {
+    let existing = find_by_id(pool, id)
+        .await?
+        .ok_or_else(|| RepoError::NotFound(format!("Denomination set {id} not found")))?;
+    if existing.is_primary {
+        return Err(RepoError::Validation(
+            "The primary currency cannot be deleted".into(),
+        ));
+    }
+    let mut tx = pool.begin().await?;
+    sqlx::query("DELETE FROM cash_denomination WHERE set_id = ?")
+        .bind(id)
+        .execute(&mut *tx)
+        .await?;
+    sqlx::query("DELETE FROM cash_denomination_set WHERE id = ?")
+        .bind(id)
+        .execute(&mut *tx)
+        .await?;
+    tx.commit().await?;
+    Ok(())
+}
+
+/// Compute a count against the configured sets
+pub async fn summarize(pool: &SqlitePool, lines: &[CashCountLine]) -> RepoResult<CashCountSummary> {
+    let sets = find_all(pool).await?;
+    CashCountSummary::compute(&sets, lines).map_err(RepoError::Validation)
+}
+
+/// Store the counted denominations of a closed shift
+pub async fn save_shift_count(
+    pool: &SqlitePool,
+    shift_id: i64,
+    summary: &CashCountSummary,
+) -> RepoResult<()> {
+    let mut tx = pool.begin().await?;
+    sqlx::query("DELETE FROM shift_cash_count WHERE shift_id = ?")
+        .bind(shift_id)
+        .execute(&mut *tx)
+        .await?;
+    for currency in &summary.currencies {
+        for line in &currency.lines {
+            sqlx::query(
+                "INSERT INTO shift_cash_count (shift_id, currency_code, value, quantity, exchange_rate) VALUES (?, ?, ?, ?, ?)",
+            )
+            .bind(shift_id)
+            .bind(&currency.currency_code)
+            .bind(line.value)
+            .bind(line.quantity)
+            .bind(currency.exchange_rate)
+            .execute(&mut *tx)
+            .await?;
+        }
+    }
+    tx.commit().await?;
+    Ok(())
+}
+
+/// Counted denominations of a shift (empty when closed without a count)
+pub async fn find_shift_count(pool: &SqlitePool, shift_id: i64) -> RepoResult<Vec<CashCountLine>> {
+    let lines = sqlx::query_as::<_, CashCountLine>(
+        "SELECT currency_code, value, quantity FROM shift_cash_count WHERE shift_id = ? ORDER BY currency_code, value DESC",
+    )
+    .bind(shift_id)
+    .fetch_all(pool)
+    .await?;
+    Ok(lines)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::db::test_pool;
+    use shared::models::DenominationKind;
+
+    #[tokio::test]
+    async fn test_secondary_set_counts_against_seeded_primary() {
+        let pool = test_pool().await;
+        let usd = create(
+            &pool,
+            &CashDenominationSetCreate {
+                currency_code: "USD".to_string(),
+                currency_symbol: "$".to_string(),
+                is_primary: false,
+                exchange_rate: Some(0.9),
+                denominations: vec![
+                    CashDenominationInput {
+                        kind: DenominationKind::Note,
+                        value: 10.0,
+                    },
+                    CashDenominationInput {
+                        kind: DenominationKind::Note,
+                        value: 20.0,
+                    },
+                ],
+            },
+        )
+        .await
+        .unwrap();
+        assert_eq!(usd.denominations.len(), 2);
+
+        let summary = summarize(
+            &pool,
+            &[
+                CashCountLine {
+                    currency_code: "EUR".to_string(),
+                    value: 0.05,
+                    quantity: 3,
+                },
+                CashCountLine {
+                    currency_code: "USD".to_string(),
+                    value: 20.0,
+                    quantity: 1,
+                },
+            ],
+        )
+        .await
+        .unwrap();
+        assert_eq!(summary.total, 18.15);
+
+        // 主币种不可删除 / 停用
+        let sets = find_all(&pool).await.unwrap();
+        assert!(sets[0].is_primary);
+        assert!(delete(&pool, sets[0].id).await.is_err());
+        delete(&pool, usd.id).await.unwrap();
+        assert!(
+            summarize(
+                &pool,
+                &[CashCountLine {
+                    currency_code: "USD".to_string(),
+                    value: 20.0,
+                    quantity: 1,
+                }],
+            )
+            .await
+            .is_err()
+        );
+    }
+}
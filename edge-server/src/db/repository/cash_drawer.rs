@@ -0,0 +1,233 @@
+//! Cash Drawer Repository
+
+use super::{RepoError, RepoResult};
+use shared::models::{
+    CashDrawerEvent, CashDrawerEventCreate, CashDrawerEventKind, CashDrawerSummary,
+};
+use sqlx::SqlitePool;
+
+const SELECT_COLUMNS: &str = "SELECT id, kind, amount, reason, operator_id, operator_name, shift_id, created_at FROM cash_drawer_event";
+
+/// Aggregate columns for [`CashDrawerSummary`] (shared with the daily report)
+pub const SUMMARY_COLUMNS: &str = "COUNT(CASE WHEN kind = 'SALE' THEN 1 END) AS sale_opens, \
+     COUNT(CASE WHEN kind = 'NO_SALE' THEN 1 END) AS no_sale_opens, \
+     COUNT(CASE WHEN kind = 'PAID_IN' THEN 1 END) AS paid_in_count, \
+     COALESCE(SUM(CASE WHEN kind = 'PAID_IN' THEN amount ELSE 0.0 END), 0.0) AS paid_in_amount, \
+     COUNT(CASE WHEN kind = 'PAID_OUT' THEN 1 END) AS paid_out_count, \
+     COALESCE(SUM(CASE WHEN kind = 'PAID_OUT' THEN amount ELSE 0.0 END), 0.0) AS paid_out_amount";
+
+/// Record a drawer event against the open shift (if any)
+///
+/// Paid-in / paid-out adjust the shift's expected cash in the same transaction,
+/// so shift close reconciliation accounts for them.
+pub async fn record(
+    pool: &SqlitePool,
+    data: CashDrawerEventCreate,
+    operator_id: i64,
+    operator_name: &str,
+) -> RepoResult<CashDrawerEvent> {
+    let amount = match data.kind {
+        CashDrawerEventKind::Sale | CashDrawerEventKind::NoSale => 0.0,
+        CashDrawerEventKind::PaidIn | CashDrawerEventKind::PaidOut => {
+            if !data.amount.is_finite() || data.amount <= 0.0 {
+                return Err(RepoError::Validation(format!(
+                    "Cash movement amount must be positive: {}",
+                    data.amount
+                )));
+            }
+            data.amount
+        }
+    };
+
+    let now = shared::util::now_millis();
+    let id = shared::util::snowflake_id();
+    let mut tx = pool.begin().await?;
+
+    let shift_id: Option<i64> =
+        sqlx::query_scalar("SELECT id FROM shift WHERE status = 'OPEN' LIMIT 1")
+            .fetch_optional(&mut *tx)
+            .await?;
+
+    sqlx::query(
+        "INSERT INTO cash_drawer_event (id, kind, amount, reason, operator_id, operator_name, shift_id, created_at) VALUES (?, ?, ?, ?, ?, ?, ?, ?)",
+    )
+    .bind(id)
+    .bind(data.kind)
+    .bind(amount)
+    .bind(&data.reason)
+    .bind(operator_id)
+    .bind(operator_name)
+    .bind(shift_id)
+    .bind(now)
+    .execute(&mut *tx)
+    .await?;
+
+    let delta = data.kind.cash_sign() * amount;
+    if let Some(shift_id) = shift_id
+        && delta != 0.0
+    {
+        sqlx::query(
+            "UPDATE shift SET expected_cash = expected_cash + ?1, last_active_at = ?2, updated_at = ?2 WHERE id = ?3",
+        )
+        .bind(delta)
+        .bind(now)
+        .bind(shift_id)
+        .execute(&mut *tx)
+        .await?;
+    }
+
+    tx.commit().await?;
+
+    find_by_id(pool, id)
+        .await?
+        .ok_or_else(|| RepoError::Database("Failed to record cash drawer event".into()))
+}
+
+pub async fn find_by_id(pool: &SqlitePool, id: i64) -> RepoResult<Option<CashDrawerEvent>> {
+    let event = sqlx::query_as::<_, CashDrawerEvent>(&format!("{SELECT_COLUMNS} WHERE id = ?"))
+        .bind(id)
+        .fetch_optional(pool)
+        .await?;
+    Ok(event)
+}
+
+pub async fn find_by_shift(pool: &SqlitePool, shift_id: i64) -> RepoResult<Vec<CashDrawerEvent>> {
+    let events = sqlx::query_as::<_, CashDrawerEvent>(&format!(
+        "{SELECT_COLUMNS} WHERE shift_id = ? ORDER BY created_at"
+    ))
+    .bind(shift_id)
+    .fetch_all(pool)
+    .await?;
+    Ok(events)
+}
+
+pub async fn find_by_date_range(
+    pool: &SqlitePool,
+    start_millis: i64,
+    end_millis: i64,
+) -> RepoResult<Vec<CashDrawerEvent>> {
+    let events = sqlx::query_as::<_, CashDrawerEvent>(&format!(
+        "{SELECT_COLUMNS} WHERE created_at >= ? AND created_at < ? ORDER BY created_at"
+    ))
+    .bind(start_millis)
+    .bind(end_millis)
+    .fetch_all(pool)
+    .await?;
+    Ok(events)
+}
+
+pub async fn summarize_shift(pool: &SqlitePool, shift_id: i64) -> RepoResult<CashDrawerSummary> {
+    let summary = sqlx::query_as::<_, CashDrawerSummary>(&format!(
+        "SELECT {SUMMARY_COLUMNS} FROM cash_drawer_event WHERE shift_id = ?"
+    ))
+    .bind(shift_id)
+    .fetch_one(pool)
+    .await?;
+    Ok(summary)
+}
+
+pub async fn summarize_range(
+    pool: &SqlitePool,
+    start_millis: i64,
+    end_millis: i64,
+) -> RepoResult<CashDrawerSummary> {
+    let summary = sqlx::query_as::<_, CashDrawerSummary>(&format!(
+        "SELECT {SUMMARY_COLUMNS} FROM cash_drawer_event WHERE created_at >= ? AND created_at < ?"
+    ))
+    .bind(start_millis)
+    .bind(end_millis)
+    .fetch_one(pool)
+    .await?;
+    Ok(summary)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::db::repository::shift;
+    use crate::db::test_pool;
+    use shared::models::ShiftCreate;
+
+    fn movement(kind: CashDrawerEventKind, amount: f64) -> CashDrawerEventCreate {
+        CashDrawerEventCreate {
+            kind,
+            amount,
+            reason: Some("test".to_string()),
+        }
+    }
+
+    #[tokio::test]
+    async fn test_movements_adjust_expected_cash() {
+        let pool = test_pool().await;
+        let s = shift::create(
+            &pool,
+            ShiftCreate {
+                operator_id: 1,
+                operator_name: "admin".to_string(),
+                starting_cash: 100.0,
+                note: None,
+            },
+        )
+        .await
+        .unwrap();
+
+        record(
+            &pool,
+            movement(CashDrawerEventKind::NoSale, 5.0),
+            1,
+            "admin",
+        )
+        .await
+        .unwrap();
+        record(
+            &pool,
+            movement(CashDrawerEventKind::PaidIn, 20.0),
+            1,
+            "admin",
+        )
+        .await
+        .unwrap();
+        let out = record(
+            &pool,
+            movement(CashDrawerEventKind::PaidOut, 7.5),
+            1,
+            "admin",
+        )
+        .await
+        .unwrap();
+        assert_eq!(out.shift_id, Some(s.id));
+        assert!(
+            record(
+                &pool,
+                movement(CashDrawerEventKind::PaidOut, 0.0),
+                1,
+                "admin"
+            )
+            .await
+            .is_err()
+        );
+
+        let s = shift::find_by_id(&pool, s.id).await.unwrap().unwrap();
+        assert_eq!(s.expected_cash, 112.5);
+
+        let summary = summarize_shift(&pool, s.id).await.unwrap();
+        assert_eq!(
+            summary,
+            CashDrawerSummary {
+                sale_opens: 0,
+                no_sale_opens: 1,
+                paid_in_count: 1,
+                paid_in_amount: 20.0,
+                paid_out_count: 1,
+                paid_out_amount: 7.5,
+            }
+        );
+        // 同一毫秒内写入的事件 created_at 相同，按类型定位而非下标
+        let events = find_by_shift(&pool, s.id).await.unwrap();
+        let no_sale = events
+            .iter()
+            .find(|e| e.kind == CashDrawerEventKind::NoSale)
+            .unwrap();
+        assert_eq!(no_sale.amount, 0.0);
+    }
+}
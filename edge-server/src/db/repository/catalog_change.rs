@@ -0,0 +1,57 @@
+//! Catalog Change Repository
+
+use super::{RepoError, RepoResult};
+use shared::models::{CatalogChange, CatalogChangeCreate};
+use sqlx::SqlitePool;
+
+const SELECT_COLUMNS: &str = "SELECT id, entity_type, entity_id, action, COALESCE(before_snapshot, 'null') as before_snapshot, COALESCE(after_snapshot, 'null') as after_snapshot, operator_id, operator_name, created_at FROM catalog_change";
+
+pub async fn create(pool: &SqlitePool, data: CatalogChangeCreate) -> RepoResult<CatalogChange> {
+    let id = shared::util::snowflake_id();
+    let now = shared::util::now_millis();
+    let before_json = data.before_snapshot.as_ref().map(|v| v.to_string());
+    let after_json = data.after_snapshot.as_ref().map(|v| v.to_string());
+
+    sqlx::query(
+        "INSERT INTO catalog_change (id, entity_type, entity_id, action, before_snapshot, after_snapshot, operator_id, operator_name, created_at) VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8, ?9)",
+    )
+    .bind(id)
+    .bind(&data.entity_type)
+    .bind(data.entity_id)
+    .bind(data.action)
+    .bind(&before_json)
+    .bind(&after_json)
+    .bind(data.operator_id)
+    .bind(&data.operator_name)
+    .bind(now)
+    .execute(pool)
+    .await?;
+
+    find_by_id(pool, id)
+        .await?
+        .ok_or_else(|| RepoError::Database("Failed to create catalog_change".into()))
+}
+
+pub async fn find_by_id(pool: &SqlitePool, id: i64) -> RepoResult<Option<CatalogChange>> {
+    let change = sqlx::query_as::<_, CatalogChange>(&format!("{SELECT_COLUMNS} WHERE id = ?"))
+        .bind(id)
+        .fetch_optional(pool)
+        .await?;
+    Ok(change)
+}
+
+/// History of one entity, newest first
+pub async fn find_by_entity(
+    pool: &SqlitePool,
+    entity_type: &str,
+    entity_id: i64,
+) -> RepoResult<Vec<CatalogChange>> {
+    let changes = sqlx::query_as::<_, CatalogChange>(&format!(
+        "{SELECT_COLUMNS} WHERE entity_type = ? AND entity_id = ? ORDER BY created_at DESC, id DESC"
+    ))
+    .bind(entity_type)
+    .bind(entity_id)
+    .fetch_all(pool)
+    .await?;
+    Ok(changes)
+}
@@ -0,0 +1,210 @@
+//! Coupon Repository (优惠码)
+//!
+//! 优惠码统一存大写。核销记录 (coupon_redemption) 与 used_count 在同一事务内更新，
+//! (coupon_id, order_id) 唯一，订单完成结算可安全重试。
+
+use super::{RepoError, RepoResult};
+use shared::models::{
+    Coupon, CouponCreate, CouponGenerate, CouponUpdate, generate_coupon_code, normalize_coupon_code,
+};
+use sqlx::{Sqlite, SqlitePool, Transaction};
+
+/// 生成码碰撞重试次数
+const CODE_ATTEMPTS: usize = 5;
+
+const COUPON_SELECT: &str = "SELECT id, code, name, product_scope, target_id, adjustment_type, adjustment_value, max_uses, used_count, valid_from, valid_until, is_active, created_at, updated_at FROM coupon";
+
+pub async fn find_all(pool: &SqlitePool) -> RepoResult<Vec<Coupon>> {
+    let coupons = sqlx::query_as::<_, Coupon>(&format!("{COUPON_SELECT} ORDER BY created_at DESC"))
+        .fetch_all(pool)
+        .await?;
+    Ok(coupons)
+}
+
+pub async fn find_by_id(pool: &SqlitePool, id: i64) -> RepoResult<Option<Coupon>> {
+    let coupon = sqlx::query_as::<_, Coupon>(&format!("{COUPON_SELECT} WHERE id = ?"))
+        .bind(id)
+        .fetch_optional(pool)
+        .await?;
+    Ok(coupon)
+}
+
+/// Lookup by code (case-insensitive, customers read it off a voucher)
+pub async fn find_by_code(pool: &SqlitePool, code: &str) -> RepoResult<Option<Coupon>> {
+    let coupon = sqlx::query_as::<_, Coupon>(&format!("{COUPON_SELECT} WHERE code = ?"))
+        .bind(normalize_coupon_code(code))
+        .fetch_optional(pool)
+        .await?;
+    Ok(coupon)
+}
+
+async fn insert(
+    tx: &mut Transaction<'_, Sqlite>,
+    id: i64,
+    code: &str,
+    data: &CouponCreate,
+    now: i64,
+) -> RepoResult<()> {
+    sqlx::query(
+        "INSERT INTO coupon (id, code, name, product_scope, target_id, adjustment_type, adjustment_value, max_uses, used_count, valid_from, valid_until, is_active, created_at, updated_at) VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8, 0, ?9, ?10, 1, ?11, ?11)",
+    )
+    .bind(id)
+    .bind(code)
+    .bind(&data.name)
+    .bind(&data.product_scope)
+    .bind(data.target_id)
+    .bind(&data.adjustment_type)
+    .bind(data.adjustment_value)
+    .bind(data.max_uses)
+    .bind(data.valid_from)
+    .bind(data.valid_until)
+    .bind(now)
+    .execute(&mut **tx)
+    .await?;
+    Ok(())
+}
+
+async fn code_exists(tx: &mut Transaction<'_, Sqlite>, code: &str) -> RepoResult<bool> {
+    let exists: bool = sqlx::query_scalar("SELECT EXISTS(SELECT 1 FROM coupon WHERE code = ?)")
+        .bind(code)
+        .fetch_one(&mut **tx)
+        .await?;
+    Ok(exists)
+}
+
+/// Unused generated code (retries on collision)
+async fn unused_code(tx: &mut Transaction<'_, Sqlite>, prefix: Option<&str>) -> RepoResult<String> {
+    for _ in 0..CODE_ATTEMPTS {
+        let code = generate_coupon_code(prefix);
+        if !code_exists(tx, &code).await? {
+            return Ok(code);
+        }
+    }
+    Err(RepoError::Database(
+        "Failed to generate a unique coupon code".into(),
+    ))
+}
+
+pub async fn create(
+    pool: &SqlitePool,
+    assigned_id: Option<i64>,
+    data: CouponCreate,
+) -> RepoResult<Coupon> {
+    let now = shared::util::now_millis();
+    let id = assigned_id.unwrap_or_else(shared::util::snowflake_id);
+    let mut tx = pool.begin().await?;
+    let code = match data.code.as_deref() {
+        Some(code) => normalize_coupon_code(code),
+        None => unused_code(&mut tx, None).await?,
+    };
+    insert(&mut tx, id, &code, &data, now).await?;
+    tx.commit().await?;
+
+    find_by_id(pool, id)
+        .await?
+        .ok_or_else(|| RepoError::Database("Failed to create coupon".into()))
+}
+
+/// Batch-generate single-use coupons in one transaction
+pub async fn generate(pool: &SqlitePool, data: CouponGenerate) -> RepoResult<Vec<Coupon>> {
+    let now = shared::util::now_millis();
+    let template = CouponCreate {
+        code: None,
+        name: data.name,
+        product_scope: data.product_scope,
+        target_id: data.target_id,
+        adjustment_type: data.adjustment_type,
+        adjustment_value: data.adjustment_value,
+        max_uses: Some(1),
+        valid_from: data.valid_from,
+        valid_until: data.valid_until,
+    };
+    let mut ids = Vec::with_capacity(data.count as usize);
+    let mut tx = pool.begin().await?;
+    for _ in 0..data.count {
+        let id = shared::util::snowflake_id();
+        let code = unused_code(&mut tx, data.prefix.as_deref()).await?;
+        insert(&mut tx, id, &code, &template, now).await?;
+        ids.push(id);
+    }
+    tx.commit().await?;
+
+    let mut coupons = Vec::with_capacity(ids.len());
+    for id in ids {
+        if let Some(coupon) = find_by_id(pool, id).await? {
+            coupons.push(coupon);
+        }
+    }
+    Ok(coupons)
+}
+
+pub async fn update(pool: &SqlitePool, id: i64, data: CouponUpdate) -> RepoResult<Coupon> {
+    let now = shared::util::now_millis();
+    let rows = sqlx::query(
+        "UPDATE coupon SET name = COALESCE(?1, name), product_scope = COALESCE(?2, product_scope), target_id = COALESCE(?3, target_id), adjustment_type = COALESCE(?4, adjustment_type), adjustment_value = COALESCE(?5, adjustment_value), max_uses = COALESCE(?6, max_uses), valid_from = COALESCE(?7, valid_from), valid_until = COALESCE(?8, valid_until), is_active = COALESCE(?9, is_active), updated_at = ?10 WHERE id = ?11",
+    )
+    .bind(&data.name)
+    .bind(&data.product_scope)
+    .bind(data.target_id)
+    .bind(&data.adjustment_type)
+    .bind(data.adjustment_value)
+    .bind(data.max_uses)
+    .bind(data.valid_from)
+    .bind(data.valid_until)
+    .bind(data.is_active)
+    .bind(now)
+    .bind(id)
+    .execute(pool)
+    .await?;
+
+    if rows.rows_affected() == 0 {
+        return Err(RepoError::NotFound(format!("Coupon {id} not found")));
+    }
+    find_by_id(pool, id)
+        .await?
+        .ok_or_else(|| RepoError::NotFound(format!("Coupon {id} not found")))
+}
+
+pub async fn delete(pool: &SqlitePool, id: i64) -> RepoResult<bool> {
+    let rows = sqlx::query("DELETE FROM coupon WHERE id = ?")
+        .bind(id)
+        .execute(pool)
+        .await?;
+    Ok(rows.rows_affected() > 0)
+}
+
+/// 订单完成记录核销并累加 used_count（幂等：已记录时返回 None）
+///
+/// 不再校验 max_uses：折扣已随订单结账，并发下超用以核销记录为准。
+pub async fn redeem_for_order(
+    pool: &SqlitePool,
+    coupon_id: i64,
+    order_id: i64,
+    amount: f64,
+) -> RepoResult<Option<Coupon>> {
+    let now = shared::util::now_millis();
+    let mut tx = pool.begin().await?;
+    let inserted = sqlx::query(
+        "INSERT OR IGNORE INTO coupon_redemption (id, coupon_id, order_id, amount, created_at) VALUES (?1, ?2, ?3, ?4, ?5)",
+    )
+    .bind(shared::util::snowflake_id())
+    .bind(coupon_id)
+    .bind(order_id)
+    .bind(amount)
+    .bind(now)
+    .execute(&mut *tx)
+    .await?;
+    if inserted.rows_affected() == 0 {
+        return Ok(None);
+    }
+    let coupon = sqlx::query_as::<_, Coupon>(
+        "UPDATE coupon SET used_count = used_count + 1, updated_at = ?1 WHERE id = ?2 RETURNING id, code, name, product_scope, target_id, adjustment_type, adjustment_value, max_uses, used_count, valid_from, valid_until, is_active, created_at, updated_at",
+    )
+    .bind(now)
+    .bind(coupon_id)
+    .fetch_optional(&mut *tx)
+    .await?
+    .ok_or_else(|| RepoError::NotFound(format!("Coupon {coupon_id} not found")))?;
+    tx.commit().await?;
+    Ok(Some(coupon))
+}
@@ -160,6 +160,31 @@ pub async fn get_refunded_items(
         .collect())
 }
 
+/// Refunded quantity and amounts for one original order line
+#[derive(Debug, Clone, sqlx::FromRow)]
+pub struct RefundedLine {
+    pub original_instance_id: String,
+    pub quantity: i64,
+    pub line_credit: f64,
+    pub tax_credit: f64,
+}
+
+/// Get per-item refunded quantities and credited amounts for an order.
+pub async fn get_refunded_lines(pool: &SqlitePool, order_pk: i64) -> RepoResult<Vec<RefundedLine>> {
+    let rows = sqlx::query_as::<_, RefundedLine>(
+        "SELECT ci.original_instance_id, SUM(ci.quantity) AS quantity, \
+         SUM(ci.line_credit) AS line_credit, SUM(ci.tax_credit) AS tax_credit \
+         FROM credit_note_item ci \
+         JOIN credit_note cn ON cn.id = ci.credit_note_id \
+         WHERE cn.original_order_pk = ? \
+         GROUP BY ci.original_instance_id",
+    )
+    .bind(order_pk)
+    .fetch_all(pool)
+    .await?;
+    Ok(rows)
+}
+
 /// Mark a credit note as synced to cloud.
 pub async fn mark_synced(pool: &SqlitePool, id: i64) -> RepoResult<()> {
     sqlx::query("UPDATE credit_note SET cloud_synced = 1 WHERE id = ?")
@@ -0,0 +1,159 @@
+//! Customer Display Repository
+
+use super::{RepoError, RepoResult};
+use shared::models::{
+    CustomerDisplay, CustomerDisplayCreate, CustomerDisplayUpdate,
+    DEFAULT_CUSTOMER_DISPLAY_COLUMNS, DEFAULT_CUSTOMER_DISPLAY_PORT,
+};
+use sqlx::SqlitePool;
+
+const SELECT_COLUMNS: &str = "SELECT id, identity, name, host, port, columns, idle_text, is_active, created_at, updated_at FROM customer_display";
+
+pub async fn find_all(pool: &SqlitePool) -> RepoResult<Vec<CustomerDisplay>> {
+    let displays =
+        sqlx::query_as::<_, CustomerDisplay>(&format!("{SELECT_COLUMNS} ORDER BY name, id"))
+            .fetch_all(pool)
+            .await?;
+    Ok(displays)
+}
+
+pub async fn find_by_id(pool: &SqlitePool, id: i64) -> RepoResult<Option<CustomerDisplay>> {
+    let display = sqlx::query_as::<_, CustomerDisplay>(&format!("{SELECT_COLUMNS} WHERE id = ?"))
+        .bind(id)
+        .fetch_optional(pool)
+        .await?;
+    Ok(display)
+}
+
+/// Active display attached to a terminal
+pub async fn find_active_by_identity(
+    pool: &SqlitePool,
+    identity: &str,
+) -> RepoResult<Option<CustomerDisplay>> {
+    let display = sqlx::query_as::<_, CustomerDisplay>(&format!(
+        "{SELECT_COLUMNS} WHERE identity = ? AND is_active = 1"
+    ))
+    .bind(identity)
+    .fetch_optional(pool)
+    .await?;
+    Ok(display)
+}
+
+pub async fn create(pool: &SqlitePool, data: CustomerDisplayCreate) -> RepoResult<CustomerDisplay> {
+    let id = shared::util::snowflake_id();
+    let now = shared::util::now_millis();
+    sqlx::query(
+        "INSERT INTO customer_display (id, identity, name, host, port, columns, idle_text, is_active, created_at, updated_at) VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7, 1, ?8, ?8)",
+    )
+    .bind(id)
+    .bind(data.identity.trim())
+    .bind(&data.name)
+    .bind(data.host.trim())
+    .bind(data.port.unwrap_or(i32::from(DEFAULT_CUSTOMER_DISPLAY_PORT)))
+    .bind(data.columns.unwrap_or(DEFAULT_CUSTOMER_DISPLAY_COLUMNS))
+    .bind(&data.idle_text)
+    .bind(now)
+    .execute(pool)
+    .await?;
+    find_by_id(pool, id)
+        .await?
+        .ok_or_else(|| RepoError::Database("Failed to create customer display".into()))
+}
+
+pub async fn update(
+    pool: &SqlitePool,
+    id: i64,
+    data: CustomerDisplayUpdate,
+) -> RepoResult<CustomerDisplay> {
+    let now = shared::util::now_millis();
+    let rows = sqlx::query(
+        "UPDATE customer_display SET identity = COALESCE(?1, identity), name = COALESCE(?2, name), host = COALESCE(?3, host), port = COALESCE(?4, port), columns = COALESCE(?5, columns), idle_text = COALESCE(?6, idle_text), is_active = COALESCE(?7, is_active), updated_at = ?8 WHERE id = ?9",
+    )
+    .bind(data.identity.as_deref().map(str::trim))
+    .bind(&data.name)
+    .bind(data.host.as_deref().map(str::trim))
+    .bind(data.port)
+    .bind(data.columns)
+    .bind(&data.idle_text)
+    .bind(data.is_active)
+    .bind(now)
+    .bind(id)
+    .execute(pool)
+    .await?;
+    if rows.rows_affected() == 0 {
+        return Err(RepoError::NotFound(format!(
+            "Customer display {id} not found"
+        )));
+    }
+    find_by_id(pool, id)
+        .await?
+        .ok_or_else(|| RepoError::NotFound(format!("Customer display {id} not found")))
+}
+
+pub async fn delete(pool: &SqlitePool, id: i64) -> RepoResult<bool> {
+    let rows = sqlx::query("DELETE FROM customer_display WHERE id = ?")
+        .bind(id)
+        .execute(pool)
+        .await?;
+    Ok(rows.rows_affected() > 0)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::db::test_pool;
+
+    fn create_data(identity: &str) -> CustomerDisplayCreate {
+        CustomerDisplayCreate {
+            identity: identity.to_string(),
+            name: "Caja 1".to_string(),
+            host: " 192.168.1.120 ".to_string(),
+            port: None,
+            columns: None,
+            idle_text: Some("Bienvenido".to_string()),
+        }
+    }
+
+    #[tokio::test]
+    async fn test_one_display_per_terminal() {
+        let pool = test_pool().await;
+        let display = create(&pool, create_data("pos-01")).await.unwrap();
+        assert_eq!(display.host, "192.168.1.120");
+        assert_eq!(display.port, 9100);
+        assert_eq!(display.columns, 20);
+
+        assert!(matches!(
+            create(&pool, create_data("pos-01")).await,
+            Err(RepoError::Duplicate(_))
+        ));
+
+        let found = find_active_by_identity(&pool, "pos-01").await.unwrap();
+        assert_eq!(found.map(|d| d.id), Some(display.id));
+
+        update(
+            &pool,
+            display.id,
+            CustomerDisplayUpdate {
+                identity: None,
+                name: None,
+                host: None,
+                port: Some(4001),
+                columns: None,
+                idle_text: None,
+                is_active: Some(false),
+            },
+        )
+        .await
+        .unwrap();
+        assert!(
+            find_active_by_identity(&pool, "pos-01")
+                .await
+                .unwrap()
+                .is_none()
+        );
+        assert_eq!(find_all(&pool).await.unwrap()[0].port, 4001);
+
+        assert!(delete(&pool, display.id).await.unwrap());
+        assert!(find_all(&pool).await.unwrap().is_empty());
+    }
+}
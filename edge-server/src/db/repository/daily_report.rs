@@ -1,7 +1,9 @@
 //! Daily Report Repository
 
-use super::{RepoError, RepoResult};
-use shared::models::{DailyReport, DailyReportGenerate, ShiftBreakdown};
+use super::{RepoError, RepoResult, cash_drawer};
+use shared::models::{
+    CashDrawerSummary, DailyReport, DailyReportGenerate, ShiftBreak, ShiftBreakdown, break_totals,
+};
 use sqlx::SqlitePool;
 
 type ShiftAggRow = (Option<i64>, i64, i64, i64, f64, f64, f64, f64, f64, f64);
@@ -18,7 +20,7 @@ type ShiftMetaRow = (
     bool,
 );
 
-const SELECT_COLUMNS: &str = "SELECT id, business_date, net_revenue, total_orders, refund_amount, refund_count, auto_generated, generated_at, generated_by_id, generated_by_name, note FROM daily_report";
+const SELECT_COLUMNS: &str = "SELECT id, business_date, net_revenue, total_orders, refund_amount, refund_count, payment_surcharge_amount, carried_in_count, carried_in_amount, carried_out_count, carried_out_amount, no_sale_opens, paid_in_amount, paid_out_amount, auto_generated, generated_at, generated_by_id, generated_by_name, note FROM daily_report";
 
 pub async fn find_by_id(pool: &SqlitePool, id: i64) -> RepoResult<Option<DailyReport>> {
     let sql = format!("{SELECT_COLUMNS} WHERE id = ?");
@@ -76,12 +78,38 @@ pub async fn find_by_date_range(
     Ok(reports)
 }
 
+/// Orders open at `boundary`: archived ones opened before and closed at/after it,
+/// plus still-active orders (`open_orders` = (start_time, total)) opened before it
+async fn open_at(
+    pool: &SqlitePool,
+    boundary: i64,
+    open_orders: &[(i64, f64)],
+) -> RepoResult<(i64, f64)> {
+    let (count, amount): (i64, f64) = sqlx::query_as(
+        "SELECT COUNT(*), COALESCE(SUM(total_amount), 0.0) FROM archived_order WHERE start_time < ?1 AND end_time >= ?1",
+    )
+    .bind(boundary)
+    .fetch_one(pool)
+    .await?;
+
+    let (open_count, open_amount) = open_orders
+        .iter()
+        .filter(|(start_time, _)| *start_time < boundary)
+        .fold((0i64, 0.0f64), |(c, a), (_, total)| (c + 1, a + total));
+    Ok((count + open_count, amount + open_amount))
+}
+
 /// Generate daily report from archived_order data
+///
+/// `open_orders` are the currently active orders as (start_time, total), used for
+/// the carried-in / carried-out figures of orders spanning the day boundaries.
+#[allow(clippy::too_many_arguments)]
 pub async fn generate(
     pool: &SqlitePool,
     data: DailyReportGenerate,
     start_millis: i64,
     end_millis: i64,
+    open_orders: &[(i64, f64)],
     operator_id: Option<i64>,
     operator_name: Option<String>,
     auto_generated: bool,
@@ -97,7 +125,7 @@ pub async fn generate(
     .fetch_one(pool)
     .await?;
 
-    // 2. Count + sum credit_note refunds
+    // 2. Count + sum credit_note refunds (整单 / 部分退款都以退款凭证入账)
     let (refund_count, refund_amount): (i64, f64) = sqlx::query_as(
         "SELECT COUNT(*), COALESCE(SUM(total_credit), 0.0) FROM credit_note WHERE created_at >= ? AND created_at < ?",
     )
@@ -109,12 +137,28 @@ pub async fn generate(
     // 3. net_revenue = total_sales - refund_amount
     let net_revenue = total_sales - refund_amount;
 
+    // 4. Payment-method surcharges of completed orders (broken out, already in total_sales)
+    let payment_surcharge_amount: f64 = sqlx::query_scalar(
+        "SELECT COALESCE(SUM(p.surcharge), 0.0) FROM archived_order_payment p JOIN archived_order ao ON ao.id = p.order_pk WHERE ao.end_time >= ? AND ao.end_time < ? AND ao.status = 'COMPLETED' AND ao.is_voided = 0 AND p.cancelled = 0",
+    )
+    .bind(start_millis)
+    .bind(end_millis)
+    .fetch_one(pool)
+    .await?;
+
+    // 5. Carry-over: orders open across the day boundaries
+    let (carried_in_count, carried_in_amount) = open_at(pool, start_millis, open_orders).await?;
+    let (carried_out_count, carried_out_amount) = open_at(pool, end_millis, open_orders).await?;
+
+    // 6. Cash drawer activity (no-sale opens, paid-in / paid-out)
+    let drawer = cash_drawer::summarize_range(pool, start_millis, end_millis).await?;
+
     // Create report + shift breakdowns in a single transaction
     let mut tx = pool.begin().await?;
 
     let report_id = shared::util::snowflake_id();
     sqlx::query(
-        "INSERT INTO daily_report (id, business_date, net_revenue, total_orders, refund_amount, refund_count, auto_generated, generated_at, generated_by_id, generated_by_name, note) VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8, ?9, ?10, ?11)",
+        "INSERT INTO daily_report (id, business_date, net_revenue, total_orders, refund_amount, refund_count, payment_surcharge_amount, carried_in_count, carried_in_amount, carried_out_count, carried_out_amount, no_sale_opens, paid_in_amount, paid_out_amount, auto_generated, generated_at, generated_by_id, generated_by_name, note) VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8, ?9, ?10, ?11, ?12, ?13, ?14, ?15, ?16, ?17, ?18, ?19)",
     )
     .bind(report_id)
     .bind(&data.business_date)
@@ -122,6 +166,14 @@ pub async fn generate(
     .bind(total_orders)
     .bind(refund_amount)
     .bind(refund_count)
+    .bind(payment_surcharge_amount)
+    .bind(carried_in_count)
+    .bind(carried_in_amount)
+    .bind(carried_out_count)
+    .bind(carried_out_amount)
+    .bind(drawer.no_sale_opens)
+    .bind(drawer.paid_in_amount)
+    .bind(drawer.paid_out_amount)
     .bind(auto_generated)
     .bind(now)
     .bind(operator_id)
@@ -178,8 +230,26 @@ pub async fn generate(
                 abnormal,
             )) = shift_meta
             {
+                // 休息时长（劳动报表）
+                let breaks: Vec<ShiftBreak> = sqlx::query_as(
+                    "SELECT id, shift_id, break_type, start_time, end_time, note FROM shift_break WHERE shift_id = ?"
+                )
+                .bind(sid)
+                .fetch_all(&mut *tx)
+                .await?;
+                let (paid_break_ms, unpaid_break_ms) = break_totals(&breaks, end.unwrap_or(now));
+
+                // 钱箱活动（收班对账）
+                let shift_drawer: CashDrawerSummary = sqlx::query_as(&format!(
+                    "SELECT {} FROM cash_drawer_event WHERE shift_id = ?",
+                    cash_drawer::SUMMARY_COLUMNS
+                ))
+                .bind(sid)
+                .fetch_one(&mut *tx)
+                .await?;
+
                 sqlx::query(
-                    "INSERT INTO daily_report_shift_breakdown (id, report_id, shift_id, operator_id, operator_name, status, start_time, end_time, starting_cash, expected_cash, actual_cash, cash_variance, abnormal_close, total_orders, completed_orders, void_orders, total_sales, total_paid, void_amount, total_tax, total_discount, total_surcharge) VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8, ?9, ?10, ?11, ?12, ?13, ?14, ?15, ?16, ?17, ?18, ?19, ?20, ?21, ?22)"
+                    "INSERT INTO daily_report_shift_breakdown (id, report_id, shift_id, operator_id, operator_name, status, start_time, end_time, starting_cash, expected_cash, actual_cash, cash_variance, abnormal_close, total_orders, completed_orders, void_orders, total_sales, total_paid, void_amount, total_tax, total_discount, total_surcharge, paid_break_ms, unpaid_break_ms, no_sale_opens, paid_in_amount, paid_out_amount) VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8, ?9, ?10, ?11, ?12, ?13, ?14, ?15, ?16, ?17, ?18, ?19, ?20, ?21, ?22, ?23, ?24, ?25, ?26, ?27)"
                 )
                 .bind(sb_id).bind(report_id).bind(sid)
                 .bind(op_id).bind(&op_name).bind(&status)
@@ -189,6 +259,9 @@ pub async fn generate(
                 .bind(total).bind(completed).bind(voided)
                 .bind(sales).bind(paid).bind(void_amt)
                 .bind(tax).bind(discount).bind(surcharge)
+                .bind(paid_break_ms).bind(unpaid_break_ms)
+                .bind(shift_drawer.no_sale_opens)
+                .bind(shift_drawer.paid_in_amount).bind(shift_drawer.paid_out_amount)
                 .execute(&mut *tx)
                 .await?;
             }
@@ -224,7 +297,7 @@ async fn find_shift_breakdowns(
     report_id: i64,
 ) -> RepoResult<Vec<ShiftBreakdown>> {
     let breakdowns = sqlx::query_as::<_, ShiftBreakdown>(
-        "SELECT id, report_id, shift_id, operator_id, operator_name, status, start_time, end_time, starting_cash, expected_cash, actual_cash, cash_variance, abnormal_close, total_orders, completed_orders, void_orders, total_sales, total_paid, void_amount, total_tax, total_discount, total_surcharge FROM daily_report_shift_breakdown WHERE report_id = ? ORDER BY start_time ASC",
+        "SELECT id, report_id, shift_id, operator_id, operator_name, status, start_time, end_time, starting_cash, expected_cash, actual_cash, cash_variance, abnormal_close, total_orders, completed_orders, void_orders, total_sales, total_paid, void_amount, total_tax, total_discount, total_surcharge, paid_break_ms, unpaid_break_ms, no_sale_opens, paid_in_amount, paid_out_amount FROM daily_report_shift_breakdown WHERE report_id = ? ORDER BY start_time ASC",
     )
     .bind(report_id)
     .fetch_all(pool)
@@ -242,7 +315,7 @@ async fn batch_load_breakdowns(pool: &SqlitePool, reports: &mut [DailyReport]) -
 
     // Shift breakdowns
     let shift_sql = format!(
-        "SELECT id, report_id, shift_id, operator_id, operator_name, status, start_time, end_time, starting_cash, expected_cash, actual_cash, cash_variance, abnormal_close, total_orders, completed_orders, void_orders, total_sales, total_paid, void_amount, total_tax, total_discount, total_surcharge FROM daily_report_shift_breakdown WHERE report_id IN ({placeholders}) ORDER BY start_time ASC"
+        "SELECT id, report_id, shift_id, operator_id, operator_name, status, start_time, end_time, starting_cash, expected_cash, actual_cash, cash_variance, abnormal_close, total_orders, completed_orders, void_orders, total_sales, total_paid, void_amount, total_tax, total_discount, total_surcharge, paid_break_ms, unpaid_break_ms, no_sale_opens, paid_in_amount, paid_out_amount FROM daily_report_shift_breakdown WHERE report_id IN ({placeholders}) ORDER BY start_time ASC"
     );
     let mut shift_query = sqlx::query_as::<_, ShiftBreakdown>(&shift_sql);
     for id in &ids {
@@ -277,3 +350,51 @@ pub async fn cleanup_old_reports(pool: &SqlitePool, retention_days: i32) -> Repo
 
     Ok(result.rows_affected())
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::db::test_pool;
+
+    #[tokio::test]
+    async fn test_generate_counts_credit_notes_as_refunds() {
+        let pool = test_pool().await;
+        sqlx::query(
+            "INSERT INTO archived_order (id, receipt_number, status, total_amount, start_time, end_time, created_at) \
+             VALUES (1, 'R-1', 'COMPLETED', 30.0, 1000, 1500, 1500), (2, 'R-2', 'COMPLETED', 20.0, 1000, 1500, 1500)",
+        )
+        .execute(&pool)
+        .await
+        .unwrap();
+        // 整单退款 R-1 + 部分退款 R-2
+        sqlx::query(
+            "INSERT INTO credit_note (id, credit_note_number, original_order_pk, original_receipt, subtotal_credit, tax_credit, total_credit, refund_method, reason, operator_id, operator_name, created_at) \
+             VALUES (10, 'CN-1', 1, 'R-1', 27.27, 2.73, 30.0, 'CASH', 'CUSTOMER_REQUEST', 1, 'Ana', 1800), \
+                    (11, 'CN-2', 2, 'R-2', 4.55, 0.45, 5.0, 'CARD', 'DAMAGED', 1, 'Ana', 1900)",
+        )
+        .execute(&pool)
+        .await
+        .unwrap();
+
+        let report = generate(
+            &pool,
+            DailyReportGenerate {
+                business_date: "2024-01-01".to_string(),
+                note: None,
+            },
+            0,
+            2000,
+            &[],
+            None,
+            None,
+            false,
+        )
+        .await
+        .unwrap();
+
+        assert_eq!(report.total_orders, 2);
+        assert_eq!(report.refund_count, 2);
+        assert_eq!(report.refund_amount, 35.0);
+        assert_eq!(report.net_revenue, 15.0);
+    }
+}
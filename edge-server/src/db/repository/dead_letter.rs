@@ -0,0 +1,174 @@
+//! Dead Letter Repository (死信队列)
+
+use super::{RepoError, RepoResult};
+use shared::models::{DeadLetter, DeadLetterFailure, DeadLetterStatus};
+use sqlx::SqlitePool;
+
+const SELECT: &str = "SELECT id, event_type, action, request_id, source, payload, failure, error, status, created_at, resolved_at FROM dead_letter";
+
+/// New dead letter (built by the message handler)
+#[derive(Debug, Clone)]
+pub struct DeadLetterCreate {
+    pub event_type: String,
+    pub action: Option<String>,
+    pub request_id: String,
+    pub source: Option<String>,
+    pub payload: Option<serde_json::Value>,
+    pub failure: DeadLetterFailure,
+    pub error: String,
+}
+
+pub async fn create(pool: &SqlitePool, data: DeadLetterCreate) -> RepoResult<DeadLetter> {
+    let id = shared::util::snowflake_id();
+    let now = shared::util::now_millis();
+    let payload_json = data
+        .payload
+        .as_ref()
+        .map(|p| serde_json::to_string(p).unwrap_or_else(|_| "null".to_string()));
+
+    sqlx::query(
+        "INSERT INTO dead_letter (id, event_type, action, request_id, source, payload, failure, error, status, created_at) VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8, 'PENDING', ?9)",
+    )
+    .bind(id)
+    .bind(&data.event_type)
+    .bind(&data.action)
+    .bind(&data.request_id)
+    .bind(&data.source)
+    .bind(payload_json)
+    .bind(data.failure)
+    .bind(&data.error)
+    .bind(now)
+    .execute(pool)
+    .await?;
+
+    find_by_id(pool, id)
+        .await?
+        .ok_or_else(|| RepoError::Database("Failed to create dead_letter".into()))
+}
+
+pub async fn find_by_id(pool: &SqlitePool, id: i64) -> RepoResult<Option<DeadLetter>> {
+    let letter = sqlx::query_as::<_, DeadLetter>(&format!("{SELECT} WHERE id = ?"))
+        .bind(id)
+        .fetch_optional(pool)
+        .await?;
+    Ok(letter)
+}
+
+/// Newest first, optionally filtered by status
+pub async fn list(
+    pool: &SqlitePool,
+    status: Option<DeadLetterStatus>,
+    limit: i64,
+) -> RepoResult<Vec<DeadLetter>> {
+    let letters = match status {
+        Some(status) => {
+            sqlx::query_as::<_, DeadLetter>(&format!(
+                "{SELECT} WHERE status = ? ORDER BY created_at DESC, id DESC LIMIT ?"
+            ))
+            .bind(status)
+            .bind(limit)
+            .fetch_all(pool)
+            .await?
+        }
+        None => {
+            sqlx::query_as::<_, DeadLetter>(&format!(
+                "{SELECT} ORDER BY created_at DESC, id DESC LIMIT ?"
+            ))
+            .bind(limit)
+            .fetch_all(pool)
+            .await?
+        }
+    };
+    Ok(letters)
+}
+
+pub async fn count_pending(pool: &SqlitePool) -> RepoResult<i64> {
+    let count: i64 =
+        sqlx::query_scalar("SELECT COUNT(*) FROM dead_letter WHERE status = 'PENDING'")
+            .fetch_one(pool)
+            .await?;
+    Ok(count)
+}
+
+/// PENDING → `status`; anything else is already handled
+async fn resolve(pool: &SqlitePool, id: i64, status: DeadLetterStatus) -> RepoResult<DeadLetter> {
+    let now = shared::util::now_millis();
+    let rows = sqlx::query(
+        "UPDATE dead_letter SET status = ?1, resolved_at = ?2 WHERE id = ?3 AND status = 'PENDING'",
+    )
+    .bind(status)
+    .bind(now)
+    .bind(id)
+    .execute(pool)
+    .await?;
+
+    let letter = find_by_id(pool, id)
+        .await?
+        .ok_or_else(|| RepoError::NotFound(format!("dead_letter {id} not found")))?;
+    if rows.rows_affected() == 0 {
+        return Err(RepoError::Validation(format!(
+            "dead_letter {id} is already {:?}",
+            letter.status
+        )));
+    }
+    Ok(letter)
+}
+
+pub async fn mark_redelivered(pool: &SqlitePool, id: i64) -> RepoResult<DeadLetter> {
+    resolve(pool, id, DeadLetterStatus::Redelivered).await
+}
+
+pub async fn mark_discarded(pool: &SqlitePool, id: i64) -> RepoResult<DeadLetter> {
+    resolve(pool, id, DeadLetterStatus::Discarded).await
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::db::test_pool;
+
+    fn sample(failure: DeadLetterFailure) -> DeadLetterCreate {
+        DeadLetterCreate {
+            event_type: "request_command".to_string(),
+            action: Some("order.add_items".to_string()),
+            request_id: uuid::Uuid::new_v4().to_string(),
+            source: Some("pos-1".to_string()),
+            payload: Some(serde_json::json!({ "action": "order.add_items", "params": {} })),
+            failure,
+            error: "boom".to_string(),
+        }
+    }
+
+    #[tokio::test]
+    async fn test_create_list_and_resolve_once() {
+        let pool = test_pool().await;
+        let first = create(&pool, sample(DeadLetterFailure::Panic))
+            .await
+            .unwrap();
+        let second = create(&pool, sample(DeadLetterFailure::Error))
+            .await
+            .unwrap();
+        assert_eq!(first.status, DeadLetterStatus::Pending);
+        assert_eq!(first.payload, sample(DeadLetterFailure::Panic).payload);
+        assert_eq!(count_pending(&pool).await.unwrap(), 2);
+
+        let redelivered = mark_redelivered(&pool, first.id).await.unwrap();
+        assert_eq!(redelivered.status, DeadLetterStatus::Redelivered);
+        assert!(redelivered.resolved_at.is_some());
+        assert!(matches!(
+            mark_discarded(&pool, first.id).await,
+            Err(RepoError::Validation(_))
+        ));
+        assert!(matches!(
+            mark_discarded(&pool, -1).await,
+            Err(RepoError::NotFound(_))
+        ));
+
+        let pending = list(&pool, Some(DeadLetterStatus::Pending), 50)
+            .await
+            .unwrap();
+        assert_eq!(pending.len(), 1);
+        assert_eq!(pending[0].id, second.id);
+        assert_eq!(list(&pool, None, 50).await.unwrap().len(), 2);
+    }
+}
@@ -0,0 +1,266 @@
+//! Delivery Channel Rule Repository (外卖渠道起送价 / 配送费)
+
+use super::{RepoError, RepoResult};
+use shared::models::{DeliveryFeeTier, DeliveryRule, DeliveryRuleCreate, DeliveryRuleUpdate};
+use sqlx::SqlitePool;
+
+const SELECT_COLUMNS: &str = "SELECT id, channel, zone, min_order, fee_tiers, is_active, created_at, updated_at FROM delivery_rule";
+
+pub async fn find_all(pool: &SqlitePool) -> RepoResult<Vec<DeliveryRule>> {
+    let rules = sqlx::query_as::<_, DeliveryRule>(&format!(
+        "{SELECT_COLUMNS} ORDER BY channel, zone IS NOT NULL, zone"
+    ))
+    .fetch_all(pool)
+    .await?;
+    Ok(rules)
+}
+
+pub async fn find_by_id(pool: &SqlitePool, id: i64) -> RepoResult<Option<DeliveryRule>> {
+    let rule = sqlx::query_as::<_, DeliveryRule>(&format!("{SELECT_COLUMNS} WHERE id = ?"))
+        .bind(id)
+        .fetch_optional(pool)
+        .await?;
+    Ok(rule)
+}
+
+/// Active rule for a channel/zone: the zone rule first, then the channel default
+pub async fn find_applicable(
+    pool: &SqlitePool,
+    channel: &str,
+    zone: Option<&str>,
+) -> RepoResult<Option<DeliveryRule>> {
+    let rule = sqlx::query_as::<_, DeliveryRule>(&format!(
+        "{SELECT_COLUMNS} WHERE is_active = 1 AND channel = ?1 COLLATE NOCASE \
+         AND (zone IS NULL OR zone = ?2 COLLATE NOCASE) \
+         ORDER BY zone IS NULL LIMIT 1"
+    ))
+    .bind(channel.trim())
+    .bind(zone.map(str::trim))
+    .fetch_optional(pool)
+    .await?;
+    Ok(rule)
+}
+
+fn validate_min_order(min_order: Option<f64>) -> RepoResult<()> {
+    if let Some(min) = min_order
+        && (!min.is_finite() || min < 0.0)
+    {
+        return Err(RepoError::Validation(
+            "min_order must be a non-negative number".into(),
+        ));
+    }
+    Ok(())
+}
+
+/// Validate and sort fee tiers (distinct non-negative thresholds and fees)
+fn normalize_tiers(tiers: &[DeliveryFeeTier]) -> RepoResult<Vec<DeliveryFeeTier>> {
+    let mut tiers = tiers.to_vec();
+    if tiers.iter().any(|t| {
+        !t.min_subtotal.is_finite() || t.min_subtotal < 0.0 || !t.fee.is_finite() || t.fee < 0.0
+    }) {
+        return Err(RepoError::Validation(
+            "Fee tiers must have non-negative min_subtotal and fee".into(),
+        ));
+    }
+    tiers.sort_by(|a, b| a.min_subtotal.total_cmp(&b.min_subtotal));
+    if tiers
+        .windows(2)
+        .any(|w| w[0].min_subtotal == w[1].min_subtotal)
+    {
+        return Err(RepoError::Validation(
+            "Fee tiers must have distinct min_subtotal".into(),
+        ));
+    }
+    Ok(tiers)
+}
+
+fn tiers_json(tiers: &[DeliveryFeeTier]) -> RepoResult<String> {
+    serde_json::to_string(tiers).map_err(|e| RepoError::Database(e.to_string()))
+}
+
+pub async fn create(pool: &SqlitePool, data: &DeliveryRuleCreate) -> RepoResult<DeliveryRule> {
+    let channel = data.channel.trim();
+    if channel.is_empty() {
+        return Err(RepoError::Validation("channel is required".into()));
+    }
+    let zone = data
+        .zone
+        .as_deref()
+        .map(str::trim)
+        .filter(|z| !z.is_empty());
+    validate_min_order(data.min_order)?;
+    let tiers = normalize_tiers(&data.fee_tiers)?;
+
+    let exists: Option<i64> = sqlx::query_scalar(
+        "SELECT id FROM delivery_rule WHERE channel = ?1 COLLATE NOCASE AND COALESCE(zone, '') = COALESCE(?2, '') COLLATE NOCASE",
+    )
+    .bind(channel)
+    .bind(zone)
+    .fetch_optional(pool)
+    .await?;
+    if exists.is_some() {
+        return Err(RepoError::Duplicate(format!(
+            "A delivery rule for {channel} / {} already exists",
+            zone.unwrap_or("*")
+        )));
+    }
+
+    let id = shared::util::snowflake_id();
+    let now = shared::util::now_millis();
+    sqlx::query(
+        "INSERT INTO delivery_rule (id, channel, zone, min_order, fee_tiers, is_active, created_at, updated_at) VALUES (?, ?, ?, ?, ?, 1, ?, ?)",
+    )
+    .bind(id)
+    .bind(channel)
+    .bind(zone)
+    .bind(data.min_order)
+    .bind(tiers_json(&tiers)?)
+    .bind(now)
+    .bind(now)
+    .execute(pool)
+    .await?;
+
+    find_by_id(pool, id)
+        .await?
+        .ok_or_else(|| RepoError::Database("Failed to create delivery rule".into()))
+}
+
+pub async fn update(
+    pool: &SqlitePool,
+    id: i64,
+    data: &DeliveryRuleUpdate,
+) -> RepoResult<DeliveryRule> {
+    let existing = find_by_id(pool, id)
+        .await?
+        .ok_or_else(|| RepoError::NotFound(format!("Delivery rule {id} not found")))?;
+    validate_min_order(data.min_order)?;
+    let min_order = if data.clear_min_order {
+        None
+    } else {
+        data.min_order.or(existing.min_order)
+    };
+    let tiers = match &data.fee_tiers {
+        Some(tiers) => normalize_tiers(tiers)?,
+        None => existing.fee_tiers,
+    };
+
+    sqlx::query(
+        "UPDATE delivery_rule SET min_order = ?1, fee_tiers = ?2, is_active = COALESCE(?3, is_active), updated_at = ?4 WHERE id = ?5",
+    )
+    .bind(min_order)
+    .bind(tiers_json(&tiers)?)
+    .bind(data.is_active)
+    .bind(shared::util::now_millis())
+    .bind(id)
+    .execute(pool)
+    .await?;
+
+    find_by_id(pool, id)
+        .await?
+        .ok_or_else(|| RepoError::NotFound(format!("Delivery rule {id} not found")))
+}
+
+pub async fn delete(pool: &SqlitePool, id: i64) -> RepoResult<()> {
+    let rows = sqlx::query("DELETE FROM delivery_rule WHERE id = ?")
+        .bind(id)
+        .execute(pool)
+        .await?;
+    if rows.rows_affected() == 0 {
+        return Err(RepoError::NotFound(format!("Delivery rule {id} not found")));
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::db::test_pool;
+
+    fn create_payload(zone: Option<&str>, min_order: f64) -> DeliveryRuleCreate {
+        DeliveryRuleCreate {
+            channel: "Glovo".to_string(),
+            zone: zone.map(str::to_string),
+            min_order: Some(min_order),
+            fee_tiers: vec![
+                DeliveryFeeTier {
+                    min_subtotal: 25.0,
+                    fee: 0.0,
+                },
+                DeliveryFeeTier {
+                    min_subtotal: 0.0,
+                    fee: 2.5,
+                },
+            ],
+        }
+    }
+
+    #[tokio::test]
+    async fn test_zone_rule_overrides_channel_default() {
+        let pool = test_pool().await;
+        let default = create(&pool, &create_payload(None, 10.0)).await.unwrap();
+        // 阶梯按起点排序存储
+        assert_eq!(default.fee_tiers[0].min_subtotal, 0.0);
+        let centro = create(&pool, &create_payload(Some("Centro"), 15.0))
+            .await
+            .unwrap();
+
+        let found = find_applicable(&pool, "glovo", Some("centro"))
+            .await
+            .unwrap()
+            .unwrap();
+        assert_eq!(found.id, centro.id);
+        let found = find_applicable(&pool, "GLOVO", Some("Norte"))
+            .await
+            .unwrap()
+            .unwrap();
+        assert_eq!(found.id, default.id);
+        assert!(
+            find_applicable(&pool, "ubereats", None)
+                .await
+                .unwrap()
+                .is_none()
+        );
+
+        // 同渠道同区域只能有一条
+        assert!(matches!(
+            create(&pool, &create_payload(Some("CENTRO"), 5.0)).await,
+            Err(RepoError::Duplicate(_))
+        ));
+
+        let updated = update(
+            &pool,
+            centro.id,
+            &DeliveryRuleUpdate {
+                min_order: None,
+                clear_min_order: true,
+                fee_tiers: None,
+                is_active: Some(false),
+            },
+        )
+        .await
+        .unwrap();
+        assert_eq!(updated.min_order, None);
+        assert_eq!(updated.fee_tiers.len(), 2);
+        let found = find_applicable(&pool, "glovo", Some("Centro"))
+            .await
+            .unwrap()
+            .unwrap();
+        assert_eq!(found.id, default.id);
+    }
+
+    #[tokio::test]
+    async fn test_rejects_invalid_tiers() {
+        let pool = test_pool().await;
+        let mut payload = create_payload(None, 10.0);
+        payload.fee_tiers[0].min_subtotal = 0.0;
+        assert!(matches!(
+            create(&pool, &payload).await,
+            Err(RepoError::Validation(_))
+        ));
+        payload.fee_tiers[0].min_subtotal = -1.0;
+        assert!(matches!(
+            create(&pool, &payload).await,
+            Err(RepoError::Validation(_))
+        ));
+    }
+}
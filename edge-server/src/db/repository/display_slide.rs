@@ -0,0 +1,137 @@
+//! Display Slide Repository
+
+use super::{RepoError, RepoResult};
+use shared::models::{
+    DEFAULT_SLIDE_DURATION_SECS, DisplaySlide, DisplaySlideCreate, DisplaySlideUpdate,
+    ImageRefEntityType,
+};
+use sqlx::SqlitePool;
+use std::collections::HashSet;
+
+const SELECT_COLUMNS: &str = "SELECT id, name, image, duration_secs, sort_order, COALESCE(active_days, 'null') AS active_days, active_start_time, active_end_time, valid_from, valid_until, is_active, created_at, updated_at FROM display_slide";
+
+pub async fn find_all(pool: &SqlitePool) -> RepoResult<Vec<DisplaySlide>> {
+    let slides =
+        sqlx::query_as::<_, DisplaySlide>(&format!("{SELECT_COLUMNS} ORDER BY sort_order, id"))
+            .fetch_all(pool)
+            .await?;
+    Ok(slides)
+}
+
+pub async fn find_active(pool: &SqlitePool) -> RepoResult<Vec<DisplaySlide>> {
+    let slides = sqlx::query_as::<_, DisplaySlide>(&format!(
+        "{SELECT_COLUMNS} WHERE is_active = 1 ORDER BY sort_order, id"
+    ))
+    .fetch_all(pool)
+    .await?;
+    Ok(slides)
+}
+
+pub async fn find_by_id(pool: &SqlitePool, id: i64) -> RepoResult<Option<DisplaySlide>> {
+    let slide = sqlx::query_as::<_, DisplaySlide>(&format!("{SELECT_COLUMNS} WHERE id = ?"))
+        .bind(id)
+        .fetch_optional(pool)
+        .await?;
+    Ok(slide)
+}
+
+pub async fn create(pool: &SqlitePool, data: DisplaySlideCreate) -> RepoResult<DisplaySlide> {
+    let id = shared::util::snowflake_id();
+    let now = shared::util::now_millis();
+    let active_days_json = data
+        .active_days
+        .as_ref()
+        .map(|v| serde_json::to_string(v).unwrap_or_else(|_| "[]".to_string()));
+    sqlx::query(
+        "INSERT INTO display_slide (id, name, image, duration_secs, sort_order, active_days, active_start_time, active_end_time, valid_from, valid_until, is_active, created_at, updated_at) VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8, ?9, ?10, 1, ?11, ?11)",
+    )
+    .bind(id)
+    .bind(&data.name)
+    .bind(&data.image)
+    .bind(data.duration_secs.unwrap_or(DEFAULT_SLIDE_DURATION_SECS))
+    .bind(data.sort_order.unwrap_or(0))
+    .bind(&active_days_json)
+    .bind(&data.active_start_time)
+    .bind(&data.active_end_time)
+    .bind(data.valid_from)
+    .bind(data.valid_until)
+    .bind(now)
+    .execute(pool)
+    .await?;
+
+    sync_image_ref(pool, id, &data.image).await;
+
+    find_by_id(pool, id)
+        .await?
+        .ok_or_else(|| RepoError::Database("Failed to create display slide".into()))
+}
+
+pub async fn update(
+    pool: &SqlitePool,
+    id: i64,
+    data: DisplaySlideUpdate,
+) -> RepoResult<DisplaySlide> {
+    let now = shared::util::now_millis();
+    let active_days_json = data
+        .active_days
+        .as_ref()
+        .map(|v| serde_json::to_string(v).unwrap_or_else(|_| "[]".to_string()));
+    let rows = sqlx::query(
+        "UPDATE display_slide SET name = COALESCE(?1, name), image = COALESCE(?2, image), duration_secs = COALESCE(?3, duration_secs), sort_order = COALESCE(?4, sort_order), active_days = COALESCE(?5, active_days), active_start_time = COALESCE(?6, active_start_time), active_end_time = COALESCE(?7, active_end_time), valid_from = COALESCE(?8, valid_from), valid_until = COALESCE(?9, valid_until), is_active = COALESCE(?10, is_active), updated_at = ?11 WHERE id = ?12",
+    )
+    .bind(&data.name)
+    .bind(&data.image)
+    .bind(data.duration_secs)
+    .bind(data.sort_order)
+    .bind(&active_days_json)
+    .bind(&data.active_start_time)
+    .bind(&data.active_end_time)
+    .bind(data.valid_from)
+    .bind(data.valid_until)
+    .bind(data.is_active)
+    .bind(now)
+    .bind(id)
+    .execute(pool)
+    .await?;
+    if rows.rows_affected() == 0 {
+        return Err(RepoError::NotFound(format!("Display slide {id} not found")));
+    }
+
+    if let Some(image) = &data.image {
+        sync_image_ref(pool, id, image).await;
+    }
+
+    find_by_id(pool, id)
+        .await?
+        .ok_or_else(|| RepoError::NotFound(format!("Display slide {id} not found")))
+}
+
+pub async fn delete(pool: &SqlitePool, id: i64) -> RepoResult<bool> {
+    if let Err(e) =
+        super::image_ref::delete_entity_refs(pool, ImageRefEntityType::DisplaySlide, id).await
+    {
+        tracing::warn!(
+            display_slide_id = id,
+            "Failed to delete display slide image refs: {e}"
+        );
+    }
+
+    let rows = sqlx::query("DELETE FROM display_slide WHERE id = ?")
+        .bind(id)
+        .execute(pool)
+        .await?;
+    Ok(rows.rows_affected() > 0)
+}
+
+/// Track the slide image in image_ref (non-critical)
+async fn sync_image_ref(pool: &SqlitePool, id: i64, image: &str) {
+    let hashes: HashSet<String> = std::iter::once(image.to_string()).collect();
+    if let Err(e) =
+        super::image_ref::sync_refs(pool, ImageRefEntityType::DisplaySlide, id, hashes).await
+    {
+        tracing::warn!(
+            display_slide_id = id,
+            "Failed to sync display slide image reference: {e}"
+        );
+    }
+}
@@ -0,0 +1,120 @@
+//! Durable Notification Repository (关键通知持久化 + 客户端投递游标)
+
+use super::{RepoError, RepoResult};
+use shared::message::NotificationPayload;
+use sqlx::SqlitePool;
+
+/// 通知保留时长，超过后插入新通知时清理
+const RETENTION_MS: i64 = 3 * 24 * 60 * 60 * 1000;
+/// 首次连接 (无游标) 的客户端只补发最近这段时间的通知
+const FIRST_CONNECT_WINDOW_MS: i64 = 24 * 60 * 60 * 1000;
+
+/// Store a notification, returns its durable id (also prunes expired entries)
+pub async fn insert(pool: &SqlitePool, payload: &NotificationPayload) -> RepoResult<i64> {
+    let now = shared::util::now_millis();
+    let json = serde_json::to_string(payload)
+        .map_err(|e| RepoError::Validation(format!("Invalid notification payload: {e}")))?;
+
+    sqlx::query("DELETE FROM durable_notification WHERE created_at < ?")
+        .bind(now - RETENTION_MS)
+        .execute(pool)
+        .await?;
+    let id = sqlx::query_scalar::<_, i64>(
+        "INSERT INTO durable_notification (payload, created_at) VALUES (?, ?) RETURNING id",
+    )
+    .bind(json)
+    .bind(now)
+    .fetch_one(pool)
+    .await?;
+    Ok(id)
+}
+
+/// Notifications not yet acknowledged by the client, oldest first (durable_id set)
+pub async fn pending_for(pool: &SqlitePool, client: &str) -> RepoResult<Vec<NotificationPayload>> {
+    let cursor =
+        sqlx::query_scalar::<_, i64>("SELECT last_id FROM notification_cursor WHERE client = ?")
+            .bind(client)
+            .fetch_optional(pool)
+            .await?;
+
+    let rows =
+        match cursor {
+            Some(last_id) => {
+                sqlx::query_as::<_, (i64, String)>(
+                    "SELECT id, payload FROM durable_notification WHERE id > ? ORDER BY id",
+                )
+                .bind(last_id)
+                .fetch_all(pool)
+                .await?
+            }
+            None => sqlx::query_as::<_, (i64, String)>(
+                "SELECT id, payload FROM durable_notification WHERE created_at >= ? ORDER BY id",
+            )
+            .bind(shared::util::now_millis() - FIRST_CONNECT_WINDOW_MS)
+            .fetch_all(pool)
+            .await?,
+        };
+
+    rows.into_iter()
+        .map(|(id, json)| {
+            let mut payload: NotificationPayload = serde_json::from_str(&json).map_err(|e| {
+                RepoError::DataCorruption(format!("Durable notification {id}: {e}"))
+            })?;
+            payload.durable_id = Some(id);
+            Ok(payload)
+        })
+        .collect()
+}
+
+/// Advance the client's cursor (never moves backwards)
+pub async fn ack(pool: &SqlitePool, client: &str, id: i64) -> RepoResult<()> {
+    sqlx::query(
+        "INSERT INTO notification_cursor (client, last_id, updated_at) VALUES (?1, ?2, ?3) ON CONFLICT(client) DO UPDATE SET last_id = MAX(last_id, excluded.last_id), updated_at = excluded.updated_at",
+    )
+    .bind(client)
+    .bind(id)
+    .bind(shared::util::now_millis())
+    .execute(pool)
+    .await?;
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::db::test_pool;
+
+    #[tokio::test]
+    async fn test_pending_and_ack_per_client() {
+        let pool = test_pool().await;
+        let first = insert(
+            &pool,
+            &NotificationPayload::error("Print failed", "Kitchen"),
+        )
+        .await
+        .unwrap();
+        let second = insert(
+            &pool,
+            &NotificationPayload::warning("Subscription", "Past due"),
+        )
+        .await
+        .unwrap();
+        assert!(second > first);
+
+        // 无游标：补发最近的全部通知
+        let pending = pending_for(&pool, "pos-1").await.unwrap();
+        assert_eq!(pending.len(), 2);
+        assert_eq!(pending[0].durable_id, Some(first));
+        assert_eq!(pending[0].title, "Print failed");
+
+        ack(&pool, "pos-1", second).await.unwrap();
+        ack(&pool, "pos-1", first).await.unwrap();
+        assert!(pending_for(&pool, "pos-1").await.unwrap().is_empty());
+
+        // 其他客户端游标独立
+        ack(&pool, "kds-1", first).await.unwrap();
+        let pending = pending_for(&pool, "kds-1").await.unwrap();
+        assert_eq!(pending.len(), 1);
+        assert_eq!(pending[0].durable_id, Some(second));
+    }
+}
@@ -0,0 +1,167 @@
+//! 86 Board Repository (临时沽清)
+
+use super::{RepoError, RepoResult};
+use shared::models::{EightySix, EightySixCreate};
+use sqlx::SqlitePool;
+
+const EIGHTY_SIX_SELECT: &str = "SELECT id, product_id, product_name, spec_id, spec_name, reason, expires_at, created_by_id, created_by_name, created_at, cleared_at, cleared_by_name FROM eighty_six";
+
+pub async fn find_by_id(pool: &SqlitePool, id: i64) -> RepoResult<Option<EightySix>> {
+    let entry = sqlx::query_as::<_, EightySix>(&format!("{EIGHTY_SIX_SELECT} WHERE id = ?"))
+        .bind(id)
+        .fetch_optional(pool)
+        .await?;
+    Ok(entry)
+}
+
+/// Entries still blocking orders at `now`
+pub async fn find_active(pool: &SqlitePool, now: i64) -> RepoResult<Vec<EightySix>> {
+    let entries = sqlx::query_as::<_, EightySix>(&format!(
+        "{EIGHTY_SIX_SELECT} WHERE cleared_at IS NULL AND (expires_at IS NULL OR expires_at > ?) ORDER BY created_at"
+    ))
+    .bind(now)
+    .fetch_all(pool)
+    .await?;
+    Ok(entries)
+}
+
+/// Entries created in `[from, to)`, cleared/expired included (manager review)
+pub async fn find_created_between(
+    pool: &SqlitePool,
+    from: i64,
+    to: i64,
+) -> RepoResult<Vec<EightySix>> {
+    let entries = sqlx::query_as::<_, EightySix>(&format!(
+        "{EIGHTY_SIX_SELECT} WHERE created_at >= ? AND created_at < ? ORDER BY created_at"
+    ))
+    .bind(from)
+    .bind(to)
+    .fetch_all(pool)
+    .await?;
+    Ok(entries)
+}
+
+/// 86 a product or spec (names are snapshotted by the caller)
+pub async fn create(
+    pool: &SqlitePool,
+    data: &EightySixCreate,
+    product_name: &str,
+    spec_name: Option<&str>,
+    created_by_id: i64,
+    created_by_name: &str,
+) -> RepoResult<EightySix> {
+    let now = shared::util::now_millis();
+    if let Some(expires_at) = data.expires_at
+        && expires_at <= now
+    {
+        return Err(RepoError::Validation(
+            "expires_at must be in the future".into(),
+        ));
+    }
+    if find_active(pool, now)
+        .await?
+        .iter()
+        .any(|e| e.covers(data.product_id, data.spec_id))
+    {
+        return Err(RepoError::Duplicate(format!(
+            "{product_name} is already 86'd"
+        )));
+    }
+
+    let id = shared::util::snowflake_id();
+    sqlx::query(
+        "INSERT INTO eighty_six (id, product_id, product_name, spec_id, spec_name, reason, expires_at, created_by_id, created_by_name, created_at) VALUES (?, ?, ?, ?, ?, ?, ?, ?, ?, ?)",
+    )
+    .bind(id)
+    .bind(data.product_id)
+    .bind(product_name)
+    .bind(data.spec_id)
+    .bind(spec_name)
+    .bind(data.reason.as_deref().map(str::trim).filter(|r| !r.is_empty()))
+    .bind(data.expires_at)
+    .bind(created_by_id)
+    .bind(created_by_name)
+    .bind(now)
+    .execute(pool)
+    .await?;
+
+    find_by_id(pool, id)
+        .await?
+        .ok_or_else(|| RepoError::Database("Failed to create 86 entry".into()))
+}
+
+/// Put an 86'd product back on sale (row kept for review)
+pub async fn clear(pool: &SqlitePool, id: i64, cleared_by_name: &str) -> RepoResult<EightySix> {
+    let rows = sqlx::query(
+        "UPDATE eighty_six SET cleared_at = ?, cleared_by_name = ? WHERE id = ? AND cleared_at IS NULL",
+    )
+    .bind(shared::util::now_millis())
+    .bind(cleared_by_name)
+    .bind(id)
+    .execute(pool)
+    .await?;
+
+    let entry = find_by_id(pool, id)
+        .await?
+        .ok_or_else(|| RepoError::NotFound(format!("86 entry {id} not found")))?;
+    if rows.rows_affected() == 0 {
+        return Err(RepoError::Validation(format!(
+            "86 entry {id} is already cleared"
+        )));
+    }
+    Ok(entry)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::db::test_pool;
+
+    fn input(spec_id: Option<i64>) -> EightySixCreate {
+        EightySixCreate {
+            product_id: 10,
+            spec_id,
+            reason: Some("  sold out ".to_string()),
+            expires_at: None,
+        }
+    }
+
+    #[tokio::test]
+    async fn test_create_rejects_covered_duplicate() {
+        let pool = test_pool().await;
+        let entry = create(&pool, &input(None), "Salmon", None, 1, "Ana")
+            .await
+            .unwrap();
+        assert_eq!(entry.reason.as_deref(), Some("sold out"));
+
+        // Whole product already 86'd → spec-level 86 is redundant
+        let err = create(&pool, &input(Some(101)), "Salmon", Some("Large"), 1, "Ana")
+            .await
+            .unwrap_err();
+        assert!(matches!(err, RepoError::Duplicate(_)));
+    }
+
+    #[tokio::test]
+    async fn test_clear_keeps_row_for_review() {
+        let pool = test_pool().await;
+        let entry = create(&pool, &input(None), "Salmon", None, 1, "Ana")
+            .await
+            .unwrap();
+
+        let cleared = clear(&pool, entry.id, "Luis").await.unwrap();
+        assert_eq!(cleared.cleared_by_name.as_deref(), Some("Luis"));
+        assert!(
+            find_active(&pool, shared::util::now_millis())
+                .await
+                .unwrap()
+                .is_empty()
+        );
+        assert!(matches!(
+            clear(&pool, entry.id, "Luis").await.unwrap_err(),
+            RepoError::Validation(_)
+        ));
+
+        let review = find_created_between(&pool, 0, i64::MAX).await.unwrap();
+        assert_eq!(review, vec![cleared]);
+    }
+}
@@ -16,6 +16,7 @@ pub struct EmployeeWithHash {
     pub is_system: bool,
     pub is_active: bool,
     pub created_at: i64,
+    pub photo: Option<String>,
 }
 
 /// Hash password using argon2
@@ -44,7 +45,7 @@ pub fn verify_password(password: &str, hash: &str) -> Result<bool, argon2::passw
 
 pub async fn find_all(pool: &SqlitePool) -> RepoResult<Vec<Employee>> {
     let employees = sqlx::query_as::<_, Employee>(
-        "SELECT id, username, name, role_id, is_system, is_active, created_at FROM employee WHERE is_active = 1 ORDER BY username",
+        "SELECT id, username, name, role_id, is_system, is_active, created_at, photo FROM employee WHERE is_active = 1 ORDER BY username",
     )
     .fetch_all(pool)
     .await?;
@@ -53,7 +54,7 @@ pub async fn find_all(pool: &SqlitePool) -> RepoResult<Vec<Employee>> {
 
 pub async fn find_all_with_inactive(pool: &SqlitePool) -> RepoResult<Vec<Employee>> {
     let employees = sqlx::query_as::<_, Employee>(
-        "SELECT id, username, name, role_id, is_system, is_active, created_at FROM employee ORDER BY username",
+        "SELECT id, username, name, role_id, is_system, is_active, created_at, photo FROM employee ORDER BY username",
     )
     .fetch_all(pool)
     .await?;
@@ -62,7 +63,7 @@ pub async fn find_all_with_inactive(pool: &SqlitePool) -> RepoResult<Vec<Employe
 
 pub async fn find_by_id(pool: &SqlitePool, id: i64) -> RepoResult<Option<Employee>> {
     let employee = sqlx::query_as::<_, Employee>(
-        "SELECT id, username, name, role_id, is_system, is_active, created_at FROM employee WHERE id = ?",
+        "SELECT id, username, name, role_id, is_system, is_active, created_at, photo FROM employee WHERE id = ?",
     )
     .bind(id)
     .fetch_optional(pool)
@@ -72,7 +73,7 @@ pub async fn find_by_id(pool: &SqlitePool, id: i64) -> RepoResult<Option<Employe
 
 pub async fn find_by_username(pool: &SqlitePool, username: &str) -> RepoResult<Option<Employee>> {
     let employee = sqlx::query_as::<_, Employee>(
-        "SELECT id, username, name, role_id, is_system, is_active, created_at FROM employee WHERE username = ? LIMIT 1",
+        "SELECT id, username, name, role_id, is_system, is_active, created_at, photo FROM employee WHERE username = ? LIMIT 1",
     )
     .bind(username)
     .fetch_optional(pool)
@@ -86,7 +87,7 @@ pub async fn find_by_username_with_hash(
     username: &str,
 ) -> RepoResult<Option<EmployeeWithHash>> {
     let employee = sqlx::query_as::<_, EmployeeWithHash>(
-        "SELECT id, username, name, hash_pass, role_id, is_system, is_active, created_at FROM employee WHERE username = ? LIMIT 1",
+        "SELECT id, username, name, hash_pass, role_id, is_system, is_active, created_at, photo FROM employee WHERE username = ? LIMIT 1",
     )
     .bind(username)
     .fetch_optional(pool)
@@ -173,6 +174,33 @@ pub async fn update(pool: &SqlitePool, id: i64, data: EmployeeUpdate) -> RepoRes
         .ok_or_else(|| RepoError::NotFound(format!("Employee {id} not found")))
 }
 
+/// Set (or clear) the employee's photo hash, returning the previous one
+pub async fn set_photo(
+    pool: &SqlitePool,
+    id: i64,
+    photo: Option<&str>,
+) -> RepoResult<Option<String>> {
+    let existing = find_by_id(pool, id)
+        .await?
+        .ok_or_else(|| RepoError::NotFound(format!("Employee {id} not found")))?;
+    sqlx::query("UPDATE employee SET photo = ?, updated_at = ? WHERE id = ?")
+        .bind(photo)
+        .bind(shared::util::now_millis())
+        .bind(id)
+        .execute(pool)
+        .await?;
+    Ok(existing.photo)
+}
+
+/// Whether another employee still references the photo (content-addressed files are shared)
+pub async fn photo_in_use(pool: &SqlitePool, photo: &str) -> RepoResult<bool> {
+    let count: i64 = sqlx::query_scalar("SELECT COUNT(*) FROM employee WHERE photo = ?")
+        .bind(photo)
+        .fetch_one(pool)
+        .await?;
+    Ok(count > 0)
+}
+
 pub async fn delete(pool: &SqlitePool, id: i64) -> RepoResult<bool> {
     let existing = find_by_id(pool, id)
         .await?
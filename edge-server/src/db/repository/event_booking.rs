@@ -0,0 +1,498 @@
+//! Event Booking Repository
+//!
+//! 预订单头、菜单、定金和修改记录。菜单修改整体替换，
+//! 同一事务内递增 revision 并写入 amendment。
+
+use super::{RepoError, RepoResult};
+use shared::error::ErrorCode;
+use shared::models::{
+    EventBooking, EventBookingAmendment, EventBookingChanges, EventBookingCreate,
+    EventBookingDeposit, EventBookingDepositCreate, EventBookingDetail, EventBookingItem,
+    EventBookingItemInput, EventBookingStatus, EventBookingUpdate,
+};
+use sqlx::{Sqlite, SqlitePool, Transaction};
+
+const BOOKING_SELECT: &str = "SELECT id, event_name, customer_name, customer_phone, member_id, event_start, headcount, table_id, table_name, zone_id, zone_name, note, status, revision, estimated_total, deposit_total, order_id, materialized_at, materialize_error, created_by_id, created_by_name, created_at, updated_at FROM event_booking";
+
+const ITEM_SELECT: &str = "SELECT id, booking_id, product_id, name, price, quantity, COALESCE(selected_options, 'null') AS selected_options, COALESCE(selected_specification, 'null') AS selected_specification, course, note, sort_order FROM event_booking_item";
+
+fn invalid_state(booking: &EventBooking, action: &str) -> RepoError {
+    RepoError::Business(
+        ErrorCode::EventBookingInvalidState,
+        format!(
+            "Cannot {action} event booking {} in status {:?}",
+            booking.id, booking.status
+        ),
+    )
+}
+
+/// Bookings whose event starts in [from, to), optionally filtered by status
+pub async fn find_range(
+    pool: &SqlitePool,
+    from: i64,
+    to: i64,
+    status: Option<EventBookingStatus>,
+) -> RepoResult<Vec<EventBooking>> {
+    let sql = format!(
+        "{BOOKING_SELECT} WHERE event_start >= ?1 AND event_start < ?2 AND (?3 IS NULL OR status = ?3) ORDER BY event_start, id"
+    );
+    let rows = sqlx::query_as::<_, EventBooking>(&sql)
+        .bind(from)
+        .bind(to)
+        .bind(status)
+        .fetch_all(pool)
+        .await?;
+    Ok(rows)
+}
+
+pub async fn find_by_id(pool: &SqlitePool, id: i64) -> RepoResult<Option<EventBooking>> {
+    let booking = sqlx::query_as::<_, EventBooking>(&format!("{BOOKING_SELECT} WHERE id = ?"))
+        .bind(id)
+        .fetch_optional(pool)
+        .await?;
+    Ok(booking)
+}
+
+pub async fn find_items(pool: &SqlitePool, booking_id: i64) -> RepoResult<Vec<EventBookingItem>> {
+    let items = sqlx::query_as::<_, EventBookingItem>(&format!(
+        "{ITEM_SELECT} WHERE booking_id = ? ORDER BY sort_order, id"
+    ))
+    .bind(booking_id)
+    .fetch_all(pool)
+    .await?;
+    Ok(items)
+}
+
+pub async fn find_deposits(
+    pool: &SqlitePool,
+    booking_id: i64,
+) -> RepoResult<Vec<EventBookingDeposit>> {
+    let deposits = sqlx::query_as::<_, EventBookingDeposit>(
+        "SELECT id, booking_id, method, amount, reference, note, operator_id, operator_name, created_at FROM event_booking_deposit WHERE booking_id = ? ORDER BY created_at, id",
+    )
+    .bind(booking_id)
+    .fetch_all(pool)
+    .await?;
+    Ok(deposits)
+}
+
+pub async fn find_detail(pool: &SqlitePool, id: i64) -> RepoResult<Option<EventBookingDetail>> {
+    let Some(booking) = find_by_id(pool, id).await? else {
+        return Ok(None);
+    };
+    let items = find_items(pool, id).await?;
+    let deposits = find_deposits(pool, id).await?;
+    let amendments = sqlx::query_as::<_, EventBookingAmendment>(
+        "SELECT id, booking_id, revision, changes, operator_id, operator_name, created_at FROM event_booking_amendment WHERE booking_id = ? ORDER BY revision",
+    )
+    .bind(id)
+    .fetch_all(pool)
+    .await?;
+    Ok(Some(EventBookingDetail {
+        booking,
+        items,
+        deposits,
+        amendments,
+    }))
+}
+
+async fn require_detail(pool: &SqlitePool, id: i64) -> RepoResult<EventBookingDetail> {
+    find_detail(pool, id).await?.ok_or_else(|| {
+        RepoError::Business(
+            ErrorCode::EventBookingNotFound,
+            format!("Event booking {id} not found"),
+        )
+    })
+}
+
+async fn insert_items(
+    tx: &mut Transaction<'_, Sqlite>,
+    booking_id: i64,
+    items: &[EventBookingItemInput],
+) -> RepoResult<()> {
+    for (index, item) in items.iter().enumerate() {
+        let options_json = item
+            .selected_options
+            .as_ref()
+            .map(|v| serde_json::to_string(v).unwrap_or_else(|_| "[]".to_string()));
+        let spec_json = item
+            .selected_specification
+            .as_ref()
+            .and_then(|s| serde_json::to_string(s).ok());
+        sqlx::query(
+            "INSERT INTO event_booking_item (id, booking_id, product_id, name, price, quantity, selected_options, selected_specification, course, note, sort_order) VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8, ?9, ?10, ?11)",
+        )
+        .bind(shared::util::snowflake_id())
+        .bind(booking_id)
+        .bind(item.product_id)
+        .bind(&item.name)
+        .bind(item.price)
+        .bind(item.quantity)
+        .bind(&options_json)
+        .bind(&spec_json)
+        .bind(&item.course)
+        .bind(&item.note)
+        .bind(index as i32)
+        .execute(&mut **tx)
+        .await?;
+    }
+    Ok(())
+}
+
+pub async fn create(
+    pool: &SqlitePool,
+    data: EventBookingCreate,
+    operator_id: i64,
+    operator_name: &str,
+) -> RepoResult<EventBookingDetail> {
+    let id = shared::util::snowflake_id();
+    let now = shared::util::now_millis();
+    let mut tx = pool.begin().await?;
+    sqlx::query(
+        "INSERT INTO event_booking (id, event_name, customer_name, customer_phone, member_id, event_start, headcount, table_id, table_name, zone_id, zone_name, note, status, revision, estimated_total, deposit_total, created_by_id, created_by_name, created_at, updated_at) VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8, ?9, ?10, ?11, ?12, ?13, 1, ?14, 0, ?15, ?16, ?17, ?17)",
+    )
+    .bind(id)
+    .bind(&data.event_name)
+    .bind(&data.customer_name)
+    .bind(&data.customer_phone)
+    .bind(data.member_id)
+    .bind(data.event_start)
+    .bind(data.headcount)
+    .bind(data.table_id)
+    .bind(&data.table_name)
+    .bind(data.zone_id)
+    .bind(&data.zone_name)
+    .bind(&data.note)
+    .bind(EventBookingStatus::Draft)
+    .bind(shared::models::estimated_total(&data.items))
+    .bind(operator_id)
+    .bind(operator_name)
+    .bind(now)
+    .execute(&mut *tx)
+    .await?;
+    insert_items(&mut tx, id, &data.items).await?;
+    tx.commit().await?;
+
+    require_detail(pool, id).await
+}
+
+/// Apply an amendment; no-op updates leave the revision untouched
+pub async fn update(
+    pool: &SqlitePool,
+    id: i64,
+    data: EventBookingUpdate,
+    operator_id: i64,
+    operator_name: &str,
+) -> RepoResult<(EventBookingDetail, EventBookingChanges)> {
+    let current = require_detail(pool, id).await?;
+    if !current.booking.status.is_editable() {
+        return Err(invalid_state(&current.booking, "amend"));
+    }
+    let changes = shared::models::booking_changes(&current.booking, &current.items, &data);
+    if changes.is_empty() {
+        return Ok((current, changes));
+    }
+
+    let now = shared::util::now_millis();
+    let revision = current.booking.revision + 1;
+    let estimated_total = data.items.as_deref().map(shared::models::estimated_total);
+    let mut tx = pool.begin().await?;
+    // revision 条件防止并发修改互相覆盖
+    let rows = sqlx::query(
+        "UPDATE event_booking SET event_name = COALESCE(?1, event_name), customer_name = COALESCE(?2, customer_name), customer_phone = COALESCE(?3, customer_phone), member_id = COALESCE(?4, member_id), event_start = COALESCE(?5, event_start), headcount = COALESCE(?6, headcount), table_id = COALESCE(?7, table_id), table_name = COALESCE(?8, table_name), zone_id = COALESCE(?9, zone_id), zone_name = COALESCE(?10, zone_name), note = COALESCE(?11, note), estimated_total = COALESCE(?12, estimated_total), revision = ?13, materialize_error = NULL, updated_at = ?14 WHERE id = ?15 AND revision = ?16 AND status IN ('DRAFT', 'CONFIRMED')",
+    )
+    .bind(&data.event_name)
+    .bind(&data.customer_name)
+    .bind(&data.customer_phone)
+    .bind(data.member_id)
+    .bind(data.event_start)
+    .bind(data.headcount)
+    .bind(data.table_id)
+    .bind(&data.table_name)
+    .bind(data.zone_id)
+    .bind(&data.zone_name)
+    .bind(&data.note)
+    .bind(estimated_total)
+    .bind(revision)
+    .bind(now)
+    .bind(id)
+    .bind(current.booking.revision)
+    .execute(&mut *tx)
+    .await?;
+    if rows.rows_affected() == 0 {
+        return Err(RepoError::Business(
+            ErrorCode::EventBookingInvalidState,
+            format!("Event booking {id} was modified concurrently"),
+        ));
+    }
+
+    if let Some(items) = &data.items {
+        sqlx::query("DELETE FROM event_booking_item WHERE booking_id = ?")
+            .bind(id)
+            .execute(&mut *tx)
+            .await?;
+        insert_items(&mut tx, id, items).await?;
+    }
+
+    let changes_json = serde_json::to_string(&changes)
+        .map_err(|e| RepoError::Validation(format!("Invalid amendment: {e}")))?;
+    sqlx::query(
+        "INSERT INTO event_booking_amendment (id, booking_id, revision, changes, operator_id, operator_name, created_at) VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7)",
+    )
+    .bind(shared::util::snowflake_id())
+    .bind(id)
+    .bind(revision)
+    .bind(changes_json)
+    .bind(operator_id)
+    .bind(operator_name)
+    .bind(now)
+    .execute(&mut *tx)
+    .await?;
+    tx.commit().await?;
+
+    Ok((require_detail(pool, id).await?, changes))
+}
+
+/// Status transition (`from` lists the allowed current statuses)
+pub async fn transition(
+    pool: &SqlitePool,
+    id: i64,
+    from: &[EventBookingStatus],
+    to: EventBookingStatus,
+) -> RepoResult<EventBookingDetail> {
+    let current = require_detail(pool, id).await?;
+    if !from.contains(&current.booking.status) {
+        return Err(invalid_state(&current.booking, &format!("move to {to:?}")));
+    }
+    let rows = sqlx::query(
+        "UPDATE event_booking SET status = ?1, updated_at = ?2 WHERE id = ?3 AND status = ?4",
+    )
+    .bind(to)
+    .bind(shared::util::now_millis())
+    .bind(id)
+    .bind(current.booking.status)
+    .execute(pool)
+    .await?;
+    if rows.rows_affected() == 0 {
+        return Err(invalid_state(&current.booking, &format!("move to {to:?}")));
+    }
+    require_detail(pool, id).await
+}
+
+pub async fn add_deposit(
+    pool: &SqlitePool,
+    booking_id: i64,
+    data: &EventBookingDepositCreate,
+    operator_id: i64,
+    operator_name: &str,
+) -> RepoResult<EventBookingDeposit> {
+    let now = shared::util::now_millis();
+    let mut tx = pool.begin().await?;
+    let rows = sqlx::query(
+        "UPDATE event_booking SET deposit_total = ROUND(deposit_total + ?1, 2), updated_at = ?2 WHERE id = ?3 AND status IN ('DRAFT', 'CONFIRMED')",
+    )
+    .bind(data.amount)
+    .bind(now)
+    .bind(booking_id)
+    .execute(&mut *tx)
+    .await?;
+    if rows.rows_affected() == 0 {
+        return Err(match find_by_id(pool, booking_id).await? {
+            Some(booking) => invalid_state(&booking, "add a deposit to"),
+            None => RepoError::Business(
+                ErrorCode::EventBookingNotFound,
+                format!("Event booking {booking_id} not found"),
+            ),
+        });
+    }
+
+    let deposit = EventBookingDeposit {
+        id: shared::util::snowflake_id(),
+        booking_id,
+        method: data.method.clone(),
+        amount: data.amount,
+        reference: data.reference.clone(),
+        note: data.note.clone(),
+        operator_id,
+        operator_name: operator_name.to_string(),
+        created_at: now,
+    };
+    sqlx::query(
+        "INSERT INTO event_booking_deposit (id, booking_id, method, amount, reference, note, operator_id, operator_name, created_at) VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8, ?9)",
+    )
+    .bind(deposit.id)
+    .bind(booking_id)
+    .bind(&deposit.method)
+    .bind(deposit.amount)
+    .bind(&deposit.reference)
+    .bind(&deposit.note)
+    .bind(operator_id)
+    .bind(operator_name)
+    .bind(now)
+    .execute(&mut *tx)
+    .await?;
+    tx.commit().await?;
+    Ok(deposit)
+}
+
+/// Confirmed bookings starting before `until` that have no order yet
+pub async fn find_due(pool: &SqlitePool, until: i64) -> RepoResult<Vec<EventBooking>> {
+    let sql = format!(
+        "{BOOKING_SELECT} WHERE status = 'CONFIRMED' AND order_id IS NULL AND event_start < ? ORDER BY event_start, id"
+    );
+    let rows = sqlx::query_as::<_, EventBooking>(&sql)
+        .bind(until)
+        .fetch_all(pool)
+        .await?;
+    Ok(rows)
+}
+
+/// Link the materialized order (booking leaves the editable states)
+pub async fn mark_materialized(
+    pool: &SqlitePool,
+    id: i64,
+    order_id: i64,
+    error: Option<&str>,
+) -> RepoResult<()> {
+    let now = shared::util::now_millis();
+    sqlx::query(
+        "UPDATE event_booking SET status = 'MATERIALIZED', order_id = ?1, materialized_at = ?2, materialize_error = ?3, updated_at = ?2 WHERE id = ?4",
+    )
+    .bind(order_id)
+    .bind(now)
+    .bind(error)
+    .bind(id)
+    .execute(pool)
+    .await?;
+    Ok(())
+}
+
+/// Record a failed materialization attempt (booking stays CONFIRMED for retry)
+pub async fn set_materialize_error(pool: &SqlitePool, id: i64, error: &str) -> RepoResult<()> {
+    sqlx::query("UPDATE event_booking SET materialize_error = ?1, updated_at = ?2 WHERE id = ?3")
+        .bind(error)
+        .bind(shared::util::now_millis())
+        .bind(id)
+        .execute(pool)
+        .await?;
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::db::test_pool;
+
+    fn item(product_id: i64, quantity: i32) -> EventBookingItemInput {
+        EventBookingItemInput {
+            product_id,
+            name: format!("Dish {product_id}"),
+            price: 12.5,
+            quantity,
+            selected_options: None,
+            selected_specification: None,
+            course: Some("Principal".to_string()),
+            note: None,
+        }
+    }
+
+    fn create_payload() -> EventBookingCreate {
+        EventBookingCreate {
+            event_name: "Comunión".to_string(),
+            customer_name: "Marta".to_string(),
+            customer_phone: None,
+            member_id: None,
+            event_start: 5_000,
+            headcount: 40,
+            table_id: None,
+            table_name: Some("Salón".to_string()),
+            zone_id: None,
+            zone_name: None,
+            note: None,
+            items: vec![item(1, 40)],
+        }
+    }
+
+    #[tokio::test]
+    async fn test_amendments_bump_revision() {
+        let pool = test_pool().await;
+        let detail = create(&pool, create_payload(), 1, "Admin").await.unwrap();
+        assert_eq!(detail.booking.revision, 1);
+        assert_eq!(detail.booking.estimated_total, 500.0);
+
+        let update = EventBookingUpdate {
+            event_name: None,
+            customer_name: None,
+            customer_phone: None,
+            member_id: None,
+            event_start: None,
+            headcount: Some(45),
+            table_id: None,
+            table_name: None,
+            zone_id: None,
+            zone_name: None,
+            note: None,
+            items: Some(vec![item(1, 45), item(2, 10)]),
+        };
+        let (amended, changes) = update_booking(&pool, detail.booking.id, update.clone()).await;
+        assert_eq!(amended.booking.revision, 2);
+        assert_eq!(amended.booking.headcount, 45);
+        assert_eq!(amended.booking.estimated_total, 687.5);
+        assert_eq!(amended.items.len(), 2);
+        assert_eq!(amended.amendments.len(), 1);
+        assert_eq!(changes.items.len(), 2);
+
+        // Same payload again: nothing changed, no new revision
+        let (same, changes) = update_booking(&pool, detail.booking.id, update).await;
+        assert!(changes.is_empty());
+        assert_eq!(same.booking.revision, 2);
+    }
+
+    async fn update_booking(
+        pool: &SqlitePool,
+        id: i64,
+        data: EventBookingUpdate,
+    ) -> (EventBookingDetail, EventBookingChanges) {
+        update(pool, id, data, 1, "Admin").await.unwrap()
+    }
+
+    #[tokio::test]
+    async fn test_due_and_materialized_bookings() {
+        let pool = test_pool().await;
+        let id = create(&pool, create_payload(), 1, "Admin")
+            .await
+            .unwrap()
+            .booking
+            .id;
+        let deposit = EventBookingDepositCreate {
+            method: "CARD".to_string(),
+            amount: 100.0,
+            reference: None,
+            note: None,
+        };
+        add_deposit(&pool, id, &deposit, 1, "Admin").await.unwrap();
+
+        // Drafts are never materialized
+        assert!(find_due(&pool, 10_000).await.unwrap().is_empty());
+
+        transition(
+            &pool,
+            id,
+            &[EventBookingStatus::Draft],
+            EventBookingStatus::Confirmed,
+        )
+        .await
+        .unwrap();
+        assert!(find_due(&pool, 5_000).await.unwrap().is_empty());
+        assert_eq!(find_due(&pool, 5_001).await.unwrap().len(), 1);
+
+        mark_materialized(&pool, id, 77, None).await.unwrap();
+        assert!(find_due(&pool, 10_000).await.unwrap().is_empty());
+        let detail = find_detail(&pool, id).await.unwrap().unwrap();
+        assert_eq!(detail.booking.status, EventBookingStatus::Materialized);
+        assert_eq!(detail.booking.deposit_total, 100.0);
+
+        // Materialized bookings are frozen
+        assert!(add_deposit(&pool, id, &deposit, 1, "Admin").await.is_err());
+    }
+}
@@ -0,0 +1,660 @@
+//! Inventory Repository (库存)
+//!
+//! 库存数量只通过调整记录变化：每次增减都在同一事务中追加 `stock_adjustment`。
+
+use std::collections::HashMap;
+
+use super::{RepoError, RepoResult};
+use shared::models::{
+    StockAdjustment, StockAdjustmentKind, StockConsumption, StockLevel, StockLevelCreate,
+    StockLevelUpdate,
+};
+use sqlx::SqlitePool;
+
+pub(super) const STOCK_LEVEL_SELECT: &str = "SELECT id, product_id, product_name, spec_id, spec_name, quantity, low_stock_threshold, barcode, created_at, updated_at FROM stock_level";
+
+const ADJUSTMENT_SELECT: &str = "SELECT id, stock_level_id, kind, delta, quantity_after, order_id, stock_count_id, stock_transfer_id, note, operator_id, operator_name, created_at FROM stock_adjustment";
+
+pub async fn find_all(pool: &SqlitePool) -> RepoResult<Vec<StockLevel>> {
+    let levels = sqlx::query_as::<_, StockLevel>(&format!(
+        "{STOCK_LEVEL_SELECT} ORDER BY product_name, spec_name"
+    ))
+    .fetch_all(pool)
+    .await?;
+    Ok(levels)
+}
+
+pub async fn find_by_id(pool: &SqlitePool, id: i64) -> RepoResult<Option<StockLevel>> {
+    let level = sqlx::query_as::<_, StockLevel>(&format!("{STOCK_LEVEL_SELECT} WHERE id = ?"))
+        .bind(id)
+        .fetch_optional(pool)
+        .await?;
+    Ok(level)
+}
+
+pub async fn find_by_barcode(pool: &SqlitePool, barcode: &str) -> RepoResult<Option<StockLevel>> {
+    let level = sqlx::query_as::<_, StockLevel>(&format!("{STOCK_LEVEL_SELECT} WHERE barcode = ?"))
+        .bind(barcode.trim())
+        .fetch_optional(pool)
+        .await?;
+    Ok(level)
+}
+
+/// Levels at or below their low-stock threshold
+pub async fn find_low(pool: &SqlitePool) -> RepoResult<Vec<StockLevel>> {
+    let levels = sqlx::query_as::<_, StockLevel>(&format!(
+        "{STOCK_LEVEL_SELECT} WHERE low_stock_threshold IS NOT NULL AND quantity <= low_stock_threshold ORDER BY product_name, spec_name"
+    ))
+    .fetch_all(pool)
+    .await?;
+    Ok(levels)
+}
+
+/// Adjustment trail of a level, newest first
+pub async fn find_adjustments(
+    pool: &SqlitePool,
+    stock_level_id: i64,
+    limit: i64,
+) -> RepoResult<Vec<StockAdjustment>> {
+    let adjustments = sqlx::query_as::<_, StockAdjustment>(&format!(
+        "{ADJUSTMENT_SELECT} WHERE stock_level_id = ? ORDER BY created_at DESC, id DESC LIMIT ?"
+    ))
+    .bind(stock_level_id)
+    .bind(limit)
+    .fetch_all(pool)
+    .await?;
+    Ok(adjustments)
+}
+
+/// Start tracking a product or spec (names are snapshotted by the caller)
+///
+/// A non-zero opening quantity is recorded as the first manual adjustment.
+pub async fn create(
+    pool: &SqlitePool,
+    data: &StockLevelCreate,
+    product_name: &str,
+    spec_name: Option<&str>,
+    operator_id: i64,
+    operator_name: &str,
+) -> RepoResult<StockLevel> {
+    let id = shared::util::snowflake_id();
+    let now = shared::util::now_millis();
+
+    let mut tx = pool.begin().await?;
+    sqlx::query(
+        "INSERT INTO stock_level (id, product_id, product_name, spec_id, spec_name, quantity, low_stock_threshold, barcode, created_at, updated_at) VALUES (?, ?, ?, ?, ?, ?, ?, ?, ?, ?)",
+    )
+    .bind(id)
+    .bind(data.product_id)
+    .bind(product_name)
+    .bind(data.spec_id)
+    .bind(spec_name)
+    .bind(data.quantity)
+    .bind(data.low_stock_threshold)
+    .bind(normalize_barcode(data.barcode.as_deref()))
+    .bind(now)
+    .bind(now)
+    .execute(&mut *tx)
+    .await
+    .map_err(|e| match RepoError::from(e) {
+        RepoError::Duplicate(msg) if msg.contains("barcode") => duplicate_barcode(),
+        RepoError::Duplicate(_) => {
+            RepoError::Duplicate(format!("Stock is already tracked for {product_name}"))
+        }
+        other => other,
+    })?;
+    if data.quantity != 0 {
+        insert_adjustment(
+            &mut tx,
+            id,
+            StockAdjustmentKind::Manual,
+            data.quantity,
+            data.quantity,
+            None,
+            None,
+            None,
+            Some("Opening stock"),
+            Some(operator_id),
+            Some(operator_name),
+            now,
+        )
+        .await?;
+    }
+    tx.commit().await?;
+
+    find_by_id(pool, id)
+        .await?
+        .ok_or_else(|| RepoError::Database("Failed to create stock level".into()))
+}
+
+pub async fn update(pool: &SqlitePool, id: i64, data: &StockLevelUpdate) -> RepoResult<StockLevel> {
+    let rows = sqlx::query(
+        "UPDATE stock_level SET low_stock_threshold = ?, barcode = ?, updated_at = ? WHERE id = ?",
+    )
+    .bind(data.low_stock_threshold)
+    .bind(normalize_barcode(data.barcode.as_deref()))
+    .bind(shared::util::now_millis())
+    .bind(id)
+    .execute(pool)
+    .await
+    .map_err(|e| match RepoError::from(e) {
+        RepoError::Duplicate(_) => duplicate_barcode(),
+        other => other,
+    })?;
+    if rows.rows_affected() == 0 {
+        return Err(RepoError::NotFound(format!("Stock level {id} not found")));
+    }
+    find_by_id(pool, id)
+        .await?
+        .ok_or_else(|| RepoError::NotFound(format!("Stock level {id} not found")))
+}
+
+/// Stop tracking (adjustment trail removed with the level)
+pub async fn delete(pool: &SqlitePool, id: i64) -> RepoResult<bool> {
+    let mut tx = pool.begin().await?;
+    sqlx::query("DELETE FROM stock_adjustment WHERE stock_level_id = ?")
+        .bind(id)
+        .execute(&mut *tx)
+        .await?;
+    let rows = sqlx::query("DELETE FROM stock_level WHERE id = ?")
+        .bind(id)
+        .execute(&mut *tx)
+        .await?;
+    tx.commit().await?;
+    Ok(rows.rows_affected() > 0)
+}
+
+/// Manual adjustment; returns the updated level
+pub async fn adjust(
+    pool: &SqlitePool,
+    id: i64,
+    delta: i64,
+    note: Option<&str>,
+    operator_id: i64,
+    operator_name: &str,
+) -> RepoResult<StockLevel> {
+    if delta == 0 {
+        return Err(RepoError::Validation(
+            "Adjustment delta must not be zero".into(),
+        ));
+    }
+    let now = shared::util::now_millis();
+
+    let mut tx = pool.begin().await?;
+    let quantity_after: Option<i64> = sqlx::query_scalar(
+        "UPDATE stock_level SET quantity = quantity + ?, updated_at = ? WHERE id = ? RETURNING quantity",
+    )
+    .bind(delta)
+    .bind(now)
+    .bind(id)
+    .fetch_optional(&mut *tx)
+    .await?;
+    let Some(quantity_after) = quantity_after else {
+        return Err(RepoError::NotFound(format!("Stock level {id} not found")));
+    };
+    insert_adjustment(
+        &mut tx,
+        id,
+        StockAdjustmentKind::Manual,
+        delta,
+        quantity_after,
+        None,
+        None,
+        None,
+        note,
+        Some(operator_id),
+        Some(operator_name),
+        now,
+    )
+    .await?;
+    tx.commit().await?;
+
+    find_by_id(pool, id)
+        .await?
+        .ok_or_else(|| RepoError::NotFound(format!("Stock level {id} not found")))
+}
+
+/// Decrement stock for a completed order
+///
+/// Each product line is charged to its spec-level stock, falling back to the
+/// whole-product level; modifier lines are charged to the stock level their
+/// attribute option links to. Untracked products and unlinked options are
+/// skipped. Idempotent per order
+/// (a replayed completion finds the existing SALE rows and changes nothing).
+/// Returns the changed levels with their quantity before the sale.
+pub async fn deduct_for_order(
+    pool: &SqlitePool,
+    order_id: i64,
+    items: &[StockConsumption],
+) -> RepoResult<Vec<(StockLevel, i64)>> {
+    let mut tx = pool.begin().await?;
+    let already: Option<i64> = sqlx::query_scalar(
+        "SELECT id FROM stock_adjustment WHERE order_id = ? AND kind = 'SALE' LIMIT 1",
+    )
+    .bind(order_id)
+    .fetch_optional(&mut *tx)
+    .await?;
+    if already.is_some() {
+        return Ok(Vec::new());
+    }
+
+    let levels = sqlx::query_as::<_, StockLevel>(STOCK_LEVEL_SELECT)
+        .fetch_all(&mut *tx)
+        .await?;
+    let option_links: HashMap<i64, i64> = sqlx::query_as(
+        "SELECT id, stock_level_id FROM attribute_option WHERE stock_level_id IS NOT NULL",
+    )
+    .fetch_all(&mut *tx)
+    .await?
+    .into_iter()
+    .collect();
+    let mut consumed: HashMap<i64, i64> = HashMap::new();
+    for item in items.iter().filter(|i| i.quantity() > 0) {
+        let level_id = match *item {
+            StockConsumption::Product {
+                product_id,
+                spec_id,
+                ..
+            } => resolve(&levels, product_id, spec_id).map(|l| l.id),
+            StockConsumption::Modifier { option_id, .. } => option_links.get(&option_id).copied(),
+        };
+        if let Some(level_id) = level_id {
+            *consumed.entry(level_id).or_default() += item.quantity();
+        }
+    }
+
+    let now = shared::util::now_millis();
+    let mut changed = Vec::with_capacity(consumed.len());
+    for level in levels {
+        let Some(&quantity) = consumed.get(&level.id) else {
+            continue;
+        };
+        let before = level.quantity;
+        let quantity_after: i64 = sqlx::query_scalar(
+            "UPDATE stock_level SET quantity = quantity - ?, updated_at = ? WHERE id = ? RETURNING quantity",
+        )
+        .bind(quantity)
+        .bind(now)
+        .bind(level.id)
+        .fetch_one(&mut *tx)
+        .await?;
+        insert_adjustment(
+            &mut tx,
+            level.id,
+            StockAdjustmentKind::Sale,
+            -quantity,
+            quantity_after,
+            Some(order_id),
+            None,
+            None,
+            None,
+            None,
+            None,
+            now,
+        )
+        .await?;
+        changed.push((
+            StockLevel {
+                quantity: quantity_after,
+                updated_at: now,
+                ..level
+            },
+            before,
+        ));
+    }
+    tx.commit().await?;
+    Ok(changed)
+}
+
+fn normalize_barcode(barcode: Option<&str>) -> Option<&str> {
+    barcode.map(str::trim).filter(|b| !b.is_empty())
+}
+
+fn duplicate_barcode() -> RepoError {
+    RepoError::Duplicate("Barcode is already used by another stock level".into())
+}
+
+/// Spec-level stock first, then the whole-product level
+fn resolve(levels: &[StockLevel], product_id: i64, spec_id: Option<i64>) -> Option<&StockLevel> {
+    let mut product_level = None;
+    for level in levels.iter().filter(|l| l.product_id == product_id) {
+        match level.spec_id {
+            Some(id) if Some(id) == spec_id => return Some(level),
+            None => product_level = Some(level),
+            Some(_) => {}
+        }
+    }
+    product_level
+}
+
+#[allow(clippy::too_many_arguments)]
+pub(super) async fn insert_adjustment(
+    tx: &mut sqlx::Transaction<'_, sqlx::Sqlite>,
+    stock_level_id: i64,
+    kind: StockAdjustmentKind,
+    delta: i64,
+    quantity_after: i64,
+    order_id: Option<i64>,
+    stock_count_id: Option<i64>,
+    stock_transfer_id: Option<i64>,
+    note: Option<&str>,
+    operator_id: Option<i64>,
+    operator_name: Option<&str>,
+    created_at: i64,
+) -> RepoResult<()> {
+    sqlx::query(
+        "INSERT INTO stock_adjustment (id, stock_level_id, kind, delta, quantity_after, order_id, stock_count_id, stock_transfer_id, note, operator_id, operator_name, created_at) VALUES (?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?)",
+    )
+    .bind(shared::util::snowflake_id())
+    .bind(stock_level_id)
+    .bind(kind.as_str())
+    .bind(delta)
+    .bind(quantity_after)
+    .bind(order_id)
+    .bind(stock_count_id)
+    .bind(stock_transfer_id)
+    .bind(note.map(str::trim).filter(|n| !n.is_empty()))
+    .bind(operator_id)
+    .bind(operator_name)
+    .bind(created_at)
+    .execute(&mut **tx)
+    .await?;
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::db::test_pool;
+
+    fn input(spec_id: Option<i64>, quantity: i64) -> StockLevelCreate {
+        StockLevelCreate {
+            product_id: 10,
+            spec_id,
+            quantity,
+            low_stock_threshold: Some(3),
+            barcode: None,
+        }
+    }
+
+    fn line(spec_id: Option<i64>, quantity: i64) -> StockConsumption {
+        StockConsumption::Product {
+            product_id: 10,
+            spec_id,
+            quantity,
+        }
+    }
+
+    #[tokio::test]
+    async fn test_deduct_prefers_spec_level_and_is_idempotent() {
+        let pool = test_pool().await;
+        let product = create(&pool, &input(None, 10), "Salmon", None, 1, "Ana")
+            .await
+            .unwrap();
+        let large = create(
+            &pool,
+            &input(Some(101), 5),
+            "Salmon",
+            Some("Large"),
+            1,
+            "Ana",
+        )
+        .await
+        .unwrap();
+        assert!(matches!(
+            create(&pool, &input(None, 1), "Salmon", None, 1, "Ana").await,
+            Err(RepoError::Duplicate(_))
+        ));
+
+        let items = [
+            line(Some(101), 2),
+            line(Some(102), 3),
+            line(None, 1),
+            StockConsumption::Product {
+                product_id: 99,
+                spec_id: None,
+                quantity: 4,
+            },
+        ];
+        let changed = deduct_for_order(&pool, 500, &items).await.unwrap();
+        assert_eq!(changed.len(), 2);
+        let after = |id: i64| changed.iter().find(|(l, _)| l.id == id).unwrap();
+        assert_eq!((after(product.id).0.quantity, after(product.id).1), (6, 10));
+        assert_eq!((after(large.id).0.quantity, after(large.id).1), (3, 5));
+        assert!(after(large.id).0.became_low(after(large.id).1));
+
+        // Replayed completion changes nothing
+        assert!(
+            deduct_for_order(&pool, 500, &items)
+                .await
+                .unwrap()
+                .is_empty()
+        );
+        assert_eq!(
+            find_by_id(&pool, product.id)
+                .await
+                .unwrap()
+                .unwrap()
+                .quantity,
+            6
+        );
+
+        let trail = find_adjustments(&pool, large.id, 10).await.unwrap();
+        assert_eq!(trail.len(), 2);
+        assert_eq!(trail[0].kind, StockAdjustmentKind::Sale);
+        assert_eq!(trail[0].order_id, Some(500));
+        assert_eq!(trail[1].note.as_deref(), Some("Opening stock"));
+        assert_eq!(find_low(&pool).await.unwrap().len(), 1);
+    }
+
+    #[tokio::test]
+    async fn test_deduct_linked_option_stock() {
+        let pool = test_pool().await;
+        let cheese = create(
+            &pool,
+            &StockLevelCreate {
+                product_id: 20,
+                spec_id: None,
+                quantity: 10,
+                low_stock_threshold: None,
+                barcode: None,
+            },
+            "Cheese",
+            None,
+            1,
+            "Ana",
+        )
+        .await
+        .unwrap();
+        sqlx::query("INSERT INTO attribute (id, name) VALUES (1, 'Extras')")
+            .execute(&pool)
+            .await
+            .unwrap();
+        sqlx::query(
+            "INSERT INTO attribute_option (id, attribute_id, name, stock_level_id) VALUES (11, 1, 'Extra cheese', ?), (12, 1, 'No onion', NULL)",
+        )
+        .bind(cheese.id)
+        .execute(&pool)
+        .await
+        .unwrap();
+
+        let items = [
+            line(None, 2),
+            StockConsumption::Modifier {
+                option_id: 11,
+                quantity: 4,
+            },
+            StockConsumption::Modifier {
+                option_id: 12,
+                quantity: 2,
+            },
+        ];
+        let changed = deduct_for_order(&pool, 600, &items).await.unwrap();
+        assert_eq!(changed.len(), 1);
+        assert_eq!((changed[0].0.id, changed[0].0.quantity), (cheese.id, 6));
+
+        // Deleting the stock level unlinks the option
+        assert!(delete(&pool, cheese.id).await.unwrap());
+        let link: Option<i64> =
+            sqlx::query_scalar("SELECT stock_level_id FROM attribute_option WHERE id = 11")
+                .fetch_one(&pool)
+                .await
+                .unwrap();
+        assert_eq!(link, None);
+    }
+
+    #[tokio::test]
+    async fn test_option_stock_link_survives_attribute_edit() {
+        use crate::db::repository::attribute;
+        use shared::models::{AttributeCreate, AttributeOptionInput, AttributeUpdate};
+
+        let pool = test_pool().await;
+        let cheese = create(&pool, &input(None, 10), "Cheese", None, 1, "Ana")
+            .await
+            .unwrap();
+        let option =
+            |id: Option<i64>, name: &str, stock_level_id: Option<i64>| AttributeOptionInput {
+                id,
+                name: name.into(),
+                price_modifier: 1.5,
+                display_order: 0,
+                receipt_name: None,
+                kitchen_print_name: None,
+                enable_quantity: false,
+                max_quantity: None,
+                stock_level_id,
+            };
+        let attr = attribute::create(
+            &pool,
+            None,
+            AttributeCreate {
+                name: "Extras".into(),
+                is_multi_select: Some(true),
+                max_selections: None,
+                default_option_ids: None,
+                display_order: None,
+                show_on_receipt: None,
+                receipt_name: None,
+                show_on_kitchen_print: None,
+                kitchen_print_name: None,
+                options: Some(vec![option(None, "Extra cheese", Some(cheese.id))]),
+            },
+        )
+        .await
+        .unwrap();
+        // Order opened with this option selected
+        let selected = attr.options[0].id;
+
+        // Attribute edited before checkout: option renamed, another added
+        let edited = attribute::update(
+            &pool,
+            attr.id,
+            AttributeUpdate {
+                options: Some(vec![
+                    option(Some(selected), "Double cheese", Some(cheese.id)),
+                    option(None, "Bacon", None),
+                ]),
+                ..Default::default()
+            },
+        )
+        .await
+        .unwrap();
+        assert_eq!(edited.options.len(), 2);
+        assert_eq!(edited.options[0].id, selected);
+        assert_eq!(edited.options[0].name, "Double cheese");
+
+        let changed = deduct_for_order(
+            &pool,
+            700,
+            &[StockConsumption::Modifier {
+                option_id: selected,
+                quantity: 3,
+            }],
+        )
+        .await
+        .unwrap();
+        assert_eq!(changed.len(), 1);
+        assert_eq!((changed[0].0.id, changed[0].0.quantity), (cheese.id, 7));
+
+        // Options left out of the update are removed
+        let trimmed = attribute::update(
+            &pool,
+            attr.id,
+            AttributeUpdate {
+                options: Some(vec![option(
+                    Some(selected),
+                    "Double cheese",
+                    Some(cheese.id),
+                )]),
+                ..Default::default()
+            },
+        )
+        .await
+        .unwrap();
+        assert_eq!(trimmed.options.len(), 1);
+        assert_eq!(trimmed.options[0].id, selected);
+    }
+
+    #[tokio::test]
+    async fn test_manual_adjust_and_delete() {
+        let pool = test_pool().await;
+        let level = create(&pool, &input(None, 0), "Salmon", None, 1, "Ana")
+            .await
+            .unwrap();
+        assert!(
+            find_adjustments(&pool, level.id, 10)
+                .await
+                .unwrap()
+                .is_empty()
+        );
+
+        let level = adjust(&pool, level.id, 12, Some(" delivery "), 2, "Luis")
+            .await
+            .unwrap();
+        assert_eq!(level.quantity, 12);
+        let level = adjust(&pool, level.id, -15, None, 2, "Luis").await.unwrap();
+        assert_eq!(level.quantity, -3);
+        assert!(matches!(
+            adjust(&pool, level.id, 0, None, 2, "Luis").await,
+            Err(RepoError::Validation(_))
+        ));
+
+        // Both adjustments may share a millisecond, so match them by delta
+        let trail = find_adjustments(&pool, level.id, 10).await.unwrap();
+        assert_eq!(trail.len(), 2);
+        let delivery = trail.iter().find(|a| a.delta == 12).unwrap();
+        assert_eq!(delivery.note.as_deref(), Some("delivery"));
+        let shrink = trail.iter().find(|a| a.delta == -15).unwrap();
+        assert_eq!(shrink.quantity_after, -3);
+
+        let level = update(
+            &pool,
+            level.id,
+            &StockLevelUpdate {
+                low_stock_threshold: None,
+                barcode: Some(" 8410001 ".into()),
+            },
+        )
+        .await
+        .unwrap();
+        assert!(!level.is_low());
+        assert_eq!(level.barcode.as_deref(), Some("8410001"));
+        assert_eq!(
+            find_by_barcode(&pool, "8410001").await.unwrap().unwrap().id,
+            level.id
+        );
+
+        assert!(delete(&pool, level.id).await.unwrap());
+        assert!(!delete(&pool, level.id).await.unwrap());
+        assert!(
+            find_adjustments(&pool, level.id, 10)
+                .await
+                .unwrap()
+                .is_empty()
+        );
+        assert!(matches!(
+            adjust(&pool, level.id, 1, None, 2, "Luis").await,
+            Err(RepoError::NotFound(_))
+        ));
+    }
+}
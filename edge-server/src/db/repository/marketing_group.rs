@@ -12,7 +12,7 @@ use sqlx::SqlitePool;
 
 pub async fn find_all(pool: &SqlitePool) -> RepoResult<Vec<MarketingGroup>> {
     let rows = sqlx::query_as::<_, MarketingGroup>(
-        "SELECT id, name, description, sort_order, points_earn_rate, created_at, updated_at FROM marketing_group ORDER BY sort_order",
+        "SELECT id, name, description, sort_order, points_earn_rate, points_redeem_unit, points_redeem_value, created_at, updated_at FROM marketing_group ORDER BY sort_order",
     )
     .fetch_all(pool)
     .await?;
@@ -21,7 +21,7 @@ pub async fn find_all(pool: &SqlitePool) -> RepoResult<Vec<MarketingGroup>> {
 
 pub async fn find_by_id(pool: &SqlitePool, id: i64) -> RepoResult<Option<MarketingGroup>> {
     let row = sqlx::query_as::<_, MarketingGroup>(
-        "SELECT id, name, description, sort_order, points_earn_rate, created_at, updated_at FROM marketing_group WHERE id = ?",
+        "SELECT id, name, description, sort_order, points_earn_rate, points_redeem_unit, points_redeem_value, created_at, updated_at FROM marketing_group WHERE id = ?",
     )
     .bind(id)
     .fetch_optional(pool)
@@ -44,6 +44,14 @@ pub async fn create(pool: &SqlitePool, data: MarketingGroupCreate) -> RepoResult
     )
     .execute(pool)
     .await?;
+    sqlx::query(
+        "UPDATE marketing_group SET points_redeem_unit = ?1, points_redeem_value = ?2 WHERE id = ?3",
+    )
+    .bind(data.points_redeem_unit)
+    .bind(data.points_redeem_value)
+    .bind(id)
+    .execute(pool)
+    .await?;
     find_by_id(pool, id)
         .await?
         .ok_or_else(|| RepoError::Database("Failed to create marketing group".into()))
@@ -71,6 +79,14 @@ pub async fn update(
             "Marketing group {id} not found"
         )));
     }
+    sqlx::query(
+        "UPDATE marketing_group SET points_redeem_unit = COALESCE(?1, points_redeem_unit), points_redeem_value = COALESCE(?2, points_redeem_value) WHERE id = ?3",
+    )
+    .bind(data.points_redeem_unit)
+    .bind(data.points_redeem_value)
+    .bind(id)
+    .execute(pool)
+    .await?;
     find_by_id(pool, id)
         .await?
         .ok_or_else(|| RepoError::NotFound(format!("Marketing group {id} not found")))
@@ -103,6 +119,10 @@ pub async fn delete(pool: &SqlitePool, id: i64) -> RepoResult<bool> {
         )
         .execute(&mut *tx)
         .await?;
+        sqlx::query("DELETE FROM stamp_ledger WHERE stamp_activity_id = ?")
+            .bind(aid)
+            .execute(&mut *tx)
+            .await?;
     }
     sqlx::query!(
         "DELETE FROM stamp_activity WHERE marketing_group_id = ?",
@@ -232,13 +252,26 @@ pub async fn delete_rule(pool: &SqlitePool, rule_id: i64) -> RepoResult<bool> {
 
 // ── StampActivity CRUD ───────────────────────────────────────
 
+const ACTIVITY_SELECT: &str = "SELECT id, marketing_group_id, name, stamps_required, reward_quantity, reward_strategy, designated_product_id, is_cyclic, is_active, valid_from, valid_until, COALESCE(active_days, 'null') as active_days, active_start_time, active_end_time, max_stamps_per_day, created_at, updated_at FROM stamp_activity";
+
+pub async fn find_activity_by_id(
+    pool: &SqlitePool,
+    activity_id: i64,
+) -> RepoResult<Option<StampActivity>> {
+    let row = sqlx::query_as::<_, StampActivity>(&format!("{ACTIVITY_SELECT} WHERE id = ?"))
+        .bind(activity_id)
+        .fetch_optional(pool)
+        .await?;
+    Ok(row)
+}
+
 pub async fn find_activities_by_group(
     pool: &SqlitePool,
     group_id: i64,
 ) -> RepoResult<Vec<StampActivity>> {
-    let rows = sqlx::query_as::<_, StampActivity>(
-        "SELECT id, marketing_group_id, name, stamps_required, reward_quantity, reward_strategy, designated_product_id, is_cyclic, is_active, created_at, updated_at FROM stamp_activity WHERE marketing_group_id = ? ORDER BY created_at DESC",
-    )
+    let rows = sqlx::query_as::<_, StampActivity>(&format!(
+        "{ACTIVITY_SELECT} WHERE marketing_group_id = ? ORDER BY created_at DESC"
+    ))
     .bind(group_id)
     .fetch_all(pool)
     .await?;
@@ -249,9 +282,9 @@ pub async fn find_active_activities_by_group(
     pool: &SqlitePool,
     group_id: i64,
 ) -> RepoResult<Vec<StampActivity>> {
-    let rows = sqlx::query_as::<_, StampActivity>(
-        "SELECT id, marketing_group_id, name, stamps_required, reward_quantity, reward_strategy, designated_product_id, is_cyclic, is_active, created_at, updated_at FROM stamp_activity WHERE marketing_group_id = ? AND is_active = 1 ORDER BY created_at DESC",
-    )
+    let rows = sqlx::query_as::<_, StampActivity>(&format!(
+        "{ACTIVITY_SELECT} WHERE marketing_group_id = ? AND is_active = 1 ORDER BY created_at DESC"
+    ))
     .bind(group_id)
     .fetch_all(pool)
     .await?;
@@ -273,18 +306,28 @@ pub async fn create_activity(
     let mut tx = pool.begin().await?;
 
     let id = shared::util::snowflake_id();
-    sqlx::query!(
-        "INSERT INTO stamp_activity (id, marketing_group_id, name, stamps_required, reward_quantity, reward_strategy, designated_product_id, is_cyclic, is_active, created_at, updated_at) VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8, 1, ?9, ?9)",
-        id,
-        group_id,
-        data.name,
-        data.stamps_required,
-        reward_quantity,
-        reward_strategy,
-        data.designated_product_id,
-        is_cyclic,
-        now
+    let active_days_json = data
+        .active_days
+        .as_ref()
+        .map(|v| serde_json::to_string(v).unwrap_or_else(|_| "[]".to_string()));
+    sqlx::query(
+        "INSERT INTO stamp_activity (id, marketing_group_id, name, stamps_required, reward_quantity, reward_strategy, designated_product_id, is_cyclic, is_active, valid_from, valid_until, active_days, active_start_time, active_end_time, max_stamps_per_day, created_at, updated_at) VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8, 1, ?9, ?10, ?11, ?12, ?13, ?14, ?15, ?15)",
     )
+    .bind(id)
+    .bind(group_id)
+    .bind(&data.name)
+    .bind(data.stamps_required)
+    .bind(reward_quantity)
+    .bind(reward_strategy)
+    .bind(data.designated_product_id)
+    .bind(is_cyclic)
+    .bind(data.valid_from)
+    .bind(data.valid_until)
+    .bind(&active_days_json)
+    .bind(&data.active_start_time)
+    .bind(&data.active_end_time)
+    .bind(data.max_stamps_per_day)
+    .bind(now)
     .execute(&mut *tx)
     .await?;
 
@@ -305,18 +348,28 @@ pub async fn update_activity(
 
     let mut tx = pool.begin().await?;
 
-    let rows = sqlx::query!(
-        "UPDATE stamp_activity SET name = COALESCE(?1, name), stamps_required = COALESCE(?2, stamps_required), reward_quantity = COALESCE(?3, reward_quantity), reward_strategy = COALESCE(?4, reward_strategy), designated_product_id = COALESCE(?5, designated_product_id), is_cyclic = COALESCE(?6, is_cyclic), is_active = COALESCE(?7, is_active), updated_at = ?8 WHERE id = ?9",
-        data.name,
-        data.stamps_required,
-        data.reward_quantity,
-        data.reward_strategy,
-        data.designated_product_id,
-        data.is_cyclic,
-        data.is_active,
-        now,
-        activity_id
+    let active_days_json = data
+        .active_days
+        .as_ref()
+        .map(|v| serde_json::to_string(v).unwrap_or_else(|_| "[]".to_string()));
+    let rows = sqlx::query(
+        "UPDATE stamp_activity SET name = COALESCE(?1, name), stamps_required = COALESCE(?2, stamps_required), reward_quantity = COALESCE(?3, reward_quantity), reward_strategy = COALESCE(?4, reward_strategy), designated_product_id = COALESCE(?5, designated_product_id), is_cyclic = COALESCE(?6, is_cyclic), is_active = COALESCE(?7, is_active), valid_from = COALESCE(?8, valid_from), valid_until = COALESCE(?9, valid_until), active_days = COALESCE(?10, active_days), active_start_time = COALESCE(?11, active_start_time), active_end_time = COALESCE(?12, active_end_time), max_stamps_per_day = COALESCE(?13, max_stamps_per_day), updated_at = ?14 WHERE id = ?15",
     )
+    .bind(&data.name)
+    .bind(data.stamps_required)
+    .bind(data.reward_quantity)
+    .bind(&data.reward_strategy)
+    .bind(data.designated_product_id)
+    .bind(data.is_cyclic)
+    .bind(data.is_active)
+    .bind(data.valid_from)
+    .bind(data.valid_until)
+    .bind(&active_days_json)
+    .bind(&data.active_start_time)
+    .bind(&data.active_end_time)
+    .bind(data.max_stamps_per_day)
+    .bind(now)
+    .bind(activity_id)
     .execute(&mut *tx)
     .await?;
     if rows.rows_affected() == 0 {
@@ -431,13 +484,9 @@ async fn load_activity_detail(
     pool: &SqlitePool,
     activity_id: i64,
 ) -> RepoResult<StampActivityDetail> {
-    let activity = sqlx::query_as::<_, StampActivity>(
-        "SELECT id, marketing_group_id, name, stamps_required, reward_quantity, reward_strategy, designated_product_id, is_cyclic, is_active, created_at, updated_at FROM stamp_activity WHERE id = ?",
-    )
-    .bind(activity_id)
-    .fetch_optional(pool)
-    .await?
-    .ok_or_else(|| RepoError::NotFound(format!("Stamp activity {activity_id} not found")))?;
+    let activity = find_activity_by_id(pool, activity_id)
+        .await?
+        .ok_or_else(|| RepoError::NotFound(format!("Stamp activity {activity_id} not found")))?;
 
     let stamp_targets = find_stamp_targets(pool, activity_id).await?;
     let reward_targets = find_reward_targets(pool, activity_id).await?;
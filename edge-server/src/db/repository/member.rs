@@ -4,7 +4,7 @@ use super::{RepoError, RepoResult};
 use shared::models::{Member, MemberCreate, MemberUpdate, MemberWithGroup};
 use sqlx::SqlitePool;
 
-const MEMBER_WITH_GROUP_SELECT: &str = "SELECT m.id, m.name, m.phone, m.card_number, m.marketing_group_id, mg.name as marketing_group_name, m.birthday, m.email, m.points_balance, m.total_spent, m.notes, m.is_active, m.created_at, m.updated_at FROM member m JOIN marketing_group mg ON m.marketing_group_id = mg.id";
+const MEMBER_WITH_GROUP_SELECT: &str = "SELECT m.id, m.name, m.phone, m.card_number, m.marketing_group_id, mg.name as marketing_group_name, m.birthday, m.email, m.points_balance, m.total_spent, m.credit_balance, m.notes, m.preferred_locale, m.is_active, m.created_at, m.updated_at FROM member m JOIN marketing_group mg ON m.marketing_group_id = mg.id";
 
 pub async fn find_all(pool: &SqlitePool) -> RepoResult<Vec<MemberWithGroup>> {
     let sql = format!(
@@ -43,7 +43,7 @@ pub async fn create(pool: &SqlitePool, data: MemberCreate) -> RepoResult<MemberW
     let now = shared::util::now_millis();
     let id = shared::util::snowflake_id();
     sqlx::query!(
-        "INSERT INTO member (id, name, phone, card_number, marketing_group_id, birthday, email, notes, is_active, created_at, updated_at) VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8, 1, ?9, ?9)",
+        "INSERT INTO member (id, name, phone, card_number, marketing_group_id, birthday, email, notes, preferred_locale, is_active, created_at, updated_at) VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8, ?9, 1, ?10, ?10)",
         id,
         data.name,
         data.phone,
@@ -52,6 +52,7 @@ pub async fn create(pool: &SqlitePool, data: MemberCreate) -> RepoResult<MemberW
         data.birthday,
         data.email,
         data.notes,
+        data.preferred_locale,
         now
     )
     .execute(pool)
@@ -64,7 +65,7 @@ pub async fn create(pool: &SqlitePool, data: MemberCreate) -> RepoResult<MemberW
 pub async fn update(pool: &SqlitePool, id: i64, data: MemberUpdate) -> RepoResult<MemberWithGroup> {
     let now = shared::util::now_millis();
     let rows = sqlx::query!(
-        "UPDATE member SET name = COALESCE(?1, name), phone = COALESCE(?2, phone), card_number = COALESCE(?3, card_number), marketing_group_id = COALESCE(?4, marketing_group_id), birthday = COALESCE(?5, birthday), email = COALESCE(?6, email), notes = COALESCE(?7, notes), is_active = COALESCE(?8, is_active), updated_at = ?9 WHERE id = ?10",
+        "UPDATE member SET name = COALESCE(?1, name), phone = COALESCE(?2, phone), card_number = COALESCE(?3, card_number), marketing_group_id = COALESCE(?4, marketing_group_id), birthday = COALESCE(?5, birthday), email = COALESCE(?6, email), notes = COALESCE(?7, notes), preferred_locale = CASE WHEN ?8 IS NULL THEN preferred_locale ELSE NULLIF(?8, '') END, is_active = COALESCE(?9, is_active), updated_at = ?10 WHERE id = ?11",
         data.name,
         data.phone,
         data.card_number,
@@ -72,6 +73,7 @@ pub async fn update(pool: &SqlitePool, id: i64, data: MemberUpdate) -> RepoResul
         data.birthday,
         data.email,
         data.notes,
+        data.preferred_locale,
         data.is_active,
         now,
         id
@@ -100,7 +102,7 @@ pub async fn delete(pool: &SqlitePool, id: i64) -> RepoResult<bool> {
 
 pub async fn find_member_by_id(pool: &SqlitePool, id: i64) -> RepoResult<Option<Member>> {
     let row = sqlx::query_as::<_, Member>(
-        "SELECT id, name, phone, card_number, marketing_group_id, birthday, email, points_balance, total_spent, notes, is_active, created_at, updated_at FROM member WHERE id = ?",
+        "SELECT id, name, phone, card_number, marketing_group_id, birthday, email, points_balance, total_spent, credit_balance, notes, preferred_locale, is_active, created_at, updated_at FROM member WHERE id = ?",
     )
     .bind(id)
     .fetch_optional(pool)
@@ -108,21 +110,21 @@ pub async fn find_member_by_id(pool: &SqlitePool, id: i64) -> RepoResult<Option<
     Ok(row)
 }
 
-/// Atomically update member stats after order completion (total_spent + points_balance)
-pub async fn update_member_stats(
+/// Atomically add to member total_spent after order completion
+///
+/// 积分余额由 member_points 仓储维护（带流水）
+pub async fn add_total_spent(
     pool: &SqlitePool,
     member_id: i64,
     spent_amount: f64,
-    points_earned: i64,
 ) -> RepoResult<()> {
     let now = shared::util::now_millis();
-    sqlx::query!(
-        "UPDATE member SET total_spent = total_spent + ?1, points_balance = points_balance + ?2, updated_at = ?3 WHERE id = ?4 AND is_active = 1",
-        spent_amount,
-        points_earned,
-        now,
-        member_id
+    sqlx::query(
+        "UPDATE member SET total_spent = total_spent + ?1, updated_at = ?2 WHERE id = ?3 AND is_active = 1",
     )
+    .bind(spent_amount)
+    .bind(now)
+    .bind(member_id)
     .execute(pool)
     .await?;
     Ok(())
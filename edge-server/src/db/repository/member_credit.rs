@@ -0,0 +1,350 @@
+//! Member Stored-Credit Repository
+//!
+//! 余额 (member.credit_balance) 与流水 (member_credit_txn) 在同一事务内更新。
+//! 订单支付扣款以 payment_id 关联，唯一索引保证每笔支付最多扣款一次、退回一次。
+
+use super::{RepoError, RepoResult};
+use shared::models::{MemberCreditKind, MemberCreditStatement, MemberCreditTransaction};
+use sqlx::{Sqlite, SqlitePool, Transaction};
+
+const TXN_SELECT: &str = "SELECT id, member_id, kind, amount, balance_after, order_id, payment_id, method, note, operator_id, operator_name, created_at FROM member_credit_txn";
+
+/// 流水写入参数
+struct NewTxn<'a> {
+    member_id: i64,
+    kind: MemberCreditKind,
+    amount: f64,
+    balance_after: f64,
+    order_id: Option<i64>,
+    payment_id: Option<i64>,
+    method: Option<&'a str>,
+    note: Option<&'a str>,
+    operator_id: Option<i64>,
+    operator_name: Option<&'a str>,
+    created_at: i64,
+}
+
+async fn insert_txn(
+    tx: &mut Transaction<'_, Sqlite>,
+    txn: NewTxn<'_>,
+) -> RepoResult<MemberCreditTransaction> {
+    let id = shared::util::snowflake_id();
+    sqlx::query(
+        "INSERT INTO member_credit_txn (id, member_id, kind, amount, balance_after, order_id, payment_id, method, note, operator_id, operator_name, created_at) VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8, ?9, ?10, ?11, ?12)",
+    )
+    .bind(id)
+    .bind(txn.member_id)
+    .bind(txn.kind)
+    .bind(txn.amount)
+    .bind(txn.balance_after)
+    .bind(txn.order_id)
+    .bind(txn.payment_id)
+    .bind(txn.method)
+    .bind(txn.note)
+    .bind(txn.operator_id)
+    .bind(txn.operator_name)
+    .bind(txn.created_at)
+    .execute(&mut **tx)
+    .await?;
+
+    Ok(MemberCreditTransaction {
+        id,
+        member_id: txn.member_id,
+        kind: txn.kind,
+        amount: txn.amount,
+        balance_after: txn.balance_after,
+        order_id: txn.order_id,
+        payment_id: txn.payment_id,
+        method: txn.method.map(String::from),
+        note: txn.note.map(String::from),
+        operator_id: txn.operator_id,
+        operator_name: txn.operator_name.map(String::from),
+        created_at: txn.created_at,
+    })
+}
+
+/// 余额增减（金额按分取整），返回新余额；`require_funds` 时余额不足返回 None
+async fn adjust_balance(
+    tx: &mut Transaction<'_, Sqlite>,
+    member_id: i64,
+    delta: f64,
+    require_funds: bool,
+    now: i64,
+) -> RepoResult<Option<f64>> {
+    let sql = if require_funds {
+        "UPDATE member SET credit_balance = ROUND(credit_balance + ?1, 2), updated_at = ?2 WHERE id = ?3 AND is_active = 1 AND ROUND(credit_balance + ?1, 2) >= 0 RETURNING credit_balance"
+    } else {
+        "UPDATE member SET credit_balance = ROUND(credit_balance + ?1, 2), updated_at = ?2 WHERE id = ?3 RETURNING credit_balance"
+    };
+    let balance: Option<f64> = sqlx::query_scalar(sql)
+        .bind(delta)
+        .bind(now)
+        .bind(member_id)
+        .fetch_optional(&mut **tx)
+        .await?;
+    Ok(balance)
+}
+
+/// 充值
+pub async fn top_up(
+    pool: &SqlitePool,
+    member_id: i64,
+    amount: f64,
+    method: &str,
+    note: Option<&str>,
+    operator_id: i64,
+    operator_name: &str,
+) -> RepoResult<MemberCreditTransaction> {
+    let now = shared::util::now_millis();
+    let mut tx = pool.begin().await?;
+    let balance = sqlx::query_scalar::<_, f64>(
+        "UPDATE member SET credit_balance = ROUND(credit_balance + ?1, 2), updated_at = ?2 WHERE id = ?3 AND is_active = 1 RETURNING credit_balance",
+    )
+    .bind(amount)
+    .bind(now)
+    .bind(member_id)
+    .fetch_optional(&mut *tx)
+    .await?
+    .ok_or_else(|| RepoError::NotFound(format!("Member {member_id} not found")))?;
+
+    let txn = insert_txn(
+        &mut tx,
+        NewTxn {
+            member_id,
+            kind: MemberCreditKind::TopUp,
+            amount,
+            balance_after: balance,
+            order_id: None,
+            payment_id: None,
+            method: Some(method),
+            note,
+            operator_id: Some(operator_id),
+            operator_name: Some(operator_name),
+            created_at: now,
+        },
+    )
+    .await?;
+    tx.commit().await?;
+    Ok(txn)
+}
+
+/// 订单支付扣款（余额不足返回 None，不做任何修改）
+pub async fn debit_for_payment(
+    pool: &SqlitePool,
+    member_id: i64,
+    order_id: i64,
+    payment_id: i64,
+    amount: f64,
+    operator_id: i64,
+    operator_name: &str,
+) -> RepoResult<Option<MemberCreditTransaction>> {
+    let now = shared::util::now_millis();
+    let mut tx = pool.begin().await?;
+    let Some(balance) = adjust_balance(&mut tx, member_id, -amount, true, now).await? else {
+        return Ok(None);
+    };
+
+    let txn = insert_txn(
+        &mut tx,
+        NewTxn {
+            member_id,
+            kind: MemberCreditKind::Payment,
+            amount: -amount,
+            balance_after: balance,
+            order_id: Some(order_id),
+            payment_id: Some(payment_id),
+            method: None,
+            note: None,
+            operator_id: Some(operator_id),
+            operator_name: Some(operator_name),
+            created_at: now,
+        },
+    )
+    .await?;
+    tx.commit().await?;
+    Ok(Some(txn))
+}
+
+/// 退回订单支付扣款（幂等：无扣款记录或已退回时返回 None）
+pub async fn refund_payment(
+    pool: &SqlitePool,
+    payment_id: i64,
+    note: &str,
+    operator_id: Option<i64>,
+    operator_name: Option<&str>,
+) -> RepoResult<Option<MemberCreditTransaction>> {
+    let now = shared::util::now_millis();
+    let mut tx = pool.begin().await?;
+
+    let debit: Option<(i64, f64, Option<i64>)> = sqlx::query_as(
+        "SELECT member_id, amount, order_id FROM member_credit_txn WHERE payment_id = ? AND kind = 'PAYMENT'",
+    )
+    .bind(payment_id)
+    .fetch_optional(&mut *tx)
+    .await?;
+    let Some((member_id, debited, order_id)) = debit else {
+        return Ok(None);
+    };
+    let refunded: bool = sqlx::query_scalar(
+        "SELECT EXISTS(SELECT 1 FROM member_credit_txn WHERE payment_id = ? AND kind = 'REFUND')",
+    )
+    .bind(payment_id)
+    .fetch_one(&mut *tx)
+    .await?;
+    if refunded {
+        return Ok(None);
+    }
+
+    // 已停用的会员同样退回（余额保留，重新启用后可用）
+    let amount = -debited;
+    let balance = adjust_balance(&mut tx, member_id, amount, false, now)
+        .await?
+        .ok_or_else(|| RepoError::NotFound(format!("Member {member_id} not found")))?;
+
+    let txn = insert_txn(
+        &mut tx,
+        NewTxn {
+            member_id,
+            kind: MemberCreditKind::Refund,
+            amount,
+            balance_after: balance,
+            order_id,
+            payment_id: Some(payment_id),
+            method: None,
+            note: Some(note),
+            operator_id,
+            operator_name,
+            created_at: now,
+        },
+    )
+    .await?;
+    tx.commit().await?;
+    Ok(Some(txn))
+}
+
+/// 时间段内的流水 [from, to)
+pub async fn find_transactions(
+    pool: &SqlitePool,
+    member_id: i64,
+    from: i64,
+    to: i64,
+) -> RepoResult<Vec<MemberCreditTransaction>> {
+    let sql = format!(
+        "{TXN_SELECT} WHERE member_id = ?1 AND created_at >= ?2 AND created_at < ?3 ORDER BY created_at, id"
+    );
+    let rows = sqlx::query_as::<_, MemberCreditTransaction>(&sql)
+        .bind(member_id)
+        .bind(from)
+        .bind(to)
+        .fetch_all(pool)
+        .await?;
+    Ok(rows)
+}
+
+/// 对账单：期初余额 + 期间流水 + 期末余额
+pub async fn statement(
+    pool: &SqlitePool,
+    member_id: i64,
+    from: i64,
+    to: i64,
+) -> RepoResult<MemberCreditStatement> {
+    let opening_balance: f64 = sqlx::query_scalar(
+        "SELECT balance_after FROM member_credit_txn WHERE member_id = ?1 AND created_at < ?2 ORDER BY created_at DESC, id DESC LIMIT 1",
+    )
+    .bind(member_id)
+    .bind(from)
+    .fetch_optional(pool)
+    .await?
+    .unwrap_or(0.0);
+
+    let transactions = find_transactions(pool, member_id, from, to).await?;
+    let closing_balance = transactions
+        .last()
+        .map_or(opening_balance, |t| t.balance_after);
+    let sum = |kind: MemberCreditKind| -> f64 {
+        let total: f64 = transactions
+            .iter()
+            .filter(|t| t.kind == kind)
+            .map(|t| t.amount.abs())
+            .sum();
+        (total * 100.0).round() / 100.0
+    };
+
+    Ok(MemberCreditStatement {
+        member_id,
+        from,
+        to,
+        opening_balance,
+        closing_balance,
+        total_top_up: sum(MemberCreditKind::TopUp),
+        total_spent: sum(MemberCreditKind::Payment),
+        total_refunded: sum(MemberCreditKind::Refund),
+        transactions,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::db::test_pool;
+
+    async fn pool_with_member() -> (SqlitePool, i64) {
+        let pool = test_pool().await;
+        sqlx::query("INSERT INTO marketing_group (id, name) VALUES (1, 'vip')")
+            .execute(&pool)
+            .await
+            .unwrap();
+        sqlx::query("INSERT INTO member (id, name, marketing_group_id) VALUES (7, 'Ana', 1)")
+            .execute(&pool)
+            .await
+            .unwrap();
+        (pool, 7)
+    }
+
+    #[tokio::test]
+    async fn test_debit_requires_funds_and_refund_is_idempotent() {
+        let (pool, member_id) = pool_with_member().await;
+        top_up(&pool, member_id, 20.0, "CASH", None, 1, "Admin")
+            .await
+            .unwrap();
+
+        let debit = debit_for_payment(&pool, member_id, 100, 501, 12.5, 1, "Admin")
+            .await
+            .unwrap()
+            .unwrap();
+        assert_eq!(debit.balance_after, 7.5);
+        // Insufficient: balance untouched, no ledger row
+        assert!(
+            debit_for_payment(&pool, member_id, 100, 502, 8.0, 1, "Admin")
+                .await
+                .unwrap()
+                .is_none()
+        );
+
+        let refund = refund_payment(&pool, 501, "void", None, None)
+            .await
+            .unwrap()
+            .unwrap();
+        assert_eq!(refund.balance_after, 20.0);
+        assert!(
+            refund_payment(&pool, 501, "void", None, None)
+                .await
+                .unwrap()
+                .is_none()
+        );
+        // Unknown payment: nothing to refund
+        assert!(
+            refund_payment(&pool, 999, "void", None, None)
+                .await
+                .unwrap()
+                .is_none()
+        );
+
+        let stmt = statement(&pool, member_id, 0, i64::MAX).await.unwrap();
+        assert_eq!(stmt.transactions.len(), 3);
+        assert_eq!(stmt.closing_balance, 20.0);
+        assert_eq!(stmt.total_top_up, 20.0);
+        assert_eq!(stmt.total_spent, 12.5);
+        assert_eq!(stmt.total_refunded, 12.5);
+    }
+}
@@ -0,0 +1,194 @@
+//! Member Loyalty Points Repository
+//!
+//! 余额 (member.points_balance) 与流水 (member_points_txn) 在同一事务内更新。
+//! 以 order_id 关联，唯一索引保证每单最多累积一次、兑换一次（结算可安全重试）。
+
+use super::{RepoError, RepoResult};
+use shared::models::{MemberPointsKind, MemberPointsTransaction};
+use sqlx::{Sqlite, SqlitePool, Transaction};
+
+const TXN_SELECT: &str = "SELECT id, member_id, kind, points, balance_after, order_id, amount, created_at FROM member_points_txn";
+
+async fn already_settled(
+    tx: &mut Transaction<'_, Sqlite>,
+    order_id: i64,
+    kind: MemberPointsKind,
+) -> RepoResult<bool> {
+    let exists: bool = sqlx::query_scalar(
+        "SELECT EXISTS(SELECT 1 FROM member_points_txn WHERE order_id = ?1 AND kind = ?2)",
+    )
+    .bind(order_id)
+    .bind(kind)
+    .fetch_one(&mut **tx)
+    .await?;
+    Ok(exists)
+}
+
+/// 余额增减 + 写流水（余额不低于 0）
+async fn apply_delta(
+    pool: &SqlitePool,
+    member_id: i64,
+    order_id: i64,
+    kind: MemberPointsKind,
+    points: i64,
+    amount: f64,
+) -> RepoResult<Option<MemberPointsTransaction>> {
+    let now = shared::util::now_millis();
+    let mut tx = pool.begin().await?;
+    if already_settled(&mut tx, order_id, kind).await? {
+        return Ok(None);
+    }
+
+    let balance_after = sqlx::query_scalar::<_, i64>(
+        "UPDATE member SET points_balance = MAX(points_balance + ?1, 0), updated_at = ?2 WHERE id = ?3 RETURNING points_balance",
+    )
+    .bind(points)
+    .bind(now)
+    .bind(member_id)
+    .fetch_optional(&mut *tx)
+    .await?
+    .ok_or_else(|| RepoError::NotFound(format!("Member {member_id} not found")))?;
+
+    let id = shared::util::snowflake_id();
+    sqlx::query(
+        "INSERT INTO member_points_txn (id, member_id, kind, points, balance_after, order_id, amount, created_at) VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8)",
+    )
+    .bind(id)
+    .bind(member_id)
+    .bind(kind)
+    .bind(points)
+    .bind(balance_after)
+    .bind(order_id)
+    .bind(amount)
+    .bind(now)
+    .execute(&mut *tx)
+    .await?;
+    tx.commit().await?;
+
+    Ok(Some(MemberPointsTransaction {
+        id,
+        member_id,
+        kind,
+        points,
+        balance_after,
+        order_id: Some(order_id),
+        amount,
+        created_at: now,
+    }))
+}
+
+/// 订单完成累积积分（幂等：已累积时返回 None）
+pub async fn earn_for_order(
+    pool: &SqlitePool,
+    member_id: i64,
+    order_id: i64,
+    points: i64,
+    paid_amount: f64,
+) -> RepoResult<Option<MemberPointsTransaction>> {
+    apply_delta(
+        pool,
+        member_id,
+        order_id,
+        MemberPointsKind::Earn,
+        points,
+        paid_amount,
+    )
+    .await
+}
+
+/// 订单完成扣减已兑换积分（幂等：已扣减时返回 None）
+///
+/// 兑换在下单时按当时余额校验；完成前余额被其他订单消耗时，余额扣至 0 为止。
+pub async fn redeem_for_order(
+    pool: &SqlitePool,
+    member_id: i64,
+    order_id: i64,
+    points: i64,
+    discount_amount: f64,
+) -> RepoResult<Option<MemberPointsTransaction>> {
+    apply_delta(
+        pool,
+        member_id,
+        order_id,
+        MemberPointsKind::Redeem,
+        -points,
+        discount_amount,
+    )
+    .await
+}
+
+/// 最近流水（新 → 旧）
+pub async fn find_recent(
+    pool: &SqlitePool,
+    member_id: i64,
+    limit: i64,
+) -> RepoResult<Vec<MemberPointsTransaction>> {
+    let sql =
+        format!("{TXN_SELECT} WHERE member_id = ?1 ORDER BY created_at DESC, id DESC LIMIT ?2");
+    let rows = sqlx::query_as::<_, MemberPointsTransaction>(&sql)
+        .bind(member_id)
+        .bind(limit)
+        .fetch_all(pool)
+        .await?;
+    Ok(rows)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::db::test_pool;
+
+    async fn pool_with_member() -> (SqlitePool, i64) {
+        let pool = test_pool().await;
+        sqlx::query("INSERT INTO marketing_group (id, name) VALUES (1, 'vip')")
+            .execute(&pool)
+            .await
+            .unwrap();
+        sqlx::query("INSERT INTO member (id, name, marketing_group_id) VALUES (7, 'Ana', 1)")
+            .execute(&pool)
+            .await
+            .unwrap();
+        (pool, 7)
+    }
+
+    #[tokio::test]
+    async fn test_settlement_is_idempotent_per_order() {
+        let (pool, member_id) = pool_with_member().await;
+
+        let earn = earn_for_order(&pool, member_id, 100, 42, 42.5)
+            .await
+            .unwrap()
+            .unwrap();
+        assert_eq!(earn.balance_after, 42);
+        assert!(
+            earn_for_order(&pool, member_id, 100, 42, 42.5)
+                .await
+                .unwrap()
+                .is_none()
+        );
+
+        let redeem = redeem_for_order(&pool, member_id, 101, 30, 3.0)
+            .await
+            .unwrap()
+            .unwrap();
+        assert_eq!(redeem.points, -30);
+        assert_eq!(redeem.balance_after, 12);
+        assert!(
+            redeem_for_order(&pool, member_id, 101, 30, 3.0)
+                .await
+                .unwrap()
+                .is_none()
+        );
+
+        // Balance never goes negative
+        let overdrawn = redeem_for_order(&pool, member_id, 102, 20, 2.0)
+            .await
+            .unwrap()
+            .unwrap();
+        assert_eq!(overdrawn.balance_after, 0);
+
+        let recent = find_recent(&pool, member_id, 10).await.unwrap();
+        assert_eq!(recent.len(), 3);
+        assert_eq!(recent.last().unwrap().kind, MemberPointsKind::Earn);
+    }
+}
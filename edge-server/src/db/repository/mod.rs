@@ -9,11 +9,19 @@ pub mod role;
 
 // Product Domain
 pub mod attribute;
+pub mod catalog_change;
+pub mod eighty_six;
+pub mod inventory;
 pub mod print_destination;
+pub mod product_pairing;
+pub mod stock_count;
+pub mod stock_transfer;
 pub mod tag;
 
 // Location
 pub mod dining_table;
+pub mod table_group;
+pub mod terminal_profile;
 pub mod zone;
 
 // Pricing
@@ -25,13 +33,30 @@ pub mod chain_entry;
 pub mod credit_note;
 pub mod invoice;
 pub mod order;
+pub mod pickup;
+pub mod sales_facts;
 
 // Payments
 pub mod payment;
+pub mod payment_surcharge;
+
+// Delivery (外卖渠道规则)
+pub mod delivery_rule;
+
+// Event Bookings (宴会预订)
+pub mod event_booking;
+
+// Reservations (订位)
+pub mod reservation;
 
 // System
+pub mod customer_display;
+pub mod display_slide;
 pub mod label_template;
 pub mod print_config;
+pub mod receipt_artifact;
+pub mod receipt_footer;
+pub mod setup_step;
 pub mod store_info;
 pub mod system_issue;
 pub mod system_state;
@@ -40,14 +65,25 @@ pub mod system_state;
 pub mod image_ref;
 
 // Marketing & Membership
+pub mod campaign_report;
+pub mod coupon;
 pub mod marketing_group;
 pub mod member;
+pub mod member_credit;
+pub mod member_points;
 pub mod stamp;
 
 // Operations (班次与日结)
+pub mod announcement;
+pub mod cash_denomination;
+pub mod cash_drawer;
 pub mod daily_report;
 pub mod shift;
 
+// Message bus (死信队列 / 关键通知)
+pub mod dead_letter;
+pub mod durable_notification;
+
 use shared::error::{AppError, ErrorCode};
 use thiserror::Error;
 
@@ -5,7 +5,7 @@
 
 use super::{RepoError, RepoResult};
 use sqlx::SqlitePool;
-use std::collections::HashMap;
+use std::collections::{BTreeMap, HashMap};
 
 /// Archived order detail (for API response)
 #[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
@@ -42,6 +42,12 @@ pub struct OrderDetail {
     pub queue_number: Option<i32>,
     pub is_voided: bool,
     pub is_upgraded: bool,
+    /// 从旧 POS 导入的历史订单（无财务凭证）
+    pub is_imported: bool,
+    /// 集成方元数据
+    pub metadata: BTreeMap<String, String>,
+    /// 外部税控设备税控码
+    pub fiscal_code: Option<String>,
     pub items: Vec<OrderDetailItem>,
     pub order_adjustments: Vec<OrderDetailAdjustment>,
     pub payments: Vec<OrderDetailPayment>,
@@ -109,6 +115,10 @@ pub struct OrderDetailPayment {
     pub split_items: Option<String>,
     pub aa_shares: Option<i32>,
     pub aa_total_shares: Option<i32>,
+    pub reference: Option<String>,
+    /// Payment-method surcharge included in `amount`
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub surcharge: Option<f64>,
 }
 
 #[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
@@ -156,6 +166,15 @@ struct OrderRow {
     queue_number: Option<i32>,
     is_voided: bool,
     is_upgraded: bool,
+    is_imported: bool,
+    metadata: Option<String>,
+    fiscal_code: Option<String>,
+}
+
+/// Parse the archived metadata JSON column (NULL / invalid = empty)
+fn parse_metadata(raw: Option<String>) -> BTreeMap<String, String> {
+    raw.and_then(|s| serde_json::from_str(&s).ok())
+        .unwrap_or_default()
 }
 
 #[derive(sqlx::FromRow)]
@@ -173,6 +192,8 @@ struct PaymentRow {
     split_items: Option<String>,
     aa_shares: Option<i32>,
     aa_total_shares: Option<i32>,
+    reference: Option<String>,
+    surcharge: Option<f64>,
 }
 
 #[derive(sqlx::FromRow)]
@@ -212,7 +233,7 @@ struct ItemRow {
 pub async fn get_order_detail(pool: &SqlitePool, order_id: i64) -> RepoResult<OrderDetail> {
     // 1. Get order
     let order: OrderRow = sqlx::query_as::<_, OrderRow>(
-        "SELECT id AS order_id, receipt_number, table_name, zone_name, status, is_retail, guest_count, original_total, total_amount, subtotal, paid_amount, discount_amount, surcharge_amount, comp_total_amount, order_manual_discount_amount, order_manual_surcharge_amount, order_rule_discount_amount, order_rule_surcharge_amount, member_id, member_name, mg_discount_amount, marketing_group_name, start_time, end_time, operator_name, void_type, loss_reason, loss_amount, void_note, queue_number, is_voided, is_upgraded FROM archived_order WHERE id = ?",
+        "SELECT id AS order_id, receipt_number, table_name, zone_name, status, is_retail, guest_count, original_total, total_amount, subtotal, paid_amount, discount_amount, surcharge_amount, comp_total_amount, order_manual_discount_amount, order_manual_surcharge_amount, order_rule_discount_amount, order_rule_surcharge_amount, member_id, member_name, mg_discount_amount, marketing_group_name, start_time, end_time, operator_name, void_type, loss_reason, loss_amount, void_note, queue_number, is_voided, is_upgraded, is_imported, metadata, fiscal_code FROM archived_order WHERE id = ?",
     )
     .bind(order_id)
     .fetch_optional(pool)
@@ -331,7 +352,7 @@ pub async fn get_order_detail(pool: &SqlitePool, order_id: i64) -> RepoResult<Or
 
     // 3. Get payments
     let payments: Vec<OrderDetailPayment> = sqlx::query_as::<_, PaymentRow>(
-        "SELECT seq, payment_id, method, amount, time, cancelled, cancel_reason, tendered, change_amount, split_type, split_items, aa_shares, aa_total_shares FROM archived_order_payment WHERE order_pk = ? ORDER BY seq",
+        "SELECT seq, payment_id, method, amount, time, cancelled, cancel_reason, tendered, change_amount, split_type, split_items, aa_shares, aa_total_shares, reference, surcharge FROM archived_order_payment WHERE order_pk = ? ORDER BY seq",
     )
     .bind(order_id)
     .fetch_all(pool)
@@ -351,6 +372,8 @@ pub async fn get_order_detail(pool: &SqlitePool, order_id: i64) -> RepoResult<Or
         split_items: r.split_items,
         aa_shares: r.aa_shares,
         aa_total_shares: r.aa_total_shares,
+        reference: r.reference,
+        surcharge: r.surcharge,
     })
     .collect();
 
@@ -418,6 +441,9 @@ pub async fn get_order_detail(pool: &SqlitePool, order_id: i64) -> RepoResult<Or
         queue_number: order.queue_number,
         is_voided: order.is_voided,
         is_upgraded: order.is_upgraded,
+        is_imported: order.is_imported,
+        metadata: parse_metadata(order.metadata),
+        fiscal_code: order.fiscal_code,
         items,
         order_adjustments,
         payments,
@@ -509,6 +535,7 @@ pub async fn build_order_detail_sync(
         customer_address: Option<String>,
         customer_email: Option<String>,
         customer_phone: Option<String>,
+        metadata: Option<String>,
     }
 
     let order: SyncOrderRow = sqlx::query_as::<_, SyncOrderRow>(
@@ -520,7 +547,8 @@ pub async fn build_order_detail_sync(
          ao.operator_id, ao.operator_name, ao.void_type, ao.loss_reason, ao.loss_amount, ao.void_note, \
          ao.member_id, ao.member_name, ao.service_type, ao.queue_number, ao.shift_id, ao.cloud_synced, \
          ao.is_voided, ao.is_upgraded, \
-         ao.customer_nif, ao.customer_nombre, ao.customer_address, ao.customer_email, ao.customer_phone \
+         ao.customer_nif, ao.customer_nombre, ao.customer_address, ao.customer_email, ao.customer_phone, \
+         ao.metadata \
          FROM archived_order ao \
          JOIN chain_entry ce ON ce.entry_type = 'ORDER' AND ce.entry_pk = ao.id \
          WHERE ao.id = ?",
@@ -768,7 +796,7 @@ pub async fn build_order_detail_sync(
     .collect();
 
     // 4. Aggregate desglose from items (GROUP BY tax_rate) using rust_decimal
-    use crate::order_money::to_decimal;
+    use crab_order_core::money::to_decimal;
     use rust_decimal::Decimal;
 
     let mut desglose_map: HashMap<i32, (Decimal, Decimal)> = HashMap::new();
@@ -893,6 +921,7 @@ pub async fn build_order_detail_sync(
             customer_address: order.customer_address,
             customer_email: order.customer_email,
             customer_phone: order.customer_phone,
+            metadata: parse_metadata(order.metadata),
         },
     })
 }
@@ -981,7 +1010,7 @@ pub async fn cleanup_synced_order_details(
     Ok(total)
 }
 
-/// Archived event row for ITEMS_ADDED events (for kitchen reprint fallback)
+/// Archived event row for ITEMS_ADDED / COURSE_FIRED events (for kitchen reprint fallback)
 #[derive(Debug, sqlx::FromRow)]
 pub struct ArchivedItemsAddedEvent {
     pub event_id: i64,
@@ -1000,7 +1029,7 @@ pub struct ArchivedOrderMeta {
     pub queue_number: Option<i32>,
 }
 
-/// Get ITEMS_ADDED events for an archived order by order_id (snowflake i64)
+/// Get ITEMS_ADDED / COURSE_FIRED events for an archived order by order_id (snowflake i64)
 pub async fn get_items_added_events_by_order_id(
     pool: &SqlitePool,
     order_id: i64,
@@ -1022,10 +1051,10 @@ pub async fn get_items_added_events_by_order_id(
         .fetch_one(pool)
         .await?;
 
-    // 2. Get ITEMS_ADDED events
+    // 2. Get ITEMS_ADDED / COURSE_FIRED events (每个事件对应一张厨房单)
     let events = sqlx::query_as::<_, ArchivedItemsAddedEvent>(
         "SELECT id as event_id, timestamp, data FROM archived_order_event \
-         WHERE order_pk = ? AND event_type = 'ITEMS_ADDED' ORDER BY seq",
+         WHERE order_pk = ? AND event_type IN ('ITEMS_ADDED', 'COURSE_FIRED') ORDER BY seq",
     )
     .bind(order_pk)
     .fetch_all(pool)
@@ -0,0 +1,221 @@
+//! Payment Surcharge Rule Repository (支付方式附加费)
+
+use super::{RepoError, RepoResult};
+use shared::models::{
+    AdjustmentType, PaymentSurchargeRule, PaymentSurchargeRuleCreate, PaymentSurchargeRuleUpdate,
+};
+use sqlx::SqlitePool;
+
+const SELECT_COLUMNS: &str = "SELECT id, payment_method, adjustment_type, value, min_ticket, is_active, created_at, updated_at FROM payment_surcharge_rule";
+
+pub async fn find_all(pool: &SqlitePool) -> RepoResult<Vec<PaymentSurchargeRule>> {
+    let rules = sqlx::query_as::<_, PaymentSurchargeRule>(&format!(
+        "{SELECT_COLUMNS} ORDER BY payment_method"
+    ))
+    .fetch_all(pool)
+    .await?;
+    Ok(rules)
+}
+
+pub async fn find_by_id(pool: &SqlitePool, id: i64) -> RepoResult<Option<PaymentSurchargeRule>> {
+    let rule = sqlx::query_as::<_, PaymentSurchargeRule>(&format!("{SELECT_COLUMNS} WHERE id = ?"))
+        .bind(id)
+        .fetch_optional(pool)
+        .await?;
+    Ok(rule)
+}
+
+/// Active rule for a payment method, only when the store toggle is on
+pub async fn find_applicable(
+    pool: &SqlitePool,
+    payment_method: &str,
+) -> RepoResult<Option<PaymentSurchargeRule>> {
+    let rule = sqlx::query_as::<_, PaymentSurchargeRule>(
+        "SELECT r.id, r.payment_method, r.adjustment_type, r.value, r.min_ticket, r.is_active, r.created_at, r.updated_at FROM payment_surcharge_rule r JOIN store_info s ON s.id = 1 WHERE s.payment_surcharge_enabled = 1 AND r.is_active = 1 AND r.payment_method = ?",
+    )
+    .bind(payment_method)
+    .fetch_optional(pool)
+    .await?;
+    Ok(rule)
+}
+
+fn validate_value(adjustment_type: &AdjustmentType, value: f64) -> RepoResult<()> {
+    if !value.is_finite() || value <= 0.0 {
+        return Err(RepoError::Validation(
+            "Surcharge value must be a positive number".into(),
+        ));
+    }
+    if *adjustment_type == AdjustmentType::Percentage && value > 100.0 {
+        return Err(RepoError::Validation(
+            "Surcharge percentage cannot exceed 100".into(),
+        ));
+    }
+    Ok(())
+}
+
+fn validate_min_ticket(min_ticket: Option<f64>) -> RepoResult<()> {
+    if let Some(min) = min_ticket
+        && (!min.is_finite() || min < 0.0)
+    {
+        return Err(RepoError::Validation(
+            "min_ticket must be a non-negative number".into(),
+        ));
+    }
+    Ok(())
+}
+
+pub async fn create(
+    pool: &SqlitePool,
+    data: &PaymentSurchargeRuleCreate,
+) -> RepoResult<PaymentSurchargeRule> {
+    let method = data.payment_method.trim();
+    if method.is_empty() {
+        return Err(RepoError::Validation("payment_method is required".into()));
+    }
+    validate_value(&data.adjustment_type, data.value)?;
+    validate_min_ticket(data.min_ticket)?;
+
+    let exists: Option<i64> =
+        sqlx::query_scalar("SELECT id FROM payment_surcharge_rule WHERE payment_method = ?")
+            .bind(method)
+            .fetch_optional(pool)
+            .await?;
+    if exists.is_some() {
+        return Err(RepoError::Duplicate(format!(
+            "A surcharge rule for {method} already exists"
+        )));
+    }
+
+    let id = shared::util::snowflake_id();
+    let now = shared::util::now_millis();
+    sqlx::query(
+        "INSERT INTO payment_surcharge_rule (id, payment_method, adjustment_type, value, min_ticket, is_active, created_at, updated_at) VALUES (?, ?, ?, ?, ?, 1, ?, ?)",
+    )
+    .bind(id)
+    .bind(method)
+    .bind(&data.adjustment_type)
+    .bind(data.value)
+    .bind(data.min_ticket)
+    .bind(now)
+    .bind(now)
+    .execute(pool)
+    .await?;
+
+    find_by_id(pool, id)
+        .await?
+        .ok_or_else(|| RepoError::Database("Failed to create surcharge rule".into()))
+}
+
+pub async fn update(
+    pool: &SqlitePool,
+    id: i64,
+    data: &PaymentSurchargeRuleUpdate,
+) -> RepoResult<PaymentSurchargeRule> {
+    let existing = find_by_id(pool, id)
+        .await?
+        .ok_or_else(|| RepoError::NotFound(format!("Surcharge rule {id} not found")))?;
+    let adjustment_type = data
+        .adjustment_type
+        .clone()
+        .unwrap_or(existing.adjustment_type);
+    validate_value(&adjustment_type, data.value.unwrap_or(existing.value))?;
+    validate_min_ticket(data.min_ticket)?;
+    let min_ticket = if data.clear_min_ticket {
+        None
+    } else {
+        data.min_ticket.or(existing.min_ticket)
+    };
+
+    sqlx::query(
+        "UPDATE payment_surcharge_rule SET adjustment_type = ?1, value = COALESCE(?2, value), min_ticket = ?3, is_active = COALESCE(?4, is_active), updated_at = ?5 WHERE id = ?6",
+    )
+    .bind(&adjustment_type)
+    .bind(data.value)
+    .bind(min_ticket)
+    .bind(data.is_active)
+    .bind(shared::util::now_millis())
+    .bind(id)
+    .execute(pool)
+    .await?;
+
+    find_by_id(pool, id)
+        .await?
+        .ok_or_else(|| RepoError::NotFound(format!("Surcharge rule {id} not found")))
+}
+
+pub async fn delete(pool: &SqlitePool, id: i64) -> RepoResult<()> {
+    let rows = sqlx::query("DELETE FROM payment_surcharge_rule WHERE id = ?")
+        .bind(id)
+        .execute(pool)
+        .await?;
+    if rows.rows_affected() == 0 {
+        return Err(RepoError::NotFound(format!(
+            "Surcharge rule {id} not found"
+        )));
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::db::test_pool;
+
+    #[tokio::test]
+    async fn test_rule_applies_only_when_store_toggle_on() {
+        let pool = test_pool().await;
+        let rule = create(
+            &pool,
+            &PaymentSurchargeRuleCreate {
+                payment_method: "CARD".to_string(),
+                adjustment_type: AdjustmentType::Percentage,
+                value: 1.5,
+                min_ticket: Some(10.0),
+            },
+        )
+        .await
+        .unwrap();
+        assert!(find_applicable(&pool, "CARD").await.unwrap().is_none());
+
+        sqlx::query("UPDATE store_info SET payment_surcharge_enabled = 1 WHERE id = 1")
+            .execute(&pool)
+            .await
+            .unwrap();
+        assert_eq!(
+            find_applicable(&pool, "CARD").await.unwrap().unwrap().id,
+            rule.id
+        );
+        assert!(find_applicable(&pool, "CASH").await.unwrap().is_none());
+
+        // 一个支付方式只能有一条规则
+        assert!(matches!(
+            create(
+                &pool,
+                &PaymentSurchargeRuleCreate {
+                    payment_method: "CARD".to_string(),
+                    adjustment_type: AdjustmentType::FixedAmount,
+                    value: 0.3,
+                    min_ticket: None,
+                },
+            )
+            .await,
+            Err(RepoError::Duplicate(_))
+        ));
+
+        let updated = update(
+            &pool,
+            rule.id,
+            &PaymentSurchargeRuleUpdate {
+                adjustment_type: None,
+                value: None,
+                min_ticket: None,
+                clear_min_ticket: true,
+                is_active: Some(false),
+            },
+        )
+        .await
+        .unwrap();
+        assert!(updated.min_ticket.is_none());
+        assert!(find_applicable(&pool, "CARD").await.unwrap().is_none());
+    }
+}
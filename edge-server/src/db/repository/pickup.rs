@@ -0,0 +1,299 @@
+//! Pickup Ticket Repository (外卖取餐单)
+
+use super::{RepoError, RepoResult};
+use rand::Rng;
+use shared::models::{DeliveryReport, DeliveryStatus, NotifyChannel, PickupStatus, PickupTicket};
+use sqlx::SqlitePool;
+
+/// 取餐码字符集 (去掉易混淆的 0/O/1/I/L)
+const CODE_ALPHABET: &[u8] = b"ABCDEFGHJKMNPQRSTUVWXYZ23456789";
+const CODE_LEN: usize = 6;
+/// 取餐码碰撞重试次数
+const CODE_ATTEMPTS: usize = 5;
+
+const PICKUP_SELECT: &str = "SELECT id, order_id, receipt_number, queue_number, pickup_code, phone, channel, status, delivery_status, attempts, gateway_message_id, last_error, created_at, ready_at, notified_at, delivered_at, collected_at, updated_at FROM pickup_ticket";
+
+/// Random pickup code (公开查询凭证，不可按序猜测)
+pub fn generate_pickup_code() -> String {
+    let mut rng = rand::thread_rng();
+    (0..CODE_LEN)
+        .map(|_| CODE_ALPHABET[rng.gen_range(0..CODE_ALPHABET.len())] as char)
+        .collect()
+}
+
+pub async fn find_by_id(pool: &SqlitePool, id: i64) -> RepoResult<Option<PickupTicket>> {
+    let ticket = sqlx::query_as::<_, PickupTicket>(&format!("{PICKUP_SELECT} WHERE id = ?"))
+        .bind(id)
+        .fetch_optional(pool)
+        .await?;
+    Ok(ticket)
+}
+
+pub async fn find_by_order(pool: &SqlitePool, order_id: i64) -> RepoResult<Option<PickupTicket>> {
+    let ticket = sqlx::query_as::<_, PickupTicket>(&format!("{PICKUP_SELECT} WHERE order_id = ?"))
+        .bind(order_id)
+        .fetch_optional(pool)
+        .await?;
+    Ok(ticket)
+}
+
+/// Lookup by pickup code (case-insensitive, customers type it by hand)
+pub async fn find_by_code(pool: &SqlitePool, code: &str) -> RepoResult<Option<PickupTicket>> {
+    let ticket =
+        sqlx::query_as::<_, PickupTicket>(&format!("{PICKUP_SELECT} WHERE pickup_code = ?"))
+            .bind(code.trim().to_ascii_uppercase())
+            .fetch_optional(pool)
+            .await?;
+    Ok(ticket)
+}
+
+/// Archived order info for a takeaway ticket: (receipt_number, queue_number, is_takeaway)
+pub async fn find_archived_order(
+    pool: &SqlitePool,
+    order_id: i64,
+) -> RepoResult<Option<(String, Option<i64>, bool)>> {
+    let row = sqlx::query_as::<_, (String, Option<i64>, bool)>(
+        "SELECT receipt_number, queue_number, (is_retail = 1 OR service_type = 'TAKEOUT') FROM archived_order WHERE id = ?",
+    )
+    .bind(order_id)
+    .fetch_optional(pool)
+    .await?;
+    Ok(row)
+}
+
+/// Tickets not yet collected (pickup board), oldest first
+pub async fn find_active(pool: &SqlitePool) -> RepoResult<Vec<PickupTicket>> {
+    let tickets = sqlx::query_as::<_, PickupTicket>(&format!(
+        "{PICKUP_SELECT} WHERE status != 'COLLECTED' ORDER BY created_at, id"
+    ))
+    .fetch_all(pool)
+    .await?;
+    Ok(tickets)
+}
+
+pub async fn create(
+    pool: &SqlitePool,
+    order_id: i64,
+    receipt_number: &str,
+    queue_number: Option<i64>,
+    phone: Option<&str>,
+    channel: NotifyChannel,
+) -> RepoResult<PickupTicket> {
+    if find_by_order(pool, order_id).await?.is_some() {
+        return Err(RepoError::Duplicate(format!(
+            "Order {order_id} already has a pickup ticket"
+        )));
+    }
+    let id = shared::util::snowflake_id();
+    let now = shared::util::now_millis();
+
+    let mut attempt = 0;
+    loop {
+        attempt += 1;
+        let result = sqlx::query(
+            "INSERT INTO pickup_ticket (id, order_id, receipt_number, queue_number, pickup_code, phone, channel, status, attempts, created_at, updated_at) VALUES (?, ?, ?, ?, ?, ?, ?, 'WAITING', 0, ?, ?)",
+        )
+        .bind(id)
+        .bind(order_id)
+        .bind(receipt_number)
+        .bind(queue_number)
+        .bind(generate_pickup_code())
+        .bind(phone)
+        .bind(channel)
+        .bind(now)
+        .bind(now)
+        .execute(pool)
+        .await;
+        match result {
+            Ok(_) => break,
+            Err(e) => match RepoError::from(e) {
+                RepoError::Duplicate(msg)
+                    if msg.contains("pickup_code") && attempt < CODE_ATTEMPTS =>
+                {
+                    continue;
+                }
+                err => return Err(err),
+            },
+        }
+    }
+
+    find_by_id(pool, id)
+        .await?
+        .ok_or_else(|| RepoError::Database("Failed to create pickup ticket".into()))
+}
+
+/// WAITING → READY; the flag is true only for the call that made the transition
+pub async fn mark_ready(pool: &SqlitePool, id: i64) -> RepoResult<(PickupTicket, bool)> {
+    let ticket = find_by_id(pool, id)
+        .await?
+        .ok_or_else(|| RepoError::NotFound(format!("Pickup ticket {id} not found")))?;
+    if ticket.status == PickupStatus::Collected {
+        return Err(RepoError::Validation(format!(
+            "Pickup ticket {id} was already collected"
+        )));
+    }
+    let now = shared::util::now_millis();
+    let rows = sqlx::query(
+        "UPDATE pickup_ticket SET status = 'READY', ready_at = ?1, updated_at = ?1 WHERE id = ?2 AND status = 'WAITING'",
+    )
+    .bind(now)
+    .bind(id)
+    .execute(pool)
+    .await?;
+    let ticket = find_by_id(pool, id)
+        .await?
+        .ok_or_else(|| RepoError::NotFound(format!("Pickup ticket {id} not found")))?;
+    Ok((ticket, rows.rows_affected() == 1))
+}
+
+pub async fn mark_collected(pool: &SqlitePool, id: i64) -> RepoResult<PickupTicket> {
+    let now = shared::util::now_millis();
+    let rows = sqlx::query(
+        "UPDATE pickup_ticket SET status = 'COLLECTED', collected_at = COALESCE(collected_at, ?1), updated_at = ?1 WHERE id = ?2",
+    )
+    .bind(now)
+    .bind(id)
+    .execute(pool)
+    .await?;
+    if rows.rows_affected() == 0 {
+        return Err(RepoError::NotFound(format!("Pickup ticket {id} not found")));
+    }
+    find_by_id(pool, id)
+        .await?
+        .ok_or_else(|| RepoError::NotFound(format!("Pickup ticket {id} not found")))
+}
+
+/// Record a send attempt (message_id on success, error on failure)
+pub async fn record_send(
+    pool: &SqlitePool,
+    id: i64,
+    result: Result<&str, &str>,
+) -> RepoResult<PickupTicket> {
+    let now = shared::util::now_millis();
+    let (status, message_id, error) = match result {
+        Ok(message_id) => (DeliveryStatus::Sent, Some(message_id), None),
+        Err(error) => (DeliveryStatus::Failed, None, Some(error)),
+    };
+    sqlx::query(
+        "UPDATE pickup_ticket SET delivery_status = ?1, gateway_message_id = COALESCE(?2, gateway_message_id), last_error = ?3, attempts = attempts + 1, notified_at = CASE WHEN ?2 IS NULL THEN notified_at ELSE ?4 END, delivered_at = NULL, updated_at = ?4 WHERE id = ?5",
+    )
+    .bind(status)
+    .bind(message_id)
+    .bind(error)
+    .bind(now)
+    .bind(id)
+    .execute(pool)
+    .await?;
+    find_by_id(pool, id)
+        .await?
+        .ok_or_else(|| RepoError::NotFound(format!("Pickup ticket {id} not found")))
+}
+
+/// Apply a gateway delivery report (unknown message ids are ignored → None)
+pub async fn apply_delivery_report(
+    pool: &SqlitePool,
+    report: &DeliveryReport,
+) -> RepoResult<Option<PickupTicket>> {
+    let now = shared::util::now_millis();
+    let delivered_at = (report.status == DeliveryStatus::Delivered).then_some(now);
+    let id = sqlx::query_scalar::<_, i64>(
+        "UPDATE pickup_ticket SET delivery_status = ?1, delivered_at = COALESCE(?2, delivered_at), last_error = ?3, updated_at = ?4 WHERE gateway_message_id = ?5 RETURNING id",
+    )
+    .bind(report.status)
+    .bind(delivered_at)
+    .bind(report.error.as_deref())
+    .bind(now)
+    .bind(&report.message_id)
+    .fetch_optional(pool)
+    .await?;
+    match id {
+        Some(id) => find_by_id(pool, id).await,
+        None => Ok(None),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::db::test_pool;
+
+    #[test]
+    fn test_generate_pickup_code() {
+        let code = generate_pickup_code();
+        assert_eq!(code.len(), CODE_LEN);
+        assert!(code.bytes().all(|b| CODE_ALPHABET.contains(&b)));
+    }
+
+    #[tokio::test]
+    async fn test_pickup_lifecycle_and_delivery_report() {
+        let pool = test_pool().await;
+        let ticket = create(
+            &pool,
+            7,
+            "FAC-0007",
+            Some(12),
+            Some("+34600111222"),
+            NotifyChannel::Whatsapp,
+        )
+        .await
+        .unwrap();
+        assert_eq!(ticket.status, PickupStatus::Waiting);
+        assert_eq!(ticket.delivery_status, None);
+        assert!(matches!(
+            create(&pool, 7, "FAC-0007", None, None, NotifyChannel::Sms).await,
+            Err(RepoError::Duplicate(_))
+        ));
+
+        let found = find_by_code(&pool, &ticket.pickup_code.to_ascii_lowercase())
+            .await
+            .unwrap()
+            .unwrap();
+        assert_eq!(found.id, ticket.id);
+
+        let (ready, transitioned) = mark_ready(&pool, ticket.id).await.unwrap();
+        assert!(transitioned);
+        assert_eq!(ready.status, PickupStatus::Ready);
+        assert!(ready.ready_at.is_some());
+        let (again, transitioned) = mark_ready(&pool, ticket.id).await.unwrap();
+        assert!(!transitioned);
+        assert_eq!(again.ready_at, ready.ready_at);
+
+        let failed = record_send(&pool, ticket.id, Err("timeout")).await.unwrap();
+        assert_eq!(failed.delivery_status, Some(DeliveryStatus::Failed));
+        assert_eq!(failed.attempts, 1);
+        let sent = record_send(&pool, ticket.id, Ok("msg-1")).await.unwrap();
+        assert_eq!(sent.delivery_status, Some(DeliveryStatus::Sent));
+        assert_eq!(sent.last_error, None);
+        assert_eq!(sent.attempts, 2);
+
+        let report = DeliveryReport {
+            message_id: "msg-1".to_string(),
+            status: DeliveryStatus::Delivered,
+            error: None,
+        };
+        let delivered = apply_delivery_report(&pool, &report)
+            .await
+            .unwrap()
+            .unwrap();
+        assert_eq!(delivered.delivery_status, Some(DeliveryStatus::Delivered));
+        assert!(delivered.delivered_at.is_some());
+        let unknown = DeliveryReport {
+            message_id: "msg-x".to_string(),
+            ..report
+        };
+        assert!(
+            apply_delivery_report(&pool, &unknown)
+                .await
+                .unwrap()
+                .is_none()
+        );
+
+        let collected = mark_collected(&pool, ticket.id).await.unwrap();
+        assert_eq!(collected.status, PickupStatus::Collected);
+        assert!(find_active(&pool).await.unwrap().is_empty());
+        assert!(matches!(
+            mark_ready(&pool, ticket.id).await,
+            Err(RepoError::Validation(_))
+        ));
+    }
+}
@@ -6,7 +6,7 @@ use sqlx::SqlitePool;
 
 pub async fn find_all_with_inactive(pool: &SqlitePool) -> RepoResult<Vec<PriceRule>> {
     let rules = sqlx::query_as::<_, PriceRule>(
-        "SELECT id, name, receipt_name, description, rule_type, product_scope, target_id, zone_scope, adjustment_type, adjustment_value, is_stackable, is_exclusive, valid_from, valid_until, COALESCE(active_days, 'null') as active_days, active_start_time, active_end_time, is_active, created_by, created_at FROM price_rule ORDER BY created_at DESC",
+        "SELECT id, name, receipt_name, description, rule_type, product_scope, target_id, zone_scope, adjustment_type, adjustment_value, is_stackable, is_exclusive, priority, valid_from, valid_until, COALESCE(active_days, 'null') as active_days, active_start_time, active_end_time, is_active, created_by, created_at FROM price_rule ORDER BY created_at DESC",
     )
     .fetch_all(pool)
     .await?;
@@ -15,7 +15,7 @@ pub async fn find_all_with_inactive(pool: &SqlitePool) -> RepoResult<Vec<PriceRu
 
 pub async fn find_all(pool: &SqlitePool) -> RepoResult<Vec<PriceRule>> {
     let rules = sqlx::query_as::<_, PriceRule>(
-        "SELECT id, name, receipt_name, description, rule_type, product_scope, target_id, zone_scope, adjustment_type, adjustment_value, is_stackable, is_exclusive, valid_from, valid_until, COALESCE(active_days, 'null') as active_days, active_start_time, active_end_time, is_active, created_by, created_at FROM price_rule WHERE is_active = 1 ORDER BY created_at DESC",
+        "SELECT id, name, receipt_name, description, rule_type, product_scope, target_id, zone_scope, adjustment_type, adjustment_value, is_stackable, is_exclusive, priority, valid_from, valid_until, COALESCE(active_days, 'null') as active_days, active_start_time, active_end_time, is_active, created_by, created_at FROM price_rule WHERE is_active = 1 ORDER BY created_at DESC",
     )
     .fetch_all(pool)
     .await?;
@@ -30,7 +30,7 @@ pub async fn find_by_zone(
 ) -> RepoResult<Vec<PriceRule>> {
     let zone_id_str = zone_id.map(|id| id.to_string()).unwrap_or_default();
     let rules = sqlx::query_as::<_, PriceRule>(
-        "SELECT id, name, receipt_name, description, rule_type, product_scope, target_id, zone_scope, adjustment_type, adjustment_value, is_stackable, is_exclusive, valid_from, valid_until, COALESCE(active_days, 'null') as active_days, active_start_time, active_end_time, is_active, created_by, created_at FROM price_rule WHERE is_active = 1 AND (zone_scope = 'all' OR (zone_scope = 'retail' AND ?1 = 1) OR zone_scope = ?2) ORDER BY created_at DESC",
+        "SELECT id, name, receipt_name, description, rule_type, product_scope, target_id, zone_scope, adjustment_type, adjustment_value, is_stackable, is_exclusive, priority, valid_from, valid_until, COALESCE(active_days, 'null') as active_days, active_start_time, active_end_time, is_active, created_by, created_at FROM price_rule WHERE is_active = 1 AND (zone_scope = 'all' OR (zone_scope = 'retail' AND ?1 = 1) OR zone_scope = ?2) ORDER BY created_at DESC",
     )
     .bind(is_retail)
     .bind(&zone_id_str)
@@ -41,7 +41,7 @@ pub async fn find_by_zone(
 
 pub async fn find_by_scope(pool: &SqlitePool, scope: ProductScope) -> RepoResult<Vec<PriceRule>> {
     let rules = sqlx::query_as::<_, PriceRule>(
-        "SELECT id, name, receipt_name, description, rule_type, product_scope, target_id, zone_scope, adjustment_type, adjustment_value, is_stackable, is_exclusive, valid_from, valid_until, COALESCE(active_days, 'null') as active_days, active_start_time, active_end_time, is_active, created_by, created_at FROM price_rule WHERE is_active = 1 AND product_scope = ? ORDER BY created_at DESC",
+        "SELECT id, name, receipt_name, description, rule_type, product_scope, target_id, zone_scope, adjustment_type, adjustment_value, is_stackable, is_exclusive, priority, valid_from, valid_until, COALESCE(active_days, 'null') as active_days, active_start_time, active_end_time, is_active, created_by, created_at FROM price_rule WHERE is_active = 1 AND product_scope = ? ORDER BY created_at DESC",
     )
     .bind(scope)
     .fetch_all(pool)
@@ -51,7 +51,7 @@ pub async fn find_by_scope(pool: &SqlitePool, scope: ProductScope) -> RepoResult
 
 pub async fn find_by_id(pool: &SqlitePool, id: i64) -> RepoResult<Option<PriceRule>> {
     let rule = sqlx::query_as::<_, PriceRule>(
-        "SELECT id, name, receipt_name, description, rule_type, product_scope, target_id, zone_scope, adjustment_type, adjustment_value, is_stackable, is_exclusive, valid_from, valid_until, COALESCE(active_days, 'null') as active_days, active_start_time, active_end_time, is_active, created_by, created_at FROM price_rule WHERE id = ?",
+        "SELECT id, name, receipt_name, description, rule_type, product_scope, target_id, zone_scope, adjustment_type, adjustment_value, is_stackable, is_exclusive, priority, valid_from, valid_until, COALESCE(active_days, 'null') as active_days, active_start_time, active_end_time, is_active, created_by, created_at FROM price_rule WHERE id = ?",
     )
     .bind(id)
     .fetch_optional(pool)
@@ -61,7 +61,7 @@ pub async fn find_by_id(pool: &SqlitePool, id: i64) -> RepoResult<Option<PriceRu
 
 pub async fn find_by_name(pool: &SqlitePool, name: &str) -> RepoResult<Option<PriceRule>> {
     let rule = sqlx::query_as::<_, PriceRule>(
-        "SELECT id, name, receipt_name, description, rule_type, product_scope, target_id, zone_scope, adjustment_type, adjustment_value, is_stackable, is_exclusive, valid_from, valid_until, COALESCE(active_days, 'null') as active_days, active_start_time, active_end_time, is_active, created_by, created_at FROM price_rule WHERE name = ? LIMIT 1",
+        "SELECT id, name, receipt_name, description, rule_type, product_scope, target_id, zone_scope, adjustment_type, adjustment_value, is_stackable, is_exclusive, priority, valid_from, valid_until, COALESCE(active_days, 'null') as active_days, active_start_time, active_end_time, is_active, created_by, created_at FROM price_rule WHERE name = ? LIMIT 1",
     )
     .bind(name)
     .fetch_optional(pool)
@@ -85,9 +85,10 @@ pub async fn create(
 
     let is_stackable = data.is_stackable.unwrap_or(true);
     let is_exclusive = data.is_exclusive.unwrap_or(false);
+    let priority = data.priority.unwrap_or(0);
     let id = assigned_id.unwrap_or_else(shared::util::snowflake_id);
     sqlx::query(
-        "INSERT INTO price_rule (id, name, receipt_name, description, rule_type, product_scope, target_id, zone_scope, adjustment_type, adjustment_value, is_stackable, is_exclusive, valid_from, valid_until, active_days, active_start_time, active_end_time, is_active, created_by, created_at, updated_at) VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8, ?9, ?10, ?11, ?12, ?13, ?14, ?15, ?16, ?17, 1, ?18, ?19, ?20)",
+        "INSERT INTO price_rule (id, name, receipt_name, description, rule_type, product_scope, target_id, zone_scope, adjustment_type, adjustment_value, is_stackable, is_exclusive, priority, valid_from, valid_until, active_days, active_start_time, active_end_time, is_active, created_by, created_at, updated_at) VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8, ?9, ?10, ?11, ?12, ?13, ?14, ?15, ?16, ?17, ?18, 1, ?19, ?20, ?21)",
     )
     .bind(id)
     .bind(&data.name)
@@ -101,6 +102,7 @@ pub async fn create(
     .bind(data.adjustment_value)
     .bind(is_stackable)
     .bind(is_exclusive)
+    .bind(priority)
     .bind(data.valid_from)
     .bind(data.valid_until)
     .bind(&active_days_json)
@@ -124,28 +126,29 @@ pub async fn update(pool: &SqlitePool, id: i64, data: PriceRuleUpdate) -> RepoRe
         .map(|v| serde_json::to_string(v).unwrap_or_else(|_| "[]".to_string()));
 
     let now = shared::util::now_millis();
-    let rows = sqlx::query!(
-        "UPDATE price_rule SET name = COALESCE(?1, name), receipt_name = COALESCE(?2, receipt_name), description = COALESCE(?3, description), rule_type = COALESCE(?4, rule_type), product_scope = COALESCE(?5, product_scope), target_id = COALESCE(?6, target_id), zone_scope = COALESCE(?7, zone_scope), adjustment_type = COALESCE(?8, adjustment_type), adjustment_value = COALESCE(?9, adjustment_value), is_stackable = COALESCE(?10, is_stackable), is_exclusive = COALESCE(?11, is_exclusive), valid_from = COALESCE(?12, valid_from), valid_until = COALESCE(?13, valid_until), active_days = COALESCE(?14, active_days), active_start_time = COALESCE(?15, active_start_time), active_end_time = COALESCE(?16, active_end_time), is_active = COALESCE(?17, is_active), updated_at = ?18 WHERE id = ?19",
-        data.name,
-        data.receipt_name,
-        data.description,
-        data.rule_type,
-        data.product_scope,
-        data.target_id,
-        data.zone_scope,
-        data.adjustment_type,
-        data.adjustment_value,
-        data.is_stackable,
-        data.is_exclusive,
-        data.valid_from,
-        data.valid_until,
-        active_days_json,
-        data.active_start_time,
-        data.active_end_time,
-        data.is_active,
-        now,
-        id
+    let rows = sqlx::query(
+        "UPDATE price_rule SET name = COALESCE(?1, name), receipt_name = COALESCE(?2, receipt_name), description = COALESCE(?3, description), rule_type = COALESCE(?4, rule_type), product_scope = COALESCE(?5, product_scope), target_id = COALESCE(?6, target_id), zone_scope = COALESCE(?7, zone_scope), adjustment_type = COALESCE(?8, adjustment_type), adjustment_value = COALESCE(?9, adjustment_value), is_stackable = COALESCE(?10, is_stackable), is_exclusive = COALESCE(?11, is_exclusive), valid_from = COALESCE(?12, valid_from), valid_until = COALESCE(?13, valid_until), active_days = COALESCE(?14, active_days), active_start_time = COALESCE(?15, active_start_time), active_end_time = COALESCE(?16, active_end_time), is_active = COALESCE(?17, is_active), priority = COALESCE(?18, priority), updated_at = ?19 WHERE id = ?20",
     )
+    .bind(&data.name)
+    .bind(&data.receipt_name)
+    .bind(&data.description)
+    .bind(&data.rule_type)
+    .bind(&data.product_scope)
+    .bind(data.target_id)
+    .bind(&data.zone_scope)
+    .bind(&data.adjustment_type)
+    .bind(data.adjustment_value)
+    .bind(data.is_stackable)
+    .bind(data.is_exclusive)
+    .bind(data.valid_from)
+    .bind(data.valid_until)
+    .bind(&active_days_json)
+    .bind(&data.active_start_time)
+    .bind(&data.active_end_time)
+    .bind(data.is_active)
+    .bind(data.priority)
+    .bind(now)
+    .bind(id)
     .execute(pool)
     .await?;
 
@@ -0,0 +1,192 @@
+//! Product Pairing Repository (关联推荐)
+//!
+//! 从归档订单挖掘商品两两共现 (同一订单)，每个商品保留置信度最高的 N 个搭配。
+
+use super::RepoResult;
+use shared::models::UpsellSuggestion;
+use sqlx::SqlitePool;
+
+/// Replace all pairings with those mined from orders completed since `since`
+///
+/// 只统计非赠送行；`min_support` 过滤偶然共现，每个商品保留 `top_n` 个。
+/// Returns the number of pairings stored.
+pub async fn recompute(
+    pool: &SqlitePool,
+    since: i64,
+    now: i64,
+    min_support: i64,
+    top_n: i64,
+) -> RepoResult<u64> {
+    let mut tx = pool.begin().await?;
+    sqlx::query("DELETE FROM product_pairing")
+        .execute(&mut *tx)
+        .await?;
+    let rows = sqlx::query(
+        "WITH basket AS ( \
+             SELECT DISTINCT i.order_pk, CAST(i.spec AS INTEGER) AS product_id \
+             FROM archived_order_item i JOIN archived_order o ON o.id = i.order_pk \
+             WHERE o.status = 'COMPLETED' AND o.is_voided = 0 AND o.end_time >= ?1 AND i.is_comped = 0 \
+         ), \
+         total AS (SELECT COUNT(DISTINCT order_pk) AS n FROM basket), \
+         freq AS (SELECT product_id, COUNT(*) AS cnt FROM basket GROUP BY product_id), \
+         pairs AS ( \
+             SELECT a.product_id, b.product_id AS paired_product_id, COUNT(*) AS support \
+             FROM basket a JOIN basket b ON a.order_pk = b.order_pk AND a.product_id != b.product_id \
+             GROUP BY a.product_id, b.product_id HAVING COUNT(*) >= ?2 \
+         ), \
+         scored AS ( \
+             SELECT p.product_id, p.paired_product_id, p.support, \
+                    CAST(p.support AS REAL) / fa.cnt AS confidence, \
+                    (CAST(p.support AS REAL) / fa.cnt) / (CAST(fb.cnt AS REAL) / total.n) AS lift \
+             FROM pairs p \
+             JOIN freq fa ON fa.product_id = p.product_id \
+             JOIN freq fb ON fb.product_id = p.paired_product_id, total \
+         ) \
+         INSERT INTO product_pairing (product_id, paired_product_id, support, confidence, lift, computed_at) \
+         SELECT product_id, paired_product_id, support, confidence, lift, ?4 FROM ( \
+             SELECT *, ROW_NUMBER() OVER ( \
+                 PARTITION BY product_id ORDER BY confidence DESC, support DESC, paired_product_id \
+             ) AS pair_rank FROM scored \
+         ) WHERE pair_rank <= ?3",
+    )
+    .bind(since)
+    .bind(min_support)
+    .bind(top_n)
+    .bind(now)
+    .execute(&mut *tx)
+    .await?;
+    tx.commit().await?;
+    Ok(rows.rows_affected())
+}
+
+/// When the pairings were last computed (None = never)
+pub async fn last_computed_at(pool: &SqlitePool) -> RepoResult<Option<i64>> {
+    let at = sqlx::query_scalar::<_, Option<i64>>("SELECT MAX(computed_at) FROM product_pairing")
+        .fetch_one(pool)
+        .await?;
+    Ok(at)
+}
+
+/// Upsell suggestions for a product: active and not 86'd at `now`, best first
+pub async fn suggestions(
+    pool: &SqlitePool,
+    product_id: i64,
+    now: i64,
+    limit: i64,
+) -> RepoResult<Vec<UpsellSuggestion>> {
+    let rows = sqlx::query_as::<_, UpsellSuggestion>(
+        "SELECT p.id AS product_id, p.name, p.image, \
+         COALESCE((SELECT s.price FROM product_spec s WHERE s.product_id = p.id AND s.is_active = 1 \
+                   ORDER BY s.is_default DESC, s.display_order LIMIT 1), 0.0) AS price, \
+         pp.support, pp.confidence, pp.lift \
+         FROM product_pairing pp JOIN product p ON p.id = pp.paired_product_id \
+         WHERE pp.product_id = ?1 AND p.is_active = 1 \
+           AND NOT EXISTS ( \
+               SELECT 1 FROM eighty_six e WHERE e.product_id = p.id AND e.spec_id IS NULL \
+               AND e.cleared_at IS NULL AND (e.expires_at IS NULL OR e.expires_at > ?2) \
+           ) \
+         ORDER BY pp.confidence DESC, pp.support DESC LIMIT ?3",
+    )
+    .bind(product_id)
+    .bind(now)
+    .bind(limit)
+    .fetch_all(pool)
+    .await?;
+    Ok(rows)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::db::test_pool;
+
+    async fn insert_order(pool: &SqlitePool, id: i64, end_time: i64, products: &[i64]) {
+        sqlx::query(
+            "INSERT INTO archived_order (id, receipt_number, status, start_time, end_time, created_at) \
+             VALUES (?1, ?2, 'COMPLETED', ?3, ?3, ?3)",
+        )
+        .bind(id)
+        .bind(format!("R-{id}"))
+        .bind(end_time)
+        .execute(pool)
+        .await
+        .unwrap();
+        for (n, product_id) in products.iter().enumerate() {
+            sqlx::query(
+                "INSERT INTO archived_order_item (id, order_pk, spec, instance_id, name) VALUES (?1, ?2, ?3, ?4, 'x')",
+            )
+            .bind(id * 100 + n as i64)
+            .bind(id)
+            .bind(product_id)
+            .bind(format!("i-{n}"))
+            .execute(pool)
+            .await
+            .unwrap();
+        }
+    }
+
+    #[tokio::test]
+    async fn test_recompute_and_suggestions() {
+        let pool = test_pool().await;
+        sqlx::query("INSERT INTO category (id, name) VALUES (1, 'Food')")
+            .execute(&pool)
+            .await
+            .unwrap();
+        for (id, name) in [(1, "Burger"), (2, "Fries"), (3, "Cola"), (4, "Salad")] {
+            sqlx::query("INSERT INTO product (id, name, category_id) VALUES (?, ?, 1)")
+                .bind(id)
+                .bind(name)
+                .execute(&pool)
+                .await
+                .unwrap();
+        }
+        sqlx::query("INSERT INTO product_spec (id, product_id, name, price, is_default) VALUES (20, 2, 'M', 2.5, 1)")
+            .execute(&pool)
+            .await
+            .unwrap();
+
+        // Burger+Fries ×3, Burger+Cola ×2, Salad alone; one old order ignored
+        insert_order(&pool, 1, 100, &[1, 2, 3]).await;
+        insert_order(&pool, 2, 110, &[1, 2]).await;
+        insert_order(&pool, 3, 120, &[1, 2, 3]).await;
+        insert_order(&pool, 4, 130, &[4]).await;
+        insert_order(&pool, 5, 10, &[1, 4]).await;
+
+        let stored = recompute(&pool, 50, 1_000, 2, 5).await.unwrap();
+        // 1→2, 1→3, 2→1, 2→3, 3→1, 3→2
+        assert_eq!(stored, 6);
+        assert_eq!(last_computed_at(&pool).await.unwrap(), Some(1_000));
+
+        let burger = suggestions(&pool, 1, 2_000, 3).await.unwrap();
+        let names: Vec<_> = burger.iter().map(|s| s.name.as_str()).collect();
+        assert_eq!(names, vec!["Fries", "Cola"]);
+        assert_eq!(burger[0].support, 3);
+        assert_eq!(burger[0].confidence, 1.0);
+        assert_eq!(burger[0].price, 2.5);
+        // Fries in 3 of 4 orders → lift = 1.0 / 0.75
+        assert!((burger[0].lift - 4.0 / 3.0).abs() < 1e-9);
+        assert!(suggestions(&pool, 4, 2_000, 3).await.unwrap().is_empty());
+
+        // 86'd products are not suggested
+        sqlx::query(
+            "INSERT INTO eighty_six (id, product_id, product_name, created_by_id, created_by_name, created_at) \
+             VALUES (1, 2, 'Fries', 1, 'admin', 1500)",
+        )
+        .execute(&pool)
+        .await
+        .unwrap();
+        let burger = suggestions(&pool, 1, 2_000, 3).await.unwrap();
+        assert_eq!(burger.len(), 1);
+        assert_eq!(burger[0].name, "Cola");
+
+        // Top-N and replacement on recompute
+        recompute(&pool, 50, 3_000, 2, 1).await.unwrap();
+        assert_eq!(last_computed_at(&pool).await.unwrap(), Some(3_000));
+        let count: i64 =
+            sqlx::query_scalar("SELECT COUNT(*) FROM product_pairing WHERE product_id = 1")
+                .fetch_one(&pool)
+                .await
+                .unwrap();
+        assert_eq!(count, 1);
+    }
+}
@@ -0,0 +1,148 @@
+//! Receipt Artifact Repository (收据存档索引)
+//!
+//! 只追加：没有更新/删除，唯一可变的是 `cloud_synced` 同步标记。
+
+use super::{RepoError, RepoResult};
+use shared::models::{ReceiptArtifact, ReceiptArtifactKind};
+use sqlx::SqlitePool;
+
+const SELECT_COLUMNS: &str = "SELECT id, receipt_number, kind, sha256, size_bytes, reprint, created_at, cloud_synced FROM receipt_artifact";
+
+pub async fn find_by_id(pool: &SqlitePool, id: i64) -> RepoResult<Option<ReceiptArtifact>> {
+    let artifact = sqlx::query_as::<_, ReceiptArtifact>(&format!("{SELECT_COLUMNS} WHERE id = ?"))
+        .bind(id)
+        .fetch_optional(pool)
+        .await?;
+    Ok(artifact)
+}
+
+/// Artifacts of a receipt, including its split receipts (`{receipt_number}-N`)
+pub async fn find_by_receipt(
+    pool: &SqlitePool,
+    receipt_number: &str,
+) -> RepoResult<Vec<ReceiptArtifact>> {
+    let artifacts = sqlx::query_as::<_, ReceiptArtifact>(&format!(
+        "{SELECT_COLUMNS} WHERE receipt_number = ?1 OR receipt_number LIKE ?1 || '-%' \
+         ORDER BY created_at, id"
+    ))
+    .bind(receipt_number)
+    .fetch_all(pool)
+    .await?;
+    Ok(artifacts)
+}
+
+/// Record a stored artifact; returns the existing row when the same content
+/// was already archived for this receipt (idempotent upload retries)
+pub async fn insert(
+    pool: &SqlitePool,
+    receipt_number: &str,
+    kind: ReceiptArtifactKind,
+    sha256: &str,
+    size_bytes: i64,
+    reprint: bool,
+) -> RepoResult<ReceiptArtifact> {
+    let existing = sqlx::query_as::<_, ReceiptArtifact>(&format!(
+        "{SELECT_COLUMNS} WHERE receipt_number = ? AND kind = ? AND sha256 = ?"
+    ))
+    .bind(receipt_number)
+    .bind(kind.as_str())
+    .bind(sha256)
+    .fetch_optional(pool)
+    .await?;
+    if let Some(artifact) = existing {
+        return Ok(artifact);
+    }
+
+    let id = shared::util::snowflake_id();
+    sqlx::query(
+        "INSERT INTO receipt_artifact (id, receipt_number, kind, sha256, size_bytes, reprint, created_at) VALUES (?, ?, ?, ?, ?, ?, ?)",
+    )
+    .bind(id)
+    .bind(receipt_number)
+    .bind(kind.as_str())
+    .bind(sha256)
+    .bind(size_bytes)
+    .bind(reprint)
+    .bind(shared::util::now_millis())
+    .execute(pool)
+    .await?;
+
+    find_by_id(pool, id)
+        .await?
+        .ok_or_else(|| RepoError::Database("Failed to create receipt artifact".into()))
+}
+
+pub async fn list_unsynced(pool: &SqlitePool, limit: i64) -> RepoResult<Vec<ReceiptArtifact>> {
+    let artifacts = sqlx::query_as::<_, ReceiptArtifact>(&format!(
+        "{SELECT_COLUMNS} WHERE cloud_synced = 0 ORDER BY id LIMIT ?"
+    ))
+    .bind(limit)
+    .fetch_all(pool)
+    .await?;
+    Ok(artifacts)
+}
+
+pub async fn mark_synced(pool: &SqlitePool, ids: &[i64]) -> RepoResult<()> {
+    if ids.is_empty() {
+        return Ok(());
+    }
+    let placeholders = ids.iter().map(|_| "?").collect::<Vec<_>>().join(",");
+    let sql = format!("UPDATE receipt_artifact SET cloud_synced = 1 WHERE id IN ({placeholders})");
+    let mut query = sqlx::query(&sql);
+    for id in ids {
+        query = query.bind(id);
+    }
+    query.execute(pool).await?;
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::db::test_pool;
+
+    #[tokio::test]
+    async fn test_insert_is_idempotent_per_content() {
+        let pool = test_pool().await;
+        let first = insert(&pool, "R0001", ReceiptArtifactKind::EscPos, "aa", 10, false)
+            .await
+            .unwrap();
+        let again = insert(&pool, "R0001", ReceiptArtifactKind::EscPos, "aa", 10, false)
+            .await
+            .unwrap();
+        assert_eq!(first.id, again.id);
+
+        // 重打 (不同内容) 与分单收据都归在同一单号下
+        insert(&pool, "R0001", ReceiptArtifactKind::EscPos, "bb", 12, true)
+            .await
+            .unwrap();
+        insert(
+            &pool,
+            "R0001-2",
+            ReceiptArtifactKind::EscPos,
+            "cc",
+            8,
+            false,
+        )
+        .await
+        .unwrap();
+        insert(&pool, "R00011", ReceiptArtifactKind::EscPos, "dd", 8, false)
+            .await
+            .unwrap();
+        let artifacts = find_by_receipt(&pool, "R0001").await.unwrap();
+        assert_eq!(artifacts.len(), 3);
+        assert!(artifacts.iter().find(|a| a.sha256 == "bb").unwrap().reprint);
+
+        let unsynced = list_unsynced(&pool, 10).await.unwrap();
+        assert_eq!(unsynced.len(), 4);
+        mark_synced(&pool, &[first.id]).await.unwrap();
+        assert_eq!(list_unsynced(&pool, 10).await.unwrap().len(), 3);
+        assert!(
+            find_by_id(&pool, first.id)
+                .await
+                .unwrap()
+                .unwrap()
+                .cloud_synced
+        );
+    }
+}
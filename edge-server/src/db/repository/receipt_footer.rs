@@ -0,0 +1,97 @@
+//! Receipt Footer Repository
+
+use super::{RepoError, RepoResult};
+use shared::models::{ReceiptFooter, ReceiptFooterCreate, ReceiptFooterUpdate};
+use sqlx::SqlitePool;
+
+const SELECT_COLUMNS: &str = "SELECT id, name, lines, survey_url, COALESCE(active_days, 'null') AS active_days, sort_order, is_active, created_at, updated_at FROM receipt_footer";
+
+pub async fn find_all(pool: &SqlitePool) -> RepoResult<Vec<ReceiptFooter>> {
+    let footers =
+        sqlx::query_as::<_, ReceiptFooter>(&format!("{SELECT_COLUMNS} ORDER BY sort_order, id"))
+            .fetch_all(pool)
+            .await?;
+    Ok(footers)
+}
+
+pub async fn find_active(pool: &SqlitePool) -> RepoResult<Vec<ReceiptFooter>> {
+    let footers = sqlx::query_as::<_, ReceiptFooter>(&format!(
+        "{SELECT_COLUMNS} WHERE is_active = 1 ORDER BY sort_order, id"
+    ))
+    .fetch_all(pool)
+    .await?;
+    Ok(footers)
+}
+
+pub async fn find_by_id(pool: &SqlitePool, id: i64) -> RepoResult<Option<ReceiptFooter>> {
+    let footer = sqlx::query_as::<_, ReceiptFooter>(&format!("{SELECT_COLUMNS} WHERE id = ?"))
+        .bind(id)
+        .fetch_optional(pool)
+        .await?;
+    Ok(footer)
+}
+
+pub async fn create(pool: &SqlitePool, data: ReceiptFooterCreate) -> RepoResult<ReceiptFooter> {
+    let id = shared::util::snowflake_id();
+    let now = shared::util::now_millis();
+    let active_days_json = data
+        .active_days
+        .as_ref()
+        .map(|v| serde_json::to_string(v).unwrap_or_else(|_| "[]".to_string()));
+    sqlx::query(
+        "INSERT INTO receipt_footer (id, name, lines, survey_url, active_days, sort_order, is_active, created_at, updated_at) VALUES (?1, ?2, ?3, ?4, ?5, ?6, 1, ?7, ?7)",
+    )
+    .bind(id)
+    .bind(&data.name)
+    .bind(&data.lines)
+    .bind(&data.survey_url)
+    .bind(&active_days_json)
+    .bind(data.sort_order.unwrap_or(0))
+    .bind(now)
+    .execute(pool)
+    .await?;
+    find_by_id(pool, id)
+        .await?
+        .ok_or_else(|| RepoError::Database("Failed to create receipt footer".into()))
+}
+
+pub async fn update(
+    pool: &SqlitePool,
+    id: i64,
+    data: ReceiptFooterUpdate,
+) -> RepoResult<ReceiptFooter> {
+    let now = shared::util::now_millis();
+    let active_days_json = data
+        .active_days
+        .as_ref()
+        .map(|v| serde_json::to_string(v).unwrap_or_else(|_| "[]".to_string()));
+    let rows = sqlx::query(
+        "UPDATE receipt_footer SET name = COALESCE(?1, name), lines = COALESCE(?2, lines), survey_url = COALESCE(?3, survey_url), active_days = COALESCE(?4, active_days), sort_order = COALESCE(?5, sort_order), is_active = COALESCE(?6, is_active), updated_at = ?7 WHERE id = ?8",
+    )
+    .bind(&data.name)
+    .bind(&data.lines)
+    .bind(&data.survey_url)
+    .bind(&active_days_json)
+    .bind(data.sort_order)
+    .bind(data.is_active)
+    .bind(now)
+    .bind(id)
+    .execute(pool)
+    .await?;
+    if rows.rows_affected() == 0 {
+        return Err(RepoError::NotFound(format!(
+            "Receipt footer {id} not found"
+        )));
+    }
+    find_by_id(pool, id)
+        .await?
+        .ok_or_else(|| RepoError::NotFound(format!("Receipt footer {id} not found")))
+}
+
+pub async fn delete(pool: &SqlitePool, id: i64) -> RepoResult<bool> {
+    let rows = sqlx::query("DELETE FROM receipt_footer WHERE id = ?")
+        .bind(id)
+        .execute(pool)
+        .await?;
+    Ok(rows.rows_affected() > 0)
+}
@@ -0,0 +1,487 @@
+//! Reservation Repository
+//!
+//! 订位的增改与状态流转。桌台 / 区域在写入前解析并做冲突检测，
+//! 检测与写入在同一事务内完成。
+
+use super::{RepoError, RepoResult, dining_table, zone};
+use shared::error::ErrorCode;
+use shared::models::{
+    DEFAULT_RESERVATION_MINUTES, Reservation, ReservationCreate, ReservationStatus,
+    ReservationUpdate,
+};
+use sqlx::{SqliteConnection, SqlitePool};
+
+const RESERVATION_SELECT: &str = "SELECT id, customer_name, customer_phone, customer_email, party_size, start_at, end_at, zone_id, zone_name, table_id, table_name, note, status, seated_at, created_by_id, created_by_name, created_at, updated_at FROM reservation";
+
+const MINUTE_MS: i64 = 60 * 1000;
+
+/// Resolved zone / table of a reservation (names snapshotted for display)
+#[derive(Debug, Default, Clone, PartialEq)]
+struct Placement {
+    zone_id: Option<i64>,
+    zone_name: Option<String>,
+    table_id: Option<i64>,
+    table_name: Option<String>,
+}
+
+fn not_found(id: i64) -> RepoError {
+    RepoError::Business(
+        ErrorCode::ReservationNotFound,
+        format!("Reservation {id} not found"),
+    )
+}
+
+fn invalid_state(reservation: &Reservation, action: &str) -> RepoError {
+    RepoError::Business(
+        ErrorCode::ReservationInvalidState,
+        format!(
+            "Cannot {action} reservation {} in status {:?}",
+            reservation.id, reservation.status
+        ),
+    )
+}
+
+/// Reservations overlapping [from, to), optionally filtered by status / zone
+pub async fn find_range(
+    pool: &SqlitePool,
+    from: i64,
+    to: i64,
+    status: Option<ReservationStatus>,
+    zone_id: Option<i64>,
+) -> RepoResult<Vec<Reservation>> {
+    let sql = format!(
+        "{RESERVATION_SELECT} WHERE start_at < ?2 AND end_at > ?1 AND (?3 IS NULL OR status = ?3) AND (?4 IS NULL OR zone_id = ?4) ORDER BY start_at, id"
+    );
+    let rows = sqlx::query_as::<_, Reservation>(&sql)
+        .bind(from)
+        .bind(to)
+        .bind(status)
+        .bind(zone_id)
+        .fetch_all(pool)
+        .await?;
+    Ok(rows)
+}
+
+pub async fn find_by_id(pool: &SqlitePool, id: i64) -> RepoResult<Option<Reservation>> {
+    let reservation =
+        sqlx::query_as::<_, Reservation>(&format!("{RESERVATION_SELECT} WHERE id = ?"))
+            .bind(id)
+            .fetch_optional(pool)
+            .await?;
+    Ok(reservation)
+}
+
+async fn require(pool: &SqlitePool, id: i64) -> RepoResult<Reservation> {
+    find_by_id(pool, id).await?.ok_or_else(|| not_found(id))
+}
+
+/// Resolve zone / table ids (a table implies its zone)
+async fn resolve_placement(
+    pool: &SqlitePool,
+    zone_id: Option<i64>,
+    table_id: Option<i64>,
+    party_size: i32,
+) -> RepoResult<Placement> {
+    let mut placement = Placement::default();
+
+    let zone_id = match table_id {
+        Some(table_id) => {
+            let table = dining_table::find_by_id(pool, table_id)
+                .await?
+                .filter(|t| t.is_active)
+                .ok_or_else(|| {
+                    RepoError::Business(
+                        ErrorCode::TableNotFound,
+                        format!("Table {table_id} not found"),
+                    )
+                })?;
+            if zone_id.is_some_and(|z| z != table.zone_id) {
+                return Err(RepoError::Validation(format!(
+                    "Table {} is not in zone {}",
+                    table.name,
+                    zone_id.unwrap_or_default()
+                )));
+            }
+            if table.capacity > 0 && party_size > table.capacity {
+                return Err(RepoError::Validation(format!(
+                    "Party of {party_size} exceeds the capacity of table {} ({})",
+                    table.name, table.capacity
+                )));
+            }
+            placement.table_id = Some(table.id);
+            placement.table_name = Some(table.name);
+            Some(table.zone_id)
+        }
+        None => zone_id,
+    };
+
+    if let Some(zone_id) = zone_id {
+        let zone = zone::find_by_id(pool, zone_id)
+            .await?
+            .filter(|z| z.is_active)
+            .ok_or_else(|| {
+                RepoError::Business(ErrorCode::ZoneNotFound, format!("Zone {zone_id} not found"))
+            })?;
+        placement.zone_id = Some(zone.id);
+        placement.zone_name = Some(zone.name);
+    }
+    Ok(placement)
+}
+
+/// Reject a slot that collides with other active reservations
+///
+/// - 桌台：同桌有效订位时段重叠即冲突
+/// - 仅区域：重叠订位人数之和超过区域内桌台总座位数即冲突（保守估计，不计算峰值）
+async fn check_conflicts(
+    conn: &mut SqliteConnection,
+    placement: &Placement,
+    party_size: i32,
+    start_at: i64,
+    end_at: i64,
+    exclude_id: i64,
+) -> RepoResult<()> {
+    if let Some(table_id) = placement.table_id {
+        let clash: Option<(i64, String)> = sqlx::query_as(
+            "SELECT id, customer_name FROM reservation WHERE table_id = ?1 AND id != ?2 AND status IN ('BOOKED', 'SEATED') AND start_at < ?4 AND end_at > ?3 LIMIT 1",
+        )
+        .bind(table_id)
+        .bind(exclude_id)
+        .bind(start_at)
+        .bind(end_at)
+        .fetch_optional(&mut *conn)
+        .await?;
+        if let Some((id, customer)) = clash {
+            return Err(RepoError::Business(
+                ErrorCode::ReservationConflict,
+                format!(
+                    "Table {} is already reserved for {customer} (reservation {id})",
+                    placement.table_name.as_deref().unwrap_or_default()
+                ),
+            ));
+        }
+        return Ok(());
+    }
+
+    let Some(zone_id) = placement.zone_id else {
+        return Ok(());
+    };
+    let seats: i64 = sqlx::query_scalar(
+        "SELECT COALESCE(SUM(capacity), 0) FROM dining_table WHERE zone_id = ? AND is_active = 1",
+    )
+    .bind(zone_id)
+    .fetch_one(&mut *conn)
+    .await?;
+    // 区域未配置座位数时不做容量校验
+    if seats <= 0 {
+        return Ok(());
+    }
+    let reserved: i64 = sqlx::query_scalar(
+        "SELECT COALESCE(SUM(party_size), 0) FROM reservation WHERE zone_id = ?1 AND id != ?2 AND status IN ('BOOKED', 'SEATED') AND start_at < ?4 AND end_at > ?3",
+    )
+    .bind(zone_id)
+    .bind(exclude_id)
+    .bind(start_at)
+    .bind(end_at)
+    .fetch_one(&mut *conn)
+    .await?;
+    if reserved + party_size as i64 > seats {
+        return Err(RepoError::Business(
+            ErrorCode::ReservationConflict,
+            format!(
+                "Zone {} has {reserved} of {seats} seats reserved in this time slot",
+                placement.zone_name.as_deref().unwrap_or_default()
+            ),
+        ));
+    }
+    Ok(())
+}
+
+pub async fn create(
+    pool: &SqlitePool,
+    data: &ReservationCreate,
+    operator_id: i64,
+    operator_name: &str,
+) -> RepoResult<Reservation> {
+    let placement = resolve_placement(pool, data.zone_id, data.table_id, data.party_size).await?;
+    let minutes = data.duration_minutes.unwrap_or(DEFAULT_RESERVATION_MINUTES);
+    let end_at = data.start_at + minutes as i64 * MINUTE_MS;
+
+    let id = shared::util::snowflake_id();
+    let now = shared::util::now_millis();
+    let mut tx = pool.begin().await?;
+    check_conflicts(
+        &mut tx,
+        &placement,
+        data.party_size,
+        data.start_at,
+        end_at,
+        id,
+    )
+    .await?;
+    sqlx::query(
+        "INSERT INTO reservation (id, customer_name, customer_phone, customer_email, party_size, start_at, end_at, zone_id, zone_name, table_id, table_name, note, status, created_by_id, created_by_name, created_at, updated_at) VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8, ?9, ?10, ?11, ?12, ?13, ?14, ?15, ?16, ?16)",
+    )
+    .bind(id)
+    .bind(&data.customer_name)
+    .bind(&data.customer_phone)
+    .bind(&data.customer_email)
+    .bind(data.party_size)
+    .bind(data.start_at)
+    .bind(end_at)
+    .bind(placement.zone_id)
+    .bind(&placement.zone_name)
+    .bind(placement.table_id)
+    .bind(&placement.table_name)
+    .bind(&data.note)
+    .bind(ReservationStatus::Booked)
+    .bind(operator_id)
+    .bind(operator_name)
+    .bind(now)
+    .execute(&mut *tx)
+    .await?;
+    tx.commit().await?;
+
+    require(pool, id).await
+}
+
+/// Amend a booked reservation; returns (before, after)
+pub async fn update(
+    pool: &SqlitePool,
+    id: i64,
+    data: &ReservationUpdate,
+) -> RepoResult<(Reservation, Reservation)> {
+    let current = require(pool, id).await?;
+    if current.status != ReservationStatus::Booked {
+        return Err(invalid_state(&current, "amend"));
+    }
+
+    let party_size = data.party_size.unwrap_or(current.party_size);
+    let start_at = data.start_at.unwrap_or(current.start_at);
+    let end_at = match data.duration_minutes {
+        Some(minutes) => start_at + minutes as i64 * MINUTE_MS,
+        None => start_at + (current.end_at - current.start_at),
+    };
+    // 换区域时原桌台失效（除非同时指定新桌台）
+    let zone_changed = data.zone_id.is_some() && data.zone_id != current.zone_id;
+    let table_id = if data.clear_table {
+        None
+    } else if data.table_id.is_some() {
+        data.table_id
+    } else if zone_changed {
+        None
+    } else {
+        current.table_id
+    };
+    let zone_id = data.zone_id.or(current.zone_id);
+    let placement = resolve_placement(pool, zone_id, table_id, party_size).await?;
+
+    let mut tx = pool.begin().await?;
+    check_conflicts(&mut tx, &placement, party_size, start_at, end_at, id).await?;
+    let rows = sqlx::query(
+        "UPDATE reservation SET customer_name = COALESCE(?1, customer_name), customer_phone = COALESCE(?2, customer_phone), customer_email = COALESCE(?3, customer_email), party_size = ?4, start_at = ?5, end_at = ?6, zone_id = ?7, zone_name = ?8, table_id = ?9, table_name = ?10, note = COALESCE(?11, note), updated_at = ?12 WHERE id = ?13 AND status = 'BOOKED'",
+    )
+    .bind(&data.customer_name)
+    .bind(&data.customer_phone)
+    .bind(&data.customer_email)
+    .bind(party_size)
+    .bind(start_at)
+    .bind(end_at)
+    .bind(placement.zone_id)
+    .bind(&placement.zone_name)
+    .bind(placement.table_id)
+    .bind(&placement.table_name)
+    .bind(&data.note)
+    .bind(shared::util::now_millis())
+    .bind(id)
+    .execute(&mut *tx)
+    .await?;
+    if rows.rows_affected() == 0 {
+        return Err(invalid_state(&current, "amend"));
+    }
+    tx.commit().await?;
+
+    Ok((current, require(pool, id).await?))
+}
+
+/// Status transition (see [`ReservationStatus::can_transition_to`])
+pub async fn transition(
+    pool: &SqlitePool,
+    id: i64,
+    to: ReservationStatus,
+) -> RepoResult<Reservation> {
+    let current = require(pool, id).await?;
+    if !current.status.can_transition_to(to) {
+        return Err(invalid_state(&current, &format!("move to {to:?}")));
+    }
+    let now = shared::util::now_millis();
+    let seated_at = (to == ReservationStatus::Seated).then_some(now);
+    let rows = sqlx::query(
+        "UPDATE reservation SET status = ?1, seated_at = COALESCE(?2, seated_at), updated_at = ?3 WHERE id = ?4 AND status = ?5",
+    )
+    .bind(to)
+    .bind(seated_at)
+    .bind(now)
+    .bind(id)
+    .bind(current.status)
+    .execute(pool)
+    .await?;
+    if rows.rows_affected() == 0 {
+        return Err(invalid_state(&current, &format!("move to {to:?}")));
+    }
+    require(pool, id).await
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::db::test_pool;
+
+    const HOUR: i64 = 60 * MINUTE_MS;
+
+    async fn seed_zone(pool: &SqlitePool, capacities: &[i32]) -> (i64, Vec<i64>) {
+        let zone_id = shared::util::snowflake_id();
+        sqlx::query("INSERT INTO zone (id, name, is_active) VALUES (?, 'Terraza', 1)")
+            .bind(zone_id)
+            .execute(pool)
+            .await
+            .unwrap();
+        let mut tables = Vec::new();
+        for (i, capacity) in capacities.iter().enumerate() {
+            let id = shared::util::snowflake_id();
+            sqlx::query(
+                "INSERT INTO dining_table (id, name, zone_id, capacity, is_active) VALUES (?, ?, ?, ?, 1)",
+            )
+            .bind(id)
+            .bind(format!("T{}", i + 1))
+            .bind(zone_id)
+            .bind(capacity)
+            .execute(pool)
+            .await
+            .unwrap();
+            tables.push(id);
+        }
+        (zone_id, tables)
+    }
+
+    fn payload(start_at: i64, party_size: i32) -> ReservationCreate {
+        ReservationCreate {
+            customer_name: "Elena".to_string(),
+            customer_phone: Some("600000000".to_string()),
+            customer_email: None,
+            party_size,
+            start_at,
+            duration_minutes: None,
+            zone_id: None,
+            table_id: None,
+            note: None,
+        }
+    }
+
+    #[tokio::test]
+    async fn test_table_conflicts() {
+        let pool = test_pool().await;
+        let (zone_id, tables) = seed_zone(&pool, &[4, 4]).await;
+
+        let mut first = payload(10 * HOUR, 2);
+        first.table_id = Some(tables[0]);
+        let booked = create(&pool, &first, 1, "Admin").await.unwrap();
+        assert_eq!(booked.zone_id, Some(zone_id));
+        assert_eq!(booked.table_name.as_deref(), Some("T1"));
+        assert_eq!(booked.end_at, 10 * HOUR + 90 * MINUTE_MS);
+
+        // Overlapping slot on the same table
+        let mut clash = payload(11 * HOUR, 2);
+        clash.table_id = Some(tables[0]);
+        let err = create(&pool, &clash, 1, "Admin").await.unwrap_err();
+        assert!(matches!(
+            err,
+            RepoError::Business(ErrorCode::ReservationConflict, _)
+        ));
+
+        // Back-to-back slot and another table are fine
+        clash.start_at = booked.end_at;
+        create(&pool, &clash, 1, "Admin").await.unwrap();
+        let mut other = payload(11 * HOUR, 2);
+        other.table_id = Some(tables[1]);
+        create(&pool, &other, 1, "Admin").await.unwrap();
+
+        // Party larger than the table
+        let mut big = payload(20 * HOUR, 6);
+        big.table_id = Some(tables[1]);
+        assert!(matches!(
+            create(&pool, &big, 1, "Admin").await,
+            Err(RepoError::Validation(_))
+        ));
+
+        // Cancelling frees the table
+        transition(&pool, booked.id, ReservationStatus::Cancelled)
+            .await
+            .unwrap();
+        let mut retry = payload(10 * HOUR, 2);
+        retry.table_id = Some(tables[0]);
+        let moved = create(&pool, &retry, 1, "Admin").await.unwrap();
+
+        // Moving it back onto an occupied slot is rejected
+        let update_to = ReservationUpdate {
+            start_at: Some(booked.end_at),
+            ..Default::default()
+        };
+        assert!(update(&pool, moved.id, &update_to).await.is_err());
+    }
+
+    #[tokio::test]
+    async fn test_zone_capacity() {
+        let pool = test_pool().await;
+        let (zone_id, _) = seed_zone(&pool, &[4, 2]).await;
+
+        let mut first = payload(10 * HOUR, 4);
+        first.zone_id = Some(zone_id);
+        create(&pool, &first, 1, "Admin").await.unwrap();
+
+        let mut second = payload(10 * HOUR, 3);
+        second.zone_id = Some(zone_id);
+        let err = create(&pool, &second, 1, "Admin").await.unwrap_err();
+        assert!(matches!(
+            err,
+            RepoError::Business(ErrorCode::ReservationConflict, _)
+        ));
+
+        second.party_size = 2;
+        create(&pool, &second, 1, "Admin").await.unwrap();
+        assert_eq!(
+            find_range(&pool, 0, 24 * HOUR, None, Some(zone_id))
+                .await
+                .unwrap()
+                .len(),
+            2
+        );
+    }
+
+    #[tokio::test]
+    async fn test_status_flow() {
+        let pool = test_pool().await;
+        let booked = create(&pool, &payload(10 * HOUR, 2), 1, "Admin")
+            .await
+            .unwrap();
+
+        let seated = transition(&pool, booked.id, ReservationStatus::Seated)
+            .await
+            .unwrap();
+        assert_eq!(seated.status, ReservationStatus::Seated);
+        assert!(seated.seated_at.is_some());
+
+        // Final states cannot move or be amended
+        assert!(
+            transition(&pool, booked.id, ReservationStatus::NoShow)
+                .await
+                .is_err()
+        );
+        let err = update(&pool, booked.id, &ReservationUpdate::default())
+            .await
+            .unwrap_err();
+        assert!(matches!(
+            err,
+            RepoError::Business(ErrorCode::ReservationInvalidState, _)
+        ));
+    }
+}
@@ -0,0 +1,290 @@
+//! Sales Facts Repository (报表读模型)
+//!
+//! 写入端由 [`crate::projection`] 调用，按订单整体替换；查询端只读事实表。
+
+use super::RepoResult;
+use shared::models::{
+    HourlySales, ItemFact, ItemSales, PaymentFact, PaymentMethodSales, SalesFact, SalesFactsReport,
+};
+use sqlx::SqlitePool;
+
+/// Replace all facts of one order (idempotent)
+pub async fn replace_order(
+    pool: &SqlitePool,
+    sale: &SalesFact,
+    items: &[ItemFact],
+    payments: &[PaymentFact],
+) -> RepoResult<()> {
+    let mut tx = pool.begin().await?;
+
+    sqlx::query("DELETE FROM payment_fact WHERE order_id = ?")
+        .bind(sale.order_id)
+        .execute(&mut *tx)
+        .await?;
+    sqlx::query("DELETE FROM item_fact WHERE order_id = ?")
+        .bind(sale.order_id)
+        .execute(&mut *tx)
+        .await?;
+    sqlx::query("DELETE FROM sales_fact WHERE order_id = ?")
+        .bind(sale.order_id)
+        .execute(&mut *tx)
+        .await?;
+
+    sqlx::query(
+        "INSERT INTO sales_fact (order_id, receipt_number, status, is_retail, service_type, zone_name, table_name, guest_count, member_id, item_count, original_total, discount, surcharge, tax, total, paid_amount, operator_id, operator_name, start_time, end_time, hour) \
+         VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8, ?9, ?10, ?11, ?12, ?13, ?14, ?15, ?16, ?17, ?18, ?19, ?20, ?21)",
+    )
+    .bind(sale.order_id)
+    .bind(&sale.receipt_number)
+    .bind(&sale.status)
+    .bind(sale.is_retail)
+    .bind(&sale.service_type)
+    .bind(&sale.zone_name)
+    .bind(&sale.table_name)
+    .bind(sale.guest_count)
+    .bind(sale.member_id)
+    .bind(sale.item_count)
+    .bind(sale.original_total)
+    .bind(sale.discount)
+    .bind(sale.surcharge)
+    .bind(sale.tax)
+    .bind(sale.total)
+    .bind(sale.paid_amount)
+    .bind(sale.operator_id)
+    .bind(&sale.operator_name)
+    .bind(sale.start_time)
+    .bind(sale.end_time)
+    .bind(sale.hour)
+    .execute(&mut *tx)
+    .await?;
+
+    for item in items {
+        sqlx::query(
+            "INSERT INTO item_fact (order_id, instance_id, product_id, name, spec_name, category_id, category_name, quantity, unit_price, line_total, tax, tax_rate, is_comped, end_time) \
+             VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8, ?9, ?10, ?11, ?12, ?13, ?14)",
+        )
+        .bind(item.order_id)
+        .bind(&item.instance_id)
+        .bind(item.product_id)
+        .bind(&item.name)
+        .bind(&item.spec_name)
+        .bind(item.category_id)
+        .bind(&item.category_name)
+        .bind(item.quantity)
+        .bind(item.unit_price)
+        .bind(item.line_total)
+        .bind(item.tax)
+        .bind(item.tax_rate)
+        .bind(item.is_comped)
+        .bind(item.end_time)
+        .execute(&mut *tx)
+        .await?;
+    }
+
+    for payment in payments {
+        sqlx::query(
+            "INSERT INTO payment_fact (payment_id, order_id, method, amount, paid_at, end_time) \
+             VALUES (?1, ?2, ?3, ?4, ?5, ?6)",
+        )
+        .bind(payment.payment_id)
+        .bind(payment.order_id)
+        .bind(&payment.method)
+        .bind(payment.amount)
+        .bind(payment.paid_at)
+        .bind(payment.end_time)
+        .execute(&mut *tx)
+        .await?;
+    }
+
+    tx.commit().await?;
+    Ok(())
+}
+
+/// Order fact by id
+pub async fn find_sale(pool: &SqlitePool, order_id: i64) -> RepoResult<Option<SalesFact>> {
+    let row = sqlx::query_as::<_, SalesFact>("SELECT * FROM sales_fact WHERE order_id = ?")
+        .bind(order_id)
+        .fetch_optional(pool)
+        .await?;
+    Ok(row)
+}
+
+/// Sales facts report for orders finished in `[from, to)`
+pub async fn report(pool: &SqlitePool, from: i64, to: i64) -> RepoResult<SalesFactsReport> {
+    let (completed_orders, void_orders, revenue): (i64, i64, f64) = sqlx::query_as(
+        "SELECT \
+            COALESCE(SUM(CASE WHEN status = 'COMPLETED' THEN 1 ELSE 0 END), 0), \
+            COALESCE(SUM(CASE WHEN status = 'VOID' THEN 1 ELSE 0 END), 0), \
+            COALESCE(SUM(CASE WHEN status = 'COMPLETED' THEN total ELSE 0.0 END), 0.0) \
+         FROM sales_fact WHERE end_time >= ?1 AND end_time < ?2",
+    )
+    .bind(from)
+    .bind(to)
+    .fetch_one(pool)
+    .await?;
+
+    let hourly = sqlx::query_as::<_, HourlySales>(
+        "SELECT hour, COUNT(*) AS orders, COALESCE(SUM(guest_count), 0) AS guests, COALESCE(SUM(total), 0.0) AS revenue \
+         FROM sales_fact \
+         WHERE status = 'COMPLETED' AND end_time >= ?1 AND end_time < ?2 \
+         GROUP BY hour ORDER BY hour",
+    )
+    .bind(from)
+    .bind(to)
+    .fetch_all(pool)
+    .await?;
+
+    let items = sqlx::query_as::<_, ItemSales>(
+        "SELECT i.product_id, MAX(i.name) AS name, MAX(i.category_name) AS category_name, \
+            COALESCE(SUM(i.quantity), 0) AS quantity, \
+            COALESCE(SUM(CASE WHEN i.is_comped = 1 THEN i.quantity ELSE 0 END), 0) AS comped_quantity, \
+            COALESCE(SUM(i.line_total), 0.0) AS revenue \
+         FROM item_fact i JOIN sales_fact s ON s.order_id = i.order_id \
+         WHERE s.status = 'COMPLETED' AND i.end_time >= ?1 AND i.end_time < ?2 \
+         GROUP BY i.product_id ORDER BY revenue DESC, quantity DESC",
+    )
+    .bind(from)
+    .bind(to)
+    .fetch_all(pool)
+    .await?;
+
+    let payments = sqlx::query_as::<_, PaymentMethodSales>(
+        "SELECT p.method, COUNT(*) AS count, COALESCE(SUM(p.amount), 0.0) AS amount \
+         FROM payment_fact p JOIN sales_fact s ON s.order_id = p.order_id \
+         WHERE s.status = 'COMPLETED' AND p.end_time >= ?1 AND p.end_time < ?2 \
+         GROUP BY p.method ORDER BY amount DESC",
+    )
+    .bind(from)
+    .bind(to)
+    .fetch_all(pool)
+    .await?;
+
+    Ok(SalesFactsReport {
+        from,
+        to,
+        completed_orders,
+        void_orders,
+        revenue,
+        hourly,
+        items,
+        payments,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::db::test_pool;
+
+    fn sale(order_id: i64, status: &str, total: f64, end_time: i64) -> SalesFact {
+        SalesFact {
+            order_id,
+            receipt_number: format!("R-{order_id}"),
+            status: status.to_string(),
+            is_retail: false,
+            service_type: Some("DINE_IN".to_string()),
+            zone_name: Some("Terraza".to_string()),
+            table_name: Some("T1".to_string()),
+            guest_count: 2,
+            member_id: None,
+            item_count: 2,
+            original_total: total,
+            discount: 0.0,
+            surcharge: 0.0,
+            tax: 0.0,
+            total,
+            paid_amount: total,
+            operator_id: 1,
+            operator_name: "Ana".to_string(),
+            start_time: end_time - 3_600_000,
+            end_time,
+            hour: 13,
+        }
+    }
+
+    fn item(order_id: i64, instance_id: &str, quantity: i32, line_total: f64) -> ItemFact {
+        ItemFact {
+            order_id,
+            instance_id: instance_id.to_string(),
+            product_id: 7,
+            name: "Caña".to_string(),
+            spec_name: None,
+            category_id: Some(1),
+            category_name: Some("Bebidas".to_string()),
+            quantity,
+            unit_price: line_total / f64::from(quantity),
+            line_total,
+            tax: 0.0,
+            tax_rate: 10,
+            is_comped: false,
+            end_time: 1_000,
+        }
+    }
+
+    fn payment(payment_id: i64, order_id: i64, method: &str, amount: f64) -> PaymentFact {
+        PaymentFact {
+            payment_id,
+            order_id,
+            method: method.to_string(),
+            amount,
+            paid_at: 900,
+            end_time: 1_000,
+        }
+    }
+
+    #[tokio::test]
+    async fn test_replace_order_is_idempotent() {
+        let pool = test_pool().await;
+        let facts = (
+            sale(1, "COMPLETED", 4.0, 1_000),
+            vec![item(1, "a", 2, 4.0)],
+            vec![payment(10, 1, "CASH", 4.0)],
+        );
+        replace_order(&pool, &facts.0, &facts.1, &facts.2)
+            .await
+            .unwrap();
+        replace_order(&pool, &facts.0, &facts.1, &facts.2)
+            .await
+            .unwrap();
+
+        let report = report(&pool, 0, 2_000).await.unwrap();
+        assert_eq!(report.completed_orders, 1);
+        assert_eq!(report.items.len(), 1);
+        assert_eq!(report.items[0].quantity, 2);
+        assert_eq!(report.payments[0].count, 1);
+        assert_eq!(find_sale(&pool, 1).await.unwrap().unwrap().total, 4.0);
+    }
+
+    #[tokio::test]
+    async fn test_report_excludes_void_orders() {
+        let pool = test_pool().await;
+        replace_order(
+            &pool,
+            &sale(1, "COMPLETED", 4.0, 1_000),
+            &[item(1, "a", 2, 4.0)],
+            &[payment(10, 1, "CASH", 4.0)],
+        )
+        .await
+        .unwrap();
+        replace_order(
+            &pool,
+            &sale(2, "VOID", 6.0, 1_000),
+            &[item(2, "b", 3, 6.0)],
+            &[],
+        )
+        .await
+        .unwrap();
+        replace_order(&pool, &sale(3, "COMPLETED", 9.0, 5_000), &[], &[])
+            .await
+            .unwrap();
+
+        let report = report(&pool, 0, 2_000).await.unwrap();
+        assert_eq!(report.completed_orders, 1);
+        assert_eq!(report.void_orders, 1);
+        assert_eq!(report.revenue, 4.0);
+        assert_eq!(report.hourly.len(), 1);
+        assert_eq!(report.hourly[0].guests, 2);
+        assert_eq!(report.items[0].quantity, 2);
+        assert_eq!(report.payments[0].amount, 4.0);
+    }
+}
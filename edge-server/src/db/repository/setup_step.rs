@@ -0,0 +1,86 @@
+//! Setup Step Repository (首次安装向导)
+
+use super::RepoResult;
+use shared::models::SetupStepKind;
+use sqlx::SqlitePool;
+
+/// 已确认 / 跳过的步骤
+#[derive(Debug, Clone, sqlx::FromRow)]
+pub struct SetupStepRecord {
+    pub step: String,
+    pub skipped: bool,
+    pub completed_by: Option<i64>,
+    pub completed_at: i64,
+}
+
+pub async fn find_all(pool: &SqlitePool) -> RepoResult<Vec<SetupStepRecord>> {
+    let rows = sqlx::query_as::<_, SetupStepRecord>(
+        "SELECT step, skipped, completed_by, completed_at FROM setup_step",
+    )
+    .fetch_all(pool)
+    .await?;
+    Ok(rows)
+}
+
+/// 确认或跳过步骤 (重复提交覆盖)
+pub async fn mark(
+    pool: &SqlitePool,
+    step: SetupStepKind,
+    skipped: bool,
+    operator_id: i64,
+) -> RepoResult<()> {
+    sqlx::query(
+        "INSERT INTO setup_step (step, skipped, completed_by, completed_at) VALUES (?1, ?2, ?3, ?4) \
+         ON CONFLICT(step) DO UPDATE SET skipped = ?2, completed_by = ?3, completed_at = ?4",
+    )
+    .bind(step.as_str())
+    .bind(skipped)
+    .bind(operator_id)
+    .bind(shared::util::now_millis())
+    .execute(pool)
+    .await?;
+    Ok(())
+}
+
+// ── Validation counts ─────────────────────────────────────────
+
+/// 非种子的在职管理员 (种子 `admin` 账号 is_system = 1)
+pub async fn count_personal_admins(pool: &SqlitePool) -> RepoResult<i64> {
+    let count = sqlx::query_scalar(
+        "SELECT COUNT(*) FROM employee e JOIN role r ON r.id = e.role_id \
+         WHERE e.is_active = 1 AND e.is_system = 0 AND r.is_active = 1 \
+         AND (r.name = 'admin' OR r.permissions LIKE '%\"all\"%')",
+    )
+    .fetch_one(pool)
+    .await?;
+    Ok(count)
+}
+
+/// 有效区域下的有效桌台
+pub async fn count_active_tables(pool: &SqlitePool) -> RepoResult<i64> {
+    let count = sqlx::query_scalar(
+        "SELECT COUNT(*) FROM dining_table t JOIN zone z ON z.id = t.zone_id \
+         WHERE t.is_active = 1 AND z.is_active = 1",
+    )
+    .fetch_one(pool)
+    .await?;
+    Ok(count)
+}
+
+pub async fn count_active_printers(pool: &SqlitePool) -> RepoResult<i64> {
+    let count = sqlx::query_scalar("SELECT COUNT(*) FROM print_destination WHERE is_active = 1")
+        .fetch_one(pool)
+        .await?;
+    Ok(count)
+}
+
+/// 税率超出 0–100% 的有效商品
+pub async fn find_invalid_tax_products(pool: &SqlitePool) -> RepoResult<Vec<String>> {
+    let names = sqlx::query_scalar(
+        "SELECT name FROM product WHERE is_active = 1 AND (tax_rate < 0 OR tax_rate > 100) \
+         ORDER BY name LIMIT 10",
+    )
+    .fetch_all(pool)
+    .await?;
+    Ok(names)
+}
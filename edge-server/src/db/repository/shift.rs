@@ -1,7 +1,11 @@
 //! Shift Repository
 
 use super::{RepoError, RepoResult};
-use shared::models::{Shift, ShiftClose, ShiftCreate, ShiftForceClose, ShiftUpdate};
+use shared::error::ErrorCode;
+use shared::models::{
+    Shift, ShiftBreak, ShiftBreakStart, ShiftClose, ShiftCreate, ShiftForceClose, ShiftStatus,
+    ShiftUpdate,
+};
 use sqlx::SqlitePool;
 
 fn validate_cash_amount(amount: f64, field_name: &str) -> RepoResult<()> {
@@ -129,6 +133,7 @@ pub async fn close(pool: &SqlitePool, id: i64, data: ShiftClose) -> RepoResult<S
             "Shift {id} not found or already closed"
         )));
     }
+    end_open_break(pool, id, now).await?;
     find_by_id(pool, id)
         .await?
         .ok_or_else(|| RepoError::NotFound(format!("Shift {id} not found")))
@@ -155,6 +160,7 @@ pub async fn force_close(pool: &SqlitePool, id: i64, data: ShiftForceClose) -> R
             "Shift {id} not found or already closed"
         )));
     }
+    end_open_break(pool, id, now).await?;
     find_by_id(pool, id)
         .await?
         .ok_or_else(|| RepoError::NotFound(format!("Shift {id} not found")))
@@ -196,3 +202,94 @@ pub async fn heartbeat(pool: &SqlitePool, id: i64) -> RepoResult<()> {
     .await?;
     Ok(())
 }
+
+// ── Breaks ──────────────────────────────────────────────────────────────
+
+const BREAK_SELECT: &str =
+    "SELECT id, shift_id, break_type, start_time, end_time, note FROM shift_break";
+
+pub async fn find_breaks(pool: &SqlitePool, shift_id: i64) -> RepoResult<Vec<ShiftBreak>> {
+    let breaks = sqlx::query_as::<_, ShiftBreak>(&format!(
+        "{BREAK_SELECT} WHERE shift_id = ? ORDER BY start_time"
+    ))
+    .bind(shift_id)
+    .fetch_all(pool)
+    .await?;
+    Ok(breaks)
+}
+
+async fn find_open_break(pool: &SqlitePool, shift_id: i64) -> RepoResult<Option<ShiftBreak>> {
+    let open = sqlx::query_as::<_, ShiftBreak>(&format!(
+        "{BREAK_SELECT} WHERE shift_id = ? AND end_time IS NULL"
+    ))
+    .bind(shift_id)
+    .fetch_optional(pool)
+    .await?;
+    Ok(open)
+}
+
+async fn require_open_shift(pool: &SqlitePool, id: i64) -> RepoResult<Shift> {
+    find_by_id(pool, id)
+        .await?
+        .filter(|s| s.status == ShiftStatus::Open)
+        .ok_or_else(|| {
+            RepoError::Business(
+                ErrorCode::ShiftNotFound,
+                format!("Shift {id} not found or already closed"),
+            )
+        })
+}
+
+pub async fn start_break(
+    pool: &SqlitePool,
+    shift_id: i64,
+    data: ShiftBreakStart,
+) -> RepoResult<ShiftBreak> {
+    require_open_shift(pool, shift_id).await?;
+    if find_open_break(pool, shift_id).await?.is_some() {
+        return Err(RepoError::Duplicate(
+            "A break is already in progress".into(),
+        ));
+    }
+
+    let now = shared::util::now_millis();
+    let id = shared::util::snowflake_id();
+    sqlx::query(
+        "INSERT INTO shift_break (id, shift_id, break_type, start_time, note) VALUES (?, ?, ?, ?, ?)",
+    )
+    .bind(id)
+    .bind(shift_id)
+    .bind(data.break_type)
+    .bind(now)
+    .bind(&data.note)
+    .execute(pool)
+    .await?;
+
+    find_open_break(pool, shift_id)
+        .await?
+        .ok_or_else(|| RepoError::Database("Failed to start break".into()))
+}
+
+pub async fn end_break(pool: &SqlitePool, shift_id: i64) -> RepoResult<ShiftBreak> {
+    require_open_shift(pool, shift_id).await?;
+    let open = find_open_break(pool, shift_id)
+        .await?
+        .ok_or_else(|| RepoError::NotFound("No break in progress".into()))?;
+
+    let now = shared::util::now_millis();
+    end_open_break(pool, shift_id, now).await?;
+    Ok(ShiftBreak {
+        end_time: Some(now),
+        ..open
+    })
+}
+
+/// Close the break in progress (also called when the shift closes)
+async fn end_open_break(pool: &SqlitePool, shift_id: i64, now: i64) -> RepoResult<()> {
+    sqlx::query("UPDATE shift_break SET end_time = ? WHERE shift_id = ? AND end_time IS NULL")
+        .bind(now)
+        .bind(shift_id)
+        .execute(pool)
+        .await?;
+    Ok(())
+}
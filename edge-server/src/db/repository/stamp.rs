@@ -1,7 +1,9 @@
 //! Stamp Progress Repository
+//!
+//! `stamp_ledger` 记录每次发章 / 兑换 (每日上限判定、活动效果统计)。
 
 use super::{RepoError, RepoResult};
-use shared::models::{MemberStampProgress, MemberStampProgressDetail};
+use shared::models::{MemberStampProgress, MemberStampProgressDetail, StampCampaignStats};
 use sqlx::SqlitePool;
 
 pub async fn find_progress_by_member(
@@ -127,6 +129,97 @@ pub async fn ensure_progress(
         .ok_or_else(|| RepoError::Database("Failed to ensure stamp progress".into()))
 }
 
+// ── Stamp Ledger ─────────────────────────────────────────────
+
+/// Stamps granted to a member for an activity since `since` (Unix millis)
+pub async fn earned_since(
+    pool: &SqlitePool,
+    member_id: i64,
+    activity_id: i64,
+    since: i64,
+) -> RepoResult<i32> {
+    let earned = sqlx::query_scalar::<_, i32>(
+        "SELECT COALESCE(SUM(stamps), 0) FROM stamp_ledger WHERE member_id = ? AND stamp_activity_id = ? AND kind = 'EARN' AND created_at >= ?",
+    )
+    .bind(member_id)
+    .bind(activity_id)
+    .bind(since)
+    .fetch_one(pool)
+    .await?;
+    Ok(earned)
+}
+
+/// Record stamps earned by an order (`capped` = withheld by the daily cap)
+pub async fn record_earn(
+    pool: &SqlitePool,
+    member_id: i64,
+    activity_id: i64,
+    order_id: i64,
+    stamps: i32,
+    capped: i32,
+    timestamp: i64,
+) -> RepoResult<()> {
+    sqlx::query(
+        "INSERT INTO stamp_ledger (member_id, stamp_activity_id, order_id, kind, stamps, capped, created_at) VALUES (?1, ?2, ?3, 'EARN', ?4, ?5, ?6)",
+    )
+    .bind(member_id)
+    .bind(activity_id)
+    .bind(order_id)
+    .bind(stamps)
+    .bind(capped)
+    .bind(timestamp)
+    .execute(pool)
+    .await?;
+    Ok(())
+}
+
+/// Record a redemption consumed on order completion
+pub async fn record_redeem(
+    pool: &SqlitePool,
+    member_id: i64,
+    activity_id: i64,
+    order_id: i64,
+    stamps: i32,
+    timestamp: i64,
+) -> RepoResult<()> {
+    sqlx::query(
+        "INSERT INTO stamp_ledger (member_id, stamp_activity_id, order_id, kind, stamps, created_at) VALUES (?1, ?2, ?3, 'REDEEM', ?4, ?5)",
+    )
+    .bind(member_id)
+    .bind(activity_id)
+    .bind(order_id)
+    .bind(stamps)
+    .bind(timestamp)
+    .execute(pool)
+    .await?;
+    Ok(())
+}
+
+/// Campaign performance per activity in `[from, to)` (activities without activity included)
+pub async fn campaign_stats(
+    pool: &SqlitePool,
+    from: i64,
+    to: i64,
+) -> RepoResult<Vec<StampCampaignStats>> {
+    let rows = sqlx::query_as::<_, StampCampaignStats>(
+        "SELECT sa.id AS stamp_activity_id, sa.name AS stamp_activity_name, sa.marketing_group_id, sa.is_active, \
+         COUNT(DISTINCT CASE WHEN l.kind = 'EARN' AND l.stamps > 0 THEN l.member_id END) AS members_earning, \
+         COUNT(DISTINCT CASE WHEN l.kind = 'EARN' AND l.stamps > 0 THEN l.order_id END) AS earning_orders, \
+         COALESCE(SUM(CASE WHEN l.kind = 'EARN' THEN l.stamps END), 0) AS stamps_earned, \
+         COALESCE(SUM(CASE WHEN l.kind = 'EARN' THEN l.capped END), 0) AS stamps_capped, \
+         COUNT(CASE WHEN l.kind = 'REDEEM' THEN 1 END) AS redemptions, \
+         COUNT(DISTINCT CASE WHEN l.kind = 'REDEEM' THEN l.member_id END) AS members_redeeming \
+         FROM stamp_activity sa \
+         LEFT JOIN stamp_ledger l ON l.stamp_activity_id = sa.id AND l.created_at >= ?1 AND l.created_at < ?2 \
+         GROUP BY sa.id ORDER BY stamps_earned DESC, sa.created_at DESC",
+    )
+    .bind(from)
+    .bind(to)
+    .fetch_all(pool)
+    .await?;
+    Ok(rows)
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -201,6 +294,22 @@ mod tests {
         .await
         .unwrap();
 
+        sqlx::query(
+            "CREATE TABLE stamp_ledger (
+                id INTEGER PRIMARY KEY AUTOINCREMENT,
+                member_id INTEGER NOT NULL,
+                stamp_activity_id INTEGER NOT NULL,
+                order_id INTEGER,
+                kind TEXT NOT NULL,
+                stamps INTEGER NOT NULL,
+                capped INTEGER NOT NULL DEFAULT 0,
+                created_at INTEGER NOT NULL
+            )",
+        )
+        .execute(&pool)
+        .await
+        .unwrap();
+
         // Seed: marketing_group + member + stamp_activity
         sqlx::query("INSERT INTO marketing_group (id, name) VALUES (1, 'VIP')")
             .execute(&pool)
@@ -347,4 +456,31 @@ mod tests {
         assert_eq!(p1.current_stamps, 5);
         assert_eq!(p2.current_stamps, 3);
     }
+
+    #[tokio::test]
+    async fn test_ledger_daily_earned_and_campaign_stats() {
+        let pool = test_pool().await;
+        record_earn(&pool, 1, 1, 100, 3, 0, 1000).await.unwrap();
+        record_earn(&pool, 1, 1, 101, 2, 4, 5000).await.unwrap();
+        record_redeem(&pool, 1, 1, 102, 10, 6000).await.unwrap();
+
+        assert_eq!(earned_since(&pool, 1, 1, 0).await.unwrap(), 5);
+        assert_eq!(earned_since(&pool, 1, 1, 2000).await.unwrap(), 2);
+        assert_eq!(earned_since(&pool, 1, 2, 0).await.unwrap(), 0);
+
+        let stats = campaign_stats(&pool, 0, 10_000).await.unwrap();
+        let coffee = stats.iter().find(|s| s.stamp_activity_id == 1).unwrap();
+        assert_eq!(coffee.stamps_earned, 5);
+        assert_eq!(coffee.stamps_capped, 4);
+        assert_eq!(coffee.earning_orders, 2);
+        assert_eq!(coffee.members_earning, 1);
+        assert_eq!(coffee.redemptions, 1);
+        let tea = stats.iter().find(|s| s.stamp_activity_id == 2).unwrap();
+        assert_eq!((tea.stamps_earned, tea.redemptions), (0, 0));
+
+        // Range excludes the first earn
+        let stats = campaign_stats(&pool, 2000, 10_000).await.unwrap();
+        let coffee = stats.iter().find(|s| s.stamp_activity_id == 1).unwrap();
+        assert_eq!(coffee.stamps_earned, 2);
+    }
 }
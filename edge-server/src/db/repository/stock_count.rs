@@ -0,0 +1,487 @@
+//! Stock Count Repository (盘点)
+//!
+//! 开始盘点时冻结理论数量；审批通过后在同一事务中按 实盘 − 理论 过账 COUNT 调整。
+//! 盘点单与明细永不删除，供会计追溯。
+
+use super::inventory::{STOCK_LEVEL_SELECT, insert_adjustment};
+use super::{RepoError, RepoResult};
+use shared::models::{
+    StockAdjustmentKind, StockCount, StockCountCreate, StockCountLine, StockCountStatus, StockLevel,
+};
+use sqlx::SqlitePool;
+
+const COUNT_SELECT: &str = "SELECT id, status, note, created_by_id, created_by_name, created_at, submitted_at, reviewed_by_id, reviewed_by_name, reviewed_at, review_note FROM stock_count";
+
+const LINE_SELECT: &str = "SELECT id, stock_count_id, stock_level_id, product_name, spec_name, theoretical_quantity, counted_quantity, counted_at, counted_quantity - theoretical_quantity AS variance FROM stock_count_line";
+
+/// Sessions newest first (without lines)
+pub async fn find_all(pool: &SqlitePool, limit: i64) -> RepoResult<Vec<StockCount>> {
+    let counts = sqlx::query_as::<_, StockCount>(&format!(
+        "{COUNT_SELECT} ORDER BY created_at DESC, id DESC LIMIT ?"
+    ))
+    .bind(limit)
+    .fetch_all(pool)
+    .await?;
+    Ok(counts)
+}
+
+/// Session with its lines
+pub async fn find_by_id(pool: &SqlitePool, id: i64) -> RepoResult<Option<StockCount>> {
+    let count = sqlx::query_as::<_, StockCount>(&format!("{COUNT_SELECT} WHERE id = ?"))
+        .bind(id)
+        .fetch_optional(pool)
+        .await?;
+    let Some(mut count) = count else {
+        return Ok(None);
+    };
+    count.lines = sqlx::query_as::<_, StockCountLine>(&format!(
+        "{LINE_SELECT} WHERE stock_count_id = ? ORDER BY product_name, spec_name"
+    ))
+    .bind(id)
+    .fetch_all(pool)
+    .await?;
+    Ok(Some(count))
+}
+
+/// Start a session, freezing the theoretical quantity of each level
+///
+/// Only one session may be open or awaiting approval at a time.
+pub async fn create(
+    pool: &SqlitePool,
+    data: &StockCountCreate,
+    operator_id: i64,
+    operator_name: &str,
+) -> RepoResult<StockCount> {
+    let id = shared::util::snowflake_id();
+    let now = shared::util::now_millis();
+
+    let mut tx = pool.begin().await?;
+    let active: Option<i64> = sqlx::query_scalar(
+        "SELECT id FROM stock_count WHERE status IN ('OPEN', 'SUBMITTED') LIMIT 1",
+    )
+    .fetch_optional(&mut *tx)
+    .await?;
+    if active.is_some() {
+        return Err(RepoError::Validation(
+            "A stock count is already in progress".into(),
+        ));
+    }
+
+    let levels = sqlx::query_as::<_, StockLevel>(STOCK_LEVEL_SELECT)
+        .fetch_all(&mut *tx)
+        .await?;
+    let levels: Vec<StockLevel> = match &data.stock_level_ids {
+        Some(ids) => {
+            if let Some(missing) = ids.iter().find(|id| !levels.iter().any(|l| l.id == **id)) {
+                return Err(RepoError::NotFound(format!(
+                    "Stock level {missing} not found"
+                )));
+            }
+            levels.into_iter().filter(|l| ids.contains(&l.id)).collect()
+        }
+        None => levels,
+    };
+    if levels.is_empty() {
+        return Err(RepoError::Validation("No stock levels to count".into()));
+    }
+
+    sqlx::query(
+        "INSERT INTO stock_count (id, status, note, created_by_id, created_by_name, created_at) VALUES (?, ?, ?, ?, ?, ?)",
+    )
+    .bind(id)
+    .bind(StockCountStatus::Open.as_str())
+    .bind(data.note.as_deref().map(str::trim).filter(|n| !n.is_empty()))
+    .bind(operator_id)
+    .bind(operator_name)
+    .bind(now)
+    .execute(&mut *tx)
+    .await?;
+    for level in &levels {
+        sqlx::query(
+            "INSERT INTO stock_count_line (id, stock_count_id, stock_level_id, product_name, spec_name, theoretical_quantity) VALUES (?, ?, ?, ?, ?, ?)",
+        )
+        .bind(shared::util::snowflake_id())
+        .bind(id)
+        .bind(level.id)
+        .bind(&level.product_name)
+        .bind(&level.spec_name)
+        .bind(level.quantity)
+        .execute(&mut *tx)
+        .await?;
+    }
+    tx.commit().await?;
+
+    find_by_id(pool, id)
+        .await?
+        .ok_or_else(|| RepoError::Database("Failed to create stock count".into()))
+}
+
+/// Set (or clear) the counted quantity of a line in an open session
+pub async fn record(
+    pool: &SqlitePool,
+    count_id: i64,
+    stock_level_id: i64,
+    counted_quantity: Option<i64>,
+) -> RepoResult<StockCountLine> {
+    if counted_quantity.is_some_and(|q| q < 0) {
+        return Err(RepoError::Validation(
+            "Counted quantity must not be negative".into(),
+        ));
+    }
+    let mut tx = pool.begin().await?;
+    ensure_status(&mut tx, count_id, &[StockCountStatus::Open]).await?;
+    let line_id: Option<i64> = sqlx::query_scalar(
+        "UPDATE stock_count_line SET counted_quantity = ?, counted_at = ? WHERE stock_count_id = ? AND stock_level_id = ? RETURNING id",
+    )
+    .bind(counted_quantity)
+    .bind(counted_quantity.map(|_| shared::util::now_millis()))
+    .bind(count_id)
+    .bind(stock_level_id)
+    .fetch_optional(&mut *tx)
+    .await?;
+    let Some(line_id) = line_id else {
+        return Err(RepoError::NotFound(format!(
+            "Stock level {stock_level_id} is not part of stock count {count_id}"
+        )));
+    };
+    tx.commit().await?;
+    find_line(pool, line_id).await
+}
+
+/// Barcode scan: add `quantity` to the line of the scanned level
+pub async fn scan(
+    pool: &SqlitePool,
+    count_id: i64,
+    barcode: &str,
+    quantity: i64,
+) -> RepoResult<StockCountLine> {
+    if quantity <= 0 {
+        return Err(RepoError::Validation(
+            "Scanned quantity must be positive".into(),
+        ));
+    }
+    let mut tx = pool.begin().await?;
+    ensure_status(&mut tx, count_id, &[StockCountStatus::Open]).await?;
+    let line_id: Option<i64> = sqlx::query_scalar(
+        "UPDATE stock_count_line SET counted_quantity = COALESCE(counted_quantity, 0) + ?, counted_at = ? WHERE stock_count_id = ? AND stock_level_id = (SELECT id FROM stock_level WHERE barcode = ?) RETURNING id",
+    )
+    .bind(quantity)
+    .bind(shared::util::now_millis())
+    .bind(count_id)
+    .bind(barcode.trim())
+    .fetch_optional(&mut *tx)
+    .await?;
+    let Some(line_id) = line_id else {
+        return Err(RepoError::NotFound(format!(
+            "Barcode {} is not part of stock count {count_id}",
+            barcode.trim()
+        )));
+    };
+    tx.commit().await?;
+    find_line(pool, line_id).await
+}
+
+/// Hand an open session over for approval
+pub async fn submit(pool: &SqlitePool, id: i64) -> RepoResult<StockCount> {
+    let mut tx = pool.begin().await?;
+    ensure_status(&mut tx, id, &[StockCountStatus::Open]).await?;
+    sqlx::query("UPDATE stock_count SET status = ?, submitted_at = ? WHERE id = ?")
+        .bind(StockCountStatus::Submitted.as_str())
+        .bind(shared::util::now_millis())
+        .bind(id)
+        .execute(&mut *tx)
+        .await?;
+    tx.commit().await?;
+    find_by_id(pool, id)
+        .await?
+        .ok_or_else(|| RepoError::NotFound(format!("Stock count {id} not found")))
+}
+
+/// Approve a submitted session and post its variances
+///
+/// Each counted line with a non-zero variance becomes a COUNT adjustment of
+/// counted − theoretical, so sales made during the count are preserved.
+/// Lines whose level was deleted meanwhile are skipped. Returns the session
+/// and the changed levels with their quantity before posting.
+pub async fn approve(
+    pool: &SqlitePool,
+    id: i64,
+    note: Option<&str>,
+    operator_id: i64,
+    operator_name: &str,
+) -> RepoResult<(StockCount, Vec<(StockLevel, i64)>)> {
+    let now = shared::util::now_millis();
+    let mut tx = pool.begin().await?;
+    ensure_status(&mut tx, id, &[StockCountStatus::Submitted]).await?;
+    review(
+        &mut tx,
+        id,
+        StockCountStatus::Approved,
+        note,
+        operator_id,
+        operator_name,
+        now,
+    )
+    .await?;
+
+    let variances: Vec<(i64, i64)> = sqlx::query_as(
+        "SELECT stock_level_id, counted_quantity - theoretical_quantity FROM stock_count_line WHERE stock_count_id = ? AND counted_quantity IS NOT NULL AND counted_quantity != theoretical_quantity",
+    )
+    .bind(id)
+    .fetch_all(&mut *tx)
+    .await?;
+    let mut changed = Vec::with_capacity(variances.len());
+    for (stock_level_id, variance) in variances {
+        let updated = sqlx::query(
+            "UPDATE stock_level SET quantity = quantity + ?, updated_at = ? WHERE id = ?",
+        )
+        .bind(variance)
+        .bind(now)
+        .bind(stock_level_id)
+        .execute(&mut *tx)
+        .await?;
+        if updated.rows_affected() == 0 {
+            continue;
+        }
+        let level = sqlx::query_as::<_, StockLevel>(&format!("{STOCK_LEVEL_SELECT} WHERE id = ?"))
+            .bind(stock_level_id)
+            .fetch_one(&mut *tx)
+            .await?;
+        insert_adjustment(
+            &mut tx,
+            level.id,
+            StockAdjustmentKind::Count,
+            variance,
+            level.quantity,
+            None,
+            Some(id),
+            None,
+            Some("Stock count"),
+            Some(operator_id),
+            Some(operator_name),
+            now,
+        )
+        .await?;
+        let before = level.quantity - variance;
+        changed.push((level, before));
+    }
+    tx.commit().await?;
+
+    let count = find_by_id(pool, id)
+        .await?
+        .ok_or_else(|| RepoError::NotFound(format!("Stock count {id} not found")))?;
+    Ok((count, changed))
+}
+
+/// Reject an open or submitted session (nothing is posted)
+pub async fn reject(
+    pool: &SqlitePool,
+    id: i64,
+    note: Option<&str>,
+    operator_id: i64,
+    operator_name: &str,
+) -> RepoResult<StockCount> {
+    let mut tx = pool.begin().await?;
+    ensure_status(
+        &mut tx,
+        id,
+        &[StockCountStatus::Open, StockCountStatus::Submitted],
+    )
+    .await?;
+    review(
+        &mut tx,
+        id,
+        StockCountStatus::Rejected,
+        note,
+        operator_id,
+        operator_name,
+        shared::util::now_millis(),
+    )
+    .await?;
+    tx.commit().await?;
+    find_by_id(pool, id)
+        .await?
+        .ok_or_else(|| RepoError::NotFound(format!("Stock count {id} not found")))
+}
+
+async fn find_line(pool: &SqlitePool, id: i64) -> RepoResult<StockCountLine> {
+    sqlx::query_as::<_, StockCountLine>(&format!("{LINE_SELECT} WHERE id = ?"))
+        .bind(id)
+        .fetch_optional(pool)
+        .await?
+        .ok_or_else(|| RepoError::NotFound(format!("Stock count line {id} not found")))
+}
+
+async fn ensure_status(
+    tx: &mut sqlx::Transaction<'_, sqlx::Sqlite>,
+    id: i64,
+    allowed: &[StockCountStatus],
+) -> RepoResult<()> {
+    let status: Option<String> = sqlx::query_scalar("SELECT status FROM stock_count WHERE id = ?")
+        .bind(id)
+        .fetch_optional(&mut **tx)
+        .await?;
+    let Some(status) = status else {
+        return Err(RepoError::NotFound(format!("Stock count {id} not found")));
+    };
+    let status = StockCountStatus::try_from(status).map_err(RepoError::DataCorruption)?;
+    if !allowed.contains(&status) {
+        return Err(RepoError::Validation(format!(
+            "Stock count {id} is {}",
+            status.as_str()
+        )));
+    }
+    Ok(())
+}
+
+async fn review(
+    tx: &mut sqlx::Transaction<'_, sqlx::Sqlite>,
+    id: i64,
+    status: StockCountStatus,
+    note: Option<&str>,
+    operator_id: i64,
+    operator_name: &str,
+    now: i64,
+) -> RepoResult<()> {
+    sqlx::query(
+        "UPDATE stock_count SET status = ?, reviewed_by_id = ?, reviewed_by_name = ?, reviewed_at = ?, review_note = ? WHERE id = ?",
+    )
+    .bind(status.as_str())
+    .bind(operator_id)
+    .bind(operator_name)
+    .bind(now)
+    .bind(note.map(str::trim).filter(|n| !n.is_empty()))
+    .bind(id)
+    .execute(&mut **tx)
+    .await?;
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::db::repository::inventory;
+    use crate::db::test_pool;
+    use shared::models::{StockConsumption, StockLevelCreate};
+
+    async fn level(pool: &SqlitePool, product_id: i64, quantity: i64, barcode: &str) -> StockLevel {
+        inventory::create(
+            pool,
+            &StockLevelCreate {
+                product_id,
+                spec_id: None,
+                quantity,
+                low_stock_threshold: None,
+                barcode: Some(barcode.to_string()),
+            },
+            "Beer",
+            None,
+            1,
+            "Ana",
+        )
+        .await
+        .unwrap()
+    }
+
+    #[tokio::test]
+    async fn test_count_approval_posts_variance_against_frozen_quantity() {
+        let pool = test_pool().await;
+        let beer = level(&pool, 10, 20, "BEER").await;
+        let wine = level(&pool, 11, 5, "WINE").await;
+        let water = level(&pool, 12, 8, "WATER").await;
+
+        let count = create(&pool, &StockCountCreate::default(), 2, "Luis")
+            .await
+            .unwrap();
+        assert_eq!(count.status, StockCountStatus::Open);
+        assert_eq!(count.lines.len(), 3);
+        assert!(matches!(
+            create(&pool, &StockCountCreate::default(), 2, "Luis").await,
+            Err(RepoError::Validation(_))
+        ));
+
+        // A sale during the count is kept: the variance is against the frozen 20
+        let sale = [StockConsumption::Product {
+            product_id: 10,
+            spec_id: None,
+            quantity: 3,
+        }];
+        inventory::deduct_for_order(&pool, 700, &sale)
+            .await
+            .unwrap();
+
+        for _ in 0..2 {
+            scan(&pool, count.id, " BEER ", 9).await.unwrap();
+        }
+        let line = record(&pool, count.id, wine.id, Some(5)).await.unwrap();
+        assert_eq!(line.variance, Some(0));
+        assert!(matches!(
+            scan(&pool, count.id, "UNKNOWN", 1).await,
+            Err(RepoError::NotFound(_))
+        ));
+        assert!(matches!(
+            approve(&pool, count.id, None, 3, "Marta").await,
+            Err(RepoError::Validation(_))
+        ));
+
+        let count = submit(&pool, count.id).await.unwrap();
+        assert_eq!(count.status, StockCountStatus::Submitted);
+        assert!(matches!(
+            record(&pool, count.id, beer.id, Some(1)).await,
+            Err(RepoError::Validation(_))
+        ));
+
+        let (count, changed) = approve(&pool, count.id, Some("ok"), 3, "Marta")
+            .await
+            .unwrap();
+        assert_eq!(count.status, StockCountStatus::Approved);
+        assert_eq!(count.reviewed_by_name.as_deref(), Some("Marta"));
+        let beer_line = count
+            .lines
+            .iter()
+            .find(|l| l.stock_level_id == beer.id)
+            .unwrap();
+        assert_eq!(
+            (beer_line.theoretical_quantity, beer_line.variance),
+            (20, Some(-2))
+        );
+        // Only beer changed (wine matched, water not counted)
+        assert_eq!(changed.len(), 1);
+        assert_eq!((changed[0].0.quantity, changed[0].1), (15, 17));
+        assert_eq!(
+            inventory::find_by_id(&pool, water.id)
+                .await
+                .unwrap()
+                .unwrap()
+                .quantity,
+            8
+        );
+
+        let trail = inventory::find_adjustments(&pool, beer.id, 10)
+            .await
+            .unwrap();
+        assert_eq!(trail[0].kind, StockAdjustmentKind::Count);
+        assert_eq!(trail[0].stock_count_id, Some(count.id));
+        assert_eq!(trail[0].delta, -2);
+
+        // History is kept and a new session can start
+        assert_eq!(find_all(&pool, 10).await.unwrap().len(), 1);
+        let next = create(
+            &pool,
+            &StockCountCreate {
+                note: None,
+                stock_level_ids: Some(vec![wine.id]),
+            },
+            2,
+            "Luis",
+        )
+        .await
+        .unwrap();
+        assert_eq!(next.lines.len(), 1);
+        let next = reject(&pool, next.id, Some("recount"), 3, "Marta")
+            .await
+            .unwrap();
+        assert_eq!(next.status, StockCountStatus::Rejected);
+        assert_eq!(next.review_note.as_deref(), Some("recount"));
+    }
+}
@@ -0,0 +1,789 @@
+//! Stock Transfer Repository (门店间调拨)
+//!
+//! 发货门店创建调拨单时即过账 TRANSFER_OUT，单据保持 IN_TRANSIT；收货门店确认
+//! 实收数量后过账 TRANSFER_IN，短收部分回冲到发货门店 (TRANSFER_RETURN)。
+//! 两店以同一 id 各存一份，经 crab-cloud 中继。
+//! `relay_pending` 标记尚未发往 cloud 的调拨单 / 收货确认 (CloudWorker 发送)。
+
+use super::inventory::{STOCK_LEVEL_SELECT, insert_adjustment};
+use super::{RepoError, RepoResult};
+use shared::cloud::transfer::{
+    MAX_TRANSFER_LINES, TransferDispatch, TransferDispatchLine, TransferPeer, TransferReceipt,
+};
+use shared::models::{
+    StockAdjustmentKind, StockLevel, StockTransfer, StockTransferCreate, StockTransferDirection,
+    StockTransferLine, StockTransferReceive, StockTransferStatus,
+};
+use sqlx::SqlitePool;
+use std::collections::HashSet;
+
+const TRANSFER_SELECT: &str = "SELECT id, direction, status, peer_store_id, peer_store_name, note, sent_by, sent_at, received_by, received_at FROM stock_transfer";
+
+const LINE_SELECT: &str = "SELECT id, stock_transfer_id, line_no, stock_level_id, product_name, spec_name, barcode, quantity, received_quantity FROM stock_transfer_line";
+
+/// Transfers newest first (without lines)
+pub async fn find_all(pool: &SqlitePool, limit: i64) -> RepoResult<Vec<StockTransfer>> {
+    let transfers = sqlx::query_as::<_, StockTransfer>(&format!(
+        "{TRANSFER_SELECT} ORDER BY sent_at DESC, id DESC LIMIT ?"
+    ))
+    .bind(limit)
+    .fetch_all(pool)
+    .await?;
+    Ok(transfers)
+}
+
+/// Transfer with its lines
+pub async fn find_by_id(pool: &SqlitePool, id: i64) -> RepoResult<Option<StockTransfer>> {
+    let transfer = sqlx::query_as::<_, StockTransfer>(&format!("{TRANSFER_SELECT} WHERE id = ?"))
+        .bind(id)
+        .fetch_optional(pool)
+        .await?;
+    let Some(mut transfer) = transfer else {
+        return Ok(None);
+    };
+    transfer.lines = sqlx::query_as::<_, StockTransferLine>(&format!(
+        "{LINE_SELECT} WHERE stock_transfer_id = ? ORDER BY line_no"
+    ))
+    .bind(id)
+    .fetch_all(pool)
+    .await?;
+    Ok(Some(transfer))
+}
+
+/// Transfers whose dispatch or receipt still has to be sent to crab-cloud
+pub async fn find_relay_pending(pool: &SqlitePool) -> RepoResult<Vec<StockTransfer>> {
+    let ids: Vec<i64> = sqlx::query_scalar(
+        "SELECT id FROM stock_transfer WHERE relay_pending = 1 ORDER BY sent_at",
+    )
+    .fetch_all(pool)
+    .await?;
+    let mut transfers = Vec::with_capacity(ids.len());
+    for id in ids {
+        if let Some(transfer) = find_by_id(pool, id).await? {
+            transfers.push(transfer);
+        }
+    }
+    Ok(transfers)
+}
+
+/// Mark a transfer's dispatch / receipt as sent to crab-cloud
+pub async fn clear_relay_pending(pool: &SqlitePool, id: i64) -> RepoResult<()> {
+    sqlx::query("UPDATE stock_transfer SET relay_pending = 0 WHERE id = ?")
+        .bind(id)
+        .execute(pool)
+        .await?;
+    Ok(())
+}
+
+/// Send goods to another store
+///
+/// Posts TRANSFER_OUT for every line in one transaction and queues the
+/// dispatch for crab-cloud. Returns the transfer and the changed levels with
+/// their quantity before the transfer.
+pub async fn create(
+    pool: &SqlitePool,
+    data: &StockTransferCreate,
+    peer: &TransferPeer,
+    operator_id: i64,
+    operator_name: &str,
+) -> RepoResult<(StockTransfer, Vec<(StockLevel, i64)>)> {
+    if data.lines.is_empty() {
+        return Err(RepoError::Validation("Transfer has no lines".into()));
+    }
+    if data.lines.len() > MAX_TRANSFER_LINES {
+        return Err(RepoError::Validation(format!(
+            "Transfer exceeds {MAX_TRANSFER_LINES} lines"
+        )));
+    }
+    if data.lines.iter().any(|l| l.quantity <= 0) {
+        return Err(RepoError::Validation(
+            "Transfer quantity must be positive".into(),
+        ));
+    }
+    let mut seen = HashSet::new();
+    if !data.lines.iter().all(|l| seen.insert(l.stock_level_id)) {
+        return Err(RepoError::Validation(
+            "Each stock level may appear only once".into(),
+        ));
+    }
+
+    let id = shared::util::snowflake_id();
+    let now = shared::util::now_millis();
+    let mut tx = pool.begin().await?;
+    sqlx::query(
+        "INSERT INTO stock_transfer (id, direction, status, peer_store_id, peer_store_name, note, sent_by, sent_at, relay_pending) VALUES (?, ?, ?, ?, ?, ?, ?, ?, 1)",
+    )
+    .bind(id)
+    .bind(StockTransferDirection::Out.as_str())
+    .bind(StockTransferStatus::InTransit.as_str())
+    .bind(peer.store_id)
+    .bind(&peer.name)
+    .bind(data.note.as_deref().map(str::trim).filter(|n| !n.is_empty()))
+    .bind(operator_name)
+    .bind(now)
+    .execute(&mut *tx)
+    .await?;
+
+    let mut changed = Vec::with_capacity(data.lines.len());
+    for (line_no, item) in (1_i64..).zip(&data.lines) {
+        let updated = sqlx::query(
+            "UPDATE stock_level SET quantity = quantity - ?, updated_at = ? WHERE id = ?",
+        )
+        .bind(item.quantity)
+        .bind(now)
+        .bind(item.stock_level_id)
+        .execute(&mut *tx)
+        .await?;
+        if updated.rows_affected() == 0 {
+            return Err(RepoError::NotFound(format!(
+                "Stock level {} not found",
+                item.stock_level_id
+            )));
+        }
+        let level = sqlx::query_as::<_, StockLevel>(&format!("{STOCK_LEVEL_SELECT} WHERE id = ?"))
+            .bind(item.stock_level_id)
+            .fetch_one(&mut *tx)
+            .await?;
+        sqlx::query(
+            "INSERT INTO stock_transfer_line (id, stock_transfer_id, line_no, stock_level_id, product_name, spec_name, barcode, quantity) VALUES (?, ?, ?, ?, ?, ?, ?, ?)",
+        )
+        .bind(shared::util::snowflake_id())
+        .bind(id)
+        .bind(line_no)
+        .bind(level.id)
+        .bind(&level.product_name)
+        .bind(&level.spec_name)
+        .bind(&level.barcode)
+        .bind(item.quantity)
+        .execute(&mut *tx)
+        .await?;
+        insert_adjustment(
+            &mut tx,
+            level.id,
+            StockAdjustmentKind::TransferOut,
+            -item.quantity,
+            level.quantity,
+            None,
+            None,
+            Some(id),
+            Some(&format!("Transfer to {}", peer.name)),
+            Some(operator_id),
+            Some(operator_name),
+            now,
+        )
+        .await?;
+        let before = level.quantity + item.quantity;
+        changed.push((level, before));
+    }
+    tx.commit().await?;
+
+    let transfer = find_by_id(pool, id)
+        .await?
+        .ok_or_else(|| RepoError::Database("Failed to create stock transfer".into()))?;
+    Ok((transfer, changed))
+}
+
+/// Record a transfer relayed from the sending store (IN, in transit)
+///
+/// Lines are pre-matched to local levels by barcode, then by product and
+/// spec name. Returns None when the transfer is already known (re-delivery).
+pub async fn record_incoming(
+    pool: &SqlitePool,
+    dispatch: &TransferDispatch,
+) -> RepoResult<Option<StockTransfer>> {
+    let mut tx = pool.begin().await?;
+    let inserted = sqlx::query(
+        "INSERT OR IGNORE INTO stock_transfer (id, direction, status, peer_store_id, peer_store_name, note, sent_by, sent_at) VALUES (?, ?, ?, ?, ?, ?, ?, ?)",
+    )
+    .bind(dispatch.transfer_id)
+    .bind(StockTransferDirection::In.as_str())
+    .bind(StockTransferStatus::InTransit.as_str())
+    .bind(dispatch.store_id)
+    .bind(&dispatch.store_name)
+    .bind(&dispatch.note)
+    .bind(&dispatch.sent_by)
+    .bind(dispatch.sent_at)
+    .execute(&mut *tx)
+    .await?;
+    if inserted.rows_affected() == 0 {
+        return Ok(None);
+    }
+
+    let levels = sqlx::query_as::<_, StockLevel>(STOCK_LEVEL_SELECT)
+        .fetch_all(&mut *tx)
+        .await?;
+    for line in &dispatch.lines {
+        let level_id = match_level(&levels, line).map(|l| l.id);
+        sqlx::query(
+            "INSERT INTO stock_transfer_line (id, stock_transfer_id, line_no, stock_level_id, product_name, spec_name, barcode, quantity) VALUES (?, ?, ?, ?, ?, ?, ?, ?)",
+        )
+        .bind(shared::util::snowflake_id())
+        .bind(dispatch.transfer_id)
+        .bind(line.line_no)
+        .bind(level_id)
+        .bind(&line.product_name)
+        .bind(&line.spec_name)
+        .bind(&line.barcode)
+        .bind(line.quantity)
+        .execute(&mut *tx)
+        .await?;
+    }
+    tx.commit().await?;
+
+    find_by_id(pool, dispatch.transfer_id).await
+}
+
+/// Goods-received confirmation of an incoming transfer
+///
+/// Every line must be confirmed once, with at most the dispatched quantity;
+/// received quantities are posted as TRANSFER_IN to the chosen levels and the
+/// receipt is queued for the sending store. Returns the transfer and the
+/// changed levels with their quantity before posting.
+pub async fn receive(
+    pool: &SqlitePool,
+    id: i64,
+    data: &StockTransferReceive,
+    operator_id: i64,
+    operator_name: &str,
+) -> RepoResult<(StockTransfer, Vec<(StockLevel, i64)>)> {
+    if data.lines.iter().any(|l| l.received_quantity < 0) {
+        return Err(RepoError::Validation(
+            "Received quantity must not be negative".into(),
+        ));
+    }
+    let transfer = find_by_id(pool, id)
+        .await?
+        .ok_or_else(|| RepoError::NotFound(format!("Stock transfer {id} not found")))?;
+    if transfer.direction != StockTransferDirection::In {
+        return Err(RepoError::Validation(format!(
+            "Stock transfer {id} is not incoming"
+        )));
+    }
+    let mut seen = HashSet::new();
+    if data.lines.len() != transfer.lines.len()
+        || !data
+            .lines
+            .iter()
+            .all(|l| seen.insert(l.line_id) && transfer.lines.iter().any(|t| t.id == l.line_id))
+    {
+        return Err(RepoError::Validation(
+            "Every transfer line must be confirmed exactly once".into(),
+        ));
+    }
+    if data.lines.iter().any(|l| {
+        transfer
+            .lines
+            .iter()
+            .any(|t| t.id == l.line_id && l.received_quantity > t.quantity)
+    }) {
+        return Err(RepoError::Validation(
+            "Received quantity exceeds the dispatched quantity".into(),
+        ));
+    }
+
+    let now = shared::util::now_millis();
+    let note = format!("Transfer from {}", transfer.peer_store_name);
+    let mut tx = pool.begin().await?;
+    let updated = sqlx::query(
+        "UPDATE stock_transfer SET status = ?, received_by = ?, received_at = ?, relay_pending = 1 WHERE id = ? AND status = ?",
+    )
+    .bind(StockTransferStatus::Received.as_str())
+    .bind(operator_name)
+    .bind(now)
+    .bind(id)
+    .bind(StockTransferStatus::InTransit.as_str())
+    .execute(&mut *tx)
+    .await?;
+    if updated.rows_affected() == 0 {
+        return Err(RepoError::Validation(format!(
+            "Stock transfer {id} is already received"
+        )));
+    }
+
+    let mut changed = Vec::with_capacity(data.lines.len());
+    for line in &data.lines {
+        sqlx::query(
+            "UPDATE stock_transfer_line SET stock_level_id = ?, received_quantity = ? WHERE id = ?",
+        )
+        .bind(line.stock_level_id)
+        .bind(line.received_quantity)
+        .bind(line.line_id)
+        .execute(&mut *tx)
+        .await?;
+        let Some(stock_level_id) = line.stock_level_id else {
+            continue;
+        };
+        let updated = sqlx::query(
+            "UPDATE stock_level SET quantity = quantity + ?, updated_at = ? WHERE id = ?",
+        )
+        .bind(line.received_quantity)
+        .bind(now)
+        .bind(stock_level_id)
+        .execute(&mut *tx)
+        .await?;
+        if updated.rows_affected() == 0 {
+            return Err(RepoError::NotFound(format!(
+                "Stock level {stock_level_id} not found"
+            )));
+        }
+        if line.received_quantity == 0 {
+            continue;
+        }
+        let level = sqlx::query_as::<_, StockLevel>(&format!("{STOCK_LEVEL_SELECT} WHERE id = ?"))
+            .bind(stock_level_id)
+            .fetch_one(&mut *tx)
+            .await?;
+        insert_adjustment(
+            &mut tx,
+            level.id,
+            StockAdjustmentKind::TransferIn,
+            line.received_quantity,
+            level.quantity,
+            None,
+            None,
+            Some(id),
+            Some(&note),
+            Some(operator_id),
+            Some(operator_name),
+            now,
+        )
+        .await?;
+        let before = level.quantity - line.received_quantity;
+        changed.push((level, before));
+    }
+    tx.commit().await?;
+
+    let transfer = find_by_id(pool, id)
+        .await?
+        .ok_or_else(|| RepoError::NotFound(format!("Stock transfer {id} not found")))?;
+    Ok((transfer, changed))
+}
+
+/// Apply the receiving store's confirmation to an outgoing transfer
+///
+/// Stock was already posted on dispatch; this records the received
+/// quantities, writes any shortfall back to the sending levels as
+/// TRANSFER_RETURN and closes the document. Returns None when it was already
+/// received (re-delivery), otherwise the transfer and the levels changed by
+/// the write-back with their quantity before posting.
+pub async fn apply_receipt(
+    pool: &SqlitePool,
+    receipt: &TransferReceipt,
+) -> RepoResult<Option<(StockTransfer, Vec<(StockLevel, i64)>)>> {
+    let mut tx = pool.begin().await?;
+    let header: Option<(String, String)> =
+        sqlx::query_as("SELECT direction, peer_store_name FROM stock_transfer WHERE id = ?")
+            .bind(receipt.transfer_id)
+            .fetch_optional(&mut *tx)
+            .await?;
+    let Some((direction, peer_store_name)) = header else {
+        return Err(RepoError::NotFound(format!(
+            "Stock transfer {} not found",
+            receipt.transfer_id
+        )));
+    };
+    if StockTransferDirection::try_from(direction).map_err(RepoError::DataCorruption)?
+        != StockTransferDirection::Out
+    {
+        return Err(RepoError::Validation(format!(
+            "Stock transfer {} is not outgoing",
+            receipt.transfer_id
+        )));
+    }
+    let updated = sqlx::query(
+        "UPDATE stock_transfer SET status = ?, received_by = ?, received_at = ? WHERE id = ? AND status = ?",
+    )
+    .bind(StockTransferStatus::Received.as_str())
+    .bind(&receipt.received_by)
+    .bind(receipt.received_at)
+    .bind(receipt.transfer_id)
+    .bind(StockTransferStatus::InTransit.as_str())
+    .execute(&mut *tx)
+    .await?;
+    if updated.rows_affected() == 0 {
+        return Ok(None);
+    }
+
+    let sent: Vec<(i64, Option<i64>, i64)> = sqlx::query_as(
+        "SELECT line_no, stock_level_id, quantity FROM stock_transfer_line WHERE stock_transfer_id = ?",
+    )
+    .bind(receipt.transfer_id)
+    .fetch_all(&mut *tx)
+    .await?;
+    let now = shared::util::now_millis();
+    let note = format!("Not received by {peer_store_name}");
+    let mut changed = Vec::new();
+    for line in &receipt.lines {
+        let Some(&(_, stock_level_id, quantity)) =
+            sent.iter().find(|(line_no, _, _)| *line_no == line.line_no)
+        else {
+            continue;
+        };
+        let received = line.received_quantity.clamp(0, quantity);
+        sqlx::query(
+            "UPDATE stock_transfer_line SET received_quantity = ? WHERE stock_transfer_id = ? AND line_no = ?",
+        )
+        .bind(received)
+        .bind(receipt.transfer_id)
+        .bind(line.line_no)
+        .execute(&mut *tx)
+        .await?;
+
+        let shortfall = quantity - received;
+        let Some(stock_level_id) = stock_level_id.filter(|_| shortfall > 0) else {
+            continue;
+        };
+        let updated = sqlx::query(
+            "UPDATE stock_level SET quantity = quantity + ?, updated_at = ? WHERE id = ?",
+        )
+        .bind(shortfall)
+        .bind(now)
+        .bind(stock_level_id)
+        .execute(&mut *tx)
+        .await?;
+        // Level deleted since dispatch: nothing to write back
+        if updated.rows_affected() == 0 {
+            continue;
+        }
+        let level = sqlx::query_as::<_, StockLevel>(&format!("{STOCK_LEVEL_SELECT} WHERE id = ?"))
+            .bind(stock_level_id)
+            .fetch_one(&mut *tx)
+            .await?;
+        insert_adjustment(
+            &mut tx,
+            level.id,
+            StockAdjustmentKind::TransferReturn,
+            shortfall,
+            level.quantity,
+            None,
+            None,
+            Some(receipt.transfer_id),
+            Some(&note),
+            None,
+            None,
+            now,
+        )
+        .await?;
+        let before = level.quantity - shortfall;
+        changed.push((level, before));
+    }
+    tx.commit().await?;
+
+    let transfer = find_by_id(pool, receipt.transfer_id)
+        .await?
+        .ok_or_else(|| {
+            RepoError::NotFound(format!("Stock transfer {} not found", receipt.transfer_id))
+        })?;
+    Ok(Some((transfer, changed)))
+}
+
+/// Barcode first, then product + spec name
+fn match_level<'a>(
+    levels: &'a [StockLevel],
+    line: &TransferDispatchLine,
+) -> Option<&'a StockLevel> {
+    line.barcode
+        .as_deref()
+        .and_then(|barcode| {
+            levels
+                .iter()
+                .find(|l| l.barcode.as_deref() == Some(barcode))
+        })
+        .or_else(|| {
+            levels
+                .iter()
+                .find(|l| l.product_name == line.product_name && l.spec_name == line.spec_name)
+        })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::db::repository::inventory;
+    use crate::db::test_pool;
+    use shared::cloud::transfer::TransferReceiptLine;
+    use shared::models::{StockLevelCreate, StockTransferItem, StockTransferReceiveLine};
+
+    async fn level(
+        pool: &SqlitePool,
+        product_id: i64,
+        name: &str,
+        quantity: i64,
+        barcode: Option<&str>,
+    ) -> StockLevel {
+        inventory::create(
+            pool,
+            &StockLevelCreate {
+                product_id,
+                spec_id: None,
+                quantity,
+                low_stock_threshold: None,
+                barcode: barcode.map(str::to_string),
+            },
+            name,
+            None,
+            1,
+            "Ana",
+        )
+        .await
+        .unwrap()
+    }
+
+    async fn quantity(pool: &SqlitePool, id: i64) -> i64 {
+        inventory::find_by_id(pool, id)
+            .await
+            .unwrap()
+            .unwrap()
+            .quantity
+    }
+
+    #[tokio::test]
+    async fn test_transfer_between_two_stores() {
+        let source = test_pool().await;
+        let destination = test_pool().await;
+        let beer = level(&source, 10, "Beer", 48, Some("8410")).await;
+        let wine = level(&source, 11, "Wine", 12, None).await;
+        // Product ids differ between stores: matched by barcode, then by name
+        let local_beer = level(&destination, 90, "Beer", 5, Some("8410")).await;
+        let local_wine = level(&destination, 91, "Wine", 0, None).await;
+
+        let peer = TransferPeer {
+            store_id: 2,
+            name: "Centro".into(),
+        };
+        let (sent, changed) = create(
+            &source,
+            &StockTransferCreate {
+                to_store_id: 2,
+                note: Some("Weekend".into()),
+                lines: vec![
+                    StockTransferItem {
+                        stock_level_id: beer.id,
+                        quantity: 24,
+                    },
+                    StockTransferItem {
+                        stock_level_id: wine.id,
+                        quantity: 6,
+                    },
+                ],
+            },
+            &peer,
+            1,
+            "Ana",
+        )
+        .await
+        .unwrap();
+        assert_eq!(sent.status, StockTransferStatus::InTransit);
+        assert_eq!((changed[0].0.quantity, changed[0].1), (24, 48));
+        assert_eq!(quantity(&source, wine.id).await, 6);
+        let trail = inventory::find_adjustments(&source, beer.id, 10)
+            .await
+            .unwrap();
+        assert_eq!(trail[0].kind, StockAdjustmentKind::TransferOut);
+        assert_eq!(trail[0].stock_transfer_id, Some(sent.id));
+        assert_eq!(find_relay_pending(&source).await.unwrap().len(), 1);
+        clear_relay_pending(&source, sent.id).await.unwrap();
+        assert!(find_relay_pending(&source).await.unwrap().is_empty());
+
+        // Relayed by crab-cloud (counterpart = source store)
+        let dispatch = TransferDispatch {
+            transfer_id: sent.id,
+            store_id: 1,
+            store_name: "Playa".into(),
+            note: sent.note.clone(),
+            sent_by: sent.sent_by.clone(),
+            sent_at: sent.sent_at,
+            lines: sent
+                .lines
+                .iter()
+                .map(|l| TransferDispatchLine {
+                    line_no: l.line_no,
+                    product_name: l.product_name.clone(),
+                    spec_name: l.spec_name.clone(),
+                    barcode: l.barcode.clone(),
+                    quantity: l.quantity,
+                })
+                .collect(),
+        };
+        let incoming = record_incoming(&destination, &dispatch)
+            .await
+            .unwrap()
+            .unwrap();
+        assert_eq!(incoming.direction, StockTransferDirection::In);
+        assert_eq!(incoming.peer_store_name, "Playa");
+        assert_eq!(incoming.lines[0].stock_level_id, Some(local_beer.id));
+        assert_eq!(incoming.lines[1].stock_level_id, Some(local_wine.id));
+        // Re-delivery is ignored
+        assert!(
+            record_incoming(&destination, &dispatch)
+                .await
+                .unwrap()
+                .is_none()
+        );
+        assert!(find_relay_pending(&destination).await.unwrap().is_empty());
+
+        // One case of wine missing on arrival
+        let over = StockTransferReceive {
+            lines: vec![
+                StockTransferReceiveLine {
+                    line_id: incoming.lines[0].id,
+                    stock_level_id: Some(local_beer.id),
+                    received_quantity: 25,
+                },
+                StockTransferReceiveLine {
+                    line_id: incoming.lines[1].id,
+                    stock_level_id: Some(local_wine.id),
+                    received_quantity: 6,
+                },
+            ],
+        };
+        assert!(matches!(
+            receive(&destination, incoming.id, &over, 2, "Luis").await,
+            Err(RepoError::Validation(_))
+        ));
+        let receipt = StockTransferReceive {
+            lines: vec![
+                StockTransferReceiveLine {
+                    line_id: incoming.lines[0].id,
+                    stock_level_id: Some(local_beer.id),
+                    received_quantity: 24,
+                },
+                StockTransferReceiveLine {
+                    line_id: incoming.lines[1].id,
+                    stock_level_id: Some(local_wine.id),
+                    received_quantity: 5,
+                },
+            ],
+        };
+        assert!(matches!(
+            receive(
+                &destination,
+                incoming.id,
+                &StockTransferReceive {
+                    lines: receipt.lines[..1].to_vec(),
+                },
+                2,
+                "Luis",
+            )
+            .await,
+            Err(RepoError::Validation(_))
+        ));
+        let (received, changed) = receive(&destination, incoming.id, &receipt, 2, "Luis")
+            .await
+            .unwrap();
+        assert_eq!(received.status, StockTransferStatus::Received);
+        assert_eq!(changed.len(), 2);
+        assert_eq!(quantity(&destination, local_beer.id).await, 29);
+        assert_eq!(quantity(&destination, local_wine.id).await, 5);
+        assert!(matches!(
+            receive(&destination, incoming.id, &receipt, 2, "Luis").await,
+            Err(RepoError::Validation(_))
+        ));
+        assert_eq!(find_relay_pending(&destination).await.unwrap().len(), 1);
+
+        // Confirmation relayed back to the source store
+        let confirmation = TransferReceipt {
+            transfer_id: sent.id,
+            store_id: 2,
+            received_by: "Luis".into(),
+            received_at: received.received_at.unwrap(),
+            lines: received
+                .lines
+                .iter()
+                .map(|l| TransferReceiptLine {
+                    line_no: l.line_no,
+                    received_quantity: l.received_quantity.unwrap(),
+                })
+                .collect(),
+        };
+        let (closed, changed) = apply_receipt(&source, &confirmation)
+            .await
+            .unwrap()
+            .unwrap();
+        assert_eq!(closed.status, StockTransferStatus::Received);
+        assert_eq!(closed.received_by.as_deref(), Some("Luis"));
+        assert_eq!(closed.lines[1].received_quantity, Some(5));
+        // Beer left on dispatch; the missing wine case is written back
+        assert_eq!(quantity(&source, beer.id).await, 24);
+        assert_eq!(changed.len(), 1);
+        assert_eq!((changed[0].0.id, changed[0].1), (wine.id, 6));
+        assert_eq!(quantity(&source, wine.id).await, 7);
+        assert!(
+            apply_receipt(&source, &confirmation)
+                .await
+                .unwrap()
+                .is_none()
+        );
+    }
+
+    #[tokio::test]
+    async fn test_partial_receipt_writes_back_shortfall() {
+        let pool = test_pool().await;
+        let beer = level(&pool, 10, "Beer", 30, None).await;
+        let wine = level(&pool, 11, "Wine", 12, None).await;
+        let peer = TransferPeer {
+            store_id: 2,
+            name: "Centro".into(),
+        };
+        let (sent, _) = create(
+            &pool,
+            &StockTransferCreate {
+                to_store_id: 2,
+                note: None,
+                lines: vec![
+                    StockTransferItem {
+                        stock_level_id: beer.id,
+                        quantity: 10,
+                    },
+                    StockTransferItem {
+                        stock_level_id: wine.id,
+                        quantity: 4,
+                    },
+                ],
+            },
+            &peer,
+            1,
+            "Ana",
+        )
+        .await
+        .unwrap();
+        assert_eq!(quantity(&pool, beer.id).await, 20);
+
+        // 7 of 10 beers arrived; the receiver over-reports the wine
+        let receipt = TransferReceipt {
+            transfer_id: sent.id,
+            store_id: 2,
+            received_by: "Luis".into(),
+            received_at: sent.sent_at + 1,
+            lines: vec![
+                TransferReceiptLine {
+                    line_no: 1,
+                    received_quantity: 7,
+                },
+                TransferReceiptLine {
+                    line_no: 2,
+                    received_quantity: 9,
+                },
+            ],
+        };
+        let (closed, changed) = apply_receipt(&pool, &receipt).await.unwrap().unwrap();
+        assert_eq!(closed.lines[0].received_quantity, Some(7));
+        assert_eq!(closed.lines[1].received_quantity, Some(4));
+        assert_eq!(changed.len(), 1);
+        assert_eq!((changed[0].0.quantity, changed[0].1), (23, 20));
+        assert_eq!(quantity(&pool, beer.id).await, 23);
+        assert_eq!(quantity(&pool, wine.id).await, 8);
+
+        let trail = inventory::find_adjustments(&pool, beer.id, 10)
+            .await
+            .unwrap();
+        assert_eq!(trail[0].kind, StockAdjustmentKind::TransferReturn);
+        assert_eq!(trail[0].delta, 3);
+        assert_eq!(trail[0].stock_transfer_id, Some(sent.id));
+        assert_eq!(trail[0].note.as_deref(), Some("Not received by Centro"));
+
+        // Re-delivery writes nothing back again
+        assert!(apply_receipt(&pool, &receipt).await.unwrap().is_none());
+        assert_eq!(quantity(&pool, beer.id).await, 23);
+    }
+}
@@ -29,7 +29,7 @@ pub async fn get_or_create(pool: &SqlitePool) -> RepoResult<StoreInfo> {
 
 pub async fn get(pool: &SqlitePool) -> RepoResult<Option<StoreInfo>> {
     let info = sqlx::query_as::<_, StoreInfo>(
-        "SELECT id, name, address, nif, logo_url, phone, email, website, business_day_cutoff, currency_code, currency_symbol, currency_decimal_places, timezone, receipt_locale, receipt_header, receipt_footer, created_at, updated_at FROM store_info WHERE id = ?",
+        "SELECT id, name, address, nif, logo_url, phone, email, website, business_day_cutoff, currency_code, currency_symbol, currency_decimal_places, timezone, receipt_locale, receipt_header, receipt_footer, tax_mode, payment_surcharge_enabled, payment_surcharge_disclaimer, receipt_archive_enabled, receipt_archive_cloud, carry_over_policy, daily_report_print_destination_id, max_cash_change, created_at, updated_at FROM store_info WHERE id = ?",
     )
     .bind(SINGLETON_ID)
     .fetch_optional(pool)
@@ -40,7 +40,7 @@ pub async fn get(pool: &SqlitePool) -> RepoResult<Option<StoreInfo>> {
 pub async fn update(pool: &SqlitePool, data: StoreInfoUpdate) -> RepoResult<StoreInfo> {
     let now = shared::util::now_millis();
     let rows = sqlx::query(
-        "UPDATE store_info SET name = COALESCE(?1, name), address = COALESCE(?2, address), nif = COALESCE(?3, nif), logo_url = COALESCE(?4, logo_url), phone = COALESCE(?5, phone), email = COALESCE(?6, email), website = COALESCE(?7, website), business_day_cutoff = COALESCE(?8, business_day_cutoff), currency_code = COALESCE(?9, currency_code), currency_symbol = COALESCE(?10, currency_symbol), currency_decimal_places = COALESCE(?11, currency_decimal_places), timezone = COALESCE(?12, timezone), receipt_locale = COALESCE(?13, receipt_locale), receipt_header = COALESCE(?14, receipt_header), receipt_footer = COALESCE(?15, receipt_footer), updated_at = ?16 WHERE id = ?17",
+        "UPDATE store_info SET name = COALESCE(?1, name), address = COALESCE(?2, address), nif = COALESCE(?3, nif), logo_url = COALESCE(?4, logo_url), phone = COALESCE(?5, phone), email = COALESCE(?6, email), website = COALESCE(?7, website), business_day_cutoff = COALESCE(?8, business_day_cutoff), currency_code = COALESCE(?9, currency_code), currency_symbol = COALESCE(?10, currency_symbol), currency_decimal_places = COALESCE(?11, currency_decimal_places), timezone = COALESCE(?12, timezone), receipt_locale = COALESCE(?13, receipt_locale), receipt_header = COALESCE(?14, receipt_header), receipt_footer = COALESCE(?15, receipt_footer), tax_mode = COALESCE(?16, tax_mode), payment_surcharge_enabled = COALESCE(?17, payment_surcharge_enabled), payment_surcharge_disclaimer = COALESCE(?18, payment_surcharge_disclaimer), receipt_archive_enabled = COALESCE(?19, receipt_archive_enabled), receipt_archive_cloud = COALESCE(?20, receipt_archive_cloud), carry_over_policy = COALESCE(?21, carry_over_policy), daily_report_print_destination_id = CASE WHEN ?22 IS NULL THEN daily_report_print_destination_id ELSE NULLIF(?22, 0) END, max_cash_change = COALESCE(?23, max_cash_change), updated_at = ?24 WHERE id = ?25",
     )
     .bind(&data.name)
     .bind(&data.address)
@@ -57,6 +57,14 @@ pub async fn update(pool: &SqlitePool, data: StoreInfoUpdate) -> RepoResult<Stor
     .bind(&data.receipt_locale)
     .bind(&data.receipt_header)
     .bind(&data.receipt_footer)
+    .bind(data.tax_mode.map(|m| m.as_str()))
+    .bind(data.payment_surcharge_enabled)
+    .bind(&data.payment_surcharge_disclaimer)
+    .bind(data.receipt_archive_enabled)
+    .bind(data.receipt_archive_cloud)
+    .bind(data.carry_over_policy.map(|p| p.as_str()))
+    .bind(data.daily_report_print_destination_id)
+    .bind(data.max_cash_change)
     .bind(now)
     .bind(SINGLETON_ID)
     .execute(pool)
@@ -69,3 +77,50 @@ pub async fn update(pool: &SqlitePool, data: StoreInfoUpdate) -> RepoResult<Stor
         .await?
         .ok_or_else(|| RepoError::Database("Failed to read store info after update".into()))
 }
+
+/// Survey code signing key, generated on first use
+pub async fn survey_secret(pool: &SqlitePool) -> RepoResult<String> {
+    get_or_create(pool).await?;
+    sqlx::query(
+        "UPDATE store_info SET survey_secret = lower(hex(randomblob(32))) WHERE id = ? AND survey_secret IS NULL",
+    )
+    .bind(SINGLETON_ID)
+    .execute(pool)
+    .await?;
+    let secret: String = sqlx::query_scalar("SELECT survey_secret FROM store_info WHERE id = ?")
+        .bind(SINGLETON_ID)
+        .fetch_one(pool)
+        .await?;
+    Ok(secret)
+}
+
+/// Replace the survey code signing key (codes on earlier receipts stop verifying)
+pub async fn rotate_survey_secret(pool: &SqlitePool) -> RepoResult<String> {
+    get_or_create(pool).await?;
+    let secret: String = sqlx::query_scalar(
+        "UPDATE store_info SET survey_secret = lower(hex(randomblob(32))) WHERE id = ? RETURNING survey_secret",
+    )
+    .bind(SINGLETON_ID)
+    .fetch_one(pool)
+    .await?;
+    Ok(secret)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::db::test_pool;
+
+    #[tokio::test]
+    async fn test_survey_secret_stable_until_rotated() {
+        let pool = test_pool().await;
+
+        let secret = survey_secret(&pool).await.unwrap();
+        assert_eq!(secret.len(), 64);
+        assert_eq!(survey_secret(&pool).await.unwrap(), secret);
+
+        let rotated = rotate_survey_secret(&pool).await.unwrap();
+        assert_ne!(rotated, secret);
+        assert_eq!(survey_secret(&pool).await.unwrap(), rotated);
+    }
+}
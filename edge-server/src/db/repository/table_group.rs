@@ -0,0 +1,319 @@
+//! Table Group Repository (拼桌)
+
+use super::{RepoError, RepoResult};
+use shared::error::ErrorCode;
+use shared::models::{DiningTable, TableGroup, table_group_name};
+use sqlx::SqlitePool;
+
+type GroupRow = (i64, i64, i64, String, i64, String, i64, i64);
+
+const GROUP_SELECT: &str = "SELECT id, host_table_id, zone_id, name, created_by_id, created_by_name, created_at, updated_at FROM table_group";
+
+async fn load_group(pool: &SqlitePool, row: GroupRow) -> RepoResult<TableGroup> {
+    let (id, host_table_id, zone_id, name, created_by_id, created_by_name, created_at, updated_at) =
+        row;
+    let members = find_member_tables(pool, id).await?;
+    Ok(TableGroup {
+        id,
+        host_table_id,
+        zone_id,
+        name,
+        table_ids: members.iter().map(|t| t.id).collect(),
+        capacity: members.iter().map(|t| t.capacity).sum(),
+        created_by_id,
+        created_by_name,
+        created_at,
+        updated_at,
+    })
+}
+
+/// Member tables in join order (host first)
+async fn find_member_tables(pool: &SqlitePool, group_id: i64) -> RepoResult<Vec<DiningTable>> {
+    let tables = sqlx::query_as::<_, DiningTable>(
+        "SELECT t.id, t.name, t.zone_id, t.capacity, t.is_active FROM table_group_member m JOIN dining_table t ON t.id = m.table_id WHERE m.group_id = ? ORDER BY m.sort_order",
+    )
+    .bind(group_id)
+    .fetch_all(pool)
+    .await?;
+    Ok(tables)
+}
+
+pub async fn find_all(pool: &SqlitePool) -> RepoResult<Vec<TableGroup>> {
+    let rows = sqlx::query_as::<_, GroupRow>(&format!("{GROUP_SELECT} ORDER BY created_at"))
+        .fetch_all(pool)
+        .await?;
+    let mut groups = Vec::with_capacity(rows.len());
+    for row in rows {
+        groups.push(load_group(pool, row).await?);
+    }
+    Ok(groups)
+}
+
+pub async fn find_by_host(pool: &SqlitePool, host_table_id: i64) -> RepoResult<Option<TableGroup>> {
+    let row = sqlx::query_as::<_, GroupRow>(&format!("{GROUP_SELECT} WHERE host_table_id = ?"))
+        .bind(host_table_id)
+        .fetch_optional(pool)
+        .await?;
+    match row {
+        Some(row) => Ok(Some(load_group(pool, row).await?)),
+        None => Ok(None),
+    }
+}
+
+/// Group containing the table (as host or member)
+pub async fn find_by_table(pool: &SqlitePool, table_id: i64) -> RepoResult<Option<TableGroup>> {
+    let row = sqlx::query_as::<_, GroupRow>(&format!(
+        "{GROUP_SELECT} WHERE id = (SELECT group_id FROM table_group_member WHERE table_id = ?)"
+    ))
+    .bind(table_id)
+    .fetch_optional(pool)
+    .await?;
+    match row {
+        Some(row) => Ok(Some(load_group(pool, row).await?)),
+        None => Ok(None),
+    }
+}
+
+async fn require_table(pool: &SqlitePool, id: i64) -> RepoResult<DiningTable> {
+    super::dining_table::find_by_id(pool, id)
+        .await?
+        .filter(|t| t.is_active)
+        .ok_or_else(|| {
+            RepoError::Business(ErrorCode::TableNotFound, format!("Table {id} not found"))
+        })
+}
+
+/// Join tables into the host table's group (created on first join)
+pub async fn join(
+    pool: &SqlitePool,
+    host_table_id: i64,
+    table_ids: &[i64],
+    operator_id: i64,
+    operator_name: &str,
+) -> RepoResult<TableGroup> {
+    let host = require_table(pool, host_table_id).await?;
+    let existing = find_by_table(pool, host_table_id).await?;
+    if let Some(group) = &existing
+        && group.host_table_id != host_table_id
+    {
+        return Err(RepoError::Business(
+            ErrorCode::TableAlreadyJoined,
+            format!("Table {} is joined to {}", host.name, group.name),
+        ));
+    }
+
+    let mut added = Vec::new();
+    for &id in table_ids {
+        if id == host_table_id || added.iter().any(|t: &DiningTable| t.id == id) {
+            continue;
+        }
+        let table = require_table(pool, id).await?;
+        if table.zone_id != host.zone_id {
+            return Err(RepoError::Validation(format!(
+                "Table {} is in a different zone",
+                table.name
+            )));
+        }
+        if let Some(group) = find_by_table(pool, id).await? {
+            return Err(RepoError::Business(
+                ErrorCode::TableAlreadyJoined,
+                format!("Table {} is joined to {}", table.name, group.name),
+            ));
+        }
+        added.push(table);
+    }
+    if added.is_empty() {
+        return Err(RepoError::Validation(
+            "At least one other table is required".into(),
+        ));
+    }
+
+    let now = shared::util::now_millis();
+    // 事务外读取现有成员：事务占用连接期间不能再从池中取连接
+    let current_members = match &existing {
+        Some(group) => find_member_tables(pool, group.id).await?,
+        None => Vec::new(),
+    };
+    let mut tx = pool.begin().await?;
+    let (group_id, mut members) = match &existing {
+        Some(group) => (group.id, current_members),
+        None => {
+            let id = shared::util::snowflake_id();
+            sqlx::query(
+                "INSERT INTO table_group (id, host_table_id, zone_id, name, created_by_id, created_by_name, created_at, updated_at) VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7, ?7)",
+            )
+            .bind(id)
+            .bind(host_table_id)
+            .bind(host.zone_id)
+            .bind(&host.name)
+            .bind(operator_id)
+            .bind(operator_name)
+            .bind(now)
+            .execute(&mut *tx)
+            .await?;
+            sqlx::query(
+                "INSERT INTO table_group_member (table_id, group_id, sort_order) VALUES (?, ?, 0)",
+            )
+            .bind(host_table_id)
+            .bind(id)
+            .execute(&mut *tx)
+            .await?;
+            (id, vec![host.clone()])
+        }
+    };
+    for table in added {
+        sqlx::query(
+            "INSERT INTO table_group_member (table_id, group_id, sort_order) VALUES (?1, ?2, (SELECT COALESCE(MAX(sort_order), -1) + 1 FROM table_group_member WHERE group_id = ?2))",
+        )
+        .bind(table.id)
+        .bind(group_id)
+        .execute(&mut *tx)
+        .await?;
+        members.push(table);
+    }
+    sqlx::query("UPDATE table_group SET name = ?1, updated_at = ?2 WHERE id = ?3")
+        .bind(table_group_name(&members))
+        .bind(now)
+        .bind(group_id)
+        .execute(&mut *tx)
+        .await?;
+    tx.commit().await?;
+
+    find_by_host(pool, host_table_id)
+        .await?
+        .ok_or_else(|| RepoError::Database("Failed to create table group".into()))
+}
+
+/// Detach tables from the host's group (empty = dissolve)
+///
+/// Returns the remaining group, or `None` once only the host is left.
+pub async fn split(
+    pool: &SqlitePool,
+    host_table_id: i64,
+    table_ids: &[i64],
+) -> RepoResult<Option<TableGroup>> {
+    let group = find_by_host(pool, host_table_id).await?.ok_or_else(|| {
+        RepoError::Business(
+            ErrorCode::TableGroupNotFound,
+            format!("Table {host_table_id} is not the host of a table group"),
+        )
+    })?;
+    if let Some(id) = table_ids.iter().find(|id| !group.contains(**id)) {
+        return Err(RepoError::Validation(format!(
+            "Table {id} is not part of {}",
+            group.name
+        )));
+    }
+
+    let members = find_member_tables(pool, group.id).await?;
+    let remaining: Vec<DiningTable> = members
+        .into_iter()
+        .filter(|t| t.id == host_table_id || !(table_ids.is_empty() || table_ids.contains(&t.id)))
+        .collect();
+
+    let mut tx = pool.begin().await?;
+    if remaining.len() <= 1 {
+        sqlx::query("DELETE FROM table_group_member WHERE group_id = ?")
+            .bind(group.id)
+            .execute(&mut *tx)
+            .await?;
+        sqlx::query("DELETE FROM table_group WHERE id = ?")
+            .bind(group.id)
+            .execute(&mut *tx)
+            .await?;
+        tx.commit().await?;
+        return Ok(None);
+    }
+    for &id in table_ids.iter().filter(|id| **id != host_table_id) {
+        sqlx::query("DELETE FROM table_group_member WHERE table_id = ? AND group_id = ?")
+            .bind(id)
+            .bind(group.id)
+            .execute(&mut *tx)
+            .await?;
+    }
+    sqlx::query("UPDATE table_group SET name = ?1, updated_at = ?2 WHERE id = ?3")
+        .bind(table_group_name(&remaining))
+        .bind(shared::util::now_millis())
+        .bind(group.id)
+        .execute(&mut *tx)
+        .await?;
+    tx.commit().await?;
+
+    find_by_host(pool, host_table_id).await
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::db::test_pool;
+    use shared::models::{DiningTableCreate, ZoneCreate};
+
+    async fn tables(pool: &SqlitePool, names: &[&str]) -> Vec<i64> {
+        let zone = super::super::zone::create(
+            pool,
+            None,
+            ZoneCreate {
+                name: "Terraza".to_string(),
+                description: None,
+            },
+        )
+        .await
+        .unwrap();
+        let mut ids = Vec::new();
+        for name in names {
+            let table = super::super::dining_table::create(
+                pool,
+                None,
+                DiningTableCreate {
+                    name: name.to_string(),
+                    zone_id: zone.id,
+                    capacity: Some(4),
+                },
+            )
+            .await
+            .unwrap();
+            ids.push(table.id);
+        }
+        ids
+    }
+
+    #[tokio::test]
+    async fn test_join_and_split_back() {
+        let pool = test_pool().await;
+        let ids = tables(&pool, &["5", "6", "7"]).await;
+
+        let group = join(&pool, ids[0], &[ids[1]], 1, "Ana").await.unwrap();
+        assert_eq!(group.name, "5+6");
+        assert_eq!(group.capacity, 8);
+
+        let group = join(&pool, ids[0], &[ids[2]], 1, "Ana").await.unwrap();
+        assert_eq!(group.name, "5+6+7");
+        assert_eq!(group.table_ids, ids);
+
+        let group = split(&pool, ids[0], &[ids[1]]).await.unwrap().unwrap();
+        assert_eq!(group.name, "5+7");
+        assert!(find_by_table(&pool, ids[1]).await.unwrap().is_none());
+
+        assert!(split(&pool, ids[0], &[]).await.unwrap().is_none());
+        assert!(find_all(&pool).await.unwrap().is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_join_rejects_table_in_another_group() {
+        let pool = test_pool().await;
+        let ids = tables(&pool, &["1", "2", "3"]).await;
+        join(&pool, ids[0], &[ids[1]], 1, "Ana").await.unwrap();
+
+        let err = join(&pool, ids[2], &[ids[1]], 1, "Ana").await.unwrap_err();
+        assert!(matches!(
+            err,
+            RepoError::Business(ErrorCode::TableAlreadyJoined, _)
+        ));
+        // A member cannot host its own group
+        let err = join(&pool, ids[1], &[ids[2]], 1, "Ana").await.unwrap_err();
+        assert!(matches!(
+            err,
+            RepoError::Business(ErrorCode::TableAlreadyJoined, _)
+        ));
+    }
+}
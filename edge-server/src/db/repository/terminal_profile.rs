@@ -0,0 +1,103 @@
+//! Terminal Profile Repository (终端漫游配置)
+//!
+//! 每个终端身份一行，保存即整体覆盖 `settings` 并递增 `version`。
+
+use super::{RepoError, RepoResult};
+use shared::models::{MAX_TERMINAL_PROFILE_SIZE, TerminalProfile};
+use sqlx::SqlitePool;
+
+const SELECT_COLUMNS: &str = "SELECT identity, settings, version, updated_at FROM terminal_profile";
+
+pub async fn find(pool: &SqlitePool, identity: &str) -> RepoResult<Option<TerminalProfile>> {
+    let profile =
+        sqlx::query_as::<_, TerminalProfile>(&format!("{SELECT_COLUMNS} WHERE identity = ?"))
+            .bind(identity)
+            .fetch_optional(pool)
+            .await?;
+    Ok(profile)
+}
+
+pub async fn find_all(pool: &SqlitePool) -> RepoResult<Vec<TerminalProfile>> {
+    let profiles =
+        sqlx::query_as::<_, TerminalProfile>(&format!("{SELECT_COLUMNS} ORDER BY identity"))
+            .fetch_all(pool)
+            .await?;
+    Ok(profiles)
+}
+
+/// Save (replace) a terminal's settings, bumping its version
+pub async fn upsert(
+    pool: &SqlitePool,
+    identity: &str,
+    settings: &serde_json::Map<String, serde_json::Value>,
+) -> RepoResult<TerminalProfile> {
+    if identity.trim().is_empty() {
+        return Err(RepoError::Validation(
+            "Terminal identity must not be empty".into(),
+        ));
+    }
+    let settings_json = serde_json::to_string(settings)
+        .map_err(|e| RepoError::Validation(format!("Invalid terminal settings: {e}")))?;
+    if settings_json.len() > MAX_TERMINAL_PROFILE_SIZE {
+        return Err(RepoError::Validation(format!(
+            "Terminal settings exceed {MAX_TERMINAL_PROFILE_SIZE} bytes"
+        )));
+    }
+
+    sqlx::query(
+        "INSERT INTO terminal_profile (identity, settings, version, updated_at) VALUES (?1, ?2, 1, ?3) \
+         ON CONFLICT(identity) DO UPDATE SET settings = ?2, version = version + 1, updated_at = ?3",
+    )
+    .bind(identity)
+    .bind(&settings_json)
+    .bind(shared::util::now_millis())
+    .execute(pool)
+    .await?;
+
+    find(pool, identity)
+        .await?
+        .ok_or_else(|| RepoError::Database("Failed to save terminal profile".into()))
+}
+
+pub async fn delete(pool: &SqlitePool, identity: &str) -> RepoResult<bool> {
+    let result = sqlx::query("DELETE FROM terminal_profile WHERE identity = ?")
+        .bind(identity)
+        .execute(pool)
+        .await?;
+    Ok(result.rows_affected() > 0)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::db::test_pool;
+
+    #[tokio::test]
+    async fn test_upsert_replaces_settings_and_bumps_version() {
+        let pool = test_pool().await;
+        assert!(find(&pool, "pos-01").await.unwrap().is_none());
+
+        let settings = serde_json::json!({"default_zone_id": 3, "printer_receipt": "EPSON"});
+        let first = upsert(&pool, "pos-01", settings.as_object().unwrap())
+            .await
+            .unwrap();
+        assert_eq!(first.version, 1);
+        assert_eq!(first.settings["default_zone_id"], 3);
+
+        let settings = serde_json::json!({"default_zone_id": 5});
+        let second = upsert(&pool, "pos-01", settings.as_object().unwrap())
+            .await
+            .unwrap();
+        assert_eq!(second.version, 2);
+        assert!(!second.settings.contains_key("printer_receipt"));
+
+        assert!(
+            upsert(&pool, " ", settings.as_object().unwrap())
+                .await
+                .is_err()
+        );
+        assert_eq!(find_all(&pool).await.unwrap().len(), 1);
+        assert!(delete(&pool, "pos-01").await.unwrap());
+        assert!(!delete(&pool, "pos-01").await.unwrap());
+    }
+}
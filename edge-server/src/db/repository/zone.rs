@@ -2,7 +2,9 @@
 
 use super::{RepoError, RepoResult};
 use shared::error::ErrorCode;
-use shared::models::{Zone, ZoneCreate, ZoneUpdate};
+use shared::models::{
+    Zone, ZoneCreate, ZoneProductOverride, ZoneProductOverrideUpsert, ZoneUpdate,
+};
 use sqlx::SqlitePool;
 
 pub async fn find_all_with_inactive(pool: &SqlitePool) -> RepoResult<Vec<Zone>> {
@@ -100,3 +102,71 @@ pub async fn delete(pool: &SqlitePool, id: i64) -> RepoResult<bool> {
         .await?;
     Ok(true)
 }
+
+// ── Zone Product Overrides ──
+
+pub async fn find_all_overrides(pool: &SqlitePool) -> RepoResult<Vec<ZoneProductOverride>> {
+    let overrides = sqlx::query_as::<_, ZoneProductOverride>(
+        "SELECT id, zone_id, product_id, price, is_available, updated_at FROM zone_product_override",
+    )
+    .fetch_all(pool)
+    .await?;
+    Ok(overrides)
+}
+
+pub async fn find_overrides_by_zone(
+    pool: &SqlitePool,
+    zone_id: i64,
+) -> RepoResult<Vec<ZoneProductOverride>> {
+    let overrides = sqlx::query_as::<_, ZoneProductOverride>(
+        "SELECT id, zone_id, product_id, price, is_available, updated_at FROM zone_product_override WHERE zone_id = ? ORDER BY product_id",
+    )
+    .bind(zone_id)
+    .fetch_all(pool)
+    .await?;
+    Ok(overrides)
+}
+
+pub async fn upsert_override(
+    pool: &SqlitePool,
+    zone_id: i64,
+    product_id: i64,
+    data: ZoneProductOverrideUpsert,
+) -> RepoResult<ZoneProductOverride> {
+    let id = shared::util::snowflake_id();
+    let now = shared::util::now_millis();
+    sqlx::query(
+        "INSERT INTO zone_product_override (id, zone_id, product_id, price, is_available, updated_at) VALUES (?1, ?2, ?3, ?4, ?5, ?6) ON CONFLICT(zone_id, product_id) DO UPDATE SET price = excluded.price, is_available = excluded.is_available, updated_at = excluded.updated_at",
+    )
+    .bind(id)
+    .bind(zone_id)
+    .bind(product_id)
+    .bind(data.price)
+    .bind(data.is_available)
+    .bind(now)
+    .execute(pool)
+    .await?;
+    sqlx::query_as::<_, ZoneProductOverride>(
+        "SELECT id, zone_id, product_id, price, is_available, updated_at FROM zone_product_override WHERE zone_id = ? AND product_id = ?",
+    )
+    .bind(zone_id)
+    .bind(product_id)
+    .fetch_optional(pool)
+    .await?
+    .ok_or_else(|| RepoError::Database("Failed to upsert zone override".into()))
+}
+
+pub async fn delete_override(
+    pool: &SqlitePool,
+    zone_id: i64,
+    product_id: i64,
+) -> RepoResult<Option<ZoneProductOverride>> {
+    let removed = sqlx::query_as::<_, ZoneProductOverride>(
+        "DELETE FROM zone_product_override WHERE zone_id = ? AND product_id = ? RETURNING id, zone_id, product_id, price, is_available, updated_at",
+    )
+    .bind(zone_id)
+    .bind(product_id)
+    .fetch_optional(pool)
+    .await?;
+    Ok(removed)
+}
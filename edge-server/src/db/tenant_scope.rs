@@ -0,0 +1,257 @@
+//! 租户作用域守卫
+//!
+//! 每个租户拥有独立的 SQLite 文件（`{tenant}/server/data/main.db`），
+//! 数据库首次在某租户凭证下打开时记录归属租户（`tenant_scope` 单行表）。
+//! 之后任何以其他租户凭证打开该数据库的尝试（工作目录配置错误、
+//! 切换租户后复用旧目录、托管模式目录重叠）都会被拒绝并记录安全日志，
+//! 确保 API、后台任务和云同步只能读写当前租户的数据。
+//!
+//! 运行期间的凭证变化（重新激活、从磁盘重载）经 [`TenantPool::verify`] 校验：
+//! 归属不一致时关闭连接池，此后所有仓储访问立即失败。
+
+use std::sync::Arc;
+
+use sqlx::SqlitePool;
+
+use crate::utils::{AppError, AppResult};
+
+/// 带租户归属校验的连接池句柄
+///
+/// 与 `ServerState.pool` 共享同一个连接池。激活凭证进入内存前必须通过
+/// [`verify`](Self::verify)；跨租户访问被拒绝后连接池即被关闭，
+/// 任何仍持有该池的 API、后台任务或云同步再取连接都会立即失败，不会读到其他租户的数据。
+#[derive(Clone, Debug)]
+pub struct TenantPool {
+    pool: SqlitePool,
+    db_path: Arc<str>,
+}
+
+impl TenantPool {
+    pub fn new(pool: SqlitePool, db_path: &str) -> Self {
+        Self {
+            pool,
+            db_path: Arc::from(db_path),
+        }
+    }
+
+    /// 以 `tenant_id` 的凭证使用数据库：未绑定时绑定，归属不一致时拒绝并关闭连接池
+    pub async fn verify(&self, tenant_id: i64) -> AppResult<()> {
+        if self.pool.is_closed() {
+            crate::security_log!(
+                "ERROR",
+                "cross_tenant_db_access",
+                expected_tenant = tenant_id,
+                db = &*self.db_path,
+                reason = "pool closed after an earlier cross-tenant attempt"
+            );
+            return Err(AppError::forbidden(format!(
+                "Database {} is locked after a cross-tenant access attempt",
+                self.db_path
+            )));
+        }
+        match bind_or_verify(&self.pool, tenant_id, &self.db_path).await {
+            Err(e) if e.code == shared::error::ErrorCode::PermissionDenied => {
+                tracing::error!(
+                    tenant_id,
+                    db = &*self.db_path,
+                    "Closing database pool after cross-tenant access attempt"
+                );
+                self.pool.close().await;
+                Err(e)
+            }
+            result => result,
+        }
+    }
+}
+
+/// 数据库当前绑定的租户（未绑定返回 None）
+pub async fn bound_tenant(pool: &SqlitePool) -> AppResult<Option<i64>> {
+    let tenant_id = sqlx::query_scalar::<_, i64>("SELECT tenant_id FROM tenant_scope WHERE id = 1")
+        .fetch_optional(pool)
+        .await
+        .map_err(|e| AppError::database(format!("Failed to read tenant scope: {e}")))?;
+    Ok(tenant_id)
+}
+
+/// 校验数据库归属 `tenant_id`；未绑定时绑定到该租户
+///
+/// 归属不一致时拒绝访问：调用方必须中止启动，不得继续使用该连接池。
+pub async fn bind_or_verify(pool: &SqlitePool, tenant_id: i64, db_path: &str) -> AppResult<()> {
+    match bound_tenant(pool).await? {
+        Some(bound) if bound == tenant_id => Ok(()),
+        Some(bound) => {
+            crate::security_log!(
+                "ERROR",
+                "cross_tenant_db_access",
+                expected_tenant = tenant_id,
+                bound_tenant = bound,
+                db = db_path
+            );
+            Err(AppError::forbidden(format!(
+                "Database {} belongs to tenant {}, refusing access for tenant {}",
+                db_path, bound, tenant_id
+            )))
+        }
+        None => {
+            // INSERT OR IGNORE + 重新校验：并发首次绑定时只有一个租户能胜出
+            sqlx::query(
+                "INSERT OR IGNORE INTO tenant_scope (id, tenant_id, bound_at) VALUES (1, ?1, ?2)",
+            )
+            .bind(tenant_id)
+            .bind(shared::util::now_millis())
+            .execute(pool)
+            .await
+            .map_err(|e| AppError::database(format!("Failed to bind tenant scope: {e}")))?;
+
+            match bound_tenant(pool).await? {
+                Some(bound) if bound == tenant_id => {
+                    tracing::info!(tenant_id, db = db_path, "Database bound to tenant");
+                    Ok(())
+                }
+                bound => {
+                    crate::security_log!(
+                        "ERROR",
+                        "cross_tenant_db_access",
+                        expected_tenant = tenant_id,
+                        bound_tenant = format!("{:?}", bound),
+                        db = db_path
+                    );
+                    Err(AppError::forbidden(format!(
+                        "Database {} could not be bound to tenant {}",
+                        db_path, tenant_id
+                    )))
+                }
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::db::DbService;
+    use std::path::Path;
+
+    const TENANT_A: i64 = 1001;
+    const TENANT_B: i64 = 2002;
+
+    async fn open(path: &Path) -> SqlitePool {
+        DbService::new(&path.to_string_lossy()).await.unwrap().pool
+    }
+
+    async fn insert_secret(pool: &SqlitePool, name: &str) {
+        sqlx::query(
+            "INSERT INTO receipt_footer (id, name, lines, sort_order, is_active, created_at, updated_at) VALUES (?1, ?2, '', 0, 1, 0, 0)",
+        )
+        .bind(shared::util::snowflake_id())
+        .bind(name)
+        .execute(pool)
+        .await
+        .unwrap();
+    }
+
+    async fn footer_names(pool: &SqlitePool) -> Vec<String> {
+        sqlx::query_scalar::<_, String>("SELECT name FROM receipt_footer")
+            .fetch_all(pool)
+            .await
+            .unwrap()
+    }
+
+    #[tokio::test]
+    async fn test_first_open_binds_tenant() {
+        let dir = tempfile::tempdir().unwrap();
+        let db = dir.path().join("main.db");
+        let pool = open(&db).await;
+
+        assert_eq!(bound_tenant(&pool).await.unwrap(), None);
+        bind_or_verify(&pool, TENANT_A, "main.db").await.unwrap();
+        assert_eq!(bound_tenant(&pool).await.unwrap(), Some(TENANT_A));
+        // Idempotent for the owner
+        bind_or_verify(&pool, TENANT_A, "main.db").await.unwrap();
+    }
+
+    #[tokio::test]
+    async fn test_foreign_tenant_rejected_after_reopen() {
+        let dir = tempfile::tempdir().unwrap();
+        let db = dir.path().join("main.db");
+        let pool = open(&db).await;
+        bind_or_verify(&pool, TENANT_A, "main.db").await.unwrap();
+        insert_secret(&pool, "tenant-a-secret").await;
+        pool.close().await;
+
+        // Tenant B pointed at tenant A's work dir (misconfigured path / reused dir)
+        let pool = open(&db).await;
+        let err = bind_or_verify(&pool, TENANT_B, "main.db")
+            .await
+            .unwrap_err();
+        assert_eq!(err.code, shared::error::ErrorCode::PermissionDenied);
+        // Binding is unchanged by the rejected attempt
+        assert_eq!(bound_tenant(&pool).await.unwrap(), Some(TENANT_A));
+    }
+
+    #[tokio::test]
+    async fn test_scope_row_cannot_be_rebound() {
+        let dir = tempfile::tempdir().unwrap();
+        let pool = open(&dir.path().join("main.db")).await;
+        bind_or_verify(&pool, TENANT_A, "main.db").await.unwrap();
+
+        // A second scope row is rejected by the schema
+        let second =
+            sqlx::query("INSERT INTO tenant_scope (id, tenant_id, bound_at) VALUES (2, ?1, 0)")
+                .bind(TENANT_B)
+                .execute(&pool)
+                .await;
+        assert!(second.is_err());
+
+        // Replaying the first-bind path for another tenant does not take over
+        assert!(bind_or_verify(&pool, TENANT_B, "main.db").await.is_err());
+        assert_eq!(bound_tenant(&pool).await.unwrap(), Some(TENANT_A));
+    }
+
+    #[tokio::test]
+    async fn test_cross_tenant_verify_blocks_repository_access() {
+        use crate::db::repository::receipt_footer;
+
+        let dir = tempfile::tempdir().unwrap();
+        let pool = open(&dir.path().join("main.db")).await;
+        let scoped = TenantPool::new(pool.clone(), "main.db");
+        scoped.verify(TENANT_A).await.unwrap();
+        insert_secret(&pool, "tenant-a-secret").await;
+        assert_eq!(receipt_footer::find_all(&pool).await.unwrap().len(), 1);
+
+        // Tenant B's credential is loaded into the running server
+        let err = scoped.verify(TENANT_B).await.unwrap_err();
+        assert_eq!(err.code, shared::error::ErrorCode::PermissionDenied);
+
+        // Every holder of the pool (API handlers, background tasks) is cut off
+        assert!(receipt_footer::find_all(&pool).await.is_err());
+        assert!(footer_names_result(&pool).await.is_err());
+        // The rightful tenant cannot silently resume on the same pool either
+        assert!(scoped.verify(TENANT_A).await.is_err());
+    }
+
+    async fn footer_names_result(pool: &SqlitePool) -> Result<Vec<String>, sqlx::Error> {
+        sqlx::query_scalar::<_, String>("SELECT name FROM receipt_footer")
+            .fetch_all(pool)
+            .await
+    }
+
+    #[tokio::test]
+    async fn test_tenant_data_does_not_leak() {
+        let dir = tempfile::tempdir().unwrap();
+        let pool_a = open(&dir.path().join("a.db")).await;
+        let pool_b = open(&dir.path().join("b.db")).await;
+        bind_or_verify(&pool_a, TENANT_A, "a.db").await.unwrap();
+        bind_or_verify(&pool_b, TENANT_B, "b.db").await.unwrap();
+
+        insert_secret(&pool_a, "tenant-a-secret").await;
+        insert_secret(&pool_b, "tenant-b-secret").await;
+
+        assert_eq!(footer_names(&pool_a).await, vec!["tenant-a-secret"]);
+        assert_eq!(footer_names(&pool_b).await, vec!["tenant-b-secret"]);
+
+        // Cross-wired pools are refused in both directions
+        assert!(bind_or_verify(&pool_a, TENANT_B, "a.db").await.is_err());
+        assert!(bind_or_verify(&pool_b, TENANT_A, "b.db").await.is_err());
+    }
+}
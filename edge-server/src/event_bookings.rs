@@ -0,0 +1,228 @@
+//! 宴会预订转单调度器
+//!
+//! 已确认的预订在活动开始前 [`MATERIALIZE_LEAD_MS`] 自动转为正式订单：
+//! 开台（预订桌台 + 人数）→ 关联会员 → 加入预订菜单 → 定金入账。
+//! 开台失败（如桌台被占用）时记录 `materialize_error`，下次检查重试。
+
+use std::time::Duration;
+
+use tokio_util::sync::CancellationToken;
+
+use crate::core::ServerState;
+use crate::db::repository::event_booking;
+use crate::orders::actions::open_table::load_order_rules;
+use shared::message::SyncChangeType;
+use shared::models::EventBookingStatus;
+use shared::order::{CommandResponse, OrderCommand, OrderCommandPayload, PaymentInput};
+
+use shared::cloud::SyncResource;
+const RESOURCE: SyncResource = SyncResource::EventBooking;
+
+/// 活动开始前多久转为正式订单
+pub const MATERIALIZE_LEAD_MS: i64 = 2 * 60 * 60 * 1000;
+
+/// 检查间隔
+const CHECK_INTERVAL: Duration = Duration::from_secs(5 * 60);
+
+/// 宴会预订转单调度器
+///
+/// 注册为 `TaskKind::Periodic`，在 `start_background_tasks()` 中启动。
+pub struct EventBookingScheduler {
+    state: ServerState,
+    shutdown: CancellationToken,
+}
+
+impl EventBookingScheduler {
+    pub fn new(state: ServerState, shutdown: CancellationToken) -> Self {
+        Self { state, shutdown }
+    }
+
+    /// 主循环：启动扫描 + 定时检查
+    pub async fn run(self) {
+        tracing::info!("Event booking scheduler started");
+
+        loop {
+            self.materialize_due().await;
+
+            tokio::select! {
+                _ = tokio::time::sleep(CHECK_INTERVAL) => {}
+                _ = self.shutdown.cancelled() => {
+                    tracing::info!("Event booking scheduler received shutdown signal");
+                    return;
+                }
+            }
+        }
+    }
+
+    async fn materialize_due(&self) {
+        let until = shared::util::now_millis() + MATERIALIZE_LEAD_MS;
+        let due = match event_booking::find_due(&self.state.pool, until).await {
+            Ok(due) => due,
+            Err(e) => {
+                tracing::error!("Failed to query due event bookings: {}", e);
+                return;
+            }
+        };
+
+        for booking in due {
+            match materialize(&self.state, booking.id).await {
+                Ok(order_id) => {
+                    tracing::info!(
+                        booking_id = booking.id,
+                        order_id,
+                        "Event booking materialized"
+                    );
+                }
+                Err(e) => {
+                    tracing::warn!(booking_id = booking.id, error = %e, "Event booking materialization failed");
+                    if let Err(e) =
+                        event_booking::set_materialize_error(&self.state.pool, booking.id, &e).await
+                    {
+                        tracing::error!(
+                            booking_id = booking.id,
+                            "Failed to record materialization error: {}",
+                            e
+                        );
+                    }
+                }
+            }
+        }
+    }
+}
+
+fn command_error(response: &CommandResponse) -> String {
+    response
+        .error
+        .as_ref()
+        .map(|e| format!("{:?}: {}", e.code, e.message))
+        .unwrap_or_else(|| "unknown error".to_string())
+}
+
+/// 将预订转为正式订单，返回新订单 ID
+///
+/// 只有开台失败视为转单失败（预订保持 CONFIRMED 以便重试）；
+/// 之后的步骤失败记录在 `materialize_error` 中，由店员在订单上手动补录。
+pub async fn materialize(state: &ServerState, booking_id: i64) -> Result<i64, String> {
+    let _guard = state.event_materialize_lock.lock().await;
+    let pool = &state.pool;
+    let booking = event_booking::find_by_id(pool, booking_id)
+        .await
+        .map_err(|e| e.to_string())?
+        .ok_or_else(|| format!("Event booking {booking_id} not found"))?;
+    if booking.status != EventBookingStatus::Confirmed || booking.order_id.is_some() {
+        return Err(format!(
+            "Event booking {booking_id} is {:?}, only confirmed bookings can be materialized",
+            booking.status
+        ));
+    }
+    let items = event_booking::find_items(pool, booking.id)
+        .await
+        .map_err(|e| e.to_string())?;
+    let deposits = event_booking::find_deposits(pool, booking.id)
+        .await
+        .map_err(|e| e.to_string())?;
+
+    let manager = state.orders_manager();
+    // 以预订创建人身份执行（事件溯源中可追溯到预订）
+    let command = |payload| {
+        OrderCommand::new(
+            booking.created_by_id,
+            booking.created_by_name.clone(),
+            payload,
+        )
+    };
+
+    // 1. 开台
+    let response = manager
+        .execute_command(command(OrderCommandPayload::OpenTable {
+            table_id: booking.table_id,
+            table_name: booking
+                .table_name
+                .clone()
+                .or_else(|| Some(booking.event_name.clone())),
+            zone_id: booking.zone_id,
+            zone_name: booking.zone_name.clone(),
+            guest_count: booking.headcount,
+            is_retail: false,
+        }))
+        .await;
+    let order_id = match (response.success, response.order_id) {
+        (true, Some(order_id)) => order_id,
+        _ => return Err(command_error(&response)),
+    };
+    let rules = load_order_rules(pool, &state.catalog_service, booking.zone_id, false).await;
+    if !rules.is_empty() {
+        manager.cache_rules(order_id, rules);
+    }
+
+    let mut warnings = Vec::new();
+
+    // 2. 关联会员（先于加菜，营销组折扣随菜品计算）
+    if let Some(member_id) = booking.member_id {
+        let response = manager
+            .execute_command(command(OrderCommandPayload::LinkMember {
+                order_id,
+                member_id,
+            }))
+            .await;
+        if !response.success {
+            warnings.push(format!("link member: {}", command_error(&response)));
+        }
+    }
+
+    // 3. 预订菜单
+    if !items.is_empty() {
+        let response = manager
+            .execute_command(command(OrderCommandPayload::AddItems {
+                order_id,
+                items: items.iter().map(|i| i.to_cart_input()).collect(),
+            }))
+            .await;
+        if !response.success {
+            warnings.push(format!("add items: {}", command_error(&response)));
+        }
+    }
+
+    // 4. 定金入账
+    for deposit in &deposits {
+        let response = manager
+            .execute_command(command(OrderCommandPayload::AddPayment {
+                order_id,
+                payment: PaymentInput {
+                    method: deposit.method.clone(),
+                    amount: deposit.amount,
+                    tendered: None,
+                    note: Some(format!("Deposit · {}", booking.event_name)),
+                    reference: deposit.reference.clone(),
+                },
+            }))
+            .await;
+        if !response.success {
+            warnings.push(format!(
+                "deposit {:.2} {}: {}",
+                deposit.amount,
+                deposit.method,
+                command_error(&response)
+            ));
+        }
+    }
+
+    let warning = (!warnings.is_empty()).then(|| warnings.join("; "));
+    event_booking::mark_materialized(pool, booking.id, order_id, warning.as_deref())
+        .await
+        .map_err(|e| e.to_string())?;
+
+    if let Ok(Some(updated)) = event_booking::find_by_id(pool, booking.id).await {
+        state
+            .broadcast_sync(
+                RESOURCE,
+                SyncChangeType::Updated,
+                booking.id,
+                Some(&updated),
+                false,
+            )
+            .await;
+    }
+
+    Ok(order_id)
+}
@@ -0,0 +1,115 @@
+//! HTTP/JSON 税控模块客户端
+//!
+//! | 操作 | 请求 |
+//! |------|------|
+//! | 登记 | `POST {base}/receipts` ([`FiscalReceipt`]) → `{ "fiscal_code": "..." }` |
+//! | 撤销 | `POST {base}/receipts/{fiscal_code}/cancel` |
+//!
+//! 配置了 API key 时以 `Authorization: Bearer <key>` 发送。4xx 视为拒绝，
+//! 连接失败 / 超时 / 5xx 视为设备不可用 (可重试)。
+
+use std::time::Duration;
+
+use async_trait::async_trait;
+use reqwest::{Client, RequestBuilder, Url};
+
+use super::{FiscalConfirmation, FiscalDevice, FiscalError, FiscalReceipt};
+
+/// 单次请求超时 (含重试总计约 3 × 5s)
+const REQUEST_TIMEOUT: Duration = Duration::from_secs(5);
+
+pub struct HttpFiscalDevice {
+    client: Client,
+    base_url: String,
+    api_key: Option<String>,
+}
+
+impl HttpFiscalDevice {
+    pub fn new(base_url: impl Into<String>, api_key: Option<String>) -> Result<Self, FiscalError> {
+        let client = Client::builder()
+            .timeout(REQUEST_TIMEOUT)
+            .build()
+            .map_err(|e| FiscalError::Unavailable(e.to_string()))?;
+        let base_url = base_url.into();
+        Url::parse(&base_url)
+            .map_err(|e| FiscalError::Unavailable(format!("Invalid fiscal device URL: {e}")))?;
+        Ok(Self {
+            client,
+            base_url,
+            api_key: api_key.filter(|k| !k.is_empty()),
+        })
+    }
+
+    /// 拼接路径段 (逐段百分号编码)
+    fn url(&self, segments: &[&str]) -> Result<Url, FiscalError> {
+        let mut url = Url::parse(&self.base_url)
+            .map_err(|e| FiscalError::Unavailable(format!("Invalid fiscal device URL: {e}")))?;
+        url.path_segments_mut()
+            .map_err(|_| FiscalError::Unavailable("Invalid fiscal device URL".into()))?
+            .pop_if_empty()
+            .extend(segments);
+        Ok(url)
+    }
+
+    fn authorize(&self, request: RequestBuilder) -> RequestBuilder {
+        match &self.api_key {
+            Some(key) => request.bearer_auth(key),
+            None => request,
+        }
+    }
+}
+
+/// 非 2xx 响应 → 错误
+async fn error_for(response: reqwest::Response) -> FiscalError {
+    let status = response.status();
+    let body = response.text().await.unwrap_or_default();
+    let detail = if body.is_empty() {
+        status.to_string()
+    } else {
+        format!("{status}: {body}")
+    };
+    if status.is_client_error() {
+        FiscalError::Rejected(detail)
+    } else {
+        FiscalError::Unavailable(detail)
+    }
+}
+
+#[async_trait]
+impl FiscalDevice for HttpFiscalDevice {
+    async fn register(&self, receipt: &FiscalReceipt) -> Result<FiscalConfirmation, FiscalError> {
+        let url = self.url(&["receipts"])?;
+        let response = self
+            .authorize(self.client.post(url))
+            .json(receipt)
+            .send()
+            .await
+            .map_err(|e| FiscalError::Unavailable(e.to_string()))?;
+        if !response.status().is_success() {
+            return Err(error_for(response).await);
+        }
+        let confirmation = response
+            .json::<FiscalConfirmation>()
+            .await
+            .map_err(|e| FiscalError::Unavailable(format!("Invalid fiscal response: {e}")))?;
+        if confirmation.fiscal_code.trim().is_empty() {
+            return Err(FiscalError::Unavailable(
+                "Fiscal device returned an empty code".into(),
+            ));
+        }
+        Ok(confirmation)
+    }
+
+    async fn cancel(&self, fiscal_code: &str) -> Result<(), FiscalError> {
+        let url = self.url(&["receipts", fiscal_code, "cancel"])?;
+        let response = self
+            .authorize(self.client.post(url))
+            .send()
+            .await
+            .map_err(|e| FiscalError::Unavailable(e.to_string()))?;
+        if !response.status().is_success() {
+            return Err(error_for(response).await);
+        }
+        Ok(())
+    }
+}
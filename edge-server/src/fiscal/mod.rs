@@ -0,0 +1,240 @@
+//! 外部税控设备 (Fiscal device)
+//!
+//! 部分地区要求收据经认证税控硬件登记。结单 (CompleteOrder) 时 OrdersManager 在预取阶段
+//! (redb 事务前) 将订单提交到本地税控模块，返回的税控码写入
+//! `OrderCompleted.fiscal_code`，随快照归档并打印在收据上。
+//!
+//! - [`FiscalDevice`] — 对接不同税控模块的 trait
+//! - [`HttpFiscalDevice`] — HTTP/JSON 模块 (`FISCAL_DEVICE_URL=http://...`)
+//! - [`SerialFiscalDevice`] — 串口 JSON 行协议 (`FISCAL_DEVICE_URL=serial:/dev/ttyUSB0`)
+//!
+//! 设备不可达时重试 [`FISCAL_ATTEMPTS`] 次，仍失败按 [`FiscalPolicy`] 处理；设备明确拒绝
+//! 总是拒绝结单。登记成功但结单事务失败时调用 [`FiscalDevice::cancel`] 撤销登记。
+
+mod http;
+mod serial;
+
+pub use http::HttpFiscalDevice;
+pub use serial::SerialFiscalDevice;
+
+use std::str::FromStr;
+use std::sync::Arc;
+use std::time::Duration;
+
+use async_trait::async_trait;
+use serde::{Deserialize, Serialize};
+use shared::order::OrderSnapshot;
+use thiserror::Error;
+
+/// 设备不可达时的总尝试次数
+pub const FISCAL_ATTEMPTS: u32 = 3;
+
+/// 重试间隔 (收银员在等待，不做指数退避)
+const RETRY_DELAY: Duration = Duration::from_millis(500);
+
+/// 税控设备错误
+#[derive(Debug, Error)]
+pub enum FiscalError {
+    #[error("Fiscal device rejected the receipt: {0}")]
+    Rejected(String),
+
+    #[error("Fiscal device unavailable: {0}")]
+    Unavailable(String),
+
+    #[error("Operation not supported by this fiscal device")]
+    Unsupported,
+}
+
+/// 设备不可用时的结单策略
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "kebab-case")]
+pub enum FiscalPolicy {
+    /// 拒绝结单 (订单保持进行中，设备恢复后重新结单)
+    #[default]
+    Block,
+    /// 无税控码结单并记录告警 (仅限法规允许事后补登的地区)
+    Allow,
+}
+
+impl FromStr for FiscalPolicy {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.trim().to_ascii_lowercase().as_str() {
+            "block" => Ok(Self::Block),
+            "allow" => Ok(Self::Allow),
+            other => Err(format!("Unknown fiscal policy: {other}")),
+        }
+    }
+}
+
+/// 税控登记行
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct FiscalLine {
+    pub name: String,
+    pub quantity: i32,
+    pub unit_price: f64,
+    pub line_total: f64,
+    pub tax_rate: i32,
+}
+
+/// 税控登记支付
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct FiscalPayment {
+    pub method: String,
+    pub amount: f64,
+}
+
+/// 税控登记请求 (收据号为设备侧幂等键)
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct FiscalReceipt {
+    pub order_id: i64,
+    pub receipt_number: String,
+    pub total: f64,
+    pub tax: f64,
+    pub lines: Vec<FiscalLine>,
+    pub payments: Vec<FiscalPayment>,
+}
+
+impl FiscalReceipt {
+    /// 由待结单快照构建 (取消的支付不登记)
+    pub fn from_snapshot(snapshot: &OrderSnapshot) -> Self {
+        Self {
+            order_id: snapshot.order_id,
+            receipt_number: snapshot.receipt_number.clone(),
+            total: snapshot.total,
+            tax: snapshot.tax,
+            lines: snapshot
+                .items
+                .iter()
+                .map(|item| FiscalLine {
+                    name: item.name.clone(),
+                    quantity: item.quantity,
+                    unit_price: item.unit_price,
+                    line_total: item.line_total,
+                    tax_rate: item.tax_rate,
+                })
+                .collect(),
+            payments: snapshot
+                .payments
+                .iter()
+                .filter(|p| !p.cancelled)
+                .map(|p| FiscalPayment {
+                    method: p.method.clone(),
+                    amount: p.amount,
+                })
+                .collect(),
+        }
+    }
+}
+
+/// 设备登记确认
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct FiscalConfirmation {
+    /// 税控码 (写入结单事件并打印)
+    pub fiscal_code: String,
+}
+
+/// 税控设备对接接口
+#[async_trait]
+pub trait FiscalDevice: Send + Sync {
+    /// 登记收据，返回税控码
+    async fn register(&self, receipt: &FiscalReceipt) -> Result<FiscalConfirmation, FiscalError>;
+
+    /// 撤销已登记的收据 (默认不支持，需在设备上手工处理)
+    async fn cancel(&self, _fiscal_code: &str) -> Result<(), FiscalError> {
+        Err(FiscalError::Unsupported)
+    }
+}
+
+/// 登记收据，设备不可达时重试 (拒绝不重试)
+pub async fn register_with_retry(
+    device: &dyn FiscalDevice,
+    receipt: &FiscalReceipt,
+) -> Result<FiscalConfirmation, FiscalError> {
+    let mut attempt = 1;
+    loop {
+        match device.register(receipt).await {
+            Err(FiscalError::Unavailable(e)) if attempt < FISCAL_ATTEMPTS => {
+                tracing::warn!(
+                    receipt = %receipt.receipt_number,
+                    attempt,
+                    error = %e,
+                    "Fiscal device unavailable, retrying"
+                );
+                attempt += 1;
+                tokio::time::sleep(RETRY_DELAY).await;
+            }
+            result => return result,
+        }
+    }
+}
+
+/// 按地址选择设备实现：`http(s)://` → HTTP，`serial:<path>` → 串口
+pub fn open(url: &str, api_key: Option<String>) -> Result<Arc<dyn FiscalDevice>, FiscalError> {
+    match url.strip_prefix("serial:") {
+        Some(path) => Ok(Arc::new(SerialFiscalDevice::new(path)?)),
+        None => Ok(Arc::new(HttpFiscalDevice::new(url, api_key)?)),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::sync::atomic::{AtomicU32, Ordering};
+
+    /// 前 `failures` 次不可达，之后返回 `CODE-{n}`
+    struct FlakyDevice {
+        failures: u32,
+        calls: AtomicU32,
+    }
+
+    #[async_trait]
+    impl FiscalDevice for FlakyDevice {
+        async fn register(&self, _: &FiscalReceipt) -> Result<FiscalConfirmation, FiscalError> {
+            let n = self.calls.fetch_add(1, Ordering::SeqCst) + 1;
+            if n <= self.failures {
+                return Err(FiscalError::Unavailable("offline".into()));
+            }
+            Ok(FiscalConfirmation {
+                fiscal_code: format!("CODE-{n}"),
+            })
+        }
+    }
+
+    fn receipt() -> FiscalReceipt {
+        FiscalReceipt::from_snapshot(&OrderSnapshot::new(1))
+    }
+
+    #[tokio::test]
+    async fn test_register_retries_until_available() {
+        let device = FlakyDevice {
+            failures: FISCAL_ATTEMPTS - 1,
+            calls: AtomicU32::new(0),
+        };
+        let confirmation = register_with_retry(&device, &receipt()).await.unwrap();
+        assert_eq!(confirmation.fiscal_code, format!("CODE-{FISCAL_ATTEMPTS}"));
+
+        let device = FlakyDevice {
+            failures: FISCAL_ATTEMPTS,
+            calls: AtomicU32::new(0),
+        };
+        assert!(matches!(
+            register_with_retry(&device, &receipt()).await,
+            Err(FiscalError::Unavailable(_))
+        ));
+        assert_eq!(device.calls.load(Ordering::SeqCst), FISCAL_ATTEMPTS);
+    }
+
+    #[test]
+    fn test_policy_and_device_selection() {
+        assert_eq!("Block".parse::<FiscalPolicy>(), Ok(FiscalPolicy::Block));
+        assert_eq!(" allow ".parse::<FiscalPolicy>(), Ok(FiscalPolicy::Allow));
+        assert!("skip".parse::<FiscalPolicy>().is_err());
+
+        assert!(open("http://127.0.0.1:9100/fiscal", None).is_ok());
+        assert!(open("serial:/dev/ttyUSB0", None).is_ok());
+        assert!(open("serial:", None).is_err());
+        assert!(open("not a url", None).is_err());
+    }
+}
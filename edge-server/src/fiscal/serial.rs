@@ -0,0 +1,167 @@
+//! 串口税控模块 (JSON 行协议)
+//!
+//! 每个请求 / 响应为一行 JSON (`\n` 结尾)，同一时间只有一个请求在线路上：
+//!
+//! ```text
+//! → {"op":"register","receipt":{...}}
+//! ← {"fiscal_code":"..."}            // 成功
+//! ← {"error":"..."}                  // 设备拒绝
+//! → {"op":"cancel","fiscal_code":"..."}
+//! ← {"ok":true}
+//! ```
+//!
+//! 波特率等线路参数由系统配置 (如 `stty -F /dev/ttyUSB0 115200 raw`)，这里只按文件读写。
+//! 打开失败 / 超时视为设备不可用 (可重试)。
+
+use std::path::PathBuf;
+use std::time::Duration;
+
+use async_trait::async_trait;
+use serde::{Deserialize, Serialize};
+use tokio::io::{AsyncBufReadExt, AsyncRead, AsyncWrite, AsyncWriteExt, BufReader};
+use tokio::sync::Mutex;
+
+use super::{FiscalConfirmation, FiscalDevice, FiscalError, FiscalReceipt};
+
+/// 单次请求超时 (含写入与等待响应)
+const REQUEST_TIMEOUT: Duration = Duration::from_secs(5);
+
+#[derive(Debug, Serialize)]
+#[serde(tag = "op", rename_all = "snake_case")]
+enum SerialRequest<'a> {
+    Register { receipt: &'a FiscalReceipt },
+    Cancel { fiscal_code: &'a str },
+}
+
+#[derive(Debug, Default, Deserialize)]
+struct SerialResponse {
+    #[serde(default)]
+    fiscal_code: Option<String>,
+    #[serde(default)]
+    error: Option<String>,
+}
+
+pub struct SerialFiscalDevice {
+    path: PathBuf,
+    /// 串口同一时间只允许一个请求
+    line: Mutex<()>,
+}
+
+impl SerialFiscalDevice {
+    pub fn new(path: impl Into<PathBuf>) -> Result<Self, FiscalError> {
+        let path = path.into();
+        if path.as_os_str().is_empty() {
+            return Err(FiscalError::Unavailable(
+                "Serial fiscal device path is empty".into(),
+            ));
+        }
+        Ok(Self {
+            path,
+            line: Mutex::new(()),
+        })
+    }
+
+    async fn request(&self, request: &SerialRequest<'_>) -> Result<SerialResponse, FiscalError> {
+        let _line = self.line.lock().await;
+        let port = tokio::fs::OpenOptions::new()
+            .read(true)
+            .write(true)
+            .open(&self.path)
+            .await
+            .map_err(|e| FiscalError::Unavailable(format!("{}: {e}", self.path.display())))?;
+        tokio::time::timeout(REQUEST_TIMEOUT, exchange(port, request))
+            .await
+            .map_err(|_| FiscalError::Unavailable("Fiscal device timed out".into()))?
+    }
+}
+
+/// 写一行请求并读取一行响应
+async fn exchange<S>(stream: S, request: &SerialRequest<'_>) -> Result<SerialResponse, FiscalError>
+where
+    S: AsyncRead + AsyncWrite + Unpin,
+{
+    let unavailable = |e: std::io::Error| FiscalError::Unavailable(e.to_string());
+    let mut stream = BufReader::new(stream);
+    let mut line = serde_json::to_vec(request)
+        .map_err(|e| FiscalError::Unavailable(format!("Failed to encode request: {e}")))?;
+    line.push(b'\n');
+    stream
+        .get_mut()
+        .write_all(&line)
+        .await
+        .map_err(unavailable)?;
+    stream.get_mut().flush().await.map_err(unavailable)?;
+
+    let mut response = String::new();
+    if stream.read_line(&mut response).await.map_err(unavailable)? == 0 {
+        return Err(FiscalError::Unavailable(
+            "Fiscal device closed the line".into(),
+        ));
+    }
+    let response: SerialResponse = serde_json::from_str(response.trim())
+        .map_err(|e| FiscalError::Unavailable(format!("Invalid fiscal response: {e}")))?;
+    match response.error {
+        Some(error) => Err(FiscalError::Rejected(error)),
+        None => Ok(response),
+    }
+}
+
+#[async_trait]
+impl FiscalDevice for SerialFiscalDevice {
+    async fn register(&self, receipt: &FiscalReceipt) -> Result<FiscalConfirmation, FiscalError> {
+        let response = self.request(&SerialRequest::Register { receipt }).await?;
+        match response.fiscal_code.filter(|c| !c.trim().is_empty()) {
+            Some(fiscal_code) => Ok(FiscalConfirmation { fiscal_code }),
+            None => Err(FiscalError::Unavailable(
+                "Fiscal device returned an empty code".into(),
+            )),
+        }
+    }
+
+    async fn cancel(&self, fiscal_code: &str) -> Result<(), FiscalError> {
+        self.request(&SerialRequest::Cancel { fiscal_code })
+            .await
+            .map(|_| ())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use shared::order::OrderSnapshot;
+
+    /// 模拟设备：读取一行请求，回写固定响应
+    async fn device(
+        reply: &'static str,
+    ) -> (tokio::io::DuplexStream, tokio::task::JoinHandle<String>) {
+        let (client, server) = tokio::io::duplex(4096);
+        let handle = tokio::spawn(async move {
+            let mut server = BufReader::new(server);
+            let mut request = String::new();
+            server.read_line(&mut request).await.unwrap();
+            server.get_mut().write_all(reply.as_bytes()).await.unwrap();
+            request
+        });
+        (client, handle)
+    }
+
+    #[tokio::test]
+    async fn test_line_protocol() {
+        let receipt = FiscalReceipt::from_snapshot(&OrderSnapshot::new(7));
+
+        let (client, handle) = device("{\"fiscal_code\":\"FC-7\"}\n").await;
+        let response = exchange(client, &SerialRequest::Register { receipt: &receipt })
+            .await
+            .unwrap();
+        assert_eq!(response.fiscal_code.as_deref(), Some("FC-7"));
+        let request: serde_json::Value = serde_json::from_str(&handle.await.unwrap()).unwrap();
+        assert_eq!(request["op"], "register");
+        assert_eq!(request["receipt"]["order_id"], 7);
+
+        let (client, _handle) = device("{\"error\":\"total mismatch\"}\n").await;
+        assert!(matches!(
+            exchange(client, &SerialRequest::Cancel { fiscal_code: "FC-7" }).await,
+            Err(FiscalError::Rejected(e)) if e == "total mismatch"
+        ));
+    }
+}
@@ -0,0 +1,110 @@
+//! 租户监管 API
+//!
+//! 默认仅监听本机回环地址，供主机运维脚本调用；配置了 `SUPERVISOR_TOKEN`
+//! 时还需携带 `Authorization: Bearer <token>`（常量时间比较）。
+//! 通过 `SUPERVISOR_BIND` 绑定到非回环地址时必须配置 token，否则拒绝启动。
+//!
+//! | 方法 | 路径 | 说明 |
+//! |------|------|------|
+//! | GET | /tenants | 所有租户状态 |
+//! | POST | /tenants/{id}/start | 启动租户 |
+//! | POST | /tenants/{id}/stop | 停止租户 |
+
+use axum::{
+    Json, Router,
+    extract::{Path, Request, State},
+    middleware::{self, Next},
+    response::Response,
+    routing::{get, post},
+};
+use std::net::IpAddr;
+use std::sync::Arc;
+
+use super::supervisor::{TenantStatus, TenantSupervisor};
+use crate::utils::validation::token_matches;
+use crate::utils::{AppError, AppResult};
+
+/// 监管 API 路由
+pub fn supervisor_router(supervisor: Arc<TenantSupervisor>, token: Option<String>) -> Router {
+    Router::new()
+        .route("/tenants", get(list_tenants))
+        .route("/tenants/{id}/start", post(start_tenant))
+        .route("/tenants/{id}/stop", post(stop_tenant))
+        .layer(middleware::from_fn(move |req: Request, next: Next| {
+            let token = token.clone();
+            async move { require_token(token.as_deref(), req, next).await }
+        }))
+        .with_state(supervisor)
+}
+
+/// 校验监听地址与 token 的组合：非回环地址必须配置 token
+pub fn check_exposure(bind: IpAddr, token: Option<&str>) -> Result<(), String> {
+    if token.is_none() && !bind.is_loopback() {
+        return Err(format!(
+            "SUPERVISOR_TOKEN is required when the supervisor API binds to non-loopback address {bind}"
+        ));
+    }
+    Ok(())
+}
+
+async fn require_token(token: Option<&str>, req: Request, next: Next) -> AppResult<Response> {
+    if let Some(expected) = token {
+        let provided = req
+            .headers()
+            .get(http::header::AUTHORIZATION)
+            .and_then(|v| v.to_str().ok())
+            .and_then(|v| v.strip_prefix("Bearer "));
+        if !provided.is_some_and(|p| token_matches(p, expected)) {
+            crate::security_log!(
+                "WARN",
+                "supervisor_unauthorized",
+                uri = format!("{:?}", req.uri())
+            );
+            return Err(AppError::unauthorized());
+        }
+    }
+    Ok(next.run(req).await)
+}
+
+/// GET /tenants
+async fn list_tenants(State(supervisor): State<Arc<TenantSupervisor>>) -> Json<Vec<TenantStatus>> {
+    Json(supervisor.status().await)
+}
+
+/// POST /tenants/{id}/start
+async fn start_tenant(
+    State(supervisor): State<Arc<TenantSupervisor>>,
+    Path(id): Path<String>,
+) -> AppResult<Json<TenantStatus>> {
+    Ok(Json(supervisor.start(&id).await?))
+}
+
+/// POST /tenants/{id}/stop
+async fn stop_tenant(
+    State(supervisor): State<Arc<TenantSupervisor>>,
+    Path(id): Path<String>,
+) -> AppResult<Json<TenantStatus>> {
+    Ok(Json(supervisor.stop(&id).await?))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_token_matches() {
+        assert!(token_matches("s3cret", "s3cret"));
+        assert!(!token_matches("s3creT", "s3cret"));
+        assert!(!token_matches("s3cre", "s3cret"));
+        assert!(!token_matches("", "s3cret"));
+    }
+
+    #[test]
+    fn test_non_loopback_requires_token() {
+        let loopback: IpAddr = "127.0.0.1".parse().unwrap();
+        let public: IpAddr = "0.0.0.0".parse().unwrap();
+        assert!(check_exposure(loopback, None).is_ok());
+        assert!(check_exposure(public, None).is_err());
+        assert!(check_exposure(public, Some("s3cret")).is_ok());
+    }
+}
@@ -0,0 +1,166 @@
+//! 租户清单
+//!
+//! JSON 格式，启动时加载；端口冲突、重复 ID 在加载时即拒绝，
+//! 避免某个租户启动到一半才发现端口被占用。
+
+use serde::{Deserialize, Serialize};
+use std::collections::HashSet;
+use std::path::{Path, PathBuf};
+
+use crate::utils::{AppError, AppResult};
+
+/// 单个托管租户
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct TenantSpec {
+    /// 租户标识（用作目录名，仅允许字母、数字、`-`、`_`）
+    pub tenant_id: String,
+    /// HTTPS API 端口
+    pub http_port: u16,
+    /// TCP 消息总线端口
+    pub message_tcp_port: u16,
+    /// 业务时区 (IANA)，缺省沿用主机配置
+    #[serde(default)]
+    pub timezone: Option<String>,
+    /// 工作目录，缺省为 `{host_dir}/tenants/{tenant_id}/server`
+    #[serde(default)]
+    pub work_dir: Option<String>,
+    /// 主进程启动时是否自动启动
+    #[serde(default = "default_autostart")]
+    pub autostart: bool,
+}
+
+fn default_autostart() -> bool {
+    true
+}
+
+impl TenantSpec {
+    /// 租户工作目录
+    pub fn work_dir(&self, host_dir: &Path) -> PathBuf {
+        match &self.work_dir {
+            Some(dir) => PathBuf::from(dir),
+            None => host_dir
+                .join("tenants")
+                .join(&self.tenant_id)
+                .join("server"),
+        }
+    }
+}
+
+/// 托管清单
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct HostingManifest {
+    pub tenants: Vec<TenantSpec>,
+}
+
+impl HostingManifest {
+    /// 从 JSON 文件加载并校验
+    pub fn load(path: &Path) -> AppResult<Self> {
+        let content = std::fs::read_to_string(path).map_err(|e| {
+            AppError::internal(format!(
+                "Failed to read hosting manifest {}: {e}",
+                path.display()
+            ))
+        })?;
+        let manifest: Self = serde_json::from_str(&content)
+            .map_err(|e| AppError::validation(format!("Invalid hosting manifest: {e}")))?;
+        manifest.validate()?;
+        Ok(manifest)
+    }
+
+    /// 校验租户 ID 与端口唯一性
+    pub fn validate(&self) -> AppResult<()> {
+        let mut ids = HashSet::new();
+        let mut ports = HashSet::new();
+        for tenant in &self.tenants {
+            validate_tenant_id(&tenant.tenant_id)?;
+            if !ids.insert(tenant.tenant_id.as_str()) {
+                return Err(AppError::validation(format!(
+                    "Duplicate tenant_id: {}",
+                    tenant.tenant_id
+                )));
+            }
+            if tenant.http_port == tenant.message_tcp_port {
+                return Err(AppError::validation(format!(
+                    "Tenant {} uses port {} for both HTTP and message bus",
+                    tenant.tenant_id, tenant.http_port
+                )));
+            }
+            for port in [tenant.http_port, tenant.message_tcp_port] {
+                if !ports.insert(port) {
+                    return Err(AppError::validation(format!(
+                        "Port {} of tenant {} is already assigned to another tenant",
+                        port, tenant.tenant_id
+                    )));
+                }
+            }
+        }
+        Ok(())
+    }
+}
+
+/// 租户 ID 会成为目录名，禁止路径分隔符和 `..`
+pub fn validate_tenant_id(tenant_id: &str) -> AppResult<()> {
+    let valid = !tenant_id.is_empty()
+        && tenant_id.len() <= 64
+        && tenant_id
+            .chars()
+            .all(|c| c.is_ascii_alphanumeric() || c == '-' || c == '_');
+    if !valid {
+        return Err(AppError::validation(format!(
+            "Invalid tenant_id '{}': use 1-64 letters, digits, '-' or '_'",
+            tenant_id
+        )));
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn spec(id: &str, http: u16, tcp: u16) -> TenantSpec {
+        TenantSpec {
+            tenant_id: id.to_string(),
+            http_port: http,
+            message_tcp_port: tcp,
+            timezone: None,
+            work_dir: None,
+            autostart: true,
+        }
+    }
+
+    #[test]
+    fn test_manifest_rejects_port_and_id_conflicts() {
+        let ok = HostingManifest {
+            tenants: vec![spec("noodles", 3001, 8101), spec("tacos", 3002, 8102)],
+        };
+        assert!(ok.validate().is_ok());
+
+        let port_clash = HostingManifest {
+            tenants: vec![spec("noodles", 3001, 8101), spec("tacos", 8101, 8102)],
+        };
+        assert!(port_clash.validate().is_err());
+
+        let dup_id = HostingManifest {
+            tenants: vec![spec("noodles", 3001, 8101), spec("noodles", 3002, 8102)],
+        };
+        assert!(dup_id.validate().is_err());
+    }
+
+    #[test]
+    fn test_tenant_id_and_work_dir() {
+        assert!(validate_tenant_id("stall_01").is_ok());
+        assert!(validate_tenant_id("../etc").is_err());
+        assert!(validate_tenant_id("").is_err());
+
+        let s = spec("tacos", 3002, 8102);
+        assert_eq!(
+            s.work_dir(Path::new("/srv/crab")),
+            PathBuf::from("/srv/crab/tenants/tacos/server")
+        );
+        let parsed: TenantSpec =
+            serde_json::from_str(r#"{"tenant_id":"t","http_port":1,"message_tcp_port":2}"#)
+                .unwrap();
+        assert!(parsed.autostart);
+    }
+}
@@ -0,0 +1,27 @@
+//! 多租户托管模式 (Multi-tenant hosting)
+//!
+//! 一个 edge-server 进程托管 N 个相互隔离的租户（如美食广场多个档口共用一台主机）。
+//! 每个租户拥有独立的 `ServerState`：独立工作目录（SQLite / redb / 图片）、
+//! 独立证书和独立端口，彼此之间不共享任何运行时状态。
+//!
+//! # 模块结构
+//!
+//! - [`HostingManifest`] - 租户清单 (`tenants.json`)
+//! - [`TenantSupervisor`] - 租户生命周期管理（独立启动/停止）
+//! - [`supervisor_router`] - 本机管理 API
+//!
+//! # 目录布局
+//!
+//! ```text
+//! {host_dir}/
+//! ├── tenants.json
+//! └── tenants/{tenant_id}/server/   # 租户 work_dir (data/, images/, certs/)
+//! ```
+
+pub mod api;
+pub mod manifest;
+pub mod supervisor;
+
+pub use api::{check_exposure, supervisor_router};
+pub use manifest::{HostingManifest, TenantSpec};
+pub use supervisor::{TenantStatus, TenantSupervisor};
@@ -0,0 +1,322 @@
+//! 租户监管器
+//!
+//! 每个租户是一个独立的 [`Server`] 任务（自己的 `ServerState`、数据库、证书和端口），
+//! 可单独启动/停止，某个租户崩溃不影响其他租户。
+
+use serde::Serialize;
+use std::collections::HashMap;
+use std::path::PathBuf;
+use tokio::sync::Mutex;
+use tokio::task::JoinHandle;
+use tokio_util::sync::CancellationToken;
+use tracing::Instrument;
+
+use super::manifest::{HostingManifest, TenantSpec};
+use crate::core::{Config, Server};
+use crate::utils::{AppError, AppResult};
+
+/// 租户运行状态
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum TenantRunState {
+    Stopped,
+    Running,
+    /// 服务任务异常退出（见 `last_error`）
+    Failed,
+}
+
+/// 租户状态快照（管理 API 返回）
+#[derive(Debug, Clone, Serialize)]
+pub struct TenantStatus {
+    pub tenant_id: String,
+    pub http_port: u16,
+    pub message_tcp_port: u16,
+    pub work_dir: String,
+    pub state: TenantRunState,
+    pub started_at: Option<i64>,
+    pub last_error: Option<String>,
+}
+
+struct RunningTenant {
+    token: CancellationToken,
+    handle: JoinHandle<Result<(), AppError>>,
+    started_at: i64,
+}
+
+struct TenantSlot {
+    spec: TenantSpec,
+    running: Option<RunningTenant>,
+    last_error: Option<String>,
+}
+
+impl TenantSlot {
+    /// 回收已退出的服务任务，记录退出原因
+    async fn reap(&mut self) {
+        if !self
+            .running
+            .as_ref()
+            .is_some_and(|r| r.handle.is_finished())
+        {
+            return;
+        }
+        if let Some(running) = self.running.take() {
+            self.last_error = match running.handle.await {
+                Ok(Ok(())) => None,
+                Ok(Err(e)) => Some(e.to_string()),
+                Err(e) => Some(format!("Tenant task panicked: {e}")),
+            };
+        }
+    }
+}
+
+/// 多租户监管器
+pub struct TenantSupervisor {
+    host_dir: PathBuf,
+    /// 主机级配置（JWT、环境、超时等），租户覆盖 work_dir / 端口 / 时区
+    base_config: Config,
+    tenants: Mutex<HashMap<String, TenantSlot>>,
+}
+
+impl TenantSupervisor {
+    pub fn new(
+        host_dir: impl Into<PathBuf>,
+        base_config: Config,
+        manifest: HostingManifest,
+    ) -> Self {
+        let tenants = manifest
+            .tenants
+            .into_iter()
+            .map(|spec| {
+                (
+                    spec.tenant_id.clone(),
+                    TenantSlot {
+                        spec,
+                        running: None,
+                        last_error: None,
+                    },
+                )
+            })
+            .collect();
+        Self {
+            host_dir: host_dir.into(),
+            base_config,
+            tenants: Mutex::new(tenants),
+        }
+    }
+
+    /// 构建租户配置：仅覆盖隔离相关字段
+    fn tenant_config(&self, spec: &TenantSpec) -> AppResult<Config> {
+        let mut config = self.base_config.clone();
+        config.work_dir = spec.work_dir(&self.host_dir).to_string_lossy().into_owned();
+        config.http_port = spec.http_port;
+        config.message_tcp_port = spec.message_tcp_port;
+        if let Some(tz) = &spec.timezone {
+            config.timezone = tz.parse().map_err(|_| {
+                AppError::validation(format!(
+                    "Invalid timezone '{}' for tenant {}",
+                    tz, spec.tenant_id
+                ))
+            })?;
+        }
+        Ok(config)
+    }
+
+    /// 启动所有 `autostart` 租户（单个失败只记录，不影响其他租户）
+    pub async fn start_autostart(&self) {
+        let ids: Vec<String> = {
+            let tenants = self.tenants.lock().await;
+            tenants
+                .values()
+                .filter(|slot| slot.spec.autostart)
+                .map(|slot| slot.spec.tenant_id.clone())
+                .collect()
+        };
+        for id in ids {
+            if let Err(e) = self.start(&id).await {
+                tracing::error!(tenant_id = %id, "Failed to start tenant: {e}");
+            }
+        }
+    }
+
+    /// 启动租户
+    pub async fn start(&self, tenant_id: &str) -> AppResult<TenantStatus> {
+        let mut tenants = self.tenants.lock().await;
+        let slot = tenants
+            .get_mut(tenant_id)
+            .ok_or_else(|| AppError::not_found(format!("Tenant {}", tenant_id)))?;
+        slot.reap().await;
+        if slot.running.is_some() {
+            return Err(AppError::conflict(format!(
+                "Tenant {} is already running",
+                tenant_id
+            )));
+        }
+
+        let config = self.tenant_config(&slot.spec)?;
+        config.ensure_work_dir_structure().map_err(|e| {
+            AppError::internal(format!(
+                "Failed to create work directory for tenant {}: {e}",
+                tenant_id
+            ))
+        })?;
+
+        let server = Server::new(config);
+        let token = server.shutdown_token();
+        let span = tracing::info_span!("tenant", tenant_id = %tenant_id);
+        let handle = tokio::spawn(async move { server.run().await }.instrument(span));
+
+        tracing::info!(
+            tenant_id,
+            http_port = slot.spec.http_port,
+            message_tcp_port = slot.spec.message_tcp_port,
+            "Tenant started"
+        );
+        slot.last_error = None;
+        slot.running = Some(RunningTenant {
+            token,
+            handle,
+            started_at: shared::util::now_millis(),
+        });
+        Ok(self.slot_status(slot))
+    }
+
+    /// 停止租户（graceful shutdown，超时后强制中止）
+    pub async fn stop(&self, tenant_id: &str) -> AppResult<TenantStatus> {
+        let running = {
+            let mut tenants = self.tenants.lock().await;
+            let slot = tenants
+                .get_mut(tenant_id)
+                .ok_or_else(|| AppError::not_found(format!("Tenant {}", tenant_id)))?;
+            slot.reap().await;
+            slot.running
+                .take()
+                .ok_or_else(|| AppError::conflict(format!("Tenant {} is not running", tenant_id)))?
+        };
+
+        // 锁外等待关闭，避免阻塞其他租户的管理操作
+        let error = self.shutdown_tenant(tenant_id, running).await;
+
+        let mut tenants = self.tenants.lock().await;
+        let slot = tenants
+            .get_mut(tenant_id)
+            .ok_or_else(|| AppError::not_found(format!("Tenant {}", tenant_id)))?;
+        slot.last_error = error;
+        Ok(self.slot_status(slot))
+    }
+
+    /// 停止所有运行中的租户（进程退出前调用）
+    pub async fn shutdown_all(&self) {
+        let running: Vec<(String, RunningTenant)> = {
+            let mut tenants = self.tenants.lock().await;
+            tenants
+                .values_mut()
+                .filter_map(|slot| {
+                    slot.running
+                        .take()
+                        .map(|r| (slot.spec.tenant_id.clone(), r))
+                })
+                .collect()
+        };
+        futures::future::join_all(
+            running
+                .into_iter()
+                .map(|(id, r)| async move { self.shutdown_tenant(&id, r).await }),
+        )
+        .await;
+    }
+
+    async fn shutdown_tenant(&self, tenant_id: &str, running: RunningTenant) -> Option<String> {
+        running.token.cancel();
+        let timeout = std::time::Duration::from_millis(self.base_config.shutdown_timeout_ms);
+        let mut handle = running.handle;
+        let error = match tokio::time::timeout(timeout, &mut handle).await {
+            Ok(Ok(Ok(()))) => None,
+            Ok(Ok(Err(e))) => Some(e.to_string()),
+            Ok(Err(e)) => Some(format!("Tenant task panicked: {e}")),
+            Err(_) => {
+                handle.abort();
+                Some(format!("Shutdown timed out after {:?}, aborted", timeout))
+            }
+        };
+        tracing::info!(tenant_id, error = ?error, "Tenant stopped");
+        error
+    }
+
+    /// 所有租户状态（按 tenant_id 排序）
+    pub async fn status(&self) -> Vec<TenantStatus> {
+        let mut tenants = self.tenants.lock().await;
+        let mut statuses = Vec::with_capacity(tenants.len());
+        for slot in tenants.values_mut() {
+            slot.reap().await;
+            statuses.push(self.slot_status(slot));
+        }
+        statuses.sort_by(|a, b| a.tenant_id.cmp(&b.tenant_id));
+        statuses
+    }
+
+    fn slot_status(&self, slot: &TenantSlot) -> TenantStatus {
+        let state = match (&slot.running, &slot.last_error) {
+            (Some(_), _) => TenantRunState::Running,
+            (None, Some(_)) => TenantRunState::Failed,
+            (None, None) => TenantRunState::Stopped,
+        };
+        TenantStatus {
+            tenant_id: slot.spec.tenant_id.clone(),
+            http_port: slot.spec.http_port,
+            message_tcp_port: slot.spec.message_tcp_port,
+            work_dir: slot
+                .spec
+                .work_dir(&self.host_dir)
+                .to_string_lossy()
+                .into_owned(),
+            state,
+            started_at: slot.running.as_ref().map(|r| r.started_at),
+            last_error: slot.last_error.clone(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn supervisor(host_dir: &std::path::Path) -> TenantSupervisor {
+        let manifest = HostingManifest {
+            tenants: vec![TenantSpec {
+                tenant_id: "noodles".to_string(),
+                http_port: 3101,
+                message_tcp_port: 8201,
+                timezone: Some("Asia/Shanghai".to_string()),
+                work_dir: None,
+                autostart: false,
+            }],
+        };
+        TenantSupervisor::new(host_dir, Config::default(), manifest)
+    }
+
+    #[tokio::test]
+    async fn test_tenant_config_isolation() {
+        let dir = tempfile::tempdir().unwrap();
+        let sup = supervisor(dir.path());
+        let tenants = sup.tenants.lock().await;
+        let config = sup
+            .tenant_config(&tenants.get("noodles").unwrap().spec)
+            .unwrap();
+        assert_eq!(config.http_port, 3101);
+        assert_eq!(config.message_tcp_port, 8201);
+        assert_eq!(config.timezone, chrono_tz::Asia::Shanghai);
+        assert!(PathBuf::from(&config.work_dir).starts_with(dir.path().join("tenants/noodles")));
+    }
+
+    #[tokio::test]
+    async fn test_unknown_and_stopped_tenant() {
+        let dir = tempfile::tempdir().unwrap();
+        let sup = supervisor(dir.path());
+        assert!(sup.start("ghost").await.is_err());
+        assert!(sup.stop("noodles").await.is_err());
+
+        let status = sup.status().await;
+        assert_eq!(status.len(), 1);
+        assert_eq!(status[0].state, TenantRunState::Stopped);
+    }
+}
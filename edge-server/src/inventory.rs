@@ -0,0 +1,123 @@
+//! 库存 (Inventory)
+//!
+//! 只跟踪建立了库存记录的商品 / 规格：
+//!
+//! - 订单完成 → OrdersManager Phase C 调用 [`InventoryTracker::on_order_completed`] 扣减
+//!   (商品本身 + 关联了库存的属性选项，如加芝士)
+//! - 进货、报损 → `/api/inventory` 手动调整
+//! - 盘点 → `/api/stock-counts` 会话，经理审批后过账差异
+//! - 门店间调拨 → `/api/stock-transfers`，发货即扣减，收货门店确认后入库 (经 crab-cloud 中继)
+//! - 数量降至低库存阈值 → 关键通知 (离线终端重连补发)
+//!
+//! 库存不阻止销售 (数量可为负)；需要停售时使用沽清 (86)。
+
+use std::sync::Arc;
+
+use sqlx::SqlitePool;
+
+use crate::core::state::ResourceVersions;
+use crate::db::repository::inventory;
+use crate::message::MessageBus;
+use shared::cloud::SyncResource;
+use shared::message::{
+    BusMessage, NotificationCategory, NotificationPayload, SyncChangeType, SyncPayload,
+};
+use shared::models::{StockConsumption, StockLevel};
+use shared::order::OrderSnapshot;
+
+pub const RESOURCE: SyncResource = SyncResource::StockLevel;
+
+/// 订单完成时扣减库存，广播变更并发出低库存通知
+///
+/// OrdersManager 不持有 ServerState，单独注入消息总线与资源版本号。
+pub struct InventoryTracker {
+    pool: SqlitePool,
+    bus: Arc<MessageBus>,
+    versions: Arc<ResourceVersions>,
+}
+
+impl InventoryTracker {
+    pub fn new(pool: SqlitePool, bus: Arc<MessageBus>, versions: Arc<ResourceVersions>) -> Self {
+        Self {
+            pool,
+            bus,
+            versions,
+        }
+    }
+
+    pub async fn on_order_completed(&self, snapshot: &OrderSnapshot) {
+        let items = consumption(snapshot);
+        if items.is_empty() {
+            return;
+        }
+        let changed = match inventory::deduct_for_order(&self.pool, snapshot.order_id, &items).await
+        {
+            Ok(changed) => changed,
+            Err(e) => {
+                tracing::error!(order_id = snapshot.order_id, error = %e, "Failed to deduct stock");
+                return;
+            }
+        };
+
+        for (level, before) in changed {
+            let payload = SyncPayload {
+                resource: RESOURCE,
+                version: self.versions.increment(RESOURCE),
+                action: SyncChangeType::Updated,
+                id: level.id,
+                data: serde_json::to_value(&level).ok(),
+                cloud_origin: false,
+            };
+            if let Err(e) = self.bus.publish(BusMessage::sync(&payload)).await {
+                tracing::debug!("Stock sync not broadcast: {}", e);
+            }
+            if level.became_low(before) {
+                notify_low_stock(&self.bus, &level).await;
+            }
+        }
+    }
+}
+
+/// 订单行消耗的库存 (已完成订单中的全部商品，含赠送)
+///
+/// 每个已选属性选项按 商品数量 × 选项数量 计入 (是否关联库存由扣减时判断)。
+pub fn consumption(snapshot: &OrderSnapshot) -> Vec<StockConsumption> {
+    let mut lines = Vec::new();
+    for item in snapshot.items.iter().filter(|item| item.quantity > 0) {
+        let quantity = i64::from(item.quantity);
+        lines.push(StockConsumption::Product {
+            product_id: item.id,
+            spec_id: item.selected_specification.as_ref().map(|s| s.id),
+            quantity,
+        });
+        for option in item.selected_options.iter().flatten() {
+            lines.push(StockConsumption::Modifier {
+                option_id: option.option_id,
+                quantity: quantity * i64::from(option.quantity),
+            });
+        }
+    }
+    lines
+}
+
+/// 低库存关键通知
+pub async fn notify_low_stock(bus: &MessageBus, level: &StockLevel) {
+    let name = match &level.spec_name {
+        Some(spec) => format!("{} ({})", level.product_name, spec),
+        None => level.product_name.clone(),
+    };
+    let payload =
+        NotificationPayload::warning("Low stock", format!("{}: {} left", name, level.quantity))
+            .with_category(NotificationCategory::Business)
+            .with_data(serde_json::json!({
+                "kind": "low_stock",
+                "stock_level_id": level.id,
+                "product_id": level.product_id,
+                "spec_id": level.spec_id,
+                "quantity": level.quantity,
+                "low_stock_threshold": level.low_stock_threshold,
+            }));
+    if let Err(e) = bus.publish_durable(payload).await {
+        tracing::debug!("Low stock notification not broadcast: {}", e);
+    }
+}
@@ -0,0 +1,178 @@
+//! 实时经营指标 (经理看板)
+//!
+//! 每隔 [`Config::kpi_interval_secs`](crate::core::Config) 秒汇总一次营业日指标，
+//! 通过 `EventType::Kpi` 推送。TCP 客户端需在握手时 `subscribe_kpi` 才会收到。
+//!
+//! - 销售额 / 订单数：已归档的 COMPLETED 订单 (不含作废)
+//! - 上周同期：上周同一营业日，截至同一时刻
+//! - 来客数：已完成 + 进行中订单的人数
+//! - 厨房积压：进行中订单在 [`KITCHEN_BACKLOG_WINDOW_MS`] 内的送厨单
+
+use std::collections::HashSet;
+use std::time::Duration;
+
+use tokio_util::sync::CancellationToken;
+
+use crate::core::ServerState;
+use crate::db::repository::store_info;
+use crate::utils::time;
+use crate::utils::{AppError, AppResult};
+use shared::message::{BusMessage, KpiPayload};
+
+/// 送厨后多久仍视为积压 (无出餐状态，按时间窗口估算)
+pub const KITCHEN_BACKLOG_WINDOW_MS: i64 = 45 * 60 * 1000;
+
+/// 一周 (上周同期对比)
+const WEEK_MS: i64 = 7 * 24 * 60 * 60 * 1000;
+
+/// 汇总当前营业日指标
+pub async fn snapshot(state: &ServerState) -> AppResult<KpiPayload> {
+    let pool = &state.pool;
+    let tz = state.config.timezone;
+    let cutoff = store_info::get(pool)
+        .await
+        .ok()
+        .flatten()
+        .map(|s| s.business_day_cutoff)
+        .unwrap_or(0);
+    let cutoff_time = time::cutoff_to_time(cutoff);
+    let business_date = time::current_business_date(cutoff_time, tz);
+    let day_start = time::date_cutoff_millis(business_date, cutoff_time, tz);
+    let now = shared::util::now_millis();
+
+    let (sales_today, orders_today, archived_covers): (f64, i64, i64) = sqlx::query_as(
+        "SELECT \
+            COALESCE(SUM(total_amount), 0.0), \
+            CAST(COUNT(*) AS INTEGER), \
+            CAST(COALESCE(SUM(guest_count), 0) AS INTEGER) \
+         FROM archived_order \
+         WHERE status = 'COMPLETED' AND is_voided = 0 AND end_time >= ?1 AND end_time < ?2",
+    )
+    .bind(day_start)
+    .bind(now)
+    .fetch_one(pool)
+    .await
+    .map_err(|e| AppError::database(e.to_string()))?;
+
+    let sales_same_day_last_week: f64 = sqlx::query_scalar(
+        "SELECT COALESCE(SUM(total_amount), 0.0) FROM archived_order \
+         WHERE status = 'COMPLETED' AND is_voided = 0 AND end_time >= ?1 AND end_time < ?2",
+    )
+    .bind(day_start - WEEK_MS)
+    .bind(now - WEEK_MS)
+    .fetch_one(pool)
+    .await
+    .map_err(|e| AppError::database(e.to_string()))?;
+
+    let active = state
+        .orders_manager()
+        .get_active_orders()
+        .map_err(|e| AppError::internal(e.to_string()))?;
+    let open_tables = active
+        .iter()
+        .filter_map(|o| o.table_id)
+        .collect::<HashSet<_>>()
+        .len() as i64;
+    let open_covers: i64 = active.iter().map(|o| o.guest_count as i64).sum();
+    let open_amount: f64 = active.iter().map(|o| o.remaining_amount).sum();
+
+    let mut kitchen_backlog_tickets = 0;
+    let mut kitchen_backlog_items = 0;
+    let mut kitchen_oldest_ticket_at: Option<i64> = None;
+    let printing = state.kitchen_print_service();
+    for order in &active {
+        let tickets = match printing.get_kitchen_orders_for_order(order.order_id) {
+            Ok(tickets) => tickets,
+            Err(e) => {
+                tracing::warn!(order_id = order.order_id, error = %e, "Failed to load kitchen orders");
+                continue;
+            }
+        };
+        for ticket in tickets
+            .iter()
+            .filter(|t| t.created_at >= now - KITCHEN_BACKLOG_WINDOW_MS)
+        {
+            kitchen_backlog_tickets += 1;
+            kitchen_backlog_items += ticket
+                .items
+                .iter()
+                .map(|i| i.context.quantity as i64)
+                .sum::<i64>();
+            kitchen_oldest_ticket_at = Some(
+                kitchen_oldest_ticket_at.map_or(ticket.created_at, |t| t.min(ticket.created_at)),
+            );
+        }
+    }
+
+    Ok(KpiPayload {
+        generated_at: now,
+        business_date: business_date.format("%Y-%m-%d").to_string(),
+        sales_today,
+        sales_same_day_last_week,
+        orders_today,
+        covers_today: archived_covers + open_covers,
+        average_ticket: if orders_today > 0 {
+            sales_today / orders_today as f64
+        } else {
+            0.0
+        },
+        open_tables,
+        open_orders: active.len() as i64,
+        open_amount,
+        kitchen_backlog_tickets,
+        kitchen_backlog_items,
+        kitchen_oldest_ticket_at,
+    })
+}
+
+/// 实时经营指标推送服务
+///
+/// 注册为 `TaskKind::Periodic`，在 `start_background_tasks()` 中启动。
+pub struct KpiService {
+    state: ServerState,
+    shutdown: CancellationToken,
+    interval: Duration,
+}
+
+impl KpiService {
+    pub fn new(state: ServerState, shutdown: CancellationToken, interval: Duration) -> Self {
+        Self {
+            state,
+            shutdown,
+            interval,
+        }
+    }
+
+    /// 主循环：按间隔汇总并推送
+    pub async fn run(self) {
+        tracing::info!(
+            interval_secs = self.interval.as_secs(),
+            "KPI service started"
+        );
+
+        loop {
+            tokio::select! {
+                _ = tokio::time::sleep(self.interval) => {}
+                _ = self.shutdown.cancelled() => {
+                    tracing::info!("KPI service received shutdown signal");
+                    return;
+                }
+            }
+
+            match snapshot(&self.state).await {
+                Ok(payload) => {
+                    if let Err(e) = self
+                        .state
+                        .message_bus()
+                        .publish(BusMessage::kpi(&payload))
+                        .await
+                    {
+                        // 没有订阅者时 broadcast 返回错误，忽略
+                        tracing::trace!("KPI publish skipped: {}", e);
+                    }
+                }
+                Err(e) => tracing::warn!("Failed to compute KPI snapshot: {}", e),
+            }
+        }
+    }
+}
@@ -23,7 +23,7 @@
 //! ├── message/       # 消息总线
 //! ├── orders/        # 订单事件溯源 (核心引擎)
 //! ├── archiving/     # 归档系统 (SQLite + 哈希链验证)
-//! ├── order_money/   # 金额计算 (rust_decimal)
+//! ├── hosting/       # 多租户托管 (单进程多租户)
 //! └── order_sync     # 重连同步协议
 //! ```
 
@@ -31,24 +31,38 @@ pub mod api;
 pub mod archiving;
 pub mod audit;
 pub mod auth;
+pub mod carry_over;
+#[cfg(feature = "chaos")]
+pub mod chaos;
 pub mod cloud;
 pub mod core;
+pub mod customer_display;
 pub mod daily_reports;
 pub mod db;
+pub mod event_bookings;
+pub mod fiscal;
+pub mod hosting;
+pub mod inventory;
+pub mod kpi;
 pub mod marketing;
 pub mod message;
-pub mod order_money;
+pub mod notify;
 pub mod order_sync;
 pub mod orders;
+pub mod pms;
 pub mod pricing;
 pub mod printing;
+pub mod projection;
+pub mod recovery;
 pub mod services;
+pub mod setup;
 pub mod shifts;
 pub mod utils;
+pub mod watchdog;
 
 // Re-export 公共类型
 pub use auth::{CurrentUser, JwtService};
-pub use core::{Config, Server, ServerState};
+pub use core::{Config, ListenerControl, ListenerPorts, Server, ServerState};
 pub use message::{BusMessage, EventType};
 pub use orders::{OrderStorage, OrdersManager};
 pub use utils::{AppError, AppResult};
@@ -57,7 +71,9 @@ pub use utils::{AppError, AppResult};
 pub use utils::{ApiResponse, ErrorCategory, ErrorCode};
 
 // Re-export logger functions
-pub use utils::logger::{cleanup_old_logs, init_logger, init_logger_with_file};
+pub use utils::logger::{
+    cleanup_old_logs, init_logger, init_logger_with_file, init_logger_with_otlp, shutdown_telemetry,
+};
 
 /// 审计日志宏 — 异步记录到 AuditService
 ///
@@ -6,17 +6,21 @@
 //! - 初始化日志系统
 //! - 启动服务器
 
+use edge_server::hosting::{HostingManifest, TenantSupervisor, check_exposure, supervisor_router};
 use edge_server::{
-    Config, Server, ServerState, cleanup_old_logs, init_logger_with_file, print_banner,
+    Config, Server, ServerState, cleanup_old_logs, init_logger_with_otlp, print_banner,
+    shutdown_telemetry,
 };
 use std::path::PathBuf;
+use std::sync::Arc;
 
 /// 设置运行环境 (仅 bin 使用)
 ///
 /// - 加载 .env 文件
 /// - 创建必要的目录结构
-/// - 初始化日志系统
-fn setup_environment() -> Result<PathBuf, Box<dyn std::error::Error>> {
+/// - 加载配置 (Profile 文件 + 环境变量)
+/// - 初始化日志系统 (配置了 OTLP 端点时同时导出 trace)
+fn setup_environment() -> Result<(PathBuf, Config), Box<dyn std::error::Error>> {
     // 加载 .env 文件 (仅 bin 层面支持)
     dotenvy::dotenv().ok();
 
@@ -57,10 +61,14 @@ fn setup_environment() -> Result<PathBuf, Box<dyn std::error::Error>> {
 
     let log_level = std::env::var("LOG_LEVEL").unwrap_or_else(|_| "info".to_string());
 
-    init_logger_with_file(
+    // 分层配置：值无效或违反 Profile 约束时拒绝启动
+    let config = Config::load().map_err(|e| format!("Invalid configuration: {e}"))?;
+
+    init_logger_with_otlp(
         Some(&log_level),
         Some(json_format),
         Some(log_dir.to_str().unwrap_or("logs")),
+        config.otlp.as_ref(),
     );
 
     // 清理旧日志 (忽略错误)
@@ -72,13 +80,13 @@ fn setup_environment() -> Result<PathBuf, Box<dyn std::error::Error>> {
         log_level
     );
 
-    Ok(work_dir)
+    Ok((work_dir, config))
 }
 
 #[tokio::main]
 async fn main() -> Result<(), Box<dyn std::error::Error>> {
-    // 1. 设置环境 (dotenv, 工作目录, 日志)
-    let work_dir = setup_environment()?;
+    // 1. 设置环境 (dotenv, 工作目录, 配置, 日志)
+    let (work_dir, config) = setup_environment()?;
 
     // 打印横幅
     print_banner();
@@ -86,13 +94,15 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
     tracing::info!("Crab Edge Server starting...");
     tracing::debug!("Work directory: {}", work_dir.display());
 
-    // 2. 加载配置 (从环境变量)
-    let config = Config::from_env();
+    // 多租户托管模式：HOSTING_MANIFEST 指向租户清单
+    if let Ok(manifest_path) = std::env::var("HOSTING_MANIFEST") {
+        return run_hosting(work_dir, config, PathBuf::from(manifest_path)).await;
+    }
 
-    // 3. 初始化服务器状态
+    // 2. 初始化服务器状态
     let state = ServerState::initialize(&config).await?;
 
-    // 4. 启动 HTTP 服务器 (Server::run 会自动启动后台任务)
+    // 3. 启动 HTTP 服务器 (Server::run 会自动启动后台任务)
     let server = Server::with_state(config, state);
     let token = server.shutdown_token();
 
@@ -109,5 +119,63 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
         tracing::error!("Server error: {}", e);
     }
 
+    shutdown_telemetry();
+    result
+}
+
+/// 多租户托管模式
+///
+/// 每个租户独立运行（独立 work_dir / 证书 / 端口），监管 API 默认仅监听本机回环地址。
+///
+/// | 变量 | 默认值 | 说明 |
+/// |------|--------|------|
+/// | HOSTING_MANIFEST | - | 租户清单 JSON |
+/// | SUPERVISOR_PORT | 9900 | 监管 API 端口 |
+/// | SUPERVISOR_BIND | 127.0.0.1 | 监管 API 监听地址 |
+/// | SUPERVISOR_TOKEN | - | 监管 API Bearer token (非回环地址时必填) |
+async fn run_hosting(
+    host_dir: PathBuf,
+    base_config: Config,
+    manifest_path: PathBuf,
+) -> Result<(), Box<dyn std::error::Error>> {
+    let manifest = HostingManifest::load(&manifest_path)?;
+    tracing::info!(
+        tenants = manifest.tenants.len(),
+        "Multi-tenant hosting mode ({})",
+        manifest_path.display()
+    );
+
+    let port: u16 = std::env::var("SUPERVISOR_PORT")
+        .ok()
+        .and_then(|p| p.parse().ok())
+        .unwrap_or(9900);
+    let token = std::env::var("SUPERVISOR_TOKEN")
+        .ok()
+        .filter(|t| !t.is_empty());
+    let bind: std::net::IpAddr = match std::env::var("SUPERVISOR_BIND") {
+        Ok(addr) => addr.parse()?,
+        Err(_) => std::net::Ipv4Addr::LOCALHOST.into(),
+    };
+    check_exposure(bind, token.as_deref())?;
+
+    let supervisor = Arc::new(TenantSupervisor::new(host_dir, base_config, manifest));
+    supervisor.start_autostart().await;
+
+    let listener = tokio::net::TcpListener::bind((bind, port)).await?;
+    tracing::info!("Supervisor API listening on {}:{}", bind, port);
+
+    let app = supervisor_router(supervisor.clone(), token);
+    let result = tokio::select! {
+        r = axum::serve(listener, app) => r.map_err(|e| e.into()),
+        _ = tokio::signal::ctrl_c() => Ok(()),
+    };
+
+    supervisor.shutdown_all().await;
+
+    if let Err(e) = &result {
+        tracing::error!("Supervisor error: {}", e);
+    }
+
+    shutdown_telemetry();
     result
 }
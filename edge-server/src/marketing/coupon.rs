@@ -0,0 +1,152 @@
+//! Coupon Redemption (优惠码核销)
+//!
+//! - 应用: `ApplyCoupon` 命令，OrdersManager 预取时按码查找并用 [`check_redeemable`] 校验
+//! - 核销: 订单完成 → Phase C 调用 [`CouponTracker::on_order_completed`] 记录并累加 used_count
+//! - 同步: 核销后广播 `SyncResource::Coupon`，使用次数随云同步回传 crab-cloud
+
+use std::sync::Arc;
+
+use sqlx::SqlitePool;
+
+use crate::core::state::ResourceVersions;
+use crate::db::repository::coupon;
+use crate::message::MessageBus;
+use shared::cloud::SyncResource;
+use shared::message::{BusMessage, SyncChangeType, SyncPayload};
+use shared::models::Coupon;
+use shared::order::types::CommandErrorCode;
+use shared::order::{AppliedCoupon, OrderSnapshot};
+
+pub const RESOURCE: SyncResource = SyncResource::Coupon;
+
+/// Validate a coupon can be applied at `now` (Unix millis)
+pub fn check_redeemable(coupon: &Coupon, now: i64) -> Result<(), (CommandErrorCode, String)> {
+    if coupon.is_exhausted() {
+        return Err((
+            CommandErrorCode::CouponExhausted,
+            format!("Coupon {} has no uses left", coupon.code),
+        ));
+    }
+    if !coupon.is_redeemable_at(now) {
+        return Err((
+            CommandErrorCode::CouponExpired,
+            format!("Coupon {} is not active", coupon.code),
+        ));
+    }
+    Ok(())
+}
+
+/// Freeze the coupon's discount terms onto the order
+pub fn to_applied(coupon: &Coupon) -> AppliedCoupon {
+    AppliedCoupon {
+        coupon_id: coupon.id,
+        code: coupon.code.clone(),
+        name: coupon.name.clone(),
+        product_scope: coupon.product_scope.clone(),
+        target_id: coupon.target_id,
+        adjustment_type: coupon.adjustment_type.clone(),
+        adjustment_value: coupon.adjustment_value,
+        amount: 0.0,
+    }
+}
+
+/// 订单完成时核销优惠码并广播使用次数
+///
+/// OrdersManager 不持有 ServerState，单独注入消息总线与资源版本号。
+pub struct CouponTracker {
+    pool: SqlitePool,
+    bus: Arc<MessageBus>,
+    versions: Arc<ResourceVersions>,
+}
+
+impl CouponTracker {
+    pub fn new(pool: SqlitePool, bus: Arc<MessageBus>, versions: Arc<ResourceVersions>) -> Self {
+        Self {
+            pool,
+            bus,
+            versions,
+        }
+    }
+
+    pub async fn on_order_completed(&self, snapshot: &OrderSnapshot) {
+        let Some(applied) = &snapshot.coupon else {
+            return;
+        };
+        let updated = match coupon::redeem_for_order(
+            &self.pool,
+            applied.coupon_id,
+            snapshot.order_id,
+            applied.amount,
+        )
+        .await
+        {
+            Ok(Some(updated)) => updated,
+            Ok(None) => return,
+            Err(e) => {
+                tracing::error!(
+                    order_id = snapshot.order_id,
+                    coupon_id = applied.coupon_id,
+                    error = %e,
+                    "Failed to record coupon redemption"
+                );
+                return;
+            }
+        };
+        tracing::info!(
+            order_id = snapshot.order_id,
+            code = %updated.code,
+            used_count = updated.used_count,
+            "Coupon redeemed"
+        );
+
+        let payload = SyncPayload {
+            resource: RESOURCE,
+            version: self.versions.increment(RESOURCE),
+            action: SyncChangeType::Updated,
+            id: updated.id,
+            data: serde_json::to_value(&updated).ok(),
+            cloud_origin: false,
+        };
+        if let Err(e) = self.bus.publish(BusMessage::sync(&payload)).await {
+            tracing::debug!("Coupon sync not broadcast: {}", e);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use shared::models::price_rule::{AdjustmentType, ProductScope};
+
+    fn single_use(used_count: i64) -> Coupon {
+        Coupon {
+            id: 1,
+            code: "ONCE".to_string(),
+            name: "Once".to_string(),
+            product_scope: ProductScope::Global,
+            target_id: None,
+            adjustment_type: AdjustmentType::FixedAmount,
+            adjustment_value: 5.0,
+            max_uses: Some(1),
+            used_count,
+            valid_from: None,
+            valid_until: Some(2_000),
+            is_active: true,
+            created_at: 0,
+            updated_at: 0,
+        }
+    }
+
+    #[test]
+    fn test_check_redeemable_codes() {
+        assert!(check_redeemable(&single_use(0), 1_000).is_ok());
+        assert!(matches!(
+            check_redeemable(&single_use(1), 1_000),
+            Err((CommandErrorCode::CouponExhausted, _))
+        ));
+        assert!(matches!(
+            check_redeemable(&single_use(0), 3_000),
+            Err((CommandErrorCode::CouponExpired, _))
+        ));
+    }
+}
@@ -0,0 +1,611 @@
+//! 会员批量导入 — 餐厅迁移旧会员名单 (CSV)
+//!
+//! ```text
+//! CSV → parse_rows → plan_rows (去重 + 营销组映射 + 校验) → MemberImportReport
+//!                                                          → apply (后台任务，总线进度通知)
+//! ```
+//!
+//! 表头按别名识别 (中/英/西，忽略大小写和重音)，必填 phone + name。
+//! group 列按营销组名称 (忽略大小写) 或 id 映射，为空时使用 `default_group_id`。
+//!
+//! 去重规则 (按规范化手机号):
+//! - 文件内重复：保留第一行，后续行计入 `duplicates_in_file`
+//! - 已有会员：`on_duplicate = skip` 跳过 (默认)；`update` 覆盖文件中给出的字段
+//!
+//! 有错误的行不导入，其余行照常处理；dry_run 只返回报告不写库。
+
+use std::collections::HashMap;
+
+use serde::{Deserialize, Serialize};
+use shared::cloud::SyncResource;
+use shared::message::{BusMessage, NotificationCategory, NotificationPayload, SyncChangeType};
+use shared::models::{MarketingGroup, MemberCreate, MemberUpdate};
+use sqlx::SqlitePool;
+
+use crate::archiving::import::{decode_text, detect_delimiter, normalize_header, parse_records};
+use crate::audit::AuditAction;
+use crate::audit_log;
+use crate::auth::CurrentUser;
+use crate::core::ServerState;
+use crate::db::repository::{marketing_group, member};
+use crate::utils::validation::{
+    MAX_EMAIL_LEN, MAX_NAME_LEN, MAX_NOTE_LEN, MAX_SHORT_TEXT_LEN, normalize_phone,
+    validate_optional_text, validate_required_text,
+};
+use crate::utils::{AppError, AppResult};
+
+/// 单次导入的行数上限
+pub const MAX_IMPORT_ROWS: usize = 50_000;
+
+/// 每处理多少行发布一次进度通知
+const PROGRESS_EVERY: usize = 100;
+
+/// 已有会员 (手机号相同) 的处理方式
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum DuplicatePolicy {
+    #[default]
+    Skip,
+    Update,
+}
+
+/// 导入选项 (API query 参数)
+#[derive(Debug, Clone, Default, Deserialize)]
+pub struct MemberImportOptions {
+    /// 只校验，返回报告
+    #[serde(default)]
+    pub dry_run: bool,
+    #[serde(default)]
+    pub on_duplicate: DuplicatePolicy,
+    /// group 列为空时使用的营销组
+    pub default_group_id: Option<i64>,
+}
+
+/// 解析后的 CSV 行 (原始文本，未校验)
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct MemberRow {
+    /// CSV 行号 (报告定位用)
+    pub line: usize,
+    pub phone: Option<String>,
+    pub name: Option<String>,
+    pub group: Option<String>,
+    pub card_number: Option<String>,
+    pub email: Option<String>,
+    pub birthday: Option<String>,
+    pub notes: Option<String>,
+}
+
+/// 行级错误
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct RowError {
+    pub line: usize,
+    pub message: String,
+}
+
+/// 校验报告 (dry_run 与正式导入相同)
+#[derive(Debug, Clone, Default, Serialize)]
+pub struct MemberImportReport {
+    pub dry_run: bool,
+    /// 后台任务 id，对应进度通知 `data.member_import.job_id` (dry_run 时为 None)
+    pub job_id: Option<String>,
+    pub total_rows: usize,
+    pub to_create: usize,
+    pub to_update: usize,
+    /// 已有会员且 on_duplicate = skip
+    pub skipped_existing: usize,
+    /// 文件内手机号重复的行
+    pub duplicates_in_file: usize,
+    pub errors: Vec<RowError>,
+}
+
+/// 计划执行的写操作
+#[derive(Debug, Clone)]
+pub enum MemberImportAction {
+    Create(MemberCreate),
+    Update { id: i64, data: MemberUpdate },
+}
+
+/// 校验结果：报告 + 待执行操作 (行号, 操作)
+#[derive(Debug)]
+pub struct MemberImportPlan {
+    pub report: MemberImportReport,
+    pub actions: Vec<(usize, MemberImportAction)>,
+}
+
+/// 后台导入进度 (总线通知的 `data.member_import`)
+#[derive(Debug, Clone, Default, Serialize)]
+pub struct MemberImportProgress {
+    pub job_id: String,
+    pub total: usize,
+    pub processed: usize,
+    pub created: usize,
+    pub updated: usize,
+    pub failed: usize,
+    pub done: bool,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+enum Column {
+    Phone,
+    Name,
+    Group,
+    CardNumber,
+    Email,
+    Birthday,
+    Notes,
+}
+
+const ALIASES: &[(Column, &[&str])] = &[
+    (
+        Column::Phone,
+        &[
+            "phone",
+            "phone_number",
+            "mobile",
+            "tel",
+            "telephone",
+            "telefono",
+            "movil",
+            "电话",
+            "手机",
+            "手机号",
+        ],
+    ),
+    (
+        Column::Name,
+        &[
+            "name",
+            "full_name",
+            "customer",
+            "nombre",
+            "cliente",
+            "姓名",
+            "名字",
+        ],
+    ),
+    (
+        Column::Group,
+        &[
+            "group",
+            "marketing_group",
+            "tier",
+            "level",
+            "grupo",
+            "nivel",
+            "会员组",
+            "分组",
+            "等级",
+        ],
+    ),
+    (
+        Column::CardNumber,
+        &[
+            "card",
+            "card_number",
+            "card_no",
+            "tarjeta",
+            "numero_tarjeta",
+            "卡号",
+        ],
+    ),
+    (
+        Column::Email,
+        &["email", "e_mail", "mail", "correo", "邮箱"],
+    ),
+    (
+        Column::Birthday,
+        &[
+            "birthday",
+            "birth_date",
+            "date_of_birth",
+            "cumpleanos",
+            "fecha_nacimiento",
+            "生日",
+        ],
+    ),
+    (
+        Column::Notes,
+        &["notes", "note", "notas", "observaciones", "备注"],
+    ),
+];
+
+/// 解析 CSV (分隔符自动识别，非 UTF-8 按 Windows-1252 解码)
+pub fn parse_rows(data: &[u8]) -> AppResult<Vec<MemberRow>> {
+    let text = decode_text(data);
+    let delimiter = detect_delimiter(&text);
+    let mut records = parse_records(&text, delimiter).into_iter();
+
+    let (_, header) = records
+        .next()
+        .ok_or_else(|| AppError::validation("CSV file is empty"))?;
+    let mut columns: HashMap<Column, usize> = HashMap::new();
+    for (pos, name) in header.iter().enumerate() {
+        let name = normalize_header(name);
+        if let Some((column, _)) = ALIASES.iter().find(|(_, a)| a.contains(&name.as_str())) {
+            columns.entry(*column).or_insert(pos);
+        }
+    }
+    let missing: Vec<&str> = [(Column::Phone, "phone"), (Column::Name, "name")]
+        .iter()
+        .filter(|(c, _)| !columns.contains_key(c))
+        .map(|(_, name)| *name)
+        .collect();
+    if !missing.is_empty() {
+        return Err(AppError::validation(format!(
+            "CSV header is missing required columns: {}",
+            missing.join(", ")
+        )));
+    }
+
+    let rows: Vec<MemberRow> = records
+        .map(|(line, record)| {
+            let get = |column: Column| {
+                columns
+                    .get(&column)
+                    .and_then(|pos| record.get(*pos))
+                    .map(|v| v.trim())
+                    .filter(|v| !v.is_empty())
+                    .map(String::from)
+            };
+            MemberRow {
+                line,
+                phone: get(Column::Phone),
+                name: get(Column::Name),
+                group: get(Column::Group),
+                card_number: get(Column::CardNumber),
+                email: get(Column::Email),
+                birthday: get(Column::Birthday),
+                notes: get(Column::Notes),
+            }
+        })
+        .collect();
+    if rows.len() > MAX_IMPORT_ROWS {
+        return Err(AppError::validation(format!(
+            "CSV has {} rows, max {MAX_IMPORT_ROWS}",
+            rows.len()
+        )));
+    }
+    Ok(rows)
+}
+
+/// 营销组映射：名称 (忽略大小写) 或 id
+fn resolve_group(value: &str, groups: &[MarketingGroup]) -> Option<i64> {
+    groups
+        .iter()
+        .find(|g| g.name.trim().eq_ignore_ascii_case(value) || g.id.to_string() == value)
+        .map(|g| g.id)
+}
+
+fn validate_row(row: &MemberRow, name: &str) -> Result<(), AppError> {
+    validate_required_text(name, "name", MAX_NAME_LEN)?;
+    validate_optional_text(&row.card_number, "card_number", MAX_SHORT_TEXT_LEN)?;
+    validate_optional_text(&row.birthday, "birthday", MAX_SHORT_TEXT_LEN)?;
+    validate_optional_text(&row.email, "email", MAX_EMAIL_LEN)?;
+    validate_optional_text(&row.notes, "notes", MAX_NOTE_LEN)?;
+    Ok(())
+}
+
+/// 校验并生成写操作 (纯函数，不访问数据库)
+///
+/// `existing` = 已有会员的规范化手机号 → 会员 id。
+pub fn plan_rows(
+    rows: &[MemberRow],
+    groups: &[MarketingGroup],
+    existing: &HashMap<String, i64>,
+    options: &MemberImportOptions,
+) -> MemberImportPlan {
+    let mut report = MemberImportReport {
+        dry_run: options.dry_run,
+        total_rows: rows.len(),
+        ..Default::default()
+    };
+    let mut actions = Vec::new();
+    let mut seen: HashMap<String, usize> = HashMap::new();
+
+    for row in rows {
+        let error = |message: String| RowError {
+            line: row.line,
+            message,
+        };
+        let Some(raw_phone) = row.phone.as_deref() else {
+            report.errors.push(error("missing phone".into()));
+            continue;
+        };
+        let phone = match normalize_phone(raw_phone) {
+            Ok(phone) => phone,
+            Err(e) => {
+                report.errors.push(error(e.message));
+                continue;
+            }
+        };
+        let name = row.name.as_deref().unwrap_or_default();
+        if let Err(e) = validate_row(row, name) {
+            report.errors.push(error(e.message));
+            continue;
+        }
+        let group_id = match row.group.as_deref() {
+            Some(group) => match resolve_group(group, groups) {
+                Some(id) => Some(id),
+                None => {
+                    report
+                        .errors
+                        .push(error(format!("unknown marketing group '{group}'")));
+                    continue;
+                }
+            },
+            None => None,
+        };
+        if let Some(first_line) = seen.get(&phone) {
+            report.duplicates_in_file += 1;
+            report.errors.push(error(format!(
+                "duplicate phone {phone} (first seen on line {first_line})"
+            )));
+            continue;
+        }
+        seen.insert(phone.clone(), row.line);
+
+        match existing.get(&phone) {
+            Some(_) if options.on_duplicate == DuplicatePolicy::Skip => {
+                report.skipped_existing += 1;
+            }
+            Some(&id) => {
+                report.to_update += 1;
+                actions.push((
+                    row.line,
+                    MemberImportAction::Update {
+                        id,
+                        data: MemberUpdate {
+                            name: Some(name.to_string()),
+                            phone: None,
+                            card_number: row.card_number.clone(),
+                            // 未给出分组时保留会员现有分组
+                            marketing_group_id: group_id,
+                            birthday: row.birthday.clone(),
+                            email: row.email.clone(),
+                            notes: row.notes.clone(),
+                            preferred_locale: None,
+                            is_active: None,
+                        },
+                    },
+                ));
+            }
+            None => {
+                let Some(marketing_group_id) = group_id.or(options.default_group_id) else {
+                    report.errors.push(error(
+                        "missing marketing group (no default_group_id)".into(),
+                    ));
+                    continue;
+                };
+                report.to_create += 1;
+                actions.push((
+                    row.line,
+                    MemberImportAction::Create(MemberCreate {
+                        name: name.to_string(),
+                        phone: Some(phone),
+                        card_number: row.card_number.clone(),
+                        marketing_group_id,
+                        birthday: row.birthday.clone(),
+                        email: row.email.clone(),
+                        notes: row.notes.clone(),
+                        preferred_locale: None,
+                    }),
+                ));
+            }
+        }
+    }
+
+    MemberImportPlan { report, actions }
+}
+
+/// 读取营销组和已有会员手机号后生成导入计划
+pub async fn plan(
+    pool: &SqlitePool,
+    rows: &[MemberRow],
+    options: &MemberImportOptions,
+) -> AppResult<MemberImportPlan> {
+    let groups = marketing_group::find_all(pool).await?;
+    if let Some(id) = options.default_group_id
+        && !groups.iter().any(|g| g.id == id)
+    {
+        return Err(AppError::validation(format!(
+            "default_group_id {id} is not a marketing group"
+        )));
+    }
+
+    // 旧数据手机号格式不统一，按规范化后的号码比对 (无效号码不参与去重)
+    let phones = sqlx::query_as::<_, (i64, String)>(
+        "SELECT id, phone FROM member WHERE is_active = 1 AND phone IS NOT NULL AND phone != ''",
+    )
+    .fetch_all(pool)
+    .await
+    .map_err(|e| AppError::database(e.to_string()))?;
+    let existing: HashMap<String, i64> = phones
+        .into_iter()
+        .filter_map(|(id, phone)| normalize_phone(&phone).ok().map(|p| (p, id)))
+        .collect();
+
+    Ok(plan_rows(rows, groups.as_slice(), &existing, options))
+}
+
+/// 发布进度通知 (无订阅者时忽略)
+async fn publish_progress(state: &ServerState, progress: &MemberImportProgress) {
+    let message = format!("{}/{}", progress.processed, progress.total);
+    let payload = if progress.done && progress.failed > 0 {
+        NotificationPayload::warning("Member import finished with errors", message)
+    } else if progress.done {
+        NotificationPayload::info("Member import finished", message)
+    } else {
+        NotificationPayload::info("Member import", message)
+    }
+    .with_category(NotificationCategory::Business)
+    .with_data(serde_json::json!({ "member_import": progress }));
+    if let Err(e) = state
+        .message_bus()
+        .publish(BusMessage::notification(&payload))
+        .await
+    {
+        tracing::debug!("Member import progress not broadcast: {}", e);
+    }
+}
+
+/// 执行导入计划 (后台任务)，逐行写库并广播会员同步事件
+pub async fn apply(
+    state: ServerState,
+    job_id: String,
+    actions: Vec<(usize, MemberImportAction)>,
+    operator: CurrentUser,
+) -> MemberImportProgress {
+    let mut progress = MemberImportProgress {
+        job_id,
+        total: actions.len(),
+        ..Default::default()
+    };
+    publish_progress(&state, &progress).await;
+
+    for (line, action) in actions {
+        let result = match action {
+            MemberImportAction::Create(data) => member::create(&state.pool, data)
+                .await
+                .map(|m| (SyncChangeType::Created, m)),
+            MemberImportAction::Update { id, data } => member::update(&state.pool, id, data)
+                .await
+                .map(|m| (SyncChangeType::Updated, m)),
+        };
+        match result {
+            Ok((change, m)) => {
+                if change == SyncChangeType::Created {
+                    progress.created += 1;
+                } else {
+                    progress.updated += 1;
+                }
+                state
+                    .broadcast_sync(SyncResource::Member, change, m.id, Some(&m), false)
+                    .await;
+            }
+            Err(e) => {
+                tracing::warn!(job_id = %progress.job_id, line, "Member import row failed: {}", e);
+                progress.failed += 1;
+            }
+        }
+        progress.processed += 1;
+        if progress.processed.is_multiple_of(PROGRESS_EVERY) && progress.processed < progress.total
+        {
+            publish_progress(&state, &progress).await;
+        }
+    }
+
+    progress.done = true;
+    publish_progress(&state, &progress).await;
+
+    audit_log!(
+        state.audit_service,
+        AuditAction::MembersImported,
+        "member",
+        &progress.job_id,
+        operator_id = Some(operator.id),
+        operator_name = Some(operator.name.clone()),
+        details = serde_json::json!({
+            "total": progress.total,
+            "created": progress.created,
+            "updated": progress.updated,
+            "failed": progress.failed,
+        })
+    );
+    tracing::info!(
+        job_id = %progress.job_id,
+        created = progress.created,
+        updated = progress.updated,
+        failed = progress.failed,
+        "Member import finished"
+    );
+    progress
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn group(id: i64, name: &str) -> MarketingGroup {
+        MarketingGroup {
+            id,
+            name: name.to_string(),
+            description: None,
+            sort_order: 0,
+            points_earn_rate: None,
+            points_redeem_unit: None,
+            points_redeem_value: None,
+            created_at: 0,
+            updated_at: 0,
+        }
+    }
+
+    #[test]
+    fn test_parse_rows_aliases() {
+        let csv = "Teléfono;Nombre;Grupo;Correo\n\
+                   600 111 222;Ana;VIP;ana@example.com\n\
+                   ;;;\n\
+                   +34 600-333-444;\"Ruiz; Luis\";;\n";
+        let rows = parse_rows(csv.as_bytes()).unwrap();
+        assert_eq!(rows.len(), 2);
+        assert_eq!(rows[0].phone.as_deref(), Some("600 111 222"));
+        assert_eq!(rows[0].group.as_deref(), Some("VIP"));
+        assert_eq!(rows[0].email.as_deref(), Some("ana@example.com"));
+        assert_eq!(rows[1].line, 4);
+        assert_eq!(rows[1].name.as_deref(), Some("Ruiz; Luis"));
+        assert_eq!(rows[1].group, None);
+
+        let err = parse_rows(b"name,email\nAna,a@b.c\n").unwrap_err();
+        assert!(err.message.contains("phone"));
+    }
+
+    #[test]
+    fn test_plan_rows_dedup_and_groups() {
+        let groups = vec![group(1, "Default"), group(2, "VIP")];
+        let existing = HashMap::from([("600111222".to_string(), 77)]);
+        let rows = parse_rows(
+            "phone,name,group\n\
+             600111222,Ana,vip\n\
+             600999888,Luis,\n\
+             600 999 888,Luis again,VIP\n\
+             600555444,Eva,Gold\n\
+             abc,Bad,\n\
+             600777666,,VIP\n"
+                .as_bytes(),
+        )
+        .unwrap();
+
+        // Skip existing, no default group → new member without group is an error
+        let plan = plan_rows(&rows, &groups, &existing, &MemberImportOptions::default());
+        assert_eq!(plan.report.total_rows, 6);
+        assert_eq!(plan.report.skipped_existing, 1);
+        assert_eq!(plan.report.to_create, 0);
+        assert_eq!(plan.report.duplicates_in_file, 1);
+        let lines: Vec<usize> = plan.report.errors.iter().map(|e| e.line).collect();
+        assert_eq!(lines, vec![3, 4, 5, 6, 7]);
+        assert!(plan.actions.is_empty());
+
+        let options = MemberImportOptions {
+            dry_run: true,
+            on_duplicate: DuplicatePolicy::Update,
+            default_group_id: Some(1),
+        };
+        let plan = plan_rows(&rows, &groups, &existing, &options);
+        assert_eq!(plan.report.to_update, 1);
+        assert_eq!(plan.report.to_create, 1);
+        assert_eq!(plan.actions.len(), 2);
+        match &plan.actions[0].1 {
+            MemberImportAction::Update { id, data } => {
+                assert_eq!(*id, 77);
+                assert_eq!(data.marketing_group_id, Some(2));
+            }
+            other => panic!("expected update, got {other:?}"),
+        }
+        match &plan.actions[1].1 {
+            MemberImportAction::Create(data) => {
+                assert_eq!(data.phone.as_deref(), Some("600999888"));
+                assert_eq!(data.marketing_group_id, 1);
+            }
+            other => panic!("expected create, got {other:?}"),
+        }
+    }
+}
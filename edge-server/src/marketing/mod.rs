@@ -1,7 +1,13 @@
 //! Marketing Engine
 //!
 //! Independent from pricing/ module.
-//! Handles MG discount calculations and stamp tracking.
+//! Handles MG discount calculations, stamp tracking, loyalty points ([`points`])
+//! and coupon redemption ([`coupon`]).
+//! Bulk member import (CSV) lives in [`member_import`], upsell pairings in [`upsell`].
 
+pub mod coupon;
+pub mod member_import;
 pub mod mg_calculator;
+pub mod points;
 pub mod stamp_tracker;
+pub mod upsell;
@@ -0,0 +1,88 @@
+//! Loyalty Points
+//!
+//! Pure functions for spend-based points (积分), configured per marketing group:
+//! - Earn: `floor(paid_amount × points_earn_rate)` on order completion
+//! - Burn: every `points_redeem_unit` points are worth `points_redeem_value` € off the order
+//!
+//! Balance and ledger writes live in `db::repository::member_points`.
+
+use rust_decimal::prelude::*;
+use shared::models::MarketingGroup;
+
+use crab_order_core::money::{to_decimal, to_f64};
+
+/// Points earned for a paid amount (None rate / non-positive amount = 0).
+pub fn points_earned(paid_amount: f64, earn_rate: Option<f64>) -> i64 {
+    let Some(rate) = earn_rate else {
+        return 0;
+    };
+    if paid_amount <= 0.0 || rate <= 0.0 {
+        return 0;
+    }
+    (to_decimal(paid_amount) * to_decimal(rate))
+        .floor()
+        .to_i64()
+        .unwrap_or(0)
+}
+
+/// Whether the group allows redeeming points.
+pub fn redemption_enabled(group: &MarketingGroup) -> bool {
+    matches!(
+        (group.points_redeem_unit, group.points_redeem_value),
+        (Some(unit), Some(value)) if unit > 0 && value > 0.0
+    )
+}
+
+/// Discount value for redeeming `points`.
+///
+/// Returns None when the group has no redemption rule or `points` is not a
+/// positive multiple of the redeem unit.
+pub fn redemption_value(points: i64, group: &MarketingGroup) -> Option<f64> {
+    if !redemption_enabled(group) || points <= 0 {
+        return None;
+    }
+    let unit = group.points_redeem_unit?;
+    let value = group.points_redeem_value?;
+    if points % unit != 0 {
+        return None;
+    }
+    let amount = Decimal::from(points / unit) * to_decimal(value);
+    Some(to_f64(amount))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn group(unit: Option<i64>, value: Option<f64>) -> MarketingGroup {
+        MarketingGroup {
+            id: 1,
+            name: "VIP".to_string(),
+            description: None,
+            sort_order: 0,
+            points_earn_rate: Some(1.0),
+            points_redeem_unit: unit,
+            points_redeem_value: value,
+            created_at: 0,
+            updated_at: 0,
+        }
+    }
+
+    #[test]
+    fn test_points_earned_floors() {
+        assert_eq!(points_earned(42.99, Some(1.0)), 42);
+        assert_eq!(points_earned(10.0, Some(1.5)), 15);
+        assert_eq!(points_earned(10.0, None), 0);
+        assert_eq!(points_earned(-5.0, Some(1.0)), 0);
+    }
+
+    #[test]
+    fn test_redemption_value_requires_whole_units() {
+        let g = group(Some(100), Some(5.0));
+        assert_eq!(redemption_value(300, &g), Some(15.0));
+        assert_eq!(redemption_value(150, &g), None);
+        assert_eq!(redemption_value(0, &g), None);
+        assert_eq!(redemption_value(100, &group(None, Some(5.0))), None);
+        assert_eq!(redemption_value(100, &group(Some(100), None)), None);
+    }
+}
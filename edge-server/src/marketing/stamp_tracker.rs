@@ -1,14 +1,18 @@
 //! Stamp Tracker
 //!
-//! Pure functions for stamp counting and reward selection.
+//! Pure functions for stamp counting, reward selection and campaign windows.
 //! Works with CartItemSnapshot from the order system.
 //!
 //! Note: CartItemSnapshot uses `id` for product_id and has no `category_id` field.
 //! The caller must provide category_id via StampItemInfo or a lookup function.
 
-use shared::models::{RewardStrategy, StampRewardTarget, StampTarget, StampTargetType};
+use shared::models::{
+    RewardStrategy, StampActivity, StampRewardTarget, StampTarget, StampTargetType,
+};
 use shared::order::CartItemSnapshot;
 
+use crate::pricing::{TimeWindow, is_within_window};
+
 /// Lightweight info struct pairing a cart item with its category_id.
 ///
 /// CartItemSnapshot lacks `category_id` (only has `category_name`).
@@ -31,6 +35,35 @@ pub fn count_stamps_for_order(items: &[StampItemInfo<'_>], stamp_targets: &[Stam
         .sum()
 }
 
+/// Whether the activity is running at `now` (active flag + campaign dates + weekday/time window).
+///
+/// Outside the window orders earn no stamps and rewards cannot be redeemed.
+pub fn is_activity_open(activity: &StampActivity, now: i64, tz: chrono_tz::Tz) -> bool {
+    activity.is_active
+        && is_within_window(
+            &TimeWindow {
+                valid_from: activity.valid_from,
+                valid_until: activity.valid_until,
+                active_days: activity.active_days.as_deref(),
+                active_start_time: activity.active_start_time.as_deref(),
+                active_end_time: activity.active_end_time.as_deref(),
+            },
+            now,
+            tz,
+        )
+}
+
+/// Apply the per-member daily earn cap.
+///
+/// `earned_today` = stamps already granted in the current business day.
+/// Returns the stamps that may still be granted (never negative).
+pub fn cap_daily_earn(earned: i32, earned_today: i32, max_per_day: Option<i32>) -> i32 {
+    match max_per_day {
+        Some(max) => earned.min((max - earned_today).max(0)),
+        None => earned,
+    }
+}
+
 /// Check if an item matches any stamp target
 fn matches_stamp_target(info: &StampItemInfo<'_>, targets: &[StampTarget]) -> bool {
     targets.iter().any(|t| match t.target_type {
@@ -126,6 +159,8 @@ mod tests {
             category_id: None,
             category_name: None,
             is_comped,
+            course: None,
+            is_held: false,
         }
     }
 
@@ -360,4 +395,68 @@ mod tests {
         let result = find_reward_item(&items, &targets, &RewardStrategy::Economizador);
         assert_eq!(result, Some("inst-1".to_string()));
     }
+
+    fn make_activity() -> StampActivity {
+        StampActivity {
+            id: 1,
+            marketing_group_id: 1,
+            name: "Coffee Card".to_string(),
+            stamps_required: 10,
+            reward_quantity: 1,
+            reward_strategy: RewardStrategy::Economizador,
+            designated_product_id: None,
+            is_cyclic: true,
+            is_active: true,
+            valid_from: None,
+            valid_until: None,
+            active_days: None,
+            active_start_time: None,
+            active_end_time: None,
+            max_stamps_per_day: None,
+            created_at: 0,
+            updated_at: 0,
+        }
+    }
+
+    #[test]
+    fn test_activity_window() {
+        let tz = chrono_tz::Europe::Madrid;
+        // 2026-03-04 (Wednesday) 10:30 Madrid = 09:30 UTC
+        let wed_morning = 1_772_616_600_000;
+        let mut activity = make_activity();
+        assert!(is_activity_open(&activity, wed_morning, tz));
+
+        activity.valid_from = Some(wed_morning + 1);
+        assert!(!is_activity_open(&activity, wed_morning, tz));
+        activity.valid_from = None;
+        activity.valid_until = Some(wed_morning - 1);
+        assert!(!is_activity_open(&activity, wed_morning, tz));
+        activity.valid_until = None;
+
+        // Weekends only
+        activity.active_days = Some(vec![0, 6]);
+        assert!(!is_activity_open(&activity, wed_morning, tz));
+        activity.active_days = Some(vec![3]);
+        assert!(is_activity_open(&activity, wed_morning, tz));
+
+        // Happy hour 15:00-18:00
+        activity.active_start_time = Some("15:00".to_string());
+        activity.active_end_time = Some("18:00".to_string());
+        assert!(!is_activity_open(&activity, wed_morning, tz));
+        activity.active_start_time = Some("10:00".to_string());
+        assert!(is_activity_open(&activity, wed_morning, tz));
+
+        activity.is_active = false;
+        assert!(!is_activity_open(&activity, wed_morning, tz));
+    }
+
+    #[test]
+    fn test_cap_daily_earn() {
+        assert_eq!(cap_daily_earn(5, 0, None), 5);
+        assert_eq!(cap_daily_earn(5, 0, Some(3)), 3);
+        assert_eq!(cap_daily_earn(5, 2, Some(3)), 1);
+        assert_eq!(cap_daily_earn(5, 3, Some(3)), 0);
+        // Cap lowered after stamps were granted today
+        assert_eq!(cap_daily_earn(5, 4, Some(3)), 0);
+    }
 }
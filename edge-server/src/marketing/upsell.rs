@@ -0,0 +1,107 @@
+//! 关联推荐 (加购提示)
+//!
+//! 每个营业日 cutoff 后从最近 [`LOOKBACK_DAYS`] 天的归档订单重算商品搭配
+//! ("点了 X 的顾客也点了 Y")，POS 加菜时通过
+//! `GET /api/products/{id}/upsell` 读取。启动时结果缺失或超过一天则立即重算。
+
+use std::sync::Arc;
+
+use chrono::NaiveTime;
+use tokio::sync::Notify;
+use tokio_util::sync::CancellationToken;
+
+use crate::core::ServerState;
+use crate::db::repository::{RepoResult, product_pairing, store_info};
+use crate::utils::time;
+
+/// 挖掘窗口 (天)
+pub const LOOKBACK_DAYS: i64 = 90;
+
+/// 最少共现订单数 (过滤偶然搭配)
+pub const MIN_SUPPORT: i64 = 3;
+
+/// 每个商品保留的搭配数
+pub const TOP_N: i64 = 5;
+
+const DAY_MS: i64 = 24 * 60 * 60 * 1000;
+
+/// Recompute pairings from the last [`LOOKBACK_DAYS`] days
+pub async fn recompute(state: &ServerState) -> RepoResult<u64> {
+    let now = shared::util::now_millis();
+    let since = now - LOOKBACK_DAYS * DAY_MS;
+    product_pairing::recompute(&state.pool, since, now, MIN_SUPPORT, TOP_N).await
+}
+
+/// 关联推荐重算调度器
+///
+/// 注册为 `TaskKind::Periodic`，在 `start_background_tasks()` 中启动。
+pub struct UpsellScheduler {
+    state: ServerState,
+    shutdown: CancellationToken,
+    config_notify: Arc<Notify>,
+}
+
+impl UpsellScheduler {
+    pub fn new(state: ServerState, shutdown: CancellationToken) -> Self {
+        let config_notify = state.config_notify.clone();
+        Self {
+            state,
+            shutdown,
+            config_notify,
+        }
+    }
+
+    /// 主循环：启动补算 + cutoff 定点重算 + 配置变更响应
+    pub async fn run(self) {
+        tracing::info!("Upsell scheduler started");
+
+        let stale = match product_pairing::last_computed_at(&self.state.pool).await {
+            Ok(Some(at)) => shared::util::now_millis() - at > DAY_MS,
+            Ok(None) => true,
+            Err(e) => {
+                tracing::warn!("Failed to check product pairings: {}", e);
+                true
+            }
+        };
+        if stale {
+            self.recompute().await;
+        }
+
+        loop {
+            let cutoff_time = self.get_cutoff_time().await;
+            let sleep_duration =
+                time::duration_until_next_cutoff(cutoff_time, self.state.config.timezone);
+
+            tokio::select! {
+                _ = tokio::time::sleep(sleep_duration) => {
+                    self.recompute().await;
+                }
+                _ = self.config_notify.notified() => {
+                    tracing::debug!("Config changed, recalculating next upsell recompute");
+                }
+                _ = self.shutdown.cancelled() => {
+                    tracing::info!("Upsell scheduler received shutdown signal");
+                    return;
+                }
+            }
+        }
+    }
+
+    async fn recompute(&self) {
+        match recompute(&self.state).await {
+            Ok(count) => tracing::info!("Recomputed {} product pairing(s)", count),
+            Err(e) => tracing::error!("Failed to recompute product pairings: {}", e),
+        }
+    }
+
+    async fn get_cutoff_time(&self) -> NaiveTime {
+        let cutoff = store_info::get(&self.state.pool)
+            .await
+            .ok()
+            .flatten()
+            .map(|s| s.business_day_cutoff)
+            .unwrap_or(0);
+
+        time::cutoff_to_time(cutoff)
+    }
+}
@@ -6,7 +6,8 @@
 //! ┌─────────────────────────────────────────────────────────┐
 //! │                     MessageBus                           │
 //! │  ┌───────────────────────────────────────────────────┐  │
-//! │  │  broadcast::Sender<BusMessage>                    │  │
+//! │  │  broadcast::Sender<BusMessage>  (all)             │  │
+//! │  │  orders │ catalog │ system │ kds  (按主题拆分)     │  │
 //! │  └───────────────────────────────────────────────────┘  │
 //! └────────────────────────┬────────────────────────────────┘
 //!                         │
@@ -29,16 +30,36 @@
 //!                                           ▼
 //!                                    Connected Clients
 //! ```
+//!
+//! # 主题通道
+//!
+//! 服务端消息同时进入全量通道和所属主题通道 ([`BusTopic`])。
+//! 进程内订阅者 (CloudWorker、本地客户端) 使用全量通道；TCP 客户端按握手声明的
+//! 主题各自订阅，订单事件积压导致的 lag 不会影响通知和响应。
+//!
+//! # 关键通知
+//!
+//! [`MessageBus::publish_durable`] 先把通知写入 SQLite 再广播；TCP 客户端按身份
+//! 维护投递游标，重连时在实时消息之前补发离线期间错过的关键通知。
+//!
+//! # 出站队列
+//!
+//! 每个 TCP 连接有独立的有界出站队列 ([`TransportConfig::client_queue_capacity`])，
+//! 由单独的写任务写入 socket；队列满时按 [`OverflowPolicy`] 处理。
+//! [`MessageBus::client_queue_stats`] 返回各连接的积压指标。
 
-use std::sync::Arc;
+use std::sync::{Arc, OnceLock};
 
 use dashmap::DashMap;
-use shared::message::BusMessage;
+use shared::message::{BusMessage, BusTopic, NotificationPayload};
+use sqlx::SqlitePool;
 use tokio::sync::broadcast;
 use tokio_util::sync::CancellationToken;
 
 use super::ConnectedClient;
+use super::client_queue::{ClientQueue, ClientQueueStats, OverflowPolicy};
 use super::transport::{MemoryTransport, Transport};
+use crate::db::repository::durable_notification;
 use crate::utils::AppError;
 
 /// Configuration for transport layer
@@ -49,6 +70,10 @@ pub struct TransportConfig {
     pub channel_capacity: usize,
     /// TLS configuration for mTLS (optional)
     pub tls_config: Option<Arc<rustls::ServerConfig>>,
+    /// Per-client outbound queue capacity (default: 256)
+    pub client_queue_capacity: usize,
+    /// What to do when a client's outbound queue is full
+    pub overflow_policy: OverflowPolicy,
 }
 
 impl Default for TransportConfig {
@@ -57,6 +82,39 @@ impl Default for TransportConfig {
             tcp_listen_addr: "0.0.0.0:8081".to_string(),
             channel_capacity: 1024,
             tls_config: None,
+            client_queue_capacity: 256,
+            overflow_policy: OverflowPolicy::default(),
+        }
+    }
+}
+
+/// 按主题拆分的服务端广播通道
+#[derive(Debug, Clone)]
+struct TopicChannels {
+    orders: broadcast::Sender<BusMessage>,
+    catalog: broadcast::Sender<BusMessage>,
+    system: broadcast::Sender<BusMessage>,
+    kds: broadcast::Sender<BusMessage>,
+}
+
+impl TopicChannels {
+    fn new(capacity: usize) -> Self {
+        Self {
+            orders: broadcast::channel(capacity).0,
+            catalog: broadcast::channel(capacity).0,
+            system: broadcast::channel(capacity).0,
+            kds: broadcast::channel(capacity).0,
+        }
+    }
+
+    /// 主题通道 (`All` 不在此处，由全量通道承载)
+    fn get(&self, topic: BusTopic) -> Option<&broadcast::Sender<BusMessage>> {
+        match topic {
+            BusTopic::All => None,
+            BusTopic::Orders => Some(&self.orders),
+            BusTopic::Catalog => Some(&self.catalog),
+            BusTopic::System => Some(&self.system),
+            BusTopic::Kds => Some(&self.kds),
         }
     }
 }
@@ -72,14 +130,25 @@ impl Default for TransportConfig {
 pub struct MessageBus {
     /// 客户端到服务器的消息通道
     client_tx: broadcast::Sender<BusMessage>,
-    /// 服务器到客户端的广播通道
+    /// 服务器到客户端的广播通道 (全量)
     server_tx: broadcast::Sender<BusMessage>,
+    /// 服务器到客户端的主题通道
+    topics: TopicChannels,
     /// 传输层配置
     pub(crate) config: TransportConfig,
     /// 关闭信号令牌
     shutdown_token: CancellationToken,
+    /// 停止接受新连接 (shutdown_token 的子令牌，已建立的连接不受影响)
+    accept_token: CancellationToken,
     /// 已连接的客户端 (Client ID -> Transport)
     pub(crate) clients: Arc<DashMap<String, Arc<dyn Transport>>>,
+    /// TCP 客户端出站队列 (Client ID -> Queue)
+    pub(crate) client_queues: Arc<DashMap<String, Arc<ClientQueue>>>,
+    /// 关键通知存储 (启动时设置；未设置时 publish_durable 退化为普通广播)
+    durable_store: Arc<OnceLock<SqlitePool>>,
+    /// 故障注册表 (启动时设置，`bus_send` 注入点)
+    #[cfg(feature = "chaos")]
+    faults: Arc<OnceLock<crate::chaos::FaultRegistry>>,
 }
 
 impl MessageBus {
@@ -93,12 +162,19 @@ impl MessageBus {
         let capacity = config.channel_capacity;
         let (client_tx, _) = broadcast::channel(capacity);
         let (server_tx, _) = broadcast::channel(capacity);
+        let shutdown_token = CancellationToken::new();
         Self {
             client_tx,
             server_tx,
+            topics: TopicChannels::new(capacity),
             config,
-            shutdown_token: CancellationToken::new(),
+            accept_token: shutdown_token.child_token(),
+            shutdown_token,
             clients: Arc::new(DashMap::new()),
+            client_queues: Arc::new(DashMap::new()),
+            durable_store: Arc::new(OnceLock::new()),
+            #[cfg(feature = "chaos")]
+            faults: Arc::new(OnceLock::new()),
         }
     }
 
@@ -121,16 +197,80 @@ impl MessageBus {
     ///
     /// 用于广播通知到所有连接的客户端
     pub async fn publish(&self, msg: BusMessage) -> Result<(), AppError> {
-        self.server_tx
-            .send(msg)
-            .map_err(|e| AppError::internal(e.to_string()))?;
-        Ok(())
+        #[cfg(feature = "chaos")]
+        if self.inject_send_fault() {
+            return Err(AppError::internal("chaos: injected bus send failure"));
+        }
+        self.route(msg)
+    }
+
+    /// 设置故障注册表 (只生效一次)
+    #[cfg(feature = "chaos")]
+    pub fn set_faults(&self, faults: crate::chaos::FaultRegistry) {
+        if self.faults.set(faults).is_err() {
+            tracing::debug!("Chaos fault registry already set");
+        }
+    }
+
+    #[cfg(feature = "chaos")]
+    fn inject_send_fault(&self) -> bool {
+        self.faults
+            .get()
+            .is_some_and(|f| f.inject(crate::chaos::FaultPoint::BusSend))
+    }
+
+    /// 设置关键通知存储 (只生效一次)
+    pub fn set_durable_store(&self, pool: SqlitePool) {
+        if self.durable_store.set(pool).is_err() {
+            tracing::debug!("Durable notification store already set");
+        }
+    }
+
+    /// 关键通知存储 (TCP 服务器补发 / 确认投递、握手加载终端配置用)
+    pub(crate) fn durable_store(&self) -> Option<&SqlitePool> {
+        self.durable_store.get()
+    }
+
+    /// 发布关键通知 (订阅告警、打印失败、召回等)
+    ///
+    /// 先落库获得 `durable_id` 再广播；离线终端重连时补发。落库失败时仍然广播。
+    pub async fn publish_durable(&self, mut payload: NotificationPayload) -> Result<(), AppError> {
+        if let Some(pool) = self.durable_store() {
+            match durable_notification::insert(pool, &payload).await {
+                Ok(id) => payload.durable_id = Some(id),
+                Err(e) => tracing::error!(
+                    title = %payload.title,
+                    "Failed to store durable notification, broadcasting only: {}",
+                    e
+                ),
+            }
+        }
+        self.publish(BusMessage::notification(&payload)).await
+    }
+
+    /// 投递服务端消息：全量通道 + 所属主题通道
+    ///
+    /// 任一通道有订阅者即成功；都没有订阅者时返回错误 (与单通道时一致)。
+    pub fn route(&self, msg: BusMessage) -> Result<(), AppError> {
+        let topic_delivered = self
+            .topics
+            .get(msg.topic())
+            .is_some_and(|tx| tx.send(msg.clone()).is_ok());
+        match self.server_tx.send(msg) {
+            Ok(_) => Ok(()),
+            Err(_) if topic_delivered => Ok(()),
+            Err(e) => Err(AppError::internal(e.to_string())),
+        }
     }
 
     /// 发送消息到服务器 (客户端 -> 服务器)
     ///
     /// 消息通过 broadcast 通道发送到 MessageHandler 处理
     pub async fn send_to_server(&self, msg: BusMessage) -> Result<(), AppError> {
+        #[cfg(feature = "chaos")]
+        if self.inject_send_fault() {
+            return Err(AppError::internal("chaos: injected bus send failure"));
+        }
         self.client_tx
             .send(msg)
             .map_err(|e| AppError::internal(e.to_string()))?;
@@ -143,6 +283,10 @@ impl MessageBus {
     ///
     /// 客户端未连接返回 404
     pub async fn send_to_client(&self, client_id: &str, msg: BusMessage) -> Result<(), AppError> {
+        #[cfg(feature = "chaos")]
+        if self.inject_send_fault() {
+            return Err(AppError::internal("chaos: injected bus send failure"));
+        }
         if let Some(transport) = self.clients.get(client_id) {
             transport.write_message(&msg).await.map_err(|e| {
                 AppError::internal(format!("Failed to send to client {}: {}", client_id, e))
@@ -170,6 +314,14 @@ impl MessageBus {
         self.server_tx.subscribe()
     }
 
+    /// 订阅指定主题 (`All` = 全量通道)
+    pub fn subscribe_topic(&self, topic: BusTopic) -> broadcast::Receiver<BusMessage> {
+        match self.topics.get(topic) {
+            Some(tx) => tx.subscribe(),
+            None => self.server_tx.subscribe(),
+        }
+    }
+
     /// 获取内存传输层 (同进程通信)
     ///
     /// 用于测试或 Oneshot 模式
@@ -197,6 +349,17 @@ impl MessageBus {
         &self.shutdown_token
     }
 
+    /// 获取接受连接令牌 (TCP 服务器监听)
+    pub fn accept_token(&self) -> &CancellationToken {
+        &self.accept_token
+    }
+
+    /// 停止接受新连接，已建立的连接继续收发 (关闭第一阶段)
+    pub fn stop_accepting(&self) {
+        tracing::info!("Message bus stopped accepting connections");
+        self.accept_token.cancel();
+    }
+
     /// 获取当前已连接客户端数量
     pub fn clients_count(&self) -> usize {
         self.clients.len()
@@ -214,6 +377,41 @@ impl MessageBus {
             .collect()
     }
 
+    /// 已连接客户端的 mTLS 证书身份
+    pub fn peer_identity(&self, client_id: &str) -> Option<String> {
+        self.clients
+            .get(client_id)
+            .and_then(|transport| transport.peer_identity())
+    }
+
+    /// 各 TCP 连接的出站队列指标 (积压、峰值、丢弃数)
+    pub fn client_queue_stats(&self) -> Vec<ClientQueueStats> {
+        self.client_queues
+            .iter()
+            .map(|entry| entry.value().stats(entry.key()))
+            .collect()
+    }
+
+    /// 强制断开所有客户端连接 (故障注入：模拟 TLS 断连)
+    ///
+    /// 返回断开的客户端数量。客户端会走正常的重连流程。
+    #[cfg(feature = "chaos")]
+    pub async fn disconnect_all_clients(&self) -> usize {
+        let transports: Vec<(String, Arc<dyn Transport>)> = self
+            .clients
+            .iter()
+            .map(|entry| (entry.key().clone(), entry.value().clone()))
+            .collect();
+        for (client_id, transport) in &transports {
+            if let Err(e) = transport.close().await {
+                tracing::debug!("chaos: close {} failed: {}", client_id, e);
+            }
+            self.clients.remove(client_id);
+        }
+        tracing::warn!("chaos: disconnected {} clients", transports.len());
+        transports.len()
+    }
+
     /// 优雅关闭消息总线
     ///
     /// 取消所有运行中的任务，包括 TCP 服务器
@@ -0,0 +1,258 @@
+//! 每客户端出站队列 (背压)
+//!
+//! 主题 forwarder 从广播通道接收并过滤消息后放入连接自己的有界队列，
+//! 由单独的写任务顺序写入 socket。慢客户端只会填满自己的队列，
+//! 不会拖住广播通道导致其它连接 lag。
+//!
+//! 队列满时按 [`OverflowPolicy`] 处理：
+//!
+//! | 策略 | 行为 |
+//! |------|------|
+//! | `drop-oldest` | 丢弃最旧消息，写任务在下一条消息前发送 full_resync |
+//! | `disconnect` | 断开连接，客户端重连后全量同步 |
+//! | `block` | forwarder 等待空位 (广播通道积压，最终 lag → full_resync) |
+
+use std::collections::VecDeque;
+use std::str::FromStr;
+use std::sync::Mutex;
+
+use serde::Serialize;
+use shared::message::BusMessage;
+use tokio::sync::Notify;
+
+/// 队列满时的处理策略
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "kebab-case")]
+pub enum OverflowPolicy {
+    /// 丢弃最旧的消息并通知客户端重新同步
+    #[default]
+    DropOldest,
+    /// 断开慢客户端
+    Disconnect,
+    /// 等待写任务腾出空位
+    Block,
+}
+
+impl FromStr for OverflowPolicy {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.trim().to_ascii_lowercase().replace('_', "-").as_str() {
+            "drop-oldest" => Ok(Self::DropOldest),
+            "disconnect" => Ok(Self::Disconnect),
+            "block" => Ok(Self::Block),
+            other => Err(format!("Unknown overflow policy: {other}")),
+        }
+    }
+}
+
+/// 队列中的消息 (durable_id 由写任务在写入成功后确认)
+#[derive(Debug, Clone)]
+pub(crate) struct QueuedMessage {
+    pub msg: BusMessage,
+    pub durable_id: Option<i64>,
+}
+
+/// 入队结果
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum PushOutcome {
+    Queued,
+    /// 队列已满，丢弃了最旧的一条
+    DroppedOldest,
+    /// 队列已满且策略为断开
+    Overflow,
+}
+
+/// 单个连接的队列指标
+#[derive(Debug, Clone, Serialize)]
+pub struct ClientQueueStats {
+    pub client_id: String,
+    pub capacity: usize,
+    pub policy: OverflowPolicy,
+    /// 当前积压
+    pub depth: usize,
+    /// 连接以来的最大积压
+    pub high_water: usize,
+    /// 连接以来丢弃的消息数
+    pub dropped: u64,
+}
+
+#[derive(Debug, Default)]
+struct QueueState {
+    items: VecDeque<QueuedMessage>,
+    high_water: usize,
+    dropped: u64,
+    /// 上次出队以来丢弃的消息数 (写任务据此发送 resync)
+    dropped_since_pop: u64,
+}
+
+/// 有界出站队列 (多个 forwarder 写入，单个写任务读取)
+#[derive(Debug)]
+pub(crate) struct ClientQueue {
+    capacity: usize,
+    policy: OverflowPolicy,
+    state: Mutex<QueueState>,
+    not_empty: Notify,
+    not_full: Notify,
+}
+
+impl ClientQueue {
+    pub fn new(capacity: usize, policy: OverflowPolicy) -> Self {
+        Self {
+            capacity: capacity.max(1),
+            policy,
+            state: Mutex::new(QueueState::default()),
+            not_empty: Notify::new(),
+            not_full: Notify::new(),
+        }
+    }
+
+    fn lock(&self) -> std::sync::MutexGuard<'_, QueueState> {
+        self.state.lock().unwrap_or_else(|e| e.into_inner())
+    }
+
+    /// 入队 (`Block` 策略下队列满时等待)
+    pub async fn push(&self, item: QueuedMessage) -> PushOutcome {
+        loop {
+            {
+                let mut state = self.lock();
+                if state.items.len() < self.capacity {
+                    return self.enqueue(&mut state, item, PushOutcome::Queued);
+                }
+                match self.policy {
+                    OverflowPolicy::DropOldest => {
+                        state.items.pop_front();
+                        state.dropped += 1;
+                        state.dropped_since_pop += 1;
+                        return self.enqueue(&mut state, item, PushOutcome::DroppedOldest);
+                    }
+                    OverflowPolicy::Disconnect => {
+                        state.dropped += 1;
+                        return PushOutcome::Overflow;
+                    }
+                    OverflowPolicy::Block => {}
+                }
+            }
+            self.not_full.notified().await;
+        }
+    }
+
+    fn enqueue(
+        &self,
+        state: &mut QueueState,
+        item: QueuedMessage,
+        outcome: PushOutcome,
+    ) -> PushOutcome {
+        state.items.push_back(item);
+        state.high_water = state.high_water.max(state.items.len());
+        self.not_empty.notify_one();
+        outcome
+    }
+
+    /// 出队 (队列空时等待)，同时返回上次出队以来丢弃的消息数
+    pub async fn pop(&self) -> (QueuedMessage, u64) {
+        loop {
+            {
+                let mut state = self.lock();
+                if let Some(item) = state.items.pop_front() {
+                    let dropped = std::mem::take(&mut state.dropped_since_pop);
+                    self.not_full.notify_one();
+                    return (item, dropped);
+                }
+            }
+            self.not_empty.notified().await;
+        }
+    }
+
+    pub fn stats(&self, client_id: &str) -> ClientQueueStats {
+        let state = self.lock();
+        ClientQueueStats {
+            client_id: client_id.to_string(),
+            capacity: self.capacity,
+            policy: self.policy,
+            depth: state.items.len(),
+            high_water: state.high_water,
+            dropped: state.dropped,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use shared::message::NotificationPayload;
+
+    fn item(title: &str) -> QueuedMessage {
+        QueuedMessage {
+            msg: BusMessage::notification(&NotificationPayload::info(title, "")),
+            durable_id: None,
+        }
+    }
+
+    fn title(item: &QueuedMessage) -> String {
+        item.msg
+            .parse_payload::<NotificationPayload>()
+            .unwrap()
+            .title
+    }
+
+    #[test]
+    fn test_overflow_policy_from_str() {
+        assert_eq!(
+            "drop-oldest".parse::<OverflowPolicy>().unwrap(),
+            OverflowPolicy::DropOldest
+        );
+        assert_eq!(
+            "DROP_OLDEST".parse::<OverflowPolicy>().unwrap(),
+            OverflowPolicy::DropOldest
+        );
+        assert_eq!(
+            "block".parse::<OverflowPolicy>().unwrap(),
+            OverflowPolicy::Block
+        );
+        assert!("fifo".parse::<OverflowPolicy>().is_err());
+    }
+
+    #[tokio::test]
+    async fn test_drop_oldest_reports_dropped_on_next_pop() {
+        let queue = ClientQueue::new(2, OverflowPolicy::DropOldest);
+        assert_eq!(queue.push(item("a")).await, PushOutcome::Queued);
+        assert_eq!(queue.push(item("b")).await, PushOutcome::Queued);
+        assert_eq!(queue.push(item("c")).await, PushOutcome::DroppedOldest);
+
+        let stats = queue.stats("c1");
+        assert_eq!((stats.depth, stats.high_water, stats.dropped), (2, 2, 1));
+
+        let (first, dropped) = queue.pop().await;
+        assert_eq!((title(&first).as_str(), dropped), ("b", 1));
+        let (second, dropped) = queue.pop().await;
+        assert_eq!((title(&second).as_str(), dropped), ("c", 0));
+    }
+
+    #[tokio::test]
+    async fn test_disconnect_policy_rejects_when_full() {
+        let queue = ClientQueue::new(1, OverflowPolicy::Disconnect);
+        assert_eq!(queue.push(item("a")).await, PushOutcome::Queued);
+        assert_eq!(queue.push(item("b")).await, PushOutcome::Overflow);
+        assert_eq!(queue.stats("c1").depth, 1);
+    }
+
+    #[tokio::test]
+    async fn test_block_policy_waits_for_space() {
+        let queue = std::sync::Arc::new(ClientQueue::new(1, OverflowPolicy::Block));
+        queue.push(item("a")).await;
+
+        let producer = {
+            let queue = queue.clone();
+            tokio::spawn(async move { queue.push(item("b")).await })
+        };
+        tokio::task::yield_now().await;
+        assert!(!producer.is_finished());
+
+        let (first, _) = queue.pop().await;
+        assert_eq!(title(&first), "a");
+        assert_eq!(producer.await.unwrap(), PushOutcome::Queued);
+        let (second, _) = queue.pop().await;
+        assert_eq!(title(&second), "b");
+    }
+}
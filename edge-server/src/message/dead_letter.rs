@@ -0,0 +1,170 @@
+//! 死信队列 & 熔断器
+//!
+//! 处理器返回错误、panic 或请求重试的消息不再被丢弃，而是写入
+//! `dead_letter` 表 (附带错误上下文)，由管理员通过
+//! `/api/dead-letters` 检查、重新投递或丢弃。
+//!
+//! 同一 action (RequestCommand) 或事件类型连续失败达到阈值后熔断：
+//! 冷却期内直接拒绝，冷却结束后放行一条试探消息 (half-open)，
+//! 成功则恢复，失败则再次熔断。业务失败 (`ProcessResult::Failed`)
+//! 不计入熔断——那是正常的拒绝，不是毒消息。
+
+use std::any::Any;
+use std::collections::HashMap;
+use std::time::{Duration, Instant};
+
+use shared::models::DeadLetterFailure;
+use sqlx::SqlitePool;
+
+use crate::db::repository::dead_letter::{self, DeadLetterCreate};
+use crate::message::{BusMessage, EventType, RequestCommandPayload};
+
+/// 连续失败多少次后熔断
+pub const DEFAULT_FAILURE_THRESHOLD: u32 = 5;
+/// 熔断冷却时间
+pub const DEFAULT_COOLDOWN: Duration = Duration::from_secs(30);
+
+#[derive(Debug, Default)]
+struct Circuit {
+    consecutive_failures: u32,
+    open_until: Option<Instant>,
+}
+
+/// 按 action / 事件类型熔断
+#[derive(Debug)]
+pub struct CircuitBreaker {
+    threshold: u32,
+    cooldown: Duration,
+    circuits: HashMap<String, Circuit>,
+}
+
+impl Default for CircuitBreaker {
+    fn default() -> Self {
+        Self::new(DEFAULT_FAILURE_THRESHOLD, DEFAULT_COOLDOWN)
+    }
+}
+
+impl CircuitBreaker {
+    pub fn new(threshold: u32, cooldown: Duration) -> Self {
+        Self {
+            threshold: threshold.max(1),
+            cooldown,
+            circuits: HashMap::new(),
+        }
+    }
+
+    /// 是否允许处理 (冷却结束后为 half-open，放行)
+    pub fn allow(&self, key: &str, now: Instant) -> bool {
+        match self.circuits.get(key).and_then(|c| c.open_until) {
+            Some(until) => now >= until,
+            None => true,
+        }
+    }
+
+    pub fn record_success(&mut self, key: &str) {
+        self.circuits.remove(key);
+    }
+
+    /// 记录一次失败，返回是否因此熔断
+    pub fn record_failure(&mut self, key: &str, now: Instant) -> bool {
+        let circuit = self.circuits.entry(key.to_string()).or_default();
+        circuit.consecutive_failures += 1;
+        if circuit.consecutive_failures >= self.threshold {
+            circuit.open_until = Some(now + self.cooldown);
+            true
+        } else {
+            false
+        }
+    }
+}
+
+/// 熔断键：RequestCommand 用 action，其它用事件类型
+pub fn circuit_key(msg: &BusMessage) -> String {
+    request_action(msg).unwrap_or_else(|| msg.event_type.to_string())
+}
+
+fn request_action(msg: &BusMessage) -> Option<String> {
+    if msg.event_type != EventType::RequestCommand {
+        return None;
+    }
+    msg.parse_payload::<RequestCommandPayload>()
+        .ok()
+        .map(|p| p.action)
+}
+
+/// panic payload → 可读消息
+pub fn panic_message(panic: &(dyn Any + Send)) -> String {
+    if let Some(s) = panic.downcast_ref::<&str>() {
+        (*s).to_string()
+    } else if let Some(s) = panic.downcast_ref::<String>() {
+        s.clone()
+    } else {
+        "unknown panic".to_string()
+    }
+}
+
+/// 写入死信 (失败只记录日志，不影响消息循环)
+pub async fn record(pool: &SqlitePool, msg: &BusMessage, failure: DeadLetterFailure, error: &str) {
+    let data = DeadLetterCreate {
+        event_type: msg.event_type.to_string(),
+        action: request_action(msg),
+        request_id: msg.request_id.to_string(),
+        source: msg.source.clone(),
+        payload: msg.parse_payload::<serde_json::Value>().ok(),
+        failure,
+        error: error.to_string(),
+    };
+    match dead_letter::create(pool, data).await {
+        Ok(letter) => tracing::warn!(
+            dead_letter_id = letter.id,
+            event_type = %letter.event_type,
+            action = ?letter.action,
+            failure = ?failure,
+            "Message dead-lettered"
+        ),
+        Err(e) => tracing::error!(error = %e, "Failed to store dead letter"),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_opens_after_threshold_and_half_opens_after_cooldown() {
+        let mut breaker = CircuitBreaker::new(3, Duration::from_secs(30));
+        let t0 = Instant::now();
+
+        assert!(!breaker.record_failure("order.add_items", t0));
+        assert!(!breaker.record_failure("order.add_items", t0));
+        assert!(breaker.record_failure("order.add_items", t0));
+        assert!(!breaker.allow("order.add_items", t0 + Duration::from_secs(10)));
+        // 其它 action 不受影响
+        assert!(breaker.allow("order.complete", t0));
+
+        // half-open: 放行一条，失败立即再次熔断
+        let t1 = t0 + Duration::from_secs(30);
+        assert!(breaker.allow("order.add_items", t1));
+        assert!(breaker.record_failure("order.add_items", t1));
+        assert!(!breaker.allow("order.add_items", t1 + Duration::from_secs(1)));
+
+        // 成功恢复
+        let t2 = t1 + Duration::from_secs(30);
+        assert!(breaker.allow("order.add_items", t2));
+        breaker.record_success("order.add_items");
+        assert!(!breaker.record_failure("order.add_items", t2));
+        assert!(breaker.allow("order.add_items", t2));
+    }
+
+    #[test]
+    fn test_circuit_key_uses_action_for_request_commands() {
+        let msg = BusMessage::request_command(&RequestCommandPayload {
+            action: "order.add_items".to_string(),
+            params: None,
+        });
+        assert_eq!(circuit_key(&msg), "order.add_items");
+
+        let other = BusMessage::new(EventType::Notification, b"{}".to_vec());
+        assert_eq!(circuit_key(&other), EventType::Notification.to_string());
+    }
+}
@@ -1,15 +1,24 @@
 //! 服务端消息处理器
 //!
 //! MessageHandler 订阅消息总线并处理业务逻辑相关的消息。
-//! 针对 1-3 客户端场景简化设计，移除重试和死信队列。
+//! 不自动重试：失败消息进入死信队列，由管理员检查后重新投递
+//! (见 [`crate::message::dead_letter`])。
 
 use std::collections::HashMap;
+use std::panic::AssertUnwindSafe;
 use std::sync::Arc;
+use std::time::Instant;
+
+use futures::FutureExt;
+use shared::models::DeadLetterFailure;
+use sqlx::SqlitePool;
 use tokio::sync::broadcast;
 use tokio_util::sync::CancellationToken;
+use tracing::Instrument;
 
+use crate::message::dead_letter::{self, CircuitBreaker};
 use crate::message::processor::{MessageProcessor, ProcessResult};
-use crate::message::{BusMessage, EventType};
+use crate::message::{BusMessage, EventType, MessageBus};
 use crate::utils::AppError;
 
 use crate::core::ServerState;
@@ -17,12 +26,16 @@ use crate::core::ServerState;
 /// 服务端消息处理器
 ///
 /// 该处理器在后台运行，处理发布到总线的所有消息，执行服务端业务逻辑。
-/// 针对 1-3 客户端场景简化，失败时仅记录日志。
+/// 处理器 panic 被捕获，不会终止消息循环。
 pub struct MessageHandler {
     receiver: broadcast::Receiver<BusMessage>,
-    broadcast_tx: Option<broadcast::Sender<BusMessage>>,
+    /// 响应回发 (经总线路由到全量及 System 主题通道)
+    bus: Option<Arc<MessageBus>>,
     shutdown_token: CancellationToken,
     processors: HashMap<EventType, Arc<dyn MessageProcessor>>,
+    /// 死信存储 (None = 仅记录日志)
+    dead_letter_pool: Option<SqlitePool>,
+    breaker: CircuitBreaker,
 }
 
 impl MessageHandler {
@@ -33,15 +46,23 @@ impl MessageHandler {
     ) -> Self {
         Self {
             receiver,
-            broadcast_tx: None,
+            bus: None,
             shutdown_token,
             processors: HashMap::new(),
+            dead_letter_pool: None,
+            breaker: CircuitBreaker::default(),
         }
     }
 
-    /// 设置广播发送端 (用于处理后发送消息)
-    pub fn with_broadcast_tx(mut self, tx: broadcast::Sender<BusMessage>) -> Self {
-        self.broadcast_tx = Some(tx);
+    /// 设置消息总线 (用于处理后回复请求方)
+    pub fn with_bus(mut self, bus: Arc<MessageBus>) -> Self {
+        self.bus = Some(bus);
+        self
+    }
+
+    /// 设置死信存储 (失败消息写入 `dead_letter` 表)
+    pub fn with_dead_letter_store(mut self, pool: SqlitePool) -> Self {
+        self.dead_letter_pool = Some(pool);
         self
     }
 
@@ -103,13 +124,13 @@ impl MessageHandler {
         tracing::info!("Message handler stopped");
     }
 
-    /// 消息处理 (简化版，无重试)
+    /// 消息处理
     async fn handle_message(&mut self, msg: &BusMessage) -> Result<(), Box<dyn std::error::Error>> {
         let event_type = msg.event_type;
 
         // 检查是否有注册该事件类型的处理器
-        if let Some(processor) = self.processors.get(&event_type) {
-            self.process_message(msg, processor.clone()).await?;
+        if let Some(processor) = self.processors.get(&event_type).cloned() {
+            self.process_message(msg, processor).await?;
         } else {
             self.handle_unregistered(msg).await?;
         }
@@ -117,77 +138,119 @@ impl MessageHandler {
         Ok(())
     }
 
-    /// 简化的消息处理 (无重试，失败仅记录日志)
+    /// 消息处理 (无自动重试)
+    ///
+    /// 错误 / panic / Retry 写入死信队列并计入熔断；业务失败只回复错误。
     async fn process_message(
-        &self,
+        &mut self,
         msg: &BusMessage,
         processor: Arc<dyn MessageProcessor>,
     ) -> Result<(), AppError> {
-        match processor.process(msg).await {
-            Ok(result) => match result {
-                ProcessResult::Success {
-                    message: success_msg,
-                    payload,
-                } => {
-                    tracing::debug!(
-                        event_type = ?msg.event_type,
-                        result = %success_msg,
-                        "Message processed"
-                    );
-
-                    // 发送响应给客户端
-                    if let (Some(source), Some(broadcast_tx)) = (&msg.source, &self.broadcast_tx) {
-                        let response_payload =
-                            shared::message::ResponsePayload::success(success_msg, payload);
-
-                        let mut ack_msg = BusMessage::response(&response_payload);
-                        ack_msg.correlation_id = Some(msg.request_id);
-                        ack_msg.target = Some(source.clone());
-
-                        if let Err(e) = broadcast_tx.send(ack_msg) {
-                            tracing::warn!("Failed to send response: {}", e);
-                        }
-                    }
-                    Ok(())
-                }
-                ProcessResult::Skipped { reason } => {
-                    tracing::debug!(event_type = ?msg.event_type, reason = %reason, "Skipped");
-                    Ok(())
-                }
-                ProcessResult::Failed { reason } => {
-                    tracing::error!(
-                        event_type = ?msg.event_type,
-                        reason = %reason,
-                        "Processing failed"
-                    );
-
-                    // 发送错误响应
-                    if let (Some(source), Some(broadcast_tx)) = (&msg.source, &self.broadcast_tx) {
-                        let response_payload =
-                            shared::message::ResponsePayload::error(reason.clone(), None);
-
-                        let mut ack_msg = BusMessage::response(&response_payload);
-                        ack_msg.correlation_id = Some(msg.request_id);
-                        ack_msg.target = Some(source.clone());
-
-                        let _ = broadcast_tx.send(ack_msg);
+        let key = dead_letter::circuit_key(msg);
+        if !self.breaker.allow(&key, Instant::now()) {
+            tracing::warn!(circuit = %key, "Circuit open, rejecting message");
+            let reason = format!("{key} is temporarily disabled after repeated failures");
+            self.send_error(msg, &reason, None);
+            return Err(AppError::internal(reason));
+        }
+
+        // 以客户端 span 为父 (帧携带 traceparent 时)，响应回传本端 span
+        let span = tracing::info_span!(
+            "bus.request",
+            event_type = %msg.event_type,
+            action = %key,
+            client = msg.source.as_deref().unwrap_or("-"),
+        );
+        if let Some(parent) = &msg.trace_context {
+            shared::telemetry::set_parent(&span, parent);
+        }
+        let trace_context = span.in_scope(shared::telemetry::current_traceparent);
+
+        let outcome = AssertUnwindSafe(processor.process(msg))
+            .catch_unwind()
+            .instrument(span)
+            .await;
+        let (failure, error) = match outcome {
+            Ok(Ok(ProcessResult::Success {
+                message: success_msg,
+                payload,
+            })) => {
+                self.breaker.record_success(&key);
+                tracing::debug!(
+                    event_type = ?msg.event_type,
+                    result = %success_msg,
+                    "Message processed"
+                );
+
+                // 发送响应给客户端
+                if let (Some(source), Some(bus)) = (&msg.source, &self.bus) {
+                    let response_payload =
+                        shared::message::ResponsePayload::success(success_msg, payload);
+
+                    let mut ack_msg =
+                        BusMessage::response(&response_payload).with_trace_context(trace_context);
+                    ack_msg.correlation_id = Some(msg.request_id);
+                    ack_msg.target = Some(source.clone());
+
+                    if let Err(e) = bus.route(ack_msg) {
+                        tracing::warn!("Failed to send response: {}", e);
                     }
-                    Err(AppError::internal(format!("Processing failed: {}", reason)))
-                }
-                ProcessResult::Retry { reason, .. } => {
-                    // 简化版不重试，直接记录为失败
-                    tracing::warn!(
-                        event_type = ?msg.event_type,
-                        reason = %reason,
-                        "Retry requested but disabled, logging as warning"
-                    );
-                    Ok(())
                 }
-            },
-            Err(e) => {
-                tracing::error!(event_type = ?msg.event_type, error = %e, "Processing error");
-                Err(e)
+                return Ok(());
             }
+            Ok(Ok(ProcessResult::Skipped { reason })) => {
+                self.breaker.record_success(&key);
+                tracing::debug!(event_type = ?msg.event_type, reason = %reason, "Skipped");
+                return Ok(());
+            }
+            Ok(Ok(ProcessResult::Failed { reason })) => {
+                // 业务拒绝 (校验失败等)，处理器本身是健康的
+                self.breaker.record_success(&key);
+                tracing::error!(
+                    event_type = ?msg.event_type,
+                    reason = %reason,
+                    "Processing failed"
+                );
+                self.send_error(msg, &reason, trace_context);
+                return Err(AppError::internal(format!("Processing failed: {}", reason)));
+            }
+            Ok(Ok(ProcessResult::Retry { reason, .. })) => (DeadLetterFailure::Retry, reason),
+            Ok(Err(e)) => (DeadLetterFailure::Error, e.to_string()),
+            Err(panic) => (
+                DeadLetterFailure::Panic,
+                format!("panic: {}", dead_letter::panic_message(panic.as_ref())),
+            ),
+        };
+
+        tracing::error!(
+            event_type = ?msg.event_type,
+            circuit = %key,
+            failure = ?failure,
+            error = %error,
+            "Processing error"
+        );
+        if let Some(pool) = &self.dead_letter_pool {
+            dead_letter::record(pool, msg, failure, &error).await;
+        }
+        if self.breaker.record_failure(&key, Instant::now()) {
+            tracing::error!(circuit = %key, "Circuit opened after repeated failures");
+        }
+        self.send_error(msg, &error, trace_context);
+        Err(AppError::internal(error))
+    }
+
+    /// 向请求方回复错误
+    fn send_error(&self, msg: &BusMessage, reason: &str, trace_context: Option<String>) {
+        if let (Some(source), Some(bus)) = (&msg.source, &self.bus) {
+            let response_payload =
+                shared::message::ResponsePayload::error(reason.to_string(), None);
+
+            let mut ack_msg =
+                BusMessage::response(&response_payload).with_trace_context(trace_context);
+            ack_msg.correlation_id = Some(msg.request_id);
+            ack_msg.target = Some(source.clone());
+
+            let _ = bus.route(ack_msg);
         }
     }
 
@@ -0,0 +1,529 @@
+//! RequestCommand 中间件链
+//!
+//! 横切关注点 (权限、限流、追踪) 只在链上注册一次，
+//! 业务处理器 ([`CommandHandler`]) 只关心业务逻辑：
+//!
+//! ```text
+//! RequestCommand ─► Auth ─► RateLimit ─► LoadShed ─► Tracing ─► handler (dispatch by action)
+//! ```
+//!
+//! 中间件可以调用 `next.run(ctx)` 继续，也可以直接返回结果短路整条链。
+
+use std::collections::HashMap;
+use std::sync::{Arc, Mutex};
+use std::time::{Duration, Instant};
+
+use async_trait::async_trait;
+use shared::error::AppError;
+use shared::order::OrderCommand;
+
+use crate::core::ServerState;
+use crate::core::load_shed::{self, LoadShedder, Priority};
+use crate::db::repository::{employee, role};
+use crate::message::BusMessage;
+use crate::message::processor::ProcessResult;
+
+/// 单条 RequestCommand 的上下文
+pub struct CommandContext<'a> {
+    pub msg: &'a BusMessage,
+    /// 操作标识 (e.g. "order.add_items")
+    pub action: String,
+    pub params: Option<serde_json::Value>,
+}
+
+impl CommandContext<'_> {
+    /// 发送方客户端 (进程内调用为 None)
+    pub fn source(&self) -> Option<&str> {
+        self.msg.source.as_deref()
+    }
+}
+
+/// 链末端的业务处理器
+#[async_trait]
+pub trait CommandHandler: Send + Sync {
+    async fn call(&self, ctx: &CommandContext<'_>) -> Result<ProcessResult, AppError>;
+}
+
+/// 中间件
+#[async_trait]
+pub trait CommandMiddleware: Send + Sync {
+    /// 名称 (日志用)
+    fn name(&self) -> &'static str;
+
+    async fn handle(
+        &self,
+        ctx: &CommandContext<'_>,
+        next: Next<'_>,
+    ) -> Result<ProcessResult, AppError>;
+}
+
+/// 链中剩余的部分
+pub struct Next<'a> {
+    middlewares: &'a [Arc<dyn CommandMiddleware>],
+    handler: &'a dyn CommandHandler,
+}
+
+impl Next<'_> {
+    pub async fn run(self, ctx: &CommandContext<'_>) -> Result<ProcessResult, AppError> {
+        match self.middlewares.split_first() {
+            Some((first, rest)) => {
+                tracing::trace!(middleware = first.name(), action = %ctx.action, "Command middleware");
+                first
+                    .handle(
+                        ctx,
+                        Next {
+                            middlewares: rest,
+                            handler: self.handler,
+                        },
+                    )
+                    .await
+            }
+            None => self.handler.call(ctx).await,
+        }
+    }
+}
+
+/// 中间件链 (按注册顺序执行)
+#[derive(Default, Clone)]
+pub struct CommandChain {
+    middlewares: Vec<Arc<dyn CommandMiddleware>>,
+}
+
+impl CommandChain {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// 追加中间件 (先注册的先执行)
+    pub fn layer(mut self, middleware: impl CommandMiddleware + 'static) -> Self {
+        self.middlewares.push(Arc::new(middleware));
+        self
+    }
+
+    pub async fn run(
+        &self,
+        ctx: &CommandContext<'_>,
+        handler: &dyn CommandHandler,
+    ) -> Result<ProcessResult, AppError> {
+        Next {
+            middlewares: &self.middlewares,
+            handler,
+        }
+        .run(ctx)
+        .await
+    }
+}
+
+// ========== Auth ==========
+
+/// 权限检查：敏感订单命令需要验证操作者权限
+pub struct AuthMiddleware {
+    state: Arc<ServerState>,
+}
+
+impl AuthMiddleware {
+    pub fn new(state: Arc<ServerState>) -> Self {
+        Self { state }
+    }
+
+    /// 检查操作者是否拥有指定权限
+    async fn check_operator_permission(&self, operator_id: i64, permission: &str) -> bool {
+        // 查询员工信息
+        let employee = match employee::find_by_id(&self.state.pool, operator_id).await {
+            Ok(Some(emp)) => emp,
+            Ok(None) => {
+                tracing::warn!(operator_id = %operator_id, "Operator not found");
+                return false;
+            }
+            Err(e) => {
+                tracing::error!(error = %e, "Failed to query operator");
+                return false;
+            }
+        };
+
+        // 查询角色权限
+        let role = match role::find_by_id(&self.state.pool, employee.role_id).await {
+            Ok(Some(r)) => r,
+            Ok(None) => {
+                tracing::warn!(role_id = %employee.role_id, "Role not found");
+                return false;
+            }
+            Err(e) => {
+                tracing::error!(error = %e, "Failed to query role");
+                return false;
+            }
+        };
+
+        // 检查权限
+        // 1. admin 角色或拥有 "all" 权限的用户拥有所有权限
+        if role.name == "admin" || role.permissions.iter().any(|p| p == "all") {
+            return true;
+        }
+
+        // 2. 精确匹配
+        if role.permissions.iter().any(|p| p == permission) {
+            return true;
+        }
+
+        // 3. 通配符匹配 (e.g., "orders:*" matches "orders:void")
+        if let Some(prefix) = permission.split(':').next() {
+            let wildcard = format!("{}:*", prefix);
+            if role.permissions.iter().any(|p| p == &wildcard) {
+                return true;
+            }
+        }
+
+        false
+    }
+}
+
+#[async_trait]
+impl CommandMiddleware for AuthMiddleware {
+    fn name(&self) -> &'static str {
+        "auth"
+    }
+
+    async fn handle(
+        &self,
+        ctx: &CommandContext<'_>,
+        next: Next<'_>,
+    ) -> Result<ProcessResult, AppError> {
+        if !ctx.action.starts_with("order.") {
+            return next.run(ctx).await;
+        }
+        // 参数无效时交给处理器报告
+        let Some(command) = ctx
+            .params
+            .as_ref()
+            .and_then(|p| serde_json::from_value::<OrderCommand>(p.clone()).ok())
+        else {
+            return next.run(ctx).await;
+        };
+
+        if let Some(required_permission) = command.payload.required_permission() {
+            let has_permission = self
+                .check_operator_permission(command.operator_id, required_permission)
+                .await;
+            if !has_permission {
+                tracing::warn!(
+                    operator_id = %command.operator_id,
+                    operator_name = %command.operator_name,
+                    required_permission = required_permission,
+                    command = ?std::mem::discriminant(&command.payload),
+                    "Permission denied: operator lacks required permission"
+                );
+                return Ok(ProcessResult::Failed {
+                    reason: format!(
+                        "Permission denied: requires {} permission",
+                        required_permission
+                    ),
+                });
+            }
+        }
+        next.run(ctx).await
+    }
+}
+
+// ========== Rate limit ==========
+
+/// 每个客户端每个窗口允许的命令数
+pub const DEFAULT_RATE_LIMIT: u32 = 60;
+pub const DEFAULT_RATE_WINDOW: Duration = Duration::from_secs(1);
+
+/// 固定窗口限流 (按客户端)
+///
+/// 防止失控的终端 (重试风暴、UI 循环) 拖垮订单引擎。
+/// 进程内调用 (无 source) 不限流。
+pub struct RateLimitMiddleware {
+    limit: u32,
+    window: Duration,
+    windows: Mutex<HashMap<String, (Instant, u32)>>,
+}
+
+impl Default for RateLimitMiddleware {
+    fn default() -> Self {
+        Self::new(DEFAULT_RATE_LIMIT, DEFAULT_RATE_WINDOW)
+    }
+}
+
+impl RateLimitMiddleware {
+    pub fn new(limit: u32, window: Duration) -> Self {
+        Self {
+            limit: limit.max(1),
+            window,
+            windows: Mutex::new(HashMap::new()),
+        }
+    }
+
+    /// 记录一次请求，返回是否允许
+    fn try_acquire(&self, client: &str, now: Instant) -> bool {
+        let mut windows = self.windows.lock().unwrap_or_else(|e| e.into_inner());
+        let (started, count) = windows.entry(client.to_string()).or_insert((now, 0));
+        if now.duration_since(*started) >= self.window {
+            *started = now;
+            *count = 0;
+        }
+        if *count >= self.limit {
+            return false;
+        }
+        *count += 1;
+        true
+    }
+}
+
+#[async_trait]
+impl CommandMiddleware for RateLimitMiddleware {
+    fn name(&self) -> &'static str {
+        "rate_limit"
+    }
+
+    async fn handle(
+        &self,
+        ctx: &CommandContext<'_>,
+        next: Next<'_>,
+    ) -> Result<ProcessResult, AppError> {
+        if let Some(source) = ctx.source()
+            && !self.try_acquire(source, Instant::now())
+        {
+            tracing::warn!(source = %source, action = %ctx.action, "Rate limit exceeded");
+            return Ok(ProcessResult::Failed {
+                reason: "Rate limit exceeded, please retry shortly".to_string(),
+            });
+        }
+        next.run(ctx).await
+    }
+}
+
+// ========== Load shedding ==========
+
+/// 负载保护：记录订单命令耗时，压力过大时拒绝 `sync.*` 请求
+///
+/// 进程内调用 (无 source) 不受限。
+pub struct LoadShedMiddleware {
+    shedder: Arc<LoadShedder>,
+}
+
+impl LoadShedMiddleware {
+    pub fn new(shedder: Arc<LoadShedder>) -> Self {
+        Self { shedder }
+    }
+}
+
+#[async_trait]
+impl CommandMiddleware for LoadShedMiddleware {
+    fn name(&self) -> &'static str {
+        "load_shed"
+    }
+
+    async fn handle(
+        &self,
+        ctx: &CommandContext<'_>,
+        next: Next<'_>,
+    ) -> Result<ProcessResult, AppError> {
+        let priority = load_shed::classify_action(&ctx.action);
+        if priority == Priority::Command {
+            let started = Instant::now();
+            let result = next.run(ctx).await;
+            if ctx.action.starts_with("order.") {
+                self.shedder.record_command_latency(started.elapsed());
+            }
+            return result;
+        }
+        if ctx.source().is_none() {
+            return next.run(ctx).await;
+        }
+
+        match self.shedder.admit(priority).await {
+            Ok(_permit) => next.run(ctx).await,
+            Err(shed) => Ok(ProcessResult::Failed {
+                reason: shed.to_string(),
+            }),
+        }
+    }
+}
+
+// ========== Tracing ==========
+
+/// 超过此耗时记录慢命令警告
+const SLOW_COMMAND: Duration = Duration::from_millis(500);
+
+/// 记录每条命令的耗时与结果
+pub struct TracingMiddleware;
+
+#[async_trait]
+impl CommandMiddleware for TracingMiddleware {
+    fn name(&self) -> &'static str {
+        "tracing"
+    }
+
+    async fn handle(
+        &self,
+        ctx: &CommandContext<'_>,
+        next: Next<'_>,
+    ) -> Result<ProcessResult, AppError> {
+        let started = Instant::now();
+        let result = next.run(ctx).await;
+        let elapsed = started.elapsed();
+
+        let outcome = match &result {
+            Ok(ProcessResult::Success { .. }) => "success",
+            Ok(ProcessResult::Failed { .. }) => "failed",
+            Ok(ProcessResult::Skipped { .. }) => "skipped",
+            Ok(ProcessResult::Retry { .. }) => "retry",
+            Err(_) => "error",
+        };
+        if elapsed >= SLOW_COMMAND {
+            tracing::warn!(
+                action = %ctx.action,
+                source = ?ctx.source(),
+                request_id = %ctx.msg.request_id,
+                elapsed_ms = elapsed.as_millis() as u64,
+                outcome,
+                "Slow request command"
+            );
+        } else if ctx.action != "ping" {
+            tracing::debug!(
+                action = %ctx.action,
+                source = ?ctx.source(),
+                request_id = %ctx.msg.request_id,
+                elapsed_ms = elapsed.as_millis() as u64,
+                outcome,
+                "Request command handled"
+            );
+        }
+        result
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::message::RequestCommandPayload;
+
+    struct Recorder {
+        name: &'static str,
+        log: Arc<Mutex<Vec<&'static str>>>,
+        short_circuit: bool,
+    }
+
+    #[async_trait]
+    impl CommandMiddleware for Recorder {
+        fn name(&self) -> &'static str {
+            self.name
+        }
+
+        async fn handle(
+            &self,
+            ctx: &CommandContext<'_>,
+            next: Next<'_>,
+        ) -> Result<ProcessResult, AppError> {
+            self.log.lock().unwrap().push(self.name);
+            if self.short_circuit {
+                return Ok(ProcessResult::Failed {
+                    reason: self.name.to_string(),
+                });
+            }
+            next.run(ctx).await
+        }
+    }
+
+    struct Echo(Arc<Mutex<Vec<&'static str>>>);
+
+    #[async_trait]
+    impl CommandHandler for Echo {
+        async fn call(&self, ctx: &CommandContext<'_>) -> Result<ProcessResult, AppError> {
+            self.0.lock().unwrap().push("handler");
+            Ok(ProcessResult::Success {
+                message: ctx.action.clone(),
+                payload: None,
+            })
+        }
+    }
+
+    fn recorder(
+        name: &'static str,
+        log: &Arc<Mutex<Vec<&'static str>>>,
+        short_circuit: bool,
+    ) -> Recorder {
+        Recorder {
+            name,
+            log: log.clone(),
+            short_circuit,
+        }
+    }
+
+    #[tokio::test]
+    async fn test_chain_runs_in_order_and_short_circuits() {
+        let msg = BusMessage::request_command(&RequestCommandPayload {
+            action: "echo".to_string(),
+            params: None,
+        });
+        let ctx = CommandContext {
+            msg: &msg,
+            action: "echo".to_string(),
+            params: None,
+        };
+
+        let log = Arc::new(Mutex::new(Vec::new()));
+        let chain = CommandChain::new()
+            .layer(recorder("auth", &log, false))
+            .layer(recorder("rate_limit", &log, false));
+        let result = chain.run(&ctx, &Echo(log.clone())).await.unwrap();
+        assert!(matches!(result, ProcessResult::Success { message, .. } if message == "echo"));
+        assert_eq!(*log.lock().unwrap(), vec!["auth", "rate_limit", "handler"]);
+
+        let log = Arc::new(Mutex::new(Vec::new()));
+        let chain = CommandChain::new()
+            .layer(recorder("auth", &log, true))
+            .layer(recorder("rate_limit", &log, false));
+        let result = chain.run(&ctx, &Echo(log.clone())).await.unwrap();
+        assert!(matches!(result, ProcessResult::Failed { reason } if reason == "auth"));
+        assert_eq!(*log.lock().unwrap(), vec!["auth"]);
+    }
+
+    #[test]
+    fn test_rate_limit_is_per_client_and_resets_per_window() {
+        let limiter = RateLimitMiddleware::new(2, Duration::from_secs(1));
+        let t0 = Instant::now();
+        assert!(limiter.try_acquire("pos-1", t0));
+        assert!(limiter.try_acquire("pos-1", t0));
+        assert!(!limiter.try_acquire("pos-1", t0 + Duration::from_millis(500)));
+        assert!(limiter.try_acquire("pos-2", t0));
+        assert!(limiter.try_acquire("pos-1", t0 + Duration::from_secs(1)));
+    }
+
+    #[tokio::test]
+    async fn test_load_shed_rejects_sync_under_stress() {
+        let shedder = Arc::new(LoadShedder::new(Duration::from_millis(100)));
+        let chain = CommandChain::new().layer(LoadShedMiddleware::new(shedder.clone()));
+        let log = Arc::new(Mutex::new(Vec::new()));
+
+        let mut msg = BusMessage::request_command(&RequestCommandPayload {
+            action: "sync.orders".to_string(),
+            params: None,
+        });
+        msg.source = Some("pos-1".to_string());
+        let ctx = CommandContext {
+            msg: &msg,
+            action: "sync.orders".to_string(),
+            params: None,
+        };
+
+        let result = chain.run(&ctx, &Echo(log.clone())).await.unwrap();
+        assert!(matches!(result, ProcessResult::Success { .. }));
+
+        shedder.record_command_latency(Duration::from_secs(1));
+        let result = chain.run(&ctx, &Echo(log.clone())).await.unwrap();
+        assert!(matches!(result, ProcessResult::Failed { .. }));
+
+        // 订单命令始终放行
+        let ctx = CommandContext {
+            msg: &msg,
+            action: "order.add_items".to_string(),
+            params: None,
+        };
+        let result = chain.run(&ctx, &Echo(log.clone())).await.unwrap();
+        assert!(matches!(result, ProcessResult::Success { .. }));
+        assert_eq!(*log.lock().unwrap(), vec!["handler", "handler"]);
+    }
+}
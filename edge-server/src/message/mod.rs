@@ -24,11 +24,17 @@
 //! - `transport/` - 传输层实现 (TCP, TLS, Memory)
 //! - `bus` - 消息总线核心
 //! - `tcp_server` - TCP 服务器实现
+//! - `client_queue` - 每客户端出站队列 (背压策略)
 //! - `handler` - 消息处理器
+//! - `dead_letter` - 死信队列 & 熔断器
 //! - `processor` - 消息处理逻辑
+//! - `middleware` - RequestCommand 中间件链 (权限 → 限流 → 负载保护 → 追踪)
 
 mod bus;
+mod client_queue;
+pub mod dead_letter;
 pub mod handler;
+pub mod middleware;
 pub mod processor;
 mod tcp_server;
 pub mod transport;
@@ -40,6 +46,7 @@ pub use transport::{MemoryTransport, TcpTransport, TlsTransport, Transport};
 
 // Message bus
 pub use bus::{MessageBus, TransportConfig};
+pub use client_queue::{ClientQueueStats, OverflowPolicy};
 
 // Handler & Processor
 pub use handler::MessageHandler;
@@ -47,8 +54,8 @@ pub use processor::{MessageProcessor, ProcessResult};
 
 // Shared message types
 pub use shared::message::{
-    BusMessage, EventType, NotificationPayload, RequestCommandPayload, ServerCommandPayload,
-    SyncPayload,
+    BusMessage, BusTopic, EventType, NotificationPayload, RequestCommandPayload,
+    ServerCommandPayload, SyncPayload,
 };
 
 // ========== Types ==========
@@ -111,4 +118,44 @@ mod tests {
         assert_eq!(r1.event_type, EventType::Notification);
         assert_eq!(r2.event_type, EventType::Notification);
     }
+
+    #[tokio::test]
+    async fn test_topic_channels() {
+        use shared::cloud::SyncResource;
+        use shared::message::SyncChangeType;
+
+        let bus = MessageBus::new();
+        let mut all = bus.subscribe();
+        let mut orders = bus.subscribe_topic(BusTopic::Orders);
+        let mut system = bus.subscribe_topic(BusTopic::System);
+
+        let order_sync = BusMessage::sync(&SyncPayload {
+            resource: SyncResource::OrderSync,
+            version: 1,
+            action: SyncChangeType::Updated,
+            id: 1,
+            data: None,
+            cloud_origin: false,
+        });
+        bus.publish(order_sync).await.unwrap();
+        bus.publish(BusMessage::notification(&NotificationPayload::info(
+            "T", "M",
+        )))
+        .await
+        .unwrap();
+
+        // 全量通道收到全部；主题通道只收到本主题
+        assert_eq!(all.recv().await.unwrap().event_type, EventType::Sync);
+        assert_eq!(
+            all.recv().await.unwrap().event_type,
+            EventType::Notification
+        );
+        assert_eq!(orders.recv().await.unwrap().event_type, EventType::Sync);
+        assert!(orders.try_recv().is_err());
+        assert_eq!(
+            system.recv().await.unwrap().event_type,
+            EventType::Notification
+        );
+        assert!(system.try_recv().is_err());
+    }
 }
@@ -1,32 +1,17 @@
 use crate::core::ServerState;
-use crate::db::repository::{employee, role, system_issue};
+use crate::db::repository::system_issue;
+use crate::message::middleware::{
+    AuthMiddleware, CommandChain, CommandContext, CommandHandler, LoadShedMiddleware,
+    RateLimitMiddleware, TracingMiddleware,
+};
 use crate::message::{BusMessage, EventType};
-use crate::orders::actions::open_table::load_matching_rules;
+use crate::orders::actions::open_table::load_order_rules;
 use async_trait::async_trait;
 use shared::error::AppError;
 use shared::message::SyncChangeType;
 use shared::order::{OrderCommand, OrderCommandPayload};
 use std::sync::Arc;
 
-/// 获取执行订单命令所需的权限
-fn get_required_permission(payload: &OrderCommandPayload) -> Option<&'static str> {
-    match payload {
-        // 敏感操作需要权限
-        OrderCommandPayload::VoidOrder { .. } => Some("orders:void"),
-        OrderCommandPayload::CompItem { .. } | OrderCommandPayload::UncompItem { .. } => {
-            Some("orders:comp")
-        }
-        OrderCommandPayload::ApplyOrderDiscount { .. }
-        | OrderCommandPayload::ApplyOrderSurcharge { .. } => Some("orders:discount"),
-        OrderCommandPayload::CancelPayment { .. } => Some("orders:refund"),
-        OrderCommandPayload::RemoveItem { .. } => Some("orders:cancel_item"),
-        OrderCommandPayload::MoveOrder { .. } => Some("tables:transfer"),
-        OrderCommandPayload::MergeOrders { .. } => Some("tables:merge_bill"),
-        // 基础操作无需特殊权限
-        _ => None,
-    }
-}
-
 /// 消息处理结果
 #[derive(Debug)]
 pub enum ProcessResult {
@@ -71,7 +56,7 @@ impl MessageProcessor for NotificationProcessor {
 
     async fn process(&self, msg: &BusMessage) -> Result<ProcessResult, AppError> {
         let payload: shared::message::NotificationPayload = msg
-            .parse_payload()
+            .parse_versioned()
             .map_err(|e| AppError::invalid(format!("Invalid notification payload: {}", e)))?;
 
         Ok(ProcessResult::Success {
@@ -102,7 +87,7 @@ impl MessageProcessor for ServerCommandProcessor {
 
     async fn process(&self, msg: &BusMessage) -> Result<ProcessResult, AppError> {
         let payload: shared::message::ServerCommandPayload = msg
-            .parse_payload()
+            .parse_versioned()
             .map_err(|e| AppError::invalid(format!("Invalid server command payload: {}", e)))?;
 
         match &payload.command {
@@ -171,63 +156,21 @@ impl MessageProcessor for ServerCommandProcessor {
 }
 
 /// 客户端请求处理器 - 处理来自客户端的 RPC 请求
+///
+/// 请求先经过中间件链 (权限 → 限流 → 追踪)，再按 action 分发。
 pub struct RequestCommandProcessor {
     state: Arc<ServerState>,
+    chain: CommandChain,
 }
 
 impl RequestCommandProcessor {
     pub fn new(state: Arc<ServerState>) -> Self {
-        Self { state }
-    }
-
-    /// 检查操作者是否拥有指定权限
-    async fn check_operator_permission(&self, operator_id: i64, permission: &str) -> bool {
-        // 查询员工信息
-        let employee = match employee::find_by_id(&self.state.pool, operator_id).await {
-            Ok(Some(emp)) => emp,
-            Ok(None) => {
-                tracing::warn!(operator_id = %operator_id, "Operator not found");
-                return false;
-            }
-            Err(e) => {
-                tracing::error!(error = %e, "Failed to query operator");
-                return false;
-            }
-        };
-
-        // 查询角色权限
-        let role = match role::find_by_id(&self.state.pool, employee.role_id).await {
-            Ok(Some(r)) => r,
-            Ok(None) => {
-                tracing::warn!(role_id = %employee.role_id, "Role not found");
-                return false;
-            }
-            Err(e) => {
-                tracing::error!(error = %e, "Failed to query role");
-                return false;
-            }
-        };
-
-        // 检查权限
-        // 1. admin 角色或拥有 "all" 权限的用户拥有所有权限
-        if role.name == "admin" || role.permissions.iter().any(|p| p == "all") {
-            return true;
-        }
-
-        // 2. 精确匹配
-        if role.permissions.iter().any(|p| p == permission) {
-            return true;
-        }
-
-        // 3. 通配符匹配 (e.g., "orders:*" matches "orders:void")
-        if let Some(prefix) = permission.split(':').next() {
-            let wildcard = format!("{}:*", prefix);
-            if role.permissions.iter().any(|p| p == &wildcard) {
-                return true;
-            }
-        }
-
-        false
+        let chain = CommandChain::new()
+            .layer(AuthMiddleware::new(state.clone()))
+            .layer(RateLimitMiddleware::default())
+            .layer(LoadShedMiddleware::new(state.load_shedder.clone()))
+            .layer(TracingMiddleware);
+        Self { state, chain }
     }
 
     /// Handle order commands (order.open_table, order.add_items, etc.)
@@ -235,6 +178,7 @@ impl RequestCommandProcessor {
         &self,
         _action: &str,
         params: &Option<serde_json::Value>,
+        source: Option<&str>,
     ) -> Result<ProcessResult, AppError> {
         // Parse the full OrderCommand from params (preserves command_id, operator info)
         let Some(params_value) = params else {
@@ -247,27 +191,7 @@ impl RequestCommandProcessor {
         let command: OrderCommand = serde_json::from_value(params_value.clone())
             .map_err(|e| AppError::invalid(format!("Invalid OrderCommand: {}", e)))?;
 
-        // 权限检查：敏感命令需要验证操作者权限
-        if let Some(required_permission) = get_required_permission(&command.payload) {
-            let has_permission = self
-                .check_operator_permission(command.operator_id, required_permission)
-                .await;
-            if !has_permission {
-                tracing::warn!(
-                    operator_id = %command.operator_id,
-                    operator_name = %command.operator_name,
-                    required_permission = required_permission,
-                    command = ?std::mem::discriminant(&command.payload),
-                    "Permission denied: operator lacks required permission"
-                );
-                return Ok(ProcessResult::Failed {
-                    reason: format!(
-                        "Permission denied: requires {} permission",
-                        required_permission
-                    ),
-                });
-            }
-        }
+        // 权限检查已由 AuthMiddleware 完成
 
         // 保存需要加载规则的命令信息
         let rule_load_info = match &command.payload {
@@ -288,15 +212,37 @@ impl RequestCommandProcessor {
             None
         };
 
+        // 客显：记录操作订单的终端 (执行前记录，事件到达客显 worker 时已可查到)
+        let terminal =
+            source.and_then(|client_id| self.state.message_bus().peer_identity(client_id));
+        if let (Some(identity), Some(order_id)) = (&terminal, command.target_order_id()) {
+            self.state
+                .customer_display
+                .note_terminal(order_id, identity);
+        }
+
         // Execute via OrdersManager (CatalogService is injected, metadata lookup is automatic)
         let response = self.state.orders_manager().execute_command(command).await;
 
         if response.success {
+            // 新开订单：关联到开台终端
+            if let (Some(identity), Some(order_id)) = (&terminal, response.order_id) {
+                self.state
+                    .customer_display
+                    .note_terminal(order_id, identity);
+            }
+
             // OpenTable 成功后加载并缓存价格规则
             if let Some((zone_id, is_retail)) = rule_load_info
                 && let Some(order_id) = response.order_id
             {
-                let rules = load_matching_rules(&self.state.pool, zone_id, is_retail).await;
+                let rules = load_order_rules(
+                    &self.state.pool,
+                    &self.state.catalog_service,
+                    zone_id,
+                    is_retail,
+                )
+                .await;
                 if !rules.is_empty() {
                     tracing::debug!(
                         order_id = %order_id,
@@ -311,9 +257,13 @@ impl RequestCommandProcessor {
             if let Some((ref order_id, ref target_zone_id)) = move_order_info {
                 // 从 snapshot 获取 is_retail（移桌不改变 is_retail）
                 if let Ok(Some(snapshot)) = self.state.orders_manager().get_snapshot(*order_id) {
-                    let rules =
-                        load_matching_rules(&self.state.pool, *target_zone_id, snapshot.is_retail)
-                            .await;
+                    let rules = load_order_rules(
+                        &self.state.pool,
+                        &self.state.catalog_service,
+                        *target_zone_id,
+                        snapshot.is_retail,
+                    )
+                    .await;
                     tracing::debug!(
                         order_id = %order_id,
                         target_zone_id = ?target_zone_id,
@@ -460,11 +410,23 @@ impl MessageProcessor for RequestCommandProcessor {
 
     async fn process(&self, msg: &BusMessage) -> Result<ProcessResult, AppError> {
         let payload: shared::message::RequestCommandPayload = msg
-            .parse_payload()
+            .parse_versioned()
             .map_err(|e| AppError::invalid(format!("Invalid payload: {}", e)))?;
 
+        let ctx = CommandContext {
+            msg,
+            action: payload.action,
+            params: payload.params,
+        };
+        self.chain.run(&ctx, self).await
+    }
+}
+
+#[async_trait]
+impl CommandHandler for RequestCommandProcessor {
+    async fn call(&self, ctx: &CommandContext<'_>) -> Result<ProcessResult, AppError> {
         // 处理具体的请求动作
-        match payload.action.as_str() {
+        match ctx.action.as_str() {
             "ping" => {
                 tracing::trace!("Client ping received");
                 // 返回 epoch 以便客户端检测服务器重启
@@ -479,7 +441,7 @@ impl MessageProcessor for RequestCommandProcessor {
             }
             "echo" => Ok(ProcessResult::Success {
                 message: "Echo".to_string(),
-                payload: payload.params,
+                payload: ctx.params.clone(),
             }),
             "status" => {
                 let status = serde_json::json!({
@@ -495,16 +457,17 @@ impl MessageProcessor for RequestCommandProcessor {
             }
             // ========== Order Commands ==========
             action if action.starts_with("order.") => {
-                self.handle_order_command(action, &payload.params).await
+                self.handle_order_command(action, &ctx.params, ctx.source())
+                    .await
             }
             // ========== Sync Commands ==========
-            "sync.orders" => self.handle_sync_orders(&payload.params).await,
-            "sync.order_snapshot" => self.handle_sync_order_snapshot(&payload.params).await,
-            "sync.active_events" => self.handle_sync_active_events(&payload.params).await,
+            "sync.orders" => self.handle_sync_orders(&ctx.params).await,
+            "sync.order_snapshot" => self.handle_sync_order_snapshot(&ctx.params).await,
+            "sync.active_events" => self.handle_sync_active_events(&ctx.params).await,
             _ => {
-                tracing::warn!("Unknown request action: {}", payload.action);
+                tracing::warn!("Unknown request action: {}", ctx.action);
                 Ok(ProcessResult::Failed {
-                    reason: format!("Unknown action: {}", payload.action),
+                    reason: format!("Unknown action: {}", ctx.action),
                 })
             }
         }
@@ -3,22 +3,31 @@
 //! 负责处理 TCP/TLS 客户端连接，包括：
 //! - 监听连接
 //! - TLS 握手
-//! - 协议握手验证
-//! - 消息转发
+//! - 协议握手验证 / 载荷编码、帧压缩与链路追踪上下文协商 / 下发终端漫游配置（见 [`shared::message::encoding`]、[`shared::message::compression`]、[`shared::message::trace_context`]）
+//! - 消息转发 (重连时先补发离线期间的关键通知，见 [`MessageBus::publish_durable`])
+//! - 每客户端出站队列与背压策略（见 [`super::client_queue`]）
+//! - 运行期重绑定端口 / 替换 TLS 证书（见 [`crate::core::listeners`]）
 
 use std::net::SocketAddr;
 use std::sync::Arc;
 
 use dashmap::DashMap;
-use shared::message::{BusMessage, EventType, HandshakePayload, PROTOCOL_VERSION, ResponsePayload};
+use shared::message::{
+    BusMessage, BusTopic, EventType, HandshakeAck, HandshakePayload, NotificationPayload,
+    PROTOCOL_VERSION, PayloadCompression, PayloadEncoding, ResponsePayload, SubscriptionFilter,
+};
+use sqlx::SqlitePool;
 use tokio::net::{TcpListener, TcpStream};
-use tokio::sync::{RwLock, broadcast};
+use tokio::sync::{RwLock, broadcast, watch};
 use tokio_rustls::TlsAcceptor;
 use tokio_util::sync::CancellationToken;
 use uuid::Uuid;
 
 use super::bus::MessageBus;
+use super::client_queue::{ClientQueue, PushOutcome, QueuedMessage};
 use super::transport::{TcpTransport, TlsTransport, Transport};
+use crate::core::ListenerControl;
+use crate::db::repository::{durable_notification, terminal_profile};
 use crate::services::tenant_binding::TenantBinding;
 use crate::utils::AppError;
 
@@ -28,21 +37,26 @@ impl MessageBus {
     /// This is a TCP server that:
     /// 1. Accepts connections
     /// 2. Reads messages from clients and publishes to client_tx (server receives)
-    /// 3. Forwards server broadcast messages to connected clients
+    /// 3. Forwards server broadcast messages to connected clients (per subscribed topic),
+    ///    through a bounded per-client outbound queue
     /// 4. Gracefully shuts down on cancellation signal
+    /// 5. Rebinds / swaps TLS at runtime when `listeners` is given
     pub async fn start_tcp_server(
         &self,
         tls_config_override: Option<Arc<rustls::ServerConfig>>,
         credential_cache: Arc<RwLock<Option<TenantBinding>>>,
+        listeners: Option<ListenerControl>,
     ) -> Result<(), AppError> {
-        let listener = TcpListener::bind(&self.config.tcp_listen_addr)
+        // 启动前端口可能已被重绑定，以控制句柄为准
+        let listen_addr = match &listeners {
+            Some(l) => format!("0.0.0.0:{}", l.ports().message_tcp_port),
+            None => self.config.tcp_listen_addr.clone(),
+        };
+        let listener = TcpListener::bind(&listen_addr)
             .await
             .map_err(|e| AppError::internal(format!("Failed to bind: {}", e)))?;
 
-        tracing::info!(
-            "Message bus TCP server listening on {}",
-            self.config.tcp_listen_addr
-        );
+        tracing::info!("Message bus TCP server listening on {}", listen_addr);
 
         // Prepare TLS acceptor: prefer override (from activation), then config
         let final_tls_config = tls_config_override.or(self.config.tls_config.clone());
@@ -58,24 +72,55 @@ impl MessageBus {
             ));
         };
 
-        self.accept_loop(listener, tls_acceptor, credential_cache)
+        self.accept_loop(listener, tls_acceptor, credential_cache, listeners)
             .await
     }
 
     /// Main accept loop
+    ///
+    /// 重绑定时先绑定新端口再替换旧监听器；已建立的客户端连接不受影响。
     async fn accept_loop(
         &self,
-        listener: TcpListener,
-        tls_acceptor: Option<TlsAcceptor>,
+        mut listener: TcpListener,
+        mut tls_acceptor: Option<TlsAcceptor>,
         credential_cache: Arc<RwLock<Option<TenantBinding>>>,
+        listeners: Option<ListenerControl>,
     ) -> Result<(), AppError> {
+        let mut ports_rx = listeners.as_ref().map(|l| l.subscribe_ports());
+        let mut tls_rx = listeners.as_ref().map(|l| l.subscribe_tls());
+
         loop {
             tokio::select! {
-                _ = self.shutdown_token().cancelled() => {
+                _ = self.accept_token().cancelled() => {
                     tracing::info!("Message bus TCP server shutting down");
                     break;
                 }
 
+                _ = wait_changed(&mut ports_rx) => {
+                    let Some(port) = ports_rx.as_mut().map(|rx| rx.borrow_and_update().message_tcp_port) else {
+                        continue;
+                    };
+                    if listener.local_addr().is_ok_and(|addr| addr.port() == port) {
+                        continue;
+                    }
+                    match TcpListener::bind(("0.0.0.0", port)).await {
+                        Ok(new_listener) => {
+                            listener = new_listener;
+                            tracing::info!(port, "Message bus TCP server rebound");
+                        }
+                        Err(e) => {
+                            tracing::error!(port, error = %e, "Message bus rebind failed, keeping current listener");
+                        }
+                    }
+                }
+
+                _ = wait_changed(&mut tls_rx) => {
+                    if let Some(tls_config) = tls_rx.as_mut().and_then(|rx| rx.borrow_and_update().clone()) {
+                        tls_acceptor = Some(TlsAcceptor::from(tls_config));
+                        tracing::info!("Message bus TLS config reloaded");
+                    }
+                }
+
                 result = listener.accept() => {
                     match result {
                         Ok((stream, addr)) => {
@@ -101,7 +146,7 @@ impl MessageBus {
         tls_acceptor: Option<TlsAcceptor>,
         credential_cache: Arc<RwLock<Option<TenantBinding>>>,
     ) {
-        let server_tx = self.sender().clone();
+        let bus = self.clone();
         let client_tx = self.sender_to_server().clone();
         let shutdown_token = self.shutdown_token().clone();
         let clients = self.clients.clone();
@@ -111,7 +156,7 @@ impl MessageBus {
                 stream,
                 addr,
                 tls_acceptor,
-                server_tx,
+                bus,
                 client_tx,
                 shutdown_token,
                 clients,
@@ -125,13 +170,23 @@ impl MessageBus {
     }
 }
 
+/// Resolve when the watched value changes; never resolves without a receiver
+async fn wait_changed<T>(rx: &mut Option<watch::Receiver<T>>) {
+    if let Some(rx) = rx
+        && rx.changed().await.is_ok()
+    {
+        return;
+    }
+    std::future::pending().await
+}
+
 /// Handle a single client connection
 #[allow(clippy::too_many_arguments)]
 async fn handle_client_connection(
     stream: TcpStream,
     addr: SocketAddr,
     tls_acceptor: Option<TlsAcceptor>,
-    server_tx: broadcast::Sender<BusMessage>,
+    bus: MessageBus,
     client_tx: broadcast::Sender<BusMessage>,
     shutdown_token: CancellationToken,
     clients: Arc<DashMap<String, Arc<dyn Transport>>>,
@@ -160,7 +215,7 @@ async fn handle_client_connection(
     };
 
     // Protocol handshake
-    let client_id = perform_handshake(&transport, addr).await?;
+    let (client_id, options) = perform_handshake(&transport, addr, bus.durable_store()).await?;
 
     // Check client connection quota before registering
     if let Err(e) = check_client_quota(&credential_cache, &clients, &transport, &client_id).await {
@@ -172,19 +227,59 @@ async fn handle_client_connection(
     clients.insert(client_id.clone(), transport.clone());
     tracing::debug!("Client registered: {}", client_id);
 
-    // 创建共享的断开检测 token
-    let disconnect_token = CancellationToken::new();
+    // 创建共享的断开检测 token (服务器关闭时一并取消)
+    let disconnect_token = shutdown_token.child_token();
     let disconnect_token_clone = disconnect_token.clone();
 
-    // Start message forwarding (当客户端断开时，forwarder 也要停止)
-    let forward_handle = spawn_server_to_client_forwarder(
+    // 先订阅主题通道 (缓冲实时消息)，再补发离线期间的关键通知，最后开始实时转发
+    let receivers: Vec<_> = options
+        .topics
+        .iter()
+        .map(|&topic| (topic, bus.subscribe_topic(topic)))
+        .collect();
+    let durable = match bus.durable_store() {
+        Some(pool) => Some(DurableCursor {
+            pool: pool.clone(),
+            replayed_upto: replay_durable_notifications(pool, &transport, &client_id, &options)
+                .await,
+        }),
+        None => None,
+    };
+    let replayed_upto = durable.as_ref().map(|cursor| cursor.replayed_upto);
+
+    // 出站队列：forwarder 过滤后入队，写任务顺序写入 socket (慢客户端只积压自己的队列)
+    let queue = Arc::new(ClientQueue::new(
+        bus.config.client_queue_capacity,
+        bus.config.overflow_policy,
+    ));
+    bus.client_queues.insert(client_id.clone(), queue.clone());
+    let writer_handle = spawn_client_writer(
         transport.clone(),
-        server_tx.subscribe(),
-        shutdown_token.clone(),
+        queue.clone(),
         client_id.clone(),
-        disconnect_token_clone,
+        options.clone(),
+        durable,
+        disconnect_token_clone.clone(),
     );
 
+    // Start message forwarding, one forwarder per subscribed topic
+    // (当客户端断开时，forwarder 也要停止)
+    let forward_handles: Vec<_> = receivers
+        .into_iter()
+        .map(|(topic, rx)| {
+            spawn_server_to_client_forwarder(
+                queue.clone(),
+                rx,
+                shutdown_token.clone(),
+                client_id.clone(),
+                options.clone(),
+                topic,
+                replayed_upto,
+                disconnect_token_clone.clone(),
+            )
+        })
+        .collect();
+
     // Read messages from client - 当检测到断开时，取消 disconnect_token
     read_client_messages(
         &transport,
@@ -197,9 +292,12 @@ async fn handle_client_connection(
     .await;
 
     // Cleanup
-    drop(forward_handle);
+    drop(forward_handles);
+    drop(writer_handle);
     let _ = transport.close().await;
     clients.remove(&client_id);
+    bus.client_queues
+        .remove_if(&client_id, |_, q| Arc::ptr_eq(q, &queue));
     tracing::debug!(client_id = %client_id, "Client removed from registry");
 
     Ok(())
@@ -249,11 +347,116 @@ async fn check_client_quota(
     Ok(())
 }
 
+/// Per-connection options negotiated in the handshake
+#[derive(Debug, Clone)]
+struct ClientOptions {
+    /// Payload encoding for server → client messages
+    encoding: PayloadEncoding,
+    /// Client subscribed to KPI pushes
+    subscribe_kpi: bool,
+    /// Client understands staff announcements
+    announcements: bool,
+    /// Broadcast channels to forward (see [`resolve_topics`])
+    topics: Vec<BusTopic>,
+    /// Server-side filters within the subscribed topics (empty = everything)
+    filters: Vec<SubscriptionFilter>,
+    /// Stable client identity for durable notification cursors
+    /// (mTLS 证书身份 > 握手 client_name > client_id)
+    identity: String,
+}
+
+impl ClientOptions {
+    fn accepts(&self, event_type: EventType) -> bool {
+        match event_type {
+            EventType::Kpi => self.subscribe_kpi,
+            EventType::Announcement => self.announcements,
+            _ => true,
+        }
+    }
+}
+
+/// Durable notification delivery state of one connection
+#[derive(Debug, Clone)]
+struct DurableCursor {
+    pool: SqlitePool,
+    /// Highest id replayed on connect (forwarders skip anything at or below it)
+    replayed_upto: i64,
+}
+
+/// Replay durable notifications the client missed while offline
+///
+/// 主题通道已在补发前订阅，补发期间发布的通知会同时出现在通道里，
+/// 返回补发到的最大 id 供 forwarder 去重。写入失败时停止补发 (未确认的下次重连再发)。
+async fn replay_durable_notifications(
+    pool: &SqlitePool,
+    transport: &Arc<dyn Transport>,
+    client_id: &str,
+    options: &ClientOptions,
+) -> i64 {
+    let pending = match durable_notification::pending_for(pool, &options.identity).await {
+        Ok(pending) => pending,
+        Err(e) => {
+            tracing::warn!(client_id = %client_id, "Failed to load durable notifications: {}", e);
+            return 0;
+        }
+    };
+    if !pending.is_empty() {
+        tracing::info!(
+            client_id = %client_id,
+            identity = %options.identity,
+            count = pending.len(),
+            "Replaying durable notifications"
+        );
+    }
+
+    let mut replayed_upto = 0;
+    for payload in pending {
+        let Some(id) = payload.durable_id else {
+            continue;
+        };
+        let msg = BusMessage::notification(&payload);
+        let msg = msg.encoded_for(options.encoding).unwrap_or_else(|e| {
+            tracing::warn!(client_id = %client_id, error = %e, "Payload encoding failed, sending JSON");
+            msg
+        });
+        if let Err(e) = transport.write_message(&msg).await {
+            tracing::debug!(client_id = %client_id, "Durable replay write failed: {}", e);
+            break;
+        }
+        if let Err(e) = durable_notification::ack(pool, &options.identity, id).await {
+            tracing::warn!(client_id = %client_id, "Failed to ack durable notification: {}", e);
+        }
+        replayed_upto = id;
+    }
+    replayed_upto
+}
+
+/// Upper bound on handshake subscription filters (each broadcast is checked against all of them)
+const MAX_SUBSCRIPTION_FILTERS: usize = 32;
+
+/// Resolve the requested topics into the channels to forward
+///
+/// 未声明主题或包含 `All` → 全量通道 (旧客户端)；否则去重并补上 `System`
+/// (响应、通知、服务器指令都在 System，连接必须接收)。
+fn resolve_topics(requested: &[BusTopic]) -> Vec<BusTopic> {
+    if requested.is_empty() || requested.contains(&BusTopic::All) {
+        return vec![BusTopic::All];
+    }
+    BusTopic::CHANNELS
+        .into_iter()
+        .filter(|t| *t == BusTopic::System || requested.contains(t))
+        .collect()
+}
+
 /// Perform protocol handshake with client
+///
+/// Returns the client id and the per-connection options negotiated in the handshake.
+/// The ack carries the terminal's roaming profile when it connects with a stable identity.
 async fn perform_handshake(
     transport: &Arc<dyn Transport>,
     addr: SocketAddr,
-) -> Result<String, AppError> {
+    pool: Option<&SqlitePool>,
+) -> Result<(String, ClientOptions), AppError> {
     tracing::debug!("Waiting for handshake from {}", addr);
 
     let msg = transport.read_message().await.map_err(|e| {
@@ -319,23 +522,73 @@ async fn perform_handshake(
         .client_id
         .unwrap_or_else(|| Uuid::new_v4().to_string());
 
+    let encoding = PayloadEncoding::negotiate(&payload.accept_encodings);
+    let compression = PayloadCompression::negotiate(&payload.accept_compression);
+    let topics = resolve_topics(&payload.topics);
+    let filters = payload
+        .filters
+        .into_iter()
+        .take(MAX_SUBSCRIPTION_FILTERS)
+        .collect::<Vec<_>>();
+    let identity = transport
+        .peer_identity()
+        .or_else(|| payload.client_name.clone())
+        .unwrap_or_else(|| client_id.clone());
+    // 漫游配置只按稳定身份 (证书 CN / 非空 client_name) 保存，随机 client_id 不下发
+    let stable_identity = (identity != client_id && !identity.is_empty()).then(|| identity.clone());
+
+    // 终端漫游配置 (按证书身份)：随握手下发，替换设备后自动恢复
+    let terminal_profile = match (pool, &stable_identity) {
+        (Some(pool), Some(identity)) => terminal_profile::find(pool, identity)
+            .await
+            .unwrap_or_else(|e| {
+                tracing::warn!(identity = %identity, "Failed to load terminal profile: {e}");
+                None
+            }),
+        _ => None,
+    };
+
     tracing::debug!(
-        "Client {} handshake success (v{}, client: {:?}, id: {})",
+        "Client {} handshake success (v{}, client: {:?}, id: {}, encoding: {:?}, compression: {:?}, topics: {:?}, filters: {})",
         addr,
         payload.version,
         payload.client_name,
-        client_id
+        client_id,
+        encoding,
+        compression,
+        topics,
+        filters.len()
     );
 
-    // 发送 RPC 响应 (用 correlation_id 关联客户端的 request_id)
+    // 发送 RPC 响应 (用 correlation_id 关联客户端的 request_id)，握手响应始终是 JSON 且不压缩
+    let ack = serde_json::to_value(HandshakeAck {
+        encoding,
+        compression,
+        identity: stable_identity,
+        terminal_profile,
+        trace_context: payload.trace_context,
+    })
+    .ok();
     let response_payload =
-        ResponsePayload::success(format!("Connected as client: {}", client_id), None);
+        ResponsePayload::success(format!("Connected as client: {}", client_id), ack);
     let response = BusMessage::response(&response_payload).with_correlation_id(msg.request_id);
     if let Err(e) = transport.write_message(&response).await {
         tracing::warn!("Failed to send handshake response: {}", e);
     }
-
-    Ok(client_id)
+    transport.set_compression(compression);
+    transport.set_trace_context(payload.trace_context);
+
+    Ok((
+        client_id,
+        ClientOptions {
+            encoding,
+            subscribe_kpi: payload.subscribe_kpi,
+            announcements: payload.announcements,
+            topics,
+            filters,
+            identity,
+        },
+    ))
 }
 
 /// Delay before closing connection after sending error (allows client to receive the message)
@@ -354,12 +607,62 @@ async fn send_handshake_error(transport: &Arc<dyn Transport>, msg: &BusMessage,
     tokio::time::sleep(tokio::time::Duration::from_millis(HANDSHAKE_ERROR_DELAY_MS)).await;
 }
 
-/// Spawn task to forward messages from server to client
+/// Build the Sync message that triggers a client-side full resync
+fn resync_message(
+    client_id: &str,
+    reason: &str,
+    topic: Option<BusTopic>,
+    dropped: u64,
+) -> BusMessage {
+    BusMessage {
+        event_type: EventType::Sync,
+        request_id: Uuid::new_v4(),
+        correlation_id: None,
+        payload: serde_json::json!({
+            "reason": reason,
+            "topic": topic,
+            "dropped_messages": dropped,
+            "action": "full_resync"
+        })
+        .to_string()
+        .into_bytes(),
+        source: Some("server".to_string()),
+        target: Some(client_id.to_string()),
+        trace_context: None,
+    }
+}
+
+/// Push into the client's outbound queue; false = the forwarder should stop
+///
+/// `disconnect` 策略下队列满时断开连接 (客户端重连后全量同步)。
+async fn enqueue(
+    queue: &ClientQueue,
+    item: QueuedMessage,
+    client_id: &str,
+    disconnect_token: &CancellationToken,
+) -> bool {
+    let outcome = tokio::select! {
+        _ = disconnect_token.cancelled() => return false,
+        outcome = queue.push(item) => outcome,
+    };
+    if outcome == PushOutcome::Overflow {
+        tracing::warn!(client_id = %client_id, "Client outbound queue full, disconnecting slow client");
+        disconnect_token.cancel();
+        return false;
+    }
+    true
+}
+
+/// Spawn task to forward one topic channel into the client's outbound queue
+#[allow(clippy::too_many_arguments)]
 fn spawn_server_to_client_forwarder(
-    transport: Arc<dyn Transport>,
+    queue: Arc<ClientQueue>,
     mut rx: broadcast::Receiver<BusMessage>,
     shutdown_token: CancellationToken,
     client_id: String,
+    options: ClientOptions,
+    topic: BusTopic,
+    replayed_upto: Option<i64>,
     disconnect_token: CancellationToken,
 ) -> tokio::task::JoinHandle<()> {
     tokio::spawn(async move {
@@ -380,9 +683,30 @@ fn spawn_server_to_client_forwarder(
                             if msg.target.as_ref().is_some_and(|target| target != &client_id) {
                                 continue;
                             }
+                            // 扩展事件只发给声明支持的连接 (旧客户端不认识该事件类型)
+                            if !options.accepts(msg.event_type) {
+                                continue;
+                            }
+                            // 握手声明的订阅过滤 (如只要 A 区订单)，在入队前丢弃以节省带宽
+                            if !msg.matches_filters(&options.filters) {
+                                continue;
+                            }
+                            // 关键通知：跳过连接时已补发的，写任务发送成功后推进游标
+                            let durable_id = match replayed_upto {
+                                Some(_) if msg.event_type == EventType::Notification => msg
+                                    .parse_payload::<NotificationPayload>()
+                                    .ok()
+                                    .and_then(|p| p.durable_id),
+                                _ => None,
+                            };
+                            if let (Some(id), Some(upto)) = (durable_id, replayed_upto)
+                                && id <= upto
+                            {
+                                continue;
+                            }
 
-                            if let Err(e) = transport.write_message(&msg).await {
-                                tracing::debug!(client_id = %client_id, "Client write failed: {}", e);
+                            let item = QueuedMessage { msg, durable_id };
+                            if !enqueue(&queue, item, &client_id, &disconnect_token).await {
                                 break;
                             }
                         }
@@ -390,26 +714,17 @@ fn spawn_server_to_client_forwarder(
                             // WiFi lag recovery: client fell behind, notify to resync
                             tracing::warn!(
                                 client_id = %client_id,
+                                topic = %topic,
                                 dropped_messages = n,
                                 "Client lagged behind, sending resync notification"
                             );
 
                             // Send a Sync message to trigger client-side full resync
-                            let resync_msg = BusMessage {
-                                event_type: EventType::Sync,
-                                request_id: Uuid::new_v4(),
-                                correlation_id: None,
-                                payload: serde_json::json!({
-                                    "reason": "lagged",
-                                    "dropped_messages": n,
-                                    "action": "full_resync"
-                                }).to_string().into_bytes(),
-                                source: Some("server".to_string()),
-                                target: Some(client_id.clone()),
+                            let item = QueuedMessage {
+                                msg: resync_message(&client_id, "lagged", Some(topic), n),
+                                durable_id: None,
                             };
-
-                            if let Err(e) = transport.write_message(&resync_msg).await {
-                                tracing::debug!(client_id = %client_id, "Failed to send resync notification: {}", e);
+                            if !enqueue(&queue, item, &client_id, &disconnect_token).await {
                                 break;
                             }
 
@@ -418,7 +733,7 @@ fn spawn_server_to_client_forwarder(
                         }
                         Err(broadcast::error::RecvError::Closed) => {
                             // Channel truly closed
-                            tracing::debug!(client_id = %client_id, "Broadcast channel closed");
+                            tracing::debug!(client_id = %client_id, topic = %topic, "Broadcast channel closed");
                             break;
                         }
                     }
@@ -430,6 +745,61 @@ fn spawn_server_to_client_forwarder(
     })
 }
 
+/// Spawn the task draining the client's outbound queue into the socket
+///
+/// 写入失败即视为连接已断开：取消 disconnect_token，forwarder 和读循环随之退出。
+fn spawn_client_writer(
+    transport: Arc<dyn Transport>,
+    queue: Arc<ClientQueue>,
+    client_id: String,
+    options: ClientOptions,
+    durable: Option<DurableCursor>,
+    disconnect_token: CancellationToken,
+) -> tokio::task::JoinHandle<()> {
+    tokio::spawn(async move {
+        loop {
+            let (item, dropped) = tokio::select! {
+                _ = disconnect_token.cancelled() => break,
+                next = queue.pop() => next,
+            };
+
+            // drop-oldest 丢弃过消息：先让客户端全量同步 (被丢弃的关键通知未确认，重连时补发)
+            if dropped > 0 {
+                tracing::warn!(
+                    client_id = %client_id,
+                    dropped_messages = dropped,
+                    "Client outbound queue overflowed, sending resync notification"
+                );
+                let resync = resync_message(&client_id, "queue_overflow", None, dropped);
+                if let Err(e) = transport.write_message(&resync).await {
+                    tracing::debug!(client_id = %client_id, "Failed to send resync notification: {}", e);
+                    disconnect_token.cancel();
+                    break;
+                }
+            }
+
+            // 转码失败时退回 JSON（客户端透明解析两种编码）
+            let msg = item.msg.encoded_for(options.encoding).unwrap_or_else(|e| {
+                tracing::warn!(client_id = %client_id, error = %e, "Payload encoding failed, sending JSON");
+                item.msg
+            });
+
+            if let Err(e) = transport.write_message(&msg).await {
+                tracing::debug!(client_id = %client_id, "Client write failed: {}", e);
+                disconnect_token.cancel();
+                break;
+            }
+            if let (Some(id), Some(cursor)) = (item.durable_id, &durable)
+                && let Err(e) = durable_notification::ack(&cursor.pool, &options.identity, id).await
+            {
+                tracing::warn!(client_id = %client_id, "Failed to ack durable notification: {}", e);
+            }
+        }
+
+        tracing::debug!(client_id = %client_id, "Client writer stopped");
+    })
+}
+
 /// Read messages from client and forward to server
 async fn read_client_messages(
     transport: &Arc<dyn Transport>,
@@ -445,6 +815,11 @@ async fn read_client_messages(
                 break;
             }
 
+            // 写任务失败或出站队列溢出 (disconnect 策略)
+            _ = disconnect_token.cancelled() => {
+                break;
+            }
+
             read_result = transport.read_message() => {
                 match read_result {
                     Ok(mut msg) => {
@@ -461,6 +836,16 @@ async fn read_client_messages(
                             continue;
                         }
 
+                        // 上行载荷只接受 JSON（二进制编码仅用于服务端 → 客户端）
+                        if msg.is_binary_payload() {
+                            tracing::warn!(
+                                target: "security",
+                                client_addr = %addr,
+                                "Client sent binary-encoded payload. Dropping message."
+                            );
+                            continue;
+                        }
+
                         // Publish to client_tx so server handlers receive it
                         if let Err(e) = client_tx.send(msg) {
                             tracing::warn!("Failed to publish client message: {}", e);
@@ -481,3 +866,22 @@ async fn read_client_messages(
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_resolve_topics() {
+        assert_eq!(resolve_topics(&[]), vec![BusTopic::All]);
+        assert_eq!(
+            resolve_topics(&[BusTopic::Orders, BusTopic::All]),
+            vec![BusTopic::All]
+        );
+        assert_eq!(
+            resolve_topics(&[BusTopic::Kds, BusTopic::Orders, BusTopic::Kds]),
+            vec![BusTopic::Orders, BusTopic::System, BusTopic::Kds]
+        );
+        assert_eq!(resolve_topics(&[BusTopic::System]), vec![BusTopic::System]);
+    }
+}
@@ -21,7 +21,7 @@ pub use tcp::TcpTransport;
 pub use tls::TlsTransport;
 
 use async_trait::async_trait;
-use shared::message::BusMessage;
+use shared::message::{BusMessage, PayloadCompression, compression, trace_context};
 use tokio::io::{AsyncReadExt, AsyncWriteExt};
 use uuid::Uuid;
 
@@ -41,6 +41,16 @@ pub trait Transport: Send + Sync + std::fmt::Debug {
     /// 关闭传输连接
     async fn close(&self) -> Result<(), AppError>;
 
+    /// 设置握手协商的帧压缩算法 (之后写出的大载荷帧按该算法压缩)
+    ///
+    /// 默认不压缩 (同进程通信无需压缩)
+    fn set_compression(&self, _compression: PayloadCompression) {}
+
+    /// 设置握手协商结果：之后写出的帧可携带链路追踪上下文
+    ///
+    /// 默认不携带 (同进程通信直接传递 `BusMessage.trace_context`)
+    fn set_trace_context(&self, _enabled: bool) {}
+
     /// 获取对端身份标识 (mTLS 场景下从证书提取)
     fn peer_identity(&self) -> Option<String> {
         None
@@ -54,6 +64,9 @@ pub trait Transport: Send + Sync + std::fmt::Debug {
 
 // ========== 辅助函数 ==========
 
+/// 单帧载荷上限 (解压后)
+const MAX_PAYLOAD_SIZE: usize = 16 * 1024 * 1024; // 16 MB
+
 /// 从异步流中读取 BusMessage
 pub(crate) async fn read_from_stream<R: AsyncReadExt + Unpin>(
     reader: &mut R,
@@ -76,8 +89,10 @@ pub(crate) async fn read_from_stream<R: AsyncReadExt + Unpin>(
         }
     }
 
+    let (type_byte, compressed) = compression::split_type_byte(type_buf[0]);
+    let (type_byte, traced) = trace_context::split_type_byte(type_byte);
     let event_type =
-        EventType::try_from(type_buf[0]).map_err(|_| AppError::invalid("Invalid event type"))?;
+        EventType::try_from(type_byte).map_err(|_| AppError::invalid("Invalid event type"))?;
 
     // 读取 Request ID (16 字节)
     let mut uuid_buf = [0u8; 16];
@@ -100,6 +115,23 @@ pub(crate) async fn read_from_stream<R: AsyncReadExt + Unpin>(
         Some(correlation_id_raw)
     };
 
+    // 读取 Trace Context (1 字节长度 + traceparent，仅置位时存在)
+    let trace_context = if traced {
+        let mut ctx_len = [0u8; 1];
+        reader
+            .read_exact(&mut ctx_len)
+            .await
+            .map_err(|e| AppError::internal(format!("Read trace context failed: {}", e)))?;
+        let mut ctx = vec![0u8; ctx_len[0] as usize];
+        reader
+            .read_exact(&mut ctx)
+            .await
+            .map_err(|e| AppError::internal(format!("Read trace context failed: {}", e)))?;
+        trace_context::from_wire(&ctx)
+    } else {
+        None
+    };
+
     // 读取载荷长度 (4 字节)
     let mut len_buf = [0u8; 4];
     reader
@@ -110,7 +142,6 @@ pub(crate) async fn read_from_stream<R: AsyncReadExt + Unpin>(
     let len = u32::from_le_bytes(len_buf) as usize;
 
     // Guard against memory exhaustion from malicious/corrupted payload length
-    const MAX_PAYLOAD_SIZE: usize = 16 * 1024 * 1024; // 16 MB
     if len > MAX_PAYLOAD_SIZE {
         return Err(AppError::invalid(format!(
             "Payload size {} exceeds maximum allowed {} bytes",
@@ -125,6 +156,11 @@ pub(crate) async fn read_from_stream<R: AsyncReadExt + Unpin>(
         .await
         .map_err(|e| AppError::internal(format!("Read payload failed: {}", e)))?;
 
+    // 压缩帧：解压后同样受 MAX_PAYLOAD_SIZE 限制
+    if compressed {
+        payload = compression::decompress(&payload, MAX_PAYLOAD_SIZE).map_err(AppError::invalid)?;
+    }
+
     Ok(BusMessage {
         request_id,
         event_type,
@@ -132,26 +168,50 @@ pub(crate) async fn read_from_stream<R: AsyncReadExt + Unpin>(
         correlation_id,
         target: None,
         payload,
+        trace_context,
     })
 }
 
 /// 向异步流写入 BusMessage
+///
+/// 载荷超过 [`compression::COMPRESSION_THRESHOLD`] 时按 `compression` 压缩；
+/// `with_trace_context` (握手协商) 时附带消息的 `traceparent`
 pub(crate) async fn write_to_stream<W: AsyncWriteExt + Unpin>(
     writer: &mut W,
     msg: &BusMessage,
+    compression: PayloadCompression,
+    with_trace_context: bool,
 ) -> Result<(), AppError> {
+    let compressed = compression::compress(&msg.payload, compression);
+    let (mut type_byte, payload) = match &compressed {
+        Some(body) => (msg.event_type as u8 | compression::COMPRESSED_FLAG, body),
+        None => (msg.event_type as u8, &msg.payload),
+    };
+    let ctx = with_trace_context
+        .then(|| trace_context::wire_value(msg.trace_context.as_deref()))
+        .flatten();
+    if ctx.is_some() {
+        type_byte |= trace_context::TRACE_CONTEXT_FLAG;
+    }
+
     let mut data = Vec::new();
-    data.push(msg.event_type as u8);
+    data.push(type_byte);
     data.extend_from_slice(msg.request_id.as_bytes());
 
     // Write correlation_id (16 bytes) - using nil UUID if None
     let correlation_bytes = msg.correlation_id.unwrap_or(Uuid::nil()).into_bytes();
     data.extend_from_slice(&correlation_bytes);
 
-    let payload_len = u32::try_from(msg.payload.len())
+    // Write trace context (1 byte length + traceparent) when flagged
+    if let Some(ctx) = ctx {
+        data.push(ctx.len() as u8);
+        data.extend_from_slice(ctx);
+    }
+
+    let payload_len = u32::try_from(payload.len())
         .map_err(|_| AppError::internal("Payload exceeds u32::MAX bytes"))?;
     data.extend_from_slice(&payload_len.to_le_bytes());
-    data.extend_from_slice(&msg.payload);
+    data.extend_from_slice(payload);
 
     writer
         .write_all(&data)
@@ -159,3 +219,92 @@ pub(crate) async fn write_to_stream<W: AsyncWriteExt + Unpin>(
         .map_err(|e| AppError::internal(format!("Write failed: {}", e)))?;
     Ok(())
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use shared::message::{EventType, compression::COMPRESSION_THRESHOLD};
+
+    fn message(payload_len: usize) -> BusMessage {
+        BusMessage {
+            request_id: Uuid::new_v4(),
+            event_type: EventType::Response,
+            source: None,
+            correlation_id: Some(Uuid::new_v4()),
+            target: None,
+            payload: format!("\"{}\"", "order ".repeat(payload_len / 6)).into_bytes(),
+            trace_context: None,
+        }
+    }
+
+    #[tokio::test]
+    async fn test_compressed_frame_roundtrip() {
+        for (len, compression) in [
+            (64, PayloadCompression::Zstd),
+            (COMPRESSION_THRESHOLD * 4, PayloadCompression::Zstd),
+            (COMPRESSION_THRESHOLD * 4, PayloadCompression::None),
+        ] {
+            let msg = message(len);
+            let mut wire = Vec::new();
+            write_to_stream(&mut wire, &msg, compression, false)
+                .await
+                .unwrap();
+
+            let compressed = wire[0] & compression::COMPRESSED_FLAG != 0;
+            assert_eq!(
+                compressed,
+                compression != PayloadCompression::None && len >= COMPRESSION_THRESHOLD
+            );
+            if compressed {
+                assert!(wire.len() < msg.payload.len());
+            }
+
+            let read = read_from_stream(&mut wire.as_slice()).await.unwrap();
+            assert_eq!(read.event_type, msg.event_type);
+            assert_eq!(read.request_id, msg.request_id);
+            assert_eq!(read.correlation_id, msg.correlation_id);
+            assert_eq!(read.payload, msg.payload);
+        }
+    }
+
+    #[tokio::test]
+    async fn test_trace_context_frame_roundtrip() {
+        let traceparent = "00-4bf92f3577b34da6a3ce929d0e0e4736-00f067aa0ba902b7-01";
+        for (len, compression) in [
+            (64, PayloadCompression::None),
+            (COMPRESSION_THRESHOLD * 4, PayloadCompression::Zstd),
+        ] {
+            let msg = message(len).with_trace_context(Some(traceparent.to_string()));
+
+            // 未协商：不置位，帧格式与旧版一致
+            let mut plain = Vec::new();
+            write_to_stream(&mut plain, &msg, compression, false)
+                .await
+                .unwrap();
+            assert_eq!(plain[0] & trace_context::TRACE_CONTEXT_FLAG, 0);
+            let read = read_from_stream(&mut plain.as_slice()).await.unwrap();
+            assert_eq!(read.trace_context, None);
+
+            let mut wire = Vec::new();
+            write_to_stream(&mut wire, &msg, compression, true)
+                .await
+                .unwrap();
+            assert_ne!(wire[0] & trace_context::TRACE_CONTEXT_FLAG, 0);
+            assert_eq!(wire.len(), plain.len() + 1 + traceparent.len());
+
+            let read = read_from_stream(&mut wire.as_slice()).await.unwrap();
+            assert_eq!(read.event_type, msg.event_type);
+            assert_eq!(read.correlation_id, msg.correlation_id);
+            assert_eq!(read.trace_context.as_deref(), Some(traceparent));
+            assert_eq!(read.payload, msg.payload);
+        }
+
+        // 无效 traceparent 不写入帧
+        let msg = message(64).with_trace_context(Some("not-a-traceparent".to_string()));
+        let mut wire = Vec::new();
+        write_to_stream(&mut wire, &msg, PayloadCompression::None, true)
+            .await
+            .unwrap();
+        assert_eq!(wire[0] & trace_context::TRACE_CONTEXT_FLAG, 0);
+    }
+}
@@ -1,9 +1,10 @@
 //! TCP 传输层实现
 
 use std::sync::Arc;
+use std::sync::atomic::{AtomicBool, AtomicU8, Ordering};
 
 use async_trait::async_trait;
-use shared::message::BusMessage;
+use shared::message::{BusMessage, PayloadCompression};
 use tokio::net::TcpStream;
 use tokio::net::tcp::{OwnedReadHalf, OwnedWriteHalf};
 use tokio::sync::Mutex;
@@ -17,6 +18,10 @@ pub struct TcpTransport {
     reader: Arc<Mutex<OwnedReadHalf>>,
     writer: Arc<Mutex<OwnedWriteHalf>>,
     addr: Option<String>,
+    /// 握手协商的帧压缩算法 ([`PayloadCompression`] ID)
+    compression: Arc<AtomicU8>,
+    /// 握手协商：帧可携带链路追踪上下文
+    trace_context: Arc<AtomicBool>,
 }
 
 impl TcpTransport {
@@ -31,6 +36,8 @@ impl TcpTransport {
             reader: Arc::new(Mutex::new(reader)),
             writer: Arc::new(Mutex::new(writer)),
             addr: peer_addr,
+            compression: Arc::new(AtomicU8::new(PayloadCompression::None as u8)),
+            trace_context: Arc::new(AtomicBool::new(false)),
         })
     }
 
@@ -42,9 +49,16 @@ impl TcpTransport {
             reader: Arc::new(Mutex::new(reader)),
             writer: Arc::new(Mutex::new(writer)),
             addr: peer_addr,
+            compression: Arc::new(AtomicU8::new(PayloadCompression::None as u8)),
+            trace_context: Arc::new(AtomicBool::new(false)),
         }
     }
 
+    /// 当前连接的帧压缩算法
+    fn compression(&self) -> PayloadCompression {
+        PayloadCompression::from_id(self.compression.load(Ordering::Relaxed)).unwrap_or_default()
+    }
+
     pub async fn read_message(&self) -> Result<BusMessage, AppError> {
         let mut reader = self.reader.lock().await;
         read_from_stream(&mut *reader).await
@@ -52,7 +66,13 @@ impl TcpTransport {
 
     pub async fn write_message(&self, msg: &BusMessage) -> Result<(), AppError> {
         let mut writer = self.writer.lock().await;
-        write_to_stream(&mut *writer, msg).await
+        write_to_stream(
+            &mut *writer,
+            msg,
+            self.compression(),
+            self.trace_context.load(Ordering::Relaxed),
+        )
+        .await
     }
 
     pub async fn close(&self) -> Result<(), AppError> {
@@ -86,6 +106,14 @@ impl Transport for TcpTransport {
         Ok(())
     }
 
+    fn set_compression(&self, compression: PayloadCompression) {
+        self.compression.store(compression as u8, Ordering::Relaxed);
+    }
+
+    fn set_trace_context(&self, enabled: bool) {
+        self.trace_context.store(enabled, Ordering::Relaxed);
+    }
+
     fn peer_addr(&self) -> Option<String> {
         self.addr.clone()
     }
@@ -1,10 +1,11 @@
 //! TLS 传输层实现 (mTLS 支持)
 
 use std::sync::Arc;
+use std::sync::atomic::{AtomicBool, AtomicU8, Ordering};
 
 use async_trait::async_trait;
 use crab_cert::CertMetadata;
-use shared::message::BusMessage;
+use shared::message::{BusMessage, PayloadCompression};
 use tokio::io::{ReadHalf, WriteHalf, split};
 use tokio::net::TcpStream;
 use tokio::sync::Mutex;
@@ -20,6 +21,10 @@ pub struct TlsTransport {
     writer: Arc<Mutex<WriteHalf<TlsStream<TcpStream>>>>,
     peer_identity: Option<String>,
     addr: Option<String>,
+    /// 握手协商的帧压缩算法 ([`PayloadCompression`] ID)
+    compression: Arc<AtomicU8>,
+    /// 握手协商：帧可携带链路追踪上下文
+    trace_context: Arc<AtomicBool>,
 }
 
 impl TlsTransport {
@@ -49,8 +54,15 @@ impl TlsTransport {
             writer: Arc::new(Mutex::new(writer)),
             peer_identity,
             addr: peer_addr,
+            compression: Arc::new(AtomicU8::new(PayloadCompression::None as u8)),
+            trace_context: Arc::new(AtomicBool::new(false)),
         }
     }
+
+    /// 当前连接的帧压缩算法
+    fn compression(&self) -> PayloadCompression {
+        PayloadCompression::from_id(self.compression.load(Ordering::Relaxed)).unwrap_or_default()
+    }
 }
 
 #[async_trait]
@@ -62,7 +74,13 @@ impl Transport for TlsTransport {
 
     async fn write_message(&self, msg: &BusMessage) -> Result<(), AppError> {
         let mut writer = self.writer.lock().await;
-        write_to_stream(&mut *writer, msg).await
+        write_to_stream(
+            &mut *writer,
+            msg,
+            self.compression(),
+            self.trace_context.load(Ordering::Relaxed),
+        )
+        .await
     }
 
     async fn close(&self) -> Result<(), AppError> {
@@ -79,6 +97,14 @@ impl Transport for TlsTransport {
         self.peer_identity.clone()
     }
 
+    fn set_compression(&self, compression: PayloadCompression) {
+        self.compression.store(compression as u8, Ordering::Relaxed);
+    }
+
+    fn set_trace_context(&self, enabled: bool) {
+        self.trace_context.store(enabled, Ordering::Relaxed);
+    }
+
     fn peer_addr(&self) -> Option<String> {
         self.addr.clone()
     }
@@ -0,0 +1,118 @@
+//! 通用 HTTP/JSON 消息网关
+//!
+//! | 操作 | 请求 |
+//! |------|------|
+//! | 发送 | `POST {base}/messages` ([`OutboundMessage`]) → `{ "message_id": "..." }` |
+//!
+//! 配置了 API key 时以 `Authorization: Bearer <key>` 发送；网关回调送达回执时
+//! 以同一 key 作为 Bearer 令牌。
+
+use std::time::Duration;
+
+use async_trait::async_trait;
+use reqwest::{Client, Url};
+
+use crate::utils::validation::token_matches;
+
+use super::{MessageGateway, NotifyError, OutboundMessage, SendReceipt};
+
+/// 网关请求超时
+const REQUEST_TIMEOUT: Duration = Duration::from_secs(10);
+
+pub struct HttpMessageGateway {
+    client: Client,
+    url: Url,
+    api_key: Option<String>,
+}
+
+impl HttpMessageGateway {
+    pub fn new(base_url: &str, api_key: Option<String>) -> Result<Self, NotifyError> {
+        let client = Client::builder()
+            .timeout(REQUEST_TIMEOUT)
+            .build()
+            .map_err(|e| NotifyError::Http(e.to_string()))?;
+        let mut url = Url::parse(base_url)
+            .map_err(|e| NotifyError::Http(format!("Invalid gateway URL: {e}")))?;
+        url.path_segments_mut()
+            .map_err(|_| NotifyError::Http("Invalid gateway URL".into()))?
+            .pop_if_empty()
+            .push("messages");
+        Ok(Self {
+            client,
+            url,
+            api_key: api_key.filter(|k| !k.is_empty()),
+        })
+    }
+}
+
+impl std::fmt::Debug for HttpMessageGateway {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("HttpMessageGateway")
+            .field("url", &self.url.as_str())
+            .field("api_key", &self.api_key.as_ref().map(|_| "***"))
+            .finish()
+    }
+}
+
+#[async_trait]
+impl MessageGateway for HttpMessageGateway {
+    async fn send(&self, message: &OutboundMessage) -> Result<SendReceipt, NotifyError> {
+        let mut request = self.client.post(self.url.clone()).json(message);
+        if let Some(key) = &self.api_key {
+            request = request.bearer_auth(key);
+        }
+        let response = request
+            .send()
+            .await
+            .map_err(|e| NotifyError::Http(e.to_string()))?;
+        let status = response.status();
+        if !status.is_success() {
+            let body = response.text().await.unwrap_or_default();
+            let detail = if body.is_empty() {
+                status.to_string()
+            } else {
+                format!("{status}: {body}")
+            };
+            return Err(if status.is_client_error() {
+                NotifyError::Rejected(detail)
+            } else {
+                NotifyError::Http(detail)
+            });
+        }
+        let receipt = response
+            .json::<SendReceipt>()
+            .await
+            .map_err(|e| NotifyError::Http(format!("Invalid gateway response: {e}")))?;
+        if receipt.message_id.is_empty() {
+            return Err(NotifyError::Http(
+                "Gateway returned an empty message id".into(),
+            ));
+        }
+        Ok(receipt)
+    }
+
+    /// 未配置 key 时拒绝所有回调 (避免匿名篡改送达状态)
+    fn verify_callback(&self, token: &str) -> bool {
+        self.api_key
+            .as_deref()
+            .is_some_and(|key| token_matches(token, key))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_messages_url_and_callback_token() {
+        let gateway =
+            HttpMessageGateway::new("https://sms.example.com/v1/", Some("secret".into())).unwrap();
+        assert_eq!(gateway.url.as_str(), "https://sms.example.com/v1/messages");
+        assert!(gateway.verify_callback("secret"));
+        assert!(!gateway.verify_callback("other"));
+
+        let anonymous = HttpMessageGateway::new("https://sms.example.com", None).unwrap();
+        assert!(!anonymous.verify_callback(""));
+        assert!(HttpMessageGateway::new("not a url", None).is_err());
+    }
+}
@@ -0,0 +1,84 @@
+//! 顾客消息通知网关 (SMS / WhatsApp)
+//!
+//! 外卖出餐时向顾客发送取餐通知。网关回调送达回执 (delivery report)，
+//! 由 `/api/public/pickup/delivery` 更新取餐单的送达状态。
+//!
+//! - [`MessageGateway`] — 对接不同短信服务商的 trait
+//! - [`HttpMessageGateway`] — 通用 HTTP/JSON 实现 (`NOTIFY_GATEWAY_URL` / `NOTIFY_GATEWAY_API_KEY`)
+
+mod http;
+
+pub use http::HttpMessageGateway;
+
+use async_trait::async_trait;
+use serde::{Deserialize, Serialize};
+use shared::models::NotifyChannel;
+use thiserror::Error;
+
+/// 网关错误
+#[derive(Debug, Error)]
+pub enum NotifyError {
+    #[error("Gateway rejected the message: {0}")]
+    Rejected(String),
+
+    #[error("Gateway unreachable: {0}")]
+    Http(String),
+}
+
+/// 待发送消息
+#[derive(Debug, Clone, Serialize)]
+pub struct OutboundMessage {
+    /// 收件号码 (E.164)
+    pub to: String,
+    pub channel: NotifyChannel,
+    pub body: String,
+    /// 幂等键 (取餐单 id + 发送次数)
+    pub external_id: String,
+}
+
+/// 网关受理回执
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SendReceipt {
+    /// 网关消息编号 (送达回执按此关联)
+    pub message_id: String,
+}
+
+/// 消息网关接口
+#[async_trait]
+pub trait MessageGateway: Send + Sync + std::fmt::Debug {
+    async fn send(&self, message: &OutboundMessage) -> Result<SendReceipt, NotifyError>;
+
+    /// 校验送达回执的调用方 (共享密钥)
+    fn verify_callback(&self, token: &str) -> bool;
+}
+
+/// 取餐通知文案
+pub fn pickup_ready_message(store_name: &str, queue_number: Option<i64>, code: &str) -> String {
+    let order = match queue_number {
+        Some(n) => format!(" #{n}"),
+        None => String::new(),
+    };
+    let prefix = if store_name.is_empty() {
+        String::new()
+    } else {
+        format!("{store_name}: ")
+    };
+    format!("{prefix}¡Tu pedido{order} está listo! Código de recogida: {code}")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_pickup_ready_message() {
+        assert_eq!(
+            pickup_ready_message("Casa Luca", Some(42), "K7P2QX"),
+            "Casa Luca: ¡Tu pedido #42 está listo! Código de recogida: K7P2QX"
+        );
+        assert_eq!(
+            pickup_ready_message("", None, "K7P2QX"),
+            "¡Tu pedido está listo! Código de recogida: K7P2QX"
+        );
+    }
+}
@@ -16,8 +16,8 @@
 //! - No gaps in sequence (can be validated)
 //! - Full sync is always available as fallback
 
-use crate::order_money::to_decimal;
 use crate::orders::manager::{ManagerError, OrdersManager};
+use crab_order_core::money::to_decimal;
 use serde::{Deserialize, Serialize};
 use shared::order::{OrderEvent, OrderSnapshot};
 
@@ -375,6 +375,7 @@ mod tests {
                 is_retail: false,
                 queue_number: None,
                 receipt_number: "RCP-TEST".to_string(),
+                tax_mode: shared::models::TaxMode::Inclusive,
             },
         };
 
@@ -402,6 +403,7 @@ mod tests {
                 is_retail: false,
                 queue_number: None,
                 receipt_number: "RCP-TEST".to_string(),
+                tax_mode: shared::models::TaxMode::Inclusive,
             },
         };
 
@@ -55,7 +55,13 @@ impl CommandHandler for AddItemsAction {
 
         // 1. Validate input items
         for item in &self.items {
-            crate::order_money::validate_cart_item(item)?;
+            crab_order_core::money::validate_cart_item(item)?;
+            if item.course.is_some_and(|course| course < 1) {
+                return Err(OrderError::InvalidOperation(
+                    CommandErrorCode::InvalidCourse,
+                    "course must be >= 1".to_string(),
+                ));
+            }
         }
 
         // 2. Load existing snapshot
@@ -113,6 +119,10 @@ impl CommandHandler for AddItemsAction {
                 let product_id_i64: i64 = item.product_id;
                 let category_id: Option<i64> = meta.map(|m| m.category_id);
                 let tag_ids: Vec<i64> = meta.map(|m| m.tags.clone()).unwrap_or_default();
+                // 分道次商品暂缓，直到该道次起菜（已起菜的道次直接送厨）
+                let held = item
+                    .course
+                    .is_some_and(|course| !snapshot.is_course_fired(course));
 
                 debug!(
                     item_idx = idx,
@@ -146,6 +156,7 @@ impl CommandHandler for AddItemsAction {
                 if let Some(ref mut spec) = snapshot.selected_specification {
                     spec.is_multi_spec = meta.map(|m| m.specs_count > 1).unwrap_or(false);
                 }
+                snapshot.is_held = held;
 
                 info!(
                     item_idx = idx,
@@ -242,6 +253,7 @@ mod tests {
             note: None,
             authorizer_id: None,
             authorizer_name: None,
+            course: None,
         }
     }
 
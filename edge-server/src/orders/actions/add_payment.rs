@@ -4,9 +4,9 @@
 
 use shared::order::types::CommandErrorCode;
 
-use crate::order_money::{MONEY_TOLERANCE, to_decimal, to_f64};
 use crate::orders::traits::{CommandContext, CommandHandler, CommandMetadata, OrderError};
-use rust_decimal::Decimal;
+use crab_order_core::money::{MONEY_TOLERANCE, to_decimal, to_f64};
+use shared::models::PaymentSurchargeRule;
 use shared::order::{EventPayload, OrderEvent, OrderEventType, OrderStatus, PaymentInput};
 
 /// AddPayment action
@@ -14,6 +14,12 @@ use shared::order::{EventPayload, OrderEvent, OrderEventType, OrderStatus, Payme
 pub struct AddPaymentAction {
     pub order_id: i64,
     pub payment: PaymentInput,
+    /// Pre-allocated payment_id (stored-credit debit is recorded before the event)
+    pub payment_id: Option<i64>,
+    /// Payment-method surcharge rule (prefetched, only when the store toggle is on)
+    pub surcharge_rule: Option<PaymentSurchargeRule>,
+    /// Store cash-change limit (set by OrdersManager from store_info)
+    pub max_cash_change: f64,
 }
 
 impl CommandHandler for AddPaymentAction {
@@ -23,7 +29,7 @@ impl CommandHandler for AddPaymentAction {
         metadata: &CommandMetadata,
     ) -> Result<Vec<OrderEvent>, OrderError> {
         // 1. Validate payment input (finite, positive, within bounds)
-        crate::order_money::validate_payment(&self.payment)?;
+        crab_order_core::money::validate_payment(&self.payment)?;
 
         // 2. Load existing snapshot
         let snapshot = ctx.load_snapshot(self.order_id)?;
@@ -64,27 +70,31 @@ impl CommandHandler for AddPaymentAction {
         // 5. Allocate sequence number
         let seq = ctx.next_sequence();
 
-        // 6. Generate payment_id
-        let payment_id = shared::util::snowflake_id();
-
-        // 7. Validate tendered amount
-        if let Some(t) = self.payment.tendered
-            && to_decimal(t) < to_decimal(self.payment.amount) - MONEY_TOLERANCE
-        {
-            return Err(OrderError::InvalidOperation(
-                CommandErrorCode::InsufficientTender,
-                format!(
-                    "Tendered {:.2} is less than required {:.2}",
-                    t, self.payment.amount
-                ),
-            ));
-        }
+        // 6. Generate payment_id (unless pre-allocated)
+        let payment_id = self.payment_id.unwrap_or_else(shared::util::snowflake_id);
+
+        // 7. Payment-method surcharge on top of the amount (min ticket excludes earlier surcharges)
+        let surcharge = self
+            .surcharge_rule
+            .as_ref()
+            .filter(|rule| rule.payment_method == self.payment.method)
+            .and_then(|rule| {
+                let ticket_total =
+                    to_decimal(snapshot.total) - to_decimal(snapshot.payment_surcharge_amount);
+                rule.compute(self.payment.amount, to_f64(ticket_total))
+            });
+        let amount = match surcharge {
+            Some(s) => to_f64(to_decimal(self.payment.amount) + to_decimal(s)),
+            None => self.payment.amount,
+        };
 
-        // 8. Calculate change for cash payments (using rust_decimal)
-        let change = self.payment.tendered.map(|t| {
-            let diff = to_decimal(t) - to_decimal(self.payment.amount);
-            to_f64(diff.max(Decimal::ZERO))
-        });
+        // 8. Validate tendered amount and compute change due (drawer policy, no change for card)
+        let tender = crab_order_core::money::calculate_change(
+            &self.payment.method,
+            amount,
+            self.payment.tendered,
+            self.max_cash_change,
+        )?;
 
         // 9. Create event
         let event = OrderEvent::new(
@@ -98,10 +108,12 @@ impl CommandHandler for AddPaymentAction {
             EventPayload::PaymentAdded {
                 payment_id,
                 method: self.payment.method.clone(),
-                amount: self.payment.amount,
-                tendered: self.payment.tendered,
-                change,
+                amount,
+                tendered: tender.tendered,
+                change: tender.change,
                 note: self.payment.note.clone(),
+                reference: self.payment.reference.clone(),
+                surcharge,
             },
         );
 
@@ -114,6 +126,7 @@ mod tests {
     use super::*;
     use crate::orders::storage::OrderStorage;
     use crate::orders::traits::CommandContext;
+    use shared::models::DEFAULT_MAX_CASH_CHANGE;
     use shared::order::OrderSnapshot;
 
     fn create_test_metadata() -> CommandMetadata {
@@ -131,6 +144,7 @@ mod tests {
             amount,
             tendered: None,
             note: None,
+            reference: None,
         }
     }
 
@@ -140,6 +154,7 @@ mod tests {
             amount,
             tendered: Some(tendered),
             note: None,
+            reference: None,
         }
     }
 
@@ -161,6 +176,9 @@ mod tests {
         let action = AddPaymentAction {
             order_id: 1001,
             payment: create_payment_input("CARD", 50.0),
+            payment_id: None,
+            surcharge_rule: None,
+            max_cash_change: DEFAULT_MAX_CASH_CHANGE,
         };
 
         let metadata = create_test_metadata();
@@ -178,6 +196,8 @@ mod tests {
             tendered,
             change,
             note,
+            reference,
+            surcharge,
         } = &event.payload
         {
             assert!(*payment_id > 0);
@@ -186,6 +206,8 @@ mod tests {
             assert!(tendered.is_none());
             assert!(change.is_none());
             assert!(note.is_none());
+            assert!(reference.is_none());
+            assert!(surcharge.is_none());
         } else {
             panic!("Expected PaymentAdded payload");
         }
@@ -208,6 +230,9 @@ mod tests {
         let action = AddPaymentAction {
             order_id: 1001,
             payment: create_cash_payment_input(85.0, 100.0),
+            payment_id: None,
+            surcharge_rule: None,
+            max_cash_change: DEFAULT_MAX_CASH_CHANGE,
         };
 
         let metadata = create_test_metadata();
@@ -225,6 +250,81 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_add_card_payment_over_tender_fails() {
+        let storage = OrderStorage::open_in_memory().unwrap();
+
+        let txn = storage.begin_write().unwrap();
+
+        let mut snapshot = OrderSnapshot::new(1001);
+        snapshot.status = OrderStatus::Active;
+        snapshot.total = 85.0;
+        storage.store_snapshot(&txn, &snapshot).unwrap();
+
+        let current_seq = storage.get_next_sequence(&txn).unwrap();
+        let mut ctx = CommandContext::new(&txn, &storage, current_seq);
+
+        let action = AddPaymentAction {
+            order_id: 1001,
+            payment: PaymentInput {
+                method: "CARD".to_string(),
+                amount: 85.0,
+                tendered: Some(100.0),
+                note: None,
+                reference: None,
+            },
+            payment_id: None,
+            surcharge_rule: None,
+            max_cash_change: DEFAULT_MAX_CASH_CHANGE,
+        };
+
+        let metadata = create_test_metadata();
+        let result = action.execute(&mut ctx, &metadata);
+
+        assert!(matches!(
+            result,
+            Err(OrderError::InvalidOperation(
+                CommandErrorCode::ChangeNotAllowed,
+                _
+            ))
+        ));
+    }
+
+    #[test]
+    fn test_add_cash_payment_respects_store_change_limit() {
+        let storage = OrderStorage::open_in_memory().unwrap();
+
+        let txn = storage.begin_write().unwrap();
+
+        let mut snapshot = OrderSnapshot::new(1001);
+        snapshot.status = OrderStatus::Active;
+        snapshot.total = 85.0;
+        storage.store_snapshot(&txn, &snapshot).unwrap();
+
+        let current_seq = storage.get_next_sequence(&txn).unwrap();
+        let mut ctx = CommandContext::new(&txn, &storage, current_seq);
+
+        // 15.00 change is within the default limit but not the store's 10.00
+        let action = AddPaymentAction {
+            order_id: 1001,
+            payment: create_cash_payment_input(85.0, 100.0),
+            payment_id: None,
+            surcharge_rule: None,
+            max_cash_change: 10.0,
+        };
+
+        let metadata = create_test_metadata();
+        let result = action.execute(&mut ctx, &metadata);
+
+        assert!(matches!(
+            result,
+            Err(OrderError::InvalidOperation(
+                CommandErrorCode::ChangeExceedsDrawerLimit,
+                _
+            ))
+        ));
+    }
+
     #[test]
     fn test_add_payment_to_completed_order_fails() {
         let storage = OrderStorage::open_in_memory().unwrap();
@@ -241,6 +341,9 @@ mod tests {
         let action = AddPaymentAction {
             order_id: 1001,
             payment: create_payment_input("CARD", 50.0),
+            payment_id: None,
+            surcharge_rule: None,
+            max_cash_change: DEFAULT_MAX_CASH_CHANGE,
         };
 
         let metadata = create_test_metadata();
@@ -265,6 +368,9 @@ mod tests {
         let action = AddPaymentAction {
             order_id: 1001,
             payment: create_payment_input("CARD", 50.0),
+            payment_id: None,
+            surcharge_rule: None,
+            max_cash_change: DEFAULT_MAX_CASH_CHANGE,
         };
 
         let metadata = create_test_metadata();
@@ -284,6 +390,9 @@ mod tests {
         let action = AddPaymentAction {
             order_id: 9999,
             payment: create_payment_input("CARD", 50.0),
+            payment_id: None,
+            surcharge_rule: None,
+            max_cash_change: DEFAULT_MAX_CASH_CHANGE,
         };
 
         let metadata = create_test_metadata();
@@ -308,6 +417,9 @@ mod tests {
         let action = AddPaymentAction {
             order_id: 1001,
             payment: create_payment_input("CASH", 0.0),
+            payment_id: None,
+            surcharge_rule: None,
+            max_cash_change: DEFAULT_MAX_CASH_CHANGE,
         };
 
         let metadata = create_test_metadata();
@@ -332,6 +444,9 @@ mod tests {
         let action = AddPaymentAction {
             order_id: 1001,
             payment: create_payment_input("CASH", -10.0),
+            payment_id: None,
+            surcharge_rule: None,
+            max_cash_change: DEFAULT_MAX_CASH_CHANGE,
         };
 
         let metadata = create_test_metadata();
@@ -358,6 +473,9 @@ mod tests {
         let action = AddPaymentAction {
             order_id: 1001,
             payment: create_payment_input("CARD", 50.0), // 50 > 40 remaining
+            payment_id: None,
+            surcharge_rule: None,
+            max_cash_change: DEFAULT_MAX_CASH_CHANGE,
         };
 
         let metadata = create_test_metadata();
@@ -387,6 +505,9 @@ mod tests {
         let action = AddPaymentAction {
             order_id: 1001,
             payment: create_payment_input("CARD", 40.0), // Exact remaining
+            payment_id: None,
+            surcharge_rule: None,
+            max_cash_change: DEFAULT_MAX_CASH_CHANGE,
         };
 
         let metadata = create_test_metadata();
@@ -394,6 +515,38 @@ mod tests {
         assert!(result.is_ok());
     }
 
+    #[test]
+    fn test_add_payment_uses_preallocated_payment_id() {
+        let storage = OrderStorage::open_in_memory().unwrap();
+
+        let txn = storage.begin_write().unwrap();
+
+        let mut snapshot = OrderSnapshot::new(1001);
+        snapshot.status = OrderStatus::Active;
+        snapshot.total = 100.0;
+        storage.store_snapshot(&txn, &snapshot).unwrap();
+
+        let current_seq = storage.get_next_sequence(&txn).unwrap();
+        let mut ctx = CommandContext::new(&txn, &storage, current_seq);
+
+        let action = AddPaymentAction {
+            order_id: 1001,
+            payment: create_payment_input("MEMBER_CREDIT", 30.0),
+            payment_id: Some(424242),
+            surcharge_rule: None,
+            max_cash_change: DEFAULT_MAX_CASH_CHANGE,
+        };
+
+        let metadata = create_test_metadata();
+        let events = action.execute(&mut ctx, &metadata).unwrap();
+
+        if let EventPayload::PaymentAdded { payment_id, .. } = &events[0].payload {
+            assert_eq!(*payment_id, 424242);
+        } else {
+            panic!("Expected PaymentAdded payload");
+        }
+    }
+
     #[test]
     fn test_add_payment_with_note() {
         let storage = OrderStorage::open_in_memory().unwrap();
@@ -413,11 +566,15 @@ mod tests {
             amount: 50.0,
             tendered: None,
             note: Some("Visa ending in 1234".to_string()),
+            reference: None,
         };
 
         let action = AddPaymentAction {
             order_id: 1001,
             payment,
+            payment_id: None,
+            surcharge_rule: None,
+            max_cash_change: DEFAULT_MAX_CASH_CHANGE,
         };
 
         let metadata = create_test_metadata();
@@ -429,4 +586,67 @@ mod tests {
             panic!("Expected PaymentAdded payload");
         }
     }
+
+    #[test]
+    fn test_add_payment_applies_surcharge_rule() {
+        use shared::models::AdjustmentType;
+
+        let storage = OrderStorage::open_in_memory().unwrap();
+        let txn = storage.begin_write().unwrap();
+
+        let mut snapshot = OrderSnapshot::new(1001);
+        snapshot.status = OrderStatus::Active;
+        snapshot.total = 40.0;
+        storage.store_snapshot(&txn, &snapshot).unwrap();
+
+        let current_seq = storage.get_next_sequence(&txn).unwrap();
+        let mut ctx = CommandContext::new(&txn, &storage, current_seq);
+
+        let rule = PaymentSurchargeRule {
+            id: 1,
+            payment_method: "CARD".to_string(),
+            adjustment_type: AdjustmentType::Percentage,
+            value: 2.0,
+            min_ticket: Some(30.0),
+            is_active: true,
+            created_at: 0,
+            updated_at: 0,
+        };
+        let metadata = create_test_metadata();
+
+        // 其他支付方式不受影响
+        let cash = AddPaymentAction {
+            order_id: 1001,
+            payment: create_cash_payment_input(10.0, 10.0),
+            payment_id: None,
+            surcharge_rule: Some(rule.clone()),
+            max_cash_change: DEFAULT_MAX_CASH_CHANGE,
+        };
+        let events = cash.execute(&mut ctx, &metadata).unwrap();
+        assert!(matches!(
+            events[0].payload,
+            EventPayload::PaymentAdded {
+                surcharge: None,
+                ..
+            }
+        ));
+
+        let card = AddPaymentAction {
+            order_id: 1001,
+            payment: create_payment_input("CARD", 25.0),
+            payment_id: None,
+            surcharge_rule: Some(rule),
+            max_cash_change: DEFAULT_MAX_CASH_CHANGE,
+        };
+        let events = card.execute(&mut ctx, &metadata).unwrap();
+        if let EventPayload::PaymentAdded {
+            amount, surcharge, ..
+        } = &events[0].payload
+        {
+            assert_eq!(*surcharge, Some(0.5));
+            assert_eq!(*amount, 25.5);
+        } else {
+            panic!("Expected PaymentAdded payload");
+        }
+    }
 }
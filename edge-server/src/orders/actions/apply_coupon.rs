@@ -0,0 +1,217 @@
+//! ApplyCoupon command handler
+//!
+//! 优惠码：折扣作为订单级折扣计入 total，使用次数在订单完成时累加。
+//! 优惠码查找与有效期/次数校验由 OrdersManager 预取，这里只校验订单状态和适用范围。
+
+use crate::orders::traits::{CommandContext, CommandHandler, CommandMetadata, OrderError};
+use crab_order_core::money::{coupon_eligible_base, recalculate_totals, to_decimal};
+use rust_decimal::prelude::*;
+use shared::order::types::CommandErrorCode;
+use shared::order::{AppliedCoupon, EventPayload, OrderEvent, OrderEventType, OrderStatus};
+
+/// ApplyCoupon action — 设置/移除优惠码
+#[derive(Debug, Clone)]
+pub struct ApplyCouponAction {
+    pub order_id: i64,
+    /// None = 移除优惠码
+    pub coupon: Option<AppliedCoupon>,
+}
+
+impl CommandHandler for ApplyCouponAction {
+    fn execute(
+        &self,
+        ctx: &mut CommandContext<'_>,
+        metadata: &CommandMetadata,
+    ) -> Result<Vec<OrderEvent>, OrderError> {
+        let mut snapshot = ctx.load_snapshot(self.order_id)?;
+
+        if !matches!(snapshot.status, OrderStatus::Active) {
+            return Err(OrderError::InvalidOperation(
+                CommandErrorCode::OrderNotActive,
+                "Cannot apply coupon on non-active order".to_string(),
+            ));
+        }
+
+        // Same rule as order-level discounts: total is locked once payments start
+        if to_decimal(snapshot.paid_amount) > Decimal::ZERO {
+            return Err(OrderError::InvalidOperation(
+                CommandErrorCode::HasPayments,
+                "Cannot apply coupon after payments have been made".to_string(),
+            ));
+        }
+
+        match &self.coupon {
+            None => {
+                if snapshot.coupon.is_none() {
+                    return Err(OrderError::InvalidOperation(
+                        CommandErrorCode::NoChangesDetected,
+                        "No coupon to remove".to_string(),
+                    ));
+                }
+            }
+            Some(coupon) => {
+                if snapshot
+                    .coupon
+                    .as_ref()
+                    .is_some_and(|c| c.coupon_id == coupon.coupon_id)
+                {
+                    return Err(OrderError::InvalidOperation(
+                        CommandErrorCode::NoChangesDetected,
+                        format!("Coupon {} is already applied", coupon.code),
+                    ));
+                }
+                if coupon_eligible_base(&snapshot, coupon) <= Decimal::ZERO {
+                    return Err(OrderError::InvalidOperation(
+                        CommandErrorCode::CouponNotApplicable,
+                        format!("Coupon {} does not apply to any item", coupon.code),
+                    ));
+                }
+            }
+        }
+
+        snapshot.coupon = self.coupon.clone();
+        recalculate_totals(&mut snapshot);
+
+        let event = OrderEvent::new(
+            ctx.next_sequence(),
+            self.order_id,
+            metadata.operator_id,
+            metadata.operator_name.clone(),
+            metadata.command_id,
+            Some(metadata.timestamp),
+            OrderEventType::CouponApplied,
+            EventPayload::CouponApplied {
+                coupon: snapshot.coupon.clone(),
+            },
+        );
+
+        Ok(vec![event])
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::orders::storage::OrderStorage;
+    use shared::models::price_rule::{AdjustmentType, ProductScope};
+    use shared::order::{CartItemSnapshot, OrderSnapshot};
+
+    fn metadata() -> CommandMetadata {
+        CommandMetadata {
+            command_id: 1,
+            operator_id: 1,
+            operator_name: "Test User".to_string(),
+            timestamp: 1234567890,
+        }
+    }
+
+    fn order_with_item(order_id: i64, price: f64) -> OrderSnapshot {
+        let mut snapshot = OrderSnapshot::new(order_id);
+        snapshot.status = OrderStatus::Active;
+        snapshot.items.push(CartItemSnapshot {
+            id: 1,
+            instance_id: "item-1".to_string(),
+            name: "Menu".to_string(),
+            price,
+            original_price: price,
+            quantity: 1,
+            unpaid_quantity: 1,
+            selected_options: None,
+            selected_specification: None,
+            manual_discount_percent: None,
+            rule_discount_amount: 0.0,
+            rule_surcharge_amount: 0.0,
+            applied_rules: vec![],
+            applied_mg_rules: vec![],
+            mg_discount_amount: 0.0,
+            unit_price: 0.0,
+            line_total: 0.0,
+            tax: 0.0,
+            tax_rate: 10,
+            note: None,
+            authorizer_id: None,
+            authorizer_name: None,
+            category_id: Some(3),
+            category_name: None,
+            is_comped: false,
+            course: None,
+            is_held: false,
+        });
+        recalculate_totals(&mut snapshot);
+        snapshot
+    }
+
+    fn coupon(product_scope: ProductScope, target_id: Option<i64>) -> AppliedCoupon {
+        AppliedCoupon {
+            coupon_id: 42,
+            code: "FIVEOFF".to_string(),
+            name: "Five off".to_string(),
+            product_scope,
+            target_id,
+            adjustment_type: AdjustmentType::FixedAmount,
+            adjustment_value: 5.0,
+            amount: 0.0,
+        }
+    }
+
+    fn run(
+        snapshot: OrderSnapshot,
+        action: ApplyCouponAction,
+    ) -> Result<Vec<OrderEvent>, OrderError> {
+        let storage = OrderStorage::open_in_memory().unwrap();
+        let txn = storage.begin_write().unwrap();
+        storage.store_snapshot(&txn, &snapshot).unwrap();
+        let mut ctx = CommandContext::new(&txn, &storage, 0);
+        action.execute(&mut ctx, &metadata())
+    }
+
+    #[test]
+    fn test_apply_coupon_emits_event_with_amount() {
+        let events = run(
+            order_with_item(1, 20.0),
+            ApplyCouponAction {
+                order_id: 1,
+                coupon: Some(coupon(ProductScope::Category, Some(3))),
+            },
+        )
+        .unwrap();
+        assert_eq!(events.len(), 1);
+        assert_eq!(events[0].event_type, OrderEventType::CouponApplied);
+        assert!(matches!(
+            &events[0].payload,
+            EventPayload::CouponApplied { coupon: Some(c) } if c.amount == 5.0
+        ));
+    }
+
+    #[test]
+    fn test_coupon_outside_scope_is_rejected() {
+        let err = run(
+            order_with_item(1, 20.0),
+            ApplyCouponAction {
+                order_id: 1,
+                coupon: Some(coupon(ProductScope::Product, Some(99))),
+            },
+        )
+        .unwrap_err();
+        assert!(matches!(
+            err,
+            OrderError::InvalidOperation(CommandErrorCode::CouponNotApplicable, _)
+        ));
+    }
+
+    #[test]
+    fn test_remove_without_coupon_is_rejected() {
+        let err = run(
+            order_with_item(1, 20.0),
+            ApplyCouponAction {
+                order_id: 1,
+                coupon: None,
+            },
+        )
+        .unwrap_err();
+        assert!(matches!(
+            err,
+            OrderError::InvalidOperation(CommandErrorCode::NoChangesDetected, _)
+        ));
+    }
+}
@@ -2,9 +2,9 @@
 //!
 //! 订单级手动折扣和附加费操作。
 
-use crate::order_money::{recalculate_totals, to_decimal, to_f64};
 use crate::orders::traits::{CommandContext, CommandHandler, CommandMetadata, OrderError};
 use crate::utils::validation::{MAX_NAME_LEN, validate_order_optional_text};
+use crab_order_core::money::{recalculate_totals, to_decimal, to_f64};
 use rust_decimal::prelude::*;
 use shared::order::types::CommandErrorCode;
 use shared::order::{EventPayload, OrderEvent, OrderEventType, OrderStatus};
@@ -270,6 +270,8 @@ mod tests {
             category_id: None,
             category_name: None,
             is_comped: false,
+            course: None,
+            is_held: false,
         }
     }
 
@@ -154,6 +154,7 @@ mod tests {
             tendered: None,
             change: None,
             note: None,
+            reference: None,
             timestamp: 1234567800,
             cancelled: false,
             cancel_reason: None,
@@ -454,6 +455,7 @@ mod tests {
             tendered: None,
             change: None,
             note: None,
+            reference: None,
             timestamp: 1234567800,
             cancelled: false,
             cancel_reason: None,
@@ -471,6 +473,7 @@ mod tests {
             tendered: None,
             change: None,
             note: None,
+            reference: None,
             timestamp: 1234567800,
             cancelled: false,
             cancel_reason: None,
@@ -133,6 +133,8 @@ mod tests {
             category_id: None,
             category_name: Some("Drinks".to_string()),
             is_comped: true,
+            course: None,
+            is_held: false,
         }
     }
 
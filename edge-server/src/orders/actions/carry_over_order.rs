@@ -0,0 +1,155 @@
+//! CarryOverOrder command handler
+//!
+//! Issued by the business-day scheduler for orders still open at the cutoff.
+//! Records which business day the order moved into; repeating the same
+//! target date is a no-op so a re-triggered cutoff never duplicates events.
+
+use chrono::NaiveDate;
+
+use crate::orders::traits::{CommandContext, CommandHandler, CommandMetadata, OrderError};
+use shared::models::CarryOverPolicy;
+use shared::order::types::CommandErrorCode;
+use shared::order::{EventPayload, OrderEvent, OrderEventType, OrderStatus};
+
+/// CarryOverOrder action
+#[derive(Debug, Clone)]
+pub struct CarryOverOrderAction {
+    pub order_id: i64,
+    pub from_business_date: String,
+    pub to_business_date: String,
+    pub policy: CarryOverPolicy,
+}
+
+fn parse_business_date(value: &str, field: &str) -> Result<NaiveDate, OrderError> {
+    NaiveDate::parse_from_str(value, "%Y-%m-%d").map_err(|_| {
+        OrderError::InvalidOperation(
+            CommandErrorCode::InvalidOperation,
+            format!("{field} must be a YYYY-MM-DD date"),
+        )
+    })
+}
+
+impl CommandHandler for CarryOverOrderAction {
+    fn execute(
+        &self,
+        ctx: &mut CommandContext<'_>,
+        metadata: &CommandMetadata,
+    ) -> Result<Vec<OrderEvent>, OrderError> {
+        // 1. Validate dates
+        let from = parse_business_date(&self.from_business_date, "from_business_date")?;
+        let to = parse_business_date(&self.to_business_date, "to_business_date")?;
+        if to <= from {
+            return Err(OrderError::InvalidOperation(
+                CommandErrorCode::InvalidOperation,
+                "to_business_date must be after from_business_date".to_string(),
+            ));
+        }
+
+        // 2. Load existing snapshot
+        let snapshot = ctx.load_snapshot(self.order_id)?;
+
+        // 3. Only open orders are carried over
+        match snapshot.status {
+            OrderStatus::Active => {}
+            OrderStatus::Completed => {
+                return Err(OrderError::OrderAlreadyCompleted(self.order_id));
+            }
+            OrderStatus::Void => {
+                return Err(OrderError::OrderAlreadyVoided(self.order_id));
+            }
+            _ => {
+                return Err(OrderError::InvalidOperation(
+                    CommandErrorCode::OrderNotActive,
+                    format!("Cannot carry over order in {:?} status", snapshot.status),
+                ));
+            }
+        }
+
+        // 4. Already carried into this business day → no-op
+        if snapshot.carry_over_date.as_deref() == Some(self.to_business_date.as_str()) {
+            return Ok(vec![]);
+        }
+
+        // 5. Allocate sequence number
+        let seq = ctx.next_sequence();
+
+        // 6. Create event
+        let event = OrderEvent::new(
+            seq,
+            self.order_id,
+            metadata.operator_id,
+            metadata.operator_name.clone(),
+            metadata.command_id,
+            Some(metadata.timestamp),
+            OrderEventType::OrderCarriedOver,
+            EventPayload::OrderCarriedOver {
+                from_business_date: self.from_business_date.clone(),
+                to_business_date: self.to_business_date.clone(),
+                policy: self.policy,
+            },
+        );
+
+        Ok(vec![event])
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::orders::storage::OrderStorage;
+    use shared::order::OrderSnapshot;
+
+    fn create_test_metadata() -> CommandMetadata {
+        CommandMetadata {
+            command_id: 1,
+            operator_id: 0,
+            operator_name: "System".to_string(),
+            timestamp: 1234567890,
+        }
+    }
+
+    fn run(snapshot: &OrderSnapshot, from: &str, to: &str) -> Result<Vec<OrderEvent>, OrderError> {
+        let storage = OrderStorage::open_in_memory().unwrap();
+        let txn = storage.begin_write().unwrap();
+        storage.store_snapshot(&txn, snapshot).unwrap();
+        let current_seq = storage.get_next_sequence(&txn).unwrap();
+        let mut ctx = CommandContext::new(&txn, &storage, current_seq);
+
+        let action = CarryOverOrderAction {
+            order_id: snapshot.order_id,
+            from_business_date: from.to_string(),
+            to_business_date: to.to_string(),
+            policy: CarryOverPolicy::Transfer,
+        };
+        action.execute(&mut ctx, &create_test_metadata())
+    }
+
+    #[test]
+    fn test_carry_over_records_event_once_per_day() {
+        let mut snapshot = OrderSnapshot::new(2001);
+        let events = run(&snapshot, "2024-01-22", "2024-01-23").unwrap();
+        assert_eq!(events.len(), 1);
+        assert_eq!(events[0].event_type, OrderEventType::OrderCarriedOver);
+
+        snapshot.carry_over_date = Some("2024-01-23".to_string());
+        assert!(
+            run(&snapshot, "2024-01-22", "2024-01-23")
+                .unwrap()
+                .is_empty()
+        );
+        assert_eq!(run(&snapshot, "2024-01-23", "2024-01-24").unwrap().len(), 1);
+    }
+
+    #[test]
+    fn test_carry_over_validates_dates_and_status() {
+        let mut snapshot = OrderSnapshot::new(2002);
+        assert!(run(&snapshot, "2024-01-23", "2024-01-23").is_err());
+        assert!(run(&snapshot, "22/01/2024", "2024-01-23").is_err());
+
+        snapshot.status = OrderStatus::Completed;
+        assert!(matches!(
+            run(&snapshot, "2024-01-22", "2024-01-23"),
+            Err(OrderError::OrderAlreadyCompleted(2002))
+        ));
+    }
+}
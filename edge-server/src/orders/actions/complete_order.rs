@@ -5,8 +5,9 @@
 use rust_decimal::prelude::*;
 use std::collections::HashMap;
 
-use crate::order_money::{is_payment_sufficient, to_decimal, to_f64};
 use crate::orders::traits::{CommandContext, CommandHandler, CommandMetadata, OrderError};
+use crab_order_core::money::{is_payment_sufficient, to_decimal, to_f64};
+use shared::models::DeliveryRule;
 use shared::order::types::{CommandErrorCode, ServiceType};
 use shared::order::{EventPayload, OrderEvent, OrderEventType, OrderStatus, PaymentSummaryItem};
 
@@ -16,6 +17,10 @@ pub struct CompleteOrderAction {
     pub order_id: i64,
     /// 服务类型（零售订单结单时确认：堂食/外带）
     pub service_type: Option<ServiceType>,
+    /// 外卖渠道规则 (prefetched from the order's channel/zone metadata)
+    pub delivery_rule: Option<DeliveryRule>,
+    /// 税控码 (prefetched: 外部税控设备已登记)
+    pub fiscal_code: Option<String>,
 }
 
 impl CommandHandler for CompleteOrderAction {
@@ -44,7 +49,21 @@ impl CommandHandler for CompleteOrderAction {
             }
         }
 
-        // 3. Calculate payment summary and total paid using precise decimal arithmetic
+        // 3. Delivery orders must reach the channel's minimum order value
+        if self.service_type != Some(ServiceType::DineIn)
+            && let Some(rule) = self.delivery_rule.as_ref().filter(|r| r.is_active)
+            && let Some(missing) = rule.shortfall(snapshot.subtotal)
+        {
+            return Err(OrderError::InvalidOperation(
+                CommandErrorCode::DeliveryMinimumNotMet,
+                format!(
+                    "Delivery minimum for {} not met: {:.2} more required",
+                    rule.channel, missing
+                ),
+            ));
+        }
+
+        // 3b. Calculate payment summary and total paid using precise decimal arithmetic
         let mut payment_summary_map: HashMap<String, Decimal> = HashMap::new();
         let mut total_paid = Decimal::ZERO;
         for payment in &snapshot.payments {
@@ -95,6 +114,7 @@ impl CommandHandler for CompleteOrderAction {
                 service_type: Some(self.service_type.unwrap_or(ServiceType::DineIn)),
                 final_total: snapshot.total,
                 payment_summary,
+                fiscal_code: self.fiscal_code.clone(),
             },
         );
 
@@ -127,6 +147,7 @@ mod tests {
             tendered: None,
             change: None,
             note: None,
+            reference: None,
             timestamp: 1234567890,
             cancelled: false,
             cancel_reason: None,
@@ -160,6 +181,8 @@ mod tests {
         let action = CompleteOrderAction {
             order_id: 1001,
             service_type: Some(ServiceType::DineIn),
+            delivery_rule: None,
+            fiscal_code: None,
         };
 
         let metadata = create_test_metadata();
@@ -175,6 +198,7 @@ mod tests {
             service_type,
             final_total,
             payment_summary,
+            ..
         } = &event.payload
         {
             assert_eq!(receipt_number, "RCP-001");
@@ -206,6 +230,8 @@ mod tests {
         let action = CompleteOrderAction {
             order_id: 1001,
             service_type: Some(ServiceType::DineIn),
+            delivery_rule: None,
+            fiscal_code: None,
         };
 
         let metadata = create_test_metadata();
@@ -252,6 +278,8 @@ mod tests {
         let action = CompleteOrderAction {
             order_id: 1001,
             service_type: Some(ServiceType::DineIn),
+            delivery_rule: None,
+            fiscal_code: None,
         };
 
         let metadata = create_test_metadata();
@@ -285,6 +313,8 @@ mod tests {
         let action = CompleteOrderAction {
             order_id: 1001,
             service_type: Some(ServiceType::DineIn),
+            delivery_rule: None,
+            fiscal_code: None,
         };
 
         let metadata = create_test_metadata();
@@ -314,6 +344,8 @@ mod tests {
         let action = CompleteOrderAction {
             order_id: 1001,
             service_type: Some(ServiceType::DineIn),
+            delivery_rule: None,
+            fiscal_code: None,
         };
 
         let metadata = create_test_metadata();
@@ -337,6 +369,8 @@ mod tests {
         let action = CompleteOrderAction {
             order_id: 1001,
             service_type: Some(ServiceType::DineIn),
+            delivery_rule: None,
+            fiscal_code: None,
         };
 
         let metadata = create_test_metadata();
@@ -360,6 +394,8 @@ mod tests {
         let action = CompleteOrderAction {
             order_id: 1001,
             service_type: Some(ServiceType::DineIn),
+            delivery_rule: None,
+            fiscal_code: None,
         };
 
         let metadata = create_test_metadata();
@@ -378,6 +414,8 @@ mod tests {
         let action = CompleteOrderAction {
             order_id: 9999,
             service_type: Some(ServiceType::DineIn),
+            delivery_rule: None,
+            fiscal_code: None,
         };
 
         let metadata = create_test_metadata();
@@ -401,6 +439,8 @@ mod tests {
         let action = CompleteOrderAction {
             order_id: 1001,
             service_type: Some(ServiceType::DineIn),
+            delivery_rule: None,
+            fiscal_code: None,
         };
 
         let metadata = create_test_metadata();
@@ -435,6 +475,8 @@ mod tests {
         let action = CompleteOrderAction {
             order_id: 1001,
             service_type: Some(ServiceType::DineIn),
+            delivery_rule: None,
+            fiscal_code: None,
         };
 
         let metadata = create_test_metadata();
@@ -467,6 +509,8 @@ mod tests {
         let action = CompleteOrderAction {
             order_id: 1001,
             service_type: Some(ServiceType::DineIn),
+            delivery_rule: None,
+            fiscal_code: None,
         };
 
         let metadata = create_test_metadata();
@@ -496,6 +540,8 @@ mod tests {
         let action = CompleteOrderAction {
             order_id: 1001,
             service_type: Some(ServiceType::Takeout),
+            delivery_rule: None,
+            fiscal_code: None,
         };
 
         let metadata = create_test_metadata();
@@ -508,4 +554,56 @@ mod tests {
             panic!("Expected OrderCompleted payload");
         }
     }
+
+    #[test]
+    fn test_complete_delivery_order_below_minimum() {
+        let storage = OrderStorage::open_in_memory().unwrap();
+        let txn = storage.begin_write().unwrap();
+
+        let mut snapshot = create_active_snapshot(1001, "RCP-DLV");
+        snapshot.subtotal = 9.0;
+        snapshot.total = 9.0;
+        snapshot.payments.push(create_payment_record("CARD", 9.0));
+        storage.store_snapshot(&txn, &snapshot).unwrap();
+
+        let current_seq = storage.get_next_sequence(&txn).unwrap();
+        let mut ctx = CommandContext::new(&txn, &storage, current_seq);
+
+        let rule = DeliveryRule {
+            id: 1,
+            channel: "glovo".to_string(),
+            zone: None,
+            min_order: Some(12.0),
+            fee_tiers: vec![],
+            is_active: true,
+            created_at: 0,
+            updated_at: 0,
+        };
+        let action = CompleteOrderAction {
+            order_id: 1001,
+            service_type: Some(ServiceType::Takeout),
+            delivery_rule: Some(rule.clone()),
+            fiscal_code: None,
+        };
+        let result = action.execute(&mut ctx, &create_test_metadata());
+        assert!(matches!(
+            result,
+            Err(OrderError::InvalidOperation(
+                CommandErrorCode::DeliveryMinimumNotMet,
+                _
+            ))
+        ));
+
+        // Inactive rule does not block completion
+        let action = CompleteOrderAction {
+            order_id: 1001,
+            service_type: Some(ServiceType::Takeout),
+            delivery_rule: Some(DeliveryRule {
+                is_active: false,
+                ..rule
+            }),
+            fiscal_code: None,
+        };
+        assert!(action.execute(&mut ctx, &create_test_metadata()).is_ok());
+    }
 }
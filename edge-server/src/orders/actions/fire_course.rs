@@ -0,0 +1,182 @@
+//! FireCourse command handler
+//!
+//! Fires a course: every held item of that course is released to the kitchen.
+//! The event carries the fired item snapshots so the kitchen ticket prints
+//! exactly what was fired.
+
+use crate::orders::traits::{CommandContext, CommandHandler, CommandMetadata, OrderError};
+use shared::order::types::CommandErrorCode;
+use shared::order::{EventPayload, OrderEvent, OrderEventType, OrderStatus};
+
+/// FireCourse action
+#[derive(Debug, Clone)]
+pub struct FireCourseAction {
+    pub order_id: i64,
+    pub course: i32,
+}
+
+impl CommandHandler for FireCourseAction {
+    fn execute(
+        &self,
+        ctx: &mut CommandContext<'_>,
+        metadata: &CommandMetadata,
+    ) -> Result<Vec<OrderEvent>, OrderError> {
+        // 1. Load existing snapshot
+        let snapshot = ctx.load_snapshot(self.order_id)?;
+
+        // 2. Validate order status
+        match snapshot.status {
+            OrderStatus::Active => {}
+            OrderStatus::Completed => {
+                return Err(OrderError::OrderAlreadyCompleted(self.order_id));
+            }
+            OrderStatus::Void => {
+                return Err(OrderError::OrderAlreadyVoided(self.order_id));
+            }
+            _ => {
+                return Err(OrderError::InvalidOperation(
+                    CommandErrorCode::OrderNotActive,
+                    format!(
+                        "Cannot fire course on order with status: {:?}",
+                        snapshot.status
+                    ),
+                ));
+            }
+        }
+
+        // 3. Collect held items of the course
+        let items: Vec<_> = snapshot
+            .items
+            .iter()
+            .filter(|i| i.is_held && i.course == Some(self.course))
+            .map(|i| {
+                let mut item = i.clone();
+                item.is_held = false;
+                item
+            })
+            .collect();
+        if items.is_empty() {
+            return Err(OrderError::InvalidOperation(
+                CommandErrorCode::NoHeldItems,
+                format!("Course {} has no held items", self.course),
+            ));
+        }
+
+        // 4. Generate event
+        let seq = ctx.next_sequence();
+        let event = OrderEvent::new(
+            seq,
+            self.order_id,
+            metadata.operator_id,
+            metadata.operator_name.clone(),
+            metadata.command_id,
+            Some(metadata.timestamp),
+            OrderEventType::CourseFired,
+            EventPayload::CourseFired {
+                course: self.course,
+                items,
+            },
+        );
+
+        Ok(vec![event])
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::orders::storage::OrderStorage;
+    use shared::order::{CartItemSnapshot, OrderSnapshot};
+
+    fn create_test_metadata() -> CommandMetadata {
+        CommandMetadata {
+            command_id: 1,
+            operator_id: 1,
+            operator_name: "Test User".to_string(),
+            timestamp: 1234567890,
+        }
+    }
+
+    fn create_test_item(instance_id: &str, course: Option<i32>, is_held: bool) -> CartItemSnapshot {
+        CartItemSnapshot {
+            id: 1,
+            instance_id: instance_id.to_string(),
+            name: "Steak".to_string(),
+            price: 20.0,
+            original_price: 0.0,
+            quantity: 1,
+            unpaid_quantity: 1,
+            selected_options: None,
+            selected_specification: None,
+            manual_discount_percent: None,
+            rule_discount_amount: 0.0,
+            rule_surcharge_amount: 0.0,
+            applied_rules: vec![],
+            applied_mg_rules: vec![],
+            mg_discount_amount: 0.0,
+            unit_price: 0.0,
+            line_total: 0.0,
+            tax: 0.0,
+            tax_rate: 0,
+            note: None,
+            authorizer_id: None,
+            authorizer_name: None,
+            category_id: None,
+            category_name: None,
+            is_comped: false,
+            course,
+            is_held,
+        }
+    }
+
+    fn execute(snapshot: &OrderSnapshot, course: i32) -> Result<Vec<OrderEvent>, OrderError> {
+        let storage = OrderStorage::open_in_memory().unwrap();
+        let txn = storage.begin_write().unwrap();
+        storage.store_snapshot(&txn, snapshot).unwrap();
+        let current_seq = storage.get_next_sequence(&txn).unwrap();
+        let mut ctx = CommandContext::new(&txn, &storage, current_seq);
+        let action = FireCourseAction {
+            order_id: 1001,
+            course,
+        };
+        action.execute(&mut ctx, &create_test_metadata())
+    }
+
+    #[test]
+    fn test_fire_course_releases_only_that_course() {
+        let mut snapshot = OrderSnapshot::new(1001);
+        snapshot.items.push(create_test_item("soup", None, false));
+        snapshot
+            .items
+            .push(create_test_item("steak", Some(2), true));
+        snapshot.items.push(create_test_item("cake", Some(3), true));
+
+        let events = execute(&snapshot, 2).unwrap();
+
+        assert_eq!(events[0].event_type, OrderEventType::CourseFired);
+        let EventPayload::CourseFired { course, items } = &events[0].payload else {
+            panic!("Expected CourseFired payload");
+        };
+        assert_eq!(*course, 2);
+        assert_eq!(items.len(), 1);
+        assert_eq!(items[0].instance_id, "steak");
+        assert!(!items[0].is_held);
+    }
+
+    #[test]
+    fn test_fire_course_without_held_items_fails() {
+        let mut snapshot = OrderSnapshot::new(1001);
+        snapshot
+            .items
+            .push(create_test_item("steak", Some(2), false));
+
+        let result = execute(&snapshot, 2);
+        assert!(matches!(
+            result,
+            Err(OrderError::InvalidOperation(
+                CommandErrorCode::NoHeldItems,
+                _
+            ))
+        ));
+    }
+}
@@ -0,0 +1,230 @@
+//! HoldItems command handler
+//!
+//! Moves held (not yet fired) items into a course. Items are held when they are
+//! added with a course that has not been fired yet; once fired they are already
+//! in the kitchen and can no longer be re-coursed.
+
+use crate::orders::traits::{CommandContext, CommandHandler, CommandMetadata, OrderError};
+use shared::order::types::CommandErrorCode;
+use shared::order::{EventPayload, OrderEvent, OrderEventType, OrderStatus};
+
+/// HoldItems action
+#[derive(Debug, Clone)]
+pub struct HoldItemsAction {
+    pub order_id: i64,
+    pub course: i32,
+    pub instance_ids: Vec<String>,
+}
+
+impl CommandHandler for HoldItemsAction {
+    fn execute(
+        &self,
+        ctx: &mut CommandContext<'_>,
+        metadata: &CommandMetadata,
+    ) -> Result<Vec<OrderEvent>, OrderError> {
+        // 1. Validate input
+        if self.course < 1 {
+            return Err(OrderError::InvalidOperation(
+                CommandErrorCode::InvalidCourse,
+                "course must be >= 1".to_string(),
+            ));
+        }
+        if self.instance_ids.is_empty() {
+            return Err(OrderError::InvalidOperation(
+                CommandErrorCode::EmptyItems,
+                "instance_ids cannot be empty".to_string(),
+            ));
+        }
+
+        // 2. Load existing snapshot
+        let snapshot = ctx.load_snapshot(self.order_id)?;
+
+        // 3. Validate order status
+        match snapshot.status {
+            OrderStatus::Active => {}
+            OrderStatus::Completed => {
+                return Err(OrderError::OrderAlreadyCompleted(self.order_id));
+            }
+            OrderStatus::Void => {
+                return Err(OrderError::OrderAlreadyVoided(self.order_id));
+            }
+            _ => {
+                return Err(OrderError::InvalidOperation(
+                    CommandErrorCode::OrderNotActive,
+                    format!(
+                        "Cannot hold items on order with status: {:?}",
+                        snapshot.status
+                    ),
+                ));
+            }
+        }
+
+        // 4. Every item must exist and still be held
+        let mut instance_ids = Vec::with_capacity(self.instance_ids.len());
+        for instance_id in &self.instance_ids {
+            let item = snapshot
+                .items
+                .iter()
+                .find(|i| i.instance_id == *instance_id)
+                .ok_or_else(|| OrderError::ItemNotFound(instance_id.clone()))?;
+            if !item.is_held {
+                return Err(OrderError::InvalidOperation(
+                    CommandErrorCode::ItemAlreadyFired,
+                    format!("Item {} was already sent to the kitchen", item.name),
+                ));
+            }
+            if !instance_ids.contains(instance_id) {
+                instance_ids.push(instance_id.clone());
+            }
+        }
+
+        // 5. Generate event
+        let seq = ctx.next_sequence();
+        let event = OrderEvent::new(
+            seq,
+            self.order_id,
+            metadata.operator_id,
+            metadata.operator_name.clone(),
+            metadata.command_id,
+            Some(metadata.timestamp),
+            OrderEventType::ItemsHeld,
+            EventPayload::ItemsHeld {
+                course: self.course,
+                instance_ids,
+            },
+        );
+
+        Ok(vec![event])
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::orders::storage::OrderStorage;
+    use shared::order::{CartItemSnapshot, OrderSnapshot};
+
+    fn create_test_metadata() -> CommandMetadata {
+        CommandMetadata {
+            command_id: 1,
+            operator_id: 1,
+            operator_name: "Test User".to_string(),
+            timestamp: 1234567890,
+        }
+    }
+
+    fn create_test_item(instance_id: &str, course: Option<i32>, is_held: bool) -> CartItemSnapshot {
+        CartItemSnapshot {
+            id: 1,
+            instance_id: instance_id.to_string(),
+            name: "Steak".to_string(),
+            price: 20.0,
+            original_price: 0.0,
+            quantity: 1,
+            unpaid_quantity: 1,
+            selected_options: None,
+            selected_specification: None,
+            manual_discount_percent: None,
+            rule_discount_amount: 0.0,
+            rule_surcharge_amount: 0.0,
+            applied_rules: vec![],
+            applied_mg_rules: vec![],
+            mg_discount_amount: 0.0,
+            unit_price: 0.0,
+            line_total: 0.0,
+            tax: 0.0,
+            tax_rate: 0,
+            note: None,
+            authorizer_id: None,
+            authorizer_name: None,
+            category_id: None,
+            category_name: None,
+            is_comped: false,
+            course,
+            is_held,
+        }
+    }
+
+    fn execute(
+        snapshot: &OrderSnapshot,
+        action: HoldItemsAction,
+    ) -> Result<Vec<OrderEvent>, OrderError> {
+        let storage = OrderStorage::open_in_memory().unwrap();
+        let txn = storage.begin_write().unwrap();
+        storage.store_snapshot(&txn, snapshot).unwrap();
+        let current_seq = storage.get_next_sequence(&txn).unwrap();
+        let mut ctx = CommandContext::new(&txn, &storage, current_seq);
+        action.execute(&mut ctx, &create_test_metadata())
+    }
+
+    #[test]
+    fn test_hold_items_moves_held_item_to_course() {
+        let mut snapshot = OrderSnapshot::new(1001);
+        snapshot
+            .items
+            .push(create_test_item("steak", Some(2), true));
+
+        let events = execute(
+            &snapshot,
+            HoldItemsAction {
+                order_id: 1001,
+                course: 3,
+                instance_ids: vec!["steak".to_string(), "steak".to_string()],
+            },
+        )
+        .unwrap();
+
+        assert_eq!(events[0].event_type, OrderEventType::ItemsHeld);
+        let EventPayload::ItemsHeld {
+            course,
+            instance_ids,
+        } = &events[0].payload
+        else {
+            panic!("Expected ItemsHeld payload");
+        };
+        assert_eq!(*course, 3);
+        assert_eq!(instance_ids, &vec!["steak".to_string()]);
+    }
+
+    #[test]
+    fn test_hold_fired_item_fails() {
+        let mut snapshot = OrderSnapshot::new(1001);
+        snapshot.items.push(create_test_item("soup", None, false));
+
+        let result = execute(
+            &snapshot,
+            HoldItemsAction {
+                order_id: 1001,
+                course: 2,
+                instance_ids: vec!["soup".to_string()],
+            },
+        );
+        assert!(matches!(
+            result,
+            Err(OrderError::InvalidOperation(
+                CommandErrorCode::ItemAlreadyFired,
+                _
+            ))
+        ));
+    }
+
+    #[test]
+    fn test_hold_invalid_course_fails() {
+        let snapshot = OrderSnapshot::new(1001);
+        let result = execute(
+            &snapshot,
+            HoldItemsAction {
+                order_id: 1001,
+                course: 0,
+                instance_ids: vec!["steak".to_string()],
+            },
+        );
+        assert!(matches!(
+            result,
+            Err(OrderError::InvalidOperation(
+                CommandErrorCode::InvalidCourse,
+                _
+            ))
+        ));
+    }
+}
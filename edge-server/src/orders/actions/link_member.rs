@@ -251,6 +251,8 @@ mod tests {
             category_id: None,
             category_name: None,
             is_comped: false,
+            course: None,
+            is_held: false,
         });
         storage.store_snapshot(&txn, &snapshot).unwrap();
 
@@ -4,25 +4,33 @@
 //! one specific command type.
 
 use crate::orders::traits::{CommandContext, CommandHandler, CommandMetadata, OrderError};
+use shared::models::DEFAULT_MAX_CASH_CHANGE;
 use shared::order::{OrderCommand, OrderCommandPayload, OrderEvent};
 
 mod add_items;
 mod add_order_note;
 mod add_payment;
+mod apply_coupon;
 mod apply_order_adjustment;
 mod cancel_payment;
 mod cancel_stamp_redemption;
+mod carry_over_order;
 mod comp_item;
 mod complete_order;
+mod fire_course;
+mod hold_items;
 mod link_member;
 mod merge_orders;
 mod modify_item;
 mod move_order;
 pub mod open_table;
+mod redeem_points;
 mod redeem_stamp;
 mod remove_item;
+mod set_order_metadata;
 mod split_order;
 mod toggle_rule_skip;
+mod transfer_items;
 mod uncomp_item;
 mod unlink_member;
 mod update_order_info;
@@ -31,23 +39,30 @@ mod void_order;
 pub use add_items::AddItemsAction;
 pub use add_order_note::AddOrderNoteAction;
 pub use add_payment::AddPaymentAction;
+pub use apply_coupon::ApplyCouponAction;
 pub use apply_order_adjustment::{ApplyOrderDiscountAction, ApplyOrderSurchargeAction};
 pub use cancel_payment::CancelPaymentAction;
 pub use cancel_stamp_redemption::CancelStampRedemptionAction;
 pub use comp_item::CompItemAction;
 pub use complete_order::CompleteOrderAction;
+pub use fire_course::FireCourseAction;
+pub use hold_items::HoldItemsAction;
 pub use link_member::LinkMemberAction;
 pub use merge_orders::MergeOrdersAction;
 pub use modify_item::ModifyItemAction;
 pub use move_order::MoveOrderAction;
 pub use open_table::OpenTableAction;
+pub use redeem_points::RedeemPointsAction;
 pub use redeem_stamp::{RedeemStampAction, RewardProductInfo};
 
+pub use carry_over_order::CarryOverOrderAction;
 pub use remove_item::RemoveItemAction;
+pub use set_order_metadata::SetOrderMetadataAction;
 pub use split_order::{
     PayAaSplitAction, SplitByAmountAction, SplitByItemsAction, StartAaSplitAction,
 };
 pub use toggle_rule_skip::ToggleRuleSkipAction;
+pub use transfer_items::TransferItemsAction;
 pub use uncomp_item::UncompItemAction;
 pub use unlink_member::UnlinkMemberAction;
 pub use update_order_info::UpdateOrderInfoAction;
@@ -68,6 +83,7 @@ pub enum CommandAction {
     VoidOrder(VoidOrderAction),
     MoveOrder(MoveOrderAction),
     MergeOrders(MergeOrdersAction),
+    TransferItems(TransferItemsAction),
     SplitByItems(SplitByItemsAction),
     SplitByAmount(SplitByAmountAction),
     StartAaSplit(StartAaSplitAction),
@@ -75,11 +91,17 @@ pub enum CommandAction {
     ToggleRuleSkip(ToggleRuleSkipAction),
     ApplyOrderDiscount(ApplyOrderDiscountAction),
     ApplyOrderSurcharge(ApplyOrderSurchargeAction),
+    HoldItems(HoldItemsAction),
+    FireCourse(FireCourseAction),
     AddOrderNote(AddOrderNoteAction),
+    SetOrderMetadata(SetOrderMetadataAction),
+    CarryOverOrder(CarryOverOrderAction),
     LinkMember(LinkMemberAction),
     UnlinkMember(UnlinkMemberAction),
     RedeemStamp(RedeemStampAction),
     CancelStampRedemption(CancelStampRedemptionAction),
+    RedeemPoints(RedeemPointsAction),
+    ApplyCoupon(ApplyCouponAction),
 }
 
 /// Manual implementation of CommandHandler for CommandAction
@@ -103,6 +125,7 @@ impl CommandHandler for CommandAction {
             CommandAction::VoidOrder(action) => action.execute(ctx, metadata),
             CommandAction::MoveOrder(action) => action.execute(ctx, metadata),
             CommandAction::MergeOrders(action) => action.execute(ctx, metadata),
+            CommandAction::TransferItems(action) => action.execute(ctx, metadata),
             CommandAction::SplitByItems(action) => action.execute(ctx, metadata),
             CommandAction::SplitByAmount(action) => action.execute(ctx, metadata),
             CommandAction::StartAaSplit(action) => action.execute(ctx, metadata),
@@ -110,11 +133,17 @@ impl CommandHandler for CommandAction {
             CommandAction::ToggleRuleSkip(action) => action.execute(ctx, metadata),
             CommandAction::ApplyOrderDiscount(action) => action.execute(ctx, metadata),
             CommandAction::ApplyOrderSurcharge(action) => action.execute(ctx, metadata),
+            CommandAction::HoldItems(action) => action.execute(ctx, metadata),
+            CommandAction::FireCourse(action) => action.execute(ctx, metadata),
             CommandAction::AddOrderNote(action) => action.execute(ctx, metadata),
+            CommandAction::SetOrderMetadata(action) => action.execute(ctx, metadata),
+            CommandAction::CarryOverOrder(action) => action.execute(ctx, metadata),
             CommandAction::LinkMember(action) => action.execute(ctx, metadata),
             CommandAction::UnlinkMember(action) => action.execute(ctx, metadata),
             CommandAction::RedeemStamp(action) => action.execute(ctx, metadata),
             CommandAction::CancelStampRedemption(action) => action.execute(ctx, metadata),
+            CommandAction::RedeemPoints(action) => action.execute(ctx, metadata),
+            CommandAction::ApplyCoupon(action) => action.execute(ctx, metadata),
         }
     }
 }
@@ -155,6 +184,9 @@ impl From<&OrderCommand> for CommandAction {
                 CommandAction::AddPayment(AddPaymentAction {
                     order_id: *order_id,
                     payment: payment.clone(),
+                    payment_id: None,
+                    surcharge_rule: None,
+                    max_cash_change: DEFAULT_MAX_CASH_CHANGE,
                 })
             }
             OrderCommandPayload::CancelPayment {
@@ -191,6 +223,8 @@ impl From<&OrderCommand> for CommandAction {
             } => CommandAction::CompleteOrder(CompleteOrderAction {
                 order_id: *order_id,
                 service_type: *service_type,
+                delivery_rule: None,
+                fiscal_code: None,
             }),
             OrderCommandPayload::VoidOrder {
                 order_id,
@@ -248,6 +282,13 @@ impl From<&OrderCommand> for CommandAction {
                 authorizer_id: *authorizer_id,
                 authorizer_name: authorizer_name.clone(),
             }),
+            OrderCommandPayload::TransferItems { .. } => {
+                // TransferItems requires data injection (target MG rules)
+                // Handled specially in OrdersManager, not via From<&OrderCommand>
+                unreachable!(
+                    "TransferItems should be handled by OrdersManager, not From<&OrderCommand>"
+                )
+            }
             OrderCommandPayload::SplitByItems {
                 order_id,
                 payment_method,
@@ -258,6 +299,7 @@ impl From<&OrderCommand> for CommandAction {
                 payment_method: payment_method.clone(),
                 items: items.clone(),
                 tendered: *tendered,
+                max_cash_change: DEFAULT_MAX_CASH_CHANGE,
             }),
             OrderCommandPayload::SplitByAmount {
                 order_id,
@@ -269,6 +311,7 @@ impl From<&OrderCommand> for CommandAction {
                 split_amount: *split_amount,
                 payment_method: payment_method.clone(),
                 tendered: *tendered,
+                max_cash_change: DEFAULT_MAX_CASH_CHANGE,
             }),
             OrderCommandPayload::StartAaSplit {
                 order_id,
@@ -282,6 +325,7 @@ impl From<&OrderCommand> for CommandAction {
                 shares: *shares,
                 payment_method: payment_method.clone(),
                 tendered: *tendered,
+                max_cash_change: DEFAULT_MAX_CASH_CHANGE,
             }),
             OrderCommandPayload::PayAaSplit {
                 order_id,
@@ -293,6 +337,7 @@ impl From<&OrderCommand> for CommandAction {
                 shares: *shares,
                 payment_method: payment_method.clone(),
                 tendered: *tendered,
+                max_cash_change: DEFAULT_MAX_CASH_CHANGE,
             }),
             OrderCommandPayload::CompItem {
                 order_id,
@@ -355,12 +400,44 @@ impl From<&OrderCommand> for CommandAction {
                 authorizer_id: *authorizer_id,
                 authorizer_name: authorizer_name.clone(),
             }),
+            OrderCommandPayload::HoldItems {
+                order_id,
+                course,
+                instance_ids,
+            } => CommandAction::HoldItems(HoldItemsAction {
+                order_id: *order_id,
+                course: *course,
+                instance_ids: instance_ids.clone(),
+            }),
+            OrderCommandPayload::FireCourse { order_id, course } => {
+                CommandAction::FireCourse(FireCourseAction {
+                    order_id: *order_id,
+                    course: *course,
+                })
+            }
             OrderCommandPayload::AddOrderNote { order_id, note } => {
                 CommandAction::AddOrderNote(AddOrderNoteAction {
                     order_id: *order_id,
                     note: note.clone(),
                 })
             }
+            OrderCommandPayload::SetOrderMetadata { order_id, metadata } => {
+                CommandAction::SetOrderMetadata(SetOrderMetadataAction {
+                    order_id: *order_id,
+                    metadata: metadata.clone(),
+                })
+            }
+            OrderCommandPayload::CarryOverOrder {
+                order_id,
+                from_business_date,
+                to_business_date,
+                policy,
+            } => CommandAction::CarryOverOrder(CarryOverOrderAction {
+                order_id: *order_id,
+                from_business_date: from_business_date.clone(),
+                to_business_date: to_business_date.clone(),
+                policy: *policy,
+            }),
             OrderCommandPayload::LinkMember { .. } => {
                 // LinkMember requires data injection (member info, MG rules)
                 // Handled specially in OrdersManager, not via From<&OrderCommand>
@@ -387,6 +464,20 @@ impl From<&OrderCommand> for CommandAction {
                 order_id: *order_id,
                 stamp_activity_id: *stamp_activity_id,
             }),
+            OrderCommandPayload::RedeemPoints { .. } => {
+                // RedeemPoints requires data injection (member balance, group burn rule)
+                // Handled specially in OrdersManager, not via From<&OrderCommand>
+                unreachable!(
+                    "RedeemPoints should be handled by OrdersManager, not From<&OrderCommand>"
+                )
+            }
+            OrderCommandPayload::ApplyCoupon { .. } => {
+                // ApplyCoupon requires data injection (coupon lookup by code)
+                // Handled specially in OrdersManager, not via From<&OrderCommand>
+                unreachable!(
+                    "ApplyCoupon should be handled by OrdersManager, not From<&OrderCommand>"
+                )
+            }
         }
     }
 }
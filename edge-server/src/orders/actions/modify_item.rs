@@ -34,7 +34,7 @@ impl CommandHandler for ModifyItemAction {
     ) -> Result<Vec<OrderEvent>, OrderError> {
         // 1. Validate text lengths + changes
         validate_order_optional_text(&self.authorizer_name, "authorizer_name", MAX_NAME_LEN)?;
-        crate::order_money::validate_item_changes(&self.changes)?;
+        crab_order_core::money::validate_item_changes(&self.changes)?;
 
         // 2. Load existing snapshot
         let snapshot = ctx.load_snapshot(self.order_id)?;
@@ -390,6 +390,8 @@ mod tests {
             category_id: None,
             category_name: None,
             is_comped: false,
+            course: None,
+            is_held: false,
         }
     }
 
@@ -5,9 +5,9 @@
 
 use rust_decimal::Decimal;
 
-use crate::order_money::to_decimal;
 use crate::orders::traits::{CommandContext, CommandHandler, CommandMetadata, OrderError};
 use crate::utils::validation::{MAX_NAME_LEN, validate_order_optional_text, validate_order_text};
+use crab_order_core::money::to_decimal;
 use shared::order::types::CommandErrorCode;
 use shared::order::{EventPayload, OrderEvent, OrderEventType, OrderStatus};
 
@@ -213,6 +213,8 @@ mod tests {
             category_id: None,
             category_name: None,
             is_comped: false,
+            course: None,
+            is_held: false,
         };
         snapshot.items.push(item);
         storage.store_snapshot(&txn, &snapshot).unwrap();
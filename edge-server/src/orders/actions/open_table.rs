@@ -7,8 +7,9 @@ use tracing::debug;
 
 use crate::db::repository::price_rule;
 use crate::orders::traits::{CommandContext, CommandHandler, CommandMetadata, OrderError};
+use crate::services::CatalogService;
 use crate::utils::validation::{MAX_NAME_LEN, validate_order_optional_text};
-use shared::models::PriceRule;
+use shared::models::{PriceRule, TaxMode};
 use shared::order::{EventPayload, OrderEvent, OrderEventType, OrderStatus};
 
 /// 加载匹配区域的价格规则（静态缓存）
@@ -69,6 +70,31 @@ pub async fn load_matching_rules(
     rules
 }
 
+/// 加载订单规则快照：区域匹配的价格规则 + 区域商品覆盖价（合成规则）
+///
+/// 开台 / 移桌 / 启动回退加载时调用，结果写入规则缓存。
+pub async fn load_order_rules(
+    pool: &SqlitePool,
+    catalog: &CatalogService,
+    zone_id: Option<i64>,
+    is_retail: bool,
+) -> Vec<PriceRule> {
+    let mut rules = load_matching_rules(pool, zone_id, is_retail).await;
+    if let Some(zone_id) = zone_id {
+        let overrides = catalog.zone_price_rules(zone_id);
+        if !overrides.is_empty() {
+            debug!(
+                target: "pricing",
+                zone_id,
+                override_count = overrides.len(),
+                "Zone price overrides added to rule snapshot"
+            );
+        }
+        rules.extend(overrides);
+    }
+    rules
+}
+
 /// OpenTable action
 #[derive(Debug, Clone)]
 pub struct OpenTableAction {
@@ -82,6 +108,8 @@ pub struct OpenTableAction {
     pub queue_number: Option<u32>,
     /// Server-generated receipt number
     pub receipt_number: String,
+    /// 门店定价含税模式（开台定格）
+    pub tax_mode: TaxMode,
 }
 
 impl CommandHandler for OpenTableAction {
@@ -129,6 +157,7 @@ impl CommandHandler for OpenTableAction {
         snapshot.is_retail = self.is_retail;
         snapshot.queue_number = self.queue_number;
         snapshot.receipt_number = self.receipt_number.clone();
+        snapshot.tax_mode = self.tax_mode;
         snapshot.status = OrderStatus::Active;
         snapshot.start_time = metadata.timestamp;
         snapshot.created_at = metadata.timestamp;
@@ -159,6 +188,7 @@ impl CommandHandler for OpenTableAction {
                 is_retail: self.is_retail,
                 queue_number: self.queue_number,
                 receipt_number: self.receipt_number.clone(),
+                tax_mode: self.tax_mode,
             },
         );
 
@@ -205,6 +235,7 @@ mod tests {
             is_retail: false,
             queue_number: None,
             receipt_number: "FAC2026012410001".to_string(),
+            tax_mode: TaxMode::Inclusive,
         };
 
         let metadata = create_test_metadata();
@@ -240,6 +271,7 @@ mod tests {
             is_retail: false,
             queue_number: None,
             receipt_number: "FAC2026012410002".to_string(),
+            tax_mode: TaxMode::Inclusive,
         };
 
         let metadata = create_test_metadata();
@@ -266,6 +298,7 @@ mod tests {
             is_retail: true,
             queue_number: Some(42),
             receipt_number: "FAC2026012410003".to_string(),
+            tax_mode: TaxMode::Inclusive,
         };
 
         let metadata = create_test_metadata();
@@ -0,0 +1,243 @@
+//! RedeemPoints command handler
+//!
+//! 积分抵扣：兑换值作为订单级折扣计入 total，积分在订单完成时扣减。
+//! 兑换规则和余额由 OrdersManager 预取并校验，这里只校验订单状态和金额上限。
+
+use crate::orders::traits::{CommandContext, CommandHandler, CommandMetadata, OrderError};
+use crab_order_core::money::{MONEY_TOLERANCE, recalculate_totals, to_decimal};
+use rust_decimal::prelude::*;
+use shared::order::types::CommandErrorCode;
+use shared::order::{EventPayload, OrderEvent, OrderEventType, OrderStatus, PointsRedemption};
+
+/// RedeemPoints action — 设置/清除积分抵扣
+#[derive(Debug, Clone)]
+pub struct RedeemPointsAction {
+    pub order_id: i64,
+    /// 0 = 清除兑换
+    pub points: i64,
+    /// 兑换值 (由营销组规则计算)
+    pub amount: f64,
+}
+
+impl CommandHandler for RedeemPointsAction {
+    fn execute(
+        &self,
+        ctx: &mut CommandContext<'_>,
+        metadata: &CommandMetadata,
+    ) -> Result<Vec<OrderEvent>, OrderError> {
+        let mut snapshot = ctx.load_snapshot(self.order_id)?;
+
+        if !matches!(snapshot.status, OrderStatus::Active) {
+            return Err(OrderError::InvalidOperation(
+                CommandErrorCode::OrderNotActive,
+                "Cannot redeem points on non-active order".to_string(),
+            ));
+        }
+
+        if self.points < 0 || !self.amount.is_finite() || self.amount < 0.0 {
+            return Err(OrderError::InvalidOperation(
+                CommandErrorCode::InvalidAmount,
+                format!("Invalid points redemption: {} points", self.points),
+            ));
+        }
+
+        // Same rule as order-level discounts: total is locked once payments start
+        if to_decimal(snapshot.paid_amount) > Decimal::ZERO {
+            return Err(OrderError::InvalidOperation(
+                CommandErrorCode::HasPayments,
+                "Cannot redeem points after payments have been made".to_string(),
+            ));
+        }
+
+        if self.points == 0 {
+            if snapshot.points_redemption.is_none() {
+                return Err(OrderError::InvalidOperation(
+                    CommandErrorCode::NoChangesDetected,
+                    "No points redemption to clear".to_string(),
+                ));
+            }
+            snapshot.points_redemption = None;
+        } else {
+            if snapshot.member_id.is_none() {
+                return Err(OrderError::InvalidOperation(
+                    CommandErrorCode::MemberRequired,
+                    "Must have a member linked to redeem points".to_string(),
+                ));
+            }
+
+            // Redemption may not exceed what the order is worth without it
+            let current = snapshot
+                .points_redemption
+                .as_ref()
+                .map_or(0.0, |r| r.amount);
+            let payable = to_decimal(snapshot.total) + to_decimal(current);
+            if to_decimal(self.amount) > payable + MONEY_TOLERANCE {
+                return Err(OrderError::InvalidOperation(
+                    CommandErrorCode::InvalidAmount,
+                    format!(
+                        "Points value {:.2} exceeds order total {:.2}",
+                        self.amount, payable
+                    ),
+                ));
+            }
+            snapshot.points_redemption = Some(PointsRedemption {
+                points: self.points,
+                amount: self.amount,
+            });
+        }
+
+        recalculate_totals(&mut snapshot);
+
+        let event = OrderEvent::new(
+            ctx.next_sequence(),
+            self.order_id,
+            metadata.operator_id,
+            metadata.operator_name.clone(),
+            metadata.command_id,
+            Some(metadata.timestamp),
+            OrderEventType::PointsRedeemed,
+            EventPayload::PointsRedeemed {
+                points: self.points,
+                amount: self.amount,
+            },
+        );
+
+        Ok(vec![event])
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::orders::storage::OrderStorage;
+    use shared::order::{CartItemSnapshot, OrderSnapshot};
+
+    fn metadata() -> CommandMetadata {
+        CommandMetadata {
+            command_id: 1,
+            operator_id: 1,
+            operator_name: "Test User".to_string(),
+            timestamp: 1234567890,
+        }
+    }
+
+    fn order_with_member(order_id: i64, price: f64) -> OrderSnapshot {
+        let mut snapshot = OrderSnapshot::new(order_id);
+        snapshot.status = OrderStatus::Active;
+        snapshot.member_id = Some(7);
+        snapshot.items.push(CartItemSnapshot {
+            id: 1,
+            instance_id: "item-1".to_string(),
+            name: "Menu".to_string(),
+            price,
+            original_price: price,
+            quantity: 1,
+            unpaid_quantity: 1,
+            selected_options: None,
+            selected_specification: None,
+            manual_discount_percent: None,
+            rule_discount_amount: 0.0,
+            rule_surcharge_amount: 0.0,
+            applied_rules: vec![],
+            applied_mg_rules: vec![],
+            mg_discount_amount: 0.0,
+            unit_price: 0.0,
+            line_total: 0.0,
+            tax: 0.0,
+            tax_rate: 10,
+            note: None,
+            authorizer_id: None,
+            authorizer_name: None,
+            category_id: None,
+            category_name: None,
+            is_comped: false,
+            course: None,
+            is_held: false,
+        });
+        recalculate_totals(&mut snapshot);
+        snapshot
+    }
+
+    fn run(
+        snapshot: OrderSnapshot,
+        action: RedeemPointsAction,
+    ) -> Result<Vec<OrderEvent>, OrderError> {
+        let storage = OrderStorage::open_in_memory().unwrap();
+        let txn = storage.begin_write().unwrap();
+        storage.store_snapshot(&txn, &snapshot).unwrap();
+        let mut ctx = CommandContext::new(&txn, &storage, 0);
+        action.execute(&mut ctx, &metadata())
+    }
+
+    #[test]
+    fn test_redeem_points_emits_event() {
+        let events = run(
+            order_with_member(1, 20.0),
+            RedeemPointsAction {
+                order_id: 1,
+                points: 100,
+                amount: 5.0,
+            },
+        )
+        .unwrap();
+        assert_eq!(events.len(), 1);
+        assert_eq!(events[0].event_type, OrderEventType::PointsRedeemed);
+        assert!(matches!(
+            events[0].payload,
+            EventPayload::PointsRedeemed {
+                points: 100,
+                amount
+            } if amount == 5.0
+        ));
+    }
+
+    #[test]
+    fn test_redeem_points_requires_member_and_caps_at_total() {
+        let mut anonymous = order_with_member(1, 20.0);
+        anonymous.member_id = None;
+        let err = run(
+            anonymous,
+            RedeemPointsAction {
+                order_id: 1,
+                points: 100,
+                amount: 5.0,
+            },
+        )
+        .unwrap_err();
+        assert!(matches!(
+            err,
+            OrderError::InvalidOperation(CommandErrorCode::MemberRequired, _)
+        ));
+
+        let err = run(
+            order_with_member(1, 4.0),
+            RedeemPointsAction {
+                order_id: 1,
+                points: 100,
+                amount: 5.0,
+            },
+        )
+        .unwrap_err();
+        assert!(matches!(
+            err,
+            OrderError::InvalidOperation(CommandErrorCode::InvalidAmount, _)
+        ));
+    }
+
+    #[test]
+    fn test_clear_without_redemption_is_rejected() {
+        let err = run(
+            order_with_member(1, 20.0),
+            RedeemPointsAction {
+                order_id: 1,
+                points: 0,
+                amount: 0.0,
+            },
+        )
+        .unwrap_err();
+        assert!(matches!(
+            err,
+            OrderError::InvalidOperation(CommandErrorCode::NoChangesDetected, _)
+        ));
+    }
+}
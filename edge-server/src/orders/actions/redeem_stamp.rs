@@ -310,6 +310,12 @@ mod tests {
             designated_product_id: None,
             is_cyclic: true,
             is_active: true,
+            valid_from: None,
+            valid_until: None,
+            active_days: None,
+            active_start_time: None,
+            active_end_time: None,
+            max_stamps_per_day: None,
             created_at: 0,
             updated_at: 0,
         }
@@ -347,6 +353,8 @@ mod tests {
             category_id,
             category_name: category_id.map(|id| format!("Cat-{}", id)),
             is_comped: false,
+            course: None,
+            is_held: false,
         }
     }
 
@@ -0,0 +1,226 @@
+//! SetOrderMetadata command handler
+//!
+//! Merges integrator key-value references (delivery platform order id,
+//! hotel room number, ...) into the order metadata.
+//! Empty value removes the key. Unchanged keys produce no event entry.
+
+use std::collections::BTreeMap;
+
+use crate::orders::traits::{CommandContext, CommandHandler, CommandMetadata, OrderError};
+use crate::utils::validation::validate_order_text;
+use shared::order::types::CommandErrorCode;
+use shared::order::{EventPayload, OrderEvent, OrderEventType, OrderStatus};
+
+/// 单个订单最多元数据条目数
+pub const MAX_METADATA_ENTRIES: usize = 20;
+/// 键最大长度
+pub const MAX_METADATA_KEY_LEN: usize = 40;
+/// 值最大长度
+pub const MAX_METADATA_VALUE_LEN: usize = 200;
+
+/// Key format: lowercase ASCII letter first, then `[a-z0-9_.-]`
+fn validate_key(key: &str) -> Result<(), OrderError> {
+    let mut chars = key.chars();
+    let valid = key.len() <= MAX_METADATA_KEY_LEN
+        && chars.next().is_some_and(|c| c.is_ascii_lowercase())
+        && chars.all(|c| c.is_ascii_lowercase() || c.is_ascii_digit() || "_.-".contains(c));
+    if !valid {
+        return Err(OrderError::InvalidOperation(
+            CommandErrorCode::InvalidOperation,
+            format!(
+                "Invalid metadata key '{key}' (lowercase letters, digits, '_', '.', '-'; max {MAX_METADATA_KEY_LEN} chars)"
+            ),
+        ));
+    }
+    Ok(())
+}
+
+/// SetOrderMetadata action
+#[derive(Debug, Clone)]
+pub struct SetOrderMetadataAction {
+    pub order_id: i64,
+    pub metadata: BTreeMap<String, String>,
+}
+
+impl CommandHandler for SetOrderMetadataAction {
+    fn execute(
+        &self,
+        ctx: &mut CommandContext<'_>,
+        metadata: &CommandMetadata,
+    ) -> Result<Vec<OrderEvent>, OrderError> {
+        // 1. Validate keys and values
+        if self.metadata.is_empty() {
+            return Err(OrderError::InvalidOperation(
+                CommandErrorCode::InvalidOperation,
+                "metadata must not be empty".to_string(),
+            ));
+        }
+        for (key, value) in &self.metadata {
+            validate_key(key)?;
+            validate_order_text(value, key, MAX_METADATA_VALUE_LEN)?;
+        }
+
+        // 2. Load existing snapshot
+        let snapshot = ctx.load_snapshot(self.order_id)?;
+
+        // 3. Validate order status - must be Active
+        match snapshot.status {
+            OrderStatus::Active => {}
+            OrderStatus::Completed => {
+                return Err(OrderError::OrderAlreadyCompleted(self.order_id));
+            }
+            OrderStatus::Void => {
+                return Err(OrderError::OrderAlreadyVoided(self.order_id));
+            }
+            _ => {
+                return Err(OrderError::InvalidOperation(
+                    CommandErrorCode::OrderNotActive,
+                    format!(
+                        "Cannot set metadata on order with status: {:?}",
+                        snapshot.status
+                    ),
+                ));
+            }
+        }
+
+        // 4. Diff against current metadata (only changed keys are recorded)
+        let mut changes = BTreeMap::new();
+        let mut previous = BTreeMap::new();
+        for (key, value) in &self.metadata {
+            let current = snapshot.metadata.get(key);
+            if current.map(String::as_str).unwrap_or("") == value.as_str() {
+                continue;
+            }
+            if let Some(current) = current {
+                previous.insert(key.clone(), current.clone());
+            }
+            changes.insert(key.clone(), value.clone());
+        }
+        if changes.is_empty() {
+            return Ok(vec![]);
+        }
+
+        // 5. Entry limit after merge
+        let added = changes
+            .iter()
+            .filter(|(k, v)| !v.is_empty() && !snapshot.metadata.contains_key(*k))
+            .count();
+        let removed = changes.values().filter(|v| v.is_empty()).count();
+        if snapshot.metadata.len() + added - removed > MAX_METADATA_ENTRIES {
+            return Err(OrderError::InvalidOperation(
+                CommandErrorCode::InvalidOperation,
+                format!("Too many metadata entries (max {MAX_METADATA_ENTRIES})"),
+            ));
+        }
+
+        // 6. Allocate sequence number
+        let seq = ctx.next_sequence();
+
+        // 7. Create event
+        let event = OrderEvent::new(
+            seq,
+            self.order_id,
+            metadata.operator_id,
+            metadata.operator_name.clone(),
+            metadata.command_id,
+            Some(metadata.timestamp),
+            OrderEventType::OrderMetadataSet,
+            EventPayload::OrderMetadataSet { changes, previous },
+        );
+
+        Ok(vec![event])
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::orders::storage::OrderStorage;
+    use shared::order::OrderSnapshot;
+
+    fn create_test_metadata() -> CommandMetadata {
+        CommandMetadata {
+            command_id: 1,
+            operator_id: 1,
+            operator_name: "Test User".to_string(),
+            timestamp: 1234567890,
+        }
+    }
+
+    fn run(
+        snapshot: &OrderSnapshot,
+        entries: &[(&str, &str)],
+    ) -> Result<Vec<OrderEvent>, OrderError> {
+        let storage = OrderStorage::open_in_memory().unwrap();
+        let txn = storage.begin_write().unwrap();
+        storage.store_snapshot(&txn, snapshot).unwrap();
+        let current_seq = storage.get_next_sequence(&txn).unwrap();
+        let mut ctx = CommandContext::new(&txn, &storage, current_seq);
+
+        let action = SetOrderMetadataAction {
+            order_id: snapshot.order_id,
+            metadata: entries
+                .iter()
+                .map(|(k, v)| (k.to_string(), v.to_string()))
+                .collect(),
+        };
+        action.execute(&mut ctx, &create_test_metadata())
+    }
+
+    #[test]
+    fn test_set_metadata_records_only_changes() {
+        let mut snapshot = OrderSnapshot::new(1001);
+        snapshot
+            .metadata
+            .insert("room".to_string(), "204".to_string());
+        snapshot
+            .metadata
+            .insert("platform".to_string(), "glovo".to_string());
+
+        let events = run(
+            &snapshot,
+            &[
+                ("room", ""),
+                ("platform", "glovo"),
+                ("platform.order_id", "GLV-9"),
+            ],
+        )
+        .unwrap();
+
+        assert_eq!(events.len(), 1);
+        assert_eq!(events[0].event_type, OrderEventType::OrderMetadataSet);
+        if let EventPayload::OrderMetadataSet { changes, previous } = &events[0].payload {
+            assert_eq!(changes.len(), 2);
+            assert_eq!(changes["room"], "");
+            assert_eq!(changes["platform.order_id"], "GLV-9");
+            assert_eq!(previous.len(), 1);
+            assert_eq!(previous["room"], "204");
+        } else {
+            panic!("Expected OrderMetadataSet payload");
+        }
+
+        // No-op when nothing changes
+        assert!(run(&snapshot, &[("room", "204")]).unwrap().is_empty());
+    }
+
+    #[test]
+    fn test_set_metadata_rejects_invalid_keys() {
+        let snapshot = OrderSnapshot::new(1002);
+        assert!(run(&snapshot, &[("Room", "1")]).is_err());
+        assert!(run(&snapshot, &[("1room", "1")]).is_err());
+        assert!(run(&snapshot, &[("room no", "1")]).is_err());
+        let too_long = "x".repeat(MAX_METADATA_VALUE_LEN + 1);
+        assert!(run(&snapshot, &[("room", too_long.as_str())]).is_err());
+        assert!(run(&snapshot, &[]).is_err());
+    }
+
+    #[test]
+    fn test_set_metadata_on_completed_order_fails() {
+        let mut snapshot = OrderSnapshot::new(1003);
+        snapshot.status = OrderStatus::Completed;
+        assert!(matches!(
+            run(&snapshot, &[("room", "204")]),
+            Err(OrderError::OrderAlreadyCompleted(1003))
+        ));
+    }
+}
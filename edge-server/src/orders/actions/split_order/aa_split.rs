@@ -3,15 +3,13 @@
 //! - **StartAaSplit**: lock headcount + pay first share(s)
 //! - **PayAaSplit**: pay additional shares in an existing AA split
 
-use crate::order_money::{to_decimal, to_f64};
 use crate::orders::traits::{CommandContext, CommandHandler, CommandMetadata, OrderError};
+use crab_order_core::money::{calculate_change, to_decimal, to_f64};
 use rust_decimal::Decimal;
 use shared::order::types::CommandErrorCode;
 use shared::order::{EventPayload, OrderEvent, OrderEventType};
 
-use super::{
-    SplitMode, validate_active_order, validate_split_mode_allowed, validate_tendered_and_change,
-};
+use super::{SplitMode, validate_active_order, validate_split_mode_allowed};
 
 // ============================================================================
 // StartAASplit (AA 开始 + 第一份支付)
@@ -24,6 +22,8 @@ pub struct StartAaSplitAction {
     pub shares: i32,
     pub payment_method: String,
     pub tendered: Option<f64>,
+    /// Store cash-change limit (set by OrdersManager from store_info)
+    pub max_cash_change: f64,
 }
 
 impl CommandHandler for StartAaSplitAction {
@@ -98,7 +98,12 @@ impl CommandHandler for StartAaSplitAction {
         );
 
         // Event 2: AASplitPaid (first payment)
-        let change = validate_tendered_and_change(self.tendered, amount_f64)?;
+        let tender = calculate_change(
+            &self.payment_method,
+            amount_f64,
+            self.tendered,
+            self.max_cash_change,
+        )?;
         let payment_id = shared::util::snowflake_id();
         let seq2 = ctx.next_sequence();
         let paid_event = OrderEvent::new(
@@ -116,8 +121,8 @@ impl CommandHandler for StartAaSplitAction {
                 payment_method: self.payment_method.clone(),
                 progress_paid: self.shares,
                 progress_total: self.total_shares,
-                tendered: self.tendered,
-                change,
+                tendered: tender.tendered,
+                change: tender.change,
             },
         );
 
@@ -135,6 +140,8 @@ pub struct PayAaSplitAction {
     pub shares: i32,
     pub payment_method: String,
     pub tendered: Option<f64>,
+    /// Store cash-change limit (set by OrdersManager from store_info)
+    pub max_cash_change: f64,
 }
 
 impl CommandHandler for PayAaSplitAction {
@@ -184,7 +191,12 @@ impl CommandHandler for PayAaSplitAction {
             return Err(OrderError::InvalidAmount);
         }
 
-        let change = validate_tendered_and_change(self.tendered, amount_f64)?;
+        let tender = calculate_change(
+            &self.payment_method,
+            amount_f64,
+            self.tendered,
+            self.max_cash_change,
+        )?;
         let payment_id = shared::util::snowflake_id();
         let seq = ctx.next_sequence();
 
@@ -205,8 +217,8 @@ impl CommandHandler for PayAaSplitAction {
                 payment_method: self.payment_method.clone(),
                 progress_paid,
                 progress_total: total_shares,
-                tendered: self.tendered,
-                change,
+                tendered: tender.tendered,
+                change: tender.change,
             },
         );
 
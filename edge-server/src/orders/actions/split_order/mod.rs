@@ -14,8 +14,8 @@ pub use aa_split::{PayAaSplitAction, StartAaSplitAction};
 pub use split_by_amount::SplitByAmountAction;
 pub use split_by_items::SplitByItemsAction;
 
-use crate::order_money::{MONEY_TOLERANCE, calculate_unit_price, to_decimal, to_f64};
 use crate::orders::traits::OrderError;
+use crab_order_core::money::calculate_line_gross;
 use rust_decimal::Decimal;
 use shared::order::types::CommandErrorCode;
 use shared::order::{OrderSnapshot, OrderStatus, SplitItem};
@@ -124,31 +124,11 @@ pub(super) fn validate_items_and_calculate(
             return Err(OrderError::InsufficientQuantity);
         }
 
-        let unit_price = calculate_unit_price(order_item);
-        calculated_amount += unit_price * Decimal::from(split_item.quantity);
+        calculated_amount +=
+            calculate_line_gross(order_item, split_item.quantity, snapshot.tax_mode);
     }
     Ok(calculated_amount)
 }
 
-/// Validate tendered amount is sufficient, return change amount.
-pub(super) fn validate_tendered_and_change(
-    tendered: Option<f64>,
-    amount_f64: f64,
-) -> Result<Option<f64>, OrderError> {
-    if let Some(t) = tendered
-        && to_decimal(t) < to_decimal(amount_f64) - MONEY_TOLERANCE
-    {
-        return Err(OrderError::InvalidOperation(
-            CommandErrorCode::InsufficientTender,
-            format!("Tendered {:.2} is less than required {:.2}", t, amount_f64),
-        ));
-    }
-    let change = tendered.map(|t| {
-        let diff = to_decimal(t) - to_decimal(amount_f64);
-        to_f64(diff.max(Decimal::ZERO))
-    });
-    Ok(change)
-}
-
 #[cfg(test)]
 mod tests;
@@ -1,13 +1,11 @@
 //! SplitByAmount (金额分单) — pays a fixed amount without item tracking
 
-use crate::order_money::{MONEY_TOLERANCE, to_decimal, to_f64};
 use crate::orders::traits::{CommandContext, CommandHandler, CommandMetadata, OrderError};
+use crab_order_core::money::{MONEY_TOLERANCE, calculate_change, to_decimal, to_f64};
 use shared::order::types::CommandErrorCode;
 use shared::order::{EventPayload, OrderEvent, OrderEventType};
 
-use super::{
-    SplitMode, validate_active_order, validate_split_mode_allowed, validate_tendered_and_change,
-};
+use super::{SplitMode, validate_active_order, validate_split_mode_allowed};
 
 #[derive(Debug, Clone)]
 pub struct SplitByAmountAction {
@@ -15,6 +13,8 @@ pub struct SplitByAmountAction {
     pub split_amount: f64,
     pub payment_method: String,
     pub tendered: Option<f64>,
+    /// Store cash-change limit (set by OrdersManager from store_info)
+    pub max_cash_change: f64,
 }
 
 impl CommandHandler for SplitByAmountAction {
@@ -43,7 +43,12 @@ impl CommandHandler for SplitByAmountAction {
             ));
         }
 
-        let change = validate_tendered_and_change(self.tendered, self.split_amount)?;
+        let tender = calculate_change(
+            &self.payment_method,
+            self.split_amount,
+            self.tendered,
+            self.max_cash_change,
+        )?;
         let payment_id = shared::util::snowflake_id();
         let seq = ctx.next_sequence();
 
@@ -59,8 +64,8 @@ impl CommandHandler for SplitByAmountAction {
                 payment_id,
                 split_amount: self.split_amount,
                 payment_method: self.payment_method.clone(),
-                tendered: self.tendered,
-                change,
+                tendered: tender.tendered,
+                change: tender.change,
             },
         );
 
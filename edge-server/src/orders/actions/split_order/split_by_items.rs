@@ -1,13 +1,12 @@
 //! SplitByItems (菜品分单) — pays for specific items
 
-use crate::order_money::{MONEY_TOLERANCE, to_decimal, to_f64};
 use crate::orders::traits::{CommandContext, CommandHandler, CommandMetadata, OrderError};
+use crab_order_core::money::{MONEY_TOLERANCE, calculate_change, to_decimal, to_f64};
 use shared::order::types::CommandErrorCode;
 use shared::order::{EventPayload, OrderEvent, OrderEventType, SplitItem};
 
 use super::{
     SplitMode, validate_active_order, validate_items_and_calculate, validate_split_mode_allowed,
-    validate_tendered_and_change,
 };
 
 #[derive(Debug, Clone)]
@@ -16,6 +15,8 @@ pub struct SplitByItemsAction {
     pub payment_method: String,
     pub items: Vec<SplitItem>,
     pub tendered: Option<f64>,
+    /// Store cash-change limit (set by OrdersManager from store_info)
+    pub max_cash_change: f64,
 }
 
 impl CommandHandler for SplitByItemsAction {
@@ -54,7 +55,12 @@ impl CommandHandler for SplitByItemsAction {
             ));
         }
 
-        let change = validate_tendered_and_change(self.tendered, amount_f64)?;
+        let tender = calculate_change(
+            &self.payment_method,
+            amount_f64,
+            self.tendered,
+            self.max_cash_change,
+        )?;
         let payment_id = shared::util::snowflake_id();
         let seq = ctx.next_sequence();
 
@@ -71,8 +77,8 @@ impl CommandHandler for SplitByItemsAction {
                 split_amount: amount_f64,
                 payment_method: self.payment_method.clone(),
                 items: self.items.clone(),
-                tendered: self.tendered,
-                change,
+                tendered: tender.tendered,
+                change: tender.change,
             },
         );
 
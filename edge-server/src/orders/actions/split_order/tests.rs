@@ -1,6 +1,7 @@
 use super::*;
 use crate::orders::storage::OrderStorage;
 use crate::orders::traits::{CommandContext, CommandHandler, CommandMetadata};
+use shared::models::DEFAULT_MAX_CASH_CHANGE;
 use shared::order::{
     CartItemSnapshot, EventPayload, OrderEventType, OrderSnapshot, OrderStatus, SplitItem,
 };
@@ -44,6 +45,8 @@ fn create_active_order_with_items(order_id: i64) -> OrderSnapshot {
         category_id: None,
         category_name: None,
         is_comped: false,
+        course: None,
+        is_held: false,
         tax: 0.0,
         tax_rate: 0,
     };
@@ -71,6 +74,8 @@ fn create_active_order_with_items(order_id: i64) -> OrderSnapshot {
         category_id: None,
         category_name: None,
         is_comped: false,
+        course: None,
+        is_held: false,
         tax: 0.0,
         tax_rate: 0,
     };
@@ -105,6 +110,7 @@ fn test_split_by_items_success() {
             unit_price: 10.0,
         }],
         tendered: None,
+        max_cash_change: DEFAULT_MAX_CASH_CHANGE,
     };
 
     let metadata = create_test_metadata();
@@ -144,6 +150,7 @@ fn test_split_by_items_empty_fails() {
         payment_method: "CASH".to_string(),
         items: vec![],
         tendered: None,
+        max_cash_change: DEFAULT_MAX_CASH_CHANGE,
     };
 
     let metadata = create_test_metadata();
@@ -169,6 +176,7 @@ fn test_split_by_amount_success() {
         split_amount: 20.0,
         payment_method: "CARD".to_string(),
         tendered: None,
+        max_cash_change: DEFAULT_MAX_CASH_CHANGE,
     };
 
     let metadata = create_test_metadata();
@@ -206,6 +214,7 @@ fn test_split_by_amount_zero_fails() {
         split_amount: 0.0,
         payment_method: "CASH".to_string(),
         tendered: None,
+        max_cash_change: DEFAULT_MAX_CASH_CHANGE,
     };
 
     let metadata = create_test_metadata();
@@ -232,6 +241,7 @@ fn test_start_aa_split_success() {
         shares: 1,
         payment_method: "CASH".to_string(),
         tendered: None,
+        max_cash_change: DEFAULT_MAX_CASH_CHANGE,
     };
 
     let metadata = create_test_metadata();
@@ -286,6 +296,7 @@ fn test_start_aa_split_invalid_total_shares() {
         shares: 1,
         payment_method: "CASH".to_string(),
         tendered: None,
+        max_cash_change: DEFAULT_MAX_CASH_CHANGE,
     };
 
     let metadata = create_test_metadata();
@@ -315,6 +326,7 @@ fn test_pay_aa_split_success() {
         shares: 1,
         payment_method: "CARD".to_string(),
         tendered: None,
+        max_cash_change: DEFAULT_MAX_CASH_CHANGE,
     };
 
     let metadata = create_test_metadata();
@@ -354,6 +366,7 @@ fn test_pay_aa_split_not_started_fails() {
         shares: 1,
         payment_method: "CASH".to_string(),
         tendered: None,
+        max_cash_change: DEFAULT_MAX_CASH_CHANGE,
     };
 
     let metadata = create_test_metadata();
@@ -385,6 +398,7 @@ fn test_item_split_then_amount_split_allowed() {
         split_amount: 10.0,
         payment_method: "CASH".to_string(),
         tendered: None,
+        max_cash_change: DEFAULT_MAX_CASH_CHANGE,
     };
 
     let metadata = create_test_metadata();
@@ -418,6 +432,7 @@ fn test_item_split_then_aa_split_allowed() {
         shares: 1,
         payment_method: "CASH".to_string(),
         tendered: None,
+        max_cash_change: DEFAULT_MAX_CASH_CHANGE,
     };
 
     let metadata = create_test_metadata();
@@ -452,6 +467,7 @@ fn test_amount_split_then_item_split_blocked() {
             unit_price: 10.0,
         }],
         tendered: None,
+        max_cash_change: DEFAULT_MAX_CASH_CHANGE,
     };
 
     let metadata = create_test_metadata();
@@ -482,6 +498,7 @@ fn test_amount_split_then_aa_split_allowed() {
         shares: 1,
         payment_method: "CASH".to_string(),
         tendered: None,
+        max_cash_change: DEFAULT_MAX_CASH_CHANGE,
     };
 
     let metadata = create_test_metadata();
@@ -516,6 +533,7 @@ fn test_aa_active_blocks_item_split() {
             unit_price: 10.0,
         }],
         tendered: None,
+        max_cash_change: DEFAULT_MAX_CASH_CHANGE,
     };
 
     let metadata = create_test_metadata();
@@ -545,6 +563,7 @@ fn test_aa_active_blocks_amount_split() {
         split_amount: 10.0,
         payment_method: "CASH".to_string(),
         tendered: None,
+        max_cash_change: DEFAULT_MAX_CASH_CHANGE,
     };
 
     let metadata = create_test_metadata();
@@ -576,6 +595,7 @@ fn test_pay_aa_split_exceeds_remaining_fails() {
         shares: 2, // Only 1 available
         payment_method: "CASH".to_string(),
         tendered: None,
+        max_cash_change: DEFAULT_MAX_CASH_CHANGE,
     };
 
     let metadata = create_test_metadata();
@@ -149,6 +149,8 @@ mod tests {
             category_id: None,
             category_name: None,
             is_comped: false,
+            course: None,
+            is_held: false,
         }
     }
 
@@ -347,6 +349,8 @@ mod tests {
             category_id: None,
             category_name: None,
             is_comped: false,
+            course: None,
+            is_held: false,
         }];
         storage.store_snapshot(&txn, &snapshot).unwrap();
 
@@ -442,6 +446,8 @@ mod tests {
             category_id: None,
             category_name: None,
             is_comped: false,
+            course: None,
+            is_held: false,
         }];
         storage.store_snapshot(&txn, &snapshot).unwrap();
 
@@ -500,6 +506,8 @@ mod tests {
             category_id: None,
             category_name: None,
             is_comped: false,
+            course: None,
+            is_held: false,
         }];
         storage.store_snapshot(&txn, &snapshot).unwrap();
 
@@ -0,0 +1,462 @@
+//! TransferItems command handler
+//!
+//! Moves selected item lines from one active order to another (e.g. a guest
+//! changes tables with part of the order). Generates two events:
+//! - ItemsTransferredOut for the source order
+//! - ItemsTransferredIn for the target order (carries the item snapshots)
+//!
+//! Per-item adjustments travel with the item: manual discounts, price rules
+//! and comp records are kept as-is. MG (member) discounts belong to the member
+//! of the order, so they are re-evaluated against the target order's
+//! marketing group. Items tied to a stamp redemption or with payments cannot
+//! be transferred.
+
+use rust_decimal::Decimal;
+
+use crate::marketing::mg_calculator;
+use crate::orders::traits::{CommandContext, CommandHandler, CommandMetadata, OrderError};
+use crab_order_core::money::{to_decimal, to_f64};
+use shared::models::MgDiscountRule;
+use shared::order::types::CommandErrorCode;
+use shared::order::{EventPayload, OrderEvent, OrderEventType, OrderSnapshot, OrderStatus};
+
+/// TransferItems action
+#[derive(Debug, Clone)]
+pub struct TransferItemsAction {
+    pub source_order_id: i64,
+    pub target_order_id: i64,
+    pub instance_ids: Vec<String>,
+    /// Target order's active MG discount rules, injected by OrdersManager
+    pub target_mg_rules: Vec<MgDiscountRule>,
+}
+
+impl TransferItemsAction {
+    fn validate_active(&self, snapshot: &OrderSnapshot, role: &str) -> Result<(), OrderError> {
+        match snapshot.status {
+            OrderStatus::Active => {}
+            OrderStatus::Completed => {
+                return Err(OrderError::OrderAlreadyCompleted(snapshot.order_id));
+            }
+            OrderStatus::Void => {
+                return Err(OrderError::OrderAlreadyVoided(snapshot.order_id));
+            }
+            OrderStatus::Merged => {
+                return Err(OrderError::InvalidOperation(
+                    CommandErrorCode::OrderAlreadyMerged,
+                    format!("{role} order {} is already merged", snapshot.order_id),
+                ));
+            }
+        }
+
+        // Split payments are calculated against the order total
+        if snapshot.aa_total_shares.is_some() {
+            return Err(OrderError::InvalidOperation(
+                CommandErrorCode::AaSplitActive,
+                format!("{role} order has active AA split, cannot transfer items"),
+            ));
+        }
+        if snapshot.has_amount_split {
+            return Err(OrderError::InvalidOperation(
+                CommandErrorCode::AmountSplitActive,
+                format!("{role} order has active amount split, cannot transfer items"),
+            ));
+        }
+        Ok(())
+    }
+}
+
+impl CommandHandler for TransferItemsAction {
+    fn execute(
+        &self,
+        ctx: &mut CommandContext<'_>,
+        metadata: &CommandMetadata,
+    ) -> Result<Vec<OrderEvent>, OrderError> {
+        // 1. Validate input
+        if self.instance_ids.is_empty() {
+            return Err(OrderError::InvalidOperation(
+                CommandErrorCode::EmptyItems,
+                "instance_ids cannot be empty".to_string(),
+            ));
+        }
+        if self.source_order_id == self.target_order_id {
+            return Err(OrderError::InvalidOperation(
+                CommandErrorCode::CannotMergeSelf,
+                "Cannot transfer items to the same order".to_string(),
+            ));
+        }
+
+        // 2. Load and validate both orders
+        let source = ctx.load_snapshot(self.source_order_id)?;
+        self.validate_active(&source, "Source")?;
+        let target = ctx.load_snapshot(self.target_order_id)?;
+        self.validate_active(&target, "Target")?;
+
+        // 3. Validate each item
+        let mut instance_ids: Vec<String> = Vec::with_capacity(self.instance_ids.len());
+        for instance_id in &self.instance_ids {
+            if instance_ids.contains(instance_id) {
+                continue;
+            }
+            let item = source
+                .items
+                .iter()
+                .find(|i| i.instance_id == *instance_id)
+                .ok_or_else(|| OrderError::ItemNotFound(instance_id.clone()))?;
+
+            let paid_qty = source
+                .paid_item_quantities
+                .get(instance_id)
+                .copied()
+                .unwrap_or(0);
+            if paid_qty > 0 {
+                return Err(OrderError::InvalidOperation(
+                    CommandErrorCode::HasPayments,
+                    format!("Item {} has payments, cannot transfer", item.name),
+                ));
+            }
+
+            let in_redemption = source.stamp_redemptions.iter().any(|r| {
+                r.reward_instance_id == *instance_id
+                    || r.comp_source_instance_id.as_deref() == Some(instance_id.as_str())
+            });
+            if in_redemption {
+                return Err(OrderError::InvalidOperation(
+                    CommandErrorCode::StampItemNotTransferable,
+                    format!(
+                        "Item {} is tied to a stamp redemption. Cancel the redemption first.",
+                        item.name
+                    ),
+                ));
+            }
+
+            instance_ids.push(instance_id.clone());
+        }
+
+        // 4. Build transferred items (MG discounts follow the target's member)
+        let same_group = source.marketing_group_id == target.marketing_group_id;
+        let items: Vec<_> = instance_ids
+            .iter()
+            .filter_map(|id| source.items.iter().find(|i| i.instance_id == *id))
+            .map(|item| {
+                let mut item = item.clone();
+                if !same_group {
+                    item.applied_mg_rules = vec![];
+                    if !self.target_mg_rules.is_empty() && !item.is_comped {
+                        // unit_price already includes the source member's MG discount
+                        let base =
+                            to_decimal(item.unit_price) + to_decimal(item.mg_discount_amount);
+                        let result = mg_calculator::calculate_mg_discount(
+                            to_f64(base.max(Decimal::ZERO)),
+                            item.id,
+                            item.category_id,
+                            &self.target_mg_rules,
+                        );
+                        item.applied_mg_rules = result.applied_rules;
+                    }
+                }
+                item
+            })
+            .collect();
+        let comps: Vec<_> = source
+            .comps
+            .iter()
+            .filter(|c| instance_ids.contains(&c.instance_id))
+            .cloned()
+            .collect();
+
+        // 5. Allocate sequence numbers for both events
+        let seq1 = ctx.next_sequence();
+        let seq2 = ctx.next_sequence();
+
+        // 6. Create ItemsTransferredOut event for source order
+        let event1 = OrderEvent::new(
+            seq1,
+            self.source_order_id,
+            metadata.operator_id,
+            metadata.operator_name.clone(),
+            metadata.command_id,
+            Some(metadata.timestamp),
+            OrderEventType::ItemsTransferredOut,
+            EventPayload::ItemsTransferredOut {
+                target_order_id: self.target_order_id,
+                target_table_name: target.table_name.clone().unwrap_or_default(),
+                instance_ids,
+            },
+        );
+
+        // 7. Create ItemsTransferredIn event for target order
+        let event2 = OrderEvent::new(
+            seq2,
+            self.target_order_id,
+            metadata.operator_id,
+            metadata.operator_name.clone(),
+            metadata.command_id,
+            Some(metadata.timestamp),
+            OrderEventType::ItemsTransferredIn,
+            EventPayload::ItemsTransferredIn {
+                source_order_id: self.source_order_id,
+                source_table_name: source.table_name.clone().unwrap_or_default(),
+                items,
+                comps,
+            },
+        );
+
+        Ok(vec![event1, event2])
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::orders::storage::OrderStorage;
+    use shared::models::price_rule::{AdjustmentType, ProductScope};
+    use shared::order::types::{CompRecord, StampRedemptionState};
+    use shared::order::{AppliedMgRule, CartItemSnapshot};
+
+    fn create_test_metadata() -> CommandMetadata {
+        CommandMetadata {
+            command_id: 1,
+            operator_id: 1,
+            operator_name: "Test User".to_string(),
+            timestamp: 1234567890,
+        }
+    }
+
+    fn create_active_order(order_id: i64, table_name: &str) -> OrderSnapshot {
+        let mut snapshot = OrderSnapshot::new(order_id);
+        snapshot.status = OrderStatus::Active;
+        snapshot.table_id = Some(order_id);
+        snapshot.table_name = Some(table_name.to_string());
+        snapshot
+    }
+
+    fn create_test_item(instance_id: &str, name: &str) -> CartItemSnapshot {
+        CartItemSnapshot {
+            id: 1,
+            instance_id: instance_id.to_string(),
+            name: name.to_string(),
+            price: 10.0,
+            original_price: 0.0,
+            quantity: 1,
+            unpaid_quantity: 1,
+            selected_options: None,
+            selected_specification: None,
+            manual_discount_percent: None,
+            rule_discount_amount: 0.0,
+            rule_surcharge_amount: 0.0,
+            applied_rules: vec![],
+            applied_mg_rules: vec![],
+            mg_discount_amount: 0.0,
+            unit_price: 10.0,
+            line_total: 10.0,
+            tax: 0.0,
+            tax_rate: 0,
+            note: None,
+            authorizer_id: None,
+            authorizer_name: None,
+            category_id: None,
+            category_name: None,
+            is_comped: false,
+            course: None,
+            is_held: false,
+        }
+    }
+
+    fn execute(
+        source: &OrderSnapshot,
+        target: &OrderSnapshot,
+        instance_ids: &[&str],
+    ) -> Result<Vec<OrderEvent>, OrderError> {
+        let storage = OrderStorage::open_in_memory().unwrap();
+        let txn = storage.begin_write().unwrap();
+        storage.store_snapshot(&txn, source).unwrap();
+        storage.store_snapshot(&txn, target).unwrap();
+        let current_seq = storage.get_next_sequence(&txn).unwrap();
+        let mut ctx = CommandContext::new(&txn, &storage, current_seq);
+        let action = TransferItemsAction {
+            source_order_id: source.order_id,
+            target_order_id: target.order_id,
+            instance_ids: instance_ids.iter().map(|s| s.to_string()).collect(),
+            target_mg_rules: vec![],
+        };
+        action.execute(&mut ctx, &create_test_metadata())
+    }
+
+    #[test]
+    fn test_transfer_items_generates_events_for_both_orders() {
+        let mut source = create_active_order(1001, "Mesa 1");
+        source.items.push(create_test_item("paella", "Paella"));
+        source.items.push(create_test_item("wine", "Wine"));
+        let mut comped = create_test_item("paella::comp::1", "Paella");
+        comped.is_comped = true;
+        source.items.push(comped);
+        source.comps.push(CompRecord {
+            comp_id: 1,
+            instance_id: "paella::comp::1".to_string(),
+            source_instance_id: "paella".to_string(),
+            item_name: "Paella".to_string(),
+            quantity: 1,
+            original_price: 10.0,
+            reason: "birthday".to_string(),
+            authorizer_id: 9,
+            authorizer_name: "Manager".to_string(),
+            timestamp: 1,
+        });
+        let target = create_active_order(1002, "Mesa 5");
+
+        let events = execute(&source, &target, &["paella", "paella::comp::1", "paella"]).unwrap();
+
+        assert_eq!(events.len(), 2);
+        assert_eq!(events[0].order_id, 1001);
+        assert_eq!(events[0].event_type, OrderEventType::ItemsTransferredOut);
+        let EventPayload::ItemsTransferredOut {
+            target_table_name,
+            instance_ids,
+            ..
+        } = &events[0].payload
+        else {
+            panic!("Expected ItemsTransferredOut payload");
+        };
+        assert_eq!(target_table_name, "Mesa 5");
+        assert_eq!(instance_ids.len(), 2);
+
+        assert_eq!(events[1].order_id, 1002);
+        assert_eq!(events[1].sequence, events[0].sequence + 1);
+        let EventPayload::ItemsTransferredIn {
+            source_table_name,
+            items,
+            comps,
+            ..
+        } = &events[1].payload
+        else {
+            panic!("Expected ItemsTransferredIn payload");
+        };
+        assert_eq!(source_table_name, "Mesa 1");
+        assert_eq!(items.len(), 2);
+        assert_eq!(comps.len(), 1);
+        assert_eq!(comps[0].instance_id, "paella::comp::1");
+    }
+
+    #[test]
+    fn test_transfer_to_same_order_fails() {
+        let mut source = create_active_order(1001, "Mesa 1");
+        source.items.push(create_test_item("paella", "Paella"));
+
+        let result = execute(&source, &source, &["paella"]);
+        assert!(matches!(
+            result,
+            Err(OrderError::InvalidOperation(
+                CommandErrorCode::CannotMergeSelf,
+                _
+            ))
+        ));
+    }
+
+    #[test]
+    fn test_transfer_to_completed_order_fails() {
+        let mut source = create_active_order(1001, "Mesa 1");
+        source.items.push(create_test_item("paella", "Paella"));
+        let mut target = create_active_order(1002, "Mesa 5");
+        target.status = OrderStatus::Completed;
+
+        let result = execute(&source, &target, &["paella"]);
+        assert!(matches!(
+            result,
+            Err(OrderError::OrderAlreadyCompleted(1002))
+        ));
+    }
+
+    #[test]
+    fn test_transfer_unknown_item_fails() {
+        let source = create_active_order(1001, "Mesa 1");
+        let target = create_active_order(1002, "Mesa 5");
+
+        let result = execute(&source, &target, &["ghost"]);
+        assert!(matches!(result, Err(OrderError::ItemNotFound(_))));
+    }
+
+    #[test]
+    fn test_transfer_paid_item_fails() {
+        let mut source = create_active_order(1001, "Mesa 1");
+        source.items.push(create_test_item("paella", "Paella"));
+        source.paid_item_quantities.insert("paella".to_string(), 1);
+        let target = create_active_order(1002, "Mesa 5");
+
+        let result = execute(&source, &target, &["paella"]);
+        assert!(matches!(
+            result,
+            Err(OrderError::InvalidOperation(
+                CommandErrorCode::HasPayments,
+                _
+            ))
+        ));
+    }
+
+    #[test]
+    fn test_transfer_with_aa_split_fails() {
+        let mut source = create_active_order(1001, "Mesa 1");
+        source.items.push(create_test_item("paella", "Paella"));
+        let mut target = create_active_order(1002, "Mesa 5");
+        target.aa_total_shares = Some(3);
+
+        let result = execute(&source, &target, &["paella"]);
+        assert!(matches!(
+            result,
+            Err(OrderError::InvalidOperation(
+                CommandErrorCode::AaSplitActive,
+                _
+            ))
+        ));
+    }
+
+    #[test]
+    fn test_transfer_stamp_reward_fails() {
+        let mut source = create_active_order(1001, "Mesa 1");
+        source.items.push(create_test_item("coffee", "Coffee"));
+        source.stamp_redemptions.push(StampRedemptionState {
+            stamp_activity_id: 1,
+            reward_instance_id: "coffee".to_string(),
+            is_comp_existing: true,
+            comp_source_instance_id: None,
+        });
+        let target = create_active_order(1002, "Mesa 5");
+
+        let result = execute(&source, &target, &["coffee"]);
+        assert!(matches!(
+            result,
+            Err(OrderError::InvalidOperation(
+                CommandErrorCode::StampItemNotTransferable,
+                _
+            ))
+        ));
+    }
+
+    #[test]
+    fn test_transfer_clears_mg_discount_of_other_member() {
+        let mut source = create_active_order(1001, "Mesa 1");
+        source.member_id = Some(7);
+        source.marketing_group_id = Some(3);
+        let mut item = create_test_item("paella", "Paella");
+        item.applied_mg_rules = vec![AppliedMgRule {
+            rule_id: 1,
+            name: "vip-10".to_string(),
+            receipt_name: Some("VIP10".to_string()),
+            product_scope: ProductScope::Global,
+            adjustment_type: AdjustmentType::Percentage,
+            adjustment_value: 10.0,
+            calculated_amount: 1.0,
+            skipped: false,
+        }];
+        item.mg_discount_amount = 1.0;
+        item.unit_price = 9.0;
+        source.items.push(item);
+        let target = create_active_order(1002, "Mesa 5");
+
+        let events = execute(&source, &target, &["paella"]).unwrap();
+
+        let EventPayload::ItemsTransferredIn { items, .. } = &events[1].payload else {
+            panic!("Expected ItemsTransferredIn payload");
+        };
+        assert!(items[0].applied_mg_rules.is_empty());
+    }
+}
@@ -172,6 +172,8 @@ mod tests {
             category_id: None,
             category_name: None,
             is_comped,
+            course: None,
+            is_held: false,
         }
     }
 
@@ -2,9 +2,9 @@
 //!
 //! Voids an active order, optionally with a reason.
 
-use crate::order_money::{to_decimal, to_f64};
 use crate::orders::traits::{CommandContext, CommandHandler, CommandMetadata, OrderError};
 use crate::utils::validation::{MAX_NAME_LEN, MAX_NOTE_LEN, validate_order_optional_text};
+use crab_order_core::money::{to_decimal, to_f64};
 use rust_decimal::Decimal;
 use shared::order::types::CommandErrorCode;
 use shared::order::{EventPayload, LossReason, OrderEvent, OrderEventType, OrderStatus, VoidType};
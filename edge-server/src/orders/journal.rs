@@ -0,0 +1,389 @@
+//! Event journal (事件追加日志)
+//!
+//! 独立于 redb 的只追加 JSON Lines 日志：每条提交成功的 [`OrderEvent`] 在广播前
+//! 写入并 fsync。redb 文件损坏到无法修复时，可用 [`JournalScan::rebuild_snapshots`]
+//! / [`OrdersManager::restore_from_journal`](super::OrdersManager::restore_from_journal)
+//! 在全新的 redb 上重建活跃订单。
+//!
+//! ```text
+//! journal/
+//!   events-000000000001.jsonl          ← 已轮转 (文件名 = 首条事件序号)
+//!   events-000000000001.jsonl.sha256   ← 轮转时写入的整文件摘要 (sha256sum 格式)
+//!   events-000000052311.jsonl          ← 当前写入
+//!
+//! {"seq":52311,"prev":"9f2c…","hash":"41aa…","event":{..}}
+//! ```
+//!
+//! - `hash = sha256(prev ‖ event_json)`，逐条串成哈希链 (跨文件延续)，
+//!   中间删改、截断都能被 [`scan`] 发现
+//! - `event_json` 为 `serde_json::Value` 的序列化 (键有序)，与 OrderEvent 的字段增减无关
+//! - 写入失败只记录错误，不影响命令处理 (日志是取证兜底，不是主存储)
+
+use std::collections::BTreeMap;
+use std::fs::{File, OpenOptions};
+use std::io::{BufRead, BufReader, Read, Write};
+use std::path::{Path, PathBuf};
+use std::sync::Arc;
+
+use parking_lot::Mutex;
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use sha2::{Digest, Sha256};
+use shared::order::{OrderEvent, OrderSnapshot};
+
+use crab_order_core::{EventAction, EventApplier};
+
+/// 单个日志文件上限，超过后轮转
+pub const MAX_FILE_BYTES: u64 = 64 * 1024 * 1024;
+
+/// 哈希链起点
+const GENESIS: &str = "0000000000000000000000000000000000000000000000000000000000000000";
+
+const FILE_PREFIX: &str = "events-";
+const FILE_EXT: &str = "jsonl";
+const DIGEST_EXT: &str = "sha256";
+
+/// 日志行
+#[derive(Debug, Serialize, Deserialize)]
+struct JournalLine {
+    seq: u64,
+    prev: String,
+    hash: String,
+    event: Value,
+}
+
+fn chain_hash(prev: &str, event_json: &[u8]) -> String {
+    let mut hasher = Sha256::new();
+    hasher.update(prev.as_bytes());
+    hasher.update(event_json);
+    hex::encode(hasher.finalize())
+}
+
+fn journal_file_name(first_seq: u64) -> String {
+    format!("{FILE_PREFIX}{first_seq:012}.{FILE_EXT}")
+}
+
+fn digest_path(path: &Path) -> PathBuf {
+    let mut name = path.as_os_str().to_owned();
+    name.push(".");
+    name.push(DIGEST_EXT);
+    PathBuf::from(name)
+}
+
+fn file_digest(path: &Path) -> std::io::Result<String> {
+    let mut file = File::open(path)?;
+    let mut hasher = Sha256::new();
+    let mut buf = [0u8; 64 * 1024];
+    loop {
+        let n = file.read(&mut buf)?;
+        if n == 0 {
+            break;
+        }
+        hasher.update(&buf[..n]);
+    }
+    Ok(hex::encode(hasher.finalize()))
+}
+
+/// 按首条序号排序的日志文件
+fn journal_files(dir: &Path) -> std::io::Result<Vec<PathBuf>> {
+    let mut files = Vec::new();
+    for entry in std::fs::read_dir(dir)? {
+        let path = entry?.path();
+        let is_journal = path
+            .file_name()
+            .and_then(|n| n.to_str())
+            .is_some_and(|n| n.starts_with(FILE_PREFIX) && n.ends_with(&format!(".{FILE_EXT}")));
+        if is_journal {
+            files.push(path);
+        }
+    }
+    files.sort();
+    Ok(files)
+}
+
+struct OpenFile {
+    file: File,
+    path: PathBuf,
+    size: u64,
+}
+
+struct JournalInner {
+    dir: PathBuf,
+    max_file_bytes: u64,
+    current: Option<OpenFile>,
+    /// 链上最后一条的 hash
+    last_hash: String,
+}
+
+/// 事件日志写入器 (Clone 共享同一文件)
+#[derive(Clone)]
+pub struct EventJournal {
+    inner: Arc<Mutex<JournalInner>>,
+}
+
+impl std::fmt::Debug for EventJournal {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("EventJournal")
+            .field("dir", &self.inner.lock().dir)
+            .finish()
+    }
+}
+
+impl EventJournal {
+    /// 打开日志目录，从最后一个文件的末行延续哈希链
+    ///
+    /// 末行不完整 (写入中断电) 时封存该文件，后续写入新文件，原始内容保留供取证。
+    pub fn open(dir: impl Into<PathBuf>) -> std::io::Result<Self> {
+        Self::open_with_limit(dir, MAX_FILE_BYTES)
+    }
+
+    pub fn open_with_limit(dir: impl Into<PathBuf>, max_file_bytes: u64) -> std::io::Result<Self> {
+        let dir = dir.into();
+        std::fs::create_dir_all(&dir)?;
+
+        let mut last_hash = GENESIS.to_string();
+        let mut current = None;
+        if let Some(path) = journal_files(&dir)?.pop() {
+            let mut intact = true;
+            for line in BufReader::new(File::open(&path)?).lines() {
+                match serde_json::from_str::<JournalLine>(&line?) {
+                    Ok(entry) => last_hash = entry.hash,
+                    Err(_) => intact = false,
+                }
+            }
+            if !intact {
+                tracing::warn!(path = %path.display(), "Event journal tail is damaged, sealing file");
+                Self::seal(&path)?;
+            } else if !digest_path(&path).exists() {
+                let file = OpenOptions::new().append(true).open(&path)?;
+                let size = file.metadata()?.len();
+                current = Some(OpenFile { file, path, size });
+            }
+        }
+
+        tracing::info!(dir = %dir.display(), "Event journal opened");
+        Ok(Self {
+            inner: Arc::new(Mutex::new(JournalInner {
+                dir,
+                max_file_bytes,
+                current,
+                last_hash,
+            })),
+        })
+    }
+
+    /// 写入整文件摘要 (轮转 / 封存)
+    fn seal(path: &Path) -> std::io::Result<()> {
+        let digest = file_digest(path)?;
+        let name = path
+            .file_name()
+            .map(|n| n.to_string_lossy().into_owned())
+            .unwrap_or_default();
+        std::fs::write(digest_path(path), format!("{digest}  {name}\n"))
+    }
+
+    /// 追加一批已提交的事件 (一次 fsync)
+    pub fn append(&self, events: &[OrderEvent]) -> std::io::Result<()> {
+        let Some(first) = events.first() else {
+            return Ok(());
+        };
+        let mut inner = self.inner.lock();
+
+        let rotate = inner
+            .current
+            .as_ref()
+            .is_some_and(|c| c.size >= inner.max_file_bytes);
+        if rotate && let Some(current) = inner.current.take() {
+            drop(current.file);
+            Self::seal(&current.path)?;
+            tracing::info!(path = %current.path.display(), "Event journal rotated");
+        }
+        if inner.current.is_none() {
+            let path = inner.dir.join(journal_file_name(first.sequence));
+            let file = OpenOptions::new().create(true).append(true).open(&path)?;
+            let size = file.metadata()?.len();
+            inner.current = Some(OpenFile { file, path, size });
+        }
+
+        let mut buf = Vec::new();
+        let mut last_hash = inner.last_hash.clone();
+        for event in events {
+            let event = serde_json::to_value(event)?;
+            let hash = chain_hash(&last_hash, &serde_json::to_vec(&event)?);
+            let line = JournalLine {
+                seq: event["sequence"].as_u64().unwrap_or_default(),
+                prev: std::mem::replace(&mut last_hash, hash.clone()),
+                hash,
+                event,
+            };
+            serde_json::to_writer(&mut buf, &line)?;
+            buf.push(b'\n');
+        }
+
+        let current = inner.current.as_mut().expect("journal file opened above");
+        current.file.write_all(&buf)?;
+        current.file.sync_data()?;
+        current.size += buf.len() as u64;
+        inner.last_hash = last_hash;
+        Ok(())
+    }
+}
+
+/// 日志扫描结果
+#[derive(Debug, Default)]
+pub struct JournalScan {
+    /// 可解析的事件 (按文件 + 行顺序)
+    pub events: Vec<OrderEvent>,
+    /// 扫描的文件数
+    pub files: usize,
+    /// 发现的问题 (哈希链断裂、摘要不符、无法解析的行)
+    pub issues: Vec<String>,
+}
+
+impl JournalScan {
+    pub fn is_intact(&self) -> bool {
+        self.issues.is_empty()
+    }
+
+    /// 按订单回放事件，重建全部订单快照 (含已终结订单)
+    pub fn rebuild_snapshots(&self) -> Vec<OrderSnapshot> {
+        let mut events: Vec<&OrderEvent> = self.events.iter().collect();
+        events.sort_by_key(|e| e.sequence);
+
+        let mut snapshots: BTreeMap<i64, OrderSnapshot> = BTreeMap::new();
+        for event in events {
+            let snapshot = snapshots
+                .entry(event.order_id)
+                .or_insert_with(|| OrderSnapshot::new(event.order_id));
+            let applier: EventAction = event.into();
+            applier.apply(snapshot, event);
+        }
+        snapshots.into_values().collect()
+    }
+
+    /// 最大事件序号
+    pub fn last_sequence(&self) -> u64 {
+        self.events.iter().map(|e| e.sequence).max().unwrap_or(0)
+    }
+}
+
+/// 读取并校验日志目录
+///
+/// 问题不会中断扫描：能解析的事件全部返回，由调用方结合 `issues` 判断可信度。
+pub fn scan(dir: &Path) -> std::io::Result<JournalScan> {
+    let mut result = JournalScan::default();
+    let mut prev = GENESIS.to_string();
+
+    for path in journal_files(dir)? {
+        result.files += 1;
+        let name = path
+            .file_name()
+            .map(|n| n.to_string_lossy().into_owned())
+            .unwrap_or_default();
+
+        let sidecar = digest_path(&path);
+        if sidecar.exists() {
+            let expected = std::fs::read_to_string(&sidecar)?;
+            let expected = expected.split_whitespace().next().unwrap_or_default();
+            if file_digest(&path)? != expected {
+                result.issues.push(format!("{name}: file digest mismatch"));
+            }
+        }
+
+        for (index, line) in BufReader::new(File::open(&path)?).lines().enumerate() {
+            let line_no = index + 1;
+            let entry = match serde_json::from_str::<JournalLine>(&line?) {
+                Ok(entry) => entry,
+                Err(e) => {
+                    result
+                        .issues
+                        .push(format!("{name}:{line_no}: unreadable line ({e})"));
+                    continue;
+                }
+            };
+            if entry.prev != prev {
+                result.issues.push(format!(
+                    "{name}:{line_no}: hash chain broken (seq {})",
+                    entry.seq
+                ));
+            }
+            if chain_hash(&entry.prev, &serde_json::to_vec(&entry.event)?) != entry.hash {
+                result.issues.push(format!(
+                    "{name}:{line_no}: checksum mismatch (seq {})",
+                    entry.seq
+                ));
+            }
+            prev = entry.hash;
+            match serde_json::from_value::<OrderEvent>(entry.event) {
+                Ok(event) => result.events.push(event),
+                Err(e) => result
+                    .issues
+                    .push(format!("{name}:{line_no}: undecodable event ({e})")),
+            }
+        }
+    }
+    Ok(result)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn lines(path: &Path) -> Vec<String> {
+        std::fs::read_to_string(path)
+            .unwrap()
+            .lines()
+            .map(String::from)
+            .collect()
+    }
+
+    #[test]
+    fn test_chain_detects_tampering() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join(journal_file_name(1));
+
+        let mut prev = GENESIS.to_string();
+        let mut content = String::new();
+        for seq in 1..=3u64 {
+            let event = serde_json::json!({ "sequence": seq, "order_id": 7 });
+            let hash = chain_hash(&prev, &serde_json::to_vec(&event).unwrap());
+            let line = JournalLine {
+                seq,
+                prev: std::mem::replace(&mut prev, hash.clone()),
+                hash,
+                event,
+            };
+            content.push_str(&serde_json::to_string(&line).unwrap());
+            content.push('\n');
+        }
+        std::fs::write(&path, &content).unwrap();
+
+        // 未篡改：链完整 (测试事件不是完整 OrderEvent，只报告解码问题)
+        let result = scan(dir.path()).unwrap();
+        assert!(result.issues.iter().all(|i| i.contains("undecodable")));
+
+        // 删除中间一行 → 链断裂
+        let mut tampered = lines(&path);
+        tampered.remove(1);
+        std::fs::write(&path, tampered.join("\n") + "\n").unwrap();
+        let result = scan(dir.path()).unwrap();
+        assert!(
+            result
+                .issues
+                .iter()
+                .any(|i| i.contains("hash chain broken"))
+        );
+
+        // 改写事件内容 → 校验和不符
+        let mut edited = lines(&path);
+        edited[0] = edited[0].replace("\"order_id\":7", "\"order_id\":8");
+        std::fs::write(&path, edited.join("\n") + "\n").unwrap();
+        let result = scan(dir.path()).unwrap();
+        assert!(
+            result
+                .issues
+                .iter()
+                .any(|i| i.contains("checksum mismatch"))
+        );
+    }
+}
@@ -0,0 +1,263 @@
+//! 活跃订单快照缓存
+//!
+//! `get_active_orders` 被多个终端轮询，每次从 redb 读取全部快照代价较高。
+//! 缓存保存活跃订单快照（已补算 line_total），由命令处理在 EventApplier
+//! 应用事件后维护：
+//!
+//! - 写事务提交时持有缓存写锁，提交成功后才更新缓存 → 与 redb 顺序一致
+//! - 事务失败 / 回滚时缓存不变
+//! - 首次读取时懒加载（同样持有写锁，避免与并发提交交错）
+//!
+//! 校验模式下每次读取都与 redb 比对，不一致时记录错误并以 redb 为准重建。
+//!
+//! 懒加载优先复用 redb 中的快照检查点 ([`OrderStorage::get_active_orders_checkpointed`])；
+//! 检查点由 [`ActiveOrdersCache::checkpoint`] 定期写入。
+
+use parking_lot::{RwLock, RwLockWriteGuard};
+use shared::order::{OrderSnapshot, OrderStatus};
+use std::collections::BTreeMap;
+use std::sync::Arc;
+use std::sync::atomic::{AtomicBool, AtomicU64, Ordering};
+
+use super::super::storage::{OrderStorage, StorageResult};
+use crab_order_core::money;
+
+type Entries = Option<BTreeMap<i64, OrderSnapshot>>;
+
+/// Active order snapshot cache (Clone 共享同一缓存)
+#[derive(Debug, Clone, Default)]
+pub struct ActiveOrdersCache {
+    /// None = 尚未加载
+    entries: Arc<RwLock<Entries>>,
+    /// 一致性校验模式
+    verify: Arc<AtomicBool>,
+    /// 最近一次写入检查点时的序列
+    checkpointed: Arc<AtomicU64>,
+}
+
+/// 提交期间持有的缓存写锁
+pub struct CacheCommitGuard<'a> {
+    entries: RwLockWriteGuard<'a, Entries>,
+}
+
+impl CacheCommitGuard<'_> {
+    /// 事务提交成功后应用已修改的快照
+    pub fn apply(mut self, snapshots: Vec<OrderSnapshot>) {
+        let Some(entries) = self.entries.as_mut() else {
+            return; // 未加载：下次读取时从 redb 加载
+        };
+        for mut snapshot in snapshots {
+            if snapshot.status == OrderStatus::Active {
+                ensure_line_totals(&mut snapshot);
+                entries.insert(snapshot.order_id, snapshot);
+            } else {
+                entries.remove(&snapshot.order_id);
+            }
+        }
+    }
+}
+
+impl ActiveOrdersCache {
+    pub fn set_verify(&self, enabled: bool) {
+        self.verify.store(enabled, Ordering::Relaxed);
+        tracing::info!(enabled, "Active orders cache verification");
+    }
+
+    /// 在提交写事务前调用，返回的守卫须在提交后 `apply`
+    pub fn begin_commit(&self) -> CacheCommitGuard<'_> {
+        CacheCommitGuard {
+            entries: self.entries.write(),
+        }
+    }
+
+    /// 校验模式下不直接使用缓存
+    pub fn is_verifying(&self) -> bool {
+        self.verify.load(Ordering::Relaxed)
+    }
+
+    /// 单个活跃订单 (未加载 / 校验模式 / 非活跃时返回 None)
+    pub fn get(&self, order_id: i64) -> Option<OrderSnapshot> {
+        if self.is_verifying() {
+            return None;
+        }
+        self.entries.read().as_ref()?.get(&order_id).cloned()
+    }
+
+    /// 将已加载的缓存写入 redb 检查点，返回写入的订单数
+    ///
+    /// 读锁内取快照与序列 (提交持有写锁，二者一致)，释放锁后再写 redb —
+    /// 提交在持有 redb 写事务时等待缓存写锁，持锁写 redb 会死锁。
+    /// 未加载或自上次检查点后无新提交时跳过。
+    pub fn checkpoint(&self, storage: &OrderStorage) -> StorageResult<Option<usize>> {
+        let (sequence, orders) = {
+            let guard = self.entries.read();
+            let Some(entries) = guard.as_ref() else {
+                return Ok(None);
+            };
+            let sequence = storage.get_current_sequence()?;
+            if sequence == self.checkpointed.load(Ordering::Relaxed) {
+                return Ok(None);
+            }
+            let orders: Vec<OrderSnapshot> = entries.values().cloned().collect();
+            (sequence, orders)
+        };
+        storage.write_checkpoint(sequence, &orders)?;
+        self.checkpointed.store(sequence, Ordering::Relaxed);
+        Ok(Some(orders.len()))
+    }
+
+    /// 丢弃缓存，下次读取时重新加载
+    pub fn invalidate(&self) {
+        *self.entries.write() = None;
+    }
+
+    /// 读取全部活跃订单（按 order_id 升序，与 redb 一致）
+    pub fn get_or_load(&self, storage: &OrderStorage) -> StorageResult<Vec<OrderSnapshot>> {
+        if !self.verify.load(Ordering::Relaxed)
+            && let Some(entries) = self.entries.read().as_ref()
+        {
+            return Ok(entries.values().cloned().collect());
+        }
+
+        let mut guard = self.entries.write();
+        let fresh = load(storage, !self.verify.load(Ordering::Relaxed))?;
+        match guard.as_ref() {
+            Some(cached) if self.verify.load(Ordering::Relaxed) => {
+                let stale: Vec<i64> = cached
+                    .keys()
+                    .chain(fresh.keys())
+                    .filter(|id| cached.get(id) != fresh.get(id))
+                    .copied()
+                    .collect();
+                if !stale.is_empty() {
+                    tracing::error!(
+                        stale_orders = ?stale,
+                        "Active orders cache diverged from redb, rebuilding"
+                    );
+                }
+            }
+            // 并发读取已加载
+            Some(cached) => return Ok(cached.values().cloned().collect()),
+            None => {}
+        }
+        let orders = fresh.values().cloned().collect();
+        *guard = Some(fresh);
+        Ok(orders)
+    }
+}
+
+/// 从 redb 加载 (`use_checkpoint = false` 时逐单读取，供校验比对)
+fn load(
+    storage: &OrderStorage,
+    use_checkpoint: bool,
+) -> StorageResult<BTreeMap<i64, OrderSnapshot>> {
+    let orders = if use_checkpoint {
+        let (orders, reused) = storage.get_active_orders_checkpointed()?;
+        tracing::debug!(
+            orders = orders.len(),
+            reused,
+            "Active orders loaded from checkpoint"
+        );
+        orders
+    } else {
+        storage.get_active_orders()?
+    };
+    Ok(orders
+        .into_iter()
+        .map(|mut order| {
+            ensure_line_totals(&mut order);
+            (order.order_id, order)
+        })
+        .collect())
+}
+
+/// 确保 line_total 已计算
+pub(super) fn ensure_line_totals(order: &mut OrderSnapshot) {
+    let needs_recalc = order
+        .items
+        .iter()
+        .any(|item| item.line_total.abs() < f64::EPSILON && !item.is_comped);
+    if needs_recalc {
+        money::recalculate_totals(order);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn store_active(storage: &OrderStorage, order_id: i64) -> OrderSnapshot {
+        let snapshot = OrderSnapshot::new(order_id);
+        let txn = storage.begin_write().unwrap();
+        storage.store_snapshot(&txn, &snapshot).unwrap();
+        storage.mark_order_active(&txn, order_id).unwrap();
+        txn.commit().unwrap();
+        snapshot
+    }
+
+    #[test]
+    fn test_commit_updates_loaded_cache() {
+        let storage = OrderStorage::open_in_memory().unwrap();
+        let cache = ActiveOrdersCache::default();
+        store_active(&storage, 1);
+        assert_eq!(cache.get_or_load(&storage).unwrap().len(), 1);
+
+        let opened = store_active(&storage, 2);
+        cache.begin_commit().apply(vec![opened]);
+        let mut closed = OrderSnapshot::new(1);
+        closed.status = OrderStatus::Completed;
+        cache.begin_commit().apply(vec![closed]);
+
+        let ids: Vec<i64> = cache
+            .get_or_load(&storage)
+            .unwrap()
+            .iter()
+            .map(|o| o.order_id)
+            .collect();
+        assert_eq!(ids, vec![2]);
+    }
+
+    #[test]
+    fn test_verify_mode_rebuilds_from_storage() {
+        let storage = OrderStorage::open_in_memory().unwrap();
+        let cache = ActiveOrdersCache::default();
+        store_active(&storage, 1);
+        cache.get_or_load(&storage).unwrap();
+
+        // Written behind the cache's back
+        store_active(&storage, 2);
+        assert_eq!(cache.get_or_load(&storage).unwrap().len(), 1);
+
+        cache.set_verify(true);
+        assert_eq!(cache.get_or_load(&storage).unwrap().len(), 2);
+
+        cache.set_verify(false);
+        assert_eq!(cache.get_or_load(&storage).unwrap().len(), 2);
+    }
+
+    #[test]
+    fn test_checkpoint_round_trip() {
+        let storage = OrderStorage::open_in_memory().unwrap();
+        let cache = ActiveOrdersCache::default();
+        assert_eq!(cache.checkpoint(&storage).unwrap(), None);
+
+        store_active(&storage, 1);
+        let txn = storage.begin_write().unwrap();
+        storage.increment_sequence(&txn).unwrap();
+        txn.commit().unwrap();
+        cache.get_or_load(&storage).unwrap();
+        assert_eq!(cache.checkpoint(&storage).unwrap(), Some(1));
+        // 无新提交：跳过
+        assert_eq!(cache.checkpoint(&storage).unwrap(), None);
+
+        let reloaded = ActiveOrdersCache::default();
+        assert_eq!(reloaded.get_or_load(&storage).unwrap().len(), 1);
+        assert_eq!(
+            storage.get_active_orders_checkpointed().unwrap().1,
+            1,
+            "unchanged order is served from the checkpoint"
+        );
+        assert!(reloaded.get(1).is_some());
+        assert!(reloaded.get(2).is_none());
+    }
+}
@@ -1,6 +1,6 @@
 use super::super::storage::StorageError;
 use super::super::traits::OrderError;
-use shared::order::{CommandError, CommandErrorCode};
+use shared::order::{CommandError, CommandErrorCode, CommandResponse, OrderSnapshot};
 use thiserror::Error;
 
 /// Manager errors
@@ -39,10 +39,40 @@ pub enum ManagerError {
     #[error("Insufficient stamps: {current}/{required}")]
     InsufficientStamps { current: i32, required: i32 },
 
+    #[error("Version conflict on order {order_id}: expected {expected}, current {current}")]
+    VersionConflict {
+        order_id: i64,
+        expected: u64,
+        current: u64,
+        /// 当前快照，随响应返回给客户端合并
+        snapshot: Box<OrderSnapshot>,
+    },
+
     #[error("Internal error: {0}")]
     Internal(String),
 }
 
+impl ManagerError {
+    /// 转换为失败响应；版本冲突附带当前快照
+    pub fn into_response(self, command_id: i64) -> CommandResponse {
+        match self {
+            ManagerError::VersionConflict {
+                order_id,
+                expected,
+                current,
+                snapshot,
+            } => {
+                let message = format!(
+                    "Version conflict on order {order_id}: expected {expected}, current {current}"
+                );
+                let error = CommandError::new(CommandErrorCode::VersionConflict, message);
+                CommandResponse::conflict(command_id, error, *snapshot)
+            }
+            err => CommandResponse::error(command_id, err.into()),
+        }
+    }
+}
+
 /// 将存储错误转换为错误码（前端负责本地化）
 fn classify_storage_error(e: &StorageError) -> CommandErrorCode {
     // 先按枚举变体精确匹配
@@ -118,6 +148,9 @@ impl From<ManagerError> for CommandError {
                 CommandErrorCode::InsufficientStamps,
                 format!("{}/{}", current, required),
             ),
+            ManagerError::VersionConflict { .. } => {
+                (CommandErrorCode::VersionConflict, err.to_string())
+            }
             ManagerError::Internal(msg) => (CommandErrorCode::InternalError, msg),
         };
         CommandError::new(code, message)
@@ -0,0 +1,152 @@
+//! 热点订单快照 LRU
+//!
+//! `get_snapshot` 按单查询 (打印、客显、支付回调、终态订单详情) 原本每次读 redb、
+//! 解码并补算 line_total。这里保存最近提交 / 读取过的快照（已补算），容量有限，
+//! 满时淘汰最久未访问的条目。
+//!
+//! - 提交成功后在缓存提交锁内写入 → 与 redb 顺序一致
+//! - 读取未命中时回填；只接受不旧于现有条目的快照 (`last_sequence`)，
+//!   避免与并发提交交错时以旧覆盖新
+//! - 归档 / 截断删除快照后存储的删除计数变化，整个缓存失效
+
+use parking_lot::Mutex;
+use shared::order::OrderSnapshot;
+use std::collections::HashMap;
+use std::sync::Arc;
+
+use super::active_cache::ensure_line_totals;
+
+/// 默认容量
+pub const HOT_SNAPSHOT_CAPACITY: usize = 256;
+
+#[derive(Debug, Default)]
+struct Inner {
+    /// order_id → (快照, 最近访问序号)
+    entries: HashMap<i64, (OrderSnapshot, u64)>,
+    tick: u64,
+    /// 条目对应的存储删除计数
+    generation: u64,
+}
+
+impl Inner {
+    /// 删除计数前移时清空；调用方的计数落后 (读取期间发生删除) 时返回 false
+    fn sync_generation(&mut self, generation: u64) -> bool {
+        if generation > self.generation {
+            self.entries.clear();
+            self.generation = generation;
+        }
+        generation == self.generation
+    }
+
+    fn next_tick(&mut self) -> u64 {
+        self.tick += 1;
+        self.tick
+    }
+}
+
+/// Bounded LRU of recently used order snapshots (Clone 共享同一缓存)
+#[derive(Debug, Clone)]
+pub struct HotSnapshots {
+    inner: Arc<Mutex<Inner>>,
+    capacity: usize,
+}
+
+impl Default for HotSnapshots {
+    fn default() -> Self {
+        Self::new(HOT_SNAPSHOT_CAPACITY)
+    }
+}
+
+impl HotSnapshots {
+    pub fn new(capacity: usize) -> Self {
+        Self {
+            inner: Arc::default(),
+            capacity: capacity.max(1),
+        }
+    }
+
+    /// 读取 (`generation` 为当前存储删除计数)
+    pub fn get(&self, order_id: i64, generation: u64) -> Option<OrderSnapshot> {
+        let mut inner = self.inner.lock();
+        if !inner.sync_generation(generation) {
+            return None;
+        }
+        let tick = inner.next_tick();
+        let (snapshot, used) = inner.entries.get_mut(&order_id)?;
+        *used = tick;
+        Some(snapshot.clone())
+    }
+
+    /// 写入快照 (`generation` 须在读取 redb 之前获取)
+    pub fn insert(&self, mut snapshot: OrderSnapshot, generation: u64) {
+        let mut inner = self.inner.lock();
+        if !inner.sync_generation(generation) {
+            return;
+        }
+        if inner
+            .entries
+            .get(&snapshot.order_id)
+            .is_some_and(|(cached, _)| cached.last_sequence > snapshot.last_sequence)
+        {
+            return;
+        }
+        ensure_line_totals(&mut snapshot);
+        let tick = inner.next_tick();
+        inner.entries.insert(snapshot.order_id, (snapshot, tick));
+        if inner.entries.len() > self.capacity
+            && let Some(&oldest) = inner
+                .entries
+                .iter()
+                .min_by_key(|(_, (_, used))| *used)
+                .map(|(id, _)| id)
+        {
+            inner.entries.remove(&oldest);
+        }
+    }
+
+    /// 丢弃全部条目 (绕过命令写快照后调用)
+    pub fn clear(&self) {
+        self.inner.lock().entries.clear();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn snapshot(order_id: i64, last_sequence: u64) -> OrderSnapshot {
+        let mut snapshot = OrderSnapshot::new(order_id);
+        snapshot.last_sequence = last_sequence;
+        snapshot
+    }
+
+    #[test]
+    fn test_evicts_least_recently_used() {
+        let hot = HotSnapshots::new(2);
+        hot.insert(snapshot(1, 1), 0);
+        hot.insert(snapshot(2, 2), 0);
+        assert!(hot.get(1, 0).is_some());
+        hot.insert(snapshot(3, 3), 0);
+
+        assert!(hot.get(1, 0).is_some());
+        assert!(hot.get(2, 0).is_none());
+        assert!(hot.get(3, 0).is_some());
+    }
+
+    #[test]
+    fn test_stale_insert_and_removal_generation() {
+        let hot = HotSnapshots::default();
+        hot.insert(snapshot(1, 5), 0);
+        hot.insert(snapshot(1, 4), 0);
+        assert_eq!(hot.get(1, 0).unwrap().last_sequence, 5);
+
+        // 归档删除后旧条目失效
+        assert!(hot.get(1, 1).is_none());
+        hot.insert(snapshot(1, 6), 1);
+        assert_eq!(hot.get(1, 1).unwrap().last_sequence, 6);
+
+        // 删除前读取的快照不回填
+        hot.insert(snapshot(2, 1), 0);
+        assert!(hot.get(2, 1).is_none());
+    }
+}
@@ -19,30 +19,47 @@
 //!   │   ├─ 4. Convert command to action and execute (sync)
 //!   │   ├─ 5. Apply events to snapshots via EventApplier
 //!   │   ├─ 6. Persist events and snapshots
-//!   │   ├─ 7. Commit transaction
+//!   │   ├─ 7. Commit transaction (holding the active orders cache lock)
 //!   │   └─ 8. Broadcast events
-//!   └─ Phase C: post_actions()      // async — stamp 追踪等后置写入
+//!   └─ Phase C: post_actions()      // async — stamp 追踪、库存扣减等后置写入
 //! ```
+//!
+//! 储值支付 (`MEMBER_CREDIT`) 在 Phase A 扣减会员余额（预分配 payment_id），
+//! Phase B 未记录该支付时（失败 / 重复命令）立即退回；支付取消或订单取消作废时
+//! 在 Phase C 退回。
 
+mod active_cache;
 mod error;
+mod hot_snapshots;
 pub use error::*;
 
+use active_cache::{ActiveOrdersCache, ensure_line_totals};
+use hot_snapshots::HotSnapshots;
+
 use super::actions::CommandAction;
-use super::appliers::EventAction;
+use super::journal::{self, EventJournal};
+use super::recorder::SessionRecorder;
 use super::storage::{OrderStorage, StorageError};
 use super::traits::{CommandContext, CommandHandler, CommandMetadata, EventApplier, OrderError};
-use crate::order_money;
+use crate::fiscal::{FiscalDevice, FiscalError, FiscalPolicy, FiscalReceipt};
+use crate::pms::{PmsClient, RoomCharge};
 use crate::pricing::matcher::is_time_valid;
 use crate::services::catalog_service::ProductMeta;
 use chrono_tz::Tz;
+use crab_order_core::EventAction;
+use crab_order_core::money::{MEMBER_CREDIT_METHOD, ROOM_CHARGE_METHOD};
 use parking_lot::RwLock;
-use shared::models::PriceRule;
+use shared::models::{DEFAULT_MAX_CASH_CHANGE, PriceRule, TaxMode};
 use shared::order::types::CommandErrorCode;
-use shared::order::{CommandResponse, OrderCommand, OrderEvent, OrderSnapshot, OrderStatus};
+use shared::order::{
+    CommandResponse, EventPayload, OrderCommand, OrderEvent, OrderSnapshot, OrderStatus, VoidType,
+};
 use std::collections::HashMap;
 use std::path::Path;
 use std::sync::Arc;
+use std::sync::atomic::{AtomicBool, Ordering};
 use tokio::sync::broadcast;
+use tracing::Instrument;
 
 /// Event broadcast channel capacity (支持高并发: 10000订单 × 4事件)
 const EVENT_CHANNEL_CAPACITY: usize = 65536;
@@ -50,18 +67,40 @@ const EVENT_CHANNEL_CAPACITY: usize = 65536;
 /// Rule cache size warning threshold
 const RULE_CACHE_WARN_THRESHOLD: usize = 500;
 
+/// 挂房账支付的 note：房间号始终在前，收银员备注 (如有) 附在后面
+fn room_charge_note(room_number: &str, note: Option<&str>) -> String {
+    match note.map(str::trim).filter(|n| !n.is_empty()) {
+        Some(note) => format!("Room {room_number} · {note}"),
+        None => format!("Room {room_number}"),
+    }
+}
+
 // ========== Prefetch Data Structures ==========
 
 /// 预取的 SQLite 数据，在 redb 事务外 async 加载
 struct PrefetchedData {
-    /// AddItems: 会员营销组折扣规则
+    /// AddItems / TransferItems (目标订单): 会员营销组折扣规则
     mg_rules: Vec<shared::models::MgDiscountRule>,
     /// LinkMember: 会员 + 营销组 + 规则
     link_member: Option<LinkMemberPrefetch>,
     /// RedeemStamp: 活动 + 章数 + 目标
     redeem_stamp: Option<RedeemStampPrefetch>,
+    /// RedeemPoints: 按营销组规则算出的兑换值
+    redeem_points: Option<RedeemPointsPrefetch>,
+    /// ApplyCoupon: 按码查到并校验过的优惠码
+    apply_coupon: Option<shared::order::AppliedCoupon>,
     /// RemoveItem/CompItem: 自动取消章兑换的预取数据
     auto_cancel: Vec<StampCancelPrefetch>,
+    /// AddPayment (MEMBER_CREDIT): 已扣款的储值支付
+    credit_debit: Option<CreditDebit>,
+    /// AddPayment (ROOM_CHARGE): 已在 PMS 过账的挂房账
+    room_charge: Option<RoomChargePosted>,
+    /// AddPayment: 支付方式附加费规则 (门店开关开启时)
+    payment_surcharge: Option<shared::models::PaymentSurchargeRule>,
+    /// CompleteOrder: 外卖渠道规则 (起送价)
+    delivery_rule: Option<shared::models::DeliveryRule>,
+    /// CompleteOrder: 已在税控设备登记的税控码
+    fiscal_code: Option<String>,
 }
 
 struct LinkMemberPrefetch {
@@ -73,14 +112,37 @@ struct LinkMemberPrefetch {
 struct RedeemStampPrefetch {
     activity: shared::models::StampActivity,
     current_stamps: i32,
+    /// Stamps granted today (daily cap applies to the order bonus)
+    earned_today: i32,
     stamp_targets: Vec<shared::models::StampTarget>,
     reward_targets: Vec<shared::models::StampRewardTarget>,
 }
 
+/// 积分兑换预取：余额与规则已在事务外校验
+#[derive(Debug, Clone, Copy)]
+struct RedeemPointsPrefetch {
+    amount: f64,
+}
+
+/// 已在 SQLite 扣款的储值支付（payment_id 预分配，事件使用同一 ID）
+#[derive(Debug, Clone, Copy)]
+struct CreditDebit {
+    payment_id: i64,
+}
+
+/// 已在 PMS 过账的挂房账（payment_id 预分配，reference 为 PMS 确认号）
+#[derive(Debug, Clone)]
+struct RoomChargePosted {
+    payment_id: i64,
+    room_number: String,
+    reference: String,
+}
+
 struct StampCancelPrefetch {
     activity_id: i64,
     activity: Option<shared::models::StampActivity>,
     current_stamps: i32,
+    earned_today: i32,
     stamp_targets: Vec<shared::models::StampTarget>,
 }
 
@@ -96,6 +158,10 @@ pub struct OrdersManager {
     epoch: String,
     /// Cached rules per order
     rule_cache: Arc<RwLock<HashMap<i64, Vec<PriceRule>>>>,
+    /// Active order snapshots (maintained on commit)
+    active_cache: ActiveOrdersCache,
+    /// 最近提交 / 查询的快照 LRU (`get_snapshot`)
+    hot_snapshots: HotSnapshots,
     /// Catalog service for product metadata lookup
     catalog_service: Option<Arc<crate::services::CatalogService>>,
     /// SQLite pool for member/marketing queries (optional, only set when SQLite is available)
@@ -108,6 +174,33 @@ pub struct OrdersManager {
     store_number: u32,
     /// 营业日分界时间 (HH:MM 格式)
     business_day_cutoff: RwLock<chrono::NaiveTime>,
+    /// 定价含税模式 (开台时写入订单快照)
+    tax_mode: RwLock<TaxMode>,
+    /// 单笔现金找零上限 (支付 / 分单支付时校验)
+    max_cash_change: RwLock<f64>,
+    /// 安装向导必需步骤已完成 (未完成时拒绝开台)
+    setup_complete: AtomicBool,
+    /// 现场问题录制 (默认关闭)
+    recorder: SessionRecorder,
+    /// 事件追加日志 (可选，独立于 redb 的取证兜底)
+    journal: Option<EventJournal>,
+    /// 酒店 PMS (挂房账，可选)
+    pms: Option<Arc<dyn PmsClient>>,
+    /// 库存扣减 (订单完成时，可选)
+    inventory: Option<Arc<crate::inventory::InventoryTracker>>,
+    /// 优惠码核销 (订单完成时，可选)
+    coupons: Option<Arc<crate::marketing::coupon::CouponTracker>>,
+    /// Prometheus 指标 (命令耗时 / redb 事务耗时，可选)
+    metrics: Option<Arc<crate::core::Metrics>>,
+    /// 外部税控设备 (结单登记，可选)
+    fiscal: Option<Arc<dyn FiscalDevice>>,
+    /// 税控设备不可用时的结单策略
+    fiscal_policy: FiscalPolicy,
+    /// 命令提交耗时告警阈值 (None = 禁用)
+    commit_alert_threshold: Option<std::time::Duration>,
+    /// 故障注册表 (`redb_commit` 注入点)
+    #[cfg(feature = "chaos")]
+    faults: crate::chaos::FaultRegistry,
 }
 
 impl std::fmt::Debug for OrdersManager {
@@ -132,12 +225,28 @@ impl OrdersManager {
             event_tx,
             epoch,
             rule_cache: Arc::new(RwLock::new(HashMap::new())),
+            active_cache: ActiveOrdersCache::default(),
+            hot_snapshots: HotSnapshots::default(),
             catalog_service: None,
             pool: None,
             archive_service: None,
             tz,
             store_number,
             business_day_cutoff: RwLock::new(chrono::NaiveTime::MIN),
+            tax_mode: RwLock::new(TaxMode::default()),
+            max_cash_change: RwLock::new(DEFAULT_MAX_CASH_CHANGE),
+            setup_complete: AtomicBool::new(true),
+            recorder: SessionRecorder::default(),
+            journal: None,
+            pms: None,
+            inventory: None,
+            coupons: None,
+            metrics: None,
+            fiscal: None,
+            fiscal_policy: FiscalPolicy::default(),
+            commit_alert_threshold: None,
+            #[cfg(feature = "chaos")]
+            faults: Default::default(),
         })
     }
 
@@ -159,6 +268,55 @@ impl OrdersManager {
         ));
     }
 
+    /// Enable the append-only event journal
+    pub fn set_event_journal(&mut self, journal: EventJournal) {
+        self.journal = Some(journal);
+    }
+
+    /// Enable room charges through a hotel PMS
+    pub fn set_pms_client(&mut self, pms: Arc<dyn PmsClient>) {
+        self.pms = Some(pms);
+    }
+
+    /// Hotel PMS client (None = room charge disabled)
+    pub fn pms_client(&self) -> Option<Arc<dyn PmsClient>> {
+        self.pms.clone()
+    }
+
+    /// Enable stock decrement on order completion
+    pub fn set_inventory_tracker(&mut self, tracker: Arc<crate::inventory::InventoryTracker>) {
+        self.inventory = Some(tracker);
+    }
+
+    /// Enable coupon redemption on order completion
+    pub fn set_coupon_tracker(&mut self, tracker: Arc<crate::marketing::coupon::CouponTracker>) {
+        self.coupons = Some(tracker);
+    }
+
+    /// Record command latency and redb transaction time
+    pub fn set_metrics(&mut self, metrics: Arc<crate::core::Metrics>) {
+        self.metrics = Some(metrics);
+    }
+
+    /// Warn when a command's redb transaction (writer wait + commit) exceeds `threshold`
+    ///
+    /// 0 = 禁用。超阈值时记录 warn 日志并累加 `crab_redb_slow_commits_total`。
+    pub fn set_commit_alert_threshold(&mut self, threshold: std::time::Duration) {
+        self.commit_alert_threshold = (!threshold.is_zero()).then_some(threshold);
+    }
+
+    /// Wire the `redb_commit` fault point to the server's fault registry
+    #[cfg(feature = "chaos")]
+    pub fn set_faults(&mut self, faults: crate::chaos::FaultRegistry) {
+        self.faults = faults;
+    }
+
+    /// Register completed orders with an external fiscal device
+    pub fn set_fiscal_device(&mut self, device: Arc<dyn FiscalDevice>, policy: FiscalPolicy) {
+        self.fiscal = Some(device);
+        self.fiscal_policy = policy;
+    }
+
     /// Generate next chain number (crash-safe via redb)
     ///
     /// Shared counter for both orders (receipt_number) and credit notes (credit_note_number).
@@ -181,6 +339,37 @@ impl OrdersManager {
         *self.business_day_cutoff.write() = parsed;
     }
 
+    /// Update the cached tax_mode (called when store_info changes)
+    ///
+    /// Only affects orders opened afterwards; active orders keep their mode.
+    pub fn update_tax_mode(&self, mode: TaxMode) {
+        *self.tax_mode.write() = mode;
+    }
+
+    /// Update the cached cash-change limit (called when store_info changes)
+    pub fn update_max_cash_change(&self, limit: f64) {
+        *self.max_cash_change.write() = limit;
+    }
+
+    /// Update the setup wizard gate (called on startup and when a step is confirmed)
+    ///
+    /// While mandatory setup steps are pending, OpenTable is rejected.
+    pub fn update_setup_complete(&self, complete: bool) {
+        self.setup_complete.store(complete, Ordering::Relaxed);
+    }
+
+    /// Current store tax mode
+    pub fn tax_mode(&self) -> TaxMode {
+        *self.tax_mode.read()
+    }
+
+    /// Start of the current business day (Unix millis), for the stamp daily cap
+    fn business_day_start(&self) -> i64 {
+        let cutoff = *self.business_day_cutoff.read();
+        let business_date = crate::utils::time::current_business_date(cutoff, self.tz);
+        crate::utils::time::date_cutoff_millis(business_date, cutoff, self.tz)
+    }
+
     /// Read current counter state: (business_date YYYYMMDD, daily_count).
     /// Used by CloudWorker to sync counter state to Cloud for recovery.
     pub fn current_counter_state(&self) -> (String, u64) {
@@ -201,15 +390,36 @@ impl OrdersManager {
             event_tx,
             epoch,
             rule_cache: Arc::new(RwLock::new(HashMap::new())),
+            active_cache: ActiveOrdersCache::default(),
+            hot_snapshots: HotSnapshots::default(),
             catalog_service: None,
             pool: None,
             archive_service: None,
             tz: chrono_tz::Europe::Madrid,
             store_number: 1,
             business_day_cutoff: RwLock::new(chrono::NaiveTime::MIN),
+            tax_mode: RwLock::new(TaxMode::default()),
+            max_cash_change: RwLock::new(DEFAULT_MAX_CASH_CHANGE),
+            setup_complete: AtomicBool::new(true),
+            recorder: SessionRecorder::default(),
+            journal: None,
+            pms: None,
+            inventory: None,
+            coupons: None,
+            metrics: None,
+            fiscal: None,
+            fiscal_policy: FiscalPolicy::default(),
+            commit_alert_threshold: None,
+            #[cfg(feature = "chaos")]
+            faults: Default::default(),
         }
     }
 
+    /// Enable/disable active orders cache verification against redb
+    pub fn set_active_cache_verify(&self, enabled: bool) {
+        self.active_cache.set_verify(enabled);
+    }
+
     /// Get the server epoch (unique instance ID)
     pub fn epoch(&self) -> &str {
         &self.epoch
@@ -241,6 +451,31 @@ impl OrdersManager {
         cache.get(&order_id).cloned()
     }
 
+    /// 命令提交耗时超过阈值时告警 (通常意味着单写者被长事务或磁盘 IO 拖住)
+    fn check_commit_latency(
+        &self,
+        command: &'static str,
+        elapsed: std::time::Duration,
+        writer_wait: std::time::Duration,
+    ) {
+        let Some(threshold) = self.commit_alert_threshold else {
+            return;
+        };
+        if elapsed <= threshold {
+            return;
+        }
+        tracing::warn!(
+            command,
+            elapsed_ms = elapsed.as_millis() as u64,
+            writer_wait_ms = writer_wait.as_millis() as u64,
+            threshold_ms = threshold.as_millis() as u64,
+            "Order commit latency exceeded threshold (redb writer contention)"
+        );
+        if let Some(metrics) = &self.metrics {
+            metrics.inc_slow_commit();
+        }
+    }
+
     /// 清除订单的规则缓存和 redb 快照
     ///
     /// 订单终结时 (Complete/Void/Move/Merge) 调用。
@@ -306,11 +541,43 @@ impl OrdersManager {
         self.event_tx.subscribe()
     }
 
+    /// Number of live event subscribers (resource watchdog)
+    pub fn subscriber_count(&self) -> usize {
+        self.event_tx.receiver_count()
+    }
+
+    /// Number of orders with cached price rules (resource watchdog)
+    pub fn cached_rule_count(&self) -> usize {
+        self.rule_cache.read().len()
+    }
+
     /// Get the underlying storage
     pub fn storage(&self) -> &OrderStorage {
         &self.storage
     }
 
+    /// Session recorder (commands/events/bus traffic for field bug replay)
+    pub fn recorder(&self) -> &SessionRecorder {
+        &self.recorder
+    }
+
+    /// Write snapshots directly (session replay: restore the orders that were
+    /// active when the recording started)
+    pub fn seed_snapshots(&self, snapshots: &[OrderSnapshot]) -> ManagerResult<()> {
+        let txn = self.storage.begin_write()?;
+        for snapshot in snapshots {
+            self.storage.store_snapshot(&txn, snapshot)?;
+            if snapshot.status == OrderStatus::Active {
+                self.storage.mark_order_active(&txn, snapshot.order_id)?;
+            }
+        }
+        self.storage.clear_checkpoint(&txn)?;
+        txn.commit().map_err(StorageError::from)?;
+        self.active_cache.invalidate();
+        self.hot_snapshots.clear();
+        Ok(())
+    }
+
     /// Get the archive service if configured
     pub fn archive_service(&self) -> Option<&crate::archiving::OrderArchiveService> {
         self.archive_service.as_ref()
@@ -318,28 +585,7 @@ impl OrdersManager {
 
     /// Execute a command and return the response
     pub async fn execute_command(&self, cmd: OrderCommand) -> CommandResponse {
-        // Phase A: prefetch SQLite data
-        let prefetched = match self.prefetch_data(&cmd).await {
-            Ok(data) => data,
-            Err(err) => return CommandResponse::error(cmd.command_id, err.into()),
-        };
-
-        // Phase B: sync redb transaction
-        match self.process_command(cmd.clone(), prefetched) {
-            Ok((response, events)) => {
-                // Broadcast events after successful commit
-                for event in &events {
-                    if self.event_tx.send(event.clone()).is_err() {
-                        tracing::warn!("Event broadcast failed: no active receivers");
-                        break;
-                    }
-                }
-                // Phase C: post-transaction async actions
-                self.post_actions(&cmd, &events).await;
-                response
-            }
-            Err(err) => CommandResponse::error(cmd.command_id, err.into()),
-        }
+        self.execute_command_with_events(cmd).await.0
     }
 
     /// Execute a command and return both the response and generated events
@@ -349,6 +595,31 @@ impl OrdersManager {
         &self,
         cmd: OrderCommand,
     ) -> (CommandResponse, Vec<OrderEvent>) {
+        let recorded = self.recorder.is_active().then(|| cmd.clone());
+        let kind = cmd.payload.kind();
+        let span = tracing::info_span!(
+            "order.command",
+            kind,
+            command_id = cmd.command_id,
+            operator_id = cmd.operator_id,
+            success = tracing::field::Empty,
+            events = tracing::field::Empty,
+        );
+        let started = std::time::Instant::now();
+        let (response, events) = self.run_command(cmd).instrument(span.clone()).await;
+        span.record("success", response.success);
+        span.record("events", events.len());
+        if let Some(metrics) = &self.metrics {
+            metrics.observe_order_command(kind, started.elapsed(), response.success);
+        }
+        if let Some(cmd) = recorded {
+            self.recorder.record_command(&cmd, &response, &events);
+        }
+        (response, events)
+    }
+
+    /// Three-phase command flow (see module docs)
+    async fn run_command(&self, cmd: OrderCommand) -> (CommandResponse, Vec<OrderEvent>) {
         // Phase A: prefetch SQLite data
         let prefetched = match self.prefetch_data(&cmd).await {
             Ok(data) => data,
@@ -358,8 +629,22 @@ impl OrdersManager {
         };
 
         // Phase B: sync redb transaction
+        let credit_debit = prefetched.credit_debit;
+        let room_charge = prefetched.room_charge.clone();
+        let fiscal_code = prefetched.fiscal_code.clone();
         match self.process_command(cmd.clone(), prefetched) {
             Ok((response, events)) => {
+                self.release_unrecorded_credit(credit_debit, &events).await;
+                self.reverse_unrecorded_room_charge(room_charge, &events)
+                    .await;
+                self.cancel_unrecorded_fiscal_receipt(fiscal_code, &events)
+                    .await;
+                // Journal before broadcast: subscribers never see an event the journal lacks
+                if let Some(journal) = &self.journal
+                    && let Err(e) = journal.append(&events)
+                {
+                    tracing::error!(error = %e, "Failed to append events to journal");
+                }
                 // Broadcast events after successful commit
                 for event in &events {
                     if self.event_tx.send(event.clone()).is_err() {
@@ -371,7 +656,13 @@ impl OrdersManager {
                 self.post_actions(&cmd, &events).await;
                 (response, events)
             }
-            Err(err) => (CommandResponse::error(cmd.command_id, err.into()), vec![]),
+            Err(err) => {
+                self.release_unrecorded_credit(credit_debit, &[]).await;
+                self.reverse_unrecorded_room_charge(room_charge, &[]).await;
+                self.cancel_unrecorded_fiscal_receipt(fiscal_code, &[])
+                    .await;
+                (err.into_response(cmd.command_id), vec![])
+            }
         }
     }
 
@@ -387,6 +678,55 @@ impl OrdersManager {
         catalog.get_product_meta_batch(&product_ids)
     }
 
+    /// Reject products marked unavailable for the order's zone (e.g. bar-only items)
+    fn check_zone_availability(
+        &self,
+        order_id: i64,
+        items: &[shared::order::CartItemInput],
+    ) -> Result<(), OrderError> {
+        let Some(catalog) = &self.catalog_service else {
+            return Ok(());
+        };
+        let Some(zone_id) = self
+            .storage
+            .get_snapshot(order_id)
+            .ok()
+            .flatten()
+            .and_then(|s| s.zone_id)
+        else {
+            return Ok(());
+        };
+        let unavailable = catalog.zone_unavailable_products(zone_id);
+        if let Some(item) = items.iter().find(|i| unavailable.contains(&i.product_id)) {
+            return Err(OrderError::InvalidOperation(
+                CommandErrorCode::ProductUnavailableInZone,
+                format!("Product '{}' is not available in this zone", item.name),
+            ));
+        }
+        Ok(())
+    }
+
+    /// Reject products/specs currently on the 86 board
+    fn check_eighty_six(&self, items: &[shared::order::CartItemInput]) -> Result<(), OrderError> {
+        let Some(catalog) = &self.catalog_service else {
+            return Ok(());
+        };
+        for item in items {
+            let spec_id = item.selected_specification.as_ref().map(|s| s.id);
+            if let Some(entry) = catalog.find_eighty_sixed(item.product_id, spec_id) {
+                let name = match &entry.spec_name {
+                    Some(spec) => format!("{} ({})", item.name, spec),
+                    None => item.name.clone(),
+                };
+                return Err(OrderError::InvalidOperation(
+                    CommandErrorCode::ProductEightySixed,
+                    format!("Product '{}' is 86'd", name),
+                ));
+            }
+        }
+        Ok(())
+    }
+
     // ========== Phase A: Async prefetch ==========
 
     /// 预取 redb 事务所需的 SQLite 数据
@@ -398,9 +738,51 @@ impl OrdersManager {
             mg_rules: vec![],
             link_member: None,
             redeem_stamp: None,
+            redeem_points: None,
+            apply_coupon: None,
             auto_cancel: vec![],
+            credit_debit: None,
+            room_charge: None,
+            payment_surcharge: None,
+            delivery_rule: None,
+            fiscal_code: None,
         };
 
+        if let shared::order::OrderCommandPayload::OpenTable { .. } = &cmd.payload
+            && !self.setup_complete.load(Ordering::Relaxed)
+        {
+            return Err(ManagerError::InvalidOperation(
+                CommandErrorCode::SetupIncomplete,
+                "Initial setup is not complete".to_string(),
+            ));
+        }
+
+        // 储值只能通过 AddPayment 支付（分单支付无法预分配 payment_id）
+        if let shared::order::OrderCommandPayload::SplitByItems { payment_method, .. }
+        | shared::order::OrderCommandPayload::SplitByAmount { payment_method, .. }
+        | shared::order::OrderCommandPayload::StartAaSplit { payment_method, .. }
+        | shared::order::OrderCommandPayload::PayAaSplit { payment_method, .. } = &cmd.payload
+            && (payment_method == MEMBER_CREDIT_METHOD || payment_method == ROOM_CHARGE_METHOD)
+        {
+            return Err(ManagerError::InvalidOperation(
+                CommandErrorCode::InvalidOperation,
+                format!("{payment_method} cannot be used for split payments"),
+            ));
+        }
+
+        // 挂房账不依赖 SQLite
+        if let shared::order::OrderCommandPayload::AddPayment { order_id, payment } = &cmd.payload
+            && payment.method == ROOM_CHARGE_METHOD
+        {
+            data.room_charge = self.post_room_charge(cmd, *order_id, payment).await?;
+            return Ok(data);
+        }
+
+        // 税控登记不依赖 SQLite
+        if let shared::order::OrderCommandPayload::CompleteOrder { order_id, .. } = &cmd.payload {
+            data.fiscal_code = self.register_fiscal_receipt(cmd, *order_id).await?;
+        }
+
         let Some(pool) = &self.pool else {
             return Ok(data);
         };
@@ -422,6 +804,24 @@ impl OrdersManager {
                         });
                 }
             }
+            shared::order::OrderCommandPayload::TransferItems {
+                target_order_id, ..
+            } => {
+                // MG discounts of transferred items follow the target order's member
+                if let Ok(Some(snapshot)) = self.storage.get_snapshot(*target_order_id)
+                    && let Some(mg_id) = snapshot.marketing_group_id
+                {
+                    data.mg_rules =
+                        crate::db::repository::marketing_group::find_active_rules_by_group(
+                            pool, mg_id,
+                        )
+                        .await
+                        .unwrap_or_else(|e| {
+                            tracing::warn!(target_order_id, error = %e, "Failed to query MG rules for TransferItems, proceeding without discounts");
+                            vec![]
+                        });
+                }
+            }
             shared::order::OrderCommandPayload::LinkMember { member_id, .. } => {
                 let member = crate::db::repository::member::find_member_by_id(pool, *member_id)
                     .await
@@ -498,14 +898,56 @@ impl OrdersManager {
                     )
                 })?;
 
-                let activity = sqlx::query_as::<_, shared::models::StampActivity>(
-                    "SELECT id, marketing_group_id, name, stamps_required, reward_quantity, reward_strategy, designated_product_id, is_cyclic, is_active, created_at, updated_at FROM stamp_activity WHERE id = ? AND is_active = 1",
+                let activity = crate::db::repository::marketing_group::find_activity_by_id(
+                    pool,
+                    *stamp_activity_id,
+                )
+                .await
+                .map_err(|e| {
+                    OrderError::InvalidOperation(
+                        CommandErrorCode::SystemBusy,
+                        format!("Failed to query stamp activity: {e}"),
+                    )
+                })?
+                .filter(|a| a.is_active)
+                .ok_or_else(|| {
+                    OrderError::InvalidOperation(
+                        CommandErrorCode::StampActivityNotFound,
+                        format!(
+                            "Stamp activity {} not found or not active",
+                            stamp_activity_id
+                        ),
+                    )
+                })?;
+
+                // 活动档期 / 时段外不可兑换
+                if !crate::marketing::stamp_tracker::is_activity_open(
+                    &activity,
+                    shared::util::now_millis(),
+                    self.tz,
+                ) {
+                    return Err(OrderError::InvalidOperation(
+                        CommandErrorCode::StampActivityOutOfWindow,
+                        format!(
+                            "Stamp activity {} is outside its campaign window",
+                            activity.name
+                        ),
+                    )
+                    .into());
+                }
+                let earned_today = crate::db::repository::stamp::earned_since(
+                    pool,
+                    member_id,
+                    *stamp_activity_id,
+                    self.business_day_start(),
                 )
-                .bind(*stamp_activity_id)
-                .fetch_optional(pool)
                 .await
-                .map_err(|e| OrderError::InvalidOperation(CommandErrorCode::SystemBusy, format!("Failed to query stamp activity: {e}")))?
-                .ok_or_else(|| OrderError::InvalidOperation(CommandErrorCode::StampActivityNotFound, format!("Stamp activity {} not found or not active", stamp_activity_id)))?;
+                .map_err(|e| {
+                    OrderError::InvalidOperation(
+                        CommandErrorCode::SystemBusy,
+                        format!("Failed to query stamp ledger: {e}"),
+                    )
+                })?;
 
                 let stamp_progress = crate::db::repository::stamp::find_progress(
                     pool,
@@ -562,10 +1004,114 @@ impl OrdersManager {
                 data.redeem_stamp = Some(RedeemStampPrefetch {
                     activity,
                     current_stamps,
+                    earned_today,
                     stamp_targets,
                     reward_targets,
                 });
             }
+            shared::order::OrderCommandPayload::RedeemPoints { order_id, points }
+                if *points > 0 =>
+            {
+                let snapshot = self
+                    .storage
+                    .get_snapshot(*order_id)?
+                    .ok_or(OrderError::OrderNotFound(*order_id))?;
+                let member_id = snapshot.member_id.ok_or_else(|| {
+                    OrderError::InvalidOperation(
+                        CommandErrorCode::MemberRequired,
+                        "Must have a member linked to redeem points".to_string(),
+                    )
+                })?;
+
+                let member = crate::db::repository::member::find_member_by_id(pool, member_id)
+                    .await
+                    .map_err(|e| {
+                        OrderError::InvalidOperation(
+                            CommandErrorCode::SystemBusy,
+                            format!("Failed to query member: {e}"),
+                        )
+                    })?
+                    .ok_or_else(|| {
+                        OrderError::InvalidOperation(
+                            CommandErrorCode::MemberNotFound,
+                            format!("Member {} not found", member_id),
+                        )
+                    })?;
+
+                // 按会员当前营销组的兑换规则
+                let mg = crate::db::repository::marketing_group::find_by_id(
+                    pool,
+                    member.marketing_group_id,
+                )
+                .await
+                .map_err(|e| {
+                    OrderError::InvalidOperation(
+                        CommandErrorCode::SystemBusy,
+                        format!("Failed to query marketing group: {e}"),
+                    )
+                })?
+                .ok_or_else(|| {
+                    OrderError::InvalidOperation(
+                        CommandErrorCode::MarketingGroupNotFound,
+                        format!("Marketing group {} not found", member.marketing_group_id),
+                    )
+                })?;
+
+                if !crate::marketing::points::redemption_enabled(&mg) {
+                    return Err(OrderError::InvalidOperation(
+                        CommandErrorCode::PointsRedemptionUnavailable,
+                        format!(
+                            "Marketing group {} does not allow redeeming points",
+                            mg.name
+                        ),
+                    )
+                    .into());
+                }
+                let amount =
+                    crate::marketing::points::redemption_value(*points, &mg).ok_or_else(|| {
+                        OrderError::InvalidOperation(
+                            CommandErrorCode::InvalidAmount,
+                            format!(
+                                "Points must be a multiple of {}",
+                                mg.points_redeem_unit.unwrap_or(1)
+                            ),
+                        )
+                    })?;
+                if *points > member.points_balance {
+                    return Err(OrderError::InvalidOperation(
+                        CommandErrorCode::InsufficientPoints,
+                        format!(
+                            "Insufficient points: {} available, {} requested",
+                            member.points_balance, points
+                        ),
+                    )
+                    .into());
+                }
+
+                data.redeem_points = Some(RedeemPointsPrefetch { amount });
+            }
+            shared::order::OrderCommandPayload::ApplyCoupon {
+                code: Some(code), ..
+            } => {
+                let coupon = crate::db::repository::coupon::find_by_code(pool, code)
+                    .await
+                    .map_err(|e| {
+                        OrderError::InvalidOperation(
+                            CommandErrorCode::SystemBusy,
+                            format!("Failed to query coupon: {e}"),
+                        )
+                    })?
+                    .ok_or_else(|| {
+                        OrderError::InvalidOperation(
+                            CommandErrorCode::CouponNotFound,
+                            format!("Coupon {} not found", code.trim()),
+                        )
+                    })?;
+                crate::marketing::coupon::check_redeemable(&coupon, shared::util::now_millis())
+                    .map_err(|(code, msg)| OrderError::InvalidOperation(code, msg))?;
+
+                data.apply_coupon = Some(crate::marketing::coupon::to_applied(&coupon));
+            }
             shared::order::OrderCommandPayload::RemoveItem { order_id, .. }
             | shared::order::OrderCommandPayload::CompItem { order_id, .. } => {
                 // Prefetch stamp data for auto-cancel validation
@@ -575,13 +1121,17 @@ impl OrdersManager {
                     for redemption in &snapshot.stamp_redemptions {
                         let activity_id = redemption.stamp_activity_id;
 
-                        let activity = sqlx::query_as::<_, shared::models::StampActivity>(
-                            "SELECT id, marketing_group_id, name, stamps_required, reward_quantity, reward_strategy, designated_product_id, is_cyclic, is_active, created_at, updated_at FROM stamp_activity WHERE id = ?",
+                        let activity = crate::db::repository::marketing_group::find_activity_by_id(
+                            pool,
+                            activity_id,
                         )
-                        .bind(activity_id)
-                        .fetch_optional(pool)
                         .await
-                        .map_err(|e| ManagerError::from(OrderError::InvalidOperation(CommandErrorCode::SystemBusy, format!("Failed to query stamp activity: {e}"))))?;
+                        .map_err(|e| {
+                            ManagerError::from(OrderError::InvalidOperation(
+                                CommandErrorCode::SystemBusy,
+                                format!("Failed to query stamp activity: {e}"),
+                            ))
+                        })?;
 
                         let progress = crate::db::repository::stamp::find_progress(
                             pool,
@@ -610,25 +1160,229 @@ impl OrdersManager {
                                 ))
                             })?;
 
+                        let earned_today = crate::db::repository::stamp::earned_since(
+                            pool,
+                            member_id,
+                            activity_id,
+                            self.business_day_start(),
+                        )
+                        .await
+                        .map_err(|e| {
+                            ManagerError::from(OrderError::InvalidOperation(
+                                CommandErrorCode::SystemBusy,
+                                format!("Failed to query stamp ledger: {e}"),
+                            ))
+                        })?;
+
                         data.auto_cancel.push(StampCancelPrefetch {
                             activity_id,
                             activity,
                             current_stamps,
+                            earned_today,
                             stamp_targets,
                         });
                     }
                 }
             }
-            _ => {}
-        }
-
-        Ok(data)
-    }
-
-    // ========== Phase B: Sync transaction ==========
-
-    /// Process command in a sync redb transaction using prefetched data
-    fn process_command(
+            shared::order::OrderCommandPayload::AddPayment { order_id, payment }
+                if payment.method == MEMBER_CREDIT_METHOD =>
+            {
+                data.credit_debit = self
+                    .debit_member_credit(pool, cmd, *order_id, payment)
+                    .await?;
+            }
+            shared::order::OrderCommandPayload::AddPayment { payment, .. } => {
+                match crate::db::repository::payment_surcharge::find_applicable(
+                    pool,
+                    &payment.method,
+                )
+                .await
+                {
+                    Ok(rule) => data.payment_surcharge = rule,
+                    Err(e) => {
+                        tracing::warn!(method = %payment.method, error = %e, "Failed to query payment surcharge rule, proceeding without");
+                    }
+                }
+            }
+            shared::order::OrderCommandPayload::CompleteOrder { order_id, .. } => {
+                // 外卖订单: 按订单元数据中的渠道/区域查询起送价规则
+                if let Ok(Some(snapshot)) = self.storage.get_snapshot(*order_id)
+                    && let Some(channel) =
+                        snapshot.metadata.get(shared::models::DELIVERY_CHANNEL_KEY)
+                {
+                    let zone = snapshot
+                        .metadata
+                        .get(shared::models::DELIVERY_ZONE_KEY)
+                        .map(String::as_str);
+                    match crate::db::repository::delivery_rule::find_applicable(pool, channel, zone)
+                        .await
+                    {
+                        Ok(rule) => data.delivery_rule = rule,
+                        Err(e) => {
+                            tracing::warn!(order_id, channel = %channel, error = %e, "Failed to query delivery rule, proceeding without");
+                        }
+                    }
+                }
+            }
+            // 拼桌中的副桌不能单独开台/被移入（订单绑定在主桌）
+            shared::order::OrderCommandPayload::OpenTable {
+                table_id: Some(table_id),
+                ..
+            }
+            | shared::order::OrderCommandPayload::MoveOrder {
+                target_table_id: table_id,
+                ..
+            } => match crate::db::repository::table_group::find_by_table(pool, *table_id).await {
+                Ok(Some(group)) if group.host_table_id != *table_id => {
+                    return Err(ManagerError::TableOccupied(format!(
+                        "Table {} is joined to {}, use the host table",
+                        table_id, group.name
+                    )));
+                }
+                Ok(_) => {}
+                Err(e) => {
+                    tracing::warn!(table_id, error = %e, "Failed to query table group, proceeding");
+                }
+            },
+            _ => {}
+        }
+
+        Ok(data)
+    }
+
+    /// 储值支付扣款（在 redb 事务前执行，失败时不产生任何修改）
+    async fn debit_member_credit(
+        &self,
+        pool: &sqlx::SqlitePool,
+        cmd: &OrderCommand,
+        order_id: i64,
+        payment: &shared::order::PaymentInput,
+    ) -> ManagerResult<Option<CreditDebit>> {
+        // 重复命令不再扣款（Phase B 返回 duplicate）
+        if self.storage.is_command_processed(cmd.command_id)? {
+            return Ok(None);
+        }
+        crab_order_core::money::validate_payment(payment)?;
+
+        let snapshot = self
+            .storage
+            .get_snapshot(order_id)?
+            .ok_or(ManagerError::OrderNotFound(order_id))?;
+        let member_id = snapshot.member_id.ok_or_else(|| {
+            ManagerError::InvalidOperation(
+                CommandErrorCode::MemberRequired,
+                "Must have a member linked to pay with stored credit".to_string(),
+            )
+        })?;
+
+        let payment_id = shared::util::snowflake_id();
+        let debit = crate::db::repository::member_credit::debit_for_payment(
+            pool,
+            member_id,
+            order_id,
+            payment_id,
+            payment.amount,
+            cmd.operator_id,
+            &cmd.operator_name,
+        )
+        .await
+        .map_err(|e| {
+            ManagerError::InvalidOperation(
+                CommandErrorCode::SystemBusy,
+                format!("Failed to debit stored credit: {e}"),
+            )
+        })?;
+        if debit.is_none() {
+            return Err(ManagerError::InvalidOperation(
+                CommandErrorCode::InsufficientCredit,
+                format!(
+                    "Member {} stored credit is insufficient for {:.2}",
+                    member_id, payment.amount
+                ),
+            ));
+        }
+        Ok(Some(CreditDebit { payment_id }))
+    }
+
+    /// 挂房账：核验住客并在 PMS 过账（在 redb 事务前执行）
+    async fn post_room_charge(
+        &self,
+        cmd: &OrderCommand,
+        order_id: i64,
+        payment: &shared::order::PaymentInput,
+    ) -> ManagerResult<Option<RoomChargePosted>> {
+        // 重复命令不再过账（Phase B 返回 duplicate）
+        if self.storage.is_command_processed(cmd.command_id)? {
+            return Ok(None);
+        }
+        let pms = self.pms.as_ref().ok_or_else(|| {
+            ManagerError::InvalidOperation(
+                CommandErrorCode::InvalidOperation,
+                "Room charge is not configured on this server".to_string(),
+            )
+        })?;
+        crab_order_core::money::validate_payment(payment)?;
+        let room_number = payment
+            .reference
+            .as_deref()
+            .map(str::trim)
+            .filter(|r| !r.is_empty())
+            .ok_or_else(|| {
+                ManagerError::InvalidOperation(
+                    CommandErrorCode::InvalidOperation,
+                    "Room number is required for a room charge".to_string(),
+                )
+            })?
+            .to_string();
+        let snapshot = self
+            .storage
+            .get_snapshot(order_id)?
+            .ok_or(ManagerError::OrderNotFound(order_id))?;
+
+        let rejected = |e: crate::pms::PmsError| {
+            ManagerError::InvalidOperation(CommandErrorCode::RoomChargeRejected, e.to_string())
+        };
+        let guest = pms.verify_guest(&room_number).await.map_err(rejected)?;
+        if !guest.charges_allowed {
+            return Err(ManagerError::InvalidOperation(
+                CommandErrorCode::RoomChargeRejected,
+                format!("Room {room_number} does not accept charges"),
+            ));
+        }
+
+        let payment_id = shared::util::snowflake_id();
+        let confirmation = pms
+            .post_charge(&RoomCharge {
+                room_number: room_number.clone(),
+                amount: payment.amount,
+                receipt_number: snapshot.receipt_number.clone(),
+                external_id: payment_id,
+                description: match &snapshot.table_name {
+                    Some(table) => format!("{} ({table})", snapshot.receipt_number),
+                    None => snapshot.receipt_number.clone(),
+                },
+            })
+            .await
+            .map_err(rejected)?;
+        tracing::info!(
+            order_id,
+            payment_id,
+            room = %room_number,
+            guest = %guest.guest_name,
+            reference = %confirmation.reference,
+            "Room charge posted"
+        );
+        Ok(Some(RoomChargePosted {
+            payment_id,
+            room_number,
+            reference: confirmation.reference,
+        }))
+    }
+
+    // ========== Phase B: Sync transaction ==========
+
+    /// Process command in a sync redb transaction using prefetched data
+    fn process_command(
         &self,
         cmd: OrderCommand,
         prefetched: PrefetchedData,
@@ -681,8 +1435,13 @@ impl OrdersManager {
             _ => None,
         };
 
-        // 4. Begin write transaction
+        // 4. Begin write transaction (等待单写者的时间单独计量)
+        let txn_started = std::time::Instant::now();
         let txn = self.storage.begin_write()?;
+        let writer_wait = txn_started.elapsed();
+        if let Some(metrics) = &self.metrics {
+            metrics.observe_redb_writer_wait(writer_wait);
+        }
 
         // Double-check idempotency within transaction
         if self
@@ -692,6 +1451,21 @@ impl OrdersManager {
             return Ok((CommandResponse::duplicate(cmd.command_id), vec![]));
         }
 
+        // Optimistic concurrency: reject edits built against a stale version
+        if let Some(expected) = cmd.expected_version
+            && let Some(order_id) = cmd.target_order_id()
+            && let Some(mut snapshot) = self.storage.get_snapshot_txn(&txn, order_id)?
+            && snapshot.version() != expected
+        {
+            ensure_line_totals(&mut snapshot);
+            return Err(ManagerError::VersionConflict {
+                order_id,
+                expected,
+                current: snapshot.version(),
+                snapshot: Box::new(snapshot),
+            });
+        }
+
         // 5. Get current sequence for context initialization
         let current_sequence = self.storage.get_current_sequence()?;
 
@@ -705,7 +1479,7 @@ impl OrdersManager {
         };
 
         // 7. Convert to action and execute (all sync, no I/O)
-        let action: CommandAction = match &cmd.payload {
+        let mut action: CommandAction = match &cmd.payload {
             shared::order::OrderCommandPayload::OpenTable {
                 table_id,
                 table_name,
@@ -730,9 +1504,12 @@ impl OrdersManager {
                     is_retail: *is_retail,
                     queue_number: pre_generated_queue,
                     receipt_number,
+                    tax_mode: self.tax_mode(),
                 })
             }
             shared::order::OrderCommandPayload::AddItems { order_id, items } => {
+                self.check_zone_availability(*order_id, items)?;
+                self.check_eighty_six(items)?;
                 let cached_rules = self.get_cached_rules(*order_id).unwrap_or_default();
                 let now = shared::util::now_millis();
                 let rules: Vec<PriceRule> = cached_rules
@@ -749,6 +1526,16 @@ impl OrdersManager {
                     mg_rules: prefetched.mg_rules,
                 })
             }
+            shared::order::OrderCommandPayload::TransferItems {
+                source_order_id,
+                target_order_id,
+                instance_ids,
+            } => CommandAction::TransferItems(super::actions::TransferItemsAction {
+                source_order_id: *source_order_id,
+                target_order_id: *target_order_id,
+                instance_ids: instance_ids.clone(),
+                target_mg_rules: prefetched.mg_rules,
+            }),
             shared::order::OrderCommandPayload::LinkMember {
                 order_id,
                 member_id,
@@ -804,9 +1591,13 @@ impl OrdersManager {
                         category_id: item.category_id,
                     })
                     .collect();
-                let order_bonus = crate::marketing::stamp_tracker::count_stamps_for_order(
-                    &items_with_category,
-                    &rs.stamp_targets,
+                let order_bonus = crate::marketing::stamp_tracker::cap_daily_earn(
+                    crate::marketing::stamp_tracker::count_stamps_for_order(
+                        &items_with_category,
+                        &rs.stamp_targets,
+                    ),
+                    rs.earned_today,
+                    rs.activity.max_stamps_per_day,
                 );
                 let effective_stamps = rs.current_stamps + order_bonus;
 
@@ -881,8 +1672,102 @@ impl OrdersManager {
                     reward_product_info,
                 })
             }
+            shared::order::OrderCommandPayload::RedeemPoints { order_id, points } => {
+                // points = 0 清除兑换，无需预取
+                let amount = if *points > 0 {
+                    prefetched
+                        .redeem_points
+                        .ok_or_else(|| {
+                            ManagerError::InvalidOperation(
+                                CommandErrorCode::PointsRedemptionUnavailable,
+                                "Loyalty points are not available on this server".to_string(),
+                            )
+                        })?
+                        .amount
+                } else {
+                    0.0
+                };
+                CommandAction::RedeemPoints(super::actions::RedeemPointsAction {
+                    order_id: *order_id,
+                    points: *points,
+                    amount,
+                })
+            }
+            shared::order::OrderCommandPayload::ApplyCoupon { order_id, code } => {
+                // code = None 移除优惠码，无需预取
+                let coupon = match code {
+                    Some(_) => Some(prefetched.apply_coupon.clone().ok_or_else(|| {
+                        ManagerError::InvalidOperation(
+                            CommandErrorCode::CouponNotFound,
+                            "Coupons are not available on this server".to_string(),
+                        )
+                    })?),
+                    None => None,
+                };
+                CommandAction::ApplyCoupon(super::actions::ApplyCouponAction {
+                    order_id: *order_id,
+                    coupon,
+                })
+            }
+            shared::order::OrderCommandPayload::AddPayment { order_id, payment }
+                if payment.method == MEMBER_CREDIT_METHOD =>
+            {
+                let debit = prefetched.credit_debit.ok_or_else(|| {
+                    ManagerError::InvalidOperation(
+                        CommandErrorCode::InvalidOperation,
+                        "Stored credit is not available on this server".to_string(),
+                    )
+                })?;
+                CommandAction::AddPayment(super::actions::AddPaymentAction {
+                    order_id: *order_id,
+                    payment: payment.clone(),
+                    payment_id: Some(debit.payment_id),
+                    surcharge_rule: None,
+                    max_cash_change: DEFAULT_MAX_CASH_CHANGE,
+                })
+            }
+            shared::order::OrderCommandPayload::AddPayment { order_id, payment }
+                if payment.method == ROOM_CHARGE_METHOD =>
+            {
+                let charge = prefetched.room_charge.ok_or_else(|| {
+                    ManagerError::InvalidOperation(
+                        CommandErrorCode::InvalidOperation,
+                        "Room charge is not configured on this server".to_string(),
+                    )
+                })?;
+                // 房间号写入 note (保留收银员备注)，reference 记录 PMS 确认号
+                let mut payment = payment.clone();
+                payment.note = Some(room_charge_note(
+                    &charge.room_number,
+                    payment.note.as_deref(),
+                ));
+                payment.reference = Some(charge.reference);
+                CommandAction::AddPayment(super::actions::AddPaymentAction {
+                    order_id: *order_id,
+                    payment,
+                    payment_id: Some(charge.payment_id),
+                    surcharge_rule: None,
+                    max_cash_change: DEFAULT_MAX_CASH_CHANGE,
+                })
+            }
             _ => (&cmd).into(),
         };
+        let max_cash_change = *self.max_cash_change.read();
+        match &mut action {
+            CommandAction::AddPayment(add_payment) => {
+                add_payment.surcharge_rule = prefetched.payment_surcharge;
+                add_payment.max_cash_change = max_cash_change;
+            }
+            CommandAction::SplitByItems(split) => split.max_cash_change = max_cash_change,
+            CommandAction::SplitByAmount(split) => split.max_cash_change = max_cash_change,
+            CommandAction::StartAaSplit(split) => split.max_cash_change = max_cash_change,
+            CommandAction::PayAaSplit(split) => split.max_cash_change = max_cash_change,
+            _ => {}
+        }
+        if let CommandAction::CompleteOrder(complete) = &mut action {
+            complete.delivery_rule = prefetched.delivery_rule;
+            complete.fiscal_code = prefetched.fiscal_code;
+        }
         let mut events = action
             .execute(&mut ctx, &metadata)
             .map_err(ManagerError::from)?;
@@ -921,10 +1806,8 @@ impl OrdersManager {
             events.extend(cancel_events);
         }
 
-        // 9. Persist events
-        for event in &events {
-            self.storage.store_event(&txn, event)?;
-        }
+        // 9. Persist events (one table handle for the whole batch)
+        self.storage.store_events(&txn, &events)?;
 
         // 10. Persist snapshots and update active order tracking
         for snapshot in ctx.modified_snapshots() {
@@ -955,8 +1838,28 @@ impl OrdersManager {
         // 12. Mark command processed
         self.storage.mark_command_processed(&txn, cmd.command_id)?;
 
-        // 13. Commit transaction
+        // 13. Commit transaction (cache lock held so cache updates follow commit order)
+        let modified: Vec<OrderSnapshot> = ctx.modified_snapshots().cloned().collect();
+        #[cfg(feature = "chaos")]
+        if self.faults.inject(crate::chaos::FaultPoint::RedbCommit) {
+            return Err(ManagerError::Storage(StorageError::Commit(
+                redb::CommitError::Storage(redb::StorageError::Io(std::io::Error::other(
+                    "chaos: injected redb commit failure",
+                ))),
+            )));
+        }
+        let cache_commit = self.active_cache.begin_commit();
         txn.commit().map_err(StorageError::from)?;
+        let generation = self.storage.removal_generation();
+        for snapshot in &modified {
+            self.hot_snapshots.insert(snapshot.clone(), generation);
+        }
+        cache_commit.apply(modified);
+        let txn_elapsed = txn_started.elapsed();
+        if let Some(metrics) = &self.metrics {
+            metrics.observe_redb_transaction(txn_elapsed);
+        }
+        self.check_commit_latency(cmd.payload.kind(), txn_elapsed, writer_wait);
 
         // 14. Clean up rule cache for terminal orders
         match &cmd.payload {
@@ -983,10 +1886,384 @@ impl OrdersManager {
     // ========== Phase C: Post-transaction async actions ==========
 
     /// 事务提交后的异步后置操作
-    async fn post_actions(&self, cmd: &OrderCommand, _events: &[OrderEvent]) {
+    async fn post_actions(&self, cmd: &OrderCommand, events: &[OrderEvent]) {
         // Track stamps for completed orders with linked members
         if let shared::order::OrderCommandPayload::CompleteOrder { order_id, .. } = &cmd.payload {
             self.track_stamps_on_completion(*order_id).await;
+            self.settle_points_on_completion(*order_id, events).await;
+            self.redeem_coupon_on_completion(*order_id, events).await;
+            self.deduct_stock_on_completion(*order_id, events).await;
+        }
+        self.refund_member_credit(cmd, events).await;
+        self.reverse_room_charges(events).await;
+    }
+
+    // ========== Inventory ==========
+
+    /// 订单完成 → 扣减库存 (重复命令无事件，不会重复扣减)
+    async fn deduct_stock_on_completion(&self, order_id: i64, events: &[OrderEvent]) {
+        let Some(inventory) = &self.inventory else {
+            return;
+        };
+        let completed = events
+            .iter()
+            .any(|e| matches!(e.payload, EventPayload::OrderCompleted { .. }));
+        if !completed {
+            return;
+        }
+        match self.storage.get_snapshot(order_id) {
+            Ok(Some(snapshot)) => inventory.on_order_completed(&snapshot).await,
+            Ok(None) => {}
+            Err(e) => {
+                tracing::error!(order_id, error = %e, "Failed to load snapshot for stock deduction");
+            }
+        }
+    }
+
+    // ========== Coupons ==========
+
+    /// 订单完成 → 核销优惠码 (以 order_id 去重，重复命令不会重复计次)
+    async fn redeem_coupon_on_completion(&self, order_id: i64, events: &[OrderEvent]) {
+        let Some(coupons) = &self.coupons else {
+            return;
+        };
+        let completed = events
+            .iter()
+            .any(|e| matches!(e.payload, EventPayload::OrderCompleted { .. }));
+        if !completed {
+            return;
+        }
+        match self.storage.get_snapshot(order_id) {
+            Ok(Some(snapshot)) => coupons.on_order_completed(&snapshot).await,
+            Ok(None) => {}
+            Err(e) => {
+                tracing::error!(order_id, error = %e, "Failed to load snapshot for coupon redemption");
+            }
+        }
+    }
+
+    // ========== Member Stored Credit ==========
+
+    /// Phase A 已扣款但事件未记录该支付（事务失败 / 重复命令）→ 退回
+    async fn release_unrecorded_credit(&self, debit: Option<CreditDebit>, events: &[OrderEvent]) {
+        let (Some(debit), Some(pool)) = (debit, &self.pool) else {
+            return;
+        };
+        let recorded = events.iter().any(|e| {
+            matches!(e.payload, EventPayload::PaymentAdded { payment_id, .. } if payment_id == debit.payment_id)
+        });
+        if recorded {
+            return;
+        }
+        if let Err(e) = crate::db::repository::member_credit::refund_payment(
+            pool,
+            debit.payment_id,
+            "payment not recorded",
+            None,
+            None,
+        )
+        .await
+        {
+            tracing::error!(payment_id = debit.payment_id, error = %e, "Failed to release stored credit debit");
+        }
+    }
+
+    /// 储值支付取消 / 订单取消作废 → 退回会员余额
+    ///
+    /// 损失结算 (LossSettled) 的作废保留已付款项，不退回。
+    async fn refund_member_credit(&self, cmd: &OrderCommand, events: &[OrderEvent]) {
+        let Some(pool) = &self.pool else { return };
+
+        let mut refunds: Vec<(i64, &str)> = Vec::new();
+        for event in events {
+            match &event.payload {
+                EventPayload::PaymentCancelled {
+                    payment_id, method, ..
+                } if method == MEMBER_CREDIT_METHOD => {
+                    refunds.push((*payment_id, "payment cancelled"));
+                }
+                EventPayload::OrderVoided {
+                    void_type: VoidType::Cancelled,
+                    ..
+                } => {
+                    let Ok(Some(snapshot)) = self.storage.get_snapshot(event.order_id) else {
+                        continue;
+                    };
+                    refunds.extend(
+                        snapshot
+                            .payments
+                            .iter()
+                            .filter(|p| !p.cancelled && p.method == MEMBER_CREDIT_METHOD)
+                            .map(|p| (p.payment_id, "order voided")),
+                    );
+                }
+                _ => {}
+            }
+        }
+
+        for (payment_id, note) in refunds {
+            match crate::db::repository::member_credit::refund_payment(
+                pool,
+                payment_id,
+                note,
+                Some(cmd.operator_id),
+                Some(&cmd.operator_name),
+            )
+            .await
+            {
+                Ok(Some(txn)) => {
+                    tracing::info!(
+                        payment_id,
+                        member_id = txn.member_id,
+                        amount = txn.amount,
+                        "Stored credit refunded"
+                    );
+                }
+                Ok(None) => {}
+                Err(e) => {
+                    tracing::error!(payment_id, error = %e, "Failed to refund stored credit");
+                }
+            }
+        }
+    }
+
+    // ========== Room Charge (PMS) ==========
+
+    /// Phase A 已过账但事件未记录该支付（事务失败 / 校验失败）→ PMS 冲正
+    async fn reverse_unrecorded_room_charge(
+        &self,
+        charge: Option<RoomChargePosted>,
+        events: &[OrderEvent],
+    ) {
+        let (Some(charge), Some(pms)) = (charge, &self.pms) else {
+            return;
+        };
+        let recorded = events.iter().any(|e| {
+            matches!(e.payload, EventPayload::PaymentAdded { payment_id, .. } if payment_id == charge.payment_id)
+        });
+        if recorded {
+            return;
+        }
+        if let Err(e) = pms.reverse_charge(&charge.reference).await {
+            tracing::error!(
+                payment_id = charge.payment_id,
+                reference = %charge.reference,
+                error = %e,
+                "Failed to reverse unrecorded room charge, reverse it in the PMS"
+            );
+        }
+    }
+
+    /// 挂房账支付取消 / 订单取消作废 → PMS 冲正
+    async fn reverse_room_charges(&self, events: &[OrderEvent]) {
+        let Some(pms) = &self.pms else { return };
+
+        let mut references: Vec<(i64, String)> = Vec::new();
+        for event in events {
+            let cancelled_payment = match &event.payload {
+                EventPayload::PaymentCancelled {
+                    payment_id, method, ..
+                } if method == ROOM_CHARGE_METHOD => Some(*payment_id),
+                EventPayload::OrderVoided {
+                    void_type: VoidType::Cancelled,
+                    ..
+                } => None,
+                _ => continue,
+            };
+            let Ok(Some(snapshot)) = self.storage.get_snapshot(event.order_id) else {
+                continue;
+            };
+            references.extend(
+                snapshot
+                    .payments
+                    .iter()
+                    .filter(|p| p.method == ROOM_CHARGE_METHOD)
+                    .filter(|p| match cancelled_payment {
+                        Some(payment_id) => p.payment_id == payment_id,
+                        None => !p.cancelled,
+                    })
+                    .filter_map(|p| Some((p.payment_id, p.reference.clone()?))),
+            );
+        }
+
+        for (payment_id, reference) in references {
+            match pms.reverse_charge(&reference).await {
+                Ok(()) => tracing::info!(payment_id, %reference, "Room charge reversed"),
+                Err(e) => tracing::error!(
+                    payment_id,
+                    %reference,
+                    error = %e,
+                    "Failed to reverse room charge, reverse it in the PMS"
+                ),
+            }
+        }
+    }
+
+    // ========== Fiscal Device ==========
+
+    /// 结单前在税控设备登记收据（在 redb 事务前执行）
+    ///
+    /// 先校验订单可结单，避免设备记录必然失败的结单。设备不可达时重试，
+    /// 仍失败按 [`FiscalPolicy`] 拒绝结单或无税控码结单。
+    async fn register_fiscal_receipt(
+        &self,
+        cmd: &OrderCommand,
+        order_id: i64,
+    ) -> ManagerResult<Option<String>> {
+        let Some(device) = &self.fiscal else {
+            return Ok(None);
+        };
+        // 重复命令不再登记（Phase B 返回 duplicate）
+        if self.storage.is_command_processed(cmd.command_id)? {
+            return Ok(None);
+        }
+        let snapshot = self
+            .storage
+            .get_snapshot(order_id)?
+            .ok_or(ManagerError::OrderNotFound(order_id))?;
+        if snapshot.status != OrderStatus::Active {
+            // Phase B 返回具体状态错误
+            return Ok(None);
+        }
+        let paid: rust_decimal::Decimal = snapshot
+            .payments
+            .iter()
+            .filter(|p| !p.cancelled)
+            .map(|p| crab_order_core::money::to_decimal(p.amount))
+            .sum();
+        let paid = crab_order_core::money::to_f64(paid);
+        if !crab_order_core::money::is_payment_sufficient(paid, snapshot.total) {
+            return Ok(None);
+        }
+
+        let receipt = FiscalReceipt::from_snapshot(&snapshot);
+        match crate::fiscal::register_with_retry(device.as_ref(), &receipt).await {
+            Ok(confirmation) => {
+                tracing::info!(
+                    order_id,
+                    receipt = %receipt.receipt_number,
+                    fiscal_code = %confirmation.fiscal_code,
+                    "Receipt registered with fiscal device"
+                );
+                Ok(Some(confirmation.fiscal_code))
+            }
+            Err(FiscalError::Rejected(e)) => Err(ManagerError::InvalidOperation(
+                CommandErrorCode::FiscalRejected,
+                e,
+            )),
+            Err(e) if self.fiscal_policy == FiscalPolicy::Allow => {
+                tracing::warn!(
+                    order_id,
+                    receipt = %receipt.receipt_number,
+                    error = %e,
+                    "Fiscal device unavailable, completing without fiscal code"
+                );
+                Ok(None)
+            }
+            Err(e) => Err(ManagerError::InvalidOperation(
+                CommandErrorCode::FiscalDeviceUnavailable,
+                e.to_string(),
+            )),
+        }
+    }
+
+    /// Phase A 已登记但结单未记录（事务失败 / 校验失败）→ 撤销登记
+    async fn cancel_unrecorded_fiscal_receipt(
+        &self,
+        fiscal_code: Option<String>,
+        events: &[OrderEvent],
+    ) {
+        let (Some(code), Some(device)) = (fiscal_code, &self.fiscal) else {
+            return;
+        };
+        let recorded = events.iter().any(|e| {
+            matches!(&e.payload, EventPayload::OrderCompleted { fiscal_code: Some(c), .. } if *c == code)
+        });
+        if recorded {
+            return;
+        }
+        if let Err(e) = device.cancel(&code).await {
+            tracing::error!(
+                fiscal_code = %code,
+                error = %e,
+                "Failed to cancel unrecorded fiscal receipt, void it on the device"
+            );
+        }
+    }
+
+    // ========== Loyalty Points ==========
+
+    /// 订单完成 → 扣减已兑换积分，再按实付金额累积积分
+    ///
+    /// 两笔流水均以 order_id 去重，重复命令或重试不会重复结算。
+    async fn settle_points_on_completion(&self, order_id: i64, events: &[OrderEvent]) {
+        let Some(pool) = &self.pool else { return };
+        let completed = events
+            .iter()
+            .any(|e| matches!(e.payload, EventPayload::OrderCompleted { .. }));
+        if !completed {
+            return;
+        }
+        let snapshot = match self.storage.get_snapshot(order_id) {
+            Ok(Some(s)) => s,
+            _ => return,
+        };
+        let Some(member_id) = snapshot.member_id else {
+            return;
+        };
+
+        if let Some(redemption) = &snapshot.points_redemption {
+            match crate::db::repository::member_points::redeem_for_order(
+                pool,
+                member_id,
+                order_id,
+                redemption.points,
+                redemption.amount,
+            )
+            .await
+            {
+                Ok(Some(txn)) => {
+                    tracing::info!(
+                        order_id,
+                        member_id,
+                        points = redemption.points,
+                        balance = txn.balance_after,
+                        "Loyalty points redeemed"
+                    );
+                }
+                Ok(None) => {}
+                Err(e) => {
+                    tracing::error!(order_id, member_id, error = %e, "Failed to deduct redeemed points");
+                }
+            }
+        }
+
+        let Some(mg_id) = snapshot.marketing_group_id else {
+            return;
+        };
+        let earn_rate = match crate::db::repository::marketing_group::find_by_id(pool, mg_id).await
+        {
+            Ok(Some(mg)) => mg.points_earn_rate,
+            Ok(None) => return,
+            Err(e) => {
+                tracing::error!(order_id, error = %e, "Failed to load marketing group for points accrual");
+                return;
+            }
+        };
+        let points = crate::marketing::points::points_earned(snapshot.paid_amount, earn_rate);
+        if points <= 0 {
+            return;
+        }
+        if let Err(e) = crate::db::repository::member_points::earn_for_order(
+            pool,
+            member_id,
+            order_id,
+            points,
+            snapshot.paid_amount,
+        )
+        .await
+        {
+            tracing::error!(order_id, member_id, error = %e, "Failed to accrue loyalty points");
         }
     }
 
@@ -997,6 +2274,9 @@ impl OrdersManager {
     /// Called after redb commit for CompleteOrder. If the order has a linked member,
     /// queries active stamp activities for the member's marketing group, counts matching
     /// items, and adds earned stamps to the member's progress in SQLite.
+    ///
+    /// Activities outside their campaign window earn nothing; the per-member daily cap
+    /// limits what is granted (withheld stamps are recorded in the ledger as `capped`).
     async fn track_stamps_on_completion(&self, order_id: i64) {
         let Some(pool) = &self.pool else { return };
 
@@ -1041,8 +2321,18 @@ impl OrdersManager {
             .collect();
 
         let now = shared::util::now_millis();
+        let day_start = self.business_day_start();
 
         for activity in &activities {
+            if !crate::marketing::stamp_tracker::is_activity_open(activity, now, self.tz) {
+                tracing::debug!(
+                    order_id,
+                    activity_id = activity.id,
+                    "Stamp activity outside campaign window, no stamps earned"
+                );
+                continue;
+            }
+
             let stamp_targets = match crate::db::repository::marketing_group::find_stamp_targets(
                 pool,
                 activity.id,
@@ -1061,12 +2351,54 @@ impl OrdersManager {
                 &stamp_targets,
             );
 
-            if earned > 0 {
+            if earned <= 0 {
+                continue;
+            }
+
+            let granted = match activity.max_stamps_per_day {
+                Some(_) => {
+                    let earned_today = match crate::db::repository::stamp::earned_since(
+                        pool,
+                        member_id,
+                        activity.id,
+                        day_start,
+                    )
+                    .await
+                    {
+                        Ok(n) => n,
+                        Err(e) => {
+                            tracing::error!(activity_id = activity.id, error = %e, "Failed to query stamp ledger, skipping capped activity");
+                            continue;
+                        }
+                    };
+                    crate::marketing::stamp_tracker::cap_daily_earn(
+                        earned,
+                        earned_today,
+                        activity.max_stamps_per_day,
+                    )
+                }
+                None => earned,
+            };
+            if let Err(e) = crate::db::repository::stamp::record_earn(
+                pool,
+                member_id,
+                activity.id,
+                order_id,
+                granted,
+                earned - granted,
+                now,
+            )
+            .await
+            {
+                tracing::error!(activity_id = activity.id, error = %e, "Failed to record stamp ledger entry");
+            }
+
+            if granted > 0 {
                 match crate::db::repository::stamp::add_stamps(
                     pool,
                     member_id,
                     activity.id,
-                    earned,
+                    granted,
                     now,
                 )
                 .await
@@ -1076,6 +2408,7 @@ impl OrdersManager {
                             member_id,
                             activity_id = activity.id,
                             earned,
+                            granted,
                             current = progress.current_stamps,
                             "Stamps tracked for order completion"
                         );
@@ -1122,6 +2455,18 @@ impl OrdersManager {
                         cycles = progress.completed_cycles,
                         "Stamp redeemed on order completion"
                     );
+                    if let Err(e) = crate::db::repository::stamp::record_redeem(
+                        pool,
+                        member_id,
+                        activity.id,
+                        order_id,
+                        activity.stamps_required,
+                        now,
+                    )
+                    .await
+                    {
+                        tracing::error!(activity_id = activity.id, error = %e, "Failed to record stamp ledger entry");
+                    }
                 }
                 Err(e) => {
                     tracing::error!(
@@ -1173,9 +2518,13 @@ impl OrdersManager {
                 continue;
             };
 
-            let order_bonus = crate::marketing::stamp_tracker::count_stamps_for_order(
-                &items_with_category,
-                &pf.stamp_targets,
+            let order_bonus = crate::marketing::stamp_tracker::cap_daily_earn(
+                crate::marketing::stamp_tracker::count_stamps_for_order(
+                    &items_with_category,
+                    &pf.stamp_targets,
+                ),
+                pf.earned_today,
+                activity.max_stamps_per_day,
             );
             let effective_stamps = pf.current_stamps + order_bonus;
 
@@ -1213,37 +2562,40 @@ impl OrdersManager {
     // ========== Public Query Methods ==========
 
     /// Get a snapshot by order ID
+    ///
+    /// 活跃订单取自活跃缓存，其余取自热点快照 LRU，未命中时读 redb 并回填。
     pub fn get_snapshot(&self, order_id: i64) -> ManagerResult<Option<OrderSnapshot>> {
+        if let Some(order) = self.active_cache.get(order_id) {
+            return Ok(Some(order));
+        }
+        let verifying = self.active_cache.is_verifying();
+        // 删除计数须在读 redb 之前获取，读取期间被归档的快照不回填
+        let generation = self.storage.removal_generation();
+        if !verifying && let Some(order) = self.hot_snapshots.get(order_id, generation) {
+            return Ok(Some(order));
+        }
         let mut snapshot = self.storage.get_snapshot(order_id)?;
         // 确保 line_total 已计算
         if let Some(ref mut order) = snapshot {
-            let needs_recalc = order
-                .items
-                .iter()
-                .any(|item| item.line_total.abs() < f64::EPSILON && !item.is_comped);
-            if needs_recalc {
-                order_money::recalculate_totals(order);
-            }
+            ensure_line_totals(order);
+            self.hot_snapshots.insert(order.clone(), generation);
         }
         Ok(snapshot)
     }
 
     /// Get all active order snapshots
     ///
+    /// Served from the in-memory cache (loaded from redb on first call).
     /// Ensures all items have `line_total` computed for consistency with order totals.
     pub fn get_active_orders(&self) -> ManagerResult<Vec<OrderSnapshot>> {
-        let mut orders = self.storage.get_active_orders()?;
-        // 确保 line_total 已计算
-        for order in &mut orders {
-            let needs_recalc = order
-                .items
-                .iter()
-                .any(|item| item.line_total.abs() < f64::EPSILON && !item.is_comped);
-            if needs_recalc {
-                order_money::recalculate_totals(order);
-            }
-        }
-        Ok(orders)
+        Ok(self.active_cache.get_or_load(&self.storage)?)
+    }
+
+    /// 将活跃订单缓存写入 redb 快照检查点 (定期任务)
+    ///
+    /// 返回写入的订单数；缓存未加载或无新提交时为 None。
+    pub fn write_snapshot_checkpoint(&self) -> ManagerResult<Option<usize>> {
+        Ok(self.active_cache.checkpoint(&self.storage)?)
     }
 
     /// Get current sequence number
@@ -1268,23 +2620,83 @@ impl OrdersManager {
 
     /// Rebuild a snapshot from events (for verification)
     ///
-    /// Uses EventApplier to apply each event to build the snapshot.
+    /// Same replay as clients use (`crab_order_core::rebuild_snapshot`).
     pub fn rebuild_snapshot(&self, order_id: i64) -> ManagerResult<OrderSnapshot> {
         let events = self.storage.get_events_for_order(order_id)?;
         if events.is_empty() {
             return Err(ManagerError::OrderNotFound(order_id));
         }
 
-        let mut snapshot = OrderSnapshot::new(order_id);
-        for event in &events {
-            let applier: EventAction = event.into();
-            applier.apply(&mut snapshot, event);
+        Ok(crab_order_core::rebuild_snapshot(order_id, &events))
+    }
+
+    /// Restore active orders from an event journal into empty storage
+    ///
+    /// For a redb file corrupted beyond repair: move it aside, start on a fresh
+    /// file and replay the journal. Terminal orders are not restored (their
+    /// archive in SQLite is authoritative). The sequence counter resumes after
+    /// the last journaled event.
+    pub fn restore_from_journal(&self, dir: &Path) -> ManagerResult<JournalRestore> {
+        if self.storage.get_current_sequence()? > 0 {
+            return Err(ManagerError::Internal(
+                "Journal restore requires empty order storage".to_string(),
+            ));
         }
+        let scan = journal::scan(dir)
+            .map_err(|e| ManagerError::Internal(format!("Failed to read event journal: {e}")))?;
 
-        Ok(snapshot)
+        let txn = self.storage.begin_write()?;
+        let mut restored_orders = Vec::new();
+        for snapshot in scan.rebuild_snapshots() {
+            if snapshot.status != OrderStatus::Active {
+                continue;
+            }
+            let events: Vec<OrderEvent> = scan
+                .events
+                .iter()
+                .filter(|e| e.order_id == snapshot.order_id)
+                .cloned()
+                .collect();
+            self.storage.store_events(&txn, &events)?;
+            self.storage.store_snapshot(&txn, &snapshot)?;
+            self.storage.mark_order_active(&txn, snapshot.order_id)?;
+            restored_orders.push(snapshot.order_id);
+        }
+        let last_sequence = scan.last_sequence();
+        self.storage.set_sequence(&txn, last_sequence)?;
+        self.storage.clear_checkpoint(&txn)?;
+        txn.commit().map_err(StorageError::from)?;
+        self.active_cache.invalidate();
+        self.hot_snapshots.clear();
+
+        tracing::warn!(
+            restored = restored_orders.len(),
+            events = scan.events.len(),
+            issues = scan.issues.len(),
+            "Active orders restored from event journal"
+        );
+        Ok(JournalRestore {
+            events: scan.events.len(),
+            restored_orders,
+            last_sequence,
+            issues: scan.issues,
+        })
     }
 }
 
+/// Result of [`OrdersManager::restore_from_journal`]
+#[derive(Debug, Clone, serde::Serialize)]
+pub struct JournalRestore {
+    /// Events read from the journal
+    pub events: usize,
+    /// Active orders written to storage
+    pub restored_orders: Vec<i64>,
+    /// Sequence counter after restore
+    pub last_sequence: u64,
+    /// Journal integrity issues found during the scan
+    pub issues: Vec<String>,
+}
+
 // Make OrdersManager Clone-able via Arc
 impl Clone for OrdersManager {
     fn clone(&self) -> Self {
@@ -1293,12 +2705,28 @@ impl Clone for OrdersManager {
             event_tx: self.event_tx.clone(),
             epoch: self.epoch.clone(),
             rule_cache: self.rule_cache.clone(),
+            active_cache: self.active_cache.clone(),
+            hot_snapshots: self.hot_snapshots.clone(),
             catalog_service: self.catalog_service.clone(),
             pool: self.pool.clone(),
             archive_service: self.archive_service.clone(),
             tz: self.tz,
             store_number: self.store_number,
             business_day_cutoff: RwLock::new(*self.business_day_cutoff.read()),
+            tax_mode: RwLock::new(*self.tax_mode.read()),
+            max_cash_change: RwLock::new(*self.max_cash_change.read()),
+            setup_complete: AtomicBool::new(self.setup_complete.load(Ordering::Relaxed)),
+            recorder: self.recorder.clone(),
+            journal: self.journal.clone(),
+            pms: self.pms.clone(),
+            inventory: self.inventory.clone(),
+            coupons: self.coupons.clone(),
+            metrics: self.metrics.clone(),
+            fiscal: self.fiscal.clone(),
+            fiscal_policy: self.fiscal_policy,
+            commit_alert_threshold: self.commit_alert_threshold,
+            #[cfg(feature = "chaos")]
+            faults: self.faults.clone(),
         }
     }
 }
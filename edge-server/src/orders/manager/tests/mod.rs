@@ -73,6 +73,7 @@ fn simple_item(product_id: i64, name: &str, price: f64, quantity: i32) -> CartIt
         note: None,
         authorizer_id: None,
         authorizer_name: None,
+        course: None,
     }
 }
 
@@ -95,6 +96,7 @@ fn create_test_rule(name: &str) -> PriceRule {
         adjustment_value: 10.0,
         is_stackable: false,
         is_exclusive: false,
+        priority: 0,
         valid_from: None,
         valid_until: None,
         active_days: None,
@@ -132,6 +134,7 @@ fn item_with_options(
         note: None,
         authorizer_id: None,
         authorizer_name: None,
+        course: None,
     }
 }
 
@@ -155,6 +158,7 @@ fn item_with_discount(
         note: None,
         authorizer_id: None,
         authorizer_name: None,
+        course: None,
     }
 }
 
@@ -260,6 +264,7 @@ async fn pay(manager: &OrdersManager, order_id: i64, amount: f64, method: &str)
                 amount,
                 tendered: if method == "CASH" { Some(amount) } else { None },
                 note: None,
+                reference: None,
             },
         },
     );
@@ -603,6 +608,7 @@ fn make_discount_rule(id: i64, percent: f64) -> PriceRule {
         adjustment_value: percent,
         is_stackable: true,
         is_exclusive: false,
+        priority: 0,
         valid_from: None,
         valid_until: None,
         active_days: None,
@@ -630,6 +636,7 @@ fn make_surcharge_rule(id: i64, percent: f64) -> PriceRule {
         adjustment_value: percent,
         is_stackable: true,
         is_exclusive: false,
+        priority: 0,
         valid_from: None,
         valid_until: None,
         active_days: None,
@@ -657,6 +664,7 @@ fn make_fixed_discount_rule(id: i64, amount: f64) -> PriceRule {
         adjustment_value: amount,
         is_stackable: true,
         is_exclusive: false,
+        priority: 0,
         valid_from: None,
         valid_until: None,
         active_days: None,
@@ -688,6 +696,7 @@ fn item_with_spec(
         note: None,
         authorizer_id: None,
         authorizer_name: None,
+        course: None,
     }
 }
 
@@ -807,6 +816,7 @@ fn make_timed_discount_rule(
         adjustment_value: percent,
         is_stackable: true,
         is_exclusive: false,
+        priority: 0,
         valid_from,
         valid_until,
         active_days,
@@ -824,3 +834,4 @@ mod test_core;
 mod test_flows;
 mod test_rules;
 mod test_rules_combo;
+mod test_scenarios;
@@ -20,6 +20,7 @@ async fn test_cannot_add_items_to_completed_order() {
                 amount: 10.0,
                 tendered: None,
                 note: None,
+                reference: None,
             },
         },
     );
@@ -420,6 +421,7 @@ async fn test_add_items_with_discount_over_100_percent() {
                 note: None,
                 authorizer_id: None,
                 authorizer_name: None,
+                course: None,
             }],
         },
     );
@@ -467,6 +469,7 @@ async fn test_add_items_with_negative_discount_acts_as_markup() {
                 note: None,
                 authorizer_id: None,
                 authorizer_name: None,
+                course: None,
             }],
         },
     );
@@ -497,6 +500,7 @@ async fn test_add_payment_with_nan_amount_rejected() {
                 amount: f64::NAN,
                 tendered: None,
                 note: None,
+                reference: None,
             },
         },
     );
@@ -524,6 +528,7 @@ async fn test_add_payment_with_infinity_amount_rejected() {
                 amount: f64::INFINITY,
                 tendered: None,
                 note: None,
+                reference: None,
             },
         },
     );
@@ -551,6 +556,7 @@ async fn test_add_payment_with_f64_max_amount_rejected() {
                 amount: f64::MAX,
                 tendered: None,
                 note: None,
+                reference: None,
             },
         },
     );
@@ -615,6 +621,7 @@ async fn test_multiple_edge_items_then_complete() {
                 amount: 52.0,
                 tendered: Some(60.0),
                 note: None,
+                reference: None,
             },
         },
     );
@@ -701,6 +708,7 @@ async fn test_add_items_with_option_price_modifiers() {
                 note: None,
                 authorizer_id: None,
                 authorizer_name: None,
+                course: None,
             }],
         },
     );
@@ -765,6 +773,7 @@ async fn test_add_items_with_negative_option_modifier() {
                 note: None,
                 authorizer_id: None,
                 authorizer_name: None,
+                course: None,
             }],
         },
     );
@@ -805,6 +814,7 @@ async fn test_add_cash_payment_tendered_less_than_amount_fails() {
                 amount: 10.0,
                 tendered: Some(5.0), // 给了 5 块，要付 10 块
                 note: None,
+                reference: None,
             },
         },
     );
@@ -866,6 +876,7 @@ async fn test_discount_surcharge_options_combined_precision() {
                 note: None,
                 authorizer_id: None,
                 authorizer_name: None,
+                course: None,
             }],
         },
     );
@@ -902,6 +913,7 @@ async fn test_nan_payment_then_complete_fails() {
                 amount: f64::NAN,
                 tendered: None,
                 note: None,
+                reference: None,
             },
         },
     );
@@ -1013,6 +1025,7 @@ async fn test_many_small_amounts_precision() {
                     note: None,
                     authorizer_id: None,
                     authorizer_name: None,
+                    course: None,
                 }],
             },
         );
@@ -1045,6 +1058,7 @@ async fn test_add_cash_payment_nan_tendered() {
                 amount: 10.0,
                 tendered: Some(f64::NAN), // NaN tendered
                 note: None,
+                reference: None,
             },
         },
     );
@@ -1091,6 +1105,7 @@ async fn test_add_payment_after_move_order() {
                 amount: 10.0,
                 tendered: None,
                 note: None,
+                reference: None,
             },
         },
     );
@@ -1122,6 +1137,7 @@ async fn test_payment_tolerance_boundary() {
                 amount: 9.99,
                 tendered: None,
                 note: None,
+                reference: None,
             },
         },
     );
@@ -1159,6 +1175,7 @@ async fn test_payment_below_tolerance_rejected() {
                 amount: 9.98,
                 tendered: None,
                 note: None,
+                reference: None,
             },
         },
     );
@@ -1247,6 +1264,7 @@ async fn test_options_discount_rule_fields_no_double_counting() {
                 note: None,
                 authorizer_id: None,
                 authorizer_name: None,
+                course: None,
             }],
         },
     );
@@ -1322,6 +1340,7 @@ async fn test_modify_item_unit_price_consistency() {
                 note: None,
                 authorizer_id: None,
                 authorizer_name: None,
+                course: None,
             }],
         },
     );
@@ -1450,6 +1469,7 @@ async fn test_add_payment_to_voided_order_fails() {
                 amount: 10.0,
                 tendered: None,
                 note: None,
+                reference: None,
             },
         },
     );
@@ -1510,6 +1530,7 @@ async fn test_void_completed_order_fails() {
                 amount: 10.0,
                 tendered: None,
                 note: None,
+                reference: None,
             },
         },
     );
@@ -1559,6 +1580,7 @@ async fn test_double_complete_fails() {
                 amount: 10.0,
                 tendered: None,
                 note: None,
+                reference: None,
             },
         },
     );
@@ -2035,6 +2057,7 @@ async fn test_cancel_already_cancelled_payment_fails() {
                 amount: 10.0,
                 tendered: None,
                 note: None,
+                reference: None,
             },
         },
     );
@@ -2130,6 +2153,7 @@ async fn test_modify_item_on_completed_order_fails() {
                 amount: 10.0,
                 tendered: None,
                 note: None,
+                reference: None,
             },
         },
     );
@@ -2188,6 +2212,7 @@ async fn test_add_payment_negative_amount_rejected() {
                 amount: -10.0,
                 tendered: None,
                 note: None,
+                reference: None,
             },
         },
     );
@@ -2211,6 +2236,7 @@ async fn test_add_payment_zero_amount_rejected() {
                 amount: 0.0,
                 tendered: None,
                 note: None,
+                reference: None,
             },
         },
     );
@@ -65,6 +65,7 @@ async fn test_add_items() {
                 note: None,
                 authorizer_id: None,
                 authorizer_name: None,
+                course: None,
             }],
         },
     );
@@ -105,6 +106,7 @@ async fn test_add_payment_and_complete() {
                 note: None,
                 authorizer_id: None,
                 authorizer_name: None,
+                course: None,
             }],
         },
     );
@@ -121,6 +123,7 @@ async fn test_add_payment_and_complete() {
                 amount: 10.0,
                 tendered: Some(20.0),
                 note: None,
+                reference: None,
             },
         },
     );
@@ -197,6 +200,30 @@ async fn test_event_broadcast() {
     assert_eq!(event.event_type, OrderEventType::TableOpened);
 }
 
+#[tokio::test]
+async fn test_active_orders_cache_follows_commits() {
+    let manager = create_test_manager();
+    // Load the cache before any write
+    assert!(manager.get_active_orders().unwrap().is_empty());
+
+    let order_id = open_table_with_items(&manager, 7, vec![simple_item(1, "Coffee", 4.5, 2)]).await;
+    let cached = manager.get_active_orders().unwrap();
+    assert_eq!(cached.len(), 1);
+    assert_eq!(cached[0], manager.get_snapshot(order_id).unwrap().unwrap());
+
+    // Rejected command leaves the cache untouched
+    let mut reopen = create_open_table_cmd(1);
+    if let OrderCommandPayload::OpenTable { table_id, .. } = &mut reopen.payload {
+        *table_id = Some(7);
+    }
+    assert!(!manager.execute_command(reopen).await.success);
+    assert_eq!(manager.get_active_orders().unwrap(), cached);
+
+    // Verification mode agrees with redb
+    manager.set_active_cache_verify(true);
+    assert_eq!(manager.get_active_orders().unwrap(), cached);
+}
+
 // ========================================================================
 // 1. rebuild_snapshot 一致性验证
 // ========================================================================
@@ -225,6 +252,7 @@ async fn test_rebuild_snapshot_matches_stored() {
                 amount: 5.0,
                 tendered: Some(10.0),
                 note: None,
+                reference: None,
             },
         },
     );
@@ -316,6 +344,7 @@ async fn test_merge_orders_source_with_payment_rejected() {
                 amount: 5.0,
                 tendered: None,
                 note: None,
+                reference: None,
             },
         },
     );
@@ -372,6 +401,7 @@ async fn test_add_payment_overpay_is_rejected() {
                 amount: 10000.0,
                 tendered: None,
                 note: None,
+                reference: None,
             },
         },
     );
@@ -403,6 +433,7 @@ async fn test_cancel_payment_then_repay_then_complete() {
                 amount: 10.0,
                 tendered: None,
                 note: None,
+                reference: None,
             },
         },
     );
@@ -443,6 +474,7 @@ async fn test_cancel_payment_then_repay_then_complete() {
                 amount: 10.0,
                 tendered: Some(20.0),
                 note: None,
+                reference: None,
             },
         },
     );
@@ -654,6 +686,7 @@ async fn test_move_order_then_complete() {
                 amount: 10.0,
                 tendered: None,
                 note: None,
+                reference: None,
             },
         },
     );
@@ -729,6 +762,7 @@ async fn test_split_by_items_then_complete() {
                 amount: 8.0,
                 tendered: None,
                 note: None,
+                reference: None,
             },
         },
     );
@@ -898,6 +932,7 @@ async fn test_get_events_since_completeness() {
                 amount: 10.0,
                 tendered: None,
                 note: None,
+                reference: None,
             },
         },
     );
@@ -966,6 +1001,7 @@ async fn test_operations_on_nonexistent_order_fail() {
                 amount: 10.0,
                 tendered: None,
                 note: None,
+                reference: None,
             },
         },
     );
@@ -999,3 +1035,427 @@ async fn test_operations_on_nonexistent_order_fail() {
     let resp = manager.execute_command(void_cmd).await;
     assert!(!resp.success);
 }
+
+#[tokio::test]
+async fn test_event_journal_restores_active_orders() {
+    let dir = tempfile::tempdir().unwrap();
+    let mut manager = create_test_manager();
+    manager.set_event_journal(EventJournal::open(dir.path()).unwrap());
+
+    let active_id = open_table_with_items(&manager, 1, vec![simple_item(1, "Coke", 2.5, 2)]).await;
+    let voided_id = open_table_with_items(&manager, 2, vec![]).await;
+    let void_cmd = OrderCommand::new(
+        1,
+        "Test Operator".to_string(),
+        OrderCommandPayload::VoidOrder {
+            order_id: voided_id,
+            void_type: VoidType::Cancelled,
+            loss_reason: None,
+            loss_amount: None,
+            note: None,
+            authorizer_id: None,
+            authorizer_name: None,
+        },
+    );
+    assert!(manager.execute_command(void_cmd).await.success);
+    let original = manager.get_snapshot(active_id).unwrap().unwrap();
+
+    // redb 丢失 → 全新存储上从日志重建
+    let fresh = create_test_manager();
+    let restore = fresh.restore_from_journal(dir.path()).unwrap();
+    assert!(restore.issues.is_empty());
+    assert_eq!(restore.restored_orders, vec![active_id]);
+    assert_eq!(
+        restore.last_sequence,
+        manager.get_current_sequence().unwrap()
+    );
+
+    let restored = fresh.get_snapshot(active_id).unwrap().unwrap();
+    assert_eq!(restored.items.len(), original.items.len());
+    assert_eq!(restored.total, original.total);
+    assert!(fresh.get_snapshot(voided_id).unwrap().is_none());
+
+    // 非空存储拒绝重建
+    assert!(manager.restore_from_journal(dir.path()).is_err());
+}
+
+/// 记录过账 / 冲正的模拟 PMS
+#[derive(Default)]
+struct MockPms {
+    reversed: parking_lot::Mutex<Vec<String>>,
+}
+
+#[async_trait::async_trait]
+impl crate::pms::PmsClient for MockPms {
+    async fn verify_guest(
+        &self,
+        room_number: &str,
+    ) -> Result<crate::pms::PmsGuest, crate::pms::PmsError> {
+        if room_number != "101" {
+            return Err(crate::pms::PmsError::GuestNotFound(room_number.to_string()));
+        }
+        Ok(crate::pms::PmsGuest {
+            room_number: room_number.to_string(),
+            guest_name: "Guest".to_string(),
+            folio_id: None,
+            charges_allowed: true,
+        })
+    }
+
+    async fn post_charge(
+        &self,
+        charge: &crate::pms::RoomCharge,
+    ) -> Result<crate::pms::PmsConfirmation, crate::pms::PmsError> {
+        Ok(crate::pms::PmsConfirmation {
+            reference: format!("PMS-{}", charge.external_id),
+        })
+    }
+
+    async fn reverse_charge(&self, reference: &str) -> Result<(), crate::pms::PmsError> {
+        self.reversed.lock().push(reference.to_string());
+        Ok(())
+    }
+}
+
+fn room_charge_cmd(order_id: i64, room: &str, note: Option<&str>) -> OrderCommand {
+    OrderCommand::new(
+        1,
+        "Test Operator".to_string(),
+        OrderCommandPayload::AddPayment {
+            order_id,
+            payment: PaymentInput {
+                method: crab_order_core::money::ROOM_CHARGE_METHOD.to_string(),
+                amount: 5.0,
+                tendered: None,
+                note: note.map(str::to_string),
+                reference: Some(room.to_string()),
+            },
+        },
+    )
+}
+
+#[tokio::test]
+async fn test_room_charge_records_pms_reference_and_reverses_on_cancel() {
+    let pms = Arc::new(MockPms::default());
+    let mut manager = create_test_manager();
+    let order_id = open_table_with_items(&manager, 1, vec![simple_item(1, "Coke", 2.5, 2)]).await;
+
+    // 未配置 PMS → 拒绝
+    assert!(
+        !manager
+            .execute_command(room_charge_cmd(order_id, "101", None))
+            .await
+            .success
+    );
+
+    manager.set_pms_client(pms.clone());
+    // 房间无住客 → 拒绝，不产生支付
+    assert!(
+        !manager
+            .execute_command(room_charge_cmd(order_id, "999", None))
+            .await
+            .success
+    );
+
+    let resp = manager
+        .execute_command(room_charge_cmd(order_id, "101", None))
+        .await;
+    assert!(resp.success);
+    let snapshot = manager.get_snapshot(order_id).unwrap().unwrap();
+    assert_eq!(snapshot.payments.len(), 1);
+    let payment = &snapshot.payments[0];
+    let reference = format!("PMS-{}", payment.payment_id);
+    assert_eq!(payment.reference.as_deref(), Some(reference.as_str()));
+    assert_eq!(payment.note.as_deref(), Some("Room 101"));
+
+    let cancel = OrderCommand::new(
+        1,
+        "Test Operator".to_string(),
+        OrderCommandPayload::CancelPayment {
+            order_id,
+            payment_id: payment.payment_id,
+            reason: None,
+            authorizer_id: None,
+            authorizer_name: None,
+        },
+    );
+    assert!(manager.execute_command(cancel).await.success);
+    assert_eq!(*pms.reversed.lock(), vec![reference]);
+}
+
+#[tokio::test]
+async fn test_room_charge_keeps_room_number_with_cashier_note() {
+    let mut manager = create_test_manager();
+    manager.set_pms_client(Arc::new(MockPms::default()));
+    let order_id = open_table_with_items(&manager, 1, vec![simple_item(1, "Coke", 2.5, 4)]).await;
+
+    let resp = manager
+        .execute_command(room_charge_cmd(order_id, "101", Some("  late checkout ")))
+        .await;
+    assert!(resp.success);
+    let snapshot = manager.get_snapshot(order_id).unwrap().unwrap();
+    assert_eq!(
+        snapshot.payments[0].note.as_deref(),
+        Some("Room 101 · late checkout")
+    );
+
+    // 空白备注等同于无备注
+    let resp = manager
+        .execute_command(room_charge_cmd(order_id, "101", Some("   ")))
+        .await;
+    assert!(resp.success);
+    let snapshot = manager.get_snapshot(order_id).unwrap().unwrap();
+    assert_eq!(snapshot.payments[1].note.as_deref(), Some("Room 101"));
+}
+
+/// 可切换在线状态的模拟税控设备
+#[derive(Default)]
+struct MockFiscal {
+    online: std::sync::atomic::AtomicBool,
+    registered: parking_lot::Mutex<Vec<String>>,
+}
+
+#[async_trait::async_trait]
+impl crate::fiscal::FiscalDevice for MockFiscal {
+    async fn register(
+        &self,
+        receipt: &crate::fiscal::FiscalReceipt,
+    ) -> Result<crate::fiscal::FiscalConfirmation, crate::fiscal::FiscalError> {
+        if !self.online.load(std::sync::atomic::Ordering::SeqCst) {
+            return Err(crate::fiscal::FiscalError::Unavailable("offline".into()));
+        }
+        self.registered.lock().push(receipt.receipt_number.clone());
+        Ok(crate::fiscal::FiscalConfirmation {
+            fiscal_code: format!("FC-{}", receipt.receipt_number),
+        })
+    }
+}
+
+#[tokio::test]
+async fn test_fiscal_device_blocks_completion_until_registered() {
+    let fiscal = Arc::new(MockFiscal::default());
+    let mut manager = create_test_manager();
+    manager.set_fiscal_device(fiscal.clone(), crate::fiscal::FiscalPolicy::Block);
+    let order_id = open_table_with_items(&manager, 1, vec![simple_item(1, "Coke", 2.5, 2)]).await;
+
+    // 未付清 → 不登记，Phase B 拒绝
+    let resp = complete_order(&manager, order_id).await;
+    assert_eq!(
+        resp.error.unwrap().code,
+        CommandErrorCode::PaymentInsufficient
+    );
+    assert!(fiscal.registered.lock().is_empty());
+
+    assert!(pay(&manager, order_id, 5.0, "CASH").await.success);
+
+    // 设备离线 → 重试后拒绝结单，订单保持进行中
+    let resp = complete_order(&manager, order_id).await;
+    assert_eq!(
+        resp.error.unwrap().code,
+        CommandErrorCode::FiscalDeviceUnavailable
+    );
+    assert_order_status(&manager, order_id, OrderStatus::Active);
+
+    fiscal
+        .online
+        .store(true, std::sync::atomic::Ordering::SeqCst);
+    let resp = complete_order(&manager, order_id).await;
+    assert!(resp.success, "{:?}", resp.error);
+    let snapshot = manager.get_snapshot(order_id).unwrap().unwrap();
+    assert_eq!(snapshot.status, OrderStatus::Completed);
+    assert_eq!(
+        snapshot.fiscal_code,
+        Some(format!("FC-{}", snapshot.receipt_number))
+    );
+    assert_eq!(fiscal.registered.lock().len(), 1);
+}
+
+#[tokio::test]
+async fn test_open_table_blocked_until_setup_complete() {
+    let manager = create_test_manager();
+    manager.update_setup_complete(false);
+    let resp = manager.execute_command(create_open_table_cmd(1)).await;
+    assert_eq!(resp.error.unwrap().code, CommandErrorCode::SetupIncomplete);
+
+    manager.update_setup_complete(true);
+    assert!(
+        manager
+            .execute_command(create_open_table_cmd(1))
+            .await
+            .success
+    );
+}
+
+#[tokio::test]
+async fn test_stale_expected_version_returns_conflict() {
+    let manager = create_test_manager();
+    let order_id = open_table_with_items(&manager, 1, vec![simple_item(1, "Coffee", 5.0, 1)]).await;
+    let version = manager.get_snapshot(order_id).unwrap().unwrap().version();
+
+    let note = |text: &str| {
+        OrderCommand::new(
+            1,
+            "Test Operator".to_string(),
+            OrderCommandPayload::AddOrderNote {
+                order_id,
+                note: text.to_string(),
+            },
+        )
+    };
+
+    // 终端 A 基于当前版本修改成功
+    let resp = manager
+        .execute_command(note("terminal A").with_expected_version(version))
+        .await;
+    assert!(resp.success, "{:?}", resp.error);
+
+    // 终端 B 仍持有旧版本 → 冲突并带回最新快照，订单不变
+    let resp = manager
+        .execute_command(note("terminal B").with_expected_version(version))
+        .await;
+    assert!(!resp.success);
+    assert_eq!(resp.error.unwrap().code, CommandErrorCode::VersionConflict);
+    let current = resp.current_snapshot.expect("conflict carries snapshot");
+    assert!(current.version() > version);
+    assert_eq!(current.note.as_deref(), Some("terminal A"));
+
+    // 基于最新版本重试成功
+    let resp = manager
+        .execute_command(note("terminal B").with_expected_version(current.version()))
+        .await;
+    assert!(resp.success, "{:?}", resp.error);
+}
+
+#[tokio::test]
+async fn test_course_items_held_until_fired() {
+    let manager = create_test_manager();
+    let starter = simple_item(1, "Soup", 6.0, 1);
+    let mut main = simple_item(2, "Steak", 20.0, 1);
+    main.course = Some(2);
+    let order_id = open_table_with_items(&manager, 1, vec![starter, main]).await;
+
+    let snapshot = manager.get_snapshot(order_id).unwrap().unwrap();
+    let held: Vec<_> = snapshot.items.iter().filter(|i| i.is_held).collect();
+    assert_eq!(held.len(), 1);
+    assert_eq!(held[0].name, "Steak");
+    // 暂缓商品照常计价
+    assert_close(
+        snapshot.subtotal,
+        26.0,
+        "held items still count toward subtotal",
+    );
+
+    let fire = |course: i32| {
+        OrderCommand::new(
+            1,
+            "Test Operator".to_string(),
+            OrderCommandPayload::FireCourse { order_id, course },
+        )
+    };
+
+    let resp = manager.execute_command(fire(2)).await;
+    assert!(resp.success, "{:?}", resp.error);
+    let snapshot = manager.get_snapshot(order_id).unwrap().unwrap();
+    assert!(snapshot.items.iter().all(|i| !i.is_held));
+    assert!(snapshot.is_course_fired(2));
+
+    // 已起菜的道次再加菜直接送厨
+    let mut extra = simple_item(3, "Fries", 4.0, 1);
+    extra.course = Some(2);
+    let resp = add_items(&manager, order_id, vec![extra]).await;
+    assert!(resp.success, "{:?}", resp.error);
+    let snapshot = manager.get_snapshot(order_id).unwrap().unwrap();
+    assert!(snapshot.items.iter().all(|i| !i.is_held));
+
+    // 没有暂缓商品的道次无法起菜
+    let resp = manager.execute_command(fire(2)).await;
+    assert_eq!(resp.error.unwrap().code, CommandErrorCode::NoHeldItems);
+}
+
+#[tokio::test]
+async fn test_slow_commit_raises_alert_metric() {
+    let metrics = Arc::new(crate::core::Metrics::new());
+    let mut manager = create_test_manager();
+    manager.set_metrics(metrics.clone());
+    // 阈值 1ns：任何提交都超阈值
+    manager.set_commit_alert_threshold(std::time::Duration::from_nanos(1));
+
+    let resp = manager.execute_command(create_open_table_cmd(1)).await;
+    assert!(resp.success, "{:?}", resp.error);
+
+    let text = metrics.render(0);
+    assert!(text.contains("crab_redb_slow_commits_total 1"));
+    assert!(text.contains("crab_redb_writer_wait_seconds_count 1"));
+
+    // 阈值 0 = 禁用
+    manager.set_commit_alert_threshold(std::time::Duration::ZERO);
+    let order_id = resp.order_id.unwrap();
+    let resp = manager
+        .execute_command(OrderCommand::new(
+            1,
+            "Test Operator".to_string(),
+            OrderCommandPayload::AddOrderNote {
+                order_id,
+                note: "window seat".to_string(),
+            },
+        ))
+        .await;
+    assert!(resp.success, "{:?}", resp.error);
+    assert!(metrics.render(0).contains("crab_redb_slow_commits_total 1"));
+}
+
+#[tokio::test]
+async fn test_transfer_items_between_tables() {
+    let manager = create_test_manager();
+
+    let source_id = open_table_with_items(
+        &manager,
+        221,
+        vec![
+            simple_item(1, "Paella", 12.0, 2),
+            simple_item(2, "Wine", 4.0, 1),
+        ],
+    )
+    .await;
+    let target_id = open_table_with_items(&manager, 222, vec![simple_item(3, "Tea", 3.0, 1)]).await;
+
+    let source = manager.get_snapshot(source_id).unwrap().unwrap();
+    let paella_id = source
+        .items
+        .iter()
+        .find(|i| i.name == "Paella")
+        .unwrap()
+        .instance_id
+        .clone();
+
+    let transfer = |instance_ids: Vec<String>| {
+        OrderCommand::new(
+            1,
+            "Test Operator".to_string(),
+            OrderCommandPayload::TransferItems {
+                source_order_id: source_id,
+                target_order_id: target_id,
+                instance_ids,
+            },
+        )
+    };
+
+    let resp = manager
+        .execute_command(transfer(vec![paella_id.clone()]))
+        .await;
+    assert!(resp.success, "{:?}", resp.error);
+
+    // 源订单保持 Active，仅剩未转移的菜品
+    let source = manager.get_snapshot(source_id).unwrap().unwrap();
+    assert_eq!(source.status, OrderStatus::Active);
+    assert_eq!(source.items.len(), 1);
+    assert_close(source.total, 4.0, "source total after transfer");
+
+    let target = manager.get_snapshot(target_id).unwrap().unwrap();
+    assert_eq!(target.items.len(), 2);
+    assert_close(target.total, 27.0, "target total after transfer");
+
+    // 已转出的菜品不可再次转移
+    let resp = manager.execute_command(transfer(vec![paella_id])).await;
+    assert!(!resp.success);
+}
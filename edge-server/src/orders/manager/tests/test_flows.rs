@@ -1049,6 +1049,7 @@ async fn test_all_commands_reject_completed_order() {
                 amount: 5.0,
                 tendered: None,
                 note: None,
+                reference: None,
             },
         },
     );
@@ -1127,6 +1128,7 @@ async fn test_all_commands_reject_voided_order() {
                 amount: 5.0,
                 tendered: None,
                 note: None,
+                reference: None,
             },
         },
     );
@@ -170,6 +170,7 @@ async fn test_complete_order_cleans_rules() {
                 amount: actual_total,
                 tendered: Some(actual_total),
                 note: None,
+                reference: None,
             },
         },
     );
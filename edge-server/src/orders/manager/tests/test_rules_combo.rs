@@ -472,6 +472,7 @@ async fn test_combo_kitchen_sink() {
             note: None,
             authorizer_id: None,
             authorizer_name: None,
+            course: None,
         }],
     )
     .await;
@@ -644,6 +645,7 @@ async fn test_combo_modify_everything_at_once() {
             note: None,
             authorizer_id: None,
             authorizer_name: None,
+            course: None,
         }],
     )
     .await;
@@ -0,0 +1,135 @@
+use super::*;
+use crate::orders::scenario::{Expectation, Scenario, ScenarioError, Step};
+use shared::order::{CommandErrorCode, OrderStatus};
+
+// ========================================================================
+// 场景 DSL: 回归语料 (edge-server/scenarios/*.json) + builder
+// ========================================================================
+
+/// 每个场景文件即一个回归测试，新增问题场景只需放入 scenarios/ 目录
+#[tokio::test]
+async fn test_scenario_corpus() {
+    let dir = std::path::Path::new(env!("CARGO_MANIFEST_DIR")).join("scenarios");
+    let mut paths: Vec<_> = std::fs::read_dir(&dir)
+        .expect("scenarios dir")
+        .filter_map(|entry| entry.ok().map(|e| e.path()))
+        .filter(|path| path.extension().is_some_and(|ext| ext == "json"))
+        .collect();
+    paths.sort();
+    assert!(!paths.is_empty(), "no scenarios in {}", dir.display());
+
+    for path in paths {
+        let scenario = Scenario::load(&path).unwrap_or_else(|e| panic!("{}: {e}", path.display()));
+        let manager = create_test_manager();
+        if let Err(e) = scenario.run(&manager).await {
+            panic!("{} ({}): {e}", scenario.name, path.display());
+        }
+    }
+}
+
+#[tokio::test]
+async fn test_scenario_builder_tracks_aliases() {
+    let manager = create_test_manager();
+    let outcome = Scenario::new("two tables")
+        .product(1, "Caña", 2.0)
+        .table(1, "Barra 1")
+        .table(2, "Barra 2")
+        .open("a", "Barra 1")
+        .open("b", "Barra 2")
+        .add("a", &[("Caña", 2)])
+        .add("b", &[("Caña", 1)])
+        .fails(
+            Some(CommandErrorCode::TableOccupied),
+            Step::Open {
+                alias: "c".to_string(),
+                table: Some("Barra 1".to_string()),
+                guests: 1,
+            },
+        )
+        .pay_rest("a")
+        .complete("a")
+        .expect(
+            Expectation::order("a")
+                .status(OrderStatus::Completed)
+                .total(4.0),
+        )
+        .expect(
+            Expectation::order("b")
+                .status(OrderStatus::Active)
+                .remaining(2.0),
+        )
+        .run(&manager)
+        .await
+        .expect("scenario runs");
+
+    let a = outcome.order_id("a").unwrap();
+    let b = outcome.order_id("b").unwrap();
+    assert_ne!(a, b);
+    assert!(outcome.order_id("c").is_none());
+    assert_eq!(outcome.commands, 7);
+}
+
+#[tokio::test]
+async fn test_scenario_raw_command_fills_order_id() {
+    let manager = create_test_manager();
+    let outcome = Scenario::new("raw surcharge")
+        .product(1, "Menú del día", 12.0)
+        .open_retail("r")
+        .add("r", &[("Menú del día", 1)])
+        .step(Step::Command {
+            order: Some("r".to_string()),
+            payload: serde_json::json!({
+                "type": "APPLY_ORDER_SURCHARGE",
+                "surcharge_amount": 1.5
+            }),
+        })
+        .expect(Expectation::order("r").total(13.5))
+        .run(&manager)
+        .await
+        .expect("scenario runs");
+    assert!(outcome.order_id("r").is_some());
+}
+
+#[tokio::test]
+async fn test_scenario_reports_failing_step() {
+    let manager = create_test_manager();
+
+    let err = Scenario::new("wrong total")
+        .product(1, "Café", 1.5)
+        .open_retail("r")
+        .add("r", &[("Café", 2)])
+        .expect(Expectation::order("r").total(4.0))
+        .run(&manager)
+        .await
+        .unwrap_err();
+    assert!(matches!(
+        err,
+        ScenarioError::Mismatch {
+            step: 2,
+            field: "total",
+            ..
+        }
+    ));
+
+    let err = Scenario::new("unknown product")
+        .open_retail("r")
+        .add("r", &[("Bocadillo", 1)])
+        .run(&manager)
+        .await
+        .unwrap_err();
+    assert!(matches!(err, ScenarioError::UnknownProduct { step: 1, .. }));
+
+    let err = Scenario::new("unexpected success")
+        .open_retail("r")
+        .fails(
+            None,
+            Step::Void {
+                order: "r".to_string(),
+                note: None,
+            },
+        )
+        .run(&manager)
+        .await
+        .unwrap_err();
+    assert!(matches!(err, ScenarioError::UnexpectedSuccess { step: 1 }));
+}
@@ -5,6 +5,10 @@
 //! - **manager**: Core OrdersManager for command processing and event generation
 //! - **storage**: redb-based persistence layer for events, snapshots, and indices
 //! - **reducer**: Event replay and snapshot computation
+//! - **recorder** / **replay**: Field session recording and replay harness
+//! - **scenario**: Declarative test scenarios (menu + tables + command steps), shared by tests and examples
+//! - **journal**: Optional append-only event journal (forensic backup independent of redb)
+//! - **predict**: Optimistic command prediction for remote terminals (in-memory action + applier run)
 //!
 //! # Architecture
 //!
@@ -27,9 +31,13 @@
 //! 7. CommandResponse is returned to client
 
 pub mod actions;
-pub mod appliers;
+pub mod journal;
 pub mod manager;
+pub mod predict;
+pub mod recorder;
 pub mod reducer;
+pub mod replay;
+pub mod scenario;
 pub mod storage;
 pub mod traits;
 
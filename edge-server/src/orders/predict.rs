@@ -0,0 +1,219 @@
+//! Optimistic command prediction
+//!
+//! 远程终端 (Client 模式) 在等待服务端往返前，用与服务端相同的 action + applier
+//! 在内存 redb 中预演订单命令，得到预测事件和快照供 UI 立即显示。
+//!
+//! 预测结果只用于显示：权威状态始终是服务端广播的事件和快照，
+//! 命令失败时调用方应回滚到预演前的快照。
+
+use shared::order::{OrderCommand, OrderCommandPayload, OrderEvent, OrderSnapshot};
+
+use super::actions::CommandAction;
+use super::storage::OrderStorage;
+use super::traits::{CommandContext, CommandHandler, CommandMetadata, EventApplier};
+use crab_order_core::EventAction;
+
+/// 预演结果
+#[derive(Debug, Clone)]
+pub struct Prediction {
+    /// 预测事件 (sequence 为本地值，不可作为同步游标)
+    pub events: Vec<OrderEvent>,
+    /// 受影响订单的预测快照
+    pub snapshots: Vec<OrderSnapshot>,
+}
+
+/// 命令是否可以在本地预演
+///
+/// 只包含纯编辑命令：不依赖服务端 prefetch 数据、商品目录或单号生成，
+/// 且没有支付/归档等外部副作用。
+pub fn is_predictable(payload: &OrderCommandPayload) -> bool {
+    matches!(
+        payload,
+        OrderCommandPayload::ModifyItem { .. }
+            | OrderCommandPayload::RemoveItem { .. }
+            | OrderCommandPayload::UpdateOrderInfo { .. }
+            | OrderCommandPayload::ToggleRuleSkip { .. }
+            | OrderCommandPayload::ApplyOrderDiscount { .. }
+            | OrderCommandPayload::ApplyOrderSurcharge { .. }
+            | OrderCommandPayload::CompItem { .. }
+            | OrderCommandPayload::UncompItem { .. }
+            | OrderCommandPayload::HoldItems { .. }
+            | OrderCommandPayload::FireCourse { .. }
+            | OrderCommandPayload::AddOrderNote { .. }
+            | OrderCommandPayload::SetOrderMetadata { .. }
+    )
+}
+
+/// 基于已知快照预演命令
+///
+/// 返回 None 表示不预演：命令不支持、缺少目标订单快照，或预演时 action 拒绝
+/// (错误由服务端权威返回，本地不重复提示)。
+pub fn predict_command(cmd: &OrderCommand, snapshots: &[OrderSnapshot]) -> Option<Prediction> {
+    if !is_predictable(&cmd.payload) {
+        return None;
+    }
+    let order_id = cmd.target_order_id()?;
+    if !snapshots.iter().any(|s| s.order_id == order_id) {
+        return None;
+    }
+
+    let storage = OrderStorage::open_in_memory().ok()?;
+    let txn = storage.begin_write().ok()?;
+    for snapshot in snapshots {
+        storage.store_snapshot(&txn, snapshot).ok()?;
+        storage.mark_order_active(&txn, snapshot.order_id).ok()?;
+    }
+
+    let current_sequence = snapshots.iter().map(|s| s.last_sequence).max().unwrap_or(0);
+    let mut ctx = CommandContext::new(&txn, &storage, current_sequence);
+    let metadata = CommandMetadata {
+        command_id: cmd.command_id,
+        operator_id: cmd.operator_id,
+        operator_name: cmd.operator_name.clone(),
+        timestamp: cmd.timestamp,
+    };
+
+    let action: CommandAction = cmd.into();
+    let events = match action.execute(&mut ctx, &metadata) {
+        Ok(events) => events,
+        Err(e) => {
+            tracing::debug!(command_id = %cmd.command_id, error = %e, "Prediction rejected by action");
+            return None;
+        }
+    };
+
+    for event in &events {
+        let mut snapshot = ctx
+            .load_snapshot(event.order_id)
+            .unwrap_or_else(|_| OrderSnapshot::new(event.order_id));
+        let applier: EventAction = event.into();
+        applier.apply(&mut snapshot, event);
+        ctx.save_snapshot(snapshot);
+    }
+
+    let touched: Vec<OrderSnapshot> = ctx
+        .modified_snapshots()
+        .filter(|s| events.iter().any(|e| e.order_id == s.order_id))
+        .cloned()
+        .collect();
+
+    Some(Prediction {
+        events,
+        snapshots: touched,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use shared::order::{CartItemSnapshot, OrderStatus};
+
+    fn active_snapshot(order_id: i64) -> OrderSnapshot {
+        let mut snapshot = OrderSnapshot::new(order_id);
+        snapshot.status = OrderStatus::Active;
+        snapshot.last_sequence = 10;
+        snapshot
+    }
+
+    fn item(instance_id: &str, price: f64, quantity: i32) -> CartItemSnapshot {
+        CartItemSnapshot {
+            id: 1,
+            instance_id: instance_id.to_string(),
+            name: "Paella".to_string(),
+            price,
+            original_price: price,
+            quantity,
+            unpaid_quantity: quantity,
+            selected_options: None,
+            selected_specification: None,
+            manual_discount_percent: None,
+            rule_discount_amount: 0.0,
+            rule_surcharge_amount: 0.0,
+            applied_rules: vec![],
+            applied_mg_rules: vec![],
+            mg_discount_amount: 0.0,
+            unit_price: 0.0,
+            line_total: 0.0,
+            tax: 0.0,
+            tax_rate: 0,
+            note: None,
+            authorizer_id: None,
+            authorizer_name: None,
+            category_id: None,
+            category_name: None,
+            is_comped: false,
+            course: None,
+            is_held: false,
+        }
+    }
+
+    #[test]
+    fn test_predict_remove_item_updates_totals() {
+        let mut snapshot = active_snapshot(1);
+        snapshot.items.push(item("paella", 10.0, 2));
+        crab_order_core::money::recalculate_totals(&mut snapshot);
+
+        let cmd = OrderCommand::new(
+            1,
+            "Ana".to_string(),
+            OrderCommandPayload::RemoveItem {
+                order_id: 1,
+                instance_id: "paella".to_string(),
+                quantity: Some(1),
+                reason: None,
+                authorizer_id: None,
+                authorizer_name: None,
+            },
+        );
+
+        let prediction = predict_command(&cmd, &[snapshot]).expect("prediction");
+        assert_eq!(prediction.events.len(), 1);
+        assert_eq!(prediction.events[0].sequence, 11);
+        assert_eq!(prediction.snapshots.len(), 1);
+        assert_eq!(prediction.snapshots[0].items[0].quantity, 1);
+        assert_eq!(prediction.snapshots[0].total, 10.0);
+    }
+
+    #[test]
+    fn test_predict_skips_unsupported_and_unknown_orders() {
+        let snapshot = active_snapshot(1);
+
+        let unknown_order = OrderCommand::new(
+            1,
+            "Ana".to_string(),
+            OrderCommandPayload::AddOrderNote {
+                order_id: 2,
+                note: "sin gluten".to_string(),
+            },
+        );
+        assert!(predict_command(&unknown_order, std::slice::from_ref(&snapshot)).is_none());
+
+        let complete = OrderCommand::new(
+            1,
+            "Ana".to_string(),
+            OrderCommandPayload::CompleteOrder {
+                order_id: 1,
+                service_type: None,
+            },
+        );
+        assert!(predict_command(&complete, &[snapshot]).is_none());
+    }
+
+    #[test]
+    fn test_predict_rejected_command_returns_none() {
+        let snapshot = active_snapshot(1);
+        let cmd = OrderCommand::new(
+            1,
+            "Ana".to_string(),
+            OrderCommandPayload::RemoveItem {
+                order_id: 1,
+                instance_id: "missing".to_string(),
+                quantity: None,
+                reason: None,
+                authorizer_id: None,
+                authorizer_name: None,
+            },
+        );
+        assert!(predict_command(&cmd, &[snapshot]).is_none());
+    }
+}
@@ -0,0 +1,424 @@
+//! Session recorder (现场问题录制)
+//!
+//! 录制一段时间窗口内的全部订单命令、响应、事件和总线流量，写入 JSON Lines
+//! 文件，供 [`super::replay`] 在全新的 OrdersManager 上重放复现。
+//!
+//! ```text
+//! {"format":"crab-session","version":1,"started_at":..,"active_orders":[..]}   ← 头部
+//! {"kind":"COMMAND","at":..,"command":{..}}
+//! {"kind":"EVENT","at":..,"event":{..}}
+//! {"kind":"RESPONSE","at":..,"response":{..}}
+//! {"kind":"BUS","at":..,"direction":"TO_CLIENTS","event_type":"sync",..}
+//! ```
+//!
+//! - 头部带录制开始时的活跃订单快照：重放前先写入，录制中途才操作的订单也能复现
+//! - 未录制时 [`SessionRecorder::record`] 只读一个原子标志，对命令处理零开销
+//! - 到达时限自动停止；写入失败时停止录制并记录错误，不影响业务
+
+use std::fs::File;
+use std::io::{BufRead, BufReader, BufWriter, Write};
+use std::path::{Path, PathBuf};
+use std::sync::Arc;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::time::Duration;
+
+use parking_lot::Mutex;
+use serde::{Deserialize, Serialize};
+use shared::message::BusMessage;
+use shared::order::{CommandResponse, OrderCommand, OrderEvent, OrderSnapshot};
+use tokio::sync::broadcast;
+use tokio_util::sync::CancellationToken;
+
+/// 文件格式标识
+pub const SESSION_FORMAT: &str = "crab-session";
+/// 文件格式版本
+pub const SESSION_VERSION: u32 = 1;
+/// 默认/最长录制时长
+pub const DEFAULT_RECORDING_DURATION: Duration = Duration::from_secs(15 * 60);
+pub const MAX_RECORDING_DURATION: Duration = Duration::from_secs(4 * 60 * 60);
+
+/// 录制文件头部
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SessionHeader {
+    pub format: String,
+    pub version: u32,
+    pub started_at: i64,
+    /// 服务器 epoch (区分重启)
+    pub epoch: String,
+    /// 开始时的全局事件序号
+    pub start_sequence: u64,
+    /// 开始时的活跃订单快照
+    pub active_orders: Vec<OrderSnapshot>,
+}
+
+/// 总线消息方向
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+pub enum BusDirection {
+    /// 客户端 → 服务器
+    ToServer,
+    /// 服务器 → 客户端
+    ToClients,
+}
+
+/// 录制条目
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(tag = "kind", rename_all = "SCREAMING_SNAKE_CASE")]
+pub enum SessionEntry {
+    Command {
+        at: i64,
+        command: OrderCommand,
+    },
+    Response {
+        at: i64,
+        response: CommandResponse,
+    },
+    Event {
+        at: i64,
+        event: OrderEvent,
+    },
+    Bus {
+        at: i64,
+        direction: BusDirection,
+        event_type: String,
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        source: Option<String>,
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        target: Option<String>,
+        payload: serde_json::Value,
+    },
+}
+
+impl SessionEntry {
+    pub fn bus(direction: BusDirection, msg: &BusMessage) -> Self {
+        Self::Bus {
+            at: shared::util::now_millis(),
+            direction,
+            event_type: msg.event_type.to_string(),
+            source: msg.source.clone(),
+            target: msg.target.clone(),
+            payload: msg.parse_payload().unwrap_or(serde_json::Value::Null),
+        }
+    }
+}
+
+/// 已加载的录制文件
+#[derive(Debug, Clone)]
+pub struct RecordedSession {
+    pub header: SessionHeader,
+    pub entries: Vec<SessionEntry>,
+}
+
+impl RecordedSession {
+    /// 读取录制文件 (末尾被截断的行会被忽略 — 录制中断电等情况)
+    pub fn load(path: impl AsRef<Path>) -> std::io::Result<Self> {
+        let reader = BufReader::new(File::open(path)?);
+        let mut lines = reader.lines();
+        let header_line = lines
+            .next()
+            .ok_or_else(|| std::io::Error::other("empty session file"))??;
+        let header: SessionHeader = serde_json::from_str(&header_line)?;
+        if header.format != SESSION_FORMAT {
+            return Err(std::io::Error::other(format!(
+                "not a session recording: {}",
+                header.format
+            )));
+        }
+
+        let mut entries = Vec::new();
+        for line in lines {
+            let line = line?;
+            match serde_json::from_str(&line) {
+                Ok(entry) => entries.push(entry),
+                Err(e) => {
+                    tracing::warn!("Skipping unreadable session entry: {}", e);
+                }
+            }
+        }
+        Ok(Self { header, entries })
+    }
+}
+
+/// 录制状态 (API 返回)
+#[derive(Debug, Clone, Serialize)]
+pub struct RecordingStatus {
+    pub active: bool,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub file_name: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub started_at: Option<i64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub ends_at: Option<i64>,
+    pub entries: u64,
+}
+
+struct ActiveRecording {
+    writer: BufWriter<File>,
+    path: PathBuf,
+    started_at: i64,
+    ends_at: i64,
+    entries: u64,
+    /// 停止总线监听任务
+    cancel: CancellationToken,
+}
+
+#[derive(Default)]
+struct RecorderInner {
+    active: AtomicBool,
+    recording: Mutex<Option<ActiveRecording>>,
+}
+
+/// 会话录制器 (Clone 共享同一录制)
+#[derive(Clone, Default)]
+pub struct SessionRecorder {
+    inner: Arc<RecorderInner>,
+}
+
+impl std::fmt::Debug for SessionRecorder {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("SessionRecorder")
+            .field("active", &self.is_active())
+            .finish()
+    }
+}
+
+impl SessionRecorder {
+    pub fn is_active(&self) -> bool {
+        self.inner.active.load(Ordering::Relaxed)
+    }
+
+    /// 开始录制到 `path`，写入头部；已在录制时返回错误
+    pub fn start(
+        &self,
+        path: PathBuf,
+        header: &SessionHeader,
+        duration: Duration,
+    ) -> std::io::Result<RecordingStatus> {
+        let mut guard = self.inner.recording.lock();
+        if guard.is_some() {
+            return Err(std::io::Error::new(
+                std::io::ErrorKind::AlreadyExists,
+                "a recording is already running",
+            ));
+        }
+        if let Some(parent) = path.parent() {
+            std::fs::create_dir_all(parent)?;
+        }
+        let mut writer = BufWriter::new(File::create(&path)?);
+        serde_json::to_writer(&mut writer, header)?;
+        writer.write_all(b"\n")?;
+
+        let duration = duration.min(MAX_RECORDING_DURATION);
+        *guard = Some(ActiveRecording {
+            writer,
+            path,
+            started_at: header.started_at,
+            ends_at: header.started_at + duration.as_millis() as i64,
+            entries: 0,
+            cancel: CancellationToken::new(),
+        });
+        self.inner.active.store(true, Ordering::Relaxed);
+        tracing::info!(
+            duration_secs = duration.as_secs(),
+            "Session recording started"
+        );
+        Ok(Self::status_of(guard.as_ref()))
+    }
+
+    /// 停止录制并落盘；返回最终状态 (未在录制时返回 None)
+    pub fn stop(&self) -> Option<RecordingStatus> {
+        let mut guard = self.inner.recording.lock();
+        self.inner.active.store(false, Ordering::Relaxed);
+        let mut recording = guard.take()?;
+        recording.cancel.cancel();
+        if let Err(e) = recording.writer.flush() {
+            tracing::error!("Failed to flush session recording: {}", e);
+        }
+        let status = Self::status_of(Some(&recording));
+        tracing::info!(
+            entries = recording.entries,
+            path = %recording.path.display(),
+            "Session recording stopped"
+        );
+        Some(RecordingStatus {
+            active: false,
+            ..status
+        })
+    }
+
+    pub fn status(&self) -> RecordingStatus {
+        Self::status_of(self.inner.recording.lock().as_ref())
+    }
+
+    fn status_of(recording: Option<&ActiveRecording>) -> RecordingStatus {
+        match recording {
+            Some(r) => RecordingStatus {
+                active: true,
+                file_name: r.path.file_name().map(|n| n.to_string_lossy().into_owned()),
+                started_at: Some(r.started_at),
+                ends_at: Some(r.ends_at),
+                entries: r.entries,
+            },
+            None => RecordingStatus {
+                active: false,
+                file_name: None,
+                started_at: None,
+                ends_at: None,
+                entries: 0,
+            },
+        }
+    }
+
+    /// 记录一条 (未录制时直接返回)
+    pub fn record(&self, entry: &SessionEntry) {
+        if !self.is_active() {
+            return;
+        }
+        let expired = {
+            let mut guard = self.inner.recording.lock();
+            let Some(recording) = guard.as_mut() else {
+                return;
+            };
+            if shared::util::now_millis() >= recording.ends_at {
+                true
+            } else {
+                let written = serde_json::to_writer(&mut recording.writer, entry)
+                    .map_err(std::io::Error::from)
+                    .and_then(|_| recording.writer.write_all(b"\n"));
+                match written {
+                    Ok(()) => {
+                        recording.entries += 1;
+                        false
+                    }
+                    Err(e) => {
+                        tracing::error!("Session recording write failed, stopping: {}", e);
+                        true
+                    }
+                }
+            }
+        };
+        if expired {
+            self.stop();
+        }
+    }
+
+    /// 录制一条命令的完整结果 (命令 → 事件 → 响应)
+    pub fn record_command(
+        &self,
+        command: &OrderCommand,
+        response: &CommandResponse,
+        events: &[OrderEvent],
+    ) {
+        if !self.is_active() {
+            return;
+        }
+        let at = shared::util::now_millis();
+        self.record(&SessionEntry::Command {
+            at,
+            command: command.clone(),
+        });
+        for event in events {
+            self.record(&SessionEntry::Event {
+                at,
+                event: event.clone(),
+            });
+        }
+        self.record(&SessionEntry::Response {
+            at,
+            response: response.clone(),
+        });
+    }
+
+    /// 监听总线双向流量直到录制停止
+    pub fn spawn_bus_tap(
+        &self,
+        mut to_server: broadcast::Receiver<BusMessage>,
+        mut to_clients: broadcast::Receiver<BusMessage>,
+    ) {
+        let Some(cancel) = self
+            .inner
+            .recording
+            .lock()
+            .as_ref()
+            .map(|r| r.cancel.clone())
+        else {
+            return;
+        };
+        let recorder = self.clone();
+        tokio::spawn(async move {
+            loop {
+                let (direction, result) = tokio::select! {
+                    _ = cancel.cancelled() => break,
+                    r = to_server.recv() => (BusDirection::ToServer, r),
+                    r = to_clients.recv() => (BusDirection::ToClients, r),
+                };
+                match result {
+                    Ok(msg) => recorder.record(&SessionEntry::bus(direction, &msg)),
+                    Err(broadcast::error::RecvError::Lagged(n)) => {
+                        tracing::warn!("Session recording bus tap lagged by {} messages", n);
+                    }
+                    Err(broadcast::error::RecvError::Closed) => break,
+                }
+            }
+        });
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use shared::order::OrderCommandPayload;
+
+    #[test]
+    fn test_record_and_load_round_trip() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("session.jsonl");
+        let recorder = SessionRecorder::default();
+
+        let command = OrderCommand::new(
+            1,
+            "Ana".to_string(),
+            OrderCommandPayload::OpenTable {
+                table_id: Some(3),
+                table_name: Some("T3".to_string()),
+                zone_id: None,
+                zone_name: None,
+                guest_count: 2,
+                is_retail: false,
+            },
+        );
+        let response = CommandResponse::success(command.command_id, Some(42));
+
+        // 未录制: 不写入
+        recorder.record_command(&command, &response, &[]);
+
+        let header = SessionHeader {
+            format: SESSION_FORMAT.to_string(),
+            version: SESSION_VERSION,
+            started_at: shared::util::now_millis(),
+            epoch: "epoch".to_string(),
+            start_sequence: 0,
+            active_orders: vec![],
+        };
+        recorder
+            .start(path.clone(), &header, Duration::from_secs(60))
+            .unwrap();
+        assert!(
+            recorder
+                .start(path.clone(), &header, Duration::from_secs(60))
+                .is_err()
+        );
+        recorder.record_command(&command, &response, &[]);
+        let status = recorder.stop().unwrap();
+        assert_eq!(status.entries, 2);
+        assert!(!recorder.is_active());
+
+        let session = RecordedSession::load(&path).unwrap();
+        assert_eq!(session.header.epoch, "epoch");
+        assert!(matches!(
+            &session.entries[..],
+            [SessionEntry::Command { .. }, SessionEntry::Response { response, .. }]
+                if response.order_id == Some(42)
+        ));
+    }
+}
@@ -5,8 +5,8 @@
 //! - `input_to_snapshot`: Convert CartItemInput to CartItemSnapshot
 //! - `input_to_snapshot_with_rules`: Convert CartItemInput to CartItemSnapshot with price rules applied
 //!
-//! Note: Event application logic has been moved to the appliers module.
-//! Use `EventAction` from `super::appliers` to apply events to snapshots.
+//! Note: Event application logic lives in `crab-order-core` (shared with clients).
+//! Use `crab_order_core::EventAction` to apply events to snapshots.
 
 use crate::pricing::{calculate_item_price, matches_product_scope};
 use shared::models::PriceRule;
@@ -21,19 +21,24 @@ use tracing::debug;
 /// - manual_discount_percent: 手动折扣
 /// - selected_options: 选项（attribute_id + option_id）
 /// - selected_specification: 规格
+/// - course: 道次（仅分道次时追加，不同道次不合并）
 ///
 /// Items with the same instance_id can be merged (quantities added together).
 ///
 /// 注意：instance_id 完全基于 CartItemInput 字段生成，不受规则计算结果影响。
 /// 这确保了同一商品在任何时刻（规则缓存是否存在）都能正确合并。
 pub fn generate_instance_id(input: &shared::order::CartItemInput) -> String {
-    generate_instance_id_from_parts(
+    let id = generate_instance_id_from_parts(
         input.product_id,
         input.price,
         input.manual_discount_percent,
         &input.selected_options,
         &input.selected_specification,
-    )
+    );
+    match input.course {
+        Some(course) => format!("{}::course::{}", id, course),
+        None => id,
+    }
 }
 
 /// Internal helper to generate instance_id from individual parts
@@ -213,6 +218,8 @@ pub fn input_to_snapshot_with_rules(
         category_id: None, // Set by AddItemsAction from ProductMeta
         category_name: None,
         is_comped: false,
+        course: input.course,
+        is_held: false, // Set by AddItemsAction (course not yet fired)
     }
 }
 
@@ -277,6 +284,7 @@ mod tests {
             note: None,
             authorizer_id: None,
             authorizer_name: None,
+            course: None,
         };
 
         let id1 = generate_instance_id(&input);
@@ -310,6 +318,7 @@ mod tests {
             note: Some("Test note".to_string()),
             authorizer_id: None,
             authorizer_name: None,
+            course: None,
         };
 
         let snapshot = input_to_snapshot(&input);
@@ -340,6 +349,7 @@ mod tests {
             note: None,
             authorizer_id: None,
             authorizer_name: None,
+            course: None,
         };
 
         let snapshot = input_to_snapshot_with_rules(&input, &[], 1, None, &[]);
@@ -365,6 +375,7 @@ mod tests {
             note: None,
             authorizer_id: None,
             authorizer_name: None,
+            course: None,
         };
 
         // 10% discount rule
@@ -381,6 +392,7 @@ mod tests {
             adjustment_value: 10.0,
             is_stackable: true,
             is_exclusive: false,
+            priority: 0,
             valid_from: None,
             valid_until: None,
             active_days: None,
@@ -444,6 +456,7 @@ mod tests {
             note: None,
             authorizer_id: None,
             authorizer_name: None,
+            course: None,
         };
 
         // 10% discount rule
@@ -460,6 +473,7 @@ mod tests {
             adjustment_value: 10.0,
             is_stackable: true,
             is_exclusive: false,
+            priority: 0,
             valid_from: None,
             valid_until: None,
             active_days: None,
@@ -496,6 +510,7 @@ mod tests {
             note: None,
             authorizer_id: None,
             authorizer_name: None,
+            course: None,
         };
 
         // 10% rule discount
@@ -512,6 +527,7 @@ mod tests {
             adjustment_value: 10.0,
             is_stackable: true,
             is_exclusive: false,
+            priority: 0,
             valid_from: None,
             valid_until: None,
             active_days: None,
@@ -550,6 +566,7 @@ mod tests {
             note: None,
             authorizer_id: None,
             authorizer_name: None,
+            course: None,
         };
 
         // Case 1: Without rules (e.g., cache miss)
@@ -569,6 +586,7 @@ mod tests {
             adjustment_value: 10.0,
             is_stackable: true,
             is_exclusive: false,
+            priority: 0,
             valid_from: None,
             valid_until: None,
             active_days: None,
@@ -611,6 +629,7 @@ mod tests {
             note: None,
             authorizer_id: None,
             authorizer_name: None,
+            course: None,
         };
 
         // Global scope rule - should apply to all products
@@ -627,6 +646,7 @@ mod tests {
             adjustment_value: 10.0,
             is_stackable: true,
             is_exclusive: false,
+            priority: 0,
             valid_from: None,
             valid_until: None,
             active_days: None,
@@ -651,6 +671,7 @@ mod tests {
             adjustment_value: 5.0,
             is_stackable: true,
             is_exclusive: false,
+            priority: 0,
             valid_from: None,
             valid_until: None,
             active_days: None,
@@ -675,6 +696,7 @@ mod tests {
             adjustment_value: 50.0, // Large discount that should NOT apply
             is_stackable: true,
             is_exclusive: false,
+            priority: 0,
             valid_from: None,
             valid_until: None,
             active_days: None,
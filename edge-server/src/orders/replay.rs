@@ -0,0 +1,391 @@
+//! Session replay harness (录制重放)
+//!
+//! 把 [`super::recorder`] 录制的命令按原顺序喂给一个全新的 OrdersManager，
+//! 并与录制时的响应 / 事件逐条比对，用于在开发环境复现门店报告的问题。
+//!
+//! 新实例生成的 ID (order_id / payment_id / instance_id …) 与录制时不同：
+//! 每条命令执行后，按位置比对录制事件与重放事件中 `*_id` 字段的取值建立映射，
+//! 后续命令中引用旧 ID 的字段在执行前改写为新 ID。
+//!
+//! ```ignore
+//! let session = RecordedSession::load("session-20250301-2130.jsonl")?;
+//! let manager = OrdersManager::new(tmp.path().join("replay.redb"), tz, 1)?;
+//! let report = replay(&session, &manager).await?;
+//! for d in &report.divergences { println!("{d}"); }
+//! ```
+
+use std::collections::HashMap;
+
+use serde::Serialize;
+use serde_json::Value;
+use shared::order::{CommandResponse, OrderCommand, OrderEvent};
+
+use super::manager::{ManagerResult, OrdersManager};
+use super::recorder::{RecordedSession, SessionEntry};
+
+/// 重放结果
+#[derive(Debug, Clone, Default, Serialize)]
+pub struct ReplayReport {
+    /// 重放的命令数
+    pub commands: usize,
+    /// 结果与录制一致的命令数
+    pub matched: usize,
+    /// 不一致的命令
+    pub divergences: Vec<Divergence>,
+}
+
+impl ReplayReport {
+    pub fn is_faithful(&self) -> bool {
+        self.divergences.is_empty()
+    }
+}
+
+/// 单条命令的重放差异
+#[derive(Debug, Clone, Serialize)]
+pub struct Divergence {
+    /// 命令在录制中的序号 (从 0 开始)
+    pub index: usize,
+    pub command_id: i64,
+    /// 命令类型 (`ADD_ITEMS` …)
+    pub command_type: String,
+    pub expected: String,
+    pub actual: String,
+}
+
+impl std::fmt::Display for Divergence {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "#{} {} ({}): expected {}, got {}",
+            self.index, self.command_type, self.command_id, self.expected, self.actual
+        )
+    }
+}
+
+/// 录制时的命令及其结果
+struct RecordedCommand {
+    command: OrderCommand,
+    response: Option<CommandResponse>,
+    events: Vec<OrderEvent>,
+}
+
+/// 按 command_id 归并条目 (并发命令的条目可能交错)
+fn collect_commands(session: &RecordedSession) -> Vec<RecordedCommand> {
+    let mut commands: Vec<RecordedCommand> = Vec::new();
+    let mut index: HashMap<i64, usize> = HashMap::new();
+    for entry in &session.entries {
+        match entry {
+            SessionEntry::Command { command, .. } => {
+                index.insert(command.command_id, commands.len());
+                commands.push(RecordedCommand {
+                    command: command.clone(),
+                    response: None,
+                    events: Vec::new(),
+                });
+            }
+            SessionEntry::Event { event, .. } => {
+                if let Some(&i) = index.get(&event.command_id) {
+                    commands[i].events.push(event.clone());
+                }
+            }
+            SessionEntry::Response { response, .. } => {
+                if let Some(&i) = index.get(&response.command_id) {
+                    commands[i].response = Some(response.clone());
+                }
+            }
+            SessionEntry::Bus { .. } => {}
+        }
+    }
+    commands
+}
+
+/// 在 `manager` 上重放录制 (manager 应为全新实例)
+pub async fn replay(
+    session: &RecordedSession,
+    manager: &OrdersManager,
+) -> ManagerResult<ReplayReport> {
+    manager.seed_snapshots(&session.header.active_orders)?;
+
+    let mut ids = IdMap::default();
+    let mut report = ReplayReport::default();
+    for (index, recorded) in collect_commands(session).into_iter().enumerate() {
+        let mut value = serde_json::to_value(&recorded.command).unwrap_or(Value::Null);
+        ids.rewrite(&mut value);
+        let command_type = value
+            .pointer("/payload/type")
+            .and_then(Value::as_str)
+            .unwrap_or("UNKNOWN")
+            .to_string();
+        let command: OrderCommand = match serde_json::from_value(value) {
+            Ok(command) => command,
+            Err(e) => {
+                report.divergences.push(Divergence {
+                    index,
+                    command_id: recorded.command.command_id,
+                    command_type,
+                    expected: "replayable command".to_string(),
+                    actual: format!("rewrite failed: {e}"),
+                });
+                continue;
+            }
+        };
+
+        let (response, events) = manager.execute_command_with_events(command).await;
+        report.commands += 1;
+
+        if let (Some(expected), Some(actual)) = (
+            recorded.response.as_ref().and_then(|r| r.order_id),
+            response.order_id,
+        ) && expected != actual
+        {
+            ids.ints.insert(expected, actual);
+        }
+        for (expected, actual) in recorded.events.iter().zip(&events) {
+            if let (Ok(expected), Ok(actual)) =
+                (serde_json::to_value(expected), serde_json::to_value(actual))
+            {
+                ids.learn(&expected, &actual);
+            }
+        }
+
+        match compare(&recorded, &response, &events) {
+            None => report.matched += 1,
+            Some((expected, actual)) => report.divergences.push(Divergence {
+                index,
+                command_id: recorded.command.command_id,
+                command_type,
+                expected,
+                actual,
+            }),
+        }
+    }
+    Ok(report)
+}
+
+/// 比较结果：成功与否 / 错误码 / 事件类型序列
+fn compare(
+    recorded: &RecordedCommand,
+    response: &CommandResponse,
+    events: &[OrderEvent],
+) -> Option<(String, String)> {
+    let outcome = |r: &CommandResponse| match &r.error {
+        None if r.success => "success".to_string(),
+        None => "failure".to_string(),
+        Some(e) => format!("{:?}", e.code),
+    };
+    if let Some(expected) = &recorded.response {
+        let (expected, actual) = (outcome(expected), outcome(response));
+        if expected != actual {
+            return Some((expected, actual));
+        }
+    }
+
+    let types = |events: &[OrderEvent]| {
+        events
+            .iter()
+            .map(|e| format!("{:?}", e.event_type))
+            .collect::<Vec<_>>()
+            .join(",")
+    };
+    let (expected, actual) = (types(&recorded.events), types(events));
+    (expected != actual).then(|| (format!("[{expected}]"), format!("[{actual}]")))
+}
+
+fn is_id_key(key: &str) -> bool {
+    key == "id" || key.ends_with("_id") || key.ends_with("_ids")
+}
+
+/// 录制 ID → 重放 ID
+#[derive(Debug, Default)]
+struct IdMap {
+    ints: HashMap<i64, i64>,
+    strings: HashMap<String, String>,
+}
+
+impl IdMap {
+    /// 同构 JSON 中 `*_id` 字段取值不同 → 记录映射
+    fn learn(&mut self, recorded: &Value, replayed: &Value) {
+        match (recorded, replayed) {
+            (Value::Object(rec), Value::Object(rep)) => {
+                for (key, rv) in rec {
+                    let Some(pv) = rep.get(key) else { continue };
+                    if is_id_key(key) {
+                        self.learn_leaf(rv, pv);
+                    } else {
+                        self.learn(rv, pv);
+                    }
+                }
+            }
+            (Value::Array(rec), Value::Array(rep)) => {
+                for (rv, pv) in rec.iter().zip(rep) {
+                    self.learn(rv, pv);
+                }
+            }
+            _ => {}
+        }
+    }
+
+    fn learn_leaf(&mut self, recorded: &Value, replayed: &Value) {
+        match (recorded, replayed) {
+            (Value::Number(a), Value::Number(b)) => {
+                if let (Some(a), Some(b)) = (a.as_i64(), b.as_i64())
+                    && a != b
+                {
+                    self.ints.insert(a, b);
+                }
+            }
+            (Value::String(a), Value::String(b)) if a != b => {
+                self.strings.insert(a.clone(), b.clone());
+            }
+            (Value::Array(a), Value::Array(b)) => {
+                for (a, b) in a.iter().zip(b) {
+                    self.learn_leaf(a, b);
+                }
+            }
+            _ => {}
+        }
+    }
+
+    fn rewrite(&self, value: &mut Value) {
+        match value {
+            Value::Object(map) => {
+                for (key, v) in map.iter_mut() {
+                    if is_id_key(key) {
+                        self.rewrite_leaf(v);
+                    } else {
+                        self.rewrite(v);
+                    }
+                }
+            }
+            Value::Array(items) => items.iter_mut().for_each(|v| self.rewrite(v)),
+            _ => {}
+        }
+    }
+
+    fn rewrite_leaf(&self, value: &mut Value) {
+        match value {
+            Value::Number(n) => {
+                if let Some(mapped) = n.as_i64().and_then(|id| self.ints.get(&id)) {
+                    *value = Value::from(*mapped);
+                }
+            }
+            Value::String(s) => {
+                if let Some(mapped) = self.strings.get(s.as_str()) {
+                    *value = Value::from(mapped.clone());
+                }
+            }
+            Value::Array(items) => items.iter_mut().for_each(|v| self.rewrite_leaf(v)),
+            _ => {}
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::orders::recorder::{SESSION_FORMAT, SESSION_VERSION, SessionHeader};
+    use crate::orders::storage::OrderStorage;
+    use shared::order::{CartItemInput, OrderCommandPayload};
+
+    fn manager() -> OrdersManager {
+        OrdersManager::with_storage(OrderStorage::open_in_memory().unwrap())
+    }
+
+    fn item() -> CartItemInput {
+        CartItemInput {
+            product_id: 7,
+            name: "Caña".to_string(),
+            price: 2.5,
+            original_price: None,
+            quantity: 2,
+            selected_options: None,
+            selected_specification: None,
+            manual_discount_percent: None,
+            note: None,
+            authorizer_id: None,
+            authorizer_name: None,
+            course: None,
+        }
+    }
+
+    /// 在 `manager` 上执行并返回录制条目 (模拟现场录制)
+    async fn run(manager: &OrdersManager, cmd: OrderCommand, out: &mut Vec<SessionEntry>) -> i64 {
+        let (response, events) = manager.execute_command_with_events(cmd.clone()).await;
+        out.push(SessionEntry::Command {
+            at: 0,
+            command: cmd,
+        });
+        out.extend(
+            events
+                .into_iter()
+                .map(|event| SessionEntry::Event { at: 0, event }),
+        );
+        let order_id = response.order_id.unwrap_or_default();
+        out.push(SessionEntry::Response { at: 0, response });
+        order_id
+    }
+
+    #[tokio::test]
+    async fn test_replay_remaps_ids_and_detects_divergence() {
+        let field = manager();
+        let mut entries = Vec::new();
+        let order_id = run(
+            &field,
+            OrderCommand::new(
+                1,
+                "Ana".to_string(),
+                OrderCommandPayload::OpenTable {
+                    table_id: Some(4),
+                    table_name: Some("T4".to_string()),
+                    zone_id: None,
+                    zone_name: None,
+                    guest_count: 2,
+                    is_retail: false,
+                },
+            ),
+            &mut entries,
+        )
+        .await;
+        run(
+            &field,
+            OrderCommand::new(
+                1,
+                "Ana".to_string(),
+                OrderCommandPayload::AddItems {
+                    order_id,
+                    items: vec![item()],
+                },
+            ),
+            &mut entries,
+        )
+        .await;
+
+        let session = RecordedSession {
+            header: SessionHeader {
+                format: SESSION_FORMAT.to_string(),
+                version: SESSION_VERSION,
+                started_at: 0,
+                epoch: field.epoch().to_string(),
+                start_sequence: 0,
+                active_orders: vec![],
+            },
+            entries,
+        };
+
+        // 新实例生成不同的 order_id，AddItems 仍应命中
+        let report = replay(&session, &manager()).await.unwrap();
+        assert_eq!(report.commands, 2);
+        assert!(report.is_faithful(), "{:?}", report.divergences);
+
+        // 篡改录制结果 → 报告差异
+        let mut tampered = session.clone();
+        for entry in &mut tampered.entries {
+            if let SessionEntry::Response { response, .. } = entry {
+                response.success = false;
+            }
+        }
+        let report = replay(&tampered, &manager()).await.unwrap();
+        assert_eq!(report.divergences.len(), 2);
+        assert_eq!(report.divergences[1].command_type, "ADD_ITEMS");
+    }
+}
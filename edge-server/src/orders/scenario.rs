@@ -0,0 +1,826 @@
+//! Scenario DSL (声明式测试场景)
+//!
+//! 用菜单 + 桌台 + 步骤序列描述一个订单场景，驱动 [`OrdersManager`] 执行并校验结果。
+//! 单元测试、压测 / 演示数据生成 (`examples/scenario.rs`) 共用同一份定义，
+//! 门店报告的问题可以直接写成一个场景文件作为回归测试。
+//!
+//! - 订单用别名引用 (`open` 的 `as`)，执行时映射为真实 order_id
+//! - 商品 / 桌台按菜单名称引用
+//! - `pay` 省略金额时付清剩余金额
+//! - `fails` 包裹一个步骤，断言其被拒绝 (可指定错误码)
+//! - `command` 直接给出任意 [`OrderCommandPayload`]，`order_id` 由别名填充
+//!
+//! ```json
+//! {
+//!   "name": "discount then pay in two methods",
+//!   "menu": [{ "id": 1, "name": "Café", "price": 1.8 }],
+//!   "tables": [{ "id": 7, "name": "Mesa 7" }],
+//!   "steps": [
+//!     { "open": { "as": "t7", "table": "Mesa 7" } },
+//!     { "add": { "order": "t7", "items": [{ "product": "Café", "qty": 2 }] } },
+//!     { "discount": { "order": "t7", "percent": 10 } },
+//!     { "pay": { "order": "t7", "method": "CARD", "amount": 2.0 } },
+//!     { "pay": { "order": "t7" } },
+//!     { "complete": { "order": "t7" } },
+//!     { "expect": { "order": "t7", "status": "COMPLETED", "total": 3.24, "paid": 3.24 } }
+//!   ]
+//! }
+//! ```
+//!
+//! 同一场景也可以用 builder 在代码里构造：
+//!
+//! ```ignore
+//! let outcome = Scenario::new("split payment")
+//!     .product(1, "Café", 1.8)
+//!     .table(7, "Mesa 7")
+//!     .open("t7", "Mesa 7")
+//!     .add("t7", &[("Café", 2)])
+//!     .pay("t7", "CARD", Some(2.0))
+//!     .pay_rest("t7")
+//!     .complete("t7")
+//!     .expect(Expectation::order("t7").status(OrderStatus::Completed).total(3.6))
+//!     .run(&manager)
+//!     .await?;
+//! ```
+
+use std::collections::HashMap;
+use std::path::Path;
+
+use serde::{Deserialize, Serialize};
+use shared::order::types::ServiceType;
+use shared::order::{
+    CartItemInput, CommandErrorCode, CommandResponse, OrderCommand, OrderCommandPayload,
+    OrderSnapshot, OrderStatus, PaymentInput, VoidType,
+};
+use thiserror::Error;
+
+use super::manager::OrdersManager;
+
+/// 金额比较容差
+const AMOUNT_EPSILON: f64 = 0.005;
+
+/// 一个完整的测试场景
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Scenario {
+    pub name: String,
+    /// 所有命令的操作员 (默认种子数据中的 admin)
+    #[serde(default)]
+    pub operator: Operator,
+    #[serde(default)]
+    pub menu: Vec<MenuProduct>,
+    #[serde(default)]
+    pub tables: Vec<ScenarioTable>,
+    #[serde(default)]
+    pub steps: Vec<Step>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Operator {
+    pub id: i64,
+    pub name: String,
+}
+
+impl Default for Operator {
+    fn default() -> Self {
+        Self {
+            id: 1,
+            name: "Admin".to_string(),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct MenuProduct {
+    pub id: i64,
+    pub name: String,
+    pub price: f64,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ScenarioTable {
+    pub id: i64,
+    pub name: String,
+    #[serde(default)]
+    pub zone_id: Option<i64>,
+    #[serde(default)]
+    pub zone_name: Option<String>,
+}
+
+/// 点单行 (按菜单名称引用商品)
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ItemLine {
+    pub product: String,
+    #[serde(default = "default_quantity")]
+    pub qty: i32,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub discount_percent: Option<f64>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub note: Option<String>,
+}
+
+fn default_quantity() -> i32 {
+    1
+}
+
+fn default_guests() -> i32 {
+    2
+}
+
+fn default_method() -> String {
+    "CASH".to_string()
+}
+
+/// 场景步骤
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum Step {
+    /// 开台 (省略 `table` = 零售单)
+    Open {
+        #[serde(rename = "as")]
+        alias: String,
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        table: Option<String>,
+        #[serde(default = "default_guests")]
+        guests: i32,
+    },
+    Add {
+        order: String,
+        items: Vec<ItemLine>,
+    },
+    /// 移除某商品的第一行 (`qty` 省略 = 整行)
+    Remove {
+        order: String,
+        product: String,
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        qty: Option<i32>,
+    },
+    /// 赠送某商品 (第一行未赠送的)
+    Comp {
+        order: String,
+        product: String,
+        #[serde(default = "default_quantity")]
+        qty: i32,
+        reason: String,
+    },
+    Discount {
+        order: String,
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        percent: Option<f64>,
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        fixed: Option<f64>,
+    },
+    /// 支付 (`amount` 省略 = 付清剩余)
+    Pay {
+        order: String,
+        #[serde(default = "default_method")]
+        method: String,
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        amount: Option<f64>,
+    },
+    Complete {
+        order: String,
+    },
+    Void {
+        order: String,
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        note: Option<String>,
+    },
+    /// 任意命令 (payload 为 OrderCommandPayload JSON，`order_id` 由别名填充)
+    Command {
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        order: Option<String>,
+        payload: serde_json::Value,
+    },
+    /// 断言内部步骤被拒绝
+    Fails {
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        code: Option<CommandErrorCode>,
+        step: Box<Step>,
+    },
+    Expect(Expectation),
+}
+
+/// 订单状态断言 (只校验给出的字段)
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct Expectation {
+    pub order: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub status: Option<OrderStatus>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub total: Option<f64>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub paid: Option<f64>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub remaining: Option<f64>,
+    /// 商品行数
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub items: Option<usize>,
+}
+
+impl Expectation {
+    pub fn order(alias: &str) -> Self {
+        Self {
+            order: alias.to_string(),
+            ..Default::default()
+        }
+    }
+
+    pub fn status(mut self, status: OrderStatus) -> Self {
+        self.status = Some(status);
+        self
+    }
+
+    pub fn total(mut self, total: f64) -> Self {
+        self.total = Some(total);
+        self
+    }
+
+    pub fn paid(mut self, paid: f64) -> Self {
+        self.paid = Some(paid);
+        self
+    }
+
+    pub fn remaining(mut self, remaining: f64) -> Self {
+        self.remaining = Some(remaining);
+        self
+    }
+
+    pub fn items(mut self, items: usize) -> Self {
+        self.items = Some(items);
+        self
+    }
+}
+
+/// 场景执行错误 (`step` 为步骤序号，从 0 开始)
+#[derive(Debug, Error)]
+pub enum ScenarioError {
+    #[error("step {step}: unknown order alias '{alias}'")]
+    UnknownOrder { step: usize, alias: String },
+
+    #[error("step {step}: unknown product '{name}'")]
+    UnknownProduct { step: usize, name: String },
+
+    #[error("step {step}: unknown table '{name}'")]
+    UnknownTable { step: usize, name: String },
+
+    #[error("step {step}: no item '{product}' in order '{alias}'")]
+    ItemNotInOrder {
+        step: usize,
+        alias: String,
+        product: String,
+    },
+
+    #[error("step {step}: invalid step: {message}")]
+    InvalidStep { step: usize, message: String },
+
+    #[error("step {step}: command failed: {message}")]
+    CommandFailed {
+        step: usize,
+        code: Option<CommandErrorCode>,
+        message: String,
+    },
+
+    #[error("step {step}: expected the command to fail, but it succeeded")]
+    UnexpectedSuccess { step: usize },
+
+    #[error("step {step}: expected error {expected:?}, got {actual:?}")]
+    WrongError {
+        step: usize,
+        expected: CommandErrorCode,
+        actual: Option<CommandErrorCode>,
+    },
+
+    #[error("step {step}: {field} expected {expected}, got {actual}")]
+    Mismatch {
+        step: usize,
+        field: &'static str,
+        expected: String,
+        actual: String,
+    },
+
+    #[error("step {step}: {message}")]
+    Snapshot { step: usize, message: String },
+}
+
+/// 场景执行结果
+#[derive(Debug, Clone, Default)]
+pub struct ScenarioOutcome {
+    /// 订单别名 → order_id
+    pub orders: HashMap<String, i64>,
+    /// 已执行的命令数 (含预期失败的命令)
+    pub commands: usize,
+}
+
+impl ScenarioOutcome {
+    pub fn order_id(&self, alias: &str) -> Option<i64> {
+        self.orders.get(alias).copied()
+    }
+}
+
+// ============================================================================
+// Builder
+// ============================================================================
+
+impl Scenario {
+    pub fn new(name: impl Into<String>) -> Self {
+        Self {
+            name: name.into(),
+            operator: Operator::default(),
+            menu: Vec::new(),
+            tables: Vec::new(),
+            steps: Vec::new(),
+        }
+    }
+
+    pub fn operator(mut self, id: i64, name: &str) -> Self {
+        self.operator = Operator {
+            id,
+            name: name.to_string(),
+        };
+        self
+    }
+
+    pub fn product(mut self, id: i64, name: &str, price: f64) -> Self {
+        self.menu.push(MenuProduct {
+            id,
+            name: name.to_string(),
+            price,
+        });
+        self
+    }
+
+    pub fn table(mut self, id: i64, name: &str) -> Self {
+        self.tables.push(ScenarioTable {
+            id,
+            name: name.to_string(),
+            zone_id: None,
+            zone_name: None,
+        });
+        self
+    }
+
+    pub fn step(mut self, step: Step) -> Self {
+        self.steps.push(step);
+        self
+    }
+
+    pub fn open(self, alias: &str, table: &str) -> Self {
+        self.step(Step::Open {
+            alias: alias.to_string(),
+            table: Some(table.to_string()),
+            guests: default_guests(),
+        })
+    }
+
+    pub fn open_retail(self, alias: &str) -> Self {
+        self.step(Step::Open {
+            alias: alias.to_string(),
+            table: None,
+            guests: 1,
+        })
+    }
+
+    pub fn add(self, alias: &str, items: &[(&str, i32)]) -> Self {
+        self.step(Step::Add {
+            order: alias.to_string(),
+            items: items
+                .iter()
+                .map(|(product, qty)| ItemLine {
+                    product: product.to_string(),
+                    qty: *qty,
+                    discount_percent: None,
+                    note: None,
+                })
+                .collect(),
+        })
+    }
+
+    pub fn discount_percent(self, alias: &str, percent: f64) -> Self {
+        self.step(Step::Discount {
+            order: alias.to_string(),
+            percent: Some(percent),
+            fixed: None,
+        })
+    }
+
+    pub fn pay(self, alias: &str, method: &str, amount: Option<f64>) -> Self {
+        self.step(Step::Pay {
+            order: alias.to_string(),
+            method: method.to_string(),
+            amount,
+        })
+    }
+
+    pub fn pay_rest(self, alias: &str) -> Self {
+        self.pay(alias, &default_method(), None)
+    }
+
+    pub fn complete(self, alias: &str) -> Self {
+        self.step(Step::Complete {
+            order: alias.to_string(),
+        })
+    }
+
+    pub fn void(self, alias: &str) -> Self {
+        self.step(Step::Void {
+            order: alias.to_string(),
+            note: None,
+        })
+    }
+
+    pub fn fails(self, code: Option<CommandErrorCode>, step: Step) -> Self {
+        self.step(Step::Fails {
+            code,
+            step: Box::new(step),
+        })
+    }
+
+    pub fn expect(self, expectation: Expectation) -> Self {
+        self.step(Step::Expect(expectation))
+    }
+}
+
+// ============================================================================
+// Loading
+// ============================================================================
+
+impl Scenario {
+    pub fn from_json(json: &str) -> serde_json::Result<Self> {
+        serde_json::from_str(json)
+    }
+
+    pub fn load(path: impl AsRef<Path>) -> std::io::Result<Self> {
+        let content = std::fs::read_to_string(path)?;
+        Self::from_json(&content).map_err(std::io::Error::other)
+    }
+}
+
+// ============================================================================
+// Runner
+// ============================================================================
+
+impl Scenario {
+    /// 按顺序执行所有步骤，遇到第一个失败 / 不符合预期的步骤即返回错误
+    pub async fn run(&self, manager: &OrdersManager) -> Result<ScenarioOutcome, ScenarioError> {
+        let mut outcome = ScenarioOutcome::default();
+        for (index, step) in self.steps.iter().enumerate() {
+            self.run_step(manager, index, step, &mut outcome).await?;
+        }
+        Ok(outcome)
+    }
+
+    async fn run_step(
+        &self,
+        manager: &OrdersManager,
+        index: usize,
+        step: &Step,
+        outcome: &mut ScenarioOutcome,
+    ) -> Result<(), ScenarioError> {
+        match step {
+            Step::Expect(expectation) => self.check(manager, index, expectation, outcome),
+            Step::Fails { code, step } => {
+                let command = self.build_command(manager, index, step, outcome)?;
+                let response = manager.execute_command(command).await;
+                outcome.commands += 1;
+                if response.success {
+                    return Err(ScenarioError::UnexpectedSuccess { step: index });
+                }
+                let actual = response.error.map(|e| e.code);
+                match code {
+                    Some(expected) if actual.as_ref() != Some(expected) => {
+                        Err(ScenarioError::WrongError {
+                            step: index,
+                            expected: expected.clone(),
+                            actual,
+                        })
+                    }
+                    _ => Ok(()),
+                }
+            }
+            _ => {
+                let command = self.build_command(manager, index, step, outcome)?;
+                let response = manager.execute_command(command).await;
+                outcome.commands += 1;
+                let response = ensure_success(index, response)?;
+                if let Step::Open { alias, .. } = step {
+                    let order_id = response.order_id.ok_or_else(|| ScenarioError::Snapshot {
+                        step: index,
+                        message: "open returned no order_id".to_string(),
+                    })?;
+                    outcome.orders.insert(alias.clone(), order_id);
+                }
+                Ok(())
+            }
+        }
+    }
+
+    fn build_command(
+        &self,
+        manager: &OrdersManager,
+        index: usize,
+        step: &Step,
+        outcome: &ScenarioOutcome,
+    ) -> Result<OrderCommand, ScenarioError> {
+        let order_id = |alias: &str| {
+            outcome
+                .order_id(alias)
+                .ok_or_else(|| ScenarioError::UnknownOrder {
+                    step: index,
+                    alias: alias.to_string(),
+                })
+        };
+
+        let payload = match step {
+            Step::Open { table, guests, .. } => match table {
+                Some(name) => {
+                    let table = self.find_table(index, name)?;
+                    OrderCommandPayload::OpenTable {
+                        table_id: Some(table.id),
+                        table_name: Some(table.name.clone()),
+                        zone_id: table.zone_id,
+                        zone_name: table.zone_name.clone(),
+                        guest_count: *guests,
+                        is_retail: false,
+                    }
+                }
+                None => OrderCommandPayload::OpenTable {
+                    table_id: None,
+                    table_name: None,
+                    zone_id: None,
+                    zone_name: None,
+                    guest_count: *guests,
+                    is_retail: true,
+                },
+            },
+            Step::Add { order, items } => OrderCommandPayload::AddItems {
+                order_id: order_id(order)?,
+                items: items
+                    .iter()
+                    .map(|line| self.cart_item(index, line))
+                    .collect::<Result<_, _>>()?,
+            },
+            Step::Remove {
+                order,
+                product,
+                qty,
+            } => {
+                let id = order_id(order)?;
+                let snapshot = snapshot(manager, index, id)?;
+                OrderCommandPayload::RemoveItem {
+                    order_id: id,
+                    instance_id: self.instance_of(index, &snapshot, order, product, false)?,
+                    quantity: *qty,
+                    reason: None,
+                    authorizer_id: None,
+                    authorizer_name: None,
+                }
+            }
+            Step::Comp {
+                order,
+                product,
+                qty,
+                reason,
+            } => {
+                let id = order_id(order)?;
+                let snapshot = snapshot(manager, index, id)?;
+                OrderCommandPayload::CompItem {
+                    order_id: id,
+                    instance_id: self.instance_of(index, &snapshot, order, product, true)?,
+                    quantity: *qty,
+                    reason: reason.clone(),
+                    authorizer_id: self.operator.id,
+                    authorizer_name: self.operator.name.clone(),
+                }
+            }
+            Step::Discount {
+                order,
+                percent,
+                fixed,
+            } => OrderCommandPayload::ApplyOrderDiscount {
+                order_id: order_id(order)?,
+                discount_percent: *percent,
+                discount_fixed: *fixed,
+                authorizer_id: None,
+                authorizer_name: None,
+            },
+            Step::Pay {
+                order,
+                method,
+                amount,
+            } => {
+                let id = order_id(order)?;
+                let amount = match amount {
+                    Some(amount) => *amount,
+                    None => snapshot(manager, index, id)?.remaining_amount,
+                };
+                OrderCommandPayload::AddPayment {
+                    order_id: id,
+                    payment: PaymentInput {
+                        method: method.clone(),
+                        amount,
+                        tendered: None,
+                        note: None,
+                        reference: None,
+                    },
+                }
+            }
+            Step::Complete { order } => OrderCommandPayload::CompleteOrder {
+                order_id: order_id(order)?,
+                service_type: Some(ServiceType::DineIn),
+            },
+            Step::Void { order, note } => OrderCommandPayload::VoidOrder {
+                order_id: order_id(order)?,
+                void_type: VoidType::Cancelled,
+                loss_reason: None,
+                loss_amount: None,
+                note: note.clone(),
+                authorizer_id: None,
+                authorizer_name: None,
+            },
+            Step::Command { order, payload } => {
+                let mut payload = payload.clone();
+                if let Some(alias) = order {
+                    let id = order_id(alias)?;
+                    let object =
+                        payload
+                            .as_object_mut()
+                            .ok_or_else(|| ScenarioError::InvalidStep {
+                                step: index,
+                                message: "command payload must be an object".to_string(),
+                            })?;
+                    object.insert("order_id".to_string(), id.into());
+                }
+                serde_json::from_value(payload).map_err(|e| ScenarioError::InvalidStep {
+                    step: index,
+                    message: format!("invalid command payload: {e}"),
+                })?
+            }
+            Step::Fails { .. } | Step::Expect(_) => {
+                return Err(ScenarioError::InvalidStep {
+                    step: index,
+                    message: "`fails` must wrap a command step".to_string(),
+                });
+            }
+        };
+
+        Ok(OrderCommand::new(
+            self.operator.id,
+            self.operator.name.clone(),
+            payload,
+        ))
+    }
+
+    fn check(
+        &self,
+        manager: &OrdersManager,
+        index: usize,
+        expectation: &Expectation,
+        outcome: &ScenarioOutcome,
+    ) -> Result<(), ScenarioError> {
+        let order_id =
+            outcome
+                .order_id(&expectation.order)
+                .ok_or_else(|| ScenarioError::UnknownOrder {
+                    step: index,
+                    alias: expectation.order.clone(),
+                })?;
+        let snapshot = snapshot(manager, index, order_id)?;
+
+        if let Some(status) = expectation.status
+            && snapshot.status != status
+        {
+            return Err(mismatch(index, "status", status, snapshot.status));
+        }
+        let amounts = [
+            ("total", expectation.total, snapshot.total),
+            ("paid", expectation.paid, snapshot.paid_amount),
+            (
+                "remaining",
+                expectation.remaining,
+                snapshot.remaining_amount,
+            ),
+        ];
+        for (field, expected, actual) in amounts {
+            if let Some(expected) = expected
+                && (expected - actual).abs() > AMOUNT_EPSILON
+            {
+                return Err(mismatch(index, field, expected, actual));
+            }
+        }
+        if let Some(items) = expectation.items
+            && snapshot.items.len() != items
+        {
+            return Err(mismatch(index, "items", items, snapshot.items.len()));
+        }
+        Ok(())
+    }
+
+    fn find_product(&self, index: usize, name: &str) -> Result<&MenuProduct, ScenarioError> {
+        self.menu
+            .iter()
+            .find(|p| p.name == name)
+            .ok_or_else(|| ScenarioError::UnknownProduct {
+                step: index,
+                name: name.to_string(),
+            })
+    }
+
+    fn find_table(&self, index: usize, name: &str) -> Result<&ScenarioTable, ScenarioError> {
+        self.tables
+            .iter()
+            .find(|t| t.name == name)
+            .ok_or_else(|| ScenarioError::UnknownTable {
+                step: index,
+                name: name.to_string(),
+            })
+    }
+
+    fn cart_item(&self, index: usize, line: &ItemLine) -> Result<CartItemInput, ScenarioError> {
+        let product = self.find_product(index, &line.product)?;
+        Ok(CartItemInput {
+            product_id: product.id,
+            name: product.name.clone(),
+            price: product.price,
+            original_price: None,
+            quantity: line.qty,
+            selected_options: None,
+            selected_specification: None,
+            manual_discount_percent: line.discount_percent,
+            note: line.note.clone(),
+            authorizer_id: None,
+            authorizer_name: None,
+            course: None,
+        })
+    }
+
+    /// 订单中该商品的第一行 (`skip_comped` = 跳过已赠送行)
+    fn instance_of(
+        &self,
+        index: usize,
+        snapshot: &OrderSnapshot,
+        alias: &str,
+        product: &str,
+        skip_comped: bool,
+    ) -> Result<String, ScenarioError> {
+        let product_id = self.find_product(index, product)?.id;
+        snapshot
+            .items
+            .iter()
+            .find(|item| item.id == product_id && !(skip_comped && item.is_comped))
+            .map(|item| item.instance_id.clone())
+            .ok_or_else(|| ScenarioError::ItemNotInOrder {
+                step: index,
+                alias: alias.to_string(),
+                product: product.to_string(),
+            })
+    }
+}
+
+fn snapshot(
+    manager: &OrdersManager,
+    index: usize,
+    order_id: i64,
+) -> Result<OrderSnapshot, ScenarioError> {
+    manager
+        .get_snapshot(order_id)
+        .map_err(|e| ScenarioError::Snapshot {
+            step: index,
+            message: e.to_string(),
+        })?
+        .ok_or_else(|| ScenarioError::Snapshot {
+            step: index,
+            message: format!("order {order_id} has no snapshot"),
+        })
+}
+
+fn ensure_success(
+    index: usize,
+    response: CommandResponse,
+) -> Result<CommandResponse, ScenarioError> {
+    if response.success {
+        return Ok(response);
+    }
+    let (code, message) = match response.error {
+        Some(e) => (Some(e.code), e.message),
+        None => (None, "unknown error".to_string()),
+    };
+    Err(ScenarioError::CommandFailed {
+        step: index,
+        code,
+        message,
+    })
+}
+
+fn mismatch(
+    index: usize,
+    field: &'static str,
+    expected: impl std::fmt::Debug,
+    actual: impl std::fmt::Debug,
+) -> ScenarioError {
+    ScenarioError::Mismatch {
+        step: index,
+        field,
+        expected: format!("{expected:?}"),
+        actual: format!("{actual:?}"),
+    }
+}
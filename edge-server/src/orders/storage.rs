@@ -17,6 +17,13 @@
 //! Uses `WriteStrategy::TwoPhase` for maximum durability against power loss.
 //! This is critical for edge devices that may experience unexpected shutdowns.
 //!
+//! # Read / Write Separation
+//!
+//! redb 为单写者 + MVCC 快照读。长时间扫描 (同步补发、报表) 只用读事务，
+//! 且单个读事务最长持有 [`READ_TXN_BUDGET`]，超时后换新事务从下一个键续扫，
+//! 避免长读事务钉住旧页面、拖慢写者提交。续扫只接受首个读事务时已提交的事件，
+//! 结果与一次性快照读一致。
+//!
 //! # Snapshot Frequency
 //!
 //! Snapshots are persisted after every event by default. For high-throughput
@@ -24,19 +31,24 @@
 //! disk writes while maintaining reasonable recovery time.
 
 use redb::{
-    Database, ReadableDatabase, ReadableTable, ReadableTableMetadata, TableDefinition,
-    WriteTransaction,
+    Database, ReadTransaction, ReadableDatabase, ReadableTable, ReadableTableMetadata,
+    TableDefinition, WriteTransaction,
 };
 use shared::models::PriceRule;
 use shared::order::{OrderEvent, OrderSnapshot};
+use shared::schema;
+use std::collections::{HashMap, HashSet};
 use std::path::Path;
 use std::sync::Arc;
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::time::{Duration, Instant};
 use thiserror::Error;
 
-/// Table for storing events: key = (order_id, sequence), value = JSON-serialized OrderEvent
+/// Table for storing events: key = (order_id, sequence), value = versioned JSON OrderEvent
+/// (`shared::schema`, rows written before versioning decode as v0)
 const EVENTS_TABLE: TableDefinition<(i64, u64), &[u8]> = TableDefinition::new("events");
 
-/// Table for storing snapshots: key = order_id, value = JSON-serialized OrderSnapshot
+/// Table for storing snapshots: key = order_id, value = versioned JSON OrderSnapshot
 const SNAPSHOTS_TABLE: TableDefinition<i64, &[u8]> = TableDefinition::new("snapshots");
 
 /// Table for tracking active orders: key = order_id, value = empty (existence check)
@@ -59,12 +71,23 @@ const DEAD_LETTER_TABLE: TableDefinition<i64, &[u8]> = TableDefinition::new("dea
 /// 开台时定格的价格规则快照，订单生命周期内规则不变
 const RULE_SNAPSHOTS_TABLE: TableDefinition<i64, &[u8]> = TableDefinition::new("rule_snapshots");
 
+/// Table for the consolidated active-order checkpoint: key = "active", value = JSON SnapshotCheckpoint
+/// 定期写入的活跃订单快照合集，启动 / 缓存重建时一次读取，避免逐单解码 + 重算
+const CHECKPOINT_TABLE: TableDefinition<&str, &[u8]> = TableDefinition::new("snapshot_checkpoint");
+
+/// 单个读事务的最长持有时间 (分段扫描)
+pub const READ_TXN_BUDGET: Duration = Duration::from_millis(200);
+
+/// Inclusive key range over the events table
+type EventKeyRange = std::ops::RangeInclusive<(i64, u64)>;
+
 const SEQUENCE_KEY: &str = "seq";
 const ORDER_COUNT_KEY: &str = "order_count";
 const QUEUE_NUMBER_KEY: &str = "queue_number";
 const QUEUE_DATE_KEY: &str = "queue_date";
 const DAILY_COUNT_KEY: &str = "daily_count";
 const DAILY_DATE_KEY: &str = "daily_date";
+const CHECKPOINT_KEY: &str = "active";
 
 /// Pending archive queue entry
 #[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
@@ -85,6 +108,15 @@ pub struct DeadLetterEntry {
     pub last_error: String,
 }
 
+/// Consolidated active-order checkpoint (快照为 `shared::schema` 版本化 JSON)
+#[derive(Debug, serde::Serialize, serde::Deserialize)]
+struct SnapshotCheckpoint {
+    /// 写入时的序列计数器：之后有事件的订单不复用
+    sequence: u64,
+    created_at: i64,
+    orders: Vec<serde_json::Value>,
+}
+
 /// Storage errors
 #[derive(Debug, Error)]
 pub enum StorageError {
@@ -119,6 +151,10 @@ pub type StorageResult<T> = Result<T, StorageError>;
 #[derive(Clone)]
 pub struct OrderStorage {
     db: Arc<Database>,
+    /// 快照删除计数 (归档 / 截断后递增)，内存快照缓存据此失效
+    removals: Arc<AtomicU64>,
+    /// 分段扫描时单个读事务的最长持有时间
+    read_budget: Duration,
 }
 
 impl OrderStorage {
@@ -169,6 +205,7 @@ impl OrderStorage {
             let _ = write_txn.open_table(PENDING_ARCHIVE_TABLE)?;
             let _ = write_txn.open_table(DEAD_LETTER_TABLE)?;
             let _ = write_txn.open_table(RULE_SNAPSHOTS_TABLE)?;
+            let _ = write_txn.open_table(CHECKPOINT_TABLE)?;
 
             // Initialize sequence counter if not exists
             let mut seq_table = write_txn.open_table(SEQUENCE_TABLE)?;
@@ -178,11 +215,14 @@ impl OrderStorage {
         }
         write_txn.commit()?;
 
-        Ok(Self { db: Arc::new(db) })
+        Ok(Self {
+            db: Arc::new(db),
+            removals: Arc::new(AtomicU64::new(0)),
+            read_budget: READ_TXN_BUDGET,
+        })
     }
 
-    /// Open an in-memory database (for testing)
-    #[cfg(test)]
+    /// Open an in-memory database (tests and client-side command prediction)
     pub fn open_in_memory() -> StorageResult<Self> {
         let db = Database::builder().create_with_backend(redb::backends::InMemoryBackend::new())?;
 
@@ -196,12 +236,17 @@ impl OrderStorage {
             let _ = write_txn.open_table(PENDING_ARCHIVE_TABLE)?;
             let _ = write_txn.open_table(DEAD_LETTER_TABLE)?;
             let _ = write_txn.open_table(RULE_SNAPSHOTS_TABLE)?;
+            let _ = write_txn.open_table(CHECKPOINT_TABLE)?;
             let mut seq_table = write_txn.open_table(SEQUENCE_TABLE)?;
             seq_table.insert(SEQUENCE_KEY, 0u64)?;
         }
         write_txn.commit()?;
 
-        Ok(Self { db: Arc::new(db) })
+        Ok(Self {
+            db: Arc::new(db),
+            removals: Arc::new(AtomicU64::new(0)),
+            read_budget: READ_TXN_BUDGET,
+        })
     }
 
     /// Begin a write transaction
@@ -209,6 +254,12 @@ impl OrderStorage {
         Ok(self.db.begin_write()?)
     }
 
+    /// Override the per-transaction budget of chunked scans
+    pub fn with_read_budget(mut self, budget: Duration) -> Self {
+        self.read_budget = budget;
+        self
+    }
+
     // ========== Sequence Operations ==========
 
     /// Get the next sequence number (does NOT increment - use within transaction)
@@ -406,10 +457,17 @@ impl OrderStorage {
 
     /// Store an event
     pub fn store_event(&self, txn: &WriteTransaction, event: &OrderEvent) -> StorageResult<()> {
+        self.store_events(txn, std::slice::from_ref(event))
+    }
+
+    /// Store a batch of events (table opened once per transaction)
+    pub fn store_events(&self, txn: &WriteTransaction, events: &[OrderEvent]) -> StorageResult<()> {
         let mut table = txn.open_table(EVENTS_TABLE)?;
-        let key = (event.order_id, event.sequence);
-        let value = serde_json::to_vec(event)?;
-        table.insert(key, value.as_slice())?;
+        for event in events {
+            let key = (event.order_id, event.sequence);
+            let value = schema::to_vec(event)?;
+            table.insert(key, value.as_slice())?;
+        }
         Ok(())
     }
 
@@ -424,7 +482,7 @@ impl OrderStorage {
 
         for result in table.range(range_start..=range_end)? {
             let (_key, value) = result?;
-            let event: OrderEvent = serde_json::from_slice(value.value())?;
+            let event: OrderEvent = schema::from_slice(value.value())?;
             events.push(event);
         }
 
@@ -434,17 +492,15 @@ impl OrderStorage {
 
     /// Get events since a given sequence (across all orders)
     pub fn get_events_since(&self, since_sequence: u64) -> StorageResult<Vec<OrderEvent>> {
-        let read_txn = self.db.begin_read()?;
-        let table = read_txn.open_table(EVENTS_TABLE)?;
-
         let mut events = Vec::new();
-        for result in table.iter()? {
-            let (_key, value) = result?;
-            let event: OrderEvent = serde_json::from_slice(value.value())?;
-            if event.sequence > since_sequence {
-                events.push(event);
-            }
-        }
+        self.scan_events(
+            |_| Ok(vec![(i64::MIN, 0)..=(i64::MAX, u64::MAX)]),
+            |event| {
+                if event.sequence > since_sequence {
+                    events.push(event);
+                }
+            },
+        )?;
 
         events.sort_by_key(|e| e.sequence);
         Ok(events)
@@ -452,33 +508,88 @@ impl OrderStorage {
 
     /// Get events for active orders since a given sequence
     pub fn get_active_events_since(&self, since_sequence: u64) -> StorageResult<Vec<OrderEvent>> {
-        let read_txn = self.db.begin_read()?;
-        let events_table = read_txn.open_table(EVENTS_TABLE)?;
-        let active_table = read_txn.open_table(ACTIVE_ORDERS_TABLE)?;
-
-        // Get active order IDs
-        let mut active_order_ids: Vec<i64> = Vec::new();
-        for result in active_table.iter()? {
-            let (key, _value) = result?;
-            active_order_ids.push(key.value());
-        }
-
         let mut events = Vec::new();
-        for order_id in &active_order_ids {
-            let range_start = (*order_id, since_sequence + 1);
-            let range_end = (*order_id, u64::MAX);
-
-            for result in events_table.range(range_start..=range_end)? {
-                let (_key, value) = result?;
-                let event: OrderEvent = serde_json::from_slice(value.value())?;
-                events.push(event);
-            }
-        }
+        self.scan_events(
+            |read_txn| {
+                // Get active order IDs (same snapshot as the first scan chunk)
+                let active_table = read_txn.open_table(ACTIVE_ORDERS_TABLE)?;
+                let mut ranges = Vec::new();
+                for result in active_table.iter()? {
+                    let (key, _value) = result?;
+                    let order_id = key.value();
+                    ranges
+                        .push((order_id, since_sequence.saturating_add(1))..=(order_id, u64::MAX));
+                }
+                Ok(ranges)
+            },
+            |event| events.push(event),
+        )?;
 
         events.sort_by_key(|e| e.sequence);
         Ok(events)
     }
 
+    /// Chunked scan over the events table
+    ///
+    /// `ranges` 在首个读事务中计算。单个读事务持有超过 `read_budget` 后释放，
+    /// 在新事务中从下一个键续扫；续扫的事务只接受 sequence ≤ 首个事务中计数器的
+    /// 事件，扫描期间新提交的事件 (客户端经广播收到) 不会以缺口形式混入结果。
+    fn scan_events(
+        &self,
+        ranges: impl FnOnce(&ReadTransaction) -> StorageResult<Vec<EventKeyRange>>,
+        mut visit: impl FnMut(OrderEvent),
+    ) -> StorageResult<()> {
+        let first_txn = self.db.begin_read()?;
+        let upper_sequence = first_txn
+            .open_table(SEQUENCE_TABLE)?
+            .get(SEQUENCE_KEY)?
+            .map(|guard| guard.value())
+            .unwrap_or(0);
+        let mut ranges = ranges(&first_txn)?.into_iter();
+        let Some(mut range) = ranges.next() else {
+            return Ok(());
+        };
+
+        let mut first_txn = Some(first_txn);
+        loop {
+            let (read_txn, resumed) = match first_txn.take() {
+                Some(txn) => (txn, false),
+                None => (self.db.begin_read()?, true),
+            };
+            let opened = Instant::now();
+            let table = read_txn.open_table(EVENTS_TABLE)?;
+
+            loop {
+                let (start, end) = range.clone().into_inner();
+                let mut resume = None;
+                for result in table.range(start..=end)? {
+                    let (key, value) = result?;
+                    let event: OrderEvent = schema::from_slice(value.value())?;
+                    if !resumed || event.sequence <= upper_sequence {
+                        visit(event);
+                    }
+                    if opened.elapsed() >= self.read_budget {
+                        resume = Some(next_event_key(key.value()));
+                        break;
+                    }
+                }
+
+                let next = match resume {
+                    Some(Some(next)) if next <= end => Some(next..=end),
+                    _ => ranges.next(),
+                };
+                let Some(next) = next else {
+                    return Ok(());
+                };
+                range = next;
+                if resume.is_some() {
+                    // 读事务超时：释放后续扫
+                    break;
+                }
+            }
+        }
+    }
+
     // ========== Snapshot Operations ==========
 
     /// Store a snapshot
@@ -488,7 +599,7 @@ impl OrderStorage {
         snapshot: &OrderSnapshot,
     ) -> StorageResult<()> {
         let mut table = txn.open_table(SNAPSHOTS_TABLE)?;
-        let value = serde_json::to_vec(snapshot)?;
+        let value = schema::to_vec(snapshot)?;
         table.insert(snapshot.order_id, value.as_slice())?;
         Ok(())
     }
@@ -500,7 +611,7 @@ impl OrderStorage {
 
         match table.get(order_id)? {
             Some(value) => {
-                let snapshot: OrderSnapshot = serde_json::from_slice(value.value())?;
+                let snapshot: OrderSnapshot = schema::from_slice(value.value())?;
                 Ok(Some(snapshot))
             }
             None => Ok(None),
@@ -517,7 +628,7 @@ impl OrderStorage {
 
         match table.get(order_id)? {
             Some(value) => {
-                let snapshot: OrderSnapshot = serde_json::from_slice(value.value())?;
+                let snapshot: OrderSnapshot = schema::from_slice(value.value())?;
                 Ok(Some(snapshot))
             }
             None => Ok(None),
@@ -532,13 +643,18 @@ impl OrderStorage {
         let mut snapshots = Vec::new();
         for result in table.iter()? {
             let (_key, value) = result?;
-            let snapshot: OrderSnapshot = serde_json::from_slice(value.value())?;
+            let snapshot: OrderSnapshot = schema::from_slice(value.value())?;
             snapshots.push(snapshot);
         }
 
         Ok(snapshots)
     }
 
+    /// 快照删除计数 (提交后递增)：读取前取值，删除发生后缓存条目即失效
+    pub fn removal_generation(&self) -> u64 {
+        self.removals.load(Ordering::Acquire)
+    }
+
     /// Remove a snapshot
     pub fn remove_snapshot(&self, txn: &WriteTransaction, order_id: i64) -> StorageResult<()> {
         let mut table = txn.open_table(SNAPSHOTS_TABLE)?;
@@ -597,6 +713,80 @@ impl OrderStorage {
         Ok(snapshots)
     }
 
+    // ========== Checkpoint ==========
+
+    /// 写入活跃订单快照合集 (`sequence` 须为快照对应的序列计数器)
+    pub fn write_checkpoint(&self, sequence: u64, orders: &[OrderSnapshot]) -> StorageResult<()> {
+        let checkpoint = SnapshotCheckpoint {
+            sequence,
+            created_at: shared::util::now_millis(),
+            orders: orders
+                .iter()
+                .map(schema::to_value)
+                .collect::<Result<_, _>>()?,
+        };
+        let value = serde_json::to_vec(&checkpoint)?;
+        let txn = self.begin_write()?;
+        {
+            let mut table = txn.open_table(CHECKPOINT_TABLE)?;
+            table.insert(CHECKPOINT_KEY, value.as_slice())?;
+        }
+        txn.commit()?;
+        Ok(())
+    }
+
+    /// 丢弃检查点 (绕过事件直接写快照时调用：种子数据 / 日志恢复)
+    pub fn clear_checkpoint(&self, txn: &WriteTransaction) -> StorageResult<()> {
+        let mut table = txn.open_table(CHECKPOINT_TABLE)?;
+        table.remove(CHECKPOINT_KEY)?;
+        Ok(())
+    }
+
+    /// 读取全部活跃订单，优先复用检查点
+    ///
+    /// 同一读事务内：仍活跃且检查点之后没有新事件的订单直接取检查点内容，
+    /// 其余 (新开台 / 有新事件 / 检查点缺失或无法解码) 逐单读快照表。
+    /// 返回 (按 order_id 升序的快照, 复用检查点的订单数)。
+    pub fn get_active_orders_checkpointed(&self) -> StorageResult<(Vec<OrderSnapshot>, usize)> {
+        let read_txn = self.db.begin_read()?;
+        let active_table = read_txn.open_table(ACTIVE_ORDERS_TABLE)?;
+        let snapshots_table = read_txn.open_table(SNAPSHOTS_TABLE)?;
+        let events_table = read_txn.open_table(EVENTS_TABLE)?;
+        let checkpoint_table = read_txn.open_table(CHECKPOINT_TABLE)?;
+
+        let (cp_sequence, mut cached) = match checkpoint_table.get(CHECKPOINT_KEY)? {
+            Some(value) => match decode_checkpoint(value.value()) {
+                Ok(cp) => cp,
+                Err(e) => {
+                    tracing::warn!(error = %e, "Ignoring undecodable snapshot checkpoint");
+                    (0, HashMap::new())
+                }
+            },
+            None => (0, HashMap::new()),
+        };
+
+        let mut snapshots = Vec::new();
+        let mut reused = 0;
+        for result in active_table.iter()? {
+            let (key, _) = result?;
+            let order_id = key.value();
+            if let Some(snapshot) = cached.remove(&order_id) {
+                let mut newer = events_table
+                    .range((order_id, cp_sequence.saturating_add(1))..=(order_id, u64::MAX))?;
+                if newer.next().is_none() {
+                    snapshots.push(snapshot);
+                    reused += 1;
+                    continue;
+                }
+            }
+            if let Some(value) = snapshots_table.get(order_id)? {
+                snapshots.push(schema::from_slice(value.value())?);
+            }
+        }
+
+        Ok((snapshots, reused))
+    }
+
     /// Find active order for a specific table (within transaction)
     ///
     /// Returns the order_id if the table is occupied by an active order.
@@ -613,7 +803,7 @@ impl OrderStorage {
             let order_id = key.value();
 
             if let Some(value) = snapshots_table.get(order_id)? {
-                let snapshot: OrderSnapshot = serde_json::from_slice(value.value())?;
+                let snapshot: OrderSnapshot = schema::from_slice(value.value())?;
                 if snapshot.table_id == Some(table_id) {
                     return Ok(Some(order_id));
                 }
@@ -636,7 +826,7 @@ impl OrderStorage {
             let order_id = key.value();
 
             if let Some(value) = snapshots_table.get(order_id)? {
-                let snapshot: OrderSnapshot = serde_json::from_slice(value.value())?;
+                let snapshot: OrderSnapshot = schema::from_slice(value.value())?;
                 if snapshot.table_id == Some(table_id) {
                     return Ok(Some(order_id));
                 }
@@ -722,7 +912,7 @@ impl OrderStorage {
         // We need to iterate and collect separately to avoid borrow issues
         for result in table.range(range_start..=range_end)? {
             let (key, value) = result?;
-            let event: OrderEvent = serde_json::from_slice(value.value())?;
+            let event: OrderEvent = schema::from_slice(value.value())?;
             events.push(event);
             let key_value = key.value();
             keys_to_remove.push((key_value.0, key_value.1));
@@ -822,6 +1012,7 @@ impl OrderStorage {
         }
 
         txn.commit()?;
+        self.removals.fetch_add(1, Ordering::Release);
         Ok(())
     }
 
@@ -961,6 +1152,204 @@ impl OrderStorage {
         Ok(count)
     }
 
+    // ========== Compaction ==========
+
+    /// 事件日志压缩候选：非活跃、不在归档队列中、最后活动早于 `cutoff` 的终态订单
+    ///
+    /// 正常归档后订单即被清理；候选主要是死信订单与崩溃遗留的终态订单。
+    /// 归档队列中的订单仍由 ArchiveWorker 负责，不参与压缩。
+    pub fn compaction_candidates(&self, cutoff: i64) -> StorageResult<Vec<i64>> {
+        let pending: HashSet<i64> = self
+            .get_pending_archives()?
+            .into_iter()
+            .map(|p| p.order_id)
+            .collect();
+
+        let read_txn = self.db.begin_read()?;
+        let active_table = read_txn.open_table(ACTIVE_ORDERS_TABLE)?;
+        let snapshots_table = read_txn.open_table(SNAPSHOTS_TABLE)?;
+
+        let mut candidates = Vec::new();
+        for result in snapshots_table.iter()? {
+            let (key, value) = result?;
+            let order_id = key.value();
+            if pending.contains(&order_id) || active_table.get(order_id)?.is_some() {
+                continue;
+            }
+            let Ok(snapshot) = schema::from_slice::<OrderSnapshot>(value.value()) else {
+                continue;
+            };
+            if !snapshot.is_active() && snapshot.end_time.unwrap_or(snapshot.updated_at) < cutoff {
+                candidates.push(order_id);
+            }
+        }
+        Ok(candidates)
+    }
+
+    /// 截断已归档订单：事件、快照、规则快照、归档 / 死信队列条目及其已处理命令 ID
+    ///
+    /// 单事务完成，返回删除的事件数。
+    pub fn truncate_order(&self, order_id: i64) -> StorageResult<usize> {
+        let txn = self.begin_write()?;
+        let events = self.remove_events_for_order(&txn, order_id)?;
+        let command_ids: Vec<i64> = events.iter().map(|e| e.command_id).collect();
+        self.cleanup_command_ids(&txn, &command_ids)?;
+        self.remove_snapshot(&txn, order_id)?;
+        self.mark_order_inactive(&txn, order_id)?;
+        {
+            let mut table = txn.open_table(RULE_SNAPSHOTS_TABLE)?;
+            table.remove(order_id)?;
+        }
+        {
+            let mut table = txn.open_table(PENDING_ARCHIVE_TABLE)?;
+            table.remove(order_id)?;
+        }
+        {
+            let mut table = txn.open_table(DEAD_LETTER_TABLE)?;
+            table.remove(order_id)?;
+        }
+        txn.commit()?;
+        self.removals.fetch_add(1, Ordering::Release);
+        Ok(events.len())
+    }
+
+    /// 截断后重建序列索引，返回 (序列计数器, 剩余事件最大序列)
+    ///
+    /// 计数器只前移不回退：客户端按序列增量同步，回退会导致新事件被当作已同步而跳过。
+    pub fn rebuild_sequence_index(&self) -> StorageResult<(u64, u64)> {
+        let txn = self.begin_write()?;
+        let max_event_seq = {
+            let table = txn.open_table(EVENTS_TABLE)?;
+            let mut max_seq = 0u64;
+            for result in table.iter()? {
+                let (key, _) = result?;
+                max_seq = max_seq.max(key.value().1);
+            }
+            max_seq
+        };
+        let current = txn
+            .open_table(SEQUENCE_TABLE)?
+            .get(SEQUENCE_KEY)?
+            .map(|guard| guard.value())
+            .unwrap_or(0);
+        let sequence = current.max(max_event_seq);
+        if sequence != current {
+            self.set_sequence(&txn, sequence)?;
+        }
+        txn.commit()?;
+        Ok((sequence, max_event_seq))
+    }
+
+    // ========== Recovery ==========
+
+    /// 异常关闭后的一致性检查 (`repair = true` 时修复可安全修复的问题)
+    ///
+    /// - 活跃订单缺少快照 → 移出活跃表 (无法恢复，仅留事件)
+    /// - 非活跃的终态订单不在归档 / 死信队列 → 重新入队归档
+    /// - 序列计数器落后于最大事件序列 → 前移
+    /// - 快照无法解码、校验和不符、事件超前于快照 (命令已落事件但快照未更新) → 仅报告
+    pub fn check_consistency(&self, repair: bool) -> StorageResult<ConsistencyReport> {
+        let mut report = ConsistencyReport::default();
+        let active_ids = self.get_active_order_ids()?;
+        report.active_orders = active_ids.len();
+        let pending: HashSet<i64> = self
+            .get_pending_archives()?
+            .into_iter()
+            .map(|p| p.order_id)
+            .collect();
+        report.pending_archives = pending.len();
+        let dead: HashSet<i64> = self
+            .get_dead_letters()?
+            .into_iter()
+            .map(|d| d.order_id)
+            .collect();
+        report.dead_letters = dead.len();
+
+        let read_txn = self.db.begin_read()?;
+        let snapshots_table = read_txn.open_table(SNAPSHOTS_TABLE)?;
+        let events_table = read_txn.open_table(EVENTS_TABLE)?;
+
+        // 每个订单的最大事件序列 + 全局最大序列
+        let mut last_event: HashMap<i64, u64> = HashMap::new();
+        let mut max_sequence = 0u64;
+        for result in events_table.iter()? {
+            let (key, _) = result?;
+            let (order_id, sequence) = key.value();
+            let last = last_event.entry(order_id).or_default();
+            *last = (*last).max(sequence);
+            max_sequence = max_sequence.max(sequence);
+        }
+
+        for &order_id in &active_ids {
+            let Some(value) = snapshots_table.get(order_id)? else {
+                report.missing_snapshots.push(order_id);
+                continue;
+            };
+            let snapshot: OrderSnapshot = match schema::from_slice(value.value()) {
+                Ok(s) => s,
+                Err(_) => {
+                    report.undecodable_snapshots.push(order_id);
+                    continue;
+                }
+            };
+            if !snapshot.state_checksum.is_empty() && !snapshot.verify_checksum() {
+                report.checksum_mismatches.push(order_id);
+            }
+            if last_event
+                .get(&order_id)
+                .is_some_and(|&seq| seq > snapshot.last_sequence)
+            {
+                report.in_flight.push(order_id);
+            }
+        }
+
+        let active: HashSet<i64> = active_ids.iter().copied().collect();
+        for result in snapshots_table.iter()? {
+            let (key, value) = result?;
+            let order_id = key.value();
+            if active.contains(&order_id) || pending.contains(&order_id) || dead.contains(&order_id)
+            {
+                continue;
+            }
+            match schema::from_slice::<OrderSnapshot>(value.value()) {
+                Ok(snapshot) if snapshot.status != shared::order::OrderStatus::Active => {
+                    report.unarchived.push(order_id);
+                }
+                Ok(_) => {}
+                Err(_) => report.undecodable_snapshots.push(order_id),
+            }
+        }
+
+        let current_sequence = self.get_current_sequence()?;
+        if max_sequence > current_sequence {
+            report.sequence_behind = Some((current_sequence, max_sequence));
+        }
+        drop(snapshots_table);
+        drop(events_table);
+        drop(read_txn);
+
+        if repair
+            && (!report.missing_snapshots.is_empty()
+                || !report.unarchived.is_empty()
+                || report.sequence_behind.is_some())
+        {
+            let txn = self.begin_write()?;
+            for &order_id in &report.missing_snapshots {
+                self.mark_order_inactive(&txn, order_id)?;
+            }
+            for &order_id in &report.unarchived {
+                self.queue_for_archive(&txn, order_id)?;
+            }
+            if let Some((_, max)) = report.sequence_behind {
+                self.set_sequence(&txn, max)?;
+            }
+            txn.commit()?;
+            report.repaired = true;
+        }
+
+        Ok(report)
+    }
+
     // ========== Statistics ==========
 
     /// Get storage statistics
@@ -986,6 +1375,48 @@ impl OrderStorage {
     }
 }
 
+/// 解码检查点 → (序列, order_id → 快照)
+fn decode_checkpoint(bytes: &[u8]) -> StorageResult<(u64, HashMap<i64, OrderSnapshot>)> {
+    let checkpoint: SnapshotCheckpoint = serde_json::from_slice(bytes)?;
+    let orders = checkpoint
+        .orders
+        .into_iter()
+        .map(|value| schema::from_value::<OrderSnapshot>(value).map(|s| (s.order_id, s)))
+        .collect::<Result<_, _>>()?;
+    Ok((checkpoint.sequence, orders))
+}
+
+/// 一致性检查结果 ([`OrderStorage::check_consistency`])
+#[derive(Debug, Clone, Default, serde::Serialize, serde::Deserialize)]
+pub struct ConsistencyReport {
+    pub active_orders: usize,
+    pub pending_archives: usize,
+    pub dead_letters: usize,
+    /// 活跃但缺少快照 (修复：移出活跃表)
+    pub missing_snapshots: Vec<i64>,
+    /// 快照无法解码
+    pub undecodable_snapshots: Vec<i64>,
+    /// 快照校验和不符
+    pub checksum_mismatches: Vec<i64>,
+    /// 事件序列超前于快照 (中断的命令)
+    pub in_flight: Vec<i64>,
+    /// 终态但未进入归档队列 (修复：重新入队)
+    pub unarchived: Vec<i64>,
+    /// 序列计数器落后 (current, max_event)，修复：前移
+    pub sequence_behind: Option<(u64, u64)>,
+    /// 是否已执行修复
+    pub repaired: bool,
+}
+
+impl ConsistencyReport {
+    /// 无需人工处理的问题
+    pub fn is_clean(&self) -> bool {
+        self.undecodable_snapshots.is_empty()
+            && self.checksum_mismatches.is_empty()
+            && self.in_flight.is_empty()
+    }
+}
+
 /// Storage statistics
 #[derive(Debug, Clone)]
 pub struct StorageStats {
@@ -996,6 +1427,14 @@ pub struct StorageStats {
     pub current_sequence: u64,
 }
 
+/// Key following `(order_id, sequence)` in the events table
+fn next_event_key((order_id, sequence): (i64, u64)) -> Option<(i64, u64)> {
+    match sequence.checked_add(1) {
+        Some(sequence) => Some((order_id, sequence)),
+        None => order_id.checked_add(1).map(|order_id| (order_id, 0)),
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -1021,6 +1460,7 @@ mod tests {
                 is_retail: false,
                 queue_number: None,
                 receipt_number: "RCP-TEST".to_string(),
+                tax_mode: shared::models::TaxMode::Inclusive,
             },
         }
     }
@@ -1045,10 +1485,14 @@ mod tests {
             total_discount: 0.0,
             total_surcharge: 0.0,
             tax: 0.0,
+            tax_mode: shared::models::TaxMode::Inclusive,
+            tax_breakdown: vec![],
             discount: 0.0,
             comp_total_amount: 0.0,
             order_manual_discount_amount: 0.0,
             order_manual_surcharge_amount: 0.0,
+            payment_surcharges: vec![],
+            payment_surcharge_amount: 0.0,
             total: 0.0,
             paid_amount: 0.0,
             remaining_amount: 0.0,
@@ -1056,6 +1500,9 @@ mod tests {
             receipt_number: String::new(),
             is_pre_payment: false,
             note: None,
+            metadata: std::collections::BTreeMap::new(),
+            carry_over_date: None,
+            fiscal_code: None,
             order_rule_discount_amount: 0.0,
             order_rule_surcharge_amount: 0.0,
             order_applied_rules: vec![],
@@ -1069,6 +1516,8 @@ mod tests {
             marketing_group_name: None,
             mg_discount_amount: 0.0,
             stamp_redemptions: Vec::new(),
+            points_redemption: None,
+            coupon: None,
             start_time: shared::util::now_millis(),
             end_time: None,
             created_at: shared::util::now_millis(),
@@ -1148,6 +1597,20 @@ mod tests {
         assert_eq!(events[1].sequence, 2);
     }
 
+    #[test]
+    fn test_store_events_batch() {
+        let storage = OrderStorage::open_in_memory().unwrap();
+        let events: Vec<OrderEvent> = (1..=50).map(|seq| create_test_event(1001, seq)).collect();
+
+        let txn = storage.begin_write().unwrap();
+        storage.store_events(&txn, &events).unwrap();
+        txn.commit().unwrap();
+
+        let stored = storage.get_events_for_order(1001).unwrap();
+        assert_eq!(stored.len(), 50);
+        assert_eq!(stored.last().unwrap().sequence, 50);
+    }
+
     #[test]
     fn test_snapshot_storage() {
         let storage = OrderStorage::open_in_memory().unwrap();
@@ -1211,6 +1674,114 @@ mod tests {
         assert!(events.iter().all(|e| e.sequence > 1));
     }
 
+    fn store_with_counter(storage: &OrderStorage, events: &[OrderEvent]) {
+        let txn = storage.begin_write().unwrap();
+        for event in events {
+            storage.store_event(&txn, event).unwrap();
+            storage.mark_order_active(&txn, event.order_id).unwrap();
+        }
+        let max = events.iter().map(|e| e.sequence).max().unwrap_or(0);
+        let current = storage.get_next_sequence(&txn).unwrap() - 1;
+        storage.set_sequence(&txn, max.max(current)).unwrap();
+        txn.commit().unwrap();
+    }
+
+    #[test]
+    fn test_chunked_scan_matches_single_pass() {
+        let storage = OrderStorage::open_in_memory().unwrap();
+        let events: Vec<_> = (1..=20)
+            .map(|seq| create_test_event(1000 + (seq as i64 % 4), seq))
+            .collect();
+        store_with_counter(&storage, &events);
+
+        let chunked = storage.clone().with_read_budget(Duration::ZERO);
+        let sequences = |events: Vec<OrderEvent>| -> Vec<u64> {
+            events.into_iter().map(|e| e.sequence).collect()
+        };
+        assert_eq!(
+            sequences(chunked.get_events_since(5).unwrap()),
+            sequences(storage.get_events_since(5).unwrap())
+        );
+        assert_eq!(
+            sequences(chunked.get_events_since(5).unwrap()),
+            (6..=20).collect::<Vec<_>>()
+        );
+        assert_eq!(
+            sequences(chunked.get_active_events_since(0).unwrap()),
+            (1..=20).collect::<Vec<_>>()
+        );
+    }
+
+    #[test]
+    fn test_resumed_scan_skips_events_committed_mid_scan() {
+        let storage = OrderStorage::open_in_memory().unwrap();
+        store_with_counter(
+            &storage,
+            &[create_test_event(2000, 1), create_test_event(2001, 2)],
+        );
+        let chunked = storage.clone().with_read_budget(Duration::ZERO);
+
+        // 扫描过程中写者提交：一条落在已扫过的键区间，一条落在未扫的键区间
+        let mut seen = Vec::new();
+        let mut committed = false;
+        chunked
+            .scan_events(
+                |_| Ok(vec![(i64::MIN, 0)..=(i64::MAX, u64::MAX)]),
+                |event| {
+                    seen.push(event.sequence);
+                    if !committed {
+                        committed = true;
+                        store_with_counter(
+                            &storage,
+                            &[create_test_event(1000, 3), create_test_event(3000, 4)],
+                        );
+                    }
+                },
+            )
+            .unwrap();
+
+        // 只返回扫描开始时已提交的事件，不出现 "有 4 缺 3" 的缺口
+        assert_eq!(seen, vec![1, 2]);
+        assert_eq!(storage.get_events_since(0).unwrap().len(), 4);
+    }
+
+    #[test]
+    fn test_heavy_reader_does_not_block_writer() {
+        let storage = OrderStorage::open_in_memory().unwrap();
+        let events: Vec<_> = (1..=200)
+            .map(|seq| create_test_event(seq as i64, seq))
+            .collect();
+        store_with_counter(&storage, &events);
+
+        // 报表式长扫描：持续占用读事务
+        let stop = Arc::new(std::sync::atomic::AtomicBool::new(false));
+        let reader = {
+            let storage = storage.clone();
+            let stop = stop.clone();
+            std::thread::spawn(move || {
+                let mut scans = 0u32;
+                while !stop.load(Ordering::Relaxed) {
+                    assert!(storage.get_events_since(0).unwrap().len() >= 200);
+                    scans += 1;
+                }
+                scans
+            })
+        };
+        let _long_reader = storage.db.begin_read().unwrap();
+
+        for seq in 201..=250 {
+            let started = Instant::now();
+            store_with_counter(&storage, &[create_test_event(seq as i64, seq)]);
+            assert!(
+                started.elapsed() < Duration::from_secs(1),
+                "writer stalled behind readers"
+            );
+        }
+        stop.store(true, Ordering::Relaxed);
+        assert!(reader.join().unwrap() > 0);
+        assert_eq!(storage.get_events_since(0).unwrap().len(), 250);
+    }
+
     #[test]
     fn test_pending_archive_queue() {
         let storage = OrderStorage::open_in_memory().unwrap();
@@ -1337,6 +1908,7 @@ mod tests {
             adjustment_value: 10.0,
             is_stackable: false,
             is_exclusive: false,
+            priority: 0,
             valid_from: None,
             valid_until: None,
             active_days: None,
@@ -1475,4 +2047,170 @@ mod tests {
         // 规则快照也应被清理
         assert!(storage.get_rule_snapshot(order_id).unwrap().is_none());
     }
+
+    #[test]
+    fn test_check_consistency_repairs_after_crash() {
+        let storage = OrderStorage::open_in_memory().unwrap();
+
+        // 9001: 活跃且一致；9002: 活跃但快照丢失；9003: 已完成但未入归档队列
+        let healthy = create_test_snapshot(9001);
+        let mut completed = create_test_snapshot(9003);
+        completed.status = OrderStatus::Completed;
+        completed.update_checksum();
+
+        let txn = storage.begin_write().unwrap();
+        storage.store_snapshot(&txn, &healthy).unwrap();
+        storage.mark_order_active(&txn, 9001).unwrap();
+        storage.mark_order_active(&txn, 9002).unwrap();
+        storage.store_snapshot(&txn, &completed).unwrap();
+        // 事件序列 (7) 超前于快照 last_sequence (0) 且计数器未更新
+        storage
+            .store_event(&txn, &create_test_event(9001, 7))
+            .unwrap();
+        txn.commit().unwrap();
+
+        let report = storage.check_consistency(false).unwrap();
+        assert_eq!(report.active_orders, 2);
+        assert_eq!(report.missing_snapshots, vec![9002]);
+        assert_eq!(report.unarchived, vec![9003]);
+        assert_eq!(report.in_flight, vec![9001]);
+        assert_eq!(report.sequence_behind, Some((0, 7)));
+        assert!(!report.repaired);
+        assert!(!report.is_clean());
+
+        let report = storage.check_consistency(true).unwrap();
+        assert!(report.repaired);
+        assert_eq!(storage.get_active_order_ids().unwrap(), vec![9001]);
+        assert_eq!(storage.get_pending_archives().unwrap()[0].order_id, 9003);
+        assert_eq!(storage.get_current_sequence().unwrap(), 7);
+
+        let report = storage.check_consistency(true).unwrap();
+        assert!(report.missing_snapshots.is_empty() && report.unarchived.is_empty());
+        assert_eq!(report.pending_archives, 1);
+        assert!(!report.repaired);
+    }
+
+    #[test]
+    fn test_compaction_truncates_old_terminal_orders() {
+        let storage = OrderStorage::open_in_memory().unwrap();
+
+        // 9101: 旧死信订单；9102: 新近完成；9103: 旧但在归档队列；9104: 活跃
+        let mut dead = create_test_snapshot(9101);
+        dead.status = OrderStatus::Completed;
+        dead.end_time = Some(1_000);
+        let mut recent = create_test_snapshot(9102);
+        recent.status = OrderStatus::Void;
+        recent.end_time = Some(10_000);
+        let mut queued = create_test_snapshot(9103);
+        queued.status = OrderStatus::Completed;
+        queued.end_time = Some(1_000);
+        let active = create_test_snapshot(9104);
+
+        let dead_events = [create_test_event(9101, 1), create_test_event(9101, 2)];
+        let txn = storage.begin_write().unwrap();
+        for snapshot in [&dead, &recent, &queued, &active] {
+            storage.store_snapshot(&txn, snapshot).unwrap();
+        }
+        storage.store_events(&txn, &dead_events).unwrap();
+        storage
+            .store_event(&txn, &create_test_event(9102, 3))
+            .unwrap();
+        for event in &dead_events {
+            storage
+                .mark_command_processed(&txn, event.command_id)
+                .unwrap();
+        }
+        storage.queue_for_archive(&txn, 9101).unwrap();
+        storage.queue_for_archive(&txn, 9103).unwrap();
+        storage.mark_order_active(&txn, 9104).unwrap();
+        storage.set_sequence(&txn, 3).unwrap();
+        txn.commit().unwrap();
+        storage.move_to_dead_letter(9101, "boom").unwrap();
+        storage.store_rule_snapshot(9101, &[]).unwrap();
+
+        assert_eq!(storage.compaction_candidates(5_000).unwrap(), vec![9101]);
+
+        assert_eq!(storage.truncate_order(9101).unwrap(), 2);
+        assert!(storage.get_snapshot(9101).unwrap().is_none());
+        assert!(storage.get_events_for_order(9101).unwrap().is_empty());
+        assert!(storage.get_rule_snapshot(9101).unwrap().is_none());
+        assert!(storage.get_dead_letters().unwrap().is_empty());
+        assert!(
+            !storage
+                .is_command_processed(dead_events[0].command_id)
+                .unwrap()
+        );
+        assert!(storage.compaction_candidates(5_000).unwrap().is_empty());
+
+        // 计数器不回退
+        assert_eq!(storage.rebuild_sequence_index().unwrap(), (3, 3));
+        storage.truncate_order(9102).unwrap();
+        assert_eq!(storage.rebuild_sequence_index().unwrap(), (3, 0));
+        assert_eq!(storage.get_current_sequence().unwrap(), 3);
+    }
+
+    #[test]
+    fn test_checkpoint_reused_only_for_unchanged_orders() {
+        let storage = OrderStorage::open_in_memory().unwrap();
+        let txn = storage.begin_write().unwrap();
+        for order_id in [1, 2] {
+            storage
+                .store_event(&txn, &create_test_event(order_id, order_id as u64))
+                .unwrap();
+            storage
+                .store_snapshot(&txn, &create_test_snapshot(order_id))
+                .unwrap();
+            storage.mark_order_active(&txn, order_id).unwrap();
+        }
+        txn.commit().unwrap();
+
+        // 检查点内容带标记，便于区分来源
+        let checkpointed: Vec<OrderSnapshot> = [1, 2]
+            .into_iter()
+            .map(|id| {
+                let mut s = create_test_snapshot(id);
+                s.table_name = Some("checkpoint".to_string());
+                s
+            })
+            .collect();
+        storage.write_checkpoint(2, &checkpointed).unwrap();
+
+        // 订单 2 有新事件，订单 3 为检查点之后开台
+        let txn = storage.begin_write().unwrap();
+        storage.store_event(&txn, &create_test_event(2, 3)).unwrap();
+        storage
+            .store_snapshot(&txn, &create_test_snapshot(3))
+            .unwrap();
+        storage.mark_order_active(&txn, 3).unwrap();
+        txn.commit().unwrap();
+
+        let (orders, reused) = storage.get_active_orders_checkpointed().unwrap();
+        assert_eq!(reused, 1);
+        let names: Vec<(i64, Option<&str>)> = orders
+            .iter()
+            .map(|o| (o.order_id, o.table_name.as_deref()))
+            .collect();
+        assert_eq!(
+            names,
+            vec![
+                (1, Some("checkpoint")),
+                (2, Some("Table 1")),
+                (3, Some("Table 1"))
+            ]
+        );
+
+        // 已不活跃的订单不从检查点带出
+        let txn = storage.begin_write().unwrap();
+        storage.mark_order_inactive(&txn, 1).unwrap();
+        txn.commit().unwrap();
+        let (orders, reused) = storage.get_active_orders_checkpointed().unwrap();
+        assert_eq!(reused, 0);
+        assert_eq!(orders.len(), 2);
+
+        let txn = storage.begin_write().unwrap();
+        storage.clear_checkpoint(&txn).unwrap();
+        txn.commit().unwrap();
+        let (orders, reused) = storage.get_active_orders_checkpointed().unwrap();
+        assert_eq!((orders.len(), reused), (2, 0));
+    }
 }
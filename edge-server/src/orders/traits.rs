@@ -3,50 +3,15 @@
 //! This module defines the traits that enable the Strategy Pattern for order command processing:
 //! - `CommandHandler`: Executes commands and generates events
 //! - `EventApplier`: Applies events to snapshots (pure function, no side effects)
+//!
+//! `EventApplier` 与 `OrderError` 定义在 `crab-order-core`，客户端共用。
 
 use crate::orders::storage::OrderStorage;
 use redb::WriteTransaction;
-use shared::order::types::CommandErrorCode;
 use shared::order::{OrderEvent, OrderSnapshot};
 use std::collections::HashMap;
-use thiserror::Error;
-
-/// Errors that can occur during order operations
-#[derive(Debug, Error)]
-pub enum OrderError {
-    #[error("Order not found: {0}")]
-    OrderNotFound(i64),
-
-    #[error("Order already completed: {0}")]
-    OrderAlreadyCompleted(i64),
-
-    #[error("Order already voided: {0}")]
-    OrderAlreadyVoided(i64),
-
-    #[error("Item not found: {0}")]
-    ItemNotFound(String),
-
-    #[error("Payment not found: {0}")]
-    PaymentNotFound(i64),
-
-    #[error("Insufficient quantity")]
-    InsufficientQuantity,
-
-    #[error("Invalid amount")]
-    InvalidAmount,
 
-    #[error("Invalid operation [{0:?}]: {1}")]
-    InvalidOperation(CommandErrorCode, String),
-
-    #[error("Table is already occupied: {0}")]
-    TableOccupied(String),
-
-    #[error("Insufficient stamps: {current}/{required}")]
-    InsufficientStamps { current: i32, required: i32 },
-
-    #[error("Storage error: {0}")]
-    Storage(String),
-}
+pub use crab_order_core::{EventApplier, OrderError};
 
 /// Command metadata extracted from OrderCommand
 #[derive(Debug, Clone)]
@@ -165,19 +130,3 @@ pub trait CommandHandler: Send + Sync {
         metadata: &CommandMetadata,
     ) -> Result<Vec<OrderEvent>, OrderError>;
 }
-
-/// Event applier trait
-///
-/// Implementations apply event data to snapshots.
-/// This is a PURE function - no business logic, no side effects, no I/O.
-/// Used for both command execution and event replay.
-pub trait EventApplier: Send + Sync {
-    /// Apply the event to the snapshot
-    ///
-    /// # Guarantees
-    /// - Pure function: same input always produces same output
-    /// - No I/O operations
-    /// - No ID generation (IDs come from the event)
-    /// - No business logic validation
-    fn apply(&self, snapshot: &mut OrderSnapshot, event: &OrderEvent);
-}
@@ -0,0 +1,150 @@
+//! 通用 HTTP/JSON PMS 客户端
+//!
+//! | 操作 | 请求 |
+//! |------|------|
+//! | 核验住客 | `GET {base}/rooms/{room}/guest` → [`PmsGuest`] (404 = 无住客) |
+//! | 挂房账 | `POST {base}/charges` ([`RoomCharge`]) → `{ "reference": "..." }` |
+//! | 冲正 | `POST {base}/charges/{reference}/reverse` |
+//!
+//! 配置了 API key 时以 `Authorization: Bearer <key>` 发送。
+
+use std::time::Duration;
+
+use async_trait::async_trait;
+use reqwest::{Client, RequestBuilder, StatusCode, Url};
+
+use super::{PmsClient, PmsConfirmation, PmsError, PmsGuest, RoomCharge};
+
+/// PMS 请求超时 (收银员在等待)
+const REQUEST_TIMEOUT: Duration = Duration::from_secs(10);
+
+pub struct HttpPmsClient {
+    client: Client,
+    base_url: String,
+    api_key: Option<String>,
+}
+
+impl HttpPmsClient {
+    pub fn new(base_url: impl Into<String>, api_key: Option<String>) -> Result<Self, PmsError> {
+        let client = Client::builder()
+            .timeout(REQUEST_TIMEOUT)
+            .build()
+            .map_err(|e| PmsError::Http(e.to_string()))?;
+        let base_url = base_url.into();
+        Url::parse(&base_url).map_err(|e| PmsError::Http(format!("Invalid PMS URL: {e}")))?;
+        Ok(Self {
+            client,
+            base_url,
+            api_key: api_key.filter(|k| !k.is_empty()),
+        })
+    }
+
+    /// 拼接路径段 (逐段百分号编码，房间号可含空格等字符)
+    fn url(&self, segments: &[&str]) -> Result<Url, PmsError> {
+        let mut url = Url::parse(&self.base_url)
+            .map_err(|e| PmsError::Http(format!("Invalid PMS URL: {e}")))?;
+        url.path_segments_mut()
+            .map_err(|_| PmsError::Http("Invalid PMS URL".into()))?
+            .pop_if_empty()
+            .extend(segments);
+        Ok(url)
+    }
+
+    fn authorize(&self, request: RequestBuilder) -> RequestBuilder {
+        match &self.api_key {
+            Some(key) => request.bearer_auth(key),
+            None => request,
+        }
+    }
+}
+
+/// 非 2xx 响应 → 错误 (客户端错误视为 PMS 拒绝)
+async fn error_for(response: reqwest::Response) -> PmsError {
+    let status = response.status();
+    let body = response.text().await.unwrap_or_default();
+    let detail = if body.is_empty() {
+        status.to_string()
+    } else {
+        format!("{status}: {body}")
+    };
+    if status.is_client_error() {
+        PmsError::Rejected(detail)
+    } else {
+        PmsError::Http(detail)
+    }
+}
+
+#[async_trait]
+impl PmsClient for HttpPmsClient {
+    async fn verify_guest(&self, room_number: &str) -> Result<PmsGuest, PmsError> {
+        let url = self.url(&["rooms", room_number, "guest"])?;
+        let response = self
+            .authorize(self.client.get(url))
+            .send()
+            .await
+            .map_err(|e| PmsError::Http(e.to_string()))?;
+        if response.status() == StatusCode::NOT_FOUND {
+            return Err(PmsError::GuestNotFound(room_number.to_string()));
+        }
+        if !response.status().is_success() {
+            return Err(error_for(response).await);
+        }
+        response
+            .json::<PmsGuest>()
+            .await
+            .map_err(|e| PmsError::Http(format!("Invalid guest response: {e}")))
+    }
+
+    async fn post_charge(&self, charge: &RoomCharge) -> Result<PmsConfirmation, PmsError> {
+        let url = self.url(&["charges"])?;
+        let response = self
+            .authorize(self.client.post(url))
+            .json(charge)
+            .send()
+            .await
+            .map_err(|e| PmsError::Http(e.to_string()))?;
+        if !response.status().is_success() {
+            return Err(error_for(response).await);
+        }
+        let confirmation = response
+            .json::<PmsConfirmation>()
+            .await
+            .map_err(|e| PmsError::Http(format!("Invalid charge response: {e}")))?;
+        if confirmation.reference.is_empty() {
+            return Err(PmsError::Http("PMS returned an empty reference".into()));
+        }
+        Ok(confirmation)
+    }
+
+    async fn reverse_charge(&self, reference: &str) -> Result<(), PmsError> {
+        let url = self.url(&["charges", reference, "reverse"])?;
+        let response = self
+            .authorize(self.client.post(url))
+            .send()
+            .await
+            .map_err(|e| PmsError::Http(e.to_string()))?;
+        if !response.status().is_success() {
+            return Err(error_for(response).await);
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_url_encodes_segments() {
+        let client = HttpPmsClient::new("https://pms.example.com/api/", None).unwrap();
+        assert_eq!(
+            client.url(&["rooms", "12 B", "guest"]).unwrap().as_str(),
+            "https://pms.example.com/api/rooms/12%20B/guest"
+        );
+        assert_eq!(
+            client.url(&["charges", "A/1", "reverse"]).unwrap().as_str(),
+            "https://pms.example.com/api/charges/A%2F1/reverse"
+        );
+        assert!(HttpPmsClient::new("not a url", None).is_err());
+    }
+}
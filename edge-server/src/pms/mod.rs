@@ -0,0 +1,85 @@
+//! 酒店 PMS 挂房账集成 (Room Charge)
+//!
+//! 酒店内的餐厅可将账单挂到客房：收银时选择 `ROOM_CHARGE` 支付方式，
+//! `PaymentInput.reference` 填房间号。OrdersManager 在 AddPayment 的预取阶段
+//! (redb 事务前) 先向 PMS 核验住客，再过账；PMS 返回的确认号写入
+//! `PaymentAdded.reference`，房间号写入 note。
+//!
+//! - [`PmsClient`] — 对接不同 PMS 的 trait
+//! - [`HttpPmsClient`] — 通用 HTTP/JSON 实现 (`PMS_URL` / `PMS_API_KEY`)
+//!
+//! 支付取消 / 订单作废时调用 [`PmsClient::reverse_charge`] 冲正。
+
+mod http;
+
+pub use http::HttpPmsClient;
+
+use async_trait::async_trait;
+use serde::{Deserialize, Serialize};
+use thiserror::Error;
+
+/// PMS 错误
+#[derive(Debug, Error)]
+pub enum PmsError {
+    #[error("No checked-in guest in room {0}")]
+    GuestNotFound(String),
+
+    #[error("PMS rejected the charge: {0}")]
+    Rejected(String),
+
+    #[error("PMS unreachable: {0}")]
+    Http(String),
+
+    #[error("Operation not supported by this PMS")]
+    Unsupported,
+}
+
+/// 在住客人
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct PmsGuest {
+    pub room_number: String,
+    pub guest_name: String,
+    /// PMS 侧的入住/账户编号
+    #[serde(default)]
+    pub folio_id: Option<String>,
+    /// 是否允许挂账 (部分房价包含"禁止挂账")
+    #[serde(default = "default_true")]
+    pub charges_allowed: bool,
+}
+
+fn default_true() -> bool {
+    true
+}
+
+/// 挂房账请求
+#[derive(Debug, Clone, Serialize)]
+pub struct RoomCharge {
+    pub room_number: String,
+    pub amount: f64,
+    pub receipt_number: String,
+    /// 幂等键 (预分配的 payment_id)
+    pub external_id: i64,
+    pub description: String,
+}
+
+/// PMS 过账确认
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct PmsConfirmation {
+    /// PMS 确认号 (写入支付事件 reference)
+    pub reference: String,
+}
+
+/// PMS 对接接口
+#[async_trait]
+pub trait PmsClient: Send + Sync {
+    /// 核验房间内的在住客人
+    async fn verify_guest(&self, room_number: &str) -> Result<PmsGuest, PmsError>;
+
+    /// 挂房账
+    async fn post_charge(&self, charge: &RoomCharge) -> Result<PmsConfirmation, PmsError>;
+
+    /// 冲正已过账的挂账 (默认不支持，需在 PMS 手工处理)
+    async fn reverse_charge(&self, _reference: &str) -> Result<(), PmsError> {
+        Err(PmsError::Unsupported)
+    }
+}
@@ -204,6 +204,7 @@ mod tests {
             adjustment_value: value,
             is_stackable: stackable,
             is_exclusive: false,
+            priority: 0,
             valid_from: None,
             valid_until: None,
             active_days: None,
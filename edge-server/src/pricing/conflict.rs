@@ -0,0 +1,257 @@
+//! Price Rule Conflict Detection
+//!
+//! Two rules conflict when they compete for the same winner slot in
+//! `select_winner`: same rule type, same product/zone scope, same stacking
+//! class (both exclusive, or both non-stackable) and overlapping time windows.
+//! Stackable rules combine deterministically and never conflict.
+//!
+//! Overlapping rules must be ordered by an explicit `priority`; otherwise the
+//! winner silently falls back to `created_at`.
+
+use serde::Serialize;
+use shared::models::PriceRule;
+
+/// Minutes in a day (time-of-day windows are `[start, end)` in minutes)
+const DAY_MINUTES: u16 = 24 * 60;
+
+/// A pair of overlapping rules
+#[derive(Debug, Clone, Serialize)]
+pub struct RuleConflict {
+    pub rule_id: i64,
+    pub rule_name: String,
+    pub other_rule_id: i64,
+    pub other_rule_name: String,
+    /// Both rules share the same explicit priority (winner decided by created_at)
+    pub same_priority: bool,
+    /// Rule that wins when both match
+    pub winner_id: i64,
+}
+
+/// Check whether two rules compete for the same winner slot
+pub fn rules_overlap(a: &PriceRule, b: &PriceRule) -> bool {
+    if a.id == b.id || !a.is_active || !b.is_active {
+        return false;
+    }
+
+    let same_scope = a.rule_type == b.rule_type
+        && a.product_scope == b.product_scope
+        && a.target_id == b.target_id
+        && a.zone_scope == b.zone_scope;
+    if !same_scope {
+        return false;
+    }
+
+    let same_class = (a.is_exclusive && b.is_exclusive)
+        || (!a.is_exclusive && !b.is_exclusive && !a.is_stackable && !b.is_stackable);
+    if !same_class {
+        return false;
+    }
+
+    validity_overlaps(a, b) && days_overlap(a, b) && time_of_day_overlaps(a, b)
+}
+
+/// Rules in `existing` that overlap `candidate` with the same explicit priority
+pub fn find_unordered_overlaps<'a>(
+    candidate: &PriceRule,
+    existing: &'a [PriceRule],
+) -> Vec<&'a PriceRule> {
+    existing
+        .iter()
+        .filter(|other| other.priority == candidate.priority && rules_overlap(candidate, other))
+        .collect()
+}
+
+/// Report every overlapping pair among `rules`
+pub fn find_conflicts(rules: &[PriceRule]) -> Vec<RuleConflict> {
+    let mut conflicts = Vec::new();
+    for (i, a) in rules.iter().enumerate() {
+        for b in &rules[i + 1..] {
+            if !rules_overlap(a, b) {
+                continue;
+            }
+            let winner = match a.priority.cmp(&b.priority) {
+                std::cmp::Ordering::Equal if a.created_at >= b.created_at => a,
+                std::cmp::Ordering::Equal => b,
+                std::cmp::Ordering::Greater => a,
+                std::cmp::Ordering::Less => b,
+            };
+            conflicts.push(RuleConflict {
+                rule_id: a.id,
+                rule_name: a.name.clone(),
+                other_rule_id: b.id,
+                other_rule_name: b.name.clone(),
+                same_priority: a.priority == b.priority,
+                winner_id: winner.id,
+            });
+        }
+    }
+    conflicts
+}
+
+/// valid_from/valid_until (None = unbounded)
+fn validity_overlaps(a: &PriceRule, b: &PriceRule) -> bool {
+    let a_from = a.valid_from.unwrap_or(i64::MIN);
+    let a_until = a.valid_until.unwrap_or(i64::MAX);
+    let b_from = b.valid_from.unwrap_or(i64::MIN);
+    let b_until = b.valid_until.unwrap_or(i64::MAX);
+    a_from <= b_until && b_from <= a_until
+}
+
+/// active_days (None = every day)
+fn days_overlap(a: &PriceRule, b: &PriceRule) -> bool {
+    match (&a.active_days, &b.active_days) {
+        (Some(a_days), Some(b_days)) => a_days.iter().any(|d| b_days.contains(d)),
+        _ => true,
+    }
+}
+
+/// active_start_time/active_end_time, same semantics as `is_time_valid`
+fn time_of_day_overlaps(a: &PriceRule, b: &PriceRule) -> bool {
+    let a_windows = time_windows(a);
+    let b_windows = time_windows(b);
+    a_windows.iter().any(|(a_start, a_end)| {
+        b_windows
+            .iter()
+            .any(|(b_start, b_end)| a_start < b_end && b_start < a_end)
+    })
+}
+
+/// Split a rule's time-of-day filter into `[start, end)` minute windows
+fn time_windows(rule: &PriceRule) -> Vec<(u16, u16)> {
+    let start = rule.active_start_time.as_deref().and_then(parse_hhmm);
+    let end = rule.active_end_time.as_deref().and_then(parse_hhmm);
+    match (start, end) {
+        (Some(start), Some(end)) if end > start => vec![(start, end)],
+        // Cross-midnight range (e.g., 21:00-04:00)
+        (Some(start), Some(end)) => vec![(start, DAY_MINUTES), (0, end)],
+        (Some(start), None) => vec![(start, DAY_MINUTES)],
+        (None, Some(end)) => vec![(0, end)],
+        (None, None) => vec![(0, DAY_MINUTES)],
+    }
+}
+
+fn parse_hhmm(value: &str) -> Option<u16> {
+    let (h, m) = value.split_once(':')?;
+    let h: u16 = h.parse().ok()?;
+    let m: u16 = m.parse().ok()?;
+    (h < 24 && m < 60).then_some(h * 60 + m)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use shared::models::{AdjustmentType, ProductScope, RuleType, ZONE_SCOPE_ALL};
+
+    fn make_rule(id: i64, stackable: bool, exclusive: bool) -> PriceRule {
+        PriceRule {
+            id,
+            name: format!("rule_{}", id),
+            receipt_name: None,
+            description: None,
+            rule_type: RuleType::Discount,
+            product_scope: ProductScope::Category,
+            target_id: Some(10),
+            zone_scope: ZONE_SCOPE_ALL.to_string(),
+            adjustment_type: AdjustmentType::Percentage,
+            adjustment_value: 10.0,
+            is_stackable: stackable,
+            is_exclusive: exclusive,
+            priority: 0,
+            valid_from: None,
+            valid_until: None,
+            active_days: None,
+            active_start_time: None,
+            active_end_time: None,
+            is_active: true,
+            created_by: None,
+            created_at: id,
+        }
+    }
+
+    #[test]
+    fn test_non_stackable_same_scope_overlaps() {
+        let a = make_rule(1, false, false);
+        let b = make_rule(2, false, false);
+        assert!(rules_overlap(&a, &b));
+    }
+
+    #[test]
+    fn test_stackable_never_conflicts() {
+        let a = make_rule(1, true, false);
+        let b = make_rule(2, true, false);
+        assert!(!rules_overlap(&a, &b));
+
+        // Exclusive vs non-stackable: exclusive always wins, no ambiguity
+        let c = make_rule(3, false, true);
+        let d = make_rule(4, false, false);
+        assert!(!rules_overlap(&c, &d));
+    }
+
+    #[test]
+    fn test_different_scope_no_overlap() {
+        let a = make_rule(1, false, false);
+        let mut b = make_rule(2, false, false);
+        b.target_id = Some(11);
+        assert!(!rules_overlap(&a, &b));
+
+        let mut c = make_rule(3, false, false);
+        c.zone_scope = "5".to_string();
+        assert!(!rules_overlap(&a, &c));
+    }
+
+    #[test]
+    fn test_disjoint_time_windows() {
+        let mut a = make_rule(1, false, false);
+        a.active_start_time = Some("09:00".to_string());
+        a.active_end_time = Some("12:00".to_string());
+        let mut b = make_rule(2, false, false);
+        b.active_start_time = Some("12:00".to_string());
+        b.active_end_time = Some("18:00".to_string());
+        assert!(!rules_overlap(&a, &b));
+
+        // Cross-midnight window reaches into the morning
+        b.active_start_time = Some("22:00".to_string());
+        b.active_end_time = Some("10:00".to_string());
+        assert!(rules_overlap(&a, &b));
+    }
+
+    #[test]
+    fn test_disjoint_days_and_validity() {
+        let mut a = make_rule(1, false, false);
+        a.active_days = Some(vec![1, 2]);
+        let mut b = make_rule(2, false, false);
+        b.active_days = Some(vec![5, 6]);
+        assert!(!rules_overlap(&a, &b));
+
+        let mut c = make_rule(3, false, false);
+        c.valid_until = Some(1000);
+        let mut d = make_rule(4, false, false);
+        d.valid_from = Some(2000);
+        assert!(!rules_overlap(&c, &d));
+    }
+
+    #[test]
+    fn test_find_unordered_overlaps_respects_priority() {
+        let existing = vec![make_rule(1, false, false), make_rule(2, false, false)];
+        let mut candidate = make_rule(3, false, false);
+        assert_eq!(find_unordered_overlaps(&candidate, &existing).len(), 2);
+
+        candidate.priority = 1;
+        assert!(find_unordered_overlaps(&candidate, &existing).is_empty());
+    }
+
+    #[test]
+    fn test_find_conflicts_report() {
+        let older = make_rule(1, false, false);
+        let mut newer = make_rule(2, false, false);
+        let conflicts = find_conflicts(&[older.clone(), newer.clone()]);
+        assert_eq!(conflicts.len(), 1);
+        assert!(conflicts[0].same_priority);
+        assert_eq!(conflicts[0].winner_id, 2);
+
+        newer.priority = -1;
+        let conflicts = find_conflicts(&[older, newer]);
+        assert!(!conflicts[0].same_priority);
+        assert_eq!(conflicts[0].winner_id, 1);
+    }
+}
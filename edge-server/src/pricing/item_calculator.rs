@@ -12,7 +12,7 @@ use shared::models::{AdjustmentType, PriceRule, ProductScope, RuleType};
 use shared::order::AppliedRule;
 use tracing::{debug, trace};
 
-use crate::order_money::{to_decimal, to_f64};
+use crab_order_core::money::{to_decimal, to_f64};
 
 /// Rounding strategy for monetary values (2 decimal places, half-up)
 #[allow(dead_code)]
@@ -91,7 +91,8 @@ pub fn calculate_effective_priority(rule: &PriceRule) -> i32 {
 // ==================== Rule Selection ====================
 
 /// Select the winning rule based on effective priority (highest wins).
-/// If tied, prefer the rule created more recently (created_at DESC).
+/// If tied, the explicit `priority` field decides; if still tied, prefer the
+/// rule created more recently (created_at DESC).
 fn select_winner<'a>(rules: &[&'a PriceRule]) -> Option<&'a PriceRule> {
     if rules.is_empty() {
         return None;
@@ -103,10 +104,10 @@ fn select_winner<'a>(rules: &[&'a PriceRule]) -> Option<&'a PriceRule> {
             let priority_a = calculate_effective_priority(a);
             let priority_b = calculate_effective_priority(b);
 
-            match priority_a.cmp(&priority_b) {
-                std::cmp::Ordering::Equal => a.created_at.cmp(&b.created_at), // Higher created_at wins
-                other => other,
-            }
+            priority_a
+                .cmp(&priority_b)
+                .then_with(|| a.priority.cmp(&b.priority)) // Explicit operator ordering
+                .then_with(|| a.created_at.cmp(&b.created_at)) // Higher created_at wins
         })
         .copied()
 }
@@ -579,6 +580,7 @@ mod tests {
             adjustment_value: value,
             is_stackable: stackable,
             is_exclusive: exclusive,
+            priority: 0,
             valid_from: None,
             valid_until: None,
             active_days: None,
@@ -989,6 +991,41 @@ mod tests {
         assert_eq!(result.applied_rules.len(), 1);
     }
 
+    #[test]
+    fn test_non_stackable_explicit_priority_beats_created_at() {
+        // Same effective_priority → explicit priority decides before created_at
+        let mut newer = make_rule_with_scope(
+            RuleType::Discount,
+            AdjustmentType::Percentage,
+            10.0,
+            false,
+            false,
+            "zone:bar",
+            ProductScope::Category,
+        );
+        newer.created_at = 5000;
+
+        let mut older = make_rule_with_scope(
+            RuleType::Discount,
+            AdjustmentType::Percentage,
+            20.0,
+            false,
+            false,
+            "zone:bar",
+            ProductScope::Category,
+        );
+        older.created_at = 1000;
+        older.priority = 1;
+
+        let rules: Vec<&PriceRule> = vec![&newer, &older];
+        let result = calculate_item_price(100.0, 0.0, 0.0, &rules);
+
+        // Older (20%) wins because of its higher explicit priority
+        assert_eq!(result.rule_discount_amount, 20.0);
+        assert_eq!(result.item_final, 80.0);
+        assert_eq!(result.applied_rules.len(), 1);
+    }
+
     // ==================== Surcharge Tests ====================
 
     #[test]
@@ -141,15 +141,40 @@ pub fn matches_zone_scope(rule: &PriceRule, zone_id: Option<&str>, is_retail: bo
 /// Note: active_days and active_time use LOCAL time (with DST handling) since rules
 /// are typically configured in local business hours.
 pub fn is_time_valid(rule: &PriceRule, current_time: i64, tz: chrono_tz::Tz) -> bool {
+    is_within_window(
+        &TimeWindow {
+            valid_from: rule.valid_from,
+            valid_until: rule.valid_until,
+            active_days: rule.active_days.as_deref(),
+            active_start_time: rule.active_start_time.as_deref(),
+            active_end_time: rule.active_end_time.as_deref(),
+        },
+        current_time,
+        tz,
+    )
+}
+
+/// Time control fields shared by price rules and stamp campaigns
+#[derive(Debug, Clone, Copy, Default)]
+pub struct TimeWindow<'a> {
+    pub valid_from: Option<i64>,
+    pub valid_until: Option<i64>,
+    pub active_days: Option<&'a [u8]>,
+    pub active_start_time: Option<&'a str>,
+    pub active_end_time: Option<&'a str>,
+}
+
+/// Check if `current_time` falls inside the window (see [`is_time_valid`])
+pub fn is_within_window(window: &TimeWindow<'_>, current_time: i64, tz: chrono_tz::Tz) -> bool {
     // Check valid_from (i64 millis comparison)
-    if let Some(from) = rule.valid_from
+    if let Some(from) = window.valid_from
         && current_time < from
     {
         return false;
     }
 
     // Check valid_until (i64 millis comparison)
-    if let Some(until) = rule.valid_until
+    if let Some(until) = window.valid_until
         && current_time > until
     {
         return false;
@@ -164,7 +189,7 @@ pub fn is_time_valid(rule: &PriceRule, current_time: i64, tz: chrono_tz::Tz) ->
     let local_datetime = current_datetime.with_timezone(&tz);
 
     // Check active_days (0=Sunday, 1=Monday, ..., 6=Saturday) in LOCAL time
-    if let Some(ref days) = rule.active_days {
+    if let Some(days) = window.active_days {
         // chrono's weekday: Mon=0, Tue=1, ..., Sun=6
         // We need: Sun=0, Mon=1, ..., Sat=6
         let weekday = local_datetime.weekday().num_days_from_sunday() as u8;
@@ -175,30 +200,31 @@ pub fn is_time_valid(rule: &PriceRule, current_time: i64, tz: chrono_tz::Tz) ->
 
     // Check active_start_time/active_end_time ("HH:MM" format) in LOCAL time
     // Supports cross-midnight ranges (e.g., 21:00-04:00)
-    if let (Some(start), Some(end)) = (&rule.active_start_time, &rule.active_end_time) {
+    if let (Some(start), Some(end)) = (window.active_start_time, window.active_end_time) {
         let current_time_str = local_datetime.format("%H:%M").to_string();
+        let current = current_time_str.as_str();
 
         let in_range = if end > start {
             // Normal range (e.g., 09:00-18:00): current must be >= start AND < end
-            current_time_str >= *start && current_time_str < *end
+            current >= start && current < end
         } else {
             // Cross-midnight range (e.g., 21:00-04:00): current must be >= start OR < end
-            current_time_str >= *start || current_time_str < *end
+            current >= start || current < end
         };
 
         if !in_range {
             return false;
         }
-    } else if let Some(ref start) = rule.active_start_time {
+    } else if let Some(start) = window.active_start_time {
         // Only start time specified
         let current_time_str = local_datetime.format("%H:%M").to_string();
-        if current_time_str < *start {
+        if current_time_str.as_str() < start {
             return false;
         }
-    } else if let Some(ref end) = rule.active_end_time {
+    } else if let Some(end) = window.active_end_time {
         // Only end time specified
         let current_time_str = local_datetime.format("%H:%M").to_string();
-        if current_time_str >= *end {
+        if current_time_str.as_str() >= end {
             return false;
         }
     }
@@ -225,6 +251,7 @@ mod tests {
             adjustment_value: 10.0,
             is_stackable: false,
             is_exclusive: false,
+            priority: 0,
             valid_from: None,
             valid_until: None,
             active_days: None,
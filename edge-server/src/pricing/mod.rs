@@ -4,11 +4,13 @@
 //! Rules are applied on the backend when items are added to orders.
 
 mod calculator;
+mod conflict;
 mod item_calculator;
 pub mod matcher;
 mod order_calculator;
 
 pub use calculator::*;
+pub use conflict::*;
 pub use item_calculator::*;
 pub use matcher::*;
 pub use order_calculator::*;
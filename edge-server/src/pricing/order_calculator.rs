@@ -12,7 +12,7 @@ use shared::models::PriceRule;
 use shared::order::AppliedRule;
 
 use super::item_calculator::{apply_discount_rules, apply_surcharge_rules};
-use crate::order_money::{to_decimal, to_f64};
+use crab_order_core::money::{to_decimal, to_f64};
 
 /// Result of order price calculation
 #[derive(Debug, Clone)]
@@ -144,6 +144,7 @@ mod tests {
             adjustment_value: value,
             is_stackable: stackable,
             is_exclusive: exclusive,
+            priority: 0,
             valid_from: None,
             valid_until: None,
             active_days: None,
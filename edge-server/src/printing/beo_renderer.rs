@@ -0,0 +1,290 @@
+//! Banquet event order (BEO) renderer
+//!
+//! Renders an EventBookingDetail into ESC/POS format for the kitchen:
+//! event header → latest amendment (if any) → menu grouped by course.
+//! No prices — the BEO is a production sheet, not a bill.
+
+use chrono_tz::Tz;
+use crab_printer::EscPosBuilder;
+use shared::models::{EventBookingDetail, EventBookingItem, ReceiptText, receipt_text};
+
+/// BEO renderer
+pub struct BeoRenderer {
+    width: usize,
+    timezone: Tz,
+    locale: String,
+}
+
+impl BeoRenderer {
+    /// Quantity column width ("120x ")
+    const COL_QTY: usize = 5;
+
+    pub fn new(width: usize, timezone: Tz, locale: String) -> Self {
+        Self {
+            width,
+            timezone,
+            locale,
+        }
+    }
+
+    /// Render a banquet event order to ESC/POS bytes
+    pub fn render(&self, detail: &EventBookingDetail) -> Vec<u8> {
+        let txt = receipt_text(&self.locale);
+        let mut b = EscPosBuilder::new(self.width);
+        let booking = &detail.booking;
+
+        // Title
+        b.center();
+        b.bold();
+        b.line(txt.beo_title);
+        b.double_size();
+        b.line(&booking.event_name);
+        b.reset_size();
+        b.bold_off();
+        b.sep_double();
+        b.left();
+
+        // Event header
+        b.line_lr(
+            &format!(
+                "{} {}",
+                txt.event_date_label,
+                format_timestamp(booking.event_start, self.timezone)
+            ),
+            &format!("{} {}", txt.revision_label, booking.revision),
+        );
+        b.line(&format!("{} {}", txt.customer_label, booking.customer_name));
+        if let Some(table) = &booking.table_name {
+            b.line(&format!("{} {}", txt.table_label, table));
+        }
+        b.bold();
+        b.double_height();
+        b.line(&format!("{} {}", txt.guests_label, booking.headcount));
+        b.reset_size();
+        b.bold_off();
+
+        // Latest amendment — the kitchen must see what changed since the last print
+        if let Some(amendment) = detail.amendments.last() {
+            b.sep_single();
+            b.center();
+            b.bold();
+            b.line(txt.amended_title);
+            b.bold_off();
+            b.left();
+            for field in &amendment.changes.fields {
+                b.line(&format!(
+                    "{}: {} -> {}",
+                    field.field,
+                    display_value(&field.from),
+                    display_value(&field.to)
+                ));
+            }
+            for item in &amendment.changes.items {
+                let line = match (item.from_quantity, item.to_quantity) {
+                    (0, to) => format!("+{}x {}", to, item.name),
+                    (from, 0) => format!("-{}x {} {}", from, item.name, txt.removed_tag),
+                    (from, to) => format!("{} -> {}x {}", from, to, item.name),
+                };
+                b.line(&line);
+            }
+        }
+
+        // Menu grouped by course (first-appearance order)
+        for (course, items) in group_by_course(&detail.items) {
+            b.sep_single();
+            if let Some(course) = course {
+                let qty: i32 = items.iter().map(|i| i.quantity).sum();
+                b.bold();
+                b.double_size();
+                b.line(&format!("{} ({})", course, qty));
+                b.reset_size();
+                b.bold_off();
+            }
+            for item in items {
+                self.render_item(&mut b, item, &txt);
+            }
+        }
+
+        b.sep_single();
+        let total_qty: i32 = detail.items.iter().map(|i| i.quantity).sum();
+        b.line_lr(
+            txt.total_units_label,
+            &format!("{} {}", total_qty, txt.units_word),
+        );
+
+        if let Some(note) = &booking.note
+            && !note.is_empty()
+        {
+            b.sep_single();
+            b.bold();
+            b.line(&format!("{} {}", txt.notes_label, note));
+            b.bold_off();
+        }
+
+        b.sep_double();
+        b.feed(6);
+        b.cut();
+
+        b.build()
+    }
+
+    fn render_item(&self, b: &mut EscPosBuilder, item: &EventBookingItem, txt: &ReceiptText) {
+        let qty_col = format!(
+            "{:>width$}",
+            format!("{}x", item.quantity),
+            width = Self::COL_QTY
+        );
+        b.line(&format!("{} {}", qty_col, item.name));
+
+        let prefix = " ".repeat(Self::COL_QTY);
+        b.bold();
+        if let Some(spec) = &item.selected_specification
+            && spec.is_multi_spec
+        {
+            b.line(&format!("{} > {} {}", prefix, txt.spec_label, spec.name));
+        }
+        for opt in item.selected_options.iter().flatten() {
+            b.line(&format!(
+                "{} > {}: {}",
+                prefix, opt.attribute_name, opt.option_name
+            ));
+        }
+        if let Some(note) = &item.note
+            && !note.is_empty()
+        {
+            b.line(&format!("{} * {}", prefix, note));
+        }
+        b.bold_off();
+    }
+}
+
+impl Default for BeoRenderer {
+    fn default() -> Self {
+        Self::new(48, chrono_tz::Europe::Madrid, "es-ES".to_string())
+    }
+}
+
+fn group_by_course(items: &[EventBookingItem]) -> Vec<(Option<&str>, Vec<&EventBookingItem>)> {
+    let mut groups: Vec<(Option<&str>, Vec<&EventBookingItem>)> = Vec::new();
+    for item in items {
+        let course = item.course.as_deref().filter(|c| !c.is_empty());
+        match groups.iter_mut().find(|(c, _)| *c == course) {
+            Some((_, list)) => list.push(item),
+            None => groups.push((course, vec![item])),
+        }
+    }
+    groups
+}
+
+fn display_value(value: &serde_json::Value) -> String {
+    match value {
+        serde_json::Value::Null => "-".to_string(),
+        serde_json::Value::String(s) => s.clone(),
+        other => other.to_string(),
+    }
+}
+
+/// Format unix timestamp (millis) to readable string in given timezone
+fn format_timestamp(ts: i64, tz: Tz) -> String {
+    if let Some(dt) = chrono::DateTime::from_timestamp_millis(ts) {
+        dt.with_timezone(&tz).format("%d/%m/%Y %H:%M").to_string()
+    } else {
+        "--/--/---- --:--".to_string()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use shared::models::{
+        EventBooking, EventBookingAmendment, EventBookingChanges, EventBookingItemChange,
+        EventBookingStatus,
+    };
+
+    fn item(id: i64, name: &str, quantity: i32, course: Option<&str>) -> EventBookingItem {
+        EventBookingItem {
+            id,
+            booking_id: 1,
+            product_id: id,
+            name: name.to_string(),
+            price: 20.0,
+            quantity,
+            selected_options: None,
+            selected_specification: None,
+            course: course.map(String::from),
+            note: None,
+            sort_order: id as i32,
+        }
+    }
+
+    fn test_detail() -> EventBookingDetail {
+        EventBookingDetail {
+            booking: EventBooking {
+                id: 1,
+                event_name: "Boda García".to_string(),
+                customer_name: "Ana García".to_string(),
+                customer_phone: None,
+                member_id: None,
+                event_start: 1740667500000,
+                headcount: 120,
+                table_id: None,
+                table_name: Some("Salón".to_string()),
+                zone_id: None,
+                zone_name: None,
+                note: Some("2 celíacos".to_string()),
+                status: EventBookingStatus::Confirmed,
+                revision: 2,
+                estimated_total: 4800.0,
+                deposit_total: 1000.0,
+                order_id: None,
+                materialized_at: None,
+                materialize_error: None,
+                created_by_id: 1,
+                created_by_name: "María".to_string(),
+                created_at: 0,
+                updated_at: 0,
+            },
+            items: vec![
+                item(1, "Croquetas", 120, Some("Entrantes")),
+                item(2, "Solomillo", 110, Some("Principal")),
+                item(3, "Jamón", 40, Some("Entrantes")),
+            ],
+            deposits: vec![],
+            amendments: vec![EventBookingAmendment {
+                id: 1,
+                booking_id: 1,
+                revision: 2,
+                changes: EventBookingChanges {
+                    fields: vec![],
+                    items: vec![EventBookingItemChange {
+                        name: "Solomillo".to_string(),
+                        course: Some("Principal".to_string()),
+                        from_quantity: 100,
+                        to_quantity: 110,
+                        from_price: 20.0,
+                        to_price: 20.0,
+                    }],
+                },
+                operator_id: 1,
+                operator_name: "María".to_string(),
+                created_at: 0,
+            }],
+        }
+    }
+
+    #[test]
+    fn test_render_beo() {
+        let data = BeoRenderer::default().render(&test_detail());
+        assert!(data.len() > 100);
+    }
+
+    #[test]
+    fn test_group_by_course_keeps_first_appearance() {
+        let detail = test_detail();
+        let groups = group_by_course(&detail.items);
+        assert_eq!(groups.len(), 2);
+        assert_eq!(groups[0].0, Some("Entrantes"));
+        assert_eq!(groups[0].1.len(), 2);
+        assert_eq!(groups[1].0, Some("Principal"));
+    }
+}
@@ -5,23 +5,23 @@
 
 use chrono_tz::Tz;
 use crab_printer::EscPosBuilder;
-use shared::models::{CreditNoteDetail, receipt_text};
+use shared::models::{CreditNoteDetail, LocaleFormat, receipt_text};
 
 /// Credit note receipt renderer
 pub struct CreditNoteReceiptRenderer {
     width: usize,
     timezone: Tz,
     locale: String,
-    currency_symbol: String,
+    format: LocaleFormat,
 }
 
 impl CreditNoteReceiptRenderer {
-    pub fn new(width: usize, timezone: Tz, locale: String, currency_symbol: String) -> Self {
+    pub fn new(width: usize, timezone: Tz, locale: String, format: LocaleFormat) -> Self {
         Self {
             width,
             timezone,
             locale,
-            currency_symbol,
+            format,
         }
     }
 
@@ -45,7 +45,7 @@ impl CreditNoteReceiptRenderer {
         // Credit note number + date
         b.line_lr(
             &format!("{} {}", txt.credit_note_num_label, cn.credit_note_number),
-            &format_timestamp(cn.created_at, self.timezone),
+            &self.format.datetime_millis(cn.created_at, &self.timezone),
         );
 
         // Original receipt reference
@@ -65,7 +65,7 @@ impl CreditNoteReceiptRenderer {
         // Items
         for item in &detail.items {
             let qty_str = format!("x{}", item.quantity);
-            let amount_str = format!("{:.2}", item.line_credit).replace('.', txt.decimal_separator);
+            let amount_str = self.format.number(item.line_credit);
             // Name column = width - qty(5) - amount(10) - spaces(2)
             let name_width = self.width.saturating_sub(17);
             let name = if item.item_name.len() > name_width {
@@ -85,12 +85,10 @@ impl CreditNoteReceiptRenderer {
         b.sep_single();
 
         // Amounts
-        let subtotal_str = format!("{:.2} {}", cn.subtotal_credit, self.currency_symbol)
-            .replace('.', txt.decimal_separator);
+        let subtotal_str = self.format.money(cn.subtotal_credit);
         b.line_lr(txt.credit_subtotal_label, &subtotal_str);
 
-        let tax_str = format!("{:.2} {}", cn.tax_credit, self.currency_symbol)
-            .replace('.', txt.decimal_separator);
+        let tax_str = self.format.money(cn.tax_credit);
         b.line_lr(txt.iva_label, &tax_str);
 
         b.sep_single();
@@ -98,8 +96,7 @@ impl CreditNoteReceiptRenderer {
         // Total (bold, double size)
         b.bold();
         b.double_size();
-        let total_str = format!("{:.2} {}", cn.total_credit, self.currency_symbol)
-            .replace('.', txt.decimal_separator);
+        let total_str = self.format.money(cn.total_credit);
         b.line_lr(txt.total_label, &total_str);
         b.reset_size();
         b.bold_off();
@@ -146,20 +143,11 @@ impl Default for CreditNoteReceiptRenderer {
             48,
             chrono_tz::Europe::Madrid,
             "es-ES".to_string(),
-            "EUR".to_string(),
+            LocaleFormat::default(),
         )
     }
 }
 
-/// Format unix timestamp (millis) to readable string in given timezone
-fn format_timestamp(ts: i64, tz: Tz) -> String {
-    if let Some(dt) = chrono::DateTime::from_timestamp_millis(ts) {
-        dt.with_timezone(&tz).format("%d/%m/%Y %H:%M").to_string()
-    } else {
-        "--/--/---- --:--".to_string()
-    }
-}
-
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -219,7 +207,7 @@ mod tests {
             48,
             chrono_tz::Europe::Madrid,
             "es-ES".to_string(),
-            "EUR".to_string(),
+            LocaleFormat::default(),
         );
         let data = renderer.render(&test_detail());
         assert!(data.len() > 100);
@@ -231,7 +219,7 @@ mod tests {
             32,
             chrono_tz::Europe::Madrid,
             "es-ES".to_string(),
-            "EUR".to_string(),
+            LocaleFormat::default(),
         );
         let data = renderer.render(&test_detail());
         assert!(data.len() > 100);
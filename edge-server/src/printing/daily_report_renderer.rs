@@ -0,0 +1,213 @@
+//! Daily report summary renderer
+//!
+//! Renders a DailyReport into ESC/POS format for the auto-print at the
+//! business-day cutoff: headline totals → cash drawer movements → per-shift
+//! sales and cash variance. The full report stays in the POS.
+
+use chrono_tz::Tz;
+use crab_printer::EscPosBuilder;
+use shared::models::{DailyReport, LocaleFormat, ShiftBreakdown, receipt_text};
+
+/// Daily report summary renderer
+pub struct DailyReportRenderer {
+    width: usize,
+    timezone: Tz,
+    locale: String,
+    format: LocaleFormat,
+}
+
+impl DailyReportRenderer {
+    pub fn new(width: usize, timezone: Tz, locale: String, format: LocaleFormat) -> Self {
+        Self {
+            width,
+            timezone,
+            locale,
+            format,
+        }
+    }
+
+    /// Render a daily report summary to ESC/POS bytes
+    pub fn render(&self, report: &DailyReport, store_name: &str) -> Vec<u8> {
+        let txt = receipt_text(&self.locale);
+        let mut b = EscPosBuilder::new(self.width);
+
+        // Title
+        b.center();
+        b.double_size();
+        b.bold();
+        b.line(txt.daily_report_title);
+        b.bold_off();
+        b.reset_size();
+        if !store_name.is_empty() {
+            b.line(store_name);
+        }
+        b.sep_double();
+        b.left();
+
+        b.line_lr(txt.business_date_label, &report.business_date);
+        if let Some(generated_at) = report.generated_at {
+            b.line_lr(
+                txt.generated_label,
+                &self.format.datetime_millis(generated_at, &self.timezone),
+            );
+        }
+        b.sep_single();
+
+        // Headline totals
+        b.line_lr(txt.orders_label, &report.total_orders.to_string());
+        if report.refund_count > 0 {
+            b.line_lr(
+                &format!("{} ({})", txt.refunds_label, report.refund_count),
+                &self.format.money_signed(-report.refund_amount),
+            );
+        }
+        if report.payment_surcharge_amount > 0.0 {
+            b.line_lr(
+                txt.payment_surcharge_label,
+                &self.format.money(report.payment_surcharge_amount),
+            );
+        }
+        if report.carried_out_count > 0 {
+            b.line_lr(
+                &format!("{} ({})", txt.carried_out_label, report.carried_out_count),
+                &self.format.money(report.carried_out_amount),
+            );
+        }
+        b.bold();
+        b.double_height();
+        b.line_lr(
+            txt.net_revenue_label,
+            &self.format.money(report.net_revenue),
+        );
+        b.reset_size();
+        b.bold_off();
+
+        // Cash drawer movements
+        if report.no_sale_opens > 0 || report.paid_in_amount > 0.0 || report.paid_out_amount > 0.0 {
+            b.sep_single();
+            b.line_lr(txt.no_sale_label, &report.no_sale_opens.to_string());
+            b.line_lr(txt.paid_in_label, &self.format.money(report.paid_in_amount));
+            b.line_lr(
+                txt.paid_out_label,
+                &self.format.money(report.paid_out_amount),
+            );
+        }
+
+        // Shifts
+        for shift in &report.shift_breakdowns {
+            b.sep_single();
+            self.render_shift(&mut b, shift, &txt);
+        }
+
+        b.sep_double();
+        b.feed(6);
+        b.cut();
+
+        b.build()
+    }
+
+    fn render_shift(
+        &self,
+        b: &mut EscPosBuilder,
+        shift: &ShiftBreakdown,
+        txt: &shared::models::ReceiptText,
+    ) {
+        b.bold();
+        b.line(&format!("{} {}", txt.cashier_label, shift.operator_name));
+        b.bold_off();
+        b.line_lr(
+            &format!("{} ({})", txt.orders_label, shift.completed_orders),
+            &self.format.money(shift.total_sales),
+        );
+        if let Some(variance) = shift.cash_variance {
+            b.line_lr(txt.cash_variance_label, &self.format.money_signed(variance));
+        }
+    }
+}
+
+impl Default for DailyReportRenderer {
+    fn default() -> Self {
+        Self::new(
+            48,
+            chrono_tz::Europe::Madrid,
+            "es-ES".to_string(),
+            LocaleFormat::default(),
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn test_report() -> DailyReport {
+        DailyReport {
+            id: 1,
+            business_date: "2026-02-27".to_string(),
+            net_revenue: 1234.5,
+            total_orders: 87,
+            refund_amount: 16.0,
+            refund_count: 1,
+            payment_surcharge_amount: 3.2,
+            carried_in_count: 0,
+            carried_in_amount: 0.0,
+            carried_out_count: 1,
+            carried_out_amount: 42.0,
+            no_sale_opens: 2,
+            paid_in_amount: 50.0,
+            paid_out_amount: 12.5,
+            auto_generated: true,
+            generated_at: Some(1740711600000),
+            generated_by_id: None,
+            generated_by_name: None,
+            note: None,
+            shift_breakdowns: vec![ShiftBreakdown {
+                id: 1,
+                report_id: 1,
+                shift_id: 10,
+                operator_id: 1,
+                operator_name: "María".to_string(),
+                status: "CLOSED".to_string(),
+                start_time: 1740660000000,
+                end_time: Some(1740700000000),
+                starting_cash: 100.0,
+                expected_cash: 480.0,
+                actual_cash: Some(478.5),
+                cash_variance: Some(-1.5),
+                abnormal_close: false,
+                total_orders: 88,
+                completed_orders: 87,
+                void_orders: 1,
+                total_sales: 1250.5,
+                total_paid: 1250.5,
+                void_amount: 9.0,
+                total_tax: 112.3,
+                total_discount: 5.0,
+                total_surcharge: 0.0,
+                paid_break_ms: 0,
+                unpaid_break_ms: 0,
+                no_sale_opens: 2,
+                paid_in_amount: 50.0,
+                paid_out_amount: 12.5,
+            }],
+        }
+    }
+
+    #[test]
+    fn test_render_daily_report() {
+        let data = DailyReportRenderer::default().render(&test_report(), "Bar Pepe");
+        assert!(data.len() > 100);
+    }
+
+    #[test]
+    fn test_render_daily_report_58mm_zh() {
+        let renderer = DailyReportRenderer::new(
+            32,
+            chrono_tz::Asia::Shanghai,
+            "zh-CN".to_string(),
+            LocaleFormat::default(),
+        );
+        let data = renderer.render(&test_report(), "");
+        assert!(data.len() > 100);
+    }
+}
@@ -43,6 +43,16 @@ impl From<PrintExecutorError> for shared::error::AppError {
 
 pub type PrintExecutorResult<T> = Result<T, PrintExecutorError>;
 
+/// A destination whose ticket could not be printed
+#[derive(Debug, Clone)]
+pub struct FailedDestination {
+    pub destination_id: String,
+    pub destination_name: String,
+    /// Rendered ESC/POS ticket (sent as-is on retry)
+    pub data: Vec<u8>,
+    pub error: String,
+}
+
 /// Print job executor
 ///
 /// Sends rendered print data to physical printers.
@@ -74,6 +84,29 @@ impl PrintExecutor {
         order: &KitchenOrder,
         destinations: &HashMap<String, PrintDestination>,
     ) -> PrintExecutorResult<()> {
+        let failed = self.print_kitchen_order_routed(order, destinations).await;
+        if !failed.is_empty() {
+            let summary: Vec<_> = failed
+                .iter()
+                .map(|f| format!("{}: {}", f.destination_name, f.error))
+                .collect();
+            return Err(PrintExecutorError::PrintFailed(summary.join("; ")));
+        }
+        Ok(())
+    }
+
+    /// Route a kitchen order to its destinations, one ticket per destination
+    ///
+    /// Items are split by `kitchen_destinations` (resolved from the
+    /// product → category → global print config chain); the renderer groups
+    /// them by category inside each ticket. Returns the destinations that
+    /// could not be printed together with their rendered data, so the caller
+    /// can queue them for retry.
+    pub async fn print_kitchen_order_routed(
+        &self,
+        order: &KitchenOrder,
+        destinations: &HashMap<String, PrintDestination>,
+    ) -> Vec<FailedDestination> {
         // Group items by destination
         let grouped = self.group_by_destination(order);
         tracing::debug!(
@@ -83,10 +116,11 @@ impl PrintExecutor {
 
         if grouped.is_empty() {
             info!("No items to print");
-            return Ok(());
+            return Vec::new();
         }
 
-        // Print to each destination
+        // Print to each destination (失败的目的地汇总后返回，不影响其他目的地)
+        let mut failed = Vec::new();
         for (dest_id, items) in grouped {
             let dest = match destinations.get(&dest_id) {
                 Some(d) => d,
@@ -107,6 +141,7 @@ impl PrintExecutor {
                 is_retail: order.is_retail,
                 created_at: order.created_at,
                 items,
+                course: order.course,
                 print_count: order.print_count,
             };
 
@@ -117,12 +152,18 @@ impl PrintExecutor {
             if let Err(e) = self.send_to_destination(dest, &data).await {
                 error!(dest = %dest.name, error = %e, "Failed to print");
                 // Continue with other destinations even if one fails
+                failed.push(FailedDestination {
+                    destination_id: dest_id,
+                    destination_name: dest.name.clone(),
+                    data,
+                    error: e.to_string(),
+                });
             } else {
                 info!(dest = %dest.name, bytes = data.len(), "Print job sent");
             }
         }
 
-        Ok(())
+        failed
     }
 
     /// Group items by their kitchen destination
@@ -144,8 +185,8 @@ impl PrintExecutor {
         groups
     }
 
-    /// Send data to a print destination
-    async fn send_to_destination(
+    /// Send data to a print destination (also used to retry queued print jobs)
+    pub async fn send_to_destination(
         &self,
         dest: &PrintDestination,
         data: &[u8],
@@ -650,6 +691,7 @@ mod tests {
                     label_destinations: vec![],
                 },
             }],
+            course: None,
             print_count: 0,
         }
     }
@@ -1,10 +1,13 @@
 //! Kitchen and Label Printing Module
 //!
 //! This module handles automatic printing on ItemsAdded events:
-//! - Kitchen printing: grouped by destination, sent to kitchen printers
+//! - Kitchen printing: grouped by destination, sent to kitchen printers;
+//!   failed destinations are queued and retried in the background
 //! - Label printing: per-item labels (e.g., bubble tea stickers)
 
+pub mod beo_renderer;
 pub mod credit_note_renderer;
+pub mod daily_report_renderer;
 pub mod executor;
 pub mod renderer;
 pub mod service;
@@ -12,10 +15,14 @@ pub mod storage;
 pub mod types;
 pub mod worker;
 
+pub use beo_renderer::BeoRenderer;
 pub use credit_note_renderer::CreditNoteReceiptRenderer;
-pub use executor::{LabelContext, PrintExecutor, PrintExecutorError, PrintExecutorResult};
+pub use daily_report_renderer::DailyReportRenderer;
+pub use executor::{
+    FailedDestination, LabelContext, PrintExecutor, PrintExecutorError, PrintExecutorResult,
+};
 pub use renderer::KitchenTicketRenderer;
 pub use service::{KitchenPrintService, PrintServiceError, PrintServiceResult};
 pub use storage::{PrintStorage, PrintStorageError, PrintStorageResult};
 pub use types::*;
-pub use worker::KitchenPrintWorker;
+pub use worker::{KitchenPrintWorker, PrintJobOutcome, PrintWorkerDeps, attempt_print_job};
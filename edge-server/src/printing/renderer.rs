@@ -97,6 +97,11 @@ impl KitchenTicketRenderer {
         };
         b.line(&title);
 
+        // Fired course (分道次起菜单)
+        if let Some(course) = order.course {
+            b.line(&format!("** {} {} **", txt.fire_course_label, course));
+        }
+
         b.bold_off();
         b.reset_size();
         b.left();
@@ -326,6 +331,7 @@ mod tests {
                     },
                 },
             ],
+            course: None,
             print_count: 0,
         }
     }
@@ -380,6 +386,7 @@ mod tests {
                     },
                 },
             ],
+            course: None,
             print_count: 0,
         }
     }
@@ -422,4 +429,17 @@ mod tests {
         // Should produce non-empty output (reprint adds more bytes)
         assert!(data.len() > 100);
     }
+
+    #[test]
+    fn test_fired_course_line() {
+        let renderer =
+            KitchenTicketRenderer::new(48, chrono_tz::Europe::Madrid, "es-ES".to_string());
+        let mut order = create_test_order();
+        let plain = renderer.render(&order);
+        order.course = Some(2);
+        let fired = renderer.render(&order);
+        let needle = b"MARCHAR PLATO 2";
+        assert!(fired.windows(needle.len()).any(|w| w == needle));
+        assert!(!plain.windows(needle.len()).any(|w| w == needle));
+    }
 }
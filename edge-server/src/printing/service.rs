@@ -1,7 +1,7 @@
 //! Kitchen/Label print service - handles print job generation and reprint
 
 use super::storage::{PrintStorage, PrintStorageError};
-use super::types::{KitchenOrder, KitchenOrderItem, LabelPrintRecord, PrintItemContext};
+use super::types::{KitchenOrder, KitchenOrderItem, LabelPrintRecord, PrintItemContext, PrintJob};
 use crate::services::CatalogService;
 use shared::order::{CartItemSnapshot, EventPayload, OrderEvent, OrderSnapshot};
 use thiserror::Error;
@@ -17,6 +17,9 @@ pub enum PrintServiceError {
     #[error("Label record not found: {0}")]
     LabelRecordNotFound(i64),
 
+    #[error("Print job not found: {0}")]
+    PrintJobNotFound(i64),
+
     #[error("Printing disabled")]
     PrintingDisabled,
 }
@@ -34,6 +37,9 @@ impl From<PrintServiceError> for shared::error::AppError {
             PrintServiceError::LabelRecordNotFound(id) => {
                 AppError::not_found(format!("Label record {}", id))
             }
+            PrintServiceError::PrintJobNotFound(id) => {
+                AppError::not_found(format!("Print job {}", id))
+            }
             PrintServiceError::PrintingDisabled => AppError::with_message(
                 ErrorCode::PrinterNotAvailable,
                 "Printing disabled".to_string(),
@@ -59,9 +65,10 @@ impl KitchenPrintService {
         Self { storage }
     }
 
-    /// Process an ItemsAdded event
+    /// Process an ItemsAdded or CourseFired event
     ///
     /// Creates KitchenOrder and LabelPrintRecord entries if printing is enabled.
+    /// Held items (分道次未起菜) are skipped; they print with their CourseFired event.
     /// Returns the created KitchenOrder ID if any items were processed.
     pub fn process_items_added(
         &self,
@@ -82,8 +89,11 @@ impl KitchenPrintService {
         }
 
         // Extract items from event
-        let items = match &event.payload {
-            EventPayload::ItemsAdded { items } => items,
+        let (items, course): (Vec<&CartItemSnapshot>, _) = match &event.payload {
+            EventPayload::ItemsAdded { items } => {
+                (items.iter().filter(|i| !i.is_held).collect(), None)
+            }
+            EventPayload::CourseFired { course, items } => (items.iter().collect(), Some(*course)),
             _ => return Ok(None),
         };
 
@@ -167,6 +177,7 @@ impl KitchenPrintService {
             is_retail: snapshot.is_retail,
             created_at: event.timestamp,
             items: kitchen_items,
+            course,
             print_count: 0,
         };
 
@@ -392,6 +403,50 @@ impl KitchenPrintService {
     pub fn cleanup_old_records(&self, max_age_secs: i64) -> PrintServiceResult<usize> {
         Ok(self.storage.cleanup_old_records(max_age_secs)?)
     }
+
+    // ========== Print Jobs (retry queue) ==========
+
+    /// Queue a failed destination print with its rendered data
+    pub fn enqueue_print_job(&self, job: &PrintJob, data: &[u8]) -> PrintServiceResult<()> {
+        let txn = self.storage.begin_write()?;
+        self.storage.store_print_job(&txn, job, Some(data))?;
+        txn.commit().map_err(PrintStorageError::from)?;
+        Ok(())
+    }
+
+    /// Persist attempt count / next attempt of a queued job
+    pub fn update_print_job(&self, job: &PrintJob) -> PrintServiceResult<()> {
+        let txn = self.storage.begin_write()?;
+        self.storage.store_print_job(&txn, job, None)?;
+        txn.commit().map_err(PrintStorageError::from)?;
+        Ok(())
+    }
+
+    /// Remove a job (printed or cancelled by staff)
+    pub fn remove_print_job(&self, id: i64) -> PrintServiceResult<()> {
+        let txn = self.storage.begin_write()?;
+        let removed = self.storage.remove_print_job(&txn, id)?;
+        txn.commit().map_err(PrintStorageError::from)?;
+        if !removed {
+            return Err(PrintServiceError::PrintJobNotFound(id));
+        }
+        Ok(())
+    }
+
+    /// Queued print jobs, pending and failed (oldest first)
+    pub fn list_print_jobs(&self) -> PrintServiceResult<Vec<PrintJob>> {
+        Ok(self.storage.list_print_jobs()?)
+    }
+
+    /// Get a print job with its rendered data
+    pub fn get_print_job(&self, id: i64) -> PrintServiceResult<(PrintJob, Vec<u8>)> {
+        let job = self
+            .storage
+            .get_print_job(id)?
+            .ok_or(PrintServiceError::PrintJobNotFound(id))?;
+        let data = self.storage.get_print_job_data(id)?.unwrap_or_default();
+        Ok((job, data))
+    }
 }
 
 impl std::fmt::Debug for KitchenPrintService {
@@ -1,6 +1,6 @@
 //! redb-based storage for kitchen orders and label records
 
-use super::types::{KitchenOrder, LabelPrintRecord};
+use super::types::{KitchenOrder, LabelPrintRecord, PrintJob, PrintJobStatus};
 use redb::{Database, ReadableDatabase, ReadableTable, TableDefinition, WriteTransaction};
 use std::path::Path;
 use std::sync::Arc;
@@ -20,6 +20,12 @@ const LABEL_RECORDS_TABLE: TableDefinition<i64, &[u8]> = TableDefinition::new("l
 const LABEL_RECORDS_BY_ORDER_TABLE: TableDefinition<(i64, i64), ()> =
     TableDefinition::new("label_records_by_order");
 
+/// Failed print jobs (retry queue): key = print_job_id (i64 snowflake), value = JSON
+const PRINT_JOBS_TABLE: TableDefinition<i64, &[u8]> = TableDefinition::new("print_jobs");
+
+/// Rendered ESC/POS data of a print job: key = print_job_id
+const PRINT_JOB_DATA_TABLE: TableDefinition<i64, &[u8]> = TableDefinition::new("print_job_data");
+
 #[derive(Debug, Error)]
 pub enum PrintStorageError {
     #[error("Database error: {0}")]
@@ -87,6 +93,8 @@ impl PrintStorage {
             let _ = write_txn.open_table(KITCHEN_ORDERS_BY_ORDER_TABLE)?;
             let _ = write_txn.open_table(LABEL_RECORDS_TABLE)?;
             let _ = write_txn.open_table(LABEL_RECORDS_BY_ORDER_TABLE)?;
+            let _ = write_txn.open_table(PRINT_JOBS_TABLE)?;
+            let _ = write_txn.open_table(PRINT_JOB_DATA_TABLE)?;
         }
         write_txn.commit()?;
 
@@ -104,6 +112,8 @@ impl PrintStorage {
             let _ = write_txn.open_table(KITCHEN_ORDERS_BY_ORDER_TABLE)?;
             let _ = write_txn.open_table(LABEL_RECORDS_TABLE)?;
             let _ = write_txn.open_table(LABEL_RECORDS_BY_ORDER_TABLE)?;
+            let _ = write_txn.open_table(PRINT_JOBS_TABLE)?;
+            let _ = write_txn.open_table(PRINT_JOB_DATA_TABLE)?;
         }
         write_txn.commit()?;
 
@@ -359,6 +369,70 @@ impl PrintStorage {
         Ok(())
     }
 
+    // ========== Print Jobs (retry queue) ==========
+
+    /// Store (insert or update) a print job; `data` only on first insert
+    pub fn store_print_job(
+        &self,
+        txn: &WriteTransaction,
+        job: &PrintJob,
+        data: Option<&[u8]>,
+    ) -> PrintStorageResult<()> {
+        let mut table = txn.open_table(PRINT_JOBS_TABLE)?;
+        let value = serde_json::to_vec(job)?;
+        table.insert(job.id, value.as_slice())?;
+
+        if let Some(data) = data {
+            let mut data_table = txn.open_table(PRINT_JOB_DATA_TABLE)?;
+            data_table.insert(job.id, data)?;
+        }
+
+        Ok(())
+    }
+
+    /// Get a print job by ID
+    pub fn get_print_job(&self, id: i64) -> PrintStorageResult<Option<PrintJob>> {
+        let read_txn = self.db.begin_read()?;
+        let table = read_txn.open_table(PRINT_JOBS_TABLE)?;
+
+        match table.get(id)? {
+            Some(guard) => Ok(Some(serde_json::from_slice(guard.value())?)),
+            None => Ok(None),
+        }
+    }
+
+    /// Get the rendered data of a print job
+    pub fn get_print_job_data(&self, id: i64) -> PrintStorageResult<Option<Vec<u8>>> {
+        let read_txn = self.db.begin_read()?;
+        let table = read_txn.open_table(PRINT_JOB_DATA_TABLE)?;
+        Ok(table.get(id)?.map(|guard| guard.value().to_vec()))
+    }
+
+    /// List all pending print jobs (oldest first)
+    pub fn list_print_jobs(&self) -> PrintStorageResult<Vec<PrintJob>> {
+        let read_txn = self.db.begin_read()?;
+        let table = read_txn.open_table(PRINT_JOBS_TABLE)?;
+
+        let mut jobs = Vec::new();
+        for result in table.iter()? {
+            let (_, guard) = result?;
+            let job: PrintJob = serde_json::from_slice(guard.value())?;
+            jobs.push(job);
+        }
+
+        jobs.sort_by_key(|j| j.created_at);
+        Ok(jobs)
+    }
+
+    /// Remove a print job and its data
+    pub fn remove_print_job(&self, txn: &WriteTransaction, id: i64) -> PrintStorageResult<bool> {
+        let mut table = txn.open_table(PRINT_JOBS_TABLE)?;
+        let mut data_table = txn.open_table(PRINT_JOB_DATA_TABLE)?;
+        let removed = table.remove(id)?.is_some();
+        data_table.remove(id)?;
+        Ok(removed)
+    }
+
     // ========== Cleanup ==========
 
     /// Clean up old records (older than max_age_secs)
@@ -411,6 +485,27 @@ impl PrintStorage {
             }
         }
 
+        // Abandoned print jobs (pending ones keep retrying)
+        {
+            let mut table = txn.open_table(PRINT_JOBS_TABLE)?;
+            let mut data_table = txn.open_table(PRINT_JOB_DATA_TABLE)?;
+
+            let mut to_delete: Vec<i64> = Vec::new();
+            for result in table.iter()? {
+                let (key, guard) = result?;
+                let job: PrintJob = serde_json::from_slice(guard.value())?;
+                if job.status == PrintJobStatus::Failed && job.created_at < cutoff {
+                    to_delete.push(key.value());
+                }
+            }
+
+            for id in to_delete {
+                table.remove(id)?;
+                data_table.remove(id)?;
+                deleted += 1;
+            }
+        }
+
         txn.commit()?;
         Ok(deleted)
     }
@@ -434,6 +529,7 @@ mod tests {
             is_retail: false,
             created_at: shared::util::now_millis(),
             items: vec![],
+            course: None,
             print_count: 0,
         };
 
@@ -445,4 +541,86 @@ mod tests {
         assert!(retrieved.is_some());
         assert_eq!(retrieved.unwrap().order_id, 200001);
     }
+
+    #[test]
+    fn test_print_job_queue() {
+        let storage = PrintStorage::open_in_memory().unwrap();
+
+        let mut job = PrintJob {
+            id: 300001,
+            kitchen_order_id: 100001,
+            order_id: 200001,
+            receipt_number: "FAC202401220001".to_string(),
+            destination_id: "1".to_string(),
+            destination_name: "Cocina".to_string(),
+            status: PrintJobStatus::Pending,
+            attempts: 1,
+            last_error: "Printer offline".to_string(),
+            next_attempt_at: 0,
+            created_at: shared::util::now_millis(),
+        };
+
+        let txn = storage.begin_write().unwrap();
+        storage
+            .store_print_job(&txn, &job, Some(b"\x1b@ticket"))
+            .unwrap();
+        txn.commit().unwrap();
+
+        // Update keeps the stored data
+        job.record_failure("Timeout".to_string(), 1_000);
+        assert_eq!(job.next_attempt_at, 1_000 + 30_000);
+        assert!(!job.is_due(1_000));
+        let txn = storage.begin_write().unwrap();
+        storage.store_print_job(&txn, &job, None).unwrap();
+        txn.commit().unwrap();
+
+        let jobs = storage.list_print_jobs().unwrap();
+        assert_eq!(jobs.len(), 1);
+        assert_eq!(jobs[0].attempts, 2);
+        assert_eq!(
+            storage.get_print_job_data(300001).unwrap().as_deref(),
+            Some(&b"\x1b@ticket"[..])
+        );
+
+        let txn = storage.begin_write().unwrap();
+        assert!(storage.remove_print_job(&txn, 300001).unwrap());
+        txn.commit().unwrap();
+        assert!(storage.get_print_job(300001).unwrap().is_none());
+        assert!(storage.get_print_job_data(300001).unwrap().is_none());
+        assert_eq!(PrintJob::retry_delay_ms(1), 15_000);
+        assert_eq!(PrintJob::retry_delay_ms(30), 5 * 60 * 1000);
+    }
+
+    #[test]
+    fn test_cleanup_keeps_pending_print_jobs() {
+        let storage = PrintStorage::open_in_memory().unwrap();
+        let old = shared::util::now_millis() - 10 * 24 * 3600 * 1000;
+
+        let txn = storage.begin_write().unwrap();
+        for (id, status) in [
+            (300002, PrintJobStatus::Pending),
+            (300003, PrintJobStatus::Failed),
+        ] {
+            let job = PrintJob {
+                id,
+                kitchen_order_id: 100001,
+                order_id: 200001,
+                receipt_number: "FAC202401220001".to_string(),
+                destination_id: "1".to_string(),
+                destination_name: "Cocina".to_string(),
+                status,
+                attempts: 10,
+                last_error: "Printer offline".to_string(),
+                next_attempt_at: 0,
+                created_at: old,
+            };
+            storage.store_print_job(&txn, &job, Some(b"x")).unwrap();
+        }
+        txn.commit().unwrap();
+
+        assert_eq!(storage.cleanup_old_records(24 * 3600).unwrap(), 1);
+        assert!(storage.get_print_job(300002).unwrap().is_some());
+        assert!(storage.get_print_job(300003).unwrap().is_none());
+        assert!(storage.get_print_job_data(300003).unwrap().is_none());
+    }
 }
@@ -43,7 +43,7 @@ pub struct KitchenOrderItem {
     pub context: PrintItemContext,
 }
 
-/// 一次点单的厨房记录（对应一个 ItemsAdded 事件）
+/// 一次点单的厨房记录（对应一个 ItemsAdded / CourseFired 事件）
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct KitchenOrder {
     pub id: i64, // = event_id (snowflake)
@@ -55,6 +55,9 @@ pub struct KitchenOrder {
     pub is_retail: bool,
     pub created_at: i64, // 时间戳
     pub items: Vec<KitchenOrderItem>,
+    /// 起菜道次（仅 CourseFired 出单时有值）
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub course: Option<i32>,
     pub print_count: u32, // 打印次数
 }
 
@@ -73,3 +76,61 @@ pub struct LabelPrintRecord {
     pub context: PrintItemContext,
     pub print_count: u32,
 }
+
+/// 打印任务状态
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+pub enum PrintJobStatus {
+    /// 等待自动重试
+    #[default]
+    Pending,
+    /// 自动重试已放弃，保留在队列中等待手动重打或取消
+    Failed,
+}
+
+/// 失败的厨房单打印任务（按目的地持久化，后台重试）
+///
+/// 已渲染的 ESC/POS 数据单独存放（PRINT_JOB_DATA_TABLE），重试时原样发送，
+/// 与首次打印内容一致。放弃重试的任务标记为 `Failed` 保留，超过记录保留期后清理。
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct PrintJob {
+    pub id: i64, // snowflake
+    pub kitchen_order_id: i64,
+    pub order_id: i64,
+    pub receipt_number: String,
+    pub destination_id: String,
+    pub destination_name: String,
+    #[serde(default)]
+    pub status: PrintJobStatus,
+    pub attempts: u32,
+    pub last_error: String,
+    pub next_attempt_at: i64,
+    pub created_at: i64,
+}
+
+/// 自动重试上限（超过后放弃并发出通知）
+pub const MAX_PRINT_JOB_ATTEMPTS: u32 = 10;
+/// 首次重试延迟
+const PRINT_JOB_BASE_DELAY_MS: i64 = 15_000;
+/// 重试延迟上限
+const PRINT_JOB_MAX_DELAY_MS: i64 = 5 * 60 * 1000;
+
+impl PrintJob {
+    /// Exponential backoff after `attempts` failed tries: 15s, 30s, 1m, … capped at 5m
+    pub fn retry_delay_ms(attempts: u32) -> i64 {
+        let exp = attempts.saturating_sub(1).min(8);
+        (PRINT_JOB_BASE_DELAY_MS << exp).min(PRINT_JOB_MAX_DELAY_MS)
+    }
+
+    /// Record a failed attempt and schedule the next one
+    pub fn record_failure(&mut self, error: String, now: i64) {
+        self.attempts += 1;
+        self.last_error = error;
+        self.next_attempt_at = now + Self::retry_delay_ms(self.attempts);
+    }
+
+    /// Pending and past its backoff (failed jobs wait for staff)
+    pub fn is_due(&self, now: i64) -> bool {
+        self.status == PrintJobStatus::Pending && self.next_attempt_at <= now
+    }
+}
@@ -3,34 +3,121 @@
 //! 监听打印事件通道，执行厨房打印。
 //! 通过 EventRouter 解耦，不直接依赖 OrdersManager。
 
+use crate::core::{Metrics, PrintFailure};
 use crate::db::repository::print_destination;
+use crate::message::MessageBus;
 use crate::orders::OrdersManager;
-use crate::printing::{KitchenPrintService, LabelContext, PrintExecutor};
+use crate::printing::{
+    KitchenPrintService, LabelContext, MAX_PRINT_JOB_ATTEMPTS, PrintExecutor, PrintJob,
+    PrintJobStatus,
+};
 use crate::services::CatalogService;
 use chrono_tz::Tz;
+use shared::message::{NotificationCategory, NotificationPayload};
 use shared::order::{OrderEvent, OrderEventType};
 use sqlx::SqlitePool;
 use std::collections::HashMap;
 use std::path::PathBuf;
 use std::sync::Arc;
+use std::time::Duration;
 use tokio::sync::mpsc;
 use tokio_util::sync::CancellationToken;
 
 /// Arc-wrapped OrderEvent (from EventRouter)
 type ArcOrderEvent = Arc<OrderEvent>;
 
+/// 失败任务队列扫描间隔
+const RETRY_SCAN_INTERVAL: Duration = Duration::from_secs(5);
+
+/// Outcome of one attempt at a queued print job
+pub enum PrintJobOutcome {
+    /// Printed and removed from the queue
+    Printed,
+    /// Still failing, rescheduled with backoff
+    Rescheduled(PrintJob),
+    /// Attempts exhausted (or destination deleted), kept as `Failed` for reprint / cancel
+    GaveUp(PrintJob),
+}
+
+/// Try a queued print job once and update the queue accordingly
+///
+/// `count_attempt = false` for manual reprints: staff reprints never exhaust the job,
+/// and a failed reprint of a pending job keeps it on the automatic schedule.
+pub async fn attempt_print_job(
+    service: &KitchenPrintService,
+    executor: &PrintExecutor,
+    destinations: &HashMap<String, shared::models::PrintDestination>,
+    mut job: PrintJob,
+    count_attempt: bool,
+) -> Result<PrintJobOutcome, crate::printing::PrintServiceError> {
+    let now = shared::util::now_millis();
+    let Some(dest) = destinations.get(&job.destination_id) else {
+        tracing::warn!(
+            job_id = %job.id,
+            dest_id = %job.destination_id,
+            "Print job destination no longer exists, giving up"
+        );
+        job.status = PrintJobStatus::Failed;
+        job.last_error = "Destination deleted".to_string();
+        service.update_print_job(&job)?;
+        return Ok(PrintJobOutcome::GaveUp(job));
+    };
+
+    let (_, data) = service.get_print_job(job.id)?;
+    match executor.send_to_destination(dest, &data).await {
+        Ok(()) => {
+            tracing::info!(
+                job_id = %job.id,
+                dest = %dest.name,
+                attempts = job.attempts,
+                "Queued kitchen print succeeded"
+            );
+            service.remove_print_job(job.id)?;
+            Ok(PrintJobOutcome::Printed)
+        }
+        Err(e) => {
+            let attempts = job.attempts;
+            job.record_failure(e.to_string(), now);
+            if !count_attempt {
+                job.attempts = attempts;
+            }
+            if job.attempts >= MAX_PRINT_JOB_ATTEMPTS {
+                job.status = PrintJobStatus::Failed;
+                service.update_print_job(&job)?;
+                return Ok(PrintJobOutcome::GaveUp(job));
+            }
+            job.status = PrintJobStatus::Pending;
+            service.update_print_job(&job)?;
+            Ok(PrintJobOutcome::Rescheduled(job))
+        }
+    }
+}
+
+/// 打印工作者依赖的服务端资源
+pub struct PrintWorkerDeps {
+    pub message_bus: Arc<MessageBus>,
+    pub pool: SqlitePool,
+    pub timezone: Tz,
+    /// 标签图片目录 (None = 标签不打印图片)
+    pub images_dir: Option<PathBuf>,
+    pub metrics: Arc<Metrics>,
+}
+
 /// 厨房打印工作者
 ///
-/// 监听打印事件通道（ItemsAdded + OrderCompleted），执行厨房打印。
-/// - ItemsAdded: 堂食立即打印，零售创建记录但跳过打印
+/// 监听打印事件通道（ItemsAdded + CourseFired + OrderCompleted），执行厨房打印。
+/// - ItemsAdded: 堂食立即打印，零售创建记录但跳过打印（分道次暂缓商品不出单）
+/// - CourseFired: 该道次暂缓商品出单
 /// - OrderCompleted: 零售订单完成时执行打印
 pub struct KitchenPrintWorker {
     orders_manager: Arc<OrdersManager>,
     kitchen_print_service: Arc<KitchenPrintService>,
     catalog_service: Arc<CatalogService>,
+    message_bus: Arc<MessageBus>,
     pool: SqlitePool,
     timezone: Tz,
     images_dir: Option<PathBuf>,
+    metrics: Arc<Metrics>,
 }
 
 impl KitchenPrintWorker {
@@ -38,23 +125,30 @@ impl KitchenPrintWorker {
         orders_manager: Arc<OrdersManager>,
         kitchen_print_service: Arc<KitchenPrintService>,
         catalog_service: Arc<CatalogService>,
-        pool: SqlitePool,
-        timezone: Tz,
-        images_dir: Option<PathBuf>,
+        deps: PrintWorkerDeps,
     ) -> Self {
+        let PrintWorkerDeps {
+            message_bus,
+            pool,
+            timezone,
+            images_dir,
+            metrics,
+        } = deps;
         Self {
             orders_manager,
             kitchen_print_service,
             catalog_service,
+            message_bus,
             pool,
             timezone,
             images_dir,
+            metrics,
         }
     }
 
     /// 运行工作者（阻塞直到通道关闭）
     ///
-    /// 接收来自 EventRouter 的 mpsc 通道（ItemsAdded + OrderCompleted）
+    /// 接收来自 EventRouter 的 mpsc 通道（ItemsAdded + CourseFired + OrderCompleted）
     pub async fn run(
         self,
         mut event_rx: mpsc::Receiver<ArcOrderEvent>,
@@ -72,6 +166,8 @@ impl KitchenPrintWorker {
         let executor = PrintExecutor::with_config(48, self.timezone, locale);
         let label_ctx =
             LabelContext::from_store_info(store_info.as_ref(), self.images_dir.as_deref());
+        let mut retry_interval = tokio::time::interval(RETRY_SCAN_INTERVAL);
+        retry_interval.set_missed_tick_behavior(tokio::time::MissedTickBehavior::Delay);
 
         loop {
             tokio::select! {
@@ -79,13 +175,16 @@ impl KitchenPrintWorker {
                     tracing::info!("Kitchen print worker received shutdown signal");
                     break;
                 }
+                _ = retry_interval.tick() => {
+                    self.retry_due_jobs(&executor).await;
+                }
                 event = event_rx.recv() => {
                     let Some(event) = event else {
                         tracing::info!("Print channel closed, kitchen print worker stopping");
                         break;
                     };
                     match event.event_type {
-                        OrderEventType::ItemsAdded => {
+                        OrderEventType::ItemsAdded | OrderEventType::CourseFired => {
                             self.handle_items_added(&event, &executor, &label_ctx).await;
                         }
                         OrderEventType::OrderCompleted => {
@@ -98,7 +197,7 @@ impl KitchenPrintWorker {
         }
     }
 
-    /// 处理 ItemsAdded 事件
+    /// 处理 ItemsAdded / CourseFired 事件
     async fn handle_items_added(
         &self,
         event: &OrderEvent,
@@ -108,7 +207,8 @@ impl KitchenPrintWorker {
         tracing::info!(
             order_id = %event.order_id,
             event_id = %event.event_id,
-            "handle_items_added: received ItemsAdded event"
+            event_type = ?event.event_type,
+            "handle_items_added: received kitchen ticket event"
         );
 
         // Get full snapshot
@@ -169,7 +269,7 @@ impl KitchenPrintWorker {
                 tracing::error!(
                     order_id = %event.order_id,
                     error = ?e,
-                    "Failed to process ItemsAdded for printing"
+                    "Failed to process kitchen ticket event for printing"
                 );
             }
         }
@@ -253,12 +353,145 @@ impl KitchenPrintWorker {
             .map(|d| (d.id.to_string(), d))
             .collect();
 
-        if let Err(e) = executor.print_kitchen_order(&order, &dest_map).await {
-            tracing::error!(
-                kitchen_order_id = %kitchen_order_id,
-                error = %e,
-                "Failed to execute print job"
-            );
+        let failed = executor.print_kitchen_order_routed(&order, &dest_map).await;
+        if failed.is_empty() {
+            return;
+        }
+
+        // 失败的目的地入队，后台按退避重试
+        let now = shared::util::now_millis();
+        let mut queued = Vec::new();
+        for f in &failed {
+            self.metrics.inc_print_failure(PrintFailure::Kitchen);
+            let mut job = PrintJob {
+                id: shared::util::snowflake_id(),
+                kitchen_order_id,
+                order_id: order.order_id,
+                receipt_number: order.receipt_number.clone(),
+                destination_id: f.destination_id.clone(),
+                destination_name: f.destination_name.clone(),
+                status: PrintJobStatus::Pending,
+                attempts: 0,
+                last_error: String::new(),
+                next_attempt_at: now,
+                created_at: now,
+            };
+            job.record_failure(f.error.clone(), now);
+            match self.kitchen_print_service.enqueue_print_job(&job, &f.data) {
+                Ok(()) => queued.push(job.id),
+                Err(e) => tracing::error!(
+                    kitchen_order_id = %kitchen_order_id,
+                    dest = %f.destination_name,
+                    error = %e,
+                    "Failed to queue print job for retry"
+                ),
+            }
+        }
+
+        let summary: Vec<_> = failed
+            .iter()
+            .map(|f| format!("{}: {}", f.destination_name, f.error))
+            .collect();
+        tracing::error!(
+            kitchen_order_id = %kitchen_order_id,
+            error = %summary.join("; "),
+            queued = queued.len(),
+            "Failed to execute print job"
+        );
+        // 关键通知：厨房单没打出来，离线终端重连后也要提示
+        let payload = NotificationPayload::error(
+            "Kitchen print failed",
+            format!(
+                "Order {}: {} (retrying automatically)",
+                order.receipt_number,
+                summary.join("; ")
+            ),
+        )
+        .with_category(NotificationCategory::Printer)
+        .with_data(serde_json::json!({
+            "kind": "print_job_failed",
+            "kitchen_order_id": kitchen_order_id,
+            "order_id": order.order_id,
+            "receipt_number": order.receipt_number,
+            "print_job_ids": queued,
+        }));
+        if let Err(e) = self.message_bus.publish_durable(payload).await {
+            tracing::debug!("Print failure notification not broadcast: {}", e);
+        }
+    }
+
+    /// 重试到期的失败打印任务
+    async fn retry_due_jobs(&self, executor: &PrintExecutor) {
+        let now = shared::util::now_millis();
+        let due: Vec<_> = match self.kitchen_print_service.list_print_jobs() {
+            Ok(jobs) => jobs.into_iter().filter(|j| j.is_due(now)).collect(),
+            Err(e) => {
+                tracing::error!(error = %e, "Failed to list queued print jobs");
+                return;
+            }
+        };
+        if due.is_empty() {
+            return;
+        }
+
+        let dest_map: HashMap<String, _> = match print_destination::find_all(&self.pool).await {
+            Ok(d) => d.into_iter().map(|d| (d.id.to_string(), d)).collect(),
+            Err(e) => {
+                tracing::error!(error = ?e, "Failed to load print destinations for retry");
+                return;
+            }
+        };
+
+        for job in due {
+            let job_id = job.id;
+            match attempt_print_job(&self.kitchen_print_service, executor, &dest_map, job, true)
+                .await
+            {
+                Ok(PrintJobOutcome::GaveUp(job)) => {
+                    self.metrics.inc_print_failure(PrintFailure::Abandoned);
+                    tracing::error!(
+                        job_id = %job.id,
+                        dest = %job.destination_name,
+                        attempts = job.attempts,
+                        error = %job.last_error,
+                        "Giving up on kitchen print job"
+                    );
+                    let payload = NotificationPayload::error(
+                        "Kitchen print abandoned",
+                        format!(
+                            "Order {} ({}): {} (reprint from the print queue)",
+                            job.receipt_number, job.destination_name, job.last_error
+                        ),
+                    )
+                    .with_category(NotificationCategory::Printer)
+                    .with_data(serde_json::json!({
+                        "kind": "print_job_failed",
+                        "print_job_id": job.id,
+                        "status": job.status,
+                        "kitchen_order_id": job.kitchen_order_id,
+                        "order_id": job.order_id,
+                        "receipt_number": job.receipt_number,
+                        "destination_id": job.destination_id,
+                    }));
+                    if let Err(e) = self.message_bus.publish_durable(payload).await {
+                        tracing::debug!("Print abandon notification not broadcast: {}", e);
+                    }
+                }
+                Ok(PrintJobOutcome::Rescheduled(job)) => {
+                    self.metrics.inc_print_failure(PrintFailure::Retry);
+                    tracing::warn!(
+                        job_id = %job.id,
+                        dest = %job.destination_name,
+                        attempts = job.attempts,
+                        error = %job.last_error,
+                        "Kitchen print retry failed"
+                    );
+                }
+                Ok(PrintJobOutcome::Printed) => {}
+                Err(e) => {
+                    tracing::error!(job_id = %job_id, error = %e, "Print job retry error");
+                }
+            }
         }
     }
 
@@ -0,0 +1,328 @@
+//! 报表投影 (Read-model projection)
+//!
+//! 消费 EventRouter 的订单事件流，用与 OrdersManager 相同的 EventApplier 在内存中
+//! 折叠进行中订单；订单进入终态时写入报表读模型 (`sales_fact` / `item_fact` /
+//! `payment_fact`，见 [`crate::db::repository::sales_facts`])：
+//!
+//! - `COMPLETED` / `VOID`: 一单一行 + 商品行 + 未取消的支付
+//! - `MERGED`: 不写入 (商品与支付已并入目标订单)
+//!
+//! 不读取归档表，也不依赖 redb 中终态订单的快照 (归档完成后即被清理)。
+//! 启动时从 redb 的活跃订单播种；未跟踪订单的非开台事件也按 redb 快照补齐，
+//! `last_sequence` 之前的事件跳过，保证不重复折叠。写入按订单整体替换，幂等。
+
+use std::collections::HashMap;
+use std::collections::hash_map::Entry;
+use std::sync::Arc;
+
+use chrono::Timelike;
+use chrono_tz::Tz;
+use sqlx::SqlitePool;
+use tokio::sync::mpsc;
+use tokio_util::sync::CancellationToken;
+
+use crate::db::repository::sales_facts;
+use crate::orders::OrderStorage;
+use crab_order_core::{EventAction, EventApplier};
+use shared::models::{ItemFact, PaymentFact, SalesFact};
+use shared::order::{EventPayload, OrderEvent, OrderSnapshot, OrderStatus};
+
+/// 一个终态订单的全部事实行
+#[derive(Debug, Clone, PartialEq)]
+pub struct OrderFacts {
+    pub sale: SalesFact,
+    pub items: Vec<ItemFact>,
+    pub payments: Vec<PaymentFact>,
+}
+
+/// 报表投影服务
+pub struct ProjectionService {
+    pool: SqlitePool,
+    storage: OrderStorage,
+    timezone: Tz,
+    /// order_id → 进行中订单 (事件折叠结果)
+    orders: HashMap<i64, OrderSnapshot>,
+}
+
+impl ProjectionService {
+    pub fn new(pool: SqlitePool, storage: OrderStorage, timezone: Tz) -> Self {
+        Self {
+            pool,
+            storage,
+            timezone,
+            orders: HashMap::new(),
+        }
+    }
+
+    /// 从 redb 活跃订单播种 (启动时调用)
+    pub fn seed(&mut self) {
+        match self.storage.get_active_orders() {
+            Ok(snapshots) => {
+                for snapshot in snapshots {
+                    self.orders.insert(snapshot.order_id, snapshot);
+                }
+                tracing::debug!(orders = self.orders.len(), "Projection seeded");
+            }
+            Err(e) => tracing::warn!("Projection failed to load active orders: {e}"),
+        }
+    }
+
+    /// 运行投影 (直到通道关闭或收到 shutdown 信号)
+    pub async fn run(
+        mut self,
+        mut event_rx: mpsc::Receiver<Arc<OrderEvent>>,
+        shutdown: CancellationToken,
+    ) {
+        self.seed();
+        tracing::info!("Projection worker started");
+        loop {
+            tokio::select! {
+                _ = shutdown.cancelled() => break,
+                event = event_rx.recv() => {
+                    let Some(event) = event else { break };
+                    self.handle(&event).await;
+                }
+            }
+        }
+        tracing::info!("Projection worker stopped");
+    }
+
+    /// 折叠一个事件，订单进入终态时写入事实表
+    pub async fn handle(&mut self, event: &OrderEvent) {
+        let Some(snapshot) = self.apply(event) else {
+            return;
+        };
+        let Some(facts) = project(&snapshot, event, self.timezone) else {
+            return;
+        };
+        if let Err(e) =
+            sales_facts::replace_order(&self.pool, &facts.sale, &facts.items, &facts.payments).await
+        {
+            tracing::error!(
+                order_id = event.order_id,
+                "Failed to write sales facts: {e}"
+            );
+        }
+    }
+
+    /// 应用事件；订单进入终态时移出并返回最终快照
+    fn apply(&mut self, event: &OrderEvent) -> Option<OrderSnapshot> {
+        let snapshot = match self.orders.entry(event.order_id) {
+            Entry::Occupied(entry) => entry.into_mut(),
+            Entry::Vacant(entry) => {
+                // 未跟踪的订单 (开台早于播种或事件丢失)：以 redb 快照为准
+                let stored = if matches!(event.payload, EventPayload::TableOpened { .. }) {
+                    None
+                } else {
+                    self.storage.get_snapshot(event.order_id).ok().flatten()
+                };
+                entry.insert(stored.unwrap_or_else(|| OrderSnapshot::new(event.order_id)))
+            }
+        };
+
+        if event.sequence > snapshot.last_sequence {
+            let applier: EventAction = event.into();
+            applier.apply(snapshot, event);
+        }
+
+        if snapshot.is_active() {
+            return None;
+        }
+        self.orders.remove(&event.order_id)
+    }
+}
+
+/// 终态快照 → 事实行 (`MERGED` / 未终结返回 None)
+pub fn project(snapshot: &OrderSnapshot, event: &OrderEvent, timezone: Tz) -> Option<OrderFacts> {
+    let status = match snapshot.status {
+        OrderStatus::Completed => "COMPLETED",
+        OrderStatus::Void => "VOID",
+        OrderStatus::Active | OrderStatus::Merged => return None,
+    };
+    let order_id = snapshot.order_id;
+    let end_time = snapshot.end_time.unwrap_or(event.timestamp);
+    let hour = chrono::DateTime::from_timestamp_millis(end_time)
+        .map(|dt| dt.with_timezone(&timezone).hour() as i32)
+        .unwrap_or(0);
+
+    let sale = SalesFact {
+        order_id,
+        receipt_number: snapshot.receipt_number.clone(),
+        status: status.to_string(),
+        is_retail: snapshot.is_retail,
+        service_type: snapshot.service_type.map(|s| s.as_str().to_string()),
+        zone_name: snapshot.zone_name.clone(),
+        table_name: snapshot.table_name.clone(),
+        guest_count: snapshot.guest_count,
+        member_id: snapshot.member_id,
+        item_count: snapshot.items.iter().map(|i| i.quantity).sum(),
+        original_total: snapshot.original_total,
+        discount: snapshot.total_discount,
+        surcharge: snapshot.total_surcharge,
+        tax: snapshot.tax,
+        total: snapshot.total,
+        paid_amount: snapshot.paid_amount,
+        operator_id: event.operator_id,
+        operator_name: event.operator_name.clone(),
+        start_time: snapshot.start_time,
+        end_time,
+        hour,
+    };
+
+    let items = snapshot
+        .items
+        .iter()
+        .map(|item| ItemFact {
+            order_id,
+            instance_id: item.instance_id.clone(),
+            product_id: item.id,
+            name: item.name.clone(),
+            spec_name: item.selected_specification.as_ref().map(|s| s.name.clone()),
+            category_id: item.category_id,
+            category_name: item.category_name.clone(),
+            quantity: item.quantity,
+            unit_price: item.unit_price,
+            line_total: item.line_total,
+            tax: item.tax,
+            tax_rate: item.tax_rate,
+            is_comped: item.is_comped,
+            end_time,
+        })
+        .collect();
+
+    let payments = snapshot
+        .payments
+        .iter()
+        .filter(|p| !p.cancelled)
+        .map(|p| PaymentFact {
+            payment_id: p.payment_id,
+            order_id,
+            method: p.method.clone(),
+            amount: p.amount,
+            paid_at: p.timestamp,
+            end_time,
+        })
+        .collect();
+
+    Some(OrderFacts {
+        sale,
+        items,
+        payments,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::db::test_pool;
+    use crate::orders::OrdersManager;
+    use crate::orders::scenario::Scenario;
+    use tokio::sync::broadcast;
+
+    async fn drain(service: &mut ProjectionService, rx: &mut broadcast::Receiver<OrderEvent>) {
+        while let Ok(event) = rx.try_recv() {
+            service.handle(&event).await;
+        }
+    }
+
+    #[tokio::test]
+    async fn test_projects_completed_and_voided_orders() {
+        let dir = tempfile::tempdir().unwrap();
+        let manager =
+            OrdersManager::new(dir.path().join("orders.redb"), chrono_tz::Europe::Madrid, 1)
+                .unwrap();
+        let mut rx = manager.subscribe();
+        let pool = test_pool().await;
+        let mut service = ProjectionService::new(
+            pool.clone(),
+            manager.storage().clone(),
+            chrono_tz::Europe::Madrid,
+        );
+
+        let outcome = Scenario::new("projection")
+            .product(1, "Caña", 2.0)
+            .product(2, "Tapa", 4.5)
+            .open_retail("sold")
+            .add("sold", &[("Caña", 3), ("Tapa", 1)])
+            .pay_rest("sold")
+            .complete("sold")
+            .open_retail("voided")
+            .add("voided", &[("Tapa", 2)])
+            .void("voided")
+            .run(&manager)
+            .await
+            .expect("scenario runs");
+        drain(&mut service, &mut rx).await;
+        assert!(service.orders.is_empty());
+
+        let sold = outcome.order_id("sold").unwrap();
+        let sale = sales_facts::find_sale(&pool, sold).await.unwrap().unwrap();
+        assert_eq!(sale.status, "COMPLETED");
+        assert_eq!(sale.item_count, 4);
+        assert_eq!(sale.total, 10.5);
+
+        let report = sales_facts::report(&pool, 0, i64::MAX).await.unwrap();
+        assert_eq!(report.completed_orders, 1);
+        assert_eq!(report.void_orders, 1);
+        assert_eq!(report.revenue, 10.5);
+        assert_eq!(report.items.len(), 2);
+        assert_eq!(report.items[0].name, "Caña");
+        assert_eq!(report.items[0].quantity, 3);
+        assert_eq!(report.payments.len(), 1);
+        assert_eq!(report.payments[0].amount, 10.5);
+    }
+
+    #[tokio::test]
+    async fn test_seeds_orders_opened_before_start() {
+        let dir = tempfile::tempdir().unwrap();
+        let manager =
+            OrdersManager::new(dir.path().join("orders.redb"), chrono_tz::Europe::Madrid, 1)
+                .unwrap();
+        let pool = test_pool().await;
+
+        // 开台 + 点单发生在投影启动前 (事件未被消费)
+        let scenario = Scenario::new("seed")
+            .product(1, "Café", 1.5)
+            .open_retail("r")
+            .add("r", &[("Café", 2)]);
+        let outcome = scenario.run(&manager).await.expect("scenario runs");
+        let order_id = outcome.order_id("r").unwrap();
+
+        let mut service = ProjectionService::new(
+            pool.clone(),
+            manager.storage().clone(),
+            chrono_tz::Europe::Madrid,
+        );
+        service.seed();
+        assert!(service.orders.contains_key(&order_id));
+
+        let mut rx = manager.subscribe();
+        let snapshot = manager.get_snapshot(order_id).unwrap().unwrap();
+        let payment = shared::order::OrderCommand {
+            command_id: shared::util::snowflake_id(),
+            timestamp: shared::util::now_millis(),
+            operator_id: 1,
+            operator_name: "Test".to_string(),
+            payload: shared::order::OrderCommandPayload::AddPayment {
+                order_id,
+                payment: shared::order::PaymentInput {
+                    method: "CARD".to_string(),
+                    amount: snapshot.total,
+                    tendered: None,
+                    note: None,
+                    reference: None,
+                },
+            },
+            expected_version: None,
+        };
+        assert!(manager.execute_command(payment).await.success);
+        drain(&mut service, &mut rx).await;
+        assert_eq!(service.orders[&order_id].paid_amount, 3.0);
+        assert!(
+            sales_facts::find_sale(&pool, order_id)
+                .await
+                .unwrap()
+                .is_none()
+        );
+    }
+}
@@ -0,0 +1,223 @@
+//! 异常关闭后的启动恢复 (Recovery)
+//!
+//! 启动时 `audit.lock` 仍存在 = 上次运行未正常关闭 (断电 / 崩溃 / 被强杀)。
+//! 此时在服务启动前执行一次恢复检查：
+//!
+//! 1. SQLite `PRAGMA quick_check`
+//! 2. redb 订单存储一致性检查并修复 ([`OrderStorage::check_consistency`])
+//! 3. 统计待归档 / 死信订单
+//!
+//! 结果写入 `data/recovery_report.json`，通过 `GET /api/recovery` 查看，
+//! 并在第一个客户端连接后向经理推送一次通知 (`data.kind = "recovery_report"`，
+//! 前端按 [`NOTIFY_PERMISSION`] 过滤)。
+
+use std::path::Path;
+use std::time::Duration;
+
+use serde::{Deserialize, Serialize};
+use sqlx::SqlitePool;
+use tokio_util::sync::CancellationToken;
+
+use crate::message::MessageBus;
+use crate::orders::storage::{ConsistencyReport, OrderStorage};
+use shared::message::{BusMessage, NotificationPayload};
+
+/// 接收恢复通知所需权限
+pub const NOTIFY_PERMISSION: &str = "settings:manage";
+
+/// 等待客户端连接的轮询间隔
+const CLIENT_POLL_INTERVAL: Duration = Duration::from_secs(2);
+
+/// 恢复报告
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct RecoveryReport {
+    /// 检测时间
+    pub detected_at: i64,
+    /// 未正常关闭的那次运行的启动时间
+    pub last_start_at: i64,
+    /// SQLite quick_check 通过
+    pub sqlite_ok: bool,
+    /// quick_check 错误 (通过时为空)
+    #[serde(default)]
+    pub sqlite_errors: Vec<String>,
+    /// 订单存储检查结果 (检查本身失败时为 None)
+    pub orders: Option<ConsistencyReport>,
+    #[serde(default)]
+    pub orders_error: Option<String>,
+    /// 已执行的修复
+    #[serde(default)]
+    pub repairs: Vec<String>,
+    /// 是否已推送通知
+    #[serde(default)]
+    pub notified: bool,
+}
+
+impl RecoveryReport {
+    /// 存在无法自动修复的问题
+    pub fn needs_attention(&self) -> bool {
+        !self.sqlite_ok
+            || self.orders_error.is_some()
+            || self.orders.as_ref().is_some_and(|o| !o.is_clean())
+    }
+
+    pub fn save(&self, path: &Path) -> std::io::Result<()> {
+        let json = serde_json::to_vec_pretty(self).map_err(std::io::Error::other)?;
+        std::fs::write(path, json)
+    }
+
+    /// 读取恢复报告 (不存在返回 None)
+    pub fn load(path: &Path) -> std::io::Result<Option<Self>> {
+        match std::fs::read(path) {
+            Ok(bytes) => serde_json::from_slice(&bytes)
+                .map(Some)
+                .map_err(std::io::Error::other),
+            Err(e) if e.kind() == std::io::ErrorKind::NotFound => Ok(None),
+            Err(e) => Err(e),
+        }
+    }
+
+    fn summary(&self) -> String {
+        let mut parts = Vec::new();
+        if !self.sqlite_ok {
+            parts.push("database integrity check failed".to_string());
+        }
+        if let Some(orders) = &self.orders {
+            if orders.pending_archives > 0 {
+                parts.push(format!(
+                    "{} orders pending archive",
+                    orders.pending_archives
+                ));
+            }
+            if orders.dead_letters > 0 {
+                parts.push(format!("{} orders failed to archive", orders.dead_letters));
+            }
+            if !orders.in_flight.is_empty() {
+                parts.push(format!(
+                    "{} interrupted order commands",
+                    orders.in_flight.len()
+                ));
+            }
+        }
+        if !self.repairs.is_empty() {
+            parts.push(format!("{} repairs applied", self.repairs.len()));
+        }
+        if parts.is_empty() {
+            "No data issues found".to_string()
+        } else {
+            parts.join(", ")
+        }
+    }
+}
+
+/// 执行恢复检查 (异常关闭后启动时调用一次)
+pub async fn run(pool: &SqlitePool, storage: &OrderStorage, last_start_at: i64) -> RecoveryReport {
+    let mut report = RecoveryReport {
+        detected_at: shared::util::now_millis(),
+        last_start_at,
+        sqlite_ok: true,
+        sqlite_errors: Vec::new(),
+        orders: None,
+        orders_error: None,
+        repairs: Vec::new(),
+        notified: false,
+    };
+
+    // 1. SQLite
+    match sqlx::query_scalar::<_, String>("PRAGMA quick_check")
+        .fetch_all(pool)
+        .await
+    {
+        Ok(rows) if rows.len() == 1 && rows[0] == "ok" => {}
+        Ok(rows) => {
+            report.sqlite_ok = false;
+            report.sqlite_errors = rows;
+        }
+        Err(e) => {
+            report.sqlite_ok = false;
+            report.sqlite_errors = vec![e.to_string()];
+        }
+    }
+
+    // 2. redb (同步操作)
+    let storage = storage.clone();
+    match tokio::task::spawn_blocking(move || storage.check_consistency(true)).await {
+        Ok(Ok(orders)) => {
+            if orders.repaired {
+                for order_id in &orders.missing_snapshots {
+                    report.repairs.push(format!(
+                        "order {order_id}: removed from active orders (snapshot missing)"
+                    ));
+                }
+                for order_id in &orders.unarchived {
+                    report
+                        .repairs
+                        .push(format!("order {order_id}: re-queued for archive"));
+                }
+                if let Some((from, to)) = orders.sequence_behind {
+                    report
+                        .repairs
+                        .push(format!("sequence counter advanced {from} → {to}"));
+                }
+            }
+            report.orders = Some(orders);
+        }
+        Ok(Err(e)) => report.orders_error = Some(e.to_string()),
+        Err(e) => report.orders_error = Some(format!("consistency check panicked: {e}")),
+    }
+
+    if report.needs_attention() {
+        tracing::error!(
+            sqlite_ok = report.sqlite_ok,
+            orders_error = ?report.orders_error,
+            repairs = report.repairs.len(),
+            "Recovery check found issues that need attention"
+        );
+    } else {
+        tracing::warn!(
+            repairs = report.repairs.len(),
+            "Recovery check completed: {}",
+            report.summary()
+        );
+    }
+    report
+}
+
+/// 第一个客户端连接后推送一次恢复通知
+pub async fn notify_once(path: &Path, bus: &MessageBus, shutdown: CancellationToken) {
+    let mut report = match RecoveryReport::load(path) {
+        Ok(Some(report)) if !report.notified => report,
+        Ok(_) => return,
+        Err(e) => {
+            tracing::warn!("Failed to read recovery report: {}", e);
+            return;
+        }
+    };
+
+    while bus.clients_count() == 0 {
+        tokio::select! {
+            _ = tokio::time::sleep(CLIENT_POLL_INTERVAL) => {}
+            _ = shutdown.cancelled() => return,
+        }
+    }
+
+    let title = "Recovered from abnormal shutdown";
+    let mut payload = if report.needs_attention() {
+        NotificationPayload::error(title, report.summary())
+    } else {
+        NotificationPayload::warning(title, report.summary())
+    };
+    payload.data = Some(serde_json::json!({
+        "kind": "recovery_report",
+        "permission": NOTIFY_PERMISSION,
+        "report": &report,
+    }));
+    if let Err(e) = bus.publish(BusMessage::notification(&payload)).await {
+        tracing::warn!("Failed to publish recovery notification: {}", e);
+        return;
+    }
+
+    report.notified = true;
+    if let Err(e) = report.save(path) {
+        tracing::warn!("Failed to update recovery report: {}", e);
+    }
+}
@@ -4,6 +4,7 @@ use std::sync::Arc;
 use tokio::sync::RwLock;
 use tokio_util::sync::CancellationToken;
 
+use crate::db::tenant_scope::TenantPool;
 use crate::services::tenant_binding::TenantBinding;
 use crate::utils::AppError;
 use shared::activation::{SubscriptionInfo, SubscriptionStatus};
@@ -49,6 +50,8 @@ pub struct ActivationService {
     work_dir: PathBuf,
     /// 凭证缓存 (内存)
     pub credential_cache: Arc<RwLock<Option<TenantBinding>>>,
+    /// 数据库租户作用域 (凭证进入缓存前校验归属)
+    tenant_pool: Option<TenantPool>,
 }
 
 /// 激活状态 (用于 API 查询)
@@ -94,6 +97,35 @@ impl ActivationService {
             auth_server_url,
             work_dir,
             credential_cache,
+            tenant_pool: None,
+        }
+    }
+
+    /// 绑定数据库租户作用域：此后激活/重载的凭证必须属于数据库的归属租户
+    pub fn with_tenant_pool(mut self, tenant_pool: TenantPool) -> Self {
+        self.tenant_pool = Some(tenant_pool);
+        self
+    }
+
+    /// 校验数据库归属 `tenant_id` (未配置作用域时跳过)
+    async fn verify_tenant(&self, tenant_id: i64) -> Result<(), AppError> {
+        match &self.tenant_pool {
+            Some(tenant_pool) => tenant_pool.verify(tenant_id).await,
+            None => Ok(()),
+        }
+    }
+
+    /// 校验数据库归属当前激活的租户 (未激活时跳过)
+    pub async fn verify_tenant_scope(&self) -> Result<(), AppError> {
+        let tenant_id = self
+            .credential_cache
+            .read()
+            .await
+            .as_ref()
+            .map(|c| c.binding.tenant_id);
+        match tenant_id {
+            Some(tenant_id) => self.verify_tenant(tenant_id).await,
+            None => Ok(()),
         }
     }
 
@@ -111,6 +143,12 @@ impl ActivationService {
         }
     }
 
+    /// 当前缓存的订阅状态 (未激活或无订阅数据时为 None)
+    pub async fn subscription_status(&self) -> Option<SubscriptionStatus> {
+        let cache = self.credential_cache.read().await;
+        cache.as_ref()?.subscription.as_ref().map(|s| s.status)
+    }
+
     /// 检查订阅是否被阻止
     ///
     /// 阻止条件 (任一满足):
@@ -431,6 +469,10 @@ impl ActivationService {
                     cred.binding.tenant_id,
                     cred.binding.entity_id
                 );
+                if let Err(e) = self.verify_tenant(cred.binding.tenant_id).await {
+                    tracing::error!("Reloaded credential rejected: {}", e);
+                    return false;
+                }
                 let mut cache = self.credential_cache.write().await;
                 *cache = Some(cred);
                 self.notify.notify_waiters();
@@ -474,6 +516,9 @@ impl ActivationService {
             credential.binding.device_id
         );
 
+        // 0. 数据库必须属于该租户 (跨租户时连接池被关闭)
+        self.verify_tenant(credential.binding.tenant_id).await?;
+
         // 1. Save to disk
         credential
             .save(&self.work_dir)
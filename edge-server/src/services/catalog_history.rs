@@ -0,0 +1,204 @@
+//! 目录变更历史
+//!
+//! 商品 / 分类 / 标签 / 价格规则每次 CRUD 都记录一条前后快照（catalog_change 表），
+//! 用于查看实体历史和回滚到某个版本（商品、价格规则）。
+
+use serde::Serialize;
+use serde::de::DeserializeOwned;
+use shared::models::{CatalogChange, CatalogChangeAction, CatalogChangeCreate};
+use sqlx::SqlitePool;
+
+use crate::auth::CurrentUser;
+use crate::db::repository::catalog_change;
+use crate::utils::{AppError, AppResult};
+
+/// 记录一次目录变更
+///
+/// 历史写入失败不影响主操作（已提交），仅记录警告。
+pub async fn record<T: Serialize>(
+    pool: &SqlitePool,
+    entity_type: &str,
+    entity_id: i64,
+    action: CatalogChangeAction,
+    before: Option<&T>,
+    after: Option<&T>,
+    operator: Option<&CurrentUser>,
+) {
+    let data = CatalogChangeCreate {
+        entity_type: entity_type.to_string(),
+        entity_id,
+        action,
+        before_snapshot: before.and_then(|v| serde_json::to_value(v).ok()),
+        after_snapshot: after.and_then(|v| serde_json::to_value(v).ok()),
+        operator_id: operator.map(|u| u.id),
+        operator_name: operator.map(|u| u.name.clone()),
+    };
+    if let Err(e) = catalog_change::create(pool, data).await {
+        tracing::warn!(entity_type, entity_id, error = %e, "Failed to record catalog change");
+    }
+}
+
+/// 查询实体的变更历史（最新在前）
+pub async fn list(
+    pool: &SqlitePool,
+    entity_type: &str,
+    entity_id: i64,
+) -> AppResult<Vec<CatalogChange>> {
+    Ok(catalog_change::find_by_entity(pool, entity_type, entity_id).await?)
+}
+
+/// 取回滚目标版本：指定变更之后的快照
+///
+/// 变更必须属于该实体，且不能是删除记录（删除后没有可恢复的版本）。
+pub async fn version_snapshot<T: DeserializeOwned>(
+    pool: &SqlitePool,
+    entity_type: &str,
+    entity_id: i64,
+    change_id: i64,
+) -> AppResult<T> {
+    let change = catalog_change::find_by_id(pool, change_id)
+        .await?
+        .filter(|c| c.entity_type == entity_type && c.entity_id == entity_id)
+        .ok_or_else(|| AppError::not_found(format!("Catalog change {}", change_id)))?;
+
+    let snapshot = change.after_snapshot.ok_or_else(|| {
+        AppError::validation(format!(
+            "Change {} is a deletion; pick an earlier version",
+            change_id
+        ))
+    })?;
+
+    serde_json::from_value(snapshot).map_err(|e| {
+        AppError::internal(format!(
+            "Catalog change {} snapshot is not a valid {}: {}",
+            change_id, entity_type, e
+        ))
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::db::test_pool;
+    use serde::Deserialize;
+
+    #[derive(Debug, Serialize, Deserialize, PartialEq)]
+    struct Item {
+        name: String,
+        price: f64,
+    }
+
+    fn item(name: &str, price: f64) -> Item {
+        Item {
+            name: name.to_string(),
+            price,
+        }
+    }
+
+    #[tokio::test]
+    async fn test_record_and_list_history() {
+        let pool = test_pool().await;
+        let v1 = item("Cola", 2.0);
+        let v2 = item("Cola", 2.5);
+
+        record(
+            &pool,
+            "product",
+            1,
+            CatalogChangeAction::Create,
+            None,
+            Some(&v1),
+            None,
+        )
+        .await;
+        record(
+            &pool,
+            "product",
+            1,
+            CatalogChangeAction::Update,
+            Some(&v1),
+            Some(&v2),
+            None,
+        )
+        .await;
+        record(
+            &pool,
+            "product",
+            2,
+            CatalogChangeAction::Create,
+            None,
+            Some(&v1),
+            None,
+        )
+        .await;
+
+        let history = list(&pool, "product", 1).await.unwrap();
+        assert_eq!(history.len(), 2);
+        assert!(history.iter().all(|c| c.entity_id == 1));
+        let create = history
+            .iter()
+            .find(|c| c.action == CatalogChangeAction::Create)
+            .unwrap();
+        assert!(create.before_snapshot.is_none());
+        assert_eq!(
+            create.after_snapshot,
+            Some(serde_json::to_value(&v1).unwrap())
+        );
+    }
+
+    #[tokio::test]
+    async fn test_version_snapshot() {
+        let pool = test_pool().await;
+        let v1 = item("Cola", 2.0);
+        record(
+            &pool,
+            "product",
+            1,
+            CatalogChangeAction::Create,
+            None,
+            Some(&v1),
+            None,
+        )
+        .await;
+        record(
+            &pool,
+            "product",
+            1,
+            CatalogChangeAction::Delete,
+            Some(&v1),
+            None,
+            None,
+        )
+        .await;
+
+        let history = list(&pool, "product", 1).await.unwrap();
+        let create_id = history
+            .iter()
+            .find(|c| c.action == CatalogChangeAction::Create)
+            .unwrap()
+            .id;
+        let delete_id = history
+            .iter()
+            .find(|c| c.action == CatalogChangeAction::Delete)
+            .unwrap()
+            .id;
+
+        let restored: Item = version_snapshot(&pool, "product", 1, create_id)
+            .await
+            .unwrap();
+        assert_eq!(restored, v1);
+
+        // Deletion has no version to restore
+        assert!(
+            version_snapshot::<Item>(&pool, "product", 1, delete_id)
+                .await
+                .is_err()
+        );
+        // Change belongs to another entity
+        assert!(
+            version_snapshot::<Item>(&pool, "price_rule", 1, create_id)
+                .await
+                .is_err()
+        );
+    }
+}
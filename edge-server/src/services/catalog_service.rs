@@ -8,15 +8,17 @@
 //! - PriceRuleEngine DB queries
 
 use super::ImageCleanupService;
-use crate::db::repository::{RepoError, RepoResult, attribute, image_ref};
+use crate::db::repository::{RepoError, RepoResult, attribute, eighty_six, image_ref, zone};
 use parking_lot::RwLock;
 use shared::error::ErrorCode;
 use shared::models::{
-    AttributeBindingFull, Category, CategoryCreate, CategoryUpdate, ImageRefEntityType, Product,
-    ProductCreate, ProductFull, ProductSpec, ProductUpdate, Tag,
+    AdjustmentType, AttributeBindingFull, Category, CategoryCreate, CategoryUpdate, EffectiveRoute,
+    EightySix, EightySixCreate, ImageRefEntityType, PriceRule, Product, ProductCreate, ProductFull,
+    ProductScope, ProductSpec, ProductUpdate, RoutingMatrixRow, RoutingSource, RuleType, Tag,
+    ZoneProductOverride, ZoneProductOverrideUpsert,
 };
 use sqlx::SqlitePool;
-use std::collections::HashMap;
+use std::collections::{HashMap, HashSet};
 use std::sync::Arc;
 
 // =============================================================================
@@ -83,6 +85,22 @@ fn resolve_print_enabled(product_flag: i32, category_flag: Option<bool>) -> bool
     }
 }
 
+/// Destination fallback chain: product overrides > category destinations > global default
+fn route_destinations(
+    product_dests: &[i64],
+    category_dests: Option<&[i64]>,
+    default_dest: Option<&str>,
+) -> (Vec<i64>, RoutingSource) {
+    if !product_dests.is_empty() {
+        return (product_dests.to_vec(), RoutingSource::Product);
+    }
+    if let Some(dests) = category_dests.filter(|d| !d.is_empty()) {
+        return (dests.to_vec(), RoutingSource::Category);
+    }
+    let default = default_dest.and_then(|d| d.parse::<i64>().ok());
+    (default.into_iter().collect(), RoutingSource::Default)
+}
+
 // =============================================================================
 // CatalogService
 // =============================================================================
@@ -97,6 +115,10 @@ pub struct CatalogService {
     categories: Arc<RwLock<HashMap<i64, Category>>>,
     /// System default print destinations
     print_defaults: Arc<RwLock<PrintDefaults>>,
+    /// Zone product overrides: zone_id -> (product_id -> override)
+    zone_overrides: Arc<RwLock<HashMap<i64, HashMap<i64, ZoneProductOverride>>>>,
+    /// Open 86 entries: id -> entry (expired ones are ignored on read)
+    eighty_sixed: Arc<RwLock<HashMap<i64, EightySix>>>,
     /// Image cleanup service
     image_cleanup: ImageCleanupService,
 }
@@ -123,6 +145,8 @@ impl CatalogService {
             products: Arc::new(RwLock::new(HashMap::new())),
             categories: Arc::new(RwLock::new(HashMap::new())),
             print_defaults: Arc::new(RwLock::new(PrintDefaults::default())),
+            zone_overrides: Arc::new(RwLock::new(HashMap::new())),
+            eighty_sixed: Arc::new(RwLock::new(HashMap::new())),
         }
     }
 
@@ -130,6 +154,8 @@ impl CatalogService {
     pub fn invalidate(&self) {
         self.products.write().clear();
         self.categories.write().clear();
+        self.zone_overrides.write().clear();
+        self.eighty_sixed.write().clear();
     }
 
     // =========================================================================
@@ -281,6 +307,8 @@ impl CatalogService {
                 }
             }
 
+            let print_dests = self.load_product_print_dests(product_id).await;
+
             let full = ProductFull {
                 id: product.id,
                 name: product.name,
@@ -292,6 +320,8 @@ impl CatalogService {
                 kitchen_print_name: product.kitchen_print_name,
                 is_kitchen_print_enabled: product.is_kitchen_print_enabled,
                 is_label_print_enabled: product.is_label_print_enabled,
+                kitchen_print_destinations: print_dests.0,
+                label_print_destinations: print_dests.1,
                 is_active: product.is_active,
                 external_id: product.external_id,
                 specs,
@@ -401,6 +431,26 @@ impl CatalogService {
             }
         }
 
+        // 7. Load zone product overrides
+        let overrides = zone::find_all_overrides(&self.pool).await?;
+        let mut by_zone: HashMap<i64, HashMap<i64, ZoneProductOverride>> = HashMap::new();
+        for o in overrides {
+            by_zone
+                .entry(o.zone_id)
+                .or_default()
+                .insert(o.product_id, o);
+        }
+        tracing::debug!(
+            zones = by_zone.len(),
+            "CatalogService loaded zone overrides"
+        );
+        *self.zone_overrides.write() = by_zone;
+
+        // 8. Load open 86 entries
+        let entries = eighty_six::find_active(&self.pool, shared::util::now_millis()).await?;
+        tracing::debug!(count = entries.len(), "CatalogService loaded 86 entries");
+        *self.eighty_sixed.write() = entries.into_iter().map(|e| (e.id, e)).collect();
+
         Ok(())
     }
 
@@ -613,6 +663,17 @@ impl CatalogService {
             }
         }
 
+        // Insert print destination overrides
+        if !data.kitchen_print_destinations.is_empty() || !data.label_print_destinations.is_empty()
+        {
+            self.replace_product_print_dests(
+                product_id,
+                &data.kitchen_print_destinations,
+                &data.label_print_destinations,
+            )
+            .await?;
+        }
+
         // Fetch the created product with all relations
         let full = self.fetch_product_full(product_id).await?;
 
@@ -653,7 +714,14 @@ impl CatalogService {
             || data.is_active.is_some()
             || data.external_id.is_some();
 
-        if !has_scalar_updates && data.tags.is_none() && data.specs.is_none() {
+        let has_print_dest_updates =
+            data.kitchen_print_destinations.is_some() || data.label_print_destinations.is_some();
+
+        if !has_scalar_updates
+            && !has_print_dest_updates
+            && data.tags.is_none()
+            && data.specs.is_none()
+        {
             return self
                 .get_product(id)
                 .ok_or_else(|| RepoError::NotFound(format!("Product {} not found", id)));
@@ -729,6 +797,25 @@ impl CatalogService {
             }
         }
 
+        // Replace print destination overrides if either kitchen or label changed
+        if has_print_dest_updates {
+            let existing = self.get_product(id);
+            let kitchen_dests = data.kitchen_print_destinations.clone().unwrap_or_else(|| {
+                existing
+                    .as_ref()
+                    .map(|p| p.kitchen_print_destinations.clone())
+                    .unwrap_or_default()
+            });
+            let label_dests = data.label_print_destinations.clone().unwrap_or_else(|| {
+                existing
+                    .as_ref()
+                    .map(|p| p.label_print_destinations.clone())
+                    .unwrap_or_default()
+            });
+            self.replace_product_print_dests(id, &kitchen_dests, &label_dests)
+                .await?;
+        }
+
         // Replace specs if provided
         if let Some(ref specs) = data.specs {
             sqlx::query!("DELETE FROM product_spec WHERE product_id = ?", id)
@@ -831,6 +918,12 @@ impl CatalogService {
             .execute(&self.pool)
             .await?;
 
+        // Clean up print destination overrides
+        sqlx::query("DELETE FROM product_print_dest WHERE product_id = ?")
+            .bind(id)
+            .execute(&self.pool)
+            .await?;
+
         // Delete specs
         sqlx::query!("DELETE FROM product_spec WHERE product_id = ?", id)
             .execute(&self.pool)
@@ -908,6 +1001,44 @@ impl CatalogService {
         Ok(full)
     }
 
+    /// Load product print destination overrides as (kitchen, label)
+    async fn load_product_print_dests(&self, product_id: i64) -> (Vec<i64>, Vec<i64>) {
+        let load = |purpose: &'static str| {
+            sqlx::query_scalar::<_, i64>(
+                "SELECT ppd.print_destination_id FROM product_print_dest ppd JOIN print_destination pd ON pd.id = ppd.print_destination_id WHERE ppd.product_id = ? AND pd.purpose = ?",
+            )
+            .bind(product_id)
+            .bind(purpose)
+            .fetch_all(&self.pool)
+        };
+        let kitchen = load("kitchen").await.unwrap_or_default();
+        let label = load("label").await.unwrap_or_default();
+        (kitchen, label)
+    }
+
+    /// Replace product print destination overrides (unified junction table)
+    async fn replace_product_print_dests(
+        &self,
+        product_id: i64,
+        kitchen: &[i64],
+        label: &[i64],
+    ) -> RepoResult<()> {
+        sqlx::query("DELETE FROM product_print_dest WHERE product_id = ?")
+            .bind(product_id)
+            .execute(&self.pool)
+            .await?;
+        for dest_id in kitchen.iter().chain(label.iter()) {
+            sqlx::query(
+                "INSERT OR IGNORE INTO product_print_dest (product_id, print_destination_id) VALUES (?, ?)",
+            )
+            .bind(product_id)
+            .bind(dest_id)
+            .execute(&self.pool)
+            .await?;
+        }
+        Ok(())
+    }
+
     /// Fetch full product data from DB (helper)
     async fn fetch_product_full(&self, product_id: i64) -> RepoResult<ProductFull> {
         // Fetch product
@@ -971,6 +1102,8 @@ impl CatalogService {
             }
         }
 
+        let print_dests = self.load_product_print_dests(product_id).await;
+
         Ok(ProductFull {
             id: product.id,
             name: product.name,
@@ -982,6 +1115,8 @@ impl CatalogService {
             kitchen_print_name: product.kitchen_print_name,
             is_kitchen_print_enabled: product.is_kitchen_print_enabled,
             is_label_print_enabled: product.is_label_print_enabled,
+            kitchen_print_destinations: print_dests.0,
+            label_print_destinations: print_dests.1,
             is_active: product.is_active,
             external_id: product.external_id,
             specs,
@@ -1001,6 +1136,222 @@ impl CatalogService {
         hashes
     }
 
+    // =========================================================================
+    // Zone Overrides
+    // =========================================================================
+
+    /// List overrides configured for a zone (from cache)
+    pub fn get_zone_overrides(&self, zone_id: i64) -> Vec<ZoneProductOverride> {
+        let cache = self.zone_overrides.read();
+        let mut overrides: Vec<_> = cache
+            .get(&zone_id)
+            .map(|m| m.values().cloned().collect())
+            .unwrap_or_default();
+        overrides.sort_by_key(|o| o.product_id);
+        overrides
+    }
+
+    /// Products that cannot be ordered in a zone
+    pub fn zone_unavailable_products(&self, zone_id: i64) -> HashSet<i64> {
+        let cache = self.zone_overrides.read();
+        cache
+            .get(&zone_id)
+            .map(|m| {
+                m.values()
+                    .filter(|o| !o.is_available)
+                    .map(|o| o.product_id)
+                    .collect()
+            })
+            .unwrap_or_default()
+    }
+
+    /// Resolve zone price overrides into product-scoped price rules
+    ///
+    /// 覆盖价针对默认规格：与目录价的差额转为固定金额加价/折扣规则，
+    /// 随开台写入规则缓存，其他规格按同一差额调整。
+    pub fn zone_price_rules(&self, zone_id: i64) -> Vec<PriceRule> {
+        let overrides = self.zone_overrides.read();
+        let Some(zone_overrides) = overrides.get(&zone_id) else {
+            return vec![];
+        };
+        let products = self.products.read();
+        let mut rules: Vec<PriceRule> = zone_overrides
+            .values()
+            .filter_map(|o| {
+                let price = o.price?;
+                let product = products.get(&o.product_id)?;
+                let base = product
+                    .specs
+                    .iter()
+                    .find(|s| s.is_default)
+                    .or_else(|| product.specs.iter().find(|s| s.is_root))
+                    .or_else(|| product.specs.first())?
+                    .price;
+                let diff = price - base;
+                if diff.abs() < 0.005 {
+                    return None;
+                }
+                Some(PriceRule {
+                    id: o.id,
+                    name: format!("Zone price · {}", product.name),
+                    receipt_name: None,
+                    description: None,
+                    rule_type: if diff > 0.0 {
+                        RuleType::Surcharge
+                    } else {
+                        RuleType::Discount
+                    },
+                    product_scope: ProductScope::Product,
+                    target_id: Some(o.product_id),
+                    zone_scope: zone_id.to_string(),
+                    adjustment_type: AdjustmentType::FixedAmount,
+                    adjustment_value: diff.abs(),
+                    is_stackable: true,
+                    is_exclusive: false,
+                    priority: 0,
+                    valid_from: None,
+                    valid_until: None,
+                    active_days: None,
+                    active_start_time: None,
+                    active_end_time: None,
+                    is_active: true,
+                    created_by: None,
+                    created_at: 0,
+                })
+            })
+            .collect();
+        rules.sort_by_key(|r| r.id);
+        rules
+    }
+
+    /// Create or replace a zone product override (DB first, then cache)
+    pub async fn upsert_zone_override(
+        &self,
+        zone_id: i64,
+        product_id: i64,
+        data: ZoneProductOverrideUpsert,
+    ) -> RepoResult<ZoneProductOverride> {
+        if !self.products.read().contains_key(&product_id) {
+            return Err(RepoError::NotFound(format!(
+                "Product {product_id} not found"
+            )));
+        }
+        let o = zone::upsert_override(&self.pool, zone_id, product_id, data).await?;
+        self.zone_overrides
+            .write()
+            .entry(zone_id)
+            .or_default()
+            .insert(product_id, o.clone());
+        Ok(o)
+    }
+
+    /// Remove a zone product override (DB first, then cache)
+    ///
+    /// Returns the removed override stamped with the deletion time.
+    pub async fn delete_zone_override(
+        &self,
+        zone_id: i64,
+        product_id: i64,
+    ) -> RepoResult<Option<ZoneProductOverride>> {
+        let removed = zone::delete_override(&self.pool, zone_id, product_id).await?;
+        if let Some(m) = self.zone_overrides.write().get_mut(&zone_id) {
+            m.remove(&product_id);
+        }
+        let now = shared::util::now_millis();
+        Ok(removed.map(|o| ZoneProductOverride {
+            updated_at: now,
+            ..o
+        }))
+    }
+
+    /// Drop cached overrides of a deleted zone (DB rows cascade with the zone)
+    ///
+    /// Returns the dropped overrides stamped with the deletion time.
+    pub fn clear_zone_overrides(&self, zone_id: i64) -> Vec<ZoneProductOverride> {
+        let now = shared::util::now_millis();
+        self.zone_overrides
+            .write()
+            .remove(&zone_id)
+            .map(|m| {
+                m.into_values()
+                    .map(|o| ZoneProductOverride {
+                        updated_at: now,
+                        ..o
+                    })
+                    .collect()
+            })
+            .unwrap_or_default()
+    }
+
+    // =========================================================================
+    // 86 Board
+    // =========================================================================
+
+    /// Entries blocking orders right now (from cache)
+    pub fn list_eighty_sixed(&self) -> Vec<EightySix> {
+        let now = shared::util::now_millis();
+        let cache = self.eighty_sixed.read();
+        let mut entries: Vec<_> = cache
+            .values()
+            .filter(|e| e.is_active(now))
+            .cloned()
+            .collect();
+        entries.sort_by_key(|e| e.created_at);
+        entries
+    }
+
+    /// Active entry blocking the product/spec, if any
+    pub fn find_eighty_sixed(&self, product_id: i64, spec_id: Option<i64>) -> Option<EightySix> {
+        let now = shared::util::now_millis();
+        let cache = self.eighty_sixed.read();
+        cache
+            .values()
+            .find(|e| e.is_active(now) && e.covers(product_id, spec_id))
+            .cloned()
+    }
+
+    /// 86 a product or spec (DB first, then cache)
+    pub async fn eighty_six(
+        &self,
+        data: EightySixCreate,
+        created_by_id: i64,
+        created_by_name: &str,
+    ) -> RepoResult<EightySix> {
+        let product = self
+            .get_product(data.product_id)
+            .ok_or_else(|| RepoError::NotFound(format!("Product {} not found", data.product_id)))?;
+        let spec_name = match data.spec_id {
+            Some(spec_id) => Some(
+                product
+                    .specs
+                    .iter()
+                    .find(|s| s.id == spec_id)
+                    .ok_or_else(|| RepoError::NotFound(format!("Spec {spec_id} not found")))?
+                    .name
+                    .clone(),
+            ),
+            None => None,
+        };
+        let entry = eighty_six::create(
+            &self.pool,
+            &data,
+            &product.name,
+            spec_name.as_deref(),
+            created_by_id,
+            created_by_name,
+        )
+        .await?;
+        self.eighty_sixed.write().insert(entry.id, entry.clone());
+        Ok(entry)
+    }
+
+    /// Put an 86'd product back on sale (DB first, then cache)
+    pub async fn clear_eighty_six(&self, id: i64, cleared_by_name: &str) -> RepoResult<EightySix> {
+        let entry = eighty_six::clear(&self.pool, id, cleared_by_name).await?;
+        self.eighty_sixed.write().remove(&id);
+        Ok(entry)
+    }
+
     // =========================================================================
     // Category - Read (from cache)
     // =========================================================================
@@ -1399,12 +1750,14 @@ impl CatalogService {
 
         let cat_dests = real_category.map(|c| &c.kitchen_print_destinations);
         let destinations = self.resolve_destinations(
+            &product.kitchen_print_destinations,
             cat_dests,
             |d| d.kitchen_destination.as_deref(),
         );
 
         tracing::info!(
             product_id,
+            product_dests = product.kitchen_print_destinations.len(),
             category_dests = ?cat_dests.map(|d| d.len()),
             global_default = ?defaults.kitchen_destination,
             resolved_destinations = ?destinations,
@@ -1460,12 +1813,14 @@ impl CatalogService {
 
         let cat_dests = real_category.map(|c| &c.label_print_destinations);
         let destinations = self.resolve_destinations(
+            &product.label_print_destinations,
             cat_dests,
             |d| d.label_destination.as_deref(),
         );
 
         tracing::info!(
             product_id,
+            product_dests = product.label_print_destinations.len(),
             category_dests = ?cat_dests.map(|d| d.len()),
             global_default = ?defaults.label_destination,
             resolved_destinations = ?destinations,
@@ -1478,28 +1833,90 @@ impl CatalogService {
         })
     }
 
-    /// Resolve print destinations: category destinations > global default
+    /// Resolve print destinations: product overrides > category destinations > global default
     fn resolve_destinations(
         &self,
+        product_dests: &[i64],
         category_dests: Option<&Vec<i64>>,
         get_default: impl FnOnce(&PrintDefaults) -> Option<&str>,
     ) -> Vec<String> {
-        if let Some(dests) = category_dests.filter(|d| !d.is_empty()) {
-            tracing::debug!(
-                dests = ?dests,
-                "resolve_destinations: using category-specific destinations"
-            );
-            dests.iter().map(|id| id.to_string()).collect()
-        } else {
-            let defaults = self.print_defaults.read();
-            let default_dest = get_default(&defaults);
-            tracing::debug!(
-                category_dests = ?category_dests,
-                default_dest = ?default_dest,
-                "resolve_destinations: category dests empty/none, falling back to global default"
-            );
-            default_dest.into_iter().map(String::from).collect()
-        }
+        let defaults = self.print_defaults.read();
+        let (dests, source) = route_destinations(
+            product_dests,
+            category_dests.map(Vec::as_slice),
+            get_default(&defaults),
+        );
+        tracing::debug!(dests = ?dests, source = ?source, "resolve_destinations: resolved");
+        dests.iter().map(|id| id.to_string()).collect()
+    }
+
+    /// Effective kitchen/label routing of every active product (routing matrix)
+    ///
+    /// Same fallback chain as [`Self::get_kitchen_print_config`] /
+    /// [`Self::get_label_print_config`], ordered by category then product sort order.
+    pub fn routing_matrix(&self) -> Vec<RoutingMatrixRow> {
+        let defaults = self.print_defaults.read().clone();
+        let products = self.products.read();
+        let categories = self.categories.read();
+
+        let route = |global_enabled: bool,
+                     product_flag: i32,
+                     category_flag: Option<bool>,
+                     product_dests: &[i64],
+                     category_dests: Option<&[i64]>,
+                     default_dest: Option<&str>| {
+            if !global_enabled || !resolve_print_enabled(product_flag, category_flag) {
+                return EffectiveRoute {
+                    enabled: false,
+                    destinations: vec![],
+                    source: RoutingSource::Disabled,
+                };
+            }
+            let (destinations, source) =
+                route_destinations(product_dests, category_dests, default_dest);
+            EffectiveRoute {
+                enabled: true,
+                destinations,
+                source,
+            }
+        };
+
+        let mut rows: Vec<(i32, i32, RoutingMatrixRow)> = products
+            .values()
+            .filter(|p| p.is_active)
+            .map(|p| {
+                let category = categories.get(&p.category_id).filter(|c| !c.is_virtual);
+                let row = RoutingMatrixRow {
+                    product_id: p.id,
+                    product_name: p.name.clone(),
+                    category_id: p.category_id,
+                    category_name: category.map(|c| c.name.clone()).unwrap_or_default(),
+                    kitchen: route(
+                        defaults.kitchen_enabled,
+                        p.is_kitchen_print_enabled,
+                        category.map(|c| c.is_kitchen_print_enabled),
+                        &p.kitchen_print_destinations,
+                        category.map(|c| c.kitchen_print_destinations.as_slice()),
+                        defaults.kitchen_destination.as_deref(),
+                    ),
+                    label: route(
+                        defaults.label_enabled,
+                        p.is_label_print_enabled,
+                        category.map(|c| c.is_label_print_enabled),
+                        &p.label_print_destinations,
+                        category.map(|c| c.label_print_destinations.as_slice()),
+                        defaults.label_destination.as_deref(),
+                    ),
+                };
+                (
+                    category.map_or(i32::MAX, |c| c.sort_order),
+                    p.sort_order,
+                    row,
+                )
+            })
+            .collect();
+        rows.sort_by_key(|a| (a.0, a.1, a.2.product_id));
+        rows.into_iter().map(|(_, _, row)| row).collect()
     }
 
     /// Check if kitchen printing is enabled (system level — global toggle)
@@ -1512,3 +1929,230 @@ impl CatalogService {
         self.print_defaults.read().label_enabled
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use sqlx::sqlite::SqlitePoolOptions;
+
+    fn test_catalog() -> CatalogService {
+        let pool = SqlitePoolOptions::new()
+            .connect_lazy("sqlite::memory:")
+            .unwrap();
+        CatalogService::new(pool, std::env::temp_dir())
+    }
+
+    fn product_with_price(id: i64, price: f64) -> ProductFull {
+        ProductFull {
+            id,
+            name: format!("P{id}"),
+            image: String::new(),
+            category_id: 1,
+            sort_order: 0,
+            tax_rate: 10,
+            receipt_name: None,
+            kitchen_print_name: None,
+            is_kitchen_print_enabled: -1,
+            is_label_print_enabled: -1,
+            kitchen_print_destinations: vec![],
+            label_print_destinations: vec![],
+            is_active: true,
+            external_id: None,
+            specs: vec![ProductSpec {
+                id: id * 10,
+                product_id: id,
+                name: "Default".to_string(),
+                price,
+                display_order: 0,
+                is_default: true,
+                is_active: true,
+                receipt_name: None,
+                is_root: true,
+            }],
+            attributes: vec![],
+            tags: vec![],
+        }
+    }
+
+    fn zone_override(
+        id: i64,
+        product_id: i64,
+        price: Option<f64>,
+        is_available: bool,
+    ) -> ZoneProductOverride {
+        ZoneProductOverride {
+            id,
+            zone_id: 7,
+            product_id,
+            price,
+            is_available,
+            updated_at: 0,
+        }
+    }
+
+    #[tokio::test]
+    async fn test_zone_price_rules_from_overrides() {
+        let catalog = test_catalog();
+        {
+            let mut products = catalog.products.write();
+            products.insert(1, product_with_price(1, 3.0));
+            products.insert(2, product_with_price(2, 5.0));
+            products.insert(3, product_with_price(3, 4.0));
+        }
+        {
+            let mut overrides = catalog.zone_overrides.write();
+            let zone = overrides.entry(7).or_default();
+            zone.insert(1, zone_override(101, 1, Some(3.5), true));
+            zone.insert(2, zone_override(102, 2, Some(4.0), true));
+            // 仅可售覆盖，无价格 → 不生成规则
+            zone.insert(3, zone_override(103, 3, None, false));
+        }
+
+        let rules = catalog.zone_price_rules(7);
+        assert_eq!(rules.len(), 2);
+        assert_eq!(rules[0].rule_type, RuleType::Surcharge);
+        assert_eq!(rules[0].target_id, Some(1));
+        assert_eq!(rules[0].zone_scope, "7");
+        assert!((rules[0].adjustment_value - 0.5).abs() < 1e-9);
+        assert_eq!(rules[1].rule_type, RuleType::Discount);
+        assert!((rules[1].adjustment_value - 1.0).abs() < 1e-9);
+
+        assert!(catalog.zone_price_rules(8).is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_zone_unavailable_products() {
+        let catalog = test_catalog();
+        {
+            let mut overrides = catalog.zone_overrides.write();
+            let zone = overrides.entry(7).or_default();
+            zone.insert(1, zone_override(101, 1, Some(3.5), true));
+            zone.insert(3, zone_override(103, 3, None, false));
+        }
+
+        let unavailable = catalog.zone_unavailable_products(7);
+        assert_eq!(unavailable, HashSet::from([3]));
+        assert!(catalog.zone_unavailable_products(8).is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_clear_zone_overrides_returns_removed() {
+        let catalog = test_catalog();
+        {
+            let mut overrides = catalog.zone_overrides.write();
+            let zone = overrides.entry(7).or_default();
+            zone.insert(1, zone_override(101, 1, Some(3.5), true));
+            zone.insert(3, zone_override(103, 3, None, false));
+        }
+
+        let mut removed = catalog.clear_zone_overrides(7);
+        removed.sort_by_key(|o| o.id);
+        assert_eq!(
+            removed.iter().map(|o| o.id).collect::<Vec<_>>(),
+            vec![101, 103]
+        );
+        // 删除时间戳用于云端 LWW 删除
+        assert!(removed.iter().all(|o| o.updated_at > 0));
+        assert!(catalog.get_zone_overrides(7).is_empty());
+        assert!(catalog.clear_zone_overrides(7).is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_find_eighty_sixed_skips_expired() {
+        let catalog = test_catalog();
+        let now = shared::util::now_millis();
+        let entry =
+            |id: i64, product_id: i64, spec_id: Option<i64>, expires_at: Option<i64>| EightySix {
+                id,
+                product_id,
+                product_name: format!("P{product_id}"),
+                spec_id,
+                spec_name: None,
+                reason: None,
+                expires_at,
+                created_by_id: 1,
+                created_by_name: "Ana".to_string(),
+                created_at: id,
+                cleared_at: None,
+                cleared_by_name: None,
+            };
+        {
+            let mut cache = catalog.eighty_sixed.write();
+            cache.insert(1, entry(1, 1, None, None));
+            cache.insert(2, entry(2, 2, Some(20), None));
+            // 已过期 → 不再拦截
+            cache.insert(3, entry(3, 3, None, Some(now - 1)));
+        }
+
+        assert_eq!(
+            catalog.find_eighty_sixed(1, Some(10)).map(|e| e.id),
+            Some(1)
+        );
+        assert_eq!(
+            catalog.find_eighty_sixed(2, Some(20)).map(|e| e.id),
+            Some(2)
+        );
+        assert!(catalog.find_eighty_sixed(2, Some(21)).is_none());
+        assert!(catalog.find_eighty_sixed(3, None).is_none());
+        assert_eq!(catalog.list_eighty_sixed().len(), 2);
+    }
+
+    #[tokio::test]
+    async fn test_routing_matrix_fallback_chain() {
+        let catalog = test_catalog();
+        catalog.set_print_defaults(true, Some("9".to_string()), false, None);
+        {
+            let mut categories = catalog.categories.write();
+            let category = |id: i64, dests: Vec<i64>| Category {
+                id,
+                name: format!("C{id}"),
+                sort_order: id as i32,
+                is_kitchen_print_enabled: true,
+                is_label_print_enabled: true,
+                is_active: true,
+                is_virtual: false,
+                match_mode: "any".to_string(),
+                is_display: true,
+                kitchen_print_destinations: dests,
+                label_print_destinations: vec![],
+                tag_ids: vec![],
+            };
+            categories.insert(1, category(1, vec![5]));
+            categories.insert(2, category(2, vec![]));
+        }
+        {
+            let mut products = catalog.products.write();
+            let mut overridden = product_with_price(1, 3.0);
+            overridden.kitchen_print_destinations = vec![6, 7];
+            products.insert(1, overridden);
+            products.insert(2, product_with_price(2, 3.0));
+            let mut uncategorized = product_with_price(3, 3.0);
+            uncategorized.category_id = 2;
+            products.insert(3, uncategorized);
+            let mut disabled = product_with_price(4, 3.0);
+            disabled.is_kitchen_print_enabled = 0;
+            products.insert(4, disabled);
+        }
+
+        let rows = catalog.routing_matrix();
+        let kitchen = |id: i64| &rows.iter().find(|r| r.product_id == id).unwrap().kitchen;
+        assert_eq!(kitchen(1).source, RoutingSource::Product);
+        assert_eq!(kitchen(1).destinations, vec![6, 7]);
+        assert_eq!(kitchen(2).source, RoutingSource::Category);
+        assert_eq!(kitchen(2).destinations, vec![5]);
+        assert_eq!(kitchen(3).source, RoutingSource::Default);
+        assert_eq!(kitchen(3).destinations, vec![9]);
+        assert_eq!(kitchen(4).source, RoutingSource::Disabled);
+        // 全局标签打印关闭
+        assert!(
+            rows.iter()
+                .all(|r| r.label.source == RoutingSource::Disabled)
+        );
+        // 分类排序在前
+        assert_eq!(rows.last().unwrap().product_id, 3);
+
+        // 实际打印解析与矩阵一致
+        let config = catalog.get_kitchen_print_config(1).unwrap();
+        assert_eq!(config.destinations, vec!["6".to_string(), "7".to_string()]);
+    }
+}
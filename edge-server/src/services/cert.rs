@@ -28,12 +28,23 @@ use crate::utils::AppError;
 pub struct CertService {
     /// 工作目录
     work_dir: PathBuf,
+    /// 强制客户端证书 (false 时无证书客户端也可握手，仅 dev Profile)
+    require_client_cert: bool,
 }
 
 impl CertService {
     /// 创建证书服务
     pub fn new(work_dir: PathBuf) -> Self {
-        Self { work_dir }
+        Self {
+            work_dir,
+            require_client_cert: true,
+        }
+    }
+
+    /// 设置是否强制客户端证书 (`Config.require_client_cert`)
+    pub fn with_client_cert_required(mut self, required: bool) -> Self {
+        self.require_client_cert = required;
+        self
     }
 
     /// 下载并保存 Root CA 证书
@@ -184,12 +195,15 @@ impl CertService {
             })?;
         }
 
-        let client_auth =
-            rustls::server::WebPkiClientVerifier::builder(Arc::new(client_auth_roots))
-                .build()
-                .map_err(|e| {
-                    AppError::internal(format!("Failed to build client verifier: {}", e))
-                })?;
+        let mut verifier =
+            rustls::server::WebPkiClientVerifier::builder(Arc::new(client_auth_roots));
+        if !self.require_client_cert {
+            tracing::warn!("mTLS client certificates are optional (dev profile)");
+            verifier = verifier.allow_unauthenticated();
+        }
+        let client_auth = verifier
+            .build()
+            .map_err(|e| AppError::internal(format!("Failed to build client verifier: {}", e)))?;
 
         // 2. Load server cert and key
         let cert_pem = fs::read_to_string(&edge_cert_path)
@@ -211,6 +225,25 @@ impl CertService {
         Ok(Some(Arc::new(config)))
     }
 
+    /// 用边缘服务器证书对内容签名 (审计包等对外证明材料)
+    ///
+    /// 未激活 (证书不存在) 时返回错误。
+    pub fn sign_bundle(&self, content: String) -> Result<crab_cert::SignedBundle, AppError> {
+        use std::fs;
+
+        let certs_dir = self.work_dir.join("certs");
+        let read = |name: &str| {
+            fs::read_to_string(certs_dir.join(name))
+                .map_err(|e| AppError::internal(format!("Failed to read {}: {}", name, e)))
+        };
+        let tenant_ca_pem = read("tenant_ca.pem")?;
+        let cert_pem = read("server.pem")?;
+        let key_pem = read("server.key.pem")?;
+
+        crab_cert::SignedBundle::sign(content, &key_pem, &cert_pem, &tenant_ca_pem)
+            .map_err(|e| AppError::internal(format!("Failed to sign bundle: {}", e)))
+    }
+
     pub fn delete_certificates(&self) -> Result<(), AppError> {
         let certs_dir = self.work_dir.join("certs");
         if certs_dir.exists() {
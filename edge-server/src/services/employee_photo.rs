@@ -0,0 +1,147 @@
+//! 员工照片 (防代打卡)
+//!
+//! 登录 / 主管授权响应内联返回使用该凭据的员工照片，POS 显示其面孔供现场核对。
+//!
+//! ```text
+//! {work_dir}/employee_photos/{sha256}.jpg
+//! ```
+//!
+//! - 上传时裁剪为正方形缩略图并重新编码为 JPEG (去除 EXIF 等元数据)
+//! - 与商品图片分开存放，只通过员工接口 (带权限检查) 访问
+
+use std::io::Cursor;
+use std::path::PathBuf;
+
+use base64::Engine;
+use image::imageops::FilterType;
+use sha2::{Digest, Sha256};
+
+use crate::utils::{AppError, AppResult};
+
+/// 上传原图上限
+pub const MAX_EMPLOYEE_PHOTO_UPLOAD_SIZE: usize = 10 * 1024 * 1024;
+
+/// 缩略图边长 (像素)
+const PHOTO_SIZE: u32 = 256;
+
+/// 缩略图 JPEG 质量 (内联在登录响应中，控制体积)
+const PHOTO_JPEG_QUALITY: u8 = 80;
+
+/// 员工照片目录
+#[derive(Debug, Clone)]
+pub struct EmployeePhotoService {
+    dir: PathBuf,
+}
+
+impl EmployeePhotoService {
+    pub fn new(dir: impl Into<PathBuf>) -> Self {
+        Self { dir: dir.into() }
+    }
+
+    fn path(&self, hash: &str) -> Option<PathBuf> {
+        // 只接受 hex SHA-256，防止路径穿越
+        (hash.len() == 64 && hash.bytes().all(|b| b.is_ascii_hexdigit()))
+            .then(|| self.dir.join(format!("{hash}.jpg")))
+    }
+
+    /// 处理并保存照片，返回内容哈希
+    pub async fn store(&self, data: &[u8]) -> AppResult<String> {
+        if data.is_empty() || data.len() > MAX_EMPLOYEE_PHOTO_UPLOAD_SIZE {
+            return Err(AppError::validation(format!(
+                "Employee photo must be 1..={MAX_EMPLOYEE_PHOTO_UPLOAD_SIZE} bytes"
+            )));
+        }
+        let data = data.to_vec();
+        let jpeg = tokio::task::spawn_blocking(move || thumbnail(&data))
+            .await
+            .map_err(|e| AppError::internal(format!("Photo processing task failed: {e}")))??;
+
+        let hash = hex::encode(Sha256::digest(&jpeg));
+        let Some(path) = self.path(&hash) else {
+            return Err(AppError::internal("Invalid photo hash"));
+        };
+        tokio::fs::create_dir_all(&self.dir)
+            .await
+            .map_err(|e| AppError::internal(format!("Create employee photo dir: {e}")))?;
+        if !path.exists() {
+            tokio::fs::write(&path, &jpeg)
+                .await
+                .map_err(|e| AppError::internal(format!("Write employee photo: {e}")))?;
+        }
+        Ok(hash)
+    }
+
+    /// 读取照片 (不存在返回 None)
+    pub async fn read(&self, hash: &str) -> Option<Vec<u8>> {
+        tokio::fs::read(self.path(hash)?).await.ok()
+    }
+
+    /// 照片的 data URL (内联在登录 / 授权响应中)
+    pub async fn data_url(&self, hash: Option<&str>) -> Option<String> {
+        let bytes = self.read(hash?).await?;
+        Some(format!(
+            "data:image/jpeg;base64,{}",
+            base64::engine::general_purpose::STANDARD.encode(bytes)
+        ))
+    }
+
+    /// 删除照片文件 (调用方确认已无员工引用)
+    pub async fn remove(&self, hash: &str) {
+        if let Some(path) = self.path(hash)
+            && let Err(e) = tokio::fs::remove_file(&path).await
+            && e.kind() != std::io::ErrorKind::NotFound
+        {
+            tracing::warn!(path = %path.display(), "Failed to remove employee photo: {e}");
+        }
+    }
+}
+
+/// 居中裁剪为正方形并缩放，重新编码为 JPEG
+fn thumbnail(data: &[u8]) -> AppResult<Vec<u8>> {
+    let img = image::load_from_memory(data)
+        .map_err(|e| AppError::validation(format!("Invalid image: {e}")))?;
+    let rgb = img
+        .resize_to_fill(PHOTO_SIZE, PHOTO_SIZE, FilterType::Lanczos3)
+        .to_rgb8();
+
+    let mut buffer = Vec::new();
+    let mut cursor = Cursor::new(&mut buffer);
+    let encoder =
+        image::codecs::jpeg::JpegEncoder::new_with_quality(&mut cursor, PHOTO_JPEG_QUALITY);
+    rgb.write_with_encoder(encoder)
+        .map_err(|e| AppError::internal(format!("Encode employee photo: {e}")))?;
+    Ok(buffer)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[tokio::test]
+    async fn test_store_crops_and_reencodes() {
+        let dir = tempfile::tempdir().unwrap();
+        let service = EmployeePhotoService::new(dir.path());
+
+        let mut png = Vec::new();
+        image::RgbImage::from_pixel(640, 480, image::Rgb([200, 120, 40]))
+            .write_to(&mut Cursor::new(&mut png), image::ImageFormat::Png)
+            .unwrap();
+
+        let hash = service.store(&png).await.unwrap();
+        let stored = service.read(&hash).await.unwrap();
+        let decoded = image::load_from_memory(&stored).unwrap();
+        assert_eq!(
+            (decoded.width(), decoded.height()),
+            (PHOTO_SIZE, PHOTO_SIZE)
+        );
+
+        let url = service.data_url(Some(&hash)).await.unwrap();
+        assert!(url.starts_with("data:image/jpeg;base64,"));
+
+        assert!(service.store(b"not an image").await.is_err());
+        assert!(service.read("../main.db").await.is_none());
+
+        service.remove(&hash).await;
+        assert!(service.data_url(Some(&hash)).await.is_none());
+    }
+}
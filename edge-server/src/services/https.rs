@@ -1,5 +1,10 @@
 use crate::auth::require_auth;
-use crate::core::{Config, ServerState};
+use crate::core::listeners::DRAIN_DEADLINE;
+use crate::core::load_shed;
+use crate::core::{ListenerControl, ServerState};
+use crate::utils::{AppError, ErrorCode};
+use axum::extract::MatchedPath;
+use axum::response::IntoResponse;
 use axum::{Router, middleware};
 use axum_server::tls_rustls::RustlsConfig;
 use parking_lot::RwLock;
@@ -8,11 +13,15 @@ use std::sync::Arc;
 use tower::Service;
 use tower_http::compression::CompressionLayer;
 use tower_http::cors::CorsLayer;
+use tracing::Instrument;
 
 pub type OneshotResult =
     Result<http::Response<axum::body::Body>, Box<dyn std::error::Error + Send + Sync>>;
 
 /// HTTP 请求日志中间件
+///
+/// 每个请求一个 `http.request` span (请求头带 `traceparent` 时以其为父)，
+/// 启用 OTLP 导出时 handler 内的 span 随之导出。
 async fn log_request(
     request: http::Request<axum::body::Body>,
     next: middleware::Next,
@@ -20,46 +29,160 @@ async fn log_request(
     let method = request.method().clone();
     let uri = request.uri().clone();
 
-    let response = next.run(request).await;
+    let span = tracing::info_span!(
+        "http.request",
+        method = %method,
+        path = uri.path(),
+        status = tracing::field::Empty,
+    );
+    if let Some(parent) = request
+        .headers()
+        .get(shared::telemetry::TRACEPARENT_HEADER)
+        .and_then(|v| v.to_str().ok())
+    {
+        shared::telemetry::set_parent(&span, parent);
+    }
+
+    let response = next.run(request).instrument(span.clone()).await;
 
     let status = response.status();
+    span.record("status", status.as_u16());
 
     tracing::info!(target: "http_access", "{} {} {}", method, uri, status);
 
     response
 }
 
+/// 请求指标中间件
+///
+/// 按路由模板 (`MatchedPath`) 记录请求数与耗时，未匹配路由归为 `unmatched`。
+async fn track_metrics(
+    axum::extract::State(state): axum::extract::State<ServerState>,
+    request: http::Request<axum::body::Body>,
+    next: middleware::Next,
+) -> http::Response<axum::body::Body> {
+    let method = request.method().clone();
+    let route = request
+        .extensions()
+        .get::<MatchedPath>()
+        .map(|path| path.as_str().to_string())
+        .unwrap_or_else(|| "unmatched".to_string());
+    let started = std::time::Instant::now();
+
+    let response = next.run(request).await;
+
+    state.metrics.observe_http_request(
+        method.as_str(),
+        &route,
+        response.status().as_u16(),
+        started.elapsed(),
+    );
+    response
+}
+
+/// 预热守卫中间件
+///
+/// 目录缓存 / 订单规则预热结束前，业务 API 返回 503 (SystemBusy)，客户端重试即可。
+/// 健康检查和登录不依赖预热，始终放行。
+async fn require_warm(
+    axum::extract::State(state): axum::extract::State<ServerState>,
+    request: http::Request<axum::body::Body>,
+    next: middleware::Next,
+) -> Result<http::Response<axum::body::Body>, AppError> {
+    let path = request.uri().path();
+    let exempt = !path.starts_with("/api/") || path.starts_with("/api/auth/");
+    if !exempt && !state.readiness.is_warm() {
+        return Err(AppError::with_message(
+            ErrorCode::SystemBusy,
+            format!(
+                "Server is warming up ({:?} pending)",
+                state.readiness.pending_warmup()
+            ),
+        ));
+    }
+    Ok(next.run(request).await)
+}
+
+/// 负载保护中间件
+///
+/// 订单命令耗时超过阈值或通道排队已满时，Sync / Report 请求返回 503 (SystemBusy)
+/// 并带 `Retry-After`；订单命令及其他业务请求始终放行。
+async fn shed_load(
+    axum::extract::State(state): axum::extract::State<ServerState>,
+    request: http::Request<axum::body::Body>,
+    next: middleware::Next,
+) -> http::Response<axum::body::Body> {
+    let priority = load_shed::classify_path(request.uri().path());
+    match state.load_shedder.admit(priority).await {
+        // permit 持有到请求处理结束
+        Ok(_permit) => next.run(request).await,
+        Err(shed) => {
+            let mut response =
+                AppError::with_message(ErrorCode::SystemBusy, shed.to_string()).into_response();
+            response.headers_mut().insert(
+                http::header::RETRY_AFTER,
+                http::HeaderValue::from(shed.retry_after.as_secs()),
+            );
+            response
+        }
+    }
+}
+
 /// Build the Axum router (without state)
 pub fn build_app() -> Router<ServerState> {
-    Router::<ServerState>::new()
+    let router = Router::<ServerState>::new()
         // Core APIs
         .merge(crate::api::auth::router())
         .merge(crate::api::health::router())
+        .merge(crate::api::metrics::router())
         .merge(crate::api::role::router())
         .merge(crate::api::upload::router())
         // Data model APIs
         .merge(crate::api::tags::router())
         .merge(crate::api::categories::router())
         .merge(crate::api::products::router())
+        .merge(crate::api::eighty_six::router())
+        .merge(crate::api::inventory::router())
+        .merge(crate::api::stock_counts::router())
+        .merge(crate::api::stock_transfers::router())
         .merge(crate::api::attributes::router())
         .merge(crate::api::has_attribute::router())
         .merge(crate::api::zones::router())
         .merge(crate::api::tables::router())
         .merge(crate::api::price_rules::router())
+        .merge(crate::api::payment_surcharges::router())
+        .merge(crate::api::delivery_rules::router())
+        .merge(crate::api::pricing::router())
         .merge(crate::api::print_destinations::router())
         .merge(crate::api::print_config::router())
+        .merge(crate::api::print_routing::router())
         .merge(crate::api::employees::router())
         .merge(crate::api::orders::router())
         .merge(crate::api::kitchen_orders::router())
+        .merge(crate::api::print_jobs::router())
         .merge(crate::api::system_state::router())
         .merge(crate::api::store_info::router())
+        .merge(crate::api::receipt_archive::router())
+        .merge(crate::api::receipt_footers::router())
+        .merge(crate::api::terminal_profiles::router())
+        .merge(crate::api::customer_displays::router())
+        .merge(crate::api::display_slides::router())
         .merge(crate::api::label_template::router())
         // Membership & Marketing
         .merge(crate::api::members::router())
+        .merge(crate::api::coupons::router())
         .merge(crate::api::marketing_groups::router())
         // Operations (班次与日结)
         .merge(crate::api::shifts::router())
         .merge(crate::api::daily_reports::router())
+        .merge(crate::api::cash_denominations::router())
+        .merge(crate::api::cash_drawer::router())
+        // Event Bookings (宴会预订)
+        .merge(crate::api::event_bookings::router())
+        // Reservations (订位)
+        .merge(crate::api::reservations::router())
+        // Staff Announcements (员工公告)
+        .merge(crate::api::announcements::router())
         // Analytics (数据统计)
         .merge(crate::api::statistics::router())
         // Archive (归档验证)
@@ -76,22 +199,42 @@ pub fn build_app() -> Router<ServerState> {
         .merge(crate::api::audit_log::router())
         // System Issues (系统问题)
         .merge(crate::api::system_issues::router())
+        // Dead Letters (死信队列)
+        .merge(crate::api::dead_letters::router())
+        // Session Recording (现场问题录制)
+        .merge(crate::api::session_recording::router())
+        // Remote Support (远程支持)
+        .merge(crate::api::support::router())
+        // Recovery (异常关闭恢复报告)
+        .merge(crate::api::recovery::router())
+        // PMS (酒店挂房账)
+        .merge(crate::api::pms::router())
+        // Pickup (外卖取餐通知)
+        .merge(crate::api::pickup::router())
+        // Capabilities (功能发现)
+        .merge(crate::api::capabilities::router())
+        // Setup Wizard (首次安装向导)
+        .merge(crate::api::setup::router())
+        // Admin (运行配置检视)
+        .merge(crate::api::admin::router())
         // Data Transfer (catalog export/import)
         .merge(crate::api::data_transfer::router())
         // Sync API
-        .merge(crate::api::sync::router())
+        .merge(crate::api::sync::router());
+    // Chaos (故障注入，仅测试构建)
+    #[cfg(feature = "chaos")]
+    let router = router.merge(crate::api::chaos::router());
+    router
 }
 
-#[derive(Clone, Debug)]
+#[derive(Clone, Debug, Default)]
 pub struct HttpsService {
-    config: Config,
     router: Arc<RwLock<Option<Router>>>,
 }
 
 impl HttpsService {
-    pub fn new(config: Config) -> Self {
+    pub fn new() -> Self {
         Self {
-            config,
             router: Arc::new(RwLock::new(None)),
         }
     }
@@ -103,7 +246,12 @@ impl HttpsService {
         let app = build_app()
             // JWT 认证中间件 - 在 Router 级别应用，require_auth 内部会跳过公共路由
             // 使用 from_fn_with_state 以便中间件可以访问 ServerState
+            // 负载保护 - 认证之后执行，未认证请求不占用通道
+            .layer(middleware::from_fn_with_state(state.clone(), shed_load))
             .layer(middleware::from_fn_with_state(state.clone(), require_auth))
+            .layer(middleware::from_fn_with_state(state.clone(), require_warm))
+            // 请求指标 - 最外层，包含认证 / 预热 / 负载保护拒绝的请求
+            .layer(middleware::from_fn_with_state(state.clone(), track_metrics))
             .with_state(state)
             // Tower HTTP 中间件
             .layer(CorsLayer::permissive())
@@ -146,10 +294,15 @@ impl HttpsService {
     }
 
     /// Explicitly start the HTTPS server
+    ///
+    /// Runs until `shutdown_signal`. Port changes from `listeners` bind the
+    /// new port first, then drain the old listener within [`DRAIN_DEADLINE`];
+    /// TLS changes are swapped in place without rebinding.
     pub async fn start_server<F>(
         &self,
         tls_config: RustlsConfig,
         shutdown_signal: F,
+        listeners: ListenerControl,
     ) -> Result<(), crate::utils::AppError>
     where
         F: std::future::Future<Output = ()> + Send + 'static,
@@ -158,24 +311,98 @@ impl HttpsService {
             crate::utils::AppError::internal("HttpsService not initialized with router")
         })?;
 
-        let addr = SocketAddr::from(([0, 0, 0, 0], self.config.http_port));
-        tracing::info!("Starting HTTPS server on {}", addr);
+        let mut ports_rx = listeners.subscribe_ports();
+        let mut tls_rx = listeners.subscribe_tls();
+        let mut port = ports_rx.borrow_and_update().http_port;
+        let mut current = Self::bind(port, tls_config.clone(), app.clone())
+            .await
+            .map_err(|e| crate::utils::AppError::internal(format!("Server error: {}", e)))?;
+        let mut draining: Vec<axum_server::Handle<std::net::SocketAddr>> = Vec::new();
 
-        let handle = axum_server::Handle::new();
+        tokio::pin!(shutdown_signal);
+        loop {
+            tokio::select! {
+                _ = &mut shutdown_signal => break,
 
-        // Handle shutdown signal
-        let handle_clone = handle.clone();
-        tokio::spawn(async move {
-            shutdown_signal.await;
-            handle_clone.graceful_shutdown(Some(std::time::Duration::from_secs(2)));
-        });
+                Ok(()) = ports_rx.changed() => {
+                    let new_port = ports_rx.borrow_and_update().http_port;
+                    if new_port == port {
+                        continue;
+                    }
+                    match Self::bind(new_port, tls_config.clone(), app.clone()).await {
+                        Ok(next) => {
+                            let old = std::mem::replace(&mut current, next);
+                            old.handle.graceful_shutdown(Some(DRAIN_DEADLINE));
+                            draining.retain(|h| h.connection_count() > 0);
+                            draining.push(old.handle);
+                            tracing::info!(from = port, to = new_port, "HTTPS server rebound, draining old listener");
+                            port = new_port;
+                        }
+                        Err(e) => {
+                            tracing::error!(port = new_port, error = %e, "HTTPS rebind failed, keeping current listener");
+                        }
+                    }
+                }
 
-        axum_server::bind_rustls(addr, tls_config)
-            .handle(handle)
-            .serve(app.into_make_service())
-            .await
-            .map_err(|e| crate::utils::AppError::internal(format!("Server error: {}", e)))?;
+                Ok(()) = tls_rx.changed() => {
+                    if let Some(config) = tls_rx.borrow_and_update().clone() {
+                        tls_config.reload_from_config(config);
+                        tracing::info!("HTTPS TLS config reloaded");
+                    }
+                }
+
+                result = &mut current.task => {
+                    let message = match result {
+                        Ok(Ok(())) => "stopped unexpectedly".to_string(),
+                        Ok(Err(e)) => e.to_string(),
+                        Err(e) => e.to_string(),
+                    };
+                    return Err(crate::utils::AppError::internal(format!("Server error: {}", message)));
+                }
+            }
+        }
+
+        for handle in &draining {
+            handle.shutdown();
+        }
+        current
+            .handle
+            .graceful_shutdown(Some(std::time::Duration::from_secs(2)));
+        let _ = current.task.await;
 
         Ok(())
     }
+
+    /// Bind and serve on `port`; resolves once the listener is bound
+    async fn bind(
+        port: u16,
+        tls_config: RustlsConfig,
+        app: Router,
+    ) -> std::io::Result<RunningListener> {
+        let addr = SocketAddr::from(([0, 0, 0, 0], port));
+        tracing::info!("Starting HTTPS server on {}", addr);
+
+        let handle = axum_server::Handle::new();
+        let mut task = tokio::spawn(
+            axum_server::bind_rustls(addr, tls_config)
+                .handle(handle.clone())
+                .serve(app.into_make_service()),
+        );
+
+        // listening() 返回 None 表示绑定失败，错误从任务结果中取
+        if handle.listening().await.is_some() {
+            return Ok(RunningListener { handle, task });
+        }
+        match (&mut task).await {
+            Ok(Err(e)) => Err(e),
+            Ok(Ok(())) => Err(std::io::Error::other("listener exited before binding")),
+            Err(e) => Err(std::io::Error::other(e)),
+        }
+    }
+}
+
+/// A bound HTTPS listener and its serve task
+struct RunningListener {
+    handle: axum_server::Handle<std::net::SocketAddr>,
+    task: tokio::task::JoinHandle<std::io::Result<()>>,
 }
@@ -23,6 +23,8 @@ impl MessageBusService {
             tcp_listen_addr: format!("0.0.0.0:{}", config.message_tcp_port),
             channel_capacity: 1024,
             tls_config: None, // TLS config will be provided during start_tcp_server
+            client_queue_capacity: config.client_queue_capacity,
+            overflow_policy: config.client_queue_overflow,
         };
 
         Self {
@@ -43,10 +45,11 @@ impl MessageBusService {
         credential_cache: std::sync::Arc<
             tokio::sync::RwLock<Option<crate::services::tenant_binding::TenantBinding>>,
         >,
+        listeners: crate::core::ListenerControl,
     ) -> Result<(), crate::utils::AppError> {
         tracing::debug!(port = self.tcp_port, "Starting Message Bus TCP server");
         self.bus
-            .start_tcp_server(Some(tls_config), credential_cache)
+            .start_tcp_server(Some(tls_config), credential_cache, Some(listeners))
             .await
     }
 }
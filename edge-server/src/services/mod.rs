@@ -7,10 +7,14 @@
 //! - [`HttpsService`] - HTTPS 服务器
 //! - [`MessageBusService`] - 消息总线服务
 //! - [`CatalogService`] - 产品和分类统一管理（含内存缓存）
+//! - [`catalog_history`] - 目录变更历史与版本回滚
+//! - [`EmployeePhotoService`] - 员工照片 (登录 / 授权时显示)
 
 pub mod activation;
+pub mod catalog_history;
 pub mod catalog_service;
 pub mod cert;
+pub mod employee_photo;
 pub mod https;
 pub mod image_cleanup;
 pub mod image_download;
@@ -21,6 +25,7 @@ pub use activation::ActivationService;
 pub use activation::ActivationStatus;
 pub use catalog_service::CatalogService;
 pub use cert::CertService;
+pub use employee_photo::EmployeePhotoService;
 pub use https::HttpsService;
 pub use image_cleanup::ImageCleanupService;
 pub use message_bus::MessageBusService;
@@ -0,0 +1,208 @@
+//! 首次安装向导 (Setup wizard)
+//!
+//! 初始配置分散在各个 API (门店信息、税率、区域桌台、员工、打印机)，向导把它们
+//! 串成固定步骤 ([`SetupStepKind::ALL`])，red_coral 按 `GET /api/setup/steps` 驱动：
+//!
+//! 1. 每个步骤按当前数据校验 ([`validate`])，有问题时不能确认
+//! 2. 确认 / 跳过记录在 `setup_step` 表；必需步骤不能跳过
+//! 3. 必需步骤全部确认前 OrdersManager 拒绝开台 (`SetupIncomplete`)，
+//!    其余配置 / 管理 API 照常可用
+//!
+//! 已在运营的门店 (有名称或有归档订单) 升级时由迁移直接标记完成。
+
+use crate::db::repository::{RepoResult, setup_step, store_info};
+use crate::utils::{AppError, AppResult};
+use shared::models::{SetupStatus, SetupStep, SetupStepKind, SetupStepSubmit};
+use sqlx::SqlitePool;
+
+/// 当前向导状态 (含各步骤校验问题)
+pub async fn status(pool: &SqlitePool) -> RepoResult<SetupStatus> {
+    let records = setup_step::find_all(pool).await?;
+    let mut steps = Vec::with_capacity(SetupStepKind::ALL.len());
+    for &step in SetupStepKind::ALL {
+        let record = records.iter().find(|r| r.step == step.as_str());
+        steps.push(SetupStep {
+            step,
+            mandatory: step.is_mandatory(),
+            completed: record.is_some(),
+            skipped: record.is_some_and(|r| r.skipped),
+            completed_at: record.map(|r| r.completed_at),
+            issues: validate(pool, step).await?,
+        });
+    }
+    Ok(SetupStatus {
+        complete: steps.iter().all(|s| !s.mandatory || s.completed),
+        steps,
+    })
+}
+
+/// 必需步骤是否全部确认
+pub async fn is_complete(pool: &SqlitePool) -> RepoResult<bool> {
+    let records = setup_step::find_all(pool).await?;
+    Ok(SetupStepKind::ALL
+        .iter()
+        .filter(|step| step.is_mandatory())
+        .all(|step| records.iter().any(|r| r.step == step.as_str())))
+}
+
+/// 按当前数据校验步骤，返回问题列表 (空 = 可确认)
+pub async fn validate(pool: &SqlitePool, step: SetupStepKind) -> RepoResult<Vec<String>> {
+    let mut issues = Vec::new();
+    match step {
+        SetupStepKind::StoreInfo => {
+            let info = store_info::get(pool).await?.unwrap_or_default();
+            for (value, field) in [
+                (&info.name, "name"),
+                (&info.address, "address"),
+                (&info.nif, "nif"),
+            ] {
+                if value.trim().is_empty() {
+                    issues.push(format!("Store {field} is required"));
+                }
+            }
+        }
+        SetupStepKind::TaxRates => {
+            let invalid = setup_step::find_invalid_tax_products(pool).await?;
+            if !invalid.is_empty() {
+                issues.push(format!(
+                    "Products with a tax rate outside 0-100%: {}",
+                    invalid.join(", ")
+                ));
+            }
+        }
+        SetupStepKind::Tables => {
+            if setup_step::count_active_tables(pool).await? == 0 {
+                issues.push("Create at least one zone with a table".to_string());
+            }
+        }
+        SetupStepKind::Admin => {
+            if setup_step::count_personal_admins(pool).await? == 0 {
+                issues.push(
+                    "Create a personal admin account (the default admin login is shared)"
+                        .to_string(),
+                );
+            }
+        }
+        SetupStepKind::Printer => {
+            if setup_step::count_active_printers(pool).await? == 0 {
+                issues.push("Configure at least one printer".to_string());
+            }
+        }
+    }
+    Ok(issues)
+}
+
+/// 确认或跳过步骤，返回更新后的状态
+pub async fn submit(
+    pool: &SqlitePool,
+    submit: &SetupStepSubmit,
+    operator_id: i64,
+) -> AppResult<SetupStatus> {
+    if submit.skip {
+        if submit.step.is_mandatory() {
+            return Err(AppError::validation(format!(
+                "Setup step {} is mandatory and cannot be skipped",
+                submit.step.as_str()
+            )));
+        }
+    } else {
+        let issues = validate(pool, submit.step).await?;
+        if !issues.is_empty() {
+            return Err(AppError::validation(format!(
+                "Setup step {} is incomplete: {}",
+                submit.step.as_str(),
+                issues.join("; ")
+            )));
+        }
+    }
+    setup_step::mark(pool, submit.step, submit.skip, operator_id).await?;
+    Ok(status(pool).await?)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::db::test_pool;
+    use shared::models::{EmployeeCreate, StoreInfoUpdate};
+
+    fn confirm(step: SetupStepKind) -> SetupStepSubmit {
+        SetupStepSubmit { step, skip: false }
+    }
+
+    #[tokio::test]
+    async fn test_wizard_requires_mandatory_steps() {
+        let pool = test_pool().await;
+        let initial = status(&pool).await.unwrap();
+        assert!(!initial.complete);
+        assert!(initial.steps.iter().all(|s| !s.completed));
+        assert!(!is_complete(&pool).await.unwrap());
+
+        // 门店信息为空 → 不能确认；必需步骤不能跳过
+        assert!(
+            submit(&pool, &confirm(SetupStepKind::StoreInfo), 1)
+                .await
+                .is_err()
+        );
+        let skip_admin = SetupStepSubmit {
+            step: SetupStepKind::Admin,
+            skip: true,
+        };
+        assert!(submit(&pool, &skip_admin, 1).await.is_err());
+
+        store_info::update(
+            &pool,
+            StoreInfoUpdate {
+                name: Some("Bar Central".into()),
+                address: Some("Calle Mayor 1".into()),
+                nif: Some("B12345678".into()),
+                ..Default::default()
+            },
+        )
+        .await
+        .unwrap();
+        submit(&pool, &confirm(SetupStepKind::StoreInfo), 1)
+            .await
+            .unwrap();
+        submit(&pool, &confirm(SetupStepKind::TaxRates), 1)
+            .await
+            .unwrap();
+
+        // 只有种子 admin → 需要个人管理员账号
+        assert!(
+            submit(&pool, &confirm(SetupStepKind::Admin), 1)
+                .await
+                .is_err()
+        );
+        crate::db::repository::employee::create(
+            &pool,
+            None,
+            EmployeeCreate {
+                username: "maria".into(),
+                password: "secret-pass".into(),
+                name: None,
+                role_id: 1,
+            },
+        )
+        .await
+        .unwrap();
+        let done = submit(&pool, &confirm(SetupStepKind::Admin), 1)
+            .await
+            .unwrap();
+        assert!(done.complete);
+        assert!(is_complete(&pool).await.unwrap());
+
+        // 可选步骤可跳过
+        let skip_printer = SetupStepSubmit {
+            step: SetupStepKind::Printer,
+            skip: true,
+        };
+        let status = submit(&pool, &skip_printer, 1).await.unwrap();
+        let printer = status
+            .steps
+            .iter()
+            .find(|s| s.step == SetupStepKind::Printer)
+            .unwrap();
+        assert!(printer.completed && printer.skipped);
+        assert!(!printer.issues.is_empty());
+    }
+}
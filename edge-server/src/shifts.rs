@@ -4,8 +4,12 @@
 //! 广播 `settlement_required` 通知前端弹窗要求操作员手动结算。
 //!
 //! 支持 `config_notify` 信号：修改 cutoff 后立即重算下次触发时间。
+//!
+//! [`ShiftBreakMonitor`] 定时检查当前班次的连续工作时长，
+//! 超过 [`BREAK_REQUIRED_AFTER_MS`] 未休息时广播 `break_required` 提醒。
 
 use std::sync::Arc;
+use std::time::Duration;
 
 use chrono::NaiveTime;
 use tokio::sync::Notify;
@@ -15,6 +19,7 @@ use crate::core::ServerState;
 use crate::db::repository::{shift, store_info};
 use crate::utils::time;
 use shared::message::SyncChangeType;
+use shared::models::{BREAK_REQUIRED_AFTER_MS, ShiftBreakStatus};
 
 use shared::cloud::SyncResource;
 const RESOURCE: SyncResource = SyncResource::Shift;
@@ -122,3 +127,84 @@ impl ShiftAutoCloseScheduler {
         time::cutoff_to_time(cutoff)
     }
 }
+
+/// 休息合规检查间隔
+const BREAK_CHECK_INTERVAL: Duration = Duration::from_secs(5 * 60);
+
+/// 班次休息合规检测
+///
+/// 注册为 `TaskKind::Periodic`。同一段连续工作只提醒一次（休息后重新计时）。
+pub struct ShiftBreakMonitor {
+    state: ServerState,
+    shutdown: CancellationToken,
+}
+
+impl ShiftBreakMonitor {
+    pub fn new(state: ServerState, shutdown: CancellationToken) -> Self {
+        Self { state, shutdown }
+    }
+
+    pub async fn run(self) {
+        tracing::info!("Shift break monitor started");
+
+        // 已提醒的 (shift_id, worked_since)
+        let mut warned: Option<(i64, i64)> = None;
+
+        loop {
+            if let Some(status) = self.check().await {
+                let key = (status.shift_id, status.worked_since);
+                if warned != Some(key) {
+                    tracing::info!(
+                        shift_id = status.shift_id,
+                        worked_since = status.worked_since,
+                        "Break required, broadcasting break_required"
+                    );
+                    self.state
+                        .broadcast_sync(
+                            RESOURCE,
+                            SyncChangeType::BreakRequired,
+                            status.shift_id,
+                            Some(&status),
+                            false,
+                        )
+                        .await;
+                    warned = Some(key);
+                }
+            }
+
+            tokio::select! {
+                _ = tokio::time::sleep(BREAK_CHECK_INTERVAL) => {}
+                _ = self.shutdown.cancelled() => {
+                    tracing::info!("Shift break monitor received shutdown signal");
+                    return;
+                }
+            }
+        }
+    }
+
+    /// 当前班次需要休息时返回其状态
+    async fn check(&self) -> Option<ShiftBreakStatus> {
+        let pool = &self.state.pool;
+        let current = match shift::find_any_open(pool).await {
+            Ok(current) => current?,
+            Err(e) => {
+                tracing::error!("Failed to load open shift: {}", e);
+                return None;
+            }
+        };
+        let now = shared::util::now_millis();
+        // 开班不足阈值时无需查询休息记录
+        if now - current.start_time < BREAK_REQUIRED_AFTER_MS {
+            return None;
+        }
+        let breaks = match shift::find_breaks(pool, current.id).await {
+            Ok(breaks) => breaks,
+            Err(e) => {
+                tracing::error!("Failed to load shift breaks: {}", e);
+                return None;
+            }
+        };
+        let status = ShiftBreakStatus::evaluate(&current, breaks, now);
+        status.break_due.then_some(status)
+    }
+}
@@ -4,35 +4,82 @@
 
 use std::path::Path;
 
+use shared::telemetry::OtlpConfig;
+
 /// Initialize the logger
 pub fn init_logger() {
     init_logger_with_file(None, None, None);
 }
 
 /// Initialize the logger with optional file output
-pub fn init_logger_with_file(log_level: Option<&str>, _json: Option<bool>, log_dir: Option<&str>) {
-    let level = log_level.unwrap_or("info");
+pub fn init_logger_with_file(log_level: Option<&str>, json: Option<bool>, log_dir: Option<&str>) {
+    init_logger_with_otlp(log_level, json, log_dir, None);
+}
+
+/// Initialize the logger with optional file output and OTLP trace export
+///
+/// OTLP 导出需 `otel` feature 且在 Tokio 运行时内调用；导出器初始化失败只告警，
+/// 本地日志照常输出。
+pub fn init_logger_with_otlp(
+    log_level: Option<&str>,
+    _json: Option<bool>,
+    log_dir: Option<&str>,
+    otlp: Option<&OtlpConfig>,
+) {
+    use tracing_subscriber::fmt::writer::BoxMakeWriter;
+    use tracing_subscriber::prelude::*;
+
+    let level = log_level
+        .unwrap_or("info")
+        .parse()
+        .unwrap_or(tracing::Level::INFO);
+
+    // Add file output if log_dir is provided
+    let writer = log_dir
+        .map(Path::new)
+        .filter(|path| path.exists())
+        .and_then(Path::to_str)
+        .map(|dir| BoxMakeWriter::new(tracing_appender::rolling::daily(dir, "edge-server")))
+        .unwrap_or_else(|| BoxMakeWriter::new(std::io::stdout));
 
-    let subscriber = tracing_subscriber::fmt()
-        .with_max_level(level.parse().unwrap_or(tracing::Level::INFO))
+    let fmt_layer = tracing_subscriber::fmt::layer()
         .with_file(true)
         .with_line_number(true)
         .with_thread_ids(false)
-        .with_target(true);
+        .with_target(true)
+        .with_writer(writer);
 
-    // Add file output if log_dir is provided
-    if let Some(dir) = log_dir {
-        let log_path = Path::new(dir);
-        if log_path.exists()
-            && let Some(dir_str) = log_path.to_str()
-        {
-            let file_appender = tracing_appender::rolling::daily(dir_str, "edge-server");
-            subscriber.with_writer(file_appender).init();
-            return;
-        }
+    #[cfg(feature = "otel")]
+    let (otel_layer, otel_error) = match otlp.map(shared::telemetry::layer) {
+        Some(Ok(layer)) => (Some(layer), None),
+        Some(Err(e)) => (None, Some(e)),
+        None => (None, None),
+    };
+    #[cfg(not(feature = "otel"))]
+    let (otel_layer, otel_error) = (
+        None::<tracing_subscriber::layer::Identity>,
+        otlp.map(|_| {
+            "OTLP endpoint configured but edge-server was built without `otel`".to_string()
+        }),
+    );
+
+    tracing_subscriber::registry()
+        .with(tracing_subscriber::filter::LevelFilter::from_level(level))
+        .with(fmt_layer)
+        .with(otel_layer)
+        .init();
+
+    if let Some(e) = otel_error {
+        tracing::warn!("Trace export disabled: {e}");
+    } else if let Some(config) = otlp {
+        tracing::info!(endpoint = %config.endpoint, "Exporting traces via OTLP");
     }
+}
 
-    subscriber.init();
+/// Flush pending spans (call before process exit)
+pub fn shutdown_telemetry() {
+    #[cfg(feature = "otel")]
+    shared::telemetry::shutdown();
 }
 
 /// Clean up old log files
@@ -10,15 +10,12 @@ use crate::utils::AppError;
 
 // ── Text length limits ──────────────────────────────────────────────
 
-/// Entity names: product, category, attribute, zone, table, tag, role, etc.
-pub const MAX_NAME_LEN: usize = 200;
+/// Entity names / notes: shared with order command validation
+pub use crab_order_core::validation::{MAX_NAME_LEN, MAX_NOTE_LEN};
 
 /// Receipt / kitchen print names (80mm = 48 chars, but allow some overflow for wrapping)
 pub const MAX_RECEIPT_NAME_LEN: usize = 64;
 
-/// Notes, descriptions, reasons (void note, comp reason, order note, etc.)
-pub const MAX_NOTE_LEN: usize = 500;
-
 /// Short identifiers: phone, card_number, NIF, color codes, etc.
 pub const MAX_SHORT_TEXT_LEN: usize = 100;
 
@@ -67,36 +64,32 @@ pub fn validate_optional_text(
     Ok(())
 }
 
-// ── Validation helpers (Order actions) ──────────────────────────────
-
-use shared::order::types::CommandErrorCode;
-
-use crate::orders::traits::OrderError;
-
-/// Validate a required string for order actions (non-empty + max length).
-pub fn validate_order_text(value: &str, field: &str, max_len: usize) -> Result<(), OrderError> {
-    if value.len() > max_len {
-        return Err(OrderError::InvalidOperation(
-            CommandErrorCode::InvalidOperation,
-            format!("{field} is too long ({} chars, max {max_len})", value.len()),
-        ));
+/// Normalize a customer phone for SMS: strip separators, require (+)digits, 6–16 digits.
+pub fn normalize_phone(phone: &str) -> Result<String, AppError> {
+    let normalized: String = phone
+        .chars()
+        .filter(|c| !matches!(c, ' ' | '-' | '(' | ')' | '.'))
+        .collect();
+    let digits = normalized.strip_prefix('+').unwrap_or(&normalized);
+    if !(6..=16).contains(&digits.len()) || !digits.bytes().all(|b| b.is_ascii_digit()) {
+        return Err(AppError::validation(format!(
+            "Invalid phone number: {phone}"
+        )));
     }
-    Ok(())
+    Ok(normalized)
 }
 
-/// Validate an optional string for order actions (max length).
-pub fn validate_order_optional_text(
-    value: &Option<String>,
-    field: &str,
-    max_len: usize,
-) -> Result<(), OrderError> {
-    if let Some(v) = value
-        && v.len() > max_len
-    {
-        return Err(OrderError::InvalidOperation(
-            CommandErrorCode::InvalidOperation,
-            format!("{field} is too long ({} chars, max {max_len})", v.len()),
-        ));
+// ── Shared secrets ──────────────────────────────────────────────────
+
+/// 常量时间比较，避免通过响应耗时逐字节猜出 token
+pub fn token_matches(provided: &str, expected: &str) -> bool {
+    let (a, b) = (provided.as_bytes(), expected.as_bytes());
+    if a.len() != b.len() {
+        return false;
     }
-    Ok(())
+    a.iter().zip(b).fold(0u8, |acc, (x, y)| acc | (x ^ y)) == 0
 }
+
+// ── Validation helpers (Order actions) ──────────────────────────────
+
+pub use crab_order_core::validation::{validate_order_optional_text, validate_order_text};
@@ -0,0 +1,313 @@
+//! 资源看门狗 (长时间运行泄漏监控)
+//!
+//! Edge 节点连续运行数月，缓慢泄漏 (订阅者未释放、客户端条目残留、任务堆积、
+//! 句柄未关闭) 只有在运行足够久之后才会暴露。看门狗每隔
+//! [`Config::resource_watchdog_interval_secs`](crate::core::Config) 秒采样一次：
+//!
+//! | 指标 | 来源 |
+//! |------|------|
+//! | `bus_server_receivers` | MessageBus 广播 (server → clients) 订阅者数 |
+//! | `bus_client_receivers` | MessageBus 上行 (clients → server) 订阅者数 |
+//! | `order_event_receivers` | OrdersManager 事件广播订阅者数 |
+//! | `bus_clients` | 已连接客户端 (DashMap 条目) |
+//! | `bus_queued_messages` | 所有 TCP 客户端出站队列积压之和 |
+//! | `tokio_tasks` | 存活的 tokio 任务数 |
+//! | `open_fds` | 打开的文件句柄 (仅 Linux，`/proc/self/fd`) |
+//! | `rule_cache_orders` | 价格规则缓存的订单数 |
+//!
+//! 最近 [`WINDOW`] 个采样内某指标单调不减且累计增长超过阈值时，
+//! 判定为疑似泄漏：记录 WARN 日志并创建 `resource_leak` 系统问题 (非阻塞，
+//! 同一指标未处理前不重复创建)。正常负载随营业时段起落，不会触发单调增长。
+
+use std::collections::{HashMap, VecDeque};
+use std::time::Duration;
+
+use serde::Serialize;
+use tokio_util::sync::CancellationToken;
+
+use crate::core::ServerState;
+use crate::db::repository::system_issue;
+use shared::models::SystemIssueCreate;
+
+/// 判定窗口 (采样数，默认间隔 5 分钟 → 2 小时)
+pub const WINDOW: usize = 24;
+
+/// 系统问题类型
+pub const ISSUE_KIND: &str = "resource_leak";
+
+/// 单次采样
+#[derive(Debug, Clone, Default, Serialize)]
+pub struct ResourceSample {
+    pub at: i64,
+    pub bus_server_receivers: u64,
+    pub bus_client_receivers: u64,
+    pub order_event_receivers: u64,
+    pub bus_clients: u64,
+    pub bus_queued_messages: u64,
+    pub tokio_tasks: u64,
+    pub open_fds: Option<u64>,
+    pub rule_cache_orders: u64,
+}
+
+impl ResourceSample {
+    /// 采集当前资源使用
+    pub fn collect(state: &ServerState) -> Self {
+        let bus = state.message_bus();
+        let orders = state.orders_manager();
+        Self {
+            at: shared::util::now_millis(),
+            bus_server_receivers: bus.sender().receiver_count() as u64,
+            bus_client_receivers: bus.sender_to_server().receiver_count() as u64,
+            order_event_receivers: orders.subscriber_count() as u64,
+            bus_clients: bus.clients_count() as u64,
+            bus_queued_messages: bus
+                .client_queue_stats()
+                .iter()
+                .map(|q| q.depth as u64)
+                .sum(),
+            tokio_tasks: tokio::runtime::Handle::current()
+                .metrics()
+                .num_alive_tasks() as u64,
+            open_fds: open_fds(),
+            rule_cache_orders: orders.cached_rule_count() as u64,
+        }
+    }
+
+    /// (指标名, 取值, 判定泄漏的最小累计增长)
+    fn metrics(&self) -> [(&'static str, Option<u64>, u64); 8] {
+        [
+            ("bus_server_receivers", Some(self.bus_server_receivers), 8),
+            ("bus_client_receivers", Some(self.bus_client_receivers), 8),
+            ("order_event_receivers", Some(self.order_event_receivers), 8),
+            ("bus_clients", Some(self.bus_clients), 16),
+            ("bus_queued_messages", Some(self.bus_queued_messages), 256),
+            ("tokio_tasks", Some(self.tokio_tasks), 64),
+            ("open_fds", self.open_fds, 64),
+            ("rule_cache_orders", Some(self.rule_cache_orders), 100),
+        ]
+    }
+}
+
+#[cfg(target_os = "linux")]
+fn open_fds() -> Option<u64> {
+    std::fs::read_dir("/proc/self/fd")
+        .ok()
+        .map(|dir| dir.count() as u64)
+}
+
+#[cfg(not(target_os = "linux"))]
+fn open_fds() -> Option<u64> {
+    None
+}
+
+/// 疑似泄漏
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct LeakSuspect {
+    pub metric: &'static str,
+    pub first: u64,
+    pub last: u64,
+}
+
+/// 单调不减且累计增长 >= `min_growth`
+fn is_monotonic_growth(series: &[u64], min_growth: u64) -> bool {
+    match (series.first(), series.last()) {
+        (Some(&first), Some(&last)) => {
+            series.windows(2).all(|w| w[1] >= w[0]) && last.saturating_sub(first) >= min_growth
+        }
+        _ => false,
+    }
+}
+
+/// 采样历史 (最近 [`WINDOW`] 个)
+#[derive(Debug, Default)]
+pub struct ResourceHistory {
+    samples: VecDeque<ResourceSample>,
+}
+
+impl ResourceHistory {
+    pub fn push(&mut self, sample: ResourceSample) {
+        if self.samples.len() == WINDOW {
+            self.samples.pop_front();
+        }
+        self.samples.push_back(sample);
+    }
+
+    /// 窗口填满后检查所有指标
+    pub fn suspects(&self) -> Vec<LeakSuspect> {
+        if self.samples.len() < WINDOW {
+            return Vec::new();
+        }
+        let mut series: HashMap<&'static str, (Vec<u64>, u64)> = HashMap::new();
+        for sample in &self.samples {
+            for (metric, value, min_growth) in sample.metrics() {
+                if let Some(value) = value {
+                    series
+                        .entry(metric)
+                        .or_insert_with(|| (Vec::with_capacity(WINDOW), min_growth))
+                        .0
+                        .push(value);
+                }
+            }
+        }
+        let mut suspects: Vec<LeakSuspect> = series
+            .into_iter()
+            .filter(|(_, (values, min_growth))| {
+                values.len() == WINDOW && is_monotonic_growth(values, *min_growth)
+            })
+            .map(|(metric, (values, _))| LeakSuspect {
+                metric,
+                first: values[0],
+                last: values[WINDOW - 1],
+            })
+            .collect();
+        suspects.sort_by_key(|s| s.metric);
+        suspects
+    }
+}
+
+/// 资源看门狗
+///
+/// 注册为 `TaskKind::Periodic`，在 `start_background_tasks()` 中启动。
+pub struct ResourceWatchdog {
+    state: ServerState,
+    shutdown: CancellationToken,
+    interval: Duration,
+    history: ResourceHistory,
+}
+
+impl ResourceWatchdog {
+    pub fn new(state: ServerState, shutdown: CancellationToken, interval: Duration) -> Self {
+        Self {
+            state,
+            shutdown,
+            interval,
+            history: ResourceHistory::default(),
+        }
+    }
+
+    /// 主循环：按间隔采样并检查增长趋势
+    pub async fn run(mut self) {
+        tracing::info!(
+            interval_secs = self.interval.as_secs(),
+            window = WINDOW,
+            "Resource watchdog started"
+        );
+
+        loop {
+            tokio::select! {
+                _ = tokio::time::sleep(self.interval) => {}
+                _ = self.shutdown.cancelled() => {
+                    tracing::info!("Resource watchdog received shutdown signal");
+                    return;
+                }
+            }
+
+            let sample = ResourceSample::collect(&self.state);
+            tracing::info!(
+                bus_server_receivers = sample.bus_server_receivers,
+                bus_client_receivers = sample.bus_client_receivers,
+                order_event_receivers = sample.order_event_receivers,
+                bus_clients = sample.bus_clients,
+                tokio_tasks = sample.tokio_tasks,
+                open_fds = ?sample.open_fds,
+                rule_cache_orders = sample.rule_cache_orders,
+                "Resource sample"
+            );
+            self.history.push(sample);
+
+            for suspect in self.history.suspects() {
+                self.report(&suspect).await;
+            }
+        }
+    }
+
+    async fn report(&self, suspect: &LeakSuspect) {
+        tracing::warn!(
+            metric = suspect.metric,
+            first = suspect.first,
+            last = suspect.last,
+            window_secs = self.interval.as_secs() * WINDOW as u64,
+            "Monotonic resource growth detected, possible leak"
+        );
+
+        // 去重：同一指标已有未处理的 issue 时不重复创建
+        let pool = &self.state.pool;
+        match system_issue::find_pending_by_kind(pool, ISSUE_KIND).await {
+            Ok(existing)
+                if existing
+                    .iter()
+                    .any(|i| i.target.as_deref() == Some(suspect.metric)) => {}
+            Ok(_) => {
+                let params = HashMap::from([
+                    ("metric".to_string(), suspect.metric.to_string()),
+                    ("first".to_string(), suspect.first.to_string()),
+                    ("last".to_string(), suspect.last.to_string()),
+                    (
+                        "window_minutes".to_string(),
+                        (self.interval.as_secs() * WINDOW as u64 / 60).to_string(),
+                    ),
+                ]);
+                if let Err(e) = system_issue::create(
+                    pool,
+                    SystemIssueCreate {
+                        source: "local".to_string(),
+                        kind: ISSUE_KIND.to_string(),
+                        blocking: false,
+                        target: Some(suspect.metric.to_string()),
+                        params,
+                        title: None,
+                        description: None,
+                        options: vec!["acknowledged".to_string(), "restarted".to_string()],
+                    },
+                )
+                .await
+                {
+                    tracing::error!("Failed to create system_issue for resource leak: {:?}", e);
+                }
+            }
+            Err(e) => tracing::error!("Failed to query pending issues: {:?}", e),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn sample(tasks: u64, clients: u64) -> ResourceSample {
+        ResourceSample {
+            tokio_tasks: tasks,
+            bus_clients: clients,
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn test_monotonic_growth() {
+        assert!(is_monotonic_growth(&[10, 10, 12, 30], 20));
+        assert!(!is_monotonic_growth(&[10, 10, 12, 29], 20));
+        // 回落 → 正常负载波动
+        assert!(!is_monotonic_growth(&[10, 50, 40, 90], 20));
+        assert!(!is_monotonic_growth(&[], 0));
+    }
+
+    #[test]
+    fn test_history_flags_only_monotonic_metrics_after_full_window() {
+        let mut history = ResourceHistory::default();
+        for i in 0..WINDOW as u64 - 1 {
+            // 任务持续增长；客户端随营业时段起落
+            history.push(sample(100 + i * 10, if i % 2 == 0 { 3 } else { 40 }));
+            assert!(history.suspects().is_empty());
+        }
+        history.push(sample(100 + WINDOW as u64 * 10, 3));
+
+        let suspects = history.suspects();
+        assert_eq!(suspects.len(), 1);
+        assert_eq!(suspects[0].metric, "tokio_tasks");
+        assert_eq!(suspects[0].first, 100);
+
+        // 窗口滑动后出现回落 → 不再报告
+        history.push(sample(50, 3));
+        assert!(history.suspects().is_empty());
+    }
+}
@@ -0,0 +1,502 @@
+//! 跨 crate 集成测试: auth → edge-server → crab-client
+//!
+//! 在发版前捕获 crate 之间的协议漂移 (证书签发、mTLS 握手、订单 RPC、云同步载荷)。
+//!
+//! ```text
+//! MockAuth (crab-cert: Root CA → Tenant CA)
+//!   ├── 签发 edge 服务器证书 → work_dir/certs/ (等同激活结果)
+//!   └── /api/auth/login + /api/cert/issue → crab-client setup()
+//! ServerState (真实数据库 / redb / 后台任务) + MessageBus TCP (mTLS)
+//!   └── crab-client 走真实 mTLS 执行订单生命周期: 开台 → 点单 → 支付 → 结单
+//! 断言:
+//!   ├── 客户端经 mTLS 收到的 OrderSync 同步事件
+//!   ├── CloudMessage::ActiveOrderSnapshot / ActiveOrderRemoved 往返 (crab-cloud 解析的类型)
+//!   └── 归档订单 CloudSyncItem → OrderDetailSync (crab-cloud sync_store 的解析路径)
+//! ```
+//!
+//! crab-cloud 依赖 Postgres，这里用 crab-cert 实现的 mock auth 代替，接口与
+//! `crab-cloud/src/api/pki` 的签发逻辑保持一致 (客户端证书 = Tenant CA 签发的 ClientAuth 证书)。
+//!
+//! 运行: `cargo test -p edge-server --test cross_crate`
+
+use std::sync::Arc;
+use std::time::Duration;
+
+use axum::extract::State;
+use axum::http::{HeaderMap, StatusCode};
+use axum::routing::post;
+use axum::{Json, Router};
+use crab_cert::{CaProfile, CertProfile, CertificateAuthority};
+use crab_client::{CrabClient, OrderCommandOutcome};
+use edge_server::core::BackgroundTasks;
+use edge_server::db::repository::order;
+use edge_server::{Config, ServerState};
+use shared::cloud::{CloudMessage, CloudSyncItem, OrderDetailSync, SyncAction, SyncResource};
+use shared::message::{BusMessage, EventType, SyncChangeType, SyncPayload};
+use shared::order::types::ServiceType;
+use shared::order::{
+    CartItemInput, CommandResponse, OrderCommand, OrderCommandPayload, PaymentInput,
+};
+use tempfile::TempDir;
+use tokio::sync::broadcast;
+
+const TENANT_ID: i64 = 4242;
+const TENANT_USERNAME: &str = "tenant-e2e";
+const TENANT_PASSWORD: &str = "tenant-e2e-password";
+const TENANT_TOKEN: &str = "tenant-e2e-token";
+const EDGE_ID: &str = "edge-e2e";
+const CLIENT_NAME: &str = "pos-e2e";
+
+// ============================================================================
+// Mock Auth Server
+// ============================================================================
+
+/// 测试用租户 PKI (Root CA → Tenant CA)
+struct TenantPki {
+    root_ca: CertificateAuthority,
+    tenant_ca: CertificateAuthority,
+}
+
+impl TenantPki {
+    fn new() -> Self {
+        let root_profile = CaProfile {
+            common_name: "Crab E2E Root CA".to_string(),
+            ..Default::default()
+        };
+        let root_ca = CertificateAuthority::new_root(root_profile).expect("create root CA");
+
+        let tenant_profile = CaProfile {
+            common_name: format!("Crab E2E Tenant {TENANT_ID}"),
+            ..Default::default()
+        };
+        let tenant_ca = CertificateAuthority::new_intermediate(tenant_profile, &root_ca)
+            .expect("create tenant CA");
+
+        Self { root_ca, tenant_ca }
+    }
+
+    /// 签发 edge 证书 (Server + Client EKU，同 crab-cloud `api/pki/activate.rs`)
+    fn issue_edge_cert(&self) -> (String, String) {
+        let mut profile = CertProfile::new_server(
+            EDGE_ID,
+            vec![EDGE_ID.to_string(), "localhost".to_string()],
+            Some(TENANT_ID),
+            crab_cert::generate_hardware_id(),
+        );
+        profile.is_client = true;
+        self.tenant_ca
+            .issue_cert(&profile)
+            .expect("issue edge certificate")
+    }
+}
+
+/// 启动 mock auth server，返回 base URL
+async fn start_mock_auth(pki: Arc<TenantPki>) -> String {
+    let app = Router::new()
+        .route("/api/auth/login", post(mock_login))
+        .route("/api/cert/issue", post(mock_issue_cert))
+        .with_state(pki);
+
+    let listener = tokio::net::TcpListener::bind("127.0.0.1:0")
+        .await
+        .expect("bind mock auth");
+    let addr = listener.local_addr().expect("mock auth addr");
+    tokio::spawn(async move {
+        let _ = axum::serve(listener, app).await;
+    });
+    format!("http://{addr}")
+}
+
+async fn mock_login(
+    Json(body): Json<serde_json::Value>,
+) -> Result<Json<serde_json::Value>, StatusCode> {
+    if body["username"] != TENANT_USERNAME || body["password"] != TENANT_PASSWORD {
+        return Err(StatusCode::UNAUTHORIZED);
+    }
+    Ok(Json(serde_json::json!({
+        "token": TENANT_TOKEN,
+        "tenant_id": TENANT_ID,
+    })))
+}
+
+async fn mock_issue_cert(
+    State(pki): State<Arc<TenantPki>>,
+    headers: HeaderMap,
+    Json(body): Json<serde_json::Value>,
+) -> Result<Json<serde_json::Value>, StatusCode> {
+    let authorized = headers
+        .get("Authorization")
+        .and_then(|v| v.to_str().ok())
+        .is_some_and(|v| v == format!("Bearer {TENANT_TOKEN}"));
+    if !authorized || body["tenant_id"] != TENANT_ID || body["is_server"] != false {
+        return Err(StatusCode::UNAUTHORIZED);
+    }
+
+    let common_name = body["common_name"]
+        .as_str()
+        .ok_or(StatusCode::BAD_REQUEST)?;
+    let device_id = body["device_id"].as_str().map(str::to_string);
+    // 同 crab-cloud `api/pki/activate_client.rs`: 只带 ClientAuth EKU
+    let profile = CertProfile::new_client(
+        common_name,
+        Some(TENANT_ID),
+        device_id,
+        Some(common_name.to_string()),
+    );
+    let (cert, key) = pki
+        .tenant_ca
+        .issue_cert(&profile)
+        .map_err(|_| StatusCode::INTERNAL_SERVER_ERROR)?;
+
+    Ok(Json(serde_json::json!({
+        "cert": cert,
+        "key": key,
+        "tenant_ca_cert": pki.tenant_ca.cert_pem(),
+    })))
+}
+
+// ============================================================================
+// Edge Server
+// ============================================================================
+
+/// 取一个空闲端口 (绑定后立即释放)
+fn free_port() -> u16 {
+    std::net::TcpListener::bind("127.0.0.1:0")
+        .and_then(|l| l.local_addr())
+        .map(|addr| addr.port())
+        .expect("allocate free port")
+}
+
+/// 写入激活证书，初始化 ServerState，启动后台任务与 mTLS 消息总线
+async fn start_edge(
+    work_dir: &TempDir,
+    pki: &TenantPki,
+    auth_url: &str,
+) -> (ServerState, BackgroundTasks, u16) {
+    let port = free_port();
+    let config = Config::builder()
+        .work_dir(work_dir.path().to_string_lossy())
+        .message_tcp_port(port)
+        .auth_server_url(auth_url)
+        .environment("test")
+        .build();
+
+    let state = ServerState::initialize(&config)
+        .await
+        .expect("initialize edge state");
+    // 全新数据库：跳过首次安装向导，直接开放开台
+    state.orders_manager.update_setup_complete(true);
+
+    let (edge_cert, edge_key) = pki.issue_edge_cert();
+    state
+        .cert_service
+        .save_certificates(
+            pki.root_ca.cert_pem(),
+            pki.tenant_ca.cert_pem(),
+            &edge_cert,
+            &edge_key,
+        )
+        .await
+        .expect("save edge certificates");
+    state
+        .cert_service
+        .verify_certificate_chain(pki.root_ca.cert_pem(), pki.tenant_ca.cert_pem(), &edge_cert)
+        .await
+        .expect("edge certificate chain");
+
+    let background_tasks = state.start_background_tasks().await;
+
+    let tls_config = state
+        .cert_service
+        .load_tls_config()
+        .expect("load edge TLS config")
+        .expect("edge TLS config present");
+    let bus = state.message_bus.bus().clone();
+    let credential_cache = state.activation.credential_cache.clone();
+    tokio::spawn(async move {
+        if let Err(e) = bus
+            .start_tcp_server(Some(tls_config), credential_cache, None)
+            .await
+        {
+            panic!("edge message bus failed: {e}");
+        }
+    });
+    wait_for_port(port).await;
+
+    (state, background_tasks, port)
+}
+
+async fn wait_for_port(port: u16) {
+    for _ in 0..50 {
+        if tokio::net::TcpStream::connect(("127.0.0.1", port))
+            .await
+            .is_ok()
+        {
+            return;
+        }
+        tokio::time::sleep(Duration::from_millis(100)).await;
+    }
+    panic!("edge message bus did not start on port {port}");
+}
+
+// ============================================================================
+// Helpers
+// ============================================================================
+
+fn command(payload: OrderCommandPayload) -> OrderCommand {
+    // 种子数据中的 admin 员工 (id = 1)
+    OrderCommand::new(1, "Admin".to_string(), payload)
+}
+
+async fn send(
+    client: &CrabClient<crab_client::Remote, crab_client::Connected>,
+    action: &str,
+    payload: OrderCommandPayload,
+) -> CommandResponse {
+    let mc = client.message_client().expect("message client");
+    match mc
+        .send_order_command(action, &command(payload))
+        .await
+        .expect("order RPC")
+    {
+        OrderCommandOutcome::Completed(response) => {
+            assert!(response.success, "{action} failed: {:?}", response.error);
+            response
+        }
+        other => panic!("{action} was not executed online: {other:?}"),
+    }
+}
+
+/// 收集指定订单的 OrderSync 事件，直到收到终结事件 (Deleted)
+async fn collect_order_syncs(
+    rx: &mut broadcast::Receiver<BusMessage>,
+    order_id: i64,
+) -> Vec<SyncPayload> {
+    let mut syncs = Vec::new();
+    let deadline = tokio::time::Instant::now() + Duration::from_secs(10);
+    loop {
+        let msg = tokio::time::timeout_at(deadline, rx.recv())
+            .await
+            .expect("timed out waiting for order sync over mTLS")
+            .expect("client broadcast channel closed");
+        if msg.event_type != EventType::Sync {
+            continue;
+        }
+        let payload: SyncPayload = msg.parse_payload().expect("SyncPayload decodes");
+        if payload.resource != SyncResource::OrderSync || payload.id != order_id {
+            continue;
+        }
+        let finished = payload.action == SyncChangeType::Deleted;
+        syncs.push(payload);
+        if finished {
+            return syncs;
+        }
+    }
+}
+
+/// 等待归档 worker 写入 chain_entry (ORDER)
+async fn wait_for_archive(state: &ServerState, order_id: i64) {
+    for _ in 0..100 {
+        let archived: Option<i64> = sqlx::query_scalar(
+            "SELECT entry_pk FROM chain_entry WHERE entry_type = 'ORDER' AND entry_pk = ?",
+        )
+        .bind(order_id)
+        .fetch_optional(&state.pool)
+        .await
+        .expect("query chain_entry");
+        if archived.is_some() {
+            return;
+        }
+        tokio::time::sleep(Duration::from_millis(100)).await;
+    }
+    panic!("order {order_id} was not archived");
+}
+
+/// 按 crab-cloud 的方式反序列化 (JSON 往返)，字段漂移会在这里失败
+fn cloud_roundtrip(message: &CloudMessage) -> CloudMessage {
+    let json = serde_json::to_string(message).expect("serialize CloudMessage");
+    serde_json::from_str(&json).expect("crab-cloud decodes CloudMessage")
+}
+
+// ============================================================================
+// Test
+// ============================================================================
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 4)]
+async fn test_order_lifecycle_across_auth_edge_client() {
+    let _ = rustls::crypto::aws_lc_rs::default_provider().install_default();
+
+    let pki = Arc::new(TenantPki::new());
+    let auth_url = start_mock_auth(pki.clone()).await;
+
+    let edge_dir = TempDir::new().unwrap();
+    let (state, background_tasks, port) = start_edge(&edge_dir, &pki, &auth_url).await;
+
+    // ── 1. 客户端: 租户登录 → 下载证书 → mTLS 连接 ──
+    let client_dir = TempDir::new().unwrap();
+    let client = CrabClient::remote()
+        .auth_server(&auth_url)
+        .cert_path(client_dir.path())
+        .client_name(CLIENT_NAME)
+        .build()
+        .expect("build remote client")
+        .setup(
+            TENANT_USERNAME,
+            TENANT_PASSWORD,
+            &format!("127.0.0.1:{port}"),
+        )
+        .await
+        .expect("client setup over mTLS");
+    assert!(client.is_connected());
+
+    let mc = client.message_client().expect("message client");
+    // edge 以证书 CN 识别终端
+    assert_eq!(mc.terminal_identity().await.as_deref(), Some(CLIENT_NAME));
+    let mut client_rx = mc.subscribe();
+
+    // ── 2. 订单生命周期 ──
+    let order_id = send(
+        &client,
+        "order.open_table",
+        OrderCommandPayload::OpenTable {
+            table_id: Some(7),
+            table_name: Some("Mesa 7".to_string()),
+            zone_id: None,
+            zone_name: None,
+            guest_count: 2,
+            is_retail: false,
+        },
+    )
+    .await
+    .order_id
+    .expect("open_table returns order_id");
+
+    send(
+        &client,
+        "order.add_items",
+        OrderCommandPayload::AddItems {
+            order_id,
+            items: vec![CartItemInput {
+                product_id: 9001,
+                name: "Café con leche".to_string(),
+                price: 1.8,
+                original_price: None,
+                quantity: 2,
+                selected_options: None,
+                selected_specification: None,
+                manual_discount_percent: None,
+                note: None,
+                authorizer_id: None,
+                authorizer_name: None,
+                course: None,
+            }],
+        },
+    )
+    .await;
+
+    // 活跃订单推送 (CloudWorker::extract_order_push 的载荷)
+    let snapshot = state
+        .orders_manager
+        .get_snapshot(order_id)
+        .expect("read snapshot")
+        .expect("active snapshot");
+    let events = state
+        .orders_manager
+        .get_events_for_order(order_id)
+        .expect("read events");
+    let expected_events = events.len();
+    match cloud_roundtrip(&CloudMessage::ActiveOrderSnapshot {
+        snapshot: Box::new(snapshot),
+        events,
+    }) {
+        CloudMessage::ActiveOrderSnapshot { snapshot, events } => {
+            assert_eq!(snapshot.order_id, order_id);
+            assert!(snapshot.is_active());
+            assert_eq!(snapshot.items.len(), 1);
+            assert_eq!(snapshot.total, 3.6);
+            assert_eq!(events.len(), expected_events);
+        }
+        other => panic!("unexpected cloud message: {other:?}"),
+    }
+
+    send(
+        &client,
+        "order.add_payment",
+        OrderCommandPayload::AddPayment {
+            order_id,
+            payment: PaymentInput {
+                method: "CASH".to_string(),
+                amount: 3.6,
+                tendered: Some(5.0),
+                note: None,
+                reference: None,
+            },
+        },
+    )
+    .await;
+
+    send(
+        &client,
+        "order.complete",
+        OrderCommandPayload::CompleteOrder {
+            order_id,
+            service_type: Some(ServiceType::DineIn),
+        },
+    )
+    .await;
+
+    // ── 3. 客户端经 mTLS 收到的同步事件 ──
+    let syncs = collect_order_syncs(&mut client_rx, order_id).await;
+    assert!(syncs.iter().all(|s| !s.cloud_origin));
+    assert!(
+        syncs
+            .iter()
+            .filter(|s| s.action != SyncChangeType::Deleted)
+            .all(|s| s.data.is_some()),
+        "active order syncs carry snapshot data"
+    );
+    assert!(syncs.windows(2).all(|w| w[0].version <= w[1].version));
+    assert_eq!(
+        syncs.last().map(|s| s.action),
+        Some(SyncChangeType::Deleted)
+    );
+
+    let completed = state
+        .orders_manager
+        .get_snapshot(order_id)
+        .expect("read snapshot");
+    assert!(completed.as_ref().is_none_or(|s| !s.is_active()));
+    assert!(matches!(
+        cloud_roundtrip(&CloudMessage::ActiveOrderRemoved { order_id }),
+        CloudMessage::ActiveOrderRemoved { order_id: id } if id == order_id
+    ));
+
+    // ── 4. 归档订单云同步载荷 (CloudWorker::build_order_sync_item) ──
+    wait_for_archive(&state, order_id).await;
+    let detail = order::build_order_detail_sync(&state.pool, order_id)
+        .await
+        .expect("build OrderDetailSync");
+    let item = CloudSyncItem {
+        resource: SyncResource::ArchivedOrder,
+        version: order_id as u64,
+        action: SyncAction::Upsert,
+        resource_id: order_id,
+        data: serde_json::to_value(&detail).expect("serialize OrderDetailSync"),
+    };
+    let item: CloudSyncItem =
+        serde_json::from_str(&serde_json::to_string(&item).unwrap()).expect("decode sync item");
+    // 同 crab-cloud `db/sync_store.rs`
+    let synced: OrderDetailSync =
+        serde_json::from_value(item.data).expect("crab-cloud decodes OrderDetailSync");
+    assert_eq!(synced.order_id, order_id);
+    assert_eq!(synced.status, "COMPLETED");
+    assert_eq!(synced.total_amount, 3.6);
+    assert_eq!(synced.detail.paid_amount, 3.6);
+    assert_eq!(synced.detail.table_name.as_deref(), Some("Mesa 7"));
+    assert!(!synced.curr_hash.is_empty());
+
+    // ── 5. 清理 ──
+    let _client = client.disconnect().await;
+    state.message_bus.bus().shutdown();
+    background_tasks.shutdown().await;
+}
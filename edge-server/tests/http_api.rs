@@ -0,0 +1,253 @@
+//! HTTP API 集成测试: 真实 ServerState + 路由中间件 (认证 / 权限)
+//!
+//! 请求经 `HttpsService::oneshot` 进入完整路由栈，不监听端口。
+//!
+//! 运行: `cargo test -p edge-server --test http_api`
+
+use std::time::Duration;
+
+use axum::body::Body;
+use edge_server::core::BackgroundTasks;
+use edge_server::db::repository::delivery_rule;
+use edge_server::services::TenantBinding;
+use edge_server::{Config, ServerState};
+use http::{Method, Request, StatusCode};
+use shared::activation::{EntityType, SignedBinding, SubscriptionInfo};
+use shared::models::{DeliveryRuleCreate, DeliveryRuleUpdate};
+use tempfile::TempDir;
+
+fn test_config(work_dir: &TempDir, public_status: bool) -> Config {
+    Config::builder()
+        .work_dir(work_dir.path().to_string_lossy())
+        .environment("test")
+        .public_status(public_status)
+        .build()
+}
+
+/// 初始化 ServerState 并等待预热完成 (预热前业务 API 返回 SystemBusy)
+async fn start_edge(work_dir: &TempDir) -> (ServerState, BackgroundTasks) {
+    start_edge_with(&test_config(work_dir, false)).await
+}
+
+async fn start_edge_with(config: &Config) -> (ServerState, BackgroundTasks) {
+    let state = ServerState::initialize(config)
+        .await
+        .expect("initialize edge state");
+    let background_tasks = state.start_background_tasks().await;
+    tokio::time::timeout(Duration::from_secs(10), state.readiness.wait_warm())
+        .await
+        .expect("edge warmup");
+    (state, background_tasks)
+}
+
+/// 签发带指定权限的访问令牌
+fn token(state: &ServerState, role_name: &str, permissions: &[&str]) -> String {
+    let permissions: Vec<String> = permissions.iter().map(|p| p.to_string()).collect();
+    state
+        .get_jwt_service()
+        .generate_token(2, "staff", "Staff", 2, role_name, &permissions, false)
+        .expect("generate token")
+}
+
+async fn post_json(
+    state: &ServerState,
+    uri: &str,
+    token: &str,
+    body: serde_json::Value,
+) -> StatusCode {
+    let request = Request::builder()
+        .method(Method::POST)
+        .uri(uri)
+        .header(http::header::AUTHORIZATION, format!("Bearer {token}"))
+        .header(http::header::CONTENT_TYPE, "application/json")
+        .body(Body::from(body.to_string()))
+        .unwrap();
+    state
+        .https
+        .oneshot(request)
+        .await
+        .expect("oneshot request")
+        .status()
+}
+
+/// 无认证 GET，返回状态码与 JSON body (非 JSON 时为 Null)
+async fn get_json(state: &ServerState, uri: &str) -> (StatusCode, serde_json::Value) {
+    let request = Request::builder()
+        .method(Method::GET)
+        .uri(uri)
+        .body(Body::empty())
+        .unwrap();
+    let response = state.https.oneshot(request).await.expect("oneshot request");
+    let status = response.status();
+    let bytes = axum::body::to_bytes(response.into_body(), usize::MAX)
+        .await
+        .unwrap();
+    (
+        status,
+        serde_json::from_slice(&bytes).unwrap_or(serde_json::Value::Null),
+    )
+}
+
+/// 写入内存凭证，模拟已激活 (可附带订阅)
+async fn activate(state: &ServerState, subscription: Option<SubscriptionInfo>) {
+    let binding = SignedBinding {
+        entity_id: "edge-test".to_string(),
+        tenant_id: 1,
+        device_id: "device-test".to_string(),
+        fingerprint: String::new(),
+        bound_at: 0,
+        entity_type: EntityType::Server,
+        last_verified_at: 0,
+        signature: String::new(),
+    };
+    *state.activation.credential_cache.write().await = Some(TenantBinding {
+        binding,
+        subscription,
+        store_number: 1,
+    });
+}
+
+fn integration<'a>(body: &'a serde_json::Value, channel: Option<&str>) -> &'a serde_json::Value {
+    body["integrations"]
+        .as_array()
+        .unwrap()
+        .iter()
+        .find(|i| i["channel"].as_str() == channel)
+        .unwrap()
+}
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 2)]
+async fn test_public_status_disabled_returns_not_found() {
+    let work_dir = TempDir::new().unwrap();
+    let (state, background_tasks) = start_edge(&work_dir).await;
+
+    let (status, _) = get_json(&state, "/status").await;
+    assert_eq!(status, StatusCode::NOT_FOUND);
+
+    state.message_bus.bus().shutdown();
+    background_tasks.shutdown().await;
+}
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 2)]
+async fn test_public_status_warming_up() {
+    let work_dir = TempDir::new().unwrap();
+    // 未启动后台任务 = 预热未完成
+    let state = ServerState::initialize(&test_config(&work_dir, true))
+        .await
+        .expect("initialize edge state");
+
+    let (status, body) = get_json(&state, "/status").await;
+    assert_eq!(status, StatusCode::OK);
+    assert_eq!(body["status"], "unavailable");
+    assert_eq!(body["accepting_orders"], false);
+    assert_eq!(body["reason"], "warming_up");
+    assert_eq!(integration(&body, None)["reason"], "warming_up");
+
+    state.message_bus.bus().shutdown();
+}
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 2)]
+async fn test_public_status_reports_each_integration() {
+    let work_dir = TempDir::new().unwrap();
+    let (state, background_tasks) = start_edge_with(&test_config(&work_dir, true)).await;
+
+    let glovo = delivery_rule::create(
+        &state.pool,
+        &DeliveryRuleCreate {
+            channel: "glovo".to_string(),
+            zone: None,
+            min_order: None,
+            fee_tiers: vec![],
+        },
+    )
+    .await
+    .unwrap();
+    delivery_rule::create(
+        &state.pool,
+        &DeliveryRuleCreate {
+            channel: "web".to_string(),
+            zone: None,
+            min_order: None,
+            fee_tiers: vec![],
+        },
+    )
+    .await
+    .unwrap();
+    delivery_rule::update(
+        &state.pool,
+        glovo.id,
+        &DeliveryRuleUpdate {
+            min_order: None,
+            clear_min_order: false,
+            fee_tiers: None,
+            is_active: Some(false),
+        },
+    )
+    .await
+    .unwrap();
+
+    // 未激活：所有集成均不接单
+    let (status, body) = get_json(&state, "/status").await;
+    assert_eq!(status, StatusCode::OK);
+    assert_eq!(body["reason"], "not_activated");
+    assert!(body["uptime_seconds"].is_u64());
+    assert!(body["protocol_version"].is_u64());
+    assert!(body.get("tenant_id").is_none());
+    assert_eq!(body["integrations"].as_array().unwrap().len(), 3);
+    assert_eq!(integration(&body, Some("web"))["reason"], "not_activated");
+
+    // 已激活：QR 与启用的渠道接单，停用的渠道单独标注
+    activate(&state, None).await;
+    let (_, body) = get_json(&state, "/status").await;
+    assert_eq!(body["status"], "open");
+    assert_eq!(body["accepting_orders"], true);
+    assert!(body.get("reason").is_none());
+    let qr = integration(&body, None);
+    assert_eq!(qr["kind"], "qr_ordering");
+    assert_eq!(qr["accepting_orders"], true);
+    let web = integration(&body, Some("web"));
+    assert_eq!(web["kind"], "delivery");
+    assert_eq!(web["accepting_orders"], true);
+    let glovo = integration(&body, Some("glovo"));
+    assert_eq!(glovo["accepting_orders"], false);
+    assert_eq!(glovo["reason"], "channel_disabled");
+
+    // 订阅被阻止
+    activate(&state, Some(SubscriptionInfo::inactive_placeholder())).await;
+    let (_, body) = get_json(&state, "/status").await;
+    assert_eq!(body["status"], "unavailable");
+    assert_eq!(body["reason"], "subscription_blocked");
+    assert_eq!(integration(&body, None)["reason"], "subscription_blocked");
+    assert_eq!(integration(&body, Some("web"))["accepting_orders"], false);
+
+    state.message_bus.bus().shutdown();
+    background_tasks.shutdown().await;
+}
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 2)]
+async fn test_credit_note_requires_refund_permission() {
+    let work_dir = TempDir::new().unwrap();
+    let (state, background_tasks) = start_edge(&work_dir).await;
+
+    let refund = serde_json::json!({
+        "original_order_pk": 1,
+        "items": [],
+        "full_refund": true,
+        "refund_method": "CASH",
+        "reason": "customer complaint",
+    });
+
+    // 收银员 (无 orders:refund) 被权限中间件拒绝
+    let cashier = token(&state, "user", &["reports:view"]);
+    let status = post_json(&state, "/api/credit-notes", &cashier, refund.clone()).await;
+    assert_eq!(status, StatusCode::FORBIDDEN);
+
+    // 经理通过权限校验 (订单不存在由业务层拒绝)
+    let manager = token(&state, "manager", &["orders:refund"]);
+    let status = post_json(&state, "/api/credit-notes", &manager, refund).await;
+    assert_ne!(status, StatusCode::FORBIDDEN);
+    assert_ne!(status, StatusCode::UNAUTHORIZED);
+
+    state.message_bus.bus().shutdown();
+    background_tasks.shutdown().await;
+}
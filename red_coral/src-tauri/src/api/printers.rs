@@ -2,6 +2,8 @@
 //!
 //! 定义收据、标签打印所需的数据结构
 
+use std::collections::HashMap;
+
 use serde::{Deserialize, Serialize};
 
 /// 店铺信息 (用于收据头部)
@@ -15,9 +17,14 @@ pub struct StoreInfo {
     pub website: Option<String>,
     pub logo_url: Option<String>,
     pub currency_symbol: Option<String>,
+    #[serde(default)]
+    pub currency_decimal_places: Option<i32>,
     pub receipt_header: Option<String>,
     pub receipt_footer: Option<String>,
     pub receipt_locale: Option<String>,
+    /// 支付方式附加费说明 (收取附加费时打印)
+    #[serde(default)]
+    pub payment_surcharge_disclaimer: Option<String>,
 }
 
 /// 附加费信息 (整单手动)
@@ -74,6 +81,27 @@ pub struct ReceiptItem {
     pub spec_name: Option<String>,
     #[serde(default)]
     pub is_comped: bool,
+    /// 商品名译文 (locale → 名称)，缺失时打印原名
+    #[serde(default)]
+    pub name_translations: HashMap<String, String>,
+}
+
+/// 支付明细
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ReceiptPayment {
+    pub method: String,
+    pub amount: f64,
+    pub tendered: Option<f64>,
+    pub change: Option<f64>,
+}
+
+/// 分单收据编号（挂在主单收据号下，从 1 开始连续编号）
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SubReceipt {
+    /// 主单收据号
+    pub master: String,
+    pub index: u32,
+    pub total: u32,
 }
 
 /// 收据数据
@@ -90,6 +118,9 @@ pub struct ReceiptData {
     pub reprint: bool,
     #[serde(default)]
     pub pre_payment: bool,
+    /// 收据语言 (打印时选择 / 会员偏好)，None = 门店 receipt_locale
+    #[serde(default)]
+    pub locale: Option<String>,
     pub store_info: Option<StoreInfo>,
     pub discount: Option<DiscountInfo>,
     pub surcharge: Option<SurchargeInfo>,
@@ -97,8 +128,23 @@ pub struct ReceiptData {
     pub rule_adjustments: Vec<RuleAdjustment>,
     pub items: Vec<ReceiptItem>,
     pub total_amount: f64,
+    /// 支付方式附加费合计 (已含在 total_amount 中)
+    #[serde(default)]
+    pub payment_surcharge_amount: f64,
     pub queue_number: Option<u32>,
     pub qr_data: Option<String>,
+    /// 页脚促销（服务端按排期解析，含调查二维码）
+    #[serde(default)]
+    pub footer_promotion: Option<shared::models::ResolvedReceiptFooter>,
+    /// 支付明细（分单收据显示本单的支付）
+    #[serde(default)]
+    pub payments: Vec<ReceiptPayment>,
+    /// 分单收据编号（按人分单打印时设置）
+    #[serde(default)]
+    pub sub_receipt: Option<SubReceipt>,
+    /// 税控码（外部税控设备登记后返回，须打印在收据上）
+    #[serde(default)]
+    pub fiscal_code: Option<String>,
 }
 
 /// 标签数据
@@ -1,13 +1,14 @@
 //! 员工认证命令
 
-use serde::Serialize;
+use std::collections::HashMap;
 use std::sync::Arc;
 use tauri::State;
 
+use crate::core::bridge::COMMAND_PERMISSIONS;
 use crate::core::response::ErrorCode;
 use crate::core::session_cache::EmployeeSession;
 use crate::core::{ApiResponse, AuthData, ClientBridge};
-use shared::client::{EscalateResponse, UserInfo};
+use shared::client::UserInfo;
 
 /// 统一登录命令 (使用 ClientBridge)
 ///
@@ -56,6 +57,96 @@ pub async fn get_current_session(
     Ok(ApiResponse::success(bridge.get_current_session().await))
 }
 
+/// 锁定终端 (手动锁屏)
+#[tauri::command]
+pub fn lock_session(bridge: State<'_, Arc<ClientBridge>>) -> Result<ApiResponse<()>, String> {
+    bridge.lock_session();
+    Ok(ApiResponse::success(()))
+}
+
+/// 查询终端是否锁定 (前端启动/刷新时恢复锁屏)
+#[tauri::command]
+pub fn is_session_locked(
+    bridge: State<'_, Arc<ClientBridge>>,
+) -> Result<ApiResponse<bool>, String> {
+    Ok(ApiResponse::success(bridge.is_session_locked()))
+}
+
+/// 当前员工输入密码解锁
+#[tauri::command]
+pub async fn unlock_session(
+    bridge: State<'_, Arc<ClientBridge>>,
+    password: String,
+) -> Result<ApiResponse<AuthData>, String> {
+    match bridge.unlock_session(&password).await {
+        Ok(session) => Ok(ApiResponse::success(AuthData {
+            mode: session.login_mode,
+            session: Some(session),
+        })),
+        Err(e) => Ok(ApiResponse::error_with_code(
+            ErrorCode::InvalidCredentials,
+            e.to_string(),
+        )),
+    }
+}
+
+/// 换人收银 (保持连接，切换登录员工)
+#[tauri::command]
+pub async fn switch_cashier(
+    bridge: State<'_, Arc<ClientBridge>>,
+    username: String,
+    password: String,
+) -> Result<ApiResponse<AuthData>, String> {
+    match bridge.switch_cashier(&username, &password).await {
+        Ok(session) => Ok(ApiResponse::success(AuthData {
+            mode: session.login_mode,
+            session: Some(session),
+        })),
+        Err(e) => Ok(ApiResponse::error_with_code(
+            ErrorCode::InvalidCredentials,
+            e.to_string(),
+        )),
+    }
+}
+
+/// 上报用户操作 (前端节流调用，重置自动锁屏计时)
+#[tauri::command]
+pub fn report_activity(bridge: State<'_, Arc<ClientBridge>>) -> Result<ApiResponse<()>, String> {
+    bridge.touch_activity();
+    Ok(ApiResponse::success(()))
+}
+
+/// 获取自动锁屏时间 (分钟，null = 关闭)
+#[tauri::command]
+pub async fn get_auto_lock_minutes(
+    bridge: State<'_, Arc<ClientBridge>>,
+) -> Result<ApiResponse<Option<u32>>, String> {
+    Ok(ApiResponse::success(bridge.get_auto_lock_minutes().await))
+}
+
+/// 设置自动锁屏时间 (分钟，null/0 = 关闭)
+#[tauri::command]
+pub async fn set_auto_lock_minutes(
+    bridge: State<'_, Arc<ClientBridge>>,
+    minutes: Option<u32>,
+) -> Result<ApiResponse<()>, String> {
+    match bridge.set_auto_lock_minutes(minutes).await {
+        Ok(()) => Ok(ApiResponse::success(())),
+        Err(e) => Ok(ApiResponse::from_bridge_error(e)),
+    }
+}
+
+/// 受权限控制的 Tauri 命令表 (命令名 → 所需权限)
+///
+/// 前端据此隐藏/禁用功能，与桥接层拦截使用同一张表
+#[tauri::command]
+pub fn get_command_permissions() -> Result<ApiResponse<HashMap<&'static str, &'static str>>, String>
+{
+    Ok(ApiResponse::success(
+        COMMAND_PERMISSIONS.iter().copied().collect(),
+    ))
+}
+
 /// 权限提升 (主管授权)
 ///
 /// 验证授权人凭据并检查权限，成功时记录审计日志
@@ -66,24 +157,11 @@ pub async fn escalate_permission(
     password: String,
     required_permission: String,
 ) -> Result<ApiResponse<UserInfo>, String> {
-    #[derive(Serialize)]
-    struct EscalateReq {
-        username: String,
-        password: String,
-        required_permission: String,
-    }
-
-    let request = EscalateReq {
-        username,
-        password,
-        required_permission,
-    };
-
     match bridge
-        .post::<EscalateResponse, _>("/api/auth/escalate", &request)
+        .escalate(username, password, required_permission)
         .await
     {
-        Ok(response) => Ok(ApiResponse::success(response.authorizer)),
+        Ok(authorizer) => Ok(ApiResponse::success(authorizer)),
         Err(e) => {
             // Extract structured ErrorCode from ClientError::Api if available
             let (code, msg) = match &e {
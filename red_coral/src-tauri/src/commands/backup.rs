@@ -9,6 +9,9 @@ pub async fn export_data(
     bridge: State<'_, Arc<ClientBridge>>,
     path: String,
 ) -> Result<ApiResponse<()>, String> {
+    if let Err(e) = bridge.authorize_command("export_data").await {
+        return Ok(ApiResponse::from_bridge_error(e));
+    }
     // Server mode: call edge-server export directly (in-process, zero network)
     if let Some(server_state) = bridge.get_server_state().await {
         let zip_bytes = edge_server::api::data_transfer::export_zip(&server_state)
@@ -54,6 +57,9 @@ pub async fn import_data(
     bridge: State<'_, Arc<ClientBridge>>,
     path: String,
 ) -> Result<ApiResponse<()>, String> {
+    if let Err(e) = bridge.authorize_command("import_data").await {
+        return Ok(ApiResponse::from_bridge_error(e));
+    }
     let zip_bytes = tokio::fs::read(&path)
         .await
         .map_err(|e| format!("Failed to read file: {e}"))?;
@@ -13,6 +13,9 @@ pub async fn create_credit_note(
     bridge: State<'_, Arc<ClientBridge>>,
     request: serde_json::Value,
 ) -> Result<ApiResponse<serde_json::Value>, String> {
+    if let Err(e) = bridge.authorize_command("create_credit_note").await {
+        return Ok(ApiResponse::from_bridge_error(e));
+    }
     match bridge
         .post::<serde_json::Value, _>("/api/credit-notes", &request)
         .await
@@ -0,0 +1,317 @@
+//! Inventory Commands
+//!
+//! 库存 CRUD + 手动调整 + 盘点 + 门店间调拨 — 代理到 edge-server REST API
+
+use std::sync::Arc;
+use tauri::State;
+
+use crate::core::{ApiResponse, ClientBridge};
+use shared::cloud::transfer::TransferPeer;
+use shared::models::{
+    StockAdjustment, StockAdjustmentCreate, StockCount, StockCountCreate, StockCountEntry,
+    StockCountLine, StockCountReview, StockCountScan, StockLevel, StockLevelCreate,
+    StockLevelUpdate, StockTransfer, StockTransferCreate, StockTransferReceive,
+};
+
+/// GET /api/inventory - 全部库存
+#[tauri::command]
+pub async fn list_stock_levels(
+    bridge: State<'_, Arc<ClientBridge>>,
+) -> Result<ApiResponse<Vec<StockLevel>>, String> {
+    match bridge.get::<Vec<StockLevel>>("/api/inventory").await {
+        Ok(levels) => Ok(ApiResponse::success(levels)),
+        Err(e) => Ok(ApiResponse::from_bridge_error(e)),
+    }
+}
+
+/// GET /api/inventory/low - 低于阈值的库存
+#[tauri::command]
+pub async fn list_low_stock(
+    bridge: State<'_, Arc<ClientBridge>>,
+) -> Result<ApiResponse<Vec<StockLevel>>, String> {
+    match bridge.get::<Vec<StockLevel>>("/api/inventory/low").await {
+        Ok(levels) => Ok(ApiResponse::success(levels)),
+        Err(e) => Ok(ApiResponse::from_bridge_error(e)),
+    }
+}
+
+/// POST /api/inventory - 开始跟踪商品 / 规格库存
+#[tauri::command]
+pub async fn create_stock_level(
+    bridge: State<'_, Arc<ClientBridge>>,
+    data: StockLevelCreate,
+) -> Result<ApiResponse<StockLevel>, String> {
+    match bridge.post::<StockLevel, _>("/api/inventory", &data).await {
+        Ok(level) => Ok(ApiResponse::success(level)),
+        Err(e) => Ok(ApiResponse::from_bridge_error(e)),
+    }
+}
+
+/// PUT /api/inventory/:id - 更新低库存阈值与条码
+#[tauri::command]
+pub async fn update_stock_level(
+    bridge: State<'_, Arc<ClientBridge>>,
+    id: i64,
+    data: StockLevelUpdate,
+) -> Result<ApiResponse<StockLevel>, String> {
+    match bridge
+        .put::<StockLevel, _>(&format!("/api/inventory/{}", id), &data)
+        .await
+    {
+        Ok(level) => Ok(ApiResponse::success(level)),
+        Err(e) => Ok(ApiResponse::from_bridge_error(e)),
+    }
+}
+
+/// DELETE /api/inventory/:id - 停止跟踪库存
+#[tauri::command]
+pub async fn delete_stock_level(
+    bridge: State<'_, Arc<ClientBridge>>,
+    id: i64,
+) -> Result<ApiResponse<crate::core::DeleteData>, String> {
+    match bridge
+        .delete::<bool>(&format!("/api/inventory/{}", id))
+        .await
+    {
+        Ok(deleted) => Ok(ApiResponse::success(crate::core::DeleteData { deleted })),
+        Err(e) => Ok(ApiResponse::from_bridge_error(e)),
+    }
+}
+
+/// POST /api/inventory/:id/adjust - 手动调整 (进货、报损)
+#[tauri::command]
+pub async fn adjust_stock(
+    bridge: State<'_, Arc<ClientBridge>>,
+    id: i64,
+    data: StockAdjustmentCreate,
+) -> Result<ApiResponse<StockLevel>, String> {
+    match bridge
+        .post::<StockLevel, _>(&format!("/api/inventory/{}/adjust", id), &data)
+        .await
+    {
+        Ok(level) => Ok(ApiResponse::success(level)),
+        Err(e) => Ok(ApiResponse::from_bridge_error(e)),
+    }
+}
+
+/// GET /api/inventory/:id/adjustments - 调整记录 (最新在前)
+#[tauri::command]
+pub async fn list_stock_adjustments(
+    bridge: State<'_, Arc<ClientBridge>>,
+    id: i64,
+    limit: Option<i64>,
+) -> Result<ApiResponse<Vec<StockAdjustment>>, String> {
+    let path = match limit {
+        Some(limit) => format!("/api/inventory/{}/adjustments?limit={}", id, limit),
+        None => format!("/api/inventory/{}/adjustments", id),
+    };
+    match bridge.get::<Vec<StockAdjustment>>(&path).await {
+        Ok(adjustments) => Ok(ApiResponse::success(adjustments)),
+        Err(e) => Ok(ApiResponse::from_bridge_error(e)),
+    }
+}
+
+/// GET /api/stock-counts - 盘点单 (最新在前，不含明细)
+#[tauri::command]
+pub async fn list_stock_counts(
+    bridge: State<'_, Arc<ClientBridge>>,
+    limit: Option<i64>,
+) -> Result<ApiResponse<Vec<StockCount>>, String> {
+    let path = match limit {
+        Some(limit) => format!("/api/stock-counts?limit={}", limit),
+        None => "/api/stock-counts".to_string(),
+    };
+    match bridge.get::<Vec<StockCount>>(&path).await {
+        Ok(counts) => Ok(ApiResponse::success(counts)),
+        Err(e) => Ok(ApiResponse::from_bridge_error(e)),
+    }
+}
+
+/// GET /api/stock-counts/:id - 盘点单及明细
+#[tauri::command]
+pub async fn get_stock_count(
+    bridge: State<'_, Arc<ClientBridge>>,
+    id: i64,
+) -> Result<ApiResponse<StockCount>, String> {
+    match bridge
+        .get::<StockCount>(&format!("/api/stock-counts/{}", id))
+        .await
+    {
+        Ok(count) => Ok(ApiResponse::success(count)),
+        Err(e) => Ok(ApiResponse::from_bridge_error(e)),
+    }
+}
+
+/// POST /api/stock-counts - 开始盘点 (冻结理论数量)
+#[tauri::command]
+pub async fn start_stock_count(
+    bridge: State<'_, Arc<ClientBridge>>,
+    data: StockCountCreate,
+) -> Result<ApiResponse<StockCount>, String> {
+    match bridge
+        .post::<StockCount, _>("/api/stock-counts", &data)
+        .await
+    {
+        Ok(count) => Ok(ApiResponse::success(count)),
+        Err(e) => Ok(ApiResponse::from_bridge_error(e)),
+    }
+}
+
+/// PUT /api/stock-counts/:id/lines/:stock_level_id - 录入实盘数量
+#[tauri::command]
+pub async fn record_stock_count(
+    bridge: State<'_, Arc<ClientBridge>>,
+    id: i64,
+    stock_level_id: i64,
+    data: StockCountEntry,
+) -> Result<ApiResponse<StockCountLine>, String> {
+    match bridge
+        .put::<StockCountLine, _>(
+            &format!("/api/stock-counts/{}/lines/{}", id, stock_level_id),
+            &data,
+        )
+        .await
+    {
+        Ok(line) => Ok(ApiResponse::success(line)),
+        Err(e) => Ok(ApiResponse::from_bridge_error(e)),
+    }
+}
+
+/// POST /api/stock-counts/:id/scan - 扫码计数 (累加)
+#[tauri::command]
+pub async fn scan_stock_count(
+    bridge: State<'_, Arc<ClientBridge>>,
+    id: i64,
+    data: StockCountScan,
+) -> Result<ApiResponse<StockCountLine>, String> {
+    match bridge
+        .post::<StockCountLine, _>(&format!("/api/stock-counts/{}/scan", id), &data)
+        .await
+    {
+        Ok(line) => Ok(ApiResponse::success(line)),
+        Err(e) => Ok(ApiResponse::from_bridge_error(e)),
+    }
+}
+
+/// POST /api/stock-counts/:id/submit - 提交审批
+#[tauri::command]
+pub async fn submit_stock_count(
+    bridge: State<'_, Arc<ClientBridge>>,
+    id: i64,
+) -> Result<ApiResponse<StockCount>, String> {
+    match bridge
+        .post::<StockCount, _>(&format!("/api/stock-counts/{}/submit", id), &())
+        .await
+    {
+        Ok(count) => Ok(ApiResponse::success(count)),
+        Err(e) => Ok(ApiResponse::from_bridge_error(e)),
+    }
+}
+
+/// POST /api/stock-counts/:id/approve - 审批通过，过账差异
+#[tauri::command]
+pub async fn approve_stock_count(
+    bridge: State<'_, Arc<ClientBridge>>,
+    id: i64,
+    data: StockCountReview,
+) -> Result<ApiResponse<StockCount>, String> {
+    match bridge
+        .post::<StockCount, _>(&format!("/api/stock-counts/{}/approve", id), &data)
+        .await
+    {
+        Ok(count) => Ok(ApiResponse::success(count)),
+        Err(e) => Ok(ApiResponse::from_bridge_error(e)),
+    }
+}
+
+/// POST /api/stock-counts/:id/reject - 驳回 (不过账)
+#[tauri::command]
+pub async fn reject_stock_count(
+    bridge: State<'_, Arc<ClientBridge>>,
+    id: i64,
+    data: StockCountReview,
+) -> Result<ApiResponse<StockCount>, String> {
+    match bridge
+        .post::<StockCount, _>(&format!("/api/stock-counts/{}/reject", id), &data)
+        .await
+    {
+        Ok(count) => Ok(ApiResponse::success(count)),
+        Err(e) => Ok(ApiResponse::from_bridge_error(e)),
+    }
+}
+
+/// GET /api/stock-transfers - 调拨单 (发出 + 收到，最新在前，不含明细)
+#[tauri::command]
+pub async fn list_stock_transfers(
+    bridge: State<'_, Arc<ClientBridge>>,
+    limit: Option<i64>,
+) -> Result<ApiResponse<Vec<StockTransfer>>, String> {
+    let path = match limit {
+        Some(limit) => format!("/api/stock-transfers?limit={}", limit),
+        None => "/api/stock-transfers".to_string(),
+    };
+    match bridge.get::<Vec<StockTransfer>>(&path).await {
+        Ok(transfers) => Ok(ApiResponse::success(transfers)),
+        Err(e) => Ok(ApiResponse::from_bridge_error(e)),
+    }
+}
+
+/// GET /api/stock-transfers/peers - 可接收调拨的同租户门店
+#[tauri::command]
+pub async fn list_transfer_peers(
+    bridge: State<'_, Arc<ClientBridge>>,
+) -> Result<ApiResponse<Vec<TransferPeer>>, String> {
+    match bridge
+        .get::<Vec<TransferPeer>>("/api/stock-transfers/peers")
+        .await
+    {
+        Ok(peers) => Ok(ApiResponse::success(peers)),
+        Err(e) => Ok(ApiResponse::from_bridge_error(e)),
+    }
+}
+
+/// GET /api/stock-transfers/:id - 调拨单及明细
+#[tauri::command]
+pub async fn get_stock_transfer(
+    bridge: State<'_, Arc<ClientBridge>>,
+    id: i64,
+) -> Result<ApiResponse<StockTransfer>, String> {
+    match bridge
+        .get::<StockTransfer>(&format!("/api/stock-transfers/{}", id))
+        .await
+    {
+        Ok(transfer) => Ok(ApiResponse::success(transfer)),
+        Err(e) => Ok(ApiResponse::from_bridge_error(e)),
+    }
+}
+
+/// POST /api/stock-transfers - 发货 (过账出库，经 cloud 发往收货门店)
+#[tauri::command]
+pub async fn send_stock_transfer(
+    bridge: State<'_, Arc<ClientBridge>>,
+    data: StockTransferCreate,
+) -> Result<ApiResponse<StockTransfer>, String> {
+    match bridge
+        .post::<StockTransfer, _>("/api/stock-transfers", &data)
+        .await
+    {
+        Ok(transfer) => Ok(ApiResponse::success(transfer)),
+        Err(e) => Ok(ApiResponse::from_bridge_error(e)),
+    }
+}
+
+/// POST /api/stock-transfers/:id/receive - 收货确认 (过账入库)
+#[tauri::command]
+pub async fn receive_stock_transfer(
+    bridge: State<'_, Arc<ClientBridge>>,
+    id: i64,
+    data: StockTransferReceive,
+) -> Result<ApiResponse<StockTransfer>, String> {
+    match bridge
+        .post::<StockTransfer, _>(&format!("/api/stock-transfers/{}/receive", id), &data)
+        .await
+    {
+        Ok(transfer) => Ok(ApiResponse::success(transfer)),
+        Err(e) => Ok(ApiResponse::from_bridge_error(e)),
+    }
+}
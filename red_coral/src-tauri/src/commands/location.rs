@@ -8,7 +8,8 @@ use tauri::State;
 use crate::core::response::{ApiResponse, ErrorCode};
 use crate::core::ClientBridge;
 use shared::models::{
-    DiningTable, DiningTableCreate, DiningTableUpdate, Zone, ZoneCreate, ZoneUpdate,
+    DiningTable, DiningTableCreate, DiningTableUpdate, Zone, ZoneCreate, ZoneProductOverride,
+    ZoneProductOverrideUpsert, ZoneUpdate,
 };
 
 // ============ Zones ============
@@ -88,6 +89,65 @@ pub async fn delete_zone(
     }
 }
 
+#[tauri::command]
+pub async fn list_zone_overrides(
+    bridge: State<'_, Arc<ClientBridge>>,
+    zone_id: i64,
+) -> Result<ApiResponse<Vec<ZoneProductOverride>>, String> {
+    match bridge
+        .get::<Vec<ZoneProductOverride>>(&format!("/api/zones/{}/overrides", zone_id))
+        .await
+    {
+        Ok(overrides) => Ok(ApiResponse::success(overrides)),
+        Err(e) => Ok(ApiResponse::error_with_code(
+            ErrorCode::DatabaseError,
+            e.to_string(),
+        )),
+    }
+}
+
+#[tauri::command]
+pub async fn set_zone_override(
+    bridge: State<'_, Arc<ClientBridge>>,
+    zone_id: i64,
+    product_id: i64,
+    data: ZoneProductOverrideUpsert,
+) -> Result<ApiResponse<ZoneProductOverride>, String> {
+    match bridge
+        .put::<ZoneProductOverride, _>(
+            &format!("/api/zones/{}/overrides/{}", zone_id, product_id),
+            &data,
+        )
+        .await
+    {
+        Ok(o) => Ok(ApiResponse::success(o)),
+        Err(e) => Ok(ApiResponse::error_with_code(
+            ErrorCode::DatabaseError,
+            e.to_string(),
+        )),
+    }
+}
+
+#[tauri::command]
+pub async fn delete_zone_override(
+    bridge: State<'_, Arc<ClientBridge>>,
+    zone_id: i64,
+    product_id: i64,
+) -> Result<ApiResponse<crate::core::DeleteData>, String> {
+    match bridge
+        .delete::<bool>(&format!("/api/zones/{}/overrides/{}", zone_id, product_id))
+        .await
+    {
+        Ok(success) => Ok(ApiResponse::success(crate::core::DeleteData {
+            deleted: success,
+        })),
+        Err(e) => Ok(ApiResponse::error_with_code(
+            ErrorCode::DatabaseError,
+            e.to_string(),
+        )),
+    }
+}
+
 // ============ Dining Tables ============
 
 #[tauri::command]
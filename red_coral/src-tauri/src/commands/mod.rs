@@ -10,6 +10,7 @@ pub mod credit_notes;
 pub mod data;
 pub mod health;
 pub mod image;
+pub mod inventory;
 pub mod location;
 pub mod mode;
 pub mod order_es;
@@ -17,6 +18,7 @@ pub mod orders;
 pub mod printer;
 pub mod shift;
 pub mod statistics;
+pub mod support;
 pub mod sync;
 pub mod system;
 pub mod tenant;
@@ -29,6 +31,7 @@ pub use credit_notes::*;
 pub use data::*;
 pub use health::*;
 pub use image::*;
+pub use inventory::*;
 pub use location::*;
 pub use mode::*;
 pub use order_es::*;
@@ -36,6 +39,7 @@ pub use orders::*;
 pub use printer::*;
 pub use shift::*;
 pub use statistics::*;
+pub use support::*;
 pub use sync::*;
 pub use system::*;
 pub use tenant::*;
@@ -5,7 +5,7 @@ use tauri::{Emitter, State};
 
 use crate::core::bridge::InitStatus;
 use crate::core::response::{ApiResponse, AppConfigResponse, ErrorCode};
-use crate::core::{AppState, ClientBridge, ModeInfo, ModeType};
+use crate::core::{AppState, ClientBridge, ModeInfo, ModeType, TerminalProfileInfo};
 
 /// 查询后端初始化状态 (先于 get_app_state 调用)
 #[tauri::command]
@@ -62,6 +62,14 @@ pub async fn get_mode_info(
     Ok(ApiResponse::success(bridge.get_mode_info().await))
 }
 
+/// 获取本终端的漫游配置 (Client 模式连接时下发)
+#[tauri::command]
+pub async fn get_terminal_profile(
+    bridge: State<'_, Arc<ClientBridge>>,
+) -> Result<ApiResponse<TerminalProfileInfo>, String> {
+    Ok(ApiResponse::success(bridge.get_terminal_profile().await))
+}
+
 /// 启动 Server 模式
 #[tauri::command]
 pub async fn start_server_mode(
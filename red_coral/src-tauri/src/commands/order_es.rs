@@ -9,6 +9,7 @@ use shared::order::{
 use std::sync::Arc;
 use tauri::State;
 
+use crate::core::bridge::BridgeError;
 use crate::core::response::ErrorCode;
 use crate::core::{ApiResponse, ClientBridge};
 
@@ -26,6 +27,9 @@ pub async fn order_execute_command(
 ) -> Result<ApiResponse<CommandResponse>, String> {
     match bridge.execute_order_command(command).await {
         Ok(response) => Ok(ApiResponse::success(response)),
+        Err(e @ (BridgeError::PermissionDenied(_) | BridgeError::SessionLocked)) => {
+            Ok(ApiResponse::from_bridge_error(e))
+        }
         Err(e) => Ok(ApiResponse::error_with_code(
             ErrorCode::DatabaseError,
             e.to_string(),
@@ -46,6 +50,9 @@ pub async fn order_execute(
     let command = OrderCommand::new(operator_id, operator_name, payload);
     match bridge.execute_order_command(command).await {
         Ok(response) => Ok(ApiResponse::success(response)),
+        Err(e @ (BridgeError::PermissionDenied(_) | BridgeError::SessionLocked)) => {
+            Ok(ApiResponse::from_bridge_error(e))
+        }
         Err(e) => Ok(ApiResponse::error_with_code(
             ErrorCode::DatabaseError,
             e.to_string(),
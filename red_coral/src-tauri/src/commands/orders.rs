@@ -133,6 +133,25 @@ pub async fn fetch_order_invoices(
     }
 }
 
+/// Email an archived order's receipt to the customer (delivered by crab-cloud)
+#[tauri::command]
+pub async fn email_order_receipt(
+    bridge: State<'_, Arc<ClientBridge>>,
+    order_pk: i64,
+    to: String,
+) -> Result<ApiResponse<serde_json::Value>, String> {
+    match bridge
+        .post::<serde_json::Value, _>(
+            &format!("/api/orders/{}/email-receipt", order_pk),
+            &serde_json::json!({ "to": to }),
+        )
+        .await
+    {
+        Ok(data) => Ok(ApiResponse::success(data)),
+        Err(e) => Ok(ApiResponse::from_bridge_error(e)),
+    }
+}
+
 /// Fetch archived order detail by ID (graph model)
 /// Uses serde_json::Value to transparently pass through all fields from edge-server
 #[tauri::command]
@@ -2,10 +2,15 @@
 //!
 //! 提供打印机列表、钱箱控制、收据打印等功能
 
+use std::sync::Arc;
+
 use crate::api::printers::ReceiptData;
 use crate::core::response::{ApiResponse, ErrorCode};
+use crate::core::ClientBridge;
 use crate::utils::printing;
 use serde::Deserialize;
+use shared::models::{ReceiptArtifactCreate, ReceiptArtifactKind};
+use tauri::State;
 
 /// 获取本地驱动打印机列表
 #[tauri::command]
@@ -45,10 +50,15 @@ pub fn open_cash_drawer(printer_name: Option<String>) -> Result<ApiResponse<()>,
 }
 
 /// 打印收据
+///
+/// `archive`: 门店开启收据存档时为 true，打印成功后把实际打印字节上传到服务端存档
+/// (预结单不存档；上传在后台进行，失败只记录日志，不影响打印结果)
 #[tauri::command]
-pub fn print_receipt(
+pub async fn print_receipt(
+    bridge: State<'_, Arc<ClientBridge>>,
     printer_name: Option<String>,
     receipt: ReceiptData,
+    archive: Option<bool>,
 ) -> Result<ApiResponse<()>, String> {
     tracing::debug!(
         printer = ?printer_name,
@@ -56,21 +66,114 @@ pub fn print_receipt(
         items = receipt.items.len(),
         "print_receipt: entry"
     );
-    match printing::print_receipt(printer_name, receipt) {
-        Ok(()) => Ok(ApiResponse::success(())),
-        Err(e) => {
-            if e == "PRINTING_NOT_SUPPORTED" {
-                Ok(ApiResponse::error_with_code(
-                    ErrorCode::PrinterNotAvailable,
-                    ErrorCode::PrinterNotAvailable.message().to_string(),
-                ))
-            } else {
-                Ok(ApiResponse::error_with_code(ErrorCode::PrintFailed, e))
+    let archive_as = archive_target(archive, &receipt);
+    let result = tauri::async_runtime::spawn_blocking(move || {
+        printing::print_receipt(printer_name, receipt)
+    })
+    .await
+    .map_err(|e| e.to_string())?;
+    match result {
+        Ok(bytes) => {
+            if let Some((receipt_number, reprint)) = archive_as {
+                spawn_archive_upload(
+                    bridge.inner().clone(),
+                    vec![(receipt_number, reprint, bytes)],
+                );
+            }
+            Ok(ApiResponse::success(()))
+        }
+        Err(e) => Ok(print_error_response(e)),
+    }
+}
+
+/// 批量打印收据（按人分单），所有收据作为一个打印任务提交
+#[tauri::command]
+pub async fn print_receipts(
+    bridge: State<'_, Arc<ClientBridge>>,
+    printer_name: Option<String>,
+    receipts: Vec<ReceiptData>,
+    archive: Option<bool>,
+) -> Result<ApiResponse<()>, String> {
+    tracing::debug!(
+        printer = ?printer_name,
+        count = receipts.len(),
+        "print_receipts: entry"
+    );
+    if receipts.is_empty() {
+        return Ok(ApiResponse::error_with_code(
+            ErrorCode::ValidationFailed,
+            "No receipts to print".to_string(),
+        ));
+    }
+    let archive_as: Vec<Option<(String, bool)>> = receipts
+        .iter()
+        .map(|r| archive_target(archive, r))
+        .collect();
+    let result = tauri::async_runtime::spawn_blocking(move || {
+        printing::print_receipts(printer_name, receipts)
+    })
+    .await
+    .map_err(|e| e.to_string())?;
+    match result {
+        Ok(rendered) => {
+            let uploads: Vec<_> = archive_as
+                .into_iter()
+                .zip(rendered)
+                .filter_map(|(target, bytes)| {
+                    target.map(|(receipt_number, reprint)| (receipt_number, reprint, bytes))
+                })
+                .collect();
+            if !uploads.is_empty() {
+                spawn_archive_upload(bridge.inner().clone(), uploads);
             }
+            Ok(ApiResponse::success(()))
         }
+        Err(e) => Ok(print_error_response(e)),
+    }
+}
+
+fn print_error_response(e: String) -> ApiResponse<()> {
+    if e == "PRINTING_NOT_SUPPORTED" {
+        ApiResponse::error_with_code(
+            ErrorCode::PrinterNotAvailable,
+            ErrorCode::PrinterNotAvailable.message().to_string(),
+        )
+    } else {
+        ApiResponse::error_with_code(ErrorCode::PrintFailed, e)
     }
 }
 
+/// 需要存档时返回 (收据号, 是否重打)
+fn archive_target(archive: Option<bool>, receipt: &ReceiptData) -> Option<(String, bool)> {
+    (archive.unwrap_or(false) && !receipt.pre_payment)
+        .then(|| (receipt.order_id.clone(), receipt.reprint))
+}
+
+/// 后台上传收据打印原文到服务端存档 (POST /api/receipt-archive)
+fn spawn_archive_upload(bridge: Arc<ClientBridge>, receipts: Vec<(String, bool, Vec<u8>)>) {
+    use base64::Engine;
+
+    tauri::async_runtime::spawn(async move {
+        for (receipt_number, reprint, bytes) in receipts {
+            let body = ReceiptArtifactCreate {
+                receipt_number: receipt_number.clone(),
+                kind: ReceiptArtifactKind::EscPos,
+                content_base64: base64::engine::general_purpose::STANDARD.encode(&bytes),
+                reprint,
+            };
+            if let Err(e) = bridge
+                .post::<serde_json::Value, _>("/api/receipt-archive", &body)
+                .await
+            {
+                tracing::warn!(
+                    receipt_number = %receipt_number,
+                    "Receipt archive upload failed: {e}"
+                );
+            }
+        }
+    });
+}
+
 /// 标签打印请求参数
 #[derive(Debug, Deserialize)]
 pub struct LabelPrintRequest {
@@ -8,7 +8,8 @@ use urlencoding::encode;
 
 use crate::core::{ApiResponse, ClientBridge};
 use shared::models::{
-    DailyReport, DailyReportGenerate, Shift, ShiftClose, ShiftCreate, ShiftForceClose, ShiftUpdate,
+    DailyReport, DailyReportGenerate, Shift, ShiftBreak, ShiftBreakStart, ShiftBreakStatus,
+    ShiftClose, ShiftCreate, ShiftForceClose, ShiftUpdate,
 };
 
 // ============ Shifts ============
@@ -151,6 +152,51 @@ pub async fn recover_stale_shifts(
     }
 }
 
+// ============ Shift Breaks ============
+
+#[tauri::command]
+pub async fn get_shift_breaks(
+    bridge: State<'_, Arc<ClientBridge>>,
+    id: i64,
+) -> Result<ApiResponse<ShiftBreakStatus>, String> {
+    match bridge
+        .get::<ShiftBreakStatus>(&format!("/api/shifts/{}/breaks", id))
+        .await
+    {
+        Ok(status) => Ok(ApiResponse::success(status)),
+        Err(e) => Ok(ApiResponse::from_bridge_error(e)),
+    }
+}
+
+#[tauri::command]
+pub async fn start_shift_break(
+    bridge: State<'_, Arc<ClientBridge>>,
+    id: i64,
+    data: ShiftBreakStart,
+) -> Result<ApiResponse<ShiftBreak>, String> {
+    match bridge
+        .post::<ShiftBreak, _>(&format!("/api/shifts/{}/breaks/start", id), &data)
+        .await
+    {
+        Ok(b) => Ok(ApiResponse::success(b)),
+        Err(e) => Ok(ApiResponse::from_bridge_error(e)),
+    }
+}
+
+#[tauri::command]
+pub async fn end_shift_break(
+    bridge: State<'_, Arc<ClientBridge>>,
+    id: i64,
+) -> Result<ApiResponse<ShiftBreak>, String> {
+    match bridge
+        .post::<ShiftBreak, _>(&format!("/api/shifts/{}/breaks/end", id), &())
+        .await
+    {
+        Ok(b) => Ok(ApiResponse::success(b)),
+        Err(e) => Ok(ApiResponse::from_bridge_error(e)),
+    }
+}
+
 // ============ Daily Reports ============
 
 #[tauri::command]
@@ -49,6 +49,24 @@ pub struct StoreOverview {
     pub discount_breakdown: Vec<AdjustmentEntry>,
     #[serde(default)]
     pub surcharge_breakdown: Vec<AdjustmentEntry>,
+    #[serde(default)]
+    pub scope: ReportScope,
+}
+
+/// Caller's report visibility (older servers omit it: full visibility)
+#[derive(Debug, Clone, Copy, Serialize, Deserialize)]
+pub struct ReportScope {
+    pub financials: bool,
+    pub labor: bool,
+}
+
+impl Default for ReportScope {
+    fn default() -> Self {
+        Self {
+            financials: true,
+            labor: true,
+        }
+    }
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -280,6 +298,8 @@ pub struct RedFlagsResponse {
     pub order_flags: OrderFlags,
     pub payment_flags: PaymentFlags,
     pub operator_breakdown: Vec<OperatorRedFlags>,
+    #[serde(default)]
+    pub scope: ReportScope,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -400,3 +420,24 @@ pub async fn list_invoices(
         }
     }
 }
+
+// ============================================================================
+// Live KPI (经理看板)
+// ============================================================================
+
+/// 获取实时经营指标快照（之后由 `kpi` 服务器消息推送）
+#[tauri::command]
+pub async fn get_kpi_snapshot(
+    bridge: State<'_, Arc<ClientBridge>>,
+) -> Result<ApiResponse<shared::message::KpiPayload>, String> {
+    match bridge
+        .get::<shared::message::KpiPayload>("/api/statistics/kpi")
+        .await
+    {
+        Ok(data) => Ok(ApiResponse::success(data)),
+        Err(e) => {
+            warn!(error = %e, "get_kpi_snapshot failed");
+            Ok(ApiResponse::from_bridge_error(e))
+        }
+    }
+}
@@ -0,0 +1,66 @@
+//! Remote Support Commands
+//!
+//! 远程支持会话 — 代理到 edge-server REST API
+
+use std::sync::Arc;
+use tauri::State;
+
+use crate::core::{ApiResponse, ClientBridge};
+use shared::cloud::support::{
+    SupportMessage, SupportMessageCreate, SupportSession, SupportSessionOpen, SupportSessionView,
+};
+
+/// GET /api/support - 当前会话 (无会话时为 null)
+#[tauri::command]
+pub async fn get_support_session(
+    bridge: State<'_, Arc<ClientBridge>>,
+) -> Result<ApiResponse<Option<SupportSessionView>>, String> {
+    match bridge
+        .get::<Option<SupportSessionView>>("/api/support")
+        .await
+    {
+        Ok(view) => Ok(ApiResponse::success(view)),
+        Err(e) => Ok(ApiResponse::from_bridge_error(e)),
+    }
+}
+
+/// POST /api/support - 开启支持会话 (需要用户同意共享日志)
+#[tauri::command]
+pub async fn open_support_session(
+    bridge: State<'_, Arc<ClientBridge>>,
+    data: SupportSessionOpen,
+) -> Result<ApiResponse<SupportSession>, String> {
+    match bridge
+        .post::<SupportSession, _>("/api/support", &data)
+        .await
+    {
+        Ok(session) => Ok(ApiResponse::success(session)),
+        Err(e) => Ok(ApiResponse::from_bridge_error(e)),
+    }
+}
+
+/// POST /api/support/messages - 向支持人员发送消息
+#[tauri::command]
+pub async fn send_support_message(
+    bridge: State<'_, Arc<ClientBridge>>,
+    data: SupportMessageCreate,
+) -> Result<ApiResponse<SupportMessage>, String> {
+    match bridge
+        .post::<SupportMessage, _>("/api/support/messages", &data)
+        .await
+    {
+        Ok(message) => Ok(ApiResponse::success(message)),
+        Err(e) => Ok(ApiResponse::from_bridge_error(e)),
+    }
+}
+
+/// DELETE /api/support - 结束支持会话
+#[tauri::command]
+pub async fn close_support_session(
+    bridge: State<'_, Arc<ClientBridge>>,
+) -> Result<ApiResponse<bool>, String> {
+    match bridge.delete::<bool>("/api/support").await {
+        Ok(closed) => Ok(ApiResponse::success(closed)),
+        Err(e) => Ok(ApiResponse::from_bridge_error(e)),
+    }
+}
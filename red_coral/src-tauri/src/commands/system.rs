@@ -105,6 +105,9 @@ pub async fn update_store_info(
     bridge: State<'_, Arc<ClientBridge>>,
     data: StoreInfoUpdate,
 ) -> Result<ApiResponse<StoreInfo>, String> {
+    if let Err(e) = bridge.authorize_command("update_store_info").await {
+        return Ok(ApiResponse::from_bridge_error(e));
+    }
     match bridge.put("/api/store-info", &data).await {
         Ok(info) => Ok(ApiResponse::success(info)),
         Err(e) => Ok(ApiResponse::error_with_code(
@@ -239,6 +242,9 @@ pub async fn create_employee(
     bridge: State<'_, Arc<ClientBridge>>,
     data: EmployeeCreate,
 ) -> Result<ApiResponse<Employee>, String> {
+    if let Err(e) = bridge.authorize_command("create_employee").await {
+        return Ok(ApiResponse::from_bridge_error(e));
+    }
     match bridge.post("/api/employees", &data).await {
         Ok(employee) => Ok(ApiResponse::success(employee)),
         Err(e) => Ok(ApiResponse::error_with_code(
@@ -254,6 +260,9 @@ pub async fn update_employee(
     id: i64,
     data: EmployeeUpdate,
 ) -> Result<ApiResponse<Employee>, String> {
+    if let Err(e) = bridge.authorize_command("update_employee").await {
+        return Ok(ApiResponse::from_bridge_error(e));
+    }
     match bridge.put(&format!("/api/employees/{}", id), &data).await {
         Ok(employee) => Ok(ApiResponse::success(employee)),
         Err(e) => Ok(ApiResponse::error_with_code(
@@ -268,6 +277,9 @@ pub async fn delete_employee(
     bridge: State<'_, Arc<ClientBridge>>,
     id: i64,
 ) -> Result<ApiResponse<DeleteData>, String> {
+    if let Err(e) = bridge.authorize_command("delete_employee").await {
+        return Ok(ApiResponse::from_bridge_error(e));
+    }
     match bridge
         .delete::<bool>(&format!("/api/employees/{}", id))
         .await
@@ -314,6 +326,9 @@ pub async fn create_price_rule(
     bridge: State<'_, Arc<ClientBridge>>,
     data: PriceRuleCreate,
 ) -> Result<ApiResponse<PriceRule>, String> {
+    if let Err(e) = bridge.authorize_command("create_price_rule").await {
+        return Ok(ApiResponse::from_bridge_error(e));
+    }
     match bridge.post("/api/price-rules", &data).await {
         Ok(rule) => Ok(ApiResponse::success(rule)),
         Err(e) => Ok(ApiResponse::error_with_code(
@@ -329,6 +344,9 @@ pub async fn update_price_rule(
     id: i64,
     data: PriceRuleUpdate,
 ) -> Result<ApiResponse<PriceRule>, String> {
+    if let Err(e) = bridge.authorize_command("update_price_rule").await {
+        return Ok(ApiResponse::from_bridge_error(e));
+    }
     match bridge.put(&format!("/api/price-rules/{}", id), &data).await {
         Ok(rule) => Ok(ApiResponse::success(rule)),
         Err(e) => Ok(ApiResponse::error_with_code(
@@ -343,6 +361,9 @@ pub async fn delete_price_rule(
     bridge: State<'_, Arc<ClientBridge>>,
     id: i64,
 ) -> Result<ApiResponse<DeleteData>, String> {
+    if let Err(e) = bridge.authorize_command("delete_price_rule").await {
+        return Ok(ApiResponse::from_bridge_error(e));
+    }
     match bridge
         .delete::<bool>(&format!("/api/price-rules/{}", id))
         .await
@@ -389,6 +410,9 @@ pub async fn create_role(
     bridge: State<'_, Arc<ClientBridge>>,
     data: RoleCreate,
 ) -> Result<ApiResponse<Role>, String> {
+    if let Err(e) = bridge.authorize_command("create_role").await {
+        return Ok(ApiResponse::from_bridge_error(e));
+    }
     match bridge.post("/api/roles", &data).await {
         Ok(role) => Ok(ApiResponse::success(role)),
         Err(e) => Ok(ApiResponse::error_with_code(
@@ -404,6 +428,9 @@ pub async fn update_role(
     id: i64,
     data: RoleUpdate,
 ) -> Result<ApiResponse<Role>, String> {
+    if let Err(e) = bridge.authorize_command("update_role").await {
+        return Ok(ApiResponse::from_bridge_error(e));
+    }
     match bridge.put(&format!("/api/roles/{}", id), &data).await {
         Ok(role) => Ok(ApiResponse::success(role)),
         Err(e) => Ok(ApiResponse::error_with_code(
@@ -418,6 +445,9 @@ pub async fn delete_role(
     bridge: State<'_, Arc<ClientBridge>>,
     id: i64,
 ) -> Result<ApiResponse<DeleteData>, String> {
+    if let Err(e) = bridge.authorize_command("delete_role").await {
+        return Ok(ApiResponse::from_bridge_error(e));
+    }
     match bridge.delete::<bool>(&format!("/api/roles/{}", id)).await {
         Ok(deleted) => Ok(ApiResponse::success(DeleteData { deleted })),
         Err(e) => Ok(ApiResponse::error_with_code(
@@ -473,6 +503,9 @@ pub async fn update_role_permissions(
     role_id: i64,
     permissions: Vec<String>,
 ) -> Result<ApiResponse<()>, String> {
+    if let Err(e) = bridge.authorize_command("update_role_permissions").await {
+        return Ok(ApiResponse::from_bridge_error(e));
+    }
     match bridge
         .put::<(), _>(&format!("/api/roles/{}/permissions", role_id), &permissions)
         .await
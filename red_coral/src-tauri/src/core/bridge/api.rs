@@ -72,7 +72,8 @@ impl ClientBridge {
 
     /// 更新 Server 模式配置 (端口配置)
     ///
-    /// 仅更新配置并保存，不启动模式
+    /// 保存配置；Server 模式运行中则立即热重绑定端口（不断开终端），
+    /// 否则在下次启动时生效
     pub async fn update_server_config(
         &self,
         http_port: u16,
@@ -85,6 +86,13 @@ impl ClientBridge {
             config.save(&self.config_path)?;
         }
         tracing::info!(http_port = %http_port, message_port = %message_port, "Server config updated");
+
+        if let ClientMode::Server { listeners, .. } = &*self.mode.read().await {
+            listeners.rebind(edge_server::ListenerPorts {
+                http_port,
+                message_tcp_port: message_port,
+            });
+        }
         Ok(())
     }
 
@@ -94,6 +94,9 @@ impl ClientBridge {
         drop(mode_guard);
 
         if let Ok(ref session) = result {
+            // 0. 登录成功即解除锁屏 (解锁 / 换人收银 / 重新登录)
+            self.finish_unlock();
+
             // 1. 保存到磁盘
             {
                 let tenant_manager = self.tenant_manager.read().await;
@@ -153,6 +156,9 @@ impl ClientBridge {
 
         drop(mode_guard);
 
+        // 登出后回到登录页，不再保持锁屏
+        self.finish_unlock();
+
         let mut tenant_manager = self.tenant_manager.write().await;
         if let Err(e) = tenant_manager.clear_current_session() {
             tracing::warn!("Failed to clear cached session: {}", e);
@@ -116,6 +116,9 @@ pub struct AppConfig {
     /// Refresh token (用于无需重新输入密码即可获取 JWT)
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub refresh_token: Option<String>,
+    /// 无操作自动锁屏时间 (分钟，None/0 = 不锁屏)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub auto_lock_minutes: Option<u32>,
 }
 
 impl Default for AppConfig {
@@ -129,6 +132,7 @@ impl Default for AppConfig {
             known_tenants: Vec::new(),
             auth_url: default_auth_url(),
             refresh_token: None,
+            auto_lock_minutes: None,
         }
     }
 }
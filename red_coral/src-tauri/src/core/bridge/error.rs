@@ -13,6 +13,12 @@ pub enum BridgeError {
     #[error("Not authenticated")]
     NotAuthenticated,
 
+    #[error("Permission denied: requires {0}")]
+    PermissionDenied(String),
+
+    #[error("Session is locked")]
+    SessionLocked,
+
     #[error("Not implemented: {0}")]
     NotImplemented(String),
 
@@ -184,12 +184,16 @@ impl ClientBridge {
         let cloud_url = server_config.cloud_url.clone();
         let http_port = server_config.http_port;
 
+        // 内嵌 edge 的日志写入 app 日志目录 (远程支持从这里读取)
+        let log_dir = self.config_path.with_file_name("logs");
+
         let edge_config = edge_server::Config::builder()
             .work_dir(work_dir)
             .http_port(server_config.http_port)
             .message_tcp_port(server_config.message_port)
             .auth_server_url(auth_url)
             .cloud_url(cloud_url)
+            .log_dir(log_dir.to_string_lossy())
             .build();
 
         drop(config);
@@ -201,6 +205,7 @@ impl ClientBridge {
         let server_instance =
             edge_server::Server::with_state(edge_config.clone(), server_state.clone());
         let shutdown_token = server_instance.shutdown_token();
+        let listeners = server_instance.listeners();
 
         let server_task = tokio::spawn(async move {
             if let Err(e) = server_instance.run().await {
@@ -236,6 +241,10 @@ impl ClientBridge {
                         result = server_rx.recv() => {
                             match result {
                                 Ok(msg) => {
+                                    // 带 target 的消息是发给某个 TCP 终端的单播，主机终端不显示
+                                    if msg.target.is_some() {
+                                        continue;
+                                    }
                                     use crate::events::MessageRoute;
                                     match MessageRoute::from_bus_message(msg) {
                                         MessageRoute::OrderSync(order_sync) => {
@@ -243,6 +252,11 @@ impl ClientBridge {
                                                 tracing::warn!("Failed to emit order sync: {}", e);
                                             }
                                         }
+                                        MessageRoute::OrderSyncBatch(batch) => {
+                                            if let Err(e) = handle_clone.emit("order-sync-batch", &*batch) {
+                                                tracing::warn!("Failed to emit order sync batch: {}", e);
+                                            }
+                                        }
                                         MessageRoute::ServerMessage(event) => {
                                             tracing::debug!(event_type = %event.event_type, "Emitting server-message");
                                             if let Err(e) = handle_clone.emit("server-message", &event) {
@@ -337,6 +351,7 @@ impl ClientBridge {
                 server_task,
                 listener_task,
                 shutdown_token,
+                listeners,
             };
         }
 
@@ -431,6 +446,8 @@ impl ClientBridge {
                 let mut rx = mc.subscribe();
                 let handle_clone = handle.clone();
                 let token = client_shutdown_token.clone();
+                let order_mirror = self.order_mirror.clone();
+                order_mirror.clear();
 
                 listener_tasks.push(tokio::spawn(async move {
                     loop {
@@ -445,12 +462,23 @@ impl ClientBridge {
                                         use crate::events::MessageRoute;
                                         match MessageRoute::from_bus_message(msg) {
                                             MessageRoute::OrderSync(order_sync) => {
+                                                order_mirror.apply(std::slice::from_ref(
+                                                    &order_sync.snapshot,
+                                                ));
                                                 if let Err(e) =
                                                     handle_clone.emit("order-sync", &*order_sync)
                                                 {
                                                     tracing::warn!("Failed to emit order sync: {}", e);
                                                 }
                                             }
+                                            MessageRoute::OrderSyncBatch(batch) => {
+                                                order_mirror.apply(&batch.snapshots);
+                                                if let Err(e) =
+                                                    handle_clone.emit("order-sync-batch", &*batch)
+                                                {
+                                                    tracing::warn!("Failed to emit order sync batch: {}", e);
+                                                }
+                                            }
                                             MessageRoute::ServerMessage(event) => {
                                                 if let Err(e) = handle_clone.emit("server-message", &event)
                                                 {
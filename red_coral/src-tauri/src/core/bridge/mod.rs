@@ -10,14 +10,18 @@ mod auth;
 mod config;
 mod error;
 mod lifecycle;
+mod optimistic;
 mod order_es;
+mod permission;
+mod session_lock;
 mod state;
 mod types;
 
 // Re-export public types
 pub use config::{resilient_load, AppConfig, ClientModeConfig, ServerModeConfig};
 pub use error::BridgeError;
-pub use types::{AppState, ModeInfo, ModeType};
+pub use permission::{command_permission, COMMAND_PERMISSIONS};
+pub use types::{AppState, ModeInfo, ModeType, TerminalProfileInfo};
 
 // Internal types (pub(crate) for use within this crate)
 pub(crate) use types::{ClientMode, LocalClientState, RemoteClientState};
@@ -70,6 +74,12 @@ pub struct ClientBridge {
     init_state: Mutex<InitState>,
     /// Lifecycle 操作互斥锁 (防止并发 start/stop 竞态)
     lifecycle_lock: tokio::sync::Mutex<()>,
+    /// 会话锁 (无操作自动锁屏)
+    session_lock: session_lock::SessionLock,
+    /// 已验证的主管授权
+    escalations: permission::EscalationGrants,
+    /// Client 模式权威订单快照镜像 (乐观预演的基准)
+    order_mirror: Arc<optimistic::OrderMirror>,
 }
 
 impl ClientBridge {
@@ -109,6 +119,9 @@ impl ClientBridge {
             app_handle,
             init_state: Mutex::new(InitState::Pending),
             lifecycle_lock: tokio::sync::Mutex::new(()),
+            session_lock: session_lock::SessionLock::new(),
+            escalations: permission::EscalationGrants::default(),
+            order_mirror: Arc::new(optimistic::OrderMirror::default()),
         })
     }
 
@@ -0,0 +1,175 @@
+//! Optimistic order command echo (Client mode)
+//!
+//! Client 模式下每个订单命令都要等服务端往返，远程终端操作明显慢于 Server 模式。
+//! 这里在发出请求前，用 edge-server 相同的 action + applier 逻辑
+//! (`edge_server::orders::predict`) 基于已知的权威快照预演命令，
+//! 立即发出 "order-optimistic" 供前端显示。
+//!
+//! - 权威状态：`OrderMirror` 只保存服务端广播/同步的快照，从不写入预测结果
+//! - 对账：服务端 order-sync 到达后前端直接用权威快照覆盖预测
+//! - 回滚：命令失败时发出 "order-optimistic-rollback"，携带当前权威快照
+
+use std::collections::HashMap;
+
+use edge_server::orders::predict;
+use shared::order::OrderStatus;
+
+use super::*;
+use crate::events::{OrderOptimisticPayload, OrderOptimisticRollbackPayload};
+
+/// 服务端权威快照镜像 (仅活跃订单)
+#[derive(Default)]
+pub(crate) struct OrderMirror {
+    snapshots: Mutex<HashMap<i64, OrderSnapshot>>,
+}
+
+impl OrderMirror {
+    /// 全量同步后替换
+    pub(crate) fn replace_all(&self, snapshots: &[OrderSnapshot]) {
+        let mut guard = self.snapshots.lock().unwrap_or_else(|e| e.into_inner());
+        guard.clear();
+        for snapshot in snapshots {
+            if snapshot.status == OrderStatus::Active {
+                guard.insert(snapshot.order_id, snapshot.clone());
+            }
+        }
+    }
+
+    /// 应用服务端广播的快照 (终态订单移出镜像)
+    pub(crate) fn apply(&self, snapshots: &[OrderSnapshot]) {
+        let mut guard = self.snapshots.lock().unwrap_or_else(|e| e.into_inner());
+        for snapshot in snapshots {
+            if snapshot.status == OrderStatus::Active {
+                guard.insert(snapshot.order_id, snapshot.clone());
+            } else {
+                guard.remove(&snapshot.order_id);
+            }
+        }
+    }
+
+    pub(crate) fn clear(&self) {
+        self.snapshots
+            .lock()
+            .unwrap_or_else(|e| e.into_inner())
+            .clear();
+    }
+
+    fn get(&self, order_id: i64) -> Option<OrderSnapshot> {
+        self.snapshots
+            .lock()
+            .unwrap_or_else(|e| e.into_inner())
+            .get(&order_id)
+            .cloned()
+    }
+}
+
+/// 预演命令，返回预测快照 (None = 不可预演)
+///
+/// 预测快照的 `last_sequence` 还原为预演前的权威值：本地序号不是全局序号，
+/// 不能参与前端的 gap 检测和同步游标。
+fn predict_with_mirror(
+    mirror: &OrderMirror,
+    command: &OrderCommand,
+) -> Option<predict::Prediction> {
+    if !predict::is_predictable(&command.payload) {
+        return None;
+    }
+    let base = mirror.get(command.target_order_id()?)?;
+    let base_sequence = base.last_sequence;
+    let mut prediction = predict::predict_command(command, std::slice::from_ref(&base))?;
+    for snapshot in &mut prediction.snapshots {
+        snapshot.last_sequence = base_sequence;
+    }
+    Some(prediction)
+}
+
+impl ClientBridge {
+    /// 发出预测结果，返回是否已发出 (决定失败时是否需要回滚)
+    pub(super) fn emit_optimistic(&self, command: &OrderCommand) -> bool {
+        let Some(handle) = &self.app_handle else {
+            return false;
+        };
+        let Some(prediction) = predict_with_mirror(&self.order_mirror, command) else {
+            return false;
+        };
+
+        let payload = OrderOptimisticPayload {
+            command_id: command.command_id,
+            events: prediction.events,
+            snapshots: prediction.snapshots,
+        };
+        match handle.emit("order-optimistic", &payload) {
+            Ok(()) => true,
+            Err(e) => {
+                tracing::warn!("Failed to emit optimistic order update: {}", e);
+                false
+            }
+        }
+    }
+
+    /// 命令失败：用权威快照撤销预测
+    pub(super) fn emit_optimistic_rollback(&self, command: &OrderCommand) {
+        let Some(handle) = &self.app_handle else {
+            return;
+        };
+        let snapshots: Vec<OrderSnapshot> = command
+            .target_order_id()
+            .and_then(|order_id| self.order_mirror.get(order_id))
+            .into_iter()
+            .collect();
+
+        tracing::debug!(command_id = %command.command_id, "Rolling back optimistic order update");
+        let payload = OrderOptimisticRollbackPayload {
+            command_id: command.command_id,
+            snapshots,
+        };
+        if let Err(e) = handle.emit("order-optimistic-rollback", &payload) {
+            tracing::warn!("Failed to emit optimistic rollback: {}", e);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn active(order_id: i64, last_sequence: u64) -> OrderSnapshot {
+        let mut snapshot = OrderSnapshot::new(order_id);
+        snapshot.last_sequence = last_sequence;
+        snapshot
+    }
+
+    #[test]
+    fn test_mirror_drops_terminal_orders() {
+        let mirror = OrderMirror::default();
+        mirror.replace_all(&[active(1, 3), active(2, 4)]);
+
+        let mut completed = active(1, 5);
+        completed.status = OrderStatus::Completed;
+        mirror.apply(&[completed]);
+
+        assert!(mirror.get(1).is_none());
+        assert_eq!(mirror.get(2).map(|s| s.last_sequence), Some(4));
+    }
+
+    #[test]
+    fn test_prediction_keeps_authoritative_sequence() {
+        let mirror = OrderMirror::default();
+        mirror.replace_all(&[active(1, 7)]);
+
+        let command = OrderCommand::new(
+            1,
+            "Ana".to_string(),
+            OrderCommandPayload::AddOrderNote {
+                order_id: 1,
+                note: "terraza".to_string(),
+            },
+        );
+        let prediction = predict_with_mirror(&mirror, &command).expect("prediction");
+        assert_eq!(prediction.snapshots[0].last_sequence, 7);
+        assert_eq!(prediction.snapshots[0].note.as_deref(), Some("terraza"));
+
+        // 镜像只保存权威快照
+        assert_eq!(mirror.get(1).and_then(|s| s.note), None);
+    }
+}
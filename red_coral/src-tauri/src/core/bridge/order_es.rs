@@ -8,6 +8,10 @@ impl ClientBridge {
         &self,
         command: OrderCommand,
     ) -> Result<CommandResponse, BridgeError> {
+        self.ensure_unlocked()?;
+        self.authorize_order_command(&command.payload).await?;
+        self.touch_activity();
+
         let mode_guard = self.mode.read().await;
 
         match &*mode_guard {
@@ -42,13 +46,13 @@ impl ClientBridge {
                     // OpenTable 成功后加载并缓存价格规则
                     if let Some((zone_id, is_retail)) = open_table_info {
                         if let Some(order_id) = response.order_id {
-                            let rules =
-                                edge_server::orders::actions::open_table::load_matching_rules(
-                                    &server_state.pool,
-                                    zone_id,
-                                    is_retail,
-                                )
-                                .await;
+                            let rules = edge_server::orders::actions::open_table::load_order_rules(
+                                &server_state.pool,
+                                &server_state.catalog_service,
+                                zone_id,
+                                is_retail,
+                            )
+                            .await;
 
                             if !rules.is_empty() {
                                 tracing::debug!(
@@ -66,13 +70,13 @@ impl ClientBridge {
                         if let Ok(Some(snapshot)) =
                             server_state.orders_manager().get_snapshot(order_id)
                         {
-                            let rules =
-                                edge_server::orders::actions::open_table::load_matching_rules(
-                                    &server_state.pool,
-                                    target_zone_id,
-                                    snapshot.is_retail,
-                                )
-                                .await;
+                            let rules = edge_server::orders::actions::open_table::load_order_rules(
+                                &server_state.pool,
+                                &server_state.catalog_service,
+                                target_zone_id,
+                                snapshot.is_retail,
+                            )
+                            .await;
                             tracing::debug!(
                                 order_id = %order_id,
                                 target_zone_id = ?target_zone_id,
@@ -136,6 +140,9 @@ impl ClientBridge {
                             }
                             shared::order::OrderCommandPayload::MoveOrder { .. } => "order.move",
                             shared::order::OrderCommandPayload::MergeOrders { .. } => "order.merge",
+                            shared::order::OrderCommandPayload::TransferItems { .. } => {
+                                "order.transfer_items"
+                            }
                             shared::order::OrderCommandPayload::UpdateOrderInfo { .. } => {
                                 "order.update_info"
                             }
@@ -154,9 +161,21 @@ impl ClientBridge {
                             shared::order::OrderCommandPayload::UncompItem { .. } => {
                                 "order.uncomp_item"
                             }
+                            shared::order::OrderCommandPayload::HoldItems { .. } => {
+                                "order.hold_items"
+                            }
+                            shared::order::OrderCommandPayload::FireCourse { .. } => {
+                                "order.fire_course"
+                            }
                             shared::order::OrderCommandPayload::AddOrderNote { .. } => {
                                 "order.add_order_note"
                             }
+                            shared::order::OrderCommandPayload::SetOrderMetadata { .. } => {
+                                "order.set_metadata"
+                            }
+                            shared::order::OrderCommandPayload::CarryOverOrder { .. } => {
+                                "order.carry_over"
+                            }
                             shared::order::OrderCommandPayload::LinkMember { .. } => {
                                 "order.link_member"
                             }
@@ -169,6 +188,12 @@ impl ClientBridge {
                             shared::order::OrderCommandPayload::CancelStampRedemption {
                                 ..
                             } => "order.cancel_stamp_redemption",
+                            shared::order::OrderCommandPayload::RedeemPoints { .. } => {
+                                "order.redeem_points"
+                            }
+                            shared::order::OrderCommandPayload::ApplyCoupon { .. } => {
+                                "order.apply_coupon"
+                            }
                         };
 
                         // Build RequestCommand message with full command (preserves command_id, operator info)
@@ -194,62 +219,76 @@ impl ClientBridge {
                         let request_msg =
                             shared::message::BusMessage::request_command(&request_payload);
 
-                        // Send via MessageClient and wait for response
-                        let response_msg = auth
-                            .request(&request_msg)
-                            .await
-                            .map_err(|e| BridgeError::Server(format!("Request failed: {}", e)))?;
-
-                        // Parse response
-                        let response_payload: shared::message::ResponsePayload = response_msg
-                            .parse_payload()
-                            .map_err(|e| BridgeError::Server(format!("Invalid response: {}", e)))?;
-
-                        if response_payload.success {
-                            // Extract CommandResponse from data if present
-                            if let Some(data) = response_payload.data {
-                                let cmd_response: CommandResponse = match serde_json::from_value(
-                                    data,
-                                ) {
-                                    Ok(r) => r,
-                                    Err(e) => {
-                                        tracing::warn!(
-                                            command_id = %command.command_id,
-                                            action = action,
-                                            error = %e,
-                                            "Failed to deserialize CommandResponse, returning failure"
-                                        );
-                                        CommandResponse {
-                                            command_id: command.command_id,
-                                            success: false,
-                                            order_id: None,
-                                            error: Some(shared::order::CommandError::new(
-                                                shared::order::CommandErrorCode::InternalError,
-                                                format!("Failed to parse server response: {}", e),
-                                            )),
+                        // 乐观预演：等待服务端前先显示预测结果，失败时回滚到权威快照
+                        let optimistic = self.emit_optimistic(&command);
+
+                        let result: Result<CommandResponse, BridgeError> = async {
+                            // Send via MessageClient and wait for response
+                            let response_msg = auth
+                                .request(&request_msg)
+                                .await
+                                .map_err(|e| BridgeError::Server(format!("Request failed: {}", e)))?;
+
+                            // Parse response
+                            let response_payload: shared::message::ResponsePayload = response_msg
+                                .parse_payload()
+                                .map_err(|e| BridgeError::Server(format!("Invalid response: {}", e)))?;
+
+                            if response_payload.success {
+                                // Extract CommandResponse from data if present
+                                if let Some(data) = response_payload.data {
+                                    let cmd_response: CommandResponse = match serde_json::from_value(
+                                        data,
+                                    ) {
+                                        Ok(r) => r,
+                                        Err(e) => {
+                                            tracing::warn!(
+                                                command_id = %command.command_id,
+                                                action = action,
+                                                error = %e,
+                                                "Failed to deserialize CommandResponse, returning failure"
+                                            );
+                                            CommandResponse {
+                                                command_id: command.command_id,
+                                                success: false,
+                                                order_id: None,
+                                                error: Some(shared::order::CommandError::new(
+                                                    shared::order::CommandErrorCode::InternalError,
+                                                    format!("Failed to parse server response: {}", e),
+                                                )),
+                                                current_snapshot: None,
+                                            }
                                         }
-                                    }
-                                };
-                                Ok(cmd_response)
+                                    };
+                                    Ok(cmd_response)
+                                } else {
+                                    Ok(CommandResponse {
+                                        command_id: command.command_id,
+                                        success: true,
+                                        order_id: None,
+                                        error: None,
+                                        current_snapshot: None,
+                                    })
+                                }
                             } else {
                                 Ok(CommandResponse {
                                     command_id: command.command_id,
-                                    success: true,
+                                    success: false,
                                     order_id: None,
-                                    error: None,
+                                    error: Some(shared::order::CommandError::new(
+                                        shared::order::CommandErrorCode::InternalError,
+                                        response_payload.message,
+                                    )),
+                                    current_snapshot: None,
                                 })
                             }
-                        } else {
-                            Ok(CommandResponse {
-                                command_id: command.command_id,
-                                success: false,
-                                order_id: None,
-                                error: Some(shared::order::CommandError::new(
-                                    shared::order::CommandErrorCode::InternalError,
-                                    response_payload.message,
-                                )),
-                            })
                         }
+                        .await;
+
+                        if optimistic && !matches!(&result, Ok(response) if response.success) {
+                            self.emit_optimistic_rollback(&command);
+                        }
+                        result
                     }
                     Some(RemoteClientState::Connected(_)) => Err(BridgeError::NotAuthenticated),
                     None => Err(BridgeError::NotInitialized),
@@ -293,6 +332,7 @@ impl ClientBridge {
                                 .map_err(|e| {
                                     BridgeError::Server(format!("Invalid sync response: {}", e))
                                 })?;
+                            self.order_mirror.replace_all(&sync_response.active_orders);
                             Ok(sync_response.active_orders)
                         } else {
                             Ok(vec![])
@@ -418,6 +458,8 @@ impl ClientBridge {
                                 .map_err(|e| {
                                     BridgeError::Server(format!("Invalid sync response: {}", e))
                                 })?;
+                            // active_orders 总是完整的活跃订单集合
+                            self.order_mirror.replace_all(&sync_response.active_orders);
                             Ok(sync_response)
                         } else {
                             Ok(SyncResponse {
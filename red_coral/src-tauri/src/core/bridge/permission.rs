@@ -0,0 +1,253 @@
+//! Role-aware command gating
+//!
+//! 在转发前按当前员工会话的权限拦截敏感命令，返回 `BridgeError::PermissionDenied`。
+//! Server 模式下订单命令和部分本地命令不经过 edge-server 的权限中间件，
+//! 这里是它们唯一的权限检查点；前端可通过 `get_command_permissions` 拿到同一张表隐藏/禁用功能。
+
+use super::*;
+use shared::client::{EscalateResponse, UserInfo};
+use std::collections::HashMap;
+
+/// 主管授权有效期：授权弹窗通过后，随后提交的命令需在此时间内使用
+const ESCALATION_TTL_MS: i64 = 2 * 60 * 1000;
+
+/// Tauri 命令 → 所需权限 (与 edge-server 对应路由的权限保持一致)
+///
+/// 订单命令按 payload 映射，见 `OrderCommandPayload::required_permission`。
+/// `open_cash_drawer` 不在表内：现金收款也会开箱，开箱权限由 edge-server 按钱箱事件类型检查
+pub const COMMAND_PERMISSIONS: &[(&str, &str)] = &[
+    // 数据导入导出 (/api/data-transfer)
+    ("export_data", "menu:manage"),
+    ("import_data", "menu:manage"),
+    // 退款 / 发票作废
+    ("create_credit_note", "orders:refund"),
+    ("create_anulacion", "orders:void"),
+    // 价格规则
+    ("create_price_rule", "price_rules:manage"),
+    ("update_price_rule", "price_rules:manage"),
+    ("delete_price_rule", "price_rules:manage"),
+    // 盘点审批
+    ("approve_stock_count", "inventory:approve_count"),
+    ("reject_stock_count", "inventory:approve_count"),
+    // 门店设置
+    ("update_store_info", "settings:manage"),
+    // 员工与角色 (仅管理员)
+    ("create_employee", "users:manage"),
+    ("update_employee", "users:manage"),
+    ("delete_employee", "users:manage"),
+    ("create_role", "users:manage"),
+    ("update_role", "users:manage"),
+    ("delete_role", "users:manage"),
+    ("update_role_permissions", "users:manage"),
+];
+
+/// 查找 Tauri 命令所需权限 (None = 登录即可)
+pub fn command_permission(command: &str) -> Option<&'static str> {
+    COMMAND_PERMISSIONS
+        .iter()
+        .find(|(name, _)| *name == command)
+        .map(|(_, permission)| *permission)
+}
+
+/// 经 `/api/auth/escalate` 验证过凭据的主管授权 (authorizer_id → 授权人 + 到期时间)
+///
+/// 订单命令里的 `authorizer_id` 由前端填写，只有在这里登记过、未过期且授权人
+/// 拥有所需权限时才认可
+#[derive(Default)]
+pub(super) struct EscalationGrants {
+    grants: Mutex<HashMap<i64, (UserInfo, i64)>>,
+}
+
+impl EscalationGrants {
+    /// 登记一次验证通过的主管授权
+    pub(super) fn record(&self, authorizer: UserInfo, now: i64) {
+        let mut grants = self.grants.lock().unwrap_or_else(|e| e.into_inner());
+        grants.insert(authorizer.id, (authorizer, now + ESCALATION_TTL_MS));
+    }
+
+    /// 授权人是否已验证、未过期且拥有指定权限
+    pub(super) fn authorizes(&self, authorizer_id: i64, permission: &str, now: i64) -> bool {
+        let mut grants = self.grants.lock().unwrap_or_else(|e| e.into_inner());
+        grants.retain(|_, (_, expires_at)| *expires_at > now);
+        grants
+            .get(&authorizer_id)
+            .is_some_and(|(authorizer, _)| authorizer.has_permission(permission))
+    }
+
+    /// 清空全部授权 (换人登录 / 锁屏)
+    pub(super) fn clear(&self) {
+        self.grants
+            .lock()
+            .unwrap_or_else(|e| e.into_inner())
+            .clear();
+    }
+}
+
+impl ClientBridge {
+    /// 检查当前会话是否拥有指定权限
+    pub async fn require_permission(&self, permission: &str) -> Result<(), BridgeError> {
+        let tenant_manager = self.tenant_manager.read().await;
+        let session = tenant_manager
+            .current_session()
+            .ok_or(BridgeError::NotAuthenticated)?;
+        if session.user_info.has_permission(permission) {
+            Ok(())
+        } else {
+            tracing::warn!(
+                username = %session.username,
+                permission = permission,
+                "Command blocked: missing permission"
+            );
+            Err(BridgeError::PermissionDenied(permission.to_string()))
+        }
+    }
+
+    /// 按 `COMMAND_PERMISSIONS` 检查 Tauri 命令
+    pub async fn authorize_command(&self, command: &str) -> Result<(), BridgeError> {
+        self.ensure_unlocked()?;
+        match command_permission(command) {
+            Some(permission) => self.require_permission(permission).await,
+            None => Ok(()),
+        }
+    }
+
+    /// 主管授权：由 edge-server 校验主管凭据与权限，通过后登记授权
+    pub async fn escalate(
+        &self,
+        username: String,
+        password: String,
+        required_permission: String,
+    ) -> Result<UserInfo, BridgeError> {
+        #[derive(serde::Serialize)]
+        struct EscalateReq {
+            username: String,
+            password: String,
+            required_permission: String,
+        }
+
+        let request = EscalateReq {
+            username,
+            password,
+            required_permission,
+        };
+        let response: EscalateResponse = self.post("/api/auth/escalate", &request).await?;
+        self.escalations
+            .record(response.authorizer.clone(), shared::util::now_millis());
+        Ok(response.authorizer)
+    }
+
+    /// 检查订单命令
+    ///
+    /// 携带主管授权人 (authorizer_id) 时，授权人必须已通过 `escalate` 验证且拥有所需权限，
+    /// 或者就是拥有该权限的当前员工本人
+    pub(super) async fn authorize_order_command(
+        &self,
+        payload: &OrderCommandPayload,
+    ) -> Result<(), BridgeError> {
+        let Some(permission) = payload.required_permission() else {
+            return Ok(());
+        };
+        let Some(authorizer_id) = payload.authorizer_id() else {
+            return self.require_permission(permission).await;
+        };
+        if self
+            .escalations
+            .authorizes(authorizer_id, permission, shared::util::now_millis())
+        {
+            return Ok(());
+        }
+        let is_current_user = self
+            .tenant_manager
+            .read()
+            .await
+            .current_session()
+            .is_some_and(|s| s.user_info.id == authorizer_id);
+        if is_current_user {
+            return self.require_permission(permission).await;
+        }
+        tracing::warn!(
+            authorizer_id = authorizer_id,
+            permission = permission,
+            "Command blocked: unverified authorizer"
+        );
+        Err(BridgeError::PermissionDenied(permission.to_string()))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_command_permission_lookup() {
+        assert_eq!(
+            command_permission("create_credit_note"),
+            Some("orders:refund")
+        );
+        assert_eq!(
+            command_permission("update_role_permissions"),
+            Some("users:manage")
+        );
+        assert_eq!(command_permission("list_products"), None);
+        assert_eq!(command_permission("open_cash_drawer"), None);
+    }
+
+    fn user(id: i64, role_name: &str, permissions: &[&str]) -> UserInfo {
+        UserInfo {
+            id,
+            username: format!("user{id}"),
+            name: format!("User {id}"),
+            role_id: 1,
+            role_name: role_name.to_string(),
+            permissions: permissions.iter().map(|p| p.to_string()).collect(),
+            is_system: false,
+            is_active: true,
+            created_at: 0,
+            photo: None,
+        }
+    }
+
+    #[test]
+    fn test_escalation_rejects_unknown_authorizer() {
+        let grants = EscalationGrants::default();
+        grants.record(user(7, "manager", &["orders:*"]), 1_000);
+
+        // 伪造的 authorizer_id 从未经过 escalate 验证
+        assert!(!grants.authorizes(99, "orders:void", 1_000));
+    }
+
+    #[test]
+    fn test_escalation_requires_authorizer_permission() {
+        let grants = EscalationGrants::default();
+        grants.record(user(7, "waiter", &["orders:comp"]), 1_000);
+
+        assert!(grants.authorizes(7, "orders:comp", 1_000));
+        assert!(!grants.authorizes(7, "orders:void", 1_000));
+    }
+
+    #[test]
+    fn test_escalation_expires() {
+        let grants = EscalationGrants::default();
+        grants.record(user(7, "admin", &[]), 1_000);
+
+        assert!(grants.authorizes(7, "orders:void", 1_000 + ESCALATION_TTL_MS - 1));
+        assert!(!grants.authorizes(7, "orders:void", 1_000 + ESCALATION_TTL_MS));
+    }
+
+    #[test]
+    fn test_escalation_clear() {
+        let grants = EscalationGrants::default();
+        grants.record(user(7, "admin", &[]), 1_000);
+        grants.clear();
+
+        assert!(!grants.authorizes(7, "orders:void", 1_000));
+    }
+
+    #[test]
+    fn test_command_permissions_unique() {
+        let mut names: Vec<_> = COMMAND_PERMISSIONS.iter().map(|(n, _)| *n).collect();
+        names.sort_unstable();
+        names.dedup();
+        assert_eq!(names.len(), COMMAND_PERMISSIONS.len());
+    }
+}
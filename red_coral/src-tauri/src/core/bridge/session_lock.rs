@@ -0,0 +1,220 @@
+//! Session auto-lock and cashier switch
+//!
+//! 无操作超过 `auto_lock_minutes` 后终端锁定：CrabClient 保持已认证、连接不断开，
+//! 前端保留当前订单界面，只是需要重新输入当前员工密码才能继续操作。
+//! 锁定期间订单命令和受权限控制的命令返回 `BridgeError::SessionLocked`。
+//!
+//! 换人收银 (`switch_cashier`) 在同一连接上用新员工凭据重新登录，
+//! 不重建连接、不重启模式；失败时终端进入锁定状态，避免停留在半登录状态。
+
+use std::sync::atomic::{AtomicBool, AtomicI64, Ordering};
+use std::time::Duration;
+
+use super::*;
+
+/// 自动锁屏检查间隔
+const AUTO_LOCK_CHECK_INTERVAL: Duration = Duration::from_secs(15);
+
+/// 会话锁状态
+pub(super) struct SessionLock {
+    /// 最近一次操作时间 (Unix millis)
+    last_activity: AtomicI64,
+    /// 是否已锁定
+    locked: AtomicBool,
+}
+
+impl SessionLock {
+    pub(super) fn new() -> Self {
+        Self {
+            last_activity: AtomicI64::new(shared::util::now_millis()),
+            locked: AtomicBool::new(false),
+        }
+    }
+
+    fn touch(&self, now: i64) {
+        self.last_activity.store(now, Ordering::Relaxed);
+    }
+
+    fn is_locked(&self) -> bool {
+        self.locked.load(Ordering::Acquire)
+    }
+
+    /// 置为锁定，返回是否由本次调用锁定
+    fn lock(&self) -> bool {
+        !self.locked.swap(true, Ordering::AcqRel)
+    }
+
+    fn unlock(&self, now: i64) {
+        self.touch(now);
+        self.locked.store(false, Ordering::Release);
+    }
+
+    /// 是否已超过空闲时限
+    fn is_idle(&self, now: i64, timeout_ms: i64) -> bool {
+        now - self.last_activity.load(Ordering::Relaxed) >= timeout_ms
+    }
+}
+
+impl ClientBridge {
+    /// 记录用户操作 (重置自动锁屏计时)
+    ///
+    /// 锁定期间不重置，只有解锁才能恢复
+    pub fn touch_activity(&self) {
+        if !self.session_lock.is_locked() {
+            self.session_lock.touch(shared::util::now_millis());
+        }
+    }
+
+    /// 终端是否处于锁定状态
+    pub fn is_session_locked(&self) -> bool {
+        self.session_lock.is_locked()
+    }
+
+    /// 锁定终端 (手动锁屏或空闲超时)
+    pub fn lock_session(&self) {
+        self.escalations.clear();
+        if self.session_lock.lock() {
+            tracing::info!("Session locked");
+            if let Some(handle) = &self.app_handle {
+                if let Err(e) = handle.emit("session-locked", true) {
+                    tracing::warn!("Failed to emit session-locked event: {}", e);
+                }
+            }
+        }
+    }
+
+    /// 锁定时拒绝操作
+    pub(super) fn ensure_unlocked(&self) -> Result<(), BridgeError> {
+        if self.session_lock.is_locked() {
+            Err(BridgeError::SessionLocked)
+        } else {
+            Ok(())
+        }
+    }
+
+    /// 当前员工重新验证密码解锁
+    pub async fn unlock_session(
+        &self,
+        password: &str,
+    ) -> Result<super::super::session_cache::EmployeeSession, BridgeError> {
+        let username = {
+            let tenant_manager = self.tenant_manager.read().await;
+            tenant_manager
+                .current_session()
+                .map(|s| s.username.clone())
+                .ok_or(BridgeError::NotAuthenticated)?
+        };
+
+        let session = self.login_employee(&username, password).await?;
+        tracing::info!(username = %username, "Session unlocked");
+        Ok(session)
+    }
+
+    /// 换人收银：在现有连接上切换为另一名员工
+    pub async fn switch_cashier(
+        &self,
+        username: &str,
+        password: &str,
+    ) -> Result<super::super::session_cache::EmployeeSession, BridgeError> {
+        let previous = self
+            .get_current_session()
+            .await
+            .map(|s| s.username)
+            .unwrap_or_default();
+
+        match self.login_employee(username, password).await {
+            Ok(session) => {
+                tracing::info!(from = %previous, to = %username, "Cashier switched");
+                Ok(session)
+            }
+            Err(e) => {
+                // 原员工的认证已在重新登录时注销，锁定终端等待重新验证
+                self.lock_session();
+                Err(e)
+            }
+        }
+    }
+
+    /// 登录成功后解除锁定 (由 `login_employee` 调用)
+    pub(super) fn finish_unlock(&self) {
+        self.escalations.clear();
+        let was_locked = self.session_lock.is_locked();
+        self.session_lock.unlock(shared::util::now_millis());
+        if was_locked {
+            if let Some(handle) = &self.app_handle {
+                if let Err(e) = handle.emit("session-locked", false) {
+                    tracing::warn!("Failed to emit session-locked event: {}", e);
+                }
+            }
+        }
+    }
+
+    /// 读取自动锁屏时间 (分钟)
+    pub async fn get_auto_lock_minutes(&self) -> Option<u32> {
+        self.config
+            .read()
+            .await
+            .auto_lock_minutes
+            .filter(|m| *m > 0)
+    }
+
+    /// 设置自动锁屏时间 (分钟，None/0 = 关闭)
+    pub async fn set_auto_lock_minutes(&self, minutes: Option<u32>) -> Result<(), BridgeError> {
+        let mut config = self.config.write().await;
+        config.auto_lock_minutes = minutes.filter(|m| *m > 0);
+        config.save(&self.config_path)
+    }
+
+    /// 启动自动锁屏后台检查
+    pub fn spawn_auto_lock_watcher(self: &Arc<Self>) {
+        let bridge = Arc::downgrade(self);
+        tauri::async_runtime::spawn(async move {
+            let mut interval = tokio::time::interval(AUTO_LOCK_CHECK_INTERVAL);
+            loop {
+                interval.tick().await;
+                let Some(bridge) = bridge.upgrade() else {
+                    break;
+                };
+                let Some(minutes) = bridge.get_auto_lock_minutes().await else {
+                    continue;
+                };
+                if bridge.is_session_locked() || bridge.get_current_session().await.is_none() {
+                    continue;
+                }
+                let timeout_ms = i64::from(minutes) * 60_000;
+                if bridge
+                    .session_lock
+                    .is_idle(shared::util::now_millis(), timeout_ms)
+                {
+                    tracing::info!(minutes, "Idle timeout reached, locking session");
+                    bridge.lock_session();
+                }
+            }
+        });
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_session_lock_idle_detection() {
+        let lock = SessionLock::new();
+        lock.touch(1_000);
+        assert!(!lock.is_idle(60_999, 60_000));
+        assert!(lock.is_idle(61_000, 60_000));
+    }
+
+    #[test]
+    fn test_session_lock_only_locks_once() {
+        let lock = SessionLock::new();
+        assert!(lock.lock());
+        assert!(!lock.lock());
+        assert!(lock.is_locked());
+
+        lock.unlock(5_000);
+        assert!(!lock.is_locked());
+        assert!(!lock.is_idle(5_000, 1));
+    }
+}
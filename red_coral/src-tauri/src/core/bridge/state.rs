@@ -159,6 +159,32 @@ impl ClientBridge {
         }
     }
 
+    /// 获取本终端的漫游配置 (仅 Client 模式)
+    pub async fn get_terminal_profile(&self) -> TerminalProfileInfo {
+        let mode_guard = self.mode.read().await;
+        let message_client = match &*mode_guard {
+            ClientMode::Client {
+                client: Some(RemoteClientState::Connected(c)),
+                ..
+            } => c.message_client(),
+            ClientMode::Client {
+                client: Some(RemoteClientState::Authenticated(c)),
+                ..
+            } => c.message_client(),
+            _ => None,
+        };
+        match message_client {
+            Some(mc) => TerminalProfileInfo {
+                identity: mc.terminal_identity().await,
+                profile: mc.terminal_profile().await,
+            },
+            None => TerminalProfileInfo {
+                identity: None,
+                profile: None,
+            },
+        }
+    }
+
     /// 获取当前活动会话 (用于启动时恢复登录状态)
     pub async fn get_current_session(
         &self,
@@ -115,6 +115,14 @@ pub struct ModeInfo {
     pub username: Option<String>,
 }
 
+/// 终端漫游配置 (Client 模式握手时由 edge-server 下发)
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct TerminalProfileInfo {
+    /// 证书身份 (保存配置时的键)；Server 模式 / 未连接时为 None
+    pub identity: Option<String>,
+    pub profile: Option<shared::models::TerminalProfile>,
+}
+
 // ============================================================================
 // Internal state enums (pub(crate) for use within bridge module)
 // ============================================================================
@@ -144,6 +152,8 @@ pub(crate) enum ClientMode {
         server_task: tokio::task::JoinHandle<()>,
         listener_task: Option<tokio::task::JoinHandle<()>>,
         shutdown_token: CancellationToken,
+        /// 监听器控制 (端口变更无需重启)
+        listeners: edge_server::ListenerControl,
     },
     /// Client 模式: 连接远程 edge-server
     Client {
@@ -16,7 +16,7 @@ pub mod tenant_manager;
 
 pub use bridge::{
     AppConfig, AppState, BridgeError, ClientBridge, ClientModeConfig, ModeInfo, ModeType,
-    ServerModeConfig,
+    ServerModeConfig, TerminalProfileInfo,
 };
 pub use paths::TenantPaths;
 pub use response::{
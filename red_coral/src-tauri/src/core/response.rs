@@ -182,6 +182,21 @@ impl<T: Serialize> ApiResponse<T> {
                 data: None,
                 details: None,
             },
+            BridgeError::PermissionDenied(permission) => Self {
+                code: Some(ErrorCode::PermissionDenied.code()),
+                message: err.to_string(),
+                data: None,
+                details: Some(HashMap::from([(
+                    "permission".to_string(),
+                    Value::String(permission.clone()),
+                )])),
+            },
+            BridgeError::SessionLocked => Self {
+                code: Some(ErrorCode::SessionLocked.code()),
+                message: err.to_string(),
+                data: None,
+                details: None,
+            },
             BridgeError::Config(_) => Self {
                 code: Some(ErrorCode::ConfigError.code()),
                 message: err.to_string(),
@@ -23,7 +23,7 @@ pub struct ServerMessageEvent {
 
 impl From<BusMessage> for ServerMessageEvent {
     fn from(msg: BusMessage) -> Self {
-        let payload = serde_json::from_slice(&msg.payload).unwrap_or_else(|e| {
+        let payload = msg.parse_payload().unwrap_or_else(|e| {
             tracing::warn!(
                 event_type = %msg.event_type,
                 error = %e,
@@ -50,6 +50,35 @@ pub struct OrderSyncPayload {
     pub snapshot: OrderSnapshot,
 }
 
+/// Coalesced order sync payload (events from one command, e.g. a large AddItems)
+///
+/// Events are in sequence order; one latest snapshot per touched order.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct OrderSyncBatchPayload {
+    pub events: Vec<OrderEvent>,
+    pub snapshots: Vec<OrderSnapshot>,
+}
+
+/// Optimistic order echo (Client mode, emitted as "order-optimistic")
+///
+/// Bridge 本地预演的结果，仅用于显示；权威状态仍以 order-sync 为准。
+/// 预测快照保留预演前的 `last_sequence`，不推进前端同步游标。
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct OrderOptimisticPayload {
+    pub command_id: i64,
+    pub events: Vec<OrderEvent>,
+    pub snapshots: Vec<OrderSnapshot>,
+}
+
+/// Optimistic rollback (emitted as "order-optimistic-rollback" when the command fails)
+///
+/// snapshots 为 bridge 已知的最新权威快照
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct OrderOptimisticRollbackPayload {
+    pub command_id: i64,
+    pub snapshots: Vec<OrderSnapshot>,
+}
+
 /// Routing information for a BusMessage
 ///
 /// Used to determine how to emit the message to the frontend.
@@ -57,6 +86,8 @@ pub enum MessageRoute {
     /// Order sync - should be emitted as "order-sync" with event + snapshot
     /// Server Authority Model: frontend uses snapshot directly, no local computation
     OrderSync(Box<OrderSyncPayload>),
+    /// Coalesced order sync - emitted as "order-sync-batch" and applied in one store update
+    OrderSyncBatch(Box<OrderSyncBatchPayload>),
     /// General server message - should be emitted as "server-message"
     ServerMessage(ServerMessageEvent),
 }
@@ -65,7 +96,8 @@ impl MessageRoute {
     /// Analyze a BusMessage and determine how it should be routed
     ///
     /// Order sync messages (resource="order_sync") are extracted with event + snapshot
-    /// and routed to the "order-sync" channel for the order store.
+    /// and routed to the "order-sync" channel for the order store; coalesced
+    /// batches (events + snapshots) go to "order-sync-batch".
     /// All other messages go to "server-message".
     ///
     /// Server Authority Model: snapshot is server-computed, frontend uses it directly.
@@ -77,7 +109,14 @@ impl MessageRoute {
                 if sync_payload.resource == shared::cloud::SyncResource::OrderSync {
                     // Try to extract OrderSyncPayload (event + snapshot) from data field
                     if let Some(data) = sync_payload.data {
-                        if let Ok(order_sync) = serde_json::from_value::<OrderSyncPayload>(data) {
+                        if data.get("events").is_some() {
+                            if let Ok(batch) = serde_json::from_value::<OrderSyncBatchPayload>(data)
+                            {
+                                return MessageRoute::OrderSyncBatch(Box::new(batch));
+                            }
+                        } else if let Ok(order_sync) =
+                            serde_json::from_value::<OrderSyncPayload>(data)
+                        {
                             return MessageRoute::OrderSync(Box::new(order_sync));
                         }
                     }
@@ -138,6 +177,7 @@ mod tests {
                 is_retail: false,
                 queue_number: None,
                 receipt_number: "RCP-001".to_string(),
+                tax_mode: shared::models::TaxMode::Inclusive,
             },
         };
 
@@ -161,10 +201,14 @@ mod tests {
             total_discount: 0.0,
             total_surcharge: 0.0,
             tax: 0.0,
+            tax_mode: shared::models::TaxMode::Inclusive,
+            tax_breakdown: vec![],
             discount: 0.0,
             comp_total_amount: 0.0,
             order_manual_discount_amount: 0.0,
             order_manual_surcharge_amount: 0.0,
+            payment_surcharges: vec![],
+            payment_surcharge_amount: 0.0,
             total: 0.0,
             paid_amount: 0.0,
             remaining_amount: 0.0,
@@ -179,6 +223,9 @@ mod tests {
             order_manual_surcharge_fixed: None,
             comps: vec![],
             note: None,
+            metadata: std::collections::BTreeMap::new(),
+            carry_over_date: None,
+            fiscal_code: None,
             start_time: 1705900000000,
             end_time: None,
             created_at: 1705900000000,
@@ -198,6 +245,8 @@ mod tests {
             marketing_group_name: None,
             mg_discount_amount: 0.0,
             stamp_redemptions: vec![],
+            points_redemption: None,
+            coupon: None,
         };
 
         // Create a Sync message with resource=OrderSync (like edge-server does)
@@ -225,8 +274,34 @@ mod tests {
                 assert_eq!(sync.snapshot.order_id, 2001);
                 assert_eq!(sync.snapshot.guest_count, 2);
             }
-            MessageRoute::ServerMessage(_) => {
-                panic!("Expected OrderSync, got ServerMessage");
+            _ => {
+                panic!("Expected OrderSync");
+            }
+        }
+    }
+
+    #[test]
+    fn test_message_route_order_sync_batch() {
+        let sync_payload = SyncPayload {
+            resource: shared::cloud::SyncResource::OrderSync,
+            version: 42,
+            action: shared::message::SyncChangeType::Created,
+            id: 2001,
+            data: Some(serde_json::json!({
+                "events": [],
+                "snapshots": [OrderSnapshot::new(2001)]
+            })),
+            cloud_origin: false,
+        };
+
+        match MessageRoute::from_bus_message(BusMessage::sync(&sync_payload)) {
+            MessageRoute::OrderSyncBatch(batch) => {
+                assert!(batch.events.is_empty());
+                assert_eq!(batch.snapshots.len(), 1);
+                assert_eq!(batch.snapshots[0].order_id, 2001);
+            }
+            _ => {
+                panic!("Expected OrderSyncBatch");
             }
         }
     }
@@ -252,8 +327,8 @@ mod tests {
             MessageRoute::ServerMessage(event) => {
                 assert_eq!(event.event_type, "sync");
             }
-            MessageRoute::OrderSync(_) => {
-                panic!("Expected ServerMessage, got OrderSync");
+            _ => {
+                panic!("Expected ServerMessage");
             }
         }
     }
@@ -132,6 +132,8 @@ pub async fn run() {
                 let _ = handle_for_task.emit("backend-ready", error);
             });
 
+            bridge.spawn_auto_lock_watcher();
+
             app.manage(bridge.clone());
 
             tracing::info!("ClientBridge initialized, restoring session...");
@@ -162,6 +164,7 @@ pub async fn run() {
             commands::retry_init,
             commands::get_app_state,
             commands::get_mode_info,
+            commands::get_terminal_profile,
             commands::get_current_mode_type,
             commands::start_server_mode,
             commands::start_client_mode,
@@ -185,6 +188,14 @@ pub async fn run() {
             commands::logout_employee,
             commands::get_current_session,
             commands::escalate_permission,
+            commands::get_command_permissions,
+            commands::lock_session,
+            commands::is_session_locked,
+            commands::unlock_session,
+            commands::switch_cashier,
+            commands::report_activity,
+            commands::get_auto_lock_minutes,
+            commands::set_auto_lock_minutes,
             // Data commands
             commands::list_tags,
             commands::get_tag,
@@ -232,17 +243,47 @@ pub async fn run() {
             commands::create_zone,
             commands::update_zone,
             commands::delete_zone,
+            commands::list_zone_overrides,
+            commands::set_zone_override,
+            commands::delete_zone_override,
             commands::list_tables,
             commands::list_tables_by_zone,
             commands::get_table,
             commands::create_table,
             commands::update_table,
             commands::delete_table,
+            // Inventory (库存)
+            commands::list_stock_levels,
+            commands::list_low_stock,
+            commands::create_stock_level,
+            commands::update_stock_level,
+            commands::delete_stock_level,
+            commands::adjust_stock,
+            commands::list_stock_adjustments,
+            commands::list_stock_counts,
+            commands::get_stock_count,
+            commands::start_stock_count,
+            commands::record_stock_count,
+            commands::scan_stock_count,
+            commands::submit_stock_count,
+            commands::approve_stock_count,
+            commands::reject_stock_count,
+            commands::list_stock_transfers,
+            commands::list_transfer_peers,
+            commands::get_stock_transfer,
+            commands::send_stock_transfer,
+            commands::receive_stock_transfer,
+            // Remote Support (远程支持)
+            commands::get_support_session,
+            commands::open_support_session,
+            commands::send_support_message,
+            commands::close_support_session,
             // Order commands (Query)
             commands::fetch_order_list,
             commands::fetch_member_order_history,
             commands::fetch_order_detail,
             commands::fetch_order_invoices,
+            commands::email_order_receipt,
             // Chain Entries (统一 hash 链时间线)
             commands::fetch_chain_entries,
             commands::fetch_chain_credit_note_detail,
@@ -314,6 +355,7 @@ pub async fn run() {
             commands::list_printers,
             commands::open_cash_drawer,
             commands::print_receipt,
+            commands::print_receipts,
             commands::print_label,
             // Health commands
             commands::get_health_status,
@@ -327,6 +369,9 @@ pub async fn run() {
             commands::force_close_shift,
             commands::heartbeat_shift,
             commands::recover_stale_shifts,
+            commands::get_shift_breaks,
+            commands::start_shift_break,
+            commands::end_shift_break,
             // Daily Report commands (日结报告)
             commands::list_daily_reports,
             commands::get_daily_report,
@@ -338,6 +383,7 @@ pub async fn run() {
             commands::get_red_flags,
             commands::get_red_flag_log,
             commands::list_invoices,
+            commands::get_kpi_snapshot,
         ])
         .on_window_event(|window, event| {
             if let tauri::WindowEvent::CloseRequested { api, .. } = event {
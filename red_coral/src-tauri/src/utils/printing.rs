@@ -46,9 +46,34 @@ mod platform {
     pub fn print_receipt(
         printer_name: Option<String>,
         receipt: crate::api::ReceiptData,
-    ) -> Result<(), String> {
+    ) -> Result<Vec<u8>, String> {
         let name = resolve_printer(printer_name)?;
         info!(printer = name, "printing receipt");
+        let data = render_receipt(&receipt);
+
+        // Print using crab-printer (sync — no async overhead needed)
+        let printer = WindowsPrinter::new(&name);
+        printer.print_sync(&data).map_err(|e| e.to_string())?;
+        Ok(data)
+    }
+
+    /// 多张收据合并为一个打印任务（分单收据不会与其他任务交错）
+    pub fn print_receipts(
+        printer_name: Option<String>,
+        receipts: Vec<crate::api::ReceiptData>,
+    ) -> Result<Vec<Vec<u8>>, String> {
+        let name = resolve_printer(printer_name)?;
+        info!(printer = name, count = receipts.len(), "printing receipt batch");
+        let rendered: Vec<Vec<u8>> = receipts.iter().map(render_receipt).collect();
+        let data: Vec<u8> = rendered.concat();
+
+        let printer = WindowsPrinter::new(&name);
+        printer.print_sync(&data).map_err(|e| e.to_string())?;
+        Ok(rendered)
+    }
+
+    /// Render one receipt (init + logo + content + cut) to printer bytes
+    fn render_receipt(receipt: &crate::api::ReceiptData) -> Vec<u8> {
         tracing::debug!(
             has_logo = receipt
                 .store_info
@@ -76,19 +101,14 @@ mod platform {
         }
 
         // Render receipt content
-        let output = ReceiptRenderer::new(&receipt, 48).render();
-        let text_bytes = convert_mixed_utf8_to_gbk(output.as_bytes());
+        let text_bytes = ReceiptRenderer::new(receipt, 48).render();
         tracing::debug!(
-            rendered_bytes = output.len(),
             gbk_bytes = text_bytes.len(),
             total_bytes = data.len() + text_bytes.len(),
             "print_receipt: rendered and encoded"
         );
         data.extend_from_slice(&text_bytes);
-
-        // Print using crab-printer (sync — no async overhead needed)
-        let printer = WindowsPrinter::new(&name);
-        printer.print_sync(&data).map_err(|e| e.to_string())
+        data
     }
 
     pub fn print_label(request: LabelPrintRequest) -> Result<(), String> {
@@ -228,7 +248,14 @@ mod platform {
     pub fn print_receipt(
         _printer_name: Option<String>,
         _receipt: crate::api::ReceiptData,
-    ) -> Result<(), String> {
+    ) -> Result<Vec<u8>, String> {
+        Err("PRINTING_NOT_SUPPORTED".to_string())
+    }
+
+    pub fn print_receipts(
+        _printer_name: Option<String>,
+        _receipts: Vec<crate::api::ReceiptData>,
+    ) -> Result<Vec<Vec<u8>>, String> {
         Err("PRINTING_NOT_SUPPORTED".to_string())
     }
 
@@ -257,14 +284,24 @@ pub fn open_cash_drawer(printer_name: Option<String>) -> Result<(), String> {
     platform::open_cash_drawer(printer_name)
 }
 
+/// 打印收据，返回实际发送给打印机的字节 (用于收据存档)
 #[instrument(skip(receipt))]
 pub fn print_receipt(
     printer_name: Option<String>,
     receipt: crate::api::ReceiptData,
-) -> Result<(), String> {
+) -> Result<Vec<u8>, String> {
     platform::print_receipt(printer_name, receipt)
 }
 
+/// 批量打印收据，按收据顺序返回每张的打印字节
+#[instrument(skip(receipts))]
+pub fn print_receipts(
+    printer_name: Option<String>,
+    receipts: Vec<crate::api::ReceiptData>,
+) -> Result<Vec<Vec<u8>>, String> {
+    platform::print_receipts(printer_name, receipts)
+}
+
 #[instrument(skip(request))]
 pub fn print_label(request: LabelPrintRequest) -> Result<(), String> {
     platform::print_label(request)
@@ -1,6 +1,6 @@
 use crate::api::ReceiptData;
 use crate::utils::escpos_text::{get_gbk_width, pad_to_gbk_width, EscPosTextBuilder};
-use shared::models::receipt_text;
+use shared::models::{pick_translation, receipt_text, resolve_receipt_locale, LocaleFormat};
 
 pub struct ReceiptRenderer<'a> {
     receipt: &'a ReceiptData,
@@ -12,24 +12,18 @@ impl<'a> ReceiptRenderer<'a> {
         Self { receipt, width }
     }
 
-    /// Get currency symbol from store info, default "€"
-    fn currency_sym(&self) -> &str {
-        self.receipt
-            .store_info
-            .as_ref()
-            .and_then(|i| i.currency_symbol.as_deref())
-            .unwrap_or("€")
-    }
-
-    pub fn render(&self) -> String {
-        let cur = self.currency_sym();
-        let locale = self
-            .receipt
-            .store_info
-            .as_ref()
-            .and_then(|i| i.receipt_locale.as_deref())
-            .unwrap_or("es-ES");
+    pub fn render(&self) -> Vec<u8> {
+        let info = self.receipt.store_info.as_ref();
+        let locale = resolve_receipt_locale(
+            self.receipt.locale.as_deref(),
+            info.and_then(|i| i.receipt_locale.as_deref()),
+        );
         let txt = receipt_text(locale);
+        let fmt = LocaleFormat::new(
+            locale,
+            info.and_then(|i| i.currency_symbol.as_deref()),
+            info.and_then(|i| i.currency_decimal_places),
+        );
         let mut b = EscPosTextBuilder::new(self.width);
         b.align_center();
         if self.receipt.void_reason.is_some() {
@@ -92,6 +86,14 @@ impl<'a> ReceiptRenderer<'a> {
             &format!("{} {}", txt.receipt_num_label, self.receipt.order_id),
             &self.receipt.timestamp,
         );
+        if let Some(sub) = &self.receipt.sub_receipt {
+            b.bold_on();
+            b.line_lr(
+                &format!("{} {}/{}", txt.sub_receipt_label, sub.index, sub.total),
+                &format!("{} {}", txt.master_receipt_label, sub.master),
+            );
+            b.bold_off();
+        }
 
         if let Some(qn) = self.receipt.queue_number {
             let pedido_str = format!("{}{:03}", txt.queue_label, qn);
@@ -119,6 +121,9 @@ impl<'a> ReceiptRenderer<'a> {
         if let Some(checkout_time) = &self.receipt.checkout_time {
             b.line_lr("", &format!("{}{}", txt.closed_label, checkout_time));
         }
+        if let Some(code) = &self.receipt.fiscal_code {
+            b.write_line(&format!("{} {}", txt.fiscal_code_label, code));
+        }
         if let Some(reason) = &self.receipt.void_reason {
             b.bold_on();
             b.write_line(&format!("{}  {}", txt.void_reason_label, reason));
@@ -141,17 +146,10 @@ impl<'a> ReceiptRenderer<'a> {
 
         for item in &self.receipt.items {
             let qty_str = pad_to_gbk_width(&item.quantity.to_string(), 3, true);
-            let name_str = pad_to_gbk_width(&item.name, 24, false);
-            let price_str = pad_to_gbk_width(
-                &format!("{:.2} {cur}", item.price).replace('.', txt.decimal_separator),
-                8,
-                true,
-            );
-            let total_str = pad_to_gbk_width(
-                &format!("{:.2} {cur}", item.total).replace('.', txt.decimal_separator),
-                10,
-                true,
-            );
+            let name = pick_translation(&item.name_translations, locale).unwrap_or(&item.name);
+            let name_str = pad_to_gbk_width(name, 24, false);
+            let price_str = pad_to_gbk_width(&fmt.money(item.price), 8, true);
+            let total_str = pad_to_gbk_width(&fmt.money(item.total), 10, true);
             b.write_line(&format!(
                 "{} {} {} {}",
                 qty_str, name_str, price_str, total_str
@@ -194,16 +192,18 @@ impl<'a> ReceiptRenderer<'a> {
                             format!("   > {}: {}", attr_name, opts_str)
                         } else if total_price > 0.0 {
                             format!(
-                                "   > {}: {} (+{:.2} {cur})",
-                                attr_name, opts_str, total_price
+                                "   > {}: {} ({})",
+                                attr_name,
+                                opts_str,
+                                fmt.money_signed(total_price)
                             )
-                            .replace('.', txt.decimal_separator)
                         } else {
                             format!(
-                                "   > {}: {} ({:.2} {cur})",
-                                attr_name, opts_str, total_price
+                                "   > {}: {} ({})",
+                                attr_name,
+                                opts_str,
+                                fmt.money(total_price)
                             )
-                            .replace('.', txt.decimal_separator)
                         };
                         b.write_line(&option_line);
                     }
@@ -214,7 +214,7 @@ impl<'a> ReceiptRenderer<'a> {
             if item.is_comped {
                 if let Some(orig) = item.original_price {
                     b.bold_on();
-                    let orig_str = format!("{:.2} {cur}", orig).replace('.', txt.decimal_separator);
+                    let orig_str = fmt.money(orig);
                     let comp_text = format!("   > {}", txt.comp_label);
                     let antes_str = txt.before_price_label;
 
@@ -239,8 +239,7 @@ impl<'a> ReceiptRenderer<'a> {
             else if let Some(orig) = item.original_price {
                 if orig > item.price + 0.001 {
                     b.bold_on();
-                    let before_str =
-                        format!("{:.2} {cur}", orig).replace('.', txt.decimal_separator);
+                    let before_str = fmt.money(orig);
 
                     // Show percentage if manual discount was applied
                     let discount_text = if let Some(dp) = item.discount_percent {
@@ -289,11 +288,12 @@ impl<'a> ReceiptRenderer<'a> {
         // Check if there are any order-level adjustments
         let has_manual_discount = self.receipt.discount.is_some();
         let has_manual_surcharge = self.receipt.surcharge.is_some();
-        let has_adjustments = has_manual_discount || has_manual_surcharge;
+        let has_payment_surcharge = self.receipt.payment_surcharge_amount > 0.005;
+        let has_adjustments =
+            has_manual_discount || has_manual_surcharge || has_payment_surcharge;
 
         if has_adjustments {
-            let subtotal_str =
-                format!("{:.2} {cur}", items_subtotal).replace('.', txt.decimal_separator);
+            let subtotal_str = fmt.money(items_subtotal);
             b.line_lr(txt.subtotal_label, &subtotal_str);
             b.dash_sep();
         }
@@ -304,13 +304,12 @@ impl<'a> ReceiptRenderer<'a> {
                 format!("- {} ({}%)", txt.order_discount_label, discount.value)
             } else {
                 format!(
-                    "- {} ({:.2} {cur})",
-                    txt.order_discount_label, discount.value
+                    "- {} ({})",
+                    txt.order_discount_label,
+                    fmt.money(discount.value)
                 )
-                .replace('.', txt.decimal_separator)
             };
-            let amount_str =
-                format!("-{:.2} {cur}", discount.amount).replace('.', txt.decimal_separator);
+            let amount_str = fmt.money(-discount.amount);
             b.write_line(&format!(
                 "{:<36}{:>10}",
                 pad_to_gbk_width(&desc, 36, false),
@@ -324,13 +323,23 @@ impl<'a> ReceiptRenderer<'a> {
                 format!("+ {} ({}%)", txt.order_surcharge_label, surcharge.value)
             } else {
                 format!(
-                    "+ {} ({:.2} {cur})",
-                    txt.order_surcharge_label, surcharge.value
+                    "+ {} ({})",
+                    txt.order_surcharge_label,
+                    fmt.money(surcharge.value)
                 )
-                .replace('.', txt.decimal_separator)
             };
-            let amount_str =
-                format!("+{:.2} {cur}", surcharge.amount).replace('.', txt.decimal_separator);
+            let amount_str = fmt.money_signed(surcharge.amount);
+            b.write_line(&format!(
+                "{:<36}{:>10}",
+                pad_to_gbk_width(&desc, 36, false),
+                amount_str
+            ));
+        }
+
+        // ── Payment-method surcharge (支付方式附加费) ──
+        if has_payment_surcharge {
+            let desc = format!("+ {}", txt.payment_surcharge_label);
+            let amount_str = fmt.money_signed(self.receipt.payment_surcharge_amount);
             b.write_line(&format!(
                 "{:<36}{:>10}",
                 pad_to_gbk_width(&desc, 36, false),
@@ -414,17 +423,16 @@ impl<'a> ReceiptRenderer<'a> {
             total_base += base_amount;
             total_tax += tax_amount;
 
-            let base_str = format!("{:.2} {cur}", base_amount).replace('.', txt.decimal_separator);
+            let base_str = fmt.money(base_amount);
             let rate_str = format!("{}%", rate_key);
-            let tax_str = format!("{:.2} {cur}", tax_amount).replace('.', txt.decimal_separator);
+            let tax_str = fmt.money(tax_amount);
 
             let col1 = pad_to_gbk_width(&rate_str, 4, true);
             let col2 = pad_to_gbk_width(&base_str, 8, true);
             let col3 = pad_to_gbk_width(&tax_str, 10, true);
 
             let left_content = if i == 0 && total_savings > 0.005 {
-                format!("{}: -{:.2} {cur}", txt.savings, total_savings)
-                    .replace('.', txt.decimal_separator)
+                format!("{}: {}", txt.savings, fmt.money(-total_savings))
             } else {
                 "".to_string()
             };
@@ -438,8 +446,8 @@ impl<'a> ReceiptRenderer<'a> {
         let sub_sep = format!("{}{}", sub_padding, "-".repeat(19));
         b.write_line(&sub_sep);
 
-        let total_base_str = format!("{:.2} {cur}", total_base).replace('.', txt.decimal_separator);
-        let total_tax_str = format!("{:.2} {cur}", total_tax).replace('.', txt.decimal_separator);
+        let total_base_str = fmt.money(total_base);
+        let total_tax_str = fmt.money(total_tax);
         let col_t2 = pad_to_gbk_width(&total_base_str, 8, true);
         let col_t3 = pad_to_gbk_width(&total_tax_str, 10, true);
         b.write_line(&format!("{}{} {}", sub_padding, col_t2, col_t3));
@@ -451,8 +459,7 @@ impl<'a> ReceiptRenderer<'a> {
         // ── TOTAL ──
         b.size_double();
         b.bold_on();
-        let total_val =
-            format!("{:.2} {cur}", self.receipt.total_amount).replace('.', txt.decimal_separator);
+        let total_val = fmt.money(self.receipt.total_amount);
         let total_label = txt.total_label;
         let max_dw = 24;
         let lw = get_gbk_width(total_label);
@@ -471,10 +478,41 @@ impl<'a> ReceiptRenderer<'a> {
         b.bold_off();
         b.size_reset();
 
+        // ── Payments ──
+        if !self.receipt.payments.is_empty() {
+            b.write("\n");
+            b.write_line(txt.payments_label);
+            b.dash_sep();
+            for payment in &self.receipt.payments {
+                let method = match payment.method.as_str() {
+                    "CASH" => txt.refund_cash,
+                    "CARD" => txt.refund_card,
+                    other => other,
+                };
+                let amount_str = fmt.money(payment.amount);
+                b.line_lr(method, &amount_str);
+                if let Some(change) = payment.change.filter(|c| *c > 0.005) {
+                    let change_str = fmt.money(change);
+                    b.line_lr(&format!("  {}", txt.change_label), &change_str);
+                }
+            }
+        }
+
         b.write("\n\n");
         b.align_center();
         b.write_line(txt.tax_included);
 
+        // Payment surcharge disclaimer (required where card surcharges are allowed)
+        if has_payment_surcharge {
+            let disclaimer = info.and_then(|i| i.payment_surcharge_disclaimer.as_deref());
+            if let Some(disclaimer) = disclaimer {
+                if !disclaimer.is_empty() {
+                    b.write("\n");
+                    b.write_line(disclaimer);
+                }
+            }
+        }
+
         // Receipt footer (custom text from store settings)
         if let Some(info) = &self.receipt.store_info {
             if let Some(footer) = &info.receipt_footer {
@@ -485,6 +523,24 @@ impl<'a> ReceiptRenderer<'a> {
             }
         }
 
+        // Footer promotion (scheduled promo lines + survey QR)
+        if let Some(promo) = &self.receipt.footer_promotion {
+            if !promo.lines.is_empty() {
+                b.write("\n");
+                for line in &promo.lines {
+                    b.write_line(line);
+                }
+            }
+            if let Some(qr) = &promo.survey_qr {
+                b.write("\n");
+                b.qr_code(qr, 6);
+                b.write("\n");
+                if let Some(code) = &promo.survey_code {
+                    b.write_line(code);
+                }
+            }
+        }
+
         b.bold_on();
         b.write_line(txt.farewell);
         b.bold_off();
@@ -492,6 +548,6 @@ impl<'a> ReceiptRenderer<'a> {
 
         b.write("\n\n\n\n\n");
         b.write("\x1D\x56\x00");
-        b.finalize()
+        b.build()
     }
 }
@@ -3,7 +3,7 @@ import { BrowserRouter, Routes, Route, Navigate } from 'react-router-dom';
 import { useSettingsStore } from '@/core/stores/settings/useSettingsStore';
 import { useBridgeStore, AppStateHelpers } from '@/core/stores/bridge';
 import { useAuthStore } from '@/core/stores/auth/useAuthStore';
-import { useSyncListener, useOrderEventListener, useOrderTimelineSync, useSyncConnection, useSystemIssueGuard } from '@/core/hooks';
+import { useSyncListener, useOrderEventListener, useOrderTimelineSync, useSyncConnection, useSystemIssueGuard, useTerminalProfileSync } from '@/core/hooks';
 import { listen } from '@tauri-apps/api/event';
 import { getCurrentWindow } from '@tauri-apps/api/window';
 import { t } from '@/infrastructure/i18n';
@@ -18,9 +18,11 @@ import { PermissionEscalationProvider } from '@/presentation/components/auth/Per
 import { NotificationProvider } from '@/presentation/components/notifications';
 import { ShiftGuard } from '@/presentation/components/shift';
 import { SystemIssueDialog } from '@/presentation/components/modals/SystemIssueDialog';
+import { AnnouncementCenter } from '@/features/announcement';
 import { VirtualKeyboard } from '@/presentation/components/ui/VirtualKeyboard';
 import { UpdateNotification } from '@/presentation/components/UpdateNotification';
 import { ShutdownOverlay } from '@/presentation/components/ShutdownOverlay';
+import { SessionLockScreen } from '@/presentation/components/auth/SessionLockScreen';
 import { useVirtualKeyboardStore } from '@/core/stores/ui';
 
 // Screens
@@ -103,6 +105,7 @@ const useAppInitialization = () => {
             is_system: session.user_info.is_system,
             is_active: session.user_info.is_active,
             created_at: session.user_info.created_at,
+            photo: session.user_info.photo,
           };
           useAuthStore.getState().setUser(user);
         } else {
@@ -154,6 +157,7 @@ const App: React.FC = () => {
   // 挂载同步相关 hooks
   useSyncListener();
   useSyncConnection();
+  useTerminalProfileSync();
 
   // 挂载订单事件监听 hook (Event Sourcing)
   useOrderEventListener();
@@ -388,6 +392,8 @@ const App: React.FC = () => {
         <PermissionEscalationProvider />
         <VirtualKeyboard />
         <SystemIssueDialog issue={currentIssue} onResolve={resolveIssue} />
+        <AnnouncementCenter />
+        <SessionLockScreen />
         <ShutdownOverlay />
 
         <Routes>
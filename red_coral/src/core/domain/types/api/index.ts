@@ -36,6 +36,8 @@ export interface CurrentUser {
   is_system: boolean;
   is_active: boolean;
   created_at: number;
+  /** Face photo (data URL) */
+  photo?: string;
 }
 
 // Category Attribute binding request
@@ -5,6 +5,8 @@
  * All entity IDs are numbers (SQLite INTEGER PRIMARY KEY).
  */
 
+import type { ItemOption, SpecificationInfo } from '../orderEvent';
+
 // ============ Common Types ============
 
 /**
@@ -151,6 +153,10 @@ export interface ProductCreate {
   is_kitchen_print_enabled?: PrintState;
   /** 标签打印启用状态 (-1=继承, 0=禁用, 1=启用) */
   is_label_print_enabled?: PrintState;
+  /** 厨房打印目标覆盖 (空 = 继承分类) */
+  kitchen_print_destinations?: number[];
+  /** 标签打印目标覆盖 (空 = 继承分类) */
+  label_print_destinations?: number[];
   /** 菜品编号 (POS 集成，全局唯一) */
   external_id?: number | null;
   tags?: number[];
@@ -170,6 +176,10 @@ export interface ProductUpdate {
   is_kitchen_print_enabled?: PrintState;
   /** 标签打印启用状态 (-1=继承, 0=禁用, 1=启用) */
   is_label_print_enabled?: PrintState;
+  /** 替换厨房打印目标覆盖 ([] = 恢复继承分类) */
+  kitchen_print_destinations?: number[];
+  /** 替换标签打印目标覆盖 ([] = 恢复继承分类) */
+  label_print_destinations?: number[];
   is_active?: boolean;
   /** 菜品编号 (POS 集成，全局唯一) */
   external_id?: number | null;
@@ -206,6 +216,10 @@ export interface ProductFull {
   is_kitchen_print_enabled: PrintState;
   /** 标签打印启用状态 (-1=继承, 0=禁用, 1=启用) */
   is_label_print_enabled: PrintState;
+  /** Kitchen print destination overrides (empty = inherit category) */
+  kitchen_print_destinations?: number[];
+  /** Label print destination overrides (empty = inherit category) */
+  label_print_destinations?: number[];
   is_active: boolean;
   /** 菜品编号 (POS 集成，全局唯一) */
   external_id: number | null;
@@ -217,6 +231,256 @@ export interface ProductFull {
   tags: Tag[];
 }
 
+/** Upsell suggestion (顾客常一起点的商品) */
+export interface UpsellSuggestion {
+  product_id: number;
+  name: string;
+  image: string;
+  /** Default spec price */
+  price: number;
+  /** Orders containing both products */
+  support: number;
+  confidence: number;
+  lift: number;
+}
+
+// ============ 86 Board (临时沽清) ============
+
+/** 86 entry — product (or spec) temporarily unavailable */
+export interface EightySix {
+  id: number;
+  product_id: number;
+  product_name: string;
+  /** null = whole product */
+  spec_id: number | null;
+  spec_name: string | null;
+  reason: string | null;
+  /** Auto-expiry (Unix millis), null = until cleared */
+  expires_at: number | null;
+  created_by_id: number;
+  created_by_name: string;
+  created_at: number;
+  cleared_at: number | null;
+  cleared_by_name: string | null;
+}
+
+export interface EightySixCreate {
+  product_id: number;
+  spec_id?: number | null;
+  reason?: string | null;
+  expires_at?: number | null;
+}
+
+// ============ Inventory (库存) ============
+
+/** Tracked stock level — product (or spec) quantity, may go negative */
+export interface StockLevel {
+  id: number;
+  product_id: number;
+  product_name: string;
+  /** null = whole product */
+  spec_id: number | null;
+  spec_name: string | null;
+  quantity: number;
+  /** Alert when quantity falls to this value, null = no alert */
+  low_stock_threshold: number | null;
+  /** Scanned during stock counts (unique) */
+  barcode: string | null;
+  created_at: number;
+  updated_at: number;
+}
+
+export interface StockLevelCreate {
+  product_id: number;
+  spec_id?: number | null;
+  /** Opening quantity */
+  quantity?: number;
+  low_stock_threshold?: number | null;
+  barcode?: string | null;
+}
+
+export interface StockLevelUpdate {
+  low_stock_threshold: number | null;
+  barcode: string | null;
+}
+
+export type StockAdjustmentKind = 'SALE' | 'MANUAL' | 'COUNT' | 'TRANSFER_OUT' | 'TRANSFER_IN' | 'TRANSFER_RETURN';
+
+export interface StockAdjustment {
+  id: number;
+  stock_level_id: number;
+  kind: StockAdjustmentKind;
+  delta: number;
+  quantity_after: number;
+  /** Completed order (SALE only) */
+  order_id: number | null;
+  /** Approved stock count (COUNT only) */
+  stock_count_id: number | null;
+  /** Inter-store transfer (TRANSFER_OUT / TRANSFER_IN / TRANSFER_RETURN only) */
+  stock_transfer_id: number | null;
+  note: string | null;
+  operator_id: number | null;
+  operator_name: string | null;
+  created_at: number;
+}
+
+export interface StockAdjustmentCreate {
+  /** Positive = stock in, negative = stock out */
+  delta: number;
+  note?: string | null;
+}
+
+/** OPEN → SUBMITTED → APPROVED (variances posted) | REJECTED */
+export type StockCountStatus = 'OPEN' | 'SUBMITTED' | 'APPROVED' | 'REJECTED';
+
+/** Stock count session (盘点) */
+export interface StockCount {
+  id: number;
+  status: StockCountStatus;
+  note: string | null;
+  created_by_id: number;
+  created_by_name: string;
+  created_at: number;
+  submitted_at: number | null;
+  reviewed_by_id: number | null;
+  reviewed_by_name: string | null;
+  reviewed_at: number | null;
+  review_note: string | null;
+  /** Empty in list responses */
+  lines: StockCountLine[];
+}
+
+export interface StockCountLine {
+  id: number;
+  stock_count_id: number;
+  stock_level_id: number;
+  product_name: string;
+  spec_name: string | null;
+  /** Quantity frozen when the session started */
+  theoretical_quantity: number;
+  /** null = not counted (left unchanged on approval) */
+  counted_quantity: number | null;
+  counted_at: number | null;
+  /** counted − theoretical, null = not counted */
+  variance: number | null;
+}
+
+export interface StockCountCreate {
+  note?: string | null;
+  /** Levels to count, null = every tracked level */
+  stock_level_ids?: number[] | null;
+}
+
+export interface StockCountEntry {
+  /** null = clear the count */
+  counted_quantity: number | null;
+}
+
+export interface StockCountScan {
+  barcode: string;
+  /** Added to the scanned line (default 1) */
+  quantity?: number;
+}
+
+export interface StockCountReview {
+  note?: string | null;
+}
+
+/** OUT = sent by this store, IN = received from another store */
+export type StockTransferDirection = 'OUT' | 'IN';
+
+/** IN_TRANSIT → RECEIVED (goods-received confirmation posted) */
+export type StockTransferStatus = 'IN_TRANSIT' | 'RECEIVED';
+
+/** Inter-store transfer (调拨单), same id in both stores */
+export interface StockTransfer {
+  id: number;
+  direction: StockTransferDirection;
+  status: StockTransferStatus;
+  /** Counterpart store (destination for OUT, source for IN) */
+  peer_store_id: number;
+  peer_store_name: string;
+  note: string | null;
+  sent_by: string;
+  sent_at: number;
+  received_by: string | null;
+  received_at: number | null;
+  /** Empty in list responses */
+  lines: StockTransferLine[];
+}
+
+export interface StockTransferLine {
+  id: number;
+  stock_transfer_id: number;
+  line_no: number;
+  /** Source level for OUT; matched level for IN (null = choose on confirmation) */
+  stock_level_id: number | null;
+  product_name: string;
+  spec_name: string | null;
+  barcode: string | null;
+  quantity: number;
+  /** null = not confirmed yet */
+  received_quantity: number | null;
+}
+
+/** Another store of the tenant that can receive transfers */
+export interface TransferPeer {
+  store_id: number;
+  name: string;
+}
+
+export interface StockTransferCreate {
+  to_store_id: number;
+  note?: string | null;
+  lines: { stock_level_id: number; quantity: number }[];
+}
+
+export interface StockTransferReceive {
+  /** Every line of the transfer */
+  lines: {
+    line_id: number;
+    /** null = not tracked here (quantity recorded, no stock posted) */
+    stock_level_id: number | null;
+    received_quantity: number;
+  }[];
+}
+
+// ============ Remote Support (远程支持) ============
+
+/** Support session opened from this store (relayed to crab-cloud) */
+export interface SupportSession {
+  session_id: number;
+  subject: string;
+  opened_by: string;
+  consent_by: string;
+  opened_at: number;
+  /** Log sharing stops and the session closes at this time */
+  expires_at: number;
+}
+
+export type SupportAuthor = 'STORE' | 'AGENT';
+
+export interface SupportMessage {
+  id: number;
+  session_id: number;
+  author: SupportAuthor;
+  author_name: string;
+  text: string;
+  sent_at: number;
+}
+
+export interface SupportSessionOpen {
+  subject: string;
+  /** Explicit consent to share diagnostics and sanitized logs */
+  consent: boolean;
+  duration_minutes?: number | null;
+}
+
+export interface SupportSessionView {
+  session: SupportSession;
+  messages: SupportMessage[];
+}
+
 // ============ Attribute ============
 
 export interface AttributeOption {
@@ -233,10 +497,14 @@ export interface AttributeOption {
   enable_quantity: boolean;
   /** Maximum quantity allowed (only effective when enable_quantity=true) */
   max_quantity: number | null;
+  /** Stock level consumed per unit sold (edge-local) */
+  stock_level_id: number | null;
 }
 
-/** Attribute option input (for create/update, without id/attribute_id/is_active) */
+/** Attribute option input (for create/update, without attribute_id/is_active) */
 export interface AttributeOptionInput {
+  /** Existing option id (kept on update); omit for a new option */
+  id?: number | null;
   name: string;
   price_modifier?: number;
   display_order?: number;
@@ -244,6 +512,7 @@ export interface AttributeOptionInput {
   kitchen_print_name?: string | null;
   enable_quantity?: boolean;
   max_quantity?: number | null;
+  stock_level_id?: number | null;
 }
 
 export interface Attribute {
@@ -360,6 +629,132 @@ export interface PrintConfig {
   default_label_printer: string | null;
 }
 
+// ============ Print Routing Matrix ============
+
+/** Where a product's effective destinations come from */
+export type RoutingSource = 'PRODUCT' | 'CATEGORY' | 'DEFAULT' | 'DISABLED';
+
+export interface EffectiveRoute {
+  enabled: boolean;
+  destinations: number[];
+  source: RoutingSource;
+}
+
+export interface RoutingMatrixRow {
+  product_id: number;
+  product_name: string;
+  category_id: number;
+  category_name: string;
+  kitchen: EffectiveRoute;
+  label: EffectiveRoute;
+}
+
+export interface RoutingMatrix {
+  /** Matrix columns (all print destinations) */
+  destinations: PrintDestination[];
+  rows: RoutingMatrixRow[];
+}
+
+// ============ Pickup (外卖取餐通知) ============
+
+export type PickupStatus = 'WAITING' | 'READY' | 'COLLECTED';
+export type NotifyChannel = 'SMS' | 'WHATSAPP';
+export type DeliveryStatus = 'SENT' | 'DELIVERED' | 'FAILED';
+
+export interface PickupTicket {
+  id: number;
+  order_id: number;
+  receipt_number: string;
+  queue_number: number | null;
+  /** Short code printed on the receipt, used by the public status page */
+  pickup_code: string;
+  phone: string | null;
+  channel: NotifyChannel;
+  status: PickupStatus;
+  /** null until a message is attempted */
+  delivery_status: DeliveryStatus | null;
+  attempts: number;
+  gateway_message_id: string | null;
+  last_error: string | null;
+  created_at: number;
+  ready_at: number | null;
+  notified_at: number | null;
+  delivered_at: number | null;
+  collected_at: number | null;
+  updated_at: number;
+}
+
+export interface PickupCreate {
+  order_id: number;
+  phone?: string | null;
+  channel?: NotifyChannel;
+}
+
+// ============ Capabilities ============
+
+export interface PlanEntitlements {
+  plan: 'basic' | 'pro' | 'enterprise';
+  status: 'inactive' | 'active' | 'past_due' | 'expired' | 'canceled' | 'unpaid';
+  features: string[];
+  /** 0 = unlimited */
+  max_stores: number;
+  /** 0 = unlimited */
+  max_clients: number;
+  blocked: boolean;
+}
+
+/** Setup wizard step (mandatory: STORE_INFO / TAX_RATES / ADMIN) */
+export type SetupStepKind = 'STORE_INFO' | 'TAX_RATES' | 'TABLES' | 'ADMIN' | 'PRINTER';
+
+export interface SetupStep {
+  step: SetupStepKind;
+  mandatory: boolean;
+  /** Confirmed (or skipped) */
+  completed: boolean;
+  skipped: boolean;
+  completed_at: number | null;
+  /** Current validation problems (must be empty to confirm) */
+  issues: string[];
+}
+
+/** GET /api/setup/steps - new orders are blocked until `complete` */
+export interface SetupStatus {
+  complete: boolean;
+  steps: SetupStep[];
+}
+
+export interface SetupStepSubmit {
+  step: SetupStepKind;
+  /** Skip an optional step */
+  skip?: boolean;
+}
+
+/** GET /api/capabilities - feature set enabled on this edge */
+export interface Capabilities {
+  version: string;
+  git_hash: string;
+  /** Modules compiled into this build */
+  modules: string[];
+  /** Configured integrations (pms / message_gateway / event_journal / public_status) */
+  integrations: string[];
+  /** null until activated */
+  plan: PlanEntitlements | null;
+  protocol: {
+    message_bus: { min: number; max: number };
+    encodings: ('json' | 'postcard')[];
+    compression: 'zstd'[];
+    topics: ('orders' | 'catalog' | 'system' | 'kds')[];
+    subscription_filters: boolean;
+    schema_versions: Record<string, number>;
+  };
+  fiscalization: {
+    mode: 'verifactu' | 'disabled';
+    serie?: string;
+    signing_certificate: boolean;
+    tax_mode: 'INCLUSIVE' | 'EXCLUSIVE';
+  };
+}
+
 // ============ Zone ============
 
 export interface Zone {
@@ -380,6 +775,23 @@ interface ZoneUpdate {
   is_active?: boolean;
 }
 
+/** Zone-level product override (terrace price, bar-only items) */
+export interface ZoneProductOverride {
+  id: number;
+  zone_id: number;
+  product_id: number;
+  /** Override price for the default spec (null = catalog price) */
+  price: number | null;
+  /** false = cannot be ordered in this zone */
+  is_available: boolean;
+  updated_at: number;
+}
+
+export interface ZoneProductOverrideUpsert {
+  price: number | null;
+  is_available: boolean;
+}
+
 // ============ Dining Table ============
 
 export interface DiningTable {
@@ -403,6 +815,23 @@ interface DiningTableUpdate {
   is_active?: boolean;
 }
 
+/** Joined tables (拼桌) — the order stays bound to the host table */
+export interface TableGroup {
+  id: number;
+  host_table_id: number;
+  zone_id: number;
+  /** Display name, e.g. "5+6" */
+  name: string;
+  /** Member tables in join order (host first) */
+  table_ids: number[];
+  /** Combined seating capacity */
+  capacity: number;
+  created_by_id: number;
+  created_by_name: string;
+  created_at: number;
+  updated_at: number;
+}
+
 // ============ Price Rule ============
 
 export type RuleType = 'DISCOUNT' | 'SURCHARGE';
@@ -423,6 +852,8 @@ export interface PriceRule {
   adjustment_value: number;
   is_stackable: boolean;
   is_exclusive: boolean;
+  /** Explicit priority among overlapping rules of the same scope (higher wins) */
+  priority: number;
   // Time fields
   valid_from: number | null;        // Unix millis (i64)
   valid_until: number | null;       // Unix millis (i64)
@@ -447,6 +878,7 @@ export interface PriceRuleCreate {
   adjustment_value: number;
   is_stackable?: boolean;
   is_exclusive?: boolean;
+  priority?: number;
   // Time fields
   valid_from?: number;        // Unix millis (i64)
   valid_until?: number;       // Unix millis (i64)
@@ -469,6 +901,7 @@ export interface PriceRuleUpdate {
   adjustment_value?: number;
   is_stackable?: boolean;
   is_exclusive?: boolean;
+  priority?: number;
   // Time fields
   valid_from?: number;        // Unix millis (i64)
   valid_until?: number;       // Unix millis (i64)
@@ -478,6 +911,17 @@ export interface PriceRuleUpdate {
   is_active?: boolean;
 }
 
+/** Overlapping price rule pair (GET /api/pricing/conflicts) */
+export interface PriceRuleConflict {
+  rule_id: number;
+  rule_name: string;
+  other_rule_id: number;
+  other_rule_name: string;
+  /** Same explicit priority: winner falls back to created_at */
+  same_priority: boolean;
+  winner_id: number;
+}
+
 // ============ Marketing Group ============
 
 export interface MarketingGroup {
@@ -486,6 +930,10 @@ export interface MarketingGroup {
   description: string | null;
   sort_order: number;
   points_earn_rate: number | null;
+  /** 积分兑换单位：每 N 积分可抵扣 (null = 不可兑换) */
+  points_redeem_unit: number | null;
+  /** 每个兑换单位抵扣金额 (€) */
+  points_redeem_value: number | null;
   created_at: number;
   updated_at: number;
 }
@@ -495,6 +943,8 @@ export interface MarketingGroupCreate {
   description?: string | null;
   sort_order?: number;
   points_earn_rate?: number | null;
+  points_redeem_unit?: number | null;
+  points_redeem_value?: number | null;
 }
 
 export interface MarketingGroupUpdate {
@@ -502,6 +952,8 @@ export interface MarketingGroupUpdate {
   description?: string | null;
   sort_order?: number;
   points_earn_rate?: number | null;
+  points_redeem_unit?: number | null;
+  points_redeem_value?: number | null;
 }
 
 export interface MgDiscountRule {
@@ -554,7 +1006,11 @@ interface Member {
   email: string | null;
   points_balance: number;
   total_spent: number;
+  /** 储值余额 */
+  credit_balance: number;
   notes: string | null;
+  /** 收据语言偏好 (null = 门店默认) */
+  preferred_locale: string | null;
   is_active: boolean;
   created_at: number;
   updated_at: number;
@@ -568,6 +1024,7 @@ export interface MemberCreate {
   birthday?: string | null;
   email?: string | null;
   notes?: string | null;
+  preferred_locale?: string | null;
 }
 
 export interface MemberUpdate {
@@ -579,12 +1036,132 @@ export interface MemberUpdate {
   email?: string | null;
   notes?: string | null;
   is_active?: boolean;
+  preferred_locale?: string | null;
 }
 
 export interface MemberWithGroup extends Member {
   marketing_group_name: string;
 }
 
+export type MemberCreditKind = 'TOP_UP' | 'PAYMENT' | 'REFUND';
+
+export interface MemberCreditTransaction {
+  id: number;
+  member_id: number;
+  kind: MemberCreditKind;
+  /** 带符号金额（充值 / 退回为正，扣款为负） */
+  amount: number;
+  balance_after: number;
+  order_id: number | null;
+  payment_id: number | null;
+  method: string | null;
+  note: string | null;
+  operator_id: number | null;
+  operator_name: string | null;
+  created_at: number;
+}
+
+export interface MemberCreditTopUp {
+  amount: number;
+  method: string;
+  note?: string | null;
+}
+
+export interface MemberCreditStatement {
+  member_id: number;
+  from: number;
+  to: number;
+  opening_balance: number;
+  closing_balance: number;
+  total_top_up: number;
+  total_spent: number;
+  total_refunded: number;
+  transactions: MemberCreditTransaction[];
+}
+
+export type MemberPointsKind = 'EARN' | 'REDEEM';
+
+export interface MemberPointsTransaction {
+  id: number;
+  member_id: number;
+  kind: MemberPointsKind;
+  /** 带符号积分（累积为正，兑换为负） */
+  points: number;
+  balance_after: number;
+  order_id: number | null;
+  /** 累积：实付金额 / 兑换：抵扣金额 */
+  amount: number;
+  created_at: number;
+}
+
+export interface MemberPointsSummary {
+  member_id: number;
+  points_balance: number;
+  points_earn_rate: number | null;
+  points_redeem_unit: number | null;
+  points_redeem_value: number | null;
+  /** 最近流水（新 → 旧） */
+  transactions: MemberPointsTransaction[];
+}
+
+// ============ Coupon ============
+
+/** 优惠码：max_uses = 1 单次码，null 不限次数 */
+export interface Coupon {
+  id: number;
+  code: string;
+  name: string;
+  product_scope: ProductScope;
+  target_id: number | null;
+  adjustment_type: AdjustmentType;
+  adjustment_value: number;
+  max_uses: number | null;
+  used_count: number;
+  valid_from: number | null;
+  valid_until: number | null;
+  is_active: boolean;
+  created_at: number;
+  updated_at: number;
+}
+
+export interface CouponCreate {
+  /** 留空自动生成 */
+  code?: string | null;
+  name: string;
+  product_scope: ProductScope;
+  target_id?: number | null;
+  adjustment_type: AdjustmentType;
+  adjustment_value: number;
+  max_uses?: number | null;
+  valid_from?: number | null;
+  valid_until?: number | null;
+}
+
+export interface CouponUpdate {
+  name?: string;
+  product_scope?: ProductScope;
+  target_id?: number | null;
+  adjustment_type?: AdjustmentType;
+  adjustment_value?: number;
+  max_uses?: number | null;
+  valid_from?: number | null;
+  valid_until?: number | null;
+  is_active?: boolean;
+}
+
+/** 批量生成单次码 */
+export interface CouponGenerate {
+  count: number;
+  prefix?: string | null;
+  name: string;
+  product_scope: ProductScope;
+  target_id?: number | null;
+  adjustment_type: AdjustmentType;
+  adjustment_value: number;
+  valid_from?: number | null;
+  valid_until?: number | null;
+}
+
 // ============ Stamp ============
 
 export type RewardStrategy = 'ECONOMIZADOR' | 'GENEROSO' | 'DESIGNATED';
@@ -600,6 +1177,18 @@ interface StampActivity {
   designated_product_id: number | null;
   is_cyclic: boolean;
   is_active: boolean;
+  /** Campaign start (Unix millis) */
+  valid_from: number | null;
+  /** Campaign end (Unix millis) */
+  valid_until: number | null;
+  /** 0=Sunday..6=Saturday, null = every day */
+  active_days: number[] | null;
+  /** HH:MM */
+  active_start_time: string | null;
+  /** HH:MM */
+  active_end_time: string | null;
+  /** Max stamps per member per business day */
+  max_stamps_per_day: number | null;
   created_at: number;
   updated_at: number;
 }
@@ -611,6 +1200,12 @@ export interface StampActivityCreate {
   reward_strategy?: RewardStrategy;
   designated_product_id?: number | null;
   is_cyclic?: boolean;
+  valid_from?: number | null;
+  valid_until?: number | null;
+  active_days?: number[] | null;
+  active_start_time?: string | null;
+  active_end_time?: string | null;
+  max_stamps_per_day?: number | null;
   stamp_targets: StampTargetInput[];
   reward_targets: StampTargetInput[];
 }
@@ -623,10 +1218,52 @@ export interface StampActivityUpdate {
   designated_product_id?: number | null;
   is_cyclic?: boolean;
   is_active?: boolean;
+  valid_from?: number | null;
+  valid_until?: number | null;
+  active_days?: number[] | null;
+  active_start_time?: string | null;
+  active_end_time?: string | null;
+  max_stamps_per_day?: number | null;
   stamp_targets?: StampTargetInput[];
   reward_targets?: StampTargetInput[];
 }
 
+/** Stamp campaign performance over a period (GET /api/statistics/stamp-campaigns) */
+export interface StampCampaignStats {
+  stamp_activity_id: number;
+  stamp_activity_name: string;
+  marketing_group_id: number;
+  is_active: boolean;
+  members_earning: number;
+  earning_orders: number;
+  stamps_earned: number;
+  stamps_capped: number;
+  redemptions: number;
+  members_redeeming: number;
+}
+
+export type CampaignKind = 'PRICE_RULE' | 'MARKETING_GROUP' | 'STAMP_ACTIVITY';
+
+export interface CampaignRoi {
+  kind: CampaignKind;
+  campaign_id: number | null;
+  name: string;
+  redemptions: number;
+  discount_cost: number;
+  attributable_revenue: number;
+  revenue_per_cost: number | null;
+  members_reached: number;
+  repeat_rate: number;
+  repeat_lift: number;
+}
+
+export interface CampaignRoiReport {
+  from: number;
+  to: number;
+  baseline_repeat_rate: number;
+  campaigns: CampaignRoi[];
+}
+
 export interface StampTargetInput {
   target_type: StampTargetType;
   target_id: number;
@@ -701,6 +1338,8 @@ export interface Employee {
   is_system: boolean;
   is_active: boolean;
   created_at: number;
+  /** SHA-256 of the stored face photo (null = no photo) */
+  photo?: string | null;
 }
 
 
@@ -770,6 +1409,10 @@ export interface User {
   is_system: boolean;
   is_active: boolean;
   created_at: number;
+  /** Face photo (data URL) returned with login / escalation */
+  photo?: string;
+  /** Employee has a stored photo (management list) */
+  has_photo?: boolean;
 }
 
 // ============ Product/Category Attribute Bindings ============
@@ -876,12 +1519,39 @@ export interface KitchenOrderListResponse {
   total: number | null;
 }
 
+/** PENDING: retried automatically with backoff; FAILED: retries exhausted, awaiting reprint/cancel */
+export type PrintJobStatus = 'PENDING' | 'FAILED';
+
+/** Failed destination print in the print queue */
+export interface PrintJob {
+  id: number;
+  kitchen_order_id: number;
+  order_id: number;
+  receipt_number: string;
+  destination_id: string;
+  destination_name: string;
+  status: PrintJobStatus;
+  attempts: number;
+  last_error: string;
+  next_attempt_at: number;
+  created_at: number;
+}
+
 // ============ Store Info ============
 
 /**
  * Store information (singleton per tenant)
  * Used for receipts, labels, and business info display
  */
+/** INCLUSIVE: menu prices include tax; EXCLUSIVE: tax is added on top */
+export type TaxMode = 'INCLUSIVE' | 'EXCLUSIVE';
+/**
+ * Orders still open at the business-day cutoff:
+ * BLOCK keeps the day open, TRANSFER moves them into the next day,
+ * FORCE_COMPLETE completes paid orders and transfers the rest
+ */
+export type CarryOverPolicy = 'BLOCK' | 'TRANSFER' | 'FORCE_COMPLETE';
+
 export interface StoreInfo {
   id: number;
   name: string;
@@ -897,6 +1567,12 @@ export interface StoreInfo {
    * E.g. 120 = 02:00, 360 = 06:00.
    */
   business_day_cutoff: number;
+  /** What happens to orders still open at the cutoff */
+  carry_over_policy: CarryOverPolicy;
+  /** Print destination for the daily report summary auto-printed at the cutoff (null = off) */
+  daily_report_print_destination_id?: number | null;
+  /** Maximum change a single cash tender may take out of the drawer (default 200) */
+  max_cash_change: number;
   /** ISO 4217 currency code (e.g. "EUR", "USD", "CNY") */
   currency_code: string | null;
   /** Currency symbol (e.g. "€", "$", "¥") */
@@ -911,6 +1587,16 @@ export interface StoreInfo {
   receipt_header: string | null;
   /** Custom receipt footer text */
   receipt_footer: string | null;
+  /** Pricing tax mode (applies to orders opened afterwards) */
+  tax_mode: TaxMode;
+  /** Apply payment-method surcharge rules (card surcharge) */
+  payment_surcharge_enabled: boolean;
+  /** Disclaimer printed on receipts that carry a payment surcharge */
+  payment_surcharge_disclaimer: string | null;
+  /** Archive the exact printed bytes of every checkout receipt */
+  receipt_archive_enabled: boolean;
+  /** Also sync archived receipts to the cloud */
+  receipt_archive_cloud: boolean;
   created_at: number | null;
   updated_at: number | null;
 }
@@ -925,6 +1611,10 @@ export interface StoreInfoUpdate {
   website?: string | null;
   /** Business day cutoff in minutes from midnight (0-480) */
   business_day_cutoff?: number;
+  carry_over_policy?: CarryOverPolicy;
+  /** 0 turns the daily report auto-print off */
+  daily_report_print_destination_id?: number;
+  max_cash_change?: number;
   currency_code?: string;
   currency_symbol?: string;
   currency_decimal_places?: number;
@@ -932,6 +1622,482 @@ export interface StoreInfoUpdate {
   receipt_locale?: string;
   receipt_header?: string;
   receipt_footer?: string;
+  tax_mode?: TaxMode;
+  payment_surcharge_enabled?: boolean;
+  payment_surcharge_disclaimer?: string;
+  receipt_archive_enabled?: boolean;
+  receipt_archive_cloud?: boolean;
+}
+
+// ============ Receipt Archive (收据存档) ============
+
+export type ReceiptArtifactKind = 'ESC_POS' | 'PDF';
+
+/** Archived receipt bytes (write-once, keyed by receipt number) */
+export interface ReceiptArtifact {
+  id: number;
+  /** Receipt number as printed (split receipts carry their `-N` suffix) */
+  receipt_number: string;
+  kind: ReceiptArtifactKind;
+  /** Hex SHA-256 of the artifact bytes */
+  sha256: string;
+  size_bytes: number;
+  reprint: boolean;
+  created_at: number;
+  cloud_synced: boolean;
+}
+
+// ============ Terminal Profile (终端漫游配置) ============
+
+/** Device-local preferences stored on the edge, keyed by certificate identity */
+export interface TerminalProfile {
+  identity: string;
+  /** Preferences object (schema owned by the terminal) */
+  settings: Record<string, unknown>;
+  /** Incremented on every save */
+  version: number;
+  updated_at: number;
+}
+
+/** Identity + profile received in the message bus handshake (Client mode only) */
+export interface TerminalProfileInfo {
+  identity: string | null;
+  profile: TerminalProfile | null;
+}
+
+// ============ Customer Display (顾客显示屏 / 客显) ============
+
+/** Pole display attached to a terminal (keyed by certificate identity) */
+export interface CustomerDisplay {
+  id: number;
+  identity: string;
+  name: string;
+  host: string;
+  port: number;
+  /** Characters per line */
+  columns: number;
+  /** Shown when no order is in progress */
+  idle_text: string | null;
+  is_active: boolean;
+  created_at: number;
+  updated_at: number;
+}
+
+export interface CustomerDisplayCreate {
+  identity: string;
+  name: string;
+  host: string;
+  port?: number;
+  columns?: number;
+  idle_text?: string | null;
+}
+
+export interface CustomerDisplayUpdate {
+  identity?: string;
+  name?: string;
+  host?: string;
+  port?: number;
+  columns?: number;
+  idle_text?: string | null;
+  is_active?: boolean;
+}
+
+// ============ Payment Surcharge (支付方式附加费) ============
+
+export interface PaymentSurchargeRule {
+  id: number;
+  /** Payment method (e.g. "CARD"), one rule per method */
+  payment_method: string;
+  adjustment_type: AdjustmentType;
+  /** Percent (0-100) or fixed amount */
+  value: number;
+  /** Minimum ticket total for the surcharge to apply (null = always) */
+  min_ticket: number | null;
+  is_active: boolean;
+  created_at: number;
+  updated_at: number;
+}
+
+export interface PaymentSurchargeRuleCreate {
+  payment_method: string;
+  adjustment_type: AdjustmentType;
+  value: number;
+  min_ticket?: number | null;
+}
+
+export interface PaymentSurchargeRuleUpdate {
+  adjustment_type?: AdjustmentType;
+  value?: number;
+  min_ticket?: number | null;
+  /** Remove the minimum ticket threshold */
+  clear_min_ticket?: boolean;
+  is_active?: boolean;
+}
+
+// ============ Delivery Rules (外卖渠道起送价 / 配送费) ============
+
+export interface DeliveryFeeTier {
+  min_subtotal: number;
+  fee: number;
+}
+
+export interface DeliveryRule {
+  id: number;
+  /** Delivery channel (order metadata "platform"), case-insensitive */
+  channel: string;
+  /** Delivery zone (null = channel default) */
+  zone: string | null;
+  /** Minimum order value (null = no minimum) */
+  min_order: number | null;
+  /** Fee tiers, sorted by min_subtotal */
+  fee_tiers: DeliveryFeeTier[];
+  is_active: boolean;
+  created_at: number;
+  updated_at: number;
+}
+
+export interface DeliveryRuleCreate {
+  channel: string;
+  zone?: string | null;
+  min_order?: number | null;
+  fee_tiers?: DeliveryFeeTier[];
+}
+
+export interface DeliveryRuleUpdate {
+  min_order?: number | null;
+  /** Remove the minimum order value */
+  clear_min_order?: boolean;
+  fee_tiers?: DeliveryFeeTier[];
+  is_active?: boolean;
+}
+
+export interface DeliveryTierHint {
+  min_subtotal: number;
+  fee: number;
+  /** Amount still to add to reach the tier */
+  amount_needed: number;
+}
+
+export interface DeliveryQuote {
+  channel: string;
+  zone: string | null;
+  subtotal: number;
+  min_order: number | null;
+  meets_minimum: boolean;
+  /** Amount still to add to reach the minimum (0 when met) */
+  amount_to_minimum: number;
+  /** Fee at the current subtotal */
+  fee: number;
+  next_tier: DeliveryTierHint | null;
+}
+
+// ============ Receipt Footer (收据页脚促销) ============
+
+export interface ReceiptFooter {
+  id: number;
+  name: string;
+  /** Promotional text, one printed line per text line */
+  lines: string;
+  /** Survey URL; `{code}` is replaced by the per-receipt code */
+  survey_url: string | null;
+  /** Active days of week (0=Sunday..6=Saturday), null = every day */
+  active_days: number[] | null;
+  /** Lower value wins when several footers are active on the same day */
+  sort_order: number;
+  is_active: boolean;
+  created_at: number;
+  updated_at: number;
+}
+
+export interface ReceiptFooterCreate {
+  name: string;
+  lines: string;
+  survey_url?: string | null;
+  active_days?: number[] | null;
+  sort_order?: number;
+}
+
+export interface ReceiptFooterUpdate {
+  name?: string;
+  lines?: string;
+  survey_url?: string | null;
+  active_days?: number[] | null;
+  sort_order?: number;
+  is_active?: boolean;
+}
+
+/** Footer resolved for one receipt (matches Rust ResolvedReceiptFooter) */
+export interface ResolvedReceiptFooter {
+  footer_id: number;
+  lines: string[];
+  survey_code: string | null;
+  survey_qr: string | null;
+}
+
+// ============ Display Slide (客显屏轮播) ============
+
+export interface DisplaySlide {
+  id: number;
+  name: string;
+  /** Image content hash (resolved via imageCache) */
+  image: string;
+  /** Seconds on screen before advancing */
+  duration_secs: number;
+  sort_order: number;
+  /** Active days of week (0=Sunday..6=Saturday), null = every day */
+  active_days: number[] | null;
+  /** Daily window "HH:MM" in store timezone (end exclusive, may cross midnight) */
+  active_start_time: string | null;
+  active_end_time: string | null;
+  valid_from: number | null;
+  valid_until: number | null;
+  is_active: boolean;
+  created_at: number;
+  updated_at: number;
+}
+
+export interface DisplaySlideCreate {
+  name: string;
+  image: string;
+  duration_secs?: number;
+  sort_order?: number;
+  active_days?: number[] | null;
+  active_start_time?: string | null;
+  active_end_time?: string | null;
+  valid_from?: number | null;
+  valid_until?: number | null;
+}
+
+export interface DisplaySlideUpdate {
+  name?: string;
+  image?: string;
+  duration_secs?: number;
+  sort_order?: number;
+  active_days?: number[] | null;
+  active_start_time?: string | null;
+  active_end_time?: string | null;
+  valid_from?: number | null;
+  valid_until?: number | null;
+  is_active?: boolean;
+}
+
+// ============ Event Booking (宴会预订) ============
+
+export type EventBookingStatus = 'DRAFT' | 'CONFIRMED' | 'MATERIALIZED' | 'CANCELLED';
+
+export interface EventBooking {
+  id: number;
+  event_name: string;
+  customer_name: string;
+  customer_phone: string | null;
+  member_id: number | null;
+  /** Event start (Unix millis) */
+  event_start: number;
+  headcount: number;
+  table_id: number | null;
+  table_name: string | null;
+  zone_id: number | null;
+  zone_name: string | null;
+  note: string | null;
+  status: EventBookingStatus;
+  /** Incremented on every amendment */
+  revision: number;
+  estimated_total: number;
+  deposit_total: number;
+  /** Order created on the event day */
+  order_id: number | null;
+  materialized_at: number | null;
+  materialize_error: string | null;
+  created_by_id: number;
+  created_by_name: string;
+  created_at: number;
+  updated_at: number;
+}
+
+export interface EventBookingItem {
+  id: number;
+  booking_id: number;
+  product_id: number;
+  name: string;
+  /** Agreed unit price */
+  price: number;
+  quantity: number;
+  selected_options: ItemOption[] | null;
+  selected_specification: SpecificationInfo | null;
+  /** Serving course (BEO grouping) */
+  course: string | null;
+  note: string | null;
+  sort_order: number;
+}
+
+export interface EventBookingDeposit {
+  id: number;
+  booking_id: number;
+  method: string;
+  amount: number;
+  reference: string | null;
+  note: string | null;
+  operator_id: number;
+  operator_name: string;
+  created_at: number;
+}
+
+export interface EventBookingFieldChange {
+  field: string;
+  from: unknown;
+  to: unknown;
+}
+
+export interface EventBookingItemChange {
+  name: string;
+  course: string | null;
+  /** 0 = newly added */
+  from_quantity: number;
+  /** 0 = removed */
+  to_quantity: number;
+  from_price: number;
+  to_price: number;
+}
+
+export interface EventBookingAmendment {
+  id: number;
+  booking_id: number;
+  /** Revision produced by this amendment */
+  revision: number;
+  changes: {
+    fields: EventBookingFieldChange[];
+    items: EventBookingItemChange[];
+  };
+  operator_id: number;
+  operator_name: string;
+  created_at: number;
+}
+
+export interface EventBookingDetail extends EventBooking {
+  items: EventBookingItem[];
+  deposits: EventBookingDeposit[];
+  amendments: EventBookingAmendment[];
+}
+
+export interface EventBookingItemInput {
+  product_id: number;
+  name: string;
+  price: number;
+  quantity: number;
+  selected_options?: ItemOption[] | null;
+  selected_specification?: SpecificationInfo | null;
+  course?: string | null;
+  note?: string | null;
+}
+
+export interface EventBookingCreate {
+  event_name: string;
+  customer_name: string;
+  customer_phone?: string | null;
+  member_id?: number | null;
+  event_start: number;
+  headcount: number;
+  table_id?: number | null;
+  table_name?: string | null;
+  zone_id?: number | null;
+  zone_name?: string | null;
+  note?: string | null;
+  items?: EventBookingItemInput[];
+}
+
+export interface EventBookingUpdate {
+  event_name?: string;
+  customer_name?: string;
+  customer_phone?: string | null;
+  member_id?: number | null;
+  event_start?: number;
+  headcount?: number;
+  table_id?: number | null;
+  table_name?: string | null;
+  zone_id?: number | null;
+  zone_name?: string | null;
+  note?: string | null;
+  /** Replaces the whole menu */
+  items?: EventBookingItemInput[];
+}
+
+export interface EventBookingDepositCreate {
+  method: string;
+  amount: number;
+  reference?: string | null;
+  note?: string | null;
+}
+
+// ============ Reservation (订位) ============
+
+export type ReservationStatus = 'BOOKED' | 'SEATED' | 'NO_SHOW' | 'CANCELLED';
+
+export interface Reservation {
+  id: number;
+  customer_name: string;
+  customer_phone: string | null;
+  customer_email: string | null;
+  party_size: number;
+  /** Slot start (Unix millis) */
+  start_at: number;
+  /** Slot end (Unix millis, exclusive) */
+  end_at: number;
+  zone_id: number | null;
+  zone_name: string | null;
+  table_id: number | null;
+  table_name: string | null;
+  note: string | null;
+  status: ReservationStatus;
+  seated_at: number | null;
+  created_by_id: number;
+  created_by_name: string;
+  created_at: number;
+  updated_at: number;
+}
+
+export interface ReservationCreate {
+  customer_name: string;
+  customer_phone?: string | null;
+  customer_email?: string | null;
+  party_size: number;
+  start_at: number;
+  /** Default 90 minutes */
+  duration_minutes?: number | null;
+  zone_id?: number | null;
+  /** Takes precedence over zone_id (zone = table's zone) */
+  table_id?: number | null;
+  note?: string | null;
+}
+
+export interface ReservationUpdate {
+  customer_name?: string | null;
+  customer_phone?: string | null;
+  customer_email?: string | null;
+  party_size?: number | null;
+  start_at?: number | null;
+  duration_minutes?: number | null;
+  zone_id?: number | null;
+  table_id?: number | null;
+  /** Drop the table assignment (keeps the zone) */
+  clear_table?: boolean;
+  note?: string | null;
+}
+
+export interface ReservationSlot {
+  start: number;
+  end: number;
+  /** Active reservations overlapping the slot */
+  reservations: number;
+  covers: number;
+  table_ids: number[];
+}
+
+export interface ReservationCalendar {
+  /** YYYY-MM-DD */
+  date: string;
+  slot_minutes: number;
+  slots: ReservationSlot[];
+  reservations: Reservation[];
 }
 
 // ============ Label Template (API DTOs) ============
@@ -1019,10 +2185,56 @@ export interface ShiftCreate {
 }
 
 export interface ShiftClose {
-  /** Actual cash counted */
+  /** Actual cash counted (ignored when cash_count is sent) */
   actual_cash: number;
   /** Notes */
   note?: string;
+  /** Counted denominations; the server computes actual_cash */
+  cash_count?: CashCountLine[];
+}
+
+export type DenominationKind = 'COIN' | 'NOTE';
+
+export interface CashDenomination {
+  id: number;
+  set_id: number;
+  kind: DenominationKind;
+  value: number;
+}
+
+export interface CashDenominationSet {
+  id: number;
+  currency_code: string;
+  currency_symbol: string;
+  is_primary: boolean;
+  /** Primary units per 1 unit of this currency */
+  exchange_rate: number;
+  is_active: boolean;
+  created_at: number;
+  updated_at: number;
+  denominations: CashDenomination[];
+}
+
+export interface CashCountLine {
+  currency_code: string;
+  value: number;
+  quantity: number;
+}
+
+export interface CurrencyCount {
+  currency_code: string;
+  currency_symbol: string;
+  is_primary: boolean;
+  exchange_rate: number;
+  lines: (Omit<CashCountLine, 'currency_code'> & { kind: DenominationKind; subtotal: number })[];
+  total: number;
+  total_in_primary: number;
+}
+
+export interface CashCountSummary {
+  currencies: CurrencyCount[];
+  /** Grand total in the primary currency */
+  total: number;
 }
 
 export interface ShiftForceClose {
@@ -1037,6 +2249,141 @@ export interface ShiftUpdate {
   note?: string;
 }
 
+/** Break type (paid breaks count as worked time) */
+export type BreakType = 'PAID' | 'UNPAID';
+
+export interface ShiftBreak {
+  id: number;
+  shift_id: number;
+  break_type: BreakType;
+  /** Break start time (Unix millis) */
+  start_time: number;
+  /** Break end time (Unix millis), null while on break */
+  end_time: number | null;
+  note: string | null;
+}
+
+export interface ShiftBreakStart {
+  /** Default UNPAID */
+  break_type?: BreakType;
+  note?: string;
+}
+
+/** Break compliance status of a shift */
+export interface ShiftBreakStatus {
+  shift_id: number;
+  breaks: ShiftBreak[];
+  /** Break in progress */
+  on_break: ShiftBreak | null;
+  /** Start of the current uninterrupted work period */
+  worked_since: number;
+  /** Worked 6h continuously without a break */
+  break_due: boolean;
+  paid_break_ms: number;
+  unpaid_break_ms: number;
+}
+
+// ============ Cash Drawer (钱箱管理) ============
+
+/** SALE: cash payment; NO_SALE: opened without a sale; PAID_IN / PAID_OUT: cash movements */
+export type CashDrawerEventKind = 'SALE' | 'NO_SALE' | 'PAID_IN' | 'PAID_OUT';
+
+export interface CashDrawerEvent {
+  id: number;
+  kind: CashDrawerEventKind;
+  /** Cash moved (0 for SALE / NO_SALE) */
+  amount: number;
+  reason: string | null;
+  operator_id: number;
+  operator_name: string;
+  /** Shift open at the time */
+  shift_id: number | null;
+  created_at: number;
+}
+
+export interface CashDrawerEventCreate {
+  kind: CashDrawerEventKind;
+  /** Required (> 0) for PAID_IN / PAID_OUT */
+  amount?: number;
+  /** Required for PAID_IN / PAID_OUT */
+  reason?: string | null;
+}
+
+export interface CashDrawerSummary {
+  sale_opens: number;
+  no_sale_opens: number;
+  paid_in_count: number;
+  paid_in_amount: number;
+  paid_out_count: number;
+  paid_out_amount: number;
+}
+
+/** Drawer events of a shift (paid-in / paid-out are included in expected_cash) */
+export interface ShiftCashDrawer {
+  shift_id: number;
+  summary: CashDrawerSummary;
+  events: CashDrawerEvent[];
+}
+
+// ============ Staff Announcements (员工公告) ============
+
+export type AnnouncementPriority = 'NORMAL' | 'URGENT';
+
+export interface AnnouncementAck {
+  employee_id: number;
+  employee_name: string;
+  /** Terminal the announcement was acknowledged on */
+  terminal: string | null;
+  acked_at: number;
+}
+
+export interface Announcement {
+  id: number;
+  message: string;
+  priority: AnnouncementPriority;
+  sender_id: number;
+  sender_name: string;
+  /** Target terminal names, empty = all terminals */
+  targets: string[];
+  created_at: number;
+  acks: AnnouncementAck[];
+}
+
+export interface AnnouncementCreate {
+  message: string;
+  priority?: AnnouncementPriority;
+  targets?: string[];
+}
+
+/** Terminal connected to the edge server (announcement target) */
+export interface ConnectedTerminal {
+  client_id: string;
+  name: string;
+  addr: string | null;
+}
+
+// ============ Live KPI (经理看板) ============
+
+/** Rolling business-day metrics pushed as `kpi` server messages */
+export interface KpiSnapshot {
+  generated_at: number;
+  /** YYYY-MM-DD */
+  business_date: string;
+  sales_today: number;
+  /** Same business day last week, up to the same time of day */
+  sales_same_day_last_week: number;
+  orders_today: number;
+  /** Completed + in-progress guests */
+  covers_today: number;
+  average_ticket: number;
+  open_tables: number;
+  open_orders: number;
+  open_amount: number;
+  kitchen_backlog_tickets: number;
+  kitchen_backlog_items: number;
+  kitchen_oldest_ticket_at: number | null;
+}
+
 // ============ Daily Report (日结报告) ============
 
 /** Shift breakdown within a daily report */
@@ -1063,6 +2410,15 @@ export interface ShiftBreakdown {
   total_tax: number;
   total_discount: number;
   total_surcharge: number;
+  /** Paid break time (millis) */
+  paid_break_ms: number;
+  /** Unpaid break time (millis, deducted from worked time) */
+  unpaid_break_ms: number;
+  /** Drawer opens without a sale */
+  no_sale_opens?: number;
+  /** Cash paid in / out of the drawer (included in expected_cash) */
+  paid_in_amount?: number;
+  paid_out_amount?: number;
 }
 
 /**
@@ -1081,6 +2437,19 @@ export interface DailyReport {
   refund_amount: number;
   /** Number of credit notes issued */
   refund_count: number;
+  /** Payment-method surcharges collected (card surcharge) */
+  payment_surcharge_amount?: number;
+  /** Orders already open when the business day started */
+  carried_in_count?: number;
+  carried_in_amount?: number;
+  /** Orders still open when the business day ended */
+  carried_out_count?: number;
+  carried_out_amount?: number;
+  /** Drawer opens without a sale */
+  no_sale_opens?: number;
+  /** Cash paid in / out of the drawer */
+  paid_in_amount?: number;
+  paid_out_amount?: number;
   /** Whether this report was auto-generated */
   auto_generated: boolean;
   /** When the report was generated (Unix millis) */
@@ -1121,6 +2490,8 @@ export type AuditAction =
   | 'order_completed'
   | 'order_voided'
   | 'order_merged'
+  | 'orders_imported'
+  | 'order_audit_exported'
   // 管理操作
   | 'employee_created'
   | 'employee_updated'
@@ -1132,6 +2503,18 @@ export type AuditAction =
   | 'product_created'
   | 'product_updated'
   | 'product_deleted'
+  | 'product_eighty_sixed'
+  | 'product_eighty_six_cleared'
+  | 'stock_level_created'
+  | 'stock_level_updated'
+  | 'stock_level_deleted'
+  | 'stock_adjusted'
+  | 'stock_count_started'
+  | 'stock_count_submitted'
+  | 'stock_count_approved'
+  | 'stock_count_rejected'
+  | 'stock_transfer_sent'
+  | 'stock_transfer_received'
   | 'category_created'
   | 'category_updated'
   | 'category_deleted'
@@ -1152,6 +2535,8 @@ export type AuditAction =
   | 'table_created'
   | 'table_updated'
   | 'table_deleted'
+  | 'tables_joined'
+  | 'tables_split'
   // 打印
   | 'label_template_created'
   | 'label_template_updated'
@@ -1163,14 +2548,39 @@ export type AuditAction =
   | 'member_created'
   | 'member_updated'
   | 'member_deleted'
+  | 'member_credit_topped_up'
+  | 'members_imported'
+  // 宴会预订
+  | 'event_booking_created'
+  | 'event_booking_updated'
+  | 'event_booking_cancelled'
+  | 'event_booking_deposit_recorded'
+  | 'reservation_created'
+  | 'reservation_updated'
+  | 'reservation_cancelled'
   // 营销组
   | 'marketing_group_created'
   | 'marketing_group_updated'
   | 'marketing_group_deleted'
+  // 优惠码
+  | 'coupon_created'
+  | 'coupon_updated'
+  | 'coupon_deleted'
   // 班次
   | 'shift_opened'
   | 'shift_updated'
   | 'shift_closed'
+  | 'shift_break_started'
+  | 'shift_break_ended'
+  // 钱箱
+  | 'cash_drawer_opened'
+  | 'cash_paid_in'
+  | 'cash_paid_out'
+  // 员工公告
+  | 'announcement_sent'
+  | 'support_session_opened'
+  | 'support_session_closed'
+  | 'anonymized_database_exported'
   // 日结报告
   | 'daily_report_generated'
   // 系统配置
@@ -1209,6 +2619,26 @@ export interface AuditListResponse {
   total: number;
 }
 
+// ============ Catalog History (目录变更历史) ============
+
+export type CatalogChangeAction = 'CREATE' | 'UPDATE' | 'DELETE' | 'REVERT';
+
+/** 目录变更记录 — 与 Rust CatalogChange 对齐 */
+export interface CatalogChange {
+  id: number;
+  /** "product" | "category" | "tag" | "price_rule" */
+  entity_type: string;
+  entity_id: number;
+  action: CatalogChangeAction;
+  /** Snapshot before the change (null for CREATE) */
+  before_snapshot: Record<string, unknown> | null;
+  /** Snapshot after the change (null for DELETE) */
+  after_snapshot: Record<string, unknown> | null;
+  operator_id: number | null;
+  operator_name: string | null;
+  created_at: number;
+}
+
 // ============ System Issues (系统问题) ============
 
 /** 系统问题 — 与 Rust SystemIssueRow 对齐 */
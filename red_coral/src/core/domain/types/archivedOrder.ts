@@ -28,6 +28,25 @@ export interface ArchivedOrderSummary {
   loss_amount: number | null;
 }
 
+/** Customer service search hit (matches backend OrderSearchResult) */
+export interface ArchivedOrderSearchResult extends ArchivedOrderSummary {
+  paid_amount: number;
+  refunded_amount: number;
+  matched_reference: string | null;
+  can_reprint: boolean;
+  can_refund: boolean;
+}
+
+/** Search criteria for /api/orders/search (at least one of receipt/amount/reference) */
+export interface ArchivedOrderSearchQuery {
+  receipt?: string;
+  amount?: number;
+  reference?: string;
+  start_time?: number;
+  end_time?: number;
+  limit?: number;
+}
+
 /** Response from fetch_order_list */
 export interface ArchivedOrderListResponse {
   orders: ArchivedOrderSummary[];
@@ -109,6 +128,9 @@ export interface ArchivedPayment {
   split_items: ArchivedSplitItem[];
   aa_shares?: number | null;
   aa_total_shares?: number | null;
+  reference?: string | null;
+  /** Payment-method surcharge included in amount */
+  surcharge?: number | null;
 }
 
 /** Event for detail view */
@@ -151,6 +173,8 @@ export interface ArchivedOrderDetail {
   marketing_group_name: string | null;
   start_time: number; // milliseconds
   end_time: number | null; // milliseconds
+  /** 税控码（外部税控设备登记后返回） */
+  fiscal_code?: string | null;
   operator_name: string | null;
   queue_number: number | null;
   // === Void Metadata ===
@@ -160,6 +184,8 @@ export interface ArchivedOrderDetail {
   void_note: string | null;
   is_voided: boolean;
   is_upgraded: boolean;
+  /** 从旧 POS 导入的历史订单（无财务凭证） */
+  is_imported: boolean;
   items: ArchivedOrderItem[];
   order_adjustments: ArchivedAdjustment[];
   payments: ArchivedPayment[];
@@ -62,7 +62,10 @@ export interface RefundableInfo {
 /** Request to create a credit note */
 export interface CreateCreditNoteRequest {
   original_order_pk: number;
+  /** 退款明细 (full_refund 时为空) */
   items: CreditNoteItemRequest[];
+  /** 整单退款：服务端按剩余可退数量展开 */
+  full_refund: boolean;
   refund_method: string;
   reason: string;
   note?: string | null;
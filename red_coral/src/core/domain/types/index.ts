@@ -60,22 +60,26 @@ export type HeldOrder = import('./orderEvent').OrderSnapshot;
 export type DraftOrder = HeldOrder;
 
 // Permission type and constants
-// 18 个可配置权限 + 1 个管理员专属权限
+// 23 个可配置权限 + 1 个管理员专属权限
 export type Permission = string;
 
 export const Permission = {
-  // === 模块化权限 (7) ===
+  // === 模块化权限 (11) ===
   MENU_MANAGE: 'menu:manage' as Permission,           // 菜单管理
   TABLES_MANAGE: 'tables:manage' as Permission,       // 桌台管理
+  BOOKINGS_MANAGE: 'bookings:manage' as Permission,   // 宴会预订
   SHIFTS_MANAGE: 'shifts:manage' as Permission,       // 班次管理
-  REPORTS_VIEW: 'reports:view' as Permission,         // 报表查看
+  ANNOUNCEMENTS_SEND: 'announcements:send' as Permission, // 员工公告
+  REPORTS_VIEW: 'reports:view' as Permission,         // 报表查看 (营业数据)
+  REPORTS_FINANCIALS: 'reports:financials' as Permission, // 完整财务
+  REPORTS_LABOR: 'reports:labor' as Permission,       // 人员报表
   PRICE_RULES_MANAGE: 'price_rules:manage' as Permission, // 价格规则
   SETTINGS_MANAGE: 'settings:manage' as Permission,   // 系统设置
   MARKETING_MANAGE: 'marketing:manage' as Permission,  // 营销组+会员管理
   ORDERS_LINK_MEMBER: 'orders:link_member' as Permission,   // 订单关联会员
   ORDERS_REDEEM_STAMP: 'orders:redeem_stamp' as Permission, // 订单兑换印花
 
-  // === 敏感操作 (9) ===
+  // === 敏感操作 (10) ===
   ORDERS_VOID: 'orders:void' as Permission,           // 作废订单
   ORDERS_DISCOUNT: 'orders:discount' as Permission,   // 应用折扣
   ORDERS_COMP: 'orders:comp' as Permission,           // 赠送菜品
@@ -85,6 +89,7 @@ export const Permission = {
   TABLES_TRANSFER: 'tables:transfer' as Permission,         // 移台
   TABLES_MERGE_BILL: 'tables:merge_bill' as Permission,     // 合台
   CASH_DRAWER_OPEN: 'cash_drawer:open' as Permission, // 打开钱箱
+  INVENTORY_APPROVE_COUNT: 'inventory:approve_count' as Permission, // 审批盘点
 
   // === 管理员专属 ===
   USERS_MANAGE: 'users:manage' as Permission,         // 用户管理 (仅 admin)
@@ -93,7 +98,7 @@ export const Permission = {
 
 // Statistics types
 export type TimeRange = 'today' | 'yesterday' | 'this_week' | 'this_month' | 'last_month' | 'custom';
-export type ActiveTab = 'overview' | 'invoices' | 'reports_shifts' | 'red_flags' | 'audit_log';
+export type ActiveTab = 'overview' | 'live' | 'invoices' | 'reports_shifts' | 'red_flags' | 'audit_log';
 
 // ── StoreOverview — flat response aligned with edge-server ──
 
@@ -129,6 +134,14 @@ export interface StoreOverview {
   zone_sales: ZoneSaleEntry[];
   discount_breakdown: AdjustmentEntry[];
   surcharge_breakdown: AdjustmentEntry[];
+  /** Caller's report scope; hidden sections are zeroed by the server */
+  scope?: ReportScope;
+}
+
+/** Report visibility (reports:financials / reports:labor) */
+export interface ReportScope {
+  financials: boolean;
+  labor: boolean;
 }
 
 export interface AdjustmentEntry {
@@ -10,7 +10,7 @@
  * - Snapshots: Computed state from events
  */
 
-import type { AppliedMgRule } from './api/models';
+import type { AdjustmentType, AppliedMgRule, CarryOverPolicy, ProductScope, TaxMode } from './api/models';
 
 // ============================================================================
 // Service Type (零售订单的服务类型)
@@ -45,16 +45,24 @@ export type OrderEventType =
   | 'ORDER_MOVED_OUT'
   | 'ORDER_MERGED'
   | 'ORDER_MERGED_OUT'
+  | 'ITEMS_TRANSFERRED_OUT'
+  | 'ITEMS_TRANSFERRED_IN'
   | 'TABLE_REASSIGNED'
   | 'ORDER_INFO_UPDATED'
   | 'RULE_SKIP_TOGGLED'
   | 'ORDER_DISCOUNT_APPLIED'
   | 'ORDER_SURCHARGE_APPLIED'
+  | 'ITEMS_HELD'
+  | 'COURSE_FIRED'
   | 'ORDER_NOTE_ADDED'
+  | 'ORDER_METADATA_SET'
+  | 'ORDER_CARRIED_OVER'
   | 'MEMBER_LINKED'
   | 'MEMBER_UNLINKED'
   | 'STAMP_REDEEMED'
-  | 'STAMP_REDEMPTION_CANCELLED';
+  | 'STAMP_REDEMPTION_CANCELLED'
+  | 'POINTS_REDEEMED'
+  | 'COUPON_APPLIED';
 
 /**
  * Order event structure (matches Rust OrderEvent)
@@ -111,16 +119,24 @@ export type EventPayload =
   | OrderMovedOutPayload
   | OrderMergedPayload
   | OrderMergedOutPayload
+  | ItemsTransferredOutPayload
+  | ItemsTransferredInPayload
   | TableReassignedPayload
   | OrderInfoUpdatedPayload
   | RuleSkipToggledPayload
   | OrderDiscountAppliedPayload
   | OrderSurchargeAppliedPayload
+  | ItemsHeldPayload
+  | CourseFiredPayload
   | OrderNoteAddedPayload
+  | OrderMetadataSetPayload
+  | OrderCarriedOverPayload
   | MemberLinkedPayload
   | MemberUnlinkedPayload
   | StampRedeemedPayload
-  | StampRedemptionCancelledPayload;
+  | StampRedemptionCancelledPayload
+  | PointsRedeemedPayload
+  | CouponAppliedPayload;
 
 export interface TableOpenedPayload {
   type: 'TABLE_OPENED';
@@ -134,6 +150,8 @@ export interface TableOpenedPayload {
   queue_number?: number | null;
   /** Server-generated receipt number (always present) */
   receipt_number: string;
+  /** 定价含税模式（开台时从门店设置定格） */
+  tax_mode?: TaxMode;
 }
 
 export interface OrderCompletedPayload {
@@ -143,6 +161,8 @@ export interface OrderCompletedPayload {
   service_type: ServiceType | null;
   final_total: number;
   payment_summary: PaymentSummaryItem[];
+  /** 税控码（外部税控设备登记后返回） */
+  fiscal_code?: string | null;
 }
 
 /** 作废类型 */
@@ -245,6 +265,10 @@ export interface PaymentAddedPayload {
   tendered?: number | null;
   change?: number | null;
   note?: string | null;
+  /** Card last-4 or terminal transaction reference */
+  reference?: string | null;
+  /** Payment-method surcharge included in amount */
+  surcharge?: number | null;
 }
 
 export interface PaymentCancelledPayload {
@@ -343,6 +367,21 @@ export interface OrderMergedOutPayload {
   reason?: string | null;
 }
 
+export interface ItemsTransferredOutPayload {
+  type: 'ITEMS_TRANSFERRED_OUT';
+  target_order_id: number;
+  target_table_name: string;
+  instance_ids: string[];
+}
+
+export interface ItemsTransferredInPayload {
+  type: 'ITEMS_TRANSFERRED_IN';
+  source_order_id: number;
+  source_table_name: string;
+  items: CartItemSnapshot[];
+  comps?: CompRecord[];
+}
+
 export interface TableReassignedPayload {
   type: 'TABLE_REASSIGNED';
   source_table_id: number;
@@ -397,6 +436,21 @@ export interface OrderSurchargeAppliedPayload {
   total: number;
 }
 
+/** 商品改入指定道次并保持暂缓 */
+export interface ItemsHeldPayload {
+  type: 'ITEMS_HELD';
+  course: number;
+  instance_ids: string[];
+}
+
+/** 道次起菜（暂缓商品送厨） */
+export interface CourseFiredPayload {
+  type: 'COURSE_FIRED';
+  course: number;
+  /** 起菜商品快照 */
+  items: CartItemSnapshot[];
+}
+
 /** 订单备注已添加/更新 */
 export interface OrderNoteAddedPayload {
   type: 'ORDER_NOTE_ADDED';
@@ -406,6 +460,25 @@ export interface OrderNoteAddedPayload {
   previous_note?: string | null;
 }
 
+/** 订单元数据已更新（仅变更的键） */
+export interface OrderMetadataSetPayload {
+  type: 'ORDER_METADATA_SET';
+  /** 变更的键 → 新值（空字符串 = 已删除） */
+  changes: Record<string, string>;
+  /** 变更键的旧值 */
+  previous?: Record<string, string>;
+}
+
+/** 跨营业日未结订单已结转到新营业日 */
+export interface OrderCarriedOverPayload {
+  type: 'ORDER_CARRIED_OVER';
+  /** 原营业日 (YYYY-MM-DD) */
+  from_business_date: string;
+  /** 结转到的营业日 (YYYY-MM-DD) */
+  to_business_date: string;
+  policy: CarryOverPolicy;
+}
+
 /** MG 折扣预计算结果 (按商品) */
 export interface MgItemDiscount {
   instance_id: string;
@@ -459,6 +532,20 @@ export interface StampRedemptionCancelledPayload {
   is_comp_existing?: boolean;
 }
 
+/** 积分抵扣已设置 (points = 0 表示清除) */
+export interface PointsRedeemedPayload {
+  type: 'POINTS_REDEEMED';
+  points: number;
+  /** Discount value of the redeemed points */
+  amount: number;
+}
+
+/** 优惠码已应用 (coupon = null 表示移除) */
+export interface CouponAppliedPayload {
+  type: 'COUPON_APPLIED';
+  coupon: AppliedCoupon | null;
+}
+
 // ============================================================================
 // Command Types
 // ============================================================================
@@ -477,6 +564,8 @@ export interface OrderCommand {
   operator_name: string;
   /** Command payload */
   payload: OrderCommandPayload;
+  /** Order version (snapshot.last_sequence) this edit was based on; omitted = no check */
+  expected_version?: number;
 }
 
 export type OrderCommandPayload =
@@ -494,17 +583,24 @@ export type OrderCommandPayload =
   | PayAaSplitCommand
   | MoveOrderCommand
   | MergeOrdersCommand
+  | TransferItemsCommand
   | UpdateOrderInfoCommand
   | ToggleRuleSkipCommand
   | CompItemCommand
   | UncompItemCommand
   | ApplyOrderDiscountCommand
   | ApplyOrderSurchargeCommand
+  | HoldItemsCommand
+  | FireCourseCommand
   | AddOrderNoteCommand
+  | SetOrderMetadataCommand
+  | CarryOverOrderCommand
   | LinkMemberCommand
   | UnlinkMemberCommand
   | RedeemStampCommand
-  | CancelStampRedemptionCommand;
+  | CancelStampRedemptionCommand
+  | RedeemPointsCommand
+  | ApplyCouponCommand;
 
 export interface OpenTableCommand {
   type: 'OPEN_TABLE';
@@ -599,6 +695,8 @@ export interface PaymentInput {
   amount: number;
   tendered?: number | null;
   note?: string | null;
+  /** Card last-4 or terminal transaction reference */
+  reference?: string | null;
 }
 
 export interface AddPaymentCommand {
@@ -672,6 +770,14 @@ export interface MergeOrdersCommand {
   authorizer_name?: string | null;
 }
 
+/** Move selected items to another order */
+export interface TransferItemsCommand {
+  type: 'TRANSFER_ITEMS';
+  source_order_id: number;
+  target_order_id: number;
+  instance_ids: string[];
+}
+
 /** Update order info (receipt_number is immutable - set at OpenTable) */
 export interface UpdateOrderInfoCommand {
   type: 'UPDATE_ORDER_INFO';
@@ -714,6 +820,22 @@ export interface ApplyOrderSurchargeCommand {
   authorizer_name?: string | null;
 }
 
+/** 将暂缓商品改入指定道次（仅未起菜的商品） */
+export interface HoldItemsCommand {
+  type: 'HOLD_ITEMS';
+  order_id: number;
+  /** 目标道次（从 1 开始） */
+  course: number;
+  instance_ids: string[];
+}
+
+/** 起菜：该道次的暂缓商品送厨出单 */
+export interface FireCourseCommand {
+  type: 'FIRE_COURSE';
+  order_id: number;
+  course: number;
+}
+
 /** 添加/清除订单备注 */
 export interface AddOrderNoteCommand {
   type: 'ADD_ORDER_NOTE';
@@ -722,6 +844,22 @@ export interface AddOrderNoteCommand {
   note: string;
 }
 
+/** 设置订单元数据（合并式，空值 = 删除该键） */
+export interface SetOrderMetadataCommand {
+  type: 'SET_ORDER_METADATA';
+  order_id: number;
+  metadata: Record<string, string>;
+}
+
+/** 结转跨营业日未结订单（营业日调度器发起） */
+export interface CarryOverOrderCommand {
+  type: 'CARRY_OVER_ORDER';
+  order_id: number;
+  from_business_date: string;
+  to_business_date: string;
+  policy: CarryOverPolicy;
+}
+
 /** 关联会员到订单 */
 export interface LinkMemberCommand {
   type: 'LINK_MEMBER';
@@ -752,6 +890,20 @@ export interface CancelStampRedemptionCommand {
   stamp_activity_id: number;
 }
 
+/** 积分抵扣 (points = 0 清除) */
+export interface RedeemPointsCommand {
+  type: 'REDEEM_POINTS';
+  order_id: number;
+  points: number;
+}
+
+/** 应用优惠码 (code = null 移除) */
+export interface ApplyCouponCommand {
+  type: 'APPLY_COUPON';
+  order_id: number;
+  code: string | null;
+}
+
 // ============================================================================
 // Response Types
 // ============================================================================
@@ -765,6 +917,8 @@ export interface CommandResponse {
   /** New order ID (only for OpenTable command) */
   order_id?: number | null;
   error?: CommandError | null;
+  /** Current order snapshot (only for VERSION_CONFLICT, used to merge) */
+  current_snapshot?: OrderSnapshot | null;
 }
 
 export interface CommandError {
@@ -794,6 +948,8 @@ export type CommandErrorCode =
   // Order Status
   | 'ORDER_NOT_ACTIVE'
   | 'ORDER_ALREADY_MERGED'
+  | 'SETUP_INCOMPLETE'
+  | 'VERSION_CONFLICT'
   // Member
   | 'MEMBER_ALREADY_LINKED'
   | 'NO_MEMBER_LINKED'
@@ -803,6 +959,7 @@ export type CommandErrorCode =
   | 'MARKETING_GROUP_NOT_FOUND'
   // Stamp Prefetch
   | 'STAMP_ACTIVITY_NOT_FOUND'
+  | 'STAMP_ACTIVITY_OUT_OF_WINDOW'
   // Split Blocks
   | 'AA_SPLIT_ACTIVE'
   | 'AMOUNT_SPLIT_ACTIVE'
@@ -817,11 +974,23 @@ export type CommandErrorCode =
   | 'INVALID_QUANTITY'
   | 'EMPTY_COMP_REASON'
   | 'ITEM_FULLY_PAID'
+  | 'PRODUCT_UNAVAILABLE_IN_ZONE'
+  | 'PRODUCT_EIGHTY_SIXED'
+  | 'INVALID_COURSE'
+  | 'ITEM_ALREADY_FIRED'
+  | 'NO_HELD_ITEMS'
   // Payment
   | 'PAYMENT_EXCEEDS_REMAINING'
   | 'INSUFFICIENT_TENDER'
   | 'PAYMENT_INSUFFICIENT'
+  | 'DELIVERY_MINIMUM_NOT_MET'
   | 'HAS_PAYMENTS'
+  | 'CHANGE_NOT_ALLOWED'
+  | 'CHANGE_EXCEEDS_DRAWER_LIMIT'
+  | 'INSUFFICIENT_CREDIT'
+  | 'ROOM_CHARGE_REJECTED'
+  | 'FISCAL_DEVICE_UNAVAILABLE'
+  | 'FISCAL_REJECTED'
   // Merge
   | 'CANNOT_MERGE_SELF'
   // AA Split
@@ -840,7 +1009,16 @@ export type CommandErrorCode =
   | 'STAMP_NO_MATCH'
   | 'STAMP_REDEMPTION_NOT_FOUND'
   | 'STAMP_TARGET_MISMATCH'
+  | 'STAMP_ITEM_NOT_TRANSFERABLE'
   | 'STAMP_PRODUCT_NOT_AVAILABLE'
+  // Points
+  | 'INSUFFICIENT_POINTS'
+  | 'POINTS_REDEMPTION_UNAVAILABLE'
+  // Coupon
+  | 'COUPON_NOT_FOUND'
+  | 'COUPON_EXPIRED'
+  | 'COUPON_EXHAUSTED'
+  | 'COUPON_NOT_APPLICABLE'
   // Rule
   | 'RULE_NOT_FOUND_IN_ORDER'
   // Order Info
@@ -935,6 +1113,10 @@ export interface OrderSnapshot {
   /** Total surcharge amount (item-level + order-level) */
   total_surcharge: number;
   tax: number;
+  /** Pricing tax mode (fixed at OpenTable) */
+  tax_mode?: TaxMode;
+  /** Tax breakdown by rate (non-comped items) */
+  tax_breakdown?: TaxBreakdownLine[];
   /** Total discount amount (order-level) */
   discount: number;
   /** Comp total amount (赠送减免总额) */
@@ -943,6 +1125,10 @@ export interface OrderSnapshot {
   order_manual_discount_amount: number;
   /** Order-level manual surcharge computed amount (整单手动附加费实际金额) */
   order_manual_surcharge_amount: number;
+  /** Payment-method surcharges (支付方式附加费，已计入 total) */
+  payment_surcharges?: PaymentSurchargeLine[];
+  /** Σ non-cancelled payment surcharges */
+  payment_surcharge_amount?: number;
   /** Total amount to pay */
   total: number;
   /** Amount already paid */
@@ -962,6 +1148,12 @@ export interface OrderSnapshot {
   is_pre_payment?: boolean;
   /** 订单备注 */
   note?: string | null;
+  /** 集成方元数据（外卖平台单号、酒店房号等） */
+  metadata?: Record<string, string>;
+  /** 最近一次结转到的营业日 (YYYY-MM-DD) */
+  carry_over_date?: string | null;
+  /** 税控码（外部税控设备登记后返回） */
+  fiscal_code?: string | null;
 
   // === Order-level Rule Adjustments ===
   /** Order-level rule discount amount */
@@ -998,6 +1190,10 @@ export interface OrderSnapshot {
   // === Stamp Redemption Tracking ===
   /** Pending stamp redemptions (consumed on order completion, reversed on member unlink) */
   stamp_redemptions?: StampRedemptionState[];
+  /** Loyalty points redeemed as an order discount (deducted on completion) */
+  points_redemption?: PointsRedemption;
+  /** Coupon applied to the order (redeemed on completion) */
+  coupon?: AppliedCoupon;
 
   start_time: number;
   end_time: number | null;
@@ -1071,6 +1267,10 @@ export interface CartItemSnapshot {
   category_name?: string | null;
   /** Whether this item has been comped (gifted) */
   is_comped?: boolean;
+  /** 道次（未分道次 = 立即送厨） */
+  course?: number | null;
+  /** 暂缓中：所属道次尚未起菜 */
+  is_held?: boolean;
   /** Internal: marks item as removed for soft delete */
   _removed?: boolean;
 }
@@ -1104,6 +1304,26 @@ export interface StampRedemptionState {
   comp_source_instance_id?: string;
 }
 
+/** Coupon applied to an order (discount recalculated with the order totals) */
+export interface AppliedCoupon {
+  coupon_id: number;
+  code: string;
+  name: string;
+  product_scope: ProductScope;
+  target_id: number | null;
+  adjustment_type: AdjustmentType;
+  adjustment_value: number;
+  /** Discount amount on the current order */
+  amount: number;
+}
+
+/** Loyalty points redemption (tracked in snapshot, settled on order completion) */
+export interface PointsRedemption {
+  points: number;
+  /** Discount value of the redeemed points */
+  amount: number;
+}
+
 /**
  * Cart item input (for AddItems command - no instance_id, generated by backend)
  */
@@ -1120,6 +1340,8 @@ export interface CartItemInput {
   note?: string | null;
   authorizer_id?: number | null;
   authorizer_name?: string | null;
+  /** 道次：指定后暂缓，直到该道次起菜 */
+  course?: number | null;
 }
 
 export interface ItemOption {
@@ -1166,11 +1388,26 @@ export interface SplitItem {
   unit_price: number;
 }
 
+/** Per-rate tax breakdown line */
+export interface TaxBreakdownLine {
+  tax_rate: number;
+  base_amount: number;
+  tax_amount: number;
+}
+
 export interface PaymentSummaryItem {
   method: string;
   amount: number;
 }
 
+/** Payment-method surcharge line (added with the payment, cancelled with it) */
+export interface PaymentSurchargeLine {
+  payment_id: number;
+  method: string;
+  amount: number;
+  cancelled?: boolean;
+}
+
 /** Split type for categorizing split payments */
 export type SplitType = 'ITEM_SPLIT' | 'AMOUNT_SPLIT' | 'AA_SPLIT';
 
@@ -1181,6 +1418,8 @@ export interface PaymentRecord {
   tendered?: number | null;
   change?: number | null;
   note?: string | null;
+  /** Card last-4 or terminal transaction reference */
+  reference?: string | null;
   timestamp: number;
   cancelled?: boolean;
   cancel_reason?: string | null;
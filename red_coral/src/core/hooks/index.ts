@@ -7,6 +7,7 @@ export * from './usePreloadCoreData';
 export * from './useOrderEventListener';
 export * from './useCommandLock';
 export * from './useSyncConnection';
+export * from './useTerminalProfileSync';
 export * from './useImageUrl';
 export * from './useShiftCloseGuard';
 export * from './useSystemIssueGuard';
@@ -5,9 +5,11 @@
  * This hook should be called once at the app level (App.tsx) after server mode starts.
  *
  * Server Authority Model:
- * - Client NEVER computes snapshots locally
  * - Backend sends 'order-sync' events containing BOTH event AND snapshot
- * - Store uses server-provided snapshots directly
+ * - Store uses server-provided snapshots directly as the authoritative state
+ * - Client mode: 'order-optimistic' shows bridge-predicted snapshots (crab-order-core)
+ *   until the authoritative 'order-sync' arrives; 'order-optimistic-rollback' undoes
+ *   only the failed command's prediction
  *
  * Event Flow:
  * 1. Listen for 'order-sync' events from Tauri backend
@@ -30,6 +32,25 @@ interface OrderSyncPayload {
   snapshot: OrderSnapshot;
 }
 
+/** Payload structure for order-sync-batch Tauri events (matches Rust OrderSyncBatchPayload) */
+interface OrderSyncBatchPayload {
+  events: OrderEvent[];
+  snapshots: OrderSnapshot[];
+}
+
+/** Payload structure for order-optimistic Tauri events (matches Rust OrderOptimisticPayload) */
+interface OrderOptimisticPayload {
+  command_id: number;
+  events: OrderEvent[];
+  snapshots: OrderSnapshot[];
+}
+
+/** Payload structure for order-optimistic-rollback Tauri events (matches Rust OrderOptimisticRollbackPayload) */
+interface OrderOptimisticRollbackPayload {
+  command_id: number;
+  snapshots: OrderSnapshot[];
+}
+
 /**
  * Hook to set up order event listeners and initialize order state
  *
@@ -99,7 +120,33 @@ export function useOrderEventListener() {
       useActiveOrdersStore.getState()._applyOrderSync(orderEvent, snapshot);
     });
 
-    unlistenRef.current = unlisten;
+    // Coalesced events from one command (e.g. large AddItems) - one store update
+    const unlistenBatch = await listen<OrderSyncBatchPayload>('order-sync-batch', (event) => {
+      const { events, snapshots } = event.payload;
+      logger.debug(`Received sync batch: ${events.length} events for ${snapshots.length} orders`, { component: 'OrderEventListener' });
+      useActiveOrdersStore.getState()._applyOrderSyncBatch(events, snapshots);
+    });
+
+    // Client 模式乐观预测：先显示，权威 order-sync 到达后覆盖
+    const unlistenOptimistic = await listen<OrderOptimisticPayload>('order-optimistic', (event) => {
+      const { command_id, snapshots } = event.payload;
+      logger.debug(`Optimistic update for command ${command_id}`, { component: 'OrderEventListener' });
+      useActiveOrdersStore.getState()._applyOptimistic(command_id, snapshots);
+    });
+
+    // 命令失败：撤销该命令的预测
+    const unlistenRollback = await listen<OrderOptimisticRollbackPayload>('order-optimistic-rollback', (event) => {
+      const { command_id, snapshots } = event.payload;
+      logger.debug(`Optimistic rollback for command ${command_id}`, { component: 'OrderEventListener' });
+      useActiveOrdersStore.getState()._rollbackOptimistic(command_id, snapshots);
+    });
+
+    unlistenRef.current = () => {
+      unlisten();
+      unlistenBatch();
+      unlistenOptimistic();
+      unlistenRollback();
+    };
     logger.debug('Event listener set up (Server Authority Mode)', { component: 'OrderEventListener' });
   }, []);
 
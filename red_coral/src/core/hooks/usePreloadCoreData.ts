@@ -6,6 +6,7 @@
  * - table: 桌台数据
  * - category: 分类数据
  * - product: 产品数据
+ * - eighty_six: 临时沽清
  *
  * 其他资源按需加载。
  */
@@ -14,7 +15,7 @@ import { useState, useEffect } from 'react';
 import { useZoneStore } from '@/features/zone';
 import { useTableStore } from '@/features/table';
 import { useCategoryStore } from '@/features/category';
-import { useProductStore } from '@/features/product';
+import { useProductStore, useEightySixStore } from '@/features/product';
 import { useTagStore } from '@/features/tag';
 import { toast } from '@/presentation/components/Toast';
 import { t } from '@/infrastructure/i18n';
@@ -26,6 +27,7 @@ const CORE_STORES: { key: string; fetch: () => Promise<unknown> }[] = [
   { key: 'common.resource.category', fetch: () => useCategoryStore.getState().fetchAll() },
   { key: 'common.resource.product', fetch: () => useProductStore.getState().fetchAll() },
   { key: 'common.resource.tag', fetch: () => useTagStore.getState().fetchAll() },
+  { key: 'common.resource.eighty_six', fetch: () => useEightySixStore.getState().fetchAll() },
 ];
 
 /**
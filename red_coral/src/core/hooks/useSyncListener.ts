@@ -17,12 +17,14 @@ import { useActiveOrdersStore } from '@/core/stores/order/useActiveOrdersStore';
 import { useShiftStore } from '@/core/stores/shift';
 import type { SyncResponse } from '@/core/domain/types/orderEvent';
 import type { Shift } from '@/core/domain/types/api';
+import { toast } from '@/presentation/components/Toast';
+import { t } from '@/infrastructure/i18n';
 
 const PRODUCT_REFRESH_DEBOUNCE_MS = 500;
 
 interface SyncPayload {
   resource: string;
-  action: 'created' | 'updated' | 'deleted' | 'settlement_required';
+  action: 'created' | 'updated' | 'deleted' | 'settlement_required' | 'break_required';
   id: number;
   version: number;
   data: unknown | null;
@@ -173,6 +175,9 @@ export function useSyncListener() {
           if (shiftData) {
             useShiftStore.getState().setStaleShift(shiftData);
           }
+        } else if (action === 'break_required') {
+          // 连续工作超过 6 小时未休息（劳动合规提醒）
+          toast.warning(t('settings.shift.break.required'));
         } else if (action === 'updated' && data) {
           const shiftData = data as Shift;
           const { currentShift } = useShiftStore.getState();
@@ -0,0 +1,148 @@
+/**
+ * Terminal Profile Sync Hook - 终端漫游配置
+ *
+ * Client 模式下把本机偏好 (打印机映射、界面缩放、性能模式等) 保存到 edge-server，
+ * 键为证书身份；连接时 edge 随握手下发已保存的配置，替换设备后自动恢复。
+ *
+ * - 连接/登录后：服务端版本与本地已应用版本不同 → 应用到本地 Store；服务端无配置 → 上传本地配置
+ * - 偏好变化后防抖上传
+ */
+
+import { useEffect } from 'react';
+import { listen } from '@tauri-apps/api/event';
+import { createTauriClient } from '@/infrastructure/api';
+import { logger } from '@/utils/logger';
+import { useBridgeStore } from '@/core/stores/bridge';
+import { usePrinterStore } from '@/core/stores/printer/usePrinterStore';
+import { useUIScaleStore } from '@/core/stores/ui/useUIScaleStore';
+import { useSettingsStore } from '@/core/stores/settings/useSettingsStore';
+
+const PROFILE_VERSION_KEY = 'terminal_profile_version';
+const SAVE_DEBOUNCE_MS = 1500;
+
+interface TerminalSettings {
+  receipt_printer: string | null;
+  label_printer: string | null;
+  cash_drawer_printer: string | null;
+  active_label_template_id: number | null;
+  auto_open_cash_drawer_after_receipt: boolean;
+  ui_scale: number;
+  performance_mode: boolean;
+}
+
+const snapshot = (): TerminalSettings => {
+  const printer = usePrinterStore.getState();
+  return {
+    receipt_printer: printer.receiptPrinter,
+    label_printer: printer.labelPrinter,
+    cash_drawer_printer: printer.cashDrawerPrinter,
+    active_label_template_id: printer.activeLabelTemplateId,
+    auto_open_cash_drawer_after_receipt: printer.autoOpenCashDrawerAfterReceipt,
+    ui_scale: useUIScaleStore.getState().scale,
+    performance_mode: useSettingsStore.getState().performanceMode,
+  };
+};
+
+const apply = (settings: Partial<TerminalSettings>) => {
+  const printer = usePrinterStore.getState();
+  if (settings.receipt_printer !== undefined) printer.setReceiptPrinter(settings.receipt_printer);
+  if (settings.label_printer !== undefined) printer.setLabelPrinter(settings.label_printer);
+  if (settings.cash_drawer_printer !== undefined) printer.setCashDrawerPrinter(settings.cash_drawer_printer);
+  if (settings.active_label_template_id !== undefined) {
+    printer.setActiveLabelTemplateId(settings.active_label_template_id);
+  }
+  if (settings.auto_open_cash_drawer_after_receipt !== undefined) {
+    printer.setAutoOpenCashDrawerAfterReceipt(settings.auto_open_cash_drawer_after_receipt);
+  }
+  if (typeof settings.ui_scale === 'number') useUIScaleStore.getState().setScale(settings.ui_scale);
+  if (typeof settings.performance_mode === 'boolean') {
+    useSettingsStore.getState().setPerformanceMode(settings.performance_mode);
+  }
+};
+
+const getAppliedVersion = (): number | null => {
+  const v = localStorage.getItem(PROFILE_VERSION_KEY);
+  return v ? Number(v) : null;
+};
+
+const setAppliedVersion = (version: number) => {
+  localStorage.setItem(PROFILE_VERSION_KEY, String(version));
+};
+
+export function useTerminalProfileSync() {
+  const isClientAuthenticated = useBridgeStore((state) => state.appState?.type === 'ClientAuthenticated');
+
+  useEffect(() => {
+    if (!isClientAuthenticated) return;
+
+    const api = createTauriClient();
+    let identity: string | null = null;
+    let lastSaved: string | null = null;
+    let applying = false;
+    let saveTimer: ReturnType<typeof setTimeout> | null = null;
+
+    const save = async () => {
+      if (!identity) return;
+      const current = snapshot();
+      const serialized = JSON.stringify(current);
+      if (serialized === lastSaved) return;
+      try {
+        const profile = await api.saveTerminalProfile(identity, { ...current });
+        lastSaved = serialized;
+        setAppliedVersion(profile.version);
+      } catch (err) {
+        logger.warn('Failed to save terminal profile', { component: 'TerminalProfileSync', error: err });
+      }
+    };
+
+    const scheduleSave = () => {
+      if (applying || !identity) return;
+      if (saveTimer) clearTimeout(saveTimer);
+      saveTimer = setTimeout(save, SAVE_DEBOUNCE_MS);
+    };
+
+    const restore = async () => {
+      try {
+        const info = await api.getTerminalProfile();
+        identity = info.identity;
+        if (!identity) return;
+
+        if (!info.profile) {
+          // 该身份首次连接：以本机配置为准
+          await save();
+          return;
+        }
+        if (info.profile.version !== getAppliedVersion()) {
+          logger.info(`Restoring terminal profile v${info.profile.version}`, { component: 'TerminalProfileSync' });
+          applying = true;
+          try {
+            apply(info.profile.settings as Partial<TerminalSettings>);
+          } finally {
+            applying = false;
+          }
+          setAppliedVersion(info.profile.version);
+        }
+        lastSaved = JSON.stringify(snapshot());
+      } catch (err) {
+        logger.warn('Failed to load terminal profile', { component: 'TerminalProfileSync', error: err });
+      }
+    };
+
+    restore();
+
+    const unsubscribers = [
+      usePrinterStore.subscribe(scheduleSave),
+      useUIScaleStore.subscribe(scheduleSave),
+      useSettingsStore.subscribe(scheduleSave),
+    ];
+    const unlistenConnection = listen<boolean>('connection-state-changed', (event) => {
+      if (event.payload) restore();
+    });
+
+    return () => {
+      if (saveTimer) clearTimeout(saveTimer);
+      unsubscribers.forEach((unsubscribe) => unsubscribe());
+      unlistenConnection.then((fn) => fn());
+    };
+  }, [isClientAuthenticated]);
+}
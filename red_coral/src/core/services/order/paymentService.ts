@@ -4,12 +4,30 @@
  * 职责：打开钱箱、打印收据
  */
 
-import type { HeldOrder } from '@/core/domain/types';
+import type { HeldOrder, PaymentRecord } from '@/core/domain/types';
 import type { ArchivedOrderDetail } from '@/core/domain/types/archivedOrder';
+import type { CashDrawerEventKind, ResolvedReceiptFooter } from '@/core/domain/types/api';
 import { logger } from '@/utils/logger';
 
 /**
- * 打开钱箱
+ * 上报钱箱开启到 edge-server（钱箱记录 / 收班对账）
+ *
+ * 上报失败不影响开箱，仅记录日志。
+ */
+export const recordCashDrawerOpen = async (
+  kind: Extract<CashDrawerEventKind, 'SALE' | 'NO_SALE'>,
+  reason?: string,
+): Promise<void> => {
+  try {
+    const { createTauriClient } = await import('@/infrastructure/api');
+    await createTauriClient().recordCashDrawerEvent({ kind, reason: reason ?? null });
+  } catch (error) {
+    logger.warn('Failed to record cash drawer open', { component: 'paymentService', action: 'recordCashDrawerOpen', error });
+  }
+};
+
+/**
+ * 打开钱箱（现金收款）
  *
  * 失败不阻止支付流程，但会 toast 提示用户。
  */
@@ -20,6 +38,7 @@ export const openCashDrawer = async (): Promise<void> => {
     const state = usePrinterStore.getState();
     const printer = state.cashDrawerPrinter || state.receiptPrinter || undefined;
     await open(printer);
+    await recordCashDrawerOpen('SALE');
   } catch (error) {
     logger.warn('Cash drawer failed to open', { component: 'paymentService', action: 'openCashDrawer', error });
     // 钱箱打开失败不阻止支付流程，但提示用户
@@ -30,6 +49,41 @@ export const openCashDrawer = async (): Promise<void> => {
   }
 };
 
+/**
+ * 解析当天的收据页脚促销
+ *
+ * 页脚是可选内容，获取失败时照常打印（不带促销）。
+ */
+const resolveFooterPromotion = async (
+  receiptNumber?: string,
+): Promise<ResolvedReceiptFooter | null> => {
+  try {
+    const { createTauriClient } = await import('@/infrastructure/api');
+    return await createTauriClient().resolveReceiptFooter(receiptNumber);
+  } catch (error) {
+    logger.warn('Receipt footer resolve failed', { component: 'paymentService', action: 'resolveFooterPromotion', error });
+    return null;
+  }
+};
+
+/**
+ * 解析收据语言：打印时选择 > 会员偏好 > 门店 receipt_locale (由渲染端回退)
+ */
+const resolveReceiptLocale = async (
+  memberId: number | null | undefined,
+  locale?: string | null,
+): Promise<string | null> => {
+  if (locale) return locale;
+  if (!memberId) return null;
+  try {
+    const { getMemberDetail } = await import('@/features/member/mutations');
+    return (await getMemberDetail(memberId)).preferred_locale ?? null;
+  } catch (error) {
+    logger.warn('Member receipt locale lookup failed', { component: 'paymentService', action: 'resolveReceiptLocale', error });
+    return null;
+  }
+};
+
 /**
  * 打印订单收据
  *
@@ -39,14 +93,41 @@ export const printOrderReceipt = async (
   order: HeldOrder,
   printerName: string | null,
   reprint = false,
+  locale?: string | null,
 ): Promise<void> => {
   const { printReceipt } = await import('@/infrastructure/print');
   const { buildReceiptData } = await import('./receiptBuilder');
   const { useStoreInfoStore } = await import('@/core/stores/settings/useStoreInfoStore');
 
   const storeInfo = useStoreInfoStore.getState().info;
-  const receipt = buildReceiptData(order, storeInfo, { reprint });
-  await printReceipt(printerName, receipt);
+  const footerPromotion = await resolveFooterPromotion(order.receipt_number);
+  const receiptLocale = await resolveReceiptLocale(order.member_id, locale);
+  const receipt = buildReceiptData(order, storeInfo, { reprint, footerPromotion, locale: receiptLocale });
+  await printReceipt(printerName, receipt, storeInfo.receipt_archive_enabled);
+};
+
+/**
+ * 按用餐者分开打印收据（分单结账后）
+ *
+ * 所有分单收据合并为一个打印任务提交，避免与其他打印交错或只打出一部分。
+ * finalPayment: 结单的最后一笔付款（订单快照中尚未包含）
+ */
+export const printDinerReceipts = async (
+  order: HeldOrder,
+  printerName: string | null,
+  finalPayment?: PaymentRecord,
+): Promise<void> => {
+  const { printReceipts } = await import('@/infrastructure/print');
+  const { buildDinerReceipts } = await import('./receiptBuilder');
+  const { useStoreInfoStore } = await import('@/core/stores/settings/useStoreInfoStore');
+
+  const storeInfo = useStoreInfoStore.getState().info;
+  const footerPromotion = await resolveFooterPromotion(order.receipt_number);
+  const settled = finalPayment ? { ...order, payments: [...order.payments, finalPayment] } : order;
+  const locale = await resolveReceiptLocale(order.member_id);
+  const receipts = buildDinerReceipts(settled, storeInfo, { footerPromotion, locale });
+  if (receipts.length === 0) return;
+  await printReceipts(printerName, receipts, storeInfo.receipt_archive_enabled);
 };
 
 /**
@@ -61,22 +142,29 @@ export const printPrePaymentReceipt = async (
   const { useStoreInfoStore } = await import('@/core/stores/settings/useStoreInfoStore');
 
   const storeInfo = useStoreInfoStore.getState().info;
-  const receipt = buildReceiptData(order, storeInfo, { prePayment: true });
+  const footerPromotion = await resolveFooterPromotion();
+  const locale = await resolveReceiptLocale(order.member_id);
+  const receipt = buildReceiptData(order, storeInfo, { prePayment: true, footerPromotion, locale });
   await printReceipt(printerName, receipt);
 };
 
 /**
  * 重打归档订单收据
+ *
+ * locale: 打印时选择的收据语言（未选择时使用会员偏好 / 门店默认）
  */
 export const reprintArchivedReceipt = async (
   order: ArchivedOrderDetail,
   printerName: string | null,
+  locale?: string | null,
 ): Promise<void> => {
   const { printReceipt } = await import('@/infrastructure/print');
   const { buildArchivedReceiptData } = await import('./receiptBuilder');
   const { useStoreInfoStore } = await import('@/core/stores/settings/useStoreInfoStore');
 
   const storeInfo = useStoreInfoStore.getState().info;
-  const receipt = buildArchivedReceiptData(order, storeInfo);
-  await printReceipt(printerName, receipt);
+  const footerPromotion = order.is_voided ? null : await resolveFooterPromotion(order.receipt_number);
+  const receiptLocale = await resolveReceiptLocale(order.member_id, locale);
+  const receipt = buildArchivedReceiptData(order, storeInfo, footerPromotion, receiptLocale);
+  await printReceipt(printerName, receipt, storeInfo.receipt_archive_enabled);
 };
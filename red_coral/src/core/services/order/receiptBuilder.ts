@@ -4,10 +4,10 @@
  * 纯数据转换，无 I/O。将 HeldOrder + StoreInfo 转为 Rust ReceiptData 格式。
  */
 
-import type { HeldOrder, AppliedRule } from '@/core/domain/types';
+import type { HeldOrder, AppliedRule, CartItemSnapshot, PaymentRecord } from '@/core/domain/types';
 import type { ArchivedOrderDetail } from '@/core/domain/types/archivedOrder';
-import type { StoreInfo } from '@/core/domain/types/api';
-import type { ReceiptData, ReceiptItem, ReceiptStoreInfo, ReceiptSurchargeInfo, ReceiptDiscountInfo, ReceiptRuleAdjustment } from '@/infrastructure/print/printService';
+import type { StoreInfo, ResolvedReceiptFooter } from '@/core/domain/types/api';
+import type { ReceiptData, ReceiptItem, ReceiptPayment, ReceiptStoreInfo, ReceiptSurchargeInfo, ReceiptDiscountInfo, ReceiptRuleAdjustment } from '@/infrastructure/print/printService';
 import { Currency } from '@/utils/currency';
 import { getLocale, t } from '@/infrastructure/i18n';
 
@@ -22,6 +22,13 @@ function formatTimestamp(ms: number): string {
   });
 }
 
+/** 可选收据语言（与 shared RECEIPT_LOCALES 对齐，名称以该语言自称显示） */
+export const RECEIPT_LANGUAGES = [
+  { locale: 'es-ES', label: 'Español' },
+  { locale: 'en', label: 'English' },
+  { locale: 'zh-CN', label: '中文' },
+];
+
 function buildStoreInfo(storeInfo: StoreInfo | null): ReceiptStoreInfo | null {
   if (!storeInfo || !storeInfo.name) return null;
   return {
@@ -33,9 +40,11 @@ function buildStoreInfo(storeInfo: StoreInfo | null): ReceiptStoreInfo | null {
     website: storeInfo.website ?? null,
     logo_url: storeInfo.logo_url ?? null,
     currency_symbol: storeInfo.currency_symbol ?? null,
+    currency_decimal_places: storeInfo.currency_decimal_places ?? null,
     receipt_header: storeInfo.receipt_header ?? null,
     receipt_footer: storeInfo.receipt_footer ?? null,
-    receipt_locale: getLocale(),
+    receipt_locale: storeInfo.receipt_locale ?? getLocale(),
+    payment_surcharge_disclaimer: storeInfo.payment_surcharge_disclaimer ?? null,
   };
 }
 
@@ -83,10 +92,83 @@ function aggregateRuleAdjustments(order: HeldOrder): ReceiptRuleAdjustment[] {
     }));
 }
 
+/**
+ * 订单明细 → 收据行
+ *
+ * PVP = unit_price (含手动折扣 + 规则折扣，服务端计算)
+ * IMPORTE = line_total (= PVP × 数量)；指定数量时按 PVP × 数量重算
+ * 赠送菜品保留在列表中，price/total 设为 0，original_price 记录原价
+ */
+function buildItem(item: CartItemSnapshot, quantityOverride?: number): ReceiptItem {
+  const quantity = quantityOverride ?? item.quantity;
+  const basePrice = item.original_price > 0 ? item.original_price : item.price;
+  const optionsTotal = item.selected_options
+    ? item.selected_options.reduce((sum, opt) => Currency.add(sum, opt.price_modifier ?? 0).toNumber(), 0)
+    : 0;
+  const priceBeforeDiscount = Currency.add(basePrice, optionsTotal).toNumber();
+
+  const selectedOptions = item.selected_options
+    ? item.selected_options
+        .filter((opt) => opt.show_on_receipt)
+        .map((opt) => ({
+          attribute_name: opt.attribute_name,
+          option_name: opt.option_name,
+          receipt_name: opt.receipt_name ?? null,
+          price_modifier: opt.price_modifier ?? 0,
+          show_on_receipt: opt.show_on_receipt,
+        }))
+    : null;
+
+  const specName = item.selected_specification?.receipt_name
+    || item.selected_specification?.name
+    || null;
+
+  // 赠送菜品: price=0, total=0, original_price=原价
+  if (item.is_comped) {
+    return {
+      name: item.name,
+      quantity,
+      price: 0,
+      total: 0,
+      tax_rate: item.tax_rate / 100,
+      discount_percent: null,
+      original_price: priceBeforeDiscount,
+      selected_options: selectedOptions,
+      spec_name: specName,
+      is_comped: true,
+    };
+  }
+
+  const pvp = item.unit_price;
+  const importe = quantityOverride === undefined
+    ? item.line_total
+    : Currency.mul(pvp, quantity).toNumber();
+  const hasAnyDiscount = priceBeforeDiscount > pvp + 0.005;
+
+  return {
+    name: item.name,
+    quantity,
+    price: pvp,
+    total: importe,
+    tax_rate: item.tax_rate / 100,
+    discount_percent: item.manual_discount_percent ?? null,
+    original_price: hasAnyDiscount ? priceBeforeDiscount : null,
+    selected_options: selectedOptions,
+    spec_name: specName,
+    is_comped: false,
+  };
+}
+
 export function buildReceiptData(
   order: HeldOrder,
   storeInfo: StoreInfo | null,
-  opts?: { reprint?: boolean; voidReason?: string; prePayment?: boolean },
+  opts?: {
+    reprint?: boolean;
+    voidReason?: string;
+    prePayment?: boolean;
+    footerPromotion?: ResolvedReceiptFooter | null;
+    locale?: string | null;
+  },
 ): ReceiptData {
   const now = Date.now();
 
@@ -135,68 +217,9 @@ export function buildReceiptData(
   // 规则已含在 PVP (unit_price) 中，不再单独显示
   const rule_adjustments: ReceiptRuleAdjustment[] = [];
 
-  // PVP = unit_price (含手动折扣 + 规则折扣，服务端计算)
-  // IMPORTE = line_total (= PVP × 数量)
-  // 赠送菜品保留在列表中，price/total 设为 0，original_price 记录原价
   const items: ReceiptItem[] = order.items
     .filter((item) => !item._removed)
-    .map((item) => {
-      const basePrice = item.original_price > 0 ? item.original_price : item.price;
-      const optionsTotal = item.selected_options
-        ? item.selected_options.reduce((sum, opt) => Currency.add(sum, opt.price_modifier ?? 0).toNumber(), 0)
-        : 0;
-      const priceBeforeDiscount = Currency.add(basePrice, optionsTotal).toNumber();
-
-      const mapOptions = () => item.selected_options
-        ? item.selected_options
-            .filter((opt) => opt.show_on_receipt)
-            .map((opt) => ({
-              attribute_name: opt.attribute_name,
-              option_name: opt.option_name,
-              receipt_name: opt.receipt_name ?? null,
-              price_modifier: opt.price_modifier ?? 0,
-              show_on_receipt: opt.show_on_receipt,
-            }))
-        : null;
-
-      const specName = item.selected_specification?.receipt_name
-        || item.selected_specification?.name
-        || null;
-
-      // 赠送菜品: price=0, total=0, original_price=原价
-      if (item.is_comped) {
-        return {
-          name: item.name,
-          quantity: item.quantity,
-          price: 0,
-          total: 0,
-          tax_rate: item.tax_rate / 100,
-          discount_percent: null,
-          original_price: priceBeforeDiscount,
-          selected_options: mapOptions(),
-          spec_name: specName,
-          is_comped: true,
-        };
-      }
-
-      // PVP = unit_price (after manual + rule adjustments)
-      const pvp = item.unit_price;
-      const importe = item.line_total;
-      const hasAnyDiscount = priceBeforeDiscount > pvp + 0.005;
-
-      return {
-        name: item.name,
-        quantity: item.quantity,
-        price: pvp,
-        total: importe,
-        tax_rate: item.tax_rate / 100,
-        discount_percent: item.manual_discount_percent ?? null,
-        original_price: hasAnyDiscount ? priceBeforeDiscount : null,
-        selected_options: mapOptions(),
-        spec_name: specName,
-        is_comped: false,
-      };
-    });
+    .map((item) => buildItem(item));
 
   return {
     order_id: order.receipt_number,
@@ -209,14 +232,20 @@ export function buildReceiptData(
     void_reason: opts?.voidReason ?? null,
     reprint: opts?.reprint ?? false,
     pre_payment: opts?.prePayment ?? false,
+    locale: opts?.locale ?? null,
     store_info,
     surcharge,
     discount,
     rule_adjustments,
     items,
     total_amount: order.total,
+    payment_surcharge_amount: order.payment_surcharge_amount ?? 0,
     queue_number: order.queue_number ?? null,
     qr_data: null,
+    footer_promotion: opts?.footerPromotion ?? null,
+    payments: [],
+    sub_receipt: null,
+    fiscal_code: order.fiscal_code ?? null,
   };
 }
 
@@ -226,6 +255,8 @@ export function buildReceiptData(
 export function buildArchivedReceiptData(
   order: ArchivedOrderDetail,
   storeInfo: StoreInfo | null,
+  footerPromotion: ResolvedReceiptFooter | null = null,
+  locale: string | null = null,
 ): ReceiptData {
   const store_info = buildStoreInfo(storeInfo);
 
@@ -330,13 +361,125 @@ export function buildArchivedReceiptData(
     void_reason: voidReason,
     reprint: true,
     pre_payment: false,
+    locale,
     store_info,
     surcharge,
     discount,
     rule_adjustments,
     items,
     total_amount: order.total,
+    payment_surcharge_amount: order.payments
+      .filter((p) => !p.cancelled)
+      .reduce((sum, p) => Currency.add(sum, p.surcharge ?? 0).toNumber(), 0),
     queue_number: order.queue_number ?? null,
     qr_data: null,
+    footer_promotion: footerPromotion,
+    payments: [],
+    sub_receipt: null,
+    fiscal_code: order.fiscal_code ?? null,
+  };
+}
+
+function buildPayment(payment: PaymentRecord): ReceiptPayment {
+  return {
+    method: payment.method,
+    amount: payment.amount,
+    tendered: payment.tendered ?? null,
+    change: payment.change ?? null,
   };
 }
+
+/**
+ * 金额/AA 分单的份额行：按税率归集订单金额，再按付款占比缩放
+ * （保证税率分组正确，渲染时按 total_amount 校正尾差）
+ */
+function buildShareItems(order: HeldOrder, amount: number, label: string): ReceiptItem[] {
+  const byRate = new Map<number, number>();
+  for (const item of order.items) {
+    if (item._removed || item.is_comped) continue;
+    byRate.set(item.tax_rate, Currency.add(byRate.get(item.tax_rate) ?? 0, item.line_total).toNumber());
+  }
+  const ratio = order.total > 0 ? Currency.div(amount, order.total).toNumber() : 0;
+  return Array.from(byRate.entries()).map(([rate, subtotal]) => {
+    const share = Currency.mul(subtotal, ratio).toNumber();
+    return {
+      name: byRate.size > 1 ? `${label} (${rate}%)` : label,
+      quantity: 1,
+      price: share,
+      total: share,
+      tax_rate: rate / 100,
+      discount_percent: null,
+      original_price: null,
+      selected_options: null,
+      spec_name: null,
+      is_comped: false,
+    };
+  });
+}
+
+/**
+ * 按用餐者分开打印收据
+ *
+ * 每笔分单付款（菜品/金额/AA）生成一张收据，其余普通付款合并为最后一张。
+ * 分单号 = 主单号-序号，收据上注明主单号；金额为该张收据的实付合计。
+ */
+export function buildDinerReceipts(
+  order: HeldOrder,
+  storeInfo: StoreInfo | null,
+  opts?: { footerPromotion?: ResolvedReceiptFooter | null; locale?: string | null },
+): ReceiptData[] {
+  const payments = order.payments.filter((p) => !p.cancelled);
+  const splits = payments.filter((p) => p.split_type);
+  const rest = payments.filter((p) => !p.split_type);
+
+  const parts: { items: ReceiptItem[]; payments: PaymentRecord[] }[] = splits.map((payment) => {
+    if (payment.split_type === 'ITEM_SPLIT') {
+      return {
+        items: (payment.split_items ?? []).map((item) => buildItem(item, item.quantity)),
+        payments: [payment],
+      };
+    }
+    const label = payment.split_type === 'AA_SPLIT' && order.aa_total_shares
+      ? t('pos.receipt.aa_share', { shares: payment.aa_shares ?? 1, total: order.aa_total_shares })
+      : t('pos.receipt.partial_payment');
+    return { items: buildShareItems(order, payment.amount, label), payments: [payment] };
+  });
+
+  if (rest.length > 0) {
+    const restAmount = rest.reduce((sum, p) => Currency.add(sum, p.amount).toNumber(), 0);
+    const hasShareSplit = splits.some((p) => p.split_type !== 'ITEM_SPLIT');
+    let items: ReceiptItem[];
+    if (hasShareSplit) {
+      items = buildShareItems(order, restAmount, t('pos.receipt.partial_payment'));
+    } else {
+      // 扣除已按菜品分单付清的数量
+      const splitQty = new Map<string, number>();
+      for (const payment of splits) {
+        for (const item of payment.split_items ?? []) {
+          splitQty.set(item.instance_id, (splitQty.get(item.instance_id) ?? 0) + item.quantity);
+        }
+      }
+      items = order.items
+        .filter((item) => !item._removed)
+        .map((item) => ({ item, qty: item.quantity - (splitQty.get(item.instance_id) ?? 0) }))
+        .filter(({ qty }) => qty > 0)
+        .map(({ item, qty }) => buildItem(item, qty));
+    }
+    parts.push({ items, payments: rest });
+  }
+
+  const base = buildReceiptData(order, storeInfo, { footerPromotion: opts?.footerPromotion, locale: opts?.locale });
+  return parts.map((part, i) => ({
+    ...base,
+    order_id: `${order.receipt_number}-${i + 1}`,
+    surcharge: null,
+    discount: null,
+    items: part.items,
+    total_amount: part.payments.reduce((sum, p) => Currency.add(sum, p.amount).toNumber(), 0),
+    payment_surcharge_amount: (order.payment_surcharges ?? [])
+      .filter((s) => !s.cancelled && part.payments.some((p) => p.payment_id === s.payment_id))
+      .reduce((sum, s) => Currency.add(sum, s.amount).toNumber(), 0),
+    payments: part.payments.map(buildPayment),
+    sub_receipt: { master: order.receipt_number, index: i + 1, total: parts.length },
+  }));
+}
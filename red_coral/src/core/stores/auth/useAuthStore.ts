@@ -67,6 +67,7 @@ export const useAuthStore = create<AuthStore>()(
             is_system: userData.is_system,
             is_active: userData.is_active,
             created_at: userData.created_at,
+            photo: userData.photo,
           };
 
           set({
@@ -166,6 +167,7 @@ export const useAuthStore = create<AuthStore>()(
           is_active: e.is_active,
           is_system: e.is_system,
           created_at: 0,
+          has_photo: !!e.photo,
         }));
       },
 
@@ -115,6 +115,8 @@ export interface UserInfo {
   is_system: boolean;
   is_active: boolean;
   created_at: number;
+  /** 员工照片 (data URL)，登录时返回 */
+  photo?: string;
 }
 
 export interface EmployeeSession {
@@ -217,6 +219,10 @@ interface BridgeStore {
   loginEmployee: (username: string, password: string) => Promise<LoginResponse>;
   logoutEmployee: () => Promise<void>;
   fetchCurrentSession: () => Promise<EmployeeSession | null>;
+  /** 锁屏后当前员工重新验证 (失败抛出) */
+  unlockSession: (password: string) => Promise<EmployeeSession | null>;
+  /** 换人收银：保持连接切换登录员工 (失败抛出，终端保持锁定) */
+  switchCashier: (username: string, password: string) => Promise<EmployeeSession | null>;
 
 }
 
@@ -471,6 +477,22 @@ export const useBridgeStore = create<BridgeStore>()(
         }
       },
 
+      unlockSession: async (password: string) => {
+        const data = await invokeApi<AuthData>('unlock_session', { password });
+        if (data.session) {
+          set({ currentSession: data.session });
+        }
+        return data.session;
+      },
+
+      switchCashier: async (username: string, password: string) => {
+        const data = await invokeApi<AuthData>('switch_cashier', { username, password });
+        if (data.session) {
+          set({ currentSession: data.session });
+        }
+        return data.session;
+      },
+
       fetchCurrentSession: async () => {
         try {
           const session = await invokeApi<EmployeeSession | null>('get_current_session');
@@ -14,4 +14,4 @@ export { cancelPayment, splitByItems, splitByAmount, startAaSplit, payAaSplit }
 export { applyOrderDiscount, applyOrderSurcharge, addOrderNote, toggleRuleSkip, moveOrder, mergeOrders, updateOrderInfo } from './adjustments';
 
 // Members
-export { linkMember, unlinkMember, redeemStamp, cancelStampRedemption } from './members';
+export { linkMember, unlinkMember, redeemStamp, cancelStampRedemption, redeemPoints, applyCoupon } from './members';
@@ -1,5 +1,5 @@
 /**
- * Member-related order commands: linkMember, unlinkMember, redeemStamp.
+ * Member-related order commands: linkMember, unlinkMember, redeemStamp, redeemPoints, applyCoupon.
  */
 
 import { createCommand } from '../commandUtils';
@@ -78,3 +78,39 @@ export const cancelStampRedemption = async (
   const response = await sendCommand(command);
   ensureSuccess(response, 'Cancel stamp redemption');
 };
+
+/**
+ * Redeem loyalty points as an order discount (0 clears the redemption).
+ * The backend validates the balance and the marketing group's redeem unit.
+ */
+export const redeemPoints = async (
+  orderId: number,
+  points: number,
+): Promise<void> => {
+  const command = createCommand({
+    type: 'REDEEM_POINTS',
+    order_id: orderId,
+    points,
+  });
+
+  const response = await sendCommand(command);
+  ensureSuccess(response, 'Redeem points');
+};
+
+/**
+ * Apply a coupon code to the order (null removes it).
+ * The backend validates the code, its validity window and remaining uses.
+ */
+export const applyCoupon = async (
+  orderId: number,
+  code: string | null,
+): Promise<void> => {
+  const command = createCommand({
+    type: 'APPLY_COUPON',
+    order_id: orderId,
+    code,
+  });
+
+  const response = await sendCommand(command);
+  ensureSuccess(response, 'Apply coupon');
+};
@@ -0,0 +1,50 @@
+import { describe, it, expect, beforeEach } from 'vitest';
+import { useActiveOrdersStore } from './useActiveOrdersStore';
+import type { OrderSnapshot } from '@/core/domain/types/orderEvent';
+
+const snapshot = (orderId: number, lastSequence: number, total: number) =>
+  ({ order_id: orderId, last_sequence: lastSequence, total, status: 'ACTIVE' }) as unknown as OrderSnapshot;
+
+const store = () => useActiveOrdersStore.getState();
+
+describe('useActiveOrdersStore optimistic rollback', () => {
+  beforeEach(() => {
+    store()._reset();
+    store()._fullSync([snapshot(1, 5, 10)], 5);
+  });
+
+  it('restores the authoritative snapshot when the only prediction fails', () => {
+    store()._applyOptimistic(100, [snapshot(1, 5, 20)]);
+    expect(store().getOrder(1)?.total).toBe(20);
+
+    store()._rollbackOptimistic(100, [snapshot(1, 5, 10)]);
+    expect(store().getOrder(1)?.total).toBe(10);
+    expect(store().optimisticCommands.size).toBe(0);
+  });
+
+  it('keeps other pending predictions when one command rolls back', () => {
+    store()._applyOptimistic(100, [snapshot(1, 5, 20)]);
+    store()._applyOptimistic(101, [snapshot(1, 5, 30)]);
+
+    store()._rollbackOptimistic(100, [snapshot(1, 5, 10)]);
+    expect(store().getOrder(1)?.total).toBe(30);
+    expect([...store().optimisticCommands.keys()]).toEqual([101]);
+  });
+
+  it('does not resurrect orders removed while the command was in flight', () => {
+    store()._applyOptimistic(100, [snapshot(1, 5, 20)]);
+    store()._fullSync([], 7);
+
+    store()._rollbackOptimistic(100, [snapshot(1, 5, 10)]);
+    expect(store().getOrder(1)).toBeUndefined();
+  });
+
+  it('ignores rollback after a newer authoritative snapshot arrived', () => {
+    store()._applyOptimistic(100, [snapshot(1, 5, 20)]);
+    store()._applyOrderSyncBatch([], [snapshot(1, 6, 25)]);
+    expect(store().optimisticCommands.size).toBe(0);
+
+    store()._rollbackOptimistic(100, [snapshot(1, 5, 10)]);
+    expect(store().getOrder(1)?.total).toBe(25);
+  });
+});
@@ -2,11 +2,14 @@
  * Active Orders Store (Server Authority Model)
  *
  * This store maintains a read-only mirror of server-side order state.
- * All state changes come from server - NO local computation allowed.
+ * All authoritative state comes from the server - the store performs no computation.
  *
  * Architecture:
- * - State is updated only through server-computed snapshots (_applyOrderSync, _fullSync)
- * - Client NEVER computes snapshots locally
+ * - Authoritative state comes only from server-computed snapshots (_applyOrderSync, _applyOrderSyncBatch, _fullSync)
+ * - The store never computes snapshots itself
+ * - Client 模式下 bridge 用 crab-order-core 预演命令并下发预测快照 (_applyOptimistic)，
+ *   按 command_id 记录，仅作显示；权威快照到达即覆盖并丢弃该订单的预测，
+ *   命令失败时 _rollbackOptimistic 只撤销该命令的预测
  * - UI components read state through selectors
  * - Commands are sent via commands/ module (fire & forget)
  *
@@ -33,6 +36,20 @@ import { logger } from '@/utils/logger';
 /** Maximum sequence gap before triggering timeline sync for an order */
 const MAX_SEQUENCE_GAP = 5;
 
+/** Drop pending predictions for orders that received an authoritative snapshot */
+function dropPredictions(
+  commands: Map<number, OrderSnapshot[]>,
+  orderIds: Set<number>,
+): Map<number, OrderSnapshot[]> {
+  if (commands.size === 0) return commands;
+  const next = new Map<number, OrderSnapshot[]>();
+  for (const [commandId, snapshots] of commands) {
+    const remaining = snapshots.filter((s) => !orderIds.has(s.order_id));
+    if (remaining.length > 0) next.set(commandId, remaining);
+  }
+  return next;
+}
+
 // ============================================================================
 // Store Interface
 // ============================================================================
@@ -58,6 +75,11 @@ interface ActiveOrdersState {
    * External listeners can watch this and trigger sync
    */
   ordersNeedingTimelineSync: Set<number>;
+  /**
+   * Pending optimistic predictions: command_id -> predicted snapshots (Client mode)
+   * Insertion order = command order; dropped on rollback or authoritative sync
+   */
+  optimisticCommands: Map<number, OrderSnapshot[]>;
 }
 
 interface ActiveOrdersActions {
@@ -91,7 +113,7 @@ interface ActiveOrdersActions {
   // ==================== Internal Methods (Server Authority) ====================
   // These methods are prefixed with _ to indicate they should only be called
   // by event listeners, not by UI components directly.
-  // IMPORTANT: Client NEVER computes snapshots locally - always use server-computed snapshots.
+  // IMPORTANT: The store never computes snapshots - authoritative state is always server-computed.
 
   /**
    * Apply order sync (Server Authority Model)
@@ -101,6 +123,30 @@ interface ActiveOrdersActions {
    */
   _applyOrderSync: (event: OrderEvent, snapshot: OrderSnapshot) => void;
 
+  /**
+   * Apply a coalesced order sync batch in a single store update
+   * Used for commands producing many events (e.g. banquet AddItems)
+   * - events: 按 sequence 排序，追加到各订单时间线
+   * - snapshots: 每个订单一份最新快照
+   */
+  _applyOrderSyncBatch: (events: OrderEvent[], snapshots: OrderSnapshot[]) => void;
+
+  /**
+   * Apply optimistic snapshots predicted by the bridge (Client mode)
+   * - 预测快照保留权威 last_sequence，不推进 lastSequence、不写入时间线
+   * - 只覆盖仍处于同一权威版本的订单 (期间已收到新快照则忽略)
+   * - 按 commandId 记录，供回滚时区分各命令的预测
+   */
+  _applyOptimistic: (commandId: number, snapshots: OrderSnapshot[]) => void;
+
+  /**
+   * Roll back one command's optimistic snapshots after it failed
+   * - snapshots 为 bridge 已知的权威快照
+   * - 订单已不在 store (期间完成/移除) 或已有更新的权威快照时不恢复
+   * - 同一订单仍有其他待确认命令的预测时，显示其中最新的一份
+   */
+  _rollbackOptimistic: (commandId: number, snapshots: OrderSnapshot[]) => void;
+
   /**
    * Full sync: replace all orders with server state
    * Called when gap is too large, on initial load, or when server epoch changes
@@ -154,6 +200,7 @@ const initialState: ActiveOrdersState = {
   connectionState: 'disconnected',
   isInitialized: false,
   serverEpoch: null,
+  optimisticCommands: new Map(),
 };
 
 export const useActiveOrdersStore = create<ActiveOrdersStore>((set, get) => ({
@@ -267,11 +314,112 @@ export const useActiveOrdersStore = create<ActiveOrdersStore>((set, get) => ({
         orders,
         timelines,
         ordersNeedingTimelineSync,
+        optimisticCommands: dropPredictions(prevState.optimisticCommands, new Set([orderId])),
         lastSequence: Math.max(prevState.lastSequence, snapshot.last_sequence),
       };
     });
   },
 
+  _applyOrderSyncBatch: (events: OrderEvent[], snapshots: OrderSnapshot[]) => {
+    set((prevState) => {
+      const orders = new Map(prevState.orders);
+      const timelines = new Map(prevState.timelines);
+      const ordersNeedingTimelineSync = new Set(prevState.ordersNeedingTimelineSync);
+      let lastSequence = prevState.lastSequence;
+
+      for (const snapshot of snapshots) {
+        const orderId = snapshot.order_id;
+        const orderEvents = events.filter((e) => e.order_id === orderId);
+        const firstSequence = orderEvents.length > 0 ? orderEvents[0].sequence : snapshot.last_sequence;
+        const localSnapshot = prevState.orders.get(orderId);
+
+        // 以批次首个事件检测 gap（批内事件本身是连续的）
+        if (localSnapshot) {
+          const gap = firstSequence - localSnapshot.last_sequence;
+          if (gap > MAX_SEQUENCE_GAP) {
+            logger.warn(
+              `Large sequence gap for order ${orderId}: local=${localSnapshot.last_sequence} -> batch start=${firstSequence}, triggering sync`,
+              { component: 'OrderSync' }
+            );
+            ordersNeedingTimelineSync.add(orderId);
+          }
+        } else if (firstSequence > 1 && orderEvents[0]?.event_type !== 'TABLE_OPENED') {
+          ordersNeedingTimelineSync.add(orderId);
+        }
+
+        orders.set(orderId, snapshot);
+
+        const existingTimeline = timelines.get(orderId) || [];
+        const known = new Set(existingTimeline.map((e) => e.event_id));
+        const fresh = orderEvents.filter((e) => !known.has(e.event_id));
+        if (fresh.length > 0) {
+          timelines.set(
+            orderId,
+            [...existingTimeline, ...fresh].sort((a, b) => a.sequence - b.sequence)
+          );
+        }
+
+        lastSequence = Math.max(lastSequence, snapshot.last_sequence);
+      }
+
+      return {
+        ...prevState,
+        orders,
+        timelines,
+        ordersNeedingTimelineSync,
+        optimisticCommands: dropPredictions(
+          prevState.optimisticCommands,
+          new Set(snapshots.map((s) => s.order_id)),
+        ),
+        lastSequence,
+      };
+    });
+  },
+
+  _applyOptimistic: (commandId: number, snapshots: OrderSnapshot[]) => {
+    set((prevState) => {
+      const orders = new Map(prevState.orders);
+      const applied: OrderSnapshot[] = [];
+      for (const snapshot of snapshots) {
+        const current = orders.get(snapshot.order_id);
+        if (current && current.last_sequence === snapshot.last_sequence) {
+          orders.set(snapshot.order_id, snapshot);
+          applied.push(snapshot);
+        }
+      }
+      if (applied.length === 0) return prevState;
+      const optimisticCommands = new Map(prevState.optimisticCommands);
+      optimisticCommands.set(commandId, applied);
+      return { ...prevState, orders, optimisticCommands };
+    });
+  },
+
+  _rollbackOptimistic: (commandId: number, snapshots: OrderSnapshot[]) => {
+    set((prevState) => {
+      if (!prevState.optimisticCommands.has(commandId)) return prevState;
+      const optimisticCommands = new Map(prevState.optimisticCommands);
+      optimisticCommands.delete(commandId);
+
+      const orders = new Map(prevState.orders);
+      for (const snapshot of snapshots) {
+        const current = orders.get(snapshot.order_id);
+        // 期间已完成/移除，或已收到更新的权威快照：保持现状
+        if (!current || current.last_sequence > snapshot.last_sequence) continue;
+
+        // 其他仍待确认命令对同一权威版本的最新预测
+        let pending: OrderSnapshot | undefined;
+        for (const predicted of optimisticCommands.values()) {
+          const match = predicted.find(
+            (s) => s.order_id === snapshot.order_id && s.last_sequence === snapshot.last_sequence,
+          );
+          if (match) pending = match;
+        }
+        orders.set(snapshot.order_id, pending ?? snapshot);
+      }
+      return { ...prevState, orders, optimisticCommands };
+    });
+  },
+
   _fullSync: (orders: OrderSnapshot[], serverSequence: number, serverEpoch?: string, events?: OrderEvent[]) => {
     set((state) => {
       const newOrders = new Map<number, OrderSnapshot>();
@@ -299,6 +447,7 @@ export const useActiveOrdersStore = create<ActiveOrdersStore>((set, get) => ({
         orders: newOrders,
         timelines: newTimelines,
         ordersNeedingTimelineSync: new Set(), // Full sync 清除所有补全请求
+        optimisticCommands: new Map(),
         lastSequence: serverSequence,
         isInitialized: true,
         connectionState: 'connected',
@@ -221,6 +221,12 @@ interface OrderSyncPayload {
   snapshot: OrderSnapshot;
 }
 
+/** Payload structure for order-sync-batch Tauri events (matches Rust OrderSyncBatchPayload) */
+interface OrderSyncBatchPayload {
+  events: OrderEvent[];
+  snapshots: OrderSnapshot[];
+}
+
 // ============================================================================
 // Event Listener Setup (Server Authority Model)
 // ============================================================================
@@ -253,6 +259,14 @@ export async function setupOrderEventListeners(): Promise<() => void> {
   });
   unlistenFns.push(unlistenOrderSync);
 
+  // Coalesced events from one command (e.g. large AddItems) - one store update
+  const unlistenOrderSyncBatch = await listen<OrderSyncBatchPayload>('order-sync-batch', (event) => {
+    const { events, snapshots } = event.payload;
+    logger.debug(`Received sync batch: ${events.length} events for ${snapshots.length} orders`, { component: 'OrderSync' });
+    useActiveOrdersStore.getState()._applyOrderSyncBatch(events, snapshots);
+  });
+  unlistenFns.push(unlistenOrderSyncBatch);
+
   // Listen for connection status changes
   const unlistenConnectionStatus = await listen<'connected' | 'disconnected'>(
     'order-connection',
@@ -5,12 +5,12 @@
  * key 必须与后端 broadcast_sync 的 resource 参数一致。
  */
 import type { SyncPayload } from '../factory/createResourceStore';
-import { useProductStore } from '@/features/product';
+import { useProductStore, useEightySixStore, useStockLevelStore } from '@/features/product';
 import { useCategoryStore } from '@/features/category/store';
 import { useTagStore } from '@/features/tag';
 import { useAttributeStore } from '@/features/attribute';
 import { useZoneStore } from '@/features/zone';
-import { useTableStore } from '@/features/table';
+import { useTableStore, useTableGroupStore } from '@/features/table';
 import { useEmployeeStore } from '@/features/user';
 import { useRoleStore } from '@/features/role';
 import { usePriceRuleStore } from '@/features/price-rule';
@@ -19,6 +19,7 @@ import { useStoreInfoStore } from '../settings/useStoreInfoStore';
 import { useLabelTemplateStore } from '../printer/useLabelTemplateStore';
 import { useMarketingGroupStore } from '@/features/marketing-group/store';
 import { useMemberStore } from '@/features/member/store';
+import { useDisplaySlideStore } from '@/features/display-slide/store';
 
 // Store interface for registry
 interface RegistryStore {
@@ -46,11 +47,14 @@ interface RegistryStore {
  */
 export const storeRegistry: Record<string, RegistryStore> = {
   product: useProductStore,
+  eighty_six: useEightySixStore,        // 临时沽清
+  stock_level: useStockLevelStore,      // 库存
   category: useCategoryStore,
   tag: useTagStore,
   attribute: useAttributeStore,
   zone: useZoneStore,
   dining_table: useTableStore,          // 后端: RESOURCE = "dining_table"
+  table_group: useTableGroupStore,      // 拼桌
   employee: useEmployeeStore,
   role: useRoleStore,                    // 后端无 sync (只读 API)
   price_rule: usePriceRuleStore,
@@ -59,6 +63,7 @@ export const storeRegistry: Record<string, RegistryStore> = {
   label_template: useLabelTemplateStore, // 标签模板
   marketing_group: useMarketingGroupStore, // 营销组
   member: useMemberStore,              // 会员
+  display_slide: useDisplaySlideStore, // 客显屏轮播
 };
 
 /**
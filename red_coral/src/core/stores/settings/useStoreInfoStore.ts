@@ -40,6 +40,9 @@ const defaultStoreInfo: StoreInfo = {
   email: null,
   website: null,
   business_day_cutoff: 120,
+  carry_over_policy: 'TRANSFER',
+  daily_report_print_destination_id: null,
+  max_cash_change: 200,
   currency_code: null,
   currency_symbol: null,
   currency_decimal_places: null,
@@ -47,6 +50,11 @@ const defaultStoreInfo: StoreInfo = {
   receipt_locale: null,
   receipt_header: null,
   receipt_footer: null,
+  tax_mode: 'INCLUSIVE',
+  payment_surcharge_enabled: false,
+  payment_surcharge_disclaimer: null,
+  receipt_archive_enabled: false,
+  receipt_archive_cloud: false,
   created_at: null,
   updated_at: null,
 };
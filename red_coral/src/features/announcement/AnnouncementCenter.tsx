@@ -0,0 +1,89 @@
+/**
+ * Announcement Center (员工公告弹窗)
+ *
+ * 全局监听 `announcement` 推送，按到达顺序逐条弹出。
+ * - URGENT 公告不可点遮罩关闭，必须确认
+ * - 已登录时确认会记录到服务端 (按员工)，未登录只关闭弹窗
+ */
+
+import React, { useCallback, useState } from 'react';
+import { Megaphone, AlertTriangle } from 'lucide-react';
+import { useI18n } from '@/hooks/useI18n';
+import { useServerMessages, ServerMessage } from '@/core/hooks/useServerMessages';
+import { useAuthStore } from '@/core/stores/auth/useAuthStore';
+import { logger } from '@/utils/logger';
+import type { Announcement } from '@/core/domain/types/api';
+import { acknowledgeAnnouncement } from './mutations';
+
+export const AnnouncementCenter: React.FC = () => {
+  const { t } = useI18n();
+  const isAuthenticated = useAuthStore((state) => state.isAuthenticated);
+  const [queue, setQueue] = useState<Announcement[]>([]);
+  const [isSubmitting, setIsSubmitting] = useState(false);
+
+  useServerMessages(useCallback((msg: ServerMessage) => {
+    if (msg.event_type !== 'announcement') return;
+    const announcement = msg.payload as Announcement;
+    setQueue((prev) => (prev.some((a) => a.id === announcement.id) ? prev : [...prev, announcement]));
+  }, []));
+
+  const current = queue[0];
+  if (!current) return null;
+
+  const isUrgent = current.priority === 'URGENT';
+
+  const handleAcknowledge = async () => {
+    setIsSubmitting(true);
+    try {
+      if (isAuthenticated) {
+        await acknowledgeAnnouncement(current.id);
+      }
+    } catch (e) {
+      logger.error('Failed to acknowledge announcement', e);
+    } finally {
+      setIsSubmitting(false);
+      setQueue((prev) => prev.filter((a) => a.id !== current.id));
+    }
+  };
+
+  return (
+    <div className="fixed inset-0 z-[90] flex items-center justify-center bg-black/60 backdrop-blur-sm animate-in fade-in duration-200">
+      {!isUrgent && <div className="absolute inset-0" onClick={handleAcknowledge} />}
+
+      <div className="relative bg-white rounded-2xl shadow-2xl w-full max-w-lg mx-4 overflow-hidden">
+        <div className={`px-6 py-4 flex items-center gap-3 ${isUrgent ? 'bg-red-50 text-red-700' : 'bg-indigo-50 text-indigo-700'}`}>
+          {isUrgent ? <AlertTriangle size={22} /> : <Megaphone size={22} />}
+          <h2 className="text-lg font-bold">
+            {isUrgent ? t('announcement.urgent_title') : t('announcement.title')}
+          </h2>
+          {queue.length > 1 && (
+            <span className="ml-auto text-xs font-medium">
+              {t('announcement.pending', { count: queue.length - 1 })}
+            </span>
+          )}
+        </div>
+
+        <div className="px-6 pt-5">
+          <p className="text-xl text-gray-900 whitespace-pre-wrap leading-relaxed">{current.message}</p>
+          <p className="mt-3 text-sm text-gray-500">
+            {current.sender_name}
+            {' · '}
+            {new Date(current.created_at).toLocaleTimeString([], { hour: '2-digit', minute: '2-digit' })}
+          </p>
+        </div>
+
+        <div className="px-6 py-5">
+          <button
+            onClick={handleAcknowledge}
+            disabled={isSubmitting}
+            className={`w-full py-3 rounded-lg font-medium text-white transition-colors disabled:opacity-50 ${
+              isUrgent ? 'bg-red-600 hover:bg-red-700' : 'bg-indigo-600 hover:bg-indigo-700'
+            }`}
+          >
+            {t('announcement.acknowledge')}
+          </button>
+        </div>
+      </div>
+    </div>
+  );
+};
@@ -0,0 +1,188 @@
+/**
+ * Announcement Management (员工公告)
+ *
+ * 经理向全部或指定终端发送公告，并查看最近公告的确认情况。
+ */
+
+import React, { useCallback, useEffect, useState } from 'react';
+import { Megaphone, Send, CheckCircle, AlertTriangle, RefreshCw } from 'lucide-react';
+import { useI18n } from '@/hooks/useI18n';
+import { toast } from '@/presentation/components/Toast';
+import { logger } from '@/utils/logger';
+import { getLocale } from '@/infrastructure/i18n';
+import { ManagementHeader } from '@/screens/Settings/components';
+import type {
+  Announcement,
+  AnnouncementPriority,
+  ConnectedTerminal,
+} from '@/core/domain/types/api';
+import { listAnnouncements, listAnnouncementTerminals, sendAnnouncement } from './mutations';
+
+const MAX_MESSAGE_LEN = 500;
+
+export const AnnouncementManagement: React.FC = () => {
+  const { t } = useI18n();
+  const [announcements, setAnnouncements] = useState<Announcement[]>([]);
+  const [terminals, setTerminals] = useState<ConnectedTerminal[]>([]);
+  const [message, setMessage] = useState('');
+  const [priority, setPriority] = useState<AnnouncementPriority>('NORMAL');
+  const [targets, setTargets] = useState<string[]>([]);
+  const [sending, setSending] = useState(false);
+
+  const load = useCallback(async () => {
+    try {
+      const [recent, connected] = await Promise.all([
+        listAnnouncements(),
+        listAnnouncementTerminals(),
+      ]);
+      setAnnouncements(recent);
+      setTerminals(connected);
+    } catch (e) {
+      logger.error('Failed to load announcements', e);
+      toast.error(t('settings.announcement.load_failed'));
+    }
+  }, [t]);
+
+  useEffect(() => {
+    load();
+  }, [load]);
+
+  const toggleTarget = (name: string) => {
+    setTargets((prev) => (prev.includes(name) ? prev.filter((n) => n !== name) : [...prev, name]));
+  };
+
+  const handleSend = async () => {
+    if (!message.trim()) return;
+    setSending(true);
+    try {
+      const sent = await sendAnnouncement({ message: message.trim(), priority, targets });
+      setAnnouncements((prev) => [sent, ...prev]);
+      setMessage('');
+      setPriority('NORMAL');
+      setTargets([]);
+      toast.success(t('settings.announcement.sent'));
+    } catch (e) {
+      logger.error('Failed to send announcement', e);
+      toast.error(t('settings.announcement.send_failed'));
+    } finally {
+      setSending(false);
+    }
+  };
+
+  const formatTime = (millis: number) =>
+    new Date(millis).toLocaleString(getLocale(), {
+      month: '2-digit',
+      day: '2-digit',
+      hour: '2-digit',
+      minute: '2-digit',
+    });
+
+  return (
+    <div className="space-y-5">
+      <ManagementHeader
+        icon={Megaphone}
+        title={t('settings.announcement.title')}
+        description={t('settings.announcement.description')}
+        themeColor="indigo"
+      />
+
+      {/* Compose */}
+      <div className="bg-white rounded-xl border border-gray-200 p-5 space-y-4">
+        <textarea
+          value={message}
+          onChange={(e) => setMessage(e.target.value.slice(0, MAX_MESSAGE_LEN))}
+          placeholder={t('settings.announcement.placeholder')}
+          rows={3}
+          className="w-full px-3 py-2 border border-gray-200 rounded-lg focus:outline-none focus:ring-2 focus:ring-indigo-500/30 resize-none"
+        />
+
+        <div>
+          <p className="text-sm font-medium text-gray-700 mb-2">{t('settings.announcement.targets')}</p>
+          <div className="flex flex-wrap gap-2">
+            <button
+              onClick={() => setTargets([])}
+              className={`px-3 py-1.5 rounded-lg text-sm border transition-colors ${
+                targets.length === 0
+                  ? 'bg-indigo-50 border-indigo-300 text-indigo-700'
+                  : 'border-gray-200 text-gray-600 hover:bg-gray-50'
+              }`}
+            >
+              {t('settings.announcement.all_terminals')}
+            </button>
+            {terminals.map((terminal) => (
+              <button
+                key={terminal.client_id}
+                onClick={() => toggleTarget(terminal.name)}
+                className={`px-3 py-1.5 rounded-lg text-sm border transition-colors ${
+                  targets.includes(terminal.name)
+                    ? 'bg-indigo-50 border-indigo-300 text-indigo-700'
+                    : 'border-gray-200 text-gray-600 hover:bg-gray-50'
+                }`}
+              >
+                {terminal.name}
+              </button>
+            ))}
+            <button
+              onClick={load}
+              className="p-1.5 rounded-lg text-gray-400 hover:text-gray-600 hover:bg-gray-100"
+              title={t('common.action.refresh')}
+            >
+              <RefreshCw size={16} />
+            </button>
+          </div>
+        </div>
+
+        <div className="flex items-center justify-between">
+          <label className="flex items-center gap-2 text-sm text-gray-700">
+            <input
+              type="checkbox"
+              checked={priority === 'URGENT'}
+              onChange={(e) => setPriority(e.target.checked ? 'URGENT' : 'NORMAL')}
+            />
+            {t('settings.announcement.urgent')}
+          </label>
+          <button
+            onClick={handleSend}
+            disabled={sending || !message.trim()}
+            className="px-4 py-2 bg-indigo-600 text-white rounded-lg hover:bg-indigo-700 disabled:opacity-50 transition-colors flex items-center gap-2"
+          >
+            <Send size={16} />
+            {t('settings.announcement.send')}
+          </button>
+        </div>
+      </div>
+
+      {/* Recent */}
+      <div className="bg-white rounded-xl border border-gray-200 divide-y divide-gray-100">
+        {announcements.length === 0 && (
+          <p className="p-5 text-sm text-gray-400">{t('settings.announcement.empty')}</p>
+        )}
+        {announcements.map((a) => (
+          <div key={a.id} className="p-4">
+            <div className="flex items-start justify-between gap-4">
+              <div className="min-w-0">
+                <p className="text-gray-900 whitespace-pre-wrap flex items-start gap-2">
+                  {a.priority === 'URGENT' && <AlertTriangle size={16} className="text-red-500 mt-1 shrink-0" />}
+                  {a.message}
+                </p>
+                <p className="text-xs text-gray-500 mt-1">
+                  {a.sender_name} · {formatTime(a.created_at)} ·{' '}
+                  {a.targets.length === 0 ? t('settings.announcement.all_terminals') : a.targets.join(', ')}
+                </p>
+              </div>
+              <span className="inline-flex items-center gap-1 text-xs text-gray-600 whitespace-nowrap">
+                <CheckCircle size={14} className={a.acks.length > 0 ? 'text-green-600' : 'text-gray-300'} />
+                {t('settings.announcement.ack_count', { count: a.acks.length })}
+              </span>
+            </div>
+            {a.acks.length > 0 && (
+              <p className="text-xs text-gray-500 mt-2">
+                {a.acks.map((ack) => `${ack.employee_name} (${formatTime(ack.acked_at)})`).join(', ')}
+              </p>
+            )}
+          </div>
+        ))}
+      </div>
+    </div>
+  );
+};
@@ -0,0 +1,12 @@
+/**
+ * Announcement Feature Module (员工公告)
+ */
+
+export { AnnouncementManagement } from './AnnouncementManagement';
+export { AnnouncementCenter } from './AnnouncementCenter';
+export {
+  listAnnouncements,
+  listAnnouncementTerminals,
+  sendAnnouncement,
+  acknowledgeAnnouncement,
+} from './mutations';
@@ -0,0 +1,26 @@
+import { invokeApi } from '@/infrastructure/api/tauri-client';
+import type {
+  Announcement,
+  AnnouncementCreate,
+  ConnectedTerminal,
+} from '@/core/domain/types/api';
+
+export async function listAnnouncements(limit?: number): Promise<Announcement[]> {
+  const qs = limit !== undefined ? `?limit=${limit}` : '';
+  return invokeApi<Announcement[]>('api_get', { path: `/api/announcements${qs}` });
+}
+
+export async function listAnnouncementTerminals(): Promise<ConnectedTerminal[]> {
+  return invokeApi<ConnectedTerminal[]>('api_get', { path: '/api/announcements/terminals' });
+}
+
+export async function sendAnnouncement(data: AnnouncementCreate): Promise<Announcement> {
+  return invokeApi<Announcement>('api_post', { path: '/api/announcements', body: data });
+}
+
+export async function acknowledgeAnnouncement(id: number): Promise<Announcement> {
+  return invokeApi<Announcement>('api_post', {
+    path: `/api/announcements/${id}/ack`,
+    body: { terminal: null },
+  });
+}
@@ -1,14 +1,15 @@
 import React, { useMemo } from 'react';
-import { X, Type, Printer, Settings2 } from 'lucide-react';
+import { X, Type, Printer, Settings2, Package } from 'lucide-react';
 import { useI18n } from '@/hooks/useI18n';
 import { useOptionActions } from './store';
 import type { AttributeOption } from '@/core/domain/types/api';
-import { FormField, FormSection, CheckboxField, inputClass } from '@/shared/components/FormField';
+import { FormField, FormSection, CheckboxField, inputClass, selectClass } from '@/shared/components/FormField';
 import { useFormInitialization } from '@/hooks/useFormInitialization';
 import { usePriceInput } from '@/hooks/usePriceInput';
 import { useFormSubmit } from '@/shared/hooks/useFormSubmit';
 import { MAX_NAME_LEN, MAX_RECEIPT_NAME_LEN } from '@/shared/constants/validation';
 import { useCurrencySymbol } from '@/core/stores/settings/useStoreInfoStore';
+import { useStockLevels } from '@/features/product';
 
 // Extended option type with index for UI (matches store type)
 interface AttributeOptionWithIndex extends AttributeOption {
@@ -25,6 +26,7 @@ interface OptionFormData {
   displayOrder: number;
   enableQuantity: boolean;
   maxQuantity: number | null;
+  stockLevelId: number | null;
 }
 
 // Map AttributeOption (snake_case) to form data (camelCase)
@@ -38,6 +40,7 @@ const mapToFormData = (opt: AttributeOptionWithIndex | null): OptionFormData =>
       displayOrder: 0,
       enableQuantity: false,
       maxQuantity: null,
+      stockLevelId: null,
     };
   }
   return {
@@ -48,6 +51,7 @@ const mapToFormData = (opt: AttributeOptionWithIndex | null): OptionFormData =>
     displayOrder: opt.display_order,
     enableQuantity: opt.enable_quantity ?? false,
     maxQuantity: opt.max_quantity ?? null,
+    stockLevelId: opt.stock_level_id ?? null,
   };
 };
 
@@ -67,6 +71,7 @@ export const OptionForm: React.FC<OptionFormProps> = React.memo(({
   const { t } = useI18n();
   const currencySymbol = useCurrencySymbol();
   const { createOption, updateOption } = useOptionActions();
+  const stockLevels = useStockLevels();
 
   // Memoize the initial form data to prevent useEffect from re-running on every render
   // Include all editable fields in deps to ensure form updates when option data changes
@@ -80,6 +85,7 @@ export const OptionForm: React.FC<OptionFormProps> = React.memo(({
       editingOption?.price_modifier,
       editingOption?.enable_quantity,
       editingOption?.max_quantity,
+      editingOption?.stock_level_id,
     ]
   );
 
@@ -129,6 +135,7 @@ export const OptionForm: React.FC<OptionFormProps> = React.memo(({
           display_order: data.displayOrder,
           enable_quantity: data.enableQuantity,
           max_quantity: data.enableQuantity ? data.maxQuantity : null,
+          stock_level_id: data.stockLevelId,
         });
       },
       onUpdate: async (data) => {
@@ -145,6 +152,7 @@ export const OptionForm: React.FC<OptionFormProps> = React.memo(({
           display_order: data.displayOrder,
           enable_quantity: data.enableQuantity,
           max_quantity: data.enableQuantity ? data.maxQuantity : null,
+          stock_level_id: data.stockLevelId,
         });
       },
       onSuccess: onClose,
@@ -243,6 +251,27 @@ export const OptionForm: React.FC<OptionFormProps> = React.memo(({
             </FormField>
           </FormSection>
 
+          {/* 库存 */}
+          <FormSection title={t('settings.attribute.section.inventory')} icon={Package} defaultCollapsed>
+            <FormField label={t('settings.attribute.option.form.stock_level')}>
+              <select
+                value={formData.stockLevelId ?? ''}
+                onChange={(e) => handleFieldChange('stockLevelId', e.target.value ? Number(e.target.value) : null)}
+                className={selectClass}
+              >
+                <option value="">{t('settings.attribute.option.form.stock_level_none')}</option>
+                {stockLevels.map((level) => (
+                  <option key={level.id} value={level.id}>
+                    {level.spec_name ? `${level.product_name} (${level.spec_name})` : level.product_name}
+                  </option>
+                ))}
+              </select>
+              <p className="mt-1 text-xs text-gray-500">
+                {t('settings.attribute.option.form.stock_level_hint')}
+              </p>
+            </FormField>
+          </FormSection>
+
           {/* 高级设置 */}
           <FormSection title={t('settings.attribute.section.advanced')} icon={Settings2} defaultCollapsed>
             <FormField label={t('settings.attribute.option.form.sort')}>
@@ -84,6 +84,7 @@ interface AttributeStore {
     kitchen_print_name?: string;
     enable_quantity?: boolean;
     max_quantity?: number | null;
+    stock_level_id?: number | null;
   }) => Promise<void>;
   updateOption: (params: {
     attributeId: number;
@@ -97,6 +98,7 @@ interface AttributeStore {
     kitchen_print_name?: string;
     enable_quantity?: boolean;
     max_quantity?: number | null;
+    stock_level_id?: number | null;
   }) => Promise<void>;
   deleteOption: (attributeId: number, index: number) => Promise<void>;
   reorderOptions: (attributeId: number, ids: number[]) => Promise<void>;
@@ -343,8 +345,8 @@ export const useAttributeStore = create<AttributeStore>((set, get) => ({
       });
 
       // Single API call to update all options (strip entity-only fields for input)
-      const optionInputs: AttributeOptionInput[] = reorderedOptions.map(({ name, price_modifier, display_order, receipt_name, kitchen_print_name, enable_quantity, max_quantity }) => ({
-        name, price_modifier, display_order, receipt_name, kitchen_print_name, enable_quantity, max_quantity,
+      const optionInputs: AttributeOptionInput[] = reorderedOptions.map(({ id, name, price_modifier, display_order, receipt_name, kitchen_print_name, enable_quantity, max_quantity, stock_level_id }) => ({
+        id, name, price_modifier, display_order, receipt_name, kitchen_print_name, enable_quantity, max_quantity, stock_level_id,
       }));
       await getApi().updateAttribute(attributeId, { options: optionInputs });
 
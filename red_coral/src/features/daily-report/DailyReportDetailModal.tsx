@@ -48,6 +48,11 @@ export const DailyReportDetailModal: React.FC<DailyReportDetailModalProps> = ({
     });
   };
 
+  const formatDuration = (millis: number) => {
+    const minutes = Math.max(0, Math.round(millis / 60000));
+    return `${Math.floor(minutes / 60)}h ${String(minutes % 60).padStart(2, '0')}m`;
+  };
+
   return (
     <div className="fixed inset-0 z-50 flex items-center justify-center">
       {/* Backdrop */}
@@ -122,6 +127,52 @@ export const DailyReportDetailModal: React.FC<DailyReportDetailModalProps> = ({
             </div>
           </div>
 
+          {/* Carry-over (orders spanning the day boundaries) */}
+          {((report.carried_in_count ?? 0) > 0 || (report.carried_out_count ?? 0) > 0) && (
+            <div className="bg-amber-50 rounded-xl p-4">
+              <h3 className="text-sm font-semibold text-amber-700 mb-3">
+                {t('settings.daily_report.section.carry_over')}
+              </h3>
+              <div className="grid grid-cols-2 gap-4 text-sm">
+                <div className="flex justify-between">
+                  <span className="text-gray-500">{t('settings.daily_report.carry_over.carried_in')}</span>
+                  <span>
+                    {report.carried_in_count ?? 0} · {formatCurrency(report.carried_in_amount ?? 0)}
+                  </span>
+                </div>
+                <div className="flex justify-between">
+                  <span className="text-gray-500">{t('settings.daily_report.carry_over.carried_out')}</span>
+                  <span>
+                    {report.carried_out_count ?? 0} · {formatCurrency(report.carried_out_amount ?? 0)}
+                  </span>
+                </div>
+              </div>
+            </div>
+          )}
+
+          {/* Cash drawer (no-sale opens, paid-in / paid-out) */}
+          {((report.no_sale_opens ?? 0) > 0 || (report.paid_in_amount ?? 0) > 0 || (report.paid_out_amount ?? 0) > 0) && (
+            <div className="bg-gray-50 rounded-xl p-4">
+              <h3 className="text-sm font-semibold text-gray-700 mb-3">
+                {t('settings.daily_report.section.cash_drawer')}
+              </h3>
+              <div className="grid grid-cols-3 gap-4 text-sm">
+                <div className="flex justify-between">
+                  <span className="text-gray-500">{t('settings.daily_report.cash_drawer.no_sale_opens')}</span>
+                  <span>{report.no_sale_opens ?? 0}</span>
+                </div>
+                <div className="flex justify-between">
+                  <span className="text-gray-500">{t('settings.daily_report.cash_drawer.paid_in')}</span>
+                  <span>{formatCurrency(report.paid_in_amount ?? 0)}</span>
+                </div>
+                <div className="flex justify-between">
+                  <span className="text-gray-500">{t('settings.daily_report.cash_drawer.paid_out')}</span>
+                  <span>{formatCurrency(report.paid_out_amount ?? 0)}</span>
+                </div>
+              </div>
+            </div>
+          )}
+
           {/* Shift Breakdowns */}
           {report.shift_breakdowns && report.shift_breakdowns.length > 0 && (
             <div className="bg-gray-50 rounded-xl p-4">
@@ -199,6 +250,26 @@ export const DailyReportDetailModal: React.FC<DailyReportDetailModalProps> = ({
                         </p>
                       </div>
                     </div>
+
+                    {/* Labor: worked time (unpaid breaks deducted) + breaks */}
+                    {shift.shift_id !== 0 && shift.end_time != null && (
+                      <div className="grid grid-cols-4 gap-3 text-sm mt-3 pt-3 border-t border-gray-100">
+                        <div>
+                          <p className="text-gray-500">{t('settings.daily_report.shift.worked')}</p>
+                          <p className="font-mono font-medium">
+                            {formatDuration(shift.end_time - shift.start_time - shift.unpaid_break_ms)}
+                          </p>
+                        </div>
+                        <div>
+                          <p className="text-gray-500">{t('settings.daily_report.shift.paid_break')}</p>
+                          <p className="font-mono font-medium">{formatDuration(shift.paid_break_ms)}</p>
+                        </div>
+                        <div>
+                          <p className="text-gray-500">{t('settings.daily_report.shift.unpaid_break')}</p>
+                          <p className="font-mono font-medium">{formatDuration(shift.unpaid_break_ms)}</p>
+                        </div>
+                      </div>
+                    )}
                   </div>
                 ))}
               </div>
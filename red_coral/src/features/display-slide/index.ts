@@ -0,0 +1,14 @@
+/**
+ * Display Slide Feature Module (客显屏轮播)
+ */
+
+// Store
+export {
+  useDisplaySlideStore,
+  useDisplaySlides,
+  useDisplaySlidesLoading,
+} from './store';
+
+// Slideshow
+export { useDisplaySlideshow } from './useDisplaySlideshow';
+export type { CurrentSlide } from './useDisplaySlideshow';
@@ -0,0 +1,14 @@
+import { createResourceStore } from '@/core/stores/factory/createResourceStore';
+import { createTauriClient } from '@/infrastructure/api';
+import type { DisplaySlide } from '@/core/domain/types/api';
+
+const getApi = () => createTauriClient();
+
+export const useDisplaySlideStore = createResourceStore<DisplaySlide>(
+  'display_slide',
+  () => getApi().listDisplaySlides()
+);
+
+// Convenience hooks
+export const useDisplaySlides = () => useDisplaySlideStore((state) => state.items);
+export const useDisplaySlidesLoading = () => useDisplaySlideStore((state) => state.isLoading);
@@ -0,0 +1,103 @@
+/**
+ * Customer Display Slideshow
+ *
+ * 客显屏空闲轮播：从服务端取当前排期内的播放列表，预下载图片到本地缓存，
+ * 按每张时长轮播。每轮结束或幻灯片变更（sync 信号）时重新拉取播放列表，
+ * 排期（时段/星期/有效期）完全由服务端决定。
+ */
+import { useEffect, useState } from 'react';
+import { invoke } from '@tauri-apps/api/core';
+import { createTauriClient } from '@/infrastructure/api';
+import { getImageUrl } from '@/core/services/imageCache';
+import { logger } from '@/utils/logger';
+import type { DisplaySlide } from '@/core/domain/types/api';
+import { useDisplaySlideStore } from './store';
+
+const getApi = () => createTauriClient();
+
+/** 播放列表为空时的重试间隔 */
+const EMPTY_RETRY_MS = 60_000;
+
+export interface CurrentSlide {
+  slide: DisplaySlide;
+  /** asset:// URL of the cached image */
+  url: string;
+}
+
+async function loadPlaylist(): Promise<CurrentSlide[]> {
+  const playlist = await getApi().getDisplayPlaylist();
+  const hashes = [...new Set(playlist.map((s) => s.image))];
+  if (hashes.length > 0) {
+    await invoke('prefetch_images', { hashes }).catch((error) => {
+      logger.warn('Slide image prefetch failed', { component: 'DisplaySlideshow', error });
+    });
+  }
+  const resolved = await Promise.all(
+    playlist.map(async (slide) => ({ slide, url: await getImageUrl(slide.image) }))
+  );
+  // 图片不可用的幻灯片跳过，不显示空白屏
+  return resolved.filter((s) => s.url);
+}
+
+/**
+ * 轮播当前幻灯片；`enabled` 为 false（客显屏非空闲）时暂停
+ *
+ * @returns 当前应显示的幻灯片，无可播放内容时为 null
+ */
+export function useDisplaySlideshow(enabled: boolean): CurrentSlide | null {
+  const syncVersion = useDisplaySlideStore((state) => state.lastVersion);
+  const [current, setCurrent] = useState<CurrentSlide | null>(null);
+
+  // 加载 store 以接收 display_slide 同步信号（未加载的 store 忽略 sync）
+  useEffect(() => {
+    useDisplaySlideStore.getState().fetchAll();
+  }, []);
+
+  useEffect(() => {
+    if (!enabled) {
+      setCurrent(null);
+      return;
+    }
+
+    let cancelled = false;
+    let timer: ReturnType<typeof setTimeout> | undefined;
+
+    const playLoop = async () => {
+      let playlist: CurrentSlide[] = [];
+      try {
+        playlist = await loadPlaylist();
+      } catch (error) {
+        logger.error('Failed to load display playlist', error, { component: 'DisplaySlideshow' });
+      }
+      if (cancelled) return;
+
+      if (playlist.length === 0) {
+        setCurrent(null);
+        timer = setTimeout(playLoop, EMPTY_RETRY_MS);
+        return;
+      }
+
+      const show = (index: number) => {
+        if (cancelled) return;
+        if (index >= playlist.length) {
+          // 一轮结束：重新拉取，让排期变化生效
+          playLoop();
+          return;
+        }
+        const item = playlist[index];
+        setCurrent(item);
+        timer = setTimeout(() => show(index + 1), item.slide.duration_secs * 1000);
+      };
+      show(0);
+    };
+
+    playLoop();
+
+    return () => {
+      cancelled = true;
+      if (timer) clearTimeout(timer);
+    };
+  }, [enabled, syncVersion]);
+
+  return current;
+}
@@ -0,0 +1,14 @@
+/**
+ * Event Booking Feature Module (宴会预订)
+ */
+
+export {
+  listEventBookings,
+  getEventBooking,
+  createEventBooking,
+  updateEventBooking,
+  confirmEventBooking,
+  cancelEventBooking,
+  recordEventBookingDeposit,
+  materializeEventBooking,
+} from './mutations';
@@ -0,0 +1,68 @@
+import { invokeApi } from '@/infrastructure/api/tauri-client';
+import type {
+  EventBooking,
+  EventBookingCreate,
+  EventBookingDeposit,
+  EventBookingDepositCreate,
+  EventBookingDetail,
+  EventBookingStatus,
+  EventBookingUpdate,
+} from '@/core/domain/types/api';
+
+export async function listEventBookings(
+  from?: number,
+  to?: number,
+  status?: EventBookingStatus,
+): Promise<EventBooking[]> {
+  const params = new URLSearchParams();
+  if (from !== undefined) params.set('from', String(from));
+  if (to !== undefined) params.set('to', String(to));
+  if (status) params.set('status', status);
+  const qs = params.toString();
+  return invokeApi<EventBooking[]>('api_get', {
+    path: `/api/event-bookings${qs ? `?${qs}` : ''}`,
+  });
+}
+
+export async function getEventBooking(id: number): Promise<EventBookingDetail> {
+  return invokeApi<EventBookingDetail>('api_get', { path: `/api/event-bookings/${id}` });
+}
+
+export async function createEventBooking(data: EventBookingCreate): Promise<EventBookingDetail> {
+  return invokeApi<EventBookingDetail>('api_post', { path: '/api/event-bookings', body: data });
+}
+
+export async function updateEventBooking(
+  id: number,
+  data: EventBookingUpdate,
+): Promise<EventBookingDetail> {
+  return invokeApi<EventBookingDetail>('api_put', {
+    path: `/api/event-bookings/${id}`,
+    body: data,
+  });
+}
+
+export async function confirmEventBooking(id: number): Promise<EventBookingDetail> {
+  return invokeApi<EventBookingDetail>('api_post', { path: `/api/event-bookings/${id}/confirm` });
+}
+
+export async function cancelEventBooking(id: number): Promise<EventBookingDetail> {
+  return invokeApi<EventBookingDetail>('api_post', { path: `/api/event-bookings/${id}/cancel` });
+}
+
+export async function recordEventBookingDeposit(
+  id: number,
+  data: EventBookingDepositCreate,
+): Promise<EventBookingDeposit> {
+  return invokeApi<EventBookingDeposit>('api_post', {
+    path: `/api/event-bookings/${id}/deposits`,
+    body: data,
+  });
+}
+
+/** Open the event order now instead of waiting for the scheduler */
+export async function materializeEventBooking(id: number): Promise<EventBookingDetail> {
+  return invokeApi<EventBookingDetail>('api_post', {
+    path: `/api/event-bookings/${id}/materialize`,
+  });
+}
@@ -24,6 +24,7 @@ import { toast } from '@/presentation/components/Toast';
 import { logger } from '@/utils/logger';
 import type {
   MarketingGroup,
+  MarketingGroupCreate,
   MarketingGroupDetail,
   MgDiscountRule,
   StampActivityDetail,
@@ -86,7 +87,7 @@ export const MarketingGroupManagement: React.FC = React.memo(() => {
   }, [selectedGroupId]);
 
   // ── Group CRUD ──
-  const handleSaveGroup = async (data: { name: string; description?: string }) => {
+  const handleSaveGroup = async (data: MarketingGroupCreate) => {
     try {
       if (editingGroup) {
         await updateMarketingGroup(editingGroup.id, data);
@@ -349,12 +350,17 @@ export const MarketingGroupManagement: React.FC = React.memo(() => {
 
 const GroupFormModal: React.FC<{
   group: MarketingGroup | null;
-  onSave: (data: { name: string; description?: string }) => void;
+  onSave: (data: MarketingGroupCreate) => void;
   onClose: () => void;
   t: (key: string, params?: Record<string, string | number>) => string;
 }> = ({ group, onSave, onClose, t }) => {
   const [name, setName] = useState(group?.name || '');
   const [description, setDescription] = useState(group?.description || '');
+  const [earnRate, setEarnRate] = useState(group?.points_earn_rate?.toString() ?? '');
+  const [redeemUnit, setRedeemUnit] = useState(group?.points_redeem_unit?.toString() ?? '');
+  const [redeemValue, setRedeemValue] = useState(group?.points_redeem_value?.toString() ?? '');
+
+  const toNumber = (v: string) => (v.trim() === '' ? undefined : Number(v));
 
   return (
     <div className="fixed inset-0 z-50 bg-black/50 backdrop-blur-sm flex items-center justify-center p-4">
@@ -390,6 +396,52 @@ const GroupFormModal: React.FC<{
               className="w-full px-3 py-2 border border-gray-300 rounded-lg focus:ring-2 focus:ring-violet-500 focus:border-violet-500"
             />
           </div>
+          <div>
+            <label className="block text-sm font-medium text-gray-700 mb-1">
+              {t('settings.marketing_group.field.points_earn_rate')}
+            </label>
+            <input
+              type="number"
+              min={0}
+              step="0.1"
+              value={earnRate}
+              onChange={(e) => setEarnRate(e.target.value)}
+              className="w-full px-3 py-2 border border-gray-300 rounded-lg focus:ring-2 focus:ring-violet-500 focus:border-violet-500"
+              placeholder="1"
+            />
+            <p className="text-xs text-gray-400 mt-1">{t('settings.marketing_group.field.points_earn_rate_hint')}</p>
+          </div>
+          <div className="grid grid-cols-2 gap-3">
+            <div>
+              <label className="block text-sm font-medium text-gray-700 mb-1">
+                {t('settings.marketing_group.field.points_redeem_unit')}
+              </label>
+              <input
+                type="number"
+                min={1}
+                step="1"
+                value={redeemUnit}
+                onChange={(e) => setRedeemUnit(e.target.value)}
+                className="w-full px-3 py-2 border border-gray-300 rounded-lg focus:ring-2 focus:ring-violet-500 focus:border-violet-500"
+                placeholder="100"
+              />
+            </div>
+            <div>
+              <label className="block text-sm font-medium text-gray-700 mb-1">
+                {t('settings.marketing_group.field.points_redeem_value')}
+              </label>
+              <input
+                type="number"
+                min={0}
+                step="0.01"
+                value={redeemValue}
+                onChange={(e) => setRedeemValue(e.target.value)}
+                className="w-full px-3 py-2 border border-gray-300 rounded-lg focus:ring-2 focus:ring-violet-500 focus:border-violet-500"
+                placeholder="5"
+              />
+            </div>
+          </div>
+          <p className="text-xs text-gray-400">{t('settings.marketing_group.field.points_redeem_hint')}</p>
         </div>
         <div className="p-5 border-t border-gray-100 flex gap-3">
           <button
@@ -400,7 +452,13 @@ const GroupFormModal: React.FC<{
           </button>
           <button
             onClick={() =>
-              onSave({ name, description: description || undefined })
+              onSave({
+                name,
+                description: description || undefined,
+                points_earn_rate: toNumber(earnRate),
+                points_redeem_unit: toNumber(redeemUnit),
+                points_redeem_value: toNumber(redeemValue),
+              })
             }
             disabled={!name.trim()}
             className="flex-1 px-4 py-3 bg-violet-500 text-white rounded-xl font-bold hover:bg-violet-600 disabled:opacity-50 transition-colors"
@@ -15,6 +15,7 @@ import { ManagementHeader, FilterBar } from '@/screens/Settings/components';
 import { FormField, FormSection, SelectField, inputClass, WheelDatePicker } from '@/shared/components/FormField';
 import { MAX_NAME_LEN, MAX_SHORT_TEXT_LEN, MAX_EMAIL_LEN, MAX_NOTE_LEN } from '@/shared/constants/validation';
 import { formatCurrency } from '@/utils/currency';
+import { RECEIPT_LANGUAGES } from '@/core/services/order/receiptBuilder';
 
 export const MemberManagement: React.FC = React.memo(() => {
   const { t } = useI18n();
@@ -253,6 +254,7 @@ const MemberFormModal: React.FC<{
   const [groupId, setGroupId] = useState<number>(member?.marketing_group_id || groups[0]?.id || 0);
   const [birthday, setBirthday] = useState(member?.birthday || '');
   const [notes, setNotes] = useState(member?.notes || '');
+  const [preferredLocale, setPreferredLocale] = useState(member?.preferred_locale || '');
 
   return (
     <div className="fixed inset-0 z-80 bg-black/50 backdrop-blur-sm flex items-center justify-center p-4">
@@ -339,6 +341,16 @@ const MemberFormModal: React.FC<{
                 className={inputClass}
               />
             </FormField>
+
+            <SelectField
+              label={t('settings.member.field.receipt_locale')}
+              value={preferredLocale}
+              onChange={(v) => setPreferredLocale(String(v))}
+              options={[
+                { value: '', label: t('settings.member.field.receipt_locale_default') },
+                ...RECEIPT_LANGUAGES.map((l) => ({ value: l.locale, label: l.label })),
+              ]}
+            />
           </FormSection>
         </div>
 
@@ -359,6 +371,8 @@ const MemberFormModal: React.FC<{
               marketing_group_id: groupId,
               birthday: birthday || null,
               notes: notes || null,
+              // 编辑时空字符串 = 恢复门店默认
+              preferred_locale: member ? preferredLocale : preferredLocale || null,
             }, member?.id)}
             disabled={!name.trim() || !groupId}
             className="px-5 py-2.5 bg-teal-600 text-white rounded-xl text-sm font-semibold hover:bg-teal-700 transition-colors shadow-lg shadow-teal-600/20 disabled:opacity-50 disabled:cursor-not-allowed"
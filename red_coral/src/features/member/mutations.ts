@@ -4,6 +4,10 @@ import type {
   MemberCreate,
   MemberUpdate,
   MemberStampProgressDetail,
+  MemberCreditTopUp,
+  MemberCreditTransaction,
+  MemberCreditStatement,
+  MemberPointsSummary,
 } from '@/core/domain/types/api';
 
 export async function listMembers(): Promise<MemberWithGroup[]> {
@@ -35,3 +39,31 @@ interface MemberDetailResponse extends MemberWithGroup {
 export async function getMemberDetail(id: number): Promise<MemberDetailResponse> {
   return invokeApi<MemberDetailResponse>('api_get', { path: `/api/members/${id}` });
 }
+
+export async function topUpMemberCredit(
+  id: number,
+  data: MemberCreditTopUp,
+): Promise<MemberCreditTransaction> {
+  return invokeApi<MemberCreditTransaction>('api_post', {
+    path: `/api/members/${id}/credit/top-up`,
+    body: data,
+  });
+}
+
+export async function getMemberCreditStatement(
+  id: number,
+  from?: number,
+  to?: number,
+): Promise<MemberCreditStatement> {
+  const params = new URLSearchParams();
+  if (from !== undefined) params.set('from', String(from));
+  if (to !== undefined) params.set('to', String(to));
+  const qs = params.toString();
+  return invokeApi<MemberCreditStatement>('api_get', {
+    path: `/api/members/${id}/credit/statement${qs ? `?${qs}` : ''}`,
+  });
+}
+
+export async function getMemberPoints(id: number): Promise<MemberPointsSummary> {
+  return invokeApi<MemberPointsSummary>('api_get', { path: `/api/members/${id}/points` });
+}
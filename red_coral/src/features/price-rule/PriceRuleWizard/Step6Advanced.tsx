@@ -35,6 +35,17 @@ export const Step6Advanced: React.FC<Step6AdvancedProps> = ({ state, updateState
           onChange={(checked) => updateState({ is_exclusive: checked })}
         />
 
+        <FormField label={t('settings.price_rule.wizard.priority')}>
+          <input
+            type="number"
+            step="1"
+            value={state.priority}
+            onChange={(e) => updateState({ priority: parseInt(e.target.value, 10) || 0 })}
+            className={inputClass}
+          />
+          <p className="mt-1 text-xs text-gray-500">{t('settings.price_rule.wizard.priority_hint')}</p>
+        </FormField>
+
         {/* Info box */}
         <div className="p-4 bg-amber-50 rounded-xl border border-amber-100">
           <p className="text-sm text-amber-800">
@@ -51,6 +51,7 @@ export interface WizardState {
   // Step 6
   is_stackable: boolean;
   is_exclusive: boolean;
+  priority: number;
 }
 
 const getInitialState = (rule?: PriceRule | null): WizardState => {
@@ -81,6 +82,7 @@ const getInitialState = (rule?: PriceRule | null): WizardState => {
       description: rule.description || '',
       is_stackable: rule.is_stackable,
       is_exclusive: rule.is_exclusive,
+      priority: rule.priority ?? 0,
     };
   }
   return {
@@ -101,6 +103,7 @@ const getInitialState = (rule?: PriceRule | null): WizardState => {
     description: '',
     is_stackable: true,
     is_exclusive: false,
+    priority: 0,
   };
 };
 
@@ -189,6 +192,7 @@ export const PriceRuleWizard: React.FC<PriceRuleWizardProps> = ({
       adjustment_value: state.adjustment_value,
       is_stackable: state.is_stackable,
       is_exclusive: state.is_exclusive,
+      priority: state.priority,
     };
 
     if (state.time_mode === 'SCHEDULE') {
@@ -5,6 +5,7 @@ import { useImageUrl } from '@/core/hooks';
 import { useSettingsStore } from '@/core/stores/settings/useSettingsStore';
 import { useLongPress } from '@/hooks/useLongPress';
 import { formatCurrency } from '@/utils/currency';
+import { useProductEightySix } from './store';
 
 /**
  * Extended Product type with computed fields from root spec
@@ -24,6 +25,7 @@ export const ProductCard = React.memo<ProductCardProps>(
   ({ product, onAdd, onLongPress, priority = false }) => {
     const imgRef = useRef<HTMLImageElement>(null);
     const performanceMode = useSettingsStore((state) => state.performanceMode);
+    const eightySix = useProductEightySix(product.id);
 
     const handleImageClick = useCallback((e: React.MouseEvent | React.TouchEvent) => {
       e.stopPropagation();
@@ -113,6 +115,18 @@ export const ProductCard = React.memo<ProductCardProps>(
           </div>
         </div>
 
+        {/* 86'd: sold out until cleared or expired */}
+        {eightySix && (
+          <div
+            className="absolute inset-0 bg-white/60 pointer-events-none flex items-start justify-end p-1.5"
+            title={eightySix.reason ?? undefined}
+          >
+            <span className="bg-red-600 text-white text-sm font-black px-2 py-0.5 rounded-md shadow-sm leading-none">
+              86
+            </span>
+          </div>
+        )}
+
         {/* Interactive Overlay - Ripple-like feedback */}
         <div className="absolute inset-0 bg-blue-500/0 group-active:bg-blue-500/5 pointer-events-none transition-colors duration-200" />
       </div>
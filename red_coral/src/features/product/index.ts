@@ -8,8 +8,17 @@ export {
   useProducts,
   useProductsLoading,
   useProductById,
+  useEightySixStore,
+  useEightySixed,
+  useProductEightySix,
+  useStockLevelStore,
+  useStockLevels,
+  useProductStock,
 } from './store';
 
+// 86 board
+export { eightySixProduct, clearEightySix } from './mutations';
+
 // Components
 export { ProductCard, type ProductWithPrice } from './ProductCard';
 export { ProductManagement } from './ProductManagement';
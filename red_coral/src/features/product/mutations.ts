@@ -2,6 +2,7 @@ import { createTauriClient } from '@/infrastructure/api';
 import { useProductStore } from './store';
 import { logger } from '@/utils/logger';
 import type { Product, Category, ProductSpec, ProductSpecInput, PrintState } from '@/core/domain/types';
+import type { EightySix, EightySixCreate } from '@/core/domain/types/api';
 import { syncAttributeBindings } from '@/screens/Settings/utils';
 
 const getApi = () => createTauriClient();
@@ -287,3 +288,17 @@ export async function loadProductFullData(productId: number) {
     externalId,
   };
 }
+
+/**
+ * 86 a product or spec (any terminal; broadcast to all via sync)
+ */
+export async function eightySixProduct(data: EightySixCreate): Promise<EightySix> {
+  return getApi().createEightySix(data);
+}
+
+/**
+ * Put an 86'd product back on sale
+ */
+export async function clearEightySix(id: number): Promise<EightySix> {
+  return getApi().clearEightySix(id);
+}
@@ -1,6 +1,6 @@
-import { createCrudResourceStore } from '@/core/stores/factory/createResourceStore';
+import { createCrudResourceStore, createResourceStore } from '@/core/stores/factory/createResourceStore';
 import { createTauriClient } from '@/infrastructure/api';
-import type { ProductFull, ProductCreate, ProductUpdate } from '@/core/domain/types/api';
+import type { ProductFull, ProductCreate, ProductUpdate, EightySix, StockLevel } from '@/core/domain/types/api';
 
 const getApi = () => createTauriClient();
 
@@ -27,3 +27,35 @@ export const useProductActions = () => ({
   remove: useProductStore.getState().remove,
   fetchAll: useProductStore.getState().fetchAll,
 });
+
+// 86 board (临时沽清)
+export const useEightySixStore = createResourceStore<EightySix>(
+  'eighty_six',
+  () => getApi().listEightySix()
+);
+
+export const useEightySixed = () => useEightySixStore((state) => state.items);
+/** Whole-product 86 entry (spec-level entries only block that spec) */
+export const useProductEightySix = (productId: number) =>
+  useEightySixStore((state) =>
+    state.items.find(
+      (e) =>
+        e.product_id === productId &&
+        e.spec_id === null &&
+        (e.expires_at === null || e.expires_at > Date.now())
+    )
+  );
+
+// Inventory (库存)
+export const useStockLevelStore = createResourceStore<StockLevel>(
+  'stock_level',
+  () => getApi().listStockLevels()
+);
+
+export const useStockLevels = () => useStockLevelStore((state) => state.items);
+/** Stock of a product: spec-level entry first, then the whole-product entry */
+export const useProductStock = (productId: number, specId: number | null = null) =>
+  useStockLevelStore((state) =>
+    state.items.find((l) => l.product_id === productId && specId !== null && l.spec_id === specId) ??
+    state.items.find((l) => l.product_id === productId && l.spec_id === null)
+  );
@@ -0,0 +1,14 @@
+/**
+ * Reservation Feature Module (订位)
+ */
+
+export {
+  listReservations,
+  getReservationCalendar,
+  getReservation,
+  createReservation,
+  updateReservation,
+  seatReservation,
+  markReservationNoShow,
+  cancelReservation,
+} from './mutations';
@@ -0,0 +1,66 @@
+import { invokeApi } from '@/infrastructure/api/tauri-client';
+import type {
+  Reservation,
+  ReservationCalendar,
+  ReservationCreate,
+  ReservationStatus,
+  ReservationUpdate,
+} from '@/core/domain/types/api';
+
+export async function listReservations(
+  from?: number,
+  to?: number,
+  status?: ReservationStatus,
+  zoneId?: number,
+): Promise<Reservation[]> {
+  const params = new URLSearchParams();
+  if (from !== undefined) params.set('from', String(from));
+  if (to !== undefined) params.set('to', String(to));
+  if (status) params.set('status', status);
+  if (zoneId !== undefined) params.set('zone_id', String(zoneId));
+  const qs = params.toString();
+  return invokeApi<Reservation[]>('api_get', {
+    path: `/api/reservations${qs ? `?${qs}` : ''}`,
+  });
+}
+
+/** Day view: slot occupancy plus that day's reservations (date = YYYY-MM-DD, store timezone) */
+export async function getReservationCalendar(
+  date: string,
+  slotMinutes?: number,
+  zoneId?: number,
+): Promise<ReservationCalendar> {
+  const params = new URLSearchParams({ date });
+  if (slotMinutes !== undefined) params.set('slot_minutes', String(slotMinutes));
+  if (zoneId !== undefined) params.set('zone_id', String(zoneId));
+  return invokeApi<ReservationCalendar>('api_get', {
+    path: `/api/reservations/calendar?${params.toString()}`,
+  });
+}
+
+export async function getReservation(id: number): Promise<Reservation> {
+  return invokeApi<Reservation>('api_get', { path: `/api/reservations/${id}` });
+}
+
+export async function createReservation(data: ReservationCreate): Promise<Reservation> {
+  return invokeApi<Reservation>('api_post', { path: '/api/reservations', body: data });
+}
+
+export async function updateReservation(
+  id: number,
+  data: ReservationUpdate,
+): Promise<Reservation> {
+  return invokeApi<Reservation>('api_put', { path: `/api/reservations/${id}`, body: data });
+}
+
+export async function seatReservation(id: number): Promise<Reservation> {
+  return invokeApi<Reservation>('api_post', { path: `/api/reservations/${id}/seat` });
+}
+
+export async function markReservationNoShow(id: number): Promise<Reservation> {
+  return invokeApi<Reservation>('api_post', { path: `/api/reservations/${id}/no-show` });
+}
+
+export async function cancelReservation(id: number): Promise<Reservation> {
+  return invokeApi<Reservation>('api_post', { path: `/api/reservations/${id}/cancel` });
+}
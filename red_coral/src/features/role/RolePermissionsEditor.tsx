@@ -9,22 +9,26 @@ import { Role, RolePermission } from '@/core/domain/types';
 import { ConfirmDialog } from '@/shared/components/ConfirmDialog';
 import { MAX_NAME_LEN, MAX_NOTE_LEN } from '@/shared/constants/validation';
 
-// Permission labels (18 configurable permissions)
+// Permission labels (23 configurable permissions)
 const usePermissionLabels = () => {
   const { t } = useI18n();
   return {
-    // === 模块化权限 (6) ===
+    // === 模块化权限 (10) ===
     'menu:manage': t('settings.permissions.menu_manage'),
     'tables:manage': t('settings.permissions.tables_manage'),
+    'bookings:manage': t('settings.permissions.bookings_manage'),
     'shifts:manage': t('settings.permissions.shifts_manage'),
+    'announcements:send': t('settings.permissions.announcements_send'),
     'reports:view': t('settings.permissions.reports_view'),
+    'reports:financials': t('settings.permissions.reports_financials'),
+    'reports:labor': t('settings.permissions.reports_labor'),
     'price_rules:manage': t('settings.permissions.price_rules_manage'),
     'settings:manage': t('settings.permissions.settings_manage'),
     // === 营销与会员 (3) ===
     'marketing:manage': t('settings.permissions.marketing_manage'),
     'orders:link_member': t('settings.permissions.orders_link_member'),
     'orders:redeem_stamp': t('settings.permissions.orders_redeem_stamp'),
-    // === 敏感操作 (9) ===
+    // === 敏感操作 (10) ===
     'orders:void': t('settings.permissions.orders_void'),
     'orders:discount': t('settings.permissions.orders_discount'),
     'orders:comp': t('settings.permissions.orders_comp'),
@@ -34,6 +38,7 @@ const usePermissionLabels = () => {
     'tables:transfer': t('settings.permissions.tables_transfer'),
     'tables:merge_bill': t('settings.permissions.tables_merge_bill'),
     'cash_drawer:open': t('settings.permissions.cash_drawer_open'),
+    'inventory:approve_count': t('settings.permissions.inventory_approve_count'),
   };
 };
 
@@ -56,7 +61,7 @@ export const RolePermissionsEditor: React.FC = () => {
 
   const [selectedRole, setSelectedRole] = useState<Role | null>(null);
 
-  // Permission groups (3 groups, 18 permissions total)
+  // Permission groups (3 groups, 23 permissions total)
   const getPermissionGroups = () => {
     return {
       modular: {
@@ -65,8 +70,12 @@ export const RolePermissionsEditor: React.FC = () => {
         perms: [
           'menu:manage',
           'tables:manage',
+          'bookings:manage',
           'shifts:manage',
+          'announcements:send',
           'reports:view',
+          'reports:financials',
+          'reports:labor',
           'price_rules:manage',
           'settings:manage',
           'marketing:manage',
@@ -85,6 +94,7 @@ export const RolePermissionsEditor: React.FC = () => {
           'tables:transfer',
           'tables:merge_bill',
           'cash_drawer:open',
+          'inventory:approve_count',
         ]
       }
     };
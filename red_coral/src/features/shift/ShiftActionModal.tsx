@@ -3,7 +3,7 @@
  *
  * 支持三种操作:
  * - open: 开班 (输入开班现金)
- * - close: 收班 (输入实际现金，计算差异)
+ * - close: 收班 (输入实际现金，计算差异；应结现金已含钱箱存入/取出)
  * - force_close: 强制关闭 (不盘点现金)
  *
  * UI 风格与 CashPaymentModal 保持一致
@@ -19,7 +19,7 @@ import { useAuthStore } from '@/core/stores/auth/useAuthStore';
 import { useShiftStore } from '@/core/stores/shift';
 import { Currency, formatCurrency } from '@/utils/currency';
 import { Numpad } from '@/presentation/components/ui/Numpad';
-import type { Shift } from '@/core/domain/types/api';
+import type { CashDrawerSummary, Shift } from '@/core/domain/types/api';
 import { MAX_NOTE_LEN } from '@/shared/constants/validation';
 import { useCurrencySymbol } from '@/core/stores/settings/useStoreInfoStore';
 
@@ -50,6 +50,25 @@ export const ShiftActionModal: React.FC<ShiftActionModalProps> = ({
   const [note, setNote] = useState('');
   const [loading, setLoading] = useState(false);
   const [isSelected, setIsSelected] = useState(true); // 覆盖模式：输入时替换全部
+  const [drawerSummary, setDrawerSummary] = useState<CashDrawerSummary | null>(null);
+
+  // 收班对账：加载本班次钱箱存取汇总
+  useEffect(() => {
+    if (!open || action !== 'close' || !shift?.id) {
+      setDrawerSummary(null);
+      return;
+    }
+    let cancelled = false;
+    getApi()
+      .getShiftCashDrawer(shift.id)
+      .then((drawer) => {
+        if (!cancelled) setDrawerSummary(drawer.summary);
+      })
+      .catch((error) => logger.warn('Failed to load shift cash drawer', { component: 'ShiftActionModal', error }));
+    return () => {
+      cancelled = true;
+    };
+  }, [open, action, shift?.id]);
 
   // Reset form when modal opens
   useEffect(() => {
@@ -287,6 +306,14 @@ export const ShiftActionModal: React.FC<ShiftActionModalProps> = ({
                 <div className="text-2xl md:text-3xl font-bold text-gray-900 mt-1 font-mono">
                   {formatCurrency(shift.expected_cash)}
                 </div>
+                {drawerSummary && (drawerSummary.paid_in_count > 0 || drawerSummary.paid_out_count > 0) && (
+                  <div className="text-xs text-gray-500 mt-1">
+                    {t('settings.shift.modal.paid_in_out', {
+                      paidIn: formatCurrency(drawerSummary.paid_in_amount),
+                      paidOut: formatCurrency(drawerSummary.paid_out_amount),
+                    })}
+                  </div>
+                )}
               </div>
 
               {/* Variance Preview */}
@@ -4,11 +4,12 @@
  * 功能:
  * - 查看班次列表
  * - 开班/收班操作
+ * - 开始/结束休息（连续工作 6 小时未休息时提醒）
  * - 查看班次详情
  */
 
 import React, { useEffect, useMemo, useState, useCallback } from 'react';
-import { Clock, Play, Square, AlertCircle, CheckCircle, XCircle } from 'lucide-react';
+import { Clock, Play, Square, AlertCircle, CheckCircle, XCircle, Coffee } from 'lucide-react';
 import { useI18n } from '@/hooks/useI18n';
 import { createTauriClient } from '@/infrastructure/api';
 import { DataTable, Column } from '@/shared/components/DataTable';
@@ -17,7 +18,7 @@ import { logger } from '@/utils/logger';
 import { useAuthStore } from '@/core/stores/auth/useAuthStore';
 import { formatCurrency } from '@/utils/currency';
 import { getLocale } from '@/infrastructure/i18n';
-import type { Shift, ShiftStatus } from '@/core/domain/types/api';
+import type { BreakType, Shift, ShiftBreakStatus, ShiftStatus } from '@/core/domain/types/api';
 
 const getApi = () => createTauriClient();
 
@@ -36,6 +37,7 @@ export const ShiftManagement: React.FC = React.memo(() => {
   const [loading, setLoading] = useState(false);
   const [searchQuery, setSearchQuery] = useState('');
   const [currentShift, setCurrentShift] = useState<Shift | null>(null);
+  const [breakStatus, setBreakStatus] = useState<ShiftBreakStatus | null>(null);
 
   // Modal state
   const [modalOpen, setModalOpen] = useState(false);
@@ -52,6 +54,7 @@ export const ShiftManagement: React.FC = React.memo(() => {
       ]);
       setShifts(allShifts);
       setCurrentShift(current);
+      setBreakStatus(current ? await getApi().getShiftBreaks(current.id) : null);
     } catch (err) {
       logger.error('Failed to load shifts', err);
       toast.error(t('settings.shift.load_failed'));
@@ -97,6 +100,27 @@ export const ShiftManagement: React.FC = React.memo(() => {
     setModalOpen(true);
   }, []);
 
+  // Start / end break
+  const handleStartBreak = useCallback(async (shift: Shift, breakType: BreakType) => {
+    try {
+      await getApi().startShiftBreak(shift.id, { break_type: breakType });
+      setBreakStatus(await getApi().getShiftBreaks(shift.id));
+    } catch (err) {
+      logger.error('Failed to start break', err);
+      toast.error(t('settings.shift.break.start_failed'));
+    }
+  }, [t]);
+
+  const handleEndBreak = useCallback(async (shift: Shift) => {
+    try {
+      await getApi().endShiftBreak(shift.id);
+      setBreakStatus(await getApi().getShiftBreaks(shift.id));
+    } catch (err) {
+      logger.error('Failed to end break', err);
+      toast.error(t('settings.shift.break.end_failed'));
+    }
+  }, [t]);
+
   // Format time
   const formatTime = (millis: number) => {
     try {
@@ -289,9 +313,46 @@ export const ShiftManagement: React.FC = React.memo(() => {
                   {' | '}
                   {t('settings.shift.expected_cash')}: {formatCurrency(currentShift.expected_cash)}
                 </p>
+                {breakStatus?.on_break && (
+                  <p className="text-sm text-emerald-700">
+                    {t('settings.shift.break.on_break')}: {formatTime(breakStatus.on_break.start_time)}
+                  </p>
+                )}
+                {breakStatus?.break_due && (
+                  <p className="text-sm font-medium text-amber-700 flex items-center gap-1">
+                    <AlertCircle size={14} />
+                    {t('settings.shift.break.required')}
+                  </p>
+                )}
               </div>
             </div>
             <div className="flex gap-2">
+              {breakStatus?.on_break ? (
+                <button
+                  onClick={() => handleEndBreak(currentShift)}
+                  className="px-4 py-2 bg-white border border-emerald-300 text-emerald-700 rounded-lg hover:bg-emerald-100 transition-colors flex items-center gap-2"
+                >
+                  <Coffee size={16} />
+                  {t('settings.shift.break.end')}
+                </button>
+              ) : (
+                <>
+                  <button
+                    onClick={() => handleStartBreak(currentShift, 'PAID')}
+                    className="px-4 py-2 bg-white border border-emerald-300 text-emerald-700 rounded-lg hover:bg-emerald-100 transition-colors flex items-center gap-2"
+                  >
+                    <Coffee size={16} />
+                    {t('settings.shift.break.start_paid')}
+                  </button>
+                  <button
+                    onClick={() => handleStartBreak(currentShift, 'UNPAID')}
+                    className="px-4 py-2 bg-white border border-emerald-300 text-emerald-700 rounded-lg hover:bg-emerald-100 transition-colors flex items-center gap-2"
+                  >
+                    <Coffee size={16} />
+                    {t('settings.shift.break.start_unpaid')}
+                  </button>
+                </>
+              )}
               <button
                 onClick={() => handleCloseShift(currentShift)}
                 className="px-4 py-2 bg-emerald-600 text-white rounded-lg hover:bg-emerald-700 transition-colors flex items-center gap-2"
@@ -2,6 +2,9 @@
 export {
   useTableStore,
   useTables,
+  useTableGroupStore,
+  useTableGroups,
+  useTableGroupOf,
 } from './store';
 
 // Components
@@ -44,3 +44,17 @@ export async function updateTable(id: number, input: UpdateTableInput): Promise<
 export async function deleteTable(id: number): Promise<void> {
   await getApi().deleteTable(id);
 }
+
+/**
+ * Join tables into the host table (order stays on the host)
+ */
+export async function joinTables(hostTableId: number, tableIds: number[]): Promise<void> {
+  await getApi().joinTables(hostTableId, tableIds);
+}
+
+/**
+ * Split tables off the host table (empty = split all)
+ */
+export async function splitTables(hostTableId: number, tableIds: number[] = []): Promise<void> {
+  await getApi().splitTables(hostTableId, tableIds);
+}
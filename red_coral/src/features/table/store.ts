@@ -1,7 +1,7 @@
 import { createResourceStore } from '@/core/stores/factory/createResourceStore';
 import { useShallow } from 'zustand/react/shallow';
 import { createTauriClient } from '@/infrastructure/api';
-import type { Table } from '@/core/domain/types/api';
+import type { Table, TableGroup } from '@/core/domain/types/api';
 
 const getApi = () => createTauriClient();
 
@@ -19,3 +19,14 @@ export const useTablesByZone = (zoneId: number) =>
   useTableStore(
     useShallow((state) => state.items.filter((t) => t.zone_id === zoneId))
   );
+
+// Joined tables (拼桌)
+export const useTableGroupStore = createResourceStore<TableGroup>(
+  'table_group',
+  () => getApi().listTableGroups()
+);
+
+export const useTableGroups = () => useTableGroupStore((state) => state.items);
+/** Group containing the table (as host or member) */
+export const useTableGroupOf = (tableId: number) =>
+  useTableGroupStore((state) => state.items.find((g) => g.table_ids.includes(tableId)));
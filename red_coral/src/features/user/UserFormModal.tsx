@@ -1,6 +1,6 @@
 import React, { useState, useEffect } from 'react';
-import { X, User as UserIcon, KeyRound, Shield, Eye, EyeOff, Settings2 } from 'lucide-react';
-import { invokeApi } from '@/infrastructure/api/tauri-client';
+import { X, User as UserIcon, KeyRound, Shield, Eye, EyeOff, Settings2, Camera, Trash2 } from 'lucide-react';
+import { invokeApi, createTauriClient } from '@/infrastructure/api/tauri-client';
 import { useI18n } from '@/hooks/useI18n';
 import { toast } from '@/presentation/components/Toast';
 import { getErrorMessage } from '@/utils/error';
@@ -40,6 +40,10 @@ export const UserFormModal: React.FC<UserFormModalProps> = ({
   const [showPassword, setShowPassword] = useState(false);
   const [showConfirmPassword, setShowConfirmPassword] = useState(false);
   const [isSubmitting, setIsSubmitting] = useState(false);
+  // 员工照片 (登录 / 主管授权时显示，仅管理员可维护)
+  const [hasPhoto, setHasPhoto] = useState(false);
+  const [photoPreview, setPhotoPreview] = useState<string | null>(null);
+  const [isPhotoUploading, setIsPhotoUploading] = useState(false);
 
   useEffect(() => {
     // Fetch roles
@@ -68,6 +72,8 @@ export const UserFormModal: React.FC<UserFormModalProps> = ({
         isActive: editingUser.is_active,
       });
       setConfirmPassword('');
+      setHasPhoto(!!editingUser.has_photo);
+      setPhotoPreview(null);
     } else if (roles.length > 0) {
       const defaultRole = roles.find(r => r.name !== 'admin') || roles[0];
       setFormData({
@@ -137,6 +143,47 @@ export const UserFormModal: React.FC<UserFormModalProps> = ({
     }
   };
 
+  const handlePhotoSelected = async (e: React.ChangeEvent<HTMLInputElement>) => {
+    const file = e.target.files?.[0];
+    e.target.value = '';
+    if (!file || !editingUser) return;
+
+    setIsPhotoUploading(true);
+    try {
+      const dataUrl = await new Promise<string>((resolve, reject) => {
+        const reader = new FileReader();
+        reader.onload = () => resolve(reader.result as string);
+        reader.onerror = () => reject(reader.error);
+        reader.readAsDataURL(file);
+      });
+      await createTauriClient().uploadEmployeePhoto(editingUser.id, dataUrl.split(',')[1] ?? '');
+      setPhotoPreview(dataUrl);
+      setHasPhoto(true);
+      toast.success(t('settings.user.message.photo_updated'));
+    } catch (error) {
+      logger.error('Employee photo upload failed', error);
+      toast.error(getErrorMessage(error));
+    } finally {
+      setIsPhotoUploading(false);
+    }
+  };
+
+  const handlePhotoRemove = async () => {
+    if (!editingUser) return;
+    setIsPhotoUploading(true);
+    try {
+      await createTauriClient().deleteEmployeePhoto(editingUser.id);
+      setPhotoPreview(null);
+      setHasPhoto(false);
+      toast.success(t('settings.user.message.photo_removed'));
+    } catch (error) {
+      logger.error('Employee photo removal failed', error);
+      toast.error(getErrorMessage(error));
+    } finally {
+      setIsPhotoUploading(false);
+    }
+  };
+
   if (!isOpen) return null;
 
   const isAdminUser = editingUser?.username === 'admin';
@@ -275,6 +322,47 @@ export const UserFormModal: React.FC<UserFormModalProps> = ({
                 </p>
               )}
             </FormField>
+
+            {editingUser && currentUser?.role_name === 'admin' && (
+              <FormField label={t('settings.user.form.photo')}>
+                <div className="flex items-center gap-4">
+                  <div className="w-16 h-16 rounded-xl bg-gray-100 border border-gray-200 overflow-hidden flex items-center justify-center text-gray-400">
+                    {photoPreview ? (
+                      <img src={photoPreview} alt={formData.displayName} className="w-full h-full object-cover" />
+                    ) : (
+                      <UserIcon size={24} className={hasPhoto ? 'text-teal-500' : undefined} />
+                    )}
+                  </div>
+                  <div className="flex flex-col gap-1.5">
+                    <div className="flex gap-2">
+                      <label className="px-3 py-1.5 bg-white border border-gray-200 rounded-lg text-sm font-medium text-gray-700 hover:bg-gray-50 cursor-pointer flex items-center gap-1.5">
+                        <Camera size={14} />
+                        {hasPhoto ? t('common.action.change') : t('common.action.upload_image')}
+                        <input
+                          type="file"
+                          accept="image/png,image/jpeg,image/webp"
+                          className="hidden"
+                          disabled={isPhotoUploading}
+                          onChange={handlePhotoSelected}
+                        />
+                      </label>
+                      {hasPhoto && (
+                        <button
+                          type="button"
+                          onClick={handlePhotoRemove}
+                          disabled={isPhotoUploading}
+                          className="px-3 py-1.5 bg-white border border-red-200 rounded-lg text-sm font-medium text-red-600 hover:bg-red-50 flex items-center gap-1.5 disabled:opacity-50"
+                        >
+                          <Trash2 size={14} />
+                          {t('common.action.delete')}
+                        </button>
+                      )}
+                    </div>
+                    <p className="text-xs text-gray-500">{t('settings.user.form.photo_hint')}</p>
+                  </div>
+                </div>
+              </FormField>
+            )}
           </FormSection>
 
           {/* Advanced Section */}
@@ -20,6 +20,7 @@ export const ErrorCode = {
   SessionExpired: 1005,
   AccountLocked: 1006,
   AccountDisabled: 1007,
+  SessionLocked: 1008,
 
   // 2xxx: Permission
   PermissionDenied: 2001,
@@ -117,6 +118,7 @@ export const ErrorCode = {
   MarketingGroupNotFound: 6601,
   LabelTemplateNotFound: 6701,
   PriceRuleNotFound: 6801,
+  PriceRuleConflict: 6803,
 
   // 7xxx: Table
   TableNotFound: 7001,
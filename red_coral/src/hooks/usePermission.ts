@@ -5,8 +5,10 @@
  * throughout the application.
  */
 
+import { useEffect, useState } from 'react';
 import { Permission as PermissionValues } from '@/core/domain/types';
 import { useHasPermission, useHasRole } from '@/core/stores/auth/useAuthStore';
+import { invokeApi } from '@/infrastructure/api/tauri-client';
 
 /**
  * General permission hook
@@ -68,4 +70,43 @@ export const useCanManageShifts = () => {
   return hasPermission(PermissionValues.SHIFTS_MANAGE);
 };
 
+// ==================== Command Gating ====================
 
+/** Tauri 命令 → 所需权限，与 ClientBridge 的拦截表一致 (进程内不变，只拉取一次) */
+let commandPermissionsPromise: Promise<Record<string, string>> | null = null;
+
+const loadCommandPermissions = () => {
+  commandPermissionsPromise ??= invokeApi<Record<string, string>>('get_command_permissions').catch(() => {
+    commandPermissionsPromise = null;
+    return {};
+  });
+  return commandPermissionsPromise;
+};
+
+/**
+ * Check if the current user may invoke a gated Tauri command
+ *
+ * Uses the same permission table the bridge enforces, so hidden/disabled
+ * buttons match the PERMISSION_DENIED the command would return.
+ *
+ * @example
+ * const canExport = useCanInvoke('export_data');
+ */
+export const useCanInvoke = (command: string) => {
+  const { hasPermission } = usePermission();
+  const [required, setRequired] = useState<string | null | undefined>(undefined);
+
+  useEffect(() => {
+    let cancelled = false;
+    loadCommandPermissions().then((map) => {
+      if (!cancelled) setRequired(map[command] ?? null);
+    });
+    return () => {
+      cancelled = true;
+    };
+  }, [command]);
+
+  // 表未加载前按无权限处理，避免按钮闪现
+  if (required === undefined) return false;
+  return required === null || hasPermission(required);
+};
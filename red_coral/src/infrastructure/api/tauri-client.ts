@@ -26,12 +26,44 @@ import type {
   ProductCreate,
   ProductUpdate,
   ProductFull,
+  UpsellSuggestion,
+  EightySix,
+  EightySixCreate,
+  StockLevel,
+  StockLevelCreate,
+  StockLevelUpdate,
+  StockAdjustment,
+  StockAdjustmentCreate,
+  StockCount,
+  StockCountCreate,
+  StockCountEntry,
+  StockCountLine,
+  StockCountReview,
+  StockCountScan,
+  StockTransfer,
+  StockTransferCreate,
+  StockTransferReceive,
+  TransferPeer,
+  SupportSession,
+  SupportSessionOpen,
+  SupportSessionView,
+  SupportMessage,
   Zone,
+  ZoneProductOverride,
+  ZoneProductOverrideUpsert,
   Table,
   PrintDestination,
   PrintDestinationCreate,
   PrintDestinationUpdate,
   PrintConfig,
+  RoutingMatrix,
+  PickupTicket,
+  PickupCreate,
+  Capabilities,
+  SetupStatus,
+  SetupStepSubmit,
+  StampCampaignStats,
+  CampaignRoiReport,
   Attribute,
   AttributeCreate,
   AttributeUpdate,
@@ -43,10 +75,30 @@ import type {
   PriceRule,
   PriceRuleCreate,
   PriceRuleUpdate,
+  PriceRuleConflict,
+  DeliveryRule,
+  DeliveryRuleCreate,
+  DeliveryRuleUpdate,
+  DeliveryQuote,
+  ReceiptArtifact,
+  TerminalProfile,
+  TerminalProfileInfo,
+  CustomerDisplay,
+  CustomerDisplayCreate,
+  CustomerDisplayUpdate,
+  CatalogChange,
   CreateProductAttributeRequest,
   CreateCategoryAttributeRequest,
   StoreInfo,
   StoreInfoUpdate,
+  ReceiptFooter,
+  ReceiptFooterCreate,
+  ReceiptFooterUpdate,
+  ResolvedReceiptFooter,
+  TableGroup,
+  DisplaySlide,
+  DisplaySlideCreate,
+  DisplaySlideUpdate,
   LabelTemplate,
   LabelTemplateCreate,
   LabelTemplateUpdate,
@@ -55,14 +107,24 @@ import type {
   ShiftClose,
   ShiftForceClose,
   ShiftUpdate,
+  ShiftBreak,
+  ShiftBreakStart,
+  ShiftBreakStatus,
+  CashDrawerEvent,
+  CashDrawerEventCreate,
+  ShiftCashDrawer,
+  KpiSnapshot,
   DailyReport,
   DailyReportGenerate,
   AuditListResponse,
   KitchenOrderListResponse,
+  PrintJob,
+  PrintJobStatus,
   LabelPrintRecord,
   SystemIssue,
   ResolveSystemIssueRequest,
 } from '@/core/domain/types/api';
+import type { ArchivedOrderSearchQuery, ArchivedOrderSearchResult } from '@/core/domain/types';
 
 // API Error class - aligned with shared::error::ErrorCode (u16)
 export class ApiError extends Error {
@@ -252,6 +314,157 @@ export class TauriApiClient {
     await invokeApi<void>('batch_update_product_sort_order', { updates });
   }
 
+  /** 加菜时的搭配推荐 (每营业日自动重算) */
+  async getUpsellSuggestions(productId: number, limit = 3): Promise<UpsellSuggestion[]> {
+    return invokeApi<UpsellSuggestion[]>('api_get', {
+      path: `/api/products/${productId}/upsell?limit=${limit}`,
+    });
+  }
+
+  async recomputeUpsell(): Promise<number> {
+    return invokeApi<number>('api_post', { path: '/api/products/upsell/recompute' });
+  }
+
+  // ============ 86 Board (临时沽清) ============
+
+  async listEightySix(): Promise<EightySix[]> {
+    return invokeApi<EightySix[]>('api_get', { path: '/api/eighty-six' });
+  }
+
+  /** 沽清记录（含已恢复/已过期），默认最近 24 小时 */
+  async listEightySixHistory(from?: number, to?: number): Promise<EightySix[]> {
+    const params = new URLSearchParams();
+    if (from !== undefined) params.set('from', String(from));
+    if (to !== undefined) params.set('to', String(to));
+    const query = params.toString();
+    return invokeApi<EightySix[]>('api_get', {
+      path: `/api/eighty-six/history${query ? `?${query}` : ''}`,
+    });
+  }
+
+  async createEightySix(data: EightySixCreate): Promise<EightySix> {
+    return invokeApi<EightySix>('api_post', { path: '/api/eighty-six', body: data });
+  }
+
+  async clearEightySix(id: number): Promise<EightySix> {
+    return invokeApi<EightySix>('api_delete', { path: `/api/eighty-six/${id}` });
+  }
+
+  // ============ Inventory (库存) ============
+
+  async listStockLevels(): Promise<StockLevel[]> {
+    return invokeApi<StockLevel[]>('list_stock_levels');
+  }
+
+  async listLowStock(): Promise<StockLevel[]> {
+    return invokeApi<StockLevel[]>('list_low_stock');
+  }
+
+  async createStockLevel(data: StockLevelCreate): Promise<StockLevel> {
+    return invokeApi<StockLevel>('create_stock_level', { data });
+  }
+
+  async updateStockLevel(id: number, data: StockLevelUpdate): Promise<StockLevel> {
+    return invokeApi<StockLevel>('update_stock_level', { id, data });
+  }
+
+  async deleteStockLevel(id: number): Promise<void> {
+    await invokeApi<void>('delete_stock_level', { id });
+  }
+
+  /** 手动调整 (进货、报损) */
+  async adjustStock(id: number, data: StockAdjustmentCreate): Promise<StockLevel> {
+    return invokeApi<StockLevel>('adjust_stock', { id, data });
+  }
+
+  /** 调整记录 (最新在前) */
+  async listStockAdjustments(id: number, limit?: number): Promise<StockAdjustment[]> {
+    return invokeApi<StockAdjustment[]>('list_stock_adjustments', { id, limit });
+  }
+
+  // ============ Stock Counts (盘点) ============
+
+  /** 盘点单 (最新在前，不含明细) */
+  async listStockCounts(limit?: number): Promise<StockCount[]> {
+    return invokeApi<StockCount[]>('list_stock_counts', { limit });
+  }
+
+  async getStockCount(id: number): Promise<StockCount> {
+    return invokeApi<StockCount>('get_stock_count', { id });
+  }
+
+  /** 开始盘点 (冻结理论数量) */
+  async startStockCount(data: StockCountCreate): Promise<StockCount> {
+    return invokeApi<StockCount>('start_stock_count', { data });
+  }
+
+  async recordStockCount(id: number, stockLevelId: number, data: StockCountEntry): Promise<StockCountLine> {
+    return invokeApi<StockCountLine>('record_stock_count', { id, stockLevelId, data });
+  }
+
+  /** 扫码计数 (累加) */
+  async scanStockCount(id: number, data: StockCountScan): Promise<StockCountLine> {
+    return invokeApi<StockCountLine>('scan_stock_count', { id, data });
+  }
+
+  async submitStockCount(id: number): Promise<StockCount> {
+    return invokeApi<StockCount>('submit_stock_count', { id });
+  }
+
+  /** 审批通过，过账差异 (需要 inventory:approve_count) */
+  async approveStockCount(id: number, data: StockCountReview): Promise<StockCount> {
+    return invokeApi<StockCount>('approve_stock_count', { id, data });
+  }
+
+  async rejectStockCount(id: number, data: StockCountReview): Promise<StockCount> {
+    return invokeApi<StockCount>('reject_stock_count', { id, data });
+  }
+
+  // ============ Stock Transfers (门店间调拨) ============
+
+  /** 调拨单 (发出 + 收到，最新在前，不含明细) */
+  async listStockTransfers(limit?: number): Promise<StockTransfer[]> {
+    return invokeApi<StockTransfer[]>('list_stock_transfers', { limit });
+  }
+
+  /** 可接收调拨的同租户门店 (cloud 未连接时为空) */
+  async listTransferPeers(): Promise<TransferPeer[]> {
+    return invokeApi<TransferPeer[]>('list_transfer_peers');
+  }
+
+  async getStockTransfer(id: number): Promise<StockTransfer> {
+    return invokeApi<StockTransfer>('get_stock_transfer', { id });
+  }
+
+  /** 发货 (过账出库，在途) */
+  async sendStockTransfer(data: StockTransferCreate): Promise<StockTransfer> {
+    return invokeApi<StockTransfer>('send_stock_transfer', { data });
+  }
+
+  /** 收货确认 (过账入库，通知发货门店) */
+  async receiveStockTransfer(id: number, data: StockTransferReceive): Promise<StockTransfer> {
+    return invokeApi<StockTransfer>('receive_stock_transfer', { id, data });
+  }
+
+  // ============ Remote Support (远程支持) ============
+
+  /** 当前支持会话 (无会话时为 null) */
+  async getSupportSession(): Promise<SupportSessionView | null> {
+    return invokeApi<SupportSessionView | null>('get_support_session');
+  }
+
+  async openSupportSession(data: SupportSessionOpen): Promise<SupportSession> {
+    return invokeApi<SupportSession>('open_support_session', { data });
+  }
+
+  async sendSupportMessage(text: string): Promise<SupportMessage> {
+    return invokeApi<SupportMessage>('send_support_message', { data: { text } });
+  }
+
+  async closeSupportSession(): Promise<void> {
+    await invokeApi<void>('close_support_session');
+  }
+
   // ============ Product Attributes ============
 
   async fetchProductAttributes(productId: number): Promise<AttributeBindingFull[]> {
@@ -304,11 +517,11 @@ export class TauriApiClient {
 
   // ============ Attribute Options ============
 
-  async addAttributeOption(attributeId: number, data: { name: string; value_code?: string; price_modifier?: number; is_default?: boolean; display_order?: number; is_active?: boolean; receipt_name?: string; kitchen_print_name?: string; enable_quantity?: boolean; max_quantity?: number | null }): Promise<Attribute> {
+  async addAttributeOption(attributeId: number, data: { name: string; value_code?: string; price_modifier?: number; is_default?: boolean; display_order?: number; is_active?: boolean; receipt_name?: string; kitchen_print_name?: string; enable_quantity?: boolean; max_quantity?: number | null; stock_level_id?: number | null }): Promise<Attribute> {
     return invokeApi<Attribute>('add_attribute_option', { attributeId, data });
   }
 
-  async updateAttributeOption(attributeId: number, index: number, data: { name?: string; value_code?: string; price_modifier?: number; is_default?: boolean; display_order?: number; is_active?: boolean; receipt_name?: string; kitchen_print_name?: string; enable_quantity?: boolean; max_quantity?: number | null }): Promise<Attribute> {
+  async updateAttributeOption(attributeId: number, index: number, data: { name?: string; value_code?: string; price_modifier?: number; is_default?: boolean; display_order?: number; is_active?: boolean; receipt_name?: string; kitchen_print_name?: string; enable_quantity?: boolean; max_quantity?: number | null; stock_level_id?: number | null }): Promise<Attribute> {
     return invokeApi<Attribute>('update_attribute_option', { attributeId, index, data });
   }
 
@@ -334,6 +547,18 @@ export class TauriApiClient {
     await invokeApi<void>('delete_zone', { id });
   }
 
+  async listZoneOverrides(zoneId: number): Promise<ZoneProductOverride[]> {
+    return invokeApi<ZoneProductOverride[]>('list_zone_overrides', { zoneId });
+  }
+
+  async setZoneOverride(zoneId: number, productId: number, data: ZoneProductOverrideUpsert): Promise<ZoneProductOverride> {
+    return invokeApi<ZoneProductOverride>('set_zone_override', { zoneId, productId, data });
+  }
+
+  async deleteZoneOverride(zoneId: number, productId: number): Promise<void> {
+    await invokeApi<void>('delete_zone_override', { zoneId, productId });
+  }
+
   // ============ Tables ============
 
   async listTables(): Promise<Table[]> {
@@ -352,6 +577,26 @@ export class TauriApiClient {
     await invokeApi<void>('delete_table', { id });
   }
 
+  async listTableGroups(): Promise<TableGroup[]> {
+    return invokeApi<TableGroup[]>('api_get', { path: '/api/tables/groups' });
+  }
+
+  /** 拼桌：将 tableIds 并入主桌 hostTableId */
+  async joinTables(hostTableId: number, tableIds: number[]): Promise<TableGroup> {
+    return invokeApi<TableGroup>('api_post', {
+      path: `/api/tables/${hostTableId}/join`,
+      body: { table_ids: tableIds },
+    });
+  }
+
+  /** 拆桌：tableIds 为空时完全拆开（返回 null） */
+  async splitTables(hostTableId: number, tableIds: number[] = []): Promise<TableGroup | null> {
+    return invokeApi<TableGroup | null>('api_post', {
+      path: `/api/tables/${hostTableId}/split`,
+      body: { table_ids: tableIds },
+    });
+  }
+
   // ============ Print Destinations ============
 
   async listPrintDestinations(): Promise<PrintDestination[]> {
@@ -380,6 +625,71 @@ export class TauriApiClient {
     return invokeApi<PrintConfig>('api_put', { path: '/api/print-config', body: config });
   }
 
+  /** 打印路由矩阵：每个商品的实际厨房 / 标签打印目标及来源 */
+  async getRoutingMatrix(): Promise<RoutingMatrix> {
+    return invokeApi<RoutingMatrix>('api_get', { path: '/api/print/routing_matrix' });
+  }
+
+  // ============ Pickup ============
+
+  /** 外卖取餐单：登记取餐码 (可选手机号，出餐时发送 SMS / WhatsApp) */
+  async registerPickup(data: PickupCreate): Promise<PickupTicket> {
+    return invokeApi<PickupTicket>('api_post', { path: '/api/pickup', body: data });
+  }
+
+  async listActivePickups(): Promise<PickupTicket[]> {
+    return invokeApi<PickupTicket[]>('api_get', { path: '/api/pickup' });
+  }
+
+  async getPickupByOrder(orderId: number): Promise<PickupTicket> {
+    return invokeApi<PickupTicket>('api_get', { path: `/api/pickup/order/${orderId}` });
+  }
+
+  async markPickupReady(id: number): Promise<PickupTicket> {
+    return invokeApi<PickupTicket>('api_post', { path: `/api/pickup/${id}/ready`, body: {} });
+  }
+
+  async resendPickupMessage(id: number): Promise<PickupTicket> {
+    return invokeApi<PickupTicket>('api_post', { path: `/api/pickup/${id}/resend`, body: {} });
+  }
+
+  async collectPickup(id: number): Promise<PickupTicket> {
+    return invokeApi<PickupTicket>('api_post', { path: `/api/pickup/${id}/collect`, body: {} });
+  }
+
+  // ============ Capabilities ============
+
+  /** 功能发现：编译模块、计划权益、协议范围、税务申报模式 */
+  async getCapabilities(): Promise<Capabilities> {
+    return invokeApi<Capabilities>('api_get', { path: '/api/capabilities' });
+  }
+
+  // ============ Setup Wizard (首次安装向导) ============
+
+  async getSetupSteps(): Promise<SetupStatus> {
+    return invokeApi<SetupStatus>('api_get', { path: '/api/setup/steps' });
+  }
+
+  /** 确认 / 跳过步骤 (校验未通过时返回错误) */
+  async submitSetupStep(data: SetupStepSubmit): Promise<SetupStatus> {
+    return invokeApi<SetupStatus>('api_post', { path: '/api/setup/steps', body: data });
+  }
+
+  // ============ Marketing Reports ============
+
+  /** 集章活动效果 (时间范围 Unix millis) */
+  async getStampCampaignStats(from: number, to: number): Promise<StampCampaignStats[]> {
+    return invokeApi<StampCampaignStats[]>('api_get', {
+      path: `/api/statistics/stamp-campaigns?from=${from}&to=${to}`,
+    });
+  }
+
+  async getCampaignRoi(from: number, to: number): Promise<CampaignRoiReport> {
+    return invokeApi<CampaignRoiReport>('api_get', {
+      path: `/api/statistics/campaign-roi?from=${from}&to=${to}`,
+    });
+  }
+
   // ============ Employees ============
 
   async listEmployees(): Promise<Employee[]> {
@@ -394,6 +704,18 @@ export class TauriApiClient {
     return invokeApi<Employee>('update_employee', { id, data });
   }
 
+  /** 上传 / 替换员工照片 (登录与主管授权时显示) */
+  async uploadEmployeePhoto(id: number, contentBase64: string): Promise<Employee> {
+    return invokeApi<Employee>('api_put', {
+      path: `/api/employees/${id}/photo`,
+      body: { content_base64: contentBase64 },
+    });
+  }
+
+  async deleteEmployeePhoto(id: number): Promise<Employee> {
+    return invokeApi<Employee>('api_delete', { path: `/api/employees/${id}/photo` });
+  }
+
   async deleteEmployee(id: number): Promise<void> {
     await invokeApi<void>('delete_employee', { id });
   }
@@ -408,6 +730,64 @@ export class TauriApiClient {
     return invokeApi<StoreInfo>('update_store_info', { data });
   }
 
+  // ============ Receipt Footers (收据页脚促销) ============
+
+  async listReceiptFooters(): Promise<ReceiptFooter[]> {
+    return invokeApi<ReceiptFooter[]>('api_get', { path: '/api/receipt-footers' });
+  }
+
+  async createReceiptFooter(data: ReceiptFooterCreate): Promise<ReceiptFooter> {
+    return invokeApi<ReceiptFooter>('api_post', { path: '/api/receipt-footers', body: data });
+  }
+
+  async updateReceiptFooter(id: number, data: ReceiptFooterUpdate): Promise<ReceiptFooter> {
+    return invokeApi<ReceiptFooter>('api_put', { path: `/api/receipt-footers/${id}`, body: data });
+  }
+
+  async deleteReceiptFooter(id: number): Promise<boolean> {
+    return invokeApi<boolean>('api_delete', { path: `/api/receipt-footers/${id}` });
+  }
+
+  /** 调查码签名密钥（配置到调查网站用于校验调查码） */
+  async getSurveySecret(): Promise<string> {
+    return invokeApi<string>('api_get', { path: '/api/receipt-footers/survey-secret' });
+  }
+
+  async rotateSurveySecret(): Promise<string> {
+    return invokeApi<string>('api_post', { path: '/api/receipt-footers/survey-secret/rotate' });
+  }
+
+  /** 当天生效的页脚；receiptNumber 为空时不生成调查码（预付单） */
+  async resolveReceiptFooter(receiptNumber?: string): Promise<ResolvedReceiptFooter | null> {
+    const query = receiptNumber ? `?receipt_number=${encodeURIComponent(receiptNumber)}` : '';
+    return invokeApi<ResolvedReceiptFooter | null>('api_get', {
+      path: `/api/receipt-footers/resolve${query}`,
+    });
+  }
+
+  // ============ Display Slides (客显屏轮播) ============
+
+  async listDisplaySlides(): Promise<DisplaySlide[]> {
+    return invokeApi<DisplaySlide[]>('api_get', { path: '/api/display-slides' });
+  }
+
+  /** 当前排期内的幻灯片（按播放顺序，排期由服务端按门店时区计算） */
+  async getDisplayPlaylist(): Promise<DisplaySlide[]> {
+    return invokeApi<DisplaySlide[]>('api_get', { path: '/api/display-slides/playlist' });
+  }
+
+  async createDisplaySlide(data: DisplaySlideCreate): Promise<DisplaySlide> {
+    return invokeApi<DisplaySlide>('api_post', { path: '/api/display-slides', body: data });
+  }
+
+  async updateDisplaySlide(id: number, data: DisplaySlideUpdate): Promise<DisplaySlide> {
+    return invokeApi<DisplaySlide>('api_put', { path: `/api/display-slides/${id}`, body: data });
+  }
+
+  async deleteDisplaySlide(id: number): Promise<boolean> {
+    return invokeApi<boolean>('api_delete', { path: `/api/display-slides/${id}` });
+  }
+
   // ============ Label Templates ============
 
   async listLabelTemplates(): Promise<LabelTemplate[]> {
@@ -448,6 +828,84 @@ export class TauriApiClient {
     await invokeApi<void>('delete_price_rule', { id });
   }
 
+  async listPriceRuleConflicts(): Promise<PriceRuleConflict[]> {
+    return invokeApi<PriceRuleConflict[]>('api_get', { path: '/api/pricing/conflicts' });
+  }
+
+  // ============ Delivery Rules (外卖渠道起送价 / 配送费) ============
+
+  async listDeliveryRules(): Promise<DeliveryRule[]> {
+    return invokeApi<DeliveryRule[]>('api_get', { path: '/api/delivery-rules' });
+  }
+
+  async createDeliveryRule(data: DeliveryRuleCreate): Promise<DeliveryRule> {
+    return invokeApi<DeliveryRule>('api_post', { path: '/api/delivery-rules', body: data });
+  }
+
+  async updateDeliveryRule(id: number, data: DeliveryRuleUpdate): Promise<DeliveryRule> {
+    return invokeApi<DeliveryRule>('api_put', { path: `/api/delivery-rules/${id}`, body: data });
+  }
+
+  async deleteDeliveryRule(id: number): Promise<void> {
+    await invokeApi<boolean>('api_delete', { path: `/api/delivery-rules/${id}` });
+  }
+
+  /** 购物车起送价 / 配送费提示 ("再加 €3 免配送费") */
+  async getDeliveryQuote(channel: string, subtotal: number, zone?: string): Promise<DeliveryQuote> {
+    const params = new URLSearchParams({ channel, subtotal: String(subtotal) });
+    if (zone) params.set('zone', zone);
+    return invokeApi<DeliveryQuote>('api_get', {
+      path: `/api/public/delivery/quote?${params.toString()}`,
+    });
+  }
+
+  // ============ Receipt Archive (收据存档) ============
+
+  /** 收据的存档原文列表 (含分单收据)；原文下载: /api/receipt-archive/artifacts/{id}/content */
+  async listReceiptArtifacts(receiptNumber: string): Promise<ReceiptArtifact[]> {
+    return invokeApi<ReceiptArtifact[]>('api_get', {
+      path: `/api/receipt-archive/${encodeURIComponent(receiptNumber)}`,
+    });
+  }
+
+  // ============ Terminal Profile (终端漫游配置) ============
+
+  /** 握手时 edge-server 下发的本终端配置 (Server 模式 / 未连接时 identity 为 null) */
+  async getTerminalProfile(): Promise<TerminalProfileInfo> {
+    return invokeApi<TerminalProfileInfo>('get_terminal_profile');
+  }
+
+  /** 保存本终端配置 (整体覆盖) */
+  async saveTerminalProfile(identity: string, settings: Record<string, unknown>): Promise<TerminalProfile> {
+    return invokeApi<TerminalProfile>('api_put', {
+      path: `/api/terminal-profiles/${encodeURIComponent(identity)}`,
+      body: { settings },
+    });
+  }
+
+  // ============ Customer Display (顾客显示屏) ============
+
+  async listCustomerDisplays(): Promise<CustomerDisplay[]> {
+    return invokeApi<CustomerDisplay[]>('api_get', { path: '/api/customer-displays' });
+  }
+
+  async createCustomerDisplay(data: CustomerDisplayCreate): Promise<CustomerDisplay> {
+    return invokeApi<CustomerDisplay>('api_post', { path: '/api/customer-displays', body: data });
+  }
+
+  async updateCustomerDisplay(id: number, data: CustomerDisplayUpdate): Promise<CustomerDisplay> {
+    return invokeApi<CustomerDisplay>('api_put', { path: `/api/customer-displays/${id}`, body: data });
+  }
+
+  async deleteCustomerDisplay(id: number): Promise<boolean> {
+    return invokeApi<boolean>('api_delete', { path: `/api/customer-displays/${id}` });
+  }
+
+  /** 在客显上显示名称与待机文字 */
+  async testCustomerDisplay(id: number): Promise<boolean> {
+    return invokeApi<boolean>('api_post', { path: `/api/customer-displays/${id}/test` });
+  }
+
   // ============ Roles ============
 
   async listRoles(): Promise<Role[]> {
@@ -496,6 +954,34 @@ export class TauriApiClient {
     return invokeApi<Shift[]>('recover_stale_shifts');
   }
 
+  async getShiftBreaks(id: number): Promise<ShiftBreakStatus> {
+    return invokeApi<ShiftBreakStatus>('get_shift_breaks', { id });
+  }
+
+  async getKpiSnapshot(): Promise<KpiSnapshot> {
+    return invokeApi<KpiSnapshot>('get_kpi_snapshot');
+  }
+
+  async startShiftBreak(id: number, data?: ShiftBreakStart): Promise<ShiftBreak> {
+    return invokeApi<ShiftBreak>('start_shift_break', { id, data: data ?? {} });
+  }
+
+  async endShiftBreak(id: number): Promise<ShiftBreak> {
+    return invokeApi<ShiftBreak>('end_shift_break', { id });
+  }
+
+  // ============ Cash Drawer (钱箱管理) ============
+
+  /** 上报钱箱开启 / 存取现金（存取计入当前班次预期现金） */
+  async recordCashDrawerEvent(data: CashDrawerEventCreate): Promise<CashDrawerEvent> {
+    return invokeApi<CashDrawerEvent>('api_post', { path: '/api/cash-drawer/events', body: data });
+  }
+
+  /** 班次钱箱明细 + 汇总（收班对账） */
+  async getShiftCashDrawer(shiftId: number): Promise<ShiftCashDrawer> {
+    return invokeApi<ShiftCashDrawer>('api_get', { path: `/api/cash-drawer/shifts/${shiftId}` });
+  }
+
   // ============ Daily Reports (日结报告) ============
 
   async listDailyReports(params?: { limit?: number; offset?: number; startDate?: string; endDate?: string }): Promise<DailyReport[]> {
@@ -549,6 +1035,18 @@ export class TauriApiClient {
     });
   }
 
+  // ============ Order Search (客服查单) ============
+
+  async searchOrders(query: ArchivedOrderSearchQuery): Promise<ArchivedOrderSearchResult[]> {
+    const params = new URLSearchParams();
+    for (const [key, value] of Object.entries(query)) {
+      if (value !== undefined && value !== '') params.set(key, String(value));
+    }
+    return invokeApi<ArchivedOrderSearchResult[]>('api_get', {
+      path: `/api/orders/search?${params.toString()}`,
+    });
+  }
+
   // ============ Kitchen Orders (厨房补打) ============
 
   async getKitchenOrdersForOrder(orderId: number): Promise<KitchenOrderListResponse> {
@@ -564,6 +1062,43 @@ export class TauriApiClient {
     });
   }
 
+  async listPrintJobs(status?: PrintJobStatus): Promise<PrintJob[]> {
+    const path = status ? `/api/print-jobs?status=${status}` : '/api/print-jobs';
+    return invokeApi<PrintJob[]>('api_get', { path });
+  }
+
+  async reprintPrintJob(id: number): Promise<boolean> {
+    return invokeApi<boolean>('api_post', {
+      path: `/api/print-jobs/${id}/reprint`,
+      body: {},
+    });
+  }
+
+  async cancelPrintJob(id: number): Promise<boolean> {
+    return invokeApi<boolean>('api_delete', { path: `/api/print-jobs/${id}` });
+  }
+
+  // ============ Catalog History (目录变更历史) ============
+
+  /** resource: 'products' | 'categories' | 'tags' | 'price-rules' */
+  async getCatalogHistory(resource: string, id: number): Promise<CatalogChange[]> {
+    return invokeApi<CatalogChange[]>('api_get', { path: `/api/${resource}/${id}/history` });
+  }
+
+  async revertProduct(id: number, changeId: number): Promise<ProductFull> {
+    return invokeApi<ProductFull>('api_post', {
+      path: `/api/products/${id}/history/${changeId}/revert`,
+      body: {},
+    });
+  }
+
+  async revertPriceRule(id: number, changeId: number): Promise<PriceRule> {
+    return invokeApi<PriceRule>('api_post', {
+      path: `/api/price-rules/${id}/history/${changeId}/revert`,
+      body: {},
+    });
+  }
+
   // ============ Label Records (标签补打) ============
 
   async getLabelRecordsForOrder(orderId: number): Promise<LabelPrintRecord[]> {
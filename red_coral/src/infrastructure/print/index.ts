@@ -2,5 +2,5 @@
  * Print Service
  */
 
-export { openCashDrawer, listPrinters, printReceipt } from './printService';
-export type { ReceiptData, ReceiptItem, ReceiptStoreInfo, ReceiptSurchargeInfo, ReceiptSelectedOption } from './printService';
+export { openCashDrawer, listPrinters, printReceipt, printReceipts } from './printService';
+export type { ReceiptData, ReceiptItem, ReceiptStoreInfo, ReceiptSurchargeInfo, ReceiptSelectedOption, ReceiptPayment, SubReceipt } from './printService';
@@ -5,11 +5,13 @@
  * - listPrinters: 获取本地驱动打印机列表
  * - openCashDrawer: 打开钱箱
  * - printReceipt: 打印收据
+ * - printReceipts: 批量打印收据（单个打印任务）
  */
 
 import { invoke } from '@tauri-apps/api/core';
 import { logger } from '@/utils/logger';
 import { t } from '@/infrastructure/i18n';
+import type { ResolvedReceiptFooter } from '@/core/domain/types/api';
 
 /** API 响应格式 */
 interface ApiResponse<T> {
@@ -29,9 +31,12 @@ export interface ReceiptStoreInfo {
   website: string | null;
   logo_url: string | null;
   currency_symbol: string | null;
+  currency_decimal_places: number | null;
   receipt_header: string | null;
   receipt_footer: string | null;
   receipt_locale: string | null;
+  /** 支付方式附加费说明 */
+  payment_surcharge_disclaimer: string | null;
 }
 
 export interface ReceiptSurchargeInfo {
@@ -75,6 +80,22 @@ export interface ReceiptItem {
   selected_options: ReceiptSelectedOption[] | null;
   spec_name: string | null;
   is_comped: boolean;
+  /** 商品名译文 (locale → 名称)，缺失时打印原名 */
+  name_translations?: Record<string, string>;
+}
+
+export interface ReceiptPayment {
+  method: string;
+  amount: number;
+  tendered: number | null;
+  change: number | null;
+}
+
+/** 分单编号（关联主单号） */
+export interface SubReceipt {
+  master: string;
+  index: number;
+  total: number;
 }
 
 export interface ReceiptData {
@@ -88,14 +109,25 @@ export interface ReceiptData {
   void_reason: string | null;
   reprint: boolean;
   pre_payment: boolean;
+  /** 收据语言 (打印时选择 / 会员偏好)，null = 门店 receipt_locale */
+  locale: string | null;
   store_info: ReceiptStoreInfo | null;
   surcharge: ReceiptSurchargeInfo | null;
   discount: ReceiptDiscountInfo | null;
   rule_adjustments: ReceiptRuleAdjustment[];
   items: ReceiptItem[];
   total_amount: number;
+  /** 支付方式附加费合计 (已含在 total_amount 中) */
+  payment_surcharge_amount: number;
   queue_number: number | null;
   qr_data: string | null;
+  /** 页脚促销（服务端按排期解析） */
+  footer_promotion: ResolvedReceiptFooter | null;
+  /** 付款明细（为空则不打印） */
+  payments: ReceiptPayment[];
+  sub_receipt: SubReceipt | null;
+  /** 税控码（外部税控设备登记后返回） */
+  fiscal_code: string | null;
 }
 
 // ── Service Functions ──
@@ -139,11 +171,38 @@ export async function openCashDrawer(printerName?: string): Promise<void> {
  * 打印收据
  * @param printerName Windows 驱动打印机名称
  * @param receipt 收据数据
+ * @param archive 打印成功后把打印原文存档到服务端（门店开启收据存档时）
  */
-export async function printReceipt(printerName: string | null, receipt: ReceiptData): Promise<void> {
+export async function printReceipt(
+  printerName: string | null,
+  receipt: ReceiptData,
+  archive = false,
+): Promise<void> {
   const response = await invoke<ApiResponse<null>>('print_receipt', {
     printer_name: printerName,
     receipt,
+    archive,
+  });
+  if (response.code !== 0) {
+    throw new Error(response.message);
+  }
+}
+
+/**
+ * 批量打印收据（合并为一个打印任务，全部成功或全部失败）
+ * @param printerName Windows 驱动打印机名称
+ * @param receipts 收据数据列表
+ * @param archive 打印成功后逐张存档打印原文
+ */
+export async function printReceipts(
+  printerName: string | null,
+  receipts: ReceiptData[],
+  archive = false,
+): Promise<void> {
+  const response = await invoke<ApiResponse<null>>('print_receipts', {
+    printer_name: printerName,
+    receipts,
+    archive,
   });
   if (response.code !== 0) {
     throw new Error(response.message);
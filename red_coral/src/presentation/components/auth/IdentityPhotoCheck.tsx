@@ -0,0 +1,57 @@
+import React from 'react';
+import { UserCheck } from 'lucide-react';
+import { useI18n } from '@/hooks/useI18n';
+
+interface IdentityPhotoCheckProps {
+  /** 员工照片 (data URL) */
+  photo: string;
+  name: string;
+  onConfirm: () => void;
+  onReject: () => void;
+}
+
+/**
+ * 凭据使用人照片核对 (防代打卡)
+ *
+ * 登录 / 主管授权成功后显示凭据所属员工的照片，现场确认是本人再继续。
+ */
+export const IdentityPhotoCheck: React.FC<IdentityPhotoCheckProps> = ({
+  photo,
+  name,
+  onConfirm,
+  onReject,
+}) => {
+  const { t } = useI18n();
+
+  return (
+    <div className="p-6 flex flex-col items-center gap-4">
+      <img
+        src={photo}
+        alt={name}
+        className="w-40 h-40 rounded-2xl object-cover border-4 border-teal-100 shadow-lg"
+      />
+      <div className="text-center">
+        <p className="text-lg font-bold text-gray-800">{name}</p>
+        <p className="text-sm text-gray-500 mt-1">{t('auth.identity_check.hint')}</p>
+      </div>
+      <div className="w-full grid grid-cols-2 gap-3 pt-2">
+        <button
+          type="button"
+          onClick={onReject}
+          className="py-3 bg-white border border-gray-200 text-gray-700 rounded-xl font-semibold hover:bg-gray-50 transition-colors"
+        >
+          {t('auth.identity_check.reject')}
+        </button>
+        <button
+          type="button"
+          onClick={onConfirm}
+          autoFocus
+          className="py-3 bg-teal-600 text-white rounded-xl font-bold hover:bg-teal-700 transition-colors shadow-lg shadow-teal-600/20 flex items-center justify-center gap-2"
+        >
+          <UserCheck size={18} />
+          <span>{t('auth.identity_check.confirm')}</span>
+        </button>
+      </div>
+    </div>
+  );
+};
@@ -0,0 +1,203 @@
+/**
+ * Session Lock Screen
+ *
+ * 无操作自动锁屏 / 手动锁屏时显示的全屏遮罩。
+ * 后端 ClientBridge 负责计时并发出 "session-locked" 事件；遮罩只覆盖界面，
+ * 下方的点单页面和进行中的订单保持不变。
+ *
+ * - 当前员工输入密码解锁
+ * - 换人收银：另一名员工输入账号密码，保持连接直接切换
+ *
+ * 同时负责上报用户操作 (节流)，重置后端的自动锁屏计时。
+ */
+
+import React, { useEffect, useRef, useState } from 'react';
+import { invoke } from '@tauri-apps/api/core';
+import { listen } from '@tauri-apps/api/event';
+import { Lock, User as UserIcon, AlertCircle, Users } from 'lucide-react';
+import { invokeApi } from '@/infrastructure/api';
+import { useI18n } from '@/hooks/useI18n';
+import { logger } from '@/utils/logger';
+import { useAuthStore } from '@/core/stores/auth/useAuthStore';
+import { useBridgeStore, type EmployeeSession } from '@/core/stores/bridge/useBridgeStore';
+import type { User } from '@/core/domain/types';
+
+/** 操作上报节流间隔 */
+const ACTIVITY_REPORT_INTERVAL_MS = 30_000;
+
+const toUser = (session: EmployeeSession): User => ({
+  id: session.user_info.id,
+  username: session.user_info.username,
+  name: session.user_info.name,
+  role_id: session.user_info.role_id,
+  role_name: session.user_info.role_name,
+  permissions: session.user_info.permissions,
+  is_system: session.user_info.is_system,
+  is_active: session.user_info.is_active,
+  created_at: session.user_info.created_at,
+  photo: session.user_info.photo,
+});
+
+export const SessionLockScreen: React.FC = () => {
+  const { t } = useI18n();
+  const isAuthenticated = useAuthStore((state) => state.isAuthenticated);
+  const currentUser = useAuthStore((state) => state.user);
+  const [locked, setLocked] = useState(false);
+  const [switching, setSwitching] = useState(false);
+  const [username, setUsername] = useState('');
+  const [password, setPassword] = useState('');
+  const [error, setError] = useState<string | null>(null);
+  const [isLoading, setIsLoading] = useState(false);
+  const lastReportRef = useRef(0);
+
+  // 锁屏事件 + 启动时恢复锁屏状态
+  useEffect(() => {
+    invokeApi<boolean>('is_session_locked')
+      .then(setLocked)
+      .catch(() => {});
+    const unlistenPromise = listen<boolean>('session-locked', (event) => {
+      setLocked(event.payload);
+    });
+
+    return () => {
+      unlistenPromise.then((unlisten) => unlisten());
+    };
+  }, []);
+
+  // 上报用户操作 (节流)
+  useEffect(() => {
+    if (!isAuthenticated || locked) return;
+
+    const report = () => {
+      const now = Date.now();
+      if (now - lastReportRef.current < ACTIVITY_REPORT_INTERVAL_MS) return;
+      lastReportRef.current = now;
+      invoke('report_activity').catch(() => {});
+    };
+
+    window.addEventListener('pointerdown', report);
+    window.addEventListener('keydown', report);
+    return () => {
+      window.removeEventListener('pointerdown', report);
+      window.removeEventListener('keydown', report);
+    };
+  }, [isAuthenticated, locked]);
+
+  // 锁定状态变化时清空表单
+  useEffect(() => {
+    setSwitching(false);
+    setUsername('');
+    setPassword('');
+    setError(null);
+  }, [locked]);
+
+  if (!locked || !isAuthenticated) return null;
+
+  const handleSubmit = async (e: React.FormEvent) => {
+    e.preventDefault();
+    if (!password || (switching && !username)) return;
+
+    setIsLoading(true);
+    setError(null);
+
+    try {
+      const { unlockSession, switchCashier } = useBridgeStore.getState();
+      const session = switching
+        ? await switchCashier(username, password)
+        : await unlockSession(password);
+      if (session) {
+        useAuthStore.getState().setUser(toUser(session));
+      }
+      setLocked(false);
+    } catch (err) {
+      logger.error('Session unlock failed', err, { component: 'SessionLockScreen' });
+      setError(err instanceof Error ? err.message : t('auth.login.failed'));
+      setPassword('');
+    } finally {
+      setIsLoading(false);
+    }
+  };
+
+  return (
+    <div className="fixed inset-0 z-[150] flex items-center justify-center bg-black/70 backdrop-blur-md p-4">
+      <div className="bg-white rounded-2xl shadow-2xl max-w-sm w-full overflow-hidden">
+        <div className="p-6 flex flex-col items-center gap-2 bg-gray-50 border-b border-gray-100">
+          <div className="p-3 bg-gray-200 rounded-full text-gray-700">
+            <Lock size={28} />
+          </div>
+          <h2 className="text-xl font-bold text-gray-800">{t('auth.lock.title')}</h2>
+          <p className="text-sm text-gray-500">
+            {switching
+              ? t('auth.lock.switch_hint')
+              : t('auth.lock.hint', { name: currentUser?.name ?? '' })}
+          </p>
+        </div>
+
+        <form onSubmit={handleSubmit} className="p-6 space-y-4">
+          {error && (
+            <div className="p-3 bg-red-50 border border-red-100 rounded-xl flex items-start gap-3 text-red-600 text-sm">
+              <AlertCircle size={18} className="shrink-0 mt-0.5" />
+              <span>{error}</span>
+            </div>
+          )}
+
+          {switching && (
+            <div className="relative">
+              <div className="absolute left-3 top-1/2 -translate-y-1/2 text-gray-400">
+                <UserIcon size={18} />
+              </div>
+              <input
+                type="text"
+                value={username}
+                onChange={(e) => setUsername(e.target.value)}
+                className="w-full pl-10 pr-4 py-3 bg-gray-50 border border-gray-200 rounded-xl focus:ring-2 focus:ring-teal-500 focus:border-transparent outline-none transition-all"
+                placeholder={t('auth.login.username')}
+                autoFocus
+              />
+            </div>
+          )}
+
+          <div className="relative">
+            <div className="absolute left-3 top-1/2 -translate-y-1/2 text-gray-400">
+              <Lock size={18} />
+            </div>
+            <input
+              type="password"
+              value={password}
+              onChange={(e) => setPassword(e.target.value)}
+              className="w-full pl-10 pr-4 py-3 bg-gray-50 border border-gray-200 rounded-xl focus:ring-2 focus:ring-teal-500 focus:border-transparent outline-none transition-all"
+              placeholder={t('auth.login.password')}
+              autoFocus={!switching}
+            />
+          </div>
+
+          <button
+            type="submit"
+            disabled={isLoading || !password || (switching && !username)}
+            className="w-full py-3.5 bg-teal-600 text-white font-bold rounded-xl hover:bg-teal-700 active:scale-[0.98] transition-all disabled:opacity-50 disabled:cursor-not-allowed flex items-center justify-center gap-2"
+          >
+            {isLoading ? (
+              <div className="w-5 h-5 border-2 border-white/30 border-t-white rounded-full animate-spin" />
+            ) : (
+              <span>{switching ? t('auth.lock.switch_submit') : t('auth.lock.unlock')}</span>
+            )}
+          </button>
+
+          <button
+            type="button"
+            onClick={() => {
+              setSwitching((prev) => !prev);
+              setUsername('');
+              setPassword('');
+              setError(null);
+            }}
+            className="w-full py-2.5 text-sm font-medium text-gray-600 hover:text-gray-800 flex items-center justify-center gap-2"
+          >
+            <Users size={16} />
+            <span>{switching ? t('auth.lock.back_to_unlock') : t('auth.lock.switch_cashier')}</span>
+          </button>
+        </form>
+      </div>
+    </div>
+  );
+};
@@ -4,6 +4,7 @@ import { logger } from '@/utils/logger';
 import { X, Shield, Lock, User as UserIcon, AlertCircle } from 'lucide-react';
 import { useI18n } from '@/hooks/useI18n';
 import { User } from '@/core/domain/types';
+import { IdentityPhotoCheck } from './IdentityPhotoCheck';
 
 interface EscalateAuthorizer {
   id: number;
@@ -15,6 +16,8 @@ interface EscalateAuthorizer {
   is_system: boolean;
   is_active: boolean;
   created_at: number;
+  /** 授权人照片 (data URL) */
+  photo?: string;
 }
 
 interface SupervisorAuthModalProps {
@@ -37,9 +40,25 @@ export const SupervisorAuthModal: React.FC<SupervisorAuthModalProps> = ({
   const [password, setPassword] = useState('');
   const [error, setError] = useState<string | null>(null);
   const [isLoading, setIsLoading] = useState(false);
+  // 授权人有照片时先核对身份再放行
+  const [pendingSupervisor, setPendingSupervisor] = useState<User | null>(null);
 
   if (!isOpen) return null;
 
+  const complete = (supervisor: User) => {
+    setPendingSupervisor(null);
+    onSuccess(supervisor);
+    setPassword('');
+    onClose();
+  };
+
+  const handleRejectIdentity = () => {
+    logger.warn('Supervisor identity rejected at photo check', { component: 'SupervisorAuthModal' });
+    setPendingSupervisor(null);
+    setPassword('');
+    setError(t('auth.identity_check.rejected'));
+  };
+
   const handleSubmit = async (e: React.FormEvent) => {
     e.preventDefault();
     if (!username || !password) return;
@@ -66,11 +85,14 @@ export const SupervisorAuthModal: React.FC<SupervisorAuthModalProps> = ({
         is_active: authorizer.is_active,
         is_system: authorizer.is_system,
         created_at: authorizer.created_at,
+        photo: authorizer.photo,
       };
 
-      onSuccess(supervisor);
-      setPassword('');
-      onClose();
+      if (supervisor.photo) {
+        setPendingSupervisor(supervisor);
+      } else {
+        complete(supervisor);
+      }
     } catch (err) {
       logger.error('Supervisor auth failed', err);
       setError(typeof err === 'string' ? err : (err as Error).message || t('auth.login.failed'));
@@ -105,7 +127,15 @@ export const SupervisorAuthModal: React.FC<SupervisorAuthModalProps> = ({
           </button>
         </div>
 
-        {/* Form */}
+        {pendingSupervisor?.photo ? (
+          <IdentityPhotoCheck
+            photo={pendingSupervisor.photo}
+            name={pendingSupervisor.name}
+            onConfirm={() => complete(pendingSupervisor)}
+            onReject={handleRejectIdentity}
+          />
+        ) : (
+        /* Form */
         <form onSubmit={handleSubmit} className="p-6 space-y-4">
           {error && (
             <div className="p-3 bg-red-50 border border-red-100 rounded-xl flex items-start gap-3 text-red-600 text-sm">
@@ -168,6 +198,7 @@ export const SupervisorAuthModal: React.FC<SupervisorAuthModalProps> = ({
             </button>
           </div>
         </form>
+        )}
       </div>
     </div>
   );
@@ -26,14 +26,14 @@ export const OrderDetailModal: React.FC<OrderDetailModalProps> = ({
 
   const receiptPrinter = usePrinterStore((state) => state.receiptPrinter);
 
-  const handleReprint = async () => {
+  const handleReprint = async (locale?: string) => {
     if (!order) return;
     if (!receiptPrinter) {
       toast.warning(t('settings.printer.no_printer'));
       return;
     }
     try {
-      await reprintArchivedReceipt(order, receiptPrinter);
+      await reprintArchivedReceipt(order, receiptPrinter, locale);
       toast.success(t('common.message.receipt_print_success'));
     } catch (error) {
       toast.error(getErrorMessage(error));
@@ -1,5 +1,5 @@
 import React, { useState, useCallback } from 'react';
-import { HeldOrder } from '@/core/domain/types';
+import { HeldOrder, PaymentRecord } from '@/core/domain/types';
 import { CreditCard, ArrowLeft, Minus, Plus, Banknote, Users, PieChart, Lock as LockIcon, Check, Clock } from 'lucide-react';
 import { useI18n } from '@/hooks/useI18n';
 import { toast } from '@/presentation/components/Toast';
@@ -7,8 +7,9 @@ import { logger } from '@/utils/logger';
 import { localizedErrorMessage } from '@/utils/error/commandError';
 import { useRetailServiceType, toBackendServiceType } from '@/core/stores/order/useCheckoutStore';
 import { formatCurrency, Currency } from '@/utils/currency';
-import { openCashDrawer } from '@/core/services/order/paymentService';
+import { openCashDrawer, printDinerReceipts } from '@/core/services/order/paymentService';
 import { completeOrder, splitByAmount, startAaSplit, payAaSplit } from '@/core/stores/order/commands';
+import { usePrinterStore } from '@/core/stores/printer/usePrinterStore';
 import { CashPaymentModal } from './CashPaymentModal';
 import { PaymentSuccessModal } from './PaymentSuccessModal';
 import { OrderSidebar } from '@/presentation/components/OrderSidebar';
@@ -24,6 +25,7 @@ interface AmountSplitPageProps {
 export const AmountSplitPage: React.FC<AmountSplitPageProps> = ({ order, onBack, onComplete, onManageTable }) => {
   const { t } = useI18n();
   const serviceType = useRetailServiceType();
+  const receiptPrinter = usePrinterStore((state) => state.receiptPrinter);
 
   const totalPaid = order.paid_amount;
   const remaining = order.remaining_amount;
@@ -188,13 +190,13 @@ export const AmountSplitPage: React.FC<AmountSplitPageProps> = ({ order, onBack,
         }
 
         const tendered = method === 'CASH' ? cashDetails?.tendered : undefined;
+        const payShares = parseInt(aaPayStr) || 1;
+        const totalShares = isAALocked ? order.aa_total_shares! : (parseInt(aaTotalStr) || 2);
 
         if (splitMode === 'AA') {
-          const payShares = parseInt(aaPayStr) || 1;
           if (isAALocked) {
             await payAaSplit(order.order_id, payShares, method, tendered);
           } else {
-            const totalShares = parseInt(aaTotalStr) || 2;
             await startAaSplit(order.order_id, totalShares, payShares, method, tendered);
           }
         } else {
@@ -207,12 +209,35 @@ export const AmountSplitPage: React.FC<AmountSplitPageProps> = ({ order, onBack,
           await completeOrder(order.order_id, [], order.is_retail ? toBackendServiceType(serviceType) : null);
         }
 
+        // 结单后可按用餐者分开打印收据（最后一笔分单尚未进入订单快照）
+        let onPrint: (() => void) | undefined;
+        if (willComplete && receiptPrinter) {
+          const isAA = splitMode === 'AA';
+          const finalPayment: PaymentRecord = {
+            payment_id: Date.now(),
+            method,
+            amount,
+            timestamp: Date.now(),
+            tendered: tendered ?? null,
+            change: tendered !== undefined ? Currency.sub(tendered, amount).toNumber() : null,
+            split_type: isAA ? 'AA_SPLIT' : 'AMOUNT_SPLIT',
+            aa_shares: isAA ? payShares : null,
+          };
+          const capturedOrder = isAA ? { ...order, aa_total_shares: totalShares } : order;
+          onPrint = () => {
+            printDinerReceipts(capturedOrder, receiptPrinter, finalPayment).catch(() => {
+              toast.error(t('settings.payment.receipt_print_failed'));
+            });
+          };
+        }
+
         if (method === 'CASH' && cashDetails?.tendered !== undefined) {
           setSuccessModal({
             isOpen: true,
             type: 'CASH',
             change: Currency.sub(cashDetails.tendered, amount).toNumber(),
             onClose: willComplete ? handleComplete_cb : () => setSuccessModal(null),
+            onPrint,
             autoCloseDelay: willComplete && order.is_retail ? 0 : 10000,
           });
         } else if (willComplete) {
@@ -220,6 +245,7 @@ export const AmountSplitPage: React.FC<AmountSplitPageProps> = ({ order, onBack,
             isOpen: true,
             type: 'NORMAL',
             onClose: handleComplete_cb,
+            onPrint,
             autoCloseDelay: order.is_retail ? 0 : 10000,
           });
         }
@@ -236,7 +262,7 @@ export const AmountSplitPage: React.FC<AmountSplitPageProps> = ({ order, onBack,
         setIsProcessingAmountSplit(false);
       }
     },
-    [order, isProcessingAmountSplit, amountSplitValue, remaining, t, splitMode, aaPayStr, aaTotalStr, isAALocked, handleComplete_cb, serviceType]
+    [order, isProcessingAmountSplit, amountSplitValue, remaining, t, splitMode, aaPayStr, aaTotalStr, isAALocked, handleComplete_cb, serviceType, receiptPrinter]
   );
 
   const handleConfirmAmountSplitCash = useCallback(
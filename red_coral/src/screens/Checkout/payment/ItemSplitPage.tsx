@@ -1,5 +1,5 @@
 import React, { useState, useMemo, useCallback } from 'react';
-import { HeldOrder } from '@/core/domain/types';
+import { HeldOrder, PaymentRecord } from '@/core/domain/types';
 import { ArrowLeft, Split, Minus, Plus, Banknote, ShoppingBag, CreditCard } from 'lucide-react';
 import { useI18n } from '@/hooks/useI18n';
 import { toast } from '@/presentation/components/Toast';
@@ -8,8 +8,9 @@ import { localizedErrorMessage } from '@/utils/error/commandError';
 import { useRetailServiceType, toBackendServiceType } from '@/core/stores/order/useCheckoutStore';
 import { formatCurrency, Currency } from '@/utils/currency';
 import { getSpecName } from '@/utils/pricing';
-import { openCashDrawer } from '@/core/services/order/paymentService';
+import { openCashDrawer, printDinerReceipts } from '@/core/services/order/paymentService';
 import { completeOrder, splitByItems } from '@/core/stores/order/commands';
+import { usePrinterStore } from '@/core/stores/printer/usePrinterStore';
 import { CashPaymentModal } from './CashPaymentModal';
 import { PaymentSuccessModal } from './PaymentSuccessModal';
 import { OrderSidebar } from '@/presentation/components/OrderSidebar';
@@ -29,6 +30,7 @@ interface ItemSplitPageProps {
 export const ItemSplitPage: React.FC<ItemSplitPageProps> = ({ order, onBack, onComplete, onManageTable }) => {
   const { t } = useI18n();
   const serviceType = useRetailServiceType();
+  const receiptPrinter = usePrinterStore((state) => state.receiptPrinter);
 
   const totalPaid = order.paid_amount;
   const remaining = order.remaining_amount;
@@ -203,12 +205,37 @@ export const ItemSplitPage: React.FC<ItemSplitPageProps> = ({ order, onBack, onC
           await completeOrder(order.order_id, [], order.is_retail ? toBackendServiceType(serviceType) : null);
         }
 
+        // 结单后可按用餐者分开打印收据（最后一笔分单尚未进入订单快照）
+        let onPrint: (() => void) | undefined;
+        if (willComplete && receiptPrinter) {
+          const change = cashDetails ? Currency.sub(cashDetails.tendered, splitTotal).toNumber() : null;
+          const finalPayment: PaymentRecord = {
+            payment_id: Date.now(),
+            method,
+            amount: splitTotal,
+            timestamp: Date.now(),
+            tendered: cashDetails?.tendered ?? null,
+            change,
+            split_type: 'ITEM_SPLIT',
+            split_items: order.items
+              .filter((item) => (splitItems[item.instance_id] ?? 0) > 0)
+              .map((item) => ({ ...item, quantity: splitItems[item.instance_id] })),
+          };
+          const capturedOrder = order;
+          onPrint = () => {
+            printDinerReceipts(capturedOrder, receiptPrinter, finalPayment).catch(() => {
+              toast.error(t('settings.payment.receipt_print_failed'));
+            });
+          };
+        }
+
         if (method === 'CASH' && cashDetails?.tendered !== undefined) {
           setSuccessModal({
             isOpen: true,
             type: 'CASH',
             change: Currency.sub(cashDetails.tendered, splitTotal).toNumber(),
             onClose: willComplete ? handleComplete : () => setSuccessModal(null),
+            onPrint,
             autoCloseDelay: willComplete && order.is_retail ? 0 : 10000,
           });
         } else if (willComplete) {
@@ -216,6 +243,7 @@ export const ItemSplitPage: React.FC<ItemSplitPageProps> = ({ order, onBack, onC
             isOpen: true,
             type: 'NORMAL',
             onClose: handleComplete,
+            onPrint,
             autoCloseDelay: order.is_retail ? 0 : 10000,
           });
         }
@@ -232,7 +260,7 @@ export const ItemSplitPage: React.FC<ItemSplitPageProps> = ({ order, onBack, onC
         setIsProcessingSplit(false);
       }
     },
-    [order, isProcessingSplit, splitItems, remaining, t, handleComplete, serviceType]
+    [order, isProcessingSplit, splitItems, splitTotal, remaining, t, handleComplete, serviceType, receiptPrinter]
   );
 
   const handleConfirmSplitCash = useCallback(
@@ -68,6 +68,7 @@ vi.mock('@/core/stores/order/commands', () => ({
   updateOrderInfo: vi.fn(),
   redeemStamp: vi.fn(),
   cancelStampRedemption: vi.fn(),
+  redeemPoints: vi.fn(),
 }));
 
 vi.mock('@/core/stores/order/commands/sendCommand', () => ({
@@ -79,6 +80,7 @@ vi.mock('@/core/stores/order/commands/sendCommand', () => ({
 
 vi.mock('@/core/services/order/paymentService', () => ({
   openCashDrawer: vi.fn(),
+  printDinerReceipts: vi.fn(),
 }));
 
 // --- Feature mocks ---
@@ -93,6 +95,7 @@ vi.mock('@/features/category', () => ({
 
 vi.mock('@/features/member/mutations', () => ({
   getMemberDetail: vi.fn(),
+  getMemberPoints: vi.fn(),
   listMembers: vi.fn(),
   searchMembers: vi.fn(),
 }));
@@ -1,6 +1,6 @@
 import React, { useState, useMemo, useCallback, useRef, useEffect } from 'react';
 import { HeldOrder } from '@/core/domain/types';
-import { Coins, CreditCard, ArrowLeft, Printer, Trash2, Split, Banknote, Utensils, ShoppingBag, Receipt, Check, Gift, Percent, TrendingUp, ClipboardList, Archive, UserCheck, Stamp, X, Crown, LayoutGrid, Tag, MoreHorizontal, StickyNote } from 'lucide-react';
+import { Coins, CreditCard, ArrowLeft, Printer, Trash2, Split, Banknote, Utensils, ShoppingBag, Receipt, Check, Gift, Percent, TrendingUp, ClipboardList, Archive, UserCheck, Stamp, X, Crown, Sparkles, LayoutGrid, Tag, MoreHorizontal, StickyNote } from 'lucide-react';
 import { useI18n } from '@/hooks/useI18n';
 import { toast } from '@/presentation/components/Toast';
 import { logger } from '@/utils/logger';
@@ -28,6 +28,7 @@ import { StampRewardPickerModal } from './StampRewardPickerModal';
 import { StampRedeemModal } from './StampRedeemModal';
 import { getMatchingItems, getDesignatedMatchingItems } from '@/utils/stampMatching';
 import { useStampProgress } from './useStampProgress';
+import { usePointsRedemption } from './usePointsRedemption';
 import { usePaymentActions } from './usePaymentActions';
 import { KitchenReprintModal } from '../KitchenReprintModal';
 import { LabelReprintModal } from '../LabelReprintModal';
@@ -131,6 +132,7 @@ export const SelectModePage: React.FC<SelectModePageProps> = ({ order, onComplet
 
   // Extracted hooks
   const stamp = useStampProgress(order);
+  const points = usePointsRedemption(order);
   const payment = usePaymentActions(order, onComplete);
 
   const [showRetailCancelConfirm, setShowRetailCancelConfirm] = useState(false);
@@ -528,6 +530,58 @@ export const SelectModePage: React.FC<SelectModePageProps> = ({ order, onComplet
 
                 return React.cloneElement(stampCardContent, { key: sp.stamp_activity_id });
               })}
+
+              {/* Points card: redeem the largest whole number of units, or clear an applied redemption */}
+              {order.member_id && points.summary?.points_redeem_unit && (
+                points.redemption ? (
+                  <div className="h-40 rounded-2xl shadow-xl bg-amber-100 text-amber-700 relative flex flex-col items-center justify-center gap-2">
+                    <button
+                      onClick={points.handleClearRedemption}
+                      disabled={payment.isProcessing}
+                      className="absolute top-2 right-2 p-1.5 rounded-full bg-amber-200 hover:bg-amber-300 text-amber-800 transition-colors disabled:opacity-50"
+                      title={t('checkout.points.cancel')}
+                    >
+                      <X size={14} />
+                    </button>
+                    <Sparkles size={28} />
+                    <div className="text-sm font-bold">{t('checkout.points.title')}</div>
+                    <div className="text-xl font-black tabular-nums">
+                      {t('checkout.points.applied', { amount: formatCurrency(points.redemption.amount) })}
+                    </div>
+                    <div className="text-xs font-medium bg-amber-200/50 px-3 py-0.5 rounded-full">
+                      {t('checkout.points.redeem_desc', { points: points.redemption.points })}
+                    </div>
+                  </div>
+                ) : points.maxPoints > 0 ? (
+                  <EscalatableGate
+                    permission={Permission.ORDERS_REDEEM_STAMP}
+                    mode="intercept"
+                    description={t('checkout.points.title')}
+                    onAuthorized={points.handleRedeemMax}
+                  >
+                    <div className="h-40 rounded-2xl shadow-xl bg-amber-500 text-white hover:shadow-2xl hover:scale-[1.02] cursor-pointer transition-all flex flex-col items-center justify-center gap-2">
+                      <Sparkles size={28} />
+                      <div className="text-sm font-bold">
+                        {t('checkout.points.balance', { points: points.summary.points_balance })}
+                      </div>
+                      <div className="text-xl font-black tabular-nums">
+                        {t('checkout.points.redeem', { amount: formatCurrency(points.maxAmount) })}
+                      </div>
+                      <div className="text-xs font-medium bg-white/20 px-3 py-0.5 rounded-full">
+                        {t('checkout.points.redeem_desc', { points: points.maxPoints })}
+                      </div>
+                    </div>
+                  </EscalatableGate>
+                ) : (
+                  <div className="h-40 rounded-2xl shadow-xl bg-amber-50 text-amber-600 flex flex-col items-center justify-center gap-2">
+                    <Sparkles size={28} />
+                    <div className="text-sm font-bold">
+                      {t('checkout.points.balance', { points: points.summary.points_balance })}
+                    </div>
+                    <div className="text-xs font-medium">{t('checkout.points.not_enough')}</div>
+                  </div>
+                )
+              )}
             </div>
           </div>
         </div>
@@ -0,0 +1,73 @@
+import { useState, useCallback, useEffect, useMemo } from 'react';
+import type { MemberPointsSummary } from '@/core/domain/types/api';
+import type { HeldOrder } from '@/core/domain/types';
+import { useI18n } from '@/hooks/useI18n';
+import { toast } from '@/presentation/components/Toast';
+import { CommandFailedError } from '@/core/stores/order/commands/sendCommand';
+import { commandErrorMessage } from '@/utils/error/commandError';
+import { redeemPoints } from '@/core/stores/order/commands';
+import { getMemberPoints } from '@/features/member/mutations';
+
+export function usePointsRedemption(order: HeldOrder) {
+  const { t } = useI18n();
+  const [summary, setSummary] = useState<MemberPointsSummary | null>(null);
+
+  useEffect(() => {
+    if (order.member_id) {
+      getMemberPoints(order.member_id)
+        .then(setSummary)
+        .catch(() => setSummary(null));
+    } else {
+      setSummary(null);
+    }
+  }, [order.member_id]);
+
+  const redemption = order.points_redemption ?? null;
+
+  // Largest whole number of redeem units covered by both the balance and the order total
+  const maxPoints = useMemo(() => {
+    const unit = summary?.points_redeem_unit;
+    const value = summary?.points_redeem_value;
+    if (!summary || !unit || !value) return 0;
+    const payable = order.total + (redemption?.amount ?? 0);
+    const units = Math.min(
+      Math.floor(summary.points_balance / unit),
+      Math.floor((payable + 0.005) / value),
+    );
+    return Math.max(0, units) * unit;
+  }, [summary, order.total, redemption]);
+
+  const maxAmount = summary?.points_redeem_unit && summary.points_redeem_value
+    ? (maxPoints / summary.points_redeem_unit) * summary.points_redeem_value
+    : 0;
+
+  const apply = useCallback(async (points: number, successKey: string) => {
+    try {
+      await redeemPoints(order.order_id, points);
+      toast.success(t(successKey));
+    } catch (e) {
+      toast.error(e instanceof CommandFailedError
+        ? commandErrorMessage(e.code)
+        : t('checkout.points.redeem_failed'));
+    }
+  }, [order.order_id, t]);
+
+  const handleRedeemMax = useCallback(
+    () => apply(maxPoints, 'checkout.points.redeemed'),
+    [apply, maxPoints],
+  );
+
+  const handleClearRedemption = useCallback(
+    () => apply(0, 'checkout.points.cleared'),
+    [apply],
+  );
+
+  return {
+    summary,
+    redemption,
+    maxPoints,
+    maxAmount,
+    handleRedeemMax,
+    handleClearRedemption,
+  };
+}
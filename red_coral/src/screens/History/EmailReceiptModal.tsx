@@ -0,0 +1,92 @@
+import React, { useState } from 'react';
+import { invokeApi } from '@/infrastructure/api';
+import { useI18n } from '@/hooks/useI18n';
+import { localizedErrorMessage } from '@/utils/error/commandError';
+import { X } from 'lucide-react';
+import { toast } from '@/presentation/components/Toast';
+import type { ArchivedOrderDetail } from '@/core/domain/types';
+
+interface EmailReceiptModalProps {
+  order: ArchivedOrderDetail;
+  onClose: () => void;
+}
+
+const EMAIL_PATTERN = /^[^\s@]+@[^\s@]+\.[^\s@]+$/;
+
+export const EmailReceiptModal: React.FC<EmailReceiptModalProps> = ({ order, onClose }) => {
+  const { t } = useI18n();
+  const [to, setTo] = useState('');
+  const [submitting, setSubmitting] = useState(false);
+
+  const valid = EMAIL_PATTERN.test(to.trim());
+
+  const handleSubmit = async () => {
+    if (!valid || submitting) return;
+    setSubmitting(true);
+
+    try {
+      await invokeApi('email_order_receipt', { orderPk: order.order_id, to: to.trim() });
+      toast.success(t('history.email_receipt.queued'));
+      onClose();
+    } catch (err) {
+      toast.error(localizedErrorMessage(err));
+    } finally {
+      setSubmitting(false);
+    }
+  };
+
+  return (
+    <div className="fixed inset-0 z-50 bg-black/50 backdrop-blur-sm flex items-center justify-center p-4" onClick={onClose}>
+      <div
+        className="bg-white rounded-2xl w-full max-w-md overflow-hidden flex flex-col shadow-xl"
+        onClick={(e) => e.stopPropagation()}
+      >
+        {/* Header */}
+        <div className="p-5 border-b border-gray-200 flex items-center justify-between shrink-0">
+          <div>
+            <h2 className="text-lg font-bold text-gray-900">{t('history.email_receipt.title')}</h2>
+            <p className="text-sm text-gray-500 mt-0.5">{order.receipt_number}</p>
+          </div>
+          <button onClick={onClose} className="p-2 hover:bg-gray-100 rounded-lg transition-colors">
+            <X size={20} className="text-gray-500" />
+          </button>
+        </div>
+
+        {/* Body */}
+        <div className="p-5">
+          <label className="block text-sm font-medium text-gray-700 mb-2">
+            {t('history.email_receipt.to')} *
+          </label>
+          <input
+            type="email"
+            value={to}
+            onChange={(e) => setTo(e.target.value)}
+            onKeyDown={(e) => { if (e.key === 'Enter') handleSubmit(); }}
+            placeholder={t('history.email_receipt.placeholder')}
+            autoFocus
+            className="w-full px-4 py-2.5 rounded-xl border border-gray-300 text-sm focus:outline-none focus:ring-2 focus:ring-blue-500/30 focus:border-blue-500"
+          />
+        </div>
+
+        {/* Footer */}
+        <div className="p-5 border-t border-gray-200 shrink-0">
+          <div className="flex gap-3">
+            <button
+              onClick={onClose}
+              className="flex-1 py-2.5 rounded-xl border border-gray-300 text-sm font-medium text-gray-700 hover:bg-gray-50 transition-colors"
+            >
+              {t('common.action.cancel')}
+            </button>
+            <button
+              onClick={handleSubmit}
+              disabled={!valid || submitting}
+              className="flex-1 py-2.5 rounded-xl bg-blue-600 text-white text-sm font-medium hover:bg-blue-700 transition-colors disabled:opacity-50 disabled:cursor-not-allowed"
+            >
+              {submitting ? '...' : t('history.email_receipt.send')}
+            </button>
+          </div>
+        </div>
+      </div>
+    </div>
+  );
+};
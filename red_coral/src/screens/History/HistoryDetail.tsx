@@ -5,7 +5,7 @@ import { useI18n } from '@/hooks/useI18n';
 import { useCategoryStore } from '@/core/stores/resources';
 import { formatCurrency, Currency } from '@/utils/currency';
 import { CATEGORY_ACCENT } from '@/utils/categoryColors';
-import { Receipt, Calendar, Printer, CreditCard, Coins, Clock, ChevronDown, ChevronUp, ChevronsDown, ChevronsUp, Ban, Gift, Stamp, Tag, Hash, Undo2, FileUp, Crown, Phone, CreditCard as CardIcon, Star, ShoppingBag, UserX } from 'lucide-react';
+import { Receipt, Calendar, Printer, CreditCard, Coins, Clock, ChevronDown, ChevronUp, ChevronsDown, ChevronsUp, Ban, Gift, Stamp, Tag, Hash, Undo2, FileUp, Crown, Phone, CreditCard as CardIcon, Star, ShoppingBag, UserX, Mail } from 'lucide-react';
 import { Permission } from '@/core/domain/types';
 import type { MemberWithGroup } from '@/core/domain/types/api';
 import { EscalatableGate } from '@/presentation/components/auth/EscalatableGate';
@@ -17,12 +17,15 @@ import { LabelReprintModal } from '@/screens/Checkout/LabelReprintModal';
 import { CreditNoteSection } from './CreditNoteSection';
 import { RefundModal } from './RefundModal';
 import { AnulacionModal } from './AnulacionModal';
+import { EmailReceiptModal } from './EmailReceiptModal';
 import { UpgradeInvoiceModal } from './UpgradeInvoiceModal';
 import { InvoiceSection } from './InvoiceSection';
+import { RECEIPT_LANGUAGES } from '@/core/services/order/receiptBuilder';
 
 interface HistoryDetailProps {
   order?: ArchivedOrderDetail;
-  onReprint: () => void;
+  /** locale: 打印时选择的收据语言（省略 = 会员偏好 / 门店默认） */
+  onReprint: (locale?: string) => void;
   hashInfo?: { prev_hash: string; curr_hash: string };
   onRefundCreated?: () => void;
   onNavigateToCreditNote?: (creditNotePk: number) => void;
@@ -65,6 +68,7 @@ export const HistoryDetail: React.FC<HistoryDetailProps> = ({ order, onReprint,
   const [expandedItems, setExpandedItems] = useState<Set<number>>(new Set());
   const [showKitchenReprint, setShowKitchenReprint] = useState(false);
   const [showLabelReprint, setShowLabelReprint] = useState(false);
+  const [showEmailReceipt, setShowEmailReceipt] = useState(false);
   const [showRefundModal, setShowRefundModal] = useState(false);
   const [showAnulacionModal, setShowAnulacionModal] = useState(false);
   const [showUpgradeModal, setShowUpgradeModal] = useState(false);
@@ -248,7 +252,25 @@ export const HistoryDetail: React.FC<HistoryDetailProps> = ({ order, onReprint,
                       <Printer size={15} />
                       <span>{t('history.action.reprint')}</span>
                     </button>
+                    {RECEIPT_LANGUAGES.map(({ locale, label }) => (
+                      <button
+                        key={locale}
+                        onClick={() => { onReprint(locale); setPrintMenuOpen(false); }}
+                        className="w-full flex items-center gap-2 pl-9 pr-3 py-1.5 text-xs text-gray-600 hover:bg-gray-50"
+                      >
+                        <span>{t('history.action.reprint_in', { language: label })}</span>
+                      </button>
+                    ))}
                   </EscalatableGate>
+                  {order.status === 'COMPLETED' && !order.is_voided && (
+                    <button
+                      onClick={() => { setShowEmailReceipt(true); setPrintMenuOpen(false); }}
+                      className="w-full flex items-center gap-2 px-3 py-2 text-sm text-gray-700 hover:bg-gray-50"
+                    >
+                      <Mail size={15} />
+                      <span>{t('history.action.email_receipt')}</span>
+                    </button>
+                  )}
                   <button
                     onClick={() => { setShowKitchenReprint(true); setPrintMenuOpen(false); }}
                     className="w-full flex items-center gap-2 px-3 py-2 text-sm text-amber-700 hover:bg-amber-50"
@@ -657,6 +679,9 @@ export const HistoryDetail: React.FC<HistoryDetailProps> = ({ order, onReprint,
             orderId={order.order_id}
             onClose={() => setShowLabelReprint(false)}
           />
+          {showEmailReceipt && (
+            <EmailReceiptModal order={order} onClose={() => setShowEmailReceipt(false)} />
+          )}
           {showRefundModal && (
             <RefundModal
               order={order}
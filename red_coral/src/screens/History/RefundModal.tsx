@@ -104,6 +104,10 @@ export const RefundModal: React.FC<RefundModalProps> = ({ order, onClose, onCrea
     );
   }, []);
 
+  const selectAll = useCallback(() => {
+    setItems((prev) => prev.map((item) => ({ ...item, selected: true, quantity: item.max_quantity })));
+  }, []);
+
   const selectedItems = useMemo(() => items.filter((item) => item.selected && item.quantity > 0), [items]);
 
   const totalCredit = useMemo(
@@ -111,6 +115,12 @@ export const RefundModal: React.FC<RefundModalProps> = ({ order, onClose, onCrea
     [selectedItems],
   );
 
+  // 整单退款：所有剩余商品全额选中时，交给服务端按剩余数量展开 (full_refund，items 为空)
+  const isFullRefund = useMemo(
+    () => items.length > 0 && items.every((item) => item.selected && item.quantity === item.max_quantity),
+    [items],
+  );
+
   const canSubmit =
     selectedItems.length > 0 &&
     (reason !== 'OTHER' || note.trim().length > 0) &&
@@ -125,10 +135,13 @@ export const RefundModal: React.FC<RefundModalProps> = ({ order, onClose, onCrea
     try {
       const request: CreateCreditNoteRequest = {
         original_order_pk: order.order_id,
-        items: selectedItems.map((item) => ({
-          instance_id: item.instance_id,
-          quantity: item.quantity,
-        })),
+        items: isFullRefund
+          ? []
+          : selectedItems.map((item) => ({
+              instance_id: item.instance_id,
+              quantity: item.quantity,
+            })),
+        full_refund: isFullRefund,
         refund_method: refundMethod,
         reason,
         note: note.trim() || undefined,
@@ -176,9 +189,18 @@ export const RefundModal: React.FC<RefundModalProps> = ({ order, onClose, onCrea
             <>
               {/* Items selection */}
               <div>
-                <label className="block text-sm font-medium text-gray-700 mb-2">
-                  {t('credit_note.modal.select_items')}
-                </label>
+                <div className="flex items-center justify-between mb-2">
+                  <label className="block text-sm font-medium text-gray-700">
+                    {t('credit_note.modal.select_items')}
+                  </label>
+                  <button
+                    onClick={selectAll}
+                    disabled={isFullRefund}
+                    className="text-xs font-medium text-red-500 hover:text-red-600 disabled:text-gray-300"
+                  >
+                    {t('credit_note.modal.refund_all')}
+                  </button>
+                </div>
                 <div className="border border-gray-200 rounded-xl overflow-hidden divide-y divide-gray-100">
                   {items.map((item, idx) => (
                     <div
@@ -106,11 +106,11 @@ export const HistoryScreen: React.FC<HistoryScreenProps> = ({ isVisible, onBack
 
   const receiptPrinter = usePrinterStore(state => state.receiptPrinter);
 
-  const handleReprint = async () => {
+  const handleReprint = async (locale?: string) => {
     if (!selectedOrder) return;
     if (!receiptPrinter) { toast.warning(t('settings.printer.no_printer')); return; }
     try {
-      await reprintArchivedReceipt(selectedOrder, receiptPrinter);
+      await reprintArchivedReceipt(selectedOrder, receiptPrinter, locale);
       toast.success(t('common.message.receipt_print_success'));
     } catch (error) {
       toast.error(getErrorMessage(error));
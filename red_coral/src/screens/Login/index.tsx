@@ -7,6 +7,8 @@ import { useBridgeStore, useAppState, AppStateHelpers } from '@/core/stores/brid
 import { useI18n } from '@/hooks/useI18n';
 import { logger } from '@/utils/logger';
 import { MAX_NAME_LEN, MAX_PASSWORD_LEN } from '@/shared/constants/validation';
+import { IdentityPhotoCheck } from '@/presentation/components/auth/IdentityPhotoCheck';
+import type { User as AuthUser } from '@/core/domain/types';
 
 interface LocationState {
   from?: { pathname: string };
@@ -30,6 +32,8 @@ export const LoginScreen: React.FC = () => {
   const [password, setPassword] = useState('');
   const [error, setError] = useState('');
   const [focusedField, setFocusedField] = useState<'username' | 'password' | null>(null);
+  // 员工有照片时登录后先核对身份 (防代打卡)
+  const [pendingUser, setPendingUser] = useState<AuthUser | null>(null);
 
   // 如果 AppState 不适合登录，重定向到正确路由
   // App 级 useAppInitialization 已完成所有初始化，直接读 store
@@ -81,8 +85,7 @@ export const LoginScreen: React.FC = () => {
       if (response.success && response.session) {
         const userInfo = response.session.user_info;
 
-        // 同步登录状态到 AuthStore
-        setAuthUser({
+        const user: AuthUser = {
           id: userInfo.id,
           username: userInfo.username,
           name: userInfo.name,
@@ -92,7 +95,15 @@ export const LoginScreen: React.FC = () => {
           is_system: userInfo.is_system,
           is_active: userInfo.is_active,
           created_at: userInfo.created_at,
-        });
+          photo: userInfo.photo,
+        };
+
+        if (user.photo) {
+          setPendingUser(user);
+        } else {
+          // 同步登录状态到 AuthStore
+          setAuthUser(user);
+        }
 
         // Navigation handled by useEffect
       } else {
@@ -103,6 +114,20 @@ export const LoginScreen: React.FC = () => {
     }
   };
 
+  const handleConfirmIdentity = () => {
+    if (!pendingUser) return;
+    setAuthUser(pendingUser);
+    setPendingUser(null);
+  };
+
+  const handleRejectIdentity = async () => {
+    logger.warn('Login identity rejected at photo check', { component: 'LoginScreen' });
+    setPendingUser(null);
+    setPassword('');
+    setError(t('auth.identity_check.rejected'));
+    await useBridgeStore.getState().logoutEmployee();
+  };
+
   const handleCloseApp = async () => {
     try {
       const appWindow = getCurrentWindow();
@@ -259,6 +284,16 @@ export const LoginScreen: React.FC = () => {
             </p>
           </div>
 
+          {pendingUser?.photo ? (
+            <div className="mt-8">
+              <IdentityPhotoCheck
+                photo={pendingUser.photo}
+                name={pendingUser.name}
+                onConfirm={handleConfirmIdentity}
+                onReject={handleRejectIdentity}
+              />
+            </div>
+          ) : (
           <form onSubmit={handleSubmit} className="space-y-6 mt-8">
             {/* Username Input */}
             <div className="space-y-1">
@@ -358,6 +393,7 @@ export const LoginScreen: React.FC = () => {
               )}
             </button>
           </form>
+          )}
         </div>
 
         {/* Footer Copyright */}
@@ -1,5 +1,6 @@
 import React from 'react';
-import { Utensils, ClipboardList, Archive, Settings as SettingsIcon, LogOut, ChartArea } from 'lucide-react';
+import { Utensils, ClipboardList, Archive, Settings as SettingsIcon, LogOut, Lock, ChartArea } from 'lucide-react';
+import { invokeApi } from '@/infrastructure/api';
 import { IconBtn } from '@/presentation/components/ui/IconBtn';
 import { useI18n } from '@/hooks/useI18n';
 import { ProtectedGate } from '@/presentation/components/auth/ProtectedGate';
@@ -58,6 +59,7 @@ export const ActionBar: React.FC<ActionBarProps> = ({
           <ClipboardList size={24} />
         </button>
         <IconBtn icon={LogOut} size={24} className="p-3 hover:bg-white/10 rounded-xl text-white/50" onClick={onRequestExit} onMouseDown={(e) => e.preventDefault()} />
+        <IconBtn icon={Lock} size={24} className="p-3 hover:bg-white/10 rounded-xl text-white/50" title={t('auth.lock.lock_now')} onClick={() => invokeApi('lock_session')} onMouseDown={(e) => e.preventDefault()} />
       </div>
 
       <div className="flex items-center gap-2">
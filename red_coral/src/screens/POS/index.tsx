@@ -185,6 +185,8 @@ export const POSScreen: React.FC = () => {
     try {
       const { openCashDrawer } = await import('@/infrastructure/print/printService');
       await openCashDrawer(selectedPrinter || undefined);
+      const { recordCashDrawerOpen } = await import('@/core/services/order/paymentService');
+      await recordCashDrawerOpen('NO_SALE');
       toast.success(t('app.action.cash_drawer_opened'));
     } catch (error) {
       logger.error('Failed to open cash drawer', error);
@@ -17,13 +17,14 @@ import {
   Crown,
   UserCheck,
   Building2,
+  Megaphone,
 } from 'lucide-react';
 import { useI18n } from '@/hooks/useI18n';
 import { useSettingsCategory, useSettingsStore } from '@/core/stores/settings/useSettingsStore';
 import { ProtectedGate } from '@/presentation/components/auth/ProtectedGate';
 import { Permission } from '@/core/domain/types';
 
-type SettingsCategory = 'TENANT_INFO' | 'PRINTER' | 'TABLES' | 'PRODUCTS' | 'CATEGORIES' | 'TAGS' | 'ATTRIBUTES' | 'PRICE_RULES' | 'MARKETING_GROUPS' | 'MEMBERS' | 'DATA_TRANSFER' | 'STORE' | 'SYSTEM' | 'USERS' | 'SHIFTS';
+type SettingsCategory = 'TENANT_INFO' | 'PRINTER' | 'TABLES' | 'PRODUCTS' | 'CATEGORIES' | 'TAGS' | 'ATTRIBUTES' | 'PRICE_RULES' | 'MARKETING_GROUPS' | 'MEMBERS' | 'DATA_TRANSFER' | 'STORE' | 'SYSTEM' | 'USERS' | 'SHIFTS' | 'ANNOUNCEMENTS';
 
 interface SettingsSidebarProps {
   onBack: () => void;
@@ -195,6 +196,15 @@ export const SettingsSidebar: React.FC<SettingsSidebarProps> = ({ onBack }) => {
           />
         </ProtectedGate>
 
+        {/* 员工公告 */}
+        <ProtectedGate permission={Permission.ANNOUNCEMENTS_SEND}>
+          <CategoryItem
+            category="ANNOUNCEMENTS"
+            icon={Megaphone}
+            label={t('settings.announcement.title')}
+          />
+        </ProtectedGate>
+
         <Divider />
 
         {/* 系统设置 */}
@@ -1,5 +1,6 @@
 import React, { useEffect, useState } from 'react';
 import { useStoreInfo, useStoreInfoStore } from '@/core/stores/settings';
+import { usePrintDestinationStore } from '@/core/stores/resources';
 import { useI18n } from '@/hooks/useI18n';
 import { Save, Store, Phone, Mail, Globe, CreditCard, ImageIcon, Loader2, Clock } from 'lucide-react';
 import { useDirtyForm } from '@/shared/hooks/useDirtyForm';
@@ -8,6 +9,7 @@ import { invoke } from '@tauri-apps/api/core';
 import { open as dialogOpen } from '@tauri-apps/plugin-dialog';
 import { ProductImage } from '@/features/product/ProductImage';
 import { getErrorMessage } from '@/utils/error';
+import type { CarryOverPolicy } from '@/core/domain/types/api';
 import { MAX_NAME_LEN, MAX_ADDRESS_LEN, MAX_SHORT_TEXT_LEN, MAX_EMAIL_LEN, MAX_URL_LEN } from '@/shared/constants/validation';
 
 const CUTOFF_PRESETS = [
@@ -22,14 +24,19 @@ const CUTOFF_PRESETS = [
   { value: 480, label: '08:00' },
 ];
 
+const CARRY_OVER_POLICIES: CarryOverPolicy[] = ['TRANSFER', 'FORCE_COMPLETE', 'BLOCK'];
+
 export const StoreSettings: React.FC = () => {
   const info = useStoreInfo();
   const { fetchAll, updateStoreInfo, isLoading, isLoaded } = useStoreInfoStore();
   const { t } = useI18n();
   const [isSaving, setIsSaving] = useState(false);
+  const printDestinations = usePrintDestinationStore((state) => state.items);
+  const fetchPrintDestinations = usePrintDestinationStore((state) => state.fetchAll);
 
   useEffect(() => {
     fetchAll();
+    fetchPrintDestinations();
   }, []);
 
   const formInfo = {
@@ -41,6 +48,9 @@ export const StoreSettings: React.FC = () => {
     email: info.email || '',
     website: info.website || '',
     businessDayCutoff: info.business_day_cutoff ?? 120,
+    carryOverPolicy: info.carry_over_policy ?? 'TRANSFER',
+    dailyReportPrintDestinationId: info.daily_report_print_destination_id ?? 0,
+    maxCashChange: info.max_cash_change ?? 200,
   };
 
   const { values: formData, handleChange, isDirty, reset } = useDirtyForm(formInfo);
@@ -76,6 +86,9 @@ export const StoreSettings: React.FC = () => {
         email: formData.email || null,
         website: formData.website || null,
         business_day_cutoff: formData.businessDayCutoff,
+        carry_over_policy: formData.carryOverPolicy,
+        daily_report_print_destination_id: formData.dailyReportPrintDestinationId,
+        max_cash_change: formData.maxCashChange,
       });
       reset(formData);
       toast.success(t('common.message.save_success'));
@@ -224,6 +237,63 @@ export const StoreSettings: React.FC = () => {
           </div>
         </div>
 
+        {/* Row 4: Open orders at cutoff */}
+        <div className="flex items-start gap-6 mt-4">
+          <div>
+            <label className={labelClass}>{t('settings.store.form.carry_over_policy')}</label>
+            <select
+              value={formData.carryOverPolicy}
+              onChange={(e) => handleChange('carryOverPolicy', e.target.value as CarryOverPolicy)}
+              className="w-40 rounded-lg border border-gray-200 bg-gray-50/50 text-sm p-2.5 focus:bg-white focus:border-blue-400 focus:ring-1 focus:ring-blue-400 transition-all outline-none cursor-pointer"
+            >
+              {CARRY_OVER_POLICIES.map((p) => (
+                <option key={p} value={p}>{t(`settings.store.carry_over.${p}`)}</option>
+              ))}
+            </select>
+          </div>
+          <div className="pt-5">
+            <p className="text-[11px] text-gray-400">{t('settings.store.form.carry_over_policy_help')}</p>
+          </div>
+        </div>
+
+        {/* Row 5: Daily report auto-print */}
+        <div className="flex items-start gap-6 mt-4">
+          <div>
+            <label className={labelClass}>{t('settings.store.form.daily_report_print')}</label>
+            <select
+              value={formData.dailyReportPrintDestinationId}
+              onChange={(e) => handleChange('dailyReportPrintDestinationId', Number(e.target.value))}
+              className="w-40 rounded-lg border border-gray-200 bg-gray-50/50 text-sm p-2.5 focus:bg-white focus:border-blue-400 focus:ring-1 focus:ring-blue-400 transition-all outline-none cursor-pointer"
+            >
+              <option value={0}>{t('settings.store.form.daily_report_print_off')}</option>
+              {printDestinations.filter((d) => d.purpose !== 'label').map((d) => (
+                <option key={d.id} value={d.id}>{d.name}</option>
+              ))}
+            </select>
+          </div>
+          <div className="pt-5">
+            <p className="text-[11px] text-gray-400">{t('settings.store.form.daily_report_print_help')}</p>
+          </div>
+        </div>
+
+        {/* Row 6: Cash-change limit */}
+        <div className="flex items-start gap-6 mt-4">
+          <div>
+            <label className={labelClass}>{t('settings.store.form.max_cash_change')}</label>
+            <input
+              type="number"
+              min={0}
+              step="0.01"
+              value={formData.maxCashChange}
+              onChange={(e) => handleChange('maxCashChange', Math.max(0, Number(e.target.value) || 0))}
+              className="w-40 rounded-lg border border-gray-200 bg-gray-50/50 text-sm p-2.5 focus:bg-white focus:border-blue-400 focus:ring-1 focus:ring-blue-400 transition-all outline-none"
+            />
+          </div>
+          <div className="pt-5">
+            <p className="text-[11px] text-gray-400">{t('settings.store.form.max_cash_change_help')}</p>
+          </div>
+        </div>
+
       </div>
     </div>
   );
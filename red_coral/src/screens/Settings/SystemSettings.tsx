@@ -5,13 +5,17 @@ import { useUIScale, useSetUIScale } from '@/core/stores/ui';
 import { useBridgeStore, AppStateHelpers } from '@/core/stores/bridge';
 import { useI18n } from '@/hooks/useI18n';
 import type { Locale } from '@/infrastructure/i18n';
-import { Monitor, Zap, Trash2, ZoomIn, Plus, Minus, Languages, Keyboard, LogOut, Info, Download, RefreshCw, Loader2, CheckCircle2 } from 'lucide-react';
+import { Monitor, Zap, Trash2, ZoomIn, Plus, Minus, Languages, Keyboard, LogOut, Lock, Info, Download, RefreshCw, Loader2, CheckCircle2 } from 'lucide-react';
 import { ConfirmDialog } from '@/shared/components/ConfirmDialog';
 import { useVirtualKeyboardStore, useVirtualKeyboardMode } from '@/core/stores/ui';
 import { useUpdateChecker, type UpdateStatus } from '@/core/hooks/useUpdateChecker';
+import { invokeApi } from '@/infrastructure/api';
 
 const SCALE_STEPS = [0.9, 0.95, 1, 1.05, 1.1, 1.15, 1.2, 1.25, 1.3];
 
+/** 自动锁屏可选时长 (分钟，0 = 关闭) */
+const AUTO_LOCK_OPTIONS = [0, 1, 3, 5, 10, 15, 30];
+
 export const SystemSettings: React.FC = () => {
   const { t, locale, setLocale } = useI18n();
   const navigate = useNavigate();
@@ -36,6 +40,18 @@ export const SystemSettings: React.FC = () => {
     import('@tauri-apps/api/app').then(({ getVersion }) => getVersion()).then(setAppVersion);
   }, []);
 
+  const [autoLockMinutes, setAutoLockMinutes] = useState(0);
+  useEffect(() => {
+    invokeApi<number | null>('get_auto_lock_minutes')
+      .then((minutes) => setAutoLockMinutes(minutes ?? 0))
+      .catch(() => {});
+  }, []);
+
+  const handleAutoLockChange = async (minutes: number) => {
+    setAutoLockMinutes(minutes);
+    await invokeApi('set_auto_lock_minutes', { minutes: minutes > 0 ? minutes : null });
+  };
+
   const scalePercent = Math.round(uiScale * 100);
 
   const handleClearCache = () => {
@@ -143,6 +159,28 @@ export const SystemSettings: React.FC = () => {
                 </select>
               </div>
 
+              {/* Auto Lock */}
+              <div className="flex items-center justify-between">
+                <div>
+                  <div className="flex items-center gap-2.5">
+                    <Lock className="w-4 h-4 text-gray-400" />
+                    <span className="text-sm font-medium text-gray-700">{t('auth.lock.auto_lock')}</span>
+                  </div>
+                  <p className="text-xs text-gray-400 mt-0.5 ml-6.5">{t('auth.lock.auto_lock_desc')}</p>
+                </div>
+                <select
+                  value={autoLockMinutes}
+                  onChange={(e) => handleAutoLockChange(Number(e.target.value))}
+                  className="border border-gray-300 rounded-lg px-3 py-1.5 bg-white focus:border-blue-500 focus:ring-blue-500 text-sm transition-colors"
+                >
+                  {AUTO_LOCK_OPTIONS.map((minutes) => (
+                    <option key={minutes} value={minutes}>
+                      {minutes === 0 ? t('auth.lock.auto_lock_off') : t('auth.lock.auto_lock_minutes', { minutes })}
+                    </option>
+                  ))}
+                </select>
+              </div>
+
               {/* Performance Mode */}
               <div className="flex items-center justify-between">
                 <div>
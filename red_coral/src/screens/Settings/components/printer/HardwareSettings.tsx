@@ -10,6 +10,7 @@ import {
   useAutoOpenCashDrawerAfterReceipt,
 } from '@/core/stores/ui';
 import { openCashDrawer } from '@/infrastructure/print/printService';
+import { recordCashDrawerOpen } from '@/core/services/order/paymentService';
 import { PrinterSelect } from './PrinterSelect';
 import { KitchenPrinterList } from './KitchenPrinterList';
 
@@ -37,6 +38,7 @@ export const HardwareSettings: React.FC<HardwareSettingsProps> = ({ printers, lo
     setTestingCashDrawer(true);
     try {
       await openCashDrawer(effectiveCashDrawerPrinter);
+      await recordCashDrawerOpen('NO_SALE', 'Hardware test');
       toast.success(t('settings.printer.cash_drawer.test_success'));
     } catch (error) {
       const message = error instanceof Error ? error.message : String(error);
@@ -9,6 +9,7 @@ import { TagManagement } from '@/features/tag';
 import { AttributeManagement } from '@/features/attribute';
 import { PriceRuleManagement } from '@/features/price-rule';
 import { ShiftManagement } from '@/features/shift';
+import { AnnouncementManagement } from '@/features/announcement';
 import { DataTransfer } from './DataTransfer';
 import { StoreSettings } from './StoreSettings';
 import { SystemSettings } from './SystemSettings';
@@ -79,6 +80,11 @@ const SettingsContent: React.FC = React.memo(() => {
             <ShiftManagement />
           </ProtectedGate>
         )}
+        {activeCategory === 'ANNOUNCEMENTS' && (
+          <ProtectedGate permission={Permission.ANNOUNCEMENTS_SEND}>
+            <AnnouncementManagement />
+          </ProtectedGate>
+        )}
         {activeCategory === 'USERS' && (
           <ProtectedGate permission={Permission.USERS_MANAGE}>
             <UserManagement />
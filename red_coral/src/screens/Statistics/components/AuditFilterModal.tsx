@@ -9,19 +9,29 @@ const RESOURCE_ACTIONS: Record<string, AuditAction[]> = {
   system: ['system_startup', 'system_shutdown', 'system_abnormal_shutdown', 'system_long_downtime'],
   auth: ['login_success', 'login_failed', 'logout', 'escalation_success'],
   system_issue: ['resolve_system_issue'],
-  order: ['order_completed', 'order_voided', 'order_merged'],
+  support_session: ['support_session_opened', 'support_session_closed'],
+  database: ['anonymized_database_exported'],
+  order: ['order_completed', 'order_voided', 'order_merged', 'orders_imported', 'order_audit_exported'],
   employee: ['employee_created', 'employee_updated', 'employee_deleted'],
   role: ['role_created', 'role_updated', 'role_deleted'],
-  product: ['product_created', 'product_updated', 'product_deleted'],
+  product: ['product_created', 'product_updated', 'product_deleted', 'product_eighty_sixed', 'product_eighty_six_cleared'],
+  stock_level: ['stock_level_created', 'stock_level_updated', 'stock_level_deleted', 'stock_adjusted'],
+  stock_count: ['stock_count_started', 'stock_count_submitted', 'stock_count_approved', 'stock_count_rejected'],
+  stock_transfer: ['stock_transfer_sent', 'stock_transfer_received'],
   category: ['category_created', 'category_updated', 'category_deleted'],
   tag: ['tag_created', 'tag_updated', 'tag_deleted'],
   attribute: ['attribute_created', 'attribute_updated', 'attribute_deleted'],
   price_rule: ['price_rule_created', 'price_rule_updated', 'price_rule_deleted'],
   zone: ['zone_created', 'zone_updated', 'zone_deleted'],
-  dining_table: ['table_created', 'table_updated', 'table_deleted'],
-  shift: ['shift_opened', 'shift_updated', 'shift_closed'],
-  member: ['member_created', 'member_updated', 'member_deleted'],
+  dining_table: ['table_created', 'table_updated', 'table_deleted', 'tables_joined', 'tables_split'],
+  shift: ['shift_opened', 'shift_updated', 'shift_closed', 'shift_break_started', 'shift_break_ended'],
+  cash_drawer: ['cash_drawer_opened', 'cash_paid_in', 'cash_paid_out'],
+  announcement: ['announcement_sent'],
+  member: ['member_created', 'member_updated', 'member_deleted', 'member_credit_topped_up'],
   marketing_group: ['marketing_group_created', 'marketing_group_updated', 'marketing_group_deleted'],
+  coupon: ['coupon_created', 'coupon_updated', 'coupon_deleted'],
+  event_booking: ['event_booking_created', 'event_booking_updated', 'event_booking_cancelled', 'event_booking_deposit_recorded'],
+  reservation: ['reservation_created', 'reservation_updated', 'reservation_cancelled'],
   print_config: ['print_config_changed'],
   print_destination: ['print_destination_created', 'print_destination_updated', 'print_destination_deleted'],
   label_template: ['label_template_created', 'label_template_updated', 'label_template_deleted'],
@@ -33,12 +43,12 @@ const RESOURCE_ACTIONS: Record<string, AuditAction[]> = {
  * 资源分类 — 将资源类型分组显示，减少视觉噪音
  */
 const RESOURCE_CATEGORIES: { group: string; resources: string[] }[] = [
-  { group: 'system', resources: ['system', 'auth', 'system_issue'] },
-  { group: 'order', resources: ['order'] },
-  { group: 'management', resources: ['employee', 'role', 'member', 'marketing_group'] },
-  { group: 'catalog', resources: ['product', 'category', 'tag', 'attribute'] },
+  { group: 'system', resources: ['system', 'auth', 'system_issue', 'support_session', 'database'] },
+  { group: 'order', resources: ['order', 'event_booking', 'reservation'] },
+  { group: 'management', resources: ['employee', 'role', 'member', 'marketing_group', 'coupon', 'announcement'] },
+  { group: 'catalog', resources: ['product', 'stock_level', 'stock_count', 'stock_transfer', 'category', 'tag', 'attribute'] },
   { group: 'venue', resources: ['zone', 'dining_table'] },
-  { group: 'config', resources: ['price_rule', 'shift', 'print_config', 'print_destination', 'label_template', 'store_info', 'daily_report'] },
+  { group: 'config', resources: ['price_rule', 'shift', 'cash_drawer', 'print_config', 'print_destination', 'label_template', 'store_info', 'daily_report'] },
 ];
 
 interface AuditFilterModalProps {
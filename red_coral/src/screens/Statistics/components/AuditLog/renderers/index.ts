@@ -37,6 +37,8 @@ type AuditActionType =
   | 'order_completed'
   | 'order_voided'
   | 'order_merged'
+  | 'orders_imported'
+  | 'order_audit_exported'
   | 'employee_created'
   | 'employee_updated'
   | 'employee_deleted'
@@ -46,9 +48,30 @@ type AuditActionType =
   | 'shift_opened'
   | 'shift_updated'
   | 'shift_closed'
+  | 'shift_break_started'
+  | 'shift_break_ended'
+  | 'cash_drawer_opened'
+  | 'cash_paid_in'
+  | 'cash_paid_out'
+  | 'announcement_sent'
+  | 'support_session_opened'
+  | 'support_session_closed'
+  | 'anonymized_database_exported'
   | 'product_created'
   | 'product_updated'
   | 'product_deleted'
+  | 'product_eighty_sixed'
+  | 'product_eighty_six_cleared'
+  | 'stock_level_created'
+  | 'stock_level_updated'
+  | 'stock_level_deleted'
+  | 'stock_adjusted'
+  | 'stock_count_started'
+  | 'stock_count_submitted'
+  | 'stock_count_approved'
+  | 'stock_count_rejected'
+  | 'stock_transfer_sent'
+  | 'stock_transfer_received'
   | 'category_created'
   | 'category_updated'
   | 'category_deleted'
@@ -67,6 +90,8 @@ type AuditActionType =
   | 'table_created'
   | 'table_updated'
   | 'table_deleted'
+  | 'tables_joined'
+  | 'tables_split'
   | 'label_template_created'
   | 'label_template_updated'
   | 'label_template_deleted'
@@ -76,9 +101,21 @@ type AuditActionType =
   | 'member_created'
   | 'member_updated'
   | 'member_deleted'
+  | 'member_credit_topped_up'
+  | 'members_imported'
+  | 'event_booking_created'
+  | 'event_booking_updated'
+  | 'event_booking_cancelled'
+  | 'event_booking_deposit_recorded'
+  | 'reservation_created'
+  | 'reservation_updated'
+  | 'reservation_cancelled'
   | 'marketing_group_created'
   | 'marketing_group_updated'
   | 'marketing_group_deleted'
+  | 'coupon_created'
+  | 'coupon_updated'
+  | 'coupon_deleted'
   | 'daily_report_generated'
   | 'print_config_changed'
   | 'store_info_changed';
@@ -104,11 +141,26 @@ export const AUDIT_RENDERERS: Partial<Record<AuditActionType, AuditDetailsRender
   order_completed: OrderCompletedRenderer,
   order_voided: OrderVoidedRenderer,
   order_merged: OrderMergedRenderer,
+  orders_imported: createSnapshotRenderer(),
+  order_audit_exported: createSnapshotRenderer(),
 
   // 班次
   shift_opened: ShiftOpenedRenderer,
   shift_updated: createDiffRenderer(),
   shift_closed: ShiftClosedRenderer,
+  shift_break_started: createSnapshotRenderer(),
+  shift_break_ended: createSnapshotRenderer(),
+
+  // 钱箱
+  cash_drawer_opened: createSnapshotRenderer(),
+  cash_paid_in: createSnapshotRenderer(),
+  cash_paid_out: createSnapshotRenderer(),
+
+  // 员工公告
+  announcement_sent: createSnapshotRenderer(['acks']),
+  support_session_opened: createSnapshotRenderer(),
+  support_session_closed: createSnapshotRenderer(),
+  anonymized_database_exported: createSnapshotRenderer(),
 
   // 员工
   employee_created: createSnapshotRenderer(['hash_pass', 'is_system']),
@@ -124,6 +176,18 @@ export const AUDIT_RENDERERS: Partial<Record<AuditActionType, AuditDetailsRender
   product_created: createSnapshotRenderer(),
   product_updated: createDiffRenderer(),
   product_deleted: createDeleteRenderer(),
+  product_eighty_sixed: createSnapshotRenderer(),
+  product_eighty_six_cleared: createSnapshotRenderer(),
+  stock_level_created: createSnapshotRenderer(),
+  stock_level_updated: createDiffRenderer(),
+  stock_level_deleted: createSnapshotRenderer(),
+  stock_adjusted: createSnapshotRenderer(),
+  stock_count_started: createSnapshotRenderer(),
+  stock_count_submitted: createSnapshotRenderer(),
+  stock_count_approved: createSnapshotRenderer(),
+  stock_count_rejected: createSnapshotRenderer(),
+  stock_transfer_sent: createSnapshotRenderer(),
+  stock_transfer_received: createSnapshotRenderer(),
 
   // 分类
   category_created: createSnapshotRenderer(),
@@ -154,6 +218,8 @@ export const AUDIT_RENDERERS: Partial<Record<AuditActionType, AuditDetailsRender
   table_created: createSnapshotRenderer(),
   table_updated: createDiffRenderer(),
   table_deleted: createDeleteRenderer(),
+  tables_joined: createSnapshotRenderer(),
+  tables_split: createSnapshotRenderer(),
 
   // 标签模板
   label_template_created: createSnapshotRenderer(),
@@ -169,12 +235,30 @@ export const AUDIT_RENDERERS: Partial<Record<AuditActionType, AuditDetailsRender
   member_created: createSnapshotRenderer(),
   member_updated: createDiffRenderer(),
   member_deleted: createDeleteRenderer(),
+  member_credit_topped_up: createSnapshotRenderer(),
+  members_imported: createSnapshotRenderer(),
+
+  // 宴会预订
+  event_booking_created: createSnapshotRenderer(),
+  event_booking_updated: createSnapshotRenderer(),
+  event_booking_cancelled: createSnapshotRenderer(),
+  event_booking_deposit_recorded: createSnapshotRenderer(),
+
+  // 订位
+  reservation_created: createSnapshotRenderer(),
+  reservation_updated: createSnapshotRenderer(),
+  reservation_cancelled: createSnapshotRenderer(),
 
   // 营销组
   marketing_group_created: createSnapshotRenderer(),
   marketing_group_updated: createDiffRenderer(),
   marketing_group_deleted: createDeleteRenderer(),
 
+  // 优惠码
+  coupon_created: createSnapshotRenderer(),
+  coupon_updated: createDiffRenderer(),
+  coupon_deleted: createDeleteRenderer(),
+
   // 日结
   daily_report_generated: createSnapshotRenderer(),
 
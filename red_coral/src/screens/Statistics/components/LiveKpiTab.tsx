@@ -0,0 +1,131 @@
+import React, { useState, useEffect, useCallback, useRef } from 'react';
+import { useI18n } from '@/hooks/useI18n';
+import { createTauriClient } from '@/infrastructure/api';
+import { useServerMessages, ServerMessage } from '@/core/hooks/useServerMessages';
+import { logger } from '@/utils/logger';
+import { formatCurrency } from '@/utils/currency/formatCurrency';
+import { TrendingUp, TrendingDown, Users, Receipt, LayoutGrid, ChefHat } from 'lucide-react';
+import type { KpiSnapshot } from '@/core/domain/types/api';
+
+const getApi = () => createTauriClient();
+
+/** Fallback refresh when no `kpi` push arrives (stream disabled or not subscribed) */
+const FALLBACK_REFRESH_MS = 60_000;
+
+const KpiCard: React.FC<{
+  icon: React.ElementType;
+  label: string;
+  value: string;
+  hint?: React.ReactNode;
+}> = ({ icon: Icon, label, value, hint }) => (
+  <div className="bg-white rounded-xl border border-gray-200 p-5">
+    <div className="flex items-center gap-2 text-sm text-gray-500 mb-2">
+      <Icon className="w-4 h-4" />{label}
+    </div>
+    <div className="text-3xl font-bold text-gray-800 tabular-nums">{value}</div>
+    {hint && <div className="text-xs mt-1">{hint}</div>}
+  </div>
+);
+
+export const LiveKpiTab: React.FC = () => {
+  const { t } = useI18n();
+  const [kpi, setKpi] = useState<KpiSnapshot | null>(null);
+  const lastUpdateRef = useRef(0);
+
+  const apply = useCallback((snapshot: KpiSnapshot) => {
+    lastUpdateRef.current = Date.now();
+    setKpi(snapshot);
+  }, []);
+
+  const load = useCallback(async () => {
+    try {
+      apply(await getApi().getKpiSnapshot());
+    } catch (e) {
+      logger.error('Failed to load KPI snapshot', e);
+    }
+  }, [apply]);
+
+  useEffect(() => {
+    load();
+    const timer = setInterval(() => {
+      if (Date.now() - lastUpdateRef.current >= FALLBACK_REFRESH_MS) load();
+    }, FALLBACK_REFRESH_MS);
+    return () => clearInterval(timer);
+  }, [load]);
+
+  useServerMessages(useCallback((msg: ServerMessage) => {
+    if (msg.event_type === 'kpi') {
+      apply(msg.payload as KpiSnapshot);
+    }
+  }, [apply]));
+
+  if (!kpi) {
+    return <p className="text-gray-400">{t('common.loading')}</p>;
+  }
+
+  const delta = kpi.sales_today - kpi.sales_same_day_last_week;
+  const deltaPct = kpi.sales_same_day_last_week > 0
+    ? Math.round((delta / kpi.sales_same_day_last_week) * 100)
+    : null;
+  const oldestMinutes = kpi.kitchen_oldest_ticket_at
+    ? Math.floor((kpi.generated_at - kpi.kitchen_oldest_ticket_at) / 60_000)
+    : null;
+
+  return (
+    <>
+      <div className="text-sm text-gray-500 mb-4">
+        {t('statistics.live.business_date', { date: kpi.business_date })}
+        {' · '}
+        {t('statistics.live.updated_at', {
+          time: new Date(kpi.generated_at).toLocaleTimeString([], { hour: '2-digit', minute: '2-digit', second: '2-digit' }),
+        })}
+      </div>
+
+      <div className="grid grid-cols-3 gap-4">
+        <KpiCard
+          icon={delta >= 0 ? TrendingUp : TrendingDown}
+          label={t('statistics.live.sales_today')}
+          value={formatCurrency(kpi.sales_today)}
+          hint={
+            <span className={delta >= 0 ? 'text-green-600' : 'text-red-600'}>
+              {t('statistics.live.vs_last_week', { amount: formatCurrency(kpi.sales_same_day_last_week) })}
+              {deltaPct !== null && ` (${deltaPct >= 0 ? '+' : ''}${deltaPct}%)`}
+            </span>
+          }
+        />
+        <KpiCard
+          icon={Receipt}
+          label={t('statistics.live.average_ticket')}
+          value={formatCurrency(kpi.average_ticket)}
+          hint={<span className="text-gray-500">{t('statistics.live.orders', { count: kpi.orders_today })}</span>}
+        />
+        <KpiCard
+          icon={Users}
+          label={t('statistics.live.covers')}
+          value={String(kpi.covers_today)}
+        />
+        <KpiCard
+          icon={LayoutGrid}
+          label={t('statistics.live.open_tables')}
+          value={String(kpi.open_tables)}
+          hint={
+            <span className="text-gray-500">
+              {t('statistics.live.open_orders', { count: kpi.open_orders, amount: formatCurrency(kpi.open_amount) })}
+            </span>
+          }
+        />
+        <KpiCard
+          icon={ChefHat}
+          label={t('statistics.live.kitchen_backlog')}
+          value={String(kpi.kitchen_backlog_tickets)}
+          hint={
+            <span className={oldestMinutes !== null && oldestMinutes >= 20 ? 'text-red-600' : 'text-gray-500'}>
+              {t('statistics.live.kitchen_items', { count: kpi.kitchen_backlog_items })}
+              {oldestMinutes !== null && ` · ${t('statistics.live.kitchen_oldest', { minutes: oldestMinutes })}`}
+            </span>
+          }
+        />
+      </div>
+    </>
+  );
+};
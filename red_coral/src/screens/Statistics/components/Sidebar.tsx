@@ -6,10 +6,12 @@ import {
   FileText,
   ClipboardList,
   ShieldCheck,
-  AlertTriangle
+  AlertTriangle,
+  Radio
 } from 'lucide-react';
 import { useI18n } from '@/hooks/useI18n';
-import { ActiveTab } from '@/core/domain/types';
+import { usePermission } from '@/hooks/usePermission';
+import { ActiveTab, Permission } from '@/core/domain/types';
 
 interface SidebarProps {
   onBack: () => void;
@@ -19,14 +21,16 @@ interface SidebarProps {
 
 export const Sidebar: React.FC<SidebarProps> = ({ onBack, activeTab, onTabChange }) => {
   const { t } = useI18n();
+  const { hasPermission } = usePermission();
 
   const menuItems = [
     { id: 'overview' as const, icon: TrendingUp, label: t('statistics.sidebar.overview') },
-    { id: 'invoices' as const, icon: FileText, label: t('statistics.sidebar.invoices') },
+    { id: 'live' as const, icon: Radio, label: t('statistics.sidebar.live') },
+    { id: 'invoices' as const, icon: FileText, label: t('statistics.sidebar.invoices'), permission: Permission.REPORTS_FINANCIALS },
     { id: 'reports_shifts' as const, icon: ClipboardList, label: t('statistics.sidebar.reports_shifts') },
     { id: 'red_flags' as const, icon: AlertTriangle, label: t('statistics.sidebar.red_flags') },
     { id: 'audit_log' as const, icon: ShieldCheck, label: t('statistics.sidebar.audit_log') },
-  ];
+  ].filter((item) => !('permission' in item) || hasPermission(item.permission));
 
   return (
     <div className="w-72 bg-white border-r border-gray-200 flex flex-col shrink-0">
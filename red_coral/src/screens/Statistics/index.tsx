@@ -2,6 +2,7 @@ import React, { useState } from 'react';
 import { useI18n } from '@/hooks/useI18n';
 import { Sidebar } from './components/Sidebar';
 import { OverviewTab } from './components/OverviewTab';
+import { LiveKpiTab } from './components/LiveKpiTab';
 import { InvoiceList } from './components/InvoiceList';
 import { ReportsAndShifts } from './components/ReportsAndShifts';
 import { RedFlagsTab } from './components/RedFlagsTab';
@@ -31,6 +32,7 @@ export const StatisticsScreen: React.FC<StatisticsScreenProps> = ({ isVisible, o
         <div className="max-w-7xl mx-auto">
           <h1 className="text-2xl font-bold text-gray-800 mb-6">
             {activeTab === 'overview' && t('statistics.sidebar.overview')}
+            {activeTab === 'live' && t('statistics.sidebar.live')}
             {activeTab === 'invoices' && t('statistics.sidebar.invoices')}
             {activeTab === 'reports_shifts' && t('statistics.sidebar.reports_shifts')}
             {activeTab === 'red_flags' && t('statistics.sidebar.red_flags')}
@@ -38,6 +40,7 @@ export const StatisticsScreen: React.FC<StatisticsScreenProps> = ({ isVisible, o
           </h1>
 
           {activeTab === 'overview' && <OverviewTab />}
+          {activeTab === 'live' && <LiveKpiTab />}
           {activeTab === 'invoices' && <InvoiceList />}
           {activeTab === 'reports_shifts' && <ReportsAndShifts />}
           {activeTab === 'red_flags' && <RedFlagsTab />}
@@ -15,11 +15,11 @@ export type { TimelineTag, DetailTag, TimelineDisplayData, EventRenderer, Transl
 
 // Renderer imports
 import { TableOpenedRenderer, OrderCompletedRenderer, OrderVoidedRenderer } from './orderLifecycle';
-import { ItemsAddedRenderer, ItemModifiedRenderer, ItemRemovedRenderer, ItemCompedRenderer, ItemUncompedRenderer } from './itemOperations';
+import { ItemsAddedRenderer, ItemModifiedRenderer, ItemRemovedRenderer, ItemCompedRenderer, ItemUncompedRenderer, ItemsHeldRenderer, CourseFiredRenderer } from './itemOperations';
 import { PaymentAddedRenderer, PaymentCancelledRenderer } from './payments';
 import { ItemSplitRenderer, AmountSplitRenderer, AaSplitStartedRenderer, AaSplitPaidRenderer, AaSplitCancelledRenderer } from './splits';
-import { OrderMergedRenderer, OrderMovedRenderer, OrderMovedOutRenderer, OrderMergedOutRenderer, TableReassignedRenderer } from './tableAndMerge';
-import { OrderInfoUpdatedRenderer, RuleSkipToggledRenderer, OrderDiscountAppliedRenderer, OrderSurchargeAppliedRenderer, OrderNoteAddedRenderer, MemberLinkedRenderer, MemberUnlinkedRenderer, StampRedeemedRenderer, StampRedemptionCancelledRenderer } from './orderInfo';
+import { OrderMergedRenderer, OrderMovedRenderer, OrderMovedOutRenderer, OrderMergedOutRenderer, TableReassignedRenderer, ItemsTransferredOutRenderer, ItemsTransferredInRenderer } from './tableAndMerge';
+import { OrderInfoUpdatedRenderer, RuleSkipToggledRenderer, OrderDiscountAppliedRenderer, OrderSurchargeAppliedRenderer, OrderNoteAddedRenderer, OrderMetadataSetRenderer, OrderCarriedOverRenderer, MemberLinkedRenderer, MemberUnlinkedRenderer, StampRedeemedRenderer, StampRedemptionCancelledRenderer, PointsRedeemedRenderer, CouponAppliedRenderer } from './orderInfo';
 
 import type { EventRenderer as EventRendererType } from './types';
 import type { TranslateFn } from './types';
@@ -46,16 +46,24 @@ export const EVENT_RENDERERS: Record<OrderEventType, EventRendererType<any>> = {
   ORDER_MOVED: OrderMovedRenderer,
   ORDER_MOVED_OUT: OrderMovedOutRenderer,
   ORDER_MERGED_OUT: OrderMergedOutRenderer,
+  ITEMS_TRANSFERRED_OUT: ItemsTransferredOutRenderer,
+  ITEMS_TRANSFERRED_IN: ItemsTransferredInRenderer,
   TABLE_REASSIGNED: TableReassignedRenderer,
   ORDER_INFO_UPDATED: OrderInfoUpdatedRenderer,
   RULE_SKIP_TOGGLED: RuleSkipToggledRenderer,
   ORDER_DISCOUNT_APPLIED: OrderDiscountAppliedRenderer,
   ORDER_SURCHARGE_APPLIED: OrderSurchargeAppliedRenderer,
+  ITEMS_HELD: ItemsHeldRenderer,
+  COURSE_FIRED: CourseFiredRenderer,
   ORDER_NOTE_ADDED: OrderNoteAddedRenderer,
+  ORDER_METADATA_SET: OrderMetadataSetRenderer,
+  ORDER_CARRIED_OVER: OrderCarriedOverRenderer,
   MEMBER_LINKED: MemberLinkedRenderer,
   MEMBER_UNLINKED: MemberUnlinkedRenderer,
   STAMP_REDEEMED: StampRedeemedRenderer,
   STAMP_REDEMPTION_CANCELLED: StampRedemptionCancelledRenderer,
+  POINTS_REDEEMED: PointsRedeemedRenderer,
+  COUPON_APPLIED: CouponAppliedRenderer,
 };
 
 /**
@@ -4,11 +4,13 @@ import type {
   ItemRemovedPayload,
   ItemCompedPayload,
   ItemUncompedPayload,
+  ItemsHeldPayload,
+  CourseFiredPayload,
   SpecificationInfo,
   ItemOption,
 } from '@/core/domain/types/orderEvent';
 import { formatCurrency } from '@/utils/currency/formatCurrency';
-import { ShoppingBag, Edit3, Trash2, Tag } from 'lucide-react';
+import { ShoppingBag, Edit3, Trash2, Tag, Pause, Flame } from 'lucide-react';
 import type { EventRenderer, TimelineTag } from './types';
 
 export const ItemsAddedRenderer: EventRenderer<ItemsAddedPayload> = {
@@ -213,3 +215,29 @@ export const ItemUncompedRenderer: EventRenderer<ItemUncompedPayload> = {
     };
   }
 };
+
+export const ItemsHeldRenderer: EventRenderer<ItemsHeldPayload> = {
+  render(event, payload, t) {
+    return {
+      title: t('timeline.items_held', { n: payload.course }),
+      details: [],
+      icon: Pause,
+      colorClass: 'bg-amber-400',
+      timestamp: event.timestamp,
+      tags: payload.instance_ids.map((id) => ({ text: `#${id.slice(-5)}`, type: 'item' as const })),
+    };
+  }
+};
+
+export const CourseFiredRenderer: EventRenderer<CourseFiredPayload> = {
+  render(event, payload, t) {
+    const items = payload.items || [];
+    return {
+      title: t('timeline.course_fired', { n: payload.course }),
+      details: items.map((item) => `${item.name} x${item.quantity}`),
+      icon: Flame,
+      colorClass: 'bg-orange-500',
+      timestamp: event.timestamp,
+    };
+  }
+};
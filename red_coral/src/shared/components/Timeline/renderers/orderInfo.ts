@@ -4,13 +4,17 @@ import type {
   OrderDiscountAppliedPayload,
   OrderSurchargeAppliedPayload,
   OrderNoteAddedPayload,
+  OrderMetadataSetPayload,
+  OrderCarriedOverPayload,
   MemberLinkedPayload,
   MemberUnlinkedPayload,
   StampRedeemedPayload,
   StampRedemptionCancelledPayload,
+  PointsRedeemedPayload,
+  CouponAppliedPayload,
 } from '@/core/domain/types/orderEvent';
 import { formatCurrency } from '@/utils/currency/formatCurrency';
-import { Edit3, Tag, UserPlus, UserMinus, Award } from 'lucide-react';
+import { Edit3, Tag, UserPlus, UserMinus, Award, CalendarClock, Ticket } from 'lucide-react';
 import type { EventRenderer, DetailTag } from './types';
 
 export const OrderInfoUpdatedRenderer: EventRenderer<OrderInfoUpdatedPayload> = {
@@ -143,6 +147,37 @@ export const OrderNoteAddedRenderer: EventRenderer<OrderNoteAddedPayload> = {
   }
 };
 
+export const OrderMetadataSetRenderer: EventRenderer<OrderMetadataSetPayload> = {
+  render(event, payload, t) {
+    const details = Object.entries(payload.changes).map(([key, value]) => {
+      const previous = payload.previous?.[key];
+      if (value === '') return `${key}: ${previous ?? ''} → ${t('timeline.labels.removed')}`;
+      return previous ? `${key}: ${previous} → ${value}` : `${key}: ${value}`;
+    });
+
+    return {
+      title: t('timeline.metadata_set'),
+      details,
+      icon: Edit3,
+      colorClass: 'bg-blue-400',
+      timestamp: event.timestamp,
+    };
+  }
+};
+
+export const OrderCarriedOverRenderer: EventRenderer<OrderCarriedOverPayload> = {
+  render(event, payload, t) {
+    return {
+      title: t('timeline.carried_over'),
+      summary: `${payload.from_business_date} → ${payload.to_business_date}`,
+      details: [`${t('timeline.labels.policy')}: ${t(`settings.store.carry_over.${payload.policy}`)}`],
+      icon: CalendarClock,
+      colorClass: 'bg-amber-500',
+      timestamp: event.timestamp,
+    };
+  }
+};
+
 export const MemberLinkedRenderer: EventRenderer<MemberLinkedPayload> = {
   render(event, payload, t) {
     return {
@@ -200,3 +235,31 @@ export const StampRedemptionCancelledRenderer: EventRenderer<StampRedemptionCanc
     };
   }
 };
+
+export const PointsRedeemedRenderer: EventRenderer<PointsRedeemedPayload> = {
+  render(event, payload, t) {
+    const isClearing = payload.points === 0;
+    return {
+      title: isClearing ? t('timeline.points_redemption_cleared') : t('timeline.points_redeemed'),
+      summary: isClearing ? undefined : `-${formatCurrency(payload.amount)}`,
+      details: isClearing ? [] : [t('checkout.points.redeem_desc', { points: payload.points })],
+      icon: Award,
+      colorClass: isClearing ? 'bg-gray-400' : 'bg-amber-400',
+      timestamp: event.timestamp,
+    };
+  }
+};
+
+export const CouponAppliedRenderer: EventRenderer<CouponAppliedPayload> = {
+  render(event, payload, t) {
+    const coupon = payload.coupon;
+    return {
+      title: coupon ? t('timeline.coupon_applied') : t('timeline.coupon_removed'),
+      summary: coupon ? `-${formatCurrency(coupon.amount)}` : undefined,
+      details: coupon ? [`${coupon.code} · ${coupon.name}`] : [],
+      icon: Ticket,
+      colorClass: coupon ? 'bg-teal-500' : 'bg-gray-400',
+      timestamp: event.timestamp,
+    };
+  }
+};
@@ -4,6 +4,8 @@ import type {
   OrderMovedOutPayload,
   OrderMergedOutPayload,
   TableReassignedPayload,
+  ItemsTransferredOutPayload,
+  ItemsTransferredInPayload,
 } from '@/core/domain/types/orderEvent';
 import { ArrowRight, ArrowLeft } from 'lucide-react';
 import type { EventRenderer } from './types';
@@ -112,3 +114,42 @@ export const TableReassignedRenderer: EventRenderer<TableReassignedPayload> = {
     };
   }
 };
+
+export const ItemsTransferredOutRenderer: EventRenderer<ItemsTransferredOutPayload> = {
+  render(event, payload, t) {
+    const details: string[] = [];
+
+    if (payload.instance_ids && payload.instance_ids.length > 0) {
+      details.push(`${t('timeline.labels.items')}: ${payload.instance_ids.length}`);
+    }
+
+    return {
+      title: t('timeline.items_transferred_out'),
+      summary: payload.target_table_name ? `${t('timeline.to')} ${payload.target_table_name}` : '',
+      details,
+      icon: ArrowRight,
+      colorClass: 'bg-indigo-500',
+      timestamp: event.timestamp,
+    };
+  }
+};
+
+export const ItemsTransferredInRenderer: EventRenderer<ItemsTransferredInPayload> = {
+  render(event, payload, t) {
+    const details: string[] = [];
+
+    if (payload.items && payload.items.length > 0) {
+      const itemCount = payload.items.reduce((sum, item) => sum + item.quantity, 0);
+      details.push(`${t('timeline.labels.items')}: ${itemCount}`);
+    }
+
+    return {
+      title: t('timeline.items_transferred_in'),
+      summary: payload.source_table_name ? `${t('timeline.from')} ${payload.source_table_name}` : '',
+      details,
+      icon: ArrowLeft,
+      colorClass: 'bg-indigo-500',
+      timestamp: event.timestamp,
+    };
+  }
+};
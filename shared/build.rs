@@ -53,6 +53,7 @@ export const ErrorCode = {
   TokenExpired: 1003,
   SessionExpired: 1005,
   AccountDisabled: 1007,
+  SessionLocked: 1008,
 
   // 2xxx: Permission
   PermissionDenied: 2001,
@@ -99,6 +100,7 @@ export const ErrorCode = {
   OrderAlreadyUpgraded: 4013,
   ImportInvalidFormat: 4015,
   ExportFailed: 4016,
+  OrderImported: 4017,
 
   // 6xxx: Product
   ProductNotFound: 6001,
@@ -122,15 +124,24 @@ export const ErrorCode = {
   LabelTemplateNotFound: 6701,
   PriceRuleNotFound: 6801,
   PriceRuleValueOutOfRange: 6802,
+  PriceRuleConflict: 6803,
 
   // 7xxx: Table
   TableNotFound: 7001,
   TableOccupied: 7002,
+  TableAlreadyJoined: 7003,
+  TableGroupNotFound: 7004,
   ZoneNotFound: 7101,
   ZoneHasTables: 7102,
   TableHasOrders: 7104,
   ShiftNotFound: 7201,
   DailyReportNotFound: 7301,
+  DailyReportBlocked: 7302,
+  EventBookingNotFound: 7401,
+  EventBookingInvalidState: 7402,
+  ReservationNotFound: 7501,
+  ReservationInvalidState: 7502,
+  ReservationConflict: 7503,
 
   // 8xxx: Employee
   EmployeeNotFound: 8001,
@@ -201,6 +201,7 @@ impl SignedBinding {
     }
 
     /// 使用 Tenant CA 私钥签名
+    #[cfg(not(target_arch = "wasm32"))]
     pub fn sign(mut self, tenant_ca_key_pem: &str) -> Result<Self, String> {
         let data = self.signable_data();
         let sig_bytes = crab_cert::sign(tenant_ca_key_pem, data.as_bytes())
@@ -210,6 +211,7 @@ impl SignedBinding {
     }
 
     /// 验证签名
+    #[cfg(not(target_arch = "wasm32"))]
     pub fn verify_signature(&self, tenant_ca_cert_pem: &str) -> Result<(), String> {
         if self.signature.is_empty() {
             return Err("Binding is not signed".into());
@@ -224,6 +226,7 @@ impl SignedBinding {
     }
 
     /// 验证硬件绑定
+    #[cfg(not(target_arch = "wasm32"))]
     pub fn verify_device(&self) -> Result<(), String> {
         let current_device_id = crab_cert::generate_hardware_id();
         if self.device_id != current_device_id {
@@ -236,6 +239,7 @@ impl SignedBinding {
     }
 
     /// 完整验证 (签名 + 硬件 + 时钟)
+    #[cfg(not(target_arch = "wasm32"))]
     pub fn validate(&self, tenant_ca_cert_pem: &str) -> Result<(), String> {
         self.verify_signature(tenant_ca_cert_pem)?;
         self.verify_device()?;
@@ -399,6 +403,7 @@ impl SubscriptionInfo {
     }
 
     /// 使用 Tenant CA 私钥签名
+    #[cfg(not(target_arch = "wasm32"))]
     pub fn sign(mut self, tenant_ca_key_pem: &str) -> Result<Self, String> {
         let data = self.signable_data();
         let sig_bytes = crab_cert::sign(tenant_ca_key_pem, data.as_bytes())
@@ -408,6 +413,7 @@ impl SubscriptionInfo {
     }
 
     /// 验证签名
+    #[cfg(not(target_arch = "wasm32"))]
     pub fn verify_signature(&self, tenant_ca_cert_pem: &str) -> Result<(), String> {
         if self.signature.is_empty() {
             return Err("Subscription is not signed".into());
@@ -427,6 +433,7 @@ impl SubscriptionInfo {
     }
 
     /// 完整验证 (签名 + 有效期)
+    #[cfg(not(target_arch = "wasm32"))]
     pub fn validate(&self, tenant_ca_cert_pem: &str) -> Result<(), String> {
         self.verify_signature(tenant_ca_cert_pem)?;
         if self.is_signature_expired() {
@@ -551,11 +558,13 @@ pub enum PlanType {
 
 // === Base64 helpers ===
 
+#[cfg(not(target_arch = "wasm32"))]
 fn base64_encode(data: &[u8]) -> String {
     use base64::{Engine as _, engine::general_purpose::STANDARD};
     STANDARD.encode(data)
 }
 
+#[cfg(not(target_arch = "wasm32"))]
 fn base64_decode(s: &str) -> Result<Vec<u8>, base64::DecodeError> {
     use base64::{Engine as _, engine::general_purpose::STANDARD};
     STANDARD.decode(s)
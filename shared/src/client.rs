@@ -35,6 +35,30 @@ pub struct UserInfo {
     pub is_system: bool,
     pub is_active: bool,
     pub created_at: i64,
+    /// 员工照片 (`data:image/jpeg;base64,...`)，POS 显示正在使用该凭据的人 (防代打卡)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub photo: Option<String>,
+}
+
+impl UserInfo {
+    /// 检查是否拥有指定权限 (规则与 edge-server `CurrentUser::has_permission` 一致)
+    ///
+    /// - 管理员角色 (`role_name == "admin"`) 与 `"all"` 拥有所有权限
+    /// - 精确匹配或 `:*` 通配符前缀匹配
+    pub fn has_permission(&self, permission: &str) -> bool {
+        if self.role_name == "admin" {
+            return true;
+        }
+        self.permissions.iter().any(|p| {
+            p == "all"
+                || p == permission
+                || p.strip_suffix(":*").is_some_and(|prefix| {
+                    permission
+                        .strip_prefix(prefix)
+                        .is_some_and(|rest| rest.starts_with(':'))
+                })
+        })
+    }
 }
 
 /// Current user response (same as UserInfo)
@@ -0,0 +1,74 @@
+//! Tenant email templates shared by edge and cloud
+//!
+//! The cloud owns the templates (tenant overrides + defaults) and the relay;
+//! the edge names a kind and supplies its variables via
+//! [`CloudMessage::SendEmail`](super::CloudMessage::SendEmail).
+
+use std::collections::BTreeMap;
+
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum EmailTemplateKind {
+    Receipt,
+    Report,
+    ReservationConfirmation,
+}
+
+impl EmailTemplateKind {
+    pub const ALL: [EmailTemplateKind; 3] = [
+        EmailTemplateKind::Receipt,
+        EmailTemplateKind::Report,
+        EmailTemplateKind::ReservationConfirmation,
+    ];
+
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            EmailTemplateKind::Receipt => "receipt",
+            EmailTemplateKind::Report => "report",
+            EmailTemplateKind::ReservationConfirmation => "reservation_confirmation",
+        }
+    }
+
+    pub fn parse(s: &str) -> Option<Self> {
+        Self::ALL.into_iter().find(|k| k.as_str() == s)
+    }
+
+    /// Placeholders the sending feature provides for this kind
+    pub fn variables(&self) -> &'static [&'static str] {
+        match self {
+            EmailTemplateKind::Receipt => &[
+                "store_name",
+                "customer_name",
+                "receipt_number",
+                "date",
+                "total",
+                "items",
+            ],
+            EmailTemplateKind::Report => &["store_name", "report_name", "date", "summary"],
+            EmailTemplateKind::ReservationConfirmation => &[
+                "store_name",
+                "customer_name",
+                "date",
+                "time",
+                "party_size",
+                "store_phone",
+            ],
+        }
+    }
+
+    /// Kinds an edge may ask the cloud to send (reports are sent by the cloud
+    /// itself when the daily report syncs)
+    pub fn edge_requestable(&self) -> bool {
+        !matches!(self, EmailTemplateKind::Report)
+    }
+}
+
+/// Edge → Cloud: render the tenant's `kind` template with `vars` and send it to `to`
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct TemplatedEmail {
+    pub kind: EmailTemplateKind,
+    pub to: String,
+    pub vars: BTreeMap<String, String>,
+}
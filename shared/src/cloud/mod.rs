@@ -1,7 +1,10 @@
 //! Cloud sync types for edge-server → crab-cloud data synchronization
 
+pub mod email;
 pub mod store_op;
+pub mod support;
 pub mod sync;
+pub mod transfer;
 pub mod ws;
 
 pub use sync::*;
@@ -9,6 +9,7 @@ use serde::{Deserialize, Serialize};
 use crate::models::{
     attribute::{Attribute, AttributeCreate, AttributeUpdate},
     category::{Category, CategoryCreate, CategoryUpdate},
+    coupon::{Coupon, CouponCreate, CouponUpdate},
     dining_table::{DiningTable, DiningTableCreate, DiningTableUpdate},
     employee::{Employee, EmployeeCreate, EmployeeUpdate},
     label_template::{LabelTemplate, LabelTemplateCreate, LabelTemplateUpdate},
@@ -187,6 +188,20 @@ pub enum StoreOp {
         id: i64,
     },
 
+    // ── Coupon ──
+    CreateCoupon {
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        id: Option<i64>,
+        data: CouponCreate,
+    },
+    UpdateCoupon {
+        id: i64,
+        data: CouponUpdate,
+    },
+    DeleteCoupon {
+        id: i64,
+    },
+
     // ── StoreInfo (singleton) ──
     UpdateStoreInfo {
         data: StoreInfoUpdate,
@@ -332,6 +347,7 @@ pub enum StoreOpData {
     Zone(Zone),
     Table(DiningTable),
     LabelTemplate(LabelTemplate),
+    Coupon(Coupon),
     StoreInfo(StoreInfo),
 }
 
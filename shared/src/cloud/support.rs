@@ -0,0 +1,156 @@
+//! Remote support session types (edge ↔ crab-cloud)
+//!
+//! A store opens a support session from the POS (with explicit consent). While
+//! the session is open the edge streams recent, sanitized log lines to
+//! crab-cloud and relays chat messages between the store and the support agent.
+//! Sessions expire on their own; either side can close them earlier.
+
+use serde::{Deserialize, Serialize};
+
+/// Default session length (minutes)
+pub const DEFAULT_SUPPORT_SESSION_MINUTES: u32 = 60;
+/// Upper bound for a session length (minutes)
+pub const MAX_SUPPORT_SESSION_MINUTES: u32 = 240;
+/// Max chat message length (chars)
+pub const MAX_SUPPORT_MESSAGE_LEN: usize = 2000;
+/// Max log lines per `SupportLogs` message
+pub const MAX_SUPPORT_LOG_LINES: usize = 500;
+
+/// Support session (opened on the edge)
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct SupportSession {
+    pub session_id: i64,
+    /// What the store needs help with
+    pub subject: String,
+    /// Employee who opened the session
+    pub opened_by: String,
+    /// Employee who consented to log sharing (same as opener today)
+    pub consent_by: String,
+    pub opened_at: i64,
+    /// Log streaming stops and the session closes at this time
+    pub expires_at: i64,
+}
+
+impl SupportSession {
+    pub fn is_expired(&self, now: i64) -> bool {
+        now >= self.expires_at
+    }
+}
+
+/// Diagnostics snapshot bundled when the session opens
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct SupportDiagnostics {
+    pub app_version: String,
+    pub os: String,
+    pub arch: String,
+    /// Server instance epoch (changes on every restart)
+    pub epoch: String,
+    pub active_orders: usize,
+    pub products: usize,
+    pub categories: usize,
+    /// Components still warming up or failed (name → state)
+    #[serde(default)]
+    pub readiness: serde_json::Value,
+    pub database_bytes: Option<u64>,
+    pub collected_at: i64,
+}
+
+/// Message author side
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+pub enum SupportAuthor {
+    /// Store staff (POS)
+    Store,
+    /// Support agent (crab-cloud)
+    Agent,
+}
+
+impl SupportAuthor {
+    /// 数据库 TEXT 列表示
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            SupportAuthor::Store => "STORE",
+            SupportAuthor::Agent => "AGENT",
+        }
+    }
+}
+
+impl TryFrom<String> for SupportAuthor {
+    type Error = String;
+
+    fn try_from(value: String) -> Result<Self, Self::Error> {
+        match value.as_str() {
+            "STORE" => Ok(SupportAuthor::Store),
+            "AGENT" => Ok(SupportAuthor::Agent),
+            other => Err(format!("invalid support author: {other}")),
+        }
+    }
+}
+
+/// Chat message within a support session
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct SupportMessage {
+    pub id: i64,
+    pub session_id: i64,
+    pub author: SupportAuthor,
+    pub author_name: String,
+    pub text: String,
+    pub sent_at: i64,
+}
+
+/// Open a support session (POS → edge)
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SupportSessionOpen {
+    pub subject: String,
+    /// Explicit consent to share diagnostics and logs; must be true
+    #[serde(default)]
+    pub consent: bool,
+    /// Session length (default [`DEFAULT_SUPPORT_SESSION_MINUTES`])
+    pub duration_minutes: Option<u32>,
+}
+
+/// Send a chat message (POS → edge, agent → crab-cloud)
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SupportMessageCreate {
+    pub text: String,
+}
+
+/// Current session with its conversation (edge API response)
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SupportSessionView {
+    pub session: SupportSession,
+    pub messages: Vec<SupportMessage>,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_author_roundtrip() {
+        for author in [SupportAuthor::Store, SupportAuthor::Agent] {
+            assert_eq!(
+                SupportAuthor::try_from(author.as_str().to_string()),
+                Ok(author)
+            );
+            assert_eq!(
+                serde_json::to_string(&author).unwrap(),
+                format!("\"{}\"", author.as_str())
+            );
+        }
+    }
+
+    #[test]
+    fn test_session_expiry() {
+        let session = SupportSession {
+            session_id: 1,
+            subject: "Printer offline".into(),
+            opened_by: "Ana".into(),
+            consent_by: "Ana".into(),
+            opened_at: 1_000,
+            expires_at: 2_000,
+        };
+        assert!(!session.is_expired(1_999));
+        assert!(session.is_expired(2_000));
+    }
+}
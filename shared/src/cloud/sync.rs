@@ -21,6 +21,8 @@ pub enum SyncResource {
     Attribute,
     AttributeBinding,
     Zone,
+    /// Per-zone product price / availability overrides
+    ZoneProductOverride,
     DiningTable,
     Employee,
     PriceRule,
@@ -34,6 +36,8 @@ pub enum SyncResource {
     LabelTemplate,
     Member,
     MarketingGroup,
+    /// Coupon codes (bidirectional: cloud pushes definitions, edge syncs redemption counts)
+    Coupon,
     /// Archived orders (edge → cloud only, not in initial sync)
     ArchivedOrder,
     /// Order sync events (edge-internal, for live order push to cloud)
@@ -48,8 +52,22 @@ pub enum SyncResource {
     ChainEntry,
     /// Chain break marker (edge → cloud, records broken chain link)
     ChainBreak,
+    /// Archived receipt bytes (edge → cloud only, opt-in per store)
+    ReceiptArtifact,
     /// Role resource (client-visible for sync status)
     Role,
+    /// Customer display slides (edge → clients only)
+    DisplaySlide,
+    /// Event bookings (edge → clients only)
+    EventBooking,
+    /// Joined table groups (edge → clients only)
+    TableGroup,
+    /// 86'd products (edge → clients only)
+    EightySix,
+    /// Inventory stock levels (edge → clients only)
+    StockLevel,
+    /// Table reservations (edge → clients only)
+    Reservation,
 }
 
 impl SyncResource {
@@ -61,11 +79,13 @@ impl SyncResource {
         Self::Attribute,
         Self::AttributeBinding,
         Self::Zone,
+        Self::ZoneProductOverride,
         Self::DiningTable,
         Self::Employee,
         Self::PriceRule,
         Self::StoreInfo,
         Self::LabelTemplate,
+        Self::Coupon,
     ];
 
     /// Resources that cloud accepts via live sync (extract_sync_item whitelist)
@@ -76,6 +96,7 @@ impl SyncResource {
         Self::Attribute,
         Self::AttributeBinding,
         Self::Zone,
+        Self::ZoneProductOverride,
         Self::DiningTable,
         Self::Employee,
         Self::PriceRule,
@@ -83,6 +104,7 @@ impl SyncResource {
         Self::Shift,
         Self::DailyReport,
         Self::LabelTemplate,
+        Self::Coupon,
     ];
 
     /// Resources exposed in the client sync/status endpoint
@@ -98,6 +120,10 @@ impl SyncResource {
         Self::PriceRule,
         Self::PrintDestination,
         Self::LabelTemplate,
+        Self::DisplaySlide,
+        Self::TableGroup,
+        Self::EightySix,
+        Self::StockLevel,
     ];
 
     pub fn as_str(&self) -> &'static str {
@@ -108,6 +134,7 @@ impl SyncResource {
             Self::Attribute => "attribute",
             Self::AttributeBinding => "attribute_binding",
             Self::Zone => "zone",
+            Self::ZoneProductOverride => "zone_product_override",
             Self::DiningTable => "dining_table",
             Self::Employee => "employee",
             Self::PriceRule => "price_rule",
@@ -121,6 +148,7 @@ impl SyncResource {
             Self::LabelTemplate => "label_template",
             Self::Member => "member",
             Self::MarketingGroup => "marketing_group",
+            Self::Coupon => "coupon",
             Self::ArchivedOrder => "archived_order",
             Self::CreditNote => "credit_note",
             Self::Invoice => "invoice",
@@ -128,7 +156,14 @@ impl SyncResource {
             Self::Anulacion => "anulacion",
             Self::ChainEntry => "chain_entry",
             Self::ChainBreak => "chain_break",
+            Self::ReceiptArtifact => "receipt_artifact",
             Self::Role => "role",
+            Self::DisplaySlide => "display_slide",
+            Self::EventBooking => "event_booking",
+            Self::TableGroup => "table_group",
+            Self::EightySix => "eighty_six",
+            Self::StockLevel => "stock_level",
+            Self::Reservation => "reservation",
         }
     }
 
@@ -148,6 +183,7 @@ impl SyncResource {
             Self::Zone => Some(50),
             Self::DiningTable => Some(500),
             Self::LabelTemplate => Some(50),
+            Self::Coupon => Some(5000),
             _ => None,
         }
     }
@@ -346,6 +382,9 @@ pub struct OrderDetailPayload {
     pub customer_email: Option<String>,
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub customer_phone: Option<String>,
+    /// Integrator metadata (外卖平台单号、酒店房号等)
+    #[serde(default, skip_serializing_if = "std::collections::BTreeMap::is_empty")]
+    pub metadata: std::collections::BTreeMap<String, String>,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -530,6 +569,22 @@ pub struct ChainBreakSync {
     pub created_at: i64,
 }
 
+// ── Receipt archive sync types ──
+
+/// Archived receipt artifact synced to cloud (writes to store_receipt_artifacts)
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ReceiptArtifactSync {
+    pub id: i64,
+    pub receipt_number: String,
+    pub kind: crate::models::ReceiptArtifactKind,
+    /// Hex SHA-256 of the decoded content (cloud re-verifies before storing)
+    pub sha256: String,
+    pub reprint: bool,
+    /// Base64 artifact bytes
+    pub content_base64: String,
+    pub created_at: i64,
+}
+
 // ── Anulación sync types ──
 
 /// Anulación data synced to cloud for Verifactu AEAT submission
@@ -1159,6 +1214,7 @@ mod tests {
                 customer_address: None,
                 customer_email: None,
                 customer_phone: None,
+                metadata: Default::default(),
                 mg_discount_amount: 0.0,
                 marketing_group_name: None,
             },
@@ -1230,6 +1286,7 @@ mod tests {
                 customer_address: None,
                 customer_email: None,
                 customer_phone: None,
+                metadata: Default::default(),
                 mg_discount_amount: 0.0,
                 marketing_group_name: None,
             },
@@ -1384,6 +1441,7 @@ mod tests {
                 customer_address: None,
                 customer_email: None,
                 customer_phone: None,
+                metadata: Default::default(),
                 mg_discount_amount: 0.0,
                 marketing_group_name: None,
             },
@@ -1792,6 +1850,7 @@ mod tests {
                     customer_address: None,
                     customer_email: None,
                     customer_phone: None,
+                    metadata: Default::default(),
                     mg_discount_amount: 0.0,
                     marketing_group_name: None,
                 },
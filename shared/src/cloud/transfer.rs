@@ -0,0 +1,66 @@
+//! Inter-store stock transfer relay types (edge ↔ crab-cloud ↔ edge)
+//!
+//! The sending edge posts a [`TransferDispatch`] when goods leave; crab-cloud
+//! persists it and relays it to the receiving store of the same tenant. The
+//! receiving edge confirms the quantities that arrived and answers with a
+//! [`TransferReceipt`], relayed back to the sender. Both documents are
+//! re-delivered on reconnect until the recipient acknowledges them, so
+//! handlers on every side must be idempotent per `transfer_id`.
+
+use serde::{Deserialize, Serialize};
+
+/// Max lines per transfer document
+pub const MAX_TRANSFER_LINES: usize = 200;
+
+/// Another store of the same tenant that can receive transfers
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct TransferPeer {
+    pub store_id: i64,
+    pub name: String,
+}
+
+/// Goods sent from one store to another
+///
+/// `store_id` / `store_name` name the counterpart: the destination when the
+/// edge sends it, the source when crab-cloud relays it (cloud fills both).
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct TransferDispatch {
+    /// Snowflake id assigned by the sending edge, shared by both stores
+    pub transfer_id: i64,
+    pub store_id: i64,
+    #[serde(default)]
+    pub store_name: String,
+    pub note: Option<String>,
+    pub sent_by: String,
+    pub sent_at: i64,
+    pub lines: Vec<TransferDispatchLine>,
+}
+
+/// One item of a dispatch (matched on the receiving side by barcode, then name)
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct TransferDispatchLine {
+    pub line_no: i64,
+    pub product_name: String,
+    pub spec_name: Option<String>,
+    pub barcode: Option<String>,
+    pub quantity: i64,
+}
+
+/// Goods-received confirmation
+///
+/// `store_id` names the counterpart, like [`TransferDispatch`].
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct TransferReceipt {
+    pub transfer_id: i64,
+    pub store_id: i64,
+    pub received_by: String,
+    pub received_at: i64,
+    pub lines: Vec<TransferReceiptLine>,
+}
+
+/// Quantity that arrived for one dispatch line
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub struct TransferReceiptLine {
+    pub line_no: i64,
+    pub received_quantity: i64,
+}
@@ -7,13 +7,19 @@ use serde::{Deserialize, Serialize};
 use crate::models::CatalogExport;
 use crate::order::{OrderEvent, OrderSnapshot};
 
+use super::email::TemplatedEmail;
 use super::store_op::{StoreOp, StoreOpResult};
+use super::support::{SupportDiagnostics, SupportMessage, SupportSession};
+use super::transfer::{TransferDispatch, TransferPeer, TransferReceipt};
 use super::{CloudSyncError, CloudSyncItem};
 
 /// Duplex message protocol over WebSocket
 ///
-/// Edge → Cloud: SyncBatch, RpcResult, ActiveOrderSnapshot, ActiveOrderRemoved
-/// Cloud → Edge: SyncAck, Rpc
+/// Edge → Cloud: SyncBatch, RpcResult, ActiveOrderSnapshot, ActiveOrderRemoved,
+/// SupportSessionOpened, SupportLogs, SendEmail
+/// Cloud → Edge: SyncAck, Rpc, TransferPeers
+/// 双向: SupportChat, SupportSessionClosed, StockTransferDispatched,
+/// StockTransferReceived, StockTransferAck
 #[derive(Debug, Clone, Serialize, Deserialize)]
 #[serde(tag = "type")]
 pub enum CloudMessage {
@@ -69,6 +75,38 @@ pub enum CloudMessage {
 
     /// 活跃订单已移除（完成/作废/合并）
     ActiveOrderRemoved { order_id: i64 },
+
+    // === 远程支持 ===
+    /// Edge → Cloud: 支持会话已开启 (重连后重发，cloud 端幂等)
+    SupportSessionOpened {
+        session: Box<SupportSession>,
+        diagnostics: Box<SupportDiagnostics>,
+    },
+
+    /// Edge → Cloud: 脱敏后的最新日志行
+    SupportLogs { session_id: i64, lines: Vec<String> },
+
+    /// 双向: 门店 ↔ 支持人员聊天消息
+    SupportChat { message: SupportMessage },
+
+    /// 双向: 会话结束 (任一方关闭或到期)
+    SupportSessionClosed { session_id: i64 },
+
+    /// Edge → Cloud: 按租户邮件模板发送 (小票 / 预订确认)
+    SendEmail { email: Box<TemplatedEmail> },
+
+    // === 门店间调拨 ===
+    /// Cloud → Edge: 同租户可接收调拨的其他门店 (连接建立后发送)
+    TransferPeers { stores: Vec<TransferPeer> },
+
+    /// 双向: 调拨单发出 (发货门店 → cloud → 收货门店)
+    StockTransferDispatched { dispatch: Box<TransferDispatch> },
+
+    /// 双向: 收货确认 (收货门店 → cloud → 发货门店)
+    StockTransferReceived { receipt: Box<TransferReceipt> },
+
+    /// Edge → Cloud: 已处理 cloud 转发的调拨单 / 收货确认 (停止重发)
+    StockTransferAck { transfer_id: i64 },
 }
 
 /// 强类型 RPC 载荷 (cloud↔edge 双向)
@@ -226,6 +264,8 @@ mod tests {
                         kitchen_print_name: None,
                         is_kitchen_print_enabled: None,
                         is_label_print_enabled: None,
+                        kitchen_print_destinations: vec![],
+                        label_print_destinations: vec![],
                         external_id: None,
                         tags: None,
                         specs: vec![ProductSpecInput {
@@ -290,4 +330,88 @@ mod tests {
             _ => panic!("Expected RpcResult"),
         }
     }
+
+    #[test]
+    fn test_support_chat_roundtrip() {
+        use crate::cloud::support::SupportAuthor;
+
+        let msg = CloudMessage::SupportChat {
+            message: SupportMessage {
+                id: 7,
+                session_id: 3,
+                author: SupportAuthor::Agent,
+                author_name: "Support".into(),
+                text: "Restart the kitchen printer".into(),
+                sent_at: 1700000000000,
+            },
+        };
+
+        let json = serde_json::to_string(&msg).unwrap();
+        assert!(json.contains(r#""type":"SupportChat"#));
+        assert!(json.contains(r#""author":"AGENT"#));
+
+        let deserialized: CloudMessage = serde_json::from_str(&json).unwrap();
+        let CloudMessage::SupportChat { message } = deserialized else {
+            panic!("Expected SupportChat");
+        };
+        assert_eq!(message.session_id, 3);
+        assert_eq!(message.author, SupportAuthor::Agent);
+    }
+
+    #[test]
+    fn test_stock_transfer_roundtrip() {
+        use crate::cloud::transfer::TransferDispatchLine;
+
+        let msg = CloudMessage::StockTransferDispatched {
+            dispatch: Box::new(TransferDispatch {
+                transfer_id: 9,
+                store_id: 2,
+                store_name: String::new(),
+                note: None,
+                sent_by: "Ana".into(),
+                sent_at: 1700000000000,
+                lines: vec![TransferDispatchLine {
+                    line_no: 1,
+                    product_name: "Beer".into(),
+                    spec_name: None,
+                    barcode: Some("8410000".into()),
+                    quantity: 24,
+                }],
+            }),
+        };
+
+        let json = serde_json::to_string(&msg).unwrap();
+        assert!(json.contains(r#""type":"StockTransferDispatched"#));
+
+        let deserialized: CloudMessage = serde_json::from_str(&json).unwrap();
+        let CloudMessage::StockTransferDispatched { dispatch } = deserialized else {
+            panic!("Expected StockTransferDispatched");
+        };
+        assert_eq!(dispatch.transfer_id, 9);
+        assert_eq!(dispatch.lines[0].quantity, 24);
+    }
+
+    #[test]
+    fn test_send_email_roundtrip() {
+        use crate::cloud::email::EmailTemplateKind;
+
+        let msg = CloudMessage::SendEmail {
+            email: Box::new(TemplatedEmail {
+                kind: EmailTemplateKind::ReservationConfirmation,
+                to: "ana@example.com".into(),
+                vars: [("party_size".to_string(), "4".to_string())].into(),
+            }),
+        };
+
+        let json = serde_json::to_string(&msg).unwrap();
+        assert!(json.contains(r#""type":"SendEmail"#));
+        assert!(json.contains(r#""kind":"reservation_confirmation"#));
+
+        let deserialized: CloudMessage = serde_json::from_str(&json).unwrap();
+        let CloudMessage::SendEmail { email } = deserialized else {
+            panic!("Expected SendEmail");
+        };
+        assert_eq!(email.to, "ana@example.com");
+        assert_eq!(email.vars["party_size"], "4");
+    }
 }
@@ -52,6 +52,8 @@ pub enum ErrorCode {
     SessionExpired = 1005,
     /// Account is disabled
     AccountDisabled = 1007,
+    /// Terminal session is locked (idle auto-lock), re-enter credentials
+    SessionLocked = 1008,
 
     // ==================== 2xxx: Permission ====================
     /// Permission denied
@@ -140,6 +142,8 @@ pub enum ErrorCode {
     ImportInvalidFormat = 4015,
     /// Export failed: internal error during export
     ExportFailed = 4016,
+    /// Imported historical order has no fiscal record
+    OrderImported = 4017,
 
     // ==================== 6xxx: Product ====================
     /// Product not found
@@ -186,12 +190,18 @@ pub enum ErrorCode {
     PriceRuleNotFound = 6801,
     /// Price rule value out of range (percentage/amount)
     PriceRuleValueOutOfRange = 6802,
+    /// Price rule overlaps another rule with the same priority
+    PriceRuleConflict = 6803,
 
     // ==================== 7xxx: Table ====================
     /// Table not found
     TableNotFound = 7001,
     /// Table is occupied
     TableOccupied = 7002,
+    /// Table is already joined to a table group
+    TableAlreadyJoined = 7003,
+    /// Table group not found
+    TableGroupNotFound = 7004,
     /// Zone not found
     ZoneNotFound = 7101,
     /// Zone has tables
@@ -203,6 +213,18 @@ pub enum ErrorCode {
     ShiftNotFound = 7201,
     /// Daily report not found
     DailyReportNotFound = 7301,
+    /// Daily report blocked by orders still open from that business day
+    DailyReportBlocked = 7302,
+    /// Event booking not found
+    EventBookingNotFound = 7401,
+    /// Event booking status does not allow this operation
+    EventBookingInvalidState = 7402,
+    /// Reservation not found
+    ReservationNotFound = 7501,
+    /// Reservation status does not allow this operation
+    ReservationInvalidState = 7502,
+    /// Table already reserved for an overlapping time slot
+    ReservationConflict = 7503,
 
     // ==================== 8xxx: Employee ====================
     /// Employee not found
@@ -297,6 +319,7 @@ impl ErrorCode {
             ErrorCode::TokenExpired => "Authentication token has expired",
             ErrorCode::SessionExpired => "Session has expired",
             ErrorCode::AccountDisabled => "Account is disabled",
+            ErrorCode::SessionLocked => "Session is locked",
 
             // Permission
             ErrorCode::PermissionDenied => "Permission denied",
@@ -348,6 +371,7 @@ impl ErrorCode {
             }
             ErrorCode::ImportInvalidFormat => "Import failed: invalid ZIP or catalog format",
             ErrorCode::ExportFailed => "Export failed: internal error during export",
+            ErrorCode::OrderImported => "Imported historical order has no fiscal record",
 
             // Product
             ErrorCode::ProductNotFound => "Product not found",
@@ -374,6 +398,9 @@ impl ErrorCode {
             ErrorCode::PriceRuleValueOutOfRange => {
                 "Price rule value is out of range (percentage or amount)"
             }
+            ErrorCode::PriceRuleConflict => {
+                "Price rule overlaps another rule with the same priority"
+            }
 
             ErrorCode::PrintDestinationNotFound => "Print destination not found",
             ErrorCode::PrintDestinationInUse => "Print destination is in use by categories",
@@ -381,11 +408,25 @@ impl ErrorCode {
             // Table
             ErrorCode::TableNotFound => "Table not found",
             ErrorCode::TableOccupied => "Table is occupied",
+            ErrorCode::TableAlreadyJoined => "Table is already joined to another table",
+            ErrorCode::TableGroupNotFound => "Table group not found",
             ErrorCode::ZoneNotFound => "Zone not found",
             ErrorCode::ZoneHasTables => "Zone has associated tables",
             ErrorCode::TableHasOrders => "Table has active orders",
             ErrorCode::ShiftNotFound => "Shift not found",
             ErrorCode::DailyReportNotFound => "Daily report not found",
+            ErrorCode::DailyReportBlocked => "Orders from this business day are still open",
+            ErrorCode::EventBookingNotFound => "Event booking not found",
+            ErrorCode::EventBookingInvalidState => {
+                "Event booking status does not allow this operation"
+            }
+            ErrorCode::ReservationNotFound => "Reservation not found",
+            ErrorCode::ReservationInvalidState => {
+                "Reservation status does not allow this operation"
+            }
+            ErrorCode::ReservationConflict => {
+                "Table is already reserved for an overlapping time slot"
+            }
 
             // Employee
             ErrorCode::EmployeeNotFound => "Employee not found",
@@ -464,6 +505,7 @@ impl TryFrom<u16> for ErrorCode {
             1003 => Ok(ErrorCode::TokenExpired),
             1005 => Ok(ErrorCode::SessionExpired),
             1007 => Ok(ErrorCode::AccountDisabled),
+            1008 => Ok(ErrorCode::SessionLocked),
 
             // Permission
             2001 => Ok(ErrorCode::PermissionDenied),
@@ -511,6 +553,7 @@ impl TryFrom<u16> for ErrorCode {
             4014 => Ok(ErrorCode::ImportBlockedActiveOrders),
             4015 => Ok(ErrorCode::ImportInvalidFormat),
             4016 => Ok(ErrorCode::ExportFailed),
+            4017 => Ok(ErrorCode::OrderImported),
 
             // Product
             6001 => Ok(ErrorCode::ProductNotFound),
@@ -535,15 +578,24 @@ impl TryFrom<u16> for ErrorCode {
             6701 => Ok(ErrorCode::LabelTemplateNotFound),
             6801 => Ok(ErrorCode::PriceRuleNotFound),
             6802 => Ok(ErrorCode::PriceRuleValueOutOfRange),
+            6803 => Ok(ErrorCode::PriceRuleConflict),
 
             // Table
             7001 => Ok(ErrorCode::TableNotFound),
             7002 => Ok(ErrorCode::TableOccupied),
+            7003 => Ok(ErrorCode::TableAlreadyJoined),
+            7004 => Ok(ErrorCode::TableGroupNotFound),
             7101 => Ok(ErrorCode::ZoneNotFound),
             7102 => Ok(ErrorCode::ZoneHasTables),
             7104 => Ok(ErrorCode::TableHasOrders),
             7201 => Ok(ErrorCode::ShiftNotFound),
             7301 => Ok(ErrorCode::DailyReportNotFound),
+            7302 => Ok(ErrorCode::DailyReportBlocked),
+            7401 => Ok(ErrorCode::EventBookingNotFound),
+            7402 => Ok(ErrorCode::EventBookingInvalidState),
+            7501 => Ok(ErrorCode::ReservationNotFound),
+            7502 => Ok(ErrorCode::ReservationInvalidState),
+            7503 => Ok(ErrorCode::ReservationConflict),
 
             // Employee
             8001 => Ok(ErrorCode::EmployeeNotFound),
@@ -610,6 +662,7 @@ mod tests {
         assert_eq!(ErrorCode::TokenExpired.code(), 1003);
         assert_eq!(ErrorCode::SessionExpired.code(), 1005);
         assert_eq!(ErrorCode::AccountDisabled.code(), 1007);
+        assert_eq!(ErrorCode::SessionLocked.code(), 1008);
 
         // Permission
         assert_eq!(ErrorCode::PermissionDenied.code(), 2001);
@@ -653,11 +706,14 @@ mod tests {
         // Table
         assert_eq!(ErrorCode::TableNotFound.code(), 7001);
         assert_eq!(ErrorCode::TableOccupied.code(), 7002);
+        assert_eq!(ErrorCode::TableAlreadyJoined.code(), 7003);
+        assert_eq!(ErrorCode::TableGroupNotFound.code(), 7004);
         assert_eq!(ErrorCode::ZoneNotFound.code(), 7101);
         assert_eq!(ErrorCode::ZoneHasTables.code(), 7102);
         assert_eq!(ErrorCode::TableHasOrders.code(), 7104);
         assert_eq!(ErrorCode::ShiftNotFound.code(), 7201);
         assert_eq!(ErrorCode::DailyReportNotFound.code(), 7301);
+        assert_eq!(ErrorCode::EventBookingNotFound.code(), 7401);
 
         // Employee
         assert_eq!(ErrorCode::MemberNotFound.code(), 8005);
@@ -857,8 +913,8 @@ mod tests {
             3001, 3002, 3003, 3004, 3005, 3006, 3007, 3009, // 3xxx Tenant
             3011, 3012, 3013, 3014, 3015, 3017, 3018, 3019, 3022, 3023, 3024, 3025, 3026, 3027,
             3028, 3029, 3030, 3031, // P12 errors (26)
-            4001, 4003, 4004, 4006, 4008, 4009, 4010, 4011, 4012, 4013, 4014, 4015,
-            4016, // 4xxx Order (13)
+            4001, 4003, 4004, 4006, 4008, 4009, 4010, 4011, 4012, 4013, 4014, 4015, 4016,
+            4017, // 4xxx Order (14)
             6001, 6002, // 6xxx Product
             6101, 6102, // 61xx Category
             6202, 6203, 6204, 6205, // 62xx Spec/ExtId
@@ -868,10 +924,12 @@ mod tests {
             6601, // 66xx Marketing
             6701, // 67xx Label Template
             6801, 6802, // 68xx Price Rule
-            7001, 7002, // 7xxx Table
+            7001, 7002, 7003, 7004, // 7xxx Table
             7101, 7102, 7104, // 71xx Zone
             7201, // 72xx Shift
-            7301, // 73xx Daily Report
+            7301, 7302, // 73xx Daily Report
+            7401, 7402, // 74xx Event Booking
+            7501, 7502, 7503, // 75xx Reservation
             8001, 8004, 8005, // 8xxx Employee+Member
             8101, 8104, // 81xx Role
             9001, 9002, 9003, 9004, 9005, 9006, // 9xxx System
@@ -881,7 +939,7 @@ mod tests {
             9401, 9402, 9403, 9404, // 94xx Storage
         ];
 
-        const EXPECTED_VARIANT_COUNT: usize = 107;
+        const EXPECTED_VARIANT_COUNT: usize = 116;
         assert_eq!(
             all_codes.len(),
             EXPECTED_VARIANT_COUNT,
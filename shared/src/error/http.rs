@@ -39,6 +39,7 @@ impl ErrorCode {
             | Self::TokenExpired
             | Self::SessionExpired
             | Self::AccountDisabled
+            | Self::SessionLocked
             | Self::VerificationCodeInvalid => StatusCode::UNAUTHORIZED,
 
             // ==================== 403 Forbidden ====================
@@ -65,6 +66,7 @@ impl ErrorCode {
             | Self::CategoryNotFound
             | Self::AttributeNotFound
             | Self::TableNotFound
+            | Self::TableGroupNotFound
             | Self::ZoneNotFound
             | Self::EmployeeNotFound
             | Self::RoleNotFound
@@ -75,6 +77,8 @@ impl ErrorCode {
             | Self::PriceRuleNotFound
             | Self::ShiftNotFound
             | Self::DailyReportNotFound
+            | Self::EventBookingNotFound
+            | Self::ReservationNotFound
             | Self::MemberNotFound => StatusCode::NOT_FOUND,
 
             // ==================== 409 Conflict ====================
@@ -85,6 +89,7 @@ impl ErrorCode {
             | Self::OrderHasCreditNotes
             | Self::OrderAlreadyUpgraded
             | Self::OrderVoidedNoCreditNote
+            | Self::OrderImported
             | Self::ImportBlockedActiveOrders
             | Self::ProductExternalIdExists
             | Self::CategoryHasProducts
@@ -93,8 +98,14 @@ impl ErrorCode {
             | Self::AttributeDuplicateBinding
             | Self::TagInUse
             | Self::PrintDestinationInUse
+            | Self::PriceRuleConflict
             | Self::TableOccupied
-            | Self::TableHasOrders => StatusCode::CONFLICT,
+            | Self::TableAlreadyJoined
+            | Self::TableHasOrders
+            | Self::DailyReportBlocked
+            | Self::EventBookingInvalidState
+            | Self::ReservationInvalidState
+            | Self::ReservationConflict => StatusCode::CONFLICT,
 
             // ==================== 410 Gone ====================
             Self::VerificationCodeExpired => StatusCode::GONE,
@@ -188,6 +199,7 @@ mod tests {
             ErrorCode::TokenExpired,
             ErrorCode::SessionExpired,
             ErrorCode::AccountDisabled,
+            ErrorCode::SessionLocked,
             ErrorCode::VerificationCodeInvalid,
         ];
         for code in cases {
@@ -233,6 +245,7 @@ mod tests {
             ErrorCode::AlreadyExists,
             ErrorCode::ProductExternalIdExists,
             ErrorCode::TableOccupied,
+            ErrorCode::PriceRuleConflict,
         ];
         for code in cases {
             assert_eq!(code.http_status(), StatusCode::CONFLICT, "{code:?}");
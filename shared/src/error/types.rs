@@ -252,6 +252,7 @@ pub type AppResult<T> = Result<T, AppError>;
 
 // ===== Axum Integration =====
 
+#[cfg(not(target_arch = "wasm32"))]
 impl axum::response::IntoResponse for AppError {
     fn into_response(self) -> axum::response::Response {
         use axum::Json;
@@ -288,6 +289,7 @@ impl axum::response::IntoResponse for AppError {
     }
 }
 
+#[cfg(not(target_arch = "wasm32"))]
 impl<T: Serialize> axum::response::IntoResponse for ApiResponse<T> {
     fn into_response(self) -> axum::response::Response {
         use super::codes::ErrorCode;
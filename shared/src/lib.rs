@@ -13,6 +13,8 @@ pub mod message;
 pub mod models;
 pub mod order;
 pub mod request;
+pub mod schema;
+pub mod telemetry;
 pub mod types;
 pub mod util;
 
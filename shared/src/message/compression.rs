@@ -0,0 +1,135 @@
+//! 帧压缩
+//!
+//! 客户端可在握手时声明支持的压缩算法，服务端在握手响应中返回该连接使用的算法：
+//!
+//! ```text
+//! Handshake { accept_compression: ["zstd"] }
+//!   → Response { data: { "encoding": ..., "compression": "zstd" } }   // 旧服务端无该字段 → 不压缩
+//! ```
+//!
+//! - 只压缩服务端 → 客户端方向、且载荷超过 [`COMPRESSION_THRESHOLD`] 的帧
+//!   (大体积 SyncResponse / 全量订单列表)，小通知保持原样
+//! - 压缩帧在事件类型字节上置 [`COMPRESSED_FLAG`]，载荷首字节为算法 ID，
+//!   读取端无需连接状态即可解压；压缩后未变小则原样发送
+//!
+//! ```text
+//! [type | 0x80][request_id 16][correlation_id 16][len 4][codec 1][compressed ...]
+//! ```
+
+use serde::{Deserialize, Serialize};
+
+/// 事件类型字节最高位：载荷已压缩
+pub const COMPRESSED_FLAG: u8 = 0x80;
+
+/// 压缩阈值 (字节)，小于该大小的载荷不压缩
+pub const COMPRESSION_THRESHOLD: usize = 4 * 1024;
+
+/// zstd 压缩级别 (局域网场景优先速度)
+#[cfg(not(target_arch = "wasm32"))]
+const ZSTD_LEVEL: i32 = 3;
+
+/// 载荷压缩算法
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+#[repr(u8)]
+pub enum PayloadCompression {
+    #[default]
+    None = 0,
+    Zstd = 1,
+}
+
+impl PayloadCompression {
+    /// 服务端支持的算法
+    pub const SUPPORTED: &'static [PayloadCompression] = &[PayloadCompression::Zstd];
+
+    /// 服务端协商：取客户端偏好中第一个受支持的算法
+    pub fn negotiate(accepted: &[PayloadCompression]) -> Self {
+        accepted
+            .iter()
+            .copied()
+            .find(|c| Self::SUPPORTED.contains(c))
+            .unwrap_or_default()
+    }
+
+    /// 从算法 ID 解析 (帧载荷首字节)
+    pub fn from_id(id: u8) -> Option<Self> {
+        match id {
+            0 => Some(Self::None),
+            1 => Some(Self::Zstd),
+            _ => None,
+        }
+    }
+}
+
+/// 拆分帧首字节为 (事件类型字节, 是否压缩)
+pub fn split_type_byte(byte: u8) -> (u8, bool) {
+    (byte & !COMPRESSED_FLAG, byte & COMPRESSED_FLAG != 0)
+}
+
+/// 按协商的算法压缩载荷
+///
+/// 返回 `None` 表示应原样发送 (未协商压缩、低于阈值或压缩后未变小)。
+#[cfg(not(target_arch = "wasm32"))]
+pub fn compress(payload: &[u8], compression: PayloadCompression) -> Option<Vec<u8>> {
+    if payload.len() < COMPRESSION_THRESHOLD {
+        return None;
+    }
+    let compressed = match compression {
+        PayloadCompression::None => return None,
+        PayloadCompression::Zstd => zstd::bulk::compress(payload, ZSTD_LEVEL).ok()?,
+    };
+    if compressed.len() + 1 >= payload.len() {
+        return None;
+    }
+    let mut body = Vec::with_capacity(compressed.len() + 1);
+    body.push(compression as u8);
+    body.extend_from_slice(&compressed);
+    Some(body)
+}
+
+/// 解压压缩帧载荷 (首字节为算法 ID)，解压后超过 `max_size` 视为错误
+#[cfg(not(target_arch = "wasm32"))]
+pub fn decompress(body: &[u8], max_size: usize) -> Result<Vec<u8>, String> {
+    let (&id, data) = body
+        .split_first()
+        .ok_or_else(|| "Empty compressed payload".to_string())?;
+    match PayloadCompression::from_id(id) {
+        Some(PayloadCompression::Zstd) => zstd::bulk::decompress(data, max_size)
+            .map_err(|e| format!("zstd decompress failed: {e}")),
+        Some(PayloadCompression::None) => Ok(data.to_vec()),
+        None => Err(format!("Unknown compression id {id}")),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_small_payload_stays_uncompressed() {
+        let small = br#"{"title":"ok"}"#;
+        assert!(compress(small, PayloadCompression::Zstd).is_none());
+
+        let large =
+            serde_json::to_vec(&vec![serde_json::json!({"name": "Café", "qty": 2}); 500]).unwrap();
+        assert!(compress(&large, PayloadCompression::None).is_none());
+
+        let body = compress(&large, PayloadCompression::Zstd).unwrap();
+        assert!(body.len() < large.len());
+        assert_eq!(body[0], PayloadCompression::Zstd as u8);
+        assert_eq!(decompress(&body, large.len()).unwrap(), large);
+        // 解压大小受限
+        assert!(decompress(&body, large.len() - 1).is_err());
+    }
+
+    #[test]
+    fn test_negotiate_and_type_byte() {
+        assert_eq!(PayloadCompression::negotiate(&[]), PayloadCompression::None);
+        assert_eq!(
+            PayloadCompression::negotiate(&[PayloadCompression::None, PayloadCompression::Zstd]),
+            PayloadCompression::Zstd
+        );
+        assert_eq!(split_type_byte(5 | COMPRESSED_FLAG), (5, true));
+        assert_eq!(split_type_byte(5), (5, false));
+    }
+}
@@ -0,0 +1,382 @@
+//! 载荷编码
+//!
+//! 载荷默认是 JSON。客户端可在握手时声明支持紧凑二进制编码 (postcard)，
+//! 服务端在握手响应中返回该连接使用的编码：
+//!
+//! ```text
+//! Handshake { accept_encodings: ["postcard", "json"] }
+//!   → Response { data: { "encoding": "postcard" } }   // 旧服务端无 data → JSON
+//! ```
+//!
+//! - 进程内总线始终是 JSON，只在 TCP 边界按连接转码（服务端 → 客户端方向）
+//! - 二进制载荷以 [`BINARY_PAYLOAD_MARKER`] 开头（合法 JSON 文本不会以该字节开头），
+//!   因此 [`BusMessage::parse_payload`](super::BusMessage::parse_payload) 可透明解析两种编码
+//!
+//! 载荷类型含 `serde_json::Value` 字段、内部标签枚举和 `skip_serializing_if`，
+//! 无法直接用非自描述格式序列化。[`CompactValue`] 是自描述的紧凑值树（整数为
+//! varint，字符串无需转义），以 postcard 编码：
+//!
+//! - 编码：直接从 JSON 文本构建值树，不经 `serde_json::Value`
+//! - 解码：[`CompactValue`] 实现 `Deserializer`，目标类型直接从值树反序列化
+
+use serde::de::value::{MapDeserializer, SeqDeserializer};
+use serde::de::{
+    self, DeserializeOwned, DeserializeSeed, EnumAccess, IntoDeserializer, MapAccess, SeqAccess,
+    Unexpected, VariantAccess, Visitor,
+};
+use serde::{Deserialize, Deserializer, Serialize, forward_to_deserialize_any};
+
+/// 二进制载荷首字节（UTF-8 中不合法，不会出现在 JSON 文本开头）
+pub const BINARY_PAYLOAD_MARKER: u8 = 0xC1;
+
+/// 载荷编码
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum PayloadEncoding {
+    #[default]
+    Json,
+    /// postcard 编码的 [`CompactValue`]
+    Postcard,
+}
+
+impl PayloadEncoding {
+    /// 服务端协商：取客户端偏好中的第一项（当前所有编码均受支持）
+    pub fn negotiate(accepted: &[PayloadEncoding]) -> Self {
+        accepted.first().copied().unwrap_or_default()
+    }
+}
+
+/// 自描述的紧凑值树 (postcard 线格式)
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub enum CompactValue {
+    Null,
+    Bool(bool),
+    U64(u64),
+    I64(i64),
+    F64(f64),
+    String(String),
+    Array(Vec<CompactValue>),
+    /// 保持字段顺序
+    Object(Vec<(String, CompactValue)>),
+}
+
+impl CompactValue {
+    /// 直接从 JSON 文本构建
+    pub fn from_json(json: &[u8]) -> Result<Self, serde_json::Error> {
+        serde_json::from_slice::<JsonTree>(json).map(|tree| tree.0)
+    }
+
+    fn unexpected(&self) -> Unexpected<'_> {
+        match self {
+            Self::Null => Unexpected::Unit,
+            Self::Bool(b) => Unexpected::Bool(*b),
+            Self::U64(u) => Unexpected::Unsigned(*u),
+            Self::I64(i) => Unexpected::Signed(*i),
+            Self::F64(f) => Unexpected::Float(*f),
+            Self::String(s) => Unexpected::Str(s),
+            Self::Array(_) => Unexpected::Seq,
+            Self::Object(_) => Unexpected::Map,
+        }
+    }
+}
+
+/// JSON 文本 → [`CompactValue`] (自描述读取，与 postcard 线格式的派生实现分开)
+struct JsonTree(CompactValue);
+
+impl<'de> Deserialize<'de> for JsonTree {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        deserializer.deserialize_any(JsonTreeVisitor).map(JsonTree)
+    }
+}
+
+struct JsonTreeVisitor;
+
+impl<'de> Visitor<'de> for JsonTreeVisitor {
+    type Value = CompactValue;
+
+    fn expecting(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        f.write_str("a JSON value")
+    }
+
+    fn visit_unit<E>(self) -> Result<CompactValue, E> {
+        Ok(CompactValue::Null)
+    }
+
+    fn visit_none<E>(self) -> Result<CompactValue, E> {
+        Ok(CompactValue::Null)
+    }
+
+    fn visit_some<D: Deserializer<'de>>(self, deserializer: D) -> Result<CompactValue, D::Error> {
+        deserializer.deserialize_any(self)
+    }
+
+    fn visit_bool<E>(self, b: bool) -> Result<CompactValue, E> {
+        Ok(CompactValue::Bool(b))
+    }
+
+    fn visit_u64<E>(self, u: u64) -> Result<CompactValue, E> {
+        Ok(CompactValue::U64(u))
+    }
+
+    fn visit_i64<E>(self, i: i64) -> Result<CompactValue, E> {
+        Ok(CompactValue::I64(i))
+    }
+
+    fn visit_f64<E>(self, f: f64) -> Result<CompactValue, E> {
+        Ok(CompactValue::F64(f))
+    }
+
+    fn visit_str<E>(self, s: &str) -> Result<CompactValue, E> {
+        Ok(CompactValue::String(s.to_owned()))
+    }
+
+    fn visit_string<E>(self, s: String) -> Result<CompactValue, E> {
+        Ok(CompactValue::String(s))
+    }
+
+    fn visit_seq<A: SeqAccess<'de>>(self, mut seq: A) -> Result<CompactValue, A::Error> {
+        let mut items = Vec::with_capacity(seq.size_hint().unwrap_or(0));
+        while let Some(JsonTree(item)) = seq.next_element()? {
+            items.push(item);
+        }
+        Ok(CompactValue::Array(items))
+    }
+
+    fn visit_map<A: MapAccess<'de>>(self, mut map: A) -> Result<CompactValue, A::Error> {
+        let mut fields = Vec::with_capacity(map.size_hint().unwrap_or(0));
+        while let Some((key, JsonTree(value))) = map.next_entry::<String, JsonTree>()? {
+            fields.push((key, value));
+        }
+        Ok(CompactValue::Object(fields))
+    }
+}
+
+impl<'de> Deserializer<'de> for CompactValue {
+    type Error = serde_json::Error;
+
+    fn deserialize_any<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value, Self::Error> {
+        match self {
+            Self::Null => visitor.visit_unit(),
+            Self::Bool(b) => visitor.visit_bool(b),
+            Self::U64(u) => visitor.visit_u64(u),
+            Self::I64(i) => visitor.visit_i64(i),
+            Self::F64(f) => visitor.visit_f64(f),
+            Self::String(s) => visitor.visit_string(s),
+            Self::Array(items) => {
+                let mut seq = SeqDeserializer::new(items.into_iter());
+                let value = visitor.visit_seq(&mut seq)?;
+                seq.end()?;
+                Ok(value)
+            }
+            Self::Object(fields) => {
+                let mut map = MapDeserializer::new(fields.into_iter());
+                let value = visitor.visit_map(&mut map)?;
+                map.end()?;
+                Ok(value)
+            }
+        }
+    }
+
+    fn deserialize_option<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value, Self::Error> {
+        match self {
+            Self::Null => visitor.visit_none(),
+            other => visitor.visit_some(other),
+        }
+    }
+
+    fn deserialize_newtype_struct<V: Visitor<'de>>(
+        self,
+        _name: &'static str,
+        visitor: V,
+    ) -> Result<V::Value, Self::Error> {
+        visitor.visit_newtype_struct(self)
+    }
+
+    /// 与 JSON 一致：单元变体为字符串，其余为单键对象
+    fn deserialize_enum<V: Visitor<'de>>(
+        self,
+        _name: &'static str,
+        _variants: &'static [&'static str],
+        visitor: V,
+    ) -> Result<V::Value, Self::Error> {
+        match self {
+            Self::String(variant) => visitor.visit_enum(variant.into_deserializer()),
+            Self::Object(mut fields) if fields.len() == 1 => {
+                let (variant, value) = fields.remove(0);
+                visitor.visit_enum(VariantDeserializer { variant, value })
+            }
+            other => Err(de::Error::invalid_type(other.unexpected(), &"enum")),
+        }
+    }
+
+    forward_to_deserialize_any! {
+        bool i8 i16 i32 i64 i128 u8 u16 u32 u64 u128 f32 f64 char str string
+        bytes byte_buf unit unit_struct seq tuple tuple_struct map struct
+        identifier ignored_any
+    }
+}
+
+impl<'de> IntoDeserializer<'de, serde_json::Error> for CompactValue {
+    type Deserializer = Self;
+
+    fn into_deserializer(self) -> Self {
+        self
+    }
+}
+
+struct VariantDeserializer {
+    variant: String,
+    value: CompactValue,
+}
+
+impl<'de> EnumAccess<'de> for VariantDeserializer {
+    type Error = serde_json::Error;
+    type Variant = CompactValue;
+
+    fn variant_seed<V: DeserializeSeed<'de>>(
+        self,
+        seed: V,
+    ) -> Result<(V::Value, CompactValue), Self::Error> {
+        let variant = seed.deserialize(IntoDeserializer::<Self::Error>::into_deserializer(
+            self.variant,
+        ))?;
+        Ok((variant, self.value))
+    }
+}
+
+impl<'de> VariantAccess<'de> for CompactValue {
+    type Error = serde_json::Error;
+
+    fn unit_variant(self) -> Result<(), Self::Error> {
+        match self {
+            Self::Null => Ok(()),
+            other => Err(de::Error::invalid_type(other.unexpected(), &"unit variant")),
+        }
+    }
+
+    fn newtype_variant_seed<T: DeserializeSeed<'de>>(
+        self,
+        seed: T,
+    ) -> Result<T::Value, Self::Error> {
+        seed.deserialize(self)
+    }
+
+    fn tuple_variant<V: Visitor<'de>>(
+        self,
+        _len: usize,
+        visitor: V,
+    ) -> Result<V::Value, Self::Error> {
+        self.deserialize_seq(visitor)
+    }
+
+    fn struct_variant<V: Visitor<'de>>(
+        self,
+        _fields: &'static [&'static str],
+        visitor: V,
+    ) -> Result<V::Value, Self::Error> {
+        self.deserialize_map(visitor)
+    }
+}
+
+/// 载荷是否为二进制编码
+pub fn is_binary(payload: &[u8]) -> bool {
+    payload.first() == Some(&BINARY_PAYLOAD_MARKER)
+}
+
+/// 将 JSON 载荷转码为目标编码
+pub fn encode_json_payload(
+    json: &[u8],
+    encoding: PayloadEncoding,
+) -> Result<Vec<u8>, serde_json::Error> {
+    match encoding {
+        PayloadEncoding::Json => Ok(json.to_vec()),
+        PayloadEncoding::Postcard => {
+            postcard::to_extend(&CompactValue::from_json(json)?, vec![BINARY_PAYLOAD_MARKER])
+                .map_err(<serde_json::Error as serde::ser::Error>::custom)
+        }
+    }
+}
+
+/// 解码二进制载荷为目标类型
+pub fn decode<T: DeserializeOwned>(payload: &[u8]) -> Result<T, serde_json::Error> {
+    let Some((&BINARY_PAYLOAD_MARKER, body)) = payload.split_first() else {
+        return Err(de::Error::custom("payload is not binary-encoded"));
+    };
+    let value: CompactValue = postcard::from_bytes(body).map_err(de::Error::custom)?;
+    T::deserialize(value)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::message::{BusMessage, SyncChangeType, SyncPayload};
+
+    #[test]
+    fn test_postcard_roundtrip_preserves_json() {
+        let sync = SyncPayload {
+            resource: crate::cloud::SyncResource::Product,
+            version: 42,
+            action: SyncChangeType::Updated,
+            id: 1001,
+            data: Some(serde_json::json!({
+                "total": 12.5,
+                "discount": -3,
+                "items": [{"name": "Café", "qty": 2}],
+                "note": null,
+            })),
+            cloud_origin: false,
+        };
+        let msg = BusMessage::sync(&sync);
+        let encoded = msg.encoded_for(PayloadEncoding::Postcard).unwrap();
+
+        assert!(is_binary(&encoded.payload));
+        assert!(encoded.payload.len() < msg.payload.len());
+        assert_eq!(
+            encoded.parse_payload::<SyncPayload>().unwrap(),
+            msg.parse_payload::<SyncPayload>().unwrap()
+        );
+    }
+
+    #[test]
+    fn test_decode_tagged_enums_from_compact_tree() {
+        use crate::cloud::{CloudMessage, CloudRpcResult};
+
+        let msg = CloudMessage::RpcResult {
+            id: "rpc-7".into(),
+            result: CloudRpcResult::Json {
+                success: true,
+                data: Some(serde_json::json!({"orders": [1, 2]})),
+                error: None,
+            },
+        };
+        let json = serde_json::to_vec(&msg).unwrap();
+        let encoded = encode_json_payload(&json, PayloadEncoding::Postcard).unwrap();
+
+        let CloudMessage::RpcResult { id, result } = decode::<CloudMessage>(&encoded).unwrap()
+        else {
+            panic!("Expected RpcResult");
+        };
+        assert_eq!(id, "rpc-7");
+        let CloudRpcResult::Json {
+            success,
+            data,
+            error,
+        } = result
+        else {
+            panic!("Expected Json result");
+        };
+        assert!(success);
+        assert_eq!(data, Some(serde_json::json!({"orders": [1, 2]})));
+        assert!(error.is_none());
+        assert!(decode::<CloudMessage>(&json).is_err());
+    }
+
+    #[test]
+    fn test_negotiate_defaults_to_json() {
+        assert_eq!(PayloadEncoding::negotiate(&[]), PayloadEncoding::Json);
+        assert_eq!(
+            PayloadEncoding::negotiate(&[PayloadEncoding::Postcard, PayloadEncoding::Json]),
+            PayloadEncoding::Postcard
+        );
+    }
+}
@@ -9,7 +9,12 @@ use std::fmt;
 
 use uuid::Uuid;
 
+pub mod compression;
+pub mod encoding;
 pub mod payload;
+pub mod trace_context;
+pub use compression::PayloadCompression;
+pub use encoding::PayloadEncoding;
 pub use payload::*;
 
 /// 协议版本号
@@ -31,6 +36,10 @@ pub enum EventType {
     Sync = 4,
     /// 请求响应
     Response = 5,
+    /// 实时经营指标 (仅推送给握手时订阅的连接)
+    Kpi = 6,
+    /// 员工公告 (仅推送给握手时声明支持的连接)
+    Announcement = 7,
 }
 
 impl TryFrom<u8> for EventType {
@@ -44,6 +53,8 @@ impl TryFrom<u8> for EventType {
             3 => Ok(EventType::RequestCommand),
             4 => Ok(EventType::Sync),
             5 => Ok(EventType::Response),
+            6 => Ok(EventType::Kpi),
+            7 => Ok(EventType::Announcement),
             _ => Err(()),
         }
     }
@@ -58,10 +69,153 @@ impl fmt::Display for EventType {
             EventType::RequestCommand => write!(f, "request_command"),
             EventType::Sync => write!(f, "sync"),
             EventType::Response => write!(f, "response"),
+            EventType::Kpi => write!(f, "kpi"),
+            EventType::Announcement => write!(f, "announcement"),
         }
     }
 }
 
+/// 广播主题 (服务端 → 客户端)
+///
+/// 每个主题一条独立的广播通道，订单事件刷屏时不会挤掉系统通知。
+/// TCP 客户端在握手时声明订阅的主题；未声明或包含 `All` 时走全量通道 (旧客户端)。
+/// `System` 承载响应、通知、指令，始终投递给所有连接。
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum BusTopic {
+    /// 全量 (兼容未按主题订阅的客户端)
+    All,
+    /// 订单事件、归档、发票、拼桌、宴会预订、订位
+    Orders,
+    /// 商品、分类、属性、价格规则、区域 / 餐桌、库存
+    Catalog,
+    /// 响应、通知、指令、KPI、公告及门店 / 员工 / 班次等系统资源
+    System,
+    /// 厨房显示：沽清、打印目标与配置、标签模板
+    Kds,
+}
+
+impl BusTopic {
+    /// 实际拆分的主题通道 (不含 `All`)
+    pub const CHANNELS: [BusTopic; 4] = [Self::Orders, Self::Catalog, Self::System, Self::Kds];
+
+    /// 同步资源所属主题
+    pub fn of_resource(resource: crate::cloud::SyncResource) -> Self {
+        use crate::cloud::SyncResource as R;
+        match resource {
+            R::OrderSync
+            | R::ArchivedOrder
+            | R::CreditNote
+            | R::Invoice
+            | R::Anulacion
+            | R::ChainEntry
+            | R::ChainBreak
+            | R::ReceiptArtifact
+            | R::TableGroup
+            | R::EventBooking
+            | R::Reservation => Self::Orders,
+            R::Product
+            | R::Category
+            | R::Tag
+            | R::Attribute
+            | R::AttributeBinding
+            | R::PriceRule
+            | R::Zone
+            | R::ZoneProductOverride
+            | R::DiningTable
+            | R::StockLevel => Self::Catalog,
+            R::EightySix | R::PrintConfig | R::PrintDestination | R::LabelTemplate => Self::Kds,
+            R::Employee
+            | R::Role
+            | R::StoreInfo
+            | R::Shift
+            | R::DailyReport
+            | R::SystemState
+            | R::SystemIssue
+            | R::Member
+            | R::MarketingGroup
+            | R::Coupon
+            | R::DisplaySlide => Self::System,
+        }
+    }
+}
+
+impl fmt::Display for BusTopic {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            BusTopic::All => write!(f, "all"),
+            BusTopic::Orders => write!(f, "orders"),
+            BusTopic::Catalog => write!(f, "catalog"),
+            BusTopic::System => write!(f, "system"),
+            BusTopic::Kds => write!(f, "kds"),
+        }
+    }
+}
+
+/// 订阅过滤条件 (握手声明，服务端在写入 socket 前过滤)
+///
+/// 各字段为 AND，未设置 = 任意；连接的多个过滤条件之间为 OR。
+/// 例：`{ resource: order_sync, zone: "A" }` 只接收 A 区的订单事件。
+/// 响应、服务器指令和定向 (unicast) 消息不受过滤影响。
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct SubscriptionFilter {
+    /// 事件类型
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub event_type: Option<EventType>,
+    /// 同步资源 (仅匹配 `Sync` 事件)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub resource: Option<crate::cloud::SyncResource>,
+    /// 区域名 (匹配订单快照的 `zone_name`，忽略大小写；无区域的零售单不匹配)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub zone: Option<String>,
+}
+
+impl SubscriptionFilter {
+    fn matches(&self, event_type: EventType, sync: Option<&SyncHead>) -> bool {
+        if self.event_type.is_some_and(|t| t != event_type) {
+            return false;
+        }
+        if let Some(resource) = self.resource
+            && sync.is_none_or(|s| s.resource != resource)
+        {
+            return false;
+        }
+        if let Some(zone) = &self.zone {
+            return sync.is_some_and(|s| {
+                s.zone_names()
+                    .any(|name| name.eq_ignore_ascii_case(zone.trim()))
+            });
+        }
+        true
+    }
+}
+
+/// 过滤所需的同步载荷字段
+#[derive(Deserialize)]
+struct SyncHead {
+    resource: crate::cloud::SyncResource,
+    #[serde(default)]
+    data: Option<serde_json::Value>,
+}
+
+impl SyncHead {
+    /// 载荷中的区域名 (订单同步: `snapshot` / 批量 `snapshots`；其它资源: `data.zone_name`)
+    fn zone_names(&self) -> impl Iterator<Item = &str> {
+        let data = self.data.as_ref();
+        let candidates = data
+            .and_then(|d| d.get("snapshot"))
+            .into_iter()
+            .chain(
+                data.and_then(|d| d.get("snapshots"))
+                    .and_then(|s| s.as_array())
+                    .into_iter()
+                    .flatten(),
+            )
+            .chain(data);
+        candidates.filter_map(|v| v.get("zone_name").and_then(|z| z.as_str()))
+    }
+}
+
 /// 简化的消息结构 - 只包含业务必需字段
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct Message<T> {
@@ -128,6 +282,7 @@ impl<T> Message<T> {
             correlation_id: self.correlation_id,
             target: None,
             payload,
+            trace_context: None,
         }
     }
 }
@@ -160,6 +315,9 @@ pub struct BusMessage {
     pub correlation_id: Option<Uuid>,
     pub target: Option<String>,
     pub payload: Vec<u8>,
+    /// W3C `traceparent` (握手协商后随帧传输，见 [`trace_context`])
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub trace_context: Option<String>,
 }
 
 impl BusMessage {
@@ -171,6 +329,7 @@ impl BusMessage {
             correlation_id: None,
             target: None,
             payload,
+            trace_context: None,
         }
     }
 
@@ -186,6 +345,12 @@ impl BusMessage {
         self
     }
 
+    /// 设置链路追踪上下文 (W3C `traceparent`)
+    pub fn with_trace_context(mut self, trace_context: Option<String>) -> Self {
+        self.trace_context = trace_context;
+        self
+    }
+
     /// 创建握手消息
     pub fn handshake(payload: &HandshakePayload) -> Self {
         Self::new(
@@ -198,7 +363,7 @@ impl BusMessage {
     /// 创建服务器指令消息
     pub fn server_command(payload: &ServerCommandPayload) -> Self {
         let payload_bytes = // SAFETY: derives Serialize — infallible
-        serde_json::to_vec(payload).expect("derive(Serialize) serialization is infallible");
+        crate::schema::to_vec(payload).expect("derive(Serialize) serialization is infallible");
         Self::new(EventType::ServerCommand, payload_bytes)
     }
 
@@ -207,7 +372,7 @@ impl BusMessage {
         Self::new(
             EventType::Notification,
             // SAFETY: derives Serialize — infallible
-            serde_json::to_vec(payload).expect("derive(Serialize) serialization is infallible"),
+            crate::schema::to_vec(payload).expect("derive(Serialize) serialization is infallible"),
         )
     }
 
@@ -216,7 +381,7 @@ impl BusMessage {
         Self::new(
             EventType::RequestCommand,
             // SAFETY: derives Serialize — infallible
-            serde_json::to_vec(payload).expect("derive(Serialize) serialization is infallible"),
+            crate::schema::to_vec(payload).expect("derive(Serialize) serialization is infallible"),
         )
     }
 
@@ -225,7 +390,7 @@ impl BusMessage {
         Self::new(
             EventType::Sync,
             // SAFETY: derives Serialize — infallible
-            serde_json::to_vec(payload).expect("derive(Serialize) serialization is infallible"),
+            crate::schema::to_vec(payload).expect("derive(Serialize) serialization is infallible"),
         )
     }
 
@@ -234,13 +399,99 @@ impl BusMessage {
         Self::new(
             EventType::Response,
             // SAFETY: derives Serialize — infallible
+            crate::schema::to_vec(payload).expect("derive(Serialize) serialization is infallible"),
+        )
+    }
+
+    /// 创建实时经营指标消息
+    pub fn kpi(payload: &KpiPayload) -> Self {
+        Self::new(
+            EventType::Kpi,
+            // SAFETY: derives Serialize — infallible
+            serde_json::to_vec(payload).expect("derive(Serialize) serialization is infallible"),
+        )
+    }
+
+    /// 创建员工公告消息
+    pub fn announcement(payload: &crate::models::Announcement) -> Self {
+        Self::new(
+            EventType::Announcement,
+            // SAFETY: derives Serialize — infallible
             serde_json::to_vec(payload).expect("derive(Serialize) serialization is infallible"),
         )
     }
 
-    /// 解析载荷为指定类型
+    /// 消息所属广播主题 (同步信号按资源类型划分，其余归入 `System`)
+    pub fn topic(&self) -> BusTopic {
+        #[derive(Deserialize)]
+        struct SyncResourceOnly {
+            resource: crate::cloud::SyncResource,
+        }
+        match self.event_type {
+            EventType::Sync => self
+                .parse_payload::<SyncResourceOnly>()
+                .map(|s| BusTopic::of_resource(s.resource))
+                .unwrap_or(BusTopic::System),
+            _ => BusTopic::System,
+        }
+    }
+
+    /// 是否通过连接的订阅过滤条件 (见 [`SubscriptionFilter`])
+    pub fn matches_filters(&self, filters: &[SubscriptionFilter]) -> bool {
+        if filters.is_empty()
+            || self.target.is_some()
+            || matches!(
+                self.event_type,
+                EventType::Response | EventType::ServerCommand | EventType::Handshake
+            )
+        {
+            return true;
+        }
+        let sync = if self.event_type == EventType::Sync {
+            self.parse_payload::<SyncHead>().ok()
+        } else {
+            None
+        };
+        filters
+            .iter()
+            .any(|f| f.matches(self.event_type, sync.as_ref()))
+    }
+
+    /// 解析载荷为指定类型 (JSON 或二进制编码均可)
     pub fn parse_payload<T: DeserializeOwned>(&self) -> Result<T, serde_json::Error> {
-        serde_json::from_slice(&self.payload)
+        if encoding::is_binary(&self.payload) {
+            encoding::decode(&self.payload)
+        } else {
+            serde_json::from_slice(&self.payload)
+        }
+    }
+
+    /// 解析版本化载荷 (旧版本载荷先经 [`crate::schema`] 升级)
+    pub fn parse_versioned<T: crate::schema::SchemaVersioned>(
+        &self,
+    ) -> Result<T, serde_json::Error> {
+        crate::schema::from_value(self.parse_payload()?)
+    }
+
+    /// 载荷是否为二进制编码
+    pub fn is_binary_payload(&self) -> bool {
+        encoding::is_binary(&self.payload)
+    }
+
+    /// 按连接协商的编码转码 JSON 载荷 (用于发往网络客户端)
+    pub fn encoded_for(&self, target: PayloadEncoding) -> Result<Self, serde_json::Error> {
+        if target == PayloadEncoding::Json || self.is_binary_payload() {
+            return Ok(self.clone());
+        }
+        Ok(Self {
+            request_id: self.request_id,
+            event_type: self.event_type,
+            source: self.source.clone(),
+            correlation_id: self.correlation_id,
+            target: self.target.clone(),
+            payload: encoding::encode_json_payload(&self.payload, target)?,
+            trace_context: self.trace_context.clone(),
+        })
     }
 }
 
@@ -295,6 +546,17 @@ mod tests {
             client_name: Some("test-client".to_string()),
             client_version: Some("0.1.0".to_string()),
             client_id: Some("uuid-v4".to_string()),
+            accept_encodings: vec![PayloadEncoding::Postcard],
+            accept_compression: vec![PayloadCompression::Zstd],
+            subscribe_kpi: true,
+            announcements: true,
+            topics: vec![BusTopic::Orders, BusTopic::Kds],
+            filters: vec![SubscriptionFilter {
+                resource: Some(crate::cloud::SyncResource::OrderSync),
+                zone: Some("A".to_string()),
+                ..Default::default()
+            }],
+            trace_context: true,
         };
 
         let msg = BusMessage::handshake(&payload);
@@ -303,5 +565,128 @@ mod tests {
 
         let parsed: HandshakePayload = msg.parse_payload().unwrap();
         assert_eq!(parsed.version, PROTOCOL_VERSION);
+        assert_eq!(parsed.accept_encodings, vec![PayloadEncoding::Postcard]);
+        assert_eq!(parsed.accept_compression, vec![PayloadCompression::Zstd]);
+        assert_eq!(parsed.topics, vec![BusTopic::Orders, BusTopic::Kds]);
+        assert_eq!(parsed.filters, payload.filters);
+        assert!(parsed.trace_context);
+
+        // 旧客户端不带 accept_encodings
+        let legacy: HandshakePayload = serde_json::from_str(
+            r#"{"version":2,"client_name":null,"client_version":null,"client_id":null}"#,
+        )
+        .unwrap();
+        assert!(legacy.accept_encodings.is_empty());
+        assert!(legacy.accept_compression.is_empty());
+        assert!(!legacy.subscribe_kpi);
+        assert!(!legacy.announcements);
+        assert!(legacy.topics.is_empty());
+        assert!(legacy.filters.is_empty());
+        assert!(!legacy.trace_context);
+    }
+
+    #[test]
+    fn test_message_topic() {
+        let sync = |resource| {
+            BusMessage::sync(&SyncPayload {
+                resource,
+                version: 1,
+                action: SyncChangeType::Updated,
+                id: 1,
+                data: None,
+                cloud_origin: false,
+            })
+        };
+        use crate::cloud::SyncResource;
+        assert_eq!(sync(SyncResource::OrderSync).topic(), BusTopic::Orders);
+        assert_eq!(sync(SyncResource::Product).topic(), BusTopic::Catalog);
+        assert_eq!(sync(SyncResource::EightySix).topic(), BusTopic::Kds);
+        assert_eq!(sync(SyncResource::StoreInfo).topic(), BusTopic::System);
+
+        let notification = BusMessage::notification(&NotificationPayload::info("T", "M"));
+        assert_eq!(notification.topic(), BusTopic::System);
+        // 非标准同步载荷 (如 lagged 重同步) 归入 System
+        let raw = BusMessage::new(EventType::Sync, br#"{"reason":"lagged"}"#.to_vec());
+        assert_eq!(raw.topic(), BusTopic::System);
+        assert_eq!(BusTopic::Kds.to_string(), "kds");
+    }
+
+    #[test]
+    fn test_subscription_filters() {
+        use crate::cloud::SyncResource;
+        let order_in = |zone: &str| {
+            BusMessage::sync(&SyncPayload {
+                resource: SyncResource::OrderSync,
+                version: 1,
+                action: SyncChangeType::Updated,
+                id: 1,
+                data: Some(serde_json::json!({
+                    "event": {},
+                    "snapshot": { "zone_name": zone }
+                })),
+                cloud_origin: false,
+            })
+        };
+        let zone_a = [SubscriptionFilter {
+            resource: Some(SyncResource::OrderSync),
+            zone: Some("a".to_string()),
+            ..Default::default()
+        }];
+
+        assert!(order_in("A").matches_filters(&zone_a));
+        assert!(!order_in("B").matches_filters(&zone_a));
+        assert!(order_in("B").matches_filters(&[]));
+
+        // 批量快照：任一订单在该区域即投递
+        let batch = BusMessage::sync(&SyncPayload {
+            resource: SyncResource::OrderSync,
+            version: 2,
+            action: SyncChangeType::Updated,
+            id: 2,
+            data: Some(serde_json::json!({
+                "events": [],
+                "snapshots": [{ "zone_name": "B" }, { "zone_name": "A" }]
+            })),
+            cloud_origin: false,
+        });
+        assert!(batch.matches_filters(&zone_a));
+
+        let notification = BusMessage::notification(&NotificationPayload::info("T", "M"));
+        assert!(!notification.matches_filters(&zone_a));
+        let with_notifications = [
+            zone_a[0].clone(),
+            SubscriptionFilter {
+                event_type: Some(EventType::Notification),
+                ..Default::default()
+            },
+        ];
+        assert!(notification.matches_filters(&with_notifications));
+
+        // 响应与定向消息不受过滤影响
+        let response = BusMessage::response(&ResponsePayload::success("ok", None));
+        assert!(response.matches_filters(&zone_a));
+        assert!(
+            order_in("B")
+                .with_target("client-1")
+                .matches_filters(&zone_a)
+        );
+    }
+
+    #[test]
+    fn test_kpi_event_type_round_trip() {
+        assert_eq!(EventType::try_from(6), Ok(EventType::Kpi));
+        assert_eq!(EventType::Kpi.to_string(), "kpi");
+        assert_eq!(EventType::try_from(7), Ok(EventType::Announcement));
+        assert!(EventType::try_from(8).is_err());
+
+        let payload = KpiPayload {
+            business_date: "2026-10-15".to_string(),
+            sales_today: 1250.5,
+            ..Default::default()
+        };
+        let msg = BusMessage::kpi(&payload);
+        assert_eq!(msg.event_type, EventType::Kpi);
+        let parsed: KpiPayload = msg.parse_payload().unwrap();
+        assert_eq!(parsed, payload);
     }
 }
@@ -113,6 +113,49 @@ pub struct HandshakePayload {
     pub client_version: Option<String>,
     /// 客户端唯一标识 (UUID)
     pub client_id: Option<String>,
+    /// 客户端支持的载荷编码 (按偏好排序)，为空 = 仅 JSON
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub accept_encodings: Vec<super::PayloadEncoding>,
+    /// 客户端支持的帧压缩算法 (按偏好排序)，为空 = 不压缩
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub accept_compression: Vec<super::PayloadCompression>,
+    /// 订阅实时经营指标推送 (`EventType::Kpi`)，旧客户端不认识该事件类型
+    #[serde(default, skip_serializing_if = "std::ops::Not::not")]
+    pub subscribe_kpi: bool,
+    /// 客户端支持员工公告事件 (`EventType::Announcement`)
+    #[serde(default, skip_serializing_if = "std::ops::Not::not")]
+    pub announcements: bool,
+    /// 订阅的广播主题，为空 = 全量 (旧客户端)；`System` 始终投递
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub topics: Vec<super::BusTopic>,
+    /// 订阅过滤条件 (在已订阅主题内进一步过滤)，为空 = 不过滤
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub filters: Vec<super::SubscriptionFilter>,
+    /// 客户端支持帧携带链路追踪上下文 (见 [`super::trace_context`])
+    #[serde(default, skip_serializing_if = "std::ops::Not::not")]
+    pub trace_context: bool,
+}
+
+/// 握手响应数据 (`ResponsePayload.data`)
+///
+/// 旧版服务端不返回该数据，客户端按 JSON 处理。
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct HandshakeAck {
+    /// 服务端发往该连接的载荷编码
+    #[serde(default)]
+    pub encoding: super::PayloadEncoding,
+    /// 服务端发往该连接的帧压缩算法 (超过阈值的载荷才压缩)
+    #[serde(default)]
+    pub compression: super::PayloadCompression,
+    /// 服务端认定的终端身份 (客户端证书 CN / client_name)，保存漫游配置时作为键
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub identity: Option<String>,
+    /// 该终端已保存的漫游配置 (首次连接的新终端为 None)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub terminal_profile: Option<crate::models::TerminalProfile>,
+    /// 双方均支持帧携带链路追踪上下文，此后请求 / 响应帧可置 `TRACE_CONTEXT_FLAG`
+    #[serde(default, skip_serializing_if = "std::ops::Not::not")]
+    pub trace_context: bool,
 }
 
 /// 通知载荷 (服务端 -> 客户端)
@@ -130,6 +173,9 @@ pub struct NotificationPayload {
     pub category: NotificationCategory,
     /// 附加数据 (JSON)
     pub data: Option<serde_json::Value>,
+    /// 持久化通知序号 (关键通知落库，离线终端重连时补发)；None = 普通广播
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub durable_id: Option<i64>,
 }
 
 /// 服务器指令载荷 (上层服务器 -> 边缘服务端)
@@ -164,6 +210,8 @@ pub enum SyncChangeType {
     Deleted,
     /// 班次需要结算
     SettlementRequired,
+    /// 连续工作超时，需要休息（劳动合规提醒）
+    BreakRequired,
 }
 
 /// 同步信号载荷 (边缘服务端 -> 所有客户端)
@@ -187,6 +235,39 @@ pub struct SyncPayload {
     pub cloud_origin: bool,
 }
 
+/// 实时经营指标载荷 (服务端 -> 经理看板)
+///
+/// 由 KPI 服务按固定间隔推送，金额为营业日累计值。
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct KpiPayload {
+    /// 生成时间 (Unix millis)
+    pub generated_at: i64,
+    /// 营业日 (YYYY-MM-DD)
+    pub business_date: String,
+    /// 今日已完成销售额
+    pub sales_today: f64,
+    /// 上周同一天截至同一时刻的销售额
+    pub sales_same_day_last_week: f64,
+    /// 今日已完成订单数
+    pub orders_today: i64,
+    /// 今日来客数 (已完成 + 进行中)
+    pub covers_today: i64,
+    /// 平均客单价 (销售额 / 已完成订单数)
+    pub average_ticket: f64,
+    /// 占用中的桌台数
+    pub open_tables: i64,
+    /// 进行中的订单数
+    pub open_orders: i64,
+    /// 进行中订单的未结金额
+    pub open_amount: f64,
+    /// 厨房积压单数 (近期送厨且订单未结)
+    pub kitchen_backlog_tickets: i64,
+    /// 厨房积压菜品数
+    pub kitchen_backlog_items: i64,
+    /// 最早积压单的送厨时间 (Unix millis)
+    pub kitchen_oldest_ticket_at: Option<i64>,
+}
+
 /// 通用响应载荷 (服务端 -> 客户端)
 ///
 /// 用于响应 RequestCommand
@@ -214,6 +295,7 @@ impl NotificationPayload {
             level: NotificationLevel::Info,
             category: NotificationCategory::System,
             data: None,
+            durable_id: None,
         }
     }
 
@@ -224,6 +306,7 @@ impl NotificationPayload {
             level: NotificationLevel::Warning,
             category: NotificationCategory::System,
             data: None,
+            durable_id: None,
         }
     }
 
@@ -234,8 +317,21 @@ impl NotificationPayload {
             level: NotificationLevel::Error,
             category: NotificationCategory::System,
             data: None,
+            durable_id: None,
         }
     }
+
+    /// 设置分类
+    pub fn with_category(mut self, category: NotificationCategory) -> Self {
+        self.category = category;
+        self
+    }
+
+    /// 设置附加数据
+    pub fn with_data(mut self, data: serde_json::Value) -> Self {
+        self.data = Some(data);
+        self
+    }
 }
 
 impl ResponsePayload {
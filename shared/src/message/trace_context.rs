@@ -0,0 +1,104 @@
+//! 帧链路追踪上下文 (W3C Trace Context)
+//!
+//! 客户端在握手时声明支持，服务端在握手响应中确认；双方确认后请求 / 响应帧可携带
+//! `traceparent`，服务端处理 span 以客户端 span 为父，响应回传服务端 span：
+//!
+//! ```text
+//! Handshake { trace_context: true }
+//!   → Response { data: { ..., "trace_context": true } }   // 旧服务端无该字段 → 不携带
+//! ```
+//!
+//! - 携带上下文的帧在事件类型字节上置 [`TRACE_CONTEXT_FLAG`] (与压缩标志独立)，
+//!   Correlation ID 之后插入 1 字节长度 + ASCII `traceparent`
+//! - 未协商的连接从不置位，旧客户端 / 旧服务端看到的帧格式不变
+//!
+//! ```text
+//! [type | 0x40][request_id 16][correlation_id 16][ctx_len 1][traceparent ...][len 4][payload ...]
+//! ```
+
+/// 事件类型字节次高位：帧携带 trace context
+pub const TRACE_CONTEXT_FLAG: u8 = 0x40;
+
+/// 拆分类型字节 → (去标志后的类型字节, 是否携带 trace context)
+pub fn split_type_byte(byte: u8) -> (u8, bool) {
+    (byte & !TRACE_CONTEXT_FLAG, byte & TRACE_CONTEXT_FLAG != 0)
+}
+
+/// 可写入帧的 trace context (格式无效或超长时返回 None，帧按无上下文发送)
+pub fn wire_value(trace_context: Option<&str>) -> Option<&[u8]> {
+    trace_context
+        .filter(|value| is_valid_traceparent(value))
+        .map(str::as_bytes)
+}
+
+/// 从帧读取的原始字节 → trace context (无效时丢弃，不影响消息本身)
+pub fn from_wire(bytes: &[u8]) -> Option<String> {
+    std::str::from_utf8(bytes)
+        .ok()
+        .filter(|value| is_valid_traceparent(value))
+        .map(str::to_string)
+}
+
+/// W3C `traceparent` 校验：`{version:2}-{trace_id:32}-{span_id:16}-{flags:2}` 小写十六进制，
+/// trace_id / span_id 不可全 0，版本 `ff` 无效
+pub fn is_valid_traceparent(value: &str) -> bool {
+    let parts: Vec<&str> = value.split('-').collect();
+    let &[version, trace_id, span_id, flags] = parts.as_slice() else {
+        return false;
+    };
+    let hex = |s: &str, len: usize| {
+        s.len() == len
+            && s.bytes()
+                .all(|b| b.is_ascii_digit() || (b'a'..=b'f').contains(&b))
+    };
+    hex(version, 2)
+        && version != "ff"
+        && hex(trace_id, 32)
+        && trace_id.bytes().any(|b| b != b'0')
+        && hex(span_id, 16)
+        && span_id.bytes().any(|b| b != b'0')
+        && hex(flags, 2)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::message::compression::COMPRESSED_FLAG;
+
+    const TRACEPARENT: &str = "00-4bf92f3577b34da6a3ce929d0e0e4736-00f067aa0ba902b7-01";
+
+    #[test]
+    fn test_split_type_byte() {
+        assert_eq!(split_type_byte(3), (3, false));
+        assert_eq!(split_type_byte(3 | TRACE_CONTEXT_FLAG), (3, true));
+        assert_eq!(
+            split_type_byte(5 | TRACE_CONTEXT_FLAG | COMPRESSED_FLAG),
+            (5 | COMPRESSED_FLAG, true)
+        );
+    }
+
+    #[test]
+    fn test_traceparent_validation() {
+        assert!(is_valid_traceparent(TRACEPARENT));
+        assert!(!is_valid_traceparent(""));
+        assert!(!is_valid_traceparent(&TRACEPARENT.to_uppercase()));
+        assert!(!is_valid_traceparent(
+            "00-00000000000000000000000000000000-00f067aa0ba902b7-01"
+        ));
+        assert!(!is_valid_traceparent(
+            "00-4bf92f3577b34da6a3ce929d0e0e4736-0000000000000000-01"
+        ));
+        assert!(!is_valid_traceparent(
+            "ff-4bf92f3577b34da6a3ce929d0e0e4736-00f067aa0ba902b7-01"
+        ));
+        assert!(!is_valid_traceparent(&format!("{TRACEPARENT}-extra")));
+
+        assert_eq!(wire_value(Some(TRACEPARENT)), Some(TRACEPARENT.as_bytes()));
+        assert_eq!(wire_value(Some("garbage")), None);
+        assert_eq!(
+            from_wire(TRACEPARENT.as_bytes()).as_deref(),
+            Some(TRACEPARENT)
+        );
+        assert_eq!(from_wire(&[0xff, 0xfe]), None);
+    }
+}
@@ -0,0 +1,102 @@
+//! Staff Announcement Model (员工公告)
+//!
+//! Short messages a manager broadcasts to all or selected terminals
+//! ("86 the salmon", "staff meeting at 4"). Staff acknowledge them on the
+//! terminal; acknowledgements are tracked per employee.
+
+use serde::{Deserialize, Serialize};
+
+/// Announcement priority (urgent ones stay on screen until acknowledged)
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+#[cfg_attr(feature = "db", derive(sqlx::Type))]
+#[cfg_attr(feature = "db", sqlx(rename_all = "SCREAMING_SNAKE_CASE"))]
+pub enum AnnouncementPriority {
+    #[default]
+    Normal,
+    Urgent,
+}
+
+/// Staff announcement
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Announcement {
+    pub id: i64,
+    pub message: String,
+    pub priority: AnnouncementPriority,
+    pub sender_id: i64,
+    pub sender_name: String,
+    /// Target terminal names, empty = all terminals
+    pub targets: Vec<String>,
+    pub created_at: i64,
+    /// Acknowledgements in ack order
+    pub acks: Vec<AnnouncementAck>,
+}
+
+impl Announcement {
+    /// Whether the terminal should show this announcement
+    pub fn is_for(&self, terminal: &str) -> bool {
+        self.targets.is_empty() || self.targets.iter().any(|t| t == terminal)
+    }
+}
+
+/// Acknowledgement of an announcement by an employee
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "db", derive(sqlx::FromRow))]
+pub struct AnnouncementAck {
+    pub employee_id: i64,
+    pub employee_name: String,
+    /// Terminal the announcement was acknowledged on
+    pub terminal: Option<String>,
+    pub acked_at: i64,
+}
+
+/// Send announcement payload
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct AnnouncementCreate {
+    pub message: String,
+    #[serde(default)]
+    pub priority: AnnouncementPriority,
+    /// Target terminal names, empty = all terminals
+    #[serde(default)]
+    pub targets: Vec<String>,
+}
+
+/// Acknowledge announcement payload
+#[derive(Debug, Clone, Serialize, Deserialize, Default)]
+pub struct AnnouncementAckInput {
+    pub terminal: Option<String>,
+}
+
+/// Terminal currently connected to the edge server (announcement target)
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ConnectedTerminal {
+    /// Connection id (changes on reconnect)
+    pub client_id: String,
+    /// Terminal name from the client certificate
+    pub name: String,
+    pub addr: Option<String>,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_is_for_targets() {
+        let mut announcement = Announcement {
+            id: 1,
+            message: "86 the salmon".to_string(),
+            priority: AnnouncementPriority::Urgent,
+            sender_id: 1,
+            sender_name: "Ana".to_string(),
+            targets: vec![],
+            created_at: 0,
+            acks: vec![],
+        };
+        assert!(announcement.is_for("bar-1"));
+
+        announcement.targets = vec!["kitchen".to_string()];
+        assert!(announcement.is_for("kitchen"));
+        assert!(!announcement.is_for("bar-1"));
+    }
+}
@@ -19,6 +19,9 @@ pub struct AttributeOption {
     pub enable_quantity: bool,
     /// Maximum quantity allowed (only effective when enable_quantity=true)
     pub max_quantity: Option<i32>,
+    /// Stock level consumed per unit sold (edge-local, not cloud-synced)
+    #[serde(default)]
+    pub stock_level_id: Option<i64>,
 }
 
 /// Attribute entity
@@ -46,9 +49,12 @@ pub struct Attribute {
     pub options: Vec<AttributeOption>,
 }
 
-/// Attribute option input (for create/update, without id/attribute_id)
+/// Attribute option input (for create/update, without attribute_id)
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct AttributeOptionInput {
+    /// Existing option id (update keeps the option and its id; None = new option)
+    #[serde(default)]
+    pub id: Option<i64>,
     pub name: String,
     #[serde(default)]
     pub price_modifier: f64,
@@ -59,6 +65,8 @@ pub struct AttributeOptionInput {
     #[serde(default)]
     pub enable_quantity: bool,
     pub max_quantity: Option<i32>,
+    #[serde(default)]
+    pub stock_level_id: Option<i64>,
 }
 
 /// Create attribute payload
@@ -0,0 +1,51 @@
+//! Marketing Campaign ROI Report (营销活动效果)
+//!
+//! 把价格规则、会员组折扣、集章兑换和已归档订单关联起来，
+//! 按活动统计使用次数、让利成本、带动营收和会员回访提升。
+
+use serde::{Deserialize, Serialize};
+
+/// Campaign source
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq, Hash, PartialOrd, Ord)]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+pub enum CampaignKind {
+    /// 价格规则 (折扣方向)
+    PriceRule,
+    /// 会员组折扣
+    MarketingGroup,
+    /// 集章兑换
+    StampActivity,
+}
+
+/// Per-campaign ROI row
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct CampaignRoi {
+    pub kind: CampaignKind,
+    /// None when the source no longer exists (e.g. deleted marketing group)
+    pub campaign_id: Option<i64>,
+    pub name: String,
+    /// Orders the campaign was applied to (stamp: rewards redeemed)
+    pub redemptions: i64,
+    /// Discount given away (stamp: menu price of the comped rewards)
+    pub discount_cost: f64,
+    /// Total of the orders the campaign was applied to
+    pub attributable_revenue: f64,
+    /// attributable_revenue / discount_cost (None when nothing was given away)
+    pub revenue_per_cost: Option<f64>,
+    /// Distinct members among the redeeming orders
+    pub members_reached: i64,
+    /// Share of reached members who came back later in the period
+    pub repeat_rate: f64,
+    /// repeat_rate minus the period baseline (all members with an order)
+    pub repeat_lift: f64,
+}
+
+/// Campaign ROI report for a period
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct CampaignRoiReport {
+    pub from: i64,
+    pub to: i64,
+    /// Share of members with an order in the period who ordered again
+    pub baseline_repeat_rate: f64,
+    pub campaigns: Vec<CampaignRoi>,
+}
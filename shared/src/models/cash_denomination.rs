@@ -0,0 +1,308 @@
+//! Cash Denomination Model (点钞面额)
+//!
+//! One denomination set (coins + notes) per currency. The primary set is the
+//! store currency; secondary sets (e.g. USD notes accepted from tourists) are
+//! converted with `exchange_rate` when the drawer is counted at shift close.
+//! Totals are computed here — terminals only send quantities.
+
+use rust_decimal::Decimal;
+use rust_decimal::prelude::{FromPrimitive, ToPrimitive};
+use serde::{Deserialize, Serialize};
+
+/// Coin or note
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+#[cfg_attr(feature = "db", derive(sqlx::Type))]
+#[cfg_attr(feature = "db", sqlx(rename_all = "SCREAMING_SNAKE_CASE"))]
+pub enum DenominationKind {
+    Coin,
+    Note,
+}
+
+/// Single denomination within a set
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "db", derive(sqlx::FromRow))]
+pub struct CashDenomination {
+    pub id: i64,
+    pub set_id: i64,
+    pub kind: DenominationKind,
+    pub value: f64,
+}
+
+/// Denomination set for one currency
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "db", derive(sqlx::FromRow))]
+pub struct CashDenominationSet {
+    pub id: i64,
+    /// ISO 4217 code (unique)
+    pub currency_code: String,
+    pub currency_symbol: String,
+    /// Store currency (exactly one set)
+    pub is_primary: bool,
+    /// Primary units per 1 unit of this currency (1.0 for the primary set)
+    pub exchange_rate: f64,
+    pub is_active: bool,
+    pub created_at: i64,
+    pub updated_at: i64,
+
+    // -- Relations (populated by application code, skipped by FromRow) --
+    /// Sorted by value ascending
+    #[cfg_attr(feature = "db", sqlx(skip))]
+    #[serde(default)]
+    pub denominations: Vec<CashDenomination>,
+}
+
+/// Denomination input (create / replace)
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CashDenominationInput {
+    pub kind: DenominationKind,
+    pub value: f64,
+}
+
+/// Create denomination set payload
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CashDenominationSetCreate {
+    pub currency_code: String,
+    #[serde(default)]
+    pub currency_symbol: String,
+    #[serde(default)]
+    pub is_primary: bool,
+    /// Required for secondary sets
+    pub exchange_rate: Option<f64>,
+    pub denominations: Vec<CashDenominationInput>,
+}
+
+/// Update denomination set payload
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CashDenominationSetUpdate {
+    pub currency_symbol: Option<String>,
+    pub exchange_rate: Option<f64>,
+    pub is_active: Option<bool>,
+    /// Replaces the whole list when present
+    pub denominations: Option<Vec<CashDenominationInput>>,
+}
+
+/// Counted quantity of one denomination
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "db", derive(sqlx::FromRow))]
+pub struct CashCountLine {
+    pub currency_code: String,
+    pub value: f64,
+    pub quantity: i64,
+}
+
+/// Counted denomination with its subtotal
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CountedDenomination {
+    pub kind: DenominationKind,
+    pub value: f64,
+    pub quantity: i64,
+    pub subtotal: f64,
+}
+
+/// Count result for one currency
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CurrencyCount {
+    pub currency_code: String,
+    pub currency_symbol: String,
+    pub is_primary: bool,
+    pub exchange_rate: f64,
+    pub lines: Vec<CountedDenomination>,
+    /// In this currency
+    pub total: f64,
+    /// Converted to the primary currency
+    pub total_in_primary: f64,
+}
+
+/// Server-computed cash count (shift close / preview)
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CashCountSummary {
+    pub currencies: Vec<CurrencyCount>,
+    /// Grand total in the primary currency (becomes `actual_cash`)
+    pub total: f64,
+}
+
+fn dec(value: f64) -> Decimal {
+    Decimal::from_f64(value).unwrap_or_default()
+}
+
+fn money(value: Decimal) -> f64 {
+    value.round_dp(2).to_f64().unwrap_or_default()
+}
+
+impl CashCountSummary {
+    /// Compute totals from counted quantities
+    ///
+    /// Every line must match an active set and one of its denominations;
+    /// quantities must be non-negative. Lines for the same denomination are summed.
+    pub fn compute(sets: &[CashDenominationSet], lines: &[CashCountLine]) -> Result<Self, String> {
+        let mut currencies: Vec<CurrencyCount> = Vec::new();
+        let mut grand_total = Decimal::ZERO;
+
+        for line in lines {
+            if line.quantity < 0 {
+                return Err(format!(
+                    "Negative quantity for {} {}",
+                    line.value, line.currency_code
+                ));
+            }
+            let set = sets
+                .iter()
+                .find(|s| s.is_active && s.currency_code == line.currency_code)
+                .ok_or_else(|| format!("No active denomination set for {}", line.currency_code))?;
+            let denomination = set
+                .denominations
+                .iter()
+                .find(|d| dec(d.value) == dec(line.value))
+                .ok_or_else(|| {
+                    format!(
+                        "{} is not a {} denomination",
+                        line.value, line.currency_code
+                    )
+                })?;
+
+            let idx = match currencies
+                .iter()
+                .position(|c| c.currency_code == set.currency_code)
+            {
+                Some(idx) => idx,
+                None => {
+                    currencies.push(CurrencyCount {
+                        currency_code: set.currency_code.clone(),
+                        currency_symbol: set.currency_symbol.clone(),
+                        is_primary: set.is_primary,
+                        exchange_rate: set.exchange_rate,
+                        lines: Vec::new(),
+                        total: 0.0,
+                        total_in_primary: 0.0,
+                    });
+                    currencies.len() - 1
+                }
+            };
+            let currency = &mut currencies[idx];
+            match currency
+                .lines
+                .iter_mut()
+                .find(|l| dec(l.value) == dec(denomination.value))
+            {
+                Some(existing) => existing.quantity += line.quantity,
+                None => currency.lines.push(CountedDenomination {
+                    kind: denomination.kind,
+                    value: denomination.value,
+                    quantity: line.quantity,
+                    subtotal: 0.0,
+                }),
+            }
+        }
+
+        for currency in &mut currencies {
+            currency.lines.sort_by(|a, b| b.value.total_cmp(&a.value));
+            let mut total = Decimal::ZERO;
+            for line in &mut currency.lines {
+                let subtotal = dec(line.value) * Decimal::from(line.quantity);
+                line.subtotal = money(subtotal);
+                total += subtotal;
+            }
+            let in_primary = (total * dec(currency.exchange_rate)).round_dp(2);
+            currency.total = money(total);
+            currency.total_in_primary = money(in_primary);
+            grand_total += in_primary;
+        }
+        // Primary currency first
+        currencies.sort_by_key(|c| !c.is_primary);
+
+        Ok(Self {
+            currencies,
+            total: money(grand_total),
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn set(
+        id: i64,
+        code: &str,
+        is_primary: bool,
+        rate: f64,
+        values: &[f64],
+    ) -> CashDenominationSet {
+        CashDenominationSet {
+            id,
+            currency_code: code.to_string(),
+            currency_symbol: String::new(),
+            is_primary,
+            exchange_rate: rate,
+            is_active: true,
+            created_at: 0,
+            updated_at: 0,
+            denominations: values
+                .iter()
+                .enumerate()
+                .map(|(i, v)| CashDenomination {
+                    id: id * 100 + i as i64,
+                    set_id: id,
+                    kind: if *v < 5.0 {
+                        DenominationKind::Coin
+                    } else {
+                        DenominationKind::Note
+                    },
+                    value: *v,
+                })
+                .collect(),
+        }
+    }
+
+    fn line(code: &str, value: f64, quantity: i64) -> CashCountLine {
+        CashCountLine {
+            currency_code: code.to_string(),
+            value,
+            quantity,
+        }
+    }
+
+    #[test]
+    fn test_compute_mixed_currencies() {
+        let sets = vec![
+            set(
+                1,
+                "EUR",
+                true,
+                1.0,
+                &[0.1, 0.2, 0.5, 1.0, 2.0, 5.0, 10.0, 20.0, 50.0],
+            ),
+            set(2, "USD", false, 0.92, &[1.0, 5.0, 10.0, 20.0]),
+        ];
+        let summary = CashCountSummary::compute(
+            &sets,
+            &[
+                line("USD", 20.0, 3),
+                line("EUR", 0.1, 3),
+                line("EUR", 0.2, 1),
+                line("EUR", 50.0, 2),
+                line("EUR", 0.1, 4),
+            ],
+        )
+        .unwrap();
+
+        let eur = &summary.currencies[0];
+        assert_eq!(eur.currency_code, "EUR");
+        // 0.1 × 7 + 0.2 — float math would give 0.9000000000000001
+        assert_eq!(eur.lines.last().unwrap().subtotal, 0.7);
+        assert_eq!(eur.total, 100.9);
+        let usd = &summary.currencies[1];
+        assert_eq!(usd.total, 60.0);
+        assert_eq!(usd.total_in_primary, 55.2);
+        assert_eq!(summary.total, 156.1);
+    }
+
+    #[test]
+    fn test_compute_rejects_unknown_denomination() {
+        let sets = vec![set(1, "EUR", true, 1.0, &[1.0, 2.0])];
+        assert!(CashCountSummary::compute(&sets, &[line("EUR", 3.0, 1)]).is_err());
+        assert!(CashCountSummary::compute(&sets, &[line("GBP", 1.0, 1)]).is_err());
+        assert!(CashCountSummary::compute(&sets, &[line("EUR", 1.0, -1)]).is_err());
+    }
+}
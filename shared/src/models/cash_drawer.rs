@@ -0,0 +1,82 @@
+//! Cash Drawer Model (钱箱管理)
+//!
+//! Every drawer open is recorded with its reason, operator and shift: cash sales,
+//! no-sale opens, and paid-in / paid-out movements (change float top-up, petty
+//! cash, supplier payments), which also adjust the shift's expected cash.
+
+use serde::{Deserialize, Serialize};
+
+/// Cash drawer event kind
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+#[cfg_attr(feature = "db", derive(sqlx::Type))]
+#[cfg_attr(feature = "db", sqlx(rename_all = "SCREAMING_SNAKE_CASE"))]
+pub enum CashDrawerEventKind {
+    /// Drawer opened for a cash payment (the cash itself is counted by the payment)
+    Sale,
+    /// Drawer opened without a sale or cash movement
+    NoSale,
+    /// Cash put into the drawer
+    PaidIn,
+    /// Cash taken out of the drawer
+    PaidOut,
+}
+
+impl CashDrawerEventKind {
+    /// Effect on the shift's expected cash per unit of `amount`
+    pub fn cash_sign(self) -> f64 {
+        match self {
+            Self::Sale | Self::NoSale => 0.0,
+            Self::PaidIn => 1.0,
+            Self::PaidOut => -1.0,
+        }
+    }
+}
+
+/// Recorded drawer event
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "db", derive(sqlx::FromRow))]
+pub struct CashDrawerEvent {
+    pub id: i64,
+    pub kind: CashDrawerEventKind,
+    /// Cash moved (0 for SALE / NO_SALE)
+    pub amount: f64,
+    pub reason: Option<String>,
+    pub operator_id: i64,
+    pub operator_name: String,
+    /// Shift open at the time (None outside shifts)
+    pub shift_id: Option<i64>,
+    pub created_at: i64,
+}
+
+/// Record a drawer event payload
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CashDrawerEventCreate {
+    pub kind: CashDrawerEventKind,
+    /// Required (> 0) for PAID_IN / PAID_OUT
+    #[serde(default)]
+    pub amount: f64,
+    pub reason: Option<String>,
+}
+
+/// Drawer activity totals (shift reconciliation / daily report)
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "db", derive(sqlx::FromRow))]
+pub struct CashDrawerSummary {
+    /// Drawer opens for cash payments
+    pub sale_opens: i64,
+    /// Drawer opens without a sale
+    pub no_sale_opens: i64,
+    pub paid_in_count: i64,
+    pub paid_in_amount: f64,
+    pub paid_out_count: i64,
+    pub paid_out_amount: f64,
+}
+
+/// Drawer events of a shift with their totals
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ShiftCashDrawer {
+    pub shift_id: i64,
+    pub summary: CashDrawerSummary,
+    pub events: Vec<CashDrawerEvent>,
+}
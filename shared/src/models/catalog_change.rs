@@ -0,0 +1,48 @@
+//! Catalog Change Model (目录变更历史)
+
+use serde::{Deserialize, Serialize};
+
+/// Catalog change action
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+#[cfg_attr(feature = "db", derive(sqlx::Type))]
+#[cfg_attr(feature = "db", sqlx(rename_all = "SCREAMING_SNAKE_CASE"))]
+pub enum CatalogChangeAction {
+    Create,
+    Update,
+    Delete,
+    /// Restored to an earlier version
+    Revert,
+}
+
+/// Catalog change record: before/after snapshot of one entity
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "db", derive(sqlx::FromRow))]
+pub struct CatalogChange {
+    pub id: i64,
+    /// Entity type ("product", "category", "tag", "price_rule")
+    pub entity_type: String,
+    pub entity_id: i64,
+    pub action: CatalogChangeAction,
+    /// Snapshot before the change (None for Create)
+    #[cfg_attr(feature = "db", sqlx(json))]
+    pub before_snapshot: Option<serde_json::Value>,
+    /// Snapshot after the change (None for Delete)
+    #[cfg_attr(feature = "db", sqlx(json))]
+    pub after_snapshot: Option<serde_json::Value>,
+    pub operator_id: Option<i64>,
+    pub operator_name: Option<String>,
+    pub created_at: i64,
+}
+
+/// Create catalog change payload
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CatalogChangeCreate {
+    pub entity_type: String,
+    pub entity_id: i64,
+    pub action: CatalogChangeAction,
+    pub before_snapshot: Option<serde_json::Value>,
+    pub after_snapshot: Option<serde_json::Value>,
+    pub operator_id: Option<i64>,
+    pub operator_name: Option<String>,
+}
@@ -0,0 +1,172 @@
+//! Coupon Model (优惠码)
+
+use rand::Rng;
+use serde::{Deserialize, Serialize};
+
+use super::price_rule::{AdjustmentType, ProductScope};
+
+/// Maximum coupons generated in one batch
+pub const MAX_COUPON_BATCH: i64 = 500;
+
+/// 优惠码字符集 (去掉易混淆的 0/O/1/I/L)
+const CODE_ALPHABET: &[u8] = b"ABCDEFGHJKMNPQRSTUVWXYZ23456789";
+const CODE_LEN: usize = 8;
+
+/// Normalize a typed code (trim + uppercase)
+pub fn normalize_coupon_code(code: &str) -> String {
+    code.trim().to_ascii_uppercase()
+}
+
+/// Random coupon code with an optional prefix (e.g. `SUMMER-K7QX2M9A`)
+///
+/// edge-server 与 crab-cloud 共用，保证两端生成的码格式一致。
+pub fn generate_coupon_code(prefix: Option<&str>) -> String {
+    let mut rng = rand::thread_rng();
+    let random: String = (0..CODE_LEN)
+        .map(|_| CODE_ALPHABET[rng.gen_range(0..CODE_ALPHABET.len())] as char)
+        .collect();
+    match prefix.map(normalize_coupon_code).filter(|p| !p.is_empty()) {
+        Some(prefix) => format!("{prefix}-{random}"),
+        None => random,
+    }
+}
+
+/// Coupon entity (优惠码)
+///
+/// 单次码 `max_uses = 1`，多次码 `max_uses = N`，不限次数 `max_uses = None`。
+/// `used_count` 在订单完成时递增，随 `SyncResource::Coupon` 同步回云端。
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "db", derive(sqlx::FromRow))]
+pub struct Coupon {
+    pub id: i64,
+    /// Redemption code (uppercase, unique per store)
+    pub code: String,
+    pub name: String,
+    /// Global / Category / Product (Tag 不支持：订单快照不含标签)
+    pub product_scope: ProductScope,
+    /// Target record ID based on scope (category/product ID)
+    pub target_id: Option<i64>,
+    pub adjustment_type: AdjustmentType,
+    /// Adjustment value (percentage: 30=30%, fixed: 5.00=€5)
+    pub adjustment_value: f64,
+    /// Maximum redemptions (None = unlimited)
+    pub max_uses: Option<i64>,
+    pub used_count: i64,
+    /// Valid from datetime (Unix millis)
+    pub valid_from: Option<i64>,
+    /// Valid until datetime (Unix millis)
+    pub valid_until: Option<i64>,
+    pub is_active: bool,
+    pub created_at: i64,
+    pub updated_at: i64,
+}
+
+impl Coupon {
+    /// Whether the coupon can still be redeemed at `now` (Unix millis)
+    pub fn is_redeemable_at(&self, now: i64) -> bool {
+        self.is_active
+            && self.valid_from.is_none_or(|from| now >= from)
+            && self.valid_until.is_none_or(|until| now <= until)
+            && !self.is_exhausted()
+    }
+
+    /// Whether all redemptions have been used
+    pub fn is_exhausted(&self) -> bool {
+        self.max_uses.is_some_and(|max| self.used_count >= max)
+    }
+}
+
+/// Create coupon payload
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CouponCreate {
+    /// Redemption code (None = generated)
+    pub code: Option<String>,
+    pub name: String,
+    pub product_scope: ProductScope,
+    pub target_id: Option<i64>,
+    pub adjustment_type: AdjustmentType,
+    pub adjustment_value: f64,
+    pub max_uses: Option<i64>,
+    pub valid_from: Option<i64>,
+    pub valid_until: Option<i64>,
+}
+
+/// Update coupon payload (code is immutable)
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CouponUpdate {
+    pub name: Option<String>,
+    pub product_scope: Option<ProductScope>,
+    pub target_id: Option<i64>,
+    pub adjustment_type: Option<AdjustmentType>,
+    pub adjustment_value: Option<f64>,
+    pub max_uses: Option<i64>,
+    pub valid_from: Option<i64>,
+    pub valid_until: Option<i64>,
+    pub is_active: Option<bool>,
+}
+
+/// Batch-generate single-use coupons sharing one discount
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CouponGenerate {
+    /// Number of codes (1..=MAX_COUPON_BATCH)
+    pub count: i64,
+    /// Optional code prefix (e.g. "SUMMER")
+    pub prefix: Option<String>,
+    pub name: String,
+    pub product_scope: ProductScope,
+    pub target_id: Option<i64>,
+    pub adjustment_type: AdjustmentType,
+    pub adjustment_value: f64,
+    pub valid_from: Option<i64>,
+    pub valid_until: Option<i64>,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn coupon(max_uses: Option<i64>, used_count: i64) -> Coupon {
+        Coupon {
+            id: 1,
+            code: "WELCOME10".to_string(),
+            name: "Welcome".to_string(),
+            product_scope: ProductScope::Global,
+            target_id: None,
+            adjustment_type: AdjustmentType::Percentage,
+            adjustment_value: 10.0,
+            max_uses,
+            used_count,
+            valid_from: Some(1_000),
+            valid_until: Some(2_000),
+            is_active: true,
+            created_at: 0,
+            updated_at: 0,
+        }
+    }
+
+    #[test]
+    fn test_generate_code_uses_normalized_prefix() {
+        let code = generate_coupon_code(Some(" summer "));
+        assert!(code.starts_with("SUMMER-"));
+        assert_eq!(code.len(), "SUMMER-".len() + CODE_LEN);
+        assert!(
+            code["SUMMER-".len()..]
+                .bytes()
+                .all(|b| CODE_ALPHABET.contains(&b))
+        );
+        assert_eq!(generate_coupon_code(None).len(), CODE_LEN);
+    }
+
+    #[test]
+    fn test_coupon_redeemable_window_and_uses() {
+        assert!(coupon(Some(1), 0).is_redeemable_at(1_500));
+        assert!(coupon(None, 99).is_redeemable_at(1_500));
+        assert!(!coupon(Some(1), 1).is_redeemable_at(1_500));
+        assert!(!coupon(Some(1), 0).is_redeemable_at(999));
+        assert!(!coupon(Some(1), 0).is_redeemable_at(2_001));
+
+        let mut inactive = coupon(None, 0);
+        inactive.is_active = false;
+        assert!(!inactive.is_redeemable_at(1_500));
+    }
+}
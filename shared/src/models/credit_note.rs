@@ -79,7 +79,10 @@ pub struct RefundableInfo {
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct CreateCreditNoteRequest {
     pub original_order_pk: i64,
+    /// 退款明细 (`full_refund` 时必须为空)
     pub items: Vec<CreditNoteItemRequest>,
+    /// 整单退款：服务端按剩余可退数量展开，金额为订单剩余可退金额
+    pub full_refund: bool,
     pub refund_method: String,
     pub reason: String,
     #[serde(skip_serializing_if = "Option::is_none")]
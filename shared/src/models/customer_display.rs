@@ -0,0 +1,55 @@
+//! Customer Display Model (顾客显示屏 / 客显)
+//!
+//! A pole display or secondary screen attached to a POS terminal, reached over
+//! TCP (network display or serial-over-IP adapter). Keyed by the terminal's
+//! client certificate identity: orders rung up on that terminal are mirrored
+//! to its display (item + price while ringing, total and change on payment).
+
+use serde::{Deserialize, Serialize};
+
+/// Default TCP port for network displays / serial adapters
+pub const DEFAULT_CUSTOMER_DISPLAY_PORT: u16 = 9100;
+/// Default line width (20 × 2 VFD)
+pub const DEFAULT_CUSTOMER_DISPLAY_COLUMNS: i32 = 20;
+
+/// Customer display entity
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "db", derive(sqlx::FromRow))]
+pub struct CustomerDisplay {
+    pub id: i64,
+    /// Terminal identity (client certificate CN, same key as terminal profiles)
+    pub identity: String,
+    pub name: String,
+    pub host: String,
+    pub port: i32,
+    /// Characters per line
+    pub columns: i32,
+    /// Shown when no order is in progress
+    pub idle_text: Option<String>,
+    pub is_active: bool,
+    pub created_at: i64,
+    pub updated_at: i64,
+}
+
+/// Create customer display payload
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CustomerDisplayCreate {
+    pub identity: String,
+    pub name: String,
+    pub host: String,
+    pub port: Option<i32>,
+    pub columns: Option<i32>,
+    pub idle_text: Option<String>,
+}
+
+/// Update customer display payload
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CustomerDisplayUpdate {
+    pub identity: Option<String>,
+    pub name: Option<String>,
+    pub host: Option<String>,
+    pub port: Option<i32>,
+    pub columns: Option<i32>,
+    pub idle_text: Option<String>,
+    pub is_active: Option<bool>,
+}
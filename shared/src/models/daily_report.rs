@@ -28,6 +28,20 @@ pub struct ShiftBreakdown {
     pub total_tax: f64,
     pub total_discount: f64,
     pub total_surcharge: f64,
+    /// Paid break time within the shift (millis)
+    #[serde(default)]
+    pub paid_break_ms: i64,
+    /// Unpaid break time within the shift (millis, deducted from worked time)
+    #[serde(default)]
+    pub unpaid_break_ms: i64,
+    /// Drawer opens without a sale during the shift
+    #[serde(default)]
+    pub no_sale_opens: i64,
+    /// Cash paid in / out of the drawer during the shift (in expected_cash)
+    #[serde(default)]
+    pub paid_in_amount: f64,
+    #[serde(default)]
+    pub paid_out_amount: f64,
 }
 
 /// Daily Report - shift settlement record
@@ -45,6 +59,27 @@ pub struct DailyReport {
     pub refund_amount: f64,
     /// Number of credit notes issued
     pub refund_count: i64,
+    /// Payment-method surcharges collected (card surcharge etc., included in net_revenue)
+    #[serde(default)]
+    pub payment_surcharge_amount: f64,
+    /// Orders already open when the business day started (结转入)
+    #[serde(default)]
+    pub carried_in_count: i64,
+    #[serde(default)]
+    pub carried_in_amount: f64,
+    /// Orders still open when the business day ended (结转出)
+    #[serde(default)]
+    pub carried_out_count: i64,
+    #[serde(default)]
+    pub carried_out_amount: f64,
+    /// Drawer opens without a sale (钱箱无销售开启)
+    #[serde(default)]
+    pub no_sale_opens: i64,
+    /// Cash paid in / out of the drawer
+    #[serde(default)]
+    pub paid_in_amount: f64,
+    #[serde(default)]
+    pub paid_out_amount: f64,
     /// Whether this report was auto-generated (e.g. by shift close)
     pub auto_generated: bool,
     /// When the report was generated (Unix millis)
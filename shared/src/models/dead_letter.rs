@@ -0,0 +1,55 @@
+//! Dead Letter Model (死信队列)
+//!
+//! Bus messages whose processor returned an error, panicked or asked for a
+//! retry are parked here with error context instead of being dropped.
+//! An admin can inspect them and redeliver or discard each one.
+
+use serde::{Deserialize, Serialize};
+
+/// Why the message was dead-lettered
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+#[cfg_attr(feature = "db", derive(sqlx::Type))]
+#[cfg_attr(feature = "db", sqlx(rename_all = "SCREAMING_SNAKE_CASE"))]
+pub enum DeadLetterFailure {
+    /// Processor returned an error (e.g. undecodable payload)
+    Error,
+    /// Processor panicked
+    Panic,
+    /// Processor asked for a retry (handler does not retry automatically)
+    Retry,
+}
+
+/// Dead letter lifecycle
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+#[cfg_attr(feature = "db", derive(sqlx::Type))]
+#[cfg_attr(feature = "db", sqlx(rename_all = "SCREAMING_SNAKE_CASE"))]
+pub enum DeadLetterStatus {
+    Pending,
+    /// Re-published to the bus (a new failure creates a new dead letter)
+    Redelivered,
+    Discarded,
+}
+
+/// Dead-lettered bus message
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "db", derive(sqlx::FromRow))]
+pub struct DeadLetter {
+    pub id: i64,
+    /// Bus event type (snake_case, e.g. "request_command")
+    pub event_type: String,
+    /// RequestCommand action (e.g. "order.add_items")
+    pub action: Option<String>,
+    pub request_id: String,
+    /// Client that sent the message
+    pub source: Option<String>,
+    /// Decoded payload (None = payload could not be decoded, not redeliverable)
+    #[cfg_attr(feature = "db", sqlx(json))]
+    pub payload: Option<serde_json::Value>,
+    pub failure: DeadLetterFailure,
+    pub error: String,
+    pub status: DeadLetterStatus,
+    pub created_at: i64,
+    pub resolved_at: Option<i64>,
+}
@@ -0,0 +1,214 @@
+//! Delivery Channel Rule Model (外卖渠道起送价 / 配送费)
+//!
+//! Delivery orders are takeout orders carrying a channel in the order
+//! metadata ([`DELIVERY_CHANNEL_KEY`], e.g. "glovo" or "web") and optionally a
+//! delivery zone ([`DELIVERY_ZONE_KEY`]). One rule per channel/zone sets the
+//! minimum order value (enforced at CompleteOrder) and the delivery fee tiers
+//! quoted to customers ("€3 more for free delivery").
+
+use rust_decimal::Decimal;
+use rust_decimal::prelude::{FromPrimitive, ToPrimitive};
+use serde::{Deserialize, Serialize};
+
+/// Order metadata key holding the delivery channel
+pub const DELIVERY_CHANNEL_KEY: &str = "platform";
+
+/// Order metadata key holding the delivery zone
+pub const DELIVERY_ZONE_KEY: &str = "delivery_zone";
+
+/// Fee charged once the order subtotal reaches `min_subtotal`
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq)]
+pub struct DeliveryFeeTier {
+    pub min_subtotal: f64,
+    pub fee: f64,
+}
+
+/// Channel constraint rule for one channel (and optionally one zone)
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+#[cfg_attr(feature = "db", derive(sqlx::FromRow))]
+pub struct DeliveryRule {
+    pub id: i64,
+    /// Delivery channel, matched case-insensitively against the order metadata
+    pub channel: String,
+    /// Delivery zone (None = every zone of the channel without a dedicated rule)
+    pub zone: Option<String>,
+    /// Minimum order value (None = no minimum)
+    pub min_order: Option<f64>,
+    /// Fee tiers, sorted by `min_subtotal`
+    #[cfg_attr(feature = "db", sqlx(json))]
+    pub fee_tiers: Vec<DeliveryFeeTier>,
+    pub is_active: bool,
+    pub created_at: i64,
+    pub updated_at: i64,
+}
+
+/// Create delivery rule payload
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct DeliveryRuleCreate {
+    pub channel: String,
+    pub zone: Option<String>,
+    pub min_order: Option<f64>,
+    #[serde(default)]
+    pub fee_tiers: Vec<DeliveryFeeTier>,
+}
+
+/// Update delivery rule payload
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct DeliveryRuleUpdate {
+    pub min_order: Option<f64>,
+    /// Remove the minimum order value
+    #[serde(default)]
+    pub clear_min_order: bool,
+    pub fee_tiers: Option<Vec<DeliveryFeeTier>>,
+    pub is_active: Option<bool>,
+}
+
+/// Next cheaper fee tier the customer can reach
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq)]
+pub struct DeliveryTierHint {
+    pub min_subtotal: f64,
+    pub fee: f64,
+    /// Amount still to add to reach the tier
+    pub amount_needed: f64,
+}
+
+/// Delivery quote for a basket (public QR / kiosk API)
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct DeliveryQuote {
+    pub channel: String,
+    pub zone: Option<String>,
+    pub subtotal: f64,
+    pub min_order: Option<f64>,
+    pub meets_minimum: bool,
+    /// Amount still to add to reach the minimum (0 when met)
+    pub amount_to_minimum: f64,
+    /// Fee at the current subtotal
+    pub fee: f64,
+    pub next_tier: Option<DeliveryTierHint>,
+}
+
+fn dec(value: f64) -> Decimal {
+    Decimal::from_f64(value).unwrap_or_default()
+}
+
+fn money(value: Decimal) -> f64 {
+    value.round_dp(2).to_f64().unwrap_or_default()
+}
+
+impl DeliveryRule {
+    /// Amount missing to reach the minimum order (None = minimum met or no minimum)
+    pub fn shortfall(&self, subtotal: f64) -> Option<f64> {
+        let min = self.min_order?;
+        let missing = dec(min) - dec(subtotal);
+        (missing > Decimal::ZERO).then(|| money(missing))
+    }
+
+    /// Tier applying at `subtotal` (highest `min_subtotal` reached)
+    fn tier_at(&self, subtotal: f64) -> Option<&DeliveryFeeTier> {
+        self.fee_tiers
+            .iter()
+            .filter(|t| dec(t.min_subtotal) <= dec(subtotal))
+            .max_by(|a, b| a.min_subtotal.total_cmp(&b.min_subtotal))
+    }
+
+    /// Delivery fee at `subtotal` (0 when below every tier)
+    pub fn fee_for(&self, subtotal: f64) -> f64 {
+        self.tier_at(subtotal).map(|t| t.fee).unwrap_or(0.0)
+    }
+
+    /// Quote for a basket: minimum check, current fee and the next cheaper tier
+    pub fn quote(&self, subtotal: f64) -> DeliveryQuote {
+        let fee = self.fee_for(subtotal);
+        let next_tier = self
+            .fee_tiers
+            .iter()
+            .filter(|t| dec(t.min_subtotal) > dec(subtotal) && t.fee < fee)
+            .min_by(|a, b| a.min_subtotal.total_cmp(&b.min_subtotal))
+            .map(|t| DeliveryTierHint {
+                min_subtotal: t.min_subtotal,
+                fee: t.fee,
+                amount_needed: money(dec(t.min_subtotal) - dec(subtotal)),
+            });
+        let shortfall = self.shortfall(subtotal);
+        DeliveryQuote {
+            channel: self.channel.clone(),
+            zone: self.zone.clone(),
+            subtotal,
+            min_order: self.min_order,
+            meets_minimum: shortfall.is_none(),
+            amount_to_minimum: shortfall.unwrap_or(0.0),
+            fee,
+            next_tier,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn rule() -> DeliveryRule {
+        DeliveryRule {
+            id: 1,
+            channel: "web".to_string(),
+            zone: None,
+            min_order: Some(12.0),
+            fee_tiers: vec![
+                DeliveryFeeTier {
+                    min_subtotal: 0.0,
+                    fee: 3.5,
+                },
+                DeliveryFeeTier {
+                    min_subtotal: 20.0,
+                    fee: 1.5,
+                },
+                DeliveryFeeTier {
+                    min_subtotal: 30.0,
+                    fee: 0.0,
+                },
+            ],
+            is_active: true,
+            created_at: 0,
+            updated_at: 0,
+        }
+    }
+
+    #[test]
+    fn test_shortfall() {
+        let r = rule();
+        assert_eq!(r.shortfall(10.3), Some(1.7));
+        assert_eq!(r.shortfall(12.0), None);
+        let no_min = DeliveryRule {
+            min_order: None,
+            ..rule()
+        };
+        assert_eq!(no_min.shortfall(0.0), None);
+    }
+
+    #[test]
+    fn test_quote_tiers() {
+        let r = rule();
+        let q = r.quote(10.0);
+        assert!(!q.meets_minimum);
+        assert_eq!(q.amount_to_minimum, 2.0);
+        assert_eq!(q.fee, 3.5);
+        assert_eq!(
+            q.next_tier,
+            Some(DeliveryTierHint {
+                min_subtotal: 20.0,
+                fee: 1.5,
+                amount_needed: 10.0
+            })
+        );
+
+        // "€3 more for free delivery"
+        let q = r.quote(27.0);
+        assert!(q.meets_minimum);
+        assert_eq!(q.fee, 1.5);
+        assert_eq!(q.next_tier.unwrap().amount_needed, 3.0);
+
+        let q = r.quote(30.0);
+        assert_eq!(q.fee, 0.0);
+        assert_eq!(q.next_tier, None);
+    }
+}
@@ -0,0 +1,203 @@
+//! Display Slide Model (客显屏轮播)
+//!
+//! 顾客显示屏空闲时轮播的促销图片。每张幻灯片引用一张内容哈希图片
+//! （与商品图片共用同一套图片同步），带播放时长和排期
+//! （有效期 + 星期 + 时段）。由店长在 edge 上管理，客户端按排期播放。
+
+use serde::{Deserialize, Serialize};
+
+/// Default on-screen duration when none is given
+pub const DEFAULT_SLIDE_DURATION_SECS: i32 = 8;
+
+/// Display slide entity
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "db", derive(sqlx::FromRow))]
+pub struct DisplaySlide {
+    pub id: i64,
+    pub name: String,
+    /// Image content hash (SHA256, same store as product images)
+    pub image: String,
+    /// Seconds on screen before advancing
+    pub duration_secs: i32,
+    /// Playback order (ascending)
+    pub sort_order: i32,
+    /// Active days of week (JSON array: 0=Sunday..6=Saturday), None = every day
+    #[cfg_attr(feature = "db", sqlx(json))]
+    pub active_days: Option<Vec<u8>>,
+    /// Daily window start "HH:MM" (store timezone)
+    pub active_start_time: Option<String>,
+    /// Daily window end "HH:MM" (exclusive; earlier than start = crosses midnight)
+    pub active_end_time: Option<String>,
+    /// Campaign start (Unix millis)
+    pub valid_from: Option<i64>,
+    /// Campaign end (Unix millis)
+    pub valid_until: Option<i64>,
+    pub is_active: bool,
+    pub created_at: i64,
+    pub updated_at: i64,
+}
+
+/// Create display slide payload
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct DisplaySlideCreate {
+    pub name: String,
+    pub image: String,
+    pub duration_secs: Option<i32>,
+    pub sort_order: Option<i32>,
+    pub active_days: Option<Vec<u8>>,
+    pub active_start_time: Option<String>,
+    pub active_end_time: Option<String>,
+    pub valid_from: Option<i64>,
+    pub valid_until: Option<i64>,
+}
+
+/// Update display slide payload
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct DisplaySlideUpdate {
+    pub name: Option<String>,
+    pub image: Option<String>,
+    pub duration_secs: Option<i32>,
+    pub sort_order: Option<i32>,
+    pub active_days: Option<Vec<u8>>,
+    pub active_start_time: Option<String>,
+    pub active_end_time: Option<String>,
+    pub valid_from: Option<i64>,
+    pub valid_until: Option<i64>,
+    pub is_active: Option<bool>,
+}
+
+impl DisplaySlide {
+    /// Whether the slide should be on screen at `now` (Unix millis)
+    ///
+    /// `weekday` (0=Sunday..6=Saturday) and `minute_of_day` are local to the
+    /// store timezone; the caller resolves them once for the whole playlist.
+    pub fn is_scheduled(&self, now: i64, weekday: u8, minute_of_day: u16) -> bool {
+        if !self.is_active {
+            return false;
+        }
+        if self.valid_from.is_some_and(|from| now < from)
+            || self.valid_until.is_some_and(|until| now > until)
+        {
+            return false;
+        }
+        if self
+            .active_days
+            .as_ref()
+            .is_some_and(|days| !days.contains(&weekday))
+        {
+            return false;
+        }
+
+        let start = self.active_start_time.as_deref().and_then(parse_hhmm);
+        let end = self.active_end_time.as_deref().and_then(parse_hhmm);
+        match (start, end) {
+            (Some(start), Some(end)) if end > start => {
+                minute_of_day >= start && minute_of_day < end
+            }
+            // Cross-midnight window (e.g., 21:00-02:00)
+            (Some(start), Some(end)) => minute_of_day >= start || minute_of_day < end,
+            (Some(start), None) => minute_of_day >= start,
+            (None, Some(end)) => minute_of_day < end,
+            (None, None) => true,
+        }
+    }
+}
+
+/// Slides to play now, in playback order (sort_order, then id)
+pub fn scheduled_slides(
+    slides: &[DisplaySlide],
+    now: i64,
+    weekday: u8,
+    minute_of_day: u16,
+) -> Vec<&DisplaySlide> {
+    let mut playlist: Vec<&DisplaySlide> = slides
+        .iter()
+        .filter(|s| s.is_scheduled(now, weekday, minute_of_day))
+        .collect();
+    playlist.sort_by_key(|s| (s.sort_order, s.id));
+    playlist
+}
+
+/// Parse "HH:MM" into minutes since midnight
+fn parse_hhmm(value: &str) -> Option<u16> {
+    let (h, m) = value.split_once(':')?;
+    let h: u16 = h.parse().ok()?;
+    let m: u16 = m.parse().ok()?;
+    (h < 24 && m < 60).then_some(h * 60 + m)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn slide(id: i64, sort_order: i32) -> DisplaySlide {
+        DisplaySlide {
+            id,
+            name: format!("slide_{}", id),
+            image: "a".repeat(64),
+            duration_secs: DEFAULT_SLIDE_DURATION_SECS,
+            sort_order,
+            active_days: None,
+            active_start_time: None,
+            active_end_time: None,
+            valid_from: None,
+            valid_until: None,
+            is_active: true,
+            created_at: 0,
+            updated_at: 0,
+        }
+    }
+
+    #[test]
+    fn test_validity_and_days() {
+        let mut s = slide(1, 0);
+        s.valid_from = Some(1_000);
+        s.valid_until = Some(2_000);
+        assert!(!s.is_scheduled(999, 1, 600));
+        assert!(s.is_scheduled(1_500, 1, 600));
+        assert!(!s.is_scheduled(2_001, 1, 600));
+
+        s.active_days = Some(vec![0, 6]);
+        assert!(!s.is_scheduled(1_500, 3, 600));
+        assert!(s.is_scheduled(1_500, 6, 600));
+
+        s.is_active = false;
+        assert!(!s.is_scheduled(1_500, 6, 600));
+    }
+
+    #[test]
+    fn test_time_windows() {
+        let mut lunch = slide(1, 0);
+        lunch.active_start_time = Some("12:00".to_string());
+        lunch.active_end_time = Some("15:30".to_string());
+        assert!(!lunch.is_scheduled(0, 1, 11 * 60 + 59));
+        assert!(lunch.is_scheduled(0, 1, 12 * 60));
+        assert!(!lunch.is_scheduled(0, 1, 15 * 60 + 30));
+
+        let mut late = slide(2, 0);
+        late.active_start_time = Some("21:00".to_string());
+        late.active_end_time = Some("02:00".to_string());
+        assert!(late.is_scheduled(0, 1, 23 * 60));
+        assert!(late.is_scheduled(0, 1, 60));
+        assert!(!late.is_scheduled(0, 1, 12 * 60));
+    }
+
+    #[test]
+    fn test_playlist_order() {
+        let mut hidden = slide(3, 0);
+        hidden.active_days = Some(vec![5]);
+        let slides = [slide(1, 2), slide(2, 1), hidden, slide(4, 1)];
+        let ids: Vec<i64> = scheduled_slides(&slides, 0, 1, 600)
+            .iter()
+            .map(|s| s.id)
+            .collect();
+        assert_eq!(ids, vec![2, 4, 1]);
+    }
+
+    #[test]
+    fn test_parse_hhmm() {
+        assert_eq!(parse_hhmm("09:05"), Some(545));
+        assert_eq!(parse_hhmm("24:00"), None);
+        assert_eq!(parse_hhmm("noon"), None);
+    }
+}
@@ -0,0 +1,97 @@
+//! 86 Board Model (临时沽清)
+//!
+//! Any terminal can "86" a product (or one of its specs) when the kitchen
+//! runs out. Entries block AddItems until cleared or until `expires_at`;
+//! cleared/expired entries are kept for the manager's morning review.
+
+use serde::{Deserialize, Serialize};
+
+/// 86 entry
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "db", derive(sqlx::FromRow))]
+pub struct EightySix {
+    pub id: i64,
+    pub product_id: i64,
+    /// Product name snapshot
+    pub product_name: String,
+    /// Spec-level 86 (None = whole product)
+    pub spec_id: Option<i64>,
+    pub spec_name: Option<String>,
+    pub reason: Option<String>,
+    /// Auto-expiry (Unix millis), None = until cleared
+    pub expires_at: Option<i64>,
+    pub created_by_id: i64,
+    pub created_by_name: String,
+    pub created_at: i64,
+    pub cleared_at: Option<i64>,
+    pub cleared_by_name: Option<String>,
+}
+
+impl EightySix {
+    /// Still blocking orders at `now`
+    pub fn is_active(&self, now: i64) -> bool {
+        self.cleared_at.is_none() && self.expires_at.is_none_or(|t| t > now)
+    }
+
+    /// Whether this entry covers the product/spec being ordered
+    pub fn covers(&self, product_id: i64, spec_id: Option<i64>) -> bool {
+        self.product_id == product_id && (self.spec_id.is_none() || self.spec_id == spec_id)
+    }
+}
+
+/// Create 86 payload
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct EightySixCreate {
+    pub product_id: i64,
+    pub spec_id: Option<i64>,
+    pub reason: Option<String>,
+    /// Auto-expiry (Unix millis)
+    pub expires_at: Option<i64>,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn entry(spec_id: Option<i64>, expires_at: Option<i64>) -> EightySix {
+        EightySix {
+            id: 1,
+            product_id: 10,
+            product_name: "Salmon".to_string(),
+            spec_id,
+            spec_name: None,
+            reason: None,
+            expires_at,
+            created_by_id: 1,
+            created_by_name: "Ana".to_string(),
+            created_at: 0,
+            cleared_at: None,
+            cleared_by_name: None,
+        }
+    }
+
+    #[test]
+    fn test_covers_product_and_spec() {
+        let product = entry(None, None);
+        assert!(product.covers(10, None));
+        assert!(product.covers(10, Some(101)));
+        assert!(!product.covers(11, None));
+
+        let spec = entry(Some(101), None);
+        assert!(spec.covers(10, Some(101)));
+        assert!(!spec.covers(10, Some(102)));
+        assert!(!spec.covers(10, None));
+    }
+
+    #[test]
+    fn test_is_active_until_expiry_or_clear() {
+        let mut e = entry(None, Some(1_000));
+        assert!(e.is_active(999));
+        assert!(!e.is_active(1_000));
+
+        e.expires_at = None;
+        assert!(e.is_active(i64::MAX));
+        e.cleared_at = Some(500);
+        assert!(!e.is_active(0));
+    }
+}
@@ -13,6 +13,10 @@ pub struct Employee {
     pub is_system: bool,
     pub is_active: bool,
     pub created_at: i64,
+    /// SHA-256 of the stored face photo (JPEG), shown at login/escalation
+    #[serde(default)]
+    #[cfg_attr(feature = "db", sqlx(default))]
+    pub photo: Option<String>,
 }
 
 /// Create employee payload
@@ -33,3 +37,9 @@ pub struct EmployeeUpdate {
     pub role_id: Option<i64>,
     pub is_active: Option<bool>,
 }
+
+/// Employee photo upload payload (image bytes base64 encoded; PNG/JPEG/WebP)
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct EmployeePhotoUpload {
+    pub content_base64: String,
+}
@@ -0,0 +1,496 @@
+//! Event Booking Model (宴会预订)
+//!
+//! 大型活动的预订单（proforma）：提前数天录入菜单、人数和定金，
+//! 活动当天自动转为正式订单（开台 + 加菜 + 定金入账）。
+//! 每次修改递增 revision 并记录变更明细，BEO 单据据此提示厨房。
+
+use serde::{Deserialize, Serialize};
+
+use crate::order::{CartItemInput, ItemOption, SpecificationInfo};
+
+/// Booking lifecycle
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+#[cfg_attr(feature = "db", derive(sqlx::Type))]
+#[cfg_attr(feature = "db", sqlx(rename_all = "SCREAMING_SNAKE_CASE"))]
+pub enum EventBookingStatus {
+    /// 草稿（可修改，不会自动转单）
+    Draft,
+    /// 已确认（活动当天自动转单）
+    Confirmed,
+    /// 已转为正式订单
+    Materialized,
+    /// 已取消
+    Cancelled,
+}
+
+impl EventBookingStatus {
+    /// Menu, headcount and deposits can still change
+    pub fn is_editable(self) -> bool {
+        matches!(self, Self::Draft | Self::Confirmed)
+    }
+}
+
+/// Event booking entity
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "db", derive(sqlx::FromRow))]
+pub struct EventBooking {
+    pub id: i64,
+    pub event_name: String,
+    pub customer_name: String,
+    pub customer_phone: Option<String>,
+    pub member_id: Option<i64>,
+    /// Event start (Unix millis)
+    pub event_start: i64,
+    pub headcount: i32,
+    pub table_id: Option<i64>,
+    pub table_name: Option<String>,
+    pub zone_id: Option<i64>,
+    pub zone_name: Option<String>,
+    pub note: Option<String>,
+    pub status: EventBookingStatus,
+    /// Incremented on every amendment (starts at 1)
+    pub revision: i32,
+    /// Σ price × quantity of the menu selections
+    pub estimated_total: f64,
+    /// Σ deposits collected
+    pub deposit_total: f64,
+    /// Active order created on the event day
+    pub order_id: Option<i64>,
+    pub materialized_at: Option<i64>,
+    /// Last automatic materialization failure (retried until resolved)
+    pub materialize_error: Option<String>,
+    pub created_by_id: i64,
+    pub created_by_name: String,
+    pub created_at: i64,
+    pub updated_at: i64,
+}
+
+/// Menu selection
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "db", derive(sqlx::FromRow))]
+pub struct EventBookingItem {
+    pub id: i64,
+    pub booking_id: i64,
+    pub product_id: i64,
+    pub name: String,
+    /// Agreed unit price
+    pub price: f64,
+    pub quantity: i32,
+    #[cfg_attr(feature = "db", sqlx(json))]
+    pub selected_options: Option<Vec<ItemOption>>,
+    #[cfg_attr(feature = "db", sqlx(json))]
+    pub selected_specification: Option<SpecificationInfo>,
+    /// Serving course (e.g. "Entrantes", "Principal"), groups the BEO
+    pub course: Option<String>,
+    pub note: Option<String>,
+    pub sort_order: i32,
+}
+
+impl EventBookingItem {
+    /// Cart input for the materialized order
+    pub fn to_cart_input(&self) -> CartItemInput {
+        CartItemInput {
+            product_id: self.product_id,
+            name: self.name.clone(),
+            price: self.price,
+            original_price: None,
+            quantity: self.quantity,
+            selected_options: self.selected_options.clone(),
+            selected_specification: self.selected_specification.clone(),
+            manual_discount_percent: None,
+            note: self.note.clone(),
+            authorizer_id: None,
+            authorizer_name: None,
+            course: None,
+        }
+    }
+}
+
+/// Deposit collected before the event
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "db", derive(sqlx::FromRow))]
+pub struct EventBookingDeposit {
+    pub id: i64,
+    pub booking_id: i64,
+    pub method: String,
+    pub amount: f64,
+    pub reference: Option<String>,
+    pub note: Option<String>,
+    pub operator_id: i64,
+    pub operator_name: String,
+    pub created_at: i64,
+}
+
+/// Amendment record (one per revision)
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "db", derive(sqlx::FromRow))]
+pub struct EventBookingAmendment {
+    pub id: i64,
+    pub booking_id: i64,
+    /// Revision produced by this amendment
+    pub revision: i32,
+    #[cfg_attr(feature = "db", sqlx(json))]
+    pub changes: EventBookingChanges,
+    pub operator_id: i64,
+    pub operator_name: String,
+    pub created_at: i64,
+}
+
+/// What an amendment changed
+#[derive(Debug, Clone, Default, Serialize, Deserialize, PartialEq)]
+pub struct EventBookingChanges {
+    pub fields: Vec<EventBookingFieldChange>,
+    pub items: Vec<EventBookingItemChange>,
+}
+
+impl EventBookingChanges {
+    pub fn is_empty(&self) -> bool {
+        self.fields.is_empty() && self.items.is_empty()
+    }
+}
+
+/// Header field change
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct EventBookingFieldChange {
+    pub field: String,
+    pub from: serde_json::Value,
+    pub to: serde_json::Value,
+}
+
+/// Menu line change (`from_quantity` 0 = added, `to_quantity` 0 = removed)
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct EventBookingItemChange {
+    pub name: String,
+    pub course: Option<String>,
+    pub from_quantity: i32,
+    pub to_quantity: i32,
+    pub from_price: f64,
+    pub to_price: f64,
+}
+
+/// Booking detail (booking + menu + deposits + amendment history)
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct EventBookingDetail {
+    #[serde(flatten)]
+    pub booking: EventBooking,
+    pub items: Vec<EventBookingItem>,
+    pub deposits: Vec<EventBookingDeposit>,
+    pub amendments: Vec<EventBookingAmendment>,
+}
+
+/// Menu selection input
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct EventBookingItemInput {
+    pub product_id: i64,
+    pub name: String,
+    pub price: f64,
+    pub quantity: i32,
+    pub selected_options: Option<Vec<ItemOption>>,
+    pub selected_specification: Option<SpecificationInfo>,
+    pub course: Option<String>,
+    pub note: Option<String>,
+}
+
+/// Create event booking payload
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct EventBookingCreate {
+    pub event_name: String,
+    pub customer_name: String,
+    pub customer_phone: Option<String>,
+    pub member_id: Option<i64>,
+    pub event_start: i64,
+    pub headcount: i32,
+    pub table_id: Option<i64>,
+    pub table_name: Option<String>,
+    pub zone_id: Option<i64>,
+    pub zone_name: Option<String>,
+    pub note: Option<String>,
+    #[serde(default)]
+    pub items: Vec<EventBookingItemInput>,
+}
+
+/// Update event booking payload (`items` replaces the whole menu)
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct EventBookingUpdate {
+    pub event_name: Option<String>,
+    pub customer_name: Option<String>,
+    pub customer_phone: Option<String>,
+    pub member_id: Option<i64>,
+    pub event_start: Option<i64>,
+    pub headcount: Option<i32>,
+    pub table_id: Option<i64>,
+    pub table_name: Option<String>,
+    pub zone_id: Option<i64>,
+    pub zone_name: Option<String>,
+    pub note: Option<String>,
+    pub items: Option<Vec<EventBookingItemInput>>,
+}
+
+/// Record deposit payload
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct EventBookingDepositCreate {
+    pub method: String,
+    pub amount: f64,
+    pub reference: Option<String>,
+    pub note: Option<String>,
+}
+
+/// Σ price × quantity, rounded to cents
+pub fn estimated_total(items: &[EventBookingItemInput]) -> f64 {
+    let total: f64 = items.iter().map(|i| i.price * i.quantity as f64).sum();
+    (total * 100.0).round() / 100.0
+}
+
+/// Diff a booking against an update (only fields present in the update are compared)
+pub fn booking_changes(
+    booking: &EventBooking,
+    items: &[EventBookingItem],
+    update: &EventBookingUpdate,
+) -> EventBookingChanges {
+    let mut changes = EventBookingChanges::default();
+
+    fn field<T: Serialize + PartialEq>(
+        out: &mut Vec<EventBookingFieldChange>,
+        name: &str,
+        from: &T,
+        to: Option<&T>,
+    ) {
+        if let Some(to) = to
+            && to != from
+        {
+            out.push(EventBookingFieldChange {
+                field: name.to_string(),
+                from: serde_json::to_value(from).unwrap_or_default(),
+                to: serde_json::to_value(to).unwrap_or_default(),
+            });
+        }
+    }
+
+    // Optional columns: a `Some` in the update replaces the stored value
+    fn optional<T: Serialize + PartialEq>(
+        out: &mut Vec<EventBookingFieldChange>,
+        name: &str,
+        from: &Option<T>,
+        to: &Option<T>,
+    ) {
+        if to.is_some() {
+            field(out, name, from, Some(to));
+        }
+    }
+
+    let f = &mut changes.fields;
+    field(
+        f,
+        "event_name",
+        &booking.event_name,
+        update.event_name.as_ref(),
+    );
+    field(
+        f,
+        "customer_name",
+        &booking.customer_name,
+        update.customer_name.as_ref(),
+    );
+    optional(
+        f,
+        "customer_phone",
+        &booking.customer_phone,
+        &update.customer_phone,
+    );
+    optional(f, "member_id", &booking.member_id, &update.member_id);
+    field(
+        f,
+        "event_start",
+        &booking.event_start,
+        update.event_start.as_ref(),
+    );
+    field(
+        f,
+        "headcount",
+        &booking.headcount,
+        update.headcount.as_ref(),
+    );
+    optional(f, "table_name", &booking.table_name, &update.table_name);
+    optional(f, "zone_name", &booking.zone_name, &update.zone_name);
+    optional(f, "note", &booking.note, &update.note);
+
+    if let Some(new_items) = &update.items {
+        changes.items = item_changes(items, new_items);
+    }
+    changes
+}
+
+/// Menu line identity: same product, specification, options and course
+fn same_line(old: &EventBookingItem, new: &EventBookingItemInput) -> bool {
+    old.product_id == new.product_id
+        && old.selected_specification.as_ref().map(|s| s.id)
+            == new.selected_specification.as_ref().map(|s| s.id)
+        && old.selected_options == new.selected_options
+        && old.course == new.course
+}
+
+fn item_changes(
+    old: &[EventBookingItem],
+    new: &[EventBookingItemInput],
+) -> Vec<EventBookingItemChange> {
+    let mut matched = vec![false; old.len()];
+    let mut out = Vec::new();
+
+    for n in new {
+        let found = old
+            .iter()
+            .enumerate()
+            .find(|(i, o)| !matched[*i] && same_line(o, n));
+        match found {
+            Some((i, o)) => {
+                matched[i] = true;
+                if o.quantity != n.quantity || o.price != n.price {
+                    out.push(EventBookingItemChange {
+                        name: n.name.clone(),
+                        course: n.course.clone(),
+                        from_quantity: o.quantity,
+                        to_quantity: n.quantity,
+                        from_price: o.price,
+                        to_price: n.price,
+                    });
+                }
+            }
+            None => out.push(EventBookingItemChange {
+                name: n.name.clone(),
+                course: n.course.clone(),
+                from_quantity: 0,
+                to_quantity: n.quantity,
+                from_price: 0.0,
+                to_price: n.price,
+            }),
+        }
+    }
+
+    for (o, _) in old.iter().zip(&matched).filter(|(_, m)| !**m) {
+        out.push(EventBookingItemChange {
+            name: o.name.clone(),
+            course: o.course.clone(),
+            from_quantity: o.quantity,
+            to_quantity: 0,
+            from_price: o.price,
+            to_price: 0.0,
+        });
+    }
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn booking() -> EventBooking {
+        EventBooking {
+            id: 1,
+            event_name: "Boda".to_string(),
+            customer_name: "Lucía".to_string(),
+            customer_phone: None,
+            member_id: None,
+            event_start: 1_000,
+            headcount: 80,
+            table_id: None,
+            table_name: None,
+            zone_id: None,
+            zone_name: None,
+            note: None,
+            status: EventBookingStatus::Confirmed,
+            revision: 1,
+            estimated_total: 0.0,
+            deposit_total: 0.0,
+            order_id: None,
+            materialized_at: None,
+            materialize_error: None,
+            created_by_id: 1,
+            created_by_name: "Admin".to_string(),
+            created_at: 0,
+            updated_at: 0,
+        }
+    }
+
+    fn item(product_id: i64, quantity: i32, course: &str) -> EventBookingItem {
+        EventBookingItem {
+            id: product_id,
+            booking_id: 1,
+            product_id,
+            name: format!("p{product_id}"),
+            price: 10.0,
+            quantity,
+            selected_options: None,
+            selected_specification: None,
+            course: Some(course.to_string()),
+            note: None,
+            sort_order: 0,
+        }
+    }
+
+    fn input(product_id: i64, quantity: i32, course: &str) -> EventBookingItemInput {
+        EventBookingItemInput {
+            product_id,
+            name: format!("p{product_id}"),
+            price: 10.0,
+            quantity,
+            selected_options: None,
+            selected_specification: None,
+            course: Some(course.to_string()),
+            note: None,
+        }
+    }
+
+    fn empty_update() -> EventBookingUpdate {
+        EventBookingUpdate {
+            event_name: None,
+            customer_name: None,
+            customer_phone: None,
+            member_id: None,
+            event_start: None,
+            headcount: None,
+            table_id: None,
+            table_name: None,
+            zone_id: None,
+            zone_name: None,
+            note: None,
+            items: None,
+        }
+    }
+
+    #[test]
+    fn test_unchanged_update_is_empty() {
+        let mut update = empty_update();
+        update.headcount = Some(80);
+        update.items = Some(vec![input(1, 80, "main")]);
+        let changes = booking_changes(&booking(), &[item(1, 80, "main")], &update);
+        assert!(changes.is_empty());
+    }
+
+    #[test]
+    fn test_field_and_item_changes() {
+        let mut update = empty_update();
+        update.headcount = Some(95);
+        update.items = Some(vec![input(1, 95, "main"), input(3, 95, "dessert")]);
+        let old = [item(1, 80, "main"), item(2, 80, "starter")];
+        let changes = booking_changes(&booking(), &old, &update);
+
+        assert_eq!(changes.fields.len(), 1);
+        assert_eq!(changes.fields[0].field, "headcount");
+        assert_eq!(changes.fields[0].to, serde_json::json!(95));
+
+        let summary: Vec<(&str, i32, i32)> = changes
+            .items
+            .iter()
+            .map(|c| (c.name.as_str(), c.from_quantity, c.to_quantity))
+            .collect();
+        assert_eq!(summary, vec![("p1", 80, 95), ("p3", 0, 95), ("p2", 80, 0)]);
+    }
+
+    #[test]
+    fn test_estimated_total_rounds_to_cents() {
+        let mut a = input(1, 3, "main");
+        a.price = 3.3;
+        assert_eq!(estimated_total(&[a]), 9.9);
+    }
+}
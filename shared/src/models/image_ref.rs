@@ -8,6 +8,7 @@ use serde::{Deserialize, Serialize};
 pub enum ImageRefEntityType {
     Product,
     LabelTemplate,
+    DisplaySlide,
 }
 
 impl ImageRefEntityType {
@@ -15,6 +16,7 @@ impl ImageRefEntityType {
         match self {
             ImageRefEntityType::Product => "product",
             ImageRefEntityType::LabelTemplate => "label_template",
+            ImageRefEntityType::DisplaySlide => "display_slide",
         }
     }
 }
@@ -0,0 +1,524 @@
+//! Inventory Model (库存)
+//!
+//! Stock is tracked per product, or per spec when a spec has its own level.
+//! Completed orders decrement the matching level; managers adjust it by hand
+//! (deliveries, waste, stock takes). Every change is recorded as a
+//! [`StockAdjustment`] so the current quantity can be explained line by line.
+//!
+//! Periodic stock takes run as [`StockCount`] sessions: theoretical
+//! quantities are frozen at the start, counted values are recorded, and a
+//! manager's approval posts the variances as COUNT adjustments.
+//!
+//! Multi-store tenants move goods with [`StockTransfer`] documents: the
+//! sending store posts TRANSFER_OUT when the goods leave, the document stays
+//! in transit until the receiving store confirms what arrived, which posts
+//! TRANSFER_IN there and closes the document on both sides. Any shortfall is
+//! written back to the sending store as TRANSFER_RETURN.
+//!
+//! Stock never blocks sales: the quantity may go negative, and falling to the
+//! low-stock threshold only raises a notification (use the 86 board to stop
+//! selling).
+
+use serde::{Deserialize, Serialize};
+
+/// Tracked stock level
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "db", derive(sqlx::FromRow))]
+pub struct StockLevel {
+    pub id: i64,
+    pub product_id: i64,
+    /// Product name snapshot
+    pub product_name: String,
+    /// Spec-level stock (None = whole product)
+    pub spec_id: Option<i64>,
+    pub spec_name: Option<String>,
+    pub quantity: i64,
+    /// Alert when quantity falls to this value (None = no alert)
+    pub low_stock_threshold: Option<i64>,
+    /// Scanned during stock counts (unique)
+    pub barcode: Option<String>,
+    pub created_at: i64,
+    pub updated_at: i64,
+}
+
+impl StockLevel {
+    /// At or below the low-stock threshold
+    pub fn is_low(&self) -> bool {
+        self.low_stock_threshold
+            .is_some_and(|threshold| self.quantity <= threshold)
+    }
+
+    /// Whether a change from `before` made this level newly low
+    pub fn became_low(&self, before: i64) -> bool {
+        self.low_stock_threshold
+            .is_some_and(|threshold| before > threshold && self.quantity <= threshold)
+    }
+}
+
+/// Adjustment reason
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq, Hash)]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+pub enum StockAdjustmentKind {
+    /// Decrement on order completion
+    Sale,
+    /// Manual adjustment (delivery, waste)
+    Manual,
+    /// Variance posted by an approved stock count
+    Count,
+    /// Goods sent to another store
+    TransferOut,
+    /// Goods received from another store
+    TransferIn,
+    /// Transfer shortfall written back to the sending store
+    TransferReturn,
+}
+
+impl StockAdjustmentKind {
+    /// 数据库 TEXT 列表示
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            StockAdjustmentKind::Sale => "SALE",
+            StockAdjustmentKind::Manual => "MANUAL",
+            StockAdjustmentKind::Count => "COUNT",
+            StockAdjustmentKind::TransferOut => "TRANSFER_OUT",
+            StockAdjustmentKind::TransferIn => "TRANSFER_IN",
+            StockAdjustmentKind::TransferReturn => "TRANSFER_RETURN",
+        }
+    }
+}
+
+impl TryFrom<String> for StockAdjustmentKind {
+    type Error = String;
+
+    fn try_from(value: String) -> Result<Self, Self::Error> {
+        match value.as_str() {
+            "SALE" => Ok(StockAdjustmentKind::Sale),
+            "MANUAL" => Ok(StockAdjustmentKind::Manual),
+            "COUNT" => Ok(StockAdjustmentKind::Count),
+            "TRANSFER_OUT" => Ok(StockAdjustmentKind::TransferOut),
+            "TRANSFER_IN" => Ok(StockAdjustmentKind::TransferIn),
+            "TRANSFER_RETURN" => Ok(StockAdjustmentKind::TransferReturn),
+            other => Err(format!("invalid stock adjustment kind: {other}")),
+        }
+    }
+}
+
+/// Stock adjustment (append-only trail)
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "db", derive(sqlx::FromRow))]
+pub struct StockAdjustment {
+    pub id: i64,
+    pub stock_level_id: i64,
+    #[cfg_attr(feature = "db", sqlx(try_from = "String"))]
+    pub kind: StockAdjustmentKind,
+    pub delta: i64,
+    pub quantity_after: i64,
+    /// Completed order (SALE only)
+    pub order_id: Option<i64>,
+    /// Approved stock count (COUNT only)
+    pub stock_count_id: Option<i64>,
+    /// Inter-store transfer (TRANSFER_OUT / TRANSFER_IN / TRANSFER_RETURN only)
+    pub stock_transfer_id: Option<i64>,
+    pub note: Option<String>,
+    pub operator_id: Option<i64>,
+    pub operator_name: Option<String>,
+    pub created_at: i64,
+}
+
+/// Start tracking a product or spec
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct StockLevelCreate {
+    pub product_id: i64,
+    pub spec_id: Option<i64>,
+    /// Opening quantity
+    #[serde(default)]
+    pub quantity: i64,
+    pub low_stock_threshold: Option<i64>,
+    pub barcode: Option<String>,
+}
+
+/// Update stock level settings (quantity only changes through adjustments)
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct StockLevelUpdate {
+    /// None = disable the low-stock alert
+    pub low_stock_threshold: Option<i64>,
+    /// None = no barcode
+    pub barcode: Option<String>,
+}
+
+/// Manual stock adjustment payload
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct StockAdjustmentCreate {
+    /// Positive = stock in, negative = stock out
+    pub delta: i64,
+    pub note: Option<String>,
+}
+
+/// Stock count session status
+///
+/// `OPEN` → `SUBMITTED` → `APPROVED` (variances posted) or `REJECTED`;
+/// an open session can also be rejected directly.
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq, Hash)]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+pub enum StockCountStatus {
+    /// Counting in progress
+    Open,
+    /// Waiting for manager approval
+    Submitted,
+    Approved,
+    Rejected,
+}
+
+impl StockCountStatus {
+    /// 数据库 TEXT 列表示
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            StockCountStatus::Open => "OPEN",
+            StockCountStatus::Submitted => "SUBMITTED",
+            StockCountStatus::Approved => "APPROVED",
+            StockCountStatus::Rejected => "REJECTED",
+        }
+    }
+}
+
+impl TryFrom<String> for StockCountStatus {
+    type Error = String;
+
+    fn try_from(value: String) -> Result<Self, Self::Error> {
+        match value.as_str() {
+            "OPEN" => Ok(StockCountStatus::Open),
+            "SUBMITTED" => Ok(StockCountStatus::Submitted),
+            "APPROVED" => Ok(StockCountStatus::Approved),
+            "REJECTED" => Ok(StockCountStatus::Rejected),
+            other => Err(format!("invalid stock count status: {other}")),
+        }
+    }
+}
+
+/// Stock count session (盘点)
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "db", derive(sqlx::FromRow))]
+pub struct StockCount {
+    pub id: i64,
+    #[cfg_attr(feature = "db", sqlx(try_from = "String"))]
+    pub status: StockCountStatus,
+    pub note: Option<String>,
+    pub created_by_id: i64,
+    pub created_by_name: String,
+    pub created_at: i64,
+    pub submitted_at: Option<i64>,
+    /// Manager who approved or rejected the session
+    pub reviewed_by_id: Option<i64>,
+    pub reviewed_by_name: Option<String>,
+    pub reviewed_at: Option<i64>,
+    pub review_note: Option<String>,
+
+    // -- Relations (populated by application code, skipped by FromRow) --
+    #[cfg_attr(feature = "db", sqlx(skip))]
+    #[serde(default)]
+    pub lines: Vec<StockCountLine>,
+}
+
+/// One stock level in a count session
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "db", derive(sqlx::FromRow))]
+pub struct StockCountLine {
+    pub id: i64,
+    pub stock_count_id: i64,
+    pub stock_level_id: i64,
+    /// Name snapshots
+    pub product_name: String,
+    pub spec_name: Option<String>,
+    /// Quantity frozen when the session started
+    pub theoretical_quantity: i64,
+    /// None = not counted (left unchanged on approval)
+    pub counted_quantity: Option<i64>,
+    pub counted_at: Option<i64>,
+    /// counted − theoretical (None = not counted)
+    pub variance: Option<i64>,
+}
+
+/// Start a count session
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct StockCountCreate {
+    pub note: Option<String>,
+    /// Levels to count (None = every tracked level)
+    pub stock_level_ids: Option<Vec<i64>>,
+}
+
+/// Record the counted quantity of one line
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct StockCountEntry {
+    /// None = clear the count
+    pub counted_quantity: Option<i64>,
+}
+
+/// Barcode scan during counting: adds `quantity` to the scanned line
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct StockCountScan {
+    pub barcode: String,
+    #[serde(default = "default_scan_quantity")]
+    pub quantity: i64,
+}
+
+fn default_scan_quantity() -> i64 {
+    1
+}
+
+/// Manager decision on a count session
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct StockCountReview {
+    pub note: Option<String>,
+}
+
+/// Transfer direction as seen from this store
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq, Hash)]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+pub enum StockTransferDirection {
+    /// Sent by this store
+    Out,
+    /// Received from another store
+    In,
+}
+
+impl StockTransferDirection {
+    /// 数据库 TEXT 列表示
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            StockTransferDirection::Out => "OUT",
+            StockTransferDirection::In => "IN",
+        }
+    }
+}
+
+impl TryFrom<String> for StockTransferDirection {
+    type Error = String;
+
+    fn try_from(value: String) -> Result<Self, Self::Error> {
+        match value.as_str() {
+            "OUT" => Ok(StockTransferDirection::Out),
+            "IN" => Ok(StockTransferDirection::In),
+            other => Err(format!("invalid stock transfer direction: {other}")),
+        }
+    }
+}
+
+/// Transfer status (`IN_TRANSIT` → `RECEIVED`)
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq, Hash)]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+pub enum StockTransferStatus {
+    /// Sent, not yet confirmed by the receiving store
+    InTransit,
+    /// Goods-received confirmation posted
+    Received,
+}
+
+impl StockTransferStatus {
+    /// 数据库 TEXT 列表示
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            StockTransferStatus::InTransit => "IN_TRANSIT",
+            StockTransferStatus::Received => "RECEIVED",
+        }
+    }
+}
+
+impl TryFrom<String> for StockTransferStatus {
+    type Error = String;
+
+    fn try_from(value: String) -> Result<Self, Self::Error> {
+        match value.as_str() {
+            "IN_TRANSIT" => Ok(StockTransferStatus::InTransit),
+            "RECEIVED" => Ok(StockTransferStatus::Received),
+            other => Err(format!("invalid stock transfer status: {other}")),
+        }
+    }
+}
+
+/// Inter-store transfer document (调拨单)
+///
+/// The id is shared by the sending and the receiving store.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "db", derive(sqlx::FromRow))]
+pub struct StockTransfer {
+    pub id: i64,
+    #[cfg_attr(feature = "db", sqlx(try_from = "String"))]
+    pub direction: StockTransferDirection,
+    #[cfg_attr(feature = "db", sqlx(try_from = "String"))]
+    pub status: StockTransferStatus,
+    /// Counterpart store (destination for OUT, source for IN)
+    pub peer_store_id: i64,
+    pub peer_store_name: String,
+    pub note: Option<String>,
+    pub sent_by: String,
+    pub sent_at: i64,
+    pub received_by: Option<String>,
+    pub received_at: Option<i64>,
+
+    // -- Relations (populated by application code, skipped by FromRow) --
+    #[cfg_attr(feature = "db", sqlx(skip))]
+    #[serde(default)]
+    pub lines: Vec<StockTransferLine>,
+}
+
+/// One item of a transfer
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "db", derive(sqlx::FromRow))]
+pub struct StockTransferLine {
+    pub id: i64,
+    pub stock_transfer_id: i64,
+    /// Position in the document (shared by both stores)
+    pub line_no: i64,
+    /// Local level: the source level for OUT; for IN the matched level
+    /// (None = no match yet, chosen on confirmation)
+    pub stock_level_id: Option<i64>,
+    /// Name snapshots from the sending store
+    pub product_name: String,
+    pub spec_name: Option<String>,
+    pub barcode: Option<String>,
+    pub quantity: i64,
+    /// None = not confirmed yet
+    pub received_quantity: Option<i64>,
+}
+
+/// Send goods to another store
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct StockTransferCreate {
+    pub to_store_id: i64,
+    pub note: Option<String>,
+    pub lines: Vec<StockTransferItem>,
+}
+
+/// One level to send
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct StockTransferItem {
+    pub stock_level_id: i64,
+    pub quantity: i64,
+}
+
+/// Goods-received confirmation of an incoming transfer
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct StockTransferReceive {
+    /// Every line of the transfer
+    pub lines: Vec<StockTransferReceiveLine>,
+}
+
+/// Received quantity of one line, booked into a local level
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct StockTransferReceiveLine {
+    pub line_id: i64,
+    /// None = not tracked here (quantity recorded, no stock posted)
+    pub stock_level_id: Option<i64>,
+    pub received_quantity: i64,
+}
+
+/// Stock consumed by a completed order line
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum StockConsumption {
+    /// The sold product itself (spec-level stock first, then whole product)
+    Product {
+        product_id: i64,
+        spec_id: Option<i64>,
+        quantity: i64,
+    },
+    /// A selected attribute option linked to a stock level
+    Modifier { option_id: i64, quantity: i64 },
+}
+
+impl StockConsumption {
+    pub fn quantity(&self) -> i64 {
+        match self {
+            Self::Product { quantity, .. } | Self::Modifier { quantity, .. } => *quantity,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn level(quantity: i64, low_stock_threshold: Option<i64>) -> StockLevel {
+        StockLevel {
+            id: 1,
+            product_id: 10,
+            product_name: "Salmon".to_string(),
+            spec_id: None,
+            spec_name: None,
+            quantity,
+            low_stock_threshold,
+            barcode: None,
+            created_at: 0,
+            updated_at: 0,
+        }
+    }
+
+    #[test]
+    fn test_low_stock_crossing() {
+        let l = level(5, Some(5));
+        assert!(l.is_low());
+        assert!(l.became_low(6));
+        // Already low before the change → no repeated alert
+        assert!(!l.became_low(5));
+        assert!(!level(6, Some(5)).became_low(8));
+        assert!(!level(-3, None).is_low());
+    }
+
+    #[test]
+    fn test_kind_roundtrip() {
+        for kind in [
+            StockAdjustmentKind::Sale,
+            StockAdjustmentKind::Manual,
+            StockAdjustmentKind::Count,
+            StockAdjustmentKind::TransferOut,
+            StockAdjustmentKind::TransferIn,
+            StockAdjustmentKind::TransferReturn,
+        ] {
+            assert_eq!(
+                StockAdjustmentKind::try_from(kind.as_str().to_string()),
+                Ok(kind)
+            );
+            assert_eq!(
+                serde_json::to_string(&kind).unwrap(),
+                format!("\"{}\"", kind.as_str())
+            );
+        }
+        for status in [
+            StockCountStatus::Open,
+            StockCountStatus::Submitted,
+            StockCountStatus::Approved,
+            StockCountStatus::Rejected,
+        ] {
+            assert_eq!(
+                StockCountStatus::try_from(status.as_str().to_string()),
+                Ok(status)
+            );
+            assert_eq!(
+                serde_json::to_string(&status).unwrap(),
+                format!("\"{}\"", status.as_str())
+            );
+        }
+        for status in [
+            StockTransferStatus::InTransit,
+            StockTransferStatus::Received,
+        ] {
+            assert_eq!(
+                StockTransferStatus::try_from(status.as_str().to_string()),
+                Ok(status)
+            );
+            assert_eq!(
+                serde_json::to_string(&status).unwrap(),
+                format!("\"{}\"", status.as_str())
+            );
+        }
+        for direction in [StockTransferDirection::Out, StockTransferDirection::In] {
+            assert_eq!(
+                StockTransferDirection::try_from(direction.as_str().to_string()),
+                Ok(direction)
+            );
+            assert_eq!(
+                serde_json::to_string(&direction).unwrap(),
+                format!("\"{}\"", direction.as_str())
+            );
+        }
+    }
+}
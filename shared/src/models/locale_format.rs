@@ -0,0 +1,229 @@
+//! Locale-aware money / date formatting.
+//!
+//! Single source of truth for how a store prints amounts and dates, driven by
+//! `StoreInfo.receipt_locale` + currency settings. Used by:
+//! - Tauri `receipt_renderer.rs` (customer receipt)
+//! - Edge `credit_note_renderer.rs` (refund receipt)
+//! - Edge statistics (red flag log detail)
+
+use chrono::{DateTime, TimeZone};
+
+use super::StoreInfo;
+
+/// Where the currency symbol goes relative to the number
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SymbolPosition {
+    /// `€12.50`
+    Before,
+    /// `12,50 €`
+    After,
+}
+
+/// Formatting rules for one store
+#[derive(Debug, Clone, PartialEq)]
+pub struct LocaleFormat {
+    pub currency_symbol: String,
+    pub symbol_position: SymbolPosition,
+    /// Space between number and symbol (`12,50 €` vs `€12.50`)
+    pub symbol_spaced: bool,
+    pub decimal_places: usize,
+    pub decimal_separator: char,
+    /// Thousands grouping (None = no grouping)
+    pub thousands_separator: Option<char>,
+    /// chrono format string for dates
+    pub date_format: &'static str,
+    /// chrono format string for times
+    pub time_format: &'static str,
+}
+
+impl Default for LocaleFormat {
+    fn default() -> Self {
+        Self::new("es-ES", None, None)
+    }
+}
+
+impl LocaleFormat {
+    /// Locale conventions + optional currency overrides.
+    ///
+    /// Supported: `"zh-CN"`, `"en"` / `"en-US"`, `"en-GB"`, default `es-ES`
+    /// (same set as `receipt_text`).
+    pub fn new(locale: &str, currency_symbol: Option<&str>, decimal_places: Option<i32>) -> Self {
+        let symbol = currency_symbol
+            .filter(|s| !s.is_empty())
+            .unwrap_or("€")
+            .to_string();
+        let decimal_places = decimal_places.map_or(2, |d| d.clamp(0, 4) as usize);
+        match locale {
+            "zh-CN" => Self {
+                currency_symbol: symbol,
+                symbol_position: SymbolPosition::Before,
+                symbol_spaced: false,
+                decimal_places,
+                decimal_separator: '.',
+                thousands_separator: Some(','),
+                date_format: "%Y-%m-%d",
+                time_format: "%H:%M",
+            },
+            "en" | "en-US" => Self {
+                currency_symbol: symbol,
+                symbol_position: SymbolPosition::Before,
+                symbol_spaced: false,
+                decimal_places,
+                decimal_separator: '.',
+                thousands_separator: Some(','),
+                date_format: "%m/%d/%Y",
+                time_format: "%H:%M",
+            },
+            "en-GB" => Self {
+                currency_symbol: symbol,
+                symbol_position: SymbolPosition::Before,
+                symbol_spaced: false,
+                decimal_places,
+                decimal_separator: '.',
+                thousands_separator: Some(','),
+                date_format: "%d/%m/%Y",
+                time_format: "%H:%M",
+            },
+            _ => Self {
+                currency_symbol: symbol,
+                symbol_position: SymbolPosition::After,
+                symbol_spaced: true,
+                decimal_places,
+                decimal_separator: ',',
+                thousands_separator: Some('.'),
+                date_format: "%d/%m/%Y",
+                time_format: "%H:%M",
+            },
+        }
+    }
+
+    /// Build from the store's settings (`receipt_locale`, currency symbol/decimals)
+    pub fn from_store_info(info: &StoreInfo) -> Self {
+        Self::new(
+            info.receipt_locale.as_deref().unwrap_or("es-ES"),
+            info.currency_symbol.as_deref(),
+            info.currency_decimal_places,
+        )
+    }
+
+    /// Plain number with locale separators, no symbol (`1.234,50`)
+    pub fn number(&self, value: f64) -> String {
+        let fixed = format!("{:.*}", self.decimal_places, value.abs());
+        let (int_part, frac_part) = match fixed.split_once('.') {
+            Some((i, f)) => (i, Some(f)),
+            None => (fixed.as_str(), None),
+        };
+
+        let mut out = String::with_capacity(fixed.len() + 4);
+        // -0.004 rounds to "0.00" → no sign
+        if value < 0.0 && fixed.bytes().any(|b| (b'1'..=b'9').contains(&b)) {
+            out.push('-');
+        }
+        match self.thousands_separator {
+            Some(sep) => {
+                for (i, c) in int_part.chars().enumerate() {
+                    if i > 0 && (int_part.len() - i) % 3 == 0 {
+                        out.push(sep);
+                    }
+                    out.push(c);
+                }
+            }
+            None => out.push_str(int_part),
+        }
+        if let Some(frac) = frac_part {
+            out.push(self.decimal_separator);
+            out.push_str(frac);
+        }
+        out
+    }
+
+    /// Amount with currency symbol (`12,50 €` / `€12.50` / `-€3.00`)
+    pub fn money(&self, value: f64) -> String {
+        let number = self.number(value);
+        let space = if self.symbol_spaced { " " } else { "" };
+        match self.symbol_position {
+            SymbolPosition::After => format!("{number}{space}{}", self.currency_symbol),
+            SymbolPosition::Before => match number.strip_prefix('-') {
+                Some(abs) => format!("-{}{space}{abs}", self.currency_symbol),
+                None => format!("{}{space}{number}", self.currency_symbol),
+            },
+        }
+    }
+
+    /// Signed amount, always with an explicit `+` / `-` (adjustment lines)
+    pub fn money_signed(&self, value: f64) -> String {
+        let money = self.money(value);
+        if money.starts_with('-') {
+            money
+        } else {
+            format!("+{money}")
+        }
+    }
+
+    pub fn date<Tz: TimeZone>(&self, dt: &DateTime<Tz>) -> String
+    where
+        Tz::Offset: std::fmt::Display,
+    {
+        dt.format(self.date_format).to_string()
+    }
+
+    pub fn datetime<Tz: TimeZone>(&self, dt: &DateTime<Tz>) -> String
+    where
+        Tz::Offset: std::fmt::Display,
+    {
+        format!(
+            "{} {}",
+            dt.format(self.date_format),
+            dt.format(self.time_format)
+        )
+    }
+
+    /// Unix millis → local date + time in `tz`
+    pub fn datetime_millis<Tz: TimeZone>(&self, millis: i64, tz: &Tz) -> String
+    where
+        Tz::Offset: std::fmt::Display,
+    {
+        match DateTime::from_timestamp_millis(millis) {
+            Some(dt) => self.datetime(&dt.with_timezone(tz)),
+            None => "--/--/---- --:--".to_string(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_money_per_locale() {
+        let es = LocaleFormat::new("es-ES", None, None);
+        assert_eq!(es.money(1234.5), "1.234,50 €");
+        assert_eq!(es.money(-3.0), "-3,00 €");
+        assert_eq!(es.money(-0.004), "0,00 €");
+
+        let en = LocaleFormat::new("en", Some("$"), None);
+        assert_eq!(en.money(1234567.891), "$1,234,567.89");
+        assert_eq!(en.money(-3.0), "-$3.00");
+        assert_eq!(en.money_signed(3.0), "+$3.00");
+
+        let zh = LocaleFormat::new("zh-CN", Some("¥"), Some(0));
+        assert_eq!(zh.money(999.6), "¥1,000");
+    }
+
+    #[test]
+    fn test_dates_follow_locale() {
+        let dt = chrono::Utc.with_ymd_and_hms(2025, 3, 7, 21, 5, 0).unwrap();
+        assert_eq!(
+            LocaleFormat::new("es-ES", None, None).datetime(&dt),
+            "07/03/2025 21:05"
+        );
+        assert_eq!(
+            LocaleFormat::new("en-US", None, None).date(&dt),
+            "03/07/2025"
+        );
+        assert_eq!(
+            LocaleFormat::new("zh-CN", None, None).date(&dt),
+            "2025-03-07"
+        );
+    }
+}
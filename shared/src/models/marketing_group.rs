@@ -14,6 +14,12 @@ pub struct MarketingGroup {
     pub sort_order: i32,
     /// 积分倍率: 消费金额 × rate = 积分 (null = 不累积积分)
     pub points_earn_rate: Option<f64>,
+    /// 积分兑换单位: 每 N 积分可抵扣 (null = 不可兑换)
+    #[serde(default)]
+    pub points_redeem_unit: Option<i64>,
+    /// 每个兑换单位抵扣金额 (€)
+    #[serde(default)]
+    pub points_redeem_value: Option<f64>,
     pub created_at: i64,
     pub updated_at: i64,
 }
@@ -25,6 +31,10 @@ pub struct MarketingGroupCreate {
     pub description: Option<String>,
     pub sort_order: Option<i32>,
     pub points_earn_rate: Option<f64>,
+    #[serde(default)]
+    pub points_redeem_unit: Option<i64>,
+    #[serde(default)]
+    pub points_redeem_value: Option<f64>,
 }
 
 /// Update marketing group payload
@@ -34,6 +44,10 @@ pub struct MarketingGroupUpdate {
     pub description: Option<String>,
     pub sort_order: Option<i32>,
     pub points_earn_rate: Option<f64>,
+    #[serde(default)]
+    pub points_redeem_unit: Option<i64>,
+    #[serde(default)]
+    pub points_redeem_value: Option<f64>,
 }
 
 /// MG Discount Rule entity
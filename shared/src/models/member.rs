@@ -15,7 +15,13 @@ pub struct Member {
     pub email: Option<String>,
     pub points_balance: i64,
     pub total_spent: f64,
+    /// 储值余额
+    #[serde(default)]
+    pub credit_balance: f64,
     pub notes: Option<String>,
+    /// 收据语言偏好 (e.g. "en", "zh-CN")，None = 门店默认
+    #[serde(default)]
+    pub preferred_locale: Option<String>,
     pub is_active: bool,
     pub created_at: i64,
     pub updated_at: i64,
@@ -31,6 +37,8 @@ pub struct MemberCreate {
     pub birthday: Option<String>,
     pub email: Option<String>,
     pub notes: Option<String>,
+    #[serde(default)]
+    pub preferred_locale: Option<String>,
 }
 
 /// Update member payload
@@ -43,6 +51,8 @@ pub struct MemberUpdate {
     pub birthday: Option<String>,
     pub email: Option<String>,
     pub notes: Option<String>,
+    /// Some("") 清除偏好
+    pub preferred_locale: Option<String>,
     pub is_active: Option<bool>,
 }
 
@@ -60,8 +70,111 @@ pub struct MemberWithGroup {
     pub email: Option<String>,
     pub points_balance: i64,
     pub total_spent: f64,
+    /// 储值余额
+    #[serde(default)]
+    pub credit_balance: f64,
     pub notes: Option<String>,
+    /// 收据语言偏好 (e.g. "en", "zh-CN")，None = 门店默认
+    #[serde(default)]
+    pub preferred_locale: Option<String>,
     pub is_active: bool,
     pub created_at: i64,
     pub updated_at: i64,
 }
+
+/// Stored-credit ledger entry kind
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+#[cfg_attr(feature = "db", derive(sqlx::Type))]
+#[cfg_attr(feature = "db", sqlx(rename_all = "SCREAMING_SNAKE_CASE"))]
+pub enum MemberCreditKind {
+    /// 充值
+    TopUp,
+    /// 订单支付扣款
+    Payment,
+    /// 支付取消 / 订单作废退回
+    Refund,
+}
+
+/// Stored-credit ledger entry (储值流水)
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "db", derive(sqlx::FromRow))]
+pub struct MemberCreditTransaction {
+    pub id: i64,
+    pub member_id: i64,
+    pub kind: MemberCreditKind,
+    /// 带符号金额（充值 / 退回为正，扣款为负）
+    pub amount: f64,
+    pub balance_after: f64,
+    pub order_id: Option<i64>,
+    pub payment_id: Option<i64>,
+    /// 充值支付方式 (CASH / CARD)
+    pub method: Option<String>,
+    pub note: Option<String>,
+    pub operator_id: Option<i64>,
+    pub operator_name: Option<String>,
+    pub created_at: i64,
+}
+
+/// Top up member stored credit payload
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct MemberCreditTopUp {
+    pub amount: f64,
+    /// 充值收款方式 (CASH / CARD)
+    pub method: String,
+    pub note: Option<String>,
+}
+
+/// Stored-credit statement for a period
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct MemberCreditStatement {
+    pub member_id: i64,
+    pub from: i64,
+    pub to: i64,
+    pub opening_balance: f64,
+    pub closing_balance: f64,
+    pub total_top_up: f64,
+    pub total_spent: f64,
+    pub total_refunded: f64,
+    pub transactions: Vec<MemberCreditTransaction>,
+}
+
+/// Points ledger entry kind
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+#[cfg_attr(feature = "db", derive(sqlx::Type))]
+#[cfg_attr(feature = "db", sqlx(rename_all = "SCREAMING_SNAKE_CASE"))]
+pub enum MemberPointsKind {
+    /// 订单完成累积
+    Earn,
+    /// 订单抵扣兑换
+    Redeem,
+}
+
+/// Points ledger entry (积分流水)
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "db", derive(sqlx::FromRow))]
+pub struct MemberPointsTransaction {
+    pub id: i64,
+    pub member_id: i64,
+    pub kind: MemberPointsKind,
+    /// 带符号积分（累积为正，兑换为负）
+    pub points: i64,
+    pub balance_after: i64,
+    pub order_id: Option<i64>,
+    /// 累积: 实付金额 / 兑换: 抵扣金额
+    pub amount: f64,
+    pub created_at: i64,
+}
+
+/// Member points balance with the group's earn/burn rule
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct MemberPointsSummary {
+    pub member_id: i64,
+    pub points_balance: i64,
+    pub points_earn_rate: Option<f64>,
+    pub points_redeem_unit: Option<i64>,
+    pub points_redeem_value: Option<f64>,
+    /// 最近流水 (新 → 旧)
+    pub transactions: Vec<MemberPointsTransaction>,
+}
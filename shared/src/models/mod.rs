@@ -4,57 +4,105 @@
 //! DB row types use `#[cfg_attr(feature = "db", derive(sqlx::FromRow))]`.
 //! All IDs are `i64` (SQLite INTEGER PRIMARY KEY).
 
+pub mod announcement;
 pub mod attribute;
+pub mod campaign_report;
+pub mod cash_denomination;
+pub mod cash_drawer;
+pub mod catalog_change;
 pub mod category;
+pub mod coupon;
 pub mod credit_note;
+pub mod customer_display;
 pub mod daily_report;
+pub mod dead_letter;
+pub mod delivery_rule;
 pub mod dining_table;
+pub mod display_slide;
+pub mod eighty_six;
 pub mod employee;
+pub mod event_booking;
 pub mod image_ref;
+pub mod inventory;
 pub mod invoice;
 pub mod label_template;
 pub mod marketing_group;
 pub mod member;
+pub mod payment_surcharge;
+pub mod pickup;
 pub mod price_rule;
 pub mod print_destination;
 pub mod product;
+pub mod receipt_archive;
+pub mod receipt_footer;
+pub mod reservation;
 pub mod role;
+pub mod sales_facts;
+pub mod setup;
 pub mod shift;
 pub mod stamp;
 pub mod store_info;
 pub mod sync;
 pub mod system_issue;
 pub mod system_state;
+pub mod table_group;
 pub mod tag;
+pub mod terminal_profile;
 pub mod zone;
 
 pub mod catalog_export;
+pub mod locale_format;
 pub mod receipt_text;
 
 // Re-exports
+pub use announcement::*;
 pub use attribute::*;
+pub use campaign_report::*;
+pub use cash_denomination::*;
+pub use cash_drawer::*;
+pub use catalog_change::*;
 pub use category::*;
+pub use coupon::*;
 pub use credit_note::*;
+pub use customer_display::*;
 pub use daily_report::*;
+pub use dead_letter::*;
+pub use delivery_rule::*;
 pub use dining_table::*;
+pub use display_slide::*;
+pub use eighty_six::*;
 pub use employee::*;
+pub use event_booking::*;
 pub use image_ref::*;
+pub use inventory::*;
 pub use invoice::*;
 pub use label_template::*;
 pub use marketing_group::*;
 pub use member::*;
+pub use payment_surcharge::*;
+pub use pickup::*;
 pub use price_rule::*;
 pub use print_destination::*;
 pub use product::*;
+pub use receipt_archive::*;
+pub use receipt_footer::*;
+pub use reservation::*;
 pub use role::*;
+pub use sales_facts::*;
+pub use setup::*;
 pub use shift::*;
 pub use stamp::*;
 pub use store_info::*;
 pub use sync::*;
 pub use system_issue::*;
 pub use system_state::*;
+pub use table_group::*;
 pub use tag::*;
+pub use terminal_profile::*;
 pub use zone::*;
 
 pub use catalog_export::{CatalogExport, validate_catalog};
-pub use receipt_text::{ReceiptText, receipt_text};
+pub use locale_format::{LocaleFormat, SymbolPosition};
+pub use receipt_text::{
+    RECEIPT_LOCALES, ReceiptText, pick_translation, receipt_text, resolve_receipt_locale,
+};
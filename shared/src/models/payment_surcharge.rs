@@ -0,0 +1,122 @@
+//! Payment Surcharge Rule Model (支付方式附加费)
+//!
+//! Some regions allow a surcharge for card payments. One rule per payment
+//! method (percentage of the payment or a fixed amount), only applied when the
+//! ticket total reaches `min_ticket`. Rules take effect only while the store
+//! toggle `StoreInfo.payment_surcharge_enabled` is on; the surcharge is added
+//! at AddPayment time as an explicit line in the order snapshot.
+
+use rust_decimal::prelude::{FromPrimitive, ToPrimitive};
+use rust_decimal::{Decimal, RoundingStrategy};
+use serde::{Deserialize, Serialize};
+
+use super::price_rule::AdjustmentType;
+
+/// Surcharge rule for one payment method
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "db", derive(sqlx::FromRow))]
+pub struct PaymentSurchargeRule {
+    pub id: i64,
+    /// Payment method (e.g. "CARD"), unique
+    pub payment_method: String,
+    pub adjustment_type: AdjustmentType,
+    /// Percent (0-100) or fixed amount
+    pub value: f64,
+    /// Minimum ticket total for the surcharge to apply (None = always)
+    pub min_ticket: Option<f64>,
+    pub is_active: bool,
+    pub created_at: i64,
+    pub updated_at: i64,
+}
+
+/// Create payment surcharge rule payload
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct PaymentSurchargeRuleCreate {
+    pub payment_method: String,
+    pub adjustment_type: AdjustmentType,
+    pub value: f64,
+    pub min_ticket: Option<f64>,
+}
+
+/// Update payment surcharge rule payload
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct PaymentSurchargeRuleUpdate {
+    pub adjustment_type: Option<AdjustmentType>,
+    pub value: Option<f64>,
+    pub min_ticket: Option<f64>,
+    /// Remove the minimum ticket threshold
+    #[serde(default)]
+    pub clear_min_ticket: bool,
+    pub is_active: Option<bool>,
+}
+
+impl PaymentSurchargeRule {
+    /// Surcharge for a payment of `amount` on a ticket totalling `ticket_total`
+    ///
+    /// Returns None when the rule is inactive, the ticket is below `min_ticket`
+    /// or the computed surcharge rounds to zero.
+    pub fn compute(&self, amount: f64, ticket_total: f64) -> Option<f64> {
+        if !self.is_active {
+            return None;
+        }
+        if let Some(min) = self.min_ticket
+            && ticket_total < min
+        {
+            return None;
+        }
+        let value = Decimal::from_f64(self.value)?;
+        let surcharge = match self.adjustment_type {
+            AdjustmentType::Percentage => Decimal::from_f64(amount)? * value / Decimal::ONE_HUNDRED,
+            AdjustmentType::FixedAmount => value,
+        }
+        .round_dp_with_strategy(2, RoundingStrategy::MidpointAwayFromZero);
+        if surcharge <= Decimal::ZERO {
+            return None;
+        }
+        surcharge.to_f64()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn rule(
+        adjustment_type: AdjustmentType,
+        value: f64,
+        min_ticket: Option<f64>,
+    ) -> PaymentSurchargeRule {
+        PaymentSurchargeRule {
+            id: 1,
+            payment_method: "CARD".to_string(),
+            adjustment_type,
+            value,
+            min_ticket,
+            is_active: true,
+            created_at: 0,
+            updated_at: 0,
+        }
+    }
+
+    #[test]
+    fn test_compute_percentage_and_fixed() {
+        let pct = rule(AdjustmentType::Percentage, 1.5, None);
+        assert_eq!(pct.compute(33.33, 33.33), Some(0.5));
+        let fixed = rule(AdjustmentType::FixedAmount, 0.3, None);
+        assert_eq!(fixed.compute(5.0, 5.0), Some(0.3));
+    }
+
+    #[test]
+    fn test_compute_respects_min_ticket_and_active() {
+        let mut r = rule(AdjustmentType::Percentage, 2.0, Some(20.0));
+        assert_eq!(r.compute(10.0, 19.99), None);
+        assert_eq!(r.compute(10.0, 20.0), Some(0.2));
+        r.is_active = false;
+        assert_eq!(r.compute(10.0, 50.0), None);
+        // 四舍五入为 0 不产生附加费
+        assert_eq!(
+            rule(AdjustmentType::Percentage, 0.1, None).compute(1.0, 1.0),
+            None
+        );
+    }
+}
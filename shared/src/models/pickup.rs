@@ -0,0 +1,109 @@
+//! Pickup Ticket Model (外卖取餐通知)
+//!
+//! 外卖订单结账时登记取餐码和手机号，出餐后标记 READY 并通过短信 / WhatsApp
+//! 通知顾客。顾客可凭取餐码查询公开状态接口，无需打电话询问。
+
+use serde::{Deserialize, Serialize};
+
+/// Pickup lifecycle
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+#[cfg_attr(feature = "db", derive(sqlx::Type))]
+#[cfg_attr(feature = "db", sqlx(rename_all = "SCREAMING_SNAKE_CASE"))]
+pub enum PickupStatus {
+    /// 制作中
+    Waiting,
+    /// 已出餐，等待顾客取餐
+    Ready,
+    /// 顾客已取餐
+    Collected,
+}
+
+/// Outbound message channel
+#[derive(Debug, Clone, Copy, Default, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+#[cfg_attr(feature = "db", derive(sqlx::Type))]
+#[cfg_attr(feature = "db", sqlx(rename_all = "SCREAMING_SNAKE_CASE"))]
+pub enum NotifyChannel {
+    #[default]
+    Sms,
+    Whatsapp,
+}
+
+/// Message delivery tracking (gateway delivery reports)
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+#[cfg_attr(feature = "db", derive(sqlx::Type))]
+#[cfg_attr(feature = "db", sqlx(rename_all = "SCREAMING_SNAKE_CASE"))]
+pub enum DeliveryStatus {
+    /// 网关已接收
+    Sent,
+    /// 已送达顾客手机
+    Delivered,
+    /// 发送失败 (可重发)
+    Failed,
+}
+
+/// Pickup ticket entity
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+#[cfg_attr(feature = "db", derive(sqlx::FromRow))]
+pub struct PickupTicket {
+    pub id: i64,
+    pub order_id: i64,
+    pub receipt_number: String,
+    pub queue_number: Option<i64>,
+    /// Short code shown on the receipt and used by the public status endpoint
+    pub pickup_code: String,
+    /// Customer phone (E.164); None = no message, customer watches the screen
+    pub phone: Option<String>,
+    pub channel: NotifyChannel,
+    pub status: PickupStatus,
+    /// None until a message is attempted
+    pub delivery_status: Option<DeliveryStatus>,
+    pub attempts: i32,
+    pub gateway_message_id: Option<String>,
+    pub last_error: Option<String>,
+    pub created_at: i64,
+    pub ready_at: Option<i64>,
+    pub notified_at: Option<i64>,
+    pub delivered_at: Option<i64>,
+    pub collected_at: Option<i64>,
+    pub updated_at: i64,
+}
+
+/// Register a pickup ticket for a takeaway order
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct PickupCreate {
+    pub order_id: i64,
+    pub phone: Option<String>,
+    #[serde(default)]
+    pub channel: NotifyChannel,
+}
+
+/// Public status (no customer data)
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct PickupPublicStatus {
+    pub pickup_code: String,
+    pub queue_number: Option<i64>,
+    pub status: PickupStatus,
+    pub ready_at: Option<i64>,
+}
+
+impl From<&PickupTicket> for PickupPublicStatus {
+    fn from(ticket: &PickupTicket) -> Self {
+        Self {
+            pickup_code: ticket.pickup_code.clone(),
+            queue_number: ticket.queue_number,
+            status: ticket.status,
+            ready_at: ticket.ready_at,
+        }
+    }
+}
+
+/// Delivery report posted back by the message gateway
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct DeliveryReport {
+    pub message_id: String,
+    pub status: DeliveryStatus,
+    pub error: Option<String>,
+}
@@ -58,6 +58,9 @@ pub struct PriceRule {
     pub adjustment_value: f64,
     pub is_stackable: bool,
     pub is_exclusive: bool,
+    /// Explicit priority among overlapping rules of the same scope (higher wins)
+    #[serde(default)]
+    pub priority: i32,
     /// Valid from datetime (Unix millis)
     pub valid_from: Option<i64>,
     /// Valid until datetime (Unix millis)
@@ -89,6 +92,7 @@ pub struct PriceRuleCreate {
     pub adjustment_value: f64,
     pub is_stackable: Option<bool>,
     pub is_exclusive: Option<bool>,
+    pub priority: Option<i32>,
     pub valid_from: Option<i64>,
     pub valid_until: Option<i64>,
     pub active_days: Option<Vec<u8>>,
@@ -111,6 +115,7 @@ pub struct PriceRuleUpdate {
     pub adjustment_value: Option<f64>,
     pub is_stackable: Option<bool>,
     pub is_exclusive: Option<bool>,
+    pub priority: Option<i32>,
     pub valid_from: Option<i64>,
     pub valid_until: Option<i64>,
     pub active_days: Option<Vec<u8>>,
@@ -87,3 +87,44 @@ pub struct PrintDestinationUpdate {
     pub printers: Option<Vec<PrinterInput>>,
     pub is_active: Option<bool>,
 }
+
+/// Where a product's effective print destinations come from
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+pub enum RoutingSource {
+    /// Product-level override
+    Product,
+    /// Category default
+    Category,
+    /// System default destination (print config)
+    Default,
+    /// Printing disabled (global toggle, category or product flag)
+    Disabled,
+}
+
+/// Effective routing for one purpose (kitchen / label)
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct EffectiveRoute {
+    pub enabled: bool,
+    pub destinations: Vec<i64>,
+    pub source: RoutingSource,
+}
+
+/// One product row of the routing matrix
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct RoutingMatrixRow {
+    pub product_id: i64,
+    pub product_name: String,
+    pub category_id: i64,
+    pub category_name: String,
+    pub kitchen: EffectiveRoute,
+    pub label: EffectiveRoute,
+}
+
+/// Effective routing of every active product (`GET /api/print/routing_matrix`)
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct RoutingMatrix {
+    /// Matrix columns (all print destinations)
+    pub destinations: Vec<PrintDestination>,
+    pub rows: Vec<RoutingMatrixRow>,
+}
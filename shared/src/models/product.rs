@@ -62,6 +62,12 @@ pub struct ProductCreate {
     pub kitchen_print_name: Option<String>,
     pub is_kitchen_print_enabled: Option<i32>,
     pub is_label_print_enabled: Option<i32>,
+    /// 厨房打印目标覆盖 (空 = 继承分类)
+    #[serde(default)]
+    pub kitchen_print_destinations: Vec<i64>,
+    /// 标签打印目标覆盖 (空 = 继承分类)
+    #[serde(default)]
+    pub label_print_destinations: Vec<i64>,
     pub external_id: Option<i64>,
     pub tags: Option<Vec<i64>>,
     /// 规格列表 (至少 1 个)
@@ -80,6 +86,10 @@ pub struct ProductUpdate {
     pub kitchen_print_name: Option<String>,
     pub is_kitchen_print_enabled: Option<i32>,
     pub is_label_print_enabled: Option<i32>,
+    /// 替换厨房打印目标覆盖 (Some(vec![]) = 恢复继承分类)
+    pub kitchen_print_destinations: Option<Vec<i64>>,
+    /// 替换标签打印目标覆盖 (Some(vec![]) = 恢复继承分类)
+    pub label_print_destinations: Option<Vec<i64>>,
     pub is_active: Option<bool>,
     pub external_id: Option<i64>,
     pub tags: Option<Vec<i64>>,
@@ -120,6 +130,12 @@ pub struct ProductFull {
     pub kitchen_print_name: Option<String>,
     pub is_kitchen_print_enabled: i32,
     pub is_label_print_enabled: i32,
+    /// Kitchen print destination overrides (empty = inherit category)
+    #[serde(default)]
+    pub kitchen_print_destinations: Vec<i64>,
+    /// Label print destination overrides (empty = inherit category)
+    #[serde(default)]
+    pub label_print_destinations: Vec<i64>,
     pub is_active: bool,
     pub external_id: Option<i64>,
     pub specs: Vec<ProductSpec>,
@@ -128,3 +144,20 @@ pub struct ProductFull {
     /// Tags attached to this product
     pub tags: Vec<super::tag::Tag>,
 }
+
+/// Upsell suggestion for a product (顾客常一起点的商品)
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+#[cfg_attr(feature = "db", derive(sqlx::FromRow))]
+pub struct UpsellSuggestion {
+    pub product_id: i64,
+    pub name: String,
+    pub image: String,
+    /// Default spec price
+    pub price: f64,
+    /// Orders containing both products
+    pub support: i64,
+    /// Share of orders with the source product that also had this one
+    pub confidence: f64,
+    /// confidence / overall frequency of this product (> 1 = real affinity)
+    pub lift: f64,
+}
@@ -0,0 +1,115 @@
+//! Receipt Archive Model (收据存档)
+//!
+//! The exact bytes sent to the printer (ESC/POS) or rendered as a document (PDF)
+//! for a completed order, keyed by receipt number. Artifacts are write-once:
+//! the edge stores each one under its SHA-256 and never rewrites or deletes it,
+//! so a dispute can be settled with the literal printed ticket.
+
+use serde::{Deserialize, Serialize};
+
+/// Artifact format
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq, Hash)]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+pub enum ReceiptArtifactKind {
+    /// Raw printer bytes (ESC/POS, as sent to the thermal printer)
+    EscPos,
+    Pdf,
+}
+
+impl ReceiptArtifactKind {
+    /// 数据库 TEXT 列表示
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            ReceiptArtifactKind::EscPos => "ESC_POS",
+            ReceiptArtifactKind::Pdf => "PDF",
+        }
+    }
+
+    /// 存档文件扩展名
+    pub fn extension(&self) -> &'static str {
+        match self {
+            ReceiptArtifactKind::EscPos => "escpos",
+            ReceiptArtifactKind::Pdf => "pdf",
+        }
+    }
+
+    /// HTTP Content-Type
+    pub fn content_type(&self) -> &'static str {
+        match self {
+            ReceiptArtifactKind::EscPos => "application/octet-stream",
+            ReceiptArtifactKind::Pdf => "application/pdf",
+        }
+    }
+}
+
+impl TryFrom<String> for ReceiptArtifactKind {
+    type Error = String;
+
+    fn try_from(value: String) -> Result<Self, Self::Error> {
+        match value.as_str() {
+            "ESC_POS" => Ok(ReceiptArtifactKind::EscPos),
+            "PDF" => Ok(ReceiptArtifactKind::Pdf),
+            other => Err(format!("invalid receipt artifact kind: {other}")),
+        }
+    }
+}
+
+/// Archived receipt artifact (metadata; the bytes live in the archive directory)
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+#[cfg_attr(feature = "db", derive(sqlx::FromRow))]
+pub struct ReceiptArtifact {
+    pub id: i64,
+    /// Receipt number as printed (split receipts carry their `-N` suffix)
+    pub receipt_number: String,
+    #[cfg_attr(feature = "db", sqlx(try_from = "String"))]
+    pub kind: ReceiptArtifactKind,
+    /// Hex SHA-256 of the artifact bytes
+    pub sha256: String,
+    pub size_bytes: i64,
+    /// Printed as a reprint (copy) rather than the original ticket
+    pub reprint: bool,
+    pub created_at: i64,
+    pub cloud_synced: bool,
+}
+
+/// Archive upload payload (bytes are base64 encoded)
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ReceiptArtifactCreate {
+    pub receipt_number: String,
+    pub kind: ReceiptArtifactKind,
+    pub content_base64: String,
+    #[serde(default)]
+    pub reprint: bool,
+}
+
+/// Receipt number usable as a directory name (no path separators or `..`)
+pub fn is_valid_receipt_number(receipt_number: &str) -> bool {
+    !receipt_number.is_empty()
+        && receipt_number.len() <= 64
+        && receipt_number
+            .chars()
+            .all(|c| c.is_ascii_alphanumeric() || matches!(c, '-' | '_'))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_kind_roundtrip_and_receipt_number() {
+        for kind in [ReceiptArtifactKind::EscPos, ReceiptArtifactKind::Pdf] {
+            assert_eq!(
+                ReceiptArtifactKind::try_from(kind.as_str().to_string()),
+                Ok(kind)
+            );
+            assert_eq!(
+                serde_json::to_string(&kind).unwrap(),
+                format!("\"{}\"", kind.as_str())
+            );
+        }
+        assert!(is_valid_receipt_number("FAC2026101500012"));
+        assert!(is_valid_receipt_number("FAC2026101500012-2"));
+        assert!(!is_valid_receipt_number("../main.db"));
+        assert!(!is_valid_receipt_number(""));
+    }
+}
@@ -0,0 +1,230 @@
+//! Receipt Footer Model (收据页脚促销)
+//!
+//! 门店可配置多个页脚：促销文字 + 满意度调查二维码，按星期排期
+//! （如工作日 A、周末 B）。打印时由服务端选出当天生效的页脚，
+//! 并为每张收据生成唯一调查码（以门店调查密钥签名）。
+
+use hmac::{Hmac, Mac};
+use serde::{Deserialize, Serialize};
+use sha2::Sha256;
+
+/// Placeholder in `survey_url` replaced by the per-receipt survey code
+pub const SURVEY_CODE_PLACEHOLDER: &str = "{code}";
+
+/// Receipt footer entity
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "db", derive(sqlx::FromRow))]
+pub struct ReceiptFooter {
+    pub id: i64,
+    pub name: String,
+    /// Promotional text, one printed line per text line
+    pub lines: String,
+    /// Survey URL encoded as QR; `{code}` is replaced by the survey code,
+    /// otherwise the code is appended as a `code` query parameter
+    pub survey_url: Option<String>,
+    /// Active days of week (JSON array: 0=Sunday..6=Saturday), None = every day
+    #[cfg_attr(feature = "db", sqlx(json))]
+    pub active_days: Option<Vec<u8>>,
+    /// Lower value wins when several footers are active on the same day
+    pub sort_order: i32,
+    pub is_active: bool,
+    pub created_at: i64,
+    pub updated_at: i64,
+}
+
+/// Create receipt footer payload
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ReceiptFooterCreate {
+    pub name: String,
+    pub lines: String,
+    pub survey_url: Option<String>,
+    pub active_days: Option<Vec<u8>>,
+    pub sort_order: Option<i32>,
+}
+
+/// Update receipt footer payload
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ReceiptFooterUpdate {
+    pub name: Option<String>,
+    pub lines: Option<String>,
+    pub survey_url: Option<String>,
+    pub active_days: Option<Vec<u8>>,
+    pub sort_order: Option<i32>,
+    pub is_active: Option<bool>,
+}
+
+/// Footer content resolved for one receipt (injected into the receipt renderer)
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct ResolvedReceiptFooter {
+    pub footer_id: i64,
+    pub lines: Vec<String>,
+    /// Per-receipt survey code (printed under the QR for manual entry)
+    pub survey_code: Option<String>,
+    /// QR payload (survey URL with the code filled in)
+    pub survey_qr: Option<String>,
+}
+
+impl ReceiptFooter {
+    /// Whether this footer is scheduled on `weekday` (0=Sunday..6=Saturday)
+    pub fn is_active_on(&self, weekday: u8) -> bool {
+        self.is_active
+            && self
+                .active_days
+                .as_ref()
+                .is_none_or(|days| days.contains(&weekday))
+    }
+
+    /// Resolve printable content for a receipt
+    ///
+    /// The survey QR is only produced when a receipt number is known,
+    /// so pre-payment bills never carry a survey code.
+    pub fn resolve(
+        &self,
+        receipt_number: Option<&str>,
+        survey_secret: &str,
+    ) -> ResolvedReceiptFooter {
+        let lines = self
+            .lines
+            .lines()
+            .map(str::trim_end)
+            .filter(|l| !l.is_empty())
+            .map(String::from)
+            .collect();
+
+        let survey = self
+            .survey_url
+            .as_deref()
+            .filter(|url| !url.trim().is_empty())
+            .zip(receipt_number)
+            .map(|(url, receipt)| {
+                let code = survey_code(survey_secret, receipt);
+                (survey_url_with_code(url.trim(), &code), code)
+            });
+
+        ResolvedReceiptFooter {
+            footer_id: self.id,
+            lines,
+            survey_qr: survey.as_ref().map(|(qr, _)| qr.clone()),
+            survey_code: survey.map(|(_, code)| code),
+        }
+    }
+}
+
+/// Pick the footer for `weekday`: first active match by sort_order, then id
+pub fn select_receipt_footer(footers: &[ReceiptFooter], weekday: u8) -> Option<&ReceiptFooter> {
+    footers
+        .iter()
+        .filter(|f| f.is_active_on(weekday))
+        .min_by_key(|f| (f.sort_order, f.id))
+}
+
+/// Per-receipt survey code: receipt number + 8-hex HMAC-SHA256 tag
+///
+/// Receipt numbers are unique, so the code is unique and stable across
+/// reprints. The tag is keyed with the store's survey secret (shared only
+/// with the survey site), so codes cannot be derived from receipt data.
+pub fn survey_code(secret: &str, receipt_number: &str) -> String {
+    let mut mac =
+        Hmac::<Sha256>::new_from_slice(secret.as_bytes()).expect("HMAC accepts keys of any length");
+    mac.update(receipt_number.as_bytes());
+    let tag = mac.finalize().into_bytes();
+    format!(
+        "{}-{:02X}{:02X}{:02X}{:02X}",
+        receipt_number, tag[0], tag[1], tag[2], tag[3]
+    )
+}
+
+fn survey_url_with_code(url: &str, code: &str) -> String {
+    if url.contains(SURVEY_CODE_PLACEHOLDER) {
+        url.replace(SURVEY_CODE_PLACEHOLDER, code)
+    } else {
+        let sep = if url.contains('?') { '&' } else { '?' };
+        format!("{}{}code={}", url, sep, code)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn footer(id: i64, days: Option<Vec<u8>>, sort_order: i32) -> ReceiptFooter {
+        ReceiptFooter {
+            id,
+            name: format!("footer_{}", id),
+            lines: "2x1 en cafés\n\nSíguenos @crab".to_string(),
+            survey_url: None,
+            active_days: days,
+            sort_order,
+            is_active: true,
+            created_at: 0,
+            updated_at: 0,
+        }
+    }
+
+    #[test]
+    fn test_weekday_weekend_schedule() {
+        let weekdays = footer(1, Some(vec![1, 2, 3, 4, 5]), 0);
+        let weekend = footer(2, Some(vec![0, 6]), 0);
+        let footers = [weekdays, weekend];
+
+        assert_eq!(select_receipt_footer(&footers, 3).unwrap().id, 1);
+        assert_eq!(select_receipt_footer(&footers, 6).unwrap().id, 2);
+        assert_eq!(select_receipt_footer(&footers, 0).unwrap().id, 2);
+    }
+
+    #[test]
+    fn test_sort_order_and_inactive() {
+        let mut fallback = footer(1, None, 10);
+        let special = footer(2, Some(vec![5]), 0);
+        assert_eq!(
+            select_receipt_footer(&[fallback.clone(), special.clone()], 5)
+                .unwrap()
+                .id,
+            2
+        );
+        assert_eq!(
+            select_receipt_footer(&[fallback.clone(), special], 4)
+                .unwrap()
+                .id,
+            1
+        );
+
+        fallback.is_active = false;
+        assert!(select_receipt_footer(&[fallback], 4).is_none());
+    }
+
+    #[test]
+    fn test_resolve_lines_and_survey() {
+        let mut f = footer(1, None, 0);
+        f.survey_url = Some("https://survey.example/s/{code}".to_string());
+
+        let resolved = f.resolve(Some("R-001"), "secret-a");
+        assert_eq!(resolved.lines, vec!["2x1 en cafés", "Síguenos @crab"]);
+        let code = resolved.survey_code.unwrap();
+        assert!(code.starts_with("R-001-"));
+        assert_eq!(
+            resolved.survey_qr.unwrap(),
+            format!("https://survey.example/s/{}", code)
+        );
+
+        // Stable across reprints, distinct per receipt and per store secret
+        assert_eq!(survey_code("secret-a", "R-001"), code);
+        assert_ne!(survey_code("secret-a", "R-002"), code);
+        assert_ne!(survey_code("secret-b", "R-001"), code);
+
+        // No receipt number (pre-payment bill) → no survey
+        assert!(f.resolve(None, "secret-a").survey_qr.is_none());
+    }
+
+    #[test]
+    fn test_survey_url_without_placeholder() {
+        assert_eq!(
+            survey_url_with_code("https://s.example/q", "R-1-ABCD"),
+            "https://s.example/q?code=R-1-ABCD"
+        );
+        assert_eq!(
+            survey_url_with_code("https://s.example/q?store=3", "R-1-ABCD"),
+            "https://s.example/q?store=3&code=R-1-ABCD"
+        );
+    }
+}
@@ -2,10 +2,14 @@
 //!
 //! All text is `&'static str` — zero allocation, used directly in hot render paths.
 
+use std::collections::HashMap;
+
 /// Localized receipt text for all three renderers:
 /// - Tauri `receipt_renderer.rs` (customer receipt)
 /// - Edge `credit_note_renderer.rs` (refund receipt)
 /// - Edge `renderer.rs` (kitchen ticket)
+/// - Edge `beo_renderer.rs` (banquet event order)
+/// - Edge `daily_report_renderer.rs` (daily report summary)
 pub struct ReceiptText {
     // ── decimal format ────────────────────────────────────────────
     /// Decimal separator: "," for es-ES, "." for others
@@ -31,6 +35,7 @@ pub struct ReceiptText {
     pub opened_label: &'static str,
     pub closed_label: &'static str,
     pub void_reason_label: &'static str,
+    pub fiscal_code_label: &'static str,
 
     // ── item table header ─────────────────────────────────────────
     pub col_qty: &'static str,
@@ -44,6 +49,8 @@ pub struct ReceiptText {
     pub comp_label: &'static str,
     pub order_discount_label: &'static str,
     pub order_surcharge_label: &'static str,
+    /// Payment-method surcharge line (card surcharge)
+    pub payment_surcharge_label: &'static str,
 
     // ── subtotal area ─────────────────────────────────────────────
     pub subtotal_label: &'static str,
@@ -81,6 +88,87 @@ pub struct ReceiptText {
     pub takeaway_tag: &'static str,
     pub spec_label: &'static str,
     pub reprint_indicator: &'static str,
+    pub fire_course_label: &'static str,
+
+    // ── banquet event order (BEO) ─────────────────────────────────
+    pub beo_title: &'static str,
+    pub event_label: &'static str,
+    pub event_date_label: &'static str,
+    pub customer_label: &'static str,
+    pub revision_label: &'static str,
+    pub amended_title: &'static str,
+    pub removed_tag: &'static str,
+    pub notes_label: &'static str,
+
+    // ── per-diner sub-receipt ─────────────────────────────────────
+    pub sub_receipt_label: &'static str,
+    pub master_receipt_label: &'static str,
+    pub payments_label: &'static str,
+    pub change_label: &'static str,
+
+    // ── daily report summary ──────────────────────────────────────
+    pub daily_report_title: &'static str,
+    pub business_date_label: &'static str,
+    pub orders_label: &'static str,
+    pub net_revenue_label: &'static str,
+    pub refunds_label: &'static str,
+    pub carried_out_label: &'static str,
+    pub no_sale_label: &'static str,
+    pub paid_in_label: &'static str,
+    pub paid_out_label: &'static str,
+    pub cash_variance_label: &'static str,
+    pub generated_label: &'static str,
+}
+
+/// Locales with dedicated receipt text (selectable per print / member preference).
+pub const RECEIPT_LOCALES: &[&str] = &["es-ES", "en", "en-US", "en-GB", "zh-CN"];
+
+/// Resolve a requested receipt locale (print-time choice or member preference).
+///
+/// Exact match first, then language prefix (`"en-AU"` → `"en"`, `"zh"` →
+/// `"zh-CN"`); unknown or missing locales fall back to `fallback` (the store
+/// receipt locale), and finally to `es-ES`.
+pub fn resolve_receipt_locale(requested: Option<&str>, fallback: Option<&str>) -> &'static str {
+    fn known(locale: &str) -> Option<&'static str> {
+        if let Some(exact) = RECEIPT_LOCALES.iter().copied().find(|l| *l == locale) {
+            return Some(exact);
+        }
+        match locale
+            .split(['-', '_'])
+            .next()?
+            .to_ascii_lowercase()
+            .as_str()
+        {
+            "en" => Some("en"),
+            "zh" => Some("zh-CN"),
+            "es" => Some("es-ES"),
+            _ => None,
+        }
+    }
+    requested
+        .and_then(known)
+        .or_else(|| fallback.and_then(known))
+        .unwrap_or("es-ES")
+}
+
+/// Pick a translated name for `locale` (exact, then same language).
+///
+/// Returns None when no translation matches — callers print the catalog name.
+pub fn pick_translation<'a>(
+    translations: &'a HashMap<String, String>,
+    locale: &str,
+) -> Option<&'a str> {
+    let language = locale.split(['-', '_']).next().unwrap_or(locale);
+    translations
+        .get(locale)
+        .or_else(|| {
+            translations
+                .iter()
+                .find(|(l, _)| l.split(['-', '_']).next() == Some(language))
+                .map(|(_, name)| name)
+        })
+        .map(String::as_str)
+        .filter(|name| !name.trim().is_empty())
 }
 
 /// Build localized receipt text for the given locale.
@@ -105,6 +193,7 @@ pub fn receipt_text(locale: &str) -> ReceiptText {
             opened_label: "开台:",
             closed_label: "结台:  ",
             void_reason_label: "作废:",
+            fiscal_code_label: "税控码:",
             col_qty: "数量",
             col_desc: "品名",
             col_price: "单价",
@@ -114,6 +203,7 @@ pub fn receipt_text(locale: &str) -> ReceiptText {
             comp_label: "INVITACION",
             order_discount_label: "Dto. Pedido",
             order_surcharge_label: "Recargo Pedido",
+            payment_surcharge_label: "刷卡附加费",
             subtotal_label: "小计",
             savings: "节省",
             total_units_label: "合计件数:",
@@ -141,6 +231,30 @@ pub fn receipt_text(locale: &str) -> ReceiptText {
             takeaway_tag: "[外带]",
             spec_label: "规格:",
             reprint_indicator: "重印",
+            fire_course_label: "起菜 道次",
+            beo_title: "宴会单 BEO",
+            event_label: "活动:",
+            event_date_label: "日期:",
+            customer_label: "客户:",
+            revision_label: "版本:",
+            amended_title: "*** 已修改 ***",
+            removed_tag: "[取消]",
+            notes_label: "备注:",
+            sub_receipt_label: "分单",
+            master_receipt_label: "主单:",
+            payments_label: "付款",
+            change_label: "找零",
+            daily_report_title: "日结报表",
+            business_date_label: "营业日:",
+            orders_label: "订单数",
+            net_revenue_label: "净营业额",
+            refunds_label: "退款",
+            carried_out_label: "跨日未结",
+            no_sale_label: "无销售开钱箱",
+            paid_in_label: "存入现金",
+            paid_out_label: "取出现金",
+            cash_variance_label: "现金差异",
+            generated_label: "生成:",
         },
         "en" | "en-US" | "en-GB" => ReceiptText {
             decimal_separator: ".",
@@ -159,6 +273,7 @@ pub fn receipt_text(locale: &str) -> ReceiptText {
             opened_label: "Opened:",
             closed_label: "Closed:  ",
             void_reason_label: "VOIDED:",
+            fiscal_code_label: "Fiscal code:",
             col_qty: "QTY",
             col_desc: "DESCRIPTION",
             col_price: "PRICE",
@@ -168,6 +283,7 @@ pub fn receipt_text(locale: &str) -> ReceiptText {
             comp_label: "INVITACION",
             order_discount_label: "Dto. Pedido",
             order_surcharge_label: "Recargo Pedido",
+            payment_surcharge_label: "Card surcharge",
             subtotal_label: "SUBTOTAL",
             savings: "SAVINGS",
             total_units_label: "Total Items:",
@@ -195,6 +311,30 @@ pub fn receipt_text(locale: &str) -> ReceiptText {
             takeaway_tag: "[TO-GO]",
             spec_label: "SPEC:",
             reprint_indicator: "REPRINT",
+            fire_course_label: "FIRE COURSE",
+            beo_title: "BANQUET EVENT ORDER",
+            event_label: "Event:",
+            event_date_label: "Date:",
+            customer_label: "Client:",
+            revision_label: "Rev:",
+            amended_title: "*** AMENDED ***",
+            removed_tag: "[REMOVED]",
+            notes_label: "Notes:",
+            sub_receipt_label: "Split",
+            master_receipt_label: "Master receipt:",
+            payments_label: "Payments",
+            change_label: "Change",
+            daily_report_title: "DAILY REPORT",
+            business_date_label: "Business day:",
+            orders_label: "Orders",
+            net_revenue_label: "Net revenue",
+            refunds_label: "Refunds",
+            carried_out_label: "Carried over",
+            no_sale_label: "No-sale opens",
+            paid_in_label: "Paid in",
+            paid_out_label: "Paid out",
+            cash_variance_label: "Cash variance",
+            generated_label: "Generated:",
         },
         // es-ES default (Verifactu compliance language)
         _ => ReceiptText {
@@ -214,6 +354,7 @@ pub fn receipt_text(locale: &str) -> ReceiptText {
             opened_label: "Apertura:",
             closed_label: "Cierre:  ",
             void_reason_label: "ANULADO:",
+            fiscal_code_label: "Cód. fiscal:",
             col_qty: "UDS",
             col_desc: "DESCRIPCION",
             col_price: "PVP",
@@ -223,6 +364,7 @@ pub fn receipt_text(locale: &str) -> ReceiptText {
             comp_label: "INVITACION",
             order_discount_label: "Dto. Pedido",
             order_surcharge_label: "Recargo Pedido",
+            payment_surcharge_label: "Recargo tarjeta",
             subtotal_label: "SUBTOTAL",
             savings: "AHORRO",
             total_units_label: "Total Uds:",
@@ -250,6 +392,64 @@ pub fn receipt_text(locale: &str) -> ReceiptText {
             takeaway_tag: "[LLEVAR]",
             spec_label: "SPEC:",
             reprint_indicator: "REIMPRESION",
+            fire_course_label: "MARCHAR PLATO",
+            beo_title: "ORDEN DE EVENTO",
+            event_label: "Evento:",
+            event_date_label: "Fecha:",
+            customer_label: "Cliente:",
+            revision_label: "Rev:",
+            amended_title: "*** MODIFICADO ***",
+            removed_tag: "[ANULADO]",
+            notes_label: "Notas:",
+            sub_receipt_label: "Cuenta",
+            master_receipt_label: "Ticket principal:",
+            payments_label: "Pagos",
+            change_label: "Cambio",
+            daily_report_title: "CIERRE DEL DIA",
+            business_date_label: "Dia:",
+            orders_label: "Pedidos",
+            net_revenue_label: "Ventas netas",
+            refunds_label: "Devoluciones",
+            carried_out_label: "Traspasados",
+            no_sale_label: "Aperturas cajon",
+            paid_in_label: "Entradas caja",
+            paid_out_label: "Salidas caja",
+            cash_variance_label: "Descuadre",
+            generated_label: "Generado:",
         },
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_resolve_receipt_locale_falls_back() {
+        assert_eq!(
+            resolve_receipt_locale(Some("en-GB"), Some("es-ES")),
+            "en-GB"
+        );
+        assert_eq!(resolve_receipt_locale(Some("en-AU"), Some("es-ES")), "en");
+        assert_eq!(resolve_receipt_locale(Some("zh"), None), "zh-CN");
+        assert_eq!(
+            resolve_receipt_locale(Some("fr-FR"), Some("zh-CN")),
+            "zh-CN"
+        );
+        assert_eq!(resolve_receipt_locale(None, Some("de")), "es-ES");
+    }
+
+    #[test]
+    fn test_pick_translation() {
+        let translations = HashMap::from([
+            ("en".to_string(), "Fried rice".to_string()),
+            ("zh-CN".to_string(), "炒饭".to_string()),
+            ("fr".to_string(), " ".to_string()),
+        ]);
+        assert_eq!(pick_translation(&translations, "en-GB"), Some("Fried rice"));
+        assert_eq!(pick_translation(&translations, "zh-CN"), Some("炒饭"));
+        // 空白译名视为缺失
+        assert_eq!(pick_translation(&translations, "fr"), None);
+        assert_eq!(pick_translation(&translations, "es-ES"), None);
+    }
+}
@@ -0,0 +1,239 @@
+//! Reservation Model (订位)
+//!
+//! 日常订位：顾客、人数、时段（开始时间 + 时长），可指定区域或具体桌台。
+//! 同一桌台的有效订位时段不能重叠；只指定区域时按区域内桌台总座位数校验。
+
+use serde::{Deserialize, Serialize};
+
+/// Default time slot length (minutes)
+pub const DEFAULT_RESERVATION_MINUTES: i32 = 90;
+/// Upper bound for a time slot (minutes)
+pub const MAX_RESERVATION_MINUTES: i32 = 12 * 60;
+/// Upper bound for a party
+pub const MAX_PARTY_SIZE: i32 = 500;
+
+/// Reservation lifecycle
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+#[cfg_attr(feature = "db", derive(sqlx::Type))]
+#[cfg_attr(feature = "db", sqlx(rename_all = "SCREAMING_SNAKE_CASE"))]
+pub enum ReservationStatus {
+    /// 已预订（可修改）
+    Booked,
+    /// 已入座
+    Seated,
+    /// 未到店
+    NoShow,
+    /// 已取消
+    Cancelled,
+}
+
+impl ReservationStatus {
+    /// Still holds its table / zone capacity
+    pub fn is_active(self) -> bool {
+        matches!(self, Self::Booked | Self::Seated)
+    }
+
+    /// Allowed transitions: only a booked reservation moves on, the rest are final
+    pub fn can_transition_to(self, to: Self) -> bool {
+        self == Self::Booked && matches!(to, Self::Seated | Self::NoShow | Self::Cancelled)
+    }
+}
+
+/// Reservation entity
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+#[cfg_attr(feature = "db", derive(sqlx::FromRow))]
+pub struct Reservation {
+    pub id: i64,
+    pub customer_name: String,
+    pub customer_phone: Option<String>,
+    pub customer_email: Option<String>,
+    pub party_size: i32,
+    /// Slot start (Unix millis)
+    pub start_at: i64,
+    /// Slot end (Unix millis, exclusive)
+    pub end_at: i64,
+    pub zone_id: Option<i64>,
+    pub zone_name: Option<String>,
+    pub table_id: Option<i64>,
+    pub table_name: Option<String>,
+    pub note: Option<String>,
+    pub status: ReservationStatus,
+    pub seated_at: Option<i64>,
+    pub created_by_id: i64,
+    pub created_by_name: String,
+    pub created_at: i64,
+    pub updated_at: i64,
+}
+
+impl Reservation {
+    /// Half-open interval overlap with [start, end)
+    pub fn overlaps(&self, start: i64, end: i64) -> bool {
+        self.start_at < end && start < self.end_at
+    }
+}
+
+/// Create reservation payload
+///
+/// `table_id` 优先：指定桌台时区域取桌台所在区域。
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ReservationCreate {
+    pub customer_name: String,
+    pub customer_phone: Option<String>,
+    pub customer_email: Option<String>,
+    pub party_size: i32,
+    pub start_at: i64,
+    /// Slot length (default [`DEFAULT_RESERVATION_MINUTES`])
+    pub duration_minutes: Option<i32>,
+    pub zone_id: Option<i64>,
+    pub table_id: Option<i64>,
+    pub note: Option<String>,
+}
+
+/// Update reservation payload (only while BOOKED)
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct ReservationUpdate {
+    pub customer_name: Option<String>,
+    pub customer_phone: Option<String>,
+    pub customer_email: Option<String>,
+    pub party_size: Option<i32>,
+    pub start_at: Option<i64>,
+    /// Keeps the current length when omitted
+    pub duration_minutes: Option<i32>,
+    pub zone_id: Option<i64>,
+    pub table_id: Option<i64>,
+    /// Drop the table assignment (keeps the zone)
+    #[serde(default)]
+    pub clear_table: bool,
+    pub note: Option<String>,
+}
+
+/// Calendar time slot (aggregated active reservations)
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct ReservationSlot {
+    pub start: i64,
+    pub end: i64,
+    /// Active reservations overlapping the slot
+    pub reservations: i32,
+    /// Σ party size of those reservations
+    pub covers: i32,
+    /// Tables held during the slot
+    pub table_ids: Vec<i64>,
+}
+
+/// Day calendar: time slots plus the reservations of that day
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ReservationCalendar {
+    /// Local date (YYYY-MM-DD)
+    pub date: String,
+    pub slot_minutes: i32,
+    pub slots: Vec<ReservationSlot>,
+    pub reservations: Vec<Reservation>,
+}
+
+/// Split [from, to) into slots of `slot_ms` and aggregate active reservations
+pub fn calendar_slots(
+    reservations: &[Reservation],
+    from: i64,
+    to: i64,
+    slot_ms: i64,
+) -> Vec<ReservationSlot> {
+    let mut slots = Vec::new();
+    if slot_ms <= 0 {
+        return slots;
+    }
+    let mut start = from;
+    while start < to {
+        let end = (start + slot_ms).min(to);
+        let mut slot = ReservationSlot {
+            start,
+            end,
+            reservations: 0,
+            covers: 0,
+            table_ids: Vec::new(),
+        };
+        for r in reservations
+            .iter()
+            .filter(|r| r.status.is_active() && r.overlaps(start, end))
+        {
+            slot.reservations += 1;
+            slot.covers += r.party_size;
+            if let Some(table_id) = r.table_id
+                && !slot.table_ids.contains(&table_id)
+            {
+                slot.table_ids.push(table_id);
+            }
+        }
+        slots.push(slot);
+        start = end;
+    }
+    slots
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn reservation(start_at: i64, end_at: i64, table_id: Option<i64>) -> Reservation {
+        Reservation {
+            id: start_at,
+            customer_name: "Pablo".to_string(),
+            customer_phone: None,
+            customer_email: None,
+            party_size: 4,
+            start_at,
+            end_at,
+            zone_id: None,
+            zone_name: None,
+            table_id,
+            table_name: None,
+            note: None,
+            status: ReservationStatus::Booked,
+            seated_at: None,
+            created_by_id: 1,
+            created_by_name: "Admin".to_string(),
+            created_at: 0,
+            updated_at: 0,
+        }
+    }
+
+    #[test]
+    fn test_status_transitions() {
+        use ReservationStatus::*;
+        assert!(Booked.can_transition_to(Seated));
+        assert!(Booked.can_transition_to(NoShow));
+        assert!(Booked.can_transition_to(Cancelled));
+        assert!(!Booked.can_transition_to(Booked));
+        assert!(!Seated.can_transition_to(Cancelled));
+        assert!(!NoShow.can_transition_to(Seated));
+        assert!(Seated.is_active());
+        assert!(!Cancelled.is_active());
+    }
+
+    #[test]
+    fn test_overlap_is_half_open() {
+        let r = reservation(100, 200, None);
+        assert!(r.overlaps(150, 250));
+        assert!(r.overlaps(0, 101));
+        assert!(!r.overlaps(200, 300));
+        assert!(!r.overlaps(0, 100));
+    }
+
+    #[test]
+    fn test_calendar_slots() {
+        let mut cancelled = reservation(0, 100, Some(9));
+        cancelled.status = ReservationStatus::Cancelled;
+        let reservations = [
+            reservation(0, 90, Some(1)),
+            reservation(30, 120, Some(2)),
+            cancelled,
+        ];
+        let slots = calendar_slots(&reservations, 0, 120, 60);
+        assert_eq!(slots.len(), 2);
+        assert_eq!(slots[0].reservations, 2);
+        assert_eq!(slots[0].covers, 8);
+        assert_eq!(slots[0].table_ids, vec![1, 2]);
+        assert_eq!(slots[1].reservations, 2);
+        assert!(calendar_slots(&reservations, 0, 120, 0).is_empty());
+    }
+}
@@ -0,0 +1,115 @@
+//! Sales Facts (报表读模型)
+//!
+//! 由投影服务从订单事件流维护的扁平事实表 (一单 / 一行商品 / 一笔支付一行)，
+//! 与归档存储 (archived_order) 解耦。新报表直接查询事实表，无需改动命令管线。
+
+use serde::{Deserialize, Serialize};
+
+/// One finished order (COMPLETED / VOID)
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+#[cfg_attr(feature = "db", derive(sqlx::FromRow))]
+pub struct SalesFact {
+    pub order_id: i64,
+    pub receipt_number: String,
+    /// COMPLETED | VOID
+    pub status: String,
+    pub is_retail: bool,
+    /// DINE_IN | TAKEOUT
+    pub service_type: Option<String>,
+    pub zone_name: Option<String>,
+    pub table_name: Option<String>,
+    pub guest_count: i32,
+    pub member_id: Option<i64>,
+    /// Sum of item quantities
+    pub item_count: i32,
+    pub original_total: f64,
+    pub discount: f64,
+    pub surcharge: f64,
+    pub tax: f64,
+    pub total: f64,
+    pub paid_amount: f64,
+    /// Operator of the terminal event (who closed / voided the order)
+    pub operator_id: i64,
+    pub operator_name: String,
+    pub start_time: i64,
+    pub end_time: i64,
+    /// Local hour of end_time (0-23, store timezone)
+    pub hour: i32,
+}
+
+/// One order line of a finished order
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+#[cfg_attr(feature = "db", derive(sqlx::FromRow))]
+pub struct ItemFact {
+    pub order_id: i64,
+    pub instance_id: String,
+    pub product_id: i64,
+    pub name: String,
+    pub spec_name: Option<String>,
+    pub category_id: Option<i64>,
+    pub category_name: Option<String>,
+    pub quantity: i32,
+    pub unit_price: f64,
+    pub line_total: f64,
+    pub tax: f64,
+    pub tax_rate: i32,
+    pub is_comped: bool,
+    pub end_time: i64,
+}
+
+/// One settled (non-cancelled) payment of a finished order
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+#[cfg_attr(feature = "db", derive(sqlx::FromRow))]
+pub struct PaymentFact {
+    pub payment_id: i64,
+    pub order_id: i64,
+    pub method: String,
+    pub amount: f64,
+    pub paid_at: i64,
+    pub end_time: i64,
+}
+
+/// Completed orders per local hour
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+#[cfg_attr(feature = "db", derive(sqlx::FromRow))]
+pub struct HourlySales {
+    pub hour: i32,
+    pub orders: i64,
+    pub guests: i64,
+    pub revenue: f64,
+}
+
+/// Product sales (completed orders)
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+#[cfg_attr(feature = "db", derive(sqlx::FromRow))]
+pub struct ItemSales {
+    pub product_id: i64,
+    pub name: String,
+    pub category_name: Option<String>,
+    pub quantity: i64,
+    /// Comped (gifted) units, included in quantity
+    pub comped_quantity: i64,
+    pub revenue: f64,
+}
+
+/// Payment method mix (completed orders)
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+#[cfg_attr(feature = "db", derive(sqlx::FromRow))]
+pub struct PaymentMethodSales {
+    pub method: String,
+    pub count: i64,
+    pub amount: f64,
+}
+
+/// Sales facts report for a period (end_time in `[from, to)`)
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct SalesFactsReport {
+    pub from: i64,
+    pub to: i64,
+    pub completed_orders: i64,
+    pub void_orders: i64,
+    pub revenue: f64,
+    pub hourly: Vec<HourlySales>,
+    pub items: Vec<ItemSales>,
+    pub payments: Vec<PaymentMethodSales>,
+}
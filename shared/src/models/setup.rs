@@ -0,0 +1,83 @@
+//! Setup Wizard Model (首次安装向导)
+//!
+//! Initial configuration is tracked as a fixed list of steps. Mandatory steps
+//! must be confirmed before the store can open orders; optional steps may be
+//! skipped (e.g. a retail counter without tables or printers).
+
+use serde::{Deserialize, Serialize};
+
+/// Setup step
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+pub enum SetupStepKind {
+    /// Store name, address and tax ID (NIF)
+    StoreInfo,
+    /// Tax mode and product tax rates
+    TaxRates,
+    /// Zones and tables (optional: retail-only stores)
+    Tables,
+    /// First personal admin account (replaces the seeded `admin` login)
+    Admin,
+    /// Receipt / kitchen printer (optional)
+    Printer,
+}
+
+impl SetupStepKind {
+    /// Wizard order
+    pub const ALL: &'static [SetupStepKind] = &[
+        Self::StoreInfo,
+        Self::TaxRates,
+        Self::Tables,
+        Self::Admin,
+        Self::Printer,
+    ];
+
+    pub fn as_str(self) -> &'static str {
+        match self {
+            Self::StoreInfo => "STORE_INFO",
+            Self::TaxRates => "TAX_RATES",
+            Self::Tables => "TABLES",
+            Self::Admin => "ADMIN",
+            Self::Printer => "PRINTER",
+        }
+    }
+
+    pub fn parse(s: &str) -> Option<Self> {
+        Self::ALL.iter().copied().find(|step| step.as_str() == s)
+    }
+
+    /// Mandatory steps block new orders until confirmed
+    pub fn is_mandatory(self) -> bool {
+        matches!(self, Self::StoreInfo | Self::TaxRates | Self::Admin)
+    }
+}
+
+/// Step state
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct SetupStep {
+    pub step: SetupStepKind,
+    pub mandatory: bool,
+    /// Confirmed (or skipped)
+    pub completed: bool,
+    pub skipped: bool,
+    pub completed_at: Option<i64>,
+    /// Current validation problems (must be empty to confirm the step)
+    pub issues: Vec<String>,
+}
+
+/// Wizard state (`GET /api/setup/steps`)
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct SetupStatus {
+    /// All mandatory steps confirmed
+    pub complete: bool,
+    pub steps: Vec<SetupStep>,
+}
+
+/// Confirm or skip a step (`POST /api/setup/steps`)
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SetupStepSubmit {
+    pub step: SetupStepKind,
+    /// Skip an optional step instead of confirming it
+    #[serde(default)]
+    pub skip: bool,
+}
@@ -52,10 +52,17 @@ pub struct ShiftCreate {
 }
 
 /// Close shift payload (normal close with cash counting)
+///
+/// With `cash_count`, the server computes `actual_cash` from the counted
+/// denominations (see [`CashCountSummary`](super::CashCountSummary)) and
+/// ignores the submitted amount.
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct ShiftClose {
+    #[serde(default)]
     pub actual_cash: f64,
     pub note: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub cash_count: Option<Vec<super::CashCountLine>>,
 }
 
 /// Force close shift payload (abnormal close without cash counting)
@@ -70,3 +77,161 @@ pub struct ShiftUpdate {
     pub starting_cash: Option<f64>,
     pub note: Option<String>,
 }
+
+/// Break type (paid breaks count as worked time)
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+#[cfg_attr(feature = "db", derive(sqlx::Type))]
+#[cfg_attr(feature = "db", sqlx(rename_all = "SCREAMING_SNAKE_CASE"))]
+pub enum BreakType {
+    Paid,
+    #[default]
+    Unpaid,
+}
+
+/// Break within a shift
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "db", derive(sqlx::FromRow))]
+pub struct ShiftBreak {
+    pub id: i64,
+    pub shift_id: i64,
+    pub break_type: BreakType,
+    /// Break start time (Unix timestamp millis)
+    pub start_time: i64,
+    /// Break end time (Unix timestamp millis), null while on break
+    pub end_time: Option<i64>,
+    pub note: Option<String>,
+}
+
+/// Start break payload
+#[derive(Debug, Clone, Serialize, Deserialize, Default)]
+pub struct ShiftBreakStart {
+    #[serde(default)]
+    pub break_type: BreakType,
+    pub note: Option<String>,
+}
+
+/// Continuous work after which a break is required (labor compliance, 6h)
+pub const BREAK_REQUIRED_AFTER_MS: i64 = 6 * 60 * 60 * 1000;
+
+/// Break compliance status of a shift
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ShiftBreakStatus {
+    pub shift_id: i64,
+    pub breaks: Vec<ShiftBreak>,
+    /// Break in progress
+    pub on_break: Option<ShiftBreak>,
+    /// Start of the current uninterrupted work period (shift start or last break end)
+    pub worked_since: i64,
+    /// Worked continuously for [`BREAK_REQUIRED_AFTER_MS`] without a break
+    pub break_due: bool,
+    pub paid_break_ms: i64,
+    pub unpaid_break_ms: i64,
+}
+
+impl ShiftBreakStatus {
+    /// Evaluate breaks of a shift at `now` (open breaks count up to `now`)
+    pub fn evaluate(shift: &Shift, breaks: Vec<ShiftBreak>, now: i64) -> Self {
+        let (paid_break_ms, unpaid_break_ms) = break_totals(&breaks, now);
+        let on_break = breaks.iter().find(|b| b.end_time.is_none()).cloned();
+        let worked_since = breaks
+            .iter()
+            .filter_map(|b| b.end_time)
+            .max()
+            .unwrap_or(shift.start_time)
+            .max(shift.start_time);
+        let break_due = shift.status == ShiftStatus::Open
+            && on_break.is_none()
+            && now - worked_since >= BREAK_REQUIRED_AFTER_MS;
+        Self {
+            shift_id: shift.id,
+            breaks,
+            on_break,
+            worked_since,
+            break_due,
+            paid_break_ms,
+            unpaid_break_ms,
+        }
+    }
+}
+
+/// Total (paid, unpaid) break time in millis; open breaks count up to `now`
+pub fn break_totals(breaks: &[ShiftBreak], now: i64) -> (i64, i64) {
+    breaks.iter().fold((0, 0), |(paid, unpaid), b| {
+        let ms = (b.end_time.unwrap_or(now) - b.start_time).max(0);
+        match b.break_type {
+            BreakType::Paid => (paid + ms, unpaid),
+            BreakType::Unpaid => (paid, unpaid + ms),
+        }
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const HOUR: i64 = 60 * 60 * 1000;
+
+    fn open_shift(start_time: i64) -> Shift {
+        Shift {
+            id: 1,
+            operator_id: 1,
+            operator_name: "Ana".to_string(),
+            status: ShiftStatus::Open,
+            start_time,
+            end_time: None,
+            starting_cash: 0.0,
+            expected_cash: 0.0,
+            actual_cash: None,
+            cash_variance: None,
+            abnormal_close: false,
+            last_active_at: None,
+            note: None,
+            created_at: None,
+            updated_at: None,
+        }
+    }
+
+    fn brk(break_type: BreakType, start_time: i64, end_time: Option<i64>) -> ShiftBreak {
+        ShiftBreak {
+            id: start_time,
+            shift_id: 1,
+            break_type,
+            start_time,
+            end_time,
+            note: None,
+        }
+    }
+
+    #[test]
+    fn test_break_due_after_six_hours_without_break() {
+        let shift = open_shift(0);
+        assert!(!ShiftBreakStatus::evaluate(&shift, vec![], 6 * HOUR - 1).break_due);
+        assert!(ShiftBreakStatus::evaluate(&shift, vec![], 6 * HOUR).break_due);
+
+        // A break resets the uninterrupted work period
+        let breaks = vec![brk(BreakType::Unpaid, 4 * HOUR, Some(4 * HOUR + HOUR / 2))];
+        let status = ShiftBreakStatus::evaluate(&shift, breaks, 7 * HOUR);
+        assert!(!status.break_due);
+        assert_eq!(status.worked_since, 4 * HOUR + HOUR / 2);
+
+        // Not due while on break
+        let breaks = vec![brk(BreakType::Paid, 6 * HOUR, None)];
+        let status = ShiftBreakStatus::evaluate(&shift, breaks, 7 * HOUR);
+        assert!(!status.break_due);
+        assert!(status.on_break.is_some());
+    }
+
+    #[test]
+    fn test_break_totals_by_type() {
+        let breaks = vec![
+            brk(BreakType::Paid, 0, Some(HOUR / 4)),
+            brk(BreakType::Unpaid, HOUR, Some(2 * HOUR)),
+            brk(BreakType::Unpaid, 3 * HOUR, None),
+        ];
+        assert_eq!(
+            break_totals(&breaks, 3 * HOUR + HOUR / 2),
+            (HOUR / 4, HOUR + HOUR / 2)
+        );
+    }
+}
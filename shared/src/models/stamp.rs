@@ -39,6 +39,19 @@ pub struct StampActivity {
     pub designated_product_id: Option<i64>,
     pub is_cyclic: bool,
     pub is_active: bool,
+    /// Campaign start (Unix millis, None = no start bound)
+    pub valid_from: Option<i64>,
+    /// Campaign end (Unix millis, None = open-ended)
+    pub valid_until: Option<i64>,
+    /// Active days of week (JSON array: 0=Sunday..6=Saturday, None = every day)
+    #[cfg_attr(feature = "db", sqlx(json))]
+    pub active_days: Option<Vec<u8>>,
+    /// Active start time (HH:MM format, local time)
+    pub active_start_time: Option<String>,
+    /// Active end time (HH:MM format, supports cross-midnight)
+    pub active_end_time: Option<String>,
+    /// Max stamps one member can earn per business day (None = unlimited)
+    pub max_stamps_per_day: Option<i32>,
     pub created_at: i64,
     pub updated_at: i64,
 }
@@ -52,6 +65,18 @@ pub struct StampActivityCreate {
     pub reward_strategy: Option<RewardStrategy>,
     pub designated_product_id: Option<i64>,
     pub is_cyclic: Option<bool>,
+    #[serde(default)]
+    pub valid_from: Option<i64>,
+    #[serde(default)]
+    pub valid_until: Option<i64>,
+    #[serde(default)]
+    pub active_days: Option<Vec<u8>>,
+    #[serde(default)]
+    pub active_start_time: Option<String>,
+    #[serde(default)]
+    pub active_end_time: Option<String>,
+    #[serde(default)]
+    pub max_stamps_per_day: Option<i32>,
     pub stamp_targets: Vec<StampTargetInput>,
     pub reward_targets: Vec<StampTargetInput>,
 }
@@ -66,6 +91,18 @@ pub struct StampActivityUpdate {
     pub designated_product_id: Option<i64>,
     pub is_cyclic: Option<bool>,
     pub is_active: Option<bool>,
+    #[serde(default)]
+    pub valid_from: Option<i64>,
+    #[serde(default)]
+    pub valid_until: Option<i64>,
+    #[serde(default)]
+    pub active_days: Option<Vec<u8>>,
+    #[serde(default)]
+    pub active_start_time: Option<String>,
+    #[serde(default)]
+    pub active_end_time: Option<String>,
+    #[serde(default)]
+    pub max_stamps_per_day: Option<i32>,
     pub stamp_targets: Option<Vec<StampTargetInput>>,
     pub reward_targets: Option<Vec<StampTargetInput>>,
 }
@@ -134,3 +171,22 @@ pub struct MemberStampProgressDetail {
     pub reward_quantity: i32,
     pub designated_product_id: Option<i64>,
 }
+
+/// Stamp campaign performance over a period (marketing report)
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "db", derive(sqlx::FromRow))]
+pub struct StampCampaignStats {
+    pub stamp_activity_id: i64,
+    pub stamp_activity_name: String,
+    pub marketing_group_id: i64,
+    pub is_active: bool,
+    /// Distinct members who earned stamps
+    pub members_earning: i64,
+    /// Orders that earned stamps
+    pub earning_orders: i64,
+    pub stamps_earned: i64,
+    /// Stamps withheld by the daily cap
+    pub stamps_capped: i64,
+    pub redemptions: i64,
+    pub members_redeeming: i64,
+}
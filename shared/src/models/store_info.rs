@@ -2,6 +2,85 @@
 
 use serde::{Deserialize, Serialize};
 
+/// 定价含税模式
+///
+/// - `Inclusive`: 菜单价含税 (西班牙 IVA)，税额从行金额中反算
+/// - `Exclusive`: 菜单价不含税，税额在行金额之上叠加
+#[derive(Debug, Clone, Copy, Default, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+pub enum TaxMode {
+    #[default]
+    Inclusive,
+    Exclusive,
+}
+
+impl TaxMode {
+    /// 数据库 TEXT 列表示 (SQLite / PostgreSQL 通用)
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            TaxMode::Inclusive => "INCLUSIVE",
+            TaxMode::Exclusive => "EXCLUSIVE",
+        }
+    }
+}
+
+impl TryFrom<String> for TaxMode {
+    type Error = String;
+
+    fn try_from(value: String) -> Result<Self, Self::Error> {
+        match value.as_str() {
+            "INCLUSIVE" => Ok(TaxMode::Inclusive),
+            "EXCLUSIVE" => Ok(TaxMode::Exclusive),
+            other => Err(format!("invalid tax_mode: {other}")),
+        }
+    }
+}
+
+/// 跨营业日未结订单处理策略 (营业日分界时仍未结账的订单)
+///
+/// - `Block`: 阻止前一营业日日结，直到订单结清
+/// - `Transfer`: 自动转入新营业日 (记录 ORDER_CARRIED_OVER 事件)
+/// - `ForceComplete`: 已付清的订单自动结单，未付清的转入新营业日
+#[derive(Debug, Clone, Copy, Default, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+pub enum CarryOverPolicy {
+    Block,
+    #[default]
+    Transfer,
+    ForceComplete,
+}
+
+impl CarryOverPolicy {
+    /// 数据库 TEXT 列表示
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            CarryOverPolicy::Block => "BLOCK",
+            CarryOverPolicy::Transfer => "TRANSFER",
+            CarryOverPolicy::ForceComplete => "FORCE_COMPLETE",
+        }
+    }
+}
+
+impl TryFrom<String> for CarryOverPolicy {
+    type Error = String;
+
+    fn try_from(value: String) -> Result<Self, Self::Error> {
+        match value.as_str() {
+            "BLOCK" => Ok(CarryOverPolicy::Block),
+            "TRANSFER" => Ok(CarryOverPolicy::Transfer),
+            "FORCE_COMPLETE" => Ok(CarryOverPolicy::ForceComplete),
+            other => Err(format!("invalid carry_over_policy: {other}")),
+        }
+    }
+}
+
+/// 默认单笔现金找零上限 (€200)
+pub const DEFAULT_MAX_CASH_CHANGE: f64 = 200.0;
+
+fn default_max_cash_change() -> f64 {
+    DEFAULT_MAX_CASH_CHANGE
+}
+
 /// Store information entity (singleton per tenant)
 #[derive(Debug, Clone, Default, Serialize, Deserialize)]
 #[cfg_attr(feature = "db", derive(sqlx::FromRow))]
@@ -35,6 +114,37 @@ pub struct StoreInfo {
     pub receipt_header: Option<String>,
     /// 收据页脚自定义文本
     pub receipt_footer: Option<String>,
+    /// 定价含税模式 (新订单开台时生效)
+    #[serde(default)]
+    #[cfg_attr(feature = "db", sqlx(try_from = "String"))]
+    pub tax_mode: TaxMode,
+    /// 启用支付方式附加费 (刷卡附加费等，仅在法规允许的地区)
+    #[serde(default)]
+    #[cfg_attr(feature = "db", sqlx(default))]
+    pub payment_surcharge_enabled: bool,
+    /// 附加费法律声明 (收据上有附加费时打印)
+    #[serde(default)]
+    #[cfg_attr(feature = "db", sqlx(default))]
+    pub payment_surcharge_disclaimer: Option<String>,
+    /// 存档每张结账收据的打印原文 (ESC/POS / PDF，见 [`crate::models::ReceiptArtifact`])
+    #[serde(default)]
+    #[cfg_attr(feature = "db", sqlx(default))]
+    pub receipt_archive_enabled: bool,
+    /// 存档收据同步到云端
+    #[serde(default)]
+    #[cfg_attr(feature = "db", sqlx(default))]
+    pub receipt_archive_cloud: bool,
+    /// 跨营业日未结订单处理策略
+    #[serde(default)]
+    #[cfg_attr(feature = "db", sqlx(try_from = "String"))]
+    pub carry_over_policy: CarryOverPolicy,
+    /// 营业日分界自动生成日报后打印汇总小票的打印目的地 (None = 不打印)
+    #[serde(default)]
+    #[cfg_attr(feature = "db", sqlx(default))]
+    pub daily_report_print_destination_id: Option<i64>,
+    /// 单笔现金支付允许从钱箱找零的上限
+    #[serde(default = "default_max_cash_change")]
+    pub max_cash_change: f64,
     pub created_at: Option<i64>,
     pub updated_at: Option<i64>,
 }
@@ -57,4 +167,13 @@ pub struct StoreInfoUpdate {
     pub receipt_locale: Option<String>,
     pub receipt_header: Option<String>,
     pub receipt_footer: Option<String>,
+    pub tax_mode: Option<TaxMode>,
+    pub payment_surcharge_enabled: Option<bool>,
+    pub payment_surcharge_disclaimer: Option<String>,
+    pub receipt_archive_enabled: Option<bool>,
+    pub receipt_archive_cloud: Option<bool>,
+    pub carry_over_policy: Option<CarryOverPolicy>,
+    /// 日报自动打印目的地 (`Some(0)` 关闭自动打印)
+    pub daily_report_print_destination_id: Option<i64>,
+    pub max_cash_change: Option<f64>,
 }
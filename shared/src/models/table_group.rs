@@ -0,0 +1,84 @@
+//! Table Group Model (拼桌)
+//!
+//! Several physical tables joined into one logical table for a large party.
+//! The order is bound to the host table; the other members are blocked from
+//! opening orders until the group is split.
+
+use serde::{Deserialize, Serialize};
+
+use super::DiningTable;
+
+/// Joined table group
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct TableGroup {
+    pub id: i64,
+    /// Table the order is bound to
+    pub host_table_id: i64,
+    pub zone_id: i64,
+    /// Display name, e.g. "5+6"
+    pub name: String,
+    /// Member tables in join order (host first)
+    pub table_ids: Vec<i64>,
+    /// Combined seating capacity
+    pub capacity: i32,
+    pub created_by_id: i64,
+    pub created_by_name: String,
+    pub created_at: i64,
+    pub updated_at: i64,
+}
+
+impl TableGroup {
+    pub fn contains(&self, table_id: i64) -> bool {
+        self.table_ids.contains(&table_id)
+    }
+}
+
+/// Join tables payload (tables are added to the host table's group)
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct TableJoin {
+    pub table_ids: Vec<i64>,
+}
+
+/// Split tables payload
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct TableSplit {
+    /// Tables to detach; empty = dissolve the whole group
+    #[serde(default)]
+    pub table_ids: Vec<i64>,
+}
+
+/// Logical table name: member names joined with "+" (host first)
+pub fn table_group_name(tables: &[DiningTable]) -> String {
+    tables
+        .iter()
+        .map(|t| t.name.as_str())
+        .collect::<Vec<_>>()
+        .join("+")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn table(id: i64, name: &str) -> DiningTable {
+        DiningTable {
+            id,
+            name: name.to_string(),
+            zone_id: 1,
+            capacity: 4,
+            is_active: true,
+        }
+    }
+
+    #[test]
+    fn test_table_group_name_keeps_join_order() {
+        let tables = vec![table(6, "6"), table(5, "5"), table(7, "7")];
+        assert_eq!(table_group_name(&tables), "6+5+7");
+    }
+
+    #[test]
+    fn test_split_payload_defaults_to_dissolve() {
+        let split: TableSplit = serde_json::from_str("{}").unwrap();
+        assert!(split.table_ids.is_empty());
+    }
+}
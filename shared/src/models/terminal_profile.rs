@@ -0,0 +1,32 @@
+//! Terminal Profile Model (终端漫游配置)
+//!
+//! Device-local preferences (default zone, printer mapping, layout, ...) stored
+//! on the edge-server and keyed by the terminal's client certificate identity,
+//! so a replacement device issued the same identity restores its configuration
+//! at connect. The edge treats `settings` as an opaque JSON object owned by the
+//! terminal application.
+
+use serde::{Deserialize, Serialize};
+
+/// Maximum serialized size of a profile's settings (bytes)
+pub const MAX_TERMINAL_PROFILE_SIZE: usize = 64 * 1024;
+
+/// Stored terminal profile
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+#[cfg_attr(feature = "db", derive(sqlx::FromRow))]
+pub struct TerminalProfile {
+    /// Client certificate identity (client_name / CN)
+    pub identity: String,
+    /// Terminal preferences (JSON object, schema owned by the client)
+    #[cfg_attr(feature = "db", sqlx(json))]
+    pub settings: serde_json::Map<String, serde_json::Value>,
+    /// Incremented on every save
+    pub version: i64,
+    pub updated_at: i64,
+}
+
+/// Save terminal profile payload
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct TerminalProfileUpdate {
+    pub settings: serde_json::Map<String, serde_json::Value>,
+}
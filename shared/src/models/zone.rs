@@ -26,3 +26,24 @@ pub struct ZoneUpdate {
     pub description: Option<String>,
     pub is_active: Option<bool>,
 }
+
+/// Zone-level product override (区域商品覆盖：露台加价、吧台专属商品等)
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "db", derive(sqlx::FromRow))]
+pub struct ZoneProductOverride {
+    pub id: i64,
+    pub zone_id: i64,
+    pub product_id: i64,
+    /// Override price for the default spec (None = keep catalog price)
+    pub price: Option<f64>,
+    /// false = product cannot be ordered in this zone
+    pub is_available: bool,
+    pub updated_at: i64,
+}
+
+/// Upsert zone product override payload
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ZoneProductOverrideUpsert {
+    pub price: Option<f64>,
+    pub is_available: bool,
+}
@@ -74,6 +74,7 @@ mod tests {
             adjustment_value: 10.0,
             is_stackable: true,
             is_exclusive: false,
+            priority: 0,
             valid_from: None,
             valid_until: None,
             active_days: None,
@@ -9,11 +9,12 @@ use super::applied_rule::AppliedRule;
 use super::event::{EventPayload, MgItemDiscount, OrderEventType};
 use super::snapshot::OrderStatus;
 use super::types::{
-    CartItemSnapshot, CompRecord, ItemChanges, ItemModificationResult, ItemOption, LossReason,
-    PaymentRecord, PaymentSummaryItem, ServiceType, SpecificationInfo, SplitItem, SplitType,
-    StampRedemptionState, VoidType,
+    AppliedCoupon, CartItemSnapshot, CompRecord, ItemChanges, ItemModificationResult, ItemOption,
+    LossReason, PaymentRecord, PaymentSummaryItem, ServiceType, SpecificationInfo, SplitItem,
+    SplitType, StampRedemptionState, VoidType,
 };
 use crate::models::price_rule::{AdjustmentType, ProductScope, RuleType};
+use crate::models::store_info::TaxMode;
 
 /// Trait for producing deterministic binary representations.
 ///
@@ -183,6 +184,15 @@ pub fn write_btreemap_str_i32(buf: &mut Vec<u8>, map: &std::collections::BTreeMa
     }
 }
 
+#[inline]
+pub fn write_btreemap_str_str(buf: &mut Vec<u8>, map: &std::collections::BTreeMap<String, String>) {
+    write_u32(buf, map.len() as u32);
+    for (k, v) in map {
+        write_str(buf, k);
+        write_str(buf, v);
+    }
+}
+
 impl CanonicalHash for String {
     fn canonical_bytes(&self, buf: &mut Vec<u8>) {
         write_str(buf, self);
@@ -226,18 +236,26 @@ impl CanonicalHash for OrderEventType {
             OrderEventType::OrderMovedOut => write_tag(buf, b"ORDER_MOVED_OUT"),
             OrderEventType::OrderMerged => write_tag(buf, b"ORDER_MERGED"),
             OrderEventType::OrderMergedOut => write_tag(buf, b"ORDER_MERGED_OUT"),
+            OrderEventType::ItemsTransferredOut => write_tag(buf, b"ITEMS_TRANSFERRED_OUT"),
+            OrderEventType::ItemsTransferredIn => write_tag(buf, b"ITEMS_TRANSFERRED_IN"),
             OrderEventType::TableReassigned => write_tag(buf, b"TABLE_REASSIGNED"),
             OrderEventType::OrderInfoUpdated => write_tag(buf, b"ORDER_INFO_UPDATED"),
             OrderEventType::RuleSkipToggled => write_tag(buf, b"RULE_SKIP_TOGGLED"),
             OrderEventType::OrderDiscountApplied => write_tag(buf, b"ORDER_DISCOUNT_APPLIED"),
             OrderEventType::OrderSurchargeApplied => write_tag(buf, b"ORDER_SURCHARGE_APPLIED"),
+            OrderEventType::ItemsHeld => write_tag(buf, b"ITEMS_HELD"),
+            OrderEventType::CourseFired => write_tag(buf, b"COURSE_FIRED"),
             OrderEventType::OrderNoteAdded => write_tag(buf, b"ORDER_NOTE_ADDED"),
+            OrderEventType::OrderMetadataSet => write_tag(buf, b"ORDER_METADATA_SET"),
+            OrderEventType::OrderCarriedOver => write_tag(buf, b"ORDER_CARRIED_OVER"),
             OrderEventType::MemberLinked => write_tag(buf, b"MEMBER_LINKED"),
             OrderEventType::MemberUnlinked => write_tag(buf, b"MEMBER_UNLINKED"),
             OrderEventType::StampRedeemed => write_tag(buf, b"STAMP_REDEEMED"),
             OrderEventType::StampRedemptionCancelled => {
                 write_tag(buf, b"STAMP_REDEMPTION_CANCELLED")
             }
+            OrderEventType::PointsRedeemed => write_tag(buf, b"POINTS_REDEEMED"),
+            OrderEventType::CouponApplied => write_tag(buf, b"COUPON_APPLIED"),
         }
     }
 }
@@ -270,6 +288,15 @@ impl CanonicalHash for ServiceType {
     }
 }
 
+impl CanonicalHash for TaxMode {
+    fn canonical_bytes(&self, buf: &mut Vec<u8>) {
+        match self {
+            TaxMode::Inclusive => write_tag(buf, b"TAX_INCLUSIVE"),
+            TaxMode::Exclusive => write_tag(buf, b"TAX_EXCLUSIVE"),
+        }
+    }
+}
+
 impl CanonicalHash for SplitType {
     fn canonical_bytes(&self, buf: &mut Vec<u8>) {
         match self {
@@ -443,6 +470,8 @@ impl CanonicalHash for CartItemSnapshot {
         write_opt_i64(buf, self.category_id);
         write_opt_str(buf, &self.category_name);
         write_bool(buf, self.is_comped);
+        write_opt_i32(buf, self.course);
+        write_bool(buf, self.is_held);
     }
 }
 
@@ -460,6 +489,7 @@ impl CanonicalHash for PaymentRecord {
         write_opt_vec(buf, &self.split_items);
         write_opt_i32(buf, self.aa_shares);
         write_opt(buf, &self.split_type);
+        write_opt_str(buf, &self.reference);
     }
 }
 
@@ -487,6 +517,19 @@ impl CanonicalHash for StampRedemptionState {
     }
 }
 
+impl CanonicalHash for AppliedCoupon {
+    fn canonical_bytes(&self, buf: &mut Vec<u8>) {
+        write_i64(buf, self.coupon_id);
+        write_str(buf, &self.code);
+        write_str(buf, &self.name);
+        self.product_scope.canonical_bytes(buf);
+        write_opt_i64(buf, self.target_id);
+        self.adjustment_type.canonical_bytes(buf);
+        write_f64(buf, self.adjustment_value);
+        write_f64(buf, self.amount);
+    }
+}
+
 // ============================================================================
 // EventPayload implementation
 // ============================================================================
@@ -503,6 +546,7 @@ impl CanonicalHash for EventPayload {
                 is_retail,
                 queue_number,
                 receipt_number,
+                tax_mode,
             } => {
                 write_tag(buf, b"TABLE_OPENED");
                 write_sep(buf);
@@ -514,6 +558,7 @@ impl CanonicalHash for EventPayload {
                 write_bool(buf, *is_retail);
                 write_opt_u32(buf, *queue_number);
                 write_str(buf, receipt_number);
+                tax_mode.canonical_bytes(buf);
             }
 
             EventPayload::OrderCompleted {
@@ -521,6 +566,7 @@ impl CanonicalHash for EventPayload {
                 service_type,
                 final_total,
                 payment_summary,
+                fiscal_code,
             } => {
                 write_tag(buf, b"ORDER_COMPLETED");
                 write_sep(buf);
@@ -528,6 +574,7 @@ impl CanonicalHash for EventPayload {
                 write_opt(buf, service_type);
                 write_f64(buf, *final_total);
                 write_vec(buf, payment_summary);
+                write_opt_str(buf, fiscal_code);
             }
 
             EventPayload::OrderVoided {
@@ -641,6 +688,8 @@ impl CanonicalHash for EventPayload {
                 tendered,
                 change,
                 note,
+                reference,
+                surcharge,
             } => {
                 write_tag(buf, b"PAYMENT_ADDED");
                 write_sep(buf);
@@ -650,6 +699,8 @@ impl CanonicalHash for EventPayload {
                 write_opt_f64(buf, *tendered);
                 write_opt_f64(buf, *change);
                 write_opt_str(buf, note);
+                write_opt_str(buf, reference);
+                write_opt_f64(buf, *surcharge);
             }
 
             EventPayload::PaymentCancelled {
@@ -828,6 +879,32 @@ impl CanonicalHash for EventPayload {
                 write_opt_str(buf, authorizer_name);
             }
 
+            EventPayload::ItemsTransferredOut {
+                target_order_id,
+                target_table_name,
+                instance_ids,
+            } => {
+                write_tag(buf, b"ITEMS_TRANSFERRED_OUT");
+                write_sep(buf);
+                write_i64(buf, *target_order_id);
+                write_str(buf, target_table_name);
+                write_vec(buf, instance_ids);
+            }
+
+            EventPayload::ItemsTransferredIn {
+                source_order_id,
+                source_table_name,
+                items,
+                comps,
+            } => {
+                write_tag(buf, b"ITEMS_TRANSFERRED_IN");
+                write_sep(buf);
+                write_i64(buf, *source_order_id);
+                write_str(buf, source_table_name);
+                write_vec(buf, items);
+                write_vec(buf, comps);
+            }
+
             EventPayload::TableReassigned {
                 source_table_id,
                 source_table_name,
@@ -920,6 +997,23 @@ impl CanonicalHash for EventPayload {
                 write_f64(buf, *total);
             }
 
+            EventPayload::ItemsHeld {
+                course,
+                instance_ids,
+            } => {
+                write_tag(buf, b"ITEMS_HELD");
+                write_sep(buf);
+                write_i32(buf, *course);
+                write_vec(buf, instance_ids);
+            }
+
+            EventPayload::CourseFired { course, items } => {
+                write_tag(buf, b"COURSE_FIRED");
+                write_sep(buf);
+                write_i32(buf, *course);
+                write_vec(buf, items);
+            }
+
             EventPayload::OrderNoteAdded {
                 note,
                 previous_note,
@@ -930,6 +1024,25 @@ impl CanonicalHash for EventPayload {
                 write_opt_str(buf, previous_note);
             }
 
+            EventPayload::OrderMetadataSet { changes, previous } => {
+                write_tag(buf, b"ORDER_METADATA_SET");
+                write_sep(buf);
+                write_btreemap_str_str(buf, changes);
+                write_btreemap_str_str(buf, previous);
+            }
+
+            EventPayload::OrderCarriedOver {
+                from_business_date,
+                to_business_date,
+                policy,
+            } => {
+                write_tag(buf, b"ORDER_CARRIED_OVER");
+                write_sep(buf);
+                write_str(buf, from_business_date);
+                write_str(buf, to_business_date);
+                write_str(buf, policy.as_str());
+            }
+
             EventPayload::MemberLinked {
                 member_id,
                 member_name,
@@ -1001,6 +1114,19 @@ impl CanonicalHash for EventPayload {
                 write_bool(buf, *is_comp_existing);
                 write_opt_str(buf, comp_source_instance_id);
             }
+
+            EventPayload::PointsRedeemed { points, amount } => {
+                write_tag(buf, b"POINTS_REDEEMED");
+                write_sep(buf);
+                write_i64(buf, *points);
+                write_f64(buf, *amount);
+            }
+
+            EventPayload::CouponApplied { coupon } => {
+                write_tag(buf, b"COUPON_APPLIED");
+                write_sep(buf);
+                write_opt(buf, coupon);
+            }
         }
     }
 }
@@ -1257,6 +1383,7 @@ pub fn compute_event_chain_hash(event: &super::event::OrderEvent) -> String {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::models::store_info::CarryOverPolicy;
     use sha2::{Digest, Sha256};
     use std::collections::BTreeMap;
 
@@ -1336,6 +1463,8 @@ mod tests {
             category_id: Some(5),
             category_name: Some("Arroces".to_string()),
             is_comped: false,
+            course: None,
+            is_held: false,
         }
     }
 
@@ -1347,6 +1476,7 @@ mod tests {
             tendered: Some(60.0),
             change: Some(10.0),
             note: Some("exact".to_string()),
+            reference: None,
             timestamp: 1700000000000,
             cancelled: false,
             cancel_reason: Some("test".to_string()),
@@ -1357,7 +1487,7 @@ mod tests {
     }
 
     // ========================================================================
-    // Helper: build all 29 EventPayload variants with full data
+    // Helper: build all 37 EventPayload variants with full data
     // ========================================================================
 
     fn build_all_test_variants() -> Vec<(&'static str, EventPayload)> {
@@ -1373,6 +1503,7 @@ mod tests {
                     is_retail: false,
                     queue_number: Some(42),
                     receipt_number: "R-001".to_string(),
+                    tax_mode: TaxMode::Inclusive,
                 },
             ),
             (
@@ -1385,6 +1516,7 @@ mod tests {
                         method: "card".to_string(),
                         amount: 99.99,
                     }],
+                    fiscal_code: None,
                 },
             ),
             (
@@ -1498,6 +1630,8 @@ mod tests {
                     tendered: Some(60.0),
                     change: Some(10.0),
                     note: Some("exact change".to_string()),
+                    reference: None,
+                    surcharge: None,
                 },
             ),
             (
@@ -1617,6 +1751,34 @@ mod tests {
                     authorizer_name: Some("Manager".to_string()),
                 },
             ),
+            (
+                "ItemsTransferredOut",
+                EventPayload::ItemsTransferredOut {
+                    target_order_id: 2002,
+                    target_table_name: "Mesa 5".to_string(),
+                    instance_ids: vec!["inst-1".to_string()],
+                },
+            ),
+            (
+                "ItemsTransferredIn",
+                EventPayload::ItemsTransferredIn {
+                    source_order_id: 1001,
+                    source_table_name: "Mesa 1".to_string(),
+                    items: vec![full_cart_item()],
+                    comps: vec![CompRecord {
+                        comp_id: 7,
+                        instance_id: "inst-1::comp::1".to_string(),
+                        source_instance_id: "inst-1".to_string(),
+                        item_name: "Paella".to_string(),
+                        quantity: 1,
+                        original_price: 12.5,
+                        reason: "birthday".to_string(),
+                        authorizer_id: 99,
+                        authorizer_name: "Manager".to_string(),
+                        timestamp: 1700000000000,
+                    }],
+                },
+            ),
             (
                 "TableReassigned",
                 EventPayload::TableReassigned {
@@ -1673,6 +1835,20 @@ mod tests {
                     total: 110.0,
                 },
             ),
+            (
+                "ItemsHeld",
+                EventPayload::ItemsHeld {
+                    course: 2,
+                    instance_ids: vec!["inst-1".to_string(), "inst-2".to_string()],
+                },
+            ),
+            (
+                "CourseFired",
+                EventPayload::CourseFired {
+                    course: 2,
+                    items: vec![full_cart_item()],
+                },
+            ),
             (
                 "OrderNoteAdded",
                 EventPayload::OrderNoteAdded {
@@ -1680,6 +1856,24 @@ mod tests {
                     previous_note: Some("regular".to_string()),
                 },
             ),
+            (
+                "OrderMetadataSet",
+                EventPayload::OrderMetadataSet {
+                    changes: BTreeMap::from([
+                        ("delivery_order_id".to_string(), "GLV-123".to_string()),
+                        ("room".to_string(), String::new()),
+                    ]),
+                    previous: BTreeMap::from([("room".to_string(), "204".to_string())]),
+                },
+            ),
+            (
+                "OrderCarriedOver",
+                EventPayload::OrderCarriedOver {
+                    from_business_date: "2024-01-22".to_string(),
+                    to_business_date: "2024-01-23".to_string(),
+                    policy: CarryOverPolicy::Transfer,
+                },
+            ),
             (
                 "MemberLinked",
                 EventPayload::MemberLinked {
@@ -1736,11 +1930,33 @@ mod tests {
                     comp_source_instance_id: Some("inst-existing".to_string()),
                 },
             ),
+            (
+                "PointsRedeemed",
+                EventPayload::PointsRedeemed {
+                    points: 300,
+                    amount: 15.0,
+                },
+            ),
+            (
+                "CouponApplied",
+                EventPayload::CouponApplied {
+                    coupon: Some(AppliedCoupon {
+                        coupon_id: 42,
+                        code: "WELCOME10".to_string(),
+                        name: "Welcome".to_string(),
+                        product_scope: ProductScope::Category,
+                        target_id: Some(7),
+                        adjustment_type: AdjustmentType::Percentage,
+                        adjustment_value: 10.0,
+                        amount: 3.5,
+                    }),
+                },
+            ),
         ]
     }
 
     // ========================================================================
-    // A. Roundtrip tests for all 29 variants
+    // A. Roundtrip tests for all 37 variants
     // ========================================================================
 
     fn assert_roundtrip_stable(name: &str, payload: &EventPayload) {
@@ -1760,8 +1976,8 @@ mod tests {
         let variants = build_all_test_variants();
         assert_eq!(
             variants.len(),
-            29,
-            "Must have test data for all 29 EventPayload variants"
+            37,
+            "Must have test data for all 37 EventPayload variants"
         );
         for (name, payload) in &variants {
             assert_roundtrip_stable(name, payload);
@@ -1810,6 +2026,8 @@ mod tests {
             tendered: None,
             change: None,
             note: None,
+            reference: None,
+            surcharge: None,
         };
         let p_neg = EventPayload::PaymentAdded {
             payment_id: 100001,
@@ -1818,6 +2036,8 @@ mod tests {
             tendered: None,
             change: None,
             note: None,
+            reference: None,
+            surcharge: None,
         };
         // After normalization, 0.0 and -0.0 produce the same hash
         assert_eq!(
@@ -1837,6 +2057,8 @@ mod tests {
             tendered: None,
             change: None,
             note: None,
+            reference: None,
+            surcharge: None,
         };
         let hash_before = canonical_sha256(&payload);
         let json = serde_json::to_string(&payload).unwrap();
@@ -1858,6 +2080,8 @@ mod tests {
             tendered: None,
             change: None,
             note: None,
+            reference: None,
+            surcharge: None,
         };
         assert_roundtrip_stable("PaymentAdded-zero", &payload);
     }
@@ -1873,6 +2097,8 @@ mod tests {
                 tendered: None,
                 change: None,
                 note: None,
+                reference: None,
+                surcharge: None,
             };
             assert_roundtrip_stable(&format!("PaymentAdded-{}", amount), &payload);
         }
@@ -1945,11 +2171,12 @@ mod tests {
             is_retail: false,
             queue_number: None,
             receipt_number: "R-20240101-001".to_string(),
+            tax_mode: TaxMode::Inclusive,
         };
 
         let hash = canonical_sha256(&payload);
         assert_eq!(
-            hash, "ba53f6636491acd0a37b209c7b4bfdbac39563a2b6af14ca1b55b2a45ea76d82",
+            hash, "765447457c09853fb904b94ae0b0b2406508e0436963c9b673d36386956ee5d0",
             "Golden hash mismatch — canonical encoding changed!"
         );
     }
@@ -1970,11 +2197,12 @@ mod tests {
                     amount: 35.50,
                 },
             ],
+            fiscal_code: None,
         };
 
         let hash = canonical_sha256(&payload);
         assert_eq!(
-            hash, "a7474f8ed97d2a411866852e77d590cc9e850f7501721af899f952f134f4d586",
+            hash, "454b681251b1bfe0f9f3341e6444f5fecd1cf978619f63846a5324d70200ad97",
             "Golden hash mismatch — canonical encoding changed!"
         );
     }
@@ -1988,11 +2216,13 @@ mod tests {
             tendered: Some(120.0),
             change: Some(20.0),
             note: None,
+            reference: None,
+            surcharge: None,
         };
 
         let hash = canonical_sha256(&payload);
         assert_eq!(
-            hash, "c0170286deed99d5b0cefd5368aac83d4477db493d3a3d95220bb84fd2b910ae",
+            hash, "b4918bf27b8f1d4c3994f7b51831ce26dbed093a97942429b8fe882d6f3bb445",
             "Golden hash mismatch — canonical encoding changed!"
         );
     }
@@ -2026,12 +2256,14 @@ mod tests {
                 category_id: Some(2),
                 category_name: Some("Bebidas".to_string()),
                 is_comped: false,
+                course: None,
+                is_held: false,
             }],
         };
 
         let hash = canonical_sha256(&payload);
         assert_eq!(
-            hash, "464c24c4f2d4b684b7ae3139df97b6abcaac8e79658b72b073fc5e412dd2fe1d",
+            hash, "2915483dff3817db8f89fabb0378b92a27f23384e16ace6c0a948f4e30f2d2e8",
             "Golden hash mismatch — canonical encoding changed!"
         );
     }
@@ -2169,6 +2401,7 @@ mod tests {
             is_retail: false,
             queue_number: None,
             receipt_number: "R001".to_string(),
+            tax_mode: TaxMode::Inclusive,
         };
 
         let h1 = canonical_sha256(&payload);
@@ -2187,6 +2420,7 @@ mod tests {
             is_retail: false,
             queue_number: None,
             receipt_number: "R001".to_string(),
+            tax_mode: TaxMode::Inclusive,
         };
         let p2 = EventPayload::TableOpened {
             table_id: Some(2),
@@ -2197,6 +2431,7 @@ mod tests {
             is_retail: false,
             queue_number: None,
             receipt_number: "R001".to_string(),
+            tax_mode: TaxMode::Inclusive,
         };
 
         assert_ne!(
@@ -2206,6 +2441,34 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_canonical_held_item_without_course_changes_hash() {
+        let released = full_cart_item();
+        let mut held = full_cart_item();
+        held.is_held = true;
+        assert_ne!(canonical_sha256(&released), canonical_sha256(&held));
+    }
+
+    #[test]
+    fn test_canonical_tax_mode_changes_hash() {
+        let opened = |tax_mode| EventPayload::TableOpened {
+            table_id: Some(1),
+            table_name: Some("T1".to_string()),
+            zone_id: None,
+            zone_name: None,
+            guest_count: 2,
+            is_retail: false,
+            queue_number: None,
+            receipt_number: "R001".to_string(),
+            tax_mode,
+        };
+
+        assert_ne!(
+            canonical_sha256(&opened(TaxMode::Inclusive)),
+            canonical_sha256(&opened(TaxMode::Exclusive))
+        );
+    }
+
     #[test]
     fn test_canonical_none_vs_some_different() {
         let p_none = EventPayload::PaymentAdded {
@@ -2215,6 +2478,8 @@ mod tests {
             tendered: None,
             change: None,
             note: None,
+            reference: None,
+            surcharge: None,
         };
         let p_some = EventPayload::PaymentAdded {
             payment_id: 100001,
@@ -2223,6 +2488,8 @@ mod tests {
             tendered: Some(50.0),
             change: Some(0.0),
             note: None,
+            reference: None,
+            surcharge: None,
         };
 
         assert_ne!(
@@ -2232,6 +2499,44 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_canonical_payment_reference() {
+        let without = EventPayload::PaymentAdded {
+            payment_id: 100001,
+            method: "CARD".to_string(),
+            amount: 50.0,
+            tendered: None,
+            change: None,
+            note: None,
+            reference: None,
+            surcharge: None,
+        };
+        let with = EventPayload::PaymentAdded {
+            payment_id: 100001,
+            method: "CARD".to_string(),
+            amount: 50.0,
+            tendered: None,
+            change: None,
+            note: None,
+            reference: Some("4242".to_string()),
+            surcharge: None,
+        };
+
+        assert_ne!(canonical_sha256(&without), canonical_sha256(&with));
+
+        let surcharged = EventPayload::PaymentAdded {
+            payment_id: 100001,
+            method: "CARD".to_string(),
+            amount: 50.75,
+            tendered: None,
+            change: None,
+            note: None,
+            reference: Some("4242".to_string()),
+            surcharge: Some(0.75),
+        };
+        assert_ne!(canonical_sha256(&with), canonical_sha256(&surcharged));
+    }
+
     #[test]
     fn test_canonical_all_event_types_covered() {
         let all_types = [
@@ -2254,16 +2559,24 @@ mod tests {
             OrderEventType::OrderMovedOut,
             OrderEventType::OrderMerged,
             OrderEventType::OrderMergedOut,
+            OrderEventType::ItemsTransferredOut,
+            OrderEventType::ItemsTransferredIn,
             OrderEventType::TableReassigned,
             OrderEventType::OrderInfoUpdated,
             OrderEventType::RuleSkipToggled,
             OrderEventType::OrderDiscountApplied,
             OrderEventType::OrderSurchargeApplied,
+            OrderEventType::ItemsHeld,
+            OrderEventType::CourseFired,
             OrderEventType::OrderNoteAdded,
+            OrderEventType::OrderMetadataSet,
+            OrderEventType::OrderCarriedOver,
             OrderEventType::MemberLinked,
             OrderEventType::MemberUnlinked,
             OrderEventType::StampRedeemed,
             OrderEventType::StampRedemptionCancelled,
+            OrderEventType::PointsRedeemed,
+            OrderEventType::CouponApplied,
         ];
 
         let mut hashes = std::collections::HashSet::new();
@@ -2278,8 +2591,8 @@ mod tests {
 
         assert_eq!(
             hashes.len(),
-            29,
-            "Must cover all 29 OrderEventType variants"
+            37,
+            "Must cover all 37 OrderEventType variants"
         );
     }
 
@@ -2338,6 +2651,7 @@ mod tests {
                 is_retail: false,
                 queue_number: None,
                 receipt_number: "R-001".to_string(),
+                tax_mode: TaxMode::Inclusive,
             },
             OrderEventType::TableOpened,
         );
@@ -2376,6 +2690,8 @@ mod tests {
                 tendered: Some(60.0),
                 change: Some(10.0),
                 note: None,
+                reference: None,
+                surcharge: None,
             },
             OrderEventType::PaymentAdded,
         );
@@ -2402,6 +2718,7 @@ mod tests {
                 is_retail: false,
                 queue_number: None,
                 receipt_number: "R-20240101-001".to_string(),
+                tax_mode: TaxMode::Inclusive,
             },
             OrderEventType::TableOpened,
         );
@@ -2414,7 +2731,7 @@ mod tests {
         );
         // Pin the golden value
         assert_eq!(
-            hash, "dca1c8432ea2b59169e4dc6ee64c1daff842535751c8045fad0a17c760996a71",
+            hash, "ea9c0e7403ae8bfe5e14e4e6bc1d308334b7eb089258e12c5231f16304c2e0b8",
             "OrderEvent golden hash changed — canonical encoding broke!"
         );
     }
@@ -3,6 +3,7 @@
 use super::types::{
     CartItemInput, ItemChanges, LossReason, PaymentInput, ServiceType, SplitItem, VoidType,
 };
+use crate::models::store_info::CarryOverPolicy;
 use serde::{Deserialize, Serialize};
 
 /// Order command wrapper
@@ -18,6 +19,10 @@ pub struct OrderCommand {
     pub operator_name: String,
     /// Command payload
     pub payload: OrderCommandPayload,
+    /// Optimistic concurrency: order version (`last_sequence`) the client edited against.
+    /// None = no check (last write wins)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub expected_version: Option<u64>,
 }
 
 /// Command payload variants
@@ -196,6 +201,13 @@ pub enum OrderCommandPayload {
         authorizer_name: Option<String>,
     },
 
+    /// Transfer selected items to another order (partial move)
+    TransferItems {
+        source_order_id: i64,
+        target_order_id: i64,
+        instance_ids: Vec<String>,
+    },
+
     // ========== Other Operations ==========
     /// Update order info (receipt_number is immutable - set at OpenTable)
     UpdateOrderInfo {
@@ -272,6 +284,18 @@ pub enum OrderCommandPayload {
         authorizer_name: Option<String>,
     },
 
+    // ========== Courses ==========
+    /// Move held items into a course (仅未起菜的商品)
+    HoldItems {
+        order_id: i64,
+        /// 目标道次 (从 1 开始)
+        course: i32,
+        instance_ids: Vec<String>,
+    },
+
+    /// Fire a course: send its held items to the kitchen
+    FireCourse { order_id: i64, course: i32 },
+
     // ========== Order Note ==========
     /// Add or clear order-level note (覆盖式，空字符串 = 清除)
     AddOrderNote {
@@ -280,6 +304,25 @@ pub enum OrderCommandPayload {
         note: String,
     },
 
+    // ========== Business Day ==========
+    /// Mark an order left open across the business-day cutoff (系统在营业日分界时发出)
+    CarryOverOrder {
+        order_id: i64,
+        /// 订单原所属营业日 (YYYY-MM-DD)
+        from_business_date: String,
+        /// 转入的营业日 (YYYY-MM-DD)
+        to_business_date: String,
+        policy: CarryOverPolicy,
+    },
+
+    // ========== Order Metadata ==========
+    /// Set external references on the order (合并式，空值 = 删除该键)
+    SetOrderMetadata {
+        order_id: i64,
+        /// 键 → 值；值为空字符串时删除该键
+        metadata: std::collections::BTreeMap<String, String>,
+    },
+
     // ========== Member ==========
     /// Link a member to the order
     LinkMember { order_id: i64, member_id: i64 },
@@ -303,12 +346,96 @@ pub enum OrderCommandPayload {
         order_id: i64,
         stamp_activity_id: i64,
     },
+
+    /// Redeem loyalty points as an order discount (0 = clear the redemption)
+    RedeemPoints { order_id: i64, points: i64 },
+
+    /// Apply a coupon code as an order discount (None = remove the coupon)
+    ApplyCoupon { order_id: i64, code: Option<String> },
 }
 
 fn default_guest_count() -> i32 {
     1
 }
 
+impl OrderCommandPayload {
+    /// Command type tag (same as the serialized `type`, e.g. `"ADD_ITEMS"`)
+    pub fn kind(&self) -> &'static str {
+        match self {
+            OrderCommandPayload::OpenTable { .. } => "OPEN_TABLE",
+            OrderCommandPayload::CompleteOrder { .. } => "COMPLETE_ORDER",
+            OrderCommandPayload::VoidOrder { .. } => "VOID_ORDER",
+            OrderCommandPayload::AddItems { .. } => "ADD_ITEMS",
+            OrderCommandPayload::ModifyItem { .. } => "MODIFY_ITEM",
+            OrderCommandPayload::RemoveItem { .. } => "REMOVE_ITEM",
+            OrderCommandPayload::AddPayment { .. } => "ADD_PAYMENT",
+            OrderCommandPayload::CancelPayment { .. } => "CANCEL_PAYMENT",
+            OrderCommandPayload::SplitByItems { .. } => "SPLIT_BY_ITEMS",
+            OrderCommandPayload::SplitByAmount { .. } => "SPLIT_BY_AMOUNT",
+            OrderCommandPayload::StartAaSplit { .. } => "START_AA_SPLIT",
+            OrderCommandPayload::PayAaSplit { .. } => "PAY_AA_SPLIT",
+            OrderCommandPayload::MoveOrder { .. } => "MOVE_ORDER",
+            OrderCommandPayload::MergeOrders { .. } => "MERGE_ORDERS",
+            OrderCommandPayload::TransferItems { .. } => "TRANSFER_ITEMS",
+            OrderCommandPayload::UpdateOrderInfo { .. } => "UPDATE_ORDER_INFO",
+            OrderCommandPayload::ToggleRuleSkip { .. } => "TOGGLE_RULE_SKIP",
+            OrderCommandPayload::CompItem { .. } => "COMP_ITEM",
+            OrderCommandPayload::UncompItem { .. } => "UNCOMP_ITEM",
+            OrderCommandPayload::ApplyOrderDiscount { .. } => "APPLY_ORDER_DISCOUNT",
+            OrderCommandPayload::ApplyOrderSurcharge { .. } => "APPLY_ORDER_SURCHARGE",
+            OrderCommandPayload::HoldItems { .. } => "HOLD_ITEMS",
+            OrderCommandPayload::FireCourse { .. } => "FIRE_COURSE",
+            OrderCommandPayload::AddOrderNote { .. } => "ADD_ORDER_NOTE",
+            OrderCommandPayload::CarryOverOrder { .. } => "CARRY_OVER_ORDER",
+            OrderCommandPayload::SetOrderMetadata { .. } => "SET_ORDER_METADATA",
+            OrderCommandPayload::LinkMember { .. } => "LINK_MEMBER",
+            OrderCommandPayload::UnlinkMember { .. } => "UNLINK_MEMBER",
+            OrderCommandPayload::RedeemStamp { .. } => "REDEEM_STAMP",
+            OrderCommandPayload::CancelStampRedemption { .. } => "CANCEL_STAMP_REDEMPTION",
+            OrderCommandPayload::RedeemPoints { .. } => "REDEEM_POINTS",
+            OrderCommandPayload::ApplyCoupon { .. } => "APPLY_COUPON",
+        }
+    }
+
+    /// 执行该命令所需的员工权限 (None = 登录即可)
+    ///
+    /// edge-server 消息总线与 red_coral 桥接层共用此映射
+    pub fn required_permission(&self) -> Option<&'static str> {
+        match self {
+            OrderCommandPayload::VoidOrder { .. } => Some("orders:void"),
+            OrderCommandPayload::CompItem { .. } | OrderCommandPayload::UncompItem { .. } => {
+                Some("orders:comp")
+            }
+            OrderCommandPayload::ApplyOrderDiscount { .. }
+            | OrderCommandPayload::ApplyOrderSurcharge { .. } => Some("orders:discount"),
+            OrderCommandPayload::CancelPayment { .. } => Some("orders:refund"),
+            OrderCommandPayload::RemoveItem { .. } => Some("orders:cancel_item"),
+            OrderCommandPayload::MoveOrder { .. } | OrderCommandPayload::TransferItems { .. } => {
+                Some("tables:transfer")
+            }
+            OrderCommandPayload::MergeOrders { .. } => Some("tables:merge_bill"),
+            _ => None,
+        }
+    }
+
+    /// 主管授权人 (权限不足时由主管授权提升)
+    pub fn authorizer_id(&self) -> Option<i64> {
+        match self {
+            OrderCommandPayload::VoidOrder { authorizer_id, .. }
+            | OrderCommandPayload::ModifyItem { authorizer_id, .. }
+            | OrderCommandPayload::RemoveItem { authorizer_id, .. }
+            | OrderCommandPayload::CancelPayment { authorizer_id, .. }
+            | OrderCommandPayload::MoveOrder { authorizer_id, .. }
+            | OrderCommandPayload::MergeOrders { authorizer_id, .. }
+            | OrderCommandPayload::ApplyOrderDiscount { authorizer_id, .. }
+            | OrderCommandPayload::ApplyOrderSurcharge { authorizer_id, .. } => *authorizer_id,
+            OrderCommandPayload::CompItem { authorizer_id, .. }
+            | OrderCommandPayload::UncompItem { authorizer_id, .. } => Some(*authorizer_id),
+            _ => None,
+        }
+    }
+}
+
 impl OrderCommand {
     /// Create a new command with auto-generated ID
     pub fn new(operator_id: i64, operator_name: String, payload: OrderCommandPayload) -> Self {
@@ -318,9 +445,16 @@ impl OrderCommand {
             operator_id,
             operator_name,
             payload,
+            expected_version: None,
         }
     }
 
+    /// Attach the order version this command was built against
+    pub fn with_expected_version(mut self, version: u64) -> Self {
+        self.expected_version = Some(version);
+        self
+    }
+
     /// Get the order ID this command targets (if applicable)
     pub fn target_order_id(&self) -> Option<i64> {
         match &self.payload {
@@ -340,17 +474,26 @@ impl OrderCommand {
             OrderCommandPayload::MergeOrders {
                 source_order_id, ..
             } => Some(*source_order_id),
+            OrderCommandPayload::TransferItems {
+                source_order_id, ..
+            } => Some(*source_order_id),
             OrderCommandPayload::UpdateOrderInfo { order_id, .. } => Some(*order_id),
             OrderCommandPayload::ToggleRuleSkip { order_id, .. } => Some(*order_id),
             OrderCommandPayload::CompItem { order_id, .. } => Some(*order_id),
             OrderCommandPayload::UncompItem { order_id, .. } => Some(*order_id),
             OrderCommandPayload::ApplyOrderDiscount { order_id, .. } => Some(*order_id),
             OrderCommandPayload::ApplyOrderSurcharge { order_id, .. } => Some(*order_id),
+            OrderCommandPayload::HoldItems { order_id, .. } => Some(*order_id),
+            OrderCommandPayload::FireCourse { order_id, .. } => Some(*order_id),
             OrderCommandPayload::AddOrderNote { order_id, .. } => Some(*order_id),
+            OrderCommandPayload::CarryOverOrder { order_id, .. } => Some(*order_id),
+            OrderCommandPayload::SetOrderMetadata { order_id, .. } => Some(*order_id),
             OrderCommandPayload::LinkMember { order_id, .. } => Some(*order_id),
             OrderCommandPayload::UnlinkMember { order_id, .. } => Some(*order_id),
             OrderCommandPayload::RedeemStamp { order_id, .. } => Some(*order_id),
             OrderCommandPayload::CancelStampRedemption { order_id, .. } => Some(*order_id),
+            OrderCommandPayload::RedeemPoints { order_id, .. } => Some(*order_id),
+            OrderCommandPayload::ApplyCoupon { order_id, .. } => Some(*order_id),
         }
     }
 }
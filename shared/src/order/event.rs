@@ -2,9 +2,10 @@
 
 use super::AppliedMgRule;
 use super::types::{
-    CartItemSnapshot, ItemChanges, ItemModificationResult, LossReason, PaymentRecord,
-    PaymentSummaryItem, ServiceType, SplitItem, VoidType,
+    AppliedCoupon, CartItemSnapshot, CompRecord, ItemChanges, ItemModificationResult, LossReason,
+    PaymentRecord, PaymentSummaryItem, ServiceType, SplitItem, VoidType,
 };
+use crate::models::store_info::{CarryOverPolicy, TaxMode};
 use serde::{Deserialize, Serialize};
 
 /// Order event - immutable audit record
@@ -68,6 +69,8 @@ pub enum OrderEventType {
     OrderMovedOut,
     OrderMerged,
     OrderMergedOut,
+    ItemsTransferredOut,
+    ItemsTransferredIn,
     TableReassigned,
 
     // Other
@@ -80,14 +83,26 @@ pub enum OrderEventType {
     OrderDiscountApplied,
     OrderSurchargeApplied,
 
+    // Courses
+    ItemsHeld,
+    CourseFired,
+
     // Order Note
     OrderNoteAdded,
 
+    // Order Metadata
+    OrderMetadataSet,
+
+    // Business Day
+    OrderCarriedOver,
+
     // Member
     MemberLinked,
     MemberUnlinked,
     StampRedeemed,
     StampRedemptionCancelled,
+    PointsRedeemed,
+    CouponApplied,
 }
 
 impl std::fmt::Display for OrderEventType {
@@ -112,16 +127,24 @@ impl std::fmt::Display for OrderEventType {
             OrderEventType::OrderMovedOut => write!(f, "ORDER_MOVED_OUT"),
             OrderEventType::OrderMerged => write!(f, "ORDER_MERGED"),
             OrderEventType::OrderMergedOut => write!(f, "ORDER_MERGED_OUT"),
+            OrderEventType::ItemsTransferredOut => write!(f, "ITEMS_TRANSFERRED_OUT"),
+            OrderEventType::ItemsTransferredIn => write!(f, "ITEMS_TRANSFERRED_IN"),
             OrderEventType::TableReassigned => write!(f, "TABLE_REASSIGNED"),
             OrderEventType::OrderInfoUpdated => write!(f, "ORDER_INFO_UPDATED"),
             OrderEventType::RuleSkipToggled => write!(f, "RULE_SKIP_TOGGLED"),
             OrderEventType::OrderDiscountApplied => write!(f, "ORDER_DISCOUNT_APPLIED"),
             OrderEventType::OrderSurchargeApplied => write!(f, "ORDER_SURCHARGE_APPLIED"),
+            OrderEventType::ItemsHeld => write!(f, "ITEMS_HELD"),
+            OrderEventType::CourseFired => write!(f, "COURSE_FIRED"),
             OrderEventType::OrderNoteAdded => write!(f, "ORDER_NOTE_ADDED"),
+            OrderEventType::OrderMetadataSet => write!(f, "ORDER_METADATA_SET"),
+            OrderEventType::OrderCarriedOver => write!(f, "ORDER_CARRIED_OVER"),
             OrderEventType::MemberLinked => write!(f, "MEMBER_LINKED"),
             OrderEventType::MemberUnlinked => write!(f, "MEMBER_UNLINKED"),
             OrderEventType::StampRedeemed => write!(f, "STAMP_REDEEMED"),
             OrderEventType::StampRedemptionCancelled => write!(f, "STAMP_REDEMPTION_CANCELLED"),
+            OrderEventType::PointsRedeemed => write!(f, "POINTS_REDEEMED"),
+            OrderEventType::CouponApplied => write!(f, "COUPON_APPLIED"),
         }
     }
 }
@@ -147,6 +170,9 @@ pub enum EventPayload {
         queue_number: Option<u32>,
         /// Server-generated receipt number (always present)
         receipt_number: String,
+        /// 定价含税模式（开台时从门店设置定格）
+        #[serde(default)]
+        tax_mode: TaxMode,
     },
 
     OrderCompleted {
@@ -156,6 +182,9 @@ pub enum EventPayload {
         service_type: Option<ServiceType>,
         final_total: f64,
         payment_summary: Vec<PaymentSummaryItem>,
+        /// 外部税控设备返回的税控码 (未接入税控设备时为 None)
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        fiscal_code: Option<String>,
     },
 
     OrderVoided {
@@ -256,6 +285,12 @@ pub enum EventPayload {
         change: Option<f64>,
         #[serde(skip_serializing_if = "Option::is_none")]
         note: Option<String>,
+        /// 卡号后四位 / 终端交易参考号（客服查单用）
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        reference: Option<String>,
+        /// 支付方式附加费（已含在 amount 中，单独记入快照 payment_surcharges）
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        surcharge: Option<f64>,
     },
 
     PaymentCancelled {
@@ -374,6 +409,23 @@ pub enum EventPayload {
         authorizer_name: Option<String>,
     },
 
+    /// Selected items moved to another order (source side)
+    ItemsTransferredOut {
+        target_order_id: i64,
+        target_table_name: String,
+        instance_ids: Vec<String>,
+    },
+
+    /// Selected items received from another order (target side)
+    ItemsTransferredIn {
+        source_order_id: i64,
+        source_table_name: String,
+        items: Vec<CartItemSnapshot>,
+        /// Comp records of the transferred comped items
+        #[serde(default, skip_serializing_if = "Vec::is_empty")]
+        comps: Vec<CompRecord>,
+    },
+
     TableReassigned {
         source_table_id: i64,
         source_table_name: String,
@@ -444,6 +496,20 @@ pub enum EventPayload {
         total: f64,
     },
 
+    // ========== Courses ==========
+    /// 商品改入指定道次并保持暂缓
+    ItemsHeld {
+        course: i32,
+        instance_ids: Vec<String>,
+    },
+
+    /// 道次起菜：暂缓商品送往厨房
+    CourseFired {
+        course: i32,
+        /// 起菜商品快照 (厨房单按此打印)
+        items: Vec<CartItemSnapshot>,
+    },
+
     // ========== Order Note ==========
     /// 订单备注已添加/更新
     OrderNoteAdded {
@@ -454,6 +520,24 @@ pub enum EventPayload {
         previous_note: Option<String>,
     },
 
+    // ========== Order Metadata ==========
+    /// 订单元数据已更新（仅记录变更的键）
+    OrderMetadataSet {
+        /// 变更的键 → 新值（空字符串 = 已删除）
+        changes: std::collections::BTreeMap<String, String>,
+        /// 变更键的旧值（新增的键不在其中，用于审计）
+        #[serde(default, skip_serializing_if = "std::collections::BTreeMap::is_empty")]
+        previous: std::collections::BTreeMap<String, String>,
+    },
+
+    // ========== Business Day ==========
+    /// 订单跨营业日未结，转入新营业日（报表按此统计结转）
+    OrderCarriedOver {
+        from_business_date: String,
+        to_business_date: String,
+        policy: CarryOverPolicy,
+    },
+
     // ========== Member ==========
     MemberLinked {
         member_id: i64,
@@ -502,6 +586,16 @@ pub enum EventPayload {
         #[serde(default, skip_serializing_if = "Option::is_none")]
         comp_source_instance_id: Option<String>,
     },
+
+    /// Loyalty points redemption set (points = 0 clears it)
+    PointsRedeemed {
+        points: i64,
+        /// Discount value of the redeemed points
+        amount: f64,
+    },
+
+    /// Coupon applied to the order (None = coupon removed)
+    CouponApplied { coupon: Option<AppliedCoupon> },
 }
 
 /// Pre-calculated MG discount for a single item (carried in MemberLinked event)
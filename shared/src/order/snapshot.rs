@@ -6,9 +6,10 @@
 
 use super::AppliedRule;
 use super::types::{
-    CartItemSnapshot, CompRecord, LossReason, PaymentRecord, ServiceType, StampRedemptionState,
-    VoidType,
+    AppliedCoupon, CartItemSnapshot, CompRecord, LossReason, PaymentRecord, PaymentSurchargeLine,
+    PointsRedemption, ServiceType, StampRedemptionState, TaxBreakdownLine, VoidType,
 };
+use crate::models::store_info::TaxMode;
 use serde::{Deserialize, Serialize};
 
 /// Order status
@@ -89,6 +90,12 @@ pub struct OrderSnapshot {
     /// Tax amount
     #[serde(default)]
     pub tax: f64,
+    /// Pricing tax mode (fixed at OpenTable from store settings)
+    #[serde(default)]
+    pub tax_mode: TaxMode,
+    /// Tax breakdown by rate (non-comped items)
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub tax_breakdown: Vec<TaxBreakdownLine>,
     /// Total discount amount (order-level only)
     #[serde(default)]
     pub discount: f64,
@@ -98,6 +105,12 @@ pub struct OrderSnapshot {
     pub order_manual_discount_amount: f64,
     /// Order-level manual surcharge computed amount (整单手动附加费实际金额)
     pub order_manual_surcharge_amount: f64,
+    /// Payment-method surcharges (支付方式附加费，随支付添加/取消，已计入 total)
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub payment_surcharges: Vec<PaymentSurchargeLine>,
+    /// Σ non-cancelled payment surcharges
+    #[serde(default)]
+    pub payment_surcharge_amount: f64,
     /// Total amount to pay
     pub total: f64,
     /// Amount already paid
@@ -129,6 +142,16 @@ pub struct OrderSnapshot {
     /// Order-level note (覆盖式，None = 无备注)
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub note: Option<String>,
+    /// External references set by integrators (外卖平台单号、酒店房号等)
+    /// BTreeMap for deterministic serialization order
+    #[serde(default, skip_serializing_if = "std::collections::BTreeMap::is_empty")]
+    pub metadata: std::collections::BTreeMap<String, String>,
+    /// 最近一次结转到的营业日 (跨营业日未结订单，YYYY-MM-DD)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub carry_over_date: Option<String>,
+    /// 外部税控设备返回的税控码 (结单时写入，打印在收据上)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub fiscal_code: Option<String>,
 
     // === Order-level Rule Adjustments ===
     /// Order-level rule discount amount (server-computed)
@@ -175,6 +198,14 @@ pub struct OrderSnapshot {
     #[serde(default, skip_serializing_if = "Vec::is_empty")]
     pub stamp_redemptions: Vec<StampRedemptionState>,
 
+    /// Loyalty points redeemed as an order discount (deducted on order completion)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub points_redemption: Option<PointsRedemption>,
+
+    /// Coupon applied as an order discount (redemption counted on order completion)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub coupon: Option<AppliedCoupon>,
+
     /// Order start time
     pub start_time: i64,
     /// Order end time
@@ -221,10 +252,14 @@ impl OrderSnapshot {
             total_discount: 0.0,
             total_surcharge: 0.0,
             tax: 0.0,
+            tax_mode: TaxMode::default(),
+            tax_breakdown: Vec::new(),
             discount: 0.0,
             comp_total_amount: 0.0,
             order_manual_discount_amount: 0.0,
             order_manual_surcharge_amount: 0.0,
+            payment_surcharges: vec![],
+            payment_surcharge_amount: 0.0,
             total: 0.0,
             paid_amount: 0.0,
             remaining_amount: 0.0,
@@ -235,6 +270,9 @@ impl OrderSnapshot {
             receipt_number: String::new(),
             is_pre_payment: false,
             note: None,
+            metadata: std::collections::BTreeMap::new(),
+            carry_over_date: None,
+            fiscal_code: None,
             order_rule_discount_amount: 0.0,
             order_rule_surcharge_amount: 0.0,
             order_applied_rules: Vec::new(),
@@ -248,6 +286,8 @@ impl OrderSnapshot {
             marketing_group_name: None,
             mg_discount_amount: 0.0,
             stamp_redemptions: Vec::new(),
+            points_redemption: None,
+            coupon: None,
             start_time: now,
             end_time: None,
             created_at: now,
@@ -259,6 +299,18 @@ impl OrderSnapshot {
         snapshot
     }
 
+    /// Order version for optimistic concurrency (`OrderCommand::expected_version`)
+    pub fn version(&self) -> u64 {
+        self.last_sequence
+    }
+
+    /// Whether a course has been fired (some of its items already went to the kitchen)
+    pub fn is_course_fired(&self, course: i32) -> bool {
+        self.items
+            .iter()
+            .any(|item| item.course == Some(course) && !item.is_held)
+    }
+
     /// Check if order is active
     pub fn is_active(&self) -> bool {
         self.status == OrderStatus::Active
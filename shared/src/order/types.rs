@@ -1,6 +1,7 @@
 //! Shared types for order event sourcing
 
-use super::AppliedRule;
+use super::{AppliedRule, OrderSnapshot};
+use crate::models::price_rule::{AdjustmentType, ProductScope};
 use serde::{Deserialize, Serialize};
 use std::fmt;
 use std::str::FromStr;
@@ -222,6 +223,13 @@ pub struct CartItemSnapshot {
     /// Whether this item has been comped (gifted)
     #[serde(default)]
     pub is_comped: bool,
+
+    /// Course number (None = no coursing, fired on add)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub course: Option<i32>,
+    /// Held: not yet sent to the kitchen (waits for FireCourse)
+    #[serde(default)]
+    pub is_held: bool,
 }
 
 /// Cart item input - for adding items (without instance_id)
@@ -256,6 +264,9 @@ pub struct CartItemInput {
     /// Authorizer name
     #[serde(skip_serializing_if = "Option::is_none")]
     pub authorizer_name: Option<String>,
+    /// Course number: held until the course is fired (None = send immediately)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub course: Option<i32>,
 }
 
 /// Item option selection
@@ -360,6 +371,9 @@ pub struct PaymentInput {
     pub tendered: Option<f64>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub note: Option<String>,
+    /// Card last-4 or terminal transaction reference
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub reference: Option<String>,
 }
 
 /// Split type for categorizing split payments
@@ -371,6 +385,18 @@ pub enum SplitType {
     AaSplit,
 }
 
+/// Payment-method surcharge line (支付方式附加费)
+///
+/// Added with the payment it belongs to; cancelled together with it.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct PaymentSurchargeLine {
+    pub payment_id: i64,
+    pub method: String,
+    pub amount: f64,
+    #[serde(default)]
+    pub cancelled: bool,
+}
+
 /// Payment record in snapshot
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
 pub struct PaymentRecord {
@@ -383,6 +409,9 @@ pub struct PaymentRecord {
     pub change: Option<f64>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub note: Option<String>,
+    /// Card last-4 or terminal transaction reference
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub reference: Option<String>,
     pub timestamp: i64,
     #[serde(default)]
     pub cancelled: bool,
@@ -399,6 +428,17 @@ pub struct PaymentRecord {
     pub split_type: Option<SplitType>,
 }
 
+/// Per-rate tax breakdown line (税率分拆，按订单快照实时计算)
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct TaxBreakdownLine {
+    /// 税率: 0, 4, 10, 21
+    pub tax_rate: i32,
+    /// 税前金额
+    pub base_amount: f64,
+    /// 税额
+    pub tax_amount: f64,
+}
+
 /// Payment summary for completed order
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct PaymentSummaryItem {
@@ -419,6 +459,9 @@ pub struct CommandResponse {
     /// Error details if failed
     #[serde(skip_serializing_if = "Option::is_none")]
     pub error: Option<CommandError>,
+    /// Current order snapshot (only for VersionConflict, so the client can merge)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub current_snapshot: Option<Box<OrderSnapshot>>,
 }
 
 impl CommandResponse {
@@ -428,6 +471,7 @@ impl CommandResponse {
             success: true,
             order_id,
             error: None,
+            current_snapshot: None,
         }
     }
 
@@ -437,6 +481,15 @@ impl CommandResponse {
             success: false,
             order_id: None,
             error: Some(error),
+            current_snapshot: None,
+        }
+    }
+
+    /// Version conflict: carries the order's current snapshot
+    pub fn conflict(command_id: i64, error: CommandError, snapshot: OrderSnapshot) -> Self {
+        Self {
+            current_snapshot: Some(Box::new(snapshot)),
+            ..Self::error(command_id, error)
         }
     }
 
@@ -446,6 +499,7 @@ impl CommandResponse {
             success: true,
             order_id: None,
             error: None,
+            current_snapshot: None,
         }
     }
 }
@@ -492,6 +546,9 @@ pub enum CommandErrorCode {
     // === Order Status ===
     OrderNotActive,
     OrderAlreadyMerged,
+    SetupIncomplete,
+    /// expected_version 与订单当前版本不一致 (另一终端已修改)
+    VersionConflict,
 
     // === Member ===
     MemberAlreadyLinked,
@@ -503,6 +560,7 @@ pub enum CommandErrorCode {
 
     // === Stamp Prefetch ===
     StampActivityNotFound,
+    StampActivityOutOfWindow,
 
     // === Split Blocks ===
     AaSplitActive,
@@ -519,12 +577,24 @@ pub enum CommandErrorCode {
     InvalidQuantity,
     EmptyCompReason,
     ItemFullyPaid,
+    ProductUnavailableInZone,
+    ProductEightySixed,
+    InvalidCourse,
+    ItemAlreadyFired,
+    NoHeldItems,
 
     // === Payment ===
     PaymentExceedsRemaining,
     InsufficientTender,
     PaymentInsufficient,
+    DeliveryMinimumNotMet,
     HasPayments,
+    ChangeNotAllowed,
+    ChangeExceedsDrawerLimit,
+    InsufficientCredit,
+    RoomChargeRejected,
+    FiscalDeviceUnavailable,
+    FiscalRejected,
 
     // === Merge ===
     CannotMergeSelf,
@@ -548,8 +618,19 @@ pub enum CommandErrorCode {
     StampNoMatch,
     StampRedemptionNotFound,
     StampTargetMismatch,
+    StampItemNotTransferable,
     StampProductNotAvailable,
 
+    // === Points ===
+    InsufficientPoints,
+    PointsRedemptionUnavailable,
+
+    // === Coupon ===
+    CouponNotFound,
+    CouponExpired,
+    CouponExhausted,
+    CouponNotApplicable,
+
     // === Rule ===
     RuleNotFoundInOrder,
 
@@ -611,6 +692,31 @@ pub struct StampRedemptionState {
     pub comp_source_instance_id: Option<String>,
 }
 
+/// Loyalty points redemption tracked in snapshot (settled on order completion)
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct PointsRedemption {
+    pub points: i64,
+    /// Discount value of the redeemed points
+    pub amount: f64,
+}
+
+/// Coupon applied to an order (redemption recorded on order completion)
+///
+/// 折扣参数在应用时冻结，之后修改优惠码不影响已应用的订单。
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct AppliedCoupon {
+    pub coupon_id: i64,
+    pub code: String,
+    pub name: String,
+    pub product_scope: ProductScope,
+    pub target_id: Option<i64>,
+    pub adjustment_type: AdjustmentType,
+    pub adjustment_value: f64,
+    /// Calculated discount amount (maintained by recalculate_totals)
+    #[serde(default)]
+    pub amount: f64,
+}
+
 /// Item modification result
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct ItemModificationResult {
@@ -655,6 +761,8 @@ mod tests {
             category_id: None,
             category_name: None,
             is_comped: false,
+            course: None,
+            is_held: false,
         };
 
         assert_eq!(item.manual_discount_percent, Some(10.0));
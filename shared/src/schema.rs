@@ -0,0 +1,264 @@
+//! Versioned payload schemas (载荷版本化)
+//!
+//! Key payloads carry an explicit `schema_version` key next to their fields
+//! when they are persisted (redb order storage) or put on the bus:
+//!
+//! ```json
+//! { "schema_version": 1, "order_id": 1, ... }
+//! ```
+//!
+//! - Old readers ignore the extra key (no type uses `deny_unknown_fields`)
+//! - Documents without the key were written before versioning → version 0
+//! - [`from_value`] runs [`SchemaVersioned::upgrade`] once per step from the
+//!   stored version to the current one, so removed/renamed fields are mapped
+//!   before serde sees the document
+//! - Documents from a newer build are decoded best-effort (unknown fields
+//!   ignored), never rejected — an older edge must keep reading its own store
+//!   after a downgrade
+//!
+//! Bumping a version: increase `SCHEMA_VERSION`, add the migration step to
+//! `upgrade`, and add a golden file under `tests/fixtures/schema/` for the
+//! new version. Golden files of released versions are never edited.
+
+use serde::Serialize;
+use serde::de::{DeserializeOwned, Error as _};
+use serde_json::{Map, Value};
+
+/// Version key injected into the top-level JSON object
+pub const VERSION_KEY: &str = "schema_version";
+
+/// A payload with an explicit schema version
+pub trait SchemaVersioned: Serialize + DeserializeOwned {
+    /// Version written by this build
+    const SCHEMA_VERSION: u16;
+
+    /// Migrate a document from version `from` to `from + 1`
+    ///
+    /// Use [`rename_field`] / [`drop_field`] / [`default_field`] for the
+    /// common cases. The default is a no-op (additive changes only).
+    fn upgrade(_from: u16, _doc: &mut Map<String, Value>) -> Result<(), String> {
+        Ok(())
+    }
+}
+
+/// Serialize with the current `schema_version`
+pub fn to_value<T: SchemaVersioned>(data: &T) -> serde_json::Result<Value> {
+    let mut value = serde_json::to_value(data)?;
+    if let Value::Object(map) = &mut value {
+        map.insert(VERSION_KEY.to_string(), Value::from(T::SCHEMA_VERSION));
+    }
+    Ok(value)
+}
+
+pub fn to_vec<T: SchemaVersioned>(data: &T) -> serde_json::Result<Vec<u8>> {
+    serde_json::to_vec(&to_value(data)?)
+}
+
+/// Read the stored version (missing = 0)
+pub fn version_of(value: &Value) -> serde_json::Result<u16> {
+    match value.get(VERSION_KEY) {
+        None => Ok(0),
+        Some(v) => v
+            .as_u64()
+            .and_then(|v| u16::try_from(v).ok())
+            .ok_or_else(|| serde_json::Error::custom(format!("invalid {VERSION_KEY}: {v}"))),
+    }
+}
+
+/// Decode any known version, upgrading older documents first
+pub fn from_value<T: SchemaVersioned>(mut value: Value) -> serde_json::Result<T> {
+    let version = version_of(&value)?;
+    if let Value::Object(map) = &mut value {
+        map.remove(VERSION_KEY);
+        if version > T::SCHEMA_VERSION {
+            tracing::debug!(
+                version,
+                supported = T::SCHEMA_VERSION,
+                "Decoding payload from a newer schema version"
+            );
+        }
+        for from in version..T::SCHEMA_VERSION {
+            T::upgrade(from, map).map_err(serde_json::Error::custom)?;
+        }
+    }
+    serde_json::from_value(value)
+}
+
+pub fn from_slice<T: SchemaVersioned>(bytes: &[u8]) -> serde_json::Result<T> {
+    from_value(serde_json::from_slice(bytes)?)
+}
+
+// ========== Migration helpers ==========
+
+/// Renamed field: move `old` to `new` (kept if `new` is already present)
+pub fn rename_field(doc: &mut Map<String, Value>, old: &str, new: &str) {
+    if let Some(value) = doc.remove(old)
+        && !doc.contains_key(new)
+    {
+        doc.insert(new.to_string(), value);
+    }
+}
+
+/// Removed field: drop it so it cannot collide with a later reuse of the name
+pub fn drop_field(doc: &mut Map<String, Value>, field: &str) {
+    doc.remove(field);
+}
+
+/// New required field: fill `value` when absent
+pub fn default_field(doc: &mut Map<String, Value>, field: &str, value: Value) {
+    doc.entry(field.to_string()).or_insert(value);
+}
+
+// ========== Versions ==========
+//
+// Changelog per type — one line per released version.
+
+/// OrderEvent
+/// - v0: pre-versioning rows (identical layout to v1)
+/// - v1: explicit `schema_version`
+impl SchemaVersioned for crate::order::OrderEvent {
+    const SCHEMA_VERSION: u16 = 1;
+}
+
+/// OrderSnapshot
+/// - v0: pre-versioning rows (identical layout to v1)
+/// - v1: explicit `schema_version`
+impl SchemaVersioned for crate::order::OrderSnapshot {
+    const SCHEMA_VERSION: u16 = 1;
+}
+
+/// Bus payloads — the wire protocol itself is versioned by
+/// [`PROTOCOL_VERSION`](crate::message::PROTOCOL_VERSION) at handshake;
+/// these versions track the payload layout within a protocol version.
+impl SchemaVersioned for crate::message::RequestCommandPayload {
+    const SCHEMA_VERSION: u16 = 1;
+}
+
+impl SchemaVersioned for crate::message::ResponsePayload {
+    const SCHEMA_VERSION: u16 = 1;
+}
+
+impl SchemaVersioned for crate::message::SyncPayload {
+    const SCHEMA_VERSION: u16 = 1;
+}
+
+impl SchemaVersioned for crate::message::NotificationPayload {
+    const SCHEMA_VERSION: u16 = 1;
+}
+
+impl SchemaVersioned for crate::message::ServerCommandPayload {
+    const SCHEMA_VERSION: u16 = 1;
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::message::{RequestCommandPayload, ResponsePayload, SyncPayload};
+    use crate::order::{OrderEvent, OrderSnapshot};
+    use serde::Deserialize;
+
+    fn golden(name: &str) -> Value {
+        let path = format!(
+            "{}/tests/fixtures/schema/{name}",
+            env!("CARGO_MANIFEST_DIR")
+        );
+        let json = std::fs::read_to_string(&path).unwrap_or_else(|e| panic!("{path}: {e}"));
+        serde_json::from_str(&json).unwrap_or_else(|e| panic!("{path}: {e}"))
+    }
+
+    /// golden → decode → encode → decode → encode is stable and tagged
+    fn assert_round_trip<T: SchemaVersioned>(name: &str) -> T {
+        let decoded: T = from_value(golden(name)).unwrap_or_else(|e| panic!("{name}: {e}"));
+        let encoded = to_value(&decoded).unwrap();
+        assert_eq!(version_of(&encoded).unwrap(), T::SCHEMA_VERSION, "{name}");
+
+        let again: T = from_value(encoded.clone()).unwrap();
+        assert_eq!(
+            to_value(&again).unwrap(),
+            encoded,
+            "{name}: round trip drifted"
+        );
+        decoded
+    }
+
+    #[test]
+    fn test_order_event_golden_files() {
+        let legacy: OrderEvent = assert_round_trip("order_event_v0.json");
+        assert_eq!(legacy.client_timestamp, None);
+        match legacy.payload {
+            crate::order::EventPayload::TableOpened { tax_mode, .. } => {
+                assert_eq!(tax_mode, crate::models::TaxMode::Inclusive);
+            }
+            other => panic!("unexpected payload {other:?}"),
+        }
+
+        let v1: OrderEvent = assert_round_trip("order_event_v1.json");
+        assert_eq!(v1.sequence, 42);
+        assert_eq!(v1.client_timestamp, Some(1759999999500));
+    }
+
+    #[test]
+    fn test_order_snapshot_golden_files() {
+        let legacy: OrderSnapshot = assert_round_trip("order_snapshot_v0.json");
+        assert!(legacy.comps.is_empty());
+        assert_eq!(legacy.paid_amount, 0.0);
+        assert!(legacy.state_checksum.is_empty());
+
+        let v1: OrderSnapshot = assert_round_trip("order_snapshot_v1.json");
+        assert_eq!(v1.table_name.as_deref(), Some("T5"));
+        assert_eq!(v1.total, 23.5);
+    }
+
+    #[test]
+    fn test_bus_payload_golden_files() {
+        let request: RequestCommandPayload = assert_round_trip("request_command_v1.json");
+        assert_eq!(request.action, "sync.orders");
+        let response: ResponsePayload = assert_round_trip("response_v1.json");
+        assert!(response.success);
+        let sync: SyncPayload = assert_round_trip("sync_v1.json");
+        assert_eq!(sync.version, 7);
+    }
+
+    #[derive(Debug, Serialize, Deserialize, PartialEq)]
+    struct Renamed {
+        display_name: String,
+        #[serde(default)]
+        seats: i32,
+    }
+
+    /// v0 `name`, v1 dropped `legacy_flag`, v2 renamed `name` → `display_name`
+    impl SchemaVersioned for Renamed {
+        const SCHEMA_VERSION: u16 = 2;
+
+        fn upgrade(from: u16, doc: &mut Map<String, Value>) -> Result<(), String> {
+            match from {
+                0 => drop_field(doc, "legacy_flag"),
+                1 => rename_field(doc, "name", "display_name"),
+                _ => {}
+            }
+            Ok(())
+        }
+    }
+
+    #[test]
+    fn test_upgrades_run_per_step_and_newer_versions_decode() {
+        let v0 = serde_json::json!({ "name": "Terraza", "legacy_flag": true, "seats": 4 });
+        assert_eq!(
+            from_value::<Renamed>(v0).unwrap(),
+            Renamed {
+                display_name: "Terraza".to_string(),
+                seats: 4
+            }
+        );
+
+        let v1 = serde_json::json!({ "schema_version": 1, "name": "Barra" });
+        assert_eq!(from_value::<Renamed>(v1).unwrap().display_name, "Barra");
+
+        // 新版本写入的文档：忽略未知字段
+        let v9 = serde_json::json!({ "schema_version": 9, "display_name": "Sala", "vip": true });
+        assert_eq!(from_value::<Renamed>(v9).unwrap().display_name, "Sala");
+
+        let bad = serde_json::json!({ "schema_version": "one", "display_name": "Sala" });
+        assert!(from_value::<Renamed>(bad).is_err());
+    }
+}
@@ -0,0 +1,169 @@
+//! 链路追踪导出 (OpenTelemetry / OTLP)
+//!
+//! edge-server 与 crab-cloud 共用：配置 OTLP 端点后，订单命令、同步与 HTTP 处理的
+//! tracing span 通过 OTLP/HTTP 批量导出到 collector；未配置时只写本地日志。
+//!
+//! - 导出器在 `otel` feature 下编译，未启用时 [`OtlpConfig`] 仍可解析，
+//!   传播函数退化为空操作 (不生成 / 不解析 `traceparent`)
+//! - 跨进程传播只使用 W3C `traceparent`：HTTP 走同名请求头，消息总线走帧扩展
+//!   (见 [`crate::message::trace_context`])
+
+use serde::{Deserialize, Serialize};
+
+/// W3C Trace Context 请求头
+pub const TRACEPARENT_HEADER: &str = "traceparent";
+
+/// OTLP 导出配置
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct OtlpConfig {
+    /// OTLP/HTTP traces 端点 (如 `http://collector:4318/v1/traces`)
+    pub endpoint: String,
+    /// 附加请求头 (鉴权等)，如 `authorization=Bearer xxx`
+    #[serde(default)]
+    pub headers: Vec<(String, String)>,
+    /// `service.name` 资源属性
+    pub service_name: String,
+}
+
+impl OtlpConfig {
+    /// 从标准 OTel 环境变量读取，未设置端点返回 None
+    ///
+    /// | 变量 | 说明 |
+    /// |------|------|
+    /// | OTEL_EXPORTER_OTLP_ENDPOINT | traces 端点 (必填) |
+    /// | OTEL_EXPORTER_OTLP_HEADERS | `k1=v1,k2=v2` |
+    /// | OTEL_SERVICE_NAME | 默认 `default_service` |
+    pub fn from_env(default_service: &str) -> Option<Self> {
+        let endpoint = std::env::var("OTEL_EXPORTER_OTLP_ENDPOINT")
+            .ok()
+            .filter(|v| !v.trim().is_empty())?;
+        Some(Self {
+            endpoint: endpoint.trim().to_string(),
+            headers: std::env::var("OTEL_EXPORTER_OTLP_HEADERS")
+                .map(|v| parse_headers(&v))
+                .unwrap_or_default(),
+            service_name: std::env::var("OTEL_SERVICE_NAME")
+                .ok()
+                .filter(|v| !v.trim().is_empty())
+                .unwrap_or_else(|| default_service.to_string()),
+        })
+    }
+}
+
+/// 解析 `k1=v1,k2=v2` (值可含 `=`，键值两端去空白，空键忽略)
+pub fn parse_headers(value: &str) -> Vec<(String, String)> {
+    value
+        .split(',')
+        .filter_map(|pair| {
+            let (key, value) = pair.split_once('=')?;
+            let key = key.trim();
+            (!key.is_empty()).then(|| (key.to_string(), value.trim().to_string()))
+        })
+        .collect()
+}
+
+#[cfg(feature = "otel")]
+mod otel {
+    use std::collections::HashMap;
+
+    use opentelemetry::propagation::TextMapPropagator;
+    use opentelemetry::trace::TracerProvider as _;
+    use opentelemetry::{KeyValue, global};
+    use opentelemetry_otlp::{SpanExporter, WithExportConfig, WithHttpConfig};
+    use opentelemetry_sdk::Resource;
+    use opentelemetry_sdk::propagation::TraceContextPropagator;
+    use opentelemetry_sdk::trace::{Tracer, TracerProvider};
+    use tracing::Subscriber;
+    use tracing_opentelemetry::{OpenTelemetryLayer, OpenTelemetrySpanExt};
+    use tracing_subscriber::registry::LookupSpan;
+
+    use super::{OtlpConfig, TRACEPARENT_HEADER};
+
+    /// 构建 OTLP 导出 layer 并注册全局 TracerProvider
+    ///
+    /// 批量导出运行在 Tokio 上，须在运行时内调用。
+    pub fn layer<S>(config: &OtlpConfig) -> Result<OpenTelemetryLayer<S, Tracer>, String>
+    where
+        S: Subscriber + for<'span> LookupSpan<'span>,
+    {
+        let exporter = SpanExporter::builder()
+            .with_http()
+            .with_endpoint(&config.endpoint)
+            .with_headers(config.headers.iter().cloned().collect::<HashMap<_, _>>())
+            .build()
+            .map_err(|e| format!("Failed to build OTLP exporter: {e}"))?;
+
+        let provider = TracerProvider::builder()
+            .with_batch_exporter(exporter, opentelemetry_sdk::runtime::Tokio)
+            .with_resource(Resource::new(vec![KeyValue::new(
+                "service.name",
+                config.service_name.clone(),
+            )]))
+            .build();
+        let tracer = provider.tracer(config.service_name.clone());
+
+        global::set_text_map_propagator(TraceContextPropagator::new());
+        global::set_tracer_provider(provider);
+        Ok(tracing_opentelemetry::layer().with_tracer(tracer))
+    }
+
+    /// 刷新并关闭导出器 (退出前调用，避免丢失最后一批 span)
+    pub fn shutdown() {
+        global::shutdown_tracer_provider();
+    }
+
+    pub fn current_traceparent() -> Option<String> {
+        let context = tracing::Span::current().context();
+        let mut carrier = HashMap::new();
+        TraceContextPropagator::new().inject_context(&context, &mut carrier);
+        carrier.remove(TRACEPARENT_HEADER)
+    }
+
+    pub fn set_parent(span: &tracing::Span, traceparent: &str) {
+        let carrier = HashMap::from([(TRACEPARENT_HEADER.to_string(), traceparent.to_string())]);
+        span.set_parent(TraceContextPropagator::new().extract(&carrier));
+    }
+}
+
+#[cfg(feature = "otel")]
+pub use otel::{layer, shutdown};
+
+/// 当前 span 的 W3C `traceparent` (未启用导出 / 无活动 span 时为 None)
+pub fn current_traceparent() -> Option<String> {
+    #[cfg(feature = "otel")]
+    {
+        otel::current_traceparent()
+    }
+    #[cfg(not(feature = "otel"))]
+    {
+        None
+    }
+}
+
+/// 以远端 `traceparent` 作为 `span` 的父上下文 (格式无效时忽略)
+pub fn set_parent(span: &tracing::Span, traceparent: &str) {
+    if !crate::message::trace_context::is_valid_traceparent(traceparent) {
+        return;
+    }
+    #[cfg(feature = "otel")]
+    otel::set_parent(span, traceparent);
+    #[cfg(not(feature = "otel"))]
+    let _ = span;
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_headers() {
+        assert_eq!(
+            parse_headers("authorization=Bearer a=b, x-tenant = 42 ,=skip,novalue"),
+            vec![
+                ("authorization".to_string(), "Bearer a=b".to_string()),
+                ("x-tenant".to_string(), "42".to_string()),
+            ]
+        );
+        assert!(parse_headers("").is_empty());
+    }
+}